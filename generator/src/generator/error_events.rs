@@ -35,6 +35,17 @@ pub(super) fn generate(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out.indent(), ".ok_or(ParseError::InsufficientData)");
     });
     outln!(out, "}}");
+    outln!(out, "");
+    outln!(
+        out,
+        "/// Parse an X11 event in the given byte order into a concrete event type."
+    );
+    outln!(
+        out,
+        "fn parse_event<T: TryParse>(event: &[u8], order: ByteOrder) -> Result<T, ParseError> {{"
+    );
+    outln!(out.indent(), "Ok(T::try_parse_with_order(event, order)?.0)");
+    outln!(out, "}}");
 }
 
 fn generate_errors(out: &mut Output, module: &xcbgen::defs::Module) {
@@ -175,13 +186,28 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
             out,
             "/// Parse a generic X11 event into a concrete event type."
         );
+        outln!(out, "pub fn parse(");
+        outln!(out.indent(), "event: &[u8],");
+        outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out, ") -> Result<Self, ParseError> {{");
+        outln!(
+            out.indent(),
+            "Self::parse_with_order(event, ext_info_provider, ByteOrder::native())"
+        );
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "/// Parse a generic X11 event in the given byte order into a concrete event type."
+        );
         outln!(
             out,
             "#[allow(clippy::cognitive_complexity, clippy::match_single_binding)]",
         );
-        outln!(out, "pub fn parse(");
+        outln!(out, "pub fn parse_with_order(");
         outln!(out.indent(), "event: &[u8],");
         outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
         outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
             outln!(out, "let event_code = response_type(event)?;");
@@ -196,7 +222,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                 outln!(
                     out,
                     "0 => return Ok({}),",
-                    "Self::Error(X11Error::try_parse(event, ext_info_provider)?)",
+                    "Self::Error(X11Error::try_parse_with_order(event, ext_info_provider, order)?)",
                 );
                 let xproto_ns = module.namespace("xproto").unwrap();
                 let event_defs = sorted_events(&xproto_ns);
@@ -207,7 +233,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                     }
                     outln!(
                         out,
-                        "xproto::{}_EVENT => return Ok(Self::{}(event.try_into()?)),",
+                        "xproto::{}_EVENT => return Ok(Self::{}(parse_event(event, order)?)),",
                         super::camel_case_to_upper_snake(event_name),
                         event_name,
                     );
@@ -215,7 +241,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                 outln!(
                     out,
                     "xproto::GE_GENERIC_EVENT => {},",
-                    "return Self::from_generic_event(event, ext_info_provider)",
+                    "return Self::from_generic_event(event, ext_info_provider, order)",
                 );
                 outln!(out, "_ => {{}}");
             });
@@ -265,7 +291,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                             }
                             outln!(
                                 out.indent(),
-                                "{}::{}_EVENT => Ok(Self::{}{}(event.try_into()?)),",
+                                "{}::{}_EVENT => Ok(Self::{}{}(parse_event(event, order)?)),",
                                 ns.header,
                                 super::camel_case_to_upper_snake(event_def.name()),
                                 get_ns_name_prefix(ns),
@@ -288,11 +314,12 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
         outln!(out, "fn from_generic_event(");
         outln!(out.indent(), "event: &[u8],");
         outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+        outln!(out.indent(), "order: ByteOrder,");
         outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
             outln!(
                 out,
-                "let ge_event = xproto::GeGenericEvent::try_from(event)?;"
+                "let ge_event: xproto::GeGenericEvent = parse_event(event, order)?;"
             );
             outln!(out, "let ext_name = ext_info_provider");
             outln!(out.indent(), ".get_from_major_opcode(ge_event.extension)");
@@ -322,7 +349,7 @@ fn generate_events(out: &mut Output, module: &xcbgen::defs::Module) {
                             }
                             outln!(
                                 out.indent(),
-                                "{}::{}_EVENT => Ok(Self::{}{}(event.try_into()?)),",
+                                "{}::{}_EVENT => Ok(Self::{}{}(parse_event(event, order)?)),",
                                 ns.header,
                                 super::camel_case_to_upper_snake(event_def.name()),
                                 get_ns_name_prefix(ns),
@@ -449,11 +476,100 @@ pub fn sent_event(&self) -> bool {{
     self.raw_response_type() & 0x80 != 0
 }}"
         );
+        outln!(out, "");
+
+        outln!(out, "/// Get a description of this event.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Returns `None` for `Event::Unknown` and `Event::Error`. See the \
+             [`introspect`](crate::introspect) module."
+        );
+        outln!(
+            out,
+            "pub fn info(&self) -> Option<&'static crate::introspect::EventInfo> {{"
+        );
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            outln!(out.indent(), "Event::Unknown(_) | Event::Error(_) => None,");
+            for ns in namespaces.iter() {
+                let event_defs = sorted_events(ns);
+                let has_feature = super::ext_has_feature(&ns.header);
+                // The events are in the same order as in the module's EVENT_INFOS
+                for (index, event_def) in event_defs.iter().enumerate() {
+                    if has_feature {
+                        outln!(out.indent(), "#[cfg(feature = \"{}\")]", ns.header);
+                    }
+                    outln!(
+                        out.indent(),
+                        "Event::{}{}(_) => Some(&{}::EVENT_INFOS[{}]),",
+                        get_ns_name_prefix(ns),
+                        event_def.name(),
+                        ns.header,
+                        index,
+                    );
+                }
+            }
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+
+    outln!(out, "impl Trace for Event {{");
+    out.indented(|out| {
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(
+            out,
+            "fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {{"
+        );
+        out.indented(|out| {
+            outln!(out, "let (name, event): (&str, &dyn Trace) = match self {{");
+            out.indented(|out| {
+                outln!(out, "Event::Unknown(value) => {{");
+                out.indented(|out| {
+                    outln!(out, "tracer.write_str(\"Event Unknown\")?;");
+                    outln!(
+                        out,
+                        "return tracer.field(\"data\", |tracer| tracer.list(value, |tracer, value| tracer.value(value)));"
+                    );
+                });
+                outln!(out, "}}");
+                outln!(out, "Event::Error(value) => return value.trace(tracer),");
+                for ns in namespaces.iter() {
+                    let event_defs = sorted_events(ns);
+                    let has_feature = super::ext_has_feature(&ns.header);
+                    for event_def in event_defs.iter() {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        let trace_name = if ns.ext_info.is_some() {
+                            format!("{}::{}", ns.header, event_def.name())
+                        } else {
+                            event_def.name().to_string()
+                        };
+                        outln!(
+                            out,
+                            "Event::{}{}(value) => (\"{}\", value),",
+                            get_ns_name_prefix(ns),
+                            event_def.name(),
+                            trace_name,
+                        );
+                    }
+                }
+            });
+            outln!(out, "}};");
+            outln!(out, "tracer.write_str(\"Event \")?;");
+            outln!(out, "tracer.write_str(name)?;");
+            outln!(out, "event.trace(tracer)");
+        });
+        outln!(out, "}}");
     });
     outln!(out, "}}");
 }
 
-fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
+pub(super) fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     let mut errors: Vec<_> = ns
         .error_defs
         .borrow()
@@ -478,7 +594,7 @@ fn sorted_errors(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::ErrorDef> {
     errors
 }
 
-fn sorted_events(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::EventDef> {
+pub(super) fn sorted_events(ns: &xcbgen::defs::Namespace) -> Vec<xcbgen::defs::EventDef> {
     let mut events: Vec<_> = ns.event_defs.borrow().values().cloned().collect();
     events.sort_by(|a, b| a.name().cmp(b.name()));
     events
@@ -7,63 +7,119 @@ use std::path::PathBuf;
 #[macro_use]
 mod output;
 mod error_events;
+mod names;
 mod namespace;
+mod resources;
 mod special_cases;
 
 use output::Output;
 
-pub(crate) fn generate(module: &xcbgen::defs::Module) -> HashMap<PathBuf, String> {
-    let mut out_map = HashMap::new();
+/// The generated files, keyed by their file name.
+pub(crate) struct Generated {
+    /// Files for `x11rb-protocol/src/protocol`.
+    pub(crate) proto_files: HashMap<PathBuf, String>,
+    /// Files for `src/protocol` in x11rb.
+    pub(crate) x11rb_files: HashMap<PathBuf, String>,
+}
 
-    let mut main_out = Output::new();
-    write_code_header(&mut main_out);
-    outln!(main_out, "//! Bindings to the X11 protocol.");
-    outln!(main_out, "//!");
-    outln!(
-        main_out,
-        "//! Each sub-module of this module corresponds to one X11 extension. It contains all the"
-    );
+/// Generate the code for all namespaces in `module`.
+///
+/// With `xid_newtypes`, XID types like `Window` become distinct types instead of aliases of `u32`.
+pub(crate) fn generate(module: &xcbgen::defs::Module, xid_newtypes: bool) -> Generated {
+    let mut proto_files = HashMap::new();
+    let mut x11rb_files = HashMap::new();
+
+    let mut main_proto_out = Output::new();
+    write_code_header(&mut main_proto_out);
+    write_protocol_module_doc(&mut main_proto_out);
+    outln!(main_proto_out, "use alloc::borrow::Cow;");
+    outln!(main_proto_out, "use alloc::vec;");
+    outln!(main_proto_out, "use alloc::vec::Vec;");
+    outln!(main_proto_out, "use core::convert::{{TryFrom, TryInto}};");
+    outln!(main_proto_out, "use crate::errors::ParseError;");
+    outln!(main_proto_out, "use crate::trace::{{Trace, Tracer}};");
+    outln!(main_proto_out, "use crate::utils::RawFdContainer;");
     outln!(
-        main_out,
-        "//! definitions from that extension. The core X11 protocol is in \
-         [`xproto`](xproto/index.html).",
+        main_proto_out,
+        "use crate::x11_utils::{{ByteOrder, TryParse, TryParseFd, X11Error}};"
     );
-    outln!(main_out, "");
-    outln!(main_out, "use std::borrow::Cow;");
-    outln!(main_out, "use std::convert::{{TryFrom, TryInto}};");
-    outln!(main_out, "use crate::errors::ParseError;");
-    outln!(main_out, "use crate::utils::RawFdContainer;");
-    outln!(main_out, "use crate::x11_utils::X11Error;");
     outln!(
-        main_out,
+        main_proto_out,
         "use crate::x11_utils::{{ExtInfoProvider, ReplyParsingFunction, Request as RequestTrait, RequestHeader}};"
     );
-    outln!(main_out, "");
+    outln!(main_proto_out, "use crate::{{BufWithFds, PiecewiseBuf}};");
+    outln!(main_proto_out, "");
+
+    let mut main_x11rb_out = Output::new();
+    write_code_header(&mut main_x11rb_out);
+    write_protocol_module_doc(&mut main_x11rb_out);
 
     let caches = RefCell::new(namespace::Caches::default());
     caches.borrow_mut().gather_enum_infos(module);
 
     let mut enum_cases = HashMap::new();
     for ns in module.sorted_namespaces() {
-        let mut ns_out = Output::new();
-        namespace::generate(&ns, &caches, &mut ns_out, &mut enum_cases);
-        out_map.insert(
+        let mut ns_proto_out = Output::new();
+        let mut ns_x11rb_out = Output::new();
+        namespace::generate(
+            &ns,
+            &caches,
+            xid_newtypes,
+            &mut ns_proto_out,
+            &mut ns_x11rb_out,
+            &mut enum_cases,
+        );
+        proto_files.insert(
+            PathBuf::from(format!("{}.rs", ns.header)),
+            ns_proto_out.into_data(),
+        );
+        x11rb_files.insert(
             PathBuf::from(format!("{}.rs", ns.header)),
-            ns_out.into_data(),
+            ns_x11rb_out.into_data(),
         );
 
-        if ext_has_feature(&ns.header) {
-            outln!(main_out, "#[cfg(feature = \"{}\")]", ns.header);
+        for main_out in [&mut main_proto_out, &mut main_x11rb_out].iter_mut() {
+            if ext_has_feature(&ns.header) {
+                outln!(main_out, "#[cfg(feature = \"{}\")]", ns.header);
+            }
+            outln!(main_out, "pub mod {};", ns.header);
         }
-        outln!(main_out, "pub mod {};", ns.header);
     }
-    outln!(main_out, "");
+    outln!(main_proto_out, "");
+    outln!(main_x11rb_out, "");
+
+    namespace::generate_request_reply_enum(&mut main_proto_out, module, enum_cases);
+    error_events::generate(&mut main_proto_out, module);
+    outln!(main_proto_out, "");
+    names::generate(&mut main_proto_out, module);
+
+    outln!(
+        main_x11rb_out,
+        "pub use x11rb_protocol::protocol::{{error_name, event_name, request_name, ErrorKind, Event, Reply, Request}};"
+    );
 
-    namespace::generate_request_reply_enum(&mut main_out, module, enum_cases);
-    error_events::generate(&mut main_out, module);
+    proto_files.insert(PathBuf::from("mod.rs"), main_proto_out.into_data());
+    x11rb_files.insert(PathBuf::from("mod.rs"), main_x11rb_out.into_data());
+    Generated {
+        proto_files,
+        x11rb_files,
+    }
+}
 
-    out_map.insert(PathBuf::from("mod.rs"), main_out.into_data());
-    out_map
+/// Add the documentation of the `protocol` module.
+fn write_protocol_module_doc(out: &mut Output) {
+    outln!(out, "//! Bindings to the X11 protocol.");
+    outln!(out, "//!");
+    outln!(
+        out,
+        "//! Each sub-module of this module corresponds to one X11 extension. It contains all the"
+    );
+    outln!(
+        out,
+        "//! definitions from that extension. The core X11 protocol is in \
+         [`xproto`](xproto/index.html).",
+    );
+    outln!(out, "");
 }
 
 fn ext_has_feature(name: &str) -> bool {
@@ -0,0 +1,350 @@
+use super::error_events::{sorted_errors, sorted_events};
+use super::get_ns_name_prefix;
+use super::output::Output;
+
+/// Generate functions for looking up the names of requests, events and errors.
+pub(super) fn generate(out: &mut Output, module: &xcbgen::defs::Module) {
+    generate_request_name(out, module);
+    outln!(out, "");
+    generate_event_name(out, module);
+    outln!(out, "");
+    generate_error_name(out, module);
+}
+
+/// Get the human-readable name of something from the given namespace.
+fn qualified_name(ns: &xcbgen::defs::Namespace, name: &str) -> String {
+    match ns.ext_info {
+        Some(ref ext_info) => format!("{}::{}", ext_info.name, name),
+        None => name.to_string(),
+    }
+}
+
+fn generate_request_name(out: &mut Output, module: &xcbgen::defs::Module) {
+    let namespaces = module.sorted_namespaces();
+
+    outln!(
+        out,
+        "/// Get the name of a request from its major and minor opcode."
+    );
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// The names of extension requests are prefixed with the name of the extension, for example"
+    );
+    outln!(
+        out,
+        "/// `RandR::SetCrtcConfig`. The extension that a major opcode belongs to is looked up via"
+    );
+    outln!(
+        out,
+        "/// `ext_info_provider`. Returns `None` if the request is not known."
+    );
+    outln!(out, "#[allow(clippy::match_single_binding)]");
+    outln!(out, "pub fn request_name(");
+    outln!(out.indent(), "major_opcode: u8,");
+    outln!(out.indent(), "minor_opcode: u16,");
+    outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+    outln!(out, ") -> Option<&'static str> {{");
+    out.indented(|out| {
+        outln!(out, "// Check if this is a core protocol request");
+        outln!(out, "match major_opcode {{");
+        out.indented(|out| {
+            let xproto_ns = module.namespace("xproto").unwrap();
+            for request_def in sorted_requests(&xproto_ns) {
+                outln!(
+                    out,
+                    "xproto::{}_REQUEST => return Some(\"{}\"),",
+                    request_opcode_name(&request_def),
+                    request_def.name,
+                );
+            }
+            outln!(out, "_ => {{}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "// Find the extension that this request could belong to"
+        );
+        outln!(out, "let minor_opcode = u8::try_from(minor_opcode).ok()?;");
+        outln!(
+            out,
+            "let ext_info = ext_info_provider.get_from_major_opcode(major_opcode);"
+        );
+        outln!(out, "match ext_info {{");
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                // skip xproto
+                if ns.ext_info.is_none() {
+                    continue;
+                }
+                let request_defs = sorted_requests(ns);
+                if request_defs.is_empty() {
+                    continue;
+                }
+
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(out, "Some(({}::X11_EXTENSION_NAME, _)) => {{", ns.header);
+                out.indented(|out| {
+                    outln!(out, "match minor_opcode {{");
+                    for request_def in request_defs.iter() {
+                        outln!(
+                            out.indent(),
+                            "{}::{}_REQUEST => Some(\"{}\"),",
+                            ns.header,
+                            request_opcode_name(request_def),
+                            qualified_name(ns, &request_def.name),
+                        );
+                    }
+                    outln!(out.indent(), "_ => None,");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+fn generate_event_name(out: &mut Output, module: &xcbgen::defs::Module) {
+    let namespaces = module.sorted_namespaces();
+
+    outln!(out, "/// Get the name of an event from its raw bytes.");
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// The names of extension events are prefixed with the name of the extension, for example"
+    );
+    outln!(
+        out,
+        "/// `RandR::ScreenChangeNotify`. The extension that an event belongs to is looked up via"
+    );
+    outln!(
+        out,
+        "/// `ext_info_provider`. Returns `None` if the event is not known or if the packet is an error."
+    );
+    outln!(out, "#[allow(clippy::match_single_binding)]");
+    outln!(out, "pub fn event_name(");
+    outln!(out.indent(), "event: &[u8],");
+    outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+    outln!(out, ") -> Option<&'static str> {{");
+    out.indented(|out| {
+        outln!(out, "let event_code = response_type(event).ok()?;");
+        outln!(out, "");
+        outln!(
+            out,
+            "// Check if this is a core protocol event, or from the generic event extension"
+        );
+        outln!(out, "match event_code {{");
+        out.indented(|out| {
+            let xproto_ns = module.namespace("xproto").unwrap();
+            for event_def in sorted_events(&xproto_ns) {
+                let event_name = event_def.name();
+                if event_name == "GeGeneric" {
+                    // This does not really count and is handled as an extension's event
+                    continue;
+                }
+                outln!(
+                    out,
+                    "xproto::{}_EVENT => return Some(\"{}\"),",
+                    super::camel_case_to_upper_snake(event_name),
+                    event_name,
+                );
+            }
+            outln!(
+                out,
+                "xproto::GE_GENERIC_EVENT => return generic_event_name(event, ext_info_provider),",
+            );
+            outln!(out, "_ => {{}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(out, "// Find the extension that this event could belong to");
+        outln!(
+            out,
+            "let ext_info = ext_info_provider.get_from_event_code(event_code);"
+        );
+        outln!(out, "match ext_info {{");
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                // skip xproto
+                if ns.ext_info.is_none() {
+                    continue;
+                }
+                let event_defs = sorted_events(ns);
+                if event_defs.iter().all(|event_def| event_def.is_xge()) {
+                    continue;
+                }
+
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(
+                    out,
+                    "Some(({}::X11_EXTENSION_NAME, ext_info)) => {{",
+                    ns.header
+                );
+                out.indented(|out| {
+                    if ns.header == "xkb" {
+                        outln!(out, "if event_code != ext_info.first_event {{");
+                        outln!(out.indent(), "return None;");
+                        outln!(out, "}}");
+                        outln!(out, "match *event.get(1)? {{");
+                    } else {
+                        outln!(out, "match event_code - ext_info.first_event {{");
+                    }
+                    for event_def in event_defs.iter() {
+                        if event_def.is_xge() {
+                            continue;
+                        }
+                        outln!(
+                            out.indent(),
+                            "{}::{}_EVENT => Some(\"{}\"),",
+                            ns.header,
+                            super::camel_case_to_upper_snake(event_def.name()),
+                            qualified_name(ns, event_def.name()),
+                        );
+                    }
+                    outln!(out.indent(), "_ => None,");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+
+    outln!(out, "#[allow(clippy::match_single_binding)]");
+    outln!(out, "fn generic_event_name(");
+    outln!(out.indent(), "event: &[u8],");
+    outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+    outln!(out, ") -> Option<&'static str> {{");
+    out.indented(|out| {
+        outln!(
+            out,
+            "let ge_event = xproto::GeGenericEvent::try_from(event).ok()?;"
+        );
+        outln!(out, "let ext_name = ext_info_provider");
+        outln!(out.indent(), ".get_from_major_opcode(ge_event.extension)");
+        outln!(out.indent(), ".map(|(name, _)| name);");
+        outln!(out, "match ext_name {{");
+        out.indented(|out| {
+            for ns in namespaces.iter() {
+                // skip xproto
+                if ns.ext_info.is_none() {
+                    continue;
+                }
+                let event_defs = sorted_events(ns);
+                if event_defs.iter().all(|event_def| !event_def.is_xge()) {
+                    continue;
+                }
+
+                if super::ext_has_feature(&ns.header) {
+                    outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                }
+                outln!(out, "Some({}::X11_EXTENSION_NAME) => {{", ns.header);
+                out.indented(|out| {
+                    outln!(out, "match ge_event.event_type {{");
+                    for event_def in event_defs.iter() {
+                        if !event_def.is_xge() {
+                            continue;
+                        }
+                        outln!(
+                            out.indent(),
+                            "{}::{}_EVENT => Some(\"{}\"),",
+                            ns.header,
+                            super::camel_case_to_upper_snake(event_def.name()),
+                            qualified_name(ns, event_def.name()),
+                        );
+                    }
+                    outln!(out.indent(), "_ => None,");
+                    outln!(out, "}}");
+                });
+                outln!(out, "}}");
+            }
+            outln!(out, "_ => None,");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+fn generate_error_name(out: &mut Output, module: &xcbgen::defs::Module) {
+    let namespaces = module.sorted_namespaces();
+
+    outln!(out, "impl ErrorKind {{");
+    out.indented(|out| {
+        outln!(out, "/// Get the name of this kind of error.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The names of extension errors are prefixed with the name of the extension, for"
+        );
+        outln!(
+            out,
+            "/// example `RandR::BadOutput`. Returns `None` for `ErrorKind::Unknown`."
+        );
+        outln!(out, "pub fn name(&self) -> Option<&'static str> {{");
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(out, "Self::Unknown(_) => None,");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+                    for error_def in sorted_errors(ns) {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(
+                            out,
+                            "Self::{}{} => Some(\"{}\"),",
+                            get_ns_name_prefix(ns),
+                            error_def.name(),
+                            qualified_name(ns, error_def.name()),
+                        );
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
+
+    outln!(out, "/// Get the name of an error from its error code.");
+    outln!(out, "///");
+    outln!(
+        out,
+        "/// This is a shorthand for `ErrorKind::from_wire_error_code(error_code, ext_info_provider).name()`."
+    );
+    outln!(out, "pub fn error_name(");
+    outln!(out.indent(), "error_code: u8,");
+    outln!(out.indent(), "ext_info_provider: &dyn ExtInfoProvider,");
+    outln!(out, ") -> Option<&'static str> {{");
+    outln!(
+        out.indent(),
+        "ErrorKind::from_wire_error_code(error_code, ext_info_provider).name()"
+    );
+    outln!(out, "}}");
+}
+
+pub(super) fn sorted_requests(
+    ns: &xcbgen::defs::Namespace,
+) -> Vec<std::rc::Rc<xcbgen::defs::RequestDef>> {
+    let mut requests: Vec<_> = ns.request_defs.borrow().values().cloned().collect();
+    requests.sort_by_key(|request_def| request_def.opcode);
+    requests
+}
+
+/// Get the name of the constant containing the opcode of a request.
+fn request_opcode_name(request_def: &xcbgen::defs::RequestDef) -> String {
+    super::camel_case_to_upper_snake(&super::namespace::to_rust_type_name(&request_def.name))
+}
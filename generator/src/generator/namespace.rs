@@ -14,6 +14,7 @@ use std::rc::Rc;
 use xcbgen::defs as xcbdefs;
 
 use super::output::Output;
+use super::resources::{self, ResourceInfo};
 use super::{get_ns_name_prefix, special_cases};
 
 #[derive(Debug, Default)]
@@ -24,24 +25,44 @@ pub(super) struct PerModuleEnumCases {
     request_parse_cases: Vec<String>,
     /// Lines that belong in the definition of Request::reply_parser.
     reply_parse_cases: Vec<String>,
+    /// Lines that belong in the definition of Request::parse_reply_with_order.
+    reply_parse_with_order_cases: Vec<String>,
     /// Lines that belong in the definition of Request::into_owned.
     request_into_owned_cases: Vec<String>,
+    /// Lines that belong in the definition of Request::serialize_with_order.
+    request_serialize_cases: Vec<String>,
+    /// Lines that belong in the definition of `Trace` for Request.
+    request_trace_cases: Vec<String>,
+    /// Lines that belong in the definition of Request::info.
+    request_info_cases: Vec<String>,
     /// Lines that belong in the Reply enum definition.
     reply_variants: Vec<String>,
+    /// Lines that belong in the definition of `Trace` for Reply.
+    reply_trace_cases: Vec<String>,
     /// Impls for From<ReplyType> for Reply enum.
     reply_from_cases: Vec<String>,
 }
 
 type EnumCases = HashMap<String, PerModuleEnumCases>;
 
-/// Generate a Rust module for namespace `ns`.
+/// Attribute for functions that send requests, so that the location where a request was sent
+/// from can be recorded.
+const TRACK_CALLER: &str =
+    "#[cfg_attr(all(feature = \"request-location\", debug_assertions), track_caller)]";
+
+/// Generate the Rust modules for namespace `ns`.
+///
+/// The protocol definitions are written to `proto_out` and the functions for sending requests to
+/// `x11rb_out`.
 pub(super) fn generate(
     ns: &xcbdefs::Namespace,
     caches: &RefCell<Caches>,
-    out: &mut Output,
+    xid_newtypes: bool,
+    proto_out: &mut Output,
+    x11rb_out: &mut Output,
     enum_cases: &mut EnumCases,
 ) {
-    NamespaceGenerator::new(ns, caches).generate(out, enum_cases);
+    NamespaceGenerator::new(ns, caches, xid_newtypes).generate(proto_out, x11rb_out, enum_cases);
 }
 
 /// Generate the Request and Reply enums containing all possible requests and replies, respectively.
@@ -81,11 +102,28 @@ pub(super) fn generate_request_reply_enum(
     outln!(out, "impl<'input> Request<'input> {{");
     out.indented(|out| {
         outln!(out, "// Parse a X11 request into a concrete type");
+        outln!(out, "pub fn parse(");
+        out.indented(|out| {
+            outln!(out, "header: RequestHeader,");
+            outln!(out, "body: &'input [u8],");
+            outln!(out, "fds: &mut Vec<RawFdContainer>,");
+            outln!(out, "ext_info_provider: &dyn ExtInfoProvider,");
+        });
+        outln!(out, ") -> Result<Self, ParseError> {{");
+        outln!(
+            out.indent(),
+            "Self::parse_with_order(header, body, fds, ext_info_provider, ByteOrder::native())",
+        );
+        outln!(out, "}}");
+        outln!(
+            out,
+            "/// Parse a X11 request whose body is in the given byte order into a concrete type"
+        );
         outln!(
             out,
             "#[allow(clippy::cognitive_complexity, clippy::single_match)]"
         );
-        outln!(out, "pub fn parse(");
+        outln!(out, "pub fn parse_with_order(");
         out.indented(|out| {
             outln!(out, "header: RequestHeader,");
             outln!(out, "body: &'input [u8],");
@@ -96,6 +134,7 @@ pub(super) fn generate_request_reply_enum(
             outln!(out, "#[allow(unused_variables)]");
             outln!(out, "fds: &mut Vec<RawFdContainer>,");
             outln!(out, "ext_info_provider: &dyn ExtInfoProvider,");
+            outln!(out, "order: ByteOrder,");
         });
         outln!(out, ") -> Result<Self, ParseError> {{");
         out.indented(|out| {
@@ -190,6 +229,191 @@ pub(super) fn generate_request_reply_enum(
             outln!(out, "}}");
         });
         outln!(out, "}}");
+        outln!(out, "/// Parse a reply to this request.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// `None` is returned if this request does not have a reply. This includes"
+        );
+        outln!(out, "/// `Request::Unknown`.");
+        outln!(out, "pub fn parse_reply<'a>(");
+        out.indented(|out| {
+            outln!(out, "&self,");
+            outln!(out, "bytes: &'a [u8],");
+            outln!(out, "fds: &mut Vec<RawFdContainer>,");
+        });
+        outln!(out, ") -> Option<Result<(Reply, &'a [u8]), ParseError>> {{");
+        outln!(
+            out.indent(),
+            "self.parse_reply_with_order(bytes, fds, ByteOrder::native())"
+        );
+        outln!(out, "}}");
+        outln!(
+            out,
+            "/// Parse a reply to this request from data in the given byte order."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// `None` is returned if this request does not have a reply. This includes"
+        );
+        outln!(out, "/// `Request::Unknown`.");
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(out, "pub fn parse_reply_with_order<'a>(");
+        out.indented(|out| {
+            outln!(out, "&self,");
+            outln!(out, "bytes: &'a [u8],");
+            outln!(out, "fds: &mut Vec<RawFdContainer>,");
+            outln!(out, "order: ByteOrder,");
+        });
+        outln!(out, ") -> Option<Result<(Reply, &'a [u8]), ParseError>> {{");
+        out.indented(|out| {
+            outln!(out, "fn parse_as<'a, R: TryParseFd + Into<Reply>>(");
+            out.indented(|out| {
+                outln!(out, "bytes: &'a [u8],");
+                outln!(out, "fds: &mut Vec<RawFdContainer>,");
+                outln!(out, "order: ByteOrder,");
+            });
+            outln!(out, ") -> Result<(Reply, &'a [u8]), ParseError> {{");
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "let (reply, remaining) = R::try_parse_fd_with_order(bytes, fds, order)?;"
+                );
+                outln!(out, "Ok((reply.into(), remaining))");
+            });
+            outln!(out, "}}");
+            outln!(out, "Some(match self {{");
+            out.indented(|out| {
+                outln!(out, "Request::Unknown(_, _) => return None,");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let reply_parse_with_order_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .reply_parse_with_order_cases
+                        .drain(..);
+                    for case in reply_parse_with_order_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}})");
+        });
+        outln!(out, "}}");
+        outln!(out, "/// Serialize this request into bytes.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The major opcodes of extensions are looked up via `ext_info_provider`. `None` is"
+        );
+        outln!(
+            out,
+            "/// returned if the request belongs to an extension that it does not know."
+        );
+        outln!(
+            out,
+            "pub fn serialize(self, ext_info_provider: &dyn ExtInfoProvider) -> \
+             Option<BufWithFds<PiecewiseBuf<'input>>> {{"
+        );
+        outln!(
+            out.indent(),
+            "self.serialize_with_order(ext_info_provider, ByteOrder::native())"
+        );
+        outln!(out, "}}");
+        outln!(
+            out,
+            "/// Serialize this request into bytes in the given byte order."
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// The major opcodes of extensions are looked up via `ext_info_provider`. `None` is"
+        );
+        outln!(
+            out,
+            "/// returned if the request belongs to an extension that it does not know."
+        );
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(out, "pub fn serialize_with_order(");
+        out.indented(|out| {
+            outln!(out, "self,");
+            outln!(out, "ext_info_provider: &dyn ExtInfoProvider,");
+            outln!(out, "order: ByteOrder,");
+        });
+        outln!(out, ") -> Option<BufWithFds<PiecewiseBuf<'input>>> {{");
+        out.indented(|out| {
+            outln!(out, "let major_opcode = |extension_name: &str| {{");
+            outln!(
+                out.indent(),
+                "ext_info_provider.get_from_extension_name(extension_name).map(|info| info.major_opcode)"
+            );
+            outln!(out, "}};");
+            outln!(out, "Some(match self {{");
+            out.indented(|out| {
+                outln!(out, "Request::Unknown(header, body) => {{");
+                outln!(
+                    out.indent(),
+                    "(vec![header.serialize_with_order(order).into(), body], vec![])"
+                );
+                outln!(out, "}}");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let request_serialize_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .request_serialize_cases
+                        .drain(..);
+                    for case in request_serialize_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}})");
+        });
+        outln!(out, "}}");
+        outln!(out, "/// Get a description of this request.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Returns `None` for `Request::Unknown`. See the [`introspect`](crate::introspect) \
+             module."
+        );
+        outln!(
+            out,
+            "pub fn info(&self) -> Option<&'static crate::introspect::RequestInfo> {{"
+        );
+        out.indented(|out| {
+            outln!(out, "match self {{");
+            out.indented(|out| {
+                outln!(out, "Request::Unknown(_, _) => None,");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let request_info_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .request_info_cases
+                        .drain(..);
+                    for case in request_info_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
         outln!(out, "/// Convert this Request into an owned version with no borrows.");
         outln!(out, "pub fn into_owned(self) -> Request<'static> {{");
         out.indented(|out| {
@@ -218,6 +442,58 @@ pub(super) fn generate_request_reply_enum(
     });
     outln!(out, "}}");
     outln!(out, "");
+    outln!(out, "impl Trace for Request<'_> {{");
+    out.indented(|out| {
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(
+            out,
+            "fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {{"
+        );
+        out.indented(|out| {
+            outln!(out, "let (name, request): (&str, &dyn Trace) = match self {{");
+            out.indented(|out| {
+                outln!(out, "Request::Unknown(header, body) => {{");
+                out.indented(|out| {
+                    outln!(out, "tracer.write_str(\"Request Unknown\")?;");
+                    outln!(
+                        out,
+                        "tracer.field(\"major-opcode\", |tracer| tracer.value(&header.major_opcode))?;"
+                    );
+                    outln!(
+                        out,
+                        "tracer.field(\"minor-opcode\", |tracer| tracer.value(&header.minor_opcode))?;"
+                    );
+                    outln!(
+                        out,
+                        "return tracer.field(\"data\", |tracer| tracer.list(body, |tracer, value| tracer.value(value)));"
+                    );
+                });
+                outln!(out, "}}");
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let request_trace_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .request_trace_cases
+                        .drain(..);
+                    for case in request_trace_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}};");
+            outln!(out, "tracer.write_str(\"Request \")?;");
+            outln!(out, "tracer.write_str(name)?;");
+            outln!(out, "request.trace(tracer)");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+    outln!(out, "");
     outln!(out, "/// Enumeration of all possible X11 replies.");
     outln!(out, "#[derive(Debug)]");
     // clippy::large_enum_variant for XkbGetKbdByNameReply.
@@ -265,6 +541,43 @@ pub(super) fn generate_request_reply_enum(
             outln!(out, "{}", case);
         }
     }
+    outln!(out, "impl Trace for Reply {{");
+    out.indented(|out| {
+        outln!(out, "#[allow(clippy::cognitive_complexity)]");
+        outln!(
+            out,
+            "fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {{"
+        );
+        out.indented(|out| {
+            outln!(out, "let (name, reply): (&str, &dyn Trace) = match self {{");
+            out.indented(|out| {
+                outln!(
+                    out,
+                    "Reply::Void => return tracer.write_str(\"Empty reply\"),"
+                );
+                for ns in namespaces.iter() {
+                    let has_feature = super::ext_has_feature(&ns.header);
+
+                    let reply_trace_cases = enum_cases
+                        .get_mut(&ns.header)
+                        .unwrap()
+                        .reply_trace_cases
+                        .drain(..);
+                    for case in reply_trace_cases {
+                        if has_feature {
+                            outln!(out, "#[cfg(feature = \"{}\")]", ns.header);
+                        }
+                        outln!(out, "{}", case);
+                    }
+                }
+            });
+            outln!(out, "}};");
+            outln!(out, "write!(tracer, \"Reply to {{}}:\", name)?;");
+            outln!(out, "reply.trace(tracer)");
+        });
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
     outln!(out, "");
 }
 
@@ -291,6 +604,8 @@ struct EnumInfo {
 #[derive(Default)]
 pub(super) struct Caches {
     enum_infos: HashMap<usize, EnumInfo>,
+    /// The XID types of fields that take values from an enum, keyed by the enum
+    enum_xid_types: HashMap<usize, Vec<xcbdefs::TypeRef>>,
     derives: HashMap<usize, Derives>,
     rust_type_names: HashMap<usize, String>,
 }
@@ -301,6 +616,13 @@ impl Caches {
         self.enum_infos[&(enum_def as *const xcbdefs::EnumDef as usize)]
     }
 
+    fn enum_xid_types(&self, enum_def: &xcbdefs::EnumDef) -> &[xcbdefs::TypeRef] {
+        self.enum_xid_types
+            .get(&(enum_def as *const xcbdefs::EnumDef as usize))
+            .map(|types| &types[..])
+            .unwrap_or(&[])
+    }
+
     fn put_enum_max_value_size(&mut self, enum_def: &xcbdefs::EnumDef, max_value_size: u8) {
         let id = enum_def as *const xcbdefs::EnumDef as usize;
         match self.enum_infos.entry(id) {
@@ -451,6 +773,26 @@ impl Caches {
             xcbdefs::FieldValueSet::Mask(_) => {}
             xcbdefs::FieldValueSet::AltMask(_) => {}
         }
+
+        // With distinct XID types, enum values need a conversion into the XID type of the field
+        let enum_type = match value_type.value_set {
+            xcbdefs::FieldValueSet::Enum(ref enum_type)
+            | xcbdefs::FieldValueSet::AltEnum(ref enum_type) => enum_type,
+            _ => return,
+        };
+        let xid_type = value_type.type_.get_resolved().get_original_type();
+        match xid_type {
+            xcbdefs::TypeRef::Xid(_) | xcbdefs::TypeRef::XidUnion(_) => {}
+            _ => return,
+        }
+        let enum_def = match enum_type.get_resolved().get_original_type() {
+            xcbdefs::TypeRef::Enum(enum_type) => enum_type.upgrade().unwrap(),
+            _ => unreachable!(),
+        };
+        self.enum_xid_types
+            .entry(&*enum_def as *const xcbdefs::EnumDef as usize)
+            .or_insert_with(Vec::new)
+            .push(xid_type);
     }
 
     fn gather_enum_infos_in_enum_def(&mut self, enum_def: &xcbdefs::EnumDef) {
@@ -483,15 +825,18 @@ struct NamespaceGenerator<'ns, 'c> {
     ns: &'ns xcbdefs::Namespace,
     caches: &'c RefCell<Caches>,
 
-    /// `Option` or `std::option::Option`
+    /// `Option` or `core::option::Option`
     option_name: &'static str,
+
+    /// Whether XID types are distinct types instead of aliases of `u32`
+    xid_newtypes: bool,
 }
 
 impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
     #[inline]
-    fn new(ns: &'ns xcbdefs::Namespace, caches: &'c RefCell<Caches>) -> Self {
+    fn new(ns: &'ns xcbdefs::Namespace, caches: &'c RefCell<Caches>, xid_newtypes: bool) -> Self {
         let option_name = if ns.header == "present" {
-            "std::option::Option"
+            "core::option::Option"
         } else {
             "Option"
         };
@@ -499,68 +844,67 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             ns,
             caches,
             option_name,
+            xid_newtypes,
         }
     }
 
-    fn generate(&self, out: &mut Output, enum_cases: &mut EnumCases) {
-        super::write_code_header(out);
-        if let Some(info) = &self.ns.ext_info {
-            outln!(out, "//! Bindings to the `{}` X11 extension.", info.name);
-        } else {
-            outln!(out, "//! Bindings to the core X11 protocol.");
-            outln!(out, "//!");
-            outln!(
-                out,
-                "//! For more documentation on the X11 protocol, see the"
-            );
-            outln!(
-                out,
-                "//! [protocol reference manual](https://www.x.org/releases/X11R7.6/doc/xproto/x11protocol.html).",
-            );
-            outln!(
-                out,
-                "//! This is especially recommended for looking up the exact semantics of"
-            );
-            outln!(out, "//! specific errors, events, or requests.");
-        }
-        outln!(out, "");
-        outln!(out, "#![allow(clippy::too_many_arguments)]");
-        outln!(out, "#![allow(clippy::identity_op)]");
-        outln!(out, "#![allow(clippy::trivially_copy_pass_by_ref)]");
-        outln!(out, "#![allow(clippy::eq_op)]");
-        outln!(out, "");
+    fn generate(&self, out: &mut Output, x11rb_out: &mut Output, enum_cases: &mut EnumCases) {
+        self.emit_module_header(out);
+        self.emit_module_header(x11rb_out);
+
+        outln!(out, "#[allow(unused_imports)]");
+        outln!(out, "use alloc::borrow::Cow;");
         outln!(out, "#[allow(unused_imports)]");
-        outln!(out, "use std::borrow::Cow;");
-        outln!(out, "use std::convert::TryFrom;");
+        outln!(out, "use alloc::vec;");
+        outln!(out, "use alloc::vec::Vec;");
+        outln!(out, "use core::convert::TryFrom;");
         outln!(out, "#[allow(unused_imports)]");
-        outln!(out, "use std::convert::TryInto;");
-        outln!(out, "use std::io::IoSlice;");
+        outln!(out, "use core::convert::TryInto;");
+        outln!(out, "use crate::errors::ParseError;");
         outln!(out, "#[allow(unused_imports)]");
         outln!(
             out,
-            "use crate::utils::{{RawFdContainer, pretty_print_bitmask, pretty_print_enum}};"
+            "use crate::introspect::{{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, \
+             RequestInfo, TypeInfo, ValueSet}};"
         );
+        outln!(out, "use crate::trace::{{Trace, Tracer}};");
         outln!(out, "#[allow(unused_imports)]");
         outln!(
             out,
-            "use crate::x11_utils::{{Request, RequestHeader, Serialize, TryParse, TryParseFd}};"
+            "use crate::utils::{{RawFdContainer, pretty_print_bitmask, pretty_print_enum}};"
         );
+        outln!(out, "#[allow(unused_imports)]");
         outln!(
             out,
-            "use crate::connection::{{BufWithFds, PiecewiseBuf, RequestConnection}};"
+            "use crate::x11_utils::{{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd}};"
         );
-        outln!(out, "#[allow(unused_imports)]");
+        outln!(out, "use crate::{{BufWithFds, PiecewiseBuf}};");
+
+        outln!(x11rb_out, "#[allow(unused_imports)]");
+        outln!(x11rb_out, "use std::borrow::Cow;");
+        outln!(x11rb_out, "#[allow(unused_imports)]");
+        outln!(x11rb_out, "use std::convert::TryInto;");
+        outln!(x11rb_out, "use std::io::IoSlice;");
+        outln!(x11rb_out, "#[allow(unused_imports)]");
+        outln!(x11rb_out, "use crate::utils::RawFdContainer;");
+        outln!(x11rb_out, "use crate::connection::RequestConnection;");
+        outln!(x11rb_out, "#[allow(unused_imports)]");
         outln!(
-            out,
+            x11rb_out,
             "use crate::cookie::{{Cookie, CookieWithFds, VoidCookie}};"
         );
         if self.ns.header == "xproto" {
-            outln!(out, "use crate::cookie::ListFontsWithInfoCookie;");
+            outln!(x11rb_out, "use crate::cookie::ListFontsWithInfoCookie;");
         }
         if self.ns.header == "record" {
-            outln!(out, "use crate::cookie::RecordEnableContextCookie;");
+            outln!(x11rb_out, "use crate::cookie::RecordEnableContextCookie;");
+        }
+        outln!(x11rb_out, "use crate::errors::ConnectionError;");
+        let resources = resources::find_resources(self.ns);
+        if !resources.is_empty() {
+            outln!(x11rb_out, "use crate::connection::Connection;");
+            outln!(x11rb_out, "use crate::errors::ReplyOrIdError;");
         }
-        outln!(out, "use crate::errors::{{ConnectionError, ParseError}};");
 
         let mut imports = self
             .ns
@@ -572,7 +916,15 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         imports.sort();
         for import in imports.iter() {
             outln!(out, "use super::{};", import);
+            outln!(x11rb_out, "#[allow(unused_imports)]");
+            outln!(x11rb_out, "use super::{};", import);
         }
+        outln!(x11rb_out, "");
+        outln!(
+            x11rb_out,
+            "pub use x11rb_protocol::protocol::{}::*;",
+            self.ns.header
+        );
 
         if let Some(ref ext_info) = self.ns.ext_info {
             outln!(out, "");
@@ -603,83 +955,434 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             );
             outln!(
                 out,
-                "/// send the maximum version of the extension that you need.",
+                "/// send the maximum version of the extension that you need.",
+            );
+            outln!(
+                out,
+                "pub const X11_XML_VERSION: (u32, u32) = ({}, {});",
+                ext_info.major_version,
+                ext_info.minor_version,
+            );
+
+            outln!(x11rb_out, "");
+            outln!(x11rb_out, "/// Get the major opcode of this extension");
+            outln!(
+                x11rb_out,
+                "fn major_opcode<Conn: RequestConnection + ?Sized>(conn: &Conn) -> Result<u8, ConnectionError> {{"
+            );
+            x11rb_out.indented(|out| {
+                outln!(
+                    out,
+                    "let info = conn.extension_information(X11_EXTENSION_NAME)?;"
+                );
+                outln!(
+                    out,
+                    "let info = info.ok_or(ConnectionError::UnsupportedExtension)?;"
+                );
+                outln!(out, "Ok(info.major_opcode)");
+            });
+            outln!(x11rb_out, "}}");
+        }
+        outln!(out, "");
+        outln!(x11rb_out, "");
+
+        let mut trait_out = Output::new();
+
+        for def in self.ns.src_order_defs.borrow().iter() {
+            match def {
+                xcbdefs::Def::Request(request_def) => {
+                    let cases_entry = enum_cases.entry(self.ns.header.clone()).or_default();
+                    self.generate_request(request_def, out, x11rb_out, &mut trait_out, cases_entry)
+                }
+                xcbdefs::Def::Event(event_def) => match event_def {
+                    xcbdefs::EventDef::Full(event_full_def) => {
+                        self.generate_event_full_def(event_full_def, out);
+                    }
+                    xcbdefs::EventDef::Copy(event_copy_def) => {
+                        self.generate_event_copy_def(event_copy_def, out);
+                    }
+                },
+                xcbdefs::Def::Error(error_def) => match error_def {
+                    xcbdefs::ErrorDef::Full(error_full_def) => {
+                        self.generate_error_full_def(error_full_def, out);
+                    }
+                    xcbdefs::ErrorDef::Copy(error_copy_def) => {
+                        self.generate_error_copy_def(error_copy_def, out);
+                    }
+                },
+                xcbdefs::Def::Type(type_def) => match type_def {
+                    xcbdefs::TypeDef::Struct(struct_def) => {
+                        self.generate_struct_def(struct_def, out)
+                    }
+                    xcbdefs::TypeDef::Union(union_def) => self.generate_union_def(union_def, out),
+                    xcbdefs::TypeDef::EventStruct(event_struct_def) => {
+                        self.generate_event_struct_def(event_struct_def, out);
+                    }
+                    xcbdefs::TypeDef::Xid(xid_type_def) => {
+                        self.generate_xid_type_def(xid_type_def, out)
+                    }
+                    xcbdefs::TypeDef::XidUnion(xid_union_def) => {
+                        self.generate_xid_union_def(xid_union_def, out)
+                    }
+                    xcbdefs::TypeDef::Enum(enum_def) => self.generate_enum_def(enum_def, out),
+                    xcbdefs::TypeDef::Alias(type_alias_def) => {
+                        self.generate_type_alias_def(type_alias_def, out)
+                    }
+                },
+            }
+        }
+
+        self.emit_introspection_tables(out);
+
+        let trait_out = trait_out.into_data();
+
+        let out = x11rb_out;
+        outln!(
+            out,
+            "/// Extension trait defining the requests of this extension.",
+        );
+        outln!(out, "pub trait ConnectionExt: RequestConnection {{");
+        out!(out.indent(), "{}", trait_out);
+        outln!(out, "}}");
+        outln!(out, "");
+        outln!(
+            out,
+            "impl<C: RequestConnection + ?Sized> ConnectionExt for C {{}}",
+        );
+
+        for resource in resources.iter() {
+            outln!(out, "");
+            self.generate_resource_wrapper(resource, out);
+        }
+    }
+
+    /// Emit the generated-code header, module documentation and lint attributes.
+    fn emit_module_header(&self, out: &mut Output) {
+        super::write_code_header(out);
+        if let Some(info) = &self.ns.ext_info {
+            outln!(out, "//! Bindings to the `{}` X11 extension.", info.name);
+        } else {
+            outln!(out, "//! Bindings to the core X11 protocol.");
+            outln!(out, "//!");
+            outln!(
+                out,
+                "//! For more documentation on the X11 protocol, see the"
+            );
+            outln!(
+                out,
+                "//! [protocol reference manual](https://www.x.org/releases/X11R7.6/doc/xproto/x11protocol.html).",
+            );
+            outln!(
+                out,
+                "//! This is especially recommended for looking up the exact semantics of"
+            );
+            outln!(out, "//! specific errors, events, or requests.");
+        }
+        outln!(out, "");
+        outln!(out, "#![allow(clippy::too_many_arguments)]");
+        outln!(out, "#![allow(clippy::identity_op)]");
+        outln!(out, "#![allow(clippy::trivially_copy_pass_by_ref)]");
+        outln!(out, "#![allow(clippy::eq_op)]");
+        outln!(out, "");
+    }
+
+    /// Generate a wrapper around the XID of a resource that frees the resource in `Drop`.
+    fn generate_resource_wrapper(&self, resource: &ResourceInfo, out: &mut Output) {
+        let name = self.get_xid_type_rust_name(&resource.xid_type);
+        let lower_name = super::camel_case_to_lower_snake(&name);
+        let wrapper = format!("{}Wrapper", name);
+        let free_function =
+            super::camel_case_to_lower_snake(&to_rust_type_name(&resource.free_request.name));
+
+        outln!(out, "/// A RAII-like wrapper around a [{}].", name);
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Instances of this struct represent a {} that is freed in `Drop` via [`{}`].",
+            name,
+            free_function,
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Any errors during `Drop` are silently ignored. Most likely an error here means that"
+        );
+        outln!(
+            out,
+            "/// your X11 connection is broken and later requests will also fail."
+        );
+        outln!(out, "#[derive(Debug)]");
+        outln!(
+            out,
+            "pub struct {}<'c, Conn: RequestConnection>(&'c Conn, {});",
+            wrapper,
+            name,
+        );
+        outln!(out, "");
+        outln!(
+            out,
+            "impl<'c, Conn: RequestConnection> {}<'c, Conn> {{",
+            wrapper
+        );
+        out.indented(|out| {
+            outln!(
+                out,
+                "/// Assume ownership of the given resource and free it in `Drop`."
+            );
+            outln!(
+                out,
+                "pub fn for_{}(conn: &'c Conn, id: {}) -> Self {{",
+                lower_name,
+                name,
+            );
+            outln!(out.indent(), "{}(conn, id)", wrapper);
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(out, "/// Get the XID of the wrapped resource.");
+            outln!(out, "pub fn id(&self) -> {} {{", name);
+            outln!(out.indent(), "self.1");
+            outln!(out, "}}");
+            outln!(out, "");
+            outln!(
+                out,
+                "/// Assume ownership of the XID of the wrapped resource."
+            );
+            outln!(out, "///");
+            outln!(
+                out,
+                "/// This function destroys this wrapper without freeing the underlying resource."
+            );
+            outln!(out, "pub fn into_id(self) -> {} {{", name);
+            outln!(out.indent(), "let id = self.1;");
+            outln!(out.indent(), "std::mem::forget(self);");
+            outln!(out.indent(), "id");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(out, "impl<'c, Conn: Connection> {}<'c, Conn> {{", wrapper);
+        out.indented(|out| {
+            for (i, (request_def, field_name)) in resource.create_requests.iter().enumerate() {
+                if i != 0 {
+                    outln!(out, "");
+                }
+                self.emit_resource_create_functions(&name, &wrapper, request_def, field_name, out);
+            }
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(
+            out,
+            "impl<Conn: RequestConnection> From<&{}<'_, Conn>> for {} {{",
+            wrapper,
+            name,
+        );
+        out.indented(|out| {
+            outln!(out, "fn from(from: &{}<'_, Conn>) -> Self {{", wrapper);
+            outln!(out.indent(), "from.1");
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "");
+
+        outln!(
+            out,
+            "impl<'c, Conn: RequestConnection> Drop for {}<'c, Conn> {{",
+            wrapper
+        );
+        out.indented(|out| {
+            outln!(out, "fn drop(&mut self) {{");
+            outln!(out.indent(), "let _ = {}(self.0, self.1);", free_function);
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Emit the functions of a resource wrapper that create the resource with the given request.
+    fn emit_resource_create_functions(
+        &self,
+        name: &str,
+        wrapper: &str,
+        request_def: &xcbdefs::RequestDef,
+        field_name: &str,
+        out: &mut Output,
+    ) {
+        let function_name = super::camel_case_to_lower_snake(&to_rust_type_name(&request_def.name));
+        let id_arg = to_rust_variable_name(field_name);
+
+        let request_fields = request_def.fields.borrow();
+        let deducible_fields = gather_deducible_fields(&*request_fields);
+        let gathered = self.gather_request_fields(request_def, &deducible_fields);
+        assert!(
+            gathered
+                .args
+                .iter()
+                .any(|(arg_name, _)| *arg_name == id_arg),
+            "request {}::{} has no argument {}",
+            self.ns.header,
+            request_def.name,
+            id_arg,
+        );
+
+        let mut generic_params = Vec::new();
+        if gathered.needs_lifetime {
+            generic_params.push("'input".to_string());
+        }
+        for (param_name, _) in gathered.generics.iter() {
+            assert_ne!(param_name, "Conn");
+            generic_params.push(param_name.clone());
+        }
+        let generic_params = if generic_params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", generic_params.join(", "))
+        };
+
+        let mut args = String::from("conn: &'c Conn");
+        let mut call_args = String::from("conn");
+        let mut forward_args = String::from("conn");
+        for (arg_name, arg_type) in gathered.args.iter() {
+            call_args.push_str(", ");
+            call_args.push_str(arg_name);
+            if *arg_name != id_arg {
+                args.push_str(", ");
+                args.push_str(arg_name);
+                args.push_str(": ");
+                args.push_str(&arg_type.as_argument());
+                forward_args.push_str(", ");
+                forward_args.push_str(arg_name);
+            }
+        }
+        let where_clause = |out: &mut Output| {
+            if !gathered.generics.is_empty() {
+                outln!(out, "where");
+                for (param_name, where_) in gathered.generics.iter() {
+                    outln!(out.indent(), "{}: {},", param_name, where_);
+                }
+            }
+        };
+
+        outln!(
+            out,
+            "/// Create a new {} and return a {} wrapper and a cookie.",
+            name,
+            name,
+        );
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// This is a thin wrapper around [`{}`] that allocates an id for the {}.",
+            function_name,
+            name,
+        );
+        outln!(
+            out,
+            "/// This function returns the resulting `{}` that owns the created {} and frees",
+            wrapper,
+            name,
+        );
+        outln!(
+            out,
+            "/// it in `Drop`. This also returns a `VoidCookie` that comes from the call to"
+        );
+        outln!(out, "/// [`{}`].", function_name);
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Errors can come from the call to [`Connection::generate_id`] or [`{}`].",
+            function_name,
+        );
+        outln!(out, "{}", TRACK_CALLER);
+        outln!(
+            out,
+            "pub fn {}_and_get_cookie{}({}) -> Result<(Self, VoidCookie<'c, Conn>), ReplyOrIdError>",
+            function_name,
+            generic_params,
+            args,
+        );
+        where_clause(out);
+        outln!(out, "{{");
+        out.indented(|out| {
+            outln!(out, "let {} = {}::from(conn.generate_id()?);", id_arg, name);
+            // A function argument with the same name would shadow the function
+            let qualifier = if gathered
+                .args
+                .iter()
+                .any(|(arg_name, _)| *arg_name == function_name)
+            {
+                "self::"
+            } else {
+                ""
+            };
+            outln!(
+                out,
+                "let cookie = {}{}({})?;",
+                qualifier,
+                function_name,
+                call_args
             );
             outln!(
                 out,
-                "pub const X11_XML_VERSION: (u32, u32) = ({}, {});",
-                ext_info.major_version,
-                ext_info.minor_version,
+                "Ok((Self::for_{}(conn, {}), cookie))",
+                super::camel_case_to_lower_snake(name),
+                id_arg
             );
-        }
+        });
+        outln!(out, "}}");
         outln!(out, "");
 
-        let mut trait_out = Output::new();
-
-        for def in self.ns.src_order_defs.borrow().iter() {
-            match def {
-                xcbdefs::Def::Request(request_def) => {
-                    let cases_entry = enum_cases.entry(self.ns.header.clone()).or_default();
-                    self.generate_request(request_def, out, &mut trait_out, cases_entry)
-                }
-                xcbdefs::Def::Event(event_def) => match event_def {
-                    xcbdefs::EventDef::Full(event_full_def) => {
-                        self.generate_event_full_def(event_full_def, out);
-                    }
-                    xcbdefs::EventDef::Copy(event_copy_def) => {
-                        self.generate_event_copy_def(event_copy_def, out);
-                    }
-                },
-                xcbdefs::Def::Error(error_def) => match error_def {
-                    xcbdefs::ErrorDef::Full(error_full_def) => {
-                        self.generate_error_full_def(error_full_def, out);
-                    }
-                    xcbdefs::ErrorDef::Copy(error_copy_def) => {
-                        self.generate_error_copy_def(error_copy_def, out);
-                    }
-                },
-                xcbdefs::Def::Type(type_def) => match type_def {
-                    xcbdefs::TypeDef::Struct(struct_def) => {
-                        self.generate_struct_def(struct_def, out)
-                    }
-                    xcbdefs::TypeDef::Union(union_def) => self.generate_union_def(union_def, out),
-                    xcbdefs::TypeDef::EventStruct(event_struct_def) => {
-                        self.generate_event_struct_def(event_struct_def, out);
-                    }
-                    xcbdefs::TypeDef::Xid(xid_type_def) => {
-                        self.generate_xid_type_def(xid_type_def, out)
-                    }
-                    xcbdefs::TypeDef::XidUnion(xid_union_def) => {
-                        self.generate_xid_union_def(xid_union_def, out)
-                    }
-                    xcbdefs::TypeDef::Enum(enum_def) => self.generate_enum_def(enum_def, out),
-                    xcbdefs::TypeDef::Alias(type_alias_def) => {
-                        self.generate_type_alias_def(type_alias_def, out)
-                    }
-                },
-            }
-        }
-
-        let trait_out = trait_out.into_data();
-
         outln!(
             out,
-            "/// Extension trait defining the requests of this extension.",
+            "/// Create a new {} and return a {} wrapper.",
+            name,
+            name
         );
-        outln!(out, "pub trait ConnectionExt: RequestConnection {{");
-        out!(out.indent(), "{}", trait_out);
-        outln!(out, "}}");
-        outln!(out, "");
+        outln!(out, "///");
         outln!(
             out,
-            "impl<C: RequestConnection + ?Sized> ConnectionExt for C {{}}",
+            "/// This is a thin wrapper around [`{}`] that allocates an id for the {}.",
+            function_name,
+            name,
+        );
+        outln!(
+            out,
+            "/// This function returns the resulting `{}` that owns the created {} and frees",
+            wrapper,
+            name,
+        );
+        outln!(out, "/// it in `Drop`.");
+        outln!(out, "///");
+        outln!(
+            out,
+            "/// Errors can come from the call to [`Connection::generate_id`] or [`{}`].",
+            function_name,
+        );
+        outln!(out, "{}", TRACK_CALLER);
+        outln!(
+            out,
+            "pub fn {}{}({}) -> Result<Self, ReplyOrIdError>",
+            function_name,
+            generic_params,
+            args,
+        );
+        where_clause(out);
+        outln!(out, "{{");
+        outln!(
+            out.indent(),
+            "Ok(Self::{}_and_get_cookie({})?.0)",
+            function_name,
+            forward_args,
         );
+        outln!(out, "}}");
     }
 
     fn generate_request(
         &self,
         request_def: &xcbdefs::RequestDef,
         out: &mut Output,
+        x11rb_out: &mut Output,
         trait_out: &mut Output,
         enum_cases: &mut PerModuleEnumCases,
     ) {
@@ -726,7 +1429,30 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         let gathered = self.gather_request_fields(request_def, &deducible_fields);
 
         self.emit_request_struct(request_def, &name, &deducible_fields, &gathered, out);
+        self.emit_request_trace_impl(request_def, &name, &deducible_fields, &gathered, out);
         let ns_prefix = get_ns_name_prefix(self.ns);
+        let trace_name = if self.ns.ext_info.is_some() {
+            format!("{}::{}", self.ns.header, request_def.name)
+        } else {
+            request_def.name.clone()
+        };
+        enum_cases.request_trace_cases.push(format!(
+            "Request::{ns_prefix}{name}(req) => (\"{trace_name}\", req),",
+            ns_prefix = ns_prefix,
+            name = name,
+            trace_name = trace_name,
+        ));
+        let info_index = super::names::sorted_requests(self.ns)
+            .iter()
+            .position(|other| other.name == request_def.name)
+            .unwrap();
+        enum_cases.request_info_cases.push(format!(
+            "Request::{ns_prefix}{name}(_) => Some(&{header}::REQUEST_INFOS[{index}]),",
+            ns_prefix = ns_prefix,
+            name = name,
+            header = self.ns.header,
+            index = info_index,
+        ));
         let lifetime_block = if gathered.needs_lifetime {
             "<'input>"
         } else {
@@ -743,7 +1469,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             enum_cases.request_parse_cases.push(format!(
                 "{header}::{opcode_name}_REQUEST => return \
                  Ok(Request::{ns_prefix}{name}({header}::{name}Request::\
-                 try_parse_request_fd(header, remaining, fds)?)),",
+                 try_parse_request_fd_with_order(header, remaining, fds, order)?)),",
                 header = self.ns.header,
                 opcode_name = super::camel_case_to_upper_snake(&name),
                 ns_prefix = ns_prefix,
@@ -752,15 +1478,17 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         } else {
             enum_cases.request_parse_cases.push(format!(
                 "{header}::{opcode_name}_REQUEST => return \
-                 Ok(Request::{ns_prefix}{name}({header}::{name}Request::try_parse_request(header, \
-                 remaining)?)),",
+                 Ok(Request::{ns_prefix}{name}({header}::{name}Request::\
+                 try_parse_request_with_order(header, remaining, order)?)),",
                 header = self.ns.header,
                 opcode_name = super::camel_case_to_upper_snake(&name),
                 ns_prefix = ns_prefix,
                 name = name,
             ));
         }
-        self.emit_request_function(request_def, &name, &function_name, &gathered, out);
+        self.emit_request_send_function(request_def, &name, &function_name, &gathered, x11rb_out);
+        self.emit_request_function(request_def, &name, &function_name, &gathered, x11rb_out);
+        outln!(x11rb_out, "");
         self.emit_request_trait_function(request_def, &name, &function_name, &gathered, trait_out);
 
         special_cases::handle_request(request_def, out);
@@ -781,6 +1509,18 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 name = name,
                 header = self.ns.header,
             ));
+            enum_cases.reply_parse_with_order_cases.push(format!(
+                "Request::{ns_prefix}{name}(_) => parse_as::<{header}::{name}Reply>(bytes, fds, order),",
+                ns_prefix = ns_prefix,
+                name = name,
+                header = self.ns.header,
+            ));
+            enum_cases.reply_trace_cases.push(format!(
+                "Reply::{ns_prefix}{name}(reply) => (\"{trace_name}\", reply),",
+                ns_prefix = ns_prefix,
+                name = name,
+                trace_name = trace_name,
+            ));
             enum_cases.reply_from_cases.push(format!(
                 r#"impl From<{header}::{name}Reply> for Reply {{
   fn from(reply: {header}::{name}Reply) -> Reply {{
@@ -804,10 +1544,12 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 true,
                 StructSizeConstraint::EmbeddedLength { minimum: 32 },
                 false,
-                false,
+                true,
                 reply.doc.as_ref(),
                 out,
             );
+            self.emit_trace_impl(&reply_struct_name, &*reply_fields, &["length"], false, out);
+            self.emit_reply_serialize(&reply_struct_name, &*reply_fields, out);
 
             outln!(out, "");
         } else {
@@ -816,6 +1558,27 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 ns_prefix = ns_prefix,
                 name = name,
             ));
+            enum_cases.reply_parse_with_order_cases.push(format!(
+                "Request::{ns_prefix}{name}(_) => return None,",
+                ns_prefix = ns_prefix,
+                name = name,
+            ));
+        }
+
+        if self.ns.ext_info.is_some() {
+            enum_cases.request_serialize_cases.push(format!(
+                "Request::{ns_prefix}{name}(req) => \
+                 req.serialize_with_order(major_opcode({header}::X11_EXTENSION_NAME)?, order),",
+                ns_prefix = ns_prefix,
+                name = name,
+                header = self.ns.header,
+            ));
+        } else {
+            enum_cases.request_serialize_cases.push(format!(
+                "Request::{ns_prefix}{name}(req) => req.serialize_with_order(order),",
+                ns_prefix = ns_prefix,
+                name = name,
+            ));
         }
 
         if gathered.needs_lifetime {
@@ -925,7 +1688,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             if gathered.needs_lifetime {
                 ("<'input>", "", "'input ")
             } else {
-                ("", "'input, ", "")
+                ("", "<'input>", "")
             };
 
         let has_members = !gathered.request_args.is_empty();
@@ -960,35 +1723,55 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             name = name
         );
         out.indented(|out| {
-            outln!(
-                out,
-                "/// Serialize this request into bytes for the provided connection",
-            );
-            outln!(
-                out,
-                "fn serialize<{lifetime}Conn>(self, conn: &Conn) -> \
-                 Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>",
-                lifetime = serialize_lifetime_block,
-            );
-            outln!(out, "where");
-            outln!(out.indent(), "Conn: RequestConnection + ?Sized,");
-            outln!(out, "{{");
+            if ns.ext_info.is_some() {
+                outln!(
+                    out,
+                    "/// Serialize this request into bytes for the extension with the given major \
+                     opcode",
+                );
+                outln!(
+                    out,
+                    "pub fn serialize{lifetime}(self, major_opcode: u8) -> \
+                     BufWithFds<PiecewiseBuf<'input>> {{",
+                    lifetime = serialize_lifetime_block,
+                );
+                outln!(
+                    out.indent(),
+                    "self.serialize_with_order(major_opcode, ByteOrder::native())"
+                );
+                outln!(out, "}}");
+                outln!(
+                    out,
+                    "/// Serialize this request into bytes in the given byte order for the \
+                     extension with the given major opcode",
+                );
+                outln!(
+                    out,
+                    "pub fn serialize_with_order{lifetime}(self, major_opcode: u8, \
+                     order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {{",
+                    lifetime = serialize_lifetime_block,
+                );
+            } else {
+                outln!(out, "/// Serialize this request into bytes");
+                outln!(
+                    out,
+                    "pub fn serialize{lifetime}(self) -> BufWithFds<PiecewiseBuf<'input>> {{",
+                    lifetime = serialize_lifetime_block,
+                );
+                outln!(
+                    out.indent(),
+                    "self.serialize_with_order(ByteOrder::native())"
+                );
+                outln!(out, "}}");
+                outln!(out, "/// Serialize this request into bytes in the given byte order");
+                outln!(
+                    out,
+                    "pub fn serialize_with_order{lifetime}(self, order: ByteOrder) -> \
+                     BufWithFds<PiecewiseBuf<'input>> {{",
+                    lifetime = serialize_lifetime_block,
+                );
+            }
             out.indented(|out| {
-                if ns.ext_info.is_some() {
-                    outln!(
-                        out,
-                        "let extension_information = \
-                         conn.extension_information(X11_EXTENSION_NAME)?",
-                    );
-                    outln!(
-                        out.indent(),
-                        ".ok_or(ConnectionError::UnsupportedExtension)?;"
-                    );
-                } else {
-                    // Silence a warning about an unused `conn`.
-                    outln!(out, "let _ = conn;");
-                }
-
                 let fields = request_def.fields.borrow();
 
                 let has_expr_fields = fields.iter().any(|field| {
@@ -1066,8 +1849,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         xcbdefs::FieldDef::Normal(normal_field) => {
                             if normal_field.name == "major_opcode" {
                                 if ns.ext_info.is_some() {
-                                    fixed_fields_bytes
-                                        .push(String::from("extension_information.major_opcode"));
+                                    fixed_fields_bytes.push(String::from("major_opcode"));
                                 } else {
                                     fixed_fields_bytes.push(format!(
                                         "{}_REQUEST",
@@ -1135,7 +1917,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 } else {
                                     outln!(
                                         tmp_out,
-                                        "let {} = self.{}.serialize();",
+                                        "let {} = self.{}.serialize_with_order(order);",
                                         bytes_name,
                                         rust_field_name,
                                     );
@@ -1164,7 +1946,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                     let bytes_name = postfix_var_name(&rust_field_name, "bytes");
                                     outln!(
                                         tmp_out,
-                                        "let {} = self.{}.serialize();",
+                                        "let {} = self.{}.serialize_with_order(order);",
                                         bytes_name,
                                         rust_field_name,
                                     );
@@ -1190,22 +1972,27 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         xcbdefs::FieldDef::Switch(switch_field) => {
                             let rust_field_name = to_rust_variable_name(&switch_field.name);
                             let bytes_name = postfix_var_name(&rust_field_name, "bytes");
+                            let mut serialize_args = self.ext_params_to_call_args(
+                                false,
+                                |name| {
+                                    if deducible_fields.get(name).is_some() {
+                                        to_rust_variable_name(name)
+                                    } else {
+                                        format!("self.{}", to_rust_variable_name(name))
+                                    }
+                                },
+                                &*switch_field.external_params.borrow(),
+                            );
+                            if !serialize_args.is_empty() {
+                                serialize_args.push_str(", ");
+                            }
+                            serialize_args.push_str("order");
                             outln!(
                                 tmp_out,
-                                "let {} = self.{}.serialize({});",
+                                "let {} = self.{}.serialize_with_order({});",
                                 bytes_name,
                                 rust_field_name,
-                                self.ext_params_to_call_args(
-                                    false,
-                                    |name| {
-                                        if deducible_fields.get(name).is_some() {
-                                            to_rust_variable_name(name)
-                                        } else {
-                                            format!("self.{}", to_rust_variable_name(name))
-                                        }
-                                    },
-                                    &*switch_field.external_params.borrow(),
-                                )
+                                serialize_args,
                             );
                             if let Some(field_size) = switch_field.size() {
                                 for i in 0..field_size {
@@ -1350,7 +2137,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 );
                 outln!(
                     out,
-                    "request0[2..4].copy_from_slice(&length.to_ne_bytes());",
+                    "request0[2..4].copy_from_slice(&length.serialize_with_order(order));",
                 );
 
                 let fds_arg = if gathered.fd_lists.is_empty() {
@@ -1398,73 +2185,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
                 outln!(
                     out,
-                    "Ok((vec![{slices}], {fds}))",
+                    "(vec![{slices}], {fds})",
                     slices = slices_arg,
                     fds = fds_arg,
                 );
             });
             outln!(out, "}}");
 
-            // Sending method
-            let is_list_fonts_with_info =
-                request_def.name == "ListFontsWithInfo" && ns.header == "xproto";
-            let is_record_enable_context =
-                request_def.name == "EnableContext" && ns.header == "record";
-            let ret_type = if is_list_fonts_with_info || is_record_enable_context {
-                assert!(request_def.reply.is_some());
-                match (is_list_fonts_with_info, is_record_enable_context) {
-                    (true, _) => "ListFontsWithInfoCookie<'_, Conn>".to_string(),
-                    (_, true) => "RecordEnableContextCookie<'_, Conn>".to_string(),
-                    _ => unreachable!(),
-                }
-            } else {
-                match (request_def.reply.is_some(), gathered.reply_has_fds) {
-                    (false, _) => "VoidCookie<'_, Conn>".to_string(),
-                    (true, false) => format!("Cookie<'_, Conn, {}Reply>", name),
-                    (true, true) => format!("CookieWithFds<'_, Conn, {}Reply>", name),
-                }
-            };
-            outln!(
-                out,
-                "pub fn send<Conn>(self, conn: &Conn) -> Result<{}, ConnectionError>",
-                ret_type,
-            );
-            outln!(out, "where");
-            outln!(out.indent(), "Conn: RequestConnection + ?Sized,");
-            outln!(out, "{{");
-            out.indented(|out| {
-                outln!(out, "let (bytes, fds) = self.serialize(conn)?;");
-                outln!(
-                    out,
-                    "let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();"
-                );
-
-                if is_list_fonts_with_info || is_record_enable_context {
-                    let cookie = match (is_list_fonts_with_info, is_record_enable_context) {
-                        (true, _) => "ListFontsWithInfoCookie",
-                        (_, true) => "RecordEnableContextCookie",
-                        _ => unreachable!(),
-                    };
-                    outln!(
-                        out,
-                        "Ok({}::new(conn.send_request_with_reply(&slices, fds)?))",
-                        cookie,
-                    )
-                } else if request_def.reply.is_some() {
-                    if gathered.reply_has_fds {
-                        outln!(
-                            out,
-                            "Ok(conn.send_request_with_reply_with_fds(&slices, fds)?)",
-                        );
-                    } else {
-                        outln!(out, "Ok(conn.send_request_with_reply(&slices, fds)?)",);
-                    }
-                } else {
-                    outln!(out, "Ok(conn.send_request_without_reply(&slices, fds)?)",);
-                }
-            });
-            outln!(out, "}}");
-
             // Parsing implementation.
             outln!(
                 out,
@@ -1478,6 +2205,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                      fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {{",
                     lifetime = parse_lifetime_block,
                 );
+                outln!(
+                    out.indent(),
+                    "Self::try_parse_request_fd_with_order(header, value, fds, ByteOrder::native())",
+                );
             } else {
                 outln!(
                     out,
@@ -1485,6 +2216,32 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                      Result<Self, ParseError> {{",
                     lifetime = parse_lifetime_block,
                 );
+                outln!(
+                    out.indent(),
+                    "Self::try_parse_request_with_order(header, value, ByteOrder::native())",
+                );
+            }
+            outln!(out, "}}");
+            outln!(
+                out,
+                "/// Parse this request given its header, its body in the given byte order, and \
+                 any fds that go along with it"
+            );
+            if gathered.has_fds() {
+                outln!(
+                    out,
+                    "pub fn try_parse_request_fd_with_order(header: RequestHeader, \
+                     value: &{lifetime}[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> \
+                     Result<Self, ParseError> {{",
+                    lifetime = parse_lifetime_block,
+                );
+            } else {
+                outln!(
+                    out,
+                    "pub fn try_parse_request_with_order(header: RequestHeader, \
+                     value: &{lifetime}[u8], order: ByteOrder) -> Result<Self, ParseError> {{",
+                    lifetime = parse_lifetime_block,
+                );
             }
             out.indented(|out| {
                 if ns.ext_info.is_some() {
@@ -1510,6 +2267,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 };
 
                 let fields = request_def.fields.borrow();
+                let body_fields = fields.iter().filter(|field| match field.name() {
+                    Some("major_opcode") | Some("minor_opcode") | Some("length") => false,
+                    _ => true,
+                });
+                if !self.fields_depend_on_byte_order(body_fields) {
+                    outln!(out, "let _ = order;");
+                }
                 let mut seen_complete_header = false;
                 let mut is_first_body_field = true;
                 for (_, field) in fields.iter().enumerate() {
@@ -1624,20 +2388,96 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 });
                 outln!(out, "}}");
             }
-        });
-        outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(
+            out,
+            "impl{lifetime} Request for {name}Request{lifetime} {{",
+            lifetime = struct_lifetime_block,
+            name = name
+        );
+        if request_def.reply.is_some() {
+            outln!(out.indent(), "type Reply = {}Reply;", name);
+        } else {
+            outln!(out.indent(), "type Reply = ();");
+        }
+        outln!(out, "}}");
+    }
+
+    fn emit_request_send_function(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        name: &str,
+        function_name: &str,
+        gathered: &GatheredRequestFields,
+        out: &mut Output,
+    ) {
+        let ns = request_def.namespace.upgrade().unwrap();
+        let is_list_fonts_with_info =
+            request_def.name == "ListFontsWithInfo" && ns.header == "xproto";
+        let is_record_enable_context = request_def.name == "EnableContext" && ns.header == "record";
+
+        let ret_type = if is_list_fonts_with_info || is_record_enable_context {
+            assert!(request_def.reply.is_some());
+            if is_list_fonts_with_info {
+                "ListFontsWithInfoCookie<'c, Conn>".to_string()
+            } else {
+                "RecordEnableContextCookie<'c, Conn>".to_string()
+            }
+        } else {
+            match (request_def.reply.is_some(), gathered.reply_has_fds) {
+                (false, _) => "VoidCookie<'c, Conn>".to_string(),
+                (true, false) => format!("Cookie<'c, Conn, {}Reply>", name),
+                (true, true) => format!("CookieWithFds<'c, Conn, {}Reply>", name),
+            }
+        };
+        let request_lifetime = if gathered.needs_lifetime { "<'_>" } else { "" };
+
+        outln!(out, "{}", TRACK_CALLER);
         outln!(
             out,
-            "impl{lifetime} Request for {name}Request{lifetime} {{",
-            lifetime = struct_lifetime_block,
-            name = name
+            "fn send_{}<'c, Conn>(req: {}Request{}, conn: &'c Conn) -> Result<{}, ConnectionError>",
+            function_name,
+            name,
+            request_lifetime,
+            ret_type,
         );
-        if request_def.reply.is_some() {
-            outln!(out.indent(), "type Reply = {}Reply;", name);
-        } else {
-            outln!(out.indent(), "type Reply = ();");
-        }
+        outln!(out, "where");
+        outln!(out.indent(), "Conn: RequestConnection + ?Sized,");
+        outln!(out, "{{");
+        out.indented(|out| {
+            if ns.ext_info.is_some() {
+                outln!(out, "let (bytes, fds) = req.serialize(major_opcode(conn)?);");
+            } else {
+                outln!(out, "let (bytes, fds) = req.serialize();");
+            }
+            outln!(
+                out,
+                "let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();"
+            );
+
+            if is_list_fonts_with_info {
+                outln!(
+                    out,
+                    "Ok(ListFontsWithInfoCookie::new(conn.send_request_with_reply(&slices, fds)?))",
+                )
+            } else if is_record_enable_context {
+                outln!(
+                    out,
+                    "Ok(RecordEnableContextCookie::new(conn.send_request_with_reply(&slices, fds)?))",
+                )
+            } else if request_def.reply.is_some() {
+                if gathered.reply_has_fds {
+                    outln!(out, "conn.send_request_with_reply_with_fds(&slices, fds)");
+                } else {
+                    outln!(out, "conn.send_request_with_reply(&slices, fds)");
+                }
+            } else {
+                outln!(out, "conn.send_request_without_reply(&slices, fds)");
+            }
+        });
         outln!(out, "}}");
+        outln!(out, "");
     }
 
     fn emit_request_function(
@@ -1700,6 +2540,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if let Some(ref doc) = request_def.doc {
             self.emit_doc(doc, out);
         }
+        outln!(out, "{}", TRACK_CALLER);
         outln!(
             out,
             "pub fn {}<{}>({}) -> Result<{}, ConnectionError>",
@@ -1738,7 +2579,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(out, "}};");
             }
 
-            outln!(out, "request0.send(conn)")
+            outln!(out, "send_{}(request0, conn)", function_name)
         });
         outln!(out, "}}");
     }
@@ -1812,6 +2653,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if let Some(ref doc) = request_def.doc {
             self.emit_doc(doc, out);
         }
+        outln!(out, "{}", TRACK_CALLER);
         outln!(
             out,
             "fn {}{}{}({}) -> Result<{}, ConnectionError>",
@@ -1901,6 +2743,12 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             event_full_def.doc.as_ref(),
             out,
         );
+        let skipped_fields: &[&str] = if event_full_def.xge {
+            &["response_type", "extension", "length", "event_type"]
+        } else {
+            &["response_type"]
+        };
+        self.emit_trace_impl(&full_name, &*fields, skipped_fields, false, out);
 
         if !event_full_def.xge {
             let deducible_fields = gather_deducible_fields(&*fields);
@@ -1973,9 +2821,16 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         deducible_fields: &HashMap<String, DeducibleField>,
         out: &mut Output,
     ) {
-        outln!(out, "impl From<&{}> for [u8; 32] {{", name);
+        outln!(out, "impl {} {{", name);
         out.indented(|out| {
-            outln!(out, "fn from(input: &{}) -> Self {{", name);
+            outln!(
+                out,
+                "/// Serialize this event into bytes in the given byte order"
+            );
+            outln!(
+                out,
+                "pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {{"
+            );
             out.indented(|out| {
                 // This gathers the bytes of the result
                 let mut result_bytes = Vec::new();
@@ -1986,7 +2841,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         |field_name| {
                             let rust_field_name = to_rust_variable_name(field_name);
                             if !deducible_fields.contains_key(field_name) {
-                                format!("input.{}", rust_field_name)
+                                format!("self.{}", rust_field_name)
                             } else {
                                 rust_field_name
                             }
@@ -2012,6 +2867,17 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         });
         outln!(out, "}}");
 
+        outln!(out, "impl From<&{}> for [u8; 32] {{", name);
+        out.indented(|out| {
+            outln!(out, "fn from(input: &{}) -> Self {{", name);
+            outln!(
+                out.indent(),
+                "input.serialize_with_order(ByteOrder::native())"
+            );
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
         outln!(out, "impl From<{}> for [u8; 32] {{", name);
         out.indented(|out| {
             outln!(out, "fn from(input: {}) -> Self {{", name);
@@ -2043,6 +2909,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             None,
             out,
         );
+        self.emit_trace_impl(&rust_name, &*struct_def.fields.borrow(), &[], true, out);
 
         outln!(out, "");
     }
@@ -2056,7 +2923,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         let union_size = union_def.size();
 
         outln!(out, "#[derive(Debug, Copy, Clone)]");
+        emit_serde_derive(out);
         outln!(out, "pub struct {}([u8; {}]);", rust_name, union_size);
+        emit_raw_bytes_trace_impl(&rust_name, out);
 
         let fields = union_def.fields.as_slice();
 
@@ -2078,6 +2947,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         rust_field_type,
                     );
                     out.indented(|out| {
+                        if !self.field_is_u8_list(field) {
+                            outln!(out, "let order = ByteOrder::native();");
+                        }
                         self.emit_field_parse(
                             field,
                             &rust_name,
@@ -2106,6 +2978,20 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "fn serialize_into(&self, bytes: &mut Vec<u8>) {{");
             outln!(out.indent(), "bytes.extend_from_slice(&self.0);");
             outln!(out, "}}");
+            // The raw bytes are kept as they appear on the wire.
+            outln!(
+                out,
+                "fn serialize_with_order(&self, _: ByteOrder) -> [u8; {}] {{",
+                union_size,
+            );
+            outln!(out.indent(), "self.0");
+            outln!(out, "}}");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _: ByteOrder) {{"
+            );
+            outln!(out.indent(), "bytes.extend_from_slice(&self.0);");
+            outln!(out, "}}");
         });
         outln!(out, "}}");
         outln!(out, "impl TryParse for {} {{", rust_name);
@@ -2128,6 +3014,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(out, "Ok((result, &value[{}..]))", union_size);
             });
             outln!(out, "}}");
+            emit_raw_try_parse_with_order(out);
         });
         outln!(out, "}}");
 
@@ -2187,6 +3074,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 );
                 let mut result_bytes = Vec::new();
                 out.indented(|out| {
+                    if !self.field_is_u8_list(field) {
+                        outln!(out, "let order = ByteOrder::native();");
+                    }
                     let bytes_name = self.emit_field_serialize(
                         field,
                         &HashMap::new(),
@@ -2236,7 +3126,9 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
 
         outln!(out, "#[derive(Debug, Copy, Clone)]");
+        emit_serde_derive(out);
         outln!(out, "pub struct {}([u8; 32]);", rust_name);
+        emit_raw_bytes_trace_impl(&rust_name, out);
 
         outln!(out, "impl {} {{", rust_name);
         out.indented(|out| {
@@ -2274,6 +3166,19 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "fn serialize_into(&self, bytes: &mut Vec<u8>) {{");
             outln!(out.indent(), "bytes.extend_from_slice(&self.0);");
             outln!(out, "}}");
+            // The raw bytes are kept as they appear on the wire.
+            outln!(
+                out,
+                "fn serialize_with_order(&self, _: ByteOrder) -> [u8; 32] {{"
+            );
+            outln!(out.indent(), "self.0");
+            outln!(out, "}}");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _: ByteOrder) {{"
+            );
+            outln!(out.indent(), "bytes.extend_from_slice(&self.0);");
+            outln!(out, "}}");
         });
         outln!(out, "}}");
 
@@ -2292,6 +3197,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 outln!(out, "Ok((result, &value[32..]))");
             });
             outln!(out, "}}");
+            emit_raw_try_parse_with_order(out);
         });
         outln!(out, "}}");
 
@@ -2329,13 +3235,31 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
     fn generate_xid_type_def(&self, xid_type_def: &xcbdefs::XidTypeDef, out: &mut Output) {
         let rust_name = self.get_xid_type_rust_name(xid_type_def);
-        outln!(out, "pub type {} = u32;", rust_name);
+        if self.xid_newtypes {
+            outln!(out, "xid_newtype!({});", rust_name);
+        } else {
+            outln!(out, "pub type {} = u32;", rust_name);
+        }
         outln!(out, "");
     }
 
     fn generate_xid_union_def(&self, xid_union_def: &xcbdefs::XidUnionDef, out: &mut Output) {
         let rust_name = self.get_xid_union_rust_name(xid_union_def);
-        outln!(out, "pub type {} = u32;", rust_name);
+        if self.xid_newtypes {
+            let members = xid_union_def
+                .types
+                .iter()
+                .map(|type_| self.type_to_rust_type(type_.get_resolved()))
+                .collect::<Vec<_>>();
+            outln!(
+                out,
+                "xid_union_newtype!({}: {});",
+                rust_name,
+                members.join(", ")
+            );
+        } else {
+            outln!(out, "pub type {} = u32;", rust_name);
+        }
         outln!(out, "");
     }
 
@@ -2405,6 +3329,28 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 );
             }
         }
+        out.indented(|out| {
+            outln!(
+                out,
+                "/// The values of this enumeration with their names in upper snake case and in \
+                 camel case."
+            );
+            outln!(out, "pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[");
+            for enum_item in enum_def.items.iter() {
+                let value = match enum_item.value {
+                    xcbdefs::EnumValue::Value(value) => format_literal_integer(value),
+                    xcbdefs::EnumValue::Bit(bit) => format!("1 << {}", bit),
+                };
+                outln!(
+                    out.indent(),
+                    "({}, \"{}\", \"{}\"),",
+                    value,
+                    ename_to_rust(&enum_item.name),
+                    ename_to_camel_case(&enum_item.name),
+                );
+            }
+            outln!(out, "];");
+        });
         outln!(out, "}}");
 
         outln!(out, "impl From<{}> for {} {{", rust_name, raw_type);
@@ -2467,6 +3413,48 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
         }
 
+        // With XID aliases, the conversion into u32 above already covers the XID types
+        let xid_types = if self.xid_newtypes {
+            self.caches.borrow().enum_xid_types(enum_def).to_vec()
+        } else {
+            Vec::new()
+        };
+        let mut xid_types = xid_types
+            .iter()
+            .map(|xid_type| self.type_to_rust_type(xid_type))
+            .collect::<Vec<_>>();
+        xid_types.sort();
+        xid_types.dedup();
+        for xid_type in xid_types.iter() {
+            let value = if raw_type == "u32" {
+                "input.0"
+            } else {
+                "u32::from(input.0)"
+            };
+            outln!(out, "impl From<{}> for {} {{", rust_name, xid_type);
+            out.indented(|out| {
+                outln!(out, "#[inline]");
+                outln!(out, "fn from(input: {}) -> Self {{", rust_name);
+                outln!(out.indent(), "Self({})", value);
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+            outln!(
+                out,
+                "impl From<{}> for {}<{}> {{",
+                rust_name,
+                self.option_name,
+                xid_type,
+            );
+            out.indented(|out| {
+                outln!(out, "#[inline]");
+                outln!(out, "fn from(input: {}) -> Self {{", rust_name);
+                outln!(out.indent(), "Some({}({}))", xid_type, value);
+                outln!(out, "}}");
+            });
+            outln!(out, "}}");
+        }
+
         outln!(out, "impl From<{}> for {} {{", raw_type, rust_name);
         out.indented(|out| {
             outln!(out, "#[inline]");
@@ -2476,54 +3464,78 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         });
         outln!(out, "}}");
 
-        // An enum is ok for bitmask if all its values are <bit>
-        // or have value zero (but not if all values are zero)
-        let ok_for_bitmask = enum_def
-            .items
-            .iter()
-            .all(|enum_item| match enum_item.value {
-                xcbdefs::EnumValue::Value(0) | xcbdefs::EnumValue::Bit(_) => true,
-                _ => false,
-            })
-            && enum_def
-                .items
-                .iter()
-                .any(|enum_item| match enum_item.value {
-                    xcbdefs::EnumValue::Value(_) => false,
-                    xcbdefs::EnumValue::Bit(_) => true,
-                });
+        let ok_for_bitmask = enum_is_bitmask(enum_def);
+
+        let into = match global_enum_size {
+            32 => "",
+            _ => ".into()",
+        };
+        let kind = if ok_for_bitmask { "bitmask" } else { "enum" };
 
-        outln!(out, "impl std::fmt::Debug for {}  {{", rust_name);
+        outln!(out, "impl core::fmt::Debug for {}  {{", rust_name);
         out.indented(|out| {
             outln!(
                 out,
-                "fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+                "fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+            );
+            outln!(
+                out.indent(),
+                "pretty_print_{}(fmt, self.0{}, Self::VARIANTS)",
+                kind,
+                into
+            );
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
+        outln!(out, "impl Trace for {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {{"
+            );
+            let method = if ok_for_bitmask {
+                "bitmask"
+            } else {
+                "enum_value"
+            };
+            outln!(out.indent(), "tracer.{}(&self.0, Self::VARIANTS)", method);
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+
+        outln!(out, "#[cfg(feature = \"serde\")]");
+        outln!(out, "impl serde::Serialize for {} {{", rust_name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{",
+            );
+            outln!(
+                out.indent(),
+                "crate::utils::serialize_{}(serializer, self.0{}, Self::VARIANTS)",
+                kind,
+                into,
+            );
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+        outln!(out, "#[cfg(feature = \"serde\")]");
+        outln!(
+            out,
+            "impl<'de> serde::Deserialize<'de> for {} {{",
+            rust_name
+        );
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{",
+            );
+            outln!(
+                out.indent(),
+                "crate::utils::deserialize_{}(deserializer, Self::VARIANTS).map(Self)",
+                kind,
             );
-            out.indented(|out| {
-                let into = match global_enum_size {
-                    32 => "",
-                    _ => ".into()",
-                };
-                outln!(out, "let variants = [");
-                for enum_item in enum_def.items.iter() {
-                    let rust_item_name = ename_to_rust(&enum_item.name);
-                    let camel_item_name = ename_to_camel_case(&enum_item.name);
-                    outln!(
-                        out.indent(),
-                        "(Self::{}.0{}, \"{}\", \"{}\"),",
-                        rust_item_name,
-                        into,
-                        rust_item_name,
-                        camel_item_name
-                    );
-                }
-                outln!(out, "];");
-                if ok_for_bitmask {
-                    outln!(out, "pretty_print_bitmask(fmt, self.0{}, &variants)", into);
-                } else {
-                    outln!(out, "pretty_print_enum(fmt, self.0{}, &variants)", into);
-                }
-            });
             outln!(out, "}}");
         });
         outln!(out, "}}");
@@ -2619,10 +3631,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         if let Some(doc) = doc {
             self.emit_doc(doc, out);
         }
-        let derives = derives.to_list();
-        if !derives.is_empty() {
-            outln!(out, "#[derive({})]", derives.join(", "));
+        let derive_list = derives.to_list();
+        if !derive_list.is_empty() {
+            outln!(out, "#[derive({})]", derive_list.join(", "));
         }
+        derives.emit_serde(out);
         outln!(out, "pub struct {} {{", name);
         for field in fields.iter() {
             if self.field_is_visible(field, &deducible_fields) {
@@ -2654,9 +3667,22 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             if has_fds {
                 assert!(external_params.is_empty());
                 outln!(out, "impl TryParseFd for {} {{", name);
+                out.indented(|out| {
+                    outln!(
+                        out,
+                        "fn try_parse_fd<'a>(value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> \
+                         Result<(Self, &'a [u8]), ParseError> {{",
+                    );
+                    outln!(
+                        out.indent(),
+                        "Self::try_parse_fd_with_order(value, fds, ByteOrder::native())",
+                    );
+                    outln!(out, "}}");
+                });
                 outln!(
                     out.indent(),
-                    "fn try_parse_fd<'a>({}: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {{",
+                    "fn try_parse_fd_with_order<'a>({}: &'a [u8], fds: &mut Vec<RawFdContainer>, \
+                     order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {{",
                     input_name,
                 );
             } else if !external_params.is_empty() {
@@ -2671,24 +3697,47 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         )
                     })
                     .collect::<Vec<_>>();
+                let args = external_params
+                    .iter()
+                    .map(|ext_param| to_rust_variable_name(&ext_param.name))
+                    .collect::<Vec<_>>();
+                out.indented(|out| {
+                    outln!(
+                        out,
+                        "pub fn try_parse(value: &[u8], {}) -> Result<(Self, &[u8]), ParseError> \
+                         {{",
+                        p.join(", "),
+                    );
+                    outln!(
+                        out.indent(),
+                        "Self::try_parse_with_order(value, {}, ByteOrder::native())",
+                        args.join(", "),
+                    );
+                    outln!(out, "}}");
+                });
                 outln!(
                     out.indent(),
-                    "pub fn try_parse({}: &[u8], {}) -> Result<(Self, &[u8]), ParseError> \
-                     {{",
+                    "pub fn try_parse_with_order({}: &[u8], {}, order: ByteOrder) -> \
+                     Result<(Self, &[u8]), ParseError> {{",
                     input_name,
                     p.join(", "),
                 );
             } else {
                 outln!(out, "impl TryParse for {} {{", name);
+                out.indented(emit_native_try_parse);
                 outln!(
                     out.indent(),
-                    "fn try_parse({}: &[u8]) -> Result<(Self, &[u8]), ParseError> {{",
+                    "fn try_parse_with_order({}: &[u8], order: ByteOrder) -> \
+                     Result<(Self, &[u8]), ParseError> {{",
                     input_name,
                 );
             }
 
             out.indented(|out| {
                 out.indented(|out| {
+                    if !self.fields_depend_on_byte_order(fields) {
+                        outln!(out, "let _ = order;");
+                    }
                     if parse_size_constraint != StructSizeConstraint::None {
                         outln!(out, "let remaining = initial_value;");
                     }
@@ -2914,14 +3963,18 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out.indented(|out| {
             if external_params.is_empty() {
                 outln!(out, "type Bytes = [u8; {}];", size);
+                emit_native_serialize(&format!("[u8; {}]", size), out);
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> [u8; {}] {{",
+                "fn serialize_with_order(&self{}, order: ByteOrder) -> [u8; {}] {{",
                 ext_params_arg_defs,
                 size,
             );
             out.indented(|out| {
+                if !self.fields_depend_on_byte_order(fields) {
+                    outln!(out, "let _ = order;");
+                }
                 // This gathers the bytes of the result
                 let mut result_bytes = Vec::new();
                 for field in fields.iter() {
@@ -2952,10 +4005,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>{}, order: ByteOrder) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
+                if !self.fields_depend_on_byte_order(fields) {
+                    outln!(out, "let _ = order;");
+                }
                 outln!(out, "bytes.reserve({});", size);
                 for field in fields.iter() {
                     if skip_length_field && field.name() == Some("length") {
@@ -2984,6 +4040,57 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         outln!(out, "}}");
     }
 
+    fn emit_reply_serialize(&self, name: &str, fields: &[xcbdefs::FieldDef], out: &mut Output) {
+        let deducible_fields = gather_deducible_fields(fields);
+
+        outln!(out, "impl Serialize for {} {{", name);
+        out.indented(|out| {
+            outln!(out, "type Bytes = Vec<u8>;");
+            emit_native_serialize("Vec<u8>", out);
+            outln!(
+                out,
+                "fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {{"
+            );
+            out.indented(|out| {
+                outln!(out, "let mut result = Vec::new();");
+                outln!(out, "self.serialize_into_with_order(&mut result, order);");
+                outln!(out, "result");
+            });
+            outln!(out, "}}");
+            outln!(
+                out,
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {{"
+            );
+            out.indented(|out| {
+                outln!(out, "let start = bytes.len();");
+                outln!(out, "bytes.reserve(32);");
+                for field in fields.iter() {
+                    self.emit_field_serialize_into(
+                        field,
+                        &deducible_fields,
+                        |field_name| {
+                            let rust_field_name = to_rust_variable_name(field_name);
+                            if !deducible_fields.contains_key(field_name) {
+                                format!("self.{}", rust_field_name)
+                            } else {
+                                rust_field_name
+                            }
+                        },
+                        "bytes",
+                        out,
+                    );
+                }
+                // The length field is filled in based on the actual size
+                outln!(
+                    out,
+                    "crate::x11_utils::finish_reply_serialization(bytes, start, order);"
+                );
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
     fn emit_variable_size_struct_serialize(
         &self,
         name: &str,
@@ -3005,19 +4112,20 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out.indented(|out| {
             if external_params.is_empty() {
                 outln!(out, "type Bytes = Vec<u8>;");
+                emit_native_serialize("Vec<u8>", out);
             } else {
                 outln!(out, "#[allow(dead_code)]");
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> Vec<u8> {{",
+                "fn serialize_with_order(&self{}, order: ByteOrder) -> Vec<u8> {{",
                 ext_params_arg_defs,
             );
             out.indented(|out| {
                 outln!(out, "let mut result = Vec::new();");
                 outln!(
                     out,
-                    "self.serialize_into(&mut result{});",
+                    "self.serialize_into_with_order(&mut result{}, order);",
                     ext_params_call_args,
                 );
                 outln!(out, "result");
@@ -3025,10 +4133,13 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>{}, order: ByteOrder) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
+                if !self.fields_depend_on_byte_order(fields) {
+                    outln!(out, "let _ = order;");
+                }
                 // Variable size structures usually have some fixed size
                 // fields at the beginning. Gather the total size of those
                 // fields...
@@ -3170,6 +4281,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     None,
                     out,
                 );
+                self.emit_trace_impl(&type_name, &*case_fields, &[], true, out);
 
                 case_infos.push(CaseInfo::MultiField(field_name, type_name));
             }
@@ -3183,13 +4295,14 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         for case in switch.cases.iter() {
             self.filter_derives_for_fields(&mut derives, &*case.fields.borrow(), false);
         }
-        let mut derives = derives.to_list();
+        let mut derive_list = derives.to_list();
         if switch.kind == xcbdefs::SwitchKind::BitCase {
-            derives.push("Default");
+            derive_list.push("Default");
         }
-        if !derives.is_empty() {
-            outln!(out, "#[derive({})]", derives.join(", "));
+        if !derive_list.is_empty() {
+            outln!(out, "#[derive({})]", derive_list.join(", "));
         }
+        derives.emit_serde(out);
 
         if switch.kind == xcbdefs::SwitchKind::BitCase {
             outln!(out, "pub struct {} {{", name);
@@ -3247,6 +4360,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
         }
 
+        self.emit_switch_trace_impl(switch, name, &case_infos, out);
+
         if generate_try_parse {
             self.emit_switch_try_parse(switch, name, &case_infos, out);
         }
@@ -3399,14 +4514,17 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 .collect::<Vec<_>>();
             outln!(
                 out.indent(),
-                "fn try_parse(value: &[u8], {}) -> Result<(Self, &[u8]), ParseError> {{",
+                "fn try_parse_with_order(value: &[u8], {}, order: ByteOrder) -> \
+                 Result<(Self, &[u8]), ParseError> {{",
                 p.join(", "),
             );
         } else {
             outln!(out, "impl TryParse for {} {{", name);
+            out.indented(emit_native_try_parse);
             outln!(
                 out.indent(),
-                "fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {{",
+                "fn try_parse_with_order(value: &[u8], order: ByteOrder) -> \
+                 Result<(Self, &[u8]), ParseError> {{",
             );
         }
 
@@ -3482,9 +4600,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                     for p in case.external_params.borrow().iter() {
                                         parse_params.push(to_rust_variable_name(&p.name));
                                     }
+                                    parse_params.push(String::from("order"));
                                     outln!(
                                         out,
-                                        "let ({}, new_remaining) = {}::try_parse({})?;",
+                                        "let ({}, new_remaining) = {}::try_parse_with_order({})?;",
                                         rust_case_name,
                                         struct_name,
                                         parse_params.join(", "),
@@ -3574,9 +4693,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                     for p in case.external_params.borrow().iter() {
                                         parse_params.push(to_rust_variable_name(&p.name));
                                     }
+                                    parse_params.push(String::from("order"));
                                     outln!(
                                         out,
-                                        "let ({}, new_remaining) = {}::try_parse({})?;",
+                                        "let ({}, new_remaining) = {}::try_parse_with_order({})?;",
                                         rust_case_var_name,
                                         struct_name,
                                         parse_params.join(", "),
@@ -3634,10 +4754,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out.indented(|out| {
             if external_params.is_empty() {
                 outln!(out, "type Bytes = [u8; {}];", size);
+                emit_native_serialize(&format!("[u8; {}]", size), out);
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> [u8; {}] {{",
+                "fn serialize_with_order(&self{}, order: ByteOrder) -> [u8; {}] {{",
                 ext_params_arg_defs,
                 size,
             );
@@ -3682,7 +4803,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                 let rust_field_name = to_rust_variable_name(field_name);
                                 outln!(
                                     out,
-                                    "{}::{}({}) => {}.serialize(),",
+                                    "{}::{}({}) => {}.serialize_with_order(order),",
                                     name,
                                     to_rust_type_name(field_name),
                                     rust_field_name,
@@ -3697,7 +4818,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>{}, order: ByteOrder) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
@@ -3739,7 +4860,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                             let rust_field_name = to_rust_variable_name(field_name);
                             outln!(
                                 out,
-                                "{}::{}(ref {}) => {}.serialize_into(bytes),",
+                                "{}::{}(ref {}) => {}.serialize_into_with_order(bytes, order),",
                                 name,
                                 to_rust_type_name(field_name),
                                 rust_field_name,
@@ -3775,19 +4896,20 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         out.indented(|out| {
             if external_params.is_empty() {
                 outln!(out, "type Bytes = Vec<u8>;");
+                emit_native_serialize("Vec<u8>", out);
             } else {
                 outln!(out, "#[allow(dead_code)]");
             }
             outln!(
                 out,
-                "fn serialize(&self{}) -> Vec<u8> {{",
+                "fn serialize_with_order(&self{}, order: ByteOrder) -> Vec<u8> {{",
                 ext_params_arg_defs,
             );
             out.indented(|out| {
                 outln!(out, "let mut result = Vec::new();");
                 outln!(
                     out,
-                    "self.serialize_into(&mut result{});",
+                    "self.serialize_into_with_order(&mut result{}, order);",
                     ext_params_call_args,
                 );
                 outln!(out, "result");
@@ -3795,7 +4917,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             outln!(out, "}}");
             outln!(
                 out,
-                "fn serialize_into(&self, bytes: &mut Vec<u8>{}) {{",
+                "fn serialize_into_with_order(&self, bytes: &mut Vec<u8>{}, order: ByteOrder) {{",
                 ext_params_arg_defs
             );
             out.indented(|out| {
@@ -3840,110 +4962,600 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                                         outln!(out, "bytes.reserve({});", fixed_part_size);
                                     }
 
-                                    for field in fields.iter() {
-                                        self.emit_field_serialize_into(
-                                            field,
-                                            &deducible_fields,
-                                            |field| to_rust_variable_name(field),
-                                            "bytes",
-                                            out,
-                                        );
-                                    }
-                                });
-                                outln!(out, "}}");
-                            }
-                            CaseInfo::MultiField(field_name, _) => {
-                                let rust_field_name = to_rust_variable_name(field_name);
-                                let ext_params_call_args = self.ext_params_to_call_args(
-                                    true,
-                                    to_rust_variable_name,
-                                    &*case.external_params.borrow(),
-                                );
-                                outln!(
+                                    for field in fields.iter() {
+                                        self.emit_field_serialize_into(
+                                            field,
+                                            &deducible_fields,
+                                            |field| to_rust_variable_name(field),
+                                            "bytes",
+                                            out,
+                                        );
+                                    }
+                                });
+                                outln!(out, "}}");
+                            }
+                            CaseInfo::MultiField(field_name, _) => {
+                                let rust_field_name = to_rust_variable_name(field_name);
+                                let ext_params_call_args = self.ext_params_to_call_args(
+                                    true,
+                                    to_rust_variable_name,
+                                    &*case.external_params.borrow(),
+                                );
+                                outln!(
+                                    out,
+                                    "if let Some(ref {}) = self.{} {{",
+                                    rust_field_name,
+                                    rust_field_name,
+                                );
+                                outln!(
+                                    out.indent(),
+                                    "{}.serialize_into_with_order(bytes{}, order);",
+                                    rust_field_name,
+                                    ext_params_call_args,
+                                );
+                                outln!(out, "}}");
+                            }
+                        }
+                    }
+                } else {
+                    outln!(out, "match self {{");
+                    out.indented(|out| {
+                        for (case, case_info) in switch.cases.iter().zip(case_infos.iter()) {
+                            match case_info {
+                                CaseInfo::SingleField(index) => {
+                                    let fields = case.fields.borrow();
+                                    let single_field = &fields[*index];
+                                    let single_field_name = single_field.name().unwrap();
+                                    let rust_single_field_name =
+                                        to_rust_variable_name(single_field_name);
+                                    let deducible_fields = gather_deducible_fields(&*fields);
+                                    outln!(
+                                        out,
+                                        "{}::{}({}) => {{",
+                                        name,
+                                        to_rust_type_name(single_field_name),
+                                        rust_single_field_name,
+                                    );
+                                    out.indented(|out| {
+                                        // Gather the total size of the head fixed size fields
+                                        // and reserve that size into the output vector to reduce
+                                        // the number of reallocations.
+                                        let mut fixed_part_size = 0;
+                                        let mut num_fixed_fields = 0;
+                                        for field in fields.iter() {
+                                            if let Some(field_size) = field.size() {
+                                                fixed_part_size += field_size;
+                                                num_fixed_fields += 1;
+                                            } else {
+                                                break;
+                                            }
+                                        }
+                                        if fixed_part_size != 0 && num_fixed_fields != 1 {
+                                            outln!(out, "bytes.reserve({});", fixed_part_size);
+                                        }
+
+                                        for field in fields.iter() {
+                                            self.emit_field_serialize_into(
+                                                field,
+                                                &deducible_fields,
+                                                |field| to_rust_variable_name(field),
+                                                "bytes",
+                                                out,
+                                            );
+                                        }
+                                    });
+                                    outln!(out, "}}");
+                                }
+                                CaseInfo::MultiField(field_name, _) => {
+                                    let rust_field_name = to_rust_variable_name(field_name);
+                                    outln!(
+                                        out,
+                                        "{}::{}({}) => {}.serialize_into_with_order(bytes, order),",
+                                        name,
+                                        to_rust_type_name(field_name),
+                                        rust_field_name,
+                                        rust_field_name,
+                                    );
+                                }
+                            }
+                        }
+                    });
+                    outln!(out, "}}");
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Emit an implementation of `Trace` for the struct `name` with the given fields.
+    ///
+    /// Fields whose name is in `skip` are left out of the trace. With `structure`, the fields are
+    /// enclosed in braces, which is used for types that appear inside of other types.
+    fn emit_trace_impl(
+        &self,
+        name: &str,
+        fields: &[xcbdefs::FieldDef],
+        skip: &[&str],
+        structure: bool,
+        out: &mut Output,
+    ) {
+        let deducible_fields = gather_deducible_fields(fields);
+        let fields = fields
+            .iter()
+            .filter(|field| self.field_is_visible(field, &deducible_fields))
+            .filter(|field| !skip.contains(&field.name().unwrap()))
+            .collect::<Vec<_>>();
+        let tracer_name = if fields.is_empty() && !structure {
+            "_tracer"
+        } else {
+            "tracer"
+        };
+
+        outln!(out, "impl Trace for {} {{", name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn trace(&self, {}: &mut Tracer<'_, '_>) -> core::fmt::Result {{",
+                tracer_name,
+            );
+            out.indented(|out| {
+                if structure {
+                    if fields.is_empty() {
+                        outln!(out, "tracer.structure(|_| Ok(()))");
+                    } else {
+                        outln!(out, "tracer.structure(|tracer| {{");
+                        out.indented(|out| {
+                            for field in fields.iter() {
+                                let value = format!(
+                                    "&self.{}",
+                                    to_rust_variable_name(field.name().unwrap())
+                                );
+                                self.emit_field_trace(field, &value, out);
+                            }
+                            outln!(out, "Ok(())");
+                        });
+                        outln!(out, "}})");
+                    }
+                } else {
+                    for field in fields.iter() {
+                        let value =
+                            format!("&self.{}", to_rust_variable_name(field.name().unwrap()));
+                        self.emit_field_trace(field, &value, out);
+                    }
+                    outln!(out, "Ok(())");
+                }
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Emit an implementation of `Trace` for the type of a `<switch>`.
+    fn emit_switch_trace_impl(
+        &self,
+        switch: &xcbdefs::SwitchField,
+        name: &str,
+        case_infos: &[CaseInfo],
+        out: &mut Output,
+    ) {
+        outln!(out, "impl Trace for {} {{", name);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {{"
+            );
+            out.indented(|out| {
+                if switch.kind == xcbdefs::SwitchKind::BitCase {
+                    outln!(out, "tracer.structure(|tracer| {{");
+                } else {
+                    outln!(out, "tracer.structure(|tracer| match self {{");
+                }
+                out.indented(|out| {
+                    for (case, case_info) in switch.cases.iter().zip(case_infos.iter()) {
+                        let (field_name, expr) = match case_info {
+                            CaseInfo::SingleField(index) => {
+                                let fields = case.fields.borrow();
+                                let single_field = &fields[*index];
+                                (
+                                    single_field.name().unwrap().to_string(),
+                                    self.field_trace_expr(single_field, "value"),
+                                )
+                            }
+                            CaseInfo::MultiField(field_name, _) => {
+                                (field_name.clone(), String::from("tracer.value(value)"))
+                            }
+                        };
+                        let trace_field = format!(
+                            "tracer.field(\"{}\", |tracer| {})",
+                            field_name.replace('_', "-"),
+                            expr,
+                        );
+                        if switch.kind == xcbdefs::SwitchKind::BitCase {
+                            outln!(
+                                out,
+                                "if let Some(ref value) = self.{} {{",
+                                to_rust_variable_name(&field_name),
+                            );
+                            outln!(out.indent(), "{}?;", trace_field);
+                            outln!(out, "}}");
+                        } else {
+                            outln!(
+                                out,
+                                "{}::{}(value) => {},",
+                                name,
+                                to_rust_type_name(&field_name),
+                                trace_field,
+                            );
+                        }
+                    }
+                    if switch.kind == xcbdefs::SwitchKind::BitCase {
+                        outln!(out, "Ok(())");
+                    }
+                });
+                outln!(out, "}})");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Emit an implementation of `Trace` for a request struct.
+    fn emit_request_trace_impl(
+        &self,
+        request_def: &xcbdefs::RequestDef,
+        name: &str,
+        deducible_fields: &HashMap<String, DeducibleField>,
+        gathered: &GatheredRequestFields,
+        out: &mut Output,
+    ) {
+        let ns = request_def.namespace.upgrade().unwrap();
+        let is_send_event = request_def.name == "SendEvent" && ns.header == "xproto";
+        let lifetime = if gathered.needs_lifetime { "<'_>" } else { "" };
+        let tracer_name = if gathered.request_args.is_empty() {
+            "_tracer"
+        } else {
+            "tracer"
+        };
+
+        outln!(out, "impl Trace for {}Request{} {{", name, lifetime);
+        out.indented(|out| {
+            outln!(
+                out,
+                "fn trace(&self, {}: &mut Tracer<'_, '_>) -> core::fmt::Result {{",
+                tracer_name,
+            );
+            out.indented(|out| {
+                for field in request_def.fields.borrow().iter() {
+                    if !self.field_is_visible(field, deducible_fields) {
+                        continue;
+                    }
+                    match field.name() {
+                        Some("major_opcode") | Some("minor_opcode") | Some("length") => continue,
+                        _ => {}
+                    }
+                    if is_send_event && field.name() == Some("event") {
+                        // The event is raw data and not a string
+                        outln!(
+                            out,
+                            "tracer.field(\"event\", |tracer| \
+                             tracer.list(&self.event[..], |tracer, value| tracer.value(value)))?;",
+                        );
+                    } else {
+                        let rust_field_name = to_rust_variable_name(field.name().unwrap());
+                        let value = match field {
+                            // Lists are stored in a Cow, which does not always coerce to a slice
+                            xcbdefs::FieldDef::List(_) => format!("&self.{}[..]", rust_field_name),
+                            _ => format!("&self.{}", rust_field_name),
+                        };
+                        self.emit_field_trace(field, &value, out);
+                    }
+                }
+                outln!(out, "Ok(())");
+            });
+            outln!(out, "}}");
+        });
+        outln!(out, "}}");
+    }
+
+    /// Emit the code that traces a field of `self`, where `value` is a reference to its value.
+    fn emit_field_trace(&self, field: &xcbdefs::FieldDef, value: &str, out: &mut Output) {
+        let field_name = field.name().unwrap();
+        outln!(
+            out,
+            "tracer.field(\"{}\", |tracer| {})?;",
+            field_name.replace('_', "-"),
+            self.field_trace_expr(field, value),
+        );
+    }
+
+    /// Returns an expression that traces `field`, where `value` is a reference to its value.
+    fn field_trace_expr(&self, field: &xcbdefs::FieldDef, value: &str) -> String {
+        match field {
+            xcbdefs::FieldDef::Normal(normal_field) => {
+                self.value_trace_expr(&normal_field.type_, value)
+            }
+            xcbdefs::FieldDef::List(list_field) => {
+                let element_type = list_field.element_type.type_.get_resolved();
+                match element_type.get_original_type() {
+                    xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Char) => {
+                        format!("tracer.string({})", value)
+                    }
+                    _ => format!(
+                        "tracer.list({}, |tracer, value| {})",
+                        value,
+                        self.value_trace_expr(&list_field.element_type, "value"),
+                    ),
+                }
+            }
+            xcbdefs::FieldDef::Switch(_) | xcbdefs::FieldDef::Fd(_) => {
+                format!("tracer.value({})", value)
+            }
+            xcbdefs::FieldDef::FdList(_) => {
+                format!(
+                    "tracer.list({}, |tracer, value| tracer.value(value))",
+                    value
+                )
+            }
+            xcbdefs::FieldDef::Pad(_)
+            | xcbdefs::FieldDef::Expr(_)
+            | xcbdefs::FieldDef::VirtualLen(_) => unreachable!(),
+        }
+    }
+
+    /// Returns an expression that traces a value of type `value_type`, where `value` is a
+    /// reference to the value.
+    ///
+    /// Atoms are traced with their names and other XIDs in hexadecimal. Values that refer to an
+    /// enumeration are traced with the names of the enumeration's values.
+    fn value_trace_expr(&self, value_type: &xcbdefs::FieldValueType, value: &str) -> String {
+        if self.use_enum_type_in_field(value_type).is_some() {
+            // The enumeration type implements Trace
+            return format!("tracer.value({})", value);
+        }
+
+        let original_type = value_type.type_.get_resolved().get_original_type();
+        match original_type {
+            xcbdefs::TypeRef::Xid(ref xid_type_def) => {
+                let xid_type_def = xid_type_def.upgrade().unwrap();
+                let ns = xid_type_def.namespace.upgrade().unwrap();
+                if ns.header == "xproto" && xid_type_def.name == "ATOM" {
+                    return format!("tracer.atom({})", value);
+                } else {
+                    return format!("tracer.xid({})", value);
+                }
+            }
+            xcbdefs::TypeRef::XidUnion(_) => return format!("tracer.xid({})", value),
+            _ => {}
+        }
+
+        let (enum_, is_mask) = match value_type.value_set {
+            xcbdefs::FieldValueSet::None => return format!("tracer.value({})", value),
+            xcbdefs::FieldValueSet::Enum(_) => unreachable!(),
+            xcbdefs::FieldValueSet::AltEnum(ref enum_) => (enum_, false),
+            xcbdefs::FieldValueSet::Mask(ref enum_)
+            | xcbdefs::FieldValueSet::AltMask(ref enum_) => (enum_, true),
+        };
+        match original_type {
+            xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card8)
+            | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card16)
+            | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Card32)
+            | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Byte)
+            | xcbdefs::TypeRef::BuiltIn(xcbdefs::BuiltInType::Bool) => {}
+            // The names of the values cannot be looked up for other types
+            _ => return format!("tracer.value({})", value),
+        }
+        let enum_def = match enum_.get_resolved() {
+            xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
+            _ => unreachable!(),
+        };
+        let method = if is_mask || enum_is_bitmask(&enum_def) {
+            "bitmask"
+        } else {
+            "enum_value"
+        };
+        let ns = enum_def.namespace.upgrade().unwrap();
+        format!(
+            "tracer.{}({}, {}::VARIANTS)",
+            method,
+            value,
+            self.type_name_to_rust_type(&self.get_enum_rust_name(&enum_def), &ns),
+        )
+    }
+
+    /// Emit the tables that describe the requests and events of this module.
+    ///
+    /// The order of the tables is relied upon by `Request::info` and `Event::info`.
+    fn emit_introspection_tables(&self, out: &mut Output) {
+        let extension = match self.ns.ext_info {
+            Some(ref ext_info) => format!("Some(\"{}\")", ext_info.name),
+            None => String::from("None"),
+        };
+
+        let request_defs = super::names::sorted_requests(self.ns);
+        outln!(
+            out,
+            "/// Descriptions of the requests in this module, sorted by opcode."
+        );
+        if request_defs.is_empty() {
+            outln!(out, "pub static REQUEST_INFOS: &[RequestInfo] = &[];");
+        } else {
+            outln!(out, "pub static REQUEST_INFOS: &[RequestInfo] = &[");
+            out.indented(|out| {
+                for request_def in request_defs.iter() {
+                    outln!(out, "RequestInfo {{");
+                    out.indented(|out| {
+                        outln!(out, "extension: {},", extension);
+                        outln!(out, "name: \"{}\",", request_def.name);
+                        outln!(out, "opcode: {},", request_def.opcode);
+                        self.emit_field_infos("fields: ", &request_def.fields.borrow(), ",", out);
+                        match request_def.reply {
+                            Some(ref reply_def) => {
+                                self.emit_field_infos(
+                                    "reply_fields: Some(",
+                                    &reply_def.fields.borrow(),
+                                    "),",
                                     out,
-                                    "if let Some(ref {}) = self.{} {{",
-                                    rust_field_name,
-                                    rust_field_name,
-                                );
-                                outln!(
-                                    out.indent(),
-                                    "{}.serialize_into(bytes{});",
-                                    rust_field_name,
-                                    ext_params_call_args,
                                 );
-                                outln!(out, "}}");
                             }
+                            None => outln!(out, "reply_fields: None,"),
                         }
-                    }
-                } else {
-                    outln!(out, "match self {{");
+                    });
+                    outln!(out, "}},");
+                }
+            });
+            outln!(out, "];");
+        }
+        outln!(out, "");
+
+        let event_defs = super::error_events::sorted_events(self.ns);
+        outln!(
+            out,
+            "/// Descriptions of the events in this module, sorted by name."
+        );
+        if event_defs.is_empty() {
+            outln!(out, "pub static EVENT_INFOS: &[EventInfo] = &[];");
+        } else {
+            outln!(out, "pub static EVENT_INFOS: &[EventInfo] = &[");
+            out.indented(|out| {
+                for event_def in event_defs.iter() {
+                    let number = match *event_def {
+                        xcbdefs::EventDef::Full(ref event_full_def) => event_full_def.number,
+                        xcbdefs::EventDef::Copy(ref event_copy_def) => event_copy_def.number,
+                    };
+                    let full_def = event_def.get_original_full_def();
+                    outln!(out, "EventInfo {{");
                     out.indented(|out| {
-                        for (case, case_info) in switch.cases.iter().zip(case_infos.iter()) {
-                            match case_info {
-                                CaseInfo::SingleField(index) => {
-                                    let fields = case.fields.borrow();
-                                    let single_field = &fields[*index];
-                                    let single_field_name = single_field.name().unwrap();
-                                    let rust_single_field_name =
-                                        to_rust_variable_name(single_field_name);
-                                    let deducible_fields = gather_deducible_fields(&*fields);
-                                    outln!(
-                                        out,
-                                        "{}::{}({}) => {{",
-                                        name,
-                                        to_rust_type_name(single_field_name),
-                                        rust_single_field_name,
-                                    );
+                        outln!(out, "extension: {},", extension);
+                        outln!(out, "name: \"{}\",", event_def.name());
+                        outln!(out, "number: {},", number);
+                        outln!(out, "xge: {},", full_def.xge);
+                        self.emit_field_infos("fields: ", &full_def.fields.borrow(), ",", out);
+                    });
+                    outln!(out, "}},");
+                }
+            });
+            outln!(out, "];");
+        }
+        outln!(out, "");
+    }
+
+    /// Emit a `&[FieldInfo]` that describes `fields`, surrounded by `prefix` and `suffix`.
+    ///
+    /// Padding and virtual fields are left out.
+    fn emit_field_infos(
+        &self,
+        prefix: &str,
+        fields: &[xcbdefs::FieldDef],
+        suffix: &str,
+        out: &mut Output,
+    ) {
+        let fields = fields
+            .iter()
+            .filter(|field| match field {
+                xcbdefs::FieldDef::Pad(_) | xcbdefs::FieldDef::VirtualLen(_) => false,
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            outln!(out, "{}&[]{}", prefix, suffix);
+            return;
+        }
+        outln!(out, "{}&[", prefix);
+        out.indented(|out| {
+            for field in fields {
+                outln!(out, "FieldInfo {{");
+                out.indented(|out| {
+                    outln!(out, "name: \"{}\",", field.name().unwrap());
+                    match field {
+                        xcbdefs::FieldDef::Normal(normal_field) => outln!(
+                            out,
+                            "kind: FieldKind::Value({}),",
+                            self.type_info_expr(&normal_field.type_),
+                        ),
+                        xcbdefs::FieldDef::Expr(expr_field) => outln!(
+                            out,
+                            "kind: FieldKind::Value({}),",
+                            self.type_info_expr(&expr_field.type_),
+                        ),
+                        xcbdefs::FieldDef::List(list_field) => outln!(
+                            out,
+                            "kind: FieldKind::List({}),",
+                            self.type_info_expr(&list_field.element_type),
+                        ),
+                        xcbdefs::FieldDef::Fd(_) => outln!(out, "kind: FieldKind::Fd,"),
+                        xcbdefs::FieldDef::FdList(_) => outln!(out, "kind: FieldKind::FdList,"),
+                        xcbdefs::FieldDef::Switch(switch_field) => {
+                            outln!(out, "kind: FieldKind::Switch(&[");
+                            out.indented(|out| {
+                                for case in switch_field.cases.iter() {
+                                    outln!(out, "CaseInfo {{");
                                     out.indented(|out| {
-                                        // Gather the total size of the head fixed size fields
-                                        // and reserve that size into the output vector to reduce
-                                        // the number of reallocations.
-                                        let mut fixed_part_size = 0;
-                                        let mut num_fixed_fields = 0;
-                                        for field in fields.iter() {
-                                            if let Some(field_size) = field.size() {
-                                                fixed_part_size += field_size;
-                                                num_fixed_fields += 1;
-                                            } else {
-                                                break;
+                                        match case.name {
+                                            Some(ref name) => {
+                                                outln!(out, "name: Some(\"{}\"),", name)
                                             }
+                                            None => outln!(out, "name: None,"),
                                         }
-                                        if fixed_part_size != 0 && num_fixed_fields != 1 {
-                                            outln!(out, "bytes.reserve({});", fixed_part_size);
-                                        }
-
-                                        for field in fields.iter() {
-                                            self.emit_field_serialize_into(
-                                                field,
-                                                &deducible_fields,
-                                                |field| to_rust_variable_name(field),
-                                                "bytes",
-                                                out,
-                                            );
-                                        }
+                                        self.emit_field_infos(
+                                            "fields: ",
+                                            &case.fields.borrow(),
+                                            ",",
+                                            out,
+                                        );
                                     });
-                                    outln!(out, "}}");
-                                }
-                                CaseInfo::MultiField(field_name, _) => {
-                                    let rust_field_name = to_rust_variable_name(field_name);
-                                    outln!(
-                                        out,
-                                        "{}::{}({}) => {}.serialize_into(bytes),",
-                                        name,
-                                        to_rust_type_name(field_name),
-                                        rust_field_name,
-                                        rust_field_name,
-                                    );
+                                    outln!(out, "}},");
                                 }
-                            }
+                            });
+                            outln!(out, "]),");
                         }
-                    });
-                    outln!(out, "}}");
-                }
-            });
-            outln!(out, "}}");
+                        xcbdefs::FieldDef::Pad(_) | xcbdefs::FieldDef::VirtualLen(_) => {
+                            unreachable!()
+                        }
+                    }
+                });
+                outln!(out, "}},");
+            }
         });
-        outln!(out, "}}");
+        outln!(out, "]{}", suffix);
+    }
+
+    /// Returns an expression for a `TypeInfo` that describes `value_type`.
+    fn type_info_expr(&self, value_type: &xcbdefs::FieldValueType) -> String {
+        let value_set = match value_type.value_set {
+            xcbdefs::FieldValueSet::None => String::from("ValueSet::None"),
+            xcbdefs::FieldValueSet::Enum(ref enum_) => {
+                format!("ValueSet::Enum({})", self.enum_info_expr(enum_))
+            }
+            xcbdefs::FieldValueSet::AltEnum(ref enum_) => {
+                format!("ValueSet::AltEnum({})", self.enum_info_expr(enum_))
+            }
+            xcbdefs::FieldValueSet::Mask(ref enum_) => {
+                format!("ValueSet::Mask({})", self.enum_info_expr(enum_))
+            }
+            xcbdefs::FieldValueSet::AltMask(ref enum_) => {
+                format!("ValueSet::AltMask({})", self.enum_info_expr(enum_))
+            }
+        };
+        format!(
+            "TypeInfo {{ name: \"{}\", value_set: {} }}",
+            value_type.type_.name(),
+            value_set,
+        )
+    }
+
+    /// Returns an expression for an `EnumInfo` that describes the referenced enumeration.
+    fn enum_info_expr(&self, enum_: &xcbdefs::NamedTypeRef) -> String {
+        let enum_def = match enum_.get_resolved() {
+            xcbdefs::TypeRef::Enum(enum_def) => enum_def.upgrade().unwrap(),
+            _ => unreachable!(),
+        };
+        let ns = enum_def.namespace.upgrade().unwrap();
+        format!(
+            "EnumInfo {{ name: \"{}\", variants: {}::VARIANTS }}",
+            enum_def.name,
+            self.type_name_to_rust_type(&self.get_enum_rust_name(&enum_def), &ns),
+        )
     }
 
     fn emit_let_value_for_dynamic_align(fields: &[xcbdefs::FieldDef], out: &mut Output) {
@@ -4082,8 +5694,8 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         self.type_to_rust_type(list_field.element_type.type_.get_resolved());
                     outln!(
                         out,
-                        "let ({}, remaining) = crate::x11_utils::parse_list::<{}>(remaining, \
-                         {}.try_into().or(Err(ParseError::ConversionFailed))?)?;",
+                        "let ({}, remaining) = crate::x11_utils::parse_list_with_order::<{}>(\
+                         remaining, {}.try_into().or(Err(ParseError::ConversionFailed))?, order)?;",
                         rust_field_name,
                         rust_element_type,
                         self.expr_to_str(
@@ -4162,9 +5774,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 for ext_param in switch_field.external_params.borrow().iter() {
                     parse_params.push(to_rust_variable_name(&ext_param.name));
                 }
+                parse_params.push(String::from("order"));
                 outln!(
                     out,
-                    "let ({}, remaining) = {}::try_parse({})?;",
+                    "let ({}, remaining) = {}::try_parse_with_order({})?;",
                     rust_field_name,
                     switch_struct_name,
                     parse_params.join(", "),
@@ -4197,7 +5810,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     "if fds.len() < fds_len {{ return Err(ParseError::MissingFileDescriptors) }}",
                 );
                 outln!(out, "let mut {} = fds.split_off(fds_len);", rust_field_name);
-                outln!(out, "std::mem::swap(fds, &mut {});", rust_field_name);
+                outln!(out, "core::mem::swap(fds, &mut {});", rust_field_name);
             }
             xcbdefs::FieldDef::Expr(expr_ref) => {
                 match expr_ref.expr {
@@ -4254,7 +5867,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         let type_type = type_.type_.get_resolved();
         let rust_type = self.type_to_rust_type(type_type);
         let params = self.get_type_parse_params(type_type, from);
-        format!("{}::try_parse({})?", rust_type, params.join(", "))
+        format!(
+            "{}::try_parse_with_order({}, order)?",
+            rust_type,
+            params.join(", ")
+        )
     }
 
     fn emit_value_post_parse(
@@ -4277,6 +5894,27 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         }
     }
 
+    /// Returns whether parsing or serializing `fields` might depend on the byte order.
+    fn fields_depend_on_byte_order<'a>(
+        &self,
+        fields: impl IntoIterator<Item = &'a xcbdefs::FieldDef>,
+    ) -> bool {
+        fields.into_iter().any(|field| match field {
+            xcbdefs::FieldDef::Normal(_) | xcbdefs::FieldDef::Switch(_) => true,
+            xcbdefs::FieldDef::List(_) => !self.field_is_u8_list(field),
+            _ => false,
+        })
+    }
+
+    /// Returns whether `field` is a list of `u8`, which does not depend on the byte order.
+    fn field_is_u8_list(&self, field: &xcbdefs::FieldDef) -> bool {
+        if let xcbdefs::FieldDef::List(list_field) = field {
+            self.rust_value_type_is_u8(&list_field.element_type)
+        } else {
+            false
+        }
+    }
+
     fn can_use_simple_list_parsing(&self, type_: &xcbdefs::FieldValueType) -> bool {
         self.get_type_parse_params(&type_.type_.get_resolved(), "")
             .len()
@@ -4378,7 +6016,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 let bytes_name = postfix_var_name(&rust_field_name, "bytes");
                 outln!(
                     out,
-                    "let {} = {}.serialize();",
+                    "let {} = {}.serialize_with_order(order);",
                     bytes_name,
                     wrap_field_ref(&switch_field.name),
                 );
@@ -4471,7 +6109,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                 } else if self.can_use_simple_list_parsing(&list_field.element_type) {
                     outln!(
                         out,
-                        "{}.serialize_into({});",
+                        "{}.serialize_into_with_order({}, order);",
                         wrap_field_ref(&list_field.name),
                         bytes_name
                     );
@@ -4481,14 +6119,40 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                         "for element in {}.iter() {{",
                         wrap_field_ref(&list_field.name)
                     );
+                    let ext_params = self
+                        .get_type_serialize_params(list_field.element_type.type_.get_resolved());
                     out.indented(|out| {
-                        self.emit_value_serialize_into(
-                            &list_field.element_type,
-                            "element",
-                            false,
-                            "bytes",
-                            out,
-                        );
+                        if !ext_params.is_empty() {
+                            let args = ext_params
+                                .iter()
+                                .map(|param| wrap_field_ref(param))
+                                .collect::<Vec<_>>();
+                            outln!(
+                                out,
+                                "element.serialize_into_with_order({}, {}, order);",
+                                bytes_name,
+                                args.join(", "),
+                            );
+                        } else if self
+                            .use_enum_type_in_field(&list_field.element_type)
+                            .is_some()
+                        {
+                            self.emit_value_serialize_into(
+                                &list_field.element_type,
+                                "*element",
+                                false,
+                                "bytes",
+                                out,
+                            );
+                        } else {
+                            self.emit_value_serialize_into(
+                                &list_field.element_type,
+                                "element",
+                                false,
+                                "bytes",
+                                out,
+                            );
+                        }
                     });
                     outln!(out, "}}");
                 }
@@ -4496,19 +6160,32 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             xcbdefs::FieldDef::Switch(switch_field) => {
                 let ext_params_args = self.ext_params_to_call_args(
                     true,
-                    to_rust_variable_name,
+                    &mut wrap_field_ref,
                     &*switch_field.external_params.borrow(),
                 );
                 outln!(
                     out,
-                    "{}.serialize_into({}{});",
+                    "{}.serialize_into_with_order({}{}, order);",
                     wrap_field_ref(&switch_field.name),
                     bytes_name,
                     ext_params_args,
                 );
             }
-            // the remaining field types are only used in request and replies,
-            // which do not implement serialize
+            xcbdefs::FieldDef::Expr(xcbdefs::ExprField {
+                type_,
+                expr: xcbdefs::Expression::Value(v),
+                ..
+            }) => {
+                // This is the response type of a reply
+                let field_size = type_.type_.get_resolved().size().unwrap();
+                assert!(field_size == 1 && u8::try_from(*v).is_ok());
+                outln!(out, "{}.push({});", bytes_name, v);
+            }
+            // FDs are not part of the serialized bytes
+            xcbdefs::FieldDef::Fd(_) | xcbdefs::FieldDef::FdList(_) => {}
+            xcbdefs::FieldDef::VirtualLen(_) => {}
+            // the remaining field types are only used in requests, which do not implement
+            // serialize
             _ => unreachable!(),
         }
     }
@@ -4599,6 +6276,11 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         let rust_field_name = to_rust_variable_name(&switch.name);
         let switch_expr_str =
             self.expr_to_str(&switch.expr, to_rust_variable_name, true, true, false);
+        // Without `switch_expr()`, the discriminant cannot be checked
+        if switch.cases.iter().any(|case| case.exprs.len() != 1) {
+            outln!(out, "let _ = {};", switch_expr_str);
+            return;
+        }
         outln!(
             out,
             "assert_eq!(self.switch_expr(), {}, \"switch `{}` has an inconsistent \
@@ -4623,14 +6305,17 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
 
             if max_wire_size > 1 && u32::from(max_wire_size / 8) > current_wire_size {
                 format!(
-                    "(u{}::from({}) as {}).serialize()",
+                    "(u{}::from({}) as {}).serialize_with_order(order)",
                     max_wire_size, value, rust_wire_type,
                 )
             } else {
-                format!("{}::from({}).serialize()", rust_wire_type, value)
+                format!(
+                    "{}::from({}).serialize_with_order(order)",
+                    rust_wire_type, value
+                )
             }
         } else {
-            format!("{}.serialize()", value)
+            format!("{}.serialize_with_order(order)", value)
         }
     }
 
@@ -4652,7 +6337,7 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             if max_wire_size > 1 && u32::from(max_wire_size / 8) > current_wire_size {
                 outln!(
                     out,
-                    "(u{}::from({}) as {}).serialize_into({});",
+                    "(u{}::from({}) as {}).serialize_into_with_order({}, order);",
                     max_wire_size,
                     value,
                     rust_wire_type,
@@ -4661,14 +6346,19 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
             } else {
                 outln!(
                     out,
-                    "{}::from({}).serialize_into({});",
+                    "{}::from({}).serialize_into_with_order({}, order);",
                     rust_wire_type,
                     value,
                     bytes_var,
                 );
             }
         } else {
-            outln!(out, "{}.serialize_into({});", value, bytes_var);
+            outln!(
+                out,
+                "{}.serialize_into_with_order({}, order);",
+                value,
+                bytes_var
+            );
         }
     }
 
@@ -5628,6 +7318,29 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
         params
     }
 
+    /// Returns the names of the external parameters needed by the `serialize` function for
+    /// `type_`.
+    fn get_type_serialize_params(&self, type_: &xcbdefs::TypeRef) -> Vec<String> {
+        let original_type = if let xcbdefs::TypeRef::Alias(type_alias_def) = type_ {
+            type_alias_def.upgrade().unwrap().get_original_type()
+        } else {
+            type_.clone()
+        };
+
+        if let xcbdefs::TypeRef::Struct(struct_def) = original_type {
+            let struct_def = struct_def.upgrade().unwrap();
+            let params = struct_def
+                .external_params
+                .borrow()
+                .iter()
+                .map(|ext_param| ext_param.name.clone())
+                .collect();
+            params
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Gets the traits that can be implemented for `struct_def`.
     fn get_derives_for_struct_def(&self, struct_def: &xcbdefs::StructDef) -> Derives {
         let id = struct_def as *const xcbdefs::StructDef as usize;
@@ -5687,9 +7400,10 @@ impl<'ns, 'c> NamespaceGenerator<'ns, 'c> {
                     }
                 }
                 xcbdefs::FieldDef::Fd(_) | xcbdefs::FieldDef::FdList(_) => {
-                    // RawFdContainer cannot be cloned
+                    // RawFdContainer cannot be cloned or serialized
                     derives.clone = false;
                     derives.copy = false;
+                    derives.serde = false;
                 }
                 xcbdefs::FieldDef::Expr(_) => {}
                 xcbdefs::FieldDef::VirtualLen(_) => {}
@@ -5779,6 +7493,7 @@ struct Derives {
     copy: bool,
     partial_eq: bool,
     eq: bool,
+    serde: bool,
 }
 
 impl Derives {
@@ -5790,6 +7505,7 @@ impl Derives {
             copy: true,
             partial_eq: true,
             eq: true,
+            serde: true,
         }
     }
 
@@ -5799,6 +7515,7 @@ impl Derives {
         self.copy &= other.copy;
         self.partial_eq &= other.partial_eq;
         self.eq &= other.eq;
+        self.serde &= other.serde;
     }
 
     fn to_list(self) -> Vec<&'static str> {
@@ -5820,6 +7537,80 @@ impl Derives {
         }
         list
     }
+
+    /// Emits the optional derives of `serde::Serialize` and `serde::Deserialize`.
+    fn emit_serde(self, out: &mut Output) {
+        if self.serde {
+            emit_serde_derive(out);
+        }
+    }
+}
+
+fn emit_serde_derive(out: &mut Output) {
+    outln!(
+        out,
+        "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]"
+    );
+}
+
+/// Emits `Serialize::serialize` and `Serialize::serialize_into` that forward to the `*_with_order`
+/// variants with the native byte order.
+/// Emit an implementation of `Trace` for a type that wraps an array of raw bytes.
+fn emit_raw_bytes_trace_impl(name: &str, out: &mut Output) {
+    outln!(out, "impl Trace for {} {{", name);
+    out.indented(|out| {
+        outln!(
+            out,
+            "fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {{"
+        );
+        outln!(
+            out.indent(),
+            "tracer.list(&self.0, |tracer, value| tracer.value(value))"
+        );
+        outln!(out, "}}");
+    });
+    outln!(out, "}}");
+}
+
+fn emit_native_serialize(bytes_type: &str, out: &mut Output) {
+    outln!(out, "fn serialize(&self) -> {} {{", bytes_type);
+    outln!(
+        out.indent(),
+        "self.serialize_with_order(ByteOrder::native())"
+    );
+    outln!(out, "}}");
+    outln!(out, "fn serialize_into(&self, bytes: &mut Vec<u8>) {{");
+    outln!(
+        out.indent(),
+        "self.serialize_into_with_order(bytes, ByteOrder::native())"
+    );
+    outln!(out, "}}");
+}
+
+/// Emits a `TryParse::try_parse_with_order` for types that keep their raw bytes as they appear on
+/// the wire and thus do not depend on the byte order.
+fn emit_raw_try_parse_with_order(out: &mut Output) {
+    outln!(
+        out,
+        "fn try_parse_with_order(value: &[u8], _: ByteOrder) -> \
+         Result<(Self, &[u8]), ParseError> {{"
+    );
+    outln!(out.indent(), "Self::try_parse(value)");
+    outln!(out, "}}");
+}
+
+/// Emits a `TryParse::try_parse` that forwards to `try_parse_with_order` with the native byte
+/// order.
+fn emit_native_try_parse(out: &mut Output) {
+    outln!(
+        out,
+        "fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {{"
+    );
+    outln!(
+        out.indent(),
+        "Self::try_parse_with_order(value, ByteOrder::native())"
+    );
+    outln!(out, "}}");
 }
 
 /// Constraints on the wire format of a struct.
@@ -6149,6 +7940,27 @@ impl GatheredRequestFields {
 /// Formats an integer such as clippy does not complain.
 ///
 /// `1234567` produces `"1_234_567"`
+/// Returns whether the values of `enum_def` are bits of a bitmask.
+///
+/// An enum is ok for bitmask if all its values are <bit> or have value zero (but not if all
+/// values are zero).
+fn enum_is_bitmask(enum_def: &xcbdefs::EnumDef) -> bool {
+    enum_def
+        .items
+        .iter()
+        .all(|enum_item| match enum_item.value {
+            xcbdefs::EnumValue::Value(0) | xcbdefs::EnumValue::Bit(_) => true,
+            _ => false,
+        })
+        && enum_def
+            .items
+            .iter()
+            .any(|enum_item| match enum_item.value {
+                xcbdefs::EnumValue::Value(_) => false,
+                xcbdefs::EnumValue::Bit(_) => true,
+            })
+}
+
 fn format_literal_integer(value: u32) -> String {
     let value = value.to_string();
     if value.len() > 5 {
@@ -6191,7 +8003,7 @@ fn needs_match_by_value(field: &xcbdefs::FieldDef) -> bool {
 ///
 /// If the name is all uppercase, all but the first
 /// letter are converter to lowercase.
-fn to_rust_type_name(name: &str) -> String {
+pub(super) fn to_rust_type_name(name: &str) -> String {
     let mut name = String::from(name);
     if name.bytes().all(|c| !c.is_ascii_lowercase()) {
         name.make_ascii_lowercase();
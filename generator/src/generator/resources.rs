@@ -0,0 +1,161 @@
+//! Finding the XID types that describe server-side resources.
+//!
+//! A resource is an XID type for which the namespace contains requests that create an instance
+//! of the type and a request that frees it again. The XML does not mark these requests, so they
+//! are recognized by their names and fields: A request like `CreatePixmap` or `OpenFont` creates
+//! the resource if its name mentions the resource and it has a field of the XID type. A request
+//! like `FreePixmap` or `CloseFont` frees the resource if this field is its only field.
+
+use std::rc::Rc;
+
+use xcbgen::defs as xcbdefs;
+
+/// Prefixes of the names of requests that create a resource.
+const CREATE_PREFIXES: &[&str] = &["Create", "Open", "Allocate", "Attach"];
+
+/// Prefixes of the names of requests that free a resource.
+const FREE_PREFIXES: &[&str] = &["Free", "Destroy", "Delete", "Close", "Deallocate", "Detach"];
+
+/// A resource together with the requests that create and free it.
+pub(super) struct ResourceInfo {
+    /// The XID type of the resource.
+    pub(super) xid_type: Rc<xcbdefs::XidTypeDef>,
+
+    /// The requests that create the resource and the names of their fields that contain the new
+    /// XID.
+    pub(super) create_requests: Vec<(Rc<xcbdefs::RequestDef>, String)>,
+
+    /// The request that frees the resource.
+    pub(super) free_request: Rc<xcbdefs::RequestDef>,
+}
+
+/// Find all resources of a namespace.
+pub(super) fn find_resources(ns: &xcbdefs::Namespace) -> Vec<ResourceInfo> {
+    let defs = ns.src_order_defs.borrow();
+    let requests = defs
+        .iter()
+        .filter_map(|def| match def {
+            xcbdefs::Def::Request(request_def) if request_def.reply.is_none() => {
+                Some(request_def.clone())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    defs.iter()
+        .filter_map(|def| match def {
+            xcbdefs::Def::Type(xcbdefs::TypeDef::Xid(xid_type_def)) => {
+                find_resource(xid_type_def, &requests)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn find_resource(
+    xid_type: &Rc<xcbdefs::XidTypeDef>,
+    requests: &[Rc<xcbdefs::RequestDef>],
+) -> Option<ResourceInfo> {
+    let rust_name = super::namespace::to_rust_type_name(&xid_type.name);
+    let create_requests = requests
+        .iter()
+        .filter(|request| mentions_resource(&request.name, &rust_name))
+        .filter_map(|request| {
+            let field = request
+                .fields
+                .borrow()
+                .iter()
+                .filter_map(|field| xid_field_name(field, xid_type))
+                .next()?;
+            Some((request.clone(), field))
+        })
+        .collect::<Vec<_>>();
+    if create_requests.is_empty() {
+        return None;
+    }
+
+    let free_requests = requests
+        .iter()
+        .filter(|request| has_prefix(&request.name, FREE_PREFIXES))
+        .filter(|request| {
+            let fields = request.fields.borrow();
+            let mut fields = fields
+                .iter()
+                .filter(|field| is_request_argument(field))
+                .collect::<Vec<_>>();
+            fields.len() == 1 && xid_field_name(fields.pop().unwrap(), xid_type).is_some()
+        })
+        .collect::<Vec<_>>();
+    // Some namespaces have more than one such request, e.g. xproto's `DestroyWindow` and
+    // `DestroySubwindows` or glx's `DestroyPixmap` and `DestroyGLXPixmap`. Prefer the one that is
+    // named exactly after the resource in this case.
+    let free_request = match free_requests[..] {
+        [free_request] => free_request.clone(),
+        _ => {
+            let is_named_after_resource = |request: &&&Rc<xcbdefs::RequestDef>| {
+                FREE_PREFIXES
+                    .iter()
+                    .any(|prefix| request.name == format!("{}{}", prefix, rust_name))
+            };
+            let mut named = free_requests.iter().filter(is_named_after_resource);
+            match (named.next(), named.next()) {
+                (Some(free_request), None) => (*free_request).clone(),
+                _ => return None,
+            }
+        }
+    };
+
+    Some(ResourceInfo {
+        xid_type: xid_type.clone(),
+        create_requests,
+        free_request,
+    })
+}
+
+fn has_prefix(name: &str, prefixes: &[&str]) -> bool {
+    prefixes.iter().any(|prefix| name.starts_with(prefix))
+}
+
+/// Check if `request_name` is the name of a request that creates the given resource.
+///
+/// Besides names like `CreateRegionFromBitmap`, this also accepts abbreviations like `CreateGC`
+/// for a `Gcontext` and names like `Create` in extensions that only have a single resource.
+fn mentions_resource(request_name: &str, resource_name: &str) -> bool {
+    let rest = match CREATE_PREFIXES
+        .iter()
+        .find(|prefix| request_name.starts_with(*prefix))
+    {
+        Some(prefix) => request_name[prefix.len()..].to_ascii_lowercase(),
+        None => return false,
+    };
+    let resource_name = resource_name.to_ascii_lowercase();
+    rest.contains(&resource_name) || resource_name.starts_with(&rest)
+}
+
+/// Check if `field` is neither padding nor part of the request header.
+fn is_request_argument(field: &xcbdefs::FieldDef) -> bool {
+    match field {
+        xcbdefs::FieldDef::Pad(_) => false,
+        _ => match field.name() {
+            Some("major_opcode") | Some("minor_opcode") | Some("length") => false,
+            _ => true,
+        },
+    }
+}
+
+/// Get the name of `field` if it is a single XID of the given type.
+fn xid_field_name(field: &xcbdefs::FieldDef, xid_type: &Rc<xcbdefs::XidTypeDef>) -> Option<String> {
+    match field {
+        xcbdefs::FieldDef::Normal(normal_field) => match normal_field.type_.type_.get_resolved() {
+            xcbdefs::TypeRef::Xid(field_type) => {
+                if Rc::ptr_eq(&field_type.upgrade().unwrap(), xid_type) {
+                    Some(normal_field.name.clone())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -146,14 +146,25 @@ fn replace_file_if_different(file_path: &Path, data: &[u8]) -> Result<(), Error>
 }
 
 fn main2() -> Result<u8, Error> {
-    let args: Vec<_> = std::env::args_os().collect();
-    if args.len() != 3 {
+    let mut args: Vec<_> = std::env::args_os().collect();
+    let xid_newtypes = args.len() > 1 && args[1] == "--xid-newtypes";
+    if xid_newtypes {
+        let _ = args.remove(1);
+    }
+    if args.len() != 4 {
         eprintln!("USAGE:");
-        eprintln!("    {} <INPUT_DIR> <OUTPUT_DIR>", args[0].to_string_lossy());
+        eprintln!(
+            "    {} [--xid-newtypes] <INPUT_DIR> <PROTO_OUTPUT_DIR> <X11RB_OUTPUT_DIR>",
+            args[0].to_string_lossy()
+        );
+        eprintln!();
+        eprintln!("OPTIONS:");
+        eprintln!("    --xid-newtypes    Generate distinct types for XIDs instead of u32 aliases");
         return Ok(1);
     }
     let input_dir_path = Path::new(&args[1]);
-    let output_dir_path = Path::new(&args[2]);
+    let proto_output_dir_path = Path::new(&args[2]);
+    let x11rb_output_dir_path = Path::new(&args[3]);
 
     let xml_files = list_xmls(input_dir_path)?;
     let module = xcbgen::defs::Module::new();
@@ -169,11 +180,17 @@ fn main2() -> Result<u8, Error> {
     xcbgen::resolve(&module).map_err(|e| Error::XcbResolveFailed { error: e })?;
     println!("Resolved successfully");
 
-    let generated = generator::generate(&module);
-    for (file_name, file_data) in generated.iter() {
-        let mut file_path = PathBuf::from(output_dir_path);
-        file_path.push(file_name);
-        replace_file_if_different(&file_path, file_data.as_bytes())?;
+    let generated = generator::generate(&module, xid_newtypes);
+    let outputs = [
+        (proto_output_dir_path, &generated.proto_files),
+        (x11rb_output_dir_path, &generated.x11rb_files),
+    ];
+    for (output_dir_path, files) in outputs.iter() {
+        for (file_name, file_data) in files.iter() {
+            let mut file_path = PathBuf::from(output_dir_path);
+            file_path.push(file_name);
+            replace_file_if_different(&file_path, file_data.as_bytes())?;
+        }
     }
     println!("Code generated successfully");
 
@@ -40,9 +40,9 @@
 //! | Get    | `Cookie::reply`                    | `Cookie::reply_unchecked` |
 //! | Ignore | `Cookie::discard_reply_and_errors` | Just drop the cookie      |
 
-use std::borrow::Cow;
 use std::convert::{TryFrom, TryInto};
 use std::io::IoSlice;
+use std::time::Duration;
 
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError, ReplyOrIdError};
@@ -60,15 +60,13 @@ use crate::x11_utils::{ExtensionInformation, X11Error};
 /// caused an error.
 pub type SequenceNumber = u64;
 
+pub use x11rb_protocol::{BufWithFds, PiecewiseBuf};
+
 // Used to avoid too-complex types.
-/// A combination of a buffer and a list of file descriptors.
-pub type BufWithFds<B> = (B, Vec<RawFdContainer>);
 /// An event and its sequence number.
 pub type EventAndSeqNumber = (Event, SequenceNumber);
 /// The raw bytes of an event and its sequence number.
 pub type RawEventAndSeqNumber<B> = (B, SequenceNumber);
-/// A buffer that is logically continuous, but presented in a number of pieces.
-pub type PiecewiseBuf<'a> = Vec<Cow<'a, [u8]>>;
 
 /// Either a raw reply or a raw error response to an X11 request.
 #[derive(Debug)]
@@ -295,6 +293,135 @@ pub trait RequestConnection {
         sequence: SequenceNumber,
     ) -> Result<Option<Self::Buf>, ConnectionError>;
 
+    /// Wait for the reply to a request, but at most for the given amount of time.
+    ///
+    /// This is like `wait_for_reply_or_error()`, but returns `ConnectionError::Timeout` if the
+    /// reply or error was not received in time. A reply that is received later can still be
+    /// waited for.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_or_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Self::Buf, ReplyError> {
+        match self.wait_for_reply_or_raw_error_timeout(sequence, timeout)? {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => {
+                Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
+            }
+        }
+    }
+
+    /// Wait for the reply to a request, but at most for the given amount of time.
+    ///
+    /// This is like `wait_for_reply_or_raw_error()`, but returns `ConnectionError::Timeout` if
+    /// the reply or error was not received in time. A reply that is received later can still be
+    /// waited for.
+    ///
+    /// The default implementation ignores the timeout and calls `wait_for_reply_or_raw_error()`.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Self::Buf>, ConnectionError> {
+        let _ = timeout;
+        self.wait_for_reply_or_raw_error(sequence)
+    }
+
+    /// Wait for the reply to a request, but at most for the given amount of time.
+    ///
+    /// This is like `wait_for_reply()`, but returns `ConnectionError::Timeout` if the reply or
+    /// error was not received in time. A reply that is received later can still be waited for.
+    ///
+    /// The default implementation ignores the timeout and calls `wait_for_reply()`.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        let _ = timeout;
+        self.wait_for_reply(sequence)
+    }
+
+    /// Wait for the reply to a request that has FDs, but at most for the given amount of time.
+    ///
+    /// This is like `wait_for_reply_with_fds()`, but returns `ConnectionError::Timeout` if the
+    /// reply or error was not received in time. A reply that is received later can still be
+    /// waited for.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_with_fds_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<BufWithFds<Self::Buf>, ReplyError> {
+        match self.wait_for_reply_with_fds_raw_timeout(sequence, timeout)? {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => {
+                Err(ReplyError::X11Error(self.parse_error(error.as_ref())?))
+            }
+        }
+    }
+
+    /// Wait for the reply to a request that has FDs, but at most for the given amount of time.
+    ///
+    /// This is like `wait_for_reply_with_fds_raw()`, but returns `ConnectionError::Timeout` if
+    /// the reply or error was not received in time. A reply that is received later can still be
+    /// waited for.
+    ///
+    /// The default implementation ignores the timeout and calls `wait_for_reply_with_fds_raw()`.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<BufWithFds<Self::Buf>, Self::Buf>, ConnectionError> {
+        let _ = timeout;
+        self.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    /// Check whether a request that does not have a reply caused an X11 error, but wait at most
+    /// for the given amount of time.
+    ///
+    /// This is like `check_for_error()`, but returns `ConnectionError::Timeout` if the X11 server
+    /// did not handle the request in time.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn check_for_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<(), ReplyError> {
+        match self.check_for_raw_error_timeout(sequence, timeout)? {
+            Some(err) => Err(self.parse_error(err.as_ref())?.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Check whether a request that does not have a reply caused an X11 error, but wait at most
+    /// for the given amount of time.
+    ///
+    /// This is like `check_for_raw_error()`, but returns `ConnectionError::Timeout` if the X11
+    /// server did not handle the request in time.
+    ///
+    /// The default implementation ignores the timeout and calls `check_for_raw_error()`.
+    ///
+    /// Users of this library will most likely not want to use this function directly.
+    fn check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Self::Buf>, ConnectionError> {
+        let _ = timeout;
+        self.check_for_raw_error(sequence)
+    }
+
     /// Prefetches the maximum request length.
     ///
     /// If the maximum request length is not cached yet, this function sends a `BigRequests::Enable`
@@ -356,6 +483,48 @@ pub trait Connection: RequestConnection {
         &self,
     ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError>;
 
+    /// Wait for a new event from the X11 server, but at most for the given amount of time.
+    ///
+    /// If no event arrives in time, `ConnectionError::Timeout` is returned.
+    fn wait_for_event_timeout(&self, timeout: Duration) -> Result<Event, ConnectionError> {
+        Ok(self.wait_for_event_with_sequence_timeout(timeout)?.0)
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server, but at most for the given amount of
+    /// time.
+    ///
+    /// If no event arrives in time, `ConnectionError::Timeout` is returned.
+    fn wait_for_raw_event_timeout(&self, timeout: Duration) -> Result<Self::Buf, ConnectionError> {
+        Ok(self.wait_for_raw_event_with_sequence_timeout(timeout)?.0)
+    }
+
+    /// Wait for a new event from the X11 server, but at most for the given amount of time.
+    ///
+    /// If no event arrives in time, `ConnectionError::Timeout` is returned.
+    fn wait_for_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<EventAndSeqNumber, ConnectionError> {
+        let (event, seq) = self.wait_for_raw_event_with_sequence_timeout(timeout)?;
+        let event = self.parse_event(event.as_ref())?;
+        Ok((event, seq))
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server, but at most for the given amount of
+    /// time.
+    ///
+    /// If no event arrives in time, `ConnectionError::Timeout` is returned.
+    ///
+    /// The default implementation ignores the timeout and calls
+    /// `wait_for_raw_event_with_sequence()`.
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber<Self::Buf>, ConnectionError> {
+        let _ = timeout;
+        self.wait_for_raw_event_with_sequence()
+    }
+
     /// Poll for a new event from the X11 server.
     fn poll_for_event(&self) -> Result<Option<Event>, ConnectionError> {
         Ok(self.poll_for_event_with_sequence()?.map(|r| r.0))
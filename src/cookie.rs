@@ -2,12 +2,17 @@
 
 use std::convert::{TryFrom, TryInto};
 use std::marker::PhantomData;
+use std::time::Duration;
 
-use crate::connection::{BufWithFds, DiscardMode, RequestConnection, RequestKind, SequenceNumber};
+use crate::connection::{
+    BufWithFds, DiscardMode, ReplyOrError, RequestConnection, RequestKind, SequenceNumber,
+};
 use crate::errors::{ConnectionError, ParseError, ReplyError};
 #[cfg(feature = "record")]
 use crate::protocol::record::EnableContextReply;
 use crate::protocol::xproto::ListFontsWithInfoReply;
+#[cfg(feature = "async")]
+use crate::rust_connection::{AsyncRustConnection, AsyncStream};
 use crate::utils::RawFdContainer;
 
 /// A handle to a possible error from the X11 server.
@@ -55,6 +60,24 @@ where
         connection.check_for_error(sequence)
     }
 
+    /// Check if the original request caused an X11 error, but wait at most for the given amount
+    /// of time.
+    ///
+    /// If the X11 server did not handle the request in time, `ConnectionError::Timeout` is
+    /// returned. The connection can still be used afterwards and errors that arrive later are
+    /// treated as events, just as if this cookie was dropped.
+    pub fn check_timeout(self, timeout: Duration) -> Result<(), ReplyError> {
+        let error = self
+            .connection
+            .check_for_raw_error_timeout(self.sequence_number, timeout)?;
+        // The answer was received, so there is nothing left to discard.
+        let (connection, _) = self.consume();
+        match error {
+            Some(err) => Err(connection.parse_error(err.as_ref())?.into()),
+            None => Ok(()),
+        }
+    }
+
     /// Ignore all errors to this request.
     ///
     /// Without calling this method, an error becomes available on the connection as an event after
@@ -69,6 +92,25 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<S: AsyncStream> VoidCookie<'_, AsyncRustConnection<S>> {
+    /// Check if the original request caused an X11 error without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`VoidCookie::check`].
+    pub async fn check_async(self) -> Result<(), ReplyError> {
+        let error = self
+            .connection
+            .check_for_raw_error_async(self.sequence_number)
+            .await?;
+        // The answer was received, so there is nothing left to discard.
+        let (connection, _) = self.consume();
+        match error {
+            Some(err) => Err(connection.parse_error(&err)?.into()),
+            None => Ok(()),
+        }
+    }
+}
+
 impl<C> Drop for VoidCookie<'_, C>
 where
     C: RequestConnection + ?Sized,
@@ -175,11 +217,51 @@ where
         Ok(conn.wait_for_reply(self.raw_cookie.into_sequence_number())?)
     }
 
+    /// Get the raw reply that the server sent, but wait at most for the given amount of time.
+    ///
+    /// If no reply arrived in time, `ConnectionError::Timeout` is returned. The connection can
+    /// still be used afterwards and the reply is discarded once it arrives, just as if this cookie
+    /// was dropped.
+    pub fn raw_reply_timeout(self, timeout: Duration) -> Result<C::Buf, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let reply = conn.wait_for_reply_or_raw_error_timeout(self.sequence_number(), timeout)?;
+        // The answer was received, so there is nothing left to discard.
+        let _ = self.into_sequence_number();
+        match reply {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => {
+                Err(ReplyError::X11Error(conn.parse_error(error.as_ref())?))
+            }
+        }
+    }
+
+    /// Get the raw reply that the server sent, but have errors handled as events and wait at
+    /// most for the given amount of time.
+    ///
+    /// See [`Cookie::raw_reply_timeout`] for what happens when the timeout expires.
+    pub fn raw_reply_unchecked_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<Option<C::Buf>, ConnectionError> {
+        let conn = self.raw_cookie.connection;
+        let reply = conn.wait_for_reply_timeout(self.sequence_number(), timeout)?;
+        // The answer was received, so there is nothing left to discard.
+        let _ = self.into_sequence_number();
+        Ok(reply)
+    }
+
     /// Get the reply that the server sent.
     pub fn reply(self) -> Result<R, ReplyError> {
         Ok(self.raw_reply()?.as_ref().try_into()?)
     }
 
+    /// Get the reply that the server sent, but wait at most for the given amount of time.
+    ///
+    /// See [`Cookie::raw_reply_timeout`] for what happens when the timeout expires.
+    pub fn reply_timeout(self, timeout: Duration) -> Result<R, ReplyError> {
+        Ok(self.raw_reply_timeout(timeout)?.as_ref().try_into()?)
+    }
+
     /// Get the reply that the server sent, but have errors handled as events.
     pub fn reply_unchecked(self) -> Result<Option<R>, ConnectionError> {
         self.raw_reply_unchecked()?
@@ -188,6 +270,17 @@ where
             .map_err(Into::into)
     }
 
+    /// Get the reply that the server sent, but have errors handled as events and wait at most for
+    /// the given amount of time.
+    ///
+    /// See [`Cookie::raw_reply_timeout`] for what happens when the timeout expires.
+    pub fn reply_unchecked_timeout(self, timeout: Duration) -> Result<Option<R>, ConnectionError> {
+        self.raw_reply_unchecked_timeout(timeout)?
+            .map(|buf| buf.as_ref().try_into())
+            .transpose()
+            .map_err(Into::into)
+    }
+
     /// Discard all responses to the request this cookie represents, even errors.
     ///
     /// Without this function, errors are treated as events after the cookie is dropped.
@@ -206,6 +299,60 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<S, R> Cookie<'_, AsyncRustConnection<S>, R>
+where
+    R: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+    S: AsyncStream,
+{
+    /// Get the raw reply that the server sent without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`Cookie::raw_reply`].
+    pub async fn raw_reply_async(self) -> Result<Vec<u8>, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let reply = conn
+            .wait_for_reply_or_raw_error_async(self.sequence_number())
+            .await?;
+        // The answer was received, so there is nothing left to discard.
+        let _ = self.into_sequence_number();
+        match reply {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => Err(ReplyError::X11Error(conn.parse_error(&error)?)),
+        }
+    }
+
+    /// Get the raw reply that the server sent without blocking the current thread, but have
+    /// errors handled as events.
+    ///
+    /// This is the `async` counterpart of [`Cookie::raw_reply_unchecked`].
+    pub async fn raw_reply_unchecked_async(self) -> Result<Option<Vec<u8>>, ConnectionError> {
+        let conn = self.raw_cookie.connection;
+        let reply = conn.wait_for_reply_async(self.sequence_number()).await?;
+        // The answer was received, so there is nothing left to discard.
+        let _ = self.into_sequence_number();
+        Ok(reply)
+    }
+
+    /// Get the reply that the server sent without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`Cookie::reply`].
+    pub async fn reply_async(self) -> Result<R, ReplyError> {
+        Ok(self.raw_reply_async().await?.as_slice().try_into()?)
+    }
+
+    /// Get the reply that the server sent without blocking the current thread, but have errors
+    /// handled as events.
+    ///
+    /// This is the `async` counterpart of [`Cookie::reply_unchecked`].
+    pub async fn reply_unchecked_async(self) -> Result<Option<R>, ConnectionError> {
+        self.raw_reply_unchecked_async()
+            .await?
+            .map(|buf| buf.as_slice().try_into())
+            .transpose()
+            .map_err(Into::into)
+    }
+}
+
 /// A handle to a response containing `RawFd` from the X11 server.
 ///
 /// When sending a request to the X11 server, this library returns a `Cookie`. This `Cookie` can
@@ -253,6 +400,62 @@ where
         let (buffer, fds) = self.raw_reply()?;
         Ok(R::try_from((buffer.as_ref(), fds))?)
     }
+
+    /// Get the raw reply that the server sent, but wait at most for the given amount of time.
+    ///
+    /// See [`Cookie::raw_reply_timeout`] for what happens when the timeout expires.
+    pub fn raw_reply_timeout(self, timeout: Duration) -> Result<BufWithFds<C::Buf>, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let reply =
+            conn.wait_for_reply_with_fds_raw_timeout(self.raw_cookie.sequence_number, timeout)?;
+        // The answer was received, so there is nothing left to discard.
+        let _ = self.raw_cookie.into_sequence_number();
+        match reply {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => {
+                Err(ReplyError::X11Error(conn.parse_error(error.as_ref())?))
+            }
+        }
+    }
+
+    /// Get the reply that the server sent, but wait at most for the given amount of time.
+    ///
+    /// See [`Cookie::raw_reply_timeout`] for what happens when the timeout expires.
+    pub fn reply_timeout(self, timeout: Duration) -> Result<R, ReplyError> {
+        let (buffer, fds) = self.raw_reply_timeout(timeout)?;
+        Ok(R::try_from((buffer.as_ref(), fds))?)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S, R> CookieWithFds<'_, AsyncRustConnection<S>, R>
+where
+    R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+    S: AsyncStream,
+{
+    /// Get the raw reply that the server sent without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`CookieWithFds::raw_reply`].
+    pub async fn raw_reply_async(self) -> Result<BufWithFds<Vec<u8>>, ReplyError> {
+        let conn = self.raw_cookie.connection;
+        let reply = conn
+            .wait_for_reply_with_fds_raw_async(self.raw_cookie.sequence_number)
+            .await?;
+        // The answer was received, so there is nothing left to discard.
+        let _ = self.raw_cookie.into_sequence_number();
+        match reply {
+            ReplyOrError::Reply(reply) => Ok(reply),
+            ReplyOrError::Error(error) => Err(ReplyError::X11Error(conn.parse_error(&error)?)),
+        }
+    }
+
+    /// Get the reply that the server sent without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`CookieWithFds::reply`].
+    pub async fn reply_async(self) -> Result<R, ReplyError> {
+        let (buffer, fds) = self.raw_reply_async().await?;
+        Ok(R::try_from((buffer.as_slice(), fds))?)
+    }
 }
 
 macro_rules! multiple_reply_cookie {
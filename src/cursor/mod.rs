@@ -44,7 +44,7 @@ impl<C: Connection> Cookie<'_, '_, C> {
     /// Get the handle from the replies from the X11 server
     pub fn reply(self) -> Result<Handle, ReplyOrIdError> {
         let mut render_version = (0, 0);
-        let mut picture_format = NONE;
+        let mut picture_format = Pictformat::from(NONE);
         if let Some((version, formats)) = self.render_info {
             let version = version.reply()?;
             render_version = (version.major_version, version.minor_version);
@@ -62,7 +62,7 @@ impl<C: Connection> Cookie<'_, '_, C> {
     /// Get the handle from the replies from the X11 server
     pub fn reply_unchecked(self) -> Result<Option<Handle>, ReplyOrIdError> {
         let mut render_version = (0, 0);
-        let mut picture_format = NONE;
+        let mut picture_format = Pictformat::from(NONE);
         if let Some((version, formats)) = self.render_info {
             match (version.reply_unchecked()?, formats.reply_unchecked()?) {
                 (Some(version), Some(formats)) => {
@@ -107,7 +107,7 @@ impl<C: Connection> Cookie<'_, '_, C> {
             _ => 0,
         };
         let cursor_size = get_cursor_size(cursor_size, xft_dpi, screen);
-        let cursor_font = conn.generate_id()?;
+        let cursor_font = Font::from(conn.generate_id()?);
         let _ = xproto::open_font(conn, cursor_font, b"cursor")?;
         Ok(Handle {
             root: screen.root,
@@ -194,7 +194,7 @@ fn create_core_cursor<C: Connection>(
     cursor_font: Font,
     cursor: u16,
 ) -> Result<xproto::Cursor, ReplyOrIdError> {
-    let result = conn.generate_id()?;
+    let result = xproto::Cursor::from(conn.generate_id()?);
     let _ = xproto::create_glyph_cursor(
         conn,
         result,
@@ -220,7 +220,8 @@ fn create_render_cursor<C: Connection>(
     image: &parse_cursor::Image,
     storage: &mut Option<(xproto::Pixmap, xproto::Gcontext, u16, u16)>,
 ) -> Result<render::Animcursorelt, ReplyOrIdError> {
-    let (cursor, picture) = (conn.generate_id()?, conn.generate_id()?);
+    let cursor = xproto::Cursor::from(conn.generate_id()?);
+    let picture = render::Picture::from(conn.generate_id()?);
 
     // Get a pixmap of the right size and a gc for it
     let (pixmap, gc) = if storage.map(|(_, _, w, h)| (w, h)) == Some((image.width, image.height)) {
@@ -231,10 +232,25 @@ fn create_render_cursor<C: Connection>(
             let _ = xproto::free_pixmap(conn, *pixmap)?;
             (*pixmap, *gc)
         } else {
-            (conn.generate_id()?, conn.generate_id()?)
+            (
+                xproto::Pixmap::from(conn.generate_id()?),
+                xproto::Gcontext::from(conn.generate_id()?),
+            )
         };
-        let _ = xproto::create_pixmap(conn, 32, pixmap, handle.root, image.width, image.height)?;
-        let _ = xproto::create_gc(conn, gc, pixmap, &Default::default())?;
+        let _ = xproto::create_pixmap(
+            conn,
+            32,
+            pixmap,
+            xproto::Drawable::from(handle.root),
+            image.width,
+            image.height,
+        )?;
+        let _ = xproto::create_gc(
+            conn,
+            gc,
+            xproto::Drawable::from(pixmap),
+            &Default::default(),
+        )?;
 
         *storage = Some((pixmap, gc, image.width, image.height));
         (pixmap, gc)
@@ -245,7 +261,7 @@ fn create_render_cursor<C: Connection>(
     let _ = xproto::put_image(
         conn,
         xproto::ImageFormat::Z_PIXMAP,
-        pixmap,
+        xproto::Drawable::from(pixmap),
         gc,
         image.width,
         image.height,
@@ -259,7 +275,7 @@ fn create_render_cursor<C: Connection>(
     let _ = render::create_picture(
         conn,
         picture,
-        pixmap,
+        xproto::Drawable::from(pixmap),
         handle.picture_format,
         &Default::default(),
     )?;
@@ -279,7 +295,7 @@ fn load_cursor<C: Connection>(
 ) -> Result<xproto::Cursor, ReplyOrIdError> {
     // Find the right cursor, load it directly if it is a core cursor
     let cursor_file = match open_cursor(&handle.theme, name) {
-        None => return Ok(NONE),
+        None => return Ok(xproto::CursorEnum::NONE.into()),
         Some(find_cursor::Cursor::CoreChar(c)) => {
             return create_core_cursor(conn, handle.cursor_font, c)
         }
@@ -288,7 +304,7 @@ fn load_cursor<C: Connection>(
 
     // We have to load a file and use RENDER to create a cursor
     if handle.render_support == RenderSupport::None {
-        return Ok(NONE);
+        return Ok(xproto::CursorEnum::NONE.into());
     }
 
     // Load the cursor from the file
@@ -316,7 +332,7 @@ fn load_cursor<C: Connection>(
     if cursors.len() == 1 {
         Ok(cursors[0].cursor)
     } else {
-        let result = conn.generate_id()?;
+        let result = xproto::Cursor::from(conn.generate_id()?);
         let _ = render::create_anim_cursor(conn, result, &cursors)?;
         for elem in cursors {
             let _ = xproto::free_cursor(conn, elem.cursor)?;
@@ -3,6 +3,8 @@
 use crate::protocol::xproto::{SetupAuthenticate, SetupFailed};
 use crate::x11_utils::X11Error;
 
+pub use x11rb_protocol::errors::ParseError;
+
 /// An error occurred  while dynamically loading libxcb.
 #[cfg(feature = "dl-libxcb")]
 #[derive(Debug, Clone)]
@@ -40,60 +42,6 @@ impl std::fmt::Display for LibxcbLoadError {
 #[cfg(feature = "dl-libxcb")]
 impl std::error::Error for LibxcbLoadError {}
 
-/// An error occurred while parsing some data
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-#[non_exhaustive]
-pub enum ParseError {
-    /// Not enough data was provided.
-    InsufficientData,
-
-    /// A value did not fit.
-    ///
-    /// This error can e.g. happen when a value that was received from the X11 server does not fit
-    /// into an `usize`.
-    ConversionFailed,
-
-    /// The value of an expression could not be computed.
-    ///
-    /// As an example, the length of the data in `xproto`'s `GetPropertyReply` is described by
-    /// `value_len * (format / 8)`. The multiplication could cause an overflow, which would be
-    /// represented by this error.
-    InvalidExpression,
-
-    /// A value was outside of its valid range.
-    ///
-    /// When parsing the value of an enumeration, not all possible integer values have a defined
-    /// meaning. This error occurs when an invalid value is encountered in this context.
-    ///
-    /// For example, `xproto` has an enumeration `Place` with possible values `OnTop` (0) and
-    /// `OnBottom` (1). Any value other than 0 or 1 generates an `InvalidValue` when parsing.
-    InvalidValue,
-
-    /// Some file descriptors were expected, but none were received.
-    MissingFileDescriptors,
-}
-
-impl std::error::Error for ParseError {}
-
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ParseError::InsufficientData => write!(f, "Insufficient data was provided"),
-            ParseError::ConversionFailed => {
-                write!(f, "A value conversion failed due to out of range data")
-            }
-            ParseError::InvalidExpression => write!(
-                f,
-                "An expression could not be computed, e.g. due to overflow"
-            ),
-            ParseError::InvalidValue => {
-                write!(f, "A value could not be parsed into an enumeration")
-            }
-            ParseError::MissingFileDescriptors => write!(f, "Missing file descriptors"),
-        }
-    }
-}
-
 /// An error that occurred while connecting to an X11 server
 #[derive(Debug)]
 #[non_exhaustive]
@@ -216,6 +164,12 @@ pub enum ConnectionError {
 
     /// An I/O error occurred on the connection.
     IOError(std::io::Error),
+
+    /// The X11 server did not answer before the given timeout expired.
+    ///
+    /// Unlike the other errors, this does not indicate a problem with the connection. It can
+    /// still be used afterwards.
+    Timeout,
 }
 
 impl std::error::Error for ConnectionError {}
@@ -232,6 +186,7 @@ impl std::fmt::Display for ConnectionError {
             ConnectionError::FDPassingFailed => write!(f, "FD passing failed"),
             ConnectionError::ParseError(err) => err.fmt(f),
             ConnectionError::IOError(err) => err.fmt(f),
+            ConnectionError::Timeout => write!(f, "Timeout expired"),
         }
     }
 }
@@ -263,7 +218,7 @@ impl std::fmt::Display for ReplyError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ReplyError::ConnectionError(e) => write!(f, "{}", e),
-            ReplyError::X11Error(e) => write!(f, "X11 error {:?}", e),
+            ReplyError::X11Error(e) => write!(f, "{}", e),
         }
     }
 }
@@ -308,7 +263,7 @@ impl std::fmt::Display for ReplyOrIdError {
         match self {
             ReplyOrIdError::IdsExhausted => f.write_str("X11 IDs have been exhausted"),
             ReplyOrIdError::ConnectionError(e) => write!(f, "{}", e),
-            ReplyOrIdError::X11Error(e) => write!(f, "X11 error {:?}", e),
+            ReplyOrIdError::X11Error(e) => write!(f, "{}", e),
         }
     }
 }
@@ -80,3 +80,10 @@
 //! a dedicated event loop for fetching events from the X11 server. All other threads can now
 //! freely use the X11 connection without events possibly getting stuck and only being processed
 //! later.
+//!
+//! ## Async Code
+//!
+//! With the `async` feature, `x11rb::rust_connection::AsyncRustConnection` is available. Its
+//! replies, errors, and events can be `.await`ed without parking a thread on the connection. Since
+//! it is woken up both when its stream becomes readable and when another thread or task received
+//! packets, the race described above does not affect it.
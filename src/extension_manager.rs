@@ -12,10 +12,10 @@ use crate::x11_utils::{ExtInfoProvider, ExtensionInformation};
 ///
 /// This helps with implementing `RequestConnection`. Most likely, you do not need this in your own
 /// code, unless you really want to implement your own X11 connection.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ExtensionManager(HashMap<&'static str, CheckState>);
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum CheckState {
     Prefetched(SequenceNumber),
     Present(ExtensionInformation),
@@ -95,6 +95,31 @@ impl ExtensionManager {
             CheckState::Error => Err(ConnectionError::UnknownError),
         }
     }
+
+    /// Record that the given extension is present with the given information.
+    ///
+    /// This is used by [`ServerConnection`](crate::server::ServerConnection), which decides
+    /// itself about the extensions that it provides.
+    pub(crate) fn add_extension(
+        &mut self,
+        extension_name: &'static str,
+        info: ExtensionInformation,
+    ) {
+        let _ = self.0.insert(extension_name, CheckState::Present(info));
+    }
+
+    /// Get the sequence number of the `QueryExtension` request for the given extension if its
+    /// reply was not yet processed.
+    #[cfg(feature = "async")]
+    pub(crate) fn pending_sequence_number(
+        &self,
+        extension_name: &'static str,
+    ) -> Option<SequenceNumber> {
+        match self.0.get(extension_name) {
+            Some(CheckState::Prefetched(sequence_number)) => Some(*sequence_number),
+            _ => None,
+        }
+    }
 }
 
 impl ExtInfoProvider for ExtensionManager {
@@ -144,6 +169,13 @@ impl ExtInfoProvider for ExtensionManager {
             })
             .max_by_key(|(_, info)| info.first_error)
     }
+
+    fn get_from_extension_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        match self.0.get(extension_name) {
+            Some(CheckState::Present(info)) => Some(*info),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -307,5 +339,8 @@ mod test {
         assert_eq!(ext_info.get_from_major_opcode(4), Some(("present", info)));
         assert_eq!(ext_info.get_from_event_code(5), Some(("present", info)));
         assert_eq!(ext_info.get_from_error_code(6), Some(("present", info)));
+        assert_eq!(ext_info.get_from_extension_name("present"), Some(info));
+        assert_eq!(ext_info.get_from_extension_name("prefetched"), None);
+        assert_eq!(ext_info.get_from_extension_name("missing"), None);
     }
 }
@@ -0,0 +1,9 @@
+//! Runtime descriptions of the requests, replies and events of the X11 protocol.
+//!
+//! The descriptions are available as `REQUEST_INFOS` and `EVENT_INFOS` in each module of
+//! [`protocol`](crate::protocol) and through [`Request::info`](crate::protocol::Request::info) and
+//! [`Event::info`](crate::protocol::Event::info).
+
+pub use x11rb_protocol::introspect::{
+    CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet,
+};
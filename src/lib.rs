@@ -98,6 +98,25 @@
 //!   resulting executable. Instead libxcb will be dynamically loaded at runtime.
 //!   This feature adds the `x11rb::xcb_ffi::load_libxcb` function, that allows load
 //!   libxcb and check for success or failure.
+//! * `async`: Enable `x11rb::rust_connection::AsyncRustConnection`, a variant of `RustConnection`
+//!   whose replies and events can be `.await`ed. This requires at least Rust 1.48.
+//! * `request-location`: In debug builds, record the location in the source code that each request
+//!   was sent from, so that `RustConnection`'s error handler can report it. This requires at least
+//!   Rust 1.46.
+//! * `testing`: Enable the code in [x11rb::testing] for testing code that uses x11rb, either
+//!   against a fake X11 server inside the current process, against a throwaway `Xvfb` or with a
+//!   mock connection that records requests.
+//! * `serde`: Implement `serde::Serialize` and `serde::Deserialize` for the structs, events,
+//!   replies, enums and bitmasks in [x11rb::protocol]. In human-readable formats, enums use the
+//!   names of their variants (e.g. `"TRUE_COLOR"`) and bitmasks use the same form as their `Debug`
+//!   output (e.g. `"SHIFT | LOCK"`). Requests do not implement these traits.
+//!
+//! # Parsing X11 data without a connection
+//!
+//! The protocol definitions in [x11rb::protocol] and the parsing and serialisation code in
+//! [x11rb::x11_utils] actually live in the `x11rb-protocol` crate and are re-exported here. That
+//! crate does not know anything about connections and works with `#![no_std]` (it only needs
+//! `alloc`), so it can be used directly by code that only needs to decode or produce X11 traffic.
 //!
 //! # Integrating x11rb with an Event Loop
 //!
@@ -150,8 +169,14 @@ pub mod errors;
 pub mod extension_manager;
 #[cfg(feature = "image")]
 pub mod image;
+pub mod introspect;
 pub mod properties;
 pub mod rust_connection;
+pub mod server;
+pub mod statistics;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod trace;
 pub mod wrapper;
 #[rustfmt::skip]
 #[allow(missing_docs)]
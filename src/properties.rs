@@ -6,7 +6,7 @@ use crate::connection::RequestConnection;
 use crate::cookie::{Cookie, VoidCookie};
 use crate::errors::{ConnectionError, ParseError, ReplyError};
 use crate::protocol::xproto::{self, Atom, AtomEnum, GetPropertyReply, Window};
-use crate::x11_utils::{Serialize, TryParse};
+use crate::x11_utils::{ByteOrder, Serialize, TryParse};
 
 // WM_CLASS
 
@@ -223,12 +223,18 @@ impl TryParse for AspectRatio {
 impl Serialize for AspectRatio {
     type Bytes = [u8; 8];
     fn serialize(&self) -> Self::Bytes {
-        let [a, b, c, d] = self.numerator.serialize();
-        let [e, f, g, h] = self.denominator.serialize();
-        [a, b, c, d, e, f, g, h]
+        self.serialize_with_order(ByteOrder::native())
     }
     fn serialize_into(&self, bytes: &mut Vec<u8>) {
-        (self.numerator, self.denominator).serialize_into(bytes);
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+        let [a, b, c, d] = self.numerator.serialize_with_order(order);
+        let [e, f, g, h] = self.denominator.serialize_with_order(order);
+        [a, b, c, d, e, f, g, h]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        (self.numerator, self.denominator).serialize_into_with_order(bytes, order);
     }
 }
 
@@ -384,12 +390,18 @@ impl TryParse for WmSizeHints {
 impl Serialize for WmSizeHints {
     type Bytes = Vec<u8>;
     fn serialize(&self) -> Self::Bytes {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
         // 18*4 surely fits into an usize, so this unwrap() cannot trigger
         let mut result = Vec::with_capacity((NUM_WM_SIZE_HINTS_ELEMENTS * 4).try_into().unwrap());
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let mut flags = 0;
         match self.position {
             Some((WmSizeHintsSpecification::UserSpecified, _, _)) => flags |= U_S_POSITION,
@@ -407,28 +419,38 @@ impl Serialize for WmSizeHints {
         flags |= self.aspect.map_or(0, |_| P_ASPECT);
         flags |= self.base_size.map_or(0, |_| P_BASE_SIZE);
         flags |= self.win_gravity.map_or(0, |_| P_WIN_GRAVITY);
-        flags.serialize_into(bytes);
+        flags.serialize_into_with_order(bytes, order);
 
         match self.position {
             Some((_, x, y)) => (x, y),
             None => (0, 0),
         }
-        .serialize_into(bytes);
+        .serialize_into_with_order(bytes, order);
 
         match self.size {
             Some((_, width, height)) => (width, height),
             None => (0, 0),
         }
-        .serialize_into(bytes);
-
-        self.min_size.unwrap_or((0, 0)).serialize_into(bytes);
-        self.max_size.unwrap_or((0, 0)).serialize_into(bytes);
-        self.size_increment.unwrap_or((0, 0)).serialize_into(bytes);
+        .serialize_into_with_order(bytes, order);
+
+        self.min_size
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.max_size
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.size_increment
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
         self.aspect
             .unwrap_or((AspectRatio::new(0, 0), AspectRatio::new(0, 0)))
-            .serialize_into(bytes);
-        self.base_size.unwrap_or((0, 0)).serialize_into(bytes);
-        self.win_gravity.map_or(0, u32::from).serialize_into(bytes);
+            .serialize_into_with_order(bytes, order);
+        self.base_size
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.win_gravity
+            .map_or(0, u32::from)
+            .serialize_into_with_order(bytes, order);
     }
 }
 
@@ -584,17 +606,20 @@ impl TryParse for WmHints {
         let (flags, remaining) = u32::try_parse(remaining)?;
         let (input, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_INPUT)?;
         let (initial_state, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_STATE)?;
-        let (icon_pixmap, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_ICON_PIXMAP)?;
-        let (icon_window, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_ICON_WINDOW)?;
+        let (icon_pixmap, remaining) =
+            parse_with_flag::<xproto::Pixmap>(remaining, flags, HINT_ICON_PIXMAP)?;
+        let (icon_window, remaining) =
+            parse_with_flag::<Window>(remaining, flags, HINT_ICON_WINDOW)?;
         let (icon_position, remaining) =
             parse_with_flag::<(i32, i32)>(remaining, flags, HINT_ICON_POSITION)?;
-        let (icon_mask, remaining) = parse_with_flag::<u32>(remaining, flags, HINT_ICON_MASK)?;
+        let (icon_mask, remaining) =
+            parse_with_flag::<xproto::Pixmap>(remaining, flags, HINT_ICON_MASK)?;
         // Apparently, some older version of ICCCM didn't have this...?
         let (window_group, remaining) = if remaining.is_empty() {
             (None, remaining)
         } else {
             let (window_group, remaining) =
-                parse_with_flag::<u32>(remaining, flags, HINT_WINDOW_GROUP)?;
+                parse_with_flag::<Window>(remaining, flags, HINT_WINDOW_GROUP)?;
             (window_group, remaining)
         };
 
@@ -628,12 +653,18 @@ impl TryParse for WmHints {
 impl Serialize for WmHints {
     type Bytes = Vec<u8>;
     fn serialize(&self) -> Self::Bytes {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
         // 9*4 surely fits into an usize, so this unwrap() cannot trigger
         let mut result = Vec::with_capacity((NUM_WM_HINTS_ELEMENTS * 4).try_into().unwrap());
-        self.serialize_into(&mut result);
+        self.serialize_into_with_order(&mut result, order);
         result
     }
-    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
         let mut flags = 0;
         flags |= self.input.map_or(0, |_| HINT_INPUT);
         flags |= self.initial_state.map_or(0, |_| HINT_STATE);
@@ -646,19 +677,29 @@ impl Serialize for WmHints {
             flags |= HINT_URGENCY;
         }
 
-        flags.serialize_into(bytes);
-        u32::from(self.input.unwrap_or(false)).serialize_into(bytes);
+        flags.serialize_into_with_order(bytes, order);
+        u32::from(self.input.unwrap_or(false)).serialize_into_with_order(bytes, order);
         match self.initial_state {
             Some(WmHintsState::Normal) => 1,
             Some(WmHintsState::Iconic) => 3,
             None => 0,
         }
-        .serialize_into(bytes);
-        self.icon_pixmap.unwrap_or(0).serialize_into(bytes);
-        self.icon_window.unwrap_or(0).serialize_into(bytes);
-        self.icon_position.unwrap_or((0, 0)).serialize_into(bytes);
-        self.icon_mask.unwrap_or(0).serialize_into(bytes);
-        self.window_group.unwrap_or(0).serialize_into(bytes);
+        .serialize_into_with_order(bytes, order);
+        self.icon_pixmap
+            .unwrap_or_default()
+            .serialize_into_with_order(bytes, order);
+        self.icon_window
+            .unwrap_or_default()
+            .serialize_into_with_order(bytes, order);
+        self.icon_position
+            .unwrap_or((0, 0))
+            .serialize_into_with_order(bytes, order);
+        self.icon_mask
+            .unwrap_or_default()
+            .serialize_into_with_order(bytes, order);
+        self.window_group
+            .unwrap_or_default()
+            .serialize_into_with_order(bytes, order);
     }
 }
 
@@ -682,7 +723,7 @@ mod test {
     use std::convert::TryInto;
 
     use super::{WmClass, WmHints, WmHintsState, WmSizeHints};
-    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity};
+    use crate::protocol::xproto::{Atom, AtomEnum, GetPropertyReply, Gravity, Window};
     use crate::x11_utils::Serialize;
 
     fn get_property_reply(value: &[u8], format: u8, type_: impl Into<Atom>) -> GetPropertyReply {
@@ -795,7 +836,7 @@ mod test {
         assert_eq!(wm_hints.icon_window, None);
         assert_eq!(wm_hints.icon_position, None);
         assert_eq!(wm_hints.icon_mask, None);
-        assert_eq!(wm_hints.window_group, Some(0x0060_0009));
+        assert_eq!(wm_hints.window_group, Some(Window::from(0x0060_0009_u32)));
         assert_eq!(wm_hints.urgent, false);
 
         assert_eq!(input, wm_hints.serialize());
@@ -10,117 +10,47 @@
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
-use std::convert::TryFrom;
 #[allow(unused_imports)]
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
-#[allow(unused_imports)]
-use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
-use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
+use crate::utils::RawFdContainer;
+use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
-use crate::errors::{ConnectionError, ParseError};
-
-/// The X11 name of the extension for QueryExtension
-pub const X11_EXTENSION_NAME: &str = "BIG-REQUESTS";
+use crate::errors::ConnectionError;
 
-/// The version number of this extension that this client library supports.
-///
-/// This constant contains the version number of this extension that is supported
-/// by this build of x11rb. For most things, it does not make sense to use this
-/// information. If you need to send a `QueryVersion`, it is recommended to instead
-/// send the maximum version of the extension that you need.
-pub const X11_XML_VERSION: (u32, u32) = (0, 0);
+pub use x11rb_protocol::protocol::bigreq::*;
 
-/// Opcode for the Enable request
-pub const ENABLE_REQUEST: u8 = 0;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EnableRequest;
-impl EnableRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            ENABLE_REQUEST,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, EnableReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_with_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != ENABLE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let _ = value;
-        Ok(EnableRequest
-        )
-    }
+/// Get the major opcode of this extension
+fn major_opcode<Conn: RequestConnection + ?Sized>(conn: &Conn) -> Result<u8, ConnectionError> {
+    let info = conn.extension_information(X11_EXTENSION_NAME)?;
+    let info = info.ok_or(ConnectionError::UnsupportedExtension)?;
+    Ok(info.major_opcode)
 }
-impl Request for EnableRequest {
-    type Reply = EnableReply;
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_enable<'c, Conn>(req: EnableRequest, conn: &'c Conn) -> Result<Cookie<'c, Conn, EnableReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_with_reply(&slices, fds)
 }
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn enable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, EnableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let request0 = EnableRequest;
-    request0.send(conn)
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EnableReply {
-    pub sequence: u16,
-    pub length: u32,
-    pub maximum_request_length: u32,
-}
-impl TryParse for EnableReply {
-    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (maximum_request_length, remaining) = u32::try_parse(remaining)?;
-        if response_type != 1 {
-            return Err(ParseError::InvalidValue);
-        }
-        let result = EnableReply { sequence, length, maximum_request_length };
-        let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
-        Ok((result, remaining))
-    }
-}
-impl TryFrom<&[u8]> for EnableReply {
-    type Error = ParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_parse(value)?.0)
-    }
+    send_enable(request0, conn)
 }
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn bigreq_enable(&self) -> Result<Cookie<'_, Self, EnableReply>, ConnectionError>
     {
         enable(self)
@@ -10,89 +10,36 @@
 
 #[allow(unused_imports)]
 use std::borrow::Cow;
-use std::convert::TryFrom;
 #[allow(unused_imports)]
 use std::convert::TryInto;
 use std::io::IoSlice;
 #[allow(unused_imports)]
-use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
-#[allow(unused_imports)]
-use crate::x11_utils::{Request, RequestHeader, Serialize, TryParse, TryParseFd};
-use crate::connection::{BufWithFds, PiecewiseBuf, RequestConnection};
+use crate::utils::RawFdContainer;
+use crate::connection::RequestConnection;
 #[allow(unused_imports)]
 use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
-use crate::errors::{ConnectionError, ParseError};
-
-/// The X11 name of the extension for QueryExtension
-pub const X11_EXTENSION_NAME: &str = "DPMS";
+use crate::errors::ConnectionError;
 
-/// The version number of this extension that this client library supports.
-///
-/// This constant contains the version number of this extension that is supported
-/// by this build of x11rb. For most things, it does not make sense to use this
-/// information. If you need to send a `QueryVersion`, it is recommended to instead
-/// send the maximum version of the extension that you need.
-pub const X11_XML_VERSION: (u32, u32) = (0, 0);
+pub use x11rb_protocol::protocol::dpms::*;
 
-/// Opcode for the GetVersion request
-pub const GET_VERSION_REQUEST: u8 = 0;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct GetVersionRequest {
-    pub client_major_version: u16,
-    pub client_minor_version: u16,
-}
-impl GetVersionRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let client_major_version_bytes = self.client_major_version.serialize();
-        let client_minor_version_bytes = self.client_minor_version.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            GET_VERSION_REQUEST,
-            0,
-            0,
-            client_major_version_bytes[0],
-            client_major_version_bytes[1],
-            client_minor_version_bytes[0],
-            client_minor_version_bytes[1],
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_with_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != GET_VERSION_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (client_major_version, remaining) = u16::try_parse(value)?;
-        let (client_minor_version, remaining) = u16::try_parse(remaining)?;
-        let _ = remaining;
-        Ok(GetVersionRequest {
-            client_major_version,
-            client_minor_version,
-        })
-    }
+/// Get the major opcode of this extension
+fn major_opcode<Conn: RequestConnection + ?Sized>(conn: &Conn) -> Result<u8, ConnectionError> {
+    let info = conn.extension_information(X11_EXTENSION_NAME)?;
+    let info = info.ok_or(ConnectionError::UnsupportedExtension)?;
+    Ok(info.major_opcode)
 }
-impl Request for GetVersionRequest {
-    type Reply = GetVersionReply;
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_get_version<'c, Conn>(req: GetVersionRequest, conn: &'c Conn) -> Result<Cookie<'c, Conn, GetVersionReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_with_reply(&slices, fds)
 }
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn get_version<Conn>(conn: &Conn, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Conn, GetVersionReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -101,285 +48,58 @@ where
         client_major_version,
         client_minor_version,
     };
-    request0.send(conn)
+    send_get_version(request0, conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct GetVersionReply {
-    pub sequence: u16,
-    pub length: u32,
-    pub server_major_version: u16,
-    pub server_minor_version: u16,
-}
-impl TryParse for GetVersionReply {
-    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (server_major_version, remaining) = u16::try_parse(remaining)?;
-        let (server_minor_version, remaining) = u16::try_parse(remaining)?;
-        if response_type != 1 {
-            return Err(ParseError::InvalidValue);
-        }
-        let result = GetVersionReply { sequence, length, server_major_version, server_minor_version };
-        let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
-        Ok((result, remaining))
-    }
-}
-impl TryFrom<&[u8]> for GetVersionReply {
-    type Error = ParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_parse(value)?.0)
-    }
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_capable<'c, Conn>(req: CapableRequest, conn: &'c Conn) -> Result<Cookie<'c, Conn, CapableReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_with_reply(&slices, fds)
 }
 
-/// Opcode for the Capable request
-pub const CAPABLE_REQUEST: u8 = 1;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct CapableRequest;
-impl CapableRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            CAPABLE_REQUEST,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, CapableReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_with_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != CAPABLE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let _ = value;
-        Ok(CapableRequest
-        )
-    }
-}
-impl Request for CapableRequest {
-    type Reply = CapableReply;
-}
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn capable<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, CapableReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let request0 = CapableRequest;
-    request0.send(conn)
+    send_capable(request0, conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct CapableReply {
-    pub sequence: u16,
-    pub length: u32,
-    pub capable: bool,
-}
-impl TryParse for CapableReply {
-    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (capable, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
-        if response_type != 1 {
-            return Err(ParseError::InvalidValue);
-        }
-        let result = CapableReply { sequence, length, capable };
-        let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
-        Ok((result, remaining))
-    }
-}
-impl TryFrom<&[u8]> for CapableReply {
-    type Error = ParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_parse(value)?.0)
-    }
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_get_timeouts<'c, Conn>(req: GetTimeoutsRequest, conn: &'c Conn) -> Result<Cookie<'c, Conn, GetTimeoutsReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_with_reply(&slices, fds)
 }
 
-/// Opcode for the GetTimeouts request
-pub const GET_TIMEOUTS_REQUEST: u8 = 2;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct GetTimeoutsRequest;
-impl GetTimeoutsRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            GET_TIMEOUTS_REQUEST,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, GetTimeoutsReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_with_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != GET_TIMEOUTS_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let _ = value;
-        Ok(GetTimeoutsRequest
-        )
-    }
-}
-impl Request for GetTimeoutsRequest {
-    type Reply = GetTimeoutsReply;
-}
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn get_timeouts<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, GetTimeoutsReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let request0 = GetTimeoutsRequest;
-    request0.send(conn)
+    send_get_timeouts(request0, conn)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct GetTimeoutsReply {
-    pub sequence: u16,
-    pub length: u32,
-    pub standby_timeout: u16,
-    pub suspend_timeout: u16,
-    pub off_timeout: u16,
-}
-impl TryParse for GetTimeoutsReply {
-    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (standby_timeout, remaining) = u16::try_parse(remaining)?;
-        let (suspend_timeout, remaining) = u16::try_parse(remaining)?;
-        let (off_timeout, remaining) = u16::try_parse(remaining)?;
-        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
-        if response_type != 1 {
-            return Err(ParseError::InvalidValue);
-        }
-        let result = GetTimeoutsReply { sequence, length, standby_timeout, suspend_timeout, off_timeout };
-        let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
-        Ok((result, remaining))
-    }
-}
-impl TryFrom<&[u8]> for GetTimeoutsReply {
-    type Error = ParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_parse(value)?.0)
-    }
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_set_timeouts<'c, Conn>(req: SetTimeoutsRequest, conn: &'c Conn) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_without_reply(&slices, fds)
 }
 
-/// Opcode for the SetTimeouts request
-pub const SET_TIMEOUTS_REQUEST: u8 = 3;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SetTimeoutsRequest {
-    pub standby_timeout: u16,
-    pub suspend_timeout: u16,
-    pub off_timeout: u16,
-}
-impl SetTimeoutsRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let standby_timeout_bytes = self.standby_timeout.serialize();
-        let suspend_timeout_bytes = self.suspend_timeout.serialize();
-        let off_timeout_bytes = self.off_timeout.serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            SET_TIMEOUTS_REQUEST,
-            0,
-            0,
-            standby_timeout_bytes[0],
-            standby_timeout_bytes[1],
-            suspend_timeout_bytes[0],
-            suspend_timeout_bytes[1],
-            off_timeout_bytes[0],
-            off_timeout_bytes[1],
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_without_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != SET_TIMEOUTS_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (standby_timeout, remaining) = u16::try_parse(value)?;
-        let (suspend_timeout, remaining) = u16::try_parse(remaining)?;
-        let (off_timeout, remaining) = u16::try_parse(remaining)?;
-        let _ = remaining;
-        Ok(SetTimeoutsRequest {
-            standby_timeout,
-            suspend_timeout,
-            off_timeout,
-        })
-    }
-}
-impl Request for SetTimeoutsRequest {
-    type Reply = ();
-}
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn set_timeouts<Conn>(conn: &Conn, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -389,229 +109,58 @@ where
         suspend_timeout,
         off_timeout,
     };
-    request0.send(conn)
+    send_set_timeouts(request0, conn)
 }
 
-/// Opcode for the Enable request
-pub const ENABLE_REQUEST: u8 = 4;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EnableRequest;
-impl EnableRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            ENABLE_REQUEST,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_without_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != ENABLE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let _ = value;
-        Ok(EnableRequest
-        )
-    }
-}
-impl Request for EnableRequest {
-    type Reply = ();
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_enable<'c, Conn>(req: EnableRequest, conn: &'c Conn) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_without_reply(&slices, fds)
 }
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn enable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let request0 = EnableRequest;
-    request0.send(conn)
+    send_enable(request0, conn)
 }
 
-/// Opcode for the Disable request
-pub const DISABLE_REQUEST: u8 = 5;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct DisableRequest;
-impl DisableRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            DISABLE_REQUEST,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_without_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != DISABLE_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let _ = value;
-        Ok(DisableRequest
-        )
-    }
-}
-impl Request for DisableRequest {
-    type Reply = ();
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_disable<'c, Conn>(req: DisableRequest, conn: &'c Conn) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_without_reply(&slices, fds)
 }
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn disable<Conn>(conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let request0 = DisableRequest;
-    request0.send(conn)
+    send_disable(request0, conn)
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub struct DPMSMode(u16);
-impl DPMSMode {
-    pub const ON: Self = Self(0);
-    pub const STANDBY: Self = Self(1);
-    pub const SUSPEND: Self = Self(2);
-    pub const OFF: Self = Self(3);
-}
-impl From<DPMSMode> for u16 {
-    #[inline]
-    fn from(input: DPMSMode) -> Self {
-        input.0
-    }
-}
-impl From<DPMSMode> for Option<u16> {
-    #[inline]
-    fn from(input: DPMSMode) -> Self {
-        Some(input.0)
-    }
-}
-impl From<DPMSMode> for u32 {
-    #[inline]
-    fn from(input: DPMSMode) -> Self {
-        u32::from(input.0)
-    }
-}
-impl From<DPMSMode> for Option<u32> {
-    #[inline]
-    fn from(input: DPMSMode) -> Self {
-        Some(u32::from(input.0))
-    }
-}
-impl From<u8> for DPMSMode {
-    #[inline]
-    fn from(value: u8) -> Self {
-        Self(value.into())
-    }
-}
-impl From<u16> for DPMSMode {
-    #[inline]
-    fn from(value: u16) -> Self {
-        Self(value)
-    }
-}
-impl std::fmt::Debug for DPMSMode  {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let variants = [
-            (Self::ON.0.into(), "ON", "On"),
-            (Self::STANDBY.0.into(), "STANDBY", "Standby"),
-            (Self::SUSPEND.0.into(), "SUSPEND", "Suspend"),
-            (Self::OFF.0.into(), "OFF", "Off"),
-        ];
-        pretty_print_enum(fmt, self.0.into(), &variants)
-    }
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_force_level<'c, Conn>(req: ForceLevelRequest, conn: &'c Conn) -> Result<VoidCookie<'c, Conn>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_without_reply(&slices, fds)
 }
 
-/// Opcode for the ForceLevel request
-pub const FORCE_LEVEL_REQUEST: u8 = 6;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ForceLevelRequest {
-    pub power_level: DPMSMode,
-}
-impl ForceLevelRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let power_level_bytes = u16::from(self.power_level).serialize();
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            FORCE_LEVEL_REQUEST,
-            0,
-            0,
-            power_level_bytes[0],
-            power_level_bytes[1],
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<VoidCookie<'_, Conn>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_without_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != FORCE_LEVEL_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let (power_level, remaining) = u16::try_parse(value)?;
-        let power_level = power_level.into();
-        let _ = remaining;
-        Ok(ForceLevelRequest {
-            power_level,
-        })
-    }
-}
-impl Request for ForceLevelRequest {
-    type Reply = ();
-}
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn force_level<Conn>(conn: &Conn, power_level: DPMSMode) -> Result<VoidCookie<'_, Conn>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
@@ -619,128 +168,66 @@ where
     let request0 = ForceLevelRequest {
         power_level,
     };
-    request0.send(conn)
+    send_force_level(request0, conn)
 }
 
-/// Opcode for the Info request
-pub const INFO_REQUEST: u8 = 7;
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct InfoRequest;
-impl InfoRequest {
-    /// Serialize this request into bytes for the provided connection
-    fn serialize<'input, Conn>(self, conn: &Conn) -> Result<BufWithFds<PiecewiseBuf<'input>>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let extension_information = conn.extension_information(X11_EXTENSION_NAME)?
-            .ok_or(ConnectionError::UnsupportedExtension)?;
-        let length_so_far = 0;
-        let mut request0 = vec![
-            extension_information.major_opcode,
-            INFO_REQUEST,
-            0,
-            0,
-        ];
-        let length_so_far = length_so_far + request0.len();
-        assert_eq!(length_so_far % 4, 0);
-        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
-        request0[2..4].copy_from_slice(&length.to_ne_bytes());
-        Ok((vec![request0.into()], vec![]))
-    }
-    pub fn send<Conn>(self, conn: &Conn) -> Result<Cookie<'_, Conn, InfoReply>, ConnectionError>
-    where
-        Conn: RequestConnection + ?Sized,
-    {
-        let (bytes, fds) = self.serialize(conn)?;
-        let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
-        Ok(conn.send_request_with_reply(&slices, fds)?)
-    }
-    /// Parse this request given its header, its body, and any fds that go along with it
-    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
-        if header.minor_opcode != INFO_REQUEST {
-            return Err(ParseError::InvalidValue);
-        }
-        let _ = value;
-        Ok(InfoRequest
-        )
-    }
-}
-impl Request for InfoRequest {
-    type Reply = InfoReply;
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+fn send_info<'c, Conn>(req: InfoRequest, conn: &'c Conn) -> Result<Cookie<'c, Conn, InfoReply>, ConnectionError>
+where
+    Conn: RequestConnection + ?Sized,
+{
+    let (bytes, fds) = req.serialize(major_opcode(conn)?);
+    let slices = bytes.iter().map(|b| IoSlice::new(&*b)).collect::<Vec<_>>();
+    conn.send_request_with_reply(&slices, fds)
 }
+
+#[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
 pub fn info<Conn>(conn: &Conn) -> Result<Cookie<'_, Conn, InfoReply>, ConnectionError>
 where
     Conn: RequestConnection + ?Sized,
 {
     let request0 = InfoRequest;
-    request0.send(conn)
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct InfoReply {
-    pub sequence: u16,
-    pub length: u32,
-    pub power_level: DPMSMode,
-    pub state: bool,
-}
-impl TryParse for InfoReply {
-    fn try_parse(initial_value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        let remaining = initial_value;
-        let (response_type, remaining) = u8::try_parse(remaining)?;
-        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
-        let (sequence, remaining) = u16::try_parse(remaining)?;
-        let (length, remaining) = u32::try_parse(remaining)?;
-        let (power_level, remaining) = u16::try_parse(remaining)?;
-        let (state, remaining) = bool::try_parse(remaining)?;
-        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
-        if response_type != 1 {
-            return Err(ParseError::InvalidValue);
-        }
-        let power_level = power_level.into();
-        let result = InfoReply { sequence, length, power_level, state };
-        let _ = remaining;
-        let remaining = initial_value.get(32 + length as usize * 4..)
-            .ok_or(ParseError::InsufficientData)?;
-        Ok((result, remaining))
-    }
-}
-impl TryFrom<&[u8]> for InfoReply {
-    type Error = ParseError;
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        Ok(Self::try_parse(value)?.0)
-    }
+    send_info(request0, conn)
 }
 
 /// Extension trait defining the requests of this extension.
 pub trait ConnectionExt: RequestConnection {
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_get_version(&self, client_major_version: u16, client_minor_version: u16) -> Result<Cookie<'_, Self, GetVersionReply>, ConnectionError>
     {
         get_version(self, client_major_version, client_minor_version)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_capable(&self) -> Result<Cookie<'_, Self, CapableReply>, ConnectionError>
     {
         capable(self)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_get_timeouts(&self) -> Result<Cookie<'_, Self, GetTimeoutsReply>, ConnectionError>
     {
         get_timeouts(self)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_set_timeouts(&self, standby_timeout: u16, suspend_timeout: u16, off_timeout: u16) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         set_timeouts(self, standby_timeout, suspend_timeout, off_timeout)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_enable(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         enable(self)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_disable(&self) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         disable(self)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_force_level(&self, power_level: DPMSMode) -> Result<VoidCookie<'_, Self>, ConnectionError>
     {
         force_level(self, power_level)
     }
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn dpms_info(&self) -> Result<Cookie<'_, Self, InfoReply>, ConnectionError>
     {
         info(self)
@@ -0,0 +1,638 @@
+//! An asynchronous variant of `RustConnection`.
+
+use std::convert::TryFrom;
+use std::future::Future;
+use std::io::IoSlice;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(windows)]
+use std::os::windows::io::{AsRawSocket, RawSocket};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use async_io::Async;
+
+use super::inner::{ConnectionInner, PollReply};
+use super::{BlockingMode, BufWithFds, RawEventAndSeqNumber, ReplyFDKind};
+use super::{DefaultStream, RustConnection, Stream};
+use crate::connection::{
+    Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyOrIdError};
+use crate::protocol::xproto::Setup;
+use crate::protocol::Event;
+use crate::statistics::{Statistics, StatisticsConnection};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtensionInformation, X11Error};
+
+/// A stream that can be used with an [`AsyncRustConnection`].
+///
+/// This is any [`Stream`] that is backed by a file descriptor (or a socket on Windows) that can
+/// be registered with the operating system's event notification mechanism.
+#[cfg(unix)]
+pub trait AsyncStream: Stream + AsRawFd {}
+
+#[cfg(unix)]
+impl<S: Stream + AsRawFd> AsyncStream for S {}
+
+/// A stream that can be used with an [`AsyncRustConnection`].
+///
+/// This is any [`Stream`] that is backed by a file descriptor (or a socket on Windows) that can
+/// be registered with the operating system's event notification mechanism.
+#[cfg(windows)]
+pub trait AsyncStream: Stream + AsRawSocket {}
+
+#[cfg(windows)]
+impl<S: Stream + AsRawSocket> AsyncStream for S {}
+
+/// The file descriptor of the stream, registered for readiness notifications.
+///
+/// This does not own the file descriptor. It is owned by the stream inside of the connection.
+#[derive(Debug)]
+struct Registration(#[cfg(unix)] RawFd, #[cfg(windows)] RawSocket);
+
+#[cfg(unix)]
+impl AsRawFd for Registration {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+#[cfg(windows)]
+impl AsRawSocket for Registration {
+    fn as_raw_socket(&self) -> RawSocket {
+        self.0
+    }
+}
+
+/// A future that is implemented by a closure.
+struct PollFn<F>(F);
+
+/// Create a future that calls `f` when it is polled.
+fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T> + Unpin,
+{
+    PollFn(f)
+}
+
+impl<T, F> Future for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<T> + Unpin,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        (self.0)(cx)
+    }
+}
+
+/// A connection to an X11 server that can be used from `async` code.
+///
+/// This is a wrapper around a [`RustConnection`] and shares its bookkeeping of requests, replies,
+/// and events. Sending requests works just like with a `RustConnection`, so all the functions in
+/// the various `ConnectionExt` traits can be used unchanged. However, waiting for the results does
+/// not block the current thread:
+///
+/// - [`Cookie::reply_async`] is the counterpart of [`Cookie::reply`],
+/// - [`VoidCookie::check_async`] is the counterpart of [`VoidCookie::check`],
+/// - [`AsyncRustConnection::wait_for_event_async`] is the counterpart of
+///   [`Connection::wait_for_event`], and
+/// - [`AsyncRustConnection::events`] provides all events as a [`futures_core::Stream`].
+///
+/// The blocking functions of [`RequestConnection`] and [`Connection`] remain available and can be
+/// mixed with the `async` ones, even from different threads.
+///
+/// Readiness of the underlying stream is detected via the [`async-io`](https://docs.rs/async-io)
+/// reactor. This does not depend on a specific executor; it works with e.g. `tokio`, `async-std`,
+/// and `smol`.
+///
+/// Note that some operations still block for a short time: Sending a request blocks until it
+/// could be written to the output buffer and the first use of an extension waits for the reply to
+/// the `QueryExtension` request. The latter can be avoided by calling
+/// [`RequestConnection::prefetch_extension_information`] early and waiting for its result with
+/// [`AsyncRustConnection::extension_information_async`].
+#[derive(Debug)]
+pub struct AsyncRustConnection<S: AsyncStream = DefaultStream> {
+    // Must be dropped before `conn` so that the file descriptor is still open when it is
+    // unregistered.
+    registration: Async<Registration>,
+    conn: RustConnection<S>,
+}
+
+impl AsyncRustConnection<DefaultStream> {
+    /// Establish a new connection.
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    ///
+    /// Establishing the connection happens synchronously, just like with
+    /// [`RustConnection::connect`].
+    pub fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
+        let (conn, screen) = RustConnection::connect(dpy_name)?;
+        Ok((Self::for_connection(conn)?, screen))
+    }
+}
+
+impl<S: AsyncStream> AsyncRustConnection<S> {
+    /// Turn an established `RustConnection` into an `AsyncRustConnection`.
+    ///
+    /// This registers the connection's stream with the `async-io` reactor.
+    pub fn for_connection(conn: RustConnection<S>) -> std::io::Result<Self> {
+        #[cfg(unix)]
+        let registration = Registration(conn.stream().as_raw_fd());
+        #[cfg(windows)]
+        let registration = Registration(conn.stream().as_raw_socket());
+        Ok(AsyncRustConnection {
+            registration: Async::new(registration)?,
+            conn,
+        })
+    }
+
+    /// Returns a reference to the wrapped `RustConnection`.
+    pub fn connection(&self) -> &RustConnection<S> {
+        &self.conn
+    }
+
+    /// Returns a reference to the contained stream.
+    pub fn stream(&self) -> &S {
+        self.conn.stream()
+    }
+
+    /// Poll for something in `ConnectionInner`, reading from the stream as necessary.
+    ///
+    /// `f` is called to check if the thing that we are waiting for arrived. If it did not, the
+    /// current task is woken up when new packets were received or the stream becomes readable.
+    fn poll_with<T>(
+        &self,
+        cx: &mut Context<'_>,
+        mut f: impl FnMut(&mut ConnectionInner) -> Option<T>,
+    ) -> Poll<Result<T, ConnectionError>> {
+        let mut inner = self.conn.inner.lock().unwrap();
+        loop {
+            if let Some(result) = f(&mut inner) {
+                return Poll::Ready(Ok(result));
+            }
+            inner = self
+                .conn
+                .read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
+            if let Some(result) = f(&mut inner) {
+                return Poll::Ready(Ok(result));
+            }
+
+            // Get woken up when someone else reads the packet that we are waiting for...
+            inner.register_waker(cx.waker());
+            // ...or when we can read something ourselves.
+            match self.registration.poll_readable(cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err.into())),
+                Poll::Pending => {
+                    self.conn.statistics.blocking_wait();
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    /// Wait for the reply or error to the request with the given sequence number.
+    pub(crate) async fn wait_for_reply_with_fds_raw_async(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds, Vec<u8>>, ConnectionError> {
+        // Ensure the request is sent
+        self.conn.flush()?;
+        let reply =
+            poll_fn(|cx| self.poll_with(cx, |inner| inner.poll_for_reply_or_error(sequence)))
+                .await?;
+        if reply.0[0] == 0 {
+            Ok(ReplyOrError::Error(reply.0))
+        } else {
+            Ok(ReplyOrError::Reply(reply))
+        }
+    }
+
+    /// Wait for the reply or error to the request with the given sequence number.
+    pub(crate) async fn wait_for_reply_or_raw_error_async(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        match self.wait_for_reply_with_fds_raw_async(sequence).await? {
+            ReplyOrError::Reply((reply, _fds)) => Ok(ReplyOrError::Reply(reply)),
+            ReplyOrError::Error(e) => Ok(ReplyOrError::Error(e)),
+        }
+    }
+
+    /// Wait for the reply to the request with the given sequence number.
+    ///
+    /// Errors are handled as events.
+    pub(crate) async fn wait_for_reply_async(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        // Ensure the request is sent
+        self.conn.flush()?;
+        poll_fn(|cx| {
+            self.poll_with(cx, |inner| match inner.poll_for_reply(sequence) {
+                PollReply::TryAgain => None,
+                PollReply::NoReply => Some(None),
+                PollReply::Reply(buffer) => Some(Some(buffer)),
+            })
+        })
+        .await
+    }
+
+    /// Check whether the request with the given sequence number caused an error.
+    pub(crate) async fn check_for_raw_error_async(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        {
+            let mut inner = self.conn.inner.lock().unwrap();
+            if inner.prepare_check_for_reply_or_error(sequence) {
+                inner = self.conn.send_sync(inner)?;
+                assert!(!inner.prepare_check_for_reply_or_error(sequence));
+            }
+            // Ensure the request is sent
+            let _inner = self.conn.flush_impl(inner, None)?;
+        }
+        poll_fn(|cx| {
+            self.poll_with(cx, |inner| {
+                match inner.poll_check_for_reply_or_error(sequence) {
+                    PollReply::TryAgain => None,
+                    PollReply::NoReply => Some(None),
+                    PollReply::Reply(buffer) => Some(Some(buffer)),
+                }
+            })
+        })
+        .await
+    }
+
+    /// Get information about an extension without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`RequestConnection::extension_information`].
+    pub async fn extension_information_async(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)?;
+        let sequence = self
+            .conn
+            .extension_manager
+            .lock()
+            .unwrap()
+            .pending_sequence_number(extension_name);
+        if let Some(sequence) = sequence {
+            // Wait until the reply to QueryExtension was received so that the extension manager
+            // does not block when it gets the reply.
+            self.conn.flush()?;
+            poll_fn(|cx| {
+                self.poll_with(cx, |inner| {
+                    if inner.was_handled(sequence) {
+                        Some(())
+                    } else {
+                        None
+                    }
+                })
+            })
+            .await?;
+        }
+        self.conn.extension_information(extension_name)
+    }
+
+    /// Poll for the next event, reading from the stream as necessary.
+    fn poll_for_raw_event_with_sequence(
+        &self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<RawEventAndSeqNumber, ConnectionError>> {
+        self.poll_with(cx, ConnectionInner::poll_for_event_with_sequence)
+    }
+
+    /// Poll for the next event and parse it.
+    fn poll_for_event(&self, cx: &mut Context<'_>) -> Poll<Result<Event, ConnectionError>> {
+        self.poll_for_raw_event_with_sequence(cx)
+            .map(|result| Ok(self.parse_event(result?.0.as_ref())?))
+    }
+
+    /// Wait for a new raw/unparsed event from the X11 server without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`Connection::wait_for_raw_event_with_sequence`].
+    pub async fn wait_for_raw_event_with_sequence_async(
+        &self,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        poll_fn(|cx| self.poll_for_raw_event_with_sequence(cx)).await
+    }
+
+    /// Wait for a new event from the X11 server without blocking the current thread.
+    ///
+    /// This is the `async` counterpart of [`Connection::wait_for_event`].
+    pub async fn wait_for_event_async(&self) -> Result<Event, ConnectionError> {
+        poll_fn(|cx| self.poll_for_event(cx)).await
+    }
+
+    /// Get a [`futures_core::Stream`] of the events that the X11 server sends.
+    ///
+    /// The stream never ends. Errors are returned as items of the stream; after an error, the
+    /// connection is most likely broken.
+    pub fn events(&self) -> EventStream<'_, S> {
+        EventStream { conn: self }
+    }
+}
+
+impl<S: AsyncStream> RequestConnection for AsyncRustConnection<S> {
+    type Buf = Vec<u8>;
+
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+    fn send_request_with_reply<Reply>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<Cookie<'_, Self, Reply>, ConnectionError>
+    where
+        Reply: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+    {
+        Ok(Cookie::new(
+            self,
+            self.conn
+                .send_request(bufs, fds, ReplyFDKind::ReplyWithoutFDs)?,
+        ))
+    }
+
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+    fn send_request_with_reply_with_fds<Reply>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<CookieWithFds<'_, Self, Reply>, ConnectionError>
+    where
+        Reply: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+    {
+        Ok(CookieWithFds::new(
+            self,
+            self.conn
+                .send_request(bufs, fds, ReplyFDKind::ReplyWithFDs)?,
+        ))
+    }
+
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        Ok(VoidCookie::new(
+            self,
+            self.conn.send_request(bufs, fds, ReplyFDKind::NoReply)?,
+        ))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, kind: RequestKind, mode: DiscardMode) {
+        self.conn.discard_reply(sequence, kind, mode)
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        self.conn.prefetch_extension_information(extension_name)
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        self.conn.extension_information(extension_name)
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        self.conn.wait_for_reply_or_raw_error(sequence)
+    }
+
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        self.conn
+            .wait_for_reply_or_raw_error_timeout(sequence, timeout)
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.conn.wait_for_reply(sequence)
+    }
+
+    fn wait_for_reply_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.conn.wait_for_reply_timeout(sequence, timeout)
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds, Vec<u8>>, ConnectionError> {
+        self.conn.wait_for_reply_with_fds_raw(sequence)
+    }
+
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<BufWithFds, Vec<u8>>, ConnectionError> {
+        self.conn
+            .wait_for_reply_with_fds_raw_timeout(sequence, timeout)
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.conn.check_for_raw_error(sequence)
+    }
+
+    fn check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.conn.check_for_raw_error_timeout(sequence, timeout)
+    }
+
+    fn maximum_request_bytes(&self) -> usize {
+        self.conn.maximum_request_bytes()
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {
+        self.conn.prefetch_maximum_request_bytes()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        self.conn.parse_error(error)
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        self.conn.parse_event(event)
+    }
+}
+
+impl<S: AsyncStream> Connection for AsyncRustConnection<S> {
+    fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        self.conn.wait_for_raw_event_with_sequence()
+    }
+
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        self.conn.wait_for_raw_event_with_sequence_timeout(timeout)
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
+        self.conn.poll_for_raw_event_with_sequence()
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        self.conn.flush()
+    }
+
+    fn setup(&self) -> &Setup {
+        self.conn.setup()
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        self.conn.generate_id()
+    }
+}
+
+impl<S: AsyncStream> StatisticsConnection for AsyncRustConnection<S> {
+    fn statistics(&self) -> Statistics {
+        self.conn.statistics()
+    }
+
+    fn count_requests_by_opcode(&self) {
+        self.conn.count_requests_by_opcode();
+    }
+}
+
+/// A [`futures_core::Stream`] of the events that an X11 server sends.
+///
+/// This type is returned by [`AsyncRustConnection::events`].
+#[derive(Debug)]
+pub struct EventStream<'a, S: AsyncStream> {
+    conn: &'a AsyncRustConnection<S>,
+}
+
+impl<S: AsyncStream> futures_core::Stream for EventStream<'_, S> {
+    type Item = Result<Event, ConnectionError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.conn.poll_for_event(cx).map(Some)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+
+    use super::AsyncRustConnection;
+    use crate::errors::ReplyError;
+    use crate::protocol::xproto::{ConnectionExt, ImageOrder, Setup};
+    use crate::protocol::Event;
+    use crate::rust_connection::{DefaultStream, RustConnection};
+
+    fn setup() -> Setup {
+        Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0,
+            resource_id_mask: 0xff,
+            motion_buffer_size: 0,
+            maximum_request_length: 0,
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 0,
+            bitmap_format_scanline_pad: 0,
+            min_keycode: 0,
+            max_keycode: 0,
+            vendor: vec![],
+            pixmap_formats: vec![],
+            roots: vec![],
+        }
+    }
+
+    fn connect() -> (AsyncRustConnection, UnixStream) {
+        let (client, server) = UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = RustConnection::for_connected_stream(stream, setup()).unwrap();
+        (AsyncRustConnection::for_connection(conn).unwrap(), server)
+    }
+
+    fn packet(kind: u8, detail: u8, sequence: u16) -> [u8; 32] {
+        let mut packet = [0; 32];
+        packet[0] = kind;
+        packet[1] = detail;
+        packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+        packet
+    }
+
+    #[test]
+    fn reply_and_event() {
+        let (conn, mut server) = connect();
+        let server = thread::spawn(move || {
+            // Read the GetInputFocus request
+            let mut request = [0; 4];
+            server.read_exact(&mut request).unwrap();
+            // Send an event first (MapNotify), then the reply
+            server.write_all(&packet(19, 0, 0)).unwrap();
+            server.write_all(&packet(1, 2, 1)).unwrap();
+            server
+        });
+
+        let cookie = conn.get_input_focus().unwrap();
+        let reply = async_io::block_on(cookie.reply_async()).unwrap();
+        assert_eq!(reply.sequence, 1);
+        assert_eq!(u8::from(reply.revert_to), 2);
+
+        match async_io::block_on(conn.wait_for_event_async()).unwrap() {
+            Event::MapNotify(event) => assert_eq!(event.sequence, 0),
+            event => panic!("Unexpected event {:?}", event),
+        }
+        drop(server.join().unwrap());
+    }
+
+    #[test]
+    fn check_error() {
+        let (conn, mut server) = connect();
+        let server = thread::spawn(move || {
+            // Read the NoOperation request and the GetInputFocus request used for syncing
+            let mut request = [0; 8];
+            server.read_exact(&mut request).unwrap();
+            // Send an error for the first request and the reply for the sync
+            server.write_all(&packet(0, 1, 1)).unwrap();
+            server.write_all(&packet(1, 0, 2)).unwrap();
+            server
+        });
+
+        let cookie = conn.no_operation().unwrap();
+        match async_io::block_on(cookie.check_async()) {
+            Err(ReplyError::X11Error(error)) => {
+                assert_eq!(error.error_code, 1);
+                assert_eq!(error.sequence, 1);
+            }
+            result => panic!("Unexpected result {:?}", result),
+        }
+        drop(server.join().unwrap());
+    }
+}
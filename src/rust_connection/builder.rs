@@ -0,0 +1,178 @@
+//! A builder for configuring a `RustConnection` before connecting.
+
+use super::error_handler::{ErrorHandler, ErrorHandlerState};
+use super::observer::{Observer, ObserverState};
+use super::{inner, parse_display, xauth, DefaultStream, RustConnection, Stream};
+use crate::errors::ConnectError;
+use crate::protocol::xproto::Setup;
+
+/// A builder for a [`RustConnection`].
+///
+/// The constructors of [`RustConnection`] use the default configuration. This builder allows to
+/// change the configuration before the connection is established.
+///
+/// # Example
+///
+/// ```no_run
+/// use x11rb::rust_connection::{ConnectionBuilder, Observer, ObservedRequest};
+///
+/// #[derive(Debug)]
+/// struct PrintRequests;
+///
+/// impl Observer for PrintRequests {
+///     fn request(&self, request: &ObservedRequest<'_>) {
+///         println!("{}: {:?}", request.sequence_number(), request.parse());
+///     }
+/// }
+///
+/// let (conn, screen) = ConnectionBuilder::new()
+///     .observer(PrintRequests)
+///     .connect(None)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct ConnectionBuilder {
+    observer: Option<ObserverState>,
+    error_handler: Option<ErrorHandlerState>,
+}
+
+impl ConnectionBuilder {
+    /// Create a new builder with the default configuration.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Install an observer that is notified about all packets that the connection sends and
+    /// receives.
+    ///
+    /// By default, no observer is installed.
+    pub fn observer(mut self, observer: impl Observer + 'static) -> Self {
+        self.observer = Some(ObserverState::new(Box::new(observer)));
+        self
+    }
+
+    /// Install a handler for X11 errors that no cookie waits for.
+    ///
+    /// By default, no error handler is installed and such errors are delivered as events. See
+    /// [`ErrorHandler`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use x11rb::rust_connection::ConnectionBuilder;
+    ///
+    /// let (conn, screen) = ConnectionBuilder::new()
+    ///     .error_handler(|error: &_, request_name: Option<&_>, location: Option<&_>| {
+    ///         let request_name = request_name.unwrap_or("unknown request");
+    ///         match location {
+    ///             Some(location) => {
+    ///                 eprintln!("{} in {} (sent from {})", error, request_name, location)
+    ///             }
+    ///             None => eprintln!("{} in {}", error, request_name),
+    ///         }
+    ///     })
+    ///     .connect(None)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn error_handler(mut self, error_handler: impl ErrorHandler + 'static) -> Self {
+        self.error_handler = Some(ErrorHandlerState::new(Box::new(error_handler)));
+        self
+    }
+
+    /// Establish a new connection.
+    ///
+    /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
+    ///
+    /// See [`RustConnection::connect`].
+    pub fn connect(
+        self,
+        dpy_name: Option<&str>,
+    ) -> Result<(RustConnection<DefaultStream>, usize), ConnectError> {
+        self.connect_wrapped(dpy_name, |stream| stream)
+    }
+
+    /// Establish a new connection, wrapping the stream to the X11 server.
+    ///
+    /// This works like [`ConnectionBuilder::connect`], but `wrap` is called with the stream
+    /// before the connection is set up. This allows to use a [`Stream`] that adds some behaviour
+    /// to a [`DefaultStream`], for example logging of all traffic.
+    pub fn connect_wrapped<S, F>(
+        self,
+        dpy_name: Option<&str>,
+        wrap: F,
+    ) -> Result<(RustConnection<S>, usize), ConnectError>
+    where
+        S: Stream,
+        F: FnOnce(DefaultStream) -> S,
+    {
+        // Parse display information
+        let parsed_display = parse_display(dpy_name).ok_or(ConnectError::DisplayParsingError)?;
+
+        // Establish connection
+        let protocol = parsed_display.protocol.as_deref();
+        let stream =
+            DefaultStream::connect(&*parsed_display.host, protocol, parsed_display.display)?;
+        let screen = parsed_display.screen.into();
+
+        let (family, address) = stream.peer_addr()?;
+        let (auth_name, auth_data) = xauth::get_auth(family, &address, parsed_display.display)
+            // Ignore all errors while determining auth; instead we just try without auth info.
+            .unwrap_or(None)
+            .and_then(|auth| xauth::compute_auth_data(auth, stream.local_addr().ok()?))
+            .unwrap_or_else(|| (Vec::new(), Vec::new()));
+
+        Ok((
+            self.connect_to_stream_with_auth_info(wrap(stream), screen, auth_name, auth_data)?,
+            screen,
+        ))
+    }
+
+    /// Establish a new connection to the given stream.
+    ///
+    /// See [`RustConnection::connect_to_stream`].
+    pub fn connect_to_stream<S: Stream>(
+        self,
+        stream: S,
+        screen: usize,
+    ) -> Result<RustConnection<S>, ConnectError> {
+        self.connect_to_stream_with_auth_info(stream, screen, Vec::new(), Vec::new())
+    }
+
+    /// Establish a new connection to the given stream, using the given authentication
+    /// information.
+    ///
+    /// See [`RustConnection::connect_to_stream_with_auth_info`].
+    pub fn connect_to_stream_with_auth_info<S: Stream>(
+        self,
+        stream: S,
+        screen: usize,
+        auth_name: Vec<u8>,
+        auth_data: Vec<u8>,
+    ) -> Result<RustConnection<S>, ConnectError> {
+        super::write_setup(&stream, auth_name, auth_data)?;
+        let setup = super::read_setup(&stream)?;
+
+        // Check that we got a valid screen number
+        if screen >= setup.roots.len() {
+            return Err(ConnectError::InvalidScreen);
+        }
+
+        // Success! Set up our state
+        self.for_connected_stream(stream, setup)
+    }
+
+    /// Establish a new connection for an already connected stream.
+    ///
+    /// See [`RustConnection::for_connected_stream`].
+    pub fn for_connected_stream<S: Stream>(
+        self,
+        stream: S,
+        setup: Setup,
+    ) -> Result<RustConnection<S>, ConnectError> {
+        let mut inner = inner::ConnectionInner::new();
+        if let Some(error_handler) = self.error_handler {
+            inner.set_error_handler(error_handler);
+        }
+        RustConnection::for_inner(stream, inner, setup, self.observer)
+    }
+}
@@ -0,0 +1,115 @@
+//! Handling X11 errors that no cookie is waiting for.
+
+use std::panic::Location;
+
+use crate::errors::ParseError;
+use crate::extension_manager::ExtensionManager;
+use crate::x11_utils::X11Error;
+
+/// A handler for X11 errors that would otherwise be delivered as events.
+///
+/// An error handler can be installed with
+/// [`ConnectionBuilder::error_handler`](super::ConnectionBuilder::error_handler). It is similar to
+/// `XSetErrorHandler` in Xlib. When a request causes an error and nothing waits for this error,
+/// the error is passed to the error handler instead of being delivered as [`Event::Error`]. This
+/// happens for example when the cookie of a request is dropped or when
+/// [`Cookie::reply_unchecked`](crate::cookie::Cookie::reply_unchecked) is used. Errors of requests
+/// whose errors are explicitly ignored, for example via
+/// [`VoidCookie::ignore_error`](crate::cookie::VoidCookie::ignore_error), are not passed to the
+/// error handler.
+///
+/// The error handler also gets the name of the request that caused the error, e.g. `MapWindow` or
+/// `RandR::SetCrtcConfig`, if it is known. When the `request-location` feature is enabled and
+/// debug assertions are active, the location in the source code where the request was sent from
+/// is also recorded. This location is `None` otherwise.
+///
+/// The error handler is called while the connection holds internal locks. Thus, it must not use
+/// the connection and should return quickly.
+///
+/// This trait is implemented for closures, so that a closure can be used as an error handler.
+///
+/// [`Event::Error`]: crate::protocol::Event::Error
+pub trait ErrorHandler: Send + Sync {
+    /// Handle an X11 error.
+    fn handle_error(
+        &self,
+        error: &X11Error,
+        request_name: Option<&'static str>,
+        location: Option<&'static Location<'static>>,
+    );
+}
+
+impl<F> ErrorHandler for F
+where
+    F: Fn(&X11Error, Option<&'static str>, Option<&'static Location<'static>>) + Send + Sync,
+{
+    fn handle_error(
+        &self,
+        error: &X11Error,
+        request_name: Option<&'static str>,
+        location: Option<&'static Location<'static>>,
+    ) {
+        self(error, request_name, location)
+    }
+}
+
+/// An installed [`ErrorHandler`] together with the information needed for parsing errors.
+pub(crate) struct ErrorHandlerState {
+    handler: Box<dyn ErrorHandler>,
+    // A copy of the connection's `ExtensionManager`. The original cannot be used, because its
+    // mutex might already be held while errors are received.
+    ext_info: ExtensionManager,
+}
+
+impl std::fmt::Debug for ErrorHandlerState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorHandlerState")
+            .field("ext_info", &self.ext_info)
+            .finish()
+    }
+}
+
+impl ErrorHandlerState {
+    pub(crate) fn new(handler: Box<dyn ErrorHandler>) -> Self {
+        Self {
+            handler,
+            ext_info: Default::default(),
+        }
+    }
+
+    /// Update the information about known extensions.
+    pub(crate) fn update_ext_info(&mut self, ext_info: &ExtensionManager) {
+        self.ext_info = ext_info.clone();
+    }
+
+    /// Parse the given error and pass it to the error handler.
+    pub(crate) fn handle(
+        &self,
+        error: &[u8],
+        location: Option<&'static Location<'static>>,
+    ) -> Result<(), ParseError> {
+        let error = X11Error::try_parse(error, &self.ext_info)?;
+        let request_name = error.request_name(&self.ext_info);
+        self.handler.handle_error(&error, request_name, location);
+        Ok(())
+    }
+}
+
+/// Get the location that the current request is sent from.
+///
+/// This is only recorded when the `request-location` feature is enabled and debug assertions are
+/// active.
+#[cfg(all(feature = "request-location", debug_assertions))]
+#[track_caller]
+pub(crate) fn request_location() -> Option<&'static Location<'static>> {
+    Some(Location::caller())
+}
+
+/// Get the location that the current request is sent from.
+///
+/// This is only recorded when the `request-location` feature is enabled and debug assertions are
+/// active.
+#[cfg(not(all(feature = "request-location", debug_assertions)))]
+pub(crate) fn request_location() -> Option<&'static Location<'static>> {
+    None
+}
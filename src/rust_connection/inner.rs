@@ -1,9 +1,14 @@
 //! A pure-rust implementation of a connection to an X11 server.
 
 use std::collections::VecDeque;
+use std::panic::Location;
+#[cfg(feature = "async")]
+use std::task::Waker;
 
+use super::error_handler::ErrorHandlerState;
 use super::{BufWithFds, RawEventAndSeqNumber, ReplyFDKind, WriteBuffer};
 use crate::connection::{DiscardMode, SequenceNumber};
+use crate::extension_manager::ExtensionManager;
 use crate::utils::RawFdContainer;
 
 #[derive(Debug, Clone)]
@@ -21,6 +26,7 @@ struct SentRequest {
     seqno: SequenceNumber,
     discard_mode: Option<DiscardMode>,
     has_fds: bool,
+    location: Option<&'static Location<'static>>,
 }
 
 #[derive(Debug)]
@@ -37,14 +43,26 @@ pub(crate) struct ConnectionInner {
     last_sequence_read: SequenceNumber,
     // Events that were read, but not yet returned to the API user
     pending_events: VecDeque<(SequenceNumber, Vec<u8>)>,
-    // Replies that were read, but not yet returned to the API user
-    pending_replies: VecDeque<(SequenceNumber, BufWithFds)>,
+    // Replies that were read, but not yet returned to the API user, together with the location
+    // that their request was sent from
+    pending_replies: VecDeque<(
+        SequenceNumber,
+        BufWithFds,
+        Option<&'static Location<'static>>,
+    )>,
 
     // FDs that were read, but not yet assigned to any reply
     pending_fds: VecDeque<RawFdContainer>,
 
     // Buffer used for writing into the stream.
     pub(super) write_buffer: WriteBuffer,
+
+    // Handler for errors that would otherwise be treated as events
+    error_handler: Option<ErrorHandlerState>,
+
+    // Tasks that wait for a packet to be received
+    #[cfg(feature = "async")]
+    wakers: Vec<Waker>,
 }
 
 impl ConnectionInner {
@@ -62,14 +80,35 @@ impl ConnectionInner {
             pending_replies: VecDeque::new(),
             pending_fds: VecDeque::new(),
             write_buffer: WriteBuffer::new(),
+            error_handler: None,
+            #[cfg(feature = "async")]
+            wakers: Vec::new(),
+        }
+    }
+
+    /// Install a handler for errors that would otherwise be treated as events.
+    pub(crate) fn set_error_handler(&mut self, error_handler: ErrorHandlerState) {
+        self.error_handler = Some(error_handler);
+    }
+
+    /// Update the information about known extensions that is used for parsing errors.
+    pub(crate) fn update_ext_info(&mut self, ext_info: &ExtensionManager) {
+        if let Some(error_handler) = &mut self.error_handler {
+            error_handler.update_ext_info(ext_info);
         }
     }
 
     /// Send a request to the X11 server.
     ///
+    /// The `location` is the place in the source code that the request was sent from, if known.
+    ///
     /// When this returns `None`, a sync with the server is necessary. Afterwards, the caller
     /// should try again.
-    pub(crate) fn send_request(&mut self, kind: ReplyFDKind) -> Option<SequenceNumber> {
+    pub(crate) fn send_request(
+        &mut self,
+        kind: ReplyFDKind,
+        location: Option<&'static Location<'static>>,
+    ) -> Option<SequenceNumber> {
         let has_response = match kind {
             ReplyFDKind::NoReply => false,
             ReplyFDKind::ReplyWithoutFDs => true,
@@ -95,6 +134,7 @@ impl ConnectionInner {
             seqno,
             discard_mode: None,
             has_fds: kind == ReplyFDKind::ReplyWithFDs,
+            location,
         };
         self.sent_requests.push_back(sent_request);
 
@@ -116,10 +156,10 @@ impl ConnectionInner {
                         .filter(|r| r.0 == seqno)
                         .is_some()
                     {
-                        if let Some((_, packet)) = self.pending_replies.remove(index) {
+                        if let Some((_, packet, location)) = self.pending_replies.remove(index) {
                             if packet.0[0] == 0 {
                                 // This is an error
-                                self.pending_events.push_back((seqno, packet.0));
+                                self.unhandled_error(seqno, packet.0, location);
                             }
                         }
                     }
@@ -166,7 +206,9 @@ impl ConnectionInner {
     /// An X11 packet was received from the connection and is now enqueued into our state.
     ///
     /// Any FDs that were received must already be enqueued before this can be called.
-    pub(crate) fn enqueue_packet(&mut self, packet: Vec<u8>) {
+    ///
+    /// Returns the sequence number of the packet.
+    pub(crate) fn enqueue_packet(&mut self, packet: Vec<u8>) -> SequenceNumber {
         let kind = packet[0];
 
         // extract_sequence_number() updates our state and is thus important to call even when we
@@ -182,7 +224,11 @@ impl ConnectionInner {
             }
             let _ = self.sent_requests.pop_front();
         }
-        let request = self.sent_requests.front().filter(|r| r.seqno == seqno);
+        let request = self
+            .sent_requests
+            .front()
+            .filter(|r| r.seqno == seqno)
+            .copied();
 
         if kind == 0 {
             // It is an error. Let's see where we have to send it to.
@@ -191,15 +237,17 @@ impl ConnectionInner {
                     Some(DiscardMode::DiscardReplyAndError) => { /* This error should be ignored */
                     }
                     Some(DiscardMode::DiscardReply) => {
-                        self.pending_events.push_back((seqno, packet))
+                        self.unhandled_error(seqno, packet, request.location)
                     }
-                    None => self
-                        .pending_replies
-                        .push_back((seqno, (packet, Vec::new()))),
+                    None => self.pending_replies.push_back((
+                        seqno,
+                        (packet, Vec::new()),
+                        request.location,
+                    )),
                 }
             } else {
                 // Unexpected error, send to main loop
-                self.pending_events.push_back((seqno, packet));
+                self.unhandled_error(seqno, packet, None);
             }
         } else if kind == 1 {
             let fds = if request.filter(|r| r.has_fds).is_some() {
@@ -223,12 +271,35 @@ impl ConnectionInner {
             if request.filter(|r| r.discard_mode.is_some()).is_some() {
                 // This reply should be discarded
             } else {
-                self.pending_replies.push_back((seqno, (packet, fds)));
+                self.pending_replies.push_back((
+                    seqno,
+                    (packet, fds),
+                    request.and_then(|r| r.location),
+                ));
             }
         } else {
             // It is an event
             self.pending_events.push_back((seqno, packet));
         }
+        seqno
+    }
+
+    /// Handle an error that no cookie waits for.
+    ///
+    /// The error is passed to the error handler, if one is installed. Otherwise, it is treated as
+    /// an event.
+    fn unhandled_error(
+        &mut self,
+        seqno: SequenceNumber,
+        error: Vec<u8>,
+        location: Option<&'static Location<'static>>,
+    ) {
+        if let Some(error_handler) = &self.error_handler {
+            if error_handler.handle(&error, location).is_ok() {
+                return;
+            }
+        }
+        self.pending_events.push_back((seqno, error));
     }
 
     /// Check if the server already sent an answer to the request with the given sequence number.
@@ -239,9 +310,20 @@ impl ConnectionInner {
         &mut self,
         sequence: SequenceNumber,
     ) -> Option<BufWithFds> {
-        for (index, (seqno, _packet)) in self.pending_replies.iter().enumerate() {
+        self.poll_for_reply_or_error_with_location(sequence)
+            .map(|(reply, _location)| reply)
+    }
+
+    /// Like `poll_for_reply_or_error()`, but also returns the location that the request was sent
+    /// from.
+    fn poll_for_reply_or_error_with_location(
+        &mut self,
+        sequence: SequenceNumber,
+    ) -> Option<(BufWithFds, Option<&'static Location<'static>>)> {
+        for (index, (seqno, _packet, _location)) in self.pending_replies.iter().enumerate() {
             if *seqno == sequence {
-                return Some(self.pending_replies.remove(index).unwrap().1);
+                let (_, packet, location) = self.pending_replies.remove(index).unwrap();
+                return Some((packet, location));
             }
         }
         None
@@ -283,12 +365,12 @@ impl ConnectionInner {
 
     /// Find the reply for the request with the given sequence number.
     ///
-    /// If the request caused an error, that error will be handled as an event. This means that a
-    /// latter call to `poll_for_event()` will return it.
+    /// If the request caused an error, that error will be passed to the error handler or handled
+    /// as an event. In the latter case, a latter call to `poll_for_event()` will return it.
     pub(crate) fn poll_for_reply(&mut self, sequence: SequenceNumber) -> PollReply {
-        if let Some(reply) = self.poll_for_reply_or_error(sequence) {
+        if let Some((reply, location)) = self.poll_for_reply_or_error_with_location(sequence) {
             if reply.0[0] == 0 {
-                self.pending_events.push_back((sequence, reply.0));
+                self.unhandled_error(sequence, reply.0, location);
                 PollReply::NoReply
             } else {
                 PollReply::Reply(reply.0)
@@ -304,6 +386,28 @@ impl ConnectionInner {
             .pop_front()
             .map(|(seqno, event)| (event, seqno))
     }
+
+    /// Check if the server already handled the request with the given sequence number.
+    ///
+    /// This is the case if a packet for this or a later request was received.
+    #[cfg(feature = "async")]
+    pub(crate) fn was_handled(&self, sequence: SequenceNumber) -> bool {
+        self.last_sequence_read >= sequence
+    }
+
+    /// Remember a task that should be woken up when new packets are enqueued.
+    #[cfg(feature = "async")]
+    pub(crate) fn register_waker(&mut self, waker: &Waker) {
+        if !self.wakers.iter().any(|w| w.will_wake(waker)) {
+            self.wakers.push(waker.clone());
+        }
+    }
+
+    /// Wake up all tasks that are waiting for new packets.
+    #[cfg(feature = "async")]
+    pub(crate) fn wake_all(&mut self) {
+        self.wakers.drain(..).for_each(Waker::wake);
+    }
 }
 
 #[cfg(test)]
@@ -318,17 +422,17 @@ mod test {
         let mut connection = ConnectionInner::new();
 
         for num in 1..0x10000 {
-            let seqno = connection.send_request(ReplyFDKind::NoReply);
+            let seqno = connection.send_request(ReplyFDKind::NoReply, None);
             assert_eq!(Some(num), seqno);
         }
         // request 0x10000 should be a sync, hence the next one is 0x10001
-        let seqno = connection.send_request(ReplyFDKind::NoReply);
+        let seqno = connection.send_request(ReplyFDKind::NoReply, None);
         assert_eq!(None, seqno);
 
-        let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs);
+        let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs, None);
         assert_eq!(Some(0x10000), seqno);
 
-        let seqno = connection.send_request(ReplyFDKind::NoReply);
+        let seqno = connection.send_request(ReplyFDKind::NoReply, None);
         assert_eq!(Some(0x10001), seqno);
     }
 
@@ -340,7 +444,7 @@ mod test {
         let mut connection = ConnectionInner::new();
 
         for num in 1..=0x10001 {
-            let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs);
+            let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs, None);
             assert_eq!(Some(num), seqno);
         }
     }
@@ -354,11 +458,11 @@ mod test {
         let mut connection = ConnectionInner::new();
 
         for num in 1..0x10000 {
-            let seqno = connection.send_request(ReplyFDKind::NoReply);
+            let seqno = connection.send_request(ReplyFDKind::NoReply, None);
             assert_eq!(Some(num), seqno);
         }
 
-        let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs);
+        let seqno = connection.send_request(ReplyFDKind::ReplyWithoutFDs, None);
         assert_eq!(Some(0x10000), seqno);
     }
 }
@@ -3,6 +3,7 @@
 use std::convert::{TryFrom, TryInto};
 use std::io::IoSlice;
 use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
 
 use crate::connection::{
     compute_length_field, Connection, DiscardMode, ReplyOrError, RequestConnection, RequestKind,
@@ -13,19 +14,33 @@ pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, R
 use crate::extension_manager::ExtensionManager;
 use crate::protocol::bigreq::{ConnectionExt as _, EnableReply};
 use crate::protocol::xproto::{Setup, SetupRequest, GET_INPUT_FOCUS_REQUEST};
+use crate::statistics::{Statistics, StatisticsCollector, StatisticsConnection};
 use crate::utils::RawFdContainer;
-use crate::x11_utils::{ExtensionInformation, Serialize};
+use crate::x11_utils::{ByteOrder, ExtensionInformation, Serialize};
 
+#[cfg(feature = "async")]
+mod asynchronous;
+mod builder;
+mod des;
+mod error_handler;
 mod id_allocator;
 mod inner;
+mod observer;
 mod packet_reader;
 mod parse_display;
 mod stream;
 mod write_buffer;
-mod xauth;
+pub mod xauth;
 
+#[cfg(feature = "async")]
+pub use asynchronous::{AsyncRustConnection, AsyncStream, EventStream};
+pub use builder::ConnectionBuilder;
+pub use error_handler::ErrorHandler;
 use inner::PollReply;
+use observer::ObserverState;
+pub use observer::{ObservedPacket, ObservedRequest, Observer};
 use packet_reader::PacketReader;
+pub use parse_display::{parse_display, ParsedDisplay};
 pub use stream::{DefaultStream, PollMode, Stream};
 use write_buffer::WriteBuffer;
 
@@ -54,6 +69,7 @@ pub(crate) enum ReplyFDKind {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum BlockingMode {
     Blocking,
+    BlockingUntil(Instant),
     NonBlocking,
 }
 
@@ -77,6 +93,8 @@ pub struct RustConnection<S: Stream = DefaultStream> {
     setup: Setup,
     extension_manager: Mutex<ExtensionManager>,
     maximum_request_bytes: Mutex<MaxRequestBytes>,
+    observer: Option<ObserverState>,
+    statistics: StatisticsCollector,
 }
 
 // Locking rules
@@ -112,37 +130,23 @@ impl RustConnection<DefaultStream> {
     ///
     /// If no `dpy_name` is provided, the value from `$DISPLAY` is used.
     pub fn connect(dpy_name: Option<&str>) -> Result<(Self, usize), ConnectError> {
-        // Parse display information
-        let parsed_display =
-            parse_display::parse_display(dpy_name).ok_or(ConnectError::DisplayParsingError)?;
-
-        // Establish connection
-        let protocol = parsed_display.protocol.as_deref();
-        let stream =
-            DefaultStream::connect(&*parsed_display.host, protocol, parsed_display.display)?;
-        let screen = parsed_display.screen.into();
-
-        let (family, address) = stream.peer_addr()?;
-        let (auth_name, auth_data) = xauth::get_auth(family, &address, parsed_display.display)
-            // Ignore all errors while determining auth; instead we just try without auth info.
-            .unwrap_or(None)
-            .unwrap_or_else(|| (Vec::new(), Vec::new()));
-
-        Ok((
-            Self::connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)?,
-            screen,
-        ))
+        ConnectionBuilder::new().connect(dpy_name)
     }
 }
 
 impl<S: Stream> RustConnection<S> {
+    /// Get a builder for configuring a connection before establishing it.
+    pub fn builder() -> ConnectionBuilder {
+        ConnectionBuilder::new()
+    }
+
     /// Establish a new connection to the given streams.
     ///
     /// `read` is used for reading data from the X11 server and `write` is used for writing.
     /// `screen` is the number of the screen that should be used. This function checks that a
     /// screen with that number exists.
     pub fn connect_to_stream(stream: S, screen: usize) -> Result<Self, ConnectError> {
-        Self::connect_to_stream_with_auth_info(stream, screen, Vec::new(), Vec::new())
+        ConnectionBuilder::new().connect_to_stream(stream, screen)
     }
 
     /// Establish a new connection to the given streams.
@@ -160,16 +164,8 @@ impl<S: Stream> RustConnection<S> {
         auth_name: Vec<u8>,
         auth_data: Vec<u8>,
     ) -> Result<Self, ConnectError> {
-        write_setup(&stream, auth_name, auth_data)?;
-        let setup = read_setup(&stream)?;
-
-        // Check that we got a valid screen number
-        if screen >= setup.roots.len() {
-            return Err(ConnectError::InvalidScreen);
-        }
-
-        // Success! Set up our state
-        Self::for_connected_stream(stream, setup)
+        ConnectionBuilder::new()
+            .connect_to_stream_with_auth_info(stream, screen, auth_name, auth_data)
     }
 
     /// Establish a new connection for an already connected stream.
@@ -178,13 +174,14 @@ impl<S: Stream> RustConnection<S> {
     /// It is assumed that `setup` was just received from the server. Thus, the first reply to a
     /// request that is sent will have sequence number one.
     pub fn for_connected_stream(stream: S, setup: Setup) -> Result<Self, ConnectError> {
-        Self::for_inner(stream, inner::ConnectionInner::new(), setup)
+        ConnectionBuilder::new().for_connected_stream(stream, setup)
     }
 
     fn for_inner(
         stream: S,
         inner: inner::ConnectionInner,
         setup: Setup,
+        observer: Option<ObserverState>,
     ) -> Result<Self, ConnectError> {
         let allocator =
             id_allocator::IDAllocator::new(setup.resource_id_base, setup.resource_id_mask)?;
@@ -197,6 +194,8 @@ impl<S: Stream> RustConnection<S> {
             setup,
             extension_manager: Default::default(),
             maximum_request_bytes: Mutex::new(MaxRequestBytes::Unknown),
+            observer,
+            statistics: Default::default(),
         })
     }
 
@@ -204,12 +203,14 @@ impl<S: Stream> RustConnection<S> {
     ///
     /// This function "does the actual work" for `send_request_with_reply()` and
     /// `send_request_without_reply()`.
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn send_request(
         &self,
         bufs: &[IoSlice<'_>],
         fds: Vec<RawFdContainer>,
         kind: ReplyFDKind,
     ) -> Result<SequenceNumber, ConnectionError> {
+        let location = error_handler::request_location();
         let mut storage = Default::default();
         let bufs = compute_length_field(self, bufs, &mut storage)?;
 
@@ -220,8 +221,12 @@ impl<S: Stream> RustConnection<S> {
         let mut inner = self.inner.lock().unwrap();
 
         loop {
-            match inner.send_request(kind) {
+            match inner.send_request(kind, location) {
                 Some(seqno) => {
+                    self.statistics.request_sent(bufs);
+                    if let Some(observer) = &self.observer {
+                        observer.request(seqno, bufs);
+                    }
                     // Now actually send the buffers
                     let _inner = self.write_all_vectored(inner, bufs, fds)?;
                     return Ok(seqno);
@@ -251,10 +256,15 @@ impl<S: Stream> RustConnection<S> {
         ];
 
         let seqno = inner
-            .send_request(ReplyFDKind::ReplyWithoutFDs)
+            .send_request(ReplyFDKind::ReplyWithoutFDs, None)
             .expect("Sending a HasResponse request should not be blocked by syncs");
         inner.discard_reply(seqno, DiscardMode::DiscardReplyAndError);
-        let inner = self.write_all_vectored(inner, &[IoSlice::new(&request)], Vec::new())?;
+        let bufs = [IoSlice::new(&request)];
+        self.statistics.request_sent(&bufs);
+        if let Some(observer) = &self.observer {
+            observer.request(seqno, &bufs);
+        }
+        let inner = self.write_all_vectored(inner, &bufs, Vec::new())?;
 
         Ok(inner)
     }
@@ -321,12 +331,25 @@ impl<S: Stream> RustConnection<S> {
         Ok(inner)
     }
 
+    /// Flush the write buffer, giving up with `ConnectionError::Timeout` once `deadline` passed.
     fn flush_impl<'a>(
         &'a self,
         mut inner: MutexGuardInner<'a>,
-    ) -> std::io::Result<MutexGuardInner<'a>> {
+        deadline: Option<Instant>,
+    ) -> Result<MutexGuardInner<'a>, ConnectionError> {
+        if inner.write_buffer.needs_flush() {
+            self.statistics.flush();
+        }
         while inner.write_buffer.needs_flush() {
-            self.stream.poll(PollMode::ReadAndWritable)?;
+            match deadline {
+                None => self.stream.poll(PollMode::ReadAndWritable)?,
+                Some(deadline) => {
+                    let timeout = remaining_time(deadline)?;
+                    let _ = self
+                        .stream
+                        .poll_timeout(PollMode::ReadAndWritable, timeout)?;
+                }
+            }
             match inner.write_buffer.flush(&self.stream) {
                 // Flush completed
                 Ok(()) => break,
@@ -336,7 +359,7 @@ impl<S: Stream> RustConnection<S> {
                     // buffered replies have been read.
                     inner = self.read_packet_and_enqueue(inner, BlockingMode::NonBlocking)?;
                 }
-                Err(e) => return Err(e),
+                Err(e) => return Err(e.into()),
             }
         }
         Ok(inner)
@@ -349,7 +372,10 @@ impl<S: Stream> RustConnection<S> {
     /// reason, you need to pass in a `MutexGuard` to be dropped. This function locks the mutex
     /// again and returns a new `MutexGuard`.
     ///
-    /// Note: If `mode` is `BlockingMode::Blocking`, the lock on `inner` will be temporarily
+    /// With `BlockingMode::BlockingUntil`, this function returns once the deadline passed, even
+    /// if no packet was received. Callers have to check the deadline themselves.
+    ///
+    /// Note: If `mode` is not `BlockingMode::NonBlocking`, the lock on `inner` will be temporarily
     /// released. While sending a request, `inner` must be kept locked to avoid sending the data
     /// of different requests interleaved. So, when `read_packet_and_enqueue` is called as part
     /// of a write, it must always be done with `mode` set to `BlockingMode::NonBlocking`.
@@ -362,10 +388,11 @@ impl<S: Stream> RustConnection<S> {
         match self.packet_reader.try_lock() {
             Err(TryLockError::WouldBlock) => {
                 // In non-blocking mode, we just return immediately
-                match mode {
+                let deadline = match mode {
                     BlockingMode::NonBlocking => return Ok(inner),
-                    BlockingMode::Blocking => {}
-                }
+                    BlockingMode::Blocking => None,
+                    BlockingMode::BlockingUntil(deadline) => Some(deadline),
+                };
 
                 // 1.1. Someone else is reading (other thread is at 2.2);
                 // wait for it. `Condvar::wait` will unlock `inner`, so
@@ -375,7 +402,17 @@ impl<S: Stream> RustConnection<S> {
                 // When `wait` finishes, other thread has enqueued a packet,
                 // so the purpose of this function has been fulfilled. `wait`
                 // will relock `inner` when it returns.
-                Ok(self.reader_condition.wait(inner).unwrap())
+                match deadline {
+                    None => Ok(self.reader_condition.wait(inner).unwrap()),
+                    Some(deadline) => {
+                        let timeout = deadline.saturating_duration_since(Instant::now());
+                        Ok(self
+                            .reader_condition
+                            .wait_timeout(inner, timeout)
+                            .unwrap()
+                            .0)
+                    }
+                }
             }
             Err(TryLockError::Poisoned(e)) => panic!("{}", e),
             Ok(mut packet_reader) => {
@@ -384,12 +421,18 @@ impl<S: Stream> RustConnection<S> {
                 let notify_on_drop = NotifyOnDrop(&self.reader_condition);
 
                 // 2.1. Poll for read if mode is blocking.
-                if mode == BlockingMode::Blocking {
+                if mode != BlockingMode::NonBlocking {
                     // 2.1.1. Unlock `inner`, so other threads can use it while
                     // during the poll.
                     drop(inner);
                     // 2.1.2. Do the actual poll
-                    self.stream.poll(PollMode::Readable)?;
+                    self.statistics.blocking_wait();
+                    if let BlockingMode::BlockingUntil(deadline) = mode {
+                        let timeout = deadline.saturating_duration_since(Instant::now());
+                        let _ = self.stream.poll_timeout(PollMode::Readable, timeout)?;
+                    } else {
+                        self.stream.poll(PollMode::Readable)?;
+                    }
                     // 2.1.3. Relock inner
                     inner = self.inner.lock().unwrap();
                 }
@@ -409,10 +452,26 @@ impl<S: Stream> RustConnection<S> {
                 drop(packet_reader);
 
                 // 2.4. Actually enqueue the read packets.
+                self.statistics
+                    .bytes_read(packets.iter().map(|packet| packet.len()).sum());
                 inner.enqueue_fds(fds);
-                packets
-                    .into_iter()
-                    .for_each(|packet| inner.enqueue_packet(packet));
+                #[cfg(feature = "async")]
+                let received_packets = !packets.is_empty();
+                match &self.observer {
+                    None => packets.into_iter().for_each(|packet| {
+                        let _ = inner.enqueue_packet(packet);
+                    }),
+                    Some(observer) => packets.into_iter().for_each(|packet| {
+                        let seqno = inner.enqueue_packet(packet.clone());
+                        observer.packet(seqno, &packet);
+                    }),
+                }
+                #[cfg(feature = "async")]
+                {
+                    if received_packets {
+                        inner.wake_all();
+                    }
+                }
 
                 // 2.5. Notify the condvar by dropping the `notify_on_drop` object.
                 // The object would have been dropped when the function returns, so
@@ -440,11 +499,116 @@ impl<S: Stream> RustConnection<S> {
     pub fn stream(&self) -> &S {
         &self.stream
     }
+
+    fn wait_for_reply_impl(
+        &self,
+        sequence: SequenceNumber,
+        deadline: Option<Instant>,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        inner = self.flush_impl(inner, deadline)?;
+        loop {
+            match inner.poll_for_reply(sequence) {
+                PollReply::TryAgain => {}
+                PollReply::NoReply => return Ok(None),
+                PollReply::Reply(buffer) => return Ok(Some(buffer)),
+            }
+            inner = self.read_packet_and_enqueue(inner, blocking_mode(deadline)?)?;
+        }
+    }
+
+    fn check_for_raw_error_impl(
+        &self,
+        sequence: SequenceNumber,
+        deadline: Option<Instant>,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.prepare_check_for_reply_or_error(sequence) {
+            inner = self.send_sync(inner)?;
+            assert!(!inner.prepare_check_for_reply_or_error(sequence));
+        }
+        // Ensure the request is sent
+        inner = self.flush_impl(inner, deadline)?;
+        loop {
+            match inner.poll_check_for_reply_or_error(sequence) {
+                PollReply::TryAgain => {}
+                PollReply::NoReply => return Ok(None),
+                PollReply::Reply(buffer) => return Ok(Some(buffer)),
+            }
+            inner = self.read_packet_and_enqueue(inner, blocking_mode(deadline)?)?;
+        }
+    }
+
+    fn wait_for_reply_with_fds_raw_impl(
+        &self,
+        sequence: SequenceNumber,
+        deadline: Option<Instant>,
+    ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        // Ensure the request is sent
+        inner = self.flush_impl(inner, deadline)?;
+        loop {
+            if let Some(reply) = inner.poll_for_reply_or_error(sequence) {
+                if reply.0[0] == 0 {
+                    return Ok(ReplyOrError::Error(reply.0));
+                } else {
+                    return Ok(ReplyOrError::Reply(reply));
+                }
+            }
+            inner = self.read_packet_and_enqueue(inner, blocking_mode(deadline)?)?;
+        }
+    }
+
+    fn wait_for_raw_event_with_sequence_impl(
+        &self,
+        deadline: Option<Instant>,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        let mut inner = self.inner.lock().unwrap();
+        loop {
+            if let Some(event) = inner.poll_for_event_with_sequence() {
+                return Ok(event);
+            }
+            inner = self.read_packet_and_enqueue(inner, blocking_mode(deadline)?)?;
+        }
+    }
+}
+
+/// Get the deadline for a wait with the given timeout.
+///
+/// A timeout that is too large to be represented is treated as no timeout at all.
+fn deadline_after(timeout: Duration) -> Option<Instant> {
+    Instant::now().checked_add(timeout)
+}
+
+/// Get the time that is left until the given deadline.
+///
+/// Returns `ConnectionError::Timeout` if the deadline already passed.
+fn remaining_time(deadline: Instant) -> Result<Duration, ConnectionError> {
+    let now = Instant::now();
+    if now >= deadline {
+        Err(ConnectionError::Timeout)
+    } else {
+        Ok(deadline - now)
+    }
+}
+
+/// Get the `BlockingMode` for waiting until the given deadline, if any.
+///
+/// Returns `ConnectionError::Timeout` if the deadline already passed.
+fn blocking_mode(deadline: Option<Instant>) -> Result<BlockingMode, ConnectionError> {
+    match deadline {
+        None => Ok(BlockingMode::Blocking),
+        Some(deadline) => {
+            let _ = remaining_time(deadline)?;
+            Ok(BlockingMode::BlockingUntil(deadline))
+        }
+    }
 }
 
 impl<S: Stream> RequestConnection for RustConnection<S> {
     type Buf = Vec<u8>;
 
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn send_request_with_reply<Reply>(
         &self,
         bufs: &[IoSlice<'_>],
@@ -459,6 +623,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         ))
     }
 
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn send_request_with_reply_with_fds<Reply>(
         &self,
         bufs: &[IoSlice<'_>],
@@ -473,6 +638,7 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         ))
     }
 
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn send_request_without_reply(
         &self,
         bufs: &[IoSlice<'_>],
@@ -502,10 +668,16 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         &self,
         extension_name: &'static str,
     ) -> Result<Option<ExtensionInformation>, ConnectionError> {
-        self.extension_manager
+        let mut extension_manager = self.extension_manager.lock().unwrap();
+        let result = extension_manager.extension_information(self, extension_name);
+        if let Some(observer) = &self.observer {
+            observer.update_ext_info(&extension_manager);
+        }
+        self.inner
             .lock()
             .unwrap()
-            .extension_information(self, extension_name)
+            .update_ext_info(&extension_manager);
+        result
     }
 
     fn wait_for_reply_or_raw_error(
@@ -518,57 +690,57 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
         }
     }
 
-    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        inner = self.flush_impl(inner)?;
-        loop {
-            match inner.poll_for_reply(sequence) {
-                PollReply::TryAgain => {}
-                PollReply::NoReply => return Ok(None),
-                PollReply::Reply(buffer) => return Ok(Some(buffer)),
-            }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        match self.wait_for_reply_with_fds_raw_timeout(sequence, timeout)? {
+            ReplyOrError::Reply((reply, _fds)) => Ok(ReplyOrError::Reply(reply)),
+            ReplyOrError::Error(e) => Ok(ReplyOrError::Error(e)),
         }
     }
 
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.wait_for_reply_impl(sequence, None)
+    }
+
+    fn wait_for_reply_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        self.wait_for_reply_impl(sequence, deadline_after(timeout))
+    }
+
     fn check_for_raw_error(
         &self,
         sequence: SequenceNumber,
     ) -> Result<Option<Buffer>, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        if inner.prepare_check_for_reply_or_error(sequence) {
-            inner = self.send_sync(inner)?;
-            assert!(!inner.prepare_check_for_reply_or_error(sequence));
-        }
-        // Ensure the request is sent
-        inner = self.flush_impl(inner)?;
-        loop {
-            match inner.poll_check_for_reply_or_error(sequence) {
-                PollReply::TryAgain => {}
-                PollReply::NoReply => return Ok(None),
-                PollReply::Reply(buffer) => return Ok(Some(buffer)),
-            }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
-        }
+        self.check_for_raw_error_impl(sequence, None)
+    }
+
+    fn check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        self.check_for_raw_error_impl(sequence, deadline_after(timeout))
     }
 
     fn wait_for_reply_with_fds_raw(
         &self,
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        // Ensure the request is sent
-        inner = self.flush_impl(inner)?;
-        loop {
-            if let Some(reply) = inner.poll_for_reply_or_error(sequence) {
-                if reply.0[0] == 0 {
-                    return Ok(ReplyOrError::Error(reply.0));
-                } else {
-                    return Ok(ReplyOrError::Reply(reply));
-                }
-            }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
-        }
+        self.wait_for_reply_with_fds_raw_impl(sequence, None)
+    }
+
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
+        self.wait_for_reply_with_fds_raw_impl(sequence, deadline_after(timeout))
     }
 
     fn maximum_request_bytes(&self) -> usize {
@@ -618,13 +790,14 @@ impl<S: Stream> RequestConnection for RustConnection<S> {
 
 impl<S: Stream> Connection for RustConnection<S> {
     fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
-        let mut inner = self.inner.lock().unwrap();
-        loop {
-            if let Some(event) = inner.poll_for_event_with_sequence() {
-                return Ok(event);
-            }
-            inner = self.read_packet_and_enqueue(inner, BlockingMode::Blocking)?;
-        }
+        self.wait_for_raw_event_with_sequence_impl(None)
+    }
+
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        self.wait_for_raw_event_with_sequence_impl(deadline_after(timeout))
     }
 
     fn poll_for_raw_event_with_sequence(
@@ -641,7 +814,7 @@ impl<S: Stream> Connection for RustConnection<S> {
 
     fn flush(&self) -> Result<(), ConnectionError> {
         let inner = self.inner.lock().unwrap();
-        let _inner = self.flush_impl(inner)?;
+        let _inner = self.flush_impl(inner, None)?;
         Ok(())
     }
 
@@ -654,14 +827,14 @@ impl<S: Stream> Connection for RustConnection<S> {
     }
 }
 
-#[cfg(target_endian = "little")]
-fn byte_order() -> u8 {
-    0x6c
-}
+impl<S: Stream> StatisticsConnection for RustConnection<S> {
+    fn statistics(&self) -> Statistics {
+        self.statistics.snapshot()
+    }
 
-#[cfg(target_endian = "big")]
-fn byte_order() -> u8 {
-    0x42
+    fn count_requests_by_opcode(&self) {
+        self.statistics.count_requests_by_opcode();
+    }
 }
 
 /// Send a `SetupRequest` to the X11 server.
@@ -671,7 +844,7 @@ fn write_setup(
     auth_data: Vec<u8>,
 ) -> Result<(), std::io::Error> {
     let request = SetupRequest {
-        byte_order: byte_order(),
+        byte_order: ByteOrder::native().setup_request_byte(),
         protocol_major_version: 11,
         protocol_minor_version: 0,
         authorization_protocol_name: auth_name,
@@ -845,4 +1018,279 @@ mod test {
             value => panic!("Unexpected value {:?}", value),
         }
     }
+
+    fn empty_setup() -> Setup {
+        Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0,
+            resource_id_mask: 0xff,
+            motion_buffer_size: 0,
+            maximum_request_length: 0,
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 0,
+            bitmap_format_scanline_pad: 0,
+            min_keycode: 0,
+            max_keycode: 0,
+            vendor: vec![],
+            pixmap_formats: vec![],
+            roots: vec![],
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reply_timeout() {
+        use std::io::Write;
+        use std::os::unix::net::UnixStream;
+        use std::time::Duration;
+
+        use super::{DefaultStream, RustConnection};
+        use crate::connection::Connection;
+        use crate::errors::{ConnectionError, ReplyError};
+        use crate::protocol::xproto::ConnectionExt;
+
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = RustConnection::for_connected_stream(stream, empty_setup()).unwrap();
+        let timeout = Duration::from_millis(10);
+
+        // The server does not answer, so waiting times out
+        match conn.get_input_focus().unwrap().reply_timeout(timeout) {
+            Err(ReplyError::ConnectionError(ConnectionError::Timeout)) => {}
+            value => panic!("Unexpected value {:?}", value),
+        }
+        match conn.wait_for_event_timeout(timeout) {
+            Err(ConnectionError::Timeout) => {}
+            value => panic!("Unexpected value {:?}", value),
+        }
+
+        // The connection is still usable afterwards
+        let cookie = conn.get_input_focus().unwrap();
+        for &sequence in &[1u16, 2] {
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+        }
+        let reply = cookie.reply_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(reply.sequence, 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn observer() {
+        use std::io::Write;
+        use std::os::unix::net::UnixStream;
+        use std::sync::{Arc, Mutex};
+
+        use super::{ConnectionBuilder, DefaultStream, ObservedPacket, ObservedRequest, Observer};
+        use crate::connection::Connection;
+        use crate::protocol::xproto::{
+            ConnectionExt, GET_INPUT_FOCUS_REQUEST, NO_OPERATION_REQUEST,
+        };
+        use crate::protocol::{ErrorKind, Event, Request};
+
+        #[derive(Debug, Default, Clone)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Recorder {
+            fn record(&self, entry: String) {
+                self.0.lock().unwrap().push(entry);
+            }
+        }
+
+        impl Observer for Recorder {
+            fn request(&self, request: &ObservedRequest<'_>) {
+                let name = match request.parse().unwrap() {
+                    Request::GetInputFocus(_) => "GetInputFocus",
+                    Request::NoOperation(_) => "NoOperation",
+                    request => panic!("Unexpected request {:?}", request),
+                };
+                self.record(format!("request {} {}", request.sequence_number(), name));
+            }
+
+            fn reply(&self, reply: &ObservedPacket<'_>) {
+                self.record(format!("reply {}", reply.sequence_number()));
+            }
+
+            fn error(&self, error: &ObservedPacket<'_>) {
+                assert_eq!(error.parse_error().unwrap().error_kind, ErrorKind::Window);
+                self.record(format!("error {}", error.sequence_number()));
+            }
+
+            fn event(&self, event: &ObservedPacket<'_>) {
+                match event.parse_event().unwrap() {
+                    Event::Expose(_) => {}
+                    event => panic!("Unexpected event {:?}", event),
+                }
+                self.record(format!("event {}", event.sequence_number()));
+            }
+        }
+
+        let recorder = Recorder::default();
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = ConnectionBuilder::new()
+            .observer(recorder.clone())
+            .for_connected_stream(stream, empty_setup())
+            .unwrap();
+
+        let cookie = conn.get_input_focus().unwrap();
+        let _ = conn.no_operation().unwrap();
+        conn.flush().unwrap();
+
+        let mut request = [0; 8];
+        server.read_exact(&mut request).unwrap();
+        assert_eq!(request[0], GET_INPUT_FOCUS_REQUEST);
+        assert_eq!(request[4], NO_OPERATION_REQUEST);
+
+        let mut reply = [0; 32];
+        reply[0] = 1;
+        reply[2..4].copy_from_slice(&1u16.to_ne_bytes());
+        let mut error = [0; 32];
+        error[1] = 3;
+        error[2..4].copy_from_slice(&2u16.to_ne_bytes());
+        let mut event = [0; 32];
+        event[0] = 12;
+        event[2..4].copy_from_slice(&2u16.to_ne_bytes());
+        server.write_all(&reply).unwrap();
+        server.write_all(&error).unwrap();
+        server.write_all(&event).unwrap();
+
+        let _ = cookie.reply().unwrap();
+        let _ = conn.wait_for_event().unwrap();
+        let _ = conn.wait_for_event().unwrap();
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                "request 1 GetInputFocus",
+                "request 2 NoOperation",
+                "reply 1",
+                "error 2",
+                "event 2",
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn error_handler() {
+        use std::io::Write;
+        use std::os::unix::net::UnixStream;
+        use std::panic::Location;
+        use std::sync::{Arc, Mutex};
+
+        use super::{ConnectionBuilder, DefaultStream};
+        use crate::connection::Connection;
+        use crate::protocol::xproto::{ConnectionExt, Window};
+        use crate::protocol::{ErrorKind, Event};
+        use crate::x11_utils::X11Error;
+
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let errors2 = Arc::clone(&errors);
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = ConnectionBuilder::new()
+            .error_handler(
+                move |error: &X11Error,
+                      request_name: Option<&'static str>,
+                      location: Option<&'static Location<'static>>| {
+                    errors2.lock().unwrap().push((
+                        error.error_kind,
+                        request_name,
+                        location.map(|location| location.file()),
+                    ));
+                },
+            )
+            .for_connected_stream(stream, empty_setup())
+            .unwrap();
+
+        // The error of the first request is unchecked, the error of the second one is ignored
+        let _ = conn.map_window(Window::from(1u32)).unwrap();
+        conn.no_operation().unwrap().ignore_error();
+        conn.flush().unwrap();
+
+        let mut request = [0; 12];
+        server.read_exact(&mut request).unwrap();
+
+        for &(error_code, major_opcode, seqno) in &[(3, 8, 1u16), (2, 127, 2)] {
+            let mut error = [0; 32];
+            error[1] = error_code;
+            error[2..4].copy_from_slice(&seqno.to_ne_bytes());
+            error[10] = major_opcode;
+            server.write_all(&error).unwrap();
+        }
+        let mut event = [0; 32];
+        event[0] = 12;
+        event[2..4].copy_from_slice(&2u16.to_ne_bytes());
+        server.write_all(&event).unwrap();
+
+        // The error is not delivered as an event
+        match conn.wait_for_event().unwrap() {
+            Event::Expose(_) => {}
+            event => panic!("Unexpected event {:?}", event),
+        }
+        assert!(conn.poll_for_event().unwrap().is_none());
+
+        let location = if cfg!(all(feature = "request-location", debug_assertions)) {
+            Some(file!())
+        } else {
+            None
+        };
+        assert_eq!(
+            *errors.lock().unwrap(),
+            [(ErrorKind::Window, Some("MapWindow"), location)]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn statistics() {
+        use std::io::Write;
+        use std::os::unix::net::UnixStream;
+
+        use super::{DefaultStream, RustConnection};
+        use crate::connection::Connection;
+        use crate::protocol::xproto::{ConnectionExt, GET_INPUT_FOCUS_REQUEST};
+        use crate::statistics::{RequestOpcode, StatisticsConnection};
+
+        let (client, mut server) = UnixStream::pair().unwrap();
+        let stream = DefaultStream::from_unix_stream(client).unwrap();
+        let conn = RustConnection::for_connected_stream(stream, empty_setup()).unwrap();
+        conn.count_requests_by_opcode();
+        let before = conn.statistics();
+
+        let cookie1 = conn.get_input_focus().unwrap();
+        let cookie2 = conn.get_input_focus().unwrap();
+        conn.flush().unwrap();
+
+        let mut request = [0; 8];
+        server.read_exact(&mut request).unwrap();
+        for &sequence in &[1u16, 2] {
+            let mut reply = [0; 32];
+            reply[0] = 1;
+            reply[2..4].copy_from_slice(&sequence.to_ne_bytes());
+            server.write_all(&reply).unwrap();
+        }
+        let _ = cookie1.reply().unwrap();
+        let _ = cookie2.reply().unwrap();
+
+        let statistics = conn.statistics().since(&before);
+        let opcode = RequestOpcode {
+            major_opcode: GET_INPUT_FOCUS_REQUEST,
+            minor_opcode: None,
+        };
+        assert_eq!(statistics.requests.get(&opcode), Some(&2));
+        assert_eq!(statistics.requests_sent, 2);
+        assert_eq!(statistics.bytes_written, 8);
+        assert_eq!(statistics.bytes_read, 64);
+        assert_eq!(statistics.flushes, 1);
+        assert_eq!(statistics.blocking_waits, 1);
+    }
 }
@@ -0,0 +1,178 @@
+//! Observing the packets that a `RustConnection` sends and receives.
+
+use std::io::IoSlice;
+use std::sync::Mutex;
+
+use crate::connection::SequenceNumber;
+use crate::errors::ParseError;
+use crate::extension_manager::ExtensionManager;
+use crate::protocol::{Event, Request};
+use crate::x11_utils::{parse_request_header, BigRequests, ExtInfoProvider, X11Error};
+
+/// An observer for the packets that a [`RustConnection`](super::RustConnection) sends and
+/// receives.
+///
+/// An observer can be installed with
+/// [`ConnectionBuilder::observer`](super::ConnectionBuilder::observer). All methods have an empty
+/// default implementation.
+///
+/// The methods are called while the connection holds internal locks. Thus, they must not use the
+/// connection and should return quickly.
+pub trait Observer: Send + Sync {
+    /// Called for every request that is sent to the X11 server.
+    ///
+    /// This includes requests that the connection sends on its own, for example for
+    /// synchronisation.
+    fn request(&self, request: &ObservedRequest<'_>) {
+        let _ = request;
+    }
+
+    /// Called for every reply that is received from the X11 server.
+    fn reply(&self, reply: &ObservedPacket<'_>) {
+        let _ = reply;
+    }
+
+    /// Called for every error that is received from the X11 server.
+    fn error(&self, error: &ObservedPacket<'_>) {
+        let _ = error;
+    }
+
+    /// Called for every event that is received from the X11 server.
+    fn event(&self, event: &ObservedPacket<'_>) {
+        let _ = event;
+    }
+}
+
+/// A request that was sent to the X11 server, as seen by an [`Observer`].
+#[derive(Debug)]
+pub struct ObservedRequest<'a> {
+    sequence_number: SequenceNumber,
+    data: &'a [u8],
+    ext_info: &'a ExtensionManager,
+}
+
+impl<'a> ObservedRequest<'a> {
+    /// Get the sequence number of the request.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        self.sequence_number
+    }
+
+    /// Get the raw bytes of the request, including its header.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Get information about the extensions that are known to the connection.
+    pub fn ext_info(&self) -> &'a dyn ExtInfoProvider {
+        self.ext_info
+    }
+
+    /// Parse the request.
+    ///
+    /// File descriptors that were sent with the request are not available. Thus, parsing requests
+    /// that contain file descriptors fails.
+    pub fn parse(&self) -> Result<Request<'a>, ParseError> {
+        let (header, body) = parse_request_header(self.data, BigRequests::Enabled)?;
+        Request::parse(header, body, &mut Vec::new(), self.ext_info)
+    }
+}
+
+/// A reply, error, or event that was received from the X11 server, as seen by an [`Observer`].
+#[derive(Debug)]
+pub struct ObservedPacket<'a> {
+    sequence_number: SequenceNumber,
+    data: &'a [u8],
+    ext_info: &'a ExtensionManager,
+}
+
+impl<'a> ObservedPacket<'a> {
+    /// Get the sequence number of the packet.
+    ///
+    /// For replies and errors, this is the sequence number of the corresponding request. For
+    /// events, this is the sequence number of the last request that the server processed before
+    /// generating the event.
+    pub fn sequence_number(&self) -> SequenceNumber {
+        self.sequence_number
+    }
+
+    /// Get the raw bytes of the packet.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Get information about the extensions that are known to the connection.
+    pub fn ext_info(&self) -> &'a dyn ExtInfoProvider {
+        self.ext_info
+    }
+
+    /// Parse the packet as an event.
+    ///
+    /// Errors are parsed into [`Event::Error`]. Replies cannot be parsed like this.
+    pub fn parse_event(&self) -> Result<Event, ParseError> {
+        Event::parse(self.data, self.ext_info)
+    }
+
+    /// Parse the packet as an error.
+    pub fn parse_error(&self) -> Result<X11Error, ParseError> {
+        X11Error::try_parse(self.data, self.ext_info)
+    }
+}
+
+/// An installed [`Observer`] together with the information needed for parsing packets.
+pub(crate) struct ObserverState {
+    observer: Box<dyn Observer>,
+    // A copy of the connection's `ExtensionManager`. The original cannot be used, because its
+    // mutex might already be held while packets are sent or received.
+    ext_info: Mutex<ExtensionManager>,
+}
+
+impl std::fmt::Debug for ObserverState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObserverState")
+            .field("ext_info", &self.ext_info)
+            .finish()
+    }
+}
+
+impl ObserverState {
+    pub(crate) fn new(observer: Box<dyn Observer>) -> Self {
+        Self {
+            observer,
+            ext_info: Default::default(),
+        }
+    }
+
+    /// Update the information about known extensions.
+    pub(crate) fn update_ext_info(&self, ext_info: &ExtensionManager) {
+        *self.ext_info.lock().unwrap() = ext_info.clone();
+    }
+
+    /// Notify the observer about a request that is being sent.
+    pub(crate) fn request(&self, sequence_number: SequenceNumber, bufs: &[IoSlice<'_>]) {
+        let data = bufs
+            .iter()
+            .flat_map(|buf| buf.iter().copied())
+            .collect::<Vec<_>>();
+        let ext_info = self.ext_info.lock().unwrap();
+        self.observer.request(&ObservedRequest {
+            sequence_number,
+            data: &data,
+            ext_info: &ext_info,
+        });
+    }
+
+    /// Notify the observer about a packet that was received.
+    pub(crate) fn packet(&self, sequence_number: SequenceNumber, data: &[u8]) {
+        let ext_info = self.ext_info.lock().unwrap();
+        let packet = ObservedPacket {
+            sequence_number,
+            data,
+            ext_info: &ext_info,
+        };
+        match data[0] {
+            0 => self.observer.error(&packet),
+            1 => self.observer.reply(&packet),
+            _ => self.observer.event(&packet),
+        }
+    }
+}
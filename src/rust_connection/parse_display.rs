@@ -1,20 +1,64 @@
+//! Utilities for parsing X11 display strings.
+
+/// A parsed X11 display string.
+///
+/// This describes the X11 server that should be connected to and the screen that should be used
+/// afterwards. It is produced by [`parse_display`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct ParsedDisplay {
-    pub(crate) host: String,
-    pub(crate) protocol: Option<String>,
-    pub(crate) display: u16,
-    pub(crate) screen: u16,
+pub struct ParsedDisplay {
+    /// The host that the X11 server runs on, or the path to a unix socket.
+    ///
+    /// This is empty for local connections. Brackets around IPv6 addresses are removed. When the
+    /// display string named a socket path, this contains the path and `protocol` is `unix`.
+    pub host: String,
+    /// The protocol that should be used for connecting, for example `tcp` or `unix`.
+    pub protocol: Option<String>,
+    /// The number of the display on the host.
+    pub display: u16,
+    /// The screen that should be used on the display.
+    pub screen: u16,
+}
+
+impl ParsedDisplay {
+    /// Get the path of the unix socket that the display string explicitly names, if any.
+    pub fn socket_path(&self) -> Option<&str> {
+        if self.protocol.as_deref() == Some("unix") && self.host.starts_with('/') {
+            Some(&self.host)
+        } else {
+            None
+        }
+    }
 }
 
-pub(crate) fn parse_display(dpy_name: Option<&str>) -> Option<ParsedDisplay> {
+/// Parse an X11 display string.
+///
+/// If `dpy_name` is `None`, the value of the `$DISPLAY` environment variable is used. `None` is
+/// returned if the display string is invalid or no display string is available.
+///
+/// Besides the usual `[protocol/][host]:display[.screen]` form, where `host` can also be an IPv6
+/// address in brackets, the path to a unix socket is accepted. Such a path may be prefixed with
+/// `unix:` and can be followed by `.screen`. A socket path is only recognised if the file exists.
+pub fn parse_display(dpy_name: Option<&str>) -> Option<ParsedDisplay> {
     // If no dpy name was provided, use the env var. If no env var exists, return None.
     match dpy_name {
-        Some(dpy_name) => parse_display_impl(dpy_name),
-        None => parse_display_impl(&std::env::var("DISPLAY").ok()?),
+        Some(dpy_name) => parse_display_impl(dpy_name, file_exists),
+        None => parse_display_impl(&std::env::var("DISPLAY").ok()?, file_exists),
     }
 }
 
-fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
+fn file_exists(path: &str) -> bool {
+    std::path::Path::new(path).exists()
+}
+
+fn parse_display_impl(dpy_name: &str, exists: impl Fn(&str) -> bool) -> Option<ParsedDisplay> {
+    // A display string can directly name a socket path
+    if dpy_name.starts_with('/') {
+        return parse_display_direct_path(dpy_name, exists);
+    }
+    if dpy_name.starts_with("unix:/") {
+        return parse_display_direct_path(&dpy_name["unix:".len()..], exists);
+    }
+
     // Everything up to the last '/' is the protocol. This part is optional.
     let (protocol, remaining) = if let Some(pos) = dpy_name.rfind('/') {
         (Some(&dpy_name[..pos]), &dpy_name[pos + 1..])
@@ -26,6 +70,14 @@ fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
     let pos = remaining.rfind(':')?;
     let (host, remaining) = (&remaining[..pos], &remaining[pos + 1..]);
 
+    // Remove the brackets around an IPv6 address. Unlike libxcb, we do this already here so that
+    // the host can directly be used for connecting.
+    let host = if host.len() >= 2 && host.starts_with('[') && host.ends_with(']') {
+        &host[1..host.len() - 1]
+    } else {
+        host
+    };
+
     // The remaining part is display.screen. The display is required and the screen optional.
     let (display, screen) = match remaining.find('.') {
         Some(pos) => (&remaining[..pos], &remaining[pos + 1..]),
@@ -45,9 +97,32 @@ fn parse_display_impl(dpy_name: &str) -> Option<ParsedDisplay> {
     })
 }
 
+/// Parse a display string that is the path to a unix socket, possibly followed by `.screen`.
+///
+/// Just like libxcb, the path is only accepted if it exists. If it does not, a screen number is
+/// stripped and the remaining path is checked again.
+fn parse_display_direct_path(path: &str, exists: impl Fn(&str) -> bool) -> Option<ParsedDisplay> {
+    let (path, screen) = if exists(path) {
+        (path, 0)
+    } else {
+        let pos = path.rfind('.')?;
+        let (path, screen) = (&path[..pos], path[pos + 1..].parse().ok()?);
+        if !exists(path) {
+            return None;
+        }
+        (path, screen)
+    };
+    Some(ParsedDisplay {
+        host: path.to_string(),
+        protocol: Some("unix".to_string()),
+        display: 0,
+        screen,
+    })
+}
+
 #[cfg(test)]
 mod test {
-    use super::{parse_display, ParsedDisplay};
+    use super::{parse_display, parse_display_impl, ParsedDisplay};
 
     fn do_parse_display(input: &str) -> Option<ParsedDisplay> {
         std::env::set_var("DISPLAY", input);
@@ -69,6 +144,7 @@ mod test {
         xcb_good_cases();
         xcb_bad_cases();
         own_good_cases();
+        path_cases();
     }
 
     fn test_missing_input() {
@@ -116,6 +192,50 @@ mod test {
         }
     }
 
+    fn path_cases() {
+        let exists = |path: &str| path == "/run/user/1000/X11-display" || path == "/tmp/x.1";
+        for (input, output) in &[
+            (
+                "/run/user/1000/X11-display",
+                Some(("/run/user/1000/X11-display", 0)),
+            ),
+            (
+                "/run/user/1000/X11-display.2",
+                Some(("/run/user/1000/X11-display", 2)),
+            ),
+            (
+                "unix:/run/user/1000/X11-display",
+                Some(("/run/user/1000/X11-display", 0)),
+            ),
+            ("/tmp/x.1", Some(("/tmp/x.1", 0))),
+            ("/tmp/x.1.3", Some(("/tmp/x.1", 3))),
+            ("/tmp/x", None),
+            ("/tmp/x.a", None),
+            ("/run/user/1000/X11-display.", None),
+            ("unix:0", Some(("unix", 0))),
+        ] {
+            let expected = output.map(|(host, screen)| {
+                let protocol = if host.starts_with('/') {
+                    Some("unix".to_string())
+                } else {
+                    None
+                };
+                ParsedDisplay {
+                    host: host.to_string(),
+                    protocol,
+                    display: 0,
+                    screen,
+                }
+            });
+            assert_eq!(
+                parse_display_impl(input, exists),
+                expected,
+                "Failed parsing correctly: {}",
+                input
+            );
+        }
+    }
+
     // Based on libxcb's test suite; (C) 2001-2006 Bart Massey, Jamey Sharp, and Josh Triplett
     fn xcb_good_cases() {
         for (input, output) in &[
@@ -279,7 +399,7 @@ mod test {
             (
                 "[::]:0",
                 ParsedDisplay {
-                    host: "[::]".to_string(),
+                    host: "::".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -288,7 +408,7 @@ mod test {
             (
                 "[1::]:0",
                 ParsedDisplay {
-                    host: "[1::]".to_string(),
+                    host: "1::".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -297,7 +417,7 @@ mod test {
             (
                 "[::1]:0",
                 ParsedDisplay {
-                    host: "[::1]".to_string(),
+                    host: "::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -306,7 +426,7 @@ mod test {
             (
                 "[::1]:0.1",
                 ParsedDisplay {
-                    host: "[::1]".to_string(),
+                    host: "::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 1,
@@ -315,7 +435,7 @@ mod test {
             (
                 "[::127.0.0.1]:0",
                 ParsedDisplay {
-                    host: "[::127.0.0.1]".to_string(),
+                    host: "::127.0.0.1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -324,7 +444,7 @@ mod test {
             (
                 "[2002:83fc:d052::1]:0",
                 ParsedDisplay {
-                    host: "[2002:83fc:d052::1]".to_string(),
+                    host: "2002:83fc:d052::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 0,
@@ -333,7 +453,7 @@ mod test {
             (
                 "[2002:83fc:d052::1]:0.1",
                 ParsedDisplay {
-                    host: "[2002:83fc:d052::1]".to_string(),
+                    host: "2002:83fc:d052::1".to_string(),
                     protocol: None,
                     display: 0,
                     screen: 1,
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::io::{IoSlice, Result};
 use std::net::{Ipv4Addr, SocketAddr, TcpStream};
 #[cfg(unix)]
@@ -6,6 +7,7 @@ use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
 use std::os::unix::net::UnixStream;
 #[cfg(windows)]
 use std::os::windows::io::{AsRawSocket, IntoRawSocket, RawSocket};
+use std::time::Duration;
 
 use super::xauth::Family;
 #[cfg(unix)]
@@ -69,6 +71,18 @@ pub trait Stream {
     /// This function shall panic if `read` and `write` are both false.
     fn poll(&self, mode: PollMode) -> Result<()>;
 
+    /// Waits for level-triggered read and/or write events on the stream, but at most for the
+    /// given amount of time.
+    ///
+    /// This works like [`Stream::poll`], but returns `Ok(false)` if the timeout expired before
+    /// the stream became ready. `Ok(true)` is returned otherwise, which may again be spurious.
+    ///
+    /// The default implementation ignores the timeout and calls [`Stream::poll`].
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<bool> {
+        let _ = timeout;
+        self.poll(mode).map(|()| true)
+    }
+
     /// Read some bytes and FDs from this reader without blocking, returning how many bytes
     /// were read.
     ///
@@ -188,13 +202,27 @@ enum DefaultStreamInner {
     TcpStream(TcpStream),
     #[cfg(unix)]
     UnixStream(UnixStream),
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    AbstractUnixStream(RawFdContainer),
 }
 
 impl DefaultStream {
     /// Try to connect to the X11 server described by the given arguments.
+    ///
+    /// For local connections on Linux, the abstract socket `@/tmp/.X11-unix/X<display>` is tried
+    /// before the socket in the file system, just like libxcb does. If `host` is an absolute path
+    /// and `protocol` is `None` or `unix`, that socket is connected to directly. This is what
+    /// [`parse_display`](super::parse_display) produces for display strings naming a socket.
     pub fn connect(host: &str, protocol: Option<&str>, display: u16) -> Result<Self> {
         const TCP_PORT_BASE: u16 = 6000;
 
+        #[cfg(unix)]
+        {
+            if (protocol.is_none() || protocol == Some("unix")) && host.starts_with('/') {
+                return Self::from_unix_stream(UnixStream::connect(host)?);
+            }
+        }
+
         if (protocol.is_none() || protocol != Some("unix")) && !host.is_empty() && host != "unix" {
             let stream = TcpStream::connect((host, TCP_PORT_BASE + display))?;
             Self::from_tcp_stream(stream)
@@ -208,14 +236,29 @@ impl DefaultStream {
                 if protocol.is_none() || protocol == Some("unix") {
                     let file_name = format!("/tmp/.X11-unix/X{}", display);
 
-                    // TODO: Try abstract socket (file name with prepended '\0')
-                    // Not supported on Rust right now: https://github.com/rust-lang/rust/issues/42048
+                    // Like libxcb, first try the abstract socket (file name with prepended '\0')
+                    #[cfg(any(target_os = "linux", target_os = "android"))]
+                    let abstract_error = match Self::connect_abstract_unix(file_name.as_bytes()) {
+                        Ok(stream) => return Ok(stream),
+                        Err(err) => err,
+                    };
 
-                    match UnixStream::connect(file_name) {
+                    match UnixStream::connect(&file_name) {
                         Ok(stream) => {
                             return Self::from_unix_stream(stream);
                         }
-                        Err(err) => error = Some(err),
+                        Err(err) => {
+                            #[cfg(any(target_os = "linux", target_os = "android"))]
+                            let err = std::io::Error::new(
+                                err.kind(),
+                                format!(
+                                    "Connecting to the abstract socket @{} failed: {}; \
+                                     connecting to {} failed: {}",
+                                    file_name, abstract_error, file_name, err
+                                ),
+                            );
+                            error = Some(err)
+                        }
                     }
                 }
             }
@@ -254,6 +297,38 @@ impl DefaultStream {
         })
     }
 
+    /// Connect to a unix socket in the abstract namespace.
+    ///
+    /// `name` is the name of the socket without the leading null byte. The stream will be set in
+    /// non-blocking mode.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn connect_abstract_unix(name: &[u8]) -> Result<Self> {
+        use nix::fcntl::{fcntl, FcntlArg, OFlag};
+        use nix::sys::socket::{
+            connect, socket, AddressFamily, SockAddr, SockFlag, SockType, UnixAddr,
+        };
+
+        let addr = UnixAddr::new_abstract(name).map_err(nix_error_to_io)?;
+        let fd = socket(
+            AddressFamily::Unix,
+            SockType::Stream,
+            SockFlag::SOCK_CLOEXEC,
+            None,
+        )
+        .map_err(nix_error_to_io)?;
+        // Make sure that the socket is closed in case of errors
+        let fd = RawFdContainer::new(fd);
+        connect(fd.as_raw_fd(), &SockAddr::Unix(addr)).map_err(nix_error_to_io)?;
+
+        let flags = fcntl(fd.as_raw_fd(), FcntlArg::F_GETFL).map_err(nix_error_to_io)?;
+        let flags = OFlag::from_bits_truncate(flags) | OFlag::O_NONBLOCK;
+        let _ = fcntl(fd.as_raw_fd(), FcntlArg::F_SETFL(flags)).map_err(nix_error_to_io)?;
+
+        Ok(Self {
+            inner: DefaultStreamInner::AbstractUnixStream(fd),
+        })
+    }
+
     /// Get the peer's address in a format suitable for xauth.
     ///
     /// The returned values can be directly given to `super::xauth::get_auth` as `family` and
@@ -291,6 +366,10 @@ impl DefaultStream {
             DefaultStreamInner::UnixStream(_) => {
                 // Fall through to the code below.
             }
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnixStream(_) => {
+                // Fall through to the code below.
+            }
         };
 
         // If we get to here: This is a local connection. Use the host name as address.
@@ -300,6 +379,19 @@ impl DefaultStream {
             .unwrap_or_else(Vec::new);
         Ok((Family::LOCAL, hostname))
     }
+
+    /// Get the address of our end of the connection.
+    ///
+    /// Returns `None` for unix sockets.
+    pub(crate) fn local_addr(&self) -> Result<Option<SocketAddr>> {
+        match self.inner {
+            DefaultStreamInner::TcpStream(ref stream) => stream.local_addr().map(Some),
+            #[cfg(unix)]
+            DefaultStreamInner::UnixStream(_) => Ok(None),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnixStream(_) => Ok(None),
+        }
+    }
 }
 
 #[cfg(unix)]
@@ -308,6 +400,8 @@ impl AsRawFd for DefaultStream {
         match self.inner {
             DefaultStreamInner::TcpStream(ref stream) => stream.as_raw_fd(),
             DefaultStreamInner::UnixStream(ref stream) => stream.as_raw_fd(),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnixStream(ref fd) => fd.as_raw_fd(),
         }
     }
 }
@@ -318,6 +412,8 @@ impl IntoRawFd for DefaultStream {
         match self.inner {
             DefaultStreamInner::TcpStream(stream) => stream.into_raw_fd(),
             DefaultStreamInner::UnixStream(stream) => stream.into_raw_fd(),
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            DefaultStreamInner::AbstractUnixStream(fd) => fd.into_raw_fd(),
         }
     }
 }
@@ -381,8 +477,11 @@ fn do_write(
     Ok(res)
 }
 
-impl Stream for DefaultStream {
-    fn poll(&self, mode: PollMode) -> Result<()> {
+impl DefaultStream {
+    /// Poll the stream with a timeout in milliseconds, where `-1` means no timeout.
+    ///
+    /// Returns whether the stream became ready before the timeout expired.
+    fn poll_impl(&self, mode: PollMode, timeout: i32) -> Result<bool> {
         #[cfg(unix)]
         {
             use nix::errno::Errno;
@@ -398,14 +497,13 @@ impl Stream for DefaultStream {
             let fd = self.as_raw_fd();
             let mut poll_fds = [PollFd::new(fd, poll_flags)];
             loop {
-                match poll(&mut poll_fds, -1) {
-                    Ok(_) => break,
+                match poll(&mut poll_fds, timeout) {
+                    // Let the errors (POLLERR) be handled when trying to read or write.
+                    Ok(count) => return Ok(count != 0),
                     Err(nix::Error::Sys(Errno::EINTR)) => {}
                     Err(e) => return Err(nix_error_to_io(e)),
                 }
             }
-            // Let the errors (POLLERR) be handled when trying to read or write.
-            Ok(())
         }
         #[cfg(windows)]
         {
@@ -425,11 +523,27 @@ impl Stream for DefaultStream {
                 events,
                 revents: 0,
             }];
-            let _ = wsa_poll(&mut poll_fds, -1)?;
+            let count = wsa_poll(&mut poll_fds, timeout)?;
             // Let the errors (POLLERR) be handled when trying to read or write.
-            Ok(())
+            Ok(count != 0)
         }
     }
+}
+
+impl Stream for DefaultStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        self.poll_impl(mode, -1).map(|_| ())
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<bool> {
+        // Round up so that we do not return before the timeout expired
+        let mut millis = timeout.as_millis();
+        if Duration::from_millis(millis as u64) < timeout {
+            millis += 1;
+        }
+        let millis = i32::try_from(millis).unwrap_or(i32::max_value());
+        self.poll_impl(mode, millis)
+    }
 
     fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
         #[cfg(unix)]
@@ -547,3 +661,59 @@ impl Stream for DefaultStream {
         }
     }
 }
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod test {
+    use std::os::unix::io::AsRawFd;
+
+    use nix::sys::socket::{
+        accept, bind, listen, socket, AddressFamily, SockAddr, SockFlag, SockType, UnixAddr,
+    };
+
+    use super::{DefaultStream, Stream};
+    use crate::utils::RawFdContainer;
+
+    #[test]
+    fn connect_abstract_unix() {
+        let name = format!("x11rb-test-abstract-{}", std::process::id());
+        let addr = UnixAddr::new_abstract(name.as_bytes()).unwrap();
+        let listener = socket(
+            AddressFamily::Unix,
+            SockType::Stream,
+            SockFlag::empty(),
+            None,
+        )
+        .unwrap();
+        let listener = RawFdContainer::new(listener);
+        bind(listener.as_raw_fd(), &SockAddr::Unix(addr)).unwrap();
+        listen(listener.as_raw_fd(), 1).unwrap();
+
+        let client = DefaultStream::connect_abstract_unix(name.as_bytes()).unwrap();
+        let server = RawFdContainer::new(accept(listener.as_raw_fd()).unwrap());
+        assert_eq!(nix::unistd::write(server.as_raw_fd(), b"x11"), Ok(3));
+
+        let mut buf = [0; 3];
+        client.read_exact(&mut buf, &mut Vec::new()).unwrap();
+        assert_eq!(&buf, b"x11");
+    }
+
+    #[test]
+    fn connect_abstract_unix_missing() {
+        let name = format!("x11rb-test-abstract-missing-{}", std::process::id());
+        let err = DefaultStream::connect_abstract_unix(name.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::ConnectionRefused);
+    }
+
+    #[test]
+    fn connect_socket_path() {
+        let path = std::env::temp_dir().join(format!("x11rb-test-socket-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+
+        let client = DefaultStream::connect(path.to_str().unwrap(), Some("unix"), 0);
+        let (_server, _) = listener.accept().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let (family, _) = client.unwrap().peer_addr().unwrap();
+        assert_eq!(family, super::Family::LOCAL);
+    }
+}
@@ -1,29 +1,68 @@
-//! Helpers for working with `~/.Xauthority`.
+//! Reading and writing `~/.Xauthority` files.
+//!
+//! An `.Xauthority` file contains the credentials that are needed for connecting to X11 servers.
+//! [`XAuthority`] allows to load such a file, to look up, add, and remove entries, and to write
+//! it back atomically. [`XAuthorityLock`] implements the lock file protocol of libXau, which
+//! should be used to protect against concurrent modifications by other programs.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use x11rb::rust_connection::xauth::{AuthEntry, Family, XAuthority, XAuthorityLock};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let path = x11rb::rust_connection::xauth::xauthority_file_name().unwrap();
+//! let _lock = XAuthorityLock::acquire(&path, 10, Duration::from_secs(1), Duration::from_secs(10))?;
+//! let mut xauthority = XAuthority::load(&path)?;
+//! xauthority.add(AuthEntry {
+//!     family: Family::LOCAL,
+//!     address: b"myhost".to_vec(),
+//!     number: b"99".to_vec(),
+//!     name: b"MIT-MAGIC-COOKIE-1".to_vec(),
+//!     data: vec![0x42; 16],
+//! });
+//! xauthority.save(&path)?;
+//! # Ok(())
+//! # }
+//! ```
 
 use std::io::Error;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::des::Des;
 use crate::protocol::xproto::Family as X11Family;
 
 const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+const XDM_AUTHORIZATION_1: &[u8] = b"XDM-AUTHORIZATION-1";
 
 /// A family describes how to interpret some bytes as an address in an `AuthEntry`.
 ///
-/// Compared to [`x11rb::protocol::xproto::Family`], this is a `u16` and not an `u8`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct Family(u16);
+/// Compared to [`crate::protocol::xproto::Family`], this is a `u16` and not an `u8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Family(u16);
 
-#[allow(dead_code)] // Some of these constants are unused, but still serve as documentation
 impl Family {
-    pub(crate) const INTERNET: Self = Self(0);
-    pub(crate) const DEC_NET: Self = Self(1);
-    pub(crate) const CHAOS: Self = Self(2);
-    pub(crate) const SERVER_INTERPRETED: Self = Self(5);
-    pub(crate) const INTERNET6: Self = Self(6);
-    pub(crate) const WILD: Self = Self(65535);
-    pub(crate) const LOCAL: Self = Self(256);
-    pub(crate) const NETNAME: Self = Self(254);
-    pub(crate) const KRB5_PRINCIPAL: Self = Self(253);
-    pub(crate) const LOCAL_HOST: Self = Self(252);
+    /// An IPv4 address.
+    pub const INTERNET: Self = Self(0);
+    /// A DECnet address.
+    pub const DEC_NET: Self = Self(1);
+    /// A Chaosnet address.
+    pub const CHAOS: Self = Self(2);
+    /// An address that is interpreted by the server.
+    pub const SERVER_INTERPRETED: Self = Self(5);
+    /// An IPv6 address.
+    pub const INTERNET6: Self = Self(6);
+    /// Matches any address.
+    pub const WILD: Self = Self(65535);
+    /// A local connection; the address is the host name.
+    pub const LOCAL: Self = Self(256);
+    /// A secure RPC netname.
+    pub const NETNAME: Self = Self(254);
+    /// A Kerberos 5 principal.
+    pub const KRB5_PRINCIPAL: Self = Self(253);
+    /// A local connection that is identified by the host name only.
+    pub const LOCAL_HOST: Self = Self(252);
 }
 
 impl From<X11Family> for Family {
@@ -38,25 +77,78 @@ impl From<u16> for Family {
     }
 }
 
+impl From<Family> for u16 {
+    fn from(value: Family) -> Self {
+        value.0
+    }
+}
+
 /// A single entry of an `.Xauthority` file.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct AuthEntry {
-    family: Family,
-    address: Vec<u8>,
-    number: Vec<u8>,
-    name: Vec<u8>,
-    data: Vec<u8>,
+pub struct AuthEntry {
+    /// The family of `address`.
+    pub family: Family,
+    /// The address of the X11 server that this entry applies to.
+    pub address: Vec<u8>,
+    /// The display number as a decimal string. An empty string matches all displays.
+    pub number: Vec<u8>,
+    /// The name of the authorization protocol, for example `MIT-MAGIC-COOKIE-1`.
+    pub name: Vec<u8>,
+    /// The data for the authorization protocol, for example the cookie.
+    pub data: Vec<u8>,
+}
+
+impl AuthEntry {
+    /// Check if this entry applies to the given display.
+    ///
+    /// `family` and `address` describe the X11 server and `display` is the display number.
+    /// Entries with family [`Family::WILD`] match any address and entries without a display
+    /// number match all displays.
+    pub fn matches(&self, family: Family, address: &[u8], display: u16) -> bool {
+        self.matches_address(family, address)
+            && self.matches_display(display.to_string().as_bytes())
+    }
+
+    fn matches_address(&self, family: Family, address: &[u8]) -> bool {
+        if family == Family::WILD || self.family == Family::WILD {
+            true
+        } else if family != self.family {
+            false
+        } else {
+            address == &self.address[..]
+        }
+    }
+
+    fn matches_display(&self, display: &[u8]) -> bool {
+        debug_assert!(!display.is_empty()); // This case is not handled here and would be a match
+        self.number.is_empty() || &self.number[..] == display
+    }
+
+    /// Read a single entry in the `.Xauthority` format.
+    ///
+    /// Returns `Ok(None)` when the end of the input is reached.
+    pub fn read(read: &mut impl std::io::Read) -> Result<Option<Self>, Error> {
+        file::read_entry(read)
+    }
+
+    /// Write this entry in the `.Xauthority` format.
+    pub fn write(&self, write: &mut impl std::io::Write) -> Result<(), Error> {
+        file::write_entry(write, self)
+    }
 }
 
 mod file {
-    //! Code for actually reading `~/.Xauthority`.
+    //! Code for actually reading and writing `~/.Xauthority`.
 
+    use std::convert::TryFrom;
     use std::env::var_os;
-    use std::fs::File;
-    use std::io::{BufReader, Error, ErrorKind, Read};
-    use std::path::PathBuf;
+    use std::ffi::OsString;
+    use std::fs::{File, OpenOptions};
+    use std::io::{BufReader, BufWriter, Error, ErrorKind, Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime};
 
-    use super::AuthEntry;
+    use super::{AuthEntry, Family};
 
     /// Read a single `u16` from an `~/.Xauthority` file.
     ///
@@ -83,7 +175,7 @@ mod file {
     /// This function tries to return `Ok(None)` when the end of the file is reached. However, the
     /// code also treats a single byte as 'end of file', because things were simpler to implement
     /// like this.
-    fn read_entry<R: Read>(read: &mut R) -> Result<Option<AuthEntry>, Error> {
+    pub(super) fn read_entry<R: Read>(read: &mut R) -> Result<Option<AuthEntry>, Error> {
         let family = match read_u16(read) {
             Ok(family) => family,
             Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
@@ -103,11 +195,40 @@ mod file {
         }))
     }
 
+    /// Write a single `u16` to an `~/.Xauthority` file.
+    fn write_u16<W: Write>(write: &mut W, value: u16) -> Result<(), Error> {
+        write.write_all(&value.to_be_bytes())
+    }
+
+    /// Write a single "byte array" to an `~/.Xauthority` file.
+    fn write_string<W: Write>(write: &mut W, value: &[u8]) -> Result<(), Error> {
+        let length = u16::try_from(value.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "Xauthority field is too long"))?;
+        write_u16(write, length)?;
+        write.write_all(value)
+    }
+
+    /// Write a single entry to an `~/.Xauthority` file.
+    pub(super) fn write_entry<W: Write>(write: &mut W, entry: &AuthEntry) -> Result<(), Error> {
+        write_u16(write, entry.family.into())?;
+        write_string(write, &entry.address)?;
+        write_string(write, &entry.number)?;
+        write_string(write, &entry.name)?;
+        write_string(write, &entry.data)
+    }
+
+    /// Append a suffix to a path, like libXau does for its lock files.
+    fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+        let mut path = OsString::from(path);
+        path.push(suffix);
+        path.into()
+    }
+
     /// Get the file name for `~/.Xauthority` based on environment variables.
     ///
-    /// The code in libXau contains a special case for Windows (looks like cygwin) that is not
-    /// handled here (yet?).
-    fn get_xauthority_file_name() -> Option<PathBuf> {
+    /// `$XAUTHORITY` is used if it is set, otherwise `.Xauthority` in `$HOME`. The code in libXau
+    /// contains a special case for Windows (looks like cygwin) that is not handled here (yet?).
+    pub fn xauthority_file_name() -> Option<PathBuf> {
         if let Some(name) = var_os("XAUTHORITY") {
             return Some(name.into());
         }
@@ -121,7 +242,7 @@ mod file {
 
     /// An iterator over the entries of an `.Xauthority` file
     #[derive(Debug)]
-    pub(crate) struct XAuthorityEntries(BufReader<File>);
+    pub struct XAuthorityEntries(BufReader<File>);
 
     impl XAuthorityEntries {
         /// Open `~/.Xauthority` for reading.
@@ -129,15 +250,16 @@ mod file {
         /// This function returns `Ok(None)` when the location of the `.Xauthority` file could not
         /// be determined. If opening the file failed (for example, because it does not exist),
         /// that error is returned.
-        pub(crate) fn new() -> Result<Option<XAuthorityEntries>, Error> {
-            get_xauthority_file_name()
-                .map(File::open)
-                .transpose()?
-                // At this point we have Option<File> and errors while opening the file were
-                // returned to the caller.
-                .map(|file| Ok(XAuthorityEntries(BufReader::new(file))))
+        pub fn new() -> Result<Option<XAuthorityEntries>, Error> {
+            xauthority_file_name()
+                .map(|path| Self::open(&path))
                 .transpose()
         }
+
+        /// Open the given `.Xauthority` file for reading.
+        pub fn open(path: &Path) -> Result<XAuthorityEntries, Error> {
+            Ok(XAuthorityEntries(BufReader::new(File::open(path)?)))
+        }
     }
 
     impl Iterator for XAuthorityEntries {
@@ -148,11 +270,235 @@ mod file {
         }
     }
 
+    /// The contents of an `.Xauthority` file.
+    ///
+    /// Modifications only happen in memory until [`XAuthority::save`] is called. Use an
+    /// [`XAuthorityLock`] to protect against concurrent modifications of the file.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct XAuthority {
+        entries: Vec<AuthEntry>,
+    }
+
+    impl XAuthority {
+        /// Create an empty list of entries.
+        pub fn new() -> Self {
+            Default::default()
+        }
+
+        /// Read all entries from the given reader.
+        pub fn read_from(mut read: impl Read) -> Result<Self, Error> {
+            let mut entries = Vec::new();
+            while let Some(entry) = read_entry(&mut read)? {
+                entries.push(entry);
+            }
+            Ok(Self { entries })
+        }
+
+        /// Write all entries to the given writer.
+        pub fn write_to(&self, mut write: impl Write) -> Result<(), Error> {
+            self.entries
+                .iter()
+                .try_for_each(|entry| write_entry(&mut write, entry))
+        }
+
+        /// Load the given `.Xauthority` file.
+        ///
+        /// A file that does not exist is treated as an empty file.
+        pub fn load(path: &Path) -> Result<Self, Error> {
+            match File::open(path) {
+                Ok(file) => Self::read_from(BufReader::new(file)),
+                Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(Self::new()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// Atomically replace the given `.Xauthority` file with these entries.
+        ///
+        /// Like the `xauth` tool, the entries are first written to a temporary file with the
+        /// suffix `-n`, which is then renamed to `path`. On unix, the file is only accessible by
+        /// its owner.
+        pub fn save(&self, path: &Path) -> Result<(), Error> {
+            let temp_path = with_suffix(path, "-n");
+            let mut options = OpenOptions::new();
+            let _ = options.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                let _ = options.mode(0o600);
+            }
+            let result = options.open(&temp_path).and_then(|file| {
+                let mut write = BufWriter::new(file);
+                self.write_to(&mut write)?;
+                write.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+                std::fs::rename(&temp_path, path)
+            });
+            if result.is_err() {
+                let _ = std::fs::remove_file(&temp_path);
+            }
+            result
+        }
+
+        /// Get all entries.
+        pub fn entries(&self) -> &[AuthEntry] {
+            &self.entries
+        }
+
+        /// Get all entries that apply to the given display.
+        ///
+        /// See [`AuthEntry::matches`] for the meaning of the arguments.
+        pub fn matching<'a>(
+            &'a self,
+            family: Family,
+            address: &'a [u8],
+            display: u16,
+        ) -> impl Iterator<Item = &'a AuthEntry> + 'a {
+            self.entries
+                .iter()
+                .filter(move |entry| entry.matches(family, address, display))
+        }
+
+        /// Add an entry.
+        ///
+        /// Like `xauth add`, this replaces an existing entry with the same family, address,
+        /// display number, and protocol name.
+        pub fn add(&mut self, entry: AuthEntry) {
+            let existing = self.entries.iter_mut().find(|existing| {
+                existing.family == entry.family
+                    && existing.address == entry.address
+                    && existing.number == entry.number
+                    && existing.name == entry.name
+            });
+            match existing {
+                Some(existing) => *existing = entry,
+                None => self.entries.push(entry),
+            }
+        }
+
+        /// Remove all entries for the given display and return how many were removed.
+        ///
+        /// Like `xauth remove`, this compares the address and the display number exactly, without
+        /// treating [`Family::WILD`] or an empty display number specially.
+        pub fn remove(&mut self, family: Family, address: &[u8], number: &[u8]) -> usize {
+            let old_len = self.entries.len();
+            self.entries.retain(|entry| {
+                entry.family != family || entry.address != address || entry.number != number
+            });
+            old_len - self.entries.len()
+        }
+
+        /// Only keep the entries for which `f` returns `true`.
+        pub fn retain(&mut self, f: impl FnMut(&AuthEntry) -> bool) {
+            self.entries.retain(f)
+        }
+    }
+
+    /// A lock on an `.Xauthority` file.
+    ///
+    /// This uses the same lock file protocol as libXau's `XauLockAuth()`: The lock is held while
+    /// the file `<path>-l` exists, which is created as a hard link to `<path>-c`. The lock is
+    /// released when this object is dropped.
+    #[derive(Debug)]
+    pub struct XAuthorityLock {
+        creat_path: PathBuf,
+        link_path: PathBuf,
+    }
+
+    impl XAuthorityLock {
+        /// Acquire the lock for the given `.Xauthority` file.
+        ///
+        /// If the lock cannot be acquired, this is retried `retries` times, waiting `timeout`
+        /// between attempts. An existing lock file that is older than `dead` is considered stale
+        /// and removed; a `dead` of zero always removes existing lock files. Returns an error of
+        /// kind `ErrorKind::TimedOut` if the lock could not be acquired.
+        pub fn acquire(
+            path: &Path,
+            retries: u32,
+            timeout: Duration,
+            dead: Duration,
+        ) -> Result<Self, Error> {
+            let creat_path = with_suffix(path, "-c");
+            let link_path = with_suffix(path, "-l");
+
+            if let Ok(metadata) = std::fs::metadata(&creat_path) {
+                let age = metadata
+                    .modified()
+                    .ok()
+                    .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+                if dead == Duration::from_secs(0) || age.map_or(false, |age| age > dead) {
+                    let _ = std::fs::remove_file(&creat_path);
+                    let _ = std::fs::remove_file(&link_path);
+                }
+            }
+
+            let mut created = false;
+            for _ in 0..retries {
+                if !created {
+                    match OpenOptions::new()
+                        .write(true)
+                        .create_new(true)
+                        .open(&creat_path)
+                    {
+                        Ok(_) => created = true,
+                        Err(ref e)
+                            if e.kind() == ErrorKind::AlreadyExists
+                                || e.kind() == ErrorKind::PermissionDenied => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                if created {
+                    match std::fs::hard_link(&creat_path, &link_path) {
+                        Ok(()) => {
+                            return Ok(Self {
+                                creat_path,
+                                link_path,
+                            })
+                        }
+                        // Someone removed our file, try to create it again
+                        Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                            created = false;
+                            continue;
+                        }
+                        Err(ref e) if e.kind() == ErrorKind::AlreadyExists => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                std::thread::sleep(timeout);
+            }
+            Err(Error::new(
+                ErrorKind::TimedOut,
+                "Timeout while locking the Xauthority file",
+            ))
+        }
+    }
+
+    impl Drop for XAuthorityLock {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.creat_path);
+            let _ = std::fs::remove_file(&self.link_path);
+        }
+    }
+
     #[cfg(test)]
     mod test {
         use super::super::{AuthEntry, Family};
-        use super::read_entry;
-        use std::io::Cursor;
+        use super::{read_entry, XAuthority, XAuthorityLock};
+        use std::io::{Cursor, ErrorKind};
+        use std::path::PathBuf;
+        use std::time::Duration;
+
+        fn entry(family: Family, address: &[u8], number: &[u8], data: &[u8]) -> AuthEntry {
+            AuthEntry {
+                family,
+                address: address.to_vec(),
+                number: number.to_vec(),
+                name: b"MIT-MAGIC-COOKIE-1".to_vec(),
+                data: data.to_vec(),
+            }
+        }
+
+        fn temp_path(name: &str) -> PathBuf {
+            std::env::temp_dir().join(format!("x11rb-test-{}-{}", name, std::process::id()))
+        }
 
         #[test]
         fn test_read() {
@@ -209,9 +555,78 @@ mod file {
             let entry = read_entry(&mut cursor).unwrap();
             assert_eq!(entry, None);
         }
+
+        #[test]
+        fn test_write_read() {
+            let mut xauthority = XAuthority::new();
+            xauthority.add(entry(Family::LOCAL, b"ZweiLED", b"1", b"1234"));
+            xauthority.add(entry(Family::INTERNET, &[1, 2, 3, 4], b"2", b"5678"));
+            let mut data = Vec::new();
+            xauthority.write_to(&mut data).unwrap();
+            assert_eq!(XAuthority::read_from(&data[..]).unwrap(), xauthority);
+        }
+
+        #[test]
+        fn test_add_remove() {
+            let mut xauthority = XAuthority::new();
+            xauthority.add(entry(Family::LOCAL, b"host", b"1", b"1234"));
+            xauthority.add(entry(Family::LOCAL, b"host", b"2", b"5678"));
+            // This replaces the first entry
+            xauthority.add(entry(Family::LOCAL, b"host", b"1", b"abcd"));
+            assert_eq!(
+                xauthority.entries(),
+                &[
+                    entry(Family::LOCAL, b"host", b"1", b"abcd"),
+                    entry(Family::LOCAL, b"host", b"2", b"5678"),
+                ]
+            );
+            let matching = xauthority
+                .matching(Family::LOCAL, b"host", 2)
+                .collect::<Vec<_>>();
+            assert_eq!(matching, [&entry(Family::LOCAL, b"host", b"2", b"5678")]);
+
+            assert_eq!(xauthority.remove(Family::LOCAL, b"host", b"1"), 1);
+            assert_eq!(xauthority.remove(Family::LOCAL, b"host", b"1"), 0);
+            assert_eq!(
+                xauthority.entries(),
+                &[entry(Family::LOCAL, b"host", b"2", b"5678")]
+            );
+        }
+
+        #[test]
+        fn test_save_load() {
+            let path = temp_path("xauthority");
+            assert_eq!(XAuthority::load(&path).unwrap(), XAuthority::new());
+
+            let mut xauthority = XAuthority::new();
+            xauthority.add(entry(Family::WILD, b"", b"", b"1234"));
+            xauthority.save(&path).unwrap();
+            let loaded = XAuthority::load(&path);
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(loaded.unwrap(), xauthority);
+        }
+
+        #[test]
+        fn test_lock() {
+            let path = temp_path("xauthority-lock");
+            let timeout = Duration::from_millis(1);
+            let dead = Duration::from_secs(60);
+
+            let lock = XAuthorityLock::acquire(&path, 1, timeout, dead).unwrap();
+            let err = XAuthorityLock::acquire(&path, 2, timeout, dead).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::TimedOut);
+            drop(lock);
+
+            let lock = XAuthorityLock::acquire(&path, 1, timeout, dead).unwrap();
+            // A dead time of zero breaks existing locks
+            let lock2 = XAuthorityLock::acquire(&path, 1, timeout, Duration::from_secs(0)).unwrap();
+            drop((lock, lock2));
+        }
     }
 }
 
+pub use file::{xauthority_file_name, XAuthority, XAuthorityEntries, XAuthorityLock};
+
 pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
 
 /// Get the authentication information necessary for connecting to the given display.
@@ -228,7 +643,7 @@ pub(crate) fn get_auth(
     address: &[u8],
     display: u16,
 ) -> Result<Option<AuthInfo>, Error> {
-    match file::XAuthorityEntries::new()? {
+    match XAuthorityEntries::new()? {
         None => Ok(None),
         Some(entries) => get_auth_impl(entries, family, address, display),
     }
@@ -240,43 +655,120 @@ pub(crate) fn get_auth_impl(
     address: &[u8],
     display: u16,
 ) -> Result<Option<AuthInfo>, Error> {
-    fn address_matches(
-        (family1, address1): (Family, &[u8]),
-        (family2, address2): (Family, &[u8]),
-    ) -> bool {
-        if family1 == Family::WILD || family2 == Family::WILD {
-            true
-        } else if family1 != family2 {
-            false
-        } else {
-            address1 == address2
+    let display = display.to_string();
+    let display = display.as_bytes();
+
+    // Like libxcb, prefer XDM-AUTHORIZATION-1 over MIT-MAGIC-COOKIE-1
+    let mut result = None;
+    for entry in entries {
+        let entry = entry?;
+
+        if entry.matches_address(family, address) && entry.matches_display(display) {
+            if entry.name == XDM_AUTHORIZATION_1 {
+                return Ok(Some((entry.name, entry.data)));
+            } else if entry.name == MIT_MAGIC_COOKIE_1 && result.is_none() {
+                result = Some((entry.name, entry.data));
+            }
         }
     }
+    Ok(result)
+}
 
-    fn display_number_matches(entry_number: &[u8], display_number: &[u8]) -> bool {
-        debug_assert!(!display_number.is_empty()); // This case is not handled here and would be a match
-        entry_number.is_empty() || entry_number == display_number
+/// Compute the authorization data that is sent to the X11 server.
+///
+/// `auth` is the authorization protocol name and data from the `.Xauthority` file, as returned by
+/// [`get_auth`]. `local_addr` is the address of our end of the connection, or `None` for a unix
+/// socket.
+///
+/// For most protocols, this just returns the given data. Returns `None` if the data cannot be
+/// computed, in which case no authorization should be used.
+pub(crate) fn compute_auth_data(
+    auth: AuthInfo,
+    local_addr: Option<SocketAddr>,
+) -> Option<AuthInfo> {
+    let (name, data) = auth;
+    if name != XDM_AUTHORIZATION_1 {
+        return Some((name, data));
     }
 
-    let display = display.to_string();
-    let display = display.as_bytes();
+    /// The counter that libxcb uses to generate fake addresses for unix sockets
+    static NONCE: AtomicU32 = AtomicU32::new(0);
 
-    for entry in entries {
-        let entry = entry?;
+    let (address, port) = match local_addr {
+        None => {
+            let address = 0xffff_ffff - NONCE.fetch_add(1, Ordering::Relaxed);
+            // Truncating the process ID is what libxcb does, too
+            (address.to_be_bytes(), std::process::id() as u16)
+        }
+        Some(SocketAddr::V4(addr)) => (addr.ip().octets(), addr.port()),
+        Some(SocketAddr::V6(addr)) => {
+            // Only IPv4-mapped addresses can be used
+            let octets = addr.ip().octets();
+            if octets[..10] != [0; 10] || octets[10..12] != [0xff; 2] {
+                return None;
+            }
+            (
+                [octets[12], octets[13], octets[14], octets[15]],
+                addr.port(),
+            )
+        }
+    };
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs() as u32)
+        .unwrap_or(0);
+    let data = xdm_authorization_1(&data, address, port, time)?;
+    Some((name, data))
+}
+
+/// Compute the authorization data for `XDM-AUTHORIZATION-1`.
+///
+/// `key` is the data from the `.Xauthority` file: 8 bytes that are sent to the server, followed
+/// by the DES key. The key's first byte is unused, leaving 56 key bits.
+///
+/// The sent data consists of the first 8 bytes of `key`, our address, our port, and the current
+/// time. This is padded to 24 bytes and then encrypted with DES in CBC mode.
+fn xdm_authorization_1(key: &[u8], address: [u8; 4], port: u16, time: u32) -> Option<Vec<u8>> {
+    if key.len() < 16 {
+        return None;
+    }
+    let mut data = Vec::with_capacity(24);
+    data.extend_from_slice(&key[..8]);
+    data.extend_from_slice(&address);
+    data.extend_from_slice(&port.to_be_bytes());
+    data.extend_from_slice(&time.to_be_bytes());
+    data.resize(24, 0);
+
+    // Spread the 56 bits of the key over 8 bytes, leaving the parity bits empty
+    let mut des_key = [0; 8];
+    let key_bits = key[9..16]
+        .iter()
+        .fold(0u64, |acc, &byte| (acc << 8) | u64::from(byte));
+    for (index, des_key_byte) in des_key.iter_mut().enumerate() {
+        *des_key_byte = ((key_bits >> (49 - 7 * index)) as u8) << 1;
+    }
+    let des = Des::new(des_key);
 
-        if address_matches((family, address), (entry.family, &entry.address))
-            && display_number_matches(&entry.number, &display[..])
-            && entry.name == MIT_MAGIC_COOKIE_1
+    let mut previous = [0; 8];
+    for chunk in data.chunks_mut(8) {
+        let mut block = [0; 8];
+        for (block_byte, (&data_byte, &previous_byte)) in
+            block.iter_mut().zip(chunk.iter().zip(previous.iter()))
         {
-            return Ok(Some((entry.name, entry.data)));
+            *block_byte = data_byte ^ previous_byte;
         }
+        previous = des.encrypt_block(block);
+        chunk.copy_from_slice(&previous);
     }
-    Ok(None)
+    Some(data)
 }
 
 #[cfg(test)]
 mod test {
-    use super::{get_auth_impl, AuthEntry, Family, MIT_MAGIC_COOKIE_1};
+    use super::{
+        get_auth_impl, xdm_authorization_1, AuthEntry, Family, MIT_MAGIC_COOKIE_1,
+        XDM_AUTHORIZATION_1,
+    };
 
     // Call the given function on a matching auth entry. The function can change the entry.
     // Afterwards, it should still be a match.
@@ -373,6 +865,62 @@ mod test {
 
     #[test]
     fn protocol_mismatch() {
-        expect_mismatch(|entry| entry.name = b"XDM-AUTHORIZATION-1".to_vec());
+        expect_mismatch(|entry| entry.name = b"MIT-KERBEROS-5".to_vec());
+    }
+
+    #[test]
+    fn prefer_xdm_authorization() {
+        let entry = |name: &[u8], data: &[u8]| {
+            Ok(AuthEntry {
+                family: Family::LOCAL,
+                address: b"whatever".to_vec(),
+                number: b"42".to_vec(),
+                name: name.to_vec(),
+                data: data.to_vec(),
+            })
+        };
+        let entries = vec![
+            entry(MIT_MAGIC_COOKIE_1, b"1234"),
+            entry(XDM_AUTHORIZATION_1, b"5678"),
+        ];
+        assert_eq!(
+            get_auth_impl(entries.into_iter(), Family::LOCAL, b"whatever", 42)
+                .unwrap()
+                .unwrap(),
+            (XDM_AUTHORIZATION_1.to_vec(), b"5678".to_vec())
+        );
+    }
+
+    #[test]
+    fn xdm_authorization_data() {
+        // The key is the 8 bytes that are sent followed by the DES key.
+        // The expected data was computed with libXdmcp's XdmcpWrap().
+        let time = u32::from_be_bytes([99, 106, 113, 120]);
+        for (des_key, expected) in &[
+            (
+                [0, 1, 2, 3, 4, 5, 6, 7],
+                [
+                    0x8d, 0x2d, 0xb9, 0xc6, 0x45, 0x70, 0xc9, 0xe4, 0x75, 0x9b, 0xe2, 0x11, 0xb2,
+                    0x07, 0x42, 0x27, 0x2f, 0xf0, 0x92, 0x6c, 0x17, 0x4f, 0x7f, 0xed,
+                ],
+            ),
+            (
+                [0xff, 0x80, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc],
+                [
+                    0x36, 0xc5, 0xf5, 0x0c, 0x9b, 0xed, 0x3b, 0x8a, 0xc8, 0x41, 0x68, 0xb1, 0xf3,
+                    0x91, 0x4b, 0x7d, 0x39, 0x22, 0x22, 0xaf, 0xaa, 0xa0, 0x39, 0x29,
+                ],
+            ),
+        ] {
+            let mut key = vec![1, 8, 15, 22, 29, 36, 43, 50];
+            key.extend_from_slice(des_key);
+            let data = xdm_authorization_1(&key, [57, 64, 71, 78], 0x555c, time).unwrap();
+            assert_eq!(&data[..], &expected[..]);
+        }
+    }
+
+    #[test]
+    fn xdm_authorization_short_key() {
+        assert_eq!(xdm_authorization_1(&[0; 15], [0; 4], 0, 0), None);
     }
 }
@@ -0,0 +1,756 @@
+//! The server side of an X11 connection.
+//!
+//! This module allows to act as an X11 server towards a client. This can be used for writing
+//! proxies or mock X11 servers for tests. A client connects via a [`Stream`] and is first
+//! represented by a [`PendingClient`]. The [`SetupRequest`] that the client sent can then be
+//! answered with a [`Setup`], a [`SetupFailed`], or a [`SetupAuthenticate`]. After a successful
+//! setup, a [`ServerConnection`] reads the requests of the client and sends back replies, errors
+//! and events.
+//!
+//! ```no_run
+//! use x11rb::protocol::xproto::{GetInputFocusReply, InputFocus, Setup};
+//! use x11rb::protocol::Request;
+//! use x11rb::rust_connection::DefaultStream;
+//! use x11rb::server::PendingClient;
+//!
+//! fn serve(stream: DefaultStream, setup: &Setup) -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = PendingClient::accept(stream)?;
+//!     let mut conn = client.send_setup(setup)?;
+//!     loop {
+//!         let (request, sequence) = conn.read_request()?;
+//!         if let Request::GetInputFocus(_) = request {
+//!             let reply = GetInputFocusReply {
+//!                 revert_to: InputFocus::NONE,
+//!                 sequence: 0,
+//!                 length: 0,
+//!                 focus: 0,
+//!             };
+//!             conn.send_reply(sequence, &reply)?;
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::convert::{TryFrom, TryInto};
+
+use crate::connection::SequenceNumber;
+use crate::errors::{ConnectError, ConnectionError, ParseError};
+use crate::extension_manager::ExtensionManager;
+use crate::protocol::xproto::{Setup, SetupAuthenticate, SetupFailed, SetupRequest};
+use crate::protocol::Request;
+use crate::rust_connection::{PollMode, Stream};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{
+    parse_request_header_with_order, BigRequests, ByteOrder, ExtensionInformation, Serialize,
+    TryParse, X11Error,
+};
+
+/// A client that connected, but whose connection setup was not answered yet.
+#[derive(Debug)]
+pub struct PendingClient<S> {
+    stream: S,
+    byte_order: ByteOrder,
+    setup_request: SetupRequest,
+}
+
+impl<S: Stream> PendingClient<S> {
+    /// Read the [`SetupRequest`] of a client from the given stream.
+    ///
+    /// The client may use either byte order. All further communication with the client happens
+    /// in the byte order that it announced in its `SetupRequest`.
+    pub fn accept(stream: S) -> Result<Self, ConnectError> {
+        let mut fds = Vec::new();
+        let mut request = vec![0; 12];
+        stream.read_exact(&mut request, &mut fds)?;
+        let byte_order = ByteOrder::from_setup_request_byte(request[0])?;
+        let auth_name_length = usize::from(u16::try_parse_with_order(&request[6..], byte_order)?.0);
+        let auth_data_length = usize::from(u16::try_parse_with_order(&request[8..], byte_order)?.0);
+        let length = 12 + pad4(auth_name_length) + pad4(auth_data_length);
+        request.resize(length, 0);
+        stream.read_exact(&mut request[12..], &mut fds)?;
+        if !fds.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "unexpectedly received FDs in connection setup",
+            )
+            .into());
+        }
+        let (setup_request, _) = SetupRequest::try_parse_with_order(&request, byte_order)?;
+        Ok(Self {
+            stream,
+            byte_order,
+            setup_request,
+        })
+    }
+
+    /// Get the byte order that the client uses.
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Get the [`SetupRequest`] that the client sent.
+    pub fn setup_request(&self) -> &SetupRequest {
+        &self.setup_request
+    }
+
+    /// Accept the client by sending the given [`Setup`].
+    ///
+    /// The `length` field of the `Setup` is ignored and the correct value is sent instead.
+    pub fn send_setup(self, setup: &Setup) -> Result<ServerConnection<S>, ConnectionError> {
+        write_setup_response(
+            &self.stream,
+            setup.serialize_with_order(self.byte_order),
+            self.byte_order,
+        )?;
+        Ok(ServerConnection::new(
+            self.stream,
+            self.byte_order,
+            setup.maximum_request_length.into(),
+        ))
+    }
+
+    /// Reject the client by sending the given [`SetupFailed`].
+    ///
+    /// The `length` field of the `SetupFailed` is ignored and the correct value is sent instead.
+    /// The stream is returned, so that the caller can decide when to close it.
+    pub fn send_setup_failed(self, failed: &SetupFailed) -> Result<S, ConnectionError> {
+        write_setup_response(
+            &self.stream,
+            failed.serialize_with_order(self.byte_order),
+            self.byte_order,
+        )?;
+        Ok(self.stream)
+    }
+
+    /// Ask the client for further authentication by sending the given [`SetupAuthenticate`].
+    ///
+    /// The X11 protocol does not specify how the authentication continues. The stream is
+    /// returned, so that the caller can implement this.
+    pub fn send_setup_authenticate(
+        self,
+        authenticate: &SetupAuthenticate,
+    ) -> Result<S, ConnectionError> {
+        write_setup_response(
+            &self.stream,
+            authenticate.serialize_with_order(self.byte_order),
+            self.byte_order,
+        )?;
+        Ok(self.stream)
+    }
+}
+
+/// The server side of an X11 connection after a successful connection setup.
+///
+/// The requests of the client are read with [`ServerConnection::read_request`]. Each request is
+/// numbered with a sequence number. This sequence number is used for sending a reply or an error
+/// for the request. Events are always sent with the sequence number of the last request that was
+/// read.
+///
+/// Requests for extensions can only be parsed if the extension was registered with
+/// [`ServerConnection::add_extension`]. If the client enables the BIG-REQUESTS extension via a
+/// `BigReqEnable` request, big requests are accepted for all following requests.
+///
+/// Requests that are longer than the maximum request length are rejected. This is initially the
+/// `maximum_request_length` of the [`Setup`] that was sent to the client and can be changed with
+/// [`ServerConnection::set_maximum_request_length`].
+#[derive(Debug)]
+pub struct ServerConnection<S> {
+    stream: S,
+    byte_order: ByteOrder,
+    fds: Vec<RawFdContainer>,
+    last_sequence_read: SequenceNumber,
+    big_requests: BigRequests,
+    maximum_request_length: u32,
+    ext_info: ExtensionManager,
+}
+
+impl<S: Stream> ServerConnection<S> {
+    fn new(stream: S, byte_order: ByteOrder, maximum_request_length: u32) -> Self {
+        Self {
+            stream,
+            byte_order,
+            fds: Vec::new(),
+            last_sequence_read: 0,
+            big_requests: BigRequests::NotEnabled,
+            maximum_request_length,
+            ext_info: Default::default(),
+        }
+    }
+
+    /// Register an extension that the server provides.
+    ///
+    /// Afterwards, requests with the extension's major opcode are parsed as requests of this
+    /// extension. The caller is responsible for answering `QueryExtension` requests with the same
+    /// information.
+    pub fn add_extension(&mut self, extension_name: &'static str, info: ExtensionInformation) {
+        self.ext_info.add_extension(extension_name, info);
+    }
+
+    /// Set the maximum length of requests that are accepted, in units of four bytes.
+    ///
+    /// After answering a `BigReqEnable` request, this should be set to the
+    /// `maximum_request_length` of the reply.
+    pub fn set_maximum_request_length(&mut self, maximum_request_length: u32) {
+        self.maximum_request_length = maximum_request_length;
+    }
+
+    /// Returns a reference to the contained stream.
+    pub fn stream(&self) -> &S {
+        &self.stream
+    }
+
+    /// Get the byte order that the client uses.
+    pub fn byte_order(&self) -> ByteOrder {
+        self.byte_order
+    }
+
+    /// Get the sequence number of the last request that was read.
+    pub fn last_sequence_read(&self) -> SequenceNumber {
+        self.last_sequence_read
+    }
+
+    /// Read the next request of the client, blocking until it is available.
+    ///
+    /// The request is returned together with its sequence number. If the request cannot be
+    /// parsed, an error is returned. The request still counts for the sequence numbering, so
+    /// that the caller can send an X11 error for it with the value of
+    /// [`ServerConnection::last_sequence_read`].
+    ///
+    /// A request whose length is too short for its header is skipped. A request that is longer
+    /// than the maximum request length is not read and
+    /// [`ConnectionError::MaximumRequestLengthExceeded`] is returned. This error is fatal, since
+    /// the following requests cannot be found in the stream anymore.
+    pub fn read_request(&mut self) -> Result<(Request<'static>, SequenceNumber), ConnectionError> {
+        let mut request = vec![0; 4];
+        self.stream.read_exact(&mut request, &mut self.fds)?;
+        let mut length = u32::from(u16::try_parse_with_order(&request[2..], self.byte_order)?.0);
+        if length == 0 && self.big_requests == BigRequests::Enabled {
+            request.resize(8, 0);
+            self.stream.read_exact(&mut request[4..], &mut self.fds)?;
+            length = u32::try_parse_with_order(&request[4..], self.byte_order)?.0;
+        }
+        self.last_sequence_read += 1;
+        if length > self.maximum_request_length {
+            return Err(ConnectionError::MaximumRequestLengthExceeded);
+        }
+        let length = usize::try_from(length)
+            .ok()
+            .and_then(|length| length.checked_mul(4))
+            .ok_or(ParseError::ConversionFailed)?;
+        if length < request.len() {
+            return Err(ParseError::InvalidValue.into());
+        }
+        let header_length = request.len();
+        request.resize(length, 0);
+        self.stream
+            .read_exact(&mut request[header_length..], &mut self.fds)?;
+
+        let (header, body) =
+            parse_request_header_with_order(&request, self.big_requests, self.byte_order)?;
+        let request = Request::parse_with_order(
+            header,
+            body,
+            &mut self.fds,
+            &self.ext_info,
+            self.byte_order,
+        )?
+        .into_owned();
+        if let Request::BigreqEnable(_) = request {
+            self.big_requests = BigRequests::Enabled;
+        }
+        Ok((request, self.last_sequence_read))
+    }
+
+    /// Send a reply to the request with the given sequence number.
+    ///
+    /// The sequence number in the reply is replaced with the given sequence number.
+    pub fn send_reply<R>(&self, sequence: SequenceNumber, reply: &R) -> Result<(), ConnectionError>
+    where
+        R: Serialize<Bytes = Vec<u8>>,
+    {
+        self.send_reply_with_fds(sequence, reply, Vec::new())
+    }
+
+    /// Send a reply with file descriptors to the request with the given sequence number.
+    ///
+    /// The sequence number in the reply is replaced with the given sequence number.
+    pub fn send_reply_with_fds<R>(
+        &self,
+        sequence: SequenceNumber,
+        reply: &R,
+        fds: Vec<RawFdContainer>,
+    ) -> Result<(), ConnectionError>
+    where
+        R: Serialize<Bytes = Vec<u8>>,
+    {
+        let mut reply = reply.serialize_with_order(self.byte_order);
+        set_sequence_number(&mut reply, sequence, self.byte_order);
+        write_all(&self.stream, &reply, fds)?;
+        Ok(())
+    }
+
+    /// Send an X11 error for the request with the given sequence number.
+    ///
+    /// The sequence number in the error is replaced with the given sequence number.
+    pub fn send_error(
+        &self,
+        sequence: SequenceNumber,
+        error: &X11Error,
+    ) -> Result<(), ConnectionError> {
+        let mut error = error.serialize_with_order(self.byte_order);
+        set_sequence_number(&mut error, sequence, self.byte_order);
+        write_all(&self.stream, &error, Vec::new())?;
+        Ok(())
+    }
+
+    /// Send an event to the client.
+    ///
+    /// The sequence number in the event is replaced with the sequence number of the last request
+    /// that was read. `event` must contain at least 32 bytes. Generic events may be longer. The
+    /// event must already be in the byte order of the client, see
+    /// [`ServerConnection::byte_order`].
+    pub fn send_event(&self, event: &[u8]) -> Result<(), ConnectionError> {
+        if event.len() < 32 {
+            return Err(ParseError::InsufficientData.into());
+        }
+        let mut event = event.to_vec();
+        // KeymapNotify events do not contain a sequence number
+        if event[0] & 0x7f != crate::protocol::xproto::KEYMAP_NOTIFY_EVENT {
+            set_sequence_number(&mut event, self.last_sequence_read, self.byte_order);
+        }
+        write_all(&self.stream, &event, Vec::new())?;
+        Ok(())
+    }
+}
+
+/// Round up to the next multiple of four.
+fn pad4(length: usize) -> usize {
+    (length + 3) & !3
+}
+
+/// Write the lower 16 bits of a sequence number into a reply, error or event.
+fn set_sequence_number(packet: &mut [u8], sequence: SequenceNumber, byte_order: ByteOrder) {
+    // Only the lower 16 bits of the sequence number are sent
+    let sequence = sequence as u16;
+    packet[2..4].copy_from_slice(&sequence.serialize_with_order(byte_order));
+}
+
+/// Send a response to a `SetupRequest`, fixing up its length field.
+fn write_setup_response(
+    stream: &impl Stream,
+    mut response: Vec<u8>,
+    byte_order: ByteOrder,
+) -> std::io::Result<()> {
+    response.resize(pad4(response.len()), 0);
+    let length = (response.len() - 8) / 4;
+    let length: u16 = length.try_into().map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "connection setup response is too long",
+        )
+    })?;
+    response[6..8].copy_from_slice(&length.serialize_with_order(byte_order));
+    write_all(stream, &response, Vec::new())
+}
+
+/// Write all of the given data and FDs to the stream.
+fn write_all(
+    stream: &impl Stream,
+    mut data: &[u8],
+    mut fds: Vec<RawFdContainer>,
+) -> std::io::Result<()> {
+    while !data.is_empty() {
+        stream.poll(PollMode::Writable)?;
+        // poll returned successfully, so the stream is writable.
+        match stream.write(data, &mut fds) {
+            Ok(0) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => data = &data[n..],
+            // Spurious wakeup from poll, try again
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(e) => return Err(e),
+        }
+    }
+    if !fds.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "failed to send FDs",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+
+    use super::{PendingClient, ServerConnection};
+    use crate::connection::{Connection, RequestConnection};
+    use crate::errors::{ConnectError, ConnectionError, ReplyError};
+    use crate::protocol::bigreq::{self, EnableReply};
+    use crate::protocol::xproto::{
+        AtomEnum, BackingStore, Colormap, ConnectionExt, Drawable, ExposeEvent, GetGeometryReply,
+        GetGeometryRequest, GetInputFocusReply, ImageOrder, InputFocus, PropMode,
+        QueryExtensionReply, Screen, Setup, SetupFailed, SetupRequest, Window, EXPOSE_EVENT,
+    };
+    use crate::protocol::{ErrorKind, Event, Request};
+    use crate::rust_connection::{DefaultStream, RustConnection};
+    use crate::wrapper::ConnectionExt as _;
+    use crate::x11_utils::{ByteOrder, ExtensionInformation, Serialize, TryParse, X11Error};
+
+    fn setup() -> Setup {
+        let screen = Screen {
+            root: Window::from(1u32),
+            default_colormap: Colormap::from(2u32),
+            white_pixel: 0xff_ffff,
+            black_pixel: 0,
+            current_input_masks: 0,
+            width_in_pixels: 640,
+            height_in_pixels: 480,
+            width_in_millimeters: 160,
+            height_in_millimeters: 120,
+            min_installed_maps: 1,
+            max_installed_maps: 1,
+            root_visual: 3,
+            backing_stores: BackingStore::NOT_USEFUL,
+            save_unders: false,
+            root_depth: 24,
+            allowed_depths: vec![],
+        };
+        Setup {
+            status: 1,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            release_number: 0,
+            resource_id_base: 0x0020_0000,
+            resource_id_mask: 0x001f_ffff,
+            motion_buffer_size: 0,
+            maximum_request_length: u16::max_value(),
+            image_byte_order: ImageOrder::LSB_FIRST,
+            bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+            bitmap_format_scanline_unit: 32,
+            bitmap_format_scanline_pad: 32,
+            min_keycode: 8,
+            max_keycode: 255,
+            vendor: b"x11rb".to_vec(),
+            pixmap_formats: vec![],
+            roots: vec![screen],
+        }
+    }
+
+    const BIGREQ_INFO: ExtensionInformation = ExtensionInformation {
+        major_opcode: 133,
+        first_event: 0,
+        first_error: 0,
+    };
+
+    /// Answer the client's requests until it disconnects.
+    ///
+    /// Returns the length of the data of all `ChangeProperty` requests.
+    fn serve(mut conn: ServerConnection<DefaultStream>) -> Vec<usize> {
+        let mut property_lengths = Vec::new();
+        loop {
+            let (request, sequence) = match conn.read_request() {
+                Ok(request) => request,
+                Err(ConnectionError::IOError(_)) => return property_lengths,
+                Err(e) => panic!("Unexpected error {:?}", e),
+            };
+            assert_eq!(sequence, conn.last_sequence_read());
+            match request {
+                Request::GetInputFocus(_) => {
+                    let reply = GetInputFocusReply {
+                        revert_to: InputFocus::PARENT,
+                        sequence: 0,
+                        length: 0,
+                        focus: Window::from(42u32),
+                    };
+                    // The client does not wait for the reply when this is the sync for a checked
+                    // request that caused an error, so it might already be gone.
+                    if conn.send_reply(sequence, &reply).is_err() {
+                        return property_lengths;
+                    }
+                }
+                Request::MapWindow(request) => {
+                    // This only converts something when XIDs are generated as distinct types
+                    #[allow(clippy::useless_conversion)]
+                    let bad_value = u32::from(request.window);
+                    let error = X11Error {
+                        error_kind: ErrorKind::Window,
+                        error_code: 3,
+                        sequence: 0,
+                        bad_value,
+                        minor_opcode: 0,
+                        major_opcode: 8,
+                    };
+                    conn.send_error(sequence, &error).unwrap();
+                    let event = ExposeEvent {
+                        response_type: EXPOSE_EVENT,
+                        sequence: 0,
+                        window: request.window,
+                        x: 0,
+                        y: 0,
+                        width: 10,
+                        height: 20,
+                        count: 0,
+                    };
+                    conn.send_event(&<[u8; 32]>::from(event)).unwrap();
+                }
+                Request::QueryExtension(request) => {
+                    assert_eq!(&request.name[..], bigreq::X11_EXTENSION_NAME.as_bytes());
+                    conn.add_extension(bigreq::X11_EXTENSION_NAME, BIGREQ_INFO);
+                    let reply = QueryExtensionReply {
+                        sequence: 0,
+                        length: 0,
+                        present: true,
+                        major_opcode: BIGREQ_INFO.major_opcode,
+                        first_event: BIGREQ_INFO.first_event,
+                        first_error: BIGREQ_INFO.first_error,
+                    };
+                    conn.send_reply(sequence, &reply).unwrap();
+                }
+                Request::BigreqEnable(_) => {
+                    let reply = EnableReply {
+                        sequence: 0,
+                        length: 0,
+                        maximum_request_length: 1 << 20,
+                    };
+                    conn.send_reply(sequence, &reply).unwrap();
+                    conn.set_maximum_request_length(reply.maximum_request_length);
+                }
+                Request::ChangeProperty(request) => property_lengths.push(request.data.len()),
+                request => panic!("Unexpected request {:?}", request),
+            }
+        }
+    }
+
+    fn connect() -> (RustConnection, ServerConnection<DefaultStream>) {
+        let (client, server) = UnixStream::pair().unwrap();
+        let server = thread::spawn(move || {
+            PendingClient::accept(DefaultStream::from_unix_stream(server).unwrap()).unwrap()
+        });
+        let client = DefaultStream::from_unix_stream(client).unwrap();
+        let client = thread::spawn(move || RustConnection::connect_to_stream(client, 0));
+        let server = server.join().unwrap();
+        let request = server.setup_request();
+        assert_eq!(
+            (
+                request.protocol_major_version,
+                request.protocol_minor_version
+            ),
+            (11, 0)
+        );
+        let server = server.send_setup(&setup()).unwrap();
+        let client = client.join().unwrap().unwrap();
+        (client, server)
+    }
+
+    #[test]
+    fn requests_replies_errors_events() {
+        let (client, server) = connect();
+        let server = thread::spawn(move || serve(server));
+        assert_eq!(
+            client.setup(),
+            &Setup {
+                length: 20,
+                ..setup()
+            }
+        );
+
+        let reply = client.get_input_focus().unwrap().reply().unwrap();
+        assert_eq!((reply.sequence, reply.focus), (1, Window::from(42u32)));
+
+        match client.map_window(Window::from(7u32)).unwrap().check() {
+            Err(ReplyError::X11Error(error)) => {
+                assert_eq!(error.error_kind, ErrorKind::Window);
+                assert_eq!((error.sequence, error.bad_value), (2, 7));
+            }
+            value => panic!("Unexpected value {:?}", value),
+        }
+        match client.wait_for_event().unwrap() {
+            Event::Expose(event) => {
+                assert_eq!((event.sequence, event.window), (2, Window::from(7u32)));
+                assert_eq!((event.width, event.height), (10, 20));
+            }
+            event => panic!("Unexpected event {:?}", event),
+        }
+
+        drop(client);
+        assert_eq!(server.join().unwrap(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn big_requests() {
+        let (client, server) = connect();
+        let server = thread::spawn(move || serve(server));
+
+        let data = vec![0x42; 300_000];
+        let (property, type_) = (AtomEnum::WM_NAME, AtomEnum::STRING);
+        let _ = client
+            .change_property8(
+                PropMode::REPLACE,
+                Window::from(1u32),
+                property,
+                type_,
+                &data,
+            )
+            .unwrap();
+        let _ = client
+            .change_property8(
+                PropMode::APPEND,
+                Window::from(1u32),
+                property,
+                type_,
+                &data[..4],
+            )
+            .unwrap();
+        assert_eq!(client.maximum_request_bytes(), 4 << 20);
+        let _ = client.get_input_focus().unwrap().reply().unwrap();
+
+        drop(client);
+        assert_eq!(server.join().unwrap(), vec![300_000, 4]);
+    }
+
+    #[test]
+    fn setup_failed() {
+        let (client, server) = UnixStream::pair().unwrap();
+        let client = DefaultStream::from_unix_stream(client).unwrap();
+        let client = thread::spawn(move || RustConnection::connect_to_stream(client, 0));
+        let server = PendingClient::accept(DefaultStream::from_unix_stream(server).unwrap());
+        let failed = SetupFailed {
+            status: 0,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            reason: b"go away".to_vec(),
+        };
+        let _stream = server.unwrap().send_setup_failed(&failed).unwrap();
+        match client.join().unwrap() {
+            Err(ConnectError::SetupFailed(received)) => {
+                assert_eq!(received.reason, failed.reason);
+                assert_eq!(received.length, 2);
+            }
+            value => panic!("Unexpected value {:?}", value),
+        }
+    }
+
+    #[test]
+    fn foreign_byte_order() {
+        let order = match ByteOrder::native() {
+            ByteOrder::LittleEndian => ByteOrder::BigEndian,
+            ByteOrder::BigEndian => ByteOrder::LittleEndian,
+        };
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let server = thread::spawn(move || {
+            let server = DefaultStream::from_unix_stream(server).unwrap();
+            let server = PendingClient::accept(server).unwrap();
+            assert_eq!(server.byte_order(), order);
+            let mut server = server.send_setup(&setup()).unwrap();
+            let (request, sequence) = server.read_request().unwrap();
+            match request {
+                Request::GetGeometry(request) => {
+                    assert_eq!(request.drawable, Drawable::from(0x0102_0304u32))
+                }
+                request => panic!("Unexpected request {:?}", request),
+            }
+            let reply = GetGeometryReply {
+                depth: 24,
+                sequence: 0,
+                length: 0,
+                root: Window::from(1u32),
+                x: -2,
+                y: 3,
+                width: 640,
+                height: 480,
+                border_width: 0,
+            };
+            server.send_reply(sequence, &reply).unwrap();
+        });
+
+        let request = SetupRequest {
+            byte_order: order.setup_request_byte(),
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            authorization_protocol_name: vec![],
+            authorization_protocol_data: vec![],
+        };
+        client
+            .write_all(&request.serialize_with_order(order))
+            .unwrap();
+        let mut response = vec![0; 8];
+        client.read_exact(&mut response).unwrap();
+        let length = u16::try_parse_with_order(&response[6..], order).unwrap().0;
+        response.resize(8 + 4 * usize::from(length), 0);
+        client.read_exact(&mut response[8..]).unwrap();
+        let (received, _) = Setup::try_parse_with_order(&response, order).unwrap();
+        assert_eq!(received, Setup { length, ..setup() });
+
+        let request = GetGeometryRequest {
+            drawable: Drawable::from(0x0102_0304u32),
+        };
+        for piece in request.serialize_with_order(order).0 {
+            client.write_all(&piece).unwrap();
+        }
+        let mut reply = vec![0; 32];
+        client.read_exact(&mut reply).unwrap();
+        let (reply, _) = GetGeometryReply::try_parse_with_order(&reply, order).unwrap();
+        assert_eq!((reply.sequence, reply.root), (1, Window::from(1u32)));
+        assert_eq!((reply.x, reply.y, reply.width), (-2, 3, 640));
+
+        server.join().unwrap();
+    }
+    #[test]
+    fn invalid_request_lengths() {
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let server = thread::spawn(move || {
+            let server = DefaultStream::from_unix_stream(server).unwrap();
+            let server = PendingClient::accept(server).unwrap();
+            let setup = Setup {
+                maximum_request_length: 100,
+                ..setup()
+            };
+            let mut server = server.send_setup(&setup).unwrap();
+            match server.read_request() {
+                Err(ConnectionError::ParseError(_)) => {}
+                value => panic!("Unexpected value {:?}", value),
+            }
+            assert_eq!(server.last_sequence_read(), 1);
+            match server.read_request() {
+                Ok((Request::GetInputFocus(_), 2)) => {}
+                value => panic!("Unexpected value {:?}", value),
+            }
+            match server.read_request() {
+                Err(ConnectionError::MaximumRequestLengthExceeded) => {}
+                value => panic!("Unexpected value {:?}", value),
+            }
+            assert_eq!(server.last_sequence_read(), 3);
+        });
+
+        let order = ByteOrder::native();
+        let request = SetupRequest {
+            byte_order: order.setup_request_byte(),
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            authorization_protocol_name: vec![],
+            authorization_protocol_data: vec![],
+        };
+        client
+            .write_all(&request.serialize_with_order(order))
+            .unwrap();
+        // A GetInputFocus request with a length of zero, a valid GetInputFocus request, and the
+        // header of a ChangeProperty request that is longer than allowed
+        for &(opcode, length) in &[(43, 0u16), (43, 1), (18, 101)] {
+            let mut header = vec![opcode, 0];
+            header.extend_from_slice(&length.to_ne_bytes());
+            client.write_all(&header).unwrap();
+        }
+
+        server.join().unwrap();
+    }
+}
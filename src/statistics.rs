@@ -0,0 +1,287 @@
+//! Statistics about the traffic of an X11 connection.
+//!
+//! Connections count the requests that they send, the bytes that they write and read, and how
+//! often they had to wait for the X11 server. This can be used to catch performance problems like
+//! round trips in a loop:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::errors::ReplyError;
+//! use x11rb::protocol::xproto::ConnectionExt;
+//! use x11rb::statistics::StatisticsConnection;
+//!
+//! fn at_most_one_round_trip<C>(conn: &C) -> Result<(), ReplyError>
+//! where
+//!     C: Connection + StatisticsConnection,
+//! {
+//!     let before = conn.statistics();
+//!     let first = conn.get_input_focus()?;
+//!     let second = conn.get_input_focus()?;
+//!     let _ = (first.reply()?, second.reply()?);
+//!     let statistics = conn.statistics().since(&before);
+//!     assert_eq!(statistics.requests_sent, 2);
+//!     assert!(statistics.blocking_waits <= 1);
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Counting the requests by their opcode costs a lock for every request, so this only happens
+//! after [`StatisticsConnection::count_requests_by_opcode`] was called.
+
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::io::IoSlice;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// The opcodes that identify the kind of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequestOpcode {
+    /// The major opcode of the request.
+    ///
+    /// For core requests, this identifies the request. For extension requests, this identifies
+    /// the extension. The major opcode of an extension can be looked up with
+    /// [`extension_information`](crate::connection::RequestConnection::extension_information).
+    pub major_opcode: u8,
+
+    /// The minor opcode of an extension request.
+    ///
+    /// This is `None` for core requests.
+    pub minor_opcode: Option<u8>,
+}
+
+impl RequestOpcode {
+    /// Get the opcodes of a request from its raw bytes.
+    ///
+    /// Returns `None` if the request is too short to contain the opcodes.
+    pub fn from_request(request: &[u8]) -> Option<Self> {
+        let major_opcode = *request.first()?;
+        // Core requests have major opcodes below 128
+        let minor_opcode = if major_opcode < 128 {
+            None
+        } else {
+            Some(*request.get(1)?)
+        };
+        Some(Self {
+            major_opcode,
+            minor_opcode,
+        })
+    }
+}
+
+/// A snapshot of the statistics of a connection.
+///
+/// All values count from the creation of the connection. Use [`Statistics::since`] to get the
+/// difference between two snapshots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statistics {
+    /// The number of requests that were sent.
+    pub requests_sent: u64,
+
+    /// The number of requests that were sent, by opcode.
+    ///
+    /// This is empty unless [`StatisticsConnection::count_requests_by_opcode`] was called.
+    pub requests: BTreeMap<RequestOpcode, u64>,
+
+    /// The number of bytes of requests that were sent.
+    pub bytes_written: u64,
+
+    /// The number of bytes of replies, errors, and events that were received.
+    pub bytes_read: u64,
+
+    /// The number of times that the connection's write buffer was flushed.
+    pub flushes: u64,
+
+    /// The number of times that waiting for a reply, error, or event could not be satisfied with
+    /// data that was already received, so that the connection had to block on the X11 server.
+    pub blocking_waits: u64,
+}
+
+impl Statistics {
+    /// Get the number of requests with the given major opcode that were sent.
+    ///
+    /// For extension requests, this counts all requests of the extension. Like
+    /// [`Statistics::requests`], this is only collected after
+    /// [`StatisticsConnection::count_requests_by_opcode`] was called.
+    pub fn requests_with_major_opcode(&self, major_opcode: u8) -> u64 {
+        self.requests
+            .iter()
+            .filter(|(opcode, _)| opcode.major_opcode == major_opcode)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Get the statistics of what happened after the `earlier` snapshot was taken.
+    pub fn since(&self, earlier: &Statistics) -> Statistics {
+        let requests = self
+            .requests
+            .iter()
+            .filter_map(|(&opcode, &count)| {
+                let earlier_count = earlier.requests.get(&opcode).copied().unwrap_or(0);
+                let count = count.saturating_sub(earlier_count);
+                if count == 0 {
+                    None
+                } else {
+                    Some((opcode, count))
+                }
+            })
+            .collect();
+        Statistics {
+            requests_sent: self.requests_sent.saturating_sub(earlier.requests_sent),
+            requests,
+            bytes_written: self.bytes_written.saturating_sub(earlier.bytes_written),
+            bytes_read: self.bytes_read.saturating_sub(earlier.bytes_read),
+            flushes: self.flushes.saturating_sub(earlier.flushes),
+            blocking_waits: self.blocking_waits.saturating_sub(earlier.blocking_waits),
+        }
+    }
+}
+
+/// A connection that collects [`Statistics`] about its traffic.
+pub trait StatisticsConnection {
+    /// Get a snapshot of the statistics of this connection.
+    fn statistics(&self) -> Statistics;
+
+    /// Start counting the requests that are sent by their opcode.
+    ///
+    /// The counts are available as [`Statistics::requests`]. Requests that were sent before this
+    /// function was called are not included.
+    fn count_requests_by_opcode(&self);
+}
+
+/// Helper for collecting [`Statistics`] in a connection.
+#[derive(Debug, Default)]
+pub(crate) struct StatisticsCollector {
+    requests_sent: AtomicU64,
+    count_by_opcode: AtomicBool,
+    requests: Mutex<BTreeMap<RequestOpcode, u64>>,
+    bytes_written: AtomicU64,
+    bytes_read: AtomicU64,
+    flushes: AtomicU64,
+    blocking_waits: AtomicU64,
+}
+
+impl StatisticsCollector {
+    /// Record that the given request was sent.
+    pub(crate) fn request_sent(&self, bufs: &[IoSlice<'_>]) {
+        let _ = self.requests_sent.fetch_add(1, Ordering::Relaxed);
+        let length: usize = bufs.iter().map(|buf| buf.len()).sum();
+        add(&self.bytes_written, length);
+
+        if self.count_by_opcode.load(Ordering::Relaxed) {
+            if let Some(opcode) = request_opcode(bufs) {
+                *self.requests.lock().unwrap().entry(opcode).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Start counting the requests by their opcode.
+    pub(crate) fn count_requests_by_opcode(&self) {
+        self.count_by_opcode.store(true, Ordering::Relaxed);
+    }
+
+    /// Record that the given number of bytes was received.
+    pub(crate) fn bytes_read(&self, length: usize) {
+        add(&self.bytes_read, length);
+    }
+
+    /// Record that the write buffer was flushed.
+    pub(crate) fn flush(&self) {
+        let _ = self.flushes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record that waiting for something had to block.
+    pub(crate) fn blocking_wait(&self) {
+        let _ = self.blocking_waits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Get a snapshot of the collected statistics.
+    pub(crate) fn snapshot(&self) -> Statistics {
+        Statistics {
+            requests_sent: self.requests_sent.load(Ordering::Relaxed),
+            requests: self.requests.lock().unwrap().clone(),
+            bytes_written: self.bytes_written.load(Ordering::Relaxed),
+            bytes_read: self.bytes_read.load(Ordering::Relaxed),
+            flushes: self.flushes.load(Ordering::Relaxed),
+            blocking_waits: self.blocking_waits.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Get the opcodes of a request that was split into the given buffers.
+///
+/// This works like [`RequestOpcode::from_request`], but does not need the request in one piece.
+fn request_opcode(bufs: &[IoSlice<'_>]) -> Option<RequestOpcode> {
+    let mut bytes = bufs.iter().flat_map(|buf| buf.iter());
+    let major_opcode = *bytes.next()?;
+    // Core requests have major opcodes below 128
+    let minor_opcode = if major_opcode < 128 {
+        None
+    } else {
+        Some(*bytes.next()?)
+    };
+    Some(RequestOpcode {
+        major_opcode,
+        minor_opcode,
+    })
+}
+
+fn add(counter: &AtomicU64, value: usize) {
+    let value: u64 = value.try_into().unwrap_or(u64::max_value());
+    let _ = counter.fetch_add(value, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::IoSlice;
+
+    use super::{RequestOpcode, StatisticsCollector};
+
+    #[test]
+    fn request_opcode() {
+        assert_eq!(RequestOpcode::from_request(&[]), None);
+        assert_eq!(
+            RequestOpcode::from_request(&[43]),
+            Some(RequestOpcode {
+                major_opcode: 43,
+                minor_opcode: None,
+            })
+        );
+        assert_eq!(RequestOpcode::from_request(&[130]), None);
+        assert_eq!(
+            RequestOpcode::from_request(&[130, 7, 1, 0]),
+            Some(RequestOpcode {
+                major_opcode: 130,
+                minor_opcode: Some(7),
+            })
+        );
+    }
+
+    #[test]
+    fn collect_and_diff() {
+        let collector = StatisticsCollector::default();
+        collector.request_sent(&[IoSlice::new(&[1, 0, 1, 0])]);
+        collector.count_requests_by_opcode();
+        collector.request_sent(&[IoSlice::new(&[43, 0, 1, 0])]);
+        let before = collector.snapshot();
+
+        collector.request_sent(&[IoSlice::new(&[130]), IoSlice::new(&[7, 2, 0, 0, 0, 0, 0])]);
+        collector.request_sent(&[IoSlice::new(&[130, 8, 1, 0])]);
+        collector.bytes_read(32);
+        collector.flush();
+        collector.blocking_wait();
+        let after = collector.snapshot();
+        assert_eq!(after.requests_sent, 4);
+        assert_eq!(after.requests.values().sum::<u64>(), 3);
+        assert_eq!(after.bytes_written, 20);
+
+        let diff = after.since(&before);
+        assert_eq!(diff.requests_sent, 2);
+        assert_eq!(diff.requests_with_major_opcode(43), 0);
+        assert_eq!(diff.requests_with_major_opcode(130), 2);
+        assert_eq!(diff.bytes_written, 12);
+        assert_eq!(diff.bytes_read, 32);
+        assert_eq!(diff.flushes, 1);
+        assert_eq!(diff.blocking_waits, 1);
+    }
+}
@@ -0,0 +1,155 @@
+//! The atoms of a fake X11 server.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use super::state::{xid, RequestError};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, GetAtomNameReply, InternAtomReply, InternAtomRequest,
+};
+
+/// The names of the predefined atoms, starting with atom 1.
+const PREDEFINED_ATOMS: [&str; 68] = [
+    "PRIMARY",
+    "SECONDARY",
+    "ARC",
+    "ATOM",
+    "BITMAP",
+    "CARDINAL",
+    "COLORMAP",
+    "CURSOR",
+    "CUT_BUFFER0",
+    "CUT_BUFFER1",
+    "CUT_BUFFER2",
+    "CUT_BUFFER3",
+    "CUT_BUFFER4",
+    "CUT_BUFFER5",
+    "CUT_BUFFER6",
+    "CUT_BUFFER7",
+    "DRAWABLE",
+    "FONT",
+    "INTEGER",
+    "PIXMAP",
+    "POINT",
+    "RECTANGLE",
+    "RESOURCE_MANAGER",
+    "RGB_COLOR_MAP",
+    "RGB_BEST_MAP",
+    "RGB_BLUE_MAP",
+    "RGB_DEFAULT_MAP",
+    "RGB_GRAY_MAP",
+    "RGB_GREEN_MAP",
+    "RGB_RED_MAP",
+    "STRING",
+    "VISUALID",
+    "WINDOW",
+    "WM_COMMAND",
+    "WM_HINTS",
+    "WM_CLIENT_MACHINE",
+    "WM_ICON_NAME",
+    "WM_ICON_SIZE",
+    "WM_NAME",
+    "WM_NORMAL_HINTS",
+    "WM_SIZE_HINTS",
+    "WM_ZOOM_HINTS",
+    "MIN_SPACE",
+    "NORM_SPACE",
+    "MAX_SPACE",
+    "END_SPACE",
+    "SUPERSCRIPT_X",
+    "SUPERSCRIPT_Y",
+    "SUBSCRIPT_X",
+    "SUBSCRIPT_Y",
+    "UNDERLINE_POSITION",
+    "UNDERLINE_THICKNESS",
+    "STRIKEOUT_ASCENT",
+    "STRIKEOUT_DESCENT",
+    "ITALIC_ANGLE",
+    "X_HEIGHT",
+    "QUAD_WIDTH",
+    "WEIGHT",
+    "POINT_SIZE",
+    "RESOLUTION",
+    "COPYRIGHT",
+    "NOTICE",
+    "FONT_NAME",
+    "FAMILY_NAME",
+    "FULL_NAME",
+    "CAP_HEIGHT",
+    "WM_CLASS",
+    "WM_TRANSIENT_FOR",
+];
+
+/// The atoms that exist on a fake X11 server.
+#[derive(Debug)]
+pub(super) struct Atoms {
+    /// The names of all atoms. The name of atom `n` is at index `n - 1`.
+    names: Vec<Vec<u8>>,
+    by_name: HashMap<Vec<u8>, Atom>,
+}
+
+impl Default for Atoms {
+    fn default() -> Self {
+        let mut atoms = Self {
+            names: Vec::new(),
+            by_name: HashMap::new(),
+        };
+        for name in PREDEFINED_ATOMS.iter() {
+            let _ = atoms.intern(name.as_bytes());
+        }
+        atoms
+    }
+}
+
+impl Atoms {
+    /// Get the atom for the given name, creating it if needed.
+    fn intern(&mut self, name: &[u8]) -> Atom {
+        if let Some(&atom) = self.by_name.get(name) {
+            return atom;
+        }
+        self.names.push(name.to_vec());
+        let atom = Atom::from(u32::try_from(self.names.len()).unwrap());
+        let _ = self.by_name.insert(name.to_vec(), atom);
+        atom
+    }
+
+    /// Check that an atom exists.
+    pub(super) fn check(&self, atom: Atom) -> Result<(), RequestError> {
+        self.name(atom).map(|_| ())
+    }
+
+    fn name(&self, atom: Atom) -> Result<&[u8], RequestError> {
+        usize::try_from(xid(atom))
+            .ok()
+            .and_then(|atom| atom.checked_sub(1))
+            .and_then(|index| self.names.get(index))
+            .map(|name| &name[..])
+            .ok_or_else(|| RequestError::new(xproto::ATOM_ERROR, xid(atom)))
+    }
+
+    /// Handle an `InternAtom` request.
+    pub(super) fn intern_atom(&mut self, request: &InternAtomRequest<'_>) -> InternAtomReply {
+        let atom = if request.only_if_exists {
+            self.by_name
+                .get(&request.name[..])
+                .copied()
+                .unwrap_or_else(|| AtomEnum::NONE.into())
+        } else {
+            self.intern(&request.name)
+        };
+        InternAtomReply {
+            sequence: 0,
+            length: 0,
+            atom,
+        }
+    }
+
+    /// Handle a `GetAtomName` request.
+    pub(super) fn get_atom_name(&self, atom: Atom) -> Result<GetAtomNameReply, RequestError> {
+        Ok(GetAtomNameReply {
+            sequence: 0,
+            length: 0,
+            name: self.name(atom)?.to_vec(),
+        })
+    }
+}
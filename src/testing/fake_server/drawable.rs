@@ -0,0 +1,174 @@
+//! Pixmaps and graphics contexts of a fake X11 server.
+
+use super::state::{xid, ClientId, RequestError, ServerState, PIXMAP_DEPTHS};
+use crate::protocol::xproto::{
+    self, CreatePixmapRequest, Drawable, Gcontext, Pixmap, Window, WindowClass,
+};
+
+/// A pixmap of the fake X11 server. Its contents are not stored.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PixmapState {
+    /// The client that created the pixmap.
+    pub(super) owner: ClientId,
+    pub(super) depth: u8,
+    pub(super) width: u16,
+    pub(super) height: u16,
+}
+
+/// A graphics context of the fake X11 server. Its values are not stored.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GcState {
+    /// The client that created the graphics context.
+    pub(super) owner: ClientId,
+    /// The depth of the drawable that the graphics context was created for.
+    depth: u8,
+}
+
+impl ServerState {
+    /// Get the pixmap with the given ID.
+    pub(super) fn pixmap(&self, pixmap: Pixmap) -> Result<&PixmapState, RequestError> {
+        self.pixmaps
+            .get(&pixmap)
+            .ok_or_else(|| RequestError::new(xproto::PIXMAP_ERROR, xid(pixmap)))
+    }
+
+    /// Get the depth of a window or pixmap.
+    fn drawable_depth(&self, drawable: Drawable) -> Result<u8, RequestError> {
+        let id = xid(drawable);
+        if let Some(window) = self.windows.get(&Window::from(id)) {
+            if window.class == WindowClass::INPUT_ONLY {
+                return Err(RequestError::matching());
+            }
+            Ok(window.depth)
+        } else if let Some(pixmap) = self.pixmaps.get(&Pixmap::from(id)) {
+            Ok(pixmap.depth)
+        } else {
+            Err(RequestError::new(xproto::DRAWABLE_ERROR, id))
+        }
+    }
+
+    /// Handle a `CreatePixmap` request.
+    pub(super) fn create_pixmap(
+        &mut self,
+        client: ClientId,
+        request: &CreatePixmapRequest,
+    ) -> Result<(), RequestError> {
+        self.check_new_id(client, request.pid)?;
+        let _ = self.drawable_depth(request.drawable)?;
+        if !PIXMAP_DEPTHS.contains(&request.depth) {
+            return Err(RequestError::value(request.depth.into()));
+        }
+        if request.width == 0 || request.height == 0 {
+            return Err(RequestError::value(0));
+        }
+        let pixmap = PixmapState {
+            owner: client,
+            depth: request.depth,
+            width: request.width,
+            height: request.height,
+        };
+        let _ = self.pixmaps.insert(request.pid, pixmap);
+        Ok(())
+    }
+
+    /// Handle a `FreePixmap` request.
+    pub(super) fn free_pixmap(&mut self, pixmap: Pixmap) -> Result<(), RequestError> {
+        let _ = self.pixmap(pixmap)?;
+        let _ = self.pixmaps.remove(&pixmap);
+        Ok(())
+    }
+
+    /// Handle a `CreateGC` request.
+    pub(super) fn create_gc(
+        &mut self,
+        client: ClientId,
+        gc: Gcontext,
+        drawable: Drawable,
+    ) -> Result<(), RequestError> {
+        self.check_new_id(client, gc)?;
+        let depth = self.drawable_depth(drawable)?;
+        let _ = self.gcs.insert(
+            gc,
+            GcState {
+                owner: client,
+                depth,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the graphics context with the given ID.
+    pub(super) fn check_gc(&self, gc: Gcontext) -> Result<&GcState, RequestError> {
+        self.gcs
+            .get(&gc)
+            .ok_or_else(|| RequestError::new(xproto::G_CONTEXT_ERROR, xid(gc)))
+    }
+
+    /// Handle a `CopyGC` request.
+    pub(super) fn copy_gc(&self, src_gc: Gcontext, dst_gc: Gcontext) -> Result<(), RequestError> {
+        if self.check_gc(src_gc)?.depth != self.check_gc(dst_gc)?.depth {
+            return Err(RequestError::matching());
+        }
+        Ok(())
+    }
+
+    /// Handle a `FreeGC` request.
+    pub(super) fn free_gc(&mut self, gc: Gcontext) -> Result<(), RequestError> {
+        let _ = self.check_gc(gc)?;
+        let _ = self.gcs.remove(&gc);
+        Ok(())
+    }
+
+    /// Handle a `ClearArea` request.
+    pub(super) fn clear_area(&self, window: Window) -> Result<(), RequestError> {
+        if self.window(window)?.class == WindowClass::INPUT_ONLY {
+            return Err(RequestError::matching());
+        }
+        Ok(())
+    }
+
+    /// Handle a `CopyArea` request.
+    pub(super) fn copy_area(
+        &self,
+        src_drawable: Drawable,
+        dst_drawable: Drawable,
+        gc: Gcontext,
+    ) -> Result<(), RequestError> {
+        let depth = self.drawable_depth(src_drawable)?;
+        self.check_drawing(dst_drawable, gc)?;
+        if depth != self.drawable_depth(dst_drawable)? {
+            return Err(RequestError::matching());
+        }
+        Ok(())
+    }
+
+    /// Handle a `CopyPlane` request.
+    pub(super) fn copy_plane(
+        &self,
+        src_drawable: Drawable,
+        dst_drawable: Drawable,
+        gc: Gcontext,
+        bit_plane: u32,
+    ) -> Result<(), RequestError> {
+        let depth = self.drawable_depth(src_drawable)?;
+        self.check_drawing(dst_drawable, gc)?;
+        // Exactly one bit must be set and it must be within the depth of the source
+        if !bit_plane.is_power_of_two() || bit_plane.trailing_zeros() >= u32::from(depth) {
+            return Err(RequestError::value(bit_plane));
+        }
+        Ok(())
+    }
+
+    /// Check the arguments of a request that draws with a graphics context.
+    pub(super) fn check_drawing(
+        &self,
+        drawable: Drawable,
+        gc: Gcontext,
+    ) -> Result<(), RequestError> {
+        let depth = self.drawable_depth(drawable)?;
+        if self.check_gc(gc)?.depth != depth {
+            return Err(RequestError::matching());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+//! An X11 server that runs inside the current process.
+
+use std::io::{IoSlice, Result};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::rust_connection::{PollMode, Stream};
+use crate::utils::RawFdContainer;
+
+mod atoms;
+mod drawable;
+mod property;
+mod state;
+mod window;
+
+pub(super) use state::setup;
+use state::{ClientId, ServerState};
+
+/// An X11 server that runs inside the current process.
+///
+/// Clients connect to this server via [`FakeServer::connect`]. All clients of a `FakeServer`
+/// share the same state, so that for example a window created by one client can be managed by
+/// another client. Cloning a `FakeServer` gives another handle to the same server.
+///
+/// Requests are handled immediately when a client sends them. Thus, everything happens
+/// deterministically and without any threads. The server implements the following parts of the
+/// core protocol:
+///
+/// * The window tree: `CreateWindow`, `DestroyWindow`, `DestroySubwindows`, `ReparentWindow`,
+///   `ChangeWindowAttributes`, `GetWindowAttributes`, `GetGeometry`, `QueryTree` and
+///   `TranslateCoordinates`.
+/// * Mapping and configuring windows with the events that this produces, including
+///   `SubstructureRedirect` for window managers: `MapWindow`, `MapSubwindows`, `UnmapWindow`,
+///   `UnmapSubwindows` and `ConfigureWindow`.
+/// * Atoms and properties: `InternAtom`, `GetAtomName`, `ChangeProperty`, `DeleteProperty`,
+///   `GetProperty` and `ListProperties`.
+/// * Selections and events between clients: `SetSelectionOwner`, `GetSelectionOwner`,
+///   `ConvertSelection` and `SendEvent`.
+/// * Pixmaps and graphics contexts: `CreatePixmap`, `FreePixmap`, `CreateGC`, `ChangeGC`,
+///   `CopyGC` and `FreeGC`. Drawing requests are checked for valid arguments, but do not draw
+///   anything.
+/// * The input focus via `SetInputFocus` and `GetInputFocus`.
+/// * `QueryExtension` and `ListExtensions`, which report that no extensions are present.
+/// * `GrabServer`, `UngrabServer`, `Bell` and `NoOperation`, which do nothing.
+///
+/// All other requests fail with an `Implementation` error.
+///
+/// There is no real input or output. Since nothing happens on its own, a client that waits for
+/// an event when none is pending would wait forever. Instead, [`FakeStream`] reports an error in
+/// this case.
+#[derive(Debug, Clone, Default)]
+pub struct FakeServer {
+    state: Arc<Mutex<ServerState>>,
+}
+
+impl FakeServer {
+    /// Create a new server without any clients.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Connect a new client to this server.
+    ///
+    /// The returned stream can be used for creating a
+    /// [`RustConnection`](crate::rust_connection::RustConnection).
+    pub fn connect(&self) -> FakeStream {
+        let client = self.state.lock().unwrap().add_client();
+        FakeStream {
+            state: Arc::clone(&self.state),
+            client,
+        }
+    }
+}
+
+/// A connection of a client to a [`FakeServer`].
+///
+/// The client is disconnected when the stream is dropped. This destroys all of its resources.
+/// This stream does not support FD passing.
+#[derive(Debug)]
+pub struct FakeStream {
+    state: Arc<Mutex<ServerState>>,
+    client: ClientId,
+}
+
+impl FakeStream {
+    fn has_output(&self) -> bool {
+        self.state.lock().unwrap().has_output(self.client)
+    }
+}
+
+impl Stream for FakeStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        if mode.writable() || self.has_output() {
+            Ok(())
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "the fake X11 server has nothing to send, so waiting would block forever",
+            ))
+        }
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<bool> {
+        // Nothing can happen while waiting, so the timeout expires immediately
+        let _ = timeout;
+        Ok(mode.writable() || self.has_output())
+    }
+
+    fn read(&self, buf: &mut [u8], _fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        match self.state.lock().unwrap().read(self.client, buf) {
+            0 if !buf.is_empty() => Err(std::io::ErrorKind::WouldBlock.into()),
+            length => Ok(length),
+        }
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        self.write_vectored(&[IoSlice::new(buf)], fds)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        if !fds.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "the fake X11 server does not support FD passing",
+            ));
+        }
+        let mut state = self.state.lock().unwrap();
+        for buf in bufs {
+            state.receive(self.client, buf);
+        }
+        Ok(bufs.iter().map(|buf| buf.len()).sum())
+    }
+}
+
+impl Drop for FakeStream {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.lock() {
+            state.disconnect(self.client);
+        }
+    }
+}
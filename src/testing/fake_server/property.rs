@@ -0,0 +1,384 @@
+//! Properties, selections and events between clients of a fake X11 server.
+
+use std::convert::TryFrom;
+
+use super::state::{xid, ClientId, RequestError, ServerState, ROOT_WINDOW};
+use crate::protocol::xproto::{
+    self, Atom, AtomEnum, ChangePropertyRequest, ConvertSelectionRequest, GetInputFocusReply,
+    GetPropertyReply, GetPropertyRequest, GetPropertyType, GetSelectionOwnerReply, InputFocus,
+    ListPropertiesReply, PropMode, Property, PropertyNotifyEvent, SelectionClearEvent,
+    SelectionNotifyEvent, SelectionRequestEvent, SendEventRequest, SetInputFocusRequest,
+    SetSelectionOwnerRequest, Timestamp, Window, WindowEnum,
+};
+
+/// The value of a property.
+#[derive(Debug)]
+pub(super) struct PropertyValue {
+    type_: Atom,
+    format: u8,
+    data: Vec<u8>,
+}
+
+/// The owner of a selection.
+#[derive(Debug)]
+pub(super) struct Selection {
+    /// The window that owns the selection, or zero.
+    pub(super) owner: Window,
+    /// The client that owns the selection.
+    pub(super) client: Option<ClientId>,
+    /// The last time that the owner changed.
+    time: Timestamp,
+}
+
+impl ServerState {
+    /// Send a `PropertyNotify` event.
+    fn property_notify(&mut self, window: Window, atom: Atom, state: Property) {
+        let event = PropertyNotifyEvent {
+            response_type: xproto::PROPERTY_NOTIFY_EVENT,
+            sequence: 0,
+            window,
+            atom,
+            time: self.time,
+            state,
+        };
+        self.deliver_event(
+            window,
+            xproto::EventMask::PROPERTY_CHANGE.into(),
+            event.into(),
+        );
+    }
+
+    /// Handle a `ChangeProperty` request.
+    pub(super) fn change_property(
+        &mut self,
+        request: &ChangePropertyRequest<'_>,
+    ) -> Result<(), RequestError> {
+        self.check_window(request.window)?;
+        self.atoms.check(request.property)?;
+        self.atoms.check(request.type_)?;
+        if ![8, 16, 32].contains(&request.format) {
+            return Err(RequestError::value(request.format.into()));
+        }
+        if ![PropMode::REPLACE, PropMode::PREPEND, PropMode::APPEND].contains(&request.mode) {
+            return Err(RequestError::value(u8::from(request.mode).into()));
+        }
+
+        let properties = &mut self.windows.get_mut(&request.window).unwrap().properties;
+        let existing = properties
+            .iter_mut()
+            .find(|(atom, _)| *atom == request.property);
+        match existing {
+            Some((_, value)) if request.mode == PropMode::REPLACE => {
+                value.type_ = request.type_;
+                value.format = request.format;
+                value.data = request.data.to_vec();
+            }
+            Some((_, value)) => {
+                if value.type_ != request.type_ || value.format != request.format {
+                    return Err(RequestError::matching());
+                }
+                if request.mode == PropMode::PREPEND {
+                    let _ = value.data.splice(..0, request.data.iter().copied());
+                } else {
+                    value.data.extend_from_slice(&request.data);
+                }
+            }
+            None => {
+                let value = PropertyValue {
+                    type_: request.type_,
+                    format: request.format,
+                    data: request.data.to_vec(),
+                };
+                properties.push((request.property, value));
+            }
+        }
+        self.property_notify(request.window, request.property, Property::NEW_VALUE);
+        Ok(())
+    }
+
+    /// Handle a `DeleteProperty` request.
+    pub(super) fn delete_property(
+        &mut self,
+        window: Window,
+        property: Atom,
+    ) -> Result<(), RequestError> {
+        self.check_window(window)?;
+        self.atoms.check(property)?;
+        let properties = &mut self.windows.get_mut(&window).unwrap().properties;
+        let length = properties.len();
+        properties.retain(|(atom, _)| *atom != property);
+        if properties.len() != length {
+            self.property_notify(window, property, Property::DELETE);
+        }
+        Ok(())
+    }
+
+    /// Handle a `GetProperty` request.
+    pub(super) fn get_property(
+        &mut self,
+        request: &GetPropertyRequest,
+    ) -> Result<GetPropertyReply, RequestError> {
+        self.check_window(request.window)?;
+        self.atoms.check(request.property)?;
+        if request.type_ != GetPropertyType::ANY.into() {
+            self.atoms.check(request.type_)?;
+        }
+        let mut reply = GetPropertyReply {
+            format: 0,
+            sequence: 0,
+            length: 0,
+            type_: AtomEnum::NONE.into(),
+            bytes_after: 0,
+            value_len: 0,
+            value: Vec::new(),
+        };
+        let properties = &self.windows[&request.window].properties;
+        let value = match properties
+            .iter()
+            .find(|(atom, _)| *atom == request.property)
+        {
+            Some((_, value)) => value,
+            None => return Ok(reply),
+        };
+        reply.format = value.format;
+        reply.type_ = value.type_;
+        if request.type_ != GetPropertyType::ANY.into() && request.type_ != value.type_ {
+            reply.bytes_after = u32::try_from(value.data.len()).unwrap();
+            return Ok(reply);
+        }
+
+        let length = value.data.len();
+        let offset = usize::try_from(request.long_offset)
+            .ok()
+            .and_then(|offset| offset.checked_mul(4))
+            .filter(|&offset| offset <= length)
+            .ok_or_else(|| RequestError::value(request.long_offset))?;
+        let wanted = usize::try_from(request.long_length)
+            .ok()
+            .and_then(|wanted| wanted.checked_mul(4))
+            .unwrap_or(usize::max_value());
+        let end = offset + wanted.min(length - offset);
+        reply.value = value.data[offset..end].to_vec();
+        reply.bytes_after = u32::try_from(length - end).unwrap();
+        reply.value_len = u32::try_from((end - offset) / usize::from(value.format / 8)).unwrap();
+
+        if request.delete && reply.bytes_after == 0 {
+            self.delete_property(request.window, request.property)?;
+        }
+        Ok(reply)
+    }
+
+    /// Handle a `ListProperties` request.
+    pub(super) fn list_properties(
+        &self,
+        window: Window,
+    ) -> Result<ListPropertiesReply, RequestError> {
+        let atoms = self
+            .window(window)?
+            .properties
+            .iter()
+            .map(|(atom, _)| *atom)
+            .collect();
+        Ok(ListPropertiesReply {
+            sequence: 0,
+            length: 0,
+            atoms,
+        })
+    }
+
+    /// Get the time from a request, replacing `CurrentTime` with the server time.
+    fn request_time(&self, time: Timestamp) -> Timestamp {
+        if time == 0 {
+            self.time
+        } else {
+            time
+        }
+    }
+
+    /// Handle a `SetSelectionOwner` request.
+    pub(super) fn set_selection_owner(
+        &mut self,
+        client: ClientId,
+        request: &SetSelectionOwnerRequest,
+    ) -> Result<(), RequestError> {
+        self.atoms.check(request.selection)?;
+        if request.owner != WindowEnum::NONE.into() {
+            self.check_window(request.owner)?;
+        }
+        let time = self.request_time(request.time);
+        let previous = self.selections.get(&request.selection);
+        if time > self.time || previous.map_or(false, |previous| time < previous.time) {
+            return Ok(());
+        }
+
+        let new_client = if request.owner == WindowEnum::NONE.into() {
+            None
+        } else {
+            Some(client)
+        };
+        if let Some(previous) = previous {
+            if let Some(previous_client) = previous.client {
+                if new_client != Some(previous_client) {
+                    let event = SelectionClearEvent {
+                        response_type: xproto::SELECTION_CLEAR_EVENT,
+                        sequence: 0,
+                        time,
+                        owner: previous.owner,
+                        selection: request.selection,
+                    };
+                    self.send_event(previous_client, event.into());
+                }
+            }
+        }
+        let selection = Selection {
+            owner: request.owner,
+            client: new_client,
+            time,
+        };
+        let _ = self.selections.insert(request.selection, selection);
+        Ok(())
+    }
+
+    /// Handle a `GetSelectionOwner` request.
+    pub(super) fn get_selection_owner(
+        &self,
+        selection: Atom,
+    ) -> Result<GetSelectionOwnerReply, RequestError> {
+        self.atoms.check(selection)?;
+        let owner = self
+            .selections
+            .get(&selection)
+            .map_or(WindowEnum::NONE.into(), |selection| selection.owner);
+        Ok(GetSelectionOwnerReply {
+            sequence: 0,
+            length: 0,
+            owner,
+        })
+    }
+
+    /// Handle a `ConvertSelection` request.
+    pub(super) fn convert_selection(
+        &mut self,
+        request: &ConvertSelectionRequest,
+    ) -> Result<(), RequestError> {
+        let requestor = self.window(request.requestor)?.owner;
+        self.atoms.check(request.selection)?;
+        self.atoms.check(request.target)?;
+        if request.property != AtomEnum::NONE.into() {
+            self.atoms.check(request.property)?;
+        }
+        let time = self.request_time(request.time);
+        let owner = self
+            .selections
+            .get(&request.selection)
+            .and_then(|selection| selection.client.map(|client| (client, selection.owner)));
+        match owner {
+            Some((client, owner)) => {
+                let event = SelectionRequestEvent {
+                    response_type: xproto::SELECTION_REQUEST_EVENT,
+                    sequence: 0,
+                    time,
+                    owner,
+                    requestor: request.requestor,
+                    selection: request.selection,
+                    target: request.target,
+                    property: request.property,
+                };
+                self.send_event(client, event.into());
+            }
+            None => {
+                let event = SelectionNotifyEvent {
+                    response_type: xproto::SELECTION_NOTIFY_EVENT,
+                    sequence: 0,
+                    time,
+                    requestor: request.requestor,
+                    selection: request.selection,
+                    target: request.target,
+                    property: AtomEnum::NONE.into(),
+                };
+                if let Some(requestor) = requestor {
+                    self.send_event(requestor, event.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle a `SendEvent` request.
+    pub(super) fn send_event_request(
+        &mut self,
+        request: &SendEventRequest<'_>,
+    ) -> Result<(), RequestError> {
+        let mut window = match xid(request.destination) {
+            // PointerWindow: There is no pointer, so it is always on the root window
+            0 => Window::from(ROOT_WINDOW),
+            // InputFocus
+            1 => match xid(self.focus) {
+                0 => return Ok(()),
+                1 => Window::from(ROOT_WINDOW),
+                _ => self.focus,
+            },
+            _ => request.destination,
+        };
+        self.check_window(window)?;
+        let mut event = *request.event;
+        if event[0] == 0 || event[0] & 0x80 != 0 {
+            return Err(RequestError::value(event[0].into()));
+        }
+        // Mark the event as sent by SendEvent
+        event[0] |= 0x80;
+
+        if request.event_mask == 0 {
+            if let Some(owner) = self.window(window)?.owner {
+                self.send_event(owner, event);
+            }
+            return Ok(());
+        }
+        loop {
+            let clients = self.interested_clients(window, request.event_mask);
+            if !clients.is_empty() {
+                for client in clients {
+                    self.send_event(client, event);
+                }
+                return Ok(());
+            }
+            let state = self.window(window)?;
+            let propagate = u32::from(state.do_not_propagate_mask) & request.event_mask == 0;
+            match state.parent {
+                Some(parent) if request.propagate && propagate => window = parent,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Handle a `SetInputFocus` request.
+    pub(super) fn set_input_focus(
+        &mut self,
+        request: &SetInputFocusRequest,
+    ) -> Result<(), RequestError> {
+        if xid(request.focus) > 1 {
+            self.check_window(request.focus)?;
+            if !self.is_viewable(request.focus) {
+                return Err(RequestError::matching());
+            }
+        }
+        self.focus = request.focus;
+        self.focus_revert_to = request.revert_to;
+        Ok(())
+    }
+
+    /// Handle a `GetInputFocus` request.
+    pub(super) fn get_input_focus(&self) -> GetInputFocusReply {
+        let revert_to = if xid(self.focus) > 1 {
+            self.focus_revert_to
+        } else {
+            InputFocus::NONE
+        };
+        GetInputFocusReply {
+            revert_to,
+            sequence: 0,
+            length: 0,
+            focus: self.focus,
+        }
+    }
+}
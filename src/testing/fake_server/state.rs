@@ -0,0 +1,526 @@
+//! The state of a fake X11 server and the handling of requests.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+
+use super::atoms::Atoms;
+use super::drawable::{GcState, PixmapState};
+use super::property::Selection;
+use super::window::WindowState;
+use crate::connection::SequenceNumber;
+use crate::extension_manager::ExtensionManager;
+use crate::protocol::xproto::{
+    self, BackingStore, Depth, Format, ImageOrder, InputFocus, ListExtensionsReply,
+    QueryExtensionReply, Screen, Setup, SetupFailed, SetupRequest, VisualClass, Visualtype, Window,
+    WindowEnum,
+};
+use crate::protocol::Request;
+use crate::testing::setup_request_length;
+use crate::x11_utils::{parse_request_header, BigRequests, Serialize, TryParse};
+
+/// Identifies a client of the server.
+pub(super) type ClientId = u32;
+
+/// The XID of the root window of the only screen.
+pub(super) const ROOT_WINDOW: u32 = 0x100;
+/// The XID of the default colormap of the screen.
+pub(super) const DEFAULT_COLORMAP: u32 = 0x101;
+/// The visual of the root window.
+pub(super) const ROOT_VISUAL: xproto::Visualid = 0x102;
+/// The depth of the root window.
+pub(super) const ROOT_DEPTH: u8 = 24;
+/// The depths that are supported for pixmaps.
+pub(super) const PIXMAP_DEPTHS: [u8; 3] = [1, 24, 32];
+
+/// The number of bits in a resource ID that a client can choose freely.
+const RESOURCE_ID_BITS: u32 = 21;
+
+#[cfg(target_endian = "little")]
+const BYTE_ORDER: u8 = 0x6c;
+
+#[cfg(target_endian = "big")]
+const BYTE_ORDER: u8 = 0x42;
+
+/// Get the value of an XID.
+///
+/// This works both with XIDs that are aliases of `u32` and with the distinct XID types that the
+/// code generator produces with `--xid-newtypes`.
+pub(super) fn xid(id: impl Into<u32>) -> u32 {
+    id.into()
+}
+
+/// An X11 error that is caused by a request.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RequestError {
+    error_code: u8,
+    bad_value: u32,
+}
+
+impl RequestError {
+    pub(super) fn new(error_code: u8, bad_value: u32) -> Self {
+        Self {
+            error_code,
+            bad_value,
+        }
+    }
+
+    pub(super) fn value(bad_value: u32) -> Self {
+        Self::new(xproto::VALUE_ERROR, bad_value)
+    }
+
+    pub(super) fn window(window: Window) -> Self {
+        Self::new(xproto::WINDOW_ERROR, xid(window))
+    }
+
+    pub(super) fn matching() -> Self {
+        Self::new(xproto::MATCH_ERROR, 0)
+    }
+}
+
+/// The result of handling a request: Either the bytes of a reply, nothing, or an error.
+type RequestResult = Result<Option<Vec<u8>>, RequestError>;
+
+fn void(result: Result<(), RequestError>) -> RequestResult {
+    result.map(|()| None)
+}
+
+fn reply<R: Serialize<Bytes = Vec<u8>>>(result: Result<R, RequestError>) -> RequestResult {
+    result.map(|reply| Some(reply.serialize()))
+}
+
+/// A client of the server.
+#[derive(Debug)]
+struct Client {
+    /// Data that was received from the client, but not handled yet.
+    input: Vec<u8>,
+    /// Data that should be sent to the client.
+    output: Vec<u8>,
+    /// Was the connection setup completed?
+    setup_done: bool,
+    /// Did the connection setup fail, so that everything else is ignored?
+    setup_failed: bool,
+    /// The sequence number of the last request of the client.
+    last_sequence: SequenceNumber,
+}
+
+/// The state of a fake X11 server.
+#[derive(Debug)]
+pub(super) struct ServerState {
+    clients: BTreeMap<ClientId, Client>,
+    next_client: ClientId,
+    pub(super) windows: HashMap<Window, WindowState>,
+    pub(super) pixmaps: HashMap<xproto::Pixmap, PixmapState>,
+    pub(super) gcs: HashMap<xproto::Gcontext, GcState>,
+    pub(super) atoms: Atoms,
+    pub(super) selections: HashMap<xproto::Atom, Selection>,
+    pub(super) focus: Window,
+    pub(super) focus_revert_to: InputFocus,
+    /// The current server time. This is incremented for every request.
+    pub(super) time: xproto::Timestamp,
+}
+
+impl Default for ServerState {
+    fn default() -> Self {
+        let mut windows = HashMap::new();
+        let _ = windows.insert(Window::from(ROOT_WINDOW), WindowState::root());
+        Self {
+            clients: BTreeMap::new(),
+            next_client: 1,
+            windows,
+            pixmaps: HashMap::new(),
+            gcs: HashMap::new(),
+            atoms: Atoms::default(),
+            selections: HashMap::new(),
+            focus: InputFocus::POINTER_ROOT.into(),
+            focus_revert_to: InputFocus::POINTER_ROOT,
+            time: 1,
+        }
+    }
+}
+
+impl ServerState {
+    /// Add a new client to the server.
+    pub(super) fn add_client(&mut self) -> ClientId {
+        let client = self.next_client;
+        self.next_client += 1;
+        let state = Client {
+            input: Vec::new(),
+            output: Vec::new(),
+            setup_done: false,
+            setup_failed: false,
+            last_sequence: 0,
+        };
+        let _ = self.clients.insert(client, state);
+        client
+    }
+
+    /// Remove a client and all of its resources.
+    pub(super) fn disconnect(&mut self, client: ClientId) {
+        let _ = self.clients.remove(&client);
+
+        let mut windows = self
+            .windows
+            .iter()
+            .filter(|(_, window)| window.owner == Some(client))
+            .map(|(&id, _)| id)
+            .collect::<Vec<_>>();
+        windows.sort();
+        for window in windows {
+            // The window might already be destroyed together with its parent
+            if self.windows.contains_key(&window) {
+                self.destroy_window(window);
+            }
+        }
+        for window in self.windows.values_mut() {
+            let _ = window.event_masks.remove(&client);
+        }
+        self.pixmaps.retain(|_, pixmap| pixmap.owner != client);
+        self.gcs.retain(|_, gc| gc.owner != client);
+        for selection in self.selections.values_mut() {
+            if selection.client == Some(client) {
+                selection.owner = WindowEnum::NONE.into();
+                selection.client = None;
+            }
+        }
+    }
+
+    /// Is there any data that should be sent to the given client?
+    pub(super) fn has_output(&self, client: ClientId) -> bool {
+        self.clients
+            .get(&client)
+            .map_or(false, |client| !client.output.is_empty())
+    }
+
+    /// Move data that should be sent to the client into the given buffer.
+    pub(super) fn read(&mut self, client: ClientId, buf: &mut [u8]) -> usize {
+        let output = match self.clients.get_mut(&client) {
+            Some(client) => &mut client.output,
+            None => return 0,
+        };
+        let length = buf.len().min(output.len());
+        buf[..length].copy_from_slice(&output[..length]);
+        let _ = output.drain(..length);
+        length
+    }
+
+    /// Handle data that the client sent.
+    pub(super) fn receive(&mut self, client_id: ClientId, data: &[u8]) {
+        let client = match self.clients.get_mut(&client_id) {
+            Some(client) => client,
+            None => return,
+        };
+        client.input.extend_from_slice(data);
+        loop {
+            let client = self.clients.get_mut(&client_id).unwrap();
+            if client.setup_failed {
+                client.input.clear();
+                return;
+            }
+            if !client.setup_done {
+                let length = match setup_request_length(&client.input) {
+                    Some(length) if length <= client.input.len() => length,
+                    _ => return,
+                };
+                let request = client.input.drain(..length).collect::<Vec<_>>();
+                self.handle_setup(client_id, &request);
+                continue;
+            }
+
+            if client.input.len() < 4 {
+                return;
+            }
+            // BIG-REQUESTS is not supported, so a length of zero is invalid. Handle it like a
+            // request without any data, which causes a `Length` error.
+            let length = usize::from(u16::from_ne_bytes([client.input[2], client.input[3]]));
+            let length = (length * 4).max(4);
+            if client.input.len() < length {
+                return;
+            }
+            let request = client.input.drain(..length).collect::<Vec<_>>();
+            client.last_sequence += 1;
+            self.time += 1;
+            self.handle_request(client_id, &request);
+        }
+    }
+
+    /// Handle a `SetupRequest`.
+    fn handle_setup(&mut self, client_id: ClientId, request: &[u8]) {
+        let client = self.clients.get_mut(&client_id).unwrap();
+        let reason: &[u8] = match SetupRequest::try_parse(request) {
+            Ok((request, _)) if request.byte_order != BYTE_ORDER => b"unsupported byte order",
+            Ok((request, _)) if request.protocol_major_version != 11 => {
+                b"unsupported protocol version"
+            }
+            Ok(_) => {
+                client.setup_done = true;
+                let mut setup = setup(client_id).serialize();
+                fix_setup_length(&mut setup);
+                client.output.extend_from_slice(&setup);
+                return;
+            }
+            Err(_) => b"invalid setup request",
+        };
+        let failed = SetupFailed {
+            status: 0,
+            protocol_major_version: 11,
+            protocol_minor_version: 0,
+            length: 0,
+            reason: reason.to_vec(),
+        };
+        let mut failed = failed.serialize();
+        fix_setup_length(&mut failed);
+        client.output.extend_from_slice(&failed);
+        client.setup_failed = true;
+    }
+
+    /// Handle a request and send its reply or error.
+    fn handle_request(&mut self, client: ClientId, request: &[u8]) {
+        let major_opcode = request[0];
+        let minor_opcode = if major_opcode < 128 { 0 } else { request[1] };
+        let ext_info = ExtensionManager::default();
+        let result = match parse_request_header(request, BigRequests::NotEnabled) {
+            Ok((header, body)) => match Request::parse(header, body, &mut Vec::new(), &ext_info) {
+                Ok(request) => self.dispatch(client, request),
+                Err(_) => Err(RequestError::new(xproto::LENGTH_ERROR, 0)),
+            },
+            Err(_) => Err(RequestError::new(xproto::LENGTH_ERROR, 0)),
+        };
+        match result {
+            Ok(Some(mut reply)) => {
+                let client = self.clients.get_mut(&client).unwrap();
+                set_sequence_number(&mut reply, client.last_sequence);
+                client.output.extend_from_slice(&reply);
+            }
+            Ok(None) => {}
+            Err(error) => {
+                let mut packet = [0; 32];
+                packet[1] = error.error_code;
+                packet[4..8].copy_from_slice(&error.bad_value.to_ne_bytes());
+                packet[8..10].copy_from_slice(&u16::from(minor_opcode).to_ne_bytes());
+                packet[10] = major_opcode;
+                self.send_event(client, packet);
+            }
+        }
+    }
+
+    /// Handle a parsed request.
+    fn dispatch(&mut self, client: ClientId, request: Request<'_>) -> RequestResult {
+        match request {
+            Request::CreateWindow(r) => void(self.create_window(client, &r)),
+            Request::ChangeWindowAttributes(r) => {
+                void(self.change_window_attributes(client, r.window, &r.value_list))
+            }
+            Request::GetWindowAttributes(r) => reply(self.get_window_attributes(client, r.window)),
+            Request::DestroyWindow(r) => void(self.check_window(r.window).map(|()| {
+                if xid(r.window) != ROOT_WINDOW {
+                    self.destroy_window(r.window)
+                }
+            })),
+            Request::DestroySubwindows(r) => void(self.destroy_subwindows(r.window)),
+            Request::ReparentWindow(r) => void(self.reparent_window(client, &r)),
+            Request::MapWindow(r) => void(self.map_window(client, r.window)),
+            Request::MapSubwindows(r) => void(self.map_subwindows(client, r.window)),
+            Request::UnmapWindow(r) => void(self.unmap_window(r.window)),
+            Request::UnmapSubwindows(r) => void(self.unmap_subwindows(r.window)),
+            Request::ConfigureWindow(r) => {
+                void(self.configure_window(client, r.window, &r.value_list))
+            }
+            Request::GetGeometry(r) => reply(self.get_geometry(r.drawable)),
+            Request::QueryTree(r) => reply(self.query_tree(r.window)),
+            Request::TranslateCoordinates(r) => reply(self.translate_coordinates(&r)),
+            Request::InternAtom(r) => reply(Ok(self.atoms.intern_atom(&r))),
+            Request::GetAtomName(r) => reply(self.atoms.get_atom_name(r.atom)),
+            Request::ChangeProperty(r) => void(self.change_property(&r)),
+            Request::DeleteProperty(r) => void(self.delete_property(r.window, r.property)),
+            Request::GetProperty(r) => reply(self.get_property(&r)),
+            Request::ListProperties(r) => reply(self.list_properties(r.window)),
+            Request::SetSelectionOwner(r) => void(self.set_selection_owner(client, &r)),
+            Request::GetSelectionOwner(r) => reply(self.get_selection_owner(r.selection)),
+            Request::ConvertSelection(r) => void(self.convert_selection(&r)),
+            Request::SendEvent(r) => void(self.send_event_request(&r)),
+            Request::SetInputFocus(r) => void(self.set_input_focus(&r)),
+            Request::GetInputFocus(_) => reply(Ok(self.get_input_focus())),
+            Request::CreatePixmap(r) => void(self.create_pixmap(client, &r)),
+            Request::FreePixmap(r) => void(self.free_pixmap(r.pixmap)),
+            Request::CreateGC(r) => void(self.create_gc(client, r.cid, r.drawable)),
+            Request::ChangeGC(r) => void(self.check_gc(r.gc).map(|_| ())),
+            Request::CopyGC(r) => void(self.copy_gc(r.src_gc, r.dst_gc)),
+            Request::FreeGC(r) => void(self.free_gc(r.gc)),
+            Request::ClearArea(r) => void(self.clear_area(r.window)),
+            Request::CopyArea(r) => void(self.copy_area(r.src_drawable, r.dst_drawable, r.gc)),
+            Request::CopyPlane(r) => {
+                void(self.copy_plane(r.src_drawable, r.dst_drawable, r.gc, r.bit_plane))
+            }
+            Request::PolyPoint(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyLine(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolySegment(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyRectangle(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyArc(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::FillPoly(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyFillRectangle(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyFillArc(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PutImage(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::ImageText8(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::ImageText16(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyText8(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::PolyText16(r) => void(self.check_drawing(r.drawable, r.gc)),
+            Request::QueryExtension(_) => reply(Ok(QueryExtensionReply {
+                sequence: 0,
+                length: 0,
+                present: false,
+                major_opcode: 0,
+                first_event: 0,
+                first_error: 0,
+            })),
+            Request::ListExtensions(_) => reply(Ok(ListExtensionsReply {
+                sequence: 0,
+                length: 0,
+                names: Vec::new(),
+            })),
+            Request::GrabServer(_)
+            | Request::UngrabServer(_)
+            | Request::Bell(_)
+            | Request::NoOperation(_) => Ok(None),
+            Request::Unknown(_, _) => Err(RequestError::new(xproto::REQUEST_ERROR, 0)),
+            _ => Err(RequestError::new(xproto::IMPLEMENTATION_ERROR, 0)),
+        }
+    }
+
+    /// Send an event or error to the given client.
+    pub(super) fn send_event(&mut self, client: ClientId, mut event: [u8; 32]) {
+        if let Some(client) = self.clients.get_mut(&client) {
+            // KeymapNotify events do not contain a sequence number
+            if event[0] & 0x7f != xproto::KEYMAP_NOTIFY_EVENT {
+                set_sequence_number(&mut event, client.last_sequence);
+            }
+            client.output.extend_from_slice(&event);
+        }
+    }
+
+    /// Get the clients that selected any of the events in `mask` on the given window.
+    pub(super) fn interested_clients(&self, window: Window, mask: u32) -> Vec<ClientId> {
+        self.windows
+            .get(&window)
+            .map(|window| {
+                window
+                    .event_masks
+                    .iter()
+                    .filter(|(_, &event_mask)| event_mask & mask != 0)
+                    .map(|(&client, _)| client)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Send an event to all clients that selected any of the events in `mask` on the given
+    /// window.
+    pub(super) fn deliver_event(&mut self, window: Window, mask: u32, event: [u8; 32]) {
+        for client in self.interested_clients(window, mask) {
+            self.send_event(client, event);
+        }
+    }
+
+    /// Check that a new resource ID belongs to the client and is not in use yet.
+    pub(super) fn check_new_id(
+        &self,
+        client: ClientId,
+        id: impl Into<u32>,
+    ) -> Result<(), RequestError> {
+        let id = id.into();
+        let in_use = self.windows.contains_key(&Window::from(id))
+            || self.pixmaps.contains_key(&xproto::Pixmap::from(id))
+            || self.gcs.contains_key(&xproto::Gcontext::from(id));
+        if id >> RESOURCE_ID_BITS != client || in_use {
+            Err(RequestError::new(xproto::ID_CHOICE_ERROR, id))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Pad a `Setup` or `SetupFailed` and compute its length field.
+fn fix_setup_length(setup: &mut Vec<u8>) {
+    let length = (setup.len() + 3) & !3;
+    setup.resize(length, 0);
+    let length = u16::try_from((length - 8) / 4).unwrap();
+    setup[6..8].copy_from_slice(&length.to_ne_bytes());
+}
+
+/// Write the lower 16 bits of a sequence number into a reply, error or event.
+fn set_sequence_number(packet: &mut [u8], sequence: SequenceNumber) {
+    // Only the lower 16 bits of the sequence number are sent
+    let sequence = sequence as u16;
+    packet[2..4].copy_from_slice(&sequence.to_ne_bytes());
+}
+
+/// Get the `Setup` that is sent to the given client.
+pub(crate) fn setup(client: ClientId) -> Setup {
+    let visual = Visualtype {
+        visual_id: ROOT_VISUAL,
+        class: VisualClass::TRUE_COLOR,
+        bits_per_rgb_value: 8,
+        colormap_entries: 256,
+        red_mask: 0xff_0000,
+        green_mask: 0xff00,
+        blue_mask: 0xff,
+    };
+    let screen = Screen {
+        root: Window::from(ROOT_WINDOW),
+        default_colormap: xproto::Colormap::from(DEFAULT_COLORMAP),
+        white_pixel: 0xff_ffff,
+        black_pixel: 0,
+        current_input_masks: 0,
+        width_in_pixels: super::window::ROOT_WIDTH,
+        height_in_pixels: super::window::ROOT_HEIGHT,
+        width_in_millimeters: 270,
+        height_in_millimeters: 203,
+        min_installed_maps: 1,
+        max_installed_maps: 1,
+        root_visual: ROOT_VISUAL,
+        backing_stores: BackingStore::NOT_USEFUL,
+        save_unders: false,
+        root_depth: ROOT_DEPTH,
+        allowed_depths: vec![
+            Depth {
+                depth: ROOT_DEPTH,
+                visuals: vec![visual],
+            },
+            Depth {
+                depth: 1,
+                visuals: Vec::new(),
+            },
+            Depth {
+                depth: 32,
+                visuals: Vec::new(),
+            },
+        ],
+    };
+    let pixmap_formats = PIXMAP_DEPTHS
+        .iter()
+        .map(|&depth| Format {
+            depth,
+            bits_per_pixel: if depth == 1 { 1 } else { 32 },
+            scanline_pad: 32,
+        })
+        .collect();
+    Setup {
+        status: 1,
+        protocol_major_version: 11,
+        protocol_minor_version: 0,
+        length: 0,
+        release_number: 0,
+        resource_id_base: client << RESOURCE_ID_BITS,
+        resource_id_mask: (1 << RESOURCE_ID_BITS) - 1,
+        motion_buffer_size: 0,
+        maximum_request_length: u16::max_value(),
+        image_byte_order: ImageOrder::LSB_FIRST,
+        bitmap_format_bit_order: ImageOrder::LSB_FIRST,
+        bitmap_format_scanline_unit: 32,
+        bitmap_format_scanline_pad: 32,
+        min_keycode: 8,
+        max_keycode: 255,
+        vendor: b"x11rb fake server".to_vec(),
+        pixmap_formats,
+        roots: vec![screen],
+    }
+}
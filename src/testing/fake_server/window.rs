@@ -0,0 +1,882 @@
+//! The window tree of a fake X11 server.
+
+use std::collections::BTreeMap;
+
+use super::property::PropertyValue;
+use super::state::{
+    xid, ClientId, RequestError, ServerState, DEFAULT_COLORMAP, ROOT_DEPTH, ROOT_VISUAL,
+    ROOT_WINDOW,
+};
+use crate::protocol::xproto::{
+    self, Atom, BackingStore, ChangeWindowAttributesAux, ColormapEnum, ConfigureNotifyEvent,
+    ConfigureRequestEvent, ConfigureWindowAux, CreateNotifyEvent, CreateWindowAux,
+    CreateWindowRequest, DestroyNotifyEvent, EventMask, ExposeEvent, GetGeometryReply,
+    GetWindowAttributesReply, Gravity, InputFocus, MapNotifyEvent, MapRequestEvent, MapState,
+    QueryTreeReply, ReparentNotifyEvent, ReparentWindowRequest, StackMode,
+    TranslateCoordinatesReply, TranslateCoordinatesRequest, UnmapNotifyEvent, Window, WindowClass,
+    WindowEnum,
+};
+
+/// The width of the root window.
+pub(super) const ROOT_WIDTH: u16 = 1024;
+/// The height of the root window.
+pub(super) const ROOT_HEIGHT: u16 = 768;
+
+/// A window of the fake X11 server.
+#[derive(Debug)]
+pub(super) struct WindowState {
+    /// The client that created the window. This is `None` for the root window.
+    pub(super) owner: Option<ClientId>,
+    /// The parent of the window. This is `None` for the root window.
+    pub(super) parent: Option<Window>,
+    /// The children of the window in stacking order from bottom to top.
+    children: Vec<Window>,
+    x: i16,
+    y: i16,
+    width: u16,
+    height: u16,
+    border_width: u16,
+    pub(super) class: WindowClass,
+    pub(super) depth: u8,
+    visual: xproto::Visualid,
+    mapped: bool,
+    override_redirect: bool,
+    bit_gravity: Gravity,
+    win_gravity: Gravity,
+    backing_store: BackingStore,
+    save_under: bool,
+    colormap: xproto::Colormap,
+    pub(super) do_not_propagate_mask: u16,
+    /// The events that each client selected on this window.
+    pub(super) event_masks: BTreeMap<ClientId, u32>,
+    /// The properties of this window in the order in which they were created.
+    pub(super) properties: Vec<(Atom, PropertyValue)>,
+}
+
+impl WindowState {
+    /// Create the root window.
+    pub(super) fn root() -> Self {
+        Self {
+            owner: None,
+            parent: None,
+            children: Vec::new(),
+            x: 0,
+            y: 0,
+            width: ROOT_WIDTH,
+            height: ROOT_HEIGHT,
+            border_width: 0,
+            class: WindowClass::INPUT_OUTPUT,
+            depth: ROOT_DEPTH,
+            visual: ROOT_VISUAL,
+            mapped: true,
+            override_redirect: false,
+            bit_gravity: Gravity::BIT_FORGET,
+            win_gravity: Gravity::NORTH_WEST,
+            backing_store: BackingStore::NOT_USEFUL,
+            save_under: false,
+            colormap: xproto::Colormap::from(DEFAULT_COLORMAP),
+            do_not_propagate_mask: 0,
+            event_masks: BTreeMap::new(),
+            properties: Vec::new(),
+        }
+    }
+}
+
+/// Convert the attributes from `CreateWindow` into those of `ChangeWindowAttributes`.
+fn to_change_aux(aux: &CreateWindowAux) -> ChangeWindowAttributesAux {
+    ChangeWindowAttributesAux {
+        background_pixmap: aux.background_pixmap,
+        background_pixel: aux.background_pixel,
+        border_pixmap: aux.border_pixmap,
+        border_pixel: aux.border_pixel,
+        bit_gravity: aux.bit_gravity,
+        win_gravity: aux.win_gravity,
+        backing_store: aux.backing_store,
+        backing_planes: aux.backing_planes,
+        backing_pixel: aux.backing_pixel,
+        override_redirect: aux.override_redirect,
+        save_under: aux.save_under,
+        event_mask: aux.event_mask,
+        do_not_propogate_mask: aux.do_not_propogate_mask,
+        colormap: aux.colormap,
+        cursor: aux.cursor,
+    }
+}
+
+impl ServerState {
+    /// Get the window with the given ID.
+    pub(super) fn window(&self, window: Window) -> Result<&WindowState, RequestError> {
+        self.windows
+            .get(&window)
+            .ok_or_else(|| RequestError::window(window))
+    }
+
+    fn window_mut(&mut self, window: Window) -> Result<&mut WindowState, RequestError> {
+        self.windows
+            .get_mut(&window)
+            .ok_or_else(|| RequestError::window(window))
+    }
+
+    /// Check that the given window exists.
+    pub(super) fn check_window(&self, window: Window) -> Result<(), RequestError> {
+        self.window(window).map(|_| ())
+    }
+
+    /// Is the window and all of its ancestors mapped?
+    pub(super) fn is_viewable(&self, mut window: Window) -> bool {
+        loop {
+            match self.windows.get(&window) {
+                Some(state) if state.mapped => match state.parent {
+                    Some(parent) => window = parent,
+                    None => return true,
+                },
+                _ => return false,
+            }
+        }
+    }
+
+    /// Is `window` the same as `ancestor` or one of its inferiors?
+    fn is_inferior_or_self(&self, mut window: Window, ancestor: Window) -> bool {
+        loop {
+            if window == ancestor {
+                return true;
+            }
+            match self.windows.get(&window).and_then(|state| state.parent) {
+                Some(parent) => window = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Get the client that redirects the structure of the given window's parent, if it is not
+    /// `client`.
+    fn redirecting_client(&self, client: ClientId, window: &WindowState) -> Option<ClientId> {
+        if window.override_redirect {
+            return None;
+        }
+        let parent = window.parent?;
+        self.interested_clients(parent, EventMask::SUBSTRUCTURE_REDIRECT.into())
+            .into_iter()
+            .find(|&redirect| redirect != client)
+    }
+
+    /// Send an event to those interested in the structure of `window`.
+    ///
+    /// `make_event` is called with the window that receives the event, which is `window` for
+    /// `StructureNotify` and its parent for `SubstructureNotify`.
+    fn deliver_structure_event<F>(&mut self, window: Window, parent: Option<Window>, make_event: F)
+    where
+        F: Fn(Window) -> [u8; 32],
+    {
+        self.deliver_event(
+            window,
+            EventMask::STRUCTURE_NOTIFY.into(),
+            make_event(window),
+        );
+        if let Some(parent) = parent {
+            self.deliver_event(
+                parent,
+                EventMask::SUBSTRUCTURE_NOTIFY.into(),
+                make_event(parent),
+            );
+        }
+    }
+
+    /// Handle a `CreateWindow` request.
+    pub(super) fn create_window(
+        &mut self,
+        client: ClientId,
+        request: &CreateWindowRequest<'_>,
+    ) -> Result<(), RequestError> {
+        self.check_new_id(client, request.wid)?;
+        let parent = self.window(request.parent)?;
+        if request.width == 0 || request.height == 0 {
+            return Err(RequestError::value(0));
+        }
+        let class = if request.class == WindowClass::COPY_FROM_PARENT {
+            parent.class
+        } else {
+            request.class
+        };
+        let (depth, visual) = if class == WindowClass::INPUT_ONLY {
+            if request.depth != 0 || request.border_width != 0 {
+                return Err(RequestError::matching());
+            }
+            (0, parent.visual)
+        } else {
+            if parent.class == WindowClass::INPUT_ONLY {
+                return Err(RequestError::matching());
+            }
+            let depth = if request.depth == 0 {
+                parent.depth
+            } else {
+                request.depth
+            };
+            let visual = if request.visual == 0 {
+                parent.visual
+            } else {
+                request.visual
+            };
+            if depth != ROOT_DEPTH || visual != ROOT_VISUAL {
+                return Err(RequestError::matching());
+            }
+            (depth, visual)
+        };
+        let aux = to_change_aux(&request.value_list);
+        self.check_event_mask(client, request.wid, &aux)?;
+
+        let window = WindowState {
+            owner: Some(client),
+            parent: Some(request.parent),
+            children: Vec::new(),
+            x: request.x,
+            y: request.y,
+            width: request.width,
+            height: request.height,
+            border_width: request.border_width,
+            class,
+            depth,
+            visual,
+            mapped: false,
+            override_redirect: false,
+            bit_gravity: Gravity::BIT_FORGET,
+            win_gravity: Gravity::NORTH_WEST,
+            backing_store: BackingStore::NOT_USEFUL,
+            save_under: false,
+            colormap: if class == WindowClass::INPUT_ONLY {
+                ColormapEnum::NONE.into()
+            } else {
+                parent.colormap
+            },
+            do_not_propagate_mask: 0,
+            event_masks: BTreeMap::new(),
+            properties: Vec::new(),
+        };
+        let _ = self.windows.insert(request.wid, window);
+        self.window_mut(request.parent)?.children.push(request.wid);
+        self.apply_attributes(client, request.wid, &aux)?;
+
+        let window = self.window(request.wid)?;
+        let event = CreateNotifyEvent {
+            response_type: xproto::CREATE_NOTIFY_EVENT,
+            sequence: 0,
+            parent: request.parent,
+            window: request.wid,
+            x: window.x,
+            y: window.y,
+            width: window.width,
+            height: window.height,
+            border_width: window.border_width,
+            override_redirect: window.override_redirect,
+        };
+        self.deliver_event(
+            request.parent,
+            EventMask::SUBSTRUCTURE_NOTIFY.into(),
+            event.into(),
+        );
+        Ok(())
+    }
+
+    /// Check that only one client selects `SubstructureRedirect` on a window.
+    fn check_event_mask(
+        &self,
+        client: ClientId,
+        window: Window,
+        aux: &ChangeWindowAttributesAux,
+    ) -> Result<(), RequestError> {
+        let redirect = u32::from(EventMask::SUBSTRUCTURE_REDIRECT);
+        match aux.event_mask {
+            Some(mask) if mask & redirect != 0 => {
+                let others = self.interested_clients(window, redirect);
+                if others.into_iter().any(|other| other != client) {
+                    Err(RequestError::new(xproto::ACCESS_ERROR, 0))
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Apply window attributes.
+    fn apply_attributes(
+        &mut self,
+        client: ClientId,
+        window: Window,
+        aux: &ChangeWindowAttributesAux,
+    ) -> Result<(), RequestError> {
+        let state = self.window_mut(window)?;
+        if let Some(bit_gravity) = aux.bit_gravity {
+            state.bit_gravity = bit_gravity;
+        }
+        if let Some(win_gravity) = aux.win_gravity {
+            state.win_gravity = win_gravity;
+        }
+        if let Some(backing_store) = aux.backing_store {
+            state.backing_store = backing_store;
+        }
+        if let Some(override_redirect) = aux.override_redirect {
+            state.override_redirect = override_redirect != 0;
+        }
+        if let Some(save_under) = aux.save_under {
+            state.save_under = save_under != 0;
+        }
+        if let Some(mask) = aux.do_not_propogate_mask {
+            state.do_not_propagate_mask = mask as u16;
+        }
+        if let Some(colormap) = aux.colormap {
+            state.colormap = colormap;
+        }
+        if let Some(mask) = aux.event_mask {
+            if mask == 0 {
+                let _ = state.event_masks.remove(&client);
+            } else {
+                let _ = state.event_masks.insert(client, mask);
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle a `ChangeWindowAttributes` request.
+    pub(super) fn change_window_attributes(
+        &mut self,
+        client: ClientId,
+        window: Window,
+        aux: &ChangeWindowAttributesAux,
+    ) -> Result<(), RequestError> {
+        self.check_window(window)?;
+        self.check_event_mask(client, window, aux)?;
+        self.apply_attributes(client, window, aux)
+    }
+
+    /// Handle a `GetWindowAttributes` request.
+    pub(super) fn get_window_attributes(
+        &self,
+        client: ClientId,
+        window: Window,
+    ) -> Result<GetWindowAttributesReply, RequestError> {
+        let state = self.window(window)?;
+        let map_state = if !state.mapped {
+            MapState::UNMAPPED
+        } else if self.is_viewable(window) {
+            MapState::VIEWABLE
+        } else {
+            MapState::UNVIEWABLE
+        };
+        Ok(GetWindowAttributesReply {
+            backing_store: state.backing_store,
+            sequence: 0,
+            length: 0,
+            visual: state.visual,
+            class: state.class,
+            bit_gravity: state.bit_gravity,
+            win_gravity: state.win_gravity,
+            backing_planes: u32::max_value(),
+            backing_pixel: 0,
+            save_under: state.save_under,
+            map_is_installed: xid(state.colormap) == DEFAULT_COLORMAP,
+            map_state,
+            override_redirect: state.override_redirect,
+            colormap: state.colormap,
+            all_event_masks: state.event_masks.values().fold(0, |acc, mask| acc | mask),
+            your_event_mask: state.event_masks.get(&client).copied().unwrap_or(0),
+            do_not_propagate_mask: state.do_not_propagate_mask,
+        })
+    }
+
+    /// Destroy a window and all of its inferiors.
+    pub(super) fn destroy_window(&mut self, window: Window) {
+        if self
+            .windows
+            .get(&window)
+            .map_or(false, |state| state.mapped)
+        {
+            self.unmap(window, false);
+        }
+        self.destroy_inferiors(window);
+        self.destroy_unmapped(window);
+    }
+
+    /// Destroy all inferiors of a window without unmapping them first.
+    fn destroy_inferiors(&mut self, window: Window) {
+        let children = match self.windows.get(&window) {
+            Some(state) => state.children.clone(),
+            None => return,
+        };
+        for child in children {
+            self.destroy_inferiors(child);
+            self.destroy_unmapped(child);
+        }
+    }
+
+    /// Destroy a single window and send `DestroyNotify` events.
+    fn destroy_unmapped(&mut self, window: Window) {
+        let parent = self.windows.get(&window).and_then(|state| state.parent);
+        self.deliver_structure_event(window, parent, |event| {
+            DestroyNotifyEvent {
+                response_type: xproto::DESTROY_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+            }
+            .into()
+        });
+        let _ = self.windows.remove(&window);
+        if let Some(parent) = parent.and_then(|parent| self.windows.get_mut(&parent)) {
+            parent.children.retain(|&child| child != window);
+        }
+        for selection in self.selections.values_mut() {
+            if selection.owner == window {
+                selection.owner = WindowEnum::NONE.into();
+                selection.client = None;
+            }
+        }
+        if self.focus == window {
+            self.revert_focus(parent);
+        }
+    }
+
+    /// The focus window became unviewable, so the focus reverts as specified by `revert_to`.
+    fn revert_focus(&mut self, parent: Option<Window>) {
+        if self.focus_revert_to == InputFocus::PARENT {
+            let mut parent = parent;
+            while let Some(window) = parent {
+                if self.is_viewable(window) {
+                    self.focus = window;
+                    self.focus_revert_to = InputFocus::NONE;
+                    return;
+                }
+                parent = self.windows.get(&window).and_then(|state| state.parent);
+            }
+        } else if self.focus_revert_to == InputFocus::POINTER_ROOT {
+            self.focus = InputFocus::POINTER_ROOT.into();
+            return;
+        }
+        self.focus = InputFocus::NONE.into();
+        self.focus_revert_to = InputFocus::NONE;
+    }
+
+    /// Handle a `DestroySubwindows` request.
+    pub(super) fn destroy_subwindows(&mut self, window: Window) -> Result<(), RequestError> {
+        let children = self.window(window)?.children.clone();
+        for &child in children.iter().rev() {
+            self.destroy_window(child);
+        }
+        Ok(())
+    }
+
+    /// Handle a `ReparentWindow` request.
+    pub(super) fn reparent_window(
+        &mut self,
+        client: ClientId,
+        request: &ReparentWindowRequest,
+    ) -> Result<(), RequestError> {
+        let (window, new_parent) = (request.window, request.parent);
+        let state = self.window(window)?;
+        let new_parent_state = self.window(new_parent)?;
+        let old_parent = match state.parent {
+            Some(parent) => parent,
+            // The root window cannot be reparented
+            None => return Err(RequestError::matching()),
+        };
+        if self.is_inferior_or_self(new_parent, window)
+            || (new_parent_state.class == WindowClass::INPUT_ONLY
+                && state.class != WindowClass::INPUT_ONLY)
+        {
+            return Err(RequestError::matching());
+        }
+
+        let was_mapped = state.mapped;
+        if was_mapped {
+            self.unmap(window, false);
+        }
+        self.window_mut(old_parent)?
+            .children
+            .retain(|&child| child != window);
+        self.window_mut(new_parent)?.children.push(window);
+        let state = self.window_mut(window)?;
+        state.parent = Some(new_parent);
+        state.x = request.x;
+        state.y = request.y;
+
+        let override_redirect = state.override_redirect;
+        let make_event = |event| {
+            ReparentNotifyEvent {
+                response_type: xproto::REPARENT_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+                parent: new_parent,
+                x: request.x,
+                y: request.y,
+                override_redirect,
+            }
+            .into()
+        };
+        self.deliver_structure_event(window, Some(old_parent), make_event);
+        if old_parent != new_parent {
+            self.deliver_event(
+                new_parent,
+                EventMask::SUBSTRUCTURE_NOTIFY.into(),
+                make_event(new_parent),
+            );
+        }
+        if was_mapped {
+            self.map_window(client, window)?;
+        }
+        Ok(())
+    }
+
+    /// Handle a `MapWindow` request.
+    pub(super) fn map_window(
+        &mut self,
+        client: ClientId,
+        window: Window,
+    ) -> Result<(), RequestError> {
+        let state = self.window(window)?;
+        if state.mapped {
+            return Ok(());
+        }
+        let parent = state.parent;
+        let override_redirect = state.override_redirect;
+        if let Some(redirect) = self.redirecting_client(client, state) {
+            let event = MapRequestEvent {
+                response_type: xproto::MAP_REQUEST_EVENT,
+                sequence: 0,
+                parent: parent.unwrap(),
+                window,
+            };
+            self.send_event(redirect, event.into());
+            return Ok(());
+        }
+
+        self.window_mut(window)?.mapped = true;
+        self.deliver_structure_event(window, parent, |event| {
+            MapNotifyEvent {
+                response_type: xproto::MAP_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+                override_redirect,
+            }
+            .into()
+        });
+        if self.is_viewable(window) {
+            self.expose(window);
+        }
+        Ok(())
+    }
+
+    /// Send `Expose` events for a window that became viewable and its viewable inferiors.
+    fn expose(&mut self, window: Window) {
+        let state = match self.windows.get(&window) {
+            Some(state) if state.mapped => state,
+            _ => return,
+        };
+        let children = state.children.clone();
+        if state.class == WindowClass::INPUT_OUTPUT {
+            let event = ExposeEvent {
+                response_type: xproto::EXPOSE_EVENT,
+                sequence: 0,
+                window,
+                x: 0,
+                y: 0,
+                width: state.width,
+                height: state.height,
+                count: 0,
+            };
+            self.deliver_event(window, EventMask::EXPOSURE.into(), event.into());
+        }
+        for child in children {
+            self.expose(child);
+        }
+    }
+
+    /// Handle a `MapSubwindows` request.
+    pub(super) fn map_subwindows(
+        &mut self,
+        client: ClientId,
+        window: Window,
+    ) -> Result<(), RequestError> {
+        let children = self.window(window)?.children.clone();
+        for &child in children.iter().rev() {
+            self.map_window(client, child)?;
+        }
+        Ok(())
+    }
+
+    /// Unmap a window and send `UnmapNotify` events.
+    fn unmap(&mut self, window: Window, from_configure: bool) {
+        let parent = match self.windows.get_mut(&window) {
+            Some(state) if state.mapped && state.parent.is_some() => {
+                state.mapped = false;
+                state.parent
+            }
+            _ => return,
+        };
+        self.deliver_structure_event(window, parent, |event| {
+            UnmapNotifyEvent {
+                response_type: xproto::UNMAP_NOTIFY_EVENT,
+                sequence: 0,
+                event,
+                window,
+                from_configure,
+            }
+            .into()
+        });
+        let focus = self.focus;
+        if self.windows.contains_key(&focus) && self.is_inferior_or_self(focus, window) {
+            self.revert_focus(parent);
+        }
+    }
+
+    /// Handle an `UnmapWindow` request.
+    pub(super) fn unmap_window(&mut self, window: Window) -> Result<(), RequestError> {
+        self.check_window(window)?;
+        self.unmap(window, false);
+        Ok(())
+    }
+
+    /// Handle an `UnmapSubwindows` request.
+    pub(super) fn unmap_subwindows(&mut self, window: Window) -> Result<(), RequestError> {
+        let children = self.window(window)?.children.clone();
+        for child in children {
+            self.unmap(child, false);
+        }
+        Ok(())
+    }
+
+    /// Handle a `ConfigureWindow` request.
+    pub(super) fn configure_window(
+        &mut self,
+        client: ClientId,
+        window: Window,
+        aux: &ConfigureWindowAux,
+    ) -> Result<(), RequestError> {
+        let state = self.window(window)?;
+        if aux.width == Some(0) || aux.height == Some(0) {
+            return Err(RequestError::value(0));
+        }
+        if aux.sibling.is_some() && aux.stack_mode.is_none() {
+            return Err(RequestError::matching());
+        }
+        let parent = match state.parent {
+            Some(parent) => parent,
+            // The root window cannot be configured
+            None => return Ok(()),
+        };
+        if let Some(sibling) = aux.sibling {
+            let sibling_parent = self.window(sibling)?.parent;
+            if sibling == window || sibling_parent != Some(parent) {
+                return Err(RequestError::matching());
+            }
+        }
+
+        if let Some(redirect) = self.redirecting_client(client, state) {
+            let event = ConfigureRequestEvent {
+                response_type: xproto::CONFIGURE_REQUEST_EVENT,
+                stack_mode: aux.stack_mode.unwrap_or(StackMode::ABOVE),
+                sequence: 0,
+                parent,
+                window,
+                sibling: aux.sibling.unwrap_or_else(|| WindowEnum::NONE.into()),
+                x: aux.x.map_or(state.x, |x| x as i16),
+                y: aux.y.map_or(state.y, |y| y as i16),
+                width: aux.width.map_or(state.width, |width| width as u16),
+                height: aux.height.map_or(state.height, |height| height as u16),
+                border_width: aux
+                    .border_width
+                    .map_or(state.border_width, |width| width as u16),
+                value_mask: configure_value_mask(aux),
+            };
+            self.send_event(redirect, event.into());
+            return Ok(());
+        }
+
+        let state = self.window_mut(window)?;
+        if let Some(x) = aux.x {
+            state.x = x as i16;
+        }
+        if let Some(y) = aux.y {
+            state.y = y as i16;
+        }
+        if let Some(width) = aux.width {
+            state.width = width as u16;
+        }
+        if let Some(height) = aux.height {
+            state.height = height as u16;
+        }
+        if let Some(border_width) = aux.border_width {
+            state.border_width = border_width as u16;
+        }
+        if let Some(stack_mode) = aux.stack_mode {
+            self.restack(window, parent, aux.sibling, stack_mode)?;
+        }
+
+        let state = self.window(window)?;
+        let siblings = &self.window(parent)?.children;
+        let position = siblings.iter().position(|&child| child == window).unwrap();
+        let above_sibling = if position == 0 {
+            WindowEnum::NONE.into()
+        } else {
+            siblings[position - 1]
+        };
+        let event = ConfigureNotifyEvent {
+            response_type: xproto::CONFIGURE_NOTIFY_EVENT,
+            sequence: 0,
+            event: WindowEnum::NONE.into(),
+            window,
+            above_sibling,
+            x: state.x,
+            y: state.y,
+            width: state.width,
+            height: state.height,
+            border_width: state.border_width,
+            override_redirect: state.override_redirect,
+        };
+        self.deliver_structure_event(window, Some(parent), |event_window| {
+            ConfigureNotifyEvent {
+                event: event_window,
+                ..event
+            }
+            .into()
+        });
+        Ok(())
+    }
+
+    /// Change the position of a window in the stacking order of its siblings.
+    fn restack(
+        &mut self,
+        window: Window,
+        parent: Window,
+        sibling: Option<Window>,
+        stack_mode: StackMode,
+    ) -> Result<(), RequestError> {
+        let children = &mut self.window_mut(parent)?.children;
+        children.retain(|&child| child != window);
+        let index = match (sibling, stack_mode) {
+            (None, StackMode::BELOW) => 0,
+            (None, _) => children.len(),
+            (Some(sibling), StackMode::BELOW) => {
+                children.iter().position(|&child| child == sibling).unwrap()
+            }
+            (Some(sibling), _) => children.iter().position(|&child| child == sibling).unwrap() + 1,
+        };
+        children.insert(index, window);
+        Ok(())
+    }
+
+    /// Handle a `GetGeometry` request.
+    pub(super) fn get_geometry(
+        &self,
+        drawable: xproto::Drawable,
+    ) -> Result<GetGeometryReply, RequestError> {
+        let id = xid(drawable);
+        let (depth, x, y, width, height, border_width) = match self.windows.get(&Window::from(id)) {
+            Some(state) => (
+                state.depth,
+                state.x,
+                state.y,
+                state.width,
+                state.height,
+                state.border_width,
+            ),
+            None => match self.pixmaps.get(&xproto::Pixmap::from(id)) {
+                Some(pixmap) => (pixmap.depth, 0, 0, pixmap.width, pixmap.height, 0),
+                None => return Err(RequestError::new(xproto::DRAWABLE_ERROR, id)),
+            },
+        };
+        Ok(GetGeometryReply {
+            depth,
+            sequence: 0,
+            length: 0,
+            root: Window::from(ROOT_WINDOW),
+            x,
+            y,
+            width,
+            height,
+            border_width,
+        })
+    }
+
+    /// Handle a `QueryTree` request.
+    pub(super) fn query_tree(&self, window: Window) -> Result<QueryTreeReply, RequestError> {
+        let state = self.window(window)?;
+        Ok(QueryTreeReply {
+            sequence: 0,
+            length: 0,
+            root: Window::from(ROOT_WINDOW),
+            parent: state.parent.unwrap_or_else(|| WindowEnum::NONE.into()),
+            children: state.children.clone(),
+        })
+    }
+
+    /// Get the position of the inside of a window relative to the root window.
+    fn absolute_position(&self, window: Window) -> Result<(i32, i32), RequestError> {
+        let state = self.window(window)?;
+        match state.parent {
+            Some(parent) => {
+                let (x, y) = self.absolute_position(parent)?;
+                let border_width = i32::from(state.border_width);
+                Ok((
+                    x + i32::from(state.x) + border_width,
+                    y + i32::from(state.y) + border_width,
+                ))
+            }
+            None => Ok((0, 0)),
+        }
+    }
+
+    /// Handle a `TranslateCoordinates` request.
+    pub(super) fn translate_coordinates(
+        &self,
+        request: &TranslateCoordinatesRequest,
+    ) -> Result<TranslateCoordinatesReply, RequestError> {
+        let (src_x, src_y) = self.absolute_position(request.src_window)?;
+        let (dst_x, dst_y) = self.absolute_position(request.dst_window)?;
+        let x = i32::from(request.src_x) + src_x - dst_x;
+        let y = i32::from(request.src_y) + src_y - dst_y;
+        let children = &self.window(request.dst_window)?.children;
+        let child = children
+            .iter()
+            .rev()
+            .copied()
+            .find(|child| {
+                let state = &self.windows[child];
+                let size = |size: u16| i32::from(size) + 2 * i32::from(state.border_width);
+                let (child_x, child_y) = (i32::from(state.x), i32::from(state.y));
+                state.mapped
+                    && child_x <= x
+                    && x < child_x + size(state.width)
+                    && child_y <= y
+                    && y < child_y + size(state.height)
+            })
+            .unwrap_or_else(|| WindowEnum::NONE.into());
+        Ok(TranslateCoordinatesReply {
+            same_screen: true,
+            sequence: 0,
+            length: 0,
+            child,
+            dst_x: x as i16,
+            dst_y: y as i16,
+        })
+    }
+}
+
+/// Get the value mask that describes which fields are set in a `ConfigureWindowAux`.
+fn configure_value_mask(aux: &ConfigureWindowAux) -> u16 {
+    let fields = [
+        aux.x.is_some(),
+        aux.y.is_some(),
+        aux.width.is_some(),
+        aux.height.is_some(),
+        aux.border_width.is_some(),
+        aux.sibling.is_some(),
+        aux.stack_mode.is_some(),
+    ];
+    fields
+        .iter()
+        .enumerate()
+        .filter(|(_, &is_set)| is_set)
+        .fold(0, |mask, (bit, _)| mask | 1 << bit)
+}
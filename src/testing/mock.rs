@@ -0,0 +1,471 @@
+//! A connection that records requests instead of sending them to an X11 server.
+
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::io::IoSlice;
+use std::sync::Mutex;
+
+use crate::connection::{
+    compute_length_field, BufWithFds, Connection, DiscardMode, RawEventAndSeqNumber, ReplyOrError,
+    RequestConnection, RequestKind, SequenceNumber,
+};
+use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
+use crate::errors::{ConnectionError, ParseError, ReplyOrIdError};
+use crate::extension_manager::ExtensionManager;
+use crate::protocol::xproto::Setup;
+use crate::protocol::{request_name, Event, Request};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, Serialize, X11Error,
+};
+
+/// The response of a [`MockConnection`] to a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse(ResponseKind);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ResponseKind {
+    Reply(Vec<u8>),
+    Error { error_code: u8, bad_value: u32 },
+}
+
+impl MockResponse {
+    /// Answer the request with the given reply.
+    ///
+    /// The sequence number of the reply is filled in automatically.
+    pub fn reply(reply: &impl Serialize<Bytes = Vec<u8>>) -> Self {
+        Self(ResponseKind::Reply(reply.serialize()))
+    }
+
+    /// Answer the request with an X11 error.
+    ///
+    /// The opcodes and the sequence number of the error are filled in automatically. For errors
+    /// of the core protocol, the constants like
+    /// [`WINDOW_ERROR`](crate::protocol::xproto::WINDOW_ERROR) can be used as `error_code`.
+    pub fn error(error_code: u8, bad_value: u32) -> Self {
+        Self(ResponseKind::Error {
+            error_code,
+            bad_value,
+        })
+    }
+}
+
+type Handler = Box<dyn FnMut(&Request<'_>) -> Option<MockResponse> + Send>;
+
+/// The answer to a request that was already sent.
+#[derive(Debug)]
+enum Pending {
+    Reply(Vec<u8>),
+    Error(Vec<u8>),
+    /// The request has a reply, but nothing was scripted for it.
+    Unscripted(String),
+}
+
+struct MockState {
+    requests: Vec<Request<'static>>,
+    last_sequence: SequenceNumber,
+    handlers: Vec<Handler>,
+    named_responses: HashMap<String, MockResponse>,
+    pending: HashMap<SequenceNumber, Pending>,
+    events: VecDeque<RawEventAndSeqNumber<Vec<u8>>>,
+    next_id: u32,
+}
+
+/// A [`Connection`] that records all requests instead of sending them to an X11 server.
+///
+/// Every request is decoded via [`Request::parse`] and kept, so that tests can check which
+/// requests some code sends without looking at the raw bytes. Replies and errors can be scripted
+/// by the name of the request via [`MockConnection::respond_to`] or with a closure via
+/// [`MockConnection::on_request`]. Events can be queued with [`MockConnection::push_event`].
+///
+/// Waiting for the reply to a request for which nothing was scripted panics. Waiting for an event
+/// when none is queued returns an error, since nothing could ever produce this event.
+///
+/// # Example
+///
+/// ```
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::{ConfigureWindowAux, ConnectionExt, GetGeometryReply};
+/// use x11rb::protocol::Request;
+/// use x11rb::testing::{MockConnection, MockResponse};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let conn = MockConnection::new();
+/// let root = conn.setup().roots[0].root;
+/// let reply = GetGeometryReply {
+///     depth: 24,
+///     sequence: 0,
+///     length: 0,
+///     root,
+///     x: 0,
+///     y: 0,
+///     width: 640,
+///     height: 480,
+///     border_width: 0,
+/// };
+/// conn.respond_to("GetGeometry", MockResponse::reply(&reply));
+///
+/// let geometry = conn.get_geometry(root)?.reply()?;
+/// let aux = ConfigureWindowAux::new().width(u32::from(geometry.width) / 2);
+/// conn.configure_window(root, &aux)?;
+///
+/// let requests = conn.take_requests();
+/// assert_eq!(requests.len(), 2);
+/// match &requests[1] {
+///     Request::ConfigureWindow(request) => assert_eq!(request.value_list.width, Some(320)),
+///     request => panic!("Unexpected request {:?}", request),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockConnection {
+    setup: Setup,
+    extensions: Mutex<ExtensionManager>,
+    state: Mutex<MockState>,
+}
+
+impl std::fmt::Debug for MockConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("MockConnection")
+            .field("setup", &self.setup)
+            .field("extensions", &self.extensions)
+            .field("requests", &state.requests)
+            .field("named_responses", &state.named_responses)
+            .field("events", &state.events)
+            .finish()
+    }
+}
+
+impl Default for MockConnection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockConnection {
+    /// Create a new connection with a setup that describes a single screen.
+    ///
+    /// This is the same setup that a [`FakeServer`](super::FakeServer) sends to its first client.
+    pub fn new() -> Self {
+        Self::with_setup(super::fake_server::setup(1))
+    }
+
+    /// Create a new connection with the given setup.
+    ///
+    /// The XIDs from [`Connection::generate_id`] are allocated based on the `resource_id_base` and
+    /// `resource_id_mask` of the setup.
+    pub fn with_setup(setup: Setup) -> Self {
+        let next_id = setup.resource_id_base;
+        let state = MockState {
+            requests: Vec::new(),
+            last_sequence: 0,
+            handlers: Vec::new(),
+            named_responses: HashMap::new(),
+            pending: HashMap::new(),
+            events: VecDeque::new(),
+            next_id,
+        };
+        Self {
+            setup,
+            extensions: Default::default(),
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Pretend that the X11 server supports the given extension.
+    ///
+    /// All other extensions are reported as missing. The information is needed for sending and
+    /// decoding requests, events and errors of the extension.
+    pub fn add_extension(&self, extension_name: &'static str, info: ExtensionInformation) {
+        self.extensions
+            .lock()
+            .unwrap()
+            .add_extension(extension_name, info);
+    }
+
+    /// Answer all future requests with the given name with `response`.
+    ///
+    /// The name is the one returned by [`request_name`], for example `GetGeometry` or
+    /// `RandR::GetScreenResources`. This replaces an earlier response for the same name.
+    pub fn respond_to(&self, request_name: impl Into<String>, response: MockResponse) {
+        let _ = self
+            .state
+            .lock()
+            .unwrap()
+            .named_responses
+            .insert(request_name.into(), response);
+    }
+
+    /// Decide about the responses to future requests with a closure.
+    ///
+    /// The closure is called for each request that is sent. If it returns `None`, the next
+    /// handler is asked. Handlers are asked in the reverse order in which they were added and
+    /// before the responses from [`MockConnection::respond_to`].
+    pub fn on_request<F>(&self, handler: F)
+    where
+        F: FnMut(&Request<'_>) -> Option<MockResponse> + Send + 'static,
+    {
+        self.state.lock().unwrap().handlers.push(Box::new(handler));
+    }
+
+    /// Queue an event that is returned by the event functions of [`Connection`].
+    ///
+    /// The sequence number of the event is set to the sequence number of the last request.
+    pub fn push_event(&self, event: impl Into<[u8; 32]>) {
+        let mut state = self.state.lock().unwrap();
+        let sequence = state.last_sequence;
+        let mut event = event.into();
+        event[2..4].copy_from_slice(&(sequence as u16).to_ne_bytes());
+        state.events.push_back((event.to_vec(), sequence));
+    }
+
+    /// Get all requests that were sent since the last call to this function.
+    pub fn take_requests(&self) -> Vec<Request<'static>> {
+        std::mem::take(&mut self.state.lock().unwrap().requests)
+    }
+
+    /// Get the number of requests that were sent since the last call to
+    /// [`MockConnection::take_requests`].
+    pub fn request_count(&self) -> usize {
+        self.state.lock().unwrap().requests.len()
+    }
+
+    fn send_request(
+        &self,
+        bufs: &[IoSlice<'_>],
+        mut fds: Vec<RawFdContainer>,
+        has_reply: bool,
+    ) -> Result<SequenceNumber, ConnectionError> {
+        let mut storage = Default::default();
+        let bufs = compute_length_field(self, bufs, &mut storage)?;
+        let data = bufs
+            .iter()
+            .flat_map(|buf| buf.iter().copied())
+            .collect::<Vec<u8>>();
+
+        let extensions = self.extensions.lock().unwrap();
+        let (header, body) = parse_request_header(&data, BigRequests::Enabled)?;
+        let request = Request::parse(header, body, &mut fds, &*extensions)?.into_owned();
+        let name = request_name(
+            header.major_opcode,
+            header.minor_opcode.into(),
+            &*extensions,
+        );
+        drop(extensions);
+
+        let mut state = self.state.lock().unwrap();
+        state.last_sequence += 1;
+        let sequence = state.last_sequence;
+        let response = state
+            .handlers
+            .iter_mut()
+            .rev()
+            .filter_map(|handler| handler(&request))
+            .next()
+            .or_else(|| name.and_then(|name| state.named_responses.get(name).cloned()));
+        let pending = match response {
+            Some(MockResponse(ResponseKind::Reply(mut reply))) => {
+                reply[2..4].copy_from_slice(&(sequence as u16).to_ne_bytes());
+                Some(Pending::Reply(reply))
+            }
+            Some(MockResponse(ResponseKind::Error {
+                error_code,
+                bad_value,
+            })) => {
+                let error = X11Error {
+                    error_kind: crate::protocol::ErrorKind::Unknown(error_code),
+                    error_code,
+                    sequence: sequence as u16,
+                    bad_value,
+                    minor_opcode: header.minor_opcode.into(),
+                    major_opcode: header.major_opcode,
+                };
+                Some(Pending::Error(<[u8; 32]>::from(&error).to_vec()))
+            }
+            None if has_reply => Some(Pending::Unscripted(format!("{:?}", request))),
+            None => None,
+        };
+        if let Some(pending) = pending {
+            let _ = state.pending.insert(sequence, pending);
+        }
+        state.requests.push(request);
+        Ok(sequence)
+    }
+
+    fn take_pending(&self, sequence: SequenceNumber) -> Option<Pending> {
+        self.state.lock().unwrap().pending.remove(&sequence)
+    }
+}
+
+impl RequestConnection for MockConnection {
+    type Buf = Vec<u8>;
+
+    fn send_request_with_reply<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<Cookie<'_, Self, R>, ConnectionError>
+    where
+        R: for<'a> TryFrom<&'a [u8], Error = ParseError>,
+    {
+        Ok(Cookie::new(self, self.send_request(bufs, fds, true)?))
+    }
+
+    fn send_request_with_reply_with_fds<R>(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<CookieWithFds<'_, Self, R>, ConnectionError>
+    where
+        R: for<'a> TryFrom<(&'a [u8], Vec<RawFdContainer>), Error = ParseError>,
+    {
+        Ok(CookieWithFds::new(
+            self,
+            self.send_request(bufs, fds, true)?,
+        ))
+    }
+
+    fn send_request_without_reply(
+        &self,
+        bufs: &[IoSlice<'_>],
+        fds: Vec<RawFdContainer>,
+    ) -> Result<VoidCookie<'_, Self>, ConnectionError> {
+        Ok(VoidCookie::new(self, self.send_request(bufs, fds, false)?))
+    }
+
+    fn discard_reply(&self, sequence: SequenceNumber, _kind: RequestKind, mode: DiscardMode) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(Pending::Error(error)) = state.pending.remove(&sequence) {
+            if mode == DiscardMode::DiscardReply {
+                state.events.push_back((error, sequence));
+            }
+        }
+    }
+
+    fn prefetch_extension_information(
+        &self,
+        _extension_name: &'static str,
+    ) -> Result<(), ConnectionError> {
+        Ok(())
+    }
+
+    fn extension_information(
+        &self,
+        extension_name: &'static str,
+    ) -> Result<Option<ExtensionInformation>, ConnectionError> {
+        Ok(self
+            .extensions
+            .lock()
+            .unwrap()
+            .get_from_extension_name(extension_name))
+    }
+
+    fn wait_for_reply_or_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<Vec<u8>>, ConnectionError> {
+        match self.take_pending(sequence) {
+            Some(Pending::Reply(reply)) => Ok(ReplyOrError::Reply(reply)),
+            Some(Pending::Error(error)) => Ok(ReplyOrError::Error(error)),
+            Some(Pending::Unscripted(request)) => panic!(
+                "No reply was scripted for request number {}: {}",
+                sequence, request
+            ),
+            None => panic!(
+                "There is no reply for request number {}; it was already received or discarded",
+                sequence
+            ),
+        }
+    }
+
+    fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<Vec<u8>>, ConnectionError> {
+        match self.wait_for_reply_or_raw_error(sequence)? {
+            ReplyOrError::Reply(reply) => Ok(Some(reply)),
+            ReplyOrError::Error(error) => {
+                self.state
+                    .lock()
+                    .unwrap()
+                    .events
+                    .push_back((error, sequence));
+                Ok(None)
+            }
+        }
+    }
+
+    fn wait_for_reply_with_fds_raw(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<ReplyOrError<BufWithFds<Vec<u8>>, Vec<u8>>, ConnectionError> {
+        Ok(match self.wait_for_reply_or_raw_error(sequence)? {
+            ReplyOrError::Reply(reply) => ReplyOrError::Reply((reply, Vec::new())),
+            ReplyOrError::Error(error) => ReplyOrError::Error(error),
+        })
+    }
+
+    fn check_for_raw_error(
+        &self,
+        sequence: SequenceNumber,
+    ) -> Result<Option<Vec<u8>>, ConnectionError> {
+        match self.take_pending(sequence) {
+            Some(Pending::Error(error)) => Ok(Some(error)),
+            _ => Ok(None),
+        }
+    }
+
+    fn prefetch_maximum_request_bytes(&self) {}
+
+    fn maximum_request_bytes(&self) -> usize {
+        // Pretend that BIG-REQUESTS is supported
+        usize::max_value()
+    }
+
+    fn parse_error(&self, error: &[u8]) -> Result<X11Error, ParseError> {
+        X11Error::try_parse(error, &*self.extensions.lock().unwrap())
+    }
+
+    fn parse_event(&self, event: &[u8]) -> Result<Event, ParseError> {
+        Event::parse(event, &*self.extensions.lock().unwrap())
+    }
+}
+
+impl Connection for MockConnection {
+    fn wait_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<RawEventAndSeqNumber<Vec<u8>>, ConnectionError> {
+        self.poll_for_raw_event_with_sequence()?.ok_or_else(|| {
+            ConnectionError::IOError(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "no event is queued in the mock connection, so waiting would block forever",
+            ))
+        })
+    }
+
+    fn poll_for_raw_event_with_sequence(
+        &self,
+    ) -> Result<Option<RawEventAndSeqNumber<Vec<u8>>>, ConnectionError> {
+        Ok(self.state.lock().unwrap().events.pop_front())
+    }
+
+    fn flush(&self) -> Result<(), ConnectionError> {
+        Ok(())
+    }
+
+    fn setup(&self) -> &Setup {
+        &self.setup
+    }
+
+    fn generate_id(&self) -> Result<u32, ReplyOrIdError> {
+        let mask = self.setup.resource_id_mask;
+        let base = self.setup.resource_id_base;
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        if mask == 0 || id & !mask != base {
+            return Err(ReplyOrIdError::IdsExhausted);
+        }
+        // Count upwards in the bits of the mask
+        let increment = mask & mask.wrapping_neg();
+        state.next_id = id.wrapping_add(increment);
+        Ok(id)
+    }
+}
@@ -0,0 +1,68 @@
+//! Helpers for testing code that uses x11rb.
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! [`FakeServer`] is an X11 server that runs inside the current process. It implements a subset of
+//! the core protocol, which is enough for running things like window manager logic against a
+//! [`RustConnection`](crate::rust_connection::RustConnection) without a real X11 server:
+//!
+//! ```
+//! use x11rb::connection::Connection;
+//! use x11rb::protocol::xproto::{ConnectionExt, CreateWindowAux, WindowClass};
+//! use x11rb::rust_connection::RustConnection;
+//! use x11rb::testing::FakeServer;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = FakeServer::new();
+//! let conn = RustConnection::connect_to_stream(server.connect(), 0)?;
+//! let root = conn.setup().roots[0].root;
+//! let window = conn.generate_id()?;
+//! conn.create_window(
+//!     0,
+//!     window,
+//!     root,
+//!     0,
+//!     0,
+//!     100,
+//!     100,
+//!     0,
+//!     WindowClass::INPUT_OUTPUT,
+//!     0,
+//!     &CreateWindowAux::new(),
+//! )?;
+//! let tree = conn.query_tree(root)?.reply()?;
+//! assert_eq!(tree.children, [window]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`MockConnection`] does not need a server at all. It records the requests that are sent through
+//! it in decoded form and answers them with scripted replies and errors.
+//!
+//! On unix, [`XvfbBuilder`] starts a real X11 server like `Xvfb` on a free display for the
+//! duration of a test.
+//!
+//! [`RecordingStream`] records the traffic of a real session to a file. [`ReplayStream`] replays
+//! such a recording without any X11 server and checks that the client sends the same requests.
+
+mod fake_server;
+mod mock;
+mod recording;
+mod replay;
+#[cfg(unix)]
+mod xvfb;
+
+pub use fake_server::{FakeServer, FakeStream};
+pub use mock::{MockConnection, MockResponse};
+pub use recording::RecordingStream;
+pub use replay::ReplayStream;
+#[cfg(unix)]
+pub use xvfb::{Xvfb, XvfbBuilder, XvfbError};
+
+/// Get the length of a `SetupRequest` from its beginning.
+fn setup_request_length(request: &[u8]) -> Option<usize> {
+    let auth_name_length = u16::from_ne_bytes([*request.get(6)?, *request.get(7)?]);
+    let auth_data_length = u16::from_ne_bytes([*request.get(8)?, *request.get(9)?]);
+    let pad = |length: u16| (usize::from(length) + 3) & !3;
+    Some(12 + pad(auth_name_length) + pad(auth_data_length))
+}
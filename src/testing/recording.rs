@@ -0,0 +1,223 @@
+//! Recording the traffic of a connection to a file.
+//!
+//! A recording is a text file with one entry per line. Lines starting with `>` describe data
+//! that the client sent to the server and lines starting with `<` describe data that the client
+//! received. Such a line contains either the data in hexadecimal or `fds` and the number of file
+//! descriptors that were passed together with the following data. Empty lines and lines starting
+//! with `#` are ignored.
+//!
+//! ```text
+//! > 6c000b000000000000000000
+//! < 01000b0000000000...
+//! ```
+
+use std::fs::File;
+use std::io::{BufRead, BufWriter, Error, ErrorKind, IoSlice, Result, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::rust_connection::{
+    ConnectError, ConnectionBuilder, DefaultStream, PollMode, RustConnection, Stream,
+};
+use crate::utils::RawFdContainer;
+
+/// The direction in which some data was sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Direction {
+    /// The data was sent from the client to the server.
+    ClientToServer,
+    /// The data was sent from the server to the client.
+    ServerToClient,
+}
+
+impl Direction {
+    fn marker(self) -> char {
+        match self {
+            Direction::ClientToServer => '>',
+            Direction::ServerToClient => '<',
+        }
+    }
+}
+
+/// An entry in a recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Entry {
+    /// Some bytes were sent.
+    Data(Direction, Vec<u8>),
+    /// Some file descriptors were sent together with the following data.
+    Fds(Direction, usize),
+}
+
+/// Read all entries of a recording.
+pub(super) fn read_recording(read: impl BufRead) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for (index, line) in read.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid line {} in recording: {}", index + 1, line),
+            )
+        };
+        let direction = match line.chars().next() {
+            Some('>') => Direction::ClientToServer,
+            Some('<') => Direction::ServerToClient,
+            _ => return Err(invalid()),
+        };
+        let content = line[1..].trim();
+        let entry = if content.starts_with("fds") {
+            let count = content[3..].trim().parse().map_err(|_| invalid())?;
+            Entry::Fds(direction, count)
+        } else {
+            Entry::Data(direction, parse_hex(content).ok_or_else(invalid)?)
+        };
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect()
+}
+
+/// A [`Stream`] that records all traffic of another stream.
+///
+/// All data and file descriptors that pass through this stream are logged to a writer in the
+/// format that [`ReplayStream`](super::ReplayStream) reads. The contents of file descriptors are
+/// not recorded, only their number.
+///
+/// # Example
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::testing::RecordingStream;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, screen) = RecordingStream::connect(None, "session.txt")?;
+/// println!("{:?}", conn.setup().roots[screen].root);
+/// # Ok(())
+/// # }
+/// ```
+pub struct RecordingStream<S> {
+    inner: S,
+    log: Mutex<Box<dyn Write + Send>>,
+}
+
+impl<S: std::fmt::Debug> std::fmt::Debug for RecordingStream<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingStream")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl RecordingStream<DefaultStream> {
+    /// Connect to an X11 server and record the session to the given file.
+    ///
+    /// See [`RustConnection::connect`] for the meaning of `dpy_name` and the return value.
+    pub fn connect(
+        dpy_name: Option<&str>,
+        path: impl AsRef<Path>,
+    ) -> std::result::Result<(RustConnection<Self>, usize), ConnectError> {
+        let log = File::create(path)?;
+        ConnectionBuilder::new().connect_wrapped(dpy_name, |stream| Self::new(stream, log))
+    }
+}
+
+impl<S: Stream> RecordingStream<S> {
+    /// Record the traffic of `inner` to `log`.
+    pub fn new(inner: S, log: impl Write + Send + 'static) -> Self {
+        Self {
+            inner,
+            log: Mutex::new(Box::new(BufWriter::new(log))),
+        }
+    }
+
+    /// Get the stream whose traffic is recorded.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Add some data and the number of file descriptors that came with it to the recording.
+    fn record<'a>(
+        &self,
+        direction: Direction,
+        fds: usize,
+        data: impl Iterator<Item = &'a [u8]>,
+    ) -> Result<()> {
+        let mut log = self.log.lock().unwrap();
+        let marker = direction.marker();
+        if fds > 0 {
+            writeln!(log, "{} fds {}", marker, fds)?;
+        }
+        let mut data = data.filter(|data| !data.is_empty()).peekable();
+        if data.peek().is_some() {
+            write!(log, "{} ", marker)?;
+            for byte in data.flatten() {
+                write!(log, "{:02x}", byte)?;
+            }
+            writeln!(log)?;
+        }
+        log.flush()
+    }
+}
+
+impl<S: Stream> Stream for RecordingStream<S> {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        self.inner.poll(mode)
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<bool> {
+        self.inner.poll_timeout(mode, timeout)
+    }
+
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let fds_before = fd_storage.len();
+        let length = self.inner.read(buf, fd_storage)?;
+        let fds = fd_storage.len() - fds_before;
+        self.record(
+            Direction::ServerToClient,
+            fds,
+            std::iter::once(&buf[..length]),
+        )?;
+        Ok(length)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let fds_before = fds.len();
+        let length = self.inner.write(buf, fds)?;
+        let fds = fds_before - fds.len();
+        self.record(
+            Direction::ClientToServer,
+            fds,
+            std::iter::once(&buf[..length]),
+        )?;
+        Ok(length)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let fds_before = fds.len();
+        let length = self.inner.write_vectored(bufs, fds)?;
+        let fds = fds_before - fds.len();
+        // Only record the part of the buffers that was actually written
+        let mut remaining = length;
+        let written = bufs.iter().map(|buf| {
+            let part = &buf[..remaining.min(buf.len())];
+            remaining -= part.len();
+            part
+        });
+        self.record(Direction::ClientToServer, fds, written)?;
+        Ok(length)
+    }
+}
@@ -0,0 +1,460 @@
+//! Replaying a recorded connection without an X11 server.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Error, ErrorKind, IoSlice, Result};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::recording::{read_recording, Direction, Entry};
+use super::setup_request_length;
+use crate::protocol::xproto::{self, QueryExtensionReply, SetupRequest};
+use crate::protocol::Request;
+use crate::rust_connection::{PollMode, Stream};
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, TryParse,
+};
+
+/// Data that the server sent in a recording.
+#[derive(Debug)]
+struct ServerChunk {
+    /// The number of bytes that the client sent before this data in the recording.
+    after_client_bytes: usize,
+    /// The number of file descriptors that were received with this data.
+    fds: usize,
+    data: Vec<u8>,
+}
+
+/// The state of a [`ReplayStream`].
+#[derive(Debug)]
+struct ReplayState {
+    /// Everything that the client should send.
+    expected: Vec<u8>,
+    /// Everything that the client did send so far.
+    written: Vec<u8>,
+    /// The number of file descriptors that the client should send, in order.
+    expected_fds: VecDeque<usize>,
+    /// The data that the client did not read yet.
+    server_chunks: VecDeque<ServerChunk>,
+}
+
+impl ReplayState {
+    /// Can the next chunk of server data be read now?
+    fn can_read(&self) -> bool {
+        self.server_chunks.front().map_or(false, |chunk| {
+            chunk.after_client_bytes <= self.written.len()
+        })
+    }
+}
+
+/// A [`Stream`] that replays a recording made with [`RecordingStream`](super::RecordingStream).
+///
+/// The data that the server sent in the recording is returned to the client. Data is only
+/// made available after the client sent everything that came before it in the recording, so that
+/// for example a reply is never received before its request was sent.
+///
+/// Everything that the client sends is compared with the recording. When the data differs, this
+/// stream panics with a message that describes the first request that does not match. File
+/// descriptors that the server sent in the recording are replaced with file descriptors for
+/// `/dev/null`.
+///
+/// Since nothing happens on its own, waiting for data that the recording does not contain (yet)
+/// would block forever. Instead, this stream reports an error in this case.
+///
+/// # Example
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::protocol::xproto::ConnectionExt;
+/// use x11rb::rust_connection::RustConnection;
+/// use x11rb::testing::ReplayStream;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let stream = ReplayStream::open("session.txt")?;
+/// let conn = RustConnection::connect_to_stream(stream, 0)?;
+/// conn.get_input_focus()?.reply()?;
+/// conn.stream().assert_finished();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReplayStream {
+    state: Mutex<ReplayState>,
+    extensions: RecordedExtensions,
+}
+
+impl ReplayStream {
+    /// Replay the recording in the given file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Replay a recording that is read from the given reader.
+    pub fn from_reader(read: impl BufRead) -> Result<Self> {
+        let mut expected = Vec::new();
+        let mut expected_fds = VecDeque::new();
+        let mut server_chunks = VecDeque::new();
+        let mut pending_server_fds = 0;
+        for entry in read_recording(read)? {
+            match entry {
+                Entry::Data(Direction::ClientToServer, data) => expected.extend(data),
+                Entry::Fds(Direction::ClientToServer, fds) => expected_fds.push_back(fds),
+                Entry::Data(Direction::ServerToClient, data) => {
+                    server_chunks.push_back(ServerChunk {
+                        after_client_bytes: expected.len(),
+                        fds: std::mem::replace(&mut pending_server_fds, 0),
+                        data,
+                    });
+                }
+                Entry::Fds(Direction::ServerToClient, fds) => pending_server_fds += fds,
+            }
+        }
+        if pending_server_fds != 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "the recording ends with file descriptors without data",
+            ));
+        }
+
+        let server_data = server_chunks
+            .iter()
+            .flat_map(|chunk| chunk.data.iter().copied())
+            .collect::<Vec<_>>();
+        let extensions = RecordedExtensions::new(&expected, &server_data);
+        let state = ReplayState {
+            expected,
+            written: Vec::new(),
+            expected_fds,
+            server_chunks,
+        };
+        Ok(Self {
+            state: Mutex::new(state),
+            extensions,
+        })
+    }
+
+    /// Check that the client sent everything that is in the recording.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client did not send all the data of the recording.
+    pub fn assert_finished(&self) {
+        let state = self.state.lock().unwrap();
+        if state.written.len() < state.expected.len() {
+            let requests = split_requests(&state.expected);
+            let (index, range) = requests
+                .iter()
+                .enumerate()
+                .find(|(_, range)| range.end > state.written.len())
+                .unwrap();
+            panic!(
+                "The client did not send all requests of the recording. The next request would \
+                 be number {}:\n{}",
+                index,
+                self.describe_request(index, &state.expected[range.clone()]),
+            );
+        }
+    }
+
+    /// Check the data that the client sent so far against the recording.
+    fn check_written(&self, state: &ReplayState) {
+        let length = state.written.len().min(state.expected.len());
+        let mismatch = state.written[..length]
+            .iter()
+            .zip(&state.expected[..length])
+            .position(|(written, expected)| written != expected);
+        let offset = match mismatch {
+            Some(offset) => offset,
+            None if state.written.len() > state.expected.len() => state.expected.len(),
+            None => return,
+        };
+
+        // Find the request that contains the difference. Everything before it is equal.
+        let (index, range) = split_requests(&state.written)
+            .into_iter()
+            .enumerate()
+            .find(|(_, range)| range.end > offset)
+            .unwrap();
+        let actual = self.describe_request(index, &state.written[range.clone()]);
+        if offset == state.expected.len() {
+            panic!(
+                "The client sent request number {}, which is not in the recording:\n{}",
+                index, actual
+            );
+        }
+        let expected_range = split_requests(&state.expected)
+            .into_iter()
+            .nth(index)
+            .unwrap();
+        let expected = self.describe_request(index, &state.expected[expected_range]);
+        panic!(
+            "Request number {} does not match the recording (first difference at byte {} of the \
+             request):\n{}",
+            index,
+            offset - range.start,
+            diff_lines(&expected, &actual),
+        );
+    }
+
+    /// Describe a request for a panic message.
+    fn describe_request(&self, index: usize, data: &[u8]) -> String {
+        if index == 0 {
+            if let Ok((setup, _)) = SetupRequest::try_parse(data) {
+                return format!("{:#?}", setup);
+            }
+        } else if let Ok((header, body)) = parse_request_header(data, BigRequests::Enabled) {
+            if let Ok(request) = Request::parse(header, body, &mut Vec::new(), &self.extensions) {
+                return format!("{:#?}", request);
+            }
+        }
+        format!("{:02x?}", data)
+    }
+}
+
+impl Stream for ReplayStream {
+    fn poll(&self, mode: PollMode) -> Result<()> {
+        let state = self.state.lock().unwrap();
+        if mode.writable() || state.can_read() {
+            Ok(())
+        } else if state.server_chunks.is_empty() {
+            Err(Error::new(
+                ErrorKind::Other,
+                "the recording contains no more data from the server, so waiting would block \
+                 forever",
+            ))
+        } else {
+            Err(Error::new(
+                ErrorKind::Other,
+                "the recording contains more data from the server only after the client sent \
+                 more requests, so waiting would block forever",
+            ))
+        }
+    }
+
+    fn poll_timeout(&self, mode: PollMode, timeout: Duration) -> Result<bool> {
+        // Nothing can happen while waiting, so the timeout expires immediately
+        let _ = timeout;
+        Ok(mode.writable() || self.state.lock().unwrap().can_read())
+    }
+
+    fn read(&self, buf: &mut [u8], fd_storage: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let mut state = self.state.lock().unwrap();
+        if !state.can_read() {
+            return Err(ErrorKind::WouldBlock.into());
+        }
+        let chunk = state.server_chunks.front_mut().unwrap();
+        for _ in 0..std::mem::replace(&mut chunk.fds, 0) {
+            fd_storage.push(dummy_fd()?);
+        }
+        let length = buf.len().min(chunk.data.len());
+        buf[..length].copy_from_slice(&chunk.data[..length]);
+        let _ = chunk.data.drain(..length);
+        if chunk.data.is_empty() {
+            let _ = state.server_chunks.pop_front();
+        }
+        Ok(length)
+    }
+
+    fn write(&self, buf: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        self.write_vectored(&[IoSlice::new(buf)], fds)
+    }
+
+    fn write_vectored(&self, bufs: &[IoSlice<'_>], fds: &mut Vec<RawFdContainer>) -> Result<usize> {
+        let mut state = self.state.lock().unwrap();
+        if !fds.is_empty() {
+            let expected_fds = state.expected_fds.pop_front();
+            assert_eq!(
+                expected_fds,
+                Some(fds.len()),
+                "The client sent a different number of file descriptors than in the recording",
+            );
+            fds.clear();
+        }
+        for buf in bufs {
+            state.written.extend_from_slice(buf);
+        }
+        self.check_written(&state);
+        Ok(bufs.iter().map(|buf| buf.len()).sum())
+    }
+}
+
+/// Get a file descriptor that stands in for a file descriptor from the recording.
+#[cfg(unix)]
+fn dummy_fd() -> Result<RawFdContainer> {
+    use std::os::unix::io::IntoRawFd;
+    Ok(RawFdContainer::new(File::open("/dev/null")?.into_raw_fd()))
+}
+
+#[cfg(not(unix))]
+fn dummy_fd() -> Result<RawFdContainer> {
+    Err(Error::new(
+        ErrorKind::Other,
+        "the recording contains file descriptors, which are not supported on this platform",
+    ))
+}
+
+/// Split the data that a client sent into the setup request and the following requests.
+///
+/// The last range is cut off at the end of the data if the last request is incomplete.
+fn split_requests(data: &[u8]) -> Vec<Range<usize>> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    let mut length = setup_request_length(data);
+    while start < data.len() {
+        // An incomplete length field is treated like a minimal request
+        let end = (start + length.unwrap_or(4).max(4)).min(data.len());
+        result.push(start..end);
+        start = end;
+        length = request_length(&data[start..]);
+    }
+    result
+}
+
+/// Get the length of a request from its beginning.
+fn request_length(request: &[u8]) -> Option<usize> {
+    let length = u16::from_ne_bytes([*request.get(2)?, *request.get(3)?]);
+    let length = if length == 0 {
+        // This is a request with the BIG-REQUESTS extension
+        u32::from_ne_bytes([
+            *request.get(4)?,
+            *request.get(5)?,
+            *request.get(6)?,
+            *request.get(7)?,
+        ])
+    } else {
+        length.into()
+    };
+    usize::try_from(length).ok()?.checked_mul(4)
+}
+
+/// Compare the lines of two texts and mark those that differ.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut result = String::new();
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => return result,
+            (Some(expected), Some(actual)) if expected == actual => {
+                result.push_str("  ");
+                result.push_str(expected);
+                result.push('\n');
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    result.push_str("- ");
+                    result.push_str(expected);
+                    result.push('\n');
+                }
+                if let Some(actual) = actual {
+                    result.push_str("+ ");
+                    result.push_str(actual);
+                    result.push('\n');
+                }
+            }
+        }
+    }
+}
+
+/// The extensions that the client queried in a recording.
+///
+/// This is used for describing extension requests.
+#[derive(Debug, Default)]
+struct RecordedExtensions(Vec<(String, ExtensionInformation)>);
+
+impl RecordedExtensions {
+    /// Find the `QueryExtension` requests of the client and their replies.
+    fn new(client_data: &[u8], server_data: &[u8]) -> Self {
+        // Find the names of all queried extensions by the sequence number of their request
+        let mut queries = Vec::new();
+        for (index, range) in split_requests(client_data).into_iter().enumerate().skip(1) {
+            let request = &client_data[range];
+            if request[0] != xproto::QUERY_EXTENSION_REQUEST {
+                continue;
+            }
+            if let Ok((header, body)) = parse_request_header(request, BigRequests::Enabled) {
+                if let Ok(Request::QueryExtension(query)) =
+                    Request::parse(header, body, &mut Vec::new(), &Self::default())
+                {
+                    // Only the lower 16 bits of the sequence number are sent
+                    let sequence = index as u16;
+                    queries.push((sequence, String::from_utf8_lossy(&query.name).into_owned()));
+                }
+            }
+        }
+
+        // Find the replies to these requests. The first packet is the response to the setup.
+        let mut extensions = Vec::new();
+        let mut packets = server_data;
+        let mut first = true;
+        while packets.len() >= 8 {
+            let length = if first {
+                8 + 4 * usize::from(u16::from_ne_bytes([packets[6], packets[7]]))
+            } else if packets[0] == 1 || packets[0] & 0x7f == xproto::GE_GENERIC_EVENT {
+                let length = u32::from_ne_bytes([packets[4], packets[5], packets[6], packets[7]]);
+                32 + 4 * usize::try_from(length).unwrap_or(0)
+            } else {
+                32
+            };
+            let packet = match packets.get(..length) {
+                Some(packet) => packet,
+                None => break,
+            };
+            if !first && packet[0] == 1 {
+                let sequence = u16::from_ne_bytes([packet[2], packet[3]]);
+                let name = queries.iter().find(|(query, _)| *query == sequence);
+                if let (Some((_, name)), Ok((reply, _))) =
+                    (name, QueryExtensionReply::try_parse(packet))
+                {
+                    if reply.present {
+                        let info = ExtensionInformation {
+                            major_opcode: reply.major_opcode,
+                            first_event: reply.first_event,
+                            first_error: reply.first_error,
+                        };
+                        extensions.push((name.clone(), info));
+                    }
+                }
+            }
+            first = false;
+            packets = &packets[length..];
+        }
+        Self(extensions)
+    }
+}
+
+impl ExtInfoProvider for RecordedExtensions {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .find(|(_, info)| info.major_opcode == major_opcode)
+            .map(|(name, info)| (&name[..], *info))
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_event != 0 && info.first_event <= event_code)
+            .max_by_key(|(_, info)| info.first_event)
+            .map(|(name, info)| (&name[..], *info))
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        self.0
+            .iter()
+            .filter(|(_, info)| info.first_error != 0 && info.first_error <= error_code)
+            .max_by_key(|(_, info)| info.first_error)
+            .map(|(name, info)| (&name[..], *info))
+    }
+
+    fn get_from_extension_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        self.0
+            .iter()
+            .find(|(name, _)| name == extension_name)
+            .map(|(_, info)| *info)
+    }
+}
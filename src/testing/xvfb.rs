@@ -0,0 +1,383 @@
+//! Starting a throwaway X11 server such as `Xvfb` for tests.
+
+use std::collections::hash_map::RandomState;
+use std::ffi::{OsStr, OsString};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufRead, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use crate::errors::ConnectError;
+use crate::rust_connection::xauth::{AuthEntry, Family, XAuthority};
+use crate::rust_connection::{DefaultStream, RustConnection};
+
+const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+
+/// The default time to wait for the X11 server to become ready.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The interval in which the socket of the X11 server is checked when probing for a free display.
+const PROBE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// An error that occurred while starting an X11 server.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum XvfbError {
+    /// An I/O error occurred, for example while starting the server process.
+    IOError(std::io::Error),
+
+    /// The server exited before it became ready.
+    Exited(ExitStatus),
+
+    /// The server did not become ready before the timeout expired.
+    Timeout,
+
+    /// The server reported a display number that could not be parsed.
+    InvalidDisplay(String),
+
+    /// No free display number was found.
+    NoFreeDisplay,
+
+    /// Connecting to the server failed.
+    ConnectError(ConnectError),
+}
+
+impl std::error::Error for XvfbError {}
+
+impl std::fmt::Display for XvfbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XvfbError::IOError(err) => err.fmt(f),
+            XvfbError::Exited(status) => write!(f, "X11 server exited early ({})", status),
+            XvfbError::Timeout => write!(f, "X11 server did not start before the timeout"),
+            XvfbError::InvalidDisplay(display) => {
+                write!(f, "X11 server reported invalid display {:?}", display)
+            }
+            XvfbError::NoFreeDisplay => write!(f, "No free display number found"),
+            XvfbError::ConnectError(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<std::io::Error> for XvfbError {
+    fn from(err: std::io::Error) -> Self {
+        XvfbError::IOError(err)
+    }
+}
+
+impl From<ConnectError> for XvfbError {
+    fn from(err: ConnectError) -> Self {
+        XvfbError::ConnectError(err)
+    }
+}
+
+/// How the display number of a new X11 server is chosen.
+#[derive(Debug, Clone, Copy)]
+enum DisplaySelection {
+    /// Let the server pick a display and report it via `-displayfd`.
+    DisplayFd,
+    /// Look for the first display without a lock file or socket in `/tmp`.
+    Probe,
+}
+
+/// A builder for starting an X11 server like `Xvfb` that is used for a single test.
+///
+/// By default, `Xvfb` from `$PATH` is started. The server lets clients only connect via its unix
+/// socket and only with a newly generated `MIT-MAGIC-COOKIE-1`. The server picks a free display
+/// number itself and reports it via `-displayfd`. For servers that do not support this, use
+/// [`XvfbBuilder::probe_display`].
+///
+/// # Example
+///
+/// ```no_run
+/// use x11rb::connection::Connection;
+/// use x11rb::testing::XvfbBuilder;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (conn, xvfb) = match XvfbBuilder::new().arg("-screen").arg("0").arg("800x600x24").spawn()? {
+///     Some(result) => result,
+///     None => {
+///         eprintln!("Xvfb is not installed, skipping test");
+///         return Ok(());
+///     }
+/// };
+/// println!("Running on {}", xvfb.display_name());
+/// assert_eq!(conn.setup().roots[0].width_in_pixels, 800);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct XvfbBuilder {
+    program: OsString,
+    args: Vec<OsString>,
+    timeout: Duration,
+    display: DisplaySelection,
+}
+
+impl Default for XvfbBuilder {
+    fn default() -> Self {
+        Self {
+            program: "Xvfb".into(),
+            args: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+            display: DisplaySelection::DisplayFd,
+        }
+    }
+}
+
+impl XvfbBuilder {
+    /// Create a new builder for starting `Xvfb`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Start the given program instead of `Xvfb`, for example `Xephyr`.
+    ///
+    /// The program must accept the `-auth` and `-nolisten` arguments of the X.Org servers. It must
+    /// also accept `-displayfd`, unless [`XvfbBuilder::probe_display`] is used.
+    pub fn program(mut self, program: impl AsRef<OsStr>) -> Self {
+        self.program = program.as_ref().into();
+        self
+    }
+
+    /// Pass an additional argument to the server.
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.args.push(arg.as_ref().into());
+        self
+    }
+
+    /// Set how long to wait for the server to become ready.
+    ///
+    /// The default is ten seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Pick the display number by looking for the first display without a lock file
+    /// `/tmp/.X<n>-lock` or socket `/tmp/.X11-unix/X<n>`.
+    ///
+    /// This is needed for servers that do not support `-displayfd`. Since another server might
+    /// take the display number before the new server does, this is less reliable.
+    pub fn probe_display(mut self) -> Self {
+        self.display = DisplaySelection::Probe;
+        self
+    }
+
+    /// Start the server and connect to it.
+    ///
+    /// Returns `Ok(None)` if the program could not be found, so that tests can be skipped when
+    /// it is not installed.
+    pub fn spawn(self) -> Result<Option<(RustConnection, Xvfb)>, XvfbError> {
+        let xvfb = match self.start()? {
+            Some(xvfb) => xvfb,
+            None => return Ok(None),
+        };
+        let conn = xvfb.connect()?;
+        Ok(Some((conn, xvfb)))
+    }
+
+    /// Start the server without connecting to it.
+    fn start(self) -> Result<Option<Xvfb>, XvfbError> {
+        let cookie = generate_cookie();
+        let xauthority = xauthority_path();
+        let mut entries = XAuthority::new();
+        entries.add(AuthEntry {
+            family: Family::WILD,
+            address: Vec::new(),
+            number: Vec::new(),
+            name: MIT_MAGIC_COOKIE_1.to_vec(),
+            data: cookie.clone(),
+        });
+        entries.save(&xauthority)?;
+
+        let mut command = Command::new(&self.program);
+        let _ = command
+            .arg("-auth")
+            .arg(&xauthority)
+            .arg("-nolisten")
+            .arg("tcp")
+            .args(&self.args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null());
+        let probed_display = match self.display {
+            DisplaySelection::DisplayFd => {
+                let _ = command.arg("-displayfd").arg("1").stdout(Stdio::piped());
+                None
+            }
+            DisplaySelection::Probe => match find_free_display() {
+                Some(display) => {
+                    let _ = command.arg(format!(":{}", display)).stdout(Stdio::null());
+                    Some(display)
+                }
+                None => {
+                    let _ = std::fs::remove_file(&xauthority);
+                    return Err(XvfbError::NoFreeDisplay);
+                }
+            },
+        };
+        let process = match command.spawn() {
+            Ok(process) => process,
+            Err(e) => {
+                let _ = std::fs::remove_file(&xauthority);
+                if e.kind() == ErrorKind::NotFound {
+                    return Ok(None);
+                }
+                return Err(e.into());
+            }
+        };
+
+        // From here on, dropping `xvfb` cleans up if something goes wrong
+        let mut xvfb = Xvfb {
+            process,
+            xauthority,
+            display: 0,
+            cookie,
+        };
+        xvfb.display = match probed_display {
+            Some(display) => {
+                wait_for_socket(&mut xvfb.process, display, self.timeout)?;
+                display
+            }
+            None => read_display(&mut xvfb.process, self.timeout)?,
+        };
+        Ok(Some(xvfb))
+    }
+}
+
+/// A running X11 server that was started by [`XvfbBuilder`].
+///
+/// The server is killed and its `.Xauthority` file is removed when this is dropped.
+#[derive(Debug)]
+pub struct Xvfb {
+    process: Child,
+    xauthority: PathBuf,
+    display: u16,
+    cookie: Vec<u8>,
+}
+
+impl Xvfb {
+    /// Get the display number of the server.
+    pub fn display(&self) -> u16 {
+        self.display
+    }
+
+    /// Get the name of the server's display, for example `:1`.
+    ///
+    /// Together with [`Xvfb::xauthority`], this can be used for running other programs on this
+    /// server by setting `$DISPLAY` and `$XAUTHORITY`.
+    pub fn display_name(&self) -> String {
+        format!(":{}", self.display)
+    }
+
+    /// Get the path to an `.Xauthority` file that contains the credentials for this server.
+    pub fn xauthority(&self) -> &Path {
+        &self.xauthority
+    }
+
+    /// Open another connection to this server.
+    pub fn connect(&self) -> Result<RustConnection, ConnectError> {
+        let stream = DefaultStream::connect("", Some("unix"), self.display)?;
+        RustConnection::connect_to_stream_with_auth_info(
+            stream,
+            0,
+            MIT_MAGIC_COOKIE_1.to_vec(),
+            self.cookie.clone(),
+        )
+    }
+}
+
+impl Drop for Xvfb {
+    fn drop(&mut self) {
+        kill(&mut self.process);
+        let _ = std::fs::remove_file(&self.xauthority);
+    }
+}
+
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Generate a random `MIT-MAGIC-COOKIE-1`.
+fn generate_cookie() -> Vec<u8> {
+    // `RandomState` is seeded randomly, which is good enough for a throwaway server
+    (0..2)
+        .flat_map(|_| {
+            RandomState::new()
+                .build_hasher()
+                .finish()
+                .to_ne_bytes()
+                .to_vec()
+        })
+        .collect()
+}
+
+/// Get a path for a new `.Xauthority` file.
+fn xauthority_path() -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!(
+        "x11rb-xvfb-{}-{}.Xauthority",
+        std::process::id(),
+        counter
+    ))
+}
+
+/// Find the first display number that has neither a lock file nor a socket.
+fn find_free_display() -> Option<u16> {
+    (1..1000).find(|display| {
+        !Path::new(&format!("/tmp/.X{}-lock", display)).exists()
+            && !Path::new(&format!("/tmp/.X11-unix/X{}", display)).exists()
+    })
+}
+
+/// Check whether the server exited.
+fn check_exited(process: &mut Child) -> Result<(), XvfbError> {
+    match process.try_wait()? {
+        Some(status) => Err(XvfbError::Exited(status)),
+        None => Ok(()),
+    }
+}
+
+/// Wait for the server to create the socket of the given display.
+fn wait_for_socket(process: &mut Child, display: u16, timeout: Duration) -> Result<(), XvfbError> {
+    let socket = format!("/tmp/.X11-unix/X{}", display);
+    let start = Instant::now();
+    while !Path::new(&socket).exists() {
+        check_exited(process)?;
+        if start.elapsed() >= timeout {
+            return Err(XvfbError::Timeout);
+        }
+        std::thread::sleep(PROBE_INTERVAL);
+    }
+    Ok(())
+}
+
+/// Read the display number that the server writes to its standard output due to `-displayfd`.
+fn read_display(process: &mut Child, timeout: Duration) -> Result<u16, XvfbError> {
+    let stdout = process.stdout.take().expect("stdout should be piped");
+    // Reading cannot time out, so do it in another thread. If the server hangs, the thread exits
+    // once the server is killed.
+    let (sender, receiver) = mpsc::channel();
+    let _ = std::thread::spawn(move || {
+        let mut line = String::new();
+        let result = BufReader::new(stdout).read_line(&mut line).map(|_| line);
+        let _ = sender.send(result);
+    });
+    let line = match receiver.recv_timeout(timeout) {
+        Ok(line) => line?,
+        Err(_) => return Err(XvfbError::Timeout),
+    };
+    if line.is_empty() {
+        // The server closed its standard output without reporting a display
+        let status = process.wait()?;
+        return Err(XvfbError::Exited(status));
+    }
+    line.trim()
+        .parse()
+        .map_err(|_| XvfbError::InvalidDisplay(line.trim().to_string()))
+}
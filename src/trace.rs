@@ -0,0 +1,5 @@
+//! One-line traces of X11 protocol messages in the style of the `xtrace` tool.
+//!
+//! See [`Trace`] for the entry point.
+
+pub use x11rb_protocol::trace::{AtomNames, Trace, Traced, Tracer};
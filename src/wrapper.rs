@@ -1,56 +1,17 @@
 //! Some wrappers around the generated code to simplify use.
 
 use std::convert::TryInto;
-use std::marker::PhantomData;
 
 use super::cookie::VoidCookie;
 use super::errors::{ConnectionError, ReplyError};
 use super::protocol::xproto::{Atom, ConnectionExt as XProtoConnectionExt, PropMode, Window};
-use super::x11_utils::TryParse;
 
-/// Iterator implementation used by `GetPropertyReply`.
-///
-/// This is the actual type returned by `GetPropertyReply::value8`, `GetPropertyReply::value16`,
-/// and `GetPropertyReply::value32`. This type needs to be public due to Rust's visibility rules.
-#[derive(Debug, Clone)]
-pub struct PropertyIterator<'a, T>(&'a [u8], PhantomData<T>);
-
-impl<'a, T> PropertyIterator<'a, T> {
-    pub(crate) fn new(value: &'a [u8]) -> Self {
-        PropertyIterator(value, PhantomData)
-    }
-}
-
-impl<T> Iterator for PropertyIterator<'_, T>
-where
-    T: TryParse,
-{
-    type Item = T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match T::try_parse(self.0) {
-            Ok((value, remaining)) => {
-                self.0 = remaining;
-                Some(value)
-            }
-            Err(_) => {
-                self.0 = &[];
-                None
-            }
-        }
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.0.len() / std::mem::size_of::<T>();
-        (size, Some(size))
-    }
-}
-
-impl<T: TryParse> std::iter::FusedIterator for PropertyIterator<'_, T> {}
+pub use x11rb_protocol::wrapper::PropertyIterator;
 
 /// Extension trait that simplifies API use
 pub trait ConnectionExt: XProtoConnectionExt {
     /// Change a property on a window with format 8.
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn change_property8<A, B>(
         &self,
         mode: PropMode,
@@ -75,6 +36,7 @@ pub trait ConnectionExt: XProtoConnectionExt {
     }
 
     /// Change a property on a window with format 16.
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn change_property16<A, B>(
         &self,
         mode: PropMode,
@@ -103,6 +65,7 @@ pub trait ConnectionExt: XProtoConnectionExt {
     }
 
     /// Change a property on a window with format 32.
+    #[cfg_attr(all(feature = "request-location", debug_assertions), track_caller)]
     fn change_property32<A, B>(
         &self,
         mode: PropMode,
@@ -13,6 +13,8 @@ use std::sync::{
     atomic::{AtomicU64, Ordering},
     Mutex,
 };
+#[cfg(unix)]
+use std::time::{Duration, Instant};
 
 use libc::c_void;
 
@@ -24,6 +26,7 @@ use crate::cookie::{Cookie, CookieWithFds, VoidCookie};
 pub use crate::errors::{ConnectError, ConnectionError, ParseError, ReplyError, ReplyOrIdError};
 use crate::extension_manager::ExtensionManager;
 use crate::protocol::xproto::Setup;
+use crate::statistics::{Statistics, StatisticsCollector, StatisticsConnection};
 use crate::utils::{CSlice, RawFdContainer};
 use crate::x11_utils::ExtensionInformation;
 
@@ -50,6 +53,7 @@ pub struct XCBConnection {
     ext_mgr: Mutex<ExtensionManager>,
     errors: pending_errors::PendingErrors,
     maximum_sequence_received: AtomicU64,
+    statistics: StatisticsCollector,
 }
 
 impl XCBConnection {
@@ -114,6 +118,7 @@ impl XCBConnection {
                     ext_mgr: Default::default(),
                     errors: Default::default(),
                     maximum_sequence_received: AtomicU64::new(0),
+                    statistics: Default::default(),
                 };
                 Ok((conn, screen as usize))
             }
@@ -141,6 +146,7 @@ impl XCBConnection {
             ext_mgr: Default::default(),
             errors: Default::default(),
             maximum_sequence_received: AtomicU64::new(0),
+            statistics: Default::default(),
         })
     }
 
@@ -242,6 +248,7 @@ impl XCBConnection {
         if seqno == 0 {
             unsafe { Err(Self::connection_error_from_connection(self.conn.as_ptr())) }
         } else {
+            self.statistics.request_sent(new_bufs);
             Ok(seqno)
         }
     }
@@ -302,6 +309,7 @@ impl XCBConnection {
             .expect("usize should have at least 32 bits");
 
         let length = 32 + length_field * 4;
+        self.statistics.bytes_read(length);
         CSlice::new(header.into_ptr(), length)
     }
 
@@ -309,6 +317,7 @@ impl XCBConnection {
         // Update our "max sequence number received" field
         atomic_u64_max(&self.maximum_sequence_received, sequence);
 
+        self.statistics.bytes_read(32);
         CSlice::new(error, 32)
     }
 
@@ -331,9 +340,77 @@ impl XCBConnection {
             // the 32-byte boundary.
             std::ptr::copy(event.add(36), event.add(32), length_field * 4);
         }
+        self.statistics.bytes_read(length);
         Ok((CSlice::new(header.into_ptr(), length), seqno))
     }
 
+    /// Wait until `check` produces a result, but give up once `timeout` expired.
+    ///
+    /// `check` must not block. Between its invocations, the connection's file descriptor is
+    /// polled for readability. Since another thread might read the packet that we are waiting
+    /// for, this poll only lasts for a short while before `check` is called again.
+    ///
+    /// Flushing the output buffer before waiting cannot be interrupted by the timeout.
+    #[cfg(unix)]
+    fn wait_with_timeout<T>(
+        &self,
+        timeout: Duration,
+        mut check: impl FnMut() -> Result<Option<T>, ConnectionError>,
+    ) -> Result<T, ConnectionError> {
+        use nix::errno::Errno;
+        use nix::poll::{poll, PollFd, PollFlags};
+
+        const MAX_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+        // A deadline that cannot be represented is treated as no deadline at all
+        let deadline = Instant::now().checked_add(timeout);
+        self.flush()?;
+        let mut blocked = false;
+        loop {
+            if let Some(result) = check()? {
+                return Ok(result);
+            }
+            if !blocked {
+                blocked = true;
+                self.statistics.blocking_wait();
+            }
+            let remaining = match deadline {
+                None => MAX_POLL_INTERVAL,
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(ConnectionError::Timeout);
+                    }
+                    deadline - now
+                }
+            };
+            // Round up so that we do not busy-loop during the last millisecond
+            let millis = remaining.min(MAX_POLL_INTERVAL).as_millis() as c_int + 1;
+            let mut poll_fds = [PollFd::new(self.as_raw_fd(), PollFlags::POLLIN)];
+            match poll(&mut poll_fds, millis) {
+                Ok(_) | Err(nix::Error::Sys(Errno::EINTR)) => {}
+                Err(e) => return Err(crate::utils::nix_error_to_io(e).into()),
+            }
+        }
+    }
+
+    /// Get the FDs that libxcb received together with a reply.
+    #[cfg(unix)]
+    fn reply_with_fds(&self, buffer: CSlice) -> BufWithFds {
+        // Get a pointer to the array of integers where libxcb saved the FD numbers.
+        // libxcb saves the list of FDs after the data of the reply. Since the reply's
+        // length is encoded in "number of 4 bytes block", the following pointer is aligned
+        // correctly (if malloc() returned an aligned chunk, which it does).
+        #[allow(clippy::cast_ptr_alignment)]
+        let fd_ptr = (unsafe { buffer.as_ptr().add(buffer.len()) }) as *const RawFd;
+
+        // The number of FDs is in the second byte (= buffer[1]) in all replies.
+        let fd_slice = unsafe { std::slice::from_raw_parts(fd_ptr, usize::from(buffer[1])) };
+        let fd_vec = fd_slice.iter().map(|&fd| RawFdContainer::new(fd)).collect();
+
+        (buffer, fd_vec)
+    }
+
     /// Reconstruct a full sequence number based on a partial value.
     ///
     /// The assumption for the algorithm here is that the given sequence number was received
@@ -424,6 +501,15 @@ impl RequestConnection for XCBConnection {
         &self,
         sequence: SequenceNumber,
     ) -> Result<ReplyOrError<CSlice>, ConnectionError> {
+        // Check if the reply was already received. Otherwise, waiting has to block.
+        if let Ok(Some(reply)) = self.poll_for_reply(sequence) {
+            if reply[0] == 0 {
+                return Ok(ReplyOrError::Error(reply));
+            } else {
+                return Ok(ReplyOrError::Reply(reply));
+            }
+        }
+        self.statistics.blocking_wait();
         unsafe {
             let mut error = null_mut();
             let reply = raw_ffi::xcb_wait_for_reply64(self.conn.as_ptr(), sequence, &mut error);
@@ -437,6 +523,26 @@ impl RequestConnection for XCBConnection {
         }
     }
 
+    #[cfg(unix)]
+    fn wait_for_reply_or_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<CSlice>, ConnectionError> {
+        self.wait_with_timeout(timeout, || match self.poll_for_reply(sequence) {
+            Err(()) => Ok(None),
+            Ok(Some(reply)) => {
+                if reply[0] == 0 {
+                    Ok(Some(ReplyOrError::Error(reply)))
+                } else {
+                    Ok(Some(ReplyOrError::Reply(reply)))
+                }
+            }
+            // This only happens when the connection is in an error state
+            Ok(None) => unsafe { Err(Self::connection_error_from_connection(self.conn.as_ptr())) },
+        })
+    }
+
     fn wait_for_reply(&self, sequence: SequenceNumber) -> Result<Option<CSlice>, ConnectionError> {
         match self.wait_for_reply_or_raw_error(sequence)? {
             ReplyOrError::Reply(reply) => Ok(Some(reply)),
@@ -447,6 +553,21 @@ impl RequestConnection for XCBConnection {
         }
     }
 
+    #[cfg(unix)]
+    fn wait_for_reply_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<CSlice>, ConnectionError> {
+        match self.wait_for_reply_or_raw_error_timeout(sequence, timeout)? {
+            ReplyOrError::Reply(reply) => Ok(Some(reply)),
+            ReplyOrError::Error(error) => {
+                self.errors.append_error((sequence, error));
+                Ok(None)
+            }
+        }
+    }
+
     #[cfg(unix)]
     fn wait_for_reply_with_fds_raw(
         &self,
@@ -456,19 +577,20 @@ impl RequestConnection for XCBConnection {
             ReplyOrError::Reply(reply) => reply,
             ReplyOrError::Error(error) => return Ok(ReplyOrError::Error(error)),
         };
+        Ok(ReplyOrError::Reply(self.reply_with_fds(buffer)))
+    }
 
-        // Get a pointer to the array of integers where libxcb saved the FD numbers.
-        // libxcb saves the list of FDs after the data of the reply. Since the reply's
-        // length is encoded in "number of 4 bytes block", the following pointer is aligned
-        // correctly (if malloc() returned an aligned chunk, which it does).
-        #[allow(clippy::cast_ptr_alignment)]
-        let fd_ptr = (unsafe { buffer.as_ptr().add(buffer.len()) }) as *const RawFd;
-
-        // The number of FDs is in the second byte (= buffer[1]) in all replies.
-        let fd_slice = unsafe { std::slice::from_raw_parts(fd_ptr, usize::from(buffer[1])) };
-        let fd_vec = fd_slice.iter().map(|&fd| RawFdContainer::new(fd)).collect();
-
-        Ok(ReplyOrError::Reply((buffer, fd_vec)))
+    #[cfg(unix)]
+    fn wait_for_reply_with_fds_raw_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<ReplyOrError<BufWithFds, Buffer>, ConnectionError> {
+        let buffer = match self.wait_for_reply_or_raw_error_timeout(sequence, timeout)? {
+            ReplyOrError::Reply(reply) => reply,
+            ReplyOrError::Error(error) => return Ok(ReplyOrError::Error(error)),
+        };
+        Ok(ReplyOrError::Reply(self.reply_with_fds(buffer)))
     }
 
     #[cfg(not(unix))]
@@ -483,6 +605,12 @@ impl RequestConnection for XCBConnection {
         &self,
         sequence: SequenceNumber,
     ) -> Result<Option<Buffer>, ConnectionError> {
+        // Check if the request was already handled. Otherwise, waiting has to block.
+        match self.poll_for_reply(sequence) {
+            Ok(Some(error)) => return Ok(Some(error)),
+            Ok(None) => return Ok(None),
+            Err(()) => self.statistics.blocking_wait(),
+        }
         let cookie = raw_ffi::xcb_void_cookie_t {
             sequence: sequence as _,
         };
@@ -494,6 +622,23 @@ impl RequestConnection for XCBConnection {
         }
     }
 
+    #[cfg(unix)]
+    fn check_for_raw_error_timeout(
+        &self,
+        sequence: SequenceNumber,
+        timeout: Duration,
+    ) -> Result<Option<Buffer>, ConnectionError> {
+        use crate::protocol::xproto::ConnectionExt as _;
+
+        // xcb_request_check() blocks until the request was handled. To avoid this, first wait for
+        // the reply to a later request. If this times out, dropping the cookie discards the reply.
+        match self.get_input_focus()?.reply_timeout(timeout) {
+            Ok(_) | Err(ReplyError::X11Error(_)) => {}
+            Err(ReplyError::ConnectionError(e)) => return Err(e),
+        }
+        self.check_for_raw_error(sequence)
+    }
+
     fn maximum_request_bytes(&self) -> usize {
         4 * unsafe { raw_ffi::xcb_get_maximum_request_length(self.conn.as_ptr()) as usize }
     }
@@ -515,9 +660,11 @@ impl RequestConnection for XCBConnection {
 
 impl Connection for XCBConnection {
     fn wait_for_raw_event_with_sequence(&self) -> Result<RawEventAndSeqNumber, ConnectionError> {
-        if let Some(error) = self.errors.get(self) {
-            return Ok((error.1, error.0));
+        // Check if an event was already received. Otherwise, waiting has to block.
+        if let Some(event) = self.poll_for_raw_event_with_sequence()? {
+            return Ok(event);
         }
+        self.statistics.blocking_wait();
         unsafe {
             let event = raw_ffi::xcb_wait_for_event(self.conn.as_ptr());
             if event.is_null() {
@@ -527,6 +674,14 @@ impl Connection for XCBConnection {
         }
     }
 
+    #[cfg(unix)]
+    fn wait_for_raw_event_with_sequence_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<RawEventAndSeqNumber, ConnectionError> {
+        self.wait_with_timeout(timeout, || self.poll_for_raw_event_with_sequence())
+    }
+
     fn poll_for_raw_event_with_sequence(
         &self,
     ) -> Result<Option<RawEventAndSeqNumber>, ConnectionError> {
@@ -548,6 +703,7 @@ impl Connection for XCBConnection {
     }
 
     fn flush(&self) -> Result<(), ConnectionError> {
+        self.statistics.flush();
         // xcb_flush() returns 0 if the connection is in (or just entered) an error state, else 1.
         let res = unsafe { raw_ffi::xcb_flush(self.conn.as_ptr()) };
         if res != 0 {
@@ -576,6 +732,17 @@ impl Connection for XCBConnection {
     }
 }
 
+/// Flushes that libxcb does implicitly, for example before waiting for a reply, are not counted.
+impl StatisticsConnection for XCBConnection {
+    fn statistics(&self) -> Statistics {
+        self.statistics.snapshot()
+    }
+
+    fn count_requests_by_opcode(&self) {
+        self.statistics.count_requests_by_opcode();
+    }
+}
+
 #[cfg(unix)]
 impl AsRawFd for XCBConnection {
     fn as_raw_fd(&self) -> RawFd {
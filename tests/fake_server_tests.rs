@@ -0,0 +1,396 @@
+#![cfg(feature = "testing")]
+
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::errors::ReplyError;
+use x11rb::protocol::xproto::{
+    AtomEnum, ChangeWindowAttributesAux, ConfigureWindowAux, ConnectionExt, CreateGCAux,
+    CreateWindowAux, EventMask, MapState, PropMode, Property, Rectangle, StackMode, Window,
+    WindowClass,
+};
+use x11rb::protocol::{ErrorKind, Event};
+use x11rb::rust_connection::RustConnection;
+use x11rb::testing::{FakeServer, FakeStream};
+use x11rb::wrapper::ConnectionExt as _;
+
+type Conn = RustConnection<FakeStream>;
+
+fn connect(server: &FakeServer) -> Conn {
+    RustConnection::connect_to_stream(server.connect(), 0).unwrap()
+}
+
+fn root(conn: &Conn) -> Window {
+    conn.setup().roots[0].root
+}
+
+fn create_window(conn: &Conn, parent: Window, aux: &CreateWindowAux) -> Window {
+    let window = conn.generate_id().unwrap();
+    conn.create_window(
+        0,
+        window,
+        parent,
+        10,
+        20,
+        100,
+        50,
+        0,
+        WindowClass::INPUT_OUTPUT,
+        0,
+        aux,
+    )
+    .unwrap()
+    .check()
+    .unwrap();
+    window
+}
+
+fn error_kind<T: std::fmt::Debug>(result: Result<T, ReplyError>) -> ErrorKind {
+    match result {
+        Err(ReplyError::X11Error(error)) => error.error_kind,
+        other => panic!("Expected an X11 error, got {:?}", other),
+    }
+}
+
+#[test]
+fn window_tree() {
+    let server = FakeServer::new();
+    let conn = connect(&server);
+    let root = root(&conn);
+    let parent = create_window(&conn, root, &CreateWindowAux::new());
+    let child1 = create_window(&conn, parent, &CreateWindowAux::new());
+    let child2 = create_window(&conn, parent, &CreateWindowAux::new());
+
+    let tree = conn.query_tree(parent).unwrap().reply().unwrap();
+    assert_eq!(tree.root, root);
+    assert_eq!(tree.parent, root);
+    assert_eq!(tree.children, [child1, child2]);
+
+    // Raise the first child above the second one
+    let aux = ConfigureWindowAux::new().stack_mode(StackMode::ABOVE);
+    conn.configure_window(child1, &aux)
+        .unwrap()
+        .check()
+        .unwrap();
+    let tree = conn.query_tree(parent).unwrap().reply().unwrap();
+    assert_eq!(tree.children, [child2, child1]);
+
+    let geometry = conn.get_geometry(child1).unwrap().reply().unwrap();
+    assert_eq!(
+        (geometry.x, geometry.y, geometry.width, geometry.height),
+        (10, 20, 100, 50)
+    );
+    let translated = conn
+        .translate_coordinates(child1, root, 1, 2)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!((translated.dst_x, translated.dst_y), (21, 42));
+
+    conn.destroy_subwindows(parent).unwrap().check().unwrap();
+    let tree = conn.query_tree(parent).unwrap().reply().unwrap();
+    assert!(tree.children.is_empty());
+    let kind = error_kind(conn.query_tree(child1).unwrap().reply());
+    assert_eq!(kind, ErrorKind::Window);
+}
+
+#[test]
+fn window_manager_redirects() {
+    let server = FakeServer::new();
+    let wm = connect(&server);
+    let app = connect(&server);
+    let root = root(&wm);
+
+    let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SUBSTRUCTURE_REDIRECT);
+    wm.change_window_attributes(root, &aux)
+        .unwrap()
+        .check()
+        .unwrap();
+    // Only one client can redirect the root window
+    let kind = error_kind(app.change_window_attributes(root, &aux).unwrap().check());
+    assert_eq!(kind, ErrorKind::Access);
+
+    let window = create_window(
+        &app,
+        root,
+        &CreateWindowAux::new().event_mask(EventMask::STRUCTURE_NOTIFY),
+    );
+    app.map_window(window).unwrap().check().unwrap();
+    app.configure_window(window, &ConfigureWindowAux::new().width(300))
+        .unwrap()
+        .check()
+        .unwrap();
+
+    // The window manager gets requests instead of the window being changed
+    match wm.poll_for_event().unwrap() {
+        Some(Event::MapRequest(event)) => assert_eq!(event.window, window),
+        other => panic!("Expected a MapRequest, got {:?}", other),
+    }
+    match wm.poll_for_event().unwrap() {
+        Some(Event::ConfigureRequest(event)) => {
+            assert_eq!(event.window, window);
+            assert_eq!(event.width, 300);
+        }
+        other => panic!("Expected a ConfigureRequest, got {:?}", other),
+    }
+    assert!(wm.poll_for_event().unwrap().is_none());
+    let attributes = app.get_window_attributes(window).unwrap().reply().unwrap();
+    assert_eq!(attributes.map_state, MapState::UNMAPPED);
+
+    // The window manager maps the window and the application is notified
+    wm.map_window(window).unwrap().check().unwrap();
+    let attributes = app.get_window_attributes(window).unwrap().reply().unwrap();
+    assert_eq!(attributes.map_state, MapState::VIEWABLE);
+    match app.poll_for_event().unwrap() {
+        Some(Event::MapNotify(event)) => assert_eq!(event.window, window),
+        other => panic!("Expected a MapNotify, got {:?}", other),
+    }
+}
+
+#[test]
+fn properties_and_atoms() {
+    let server = FakeServer::new();
+    let conn = connect(&server);
+    let window = create_window(
+        &conn,
+        root(&conn),
+        &CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+    );
+
+    let atom = conn
+        .intern_atom(false, b"_TEST")
+        .unwrap()
+        .reply()
+        .unwrap()
+        .atom;
+    assert_eq!(
+        conn.intern_atom(true, b"_TEST")
+            .unwrap()
+            .reply()
+            .unwrap()
+            .atom,
+        atom
+    );
+    assert_eq!(
+        conn.intern_atom(true, b"_MISSING")
+            .unwrap()
+            .reply()
+            .unwrap()
+            .atom,
+        0
+    );
+    let name = conn.get_atom_name(atom).unwrap().reply().unwrap().name;
+    assert_eq!(name, b"_TEST");
+    let wm_name = conn
+        .get_atom_name(AtomEnum::WM_NAME.into())
+        .unwrap()
+        .reply()
+        .unwrap()
+        .name;
+    assert_eq!(wm_name, b"WM_NAME");
+
+    conn.change_property8(PropMode::REPLACE, window, atom, AtomEnum::STRING, b"Hello")
+        .unwrap()
+        .check()
+        .unwrap();
+    conn.change_property8(PropMode::APPEND, window, atom, AtomEnum::STRING, b" World")
+        .unwrap()
+        .check()
+        .unwrap();
+    let kind = error_kind(
+        conn.change_property32(PropMode::APPEND, window, atom, AtomEnum::CARDINAL, &[1])
+            .unwrap()
+            .check(),
+    );
+    assert_eq!(kind, ErrorKind::Match);
+
+    let reply = conn
+        .get_property(false, window, atom, AtomEnum::STRING, 1, 1)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.value, b"o Wo");
+    assert_eq!(reply.bytes_after, 3);
+    let properties = conn.list_properties(window).unwrap().reply().unwrap();
+    assert_eq!(properties.atoms, [atom]);
+
+    let reply = conn
+        .get_property(true, window, atom, AtomEnum::STRING, 0, 100)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.value, b"Hello World");
+    let properties = conn.list_properties(window).unwrap().reply().unwrap();
+    assert!(properties.atoms.is_empty());
+
+    let mut notifications = Vec::new();
+    while let Some(event) = conn.poll_for_event().unwrap() {
+        match event {
+            Event::PropertyNotify(event) => notifications.push(event.state),
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+    assert_eq!(
+        notifications,
+        [Property::NEW_VALUE, Property::NEW_VALUE, Property::DELETE]
+    );
+}
+
+#[test]
+fn selections() {
+    let server = FakeServer::new();
+    let owner = connect(&server);
+    let requestor = connect(&server);
+    let owner_window = create_window(&owner, root(&owner), &CreateWindowAux::new());
+    let requestor_window = create_window(&requestor, root(&owner), &CreateWindowAux::new());
+    let selection = AtomEnum::PRIMARY.into();
+
+    owner
+        .set_selection_owner(owner_window, selection, 0u32)
+        .unwrap()
+        .check()
+        .unwrap();
+    let reply = requestor
+        .get_selection_owner(selection)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.owner, owner_window);
+
+    requestor
+        .convert_selection(
+            requestor_window,
+            selection,
+            AtomEnum::STRING.into(),
+            AtomEnum::PRIMARY,
+            0u32,
+        )
+        .unwrap()
+        .check()
+        .unwrap();
+    match owner.poll_for_event().unwrap() {
+        Some(Event::SelectionRequest(event)) => {
+            assert_eq!(event.owner, owner_window);
+            assert_eq!(event.requestor, requestor_window);
+            assert_eq!(event.target, AtomEnum::STRING.into());
+        }
+        other => panic!("Expected a SelectionRequest, got {:?}", other),
+    }
+
+    // Taking over the selection notifies the previous owner
+    requestor
+        .set_selection_owner(requestor_window, selection, 0u32)
+        .unwrap()
+        .check()
+        .unwrap();
+    match owner.poll_for_event().unwrap() {
+        Some(Event::SelectionClear(event)) => assert_eq!(event.owner, owner_window),
+        other => panic!("Expected a SelectionClear, got {:?}", other),
+    }
+
+    // The selection is lost when its owner disconnects
+    drop(requestor);
+    let reply = owner
+        .get_selection_owner(selection)
+        .unwrap()
+        .reply()
+        .unwrap();
+    assert_eq!(reply.owner, 0);
+}
+
+#[test]
+fn pixmaps_and_gcs() {
+    let server = FakeServer::new();
+    let conn = connect(&server);
+    let root = root(&conn);
+    let depth = conn.setup().roots[0].root_depth;
+
+    let pixmap = conn.generate_id().unwrap();
+    conn.create_pixmap(depth, pixmap, root, 10, 10)
+        .unwrap()
+        .check()
+        .unwrap();
+    let geometry = conn.get_geometry(pixmap).unwrap().reply().unwrap();
+    assert_eq!((geometry.depth, geometry.width), (depth, 10));
+
+    let gc = conn.generate_id().unwrap();
+    conn.create_gc(gc, pixmap, &CreateGCAux::new().foreground(1))
+        .unwrap()
+        .check()
+        .unwrap();
+    let rectangle = Rectangle {
+        x: 0,
+        y: 0,
+        width: 5,
+        height: 5,
+    };
+    conn.poly_fill_rectangle(pixmap, gc, &[rectangle])
+        .unwrap()
+        .check()
+        .unwrap();
+
+    // The GC cannot be used with a drawable of another depth
+    let bitmap = conn.generate_id().unwrap();
+    conn.create_pixmap(1, bitmap, root, 10, 10)
+        .unwrap()
+        .check()
+        .unwrap();
+    let kind = error_kind(
+        conn.poly_fill_rectangle(bitmap, gc, &[rectangle])
+            .unwrap()
+            .check(),
+    );
+    assert_eq!(kind, ErrorKind::Match);
+
+    let kind = error_kind(conn.create_pixmap(7, bitmap, root, 10, 10).unwrap().check());
+    assert_eq!(kind, ErrorKind::IDChoice);
+
+    conn.free_gc(gc).unwrap().check().unwrap();
+    let kind = error_kind(conn.free_gc(gc).unwrap().check());
+    assert_eq!(kind, ErrorKind::GContext);
+    conn.free_pixmap(pixmap).unwrap().check().unwrap();
+    let kind = error_kind(conn.get_geometry(pixmap).unwrap().reply());
+    assert_eq!(kind, ErrorKind::Drawable);
+}
+
+#[test]
+fn no_extensions() {
+    let server = FakeServer::new();
+    let conn = connect(&server);
+    let reply = conn.query_extension(b"RANDR").unwrap().reply().unwrap();
+    assert!(!reply.present);
+    assert!(conn.extension_information("RANDR").unwrap().is_none());
+}
+
+#[test]
+fn disconnect_destroys_windows() {
+    let server = FakeServer::new();
+    let conn1 = connect(&server);
+    let conn2 = connect(&server);
+    let root = root(&conn1);
+    let aux = ChangeWindowAttributesAux::new().event_mask(EventMask::SUBSTRUCTURE_NOTIFY);
+    conn1
+        .change_window_attributes(root, &aux)
+        .unwrap()
+        .check()
+        .unwrap();
+
+    let window = create_window(&conn2, root, &CreateWindowAux::new());
+    drop(conn2);
+
+    let tree = conn1.query_tree(root).unwrap().reply().unwrap();
+    assert!(tree.children.is_empty());
+    match conn1.poll_for_event().unwrap() {
+        Some(Event::CreateNotify(event)) => assert_eq!(event.window, window),
+        other => panic!("Expected a CreateNotify, got {:?}", other),
+    }
+    match conn1.poll_for_event().unwrap() {
+        Some(Event::DestroyNotify(event)) => assert_eq!(event.window, window),
+        other => panic!("Expected a DestroyNotify, got {:?}", other),
+    }
+}
+
+#[test]
+fn waiting_without_events_fails() {
+    let server = FakeServer::new();
+    let conn = connect(&server);
+    assert!(conn.wait_for_event().is_err());
+}
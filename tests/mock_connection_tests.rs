@@ -0,0 +1,137 @@
+#![cfg(feature = "testing")]
+
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::protocol::xproto::{
+    ConfigureNotifyEvent, ConfigureWindowAux, ConnectionExt, InternAtomReply,
+    CONFIGURE_NOTIFY_EVENT, GET_GEOMETRY_REQUEST, WINDOW_ERROR,
+};
+use x11rb::protocol::{ErrorKind, Event, Request};
+use x11rb::testing::{MockConnection, MockResponse};
+
+#[test]
+fn requests_are_recorded() -> Result<(), ReplyError> {
+    let conn = MockConnection::new();
+    let window = conn.generate_id().unwrap();
+    conn.configure_window(window, &ConfigureWindowAux::new().x(10).y(20))?;
+    conn.map_window(window)?;
+    assert_eq!(conn.request_count(), 2);
+
+    let requests = conn.take_requests();
+    match &requests[..] {
+        [Request::ConfigureWindow(configure), Request::MapWindow(map)] => {
+            assert_eq!(configure.window, window);
+            assert_eq!(*configure.value_list, ConfigureWindowAux::new().x(10).y(20));
+            assert_eq!(map.window, window);
+        }
+        requests => panic!("Unexpected requests {:?}", requests),
+    }
+    assert_eq!(conn.request_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn scripted_errors() -> Result<(), ReplyError> {
+    let conn = MockConnection::new();
+    conn.respond_to("GetGeometry", MockResponse::error(WINDOW_ERROR, 42));
+    conn.respond_to("MapWindow", MockResponse::error(WINDOW_ERROR, 43));
+
+    match conn.get_geometry(42)?.reply() {
+        Err(ReplyError::X11Error(error)) => {
+            assert_eq!(error.error_kind, ErrorKind::Window);
+            assert_eq!(error.bad_value, 42);
+            assert_eq!(error.major_opcode, GET_GEOMETRY_REQUEST);
+        }
+        result => panic!("Unexpected result {:?}", result),
+    }
+
+    match conn.map_window(43)?.check() {
+        Err(ReplyError::X11Error(error)) => assert_eq!(error.bad_value, 43),
+        result => panic!("Unexpected result {:?}", result),
+    }
+
+    // Errors of unchecked requests are reported as events
+    conn.map_window(44)?.ignore_error();
+    conn.map_window(45)?;
+    match conn.poll_for_event()? {
+        Some(Event::Error(error)) => assert_eq!(error.bad_value, 43),
+        event => panic!("Unexpected event {:?}", event),
+    }
+    assert!(conn.poll_for_event()?.is_none());
+    Ok(())
+}
+
+fn atom_reply(atom: u32) -> MockResponse {
+    MockResponse::reply(&InternAtomReply {
+        sequence: 0,
+        length: 0,
+        atom,
+    })
+}
+
+#[test]
+fn request_handlers() -> Result<(), ReplyError> {
+    let conn = MockConnection::new();
+    conn.respond_to("InternAtom", atom_reply(0));
+    conn.on_request(|request| match request {
+        Request::InternAtom(request) if *request.name == b"FIRST"[..] => Some(atom_reply(1000)),
+        _ => None,
+    });
+    conn.on_request(|request| match request {
+        Request::InternAtom(request) if request.name.starts_with(b"F") => Some(atom_reply(2000)),
+        _ => None,
+    });
+
+    // The handler that was added last is asked first
+    assert_eq!(conn.intern_atom(false, b"FIRST")?.reply()?.atom, 2000);
+    assert_eq!(conn.intern_atom(false, b"FOO")?.reply()?.atom, 2000);
+    assert_eq!(conn.intern_atom(false, b"BAR")?.reply()?.atom, 0);
+    Ok(())
+}
+
+#[test]
+fn events() -> Result<(), ReplyError> {
+    let conn = MockConnection::new();
+    conn.map_window(1)?;
+    conn.push_event(ConfigureNotifyEvent {
+        response_type: CONFIGURE_NOTIFY_EVENT,
+        sequence: 0,
+        event: 1,
+        window: 1,
+        above_sibling: 0,
+        x: 0,
+        y: 0,
+        width: 100,
+        height: 50,
+        border_width: 0,
+        override_redirect: false,
+    });
+
+    match conn.wait_for_event_with_sequence()? {
+        (Event::ConfigureNotify(event), 1) => {
+            assert_eq!(event.window, 1);
+            assert_eq!(event.width, 100);
+            assert_eq!(event.sequence, 1);
+        }
+        event => panic!("Unexpected event {:?}", event),
+    }
+
+    // Waiting for an event that can never arrive is an error instead of blocking
+    assert!(conn.wait_for_event().is_err());
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "No reply was scripted for request number 1")]
+fn unscripted_reply() {
+    let conn = MockConnection::new();
+    let _ = conn.get_input_focus().unwrap().reply();
+}
+
+#[test]
+fn generate_id() {
+    let conn = MockConnection::new();
+    let base = conn.setup().resource_id_base;
+    assert_eq!(conn.generate_id().unwrap(), base);
+    assert_eq!(conn.generate_id().unwrap(), base + 1);
+}
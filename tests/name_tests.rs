@@ -0,0 +1,180 @@
+use x11rb::protocol::{error_name, event_name, request_name, ErrorKind};
+use x11rb::x11_utils::{ExtInfoProvider, ExtensionInformation, X11Error};
+
+/// An `ExtInfoProvider` that knows about RandR and XInput.
+struct ExtInfo;
+
+const RANDR: ExtensionInformation = ExtensionInformation {
+    major_opcode: 140,
+    first_event: 89,
+    first_error: 147,
+};
+
+const XINPUT: ExtensionInformation = ExtensionInformation {
+    major_opcode: 131,
+    first_event: 66,
+    first_error: 129,
+};
+
+impl ExtInfoProvider for ExtInfo {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        match major_opcode {
+            140 => Some(("RANDR", RANDR)),
+            131 => Some(("XInputExtension", XINPUT)),
+            _ => None,
+        }
+    }
+
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        match event_code {
+            89..=90 => Some(("RANDR", RANDR)),
+            66..=88 => Some(("XInputExtension", XINPUT)),
+            _ => None,
+        }
+    }
+
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        match error_code {
+            147..=150 => Some(("RANDR", RANDR)),
+            129..=133 => Some(("XInputExtension", XINPUT)),
+            _ => None,
+        }
+    }
+
+    fn get_from_extension_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        match extension_name {
+            "RANDR" => Some(RANDR),
+            "XInputExtension" => Some(XINPUT),
+            _ => None,
+        }
+    }
+}
+
+fn event(response_type: u8) -> [u8; 32] {
+    let mut event = [0; 32];
+    event[0] = response_type;
+    event
+}
+
+#[test]
+fn core_names() {
+    assert_eq!(request_name(8, 0, &ExtInfo), Some("MapWindow"));
+    assert_eq!(request_name(127, 0, &ExtInfo), Some("NoOperation"));
+    assert_eq!(event_name(&event(12), &ExtInfo), Some("Expose"));
+    // The "sent event" flag is ignored
+    assert_eq!(event_name(&event(12 | 0x80), &ExtInfo), Some("Expose"));
+    assert_eq!(error_name(3, &ExtInfo), Some("Window"));
+    assert_eq!(ErrorKind::Window.name(), Some("Window"));
+}
+
+#[test]
+fn unknown_names() {
+    assert_eq!(request_name(200, 1, &ExtInfo), None);
+    assert_eq!(request_name(140, 1000, &ExtInfo), None);
+    assert_eq!(event_name(&event(0), &ExtInfo), None);
+    assert_eq!(event_name(&event(120), &ExtInfo), None);
+    assert_eq!(event_name(&[], &ExtInfo), None);
+    assert_eq!(error_name(200, &ExtInfo), None);
+    assert_eq!(ErrorKind::Unknown(200).name(), None);
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn extension_names() {
+    assert_eq!(
+        request_name(140, 21, &ExtInfo),
+        Some("RandR::SetCrtcConfig")
+    );
+    assert_eq!(
+        event_name(&event(89), &ExtInfo),
+        Some("RandR::ScreenChangeNotify")
+    );
+    assert_eq!(error_name(147, &ExtInfo), Some("RandR::BadOutput"));
+}
+
+#[cfg(feature = "xinput")]
+#[test]
+fn generic_event_name() {
+    let mut event = event(35);
+    // extension
+    event[1] = 131;
+    // event type
+    event[8..10].copy_from_slice(&1u16.to_ne_bytes());
+    assert_eq!(event_name(&event, &ExtInfo), Some("Input::DeviceChanged"));
+}
+
+#[test]
+fn display_error() {
+    let mut error = event(0);
+    error[1] = 3;
+    error[2..4].copy_from_slice(&42u16.to_ne_bytes());
+    error[4..8].copy_from_slice(&0x1234u32.to_ne_bytes());
+    error[10] = 8;
+    let error = X11Error::try_parse(&error, &ExtInfo).unwrap();
+    assert_eq!(error.request_name(&ExtInfo), Some("MapWindow"));
+    assert_eq!(
+        error.to_string(),
+        "X11 error Window in request MapWindow (sequence number 42, bad value 0x1234)"
+    );
+}
+
+#[test]
+fn display_unknown_error() {
+    let mut error = event(0);
+    error[1] = 200;
+    error[8..10].copy_from_slice(&7u16.to_ne_bytes());
+    error[10] = 210;
+    let error = X11Error::try_parse(&error, &ExtInfo).unwrap();
+    assert_eq!(error.request_name(&ExtInfo), None);
+    assert_eq!(
+        error.to_string(),
+        "X11 error with code 200 in request with major opcode 210 and minor opcode 7 \
+         (sequence number 0, bad value 0x0)"
+    );
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn display_extension_error() {
+    let mut error = event(0);
+    error[1] = 147;
+    error[4..8].copy_from_slice(&0x42u32.to_ne_bytes());
+    error[8..10].copy_from_slice(&21u16.to_ne_bytes());
+    error[10] = 140;
+    let error = X11Error::try_parse(&error, &ExtInfo).unwrap();
+    assert_eq!(
+        error.display_with(&ExtInfo).to_string(),
+        "X11 error RandR::BadOutput in request RandR::SetCrtcConfig \
+         (sequence number 0, bad value 0x42)"
+    );
+    // Without the extension information, only the opcodes are known
+    assert_eq!(
+        error.to_string(),
+        "X11 error RandR::BadOutput in request with major opcode 140 and minor opcode 21 \
+         (sequence number 0, bad value 0x42)"
+    );
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn trace_extension_error() {
+    use x11rb::trace::Trace;
+
+    let mut error = event(0);
+    error[1] = 147;
+    error[4..8].copy_from_slice(&0x42u32.to_ne_bytes());
+    error[8..10].copy_from_slice(&21u16.to_ne_bytes());
+    error[10] = 140;
+    let error = X11Error::try_parse(&error, &ExtInfo).unwrap();
+    assert_eq!(
+        error.traced_with(&ExtInfo).to_string(),
+        "Error RandrBadOutput sequence=0 bad-value=0x00000042 major-opcode=140 \
+         minor-opcode=21 request=RandR::SetCrtcConfig"
+    );
+    // Without the extension information, the request is unknown
+    assert_eq!(
+        error.traced().to_string(),
+        "Error RandrBadOutput sequence=0 bad-value=0x00000042 major-opcode=140 \
+         minor-opcode=21"
+    );
+}
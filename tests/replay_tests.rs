@@ -0,0 +1,99 @@
+#![cfg(feature = "testing")]
+
+use std::io::{Result, Write};
+use std::sync::{Arc, Mutex};
+
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::protocol::xproto::{ConnectionExt, CreateWindowAux, WindowClass};
+use x11rb::rust_connection::RustConnection;
+use x11rb::testing::{FakeServer, RecordingStream, ReplayStream};
+
+/// A writer that can be read after it was moved into a `RecordingStream`.
+#[derive(Debug, Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The code under test: Create a window with the given width and check the window tree.
+fn client_code(conn: &impl Connection, width: u16) -> std::result::Result<(), ReplyError> {
+    let root = conn.setup().roots[0].root;
+    let window = conn.generate_id().unwrap();
+    conn.create_window(
+        0,
+        window,
+        root,
+        0,
+        0,
+        width,
+        100,
+        0,
+        WindowClass::INPUT_OUTPUT,
+        0,
+        &CreateWindowAux::new(),
+    )?;
+    let atom = conn.intern_atom(false, b"_X11RB_TEST")?.reply()?.atom;
+    assert_ne!(atom, 0);
+    let tree = conn.query_tree(root)?.reply()?;
+    assert_eq!(tree.children, [window]);
+    Ok(())
+}
+
+fn record(width: u16) -> Vec<u8> {
+    let buffer = SharedBuffer::default();
+    let server = FakeServer::new();
+    let stream = RecordingStream::new(server.connect(), buffer.clone());
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    client_code(&conn, width).unwrap();
+    drop(conn);
+    let recording = buffer.0.lock().unwrap().clone();
+    recording
+}
+
+#[test]
+fn record_and_replay() {
+    let recording = record(100);
+    assert!(recording.starts_with(b"> 6c") || recording.starts_with(b"> 42"));
+
+    let stream = ReplayStream::from_reader(&recording[..]).unwrap();
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    client_code(&conn, 100).unwrap();
+    conn.stream().assert_finished();
+
+    // There is nothing left to wait for
+    assert!(conn.wait_for_event().is_err());
+}
+
+#[test]
+#[should_panic(expected = "Request number 1 does not match the recording")]
+fn replay_with_different_request() {
+    let recording = record(100);
+    let stream = ReplayStream::from_reader(&recording[..]).unwrap();
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    let _ = client_code(&conn, 200);
+}
+
+#[test]
+#[should_panic(expected = "The client did not send all requests of the recording")]
+fn replay_not_finished() {
+    let recording = record(100);
+    let stream = ReplayStream::from_reader(&recording[..]).unwrap();
+    let conn = RustConnection::connect_to_stream(stream, 0).unwrap();
+    conn.stream().assert_finished();
+}
+
+#[test]
+fn invalid_recording() {
+    assert!(ReplayStream::from_reader(&b"> 6c00\n? 00\n"[..]).is_err());
+    assert!(ReplayStream::from_reader(&b"> 6c0\n"[..]).is_err());
+    assert!(ReplayStream::from_reader(&b"# comment\n\n< fds 1\n"[..]).is_err());
+}
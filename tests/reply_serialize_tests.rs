@@ -0,0 +1,262 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use x11rb::protocol::xproto::{
+    Charinfo, FontDraw, Fontprop, GetGeometryReply, GetGeometryRequest, GetImageReply,
+    GetPropertyReply, InternAtomReply, ListFontsWithInfoReply, MapWindowRequest, QueryTreeReply,
+};
+use x11rb::protocol::{Reply, Request};
+use x11rb::x11_utils::{Serialize, TryParse};
+
+/// Serialize a reply, check that the result is well-formed, and parse it again.
+fn round_trip<T>(reply: &T) -> Vec<u8>
+where
+    T: Serialize<Bytes = Vec<u8>> + TryParse + PartialEq + Debug,
+{
+    let bytes = reply.serialize();
+    assert_eq!(bytes[0], 1);
+    assert!(bytes.len() >= 32);
+    let length = u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    assert_eq!(bytes.len(), 32 + usize::try_from(length).unwrap() * 4);
+
+    let (parsed, remaining) = T::try_parse(&bytes).unwrap();
+    assert_eq!(&parsed, reply);
+    assert!(remaining.is_empty());
+
+    // serialize_into() appends to the existing data
+    let mut appended = vec![1, 2, 3, 4];
+    reply.serialize_into(&mut appended);
+    assert_eq!(appended[..4], [1, 2, 3, 4]);
+    assert_eq!(appended[4..], bytes[..]);
+
+    bytes
+}
+
+#[test]
+fn fixed_size_reply() {
+    let reply = GetGeometryReply {
+        depth: 24,
+        sequence: 0x1234,
+        length: 0,
+        root: 0x0102_0304,
+        x: -5,
+        y: 7,
+        width: 640,
+        height: 480,
+        border_width: 2,
+    };
+    let bytes = round_trip(&reply);
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytes[1], 24);
+    assert_eq!(bytes[2..4], 0x1234u16.to_ne_bytes());
+    assert_eq!(bytes[8..12], 0x0102_0304u32.to_ne_bytes());
+    // Trailing padding
+    assert_eq!(bytes[22..], [0; 10]);
+
+    round_trip(&InternAtomReply {
+        sequence: 1,
+        length: 0,
+        atom: 42,
+    });
+}
+
+#[test]
+fn length_is_computed() {
+    let reply = QueryTreeReply {
+        sequence: 10,
+        // This value is ignored; the serialized reply contains the correct length
+        length: 1000,
+        root: 1,
+        parent: 2,
+        children: (100..110).collect(),
+    };
+    let bytes = reply.serialize();
+    assert_eq!(bytes.len(), 32 + 10 * 4);
+    assert_eq!(bytes[4..8], 10u32.to_ne_bytes());
+
+    let reply = QueryTreeReply {
+        length: 10,
+        ..reply
+    };
+    round_trip(&reply);
+}
+
+#[test]
+fn variable_size_replies() {
+    round_trip(&QueryTreeReply {
+        sequence: 10,
+        length: 0,
+        root: 1,
+        parent: 2,
+        children: Vec::new(),
+    });
+
+    // 7 bytes of data are padded to 8 bytes
+    let bytes = round_trip(&GetPropertyReply {
+        format: 8,
+        sequence: 3,
+        length: 2,
+        type_: 31,
+        bytes_after: 0,
+        value_len: 7,
+        value: b"x11rb!!".to_vec(),
+    });
+    assert_eq!(bytes.len(), 40);
+
+    round_trip(&GetPropertyReply {
+        format: 32,
+        sequence: 3,
+        length: 2,
+        type_: 6,
+        bytes_after: 4,
+        value_len: 2,
+        value: vec![1, 2, 3, 4, 5, 6, 7, 8],
+    });
+
+    round_trip(&GetImageReply {
+        depth: 24,
+        sequence: 5,
+        visual: 33,
+        data: (0..64).collect(),
+    });
+}
+
+#[test]
+fn reply_with_structs() {
+    let charinfo = Charinfo {
+        left_side_bearing: -1,
+        right_side_bearing: 5,
+        character_width: 6,
+        ascent: 8,
+        descent: 2,
+        attributes: 0,
+    };
+    let reply = ListFontsWithInfoReply {
+        sequence: 8,
+        length: 11,
+        min_bounds: charinfo,
+        max_bounds: charinfo,
+        min_char_or_byte2: 32,
+        max_char_or_byte2: 126,
+        default_char: 32,
+        draw_direction: FontDraw::LEFT_TO_RIGHT,
+        min_byte1: 0,
+        max_byte1: 0,
+        all_chars_exist: true,
+        font_ascent: 8,
+        font_descent: 2,
+        replies_hint: 0,
+        properties: vec![Fontprop { name: 1, value: 2 }],
+        name: b"fixed".to_vec(),
+    };
+    let bytes = round_trip(&reply);
+    assert_eq!(bytes.len(), 60 + 8 + 8);
+}
+
+#[cfg(feature = "randr")]
+#[test]
+fn extension_reply() {
+    use x11rb::protocol::randr::{GetScreenResourcesReply, ModeFlag, ModeInfo};
+
+    let mode = ModeInfo {
+        id: 7,
+        width: 1920,
+        height: 1080,
+        dot_clock: 148_500_000,
+        hsync_start: 2008,
+        hsync_end: 2052,
+        htotal: 2200,
+        hskew: 0,
+        vsync_start: 1084,
+        vsync_end: 1089,
+        vtotal: 1125,
+        name_len: 9,
+        mode_flags: u32::from(ModeFlag::HSYNC_POSITIVE | ModeFlag::VSYNC_POSITIVE),
+    };
+    round_trip(&GetScreenResourcesReply {
+        sequence: 2,
+        length: 14,
+        timestamp: 100,
+        config_timestamp: 99,
+        crtcs: vec![60, 61],
+        outputs: vec![70],
+        modes: vec![mode],
+        names: b"1920x1080".to_vec(),
+    });
+}
+
+#[cfg(feature = "xkb")]
+#[test]
+fn reply_with_switch() {
+    use x11rb::protocol::xkb::GetMapReply;
+
+    // A GetMap reply that contains none of the optional parts. This type does not implement
+    // `PartialEq`, so only the bytes are compared.
+    let mut bytes = vec![0; 40];
+    bytes[0] = 1;
+    bytes[1] = 3;
+    bytes[4..8].copy_from_slice(&2u32.to_ne_bytes());
+    bytes[10] = 8;
+    bytes[11] = 255;
+    let (reply, remaining) = GetMapReply::try_parse(&bytes).unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(reply.serialize(), bytes);
+}
+
+#[test]
+fn parse_reply_for_request() {
+    let reply = GetGeometryReply {
+        depth: 24,
+        sequence: 3,
+        length: 0,
+        root: 1,
+        x: 0,
+        y: 0,
+        width: 640,
+        height: 480,
+        border_width: 0,
+    };
+    let bytes = round_trip(&reply);
+
+    let request = Request::GetGeometry(GetGeometryRequest { drawable: 1 });
+    match request.parse_reply(&bytes, &mut Vec::new()) {
+        Some(Ok((Reply::GetGeometry(parsed), remaining))) => {
+            assert_eq!(parsed, reply);
+            assert!(remaining.is_empty());
+        }
+        value => panic!("Unexpected value {:?}", value),
+    }
+
+    // Requests without a reply do not parse anything
+    let request = Request::MapWindow(MapWindowRequest { window: 1 });
+    assert!(request.reply_parser().is_none());
+    assert!(request.parse_reply(&bytes, &mut Vec::new()).is_none());
+}
+
+#[cfg(all(unix, feature = "shm"))]
+#[test]
+fn parse_reply_with_fds_for_request() {
+    use std::fs::File;
+    use x11rb::protocol::shm::CreateSegmentRequest;
+    use x11rb::utils::RawFdContainer;
+
+    let mut bytes = vec![0; 32];
+    bytes[0] = 1;
+    bytes[1] = 1;
+    bytes[2..4].copy_from_slice(&4u16.to_ne_bytes());
+    let mut fds = vec![RawFdContainer::from(File::open("/dev/null").unwrap())];
+
+    let request = Request::ShmCreateSegment(CreateSegmentRequest {
+        shmseg: 1,
+        size: 4096,
+        read_only: false,
+    });
+    match request.parse_reply(&bytes, &mut fds) {
+        Some(Ok((Reply::ShmCreateSegment(parsed), remaining))) => {
+            assert_eq!((parsed.nfd, parsed.sequence), (1, 4));
+            assert!(remaining.is_empty());
+        }
+        value => panic!("Unexpected value {:?}", value),
+    }
+    assert!(fds.is_empty());
+}
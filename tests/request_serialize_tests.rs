@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use x11rb::protocol::bigreq::{self, EnableRequest};
+use x11rb::protocol::xproto::{GetInputFocusRequest, InternAtomRequest};
+use x11rb::protocol::Request;
+use x11rb::x11_utils::{
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, RequestHeader,
+};
+
+const BIGREQ: ExtensionInformation = ExtensionInformation {
+    major_opcode: 133,
+    first_event: 0,
+    first_error: 0,
+};
+
+struct ExtInfo;
+
+impl ExtInfoProvider for ExtInfo {
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        match major_opcode {
+            133 => Some((bigreq::X11_EXTENSION_NAME, BIGREQ)),
+            _ => None,
+        }
+    }
+
+    fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_extension_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        match extension_name {
+            bigreq::X11_EXTENSION_NAME => Some(BIGREQ),
+            _ => None,
+        }
+    }
+}
+
+struct NoExtensions;
+
+impl ExtInfoProvider for NoExtensions {
+    fn get_from_major_opcode(&self, _major_opcode: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_event_code(&self, _event_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_error_code(&self, _error_code: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+}
+
+/// Serialize a request via the `Request` enum and concatenate the pieces.
+fn serialize(request: Request<'_>, ext_info: &dyn ExtInfoProvider) -> Vec<u8> {
+    let (pieces, fds) = request.serialize(ext_info).unwrap();
+    assert!(fds.is_empty());
+    pieces.concat()
+}
+
+#[test]
+fn core_request() {
+    let request = Request::GetInputFocus(GetInputFocusRequest);
+    assert_eq!(serialize(request, &NoExtensions), [43, 0, 1, 0]);
+}
+
+#[test]
+fn extension_request() {
+    let request = Request::BigreqEnable(EnableRequest);
+    assert_eq!(serialize(request, &ExtInfo), [133, 0, 1, 0]);
+
+    let request = Request::BigreqEnable(EnableRequest);
+    assert!(request.serialize(&NoExtensions).is_none());
+}
+
+#[test]
+fn unknown_request() {
+    let header = RequestHeader {
+        major_opcode: 200,
+        minor_opcode: 7,
+        remaining_length: 1,
+    };
+    let request = Request::Unknown(header, Cow::Borrowed(&[1, 2, 3, 4]));
+    let mut expected = vec![200, 7];
+    expected.extend(&2u16.to_ne_bytes());
+    expected.extend(&[1, 2, 3, 4]);
+    assert_eq!(serialize(request, &NoExtensions), expected);
+}
+
+#[test]
+fn big_unknown_request() {
+    let body = vec![0; 4 * 0x1_0000];
+    let header = RequestHeader {
+        major_opcode: 200,
+        minor_opcode: 7,
+        remaining_length: 0x1_0000,
+    };
+    let bytes = serialize(Request::Unknown(header, Cow::Owned(body)), &NoExtensions);
+    assert_eq!(bytes[..4], [200, 7, 0, 0]);
+    assert_eq!(bytes[4..8], 0x1_0002u32.to_ne_bytes());
+    assert_eq!(bytes.len(), 8 + 4 * 0x1_0000);
+
+    let (parsed, body) = parse_request_header(&bytes, BigRequests::Enabled).unwrap();
+    assert_eq!(parsed.remaining_length, header.remaining_length);
+    assert_eq!(body.len(), 4 * 0x1_0000);
+}
+
+#[test]
+fn round_trip() {
+    let request = InternAtomRequest {
+        only_if_exists: true,
+        name: Cow::Borrowed(b"WM_NAME"),
+    };
+    let bytes = serialize(Request::InternAtom(request.clone()), &ExtInfo);
+    let (header, body) = parse_request_header(&bytes, BigRequests::NotEnabled).unwrap();
+    match Request::parse(header, body, &mut Vec::new(), &ExtInfo).unwrap() {
+        Request::InternAtom(parsed) => assert_eq!(parsed, request),
+        parsed => panic!("Unexpected request {:?}", parsed),
+    }
+
+    let bytes = serialize(Request::BigreqEnable(EnableRequest), &ExtInfo);
+    let (header, body) = parse_request_header(&bytes, BigRequests::NotEnabled).unwrap();
+    match Request::parse(header, body, &mut Vec::new(), &ExtInfo).unwrap() {
+        Request::BigreqEnable(_) => {}
+        parsed => panic!("Unexpected request {:?}", parsed),
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "testing")]
+
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyOrIdError;
+use x11rb::protocol::xproto::{CreateGCAux, GcontextWrapper, PixmapWrapper};
+use x11rb::protocol::Request;
+use x11rb::testing::MockConnection;
+
+#[test]
+fn wrapper_frees_resource() -> Result<(), ReplyOrIdError> {
+    let conn = MockConnection::new();
+    let root = conn.setup().roots[0].root;
+    let pixmap = PixmapWrapper::create_pixmap(&conn, 24, root, 10, 20)?;
+    let id = pixmap.id();
+    drop(pixmap);
+
+    match &conn.take_requests()[..] {
+        [Request::CreatePixmap(create), Request::FreePixmap(free)] => {
+            assert_eq!(create.pid, id);
+            assert_eq!((create.width, create.height), (10, 20));
+            assert_eq!(free.pixmap, id);
+        }
+        requests => panic!("Unexpected requests {:?}", requests),
+    }
+    Ok(())
+}
+
+#[test]
+fn into_id_keeps_resource() -> Result<(), ReplyOrIdError> {
+    let conn = MockConnection::new();
+    let root = conn.setup().roots[0].root;
+    let (gc, cookie) =
+        GcontextWrapper::create_gc_and_get_cookie(&conn, root, &CreateGCAux::new().foreground(1))?;
+    cookie.check()?;
+    let id = gc.into_id();
+
+    match &conn.take_requests()[..] {
+        [Request::CreateGC(create)] => assert_eq!(create.cid, id),
+        requests => panic!("Unexpected requests {:?}", requests),
+    }
+    Ok(())
+}
+
+#[test]
+fn wrap_existing_resource() {
+    let conn = MockConnection::new();
+    drop(PixmapWrapper::for_pixmap(&conn, 42));
+
+    match &conn.take_requests()[..] {
+        [Request::FreePixmap(free)] => assert_eq!(free.pixmap, 42),
+        requests => panic!("Unexpected requests {:?}", requests),
+    }
+}
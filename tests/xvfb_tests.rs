@@ -0,0 +1,70 @@
+#![cfg(all(feature = "testing", unix))]
+
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::ConnectionExt;
+use x11rb::testing::{XvfbBuilder, XvfbError};
+
+#[test]
+fn missing_program_is_skipped() {
+    let result = XvfbBuilder::new()
+        .program("/nonexistent/x11rb-test-server")
+        .spawn()
+        .unwrap();
+    assert!(result.is_none());
+}
+
+#[test]
+fn server_exits_early() {
+    match XvfbBuilder::new().program("false").spawn() {
+        Err(XvfbError::Exited(status)) => assert!(!status.success()),
+        other => panic!("Expected the server to exit, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn invalid_display_number() {
+    // A "server" that reports nonsense via -displayfd and then waits to be killed
+    let script = std::env::temp_dir().join(format!("x11rb-fake-xvfb-{}", std::process::id()));
+    std::fs::write(&script, "#!/bin/sh\necho garbage\nexec sleep 60\n").unwrap();
+    let mut permissions = std::fs::metadata(&script).unwrap().permissions();
+    std::os::unix::fs::PermissionsExt::set_mode(&mut permissions, 0o755);
+    std::fs::set_permissions(&script, permissions).unwrap();
+
+    // Another thread might have forked while the script was open for writing, which makes
+    // executing it fail with ETXTBSY until that child called exec.
+    let mut result = XvfbBuilder::new().program(&script).spawn();
+    while let Err(XvfbError::IOError(ref e)) = result {
+        if e.raw_os_error() != Some(26) {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        result = XvfbBuilder::new().program(&script).spawn();
+    }
+    std::fs::remove_file(&script).unwrap();
+    match result {
+        Err(XvfbError::InvalidDisplay(display)) => assert_eq!(display, "garbage"),
+        other => panic!("Expected an invalid display, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn xvfb() {
+    let (conn, xvfb) = match XvfbBuilder::new().spawn().unwrap() {
+        Some(result) => result,
+        None => {
+            eprintln!("Xvfb is not installed, skipping test");
+            return;
+        }
+    };
+    assert!(xvfb.xauthority().exists());
+    let root = conn.setup().roots[0].root;
+    let _ = conn.query_tree(root).unwrap().reply().unwrap();
+
+    // Another client can connect to the same server
+    let conn2 = xvfb.connect().unwrap();
+    let _ = conn2.get_input_focus().unwrap().reply().unwrap();
+
+    let xauthority = xvfb.xauthority().to_path_buf();
+    drop(xvfb);
+    assert!(!xauthority.exists());
+}
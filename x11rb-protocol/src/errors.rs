@@ -0,0 +1,65 @@
+//! This module contains the errors that can occur while parsing X11 data.
+
+/// An error occurred while parsing some data
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Not enough data was provided.
+    InsufficientData,
+
+    /// A value did not fit.
+    ///
+    /// This error can e.g. happen when a value that was received from the X11 server does not fit
+    /// into an `usize`.
+    ConversionFailed,
+
+    /// The value of an expression could not be computed.
+    ///
+    /// As an example, the length of the data in `xproto`'s `GetPropertyReply` is described by
+    /// `value_len * (format / 8)`. The multiplication could cause an overflow, which would be
+    /// represented by this error.
+    InvalidExpression,
+
+    /// A value was outside of its valid range.
+    ///
+    /// When parsing the value of an enumeration, not all possible integer values have a defined
+    /// meaning. This error occurs when an invalid value is encountered in this context.
+    ///
+    /// For example, `xproto` has an enumeration `Place` with possible values `OnTop` (0) and
+    /// `OnBottom` (1). Any value other than 0 or 1 generates an `InvalidValue` when parsing.
+    InvalidValue,
+
+    /// Some file descriptors were expected, but none were received.
+    MissingFileDescriptors,
+
+    /// The data is in a byte order that the type cannot be parsed from.
+    ///
+    /// Types that implement [`TryParse`](crate::x11_utils::TryParse) only for the native byte
+    /// order produce this error when they are asked to parse data in the other byte order.
+    UnsupportedByteOrder,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::InsufficientData => write!(f, "Insufficient data was provided"),
+            ParseError::ConversionFailed => {
+                write!(f, "A value conversion failed due to out of range data")
+            }
+            ParseError::InvalidExpression => write!(
+                f,
+                "An expression could not be computed, e.g. due to overflow"
+            ),
+            ParseError::InvalidValue => {
+                write!(f, "A value could not be parsed into an enumeration")
+            }
+            ParseError::MissingFileDescriptors => write!(f, "Missing file descriptors"),
+            ParseError::UnsupportedByteOrder => {
+                write!(f, "The data is in an unsupported byte order")
+            }
+        }
+    }
+}
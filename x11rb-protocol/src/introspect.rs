@@ -0,0 +1,179 @@
+//! Runtime descriptions of the requests, replies and events of the X11 protocol.
+//!
+//! The generator emits a static table describing the requests and events of each module in
+//! [`protocol`](crate::protocol). The tables are available as `REQUEST_INFOS` and `EVENT_INFOS`
+//! in each module, e.g. [`xproto::REQUEST_INFOS`](crate::protocol::xproto::REQUEST_INFOS), and
+//! through [`Request::info`](crate::protocol::Request::info) and
+//! [`Event::info`](crate::protocol::Event::info).
+//!
+//! Fields are listed in the order in which they appear on the wire. Padding is left out, but
+//! everything else, including opcodes and lengths, is included. Names of fields and types are the
+//! ones used by xcb-proto.
+//!
+//! ```
+//! use x11rb_protocol::introspect::{FieldKind, ValueSet};
+//! use x11rb_protocol::protocol::xproto::GetPropertyRequest;
+//! use x11rb_protocol::protocol::Request;
+//!
+//! let request = Request::GetProperty(GetPropertyRequest {
+//!     delete: false,
+//!     window: 1u32.into(),
+//!     property: 2u32.into(),
+//!     type_: 0u32.into(),
+//!     long_offset: 0,
+//!     long_length: 0,
+//! });
+//! let info = request.info().unwrap();
+//! assert_eq!((info.extension, info.name, info.opcode), (None, "GetProperty", 20));
+//!
+//! let type_field = info.fields.iter().find(|field| field.name == "type").unwrap();
+//! match type_field.kind {
+//!     FieldKind::Value(type_info) => {
+//!         assert_eq!(type_info.name, "ATOM");
+//!         match type_info.value_set {
+//!             ValueSet::AltEnum(enum_info) => assert_eq!(enum_info.name, "GetPropertyType"),
+//!             _ => unreachable!(),
+//!         }
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+/// A description of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestInfo {
+    /// The name of the extension that the request belongs to, e.g. `RandR`.
+    ///
+    /// This is `None` for requests of the core protocol.
+    pub extension: Option<&'static str>,
+
+    /// The name of the request, e.g. `GetProperty`.
+    pub name: &'static str,
+
+    /// The opcode of the request.
+    ///
+    /// For extension requests, this is the minor opcode. The major opcode of an extension is
+    /// assigned by the X11 server.
+    pub opcode: u8,
+
+    /// The fields of the request.
+    pub fields: &'static [FieldInfo],
+
+    /// The fields of the reply to this request, or `None` if the request has no reply.
+    pub reply_fields: Option<&'static [FieldInfo]>,
+}
+
+/// A description of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EventInfo {
+    /// The name of the extension that the event belongs to, e.g. `RandR`.
+    ///
+    /// This is `None` for events of the core protocol.
+    pub extension: Option<&'static str>,
+
+    /// The name of the event, e.g. `Expose`.
+    pub name: &'static str,
+
+    /// The number of the event.
+    ///
+    /// For extension events, this is relative to the first event of the extension, or the event
+    /// type for events that are sent via the generic event extension.
+    pub number: u16,
+
+    /// Is this event sent via the generic event extension?
+    pub xge: bool,
+
+    /// The fields of the event.
+    pub fields: &'static [FieldInfo],
+}
+
+/// A description of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FieldInfo {
+    /// The name of the field, e.g. `long_offset`.
+    pub name: &'static str,
+
+    /// What kind of field this is.
+    pub kind: FieldKind,
+}
+
+/// The different kinds of fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldKind {
+    /// A single value of the given type.
+    Value(TypeInfo),
+
+    /// A list of values of the given type.
+    List(TypeInfo),
+
+    /// A file descriptor.
+    Fd,
+
+    /// A list of file descriptors.
+    FdList,
+
+    /// A `<switch>` whose cases are only present depending on the value of another field.
+    Switch(&'static [CaseInfo]),
+}
+
+/// A description of the type of a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TypeInfo {
+    /// The name of the type, e.g. `CARD32`, `WINDOW` or `xproto:POINT`.
+    pub name: &'static str,
+
+    /// The enumeration that describes the possible values.
+    pub value_set: ValueSet,
+}
+
+/// The values that a field can take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueSet {
+    /// The field can take any value of its type.
+    None,
+
+    /// The field can only take values of the given enumeration.
+    Enum(EnumInfo),
+
+    /// The field can take any value of its type, but the values of the given enumeration have a
+    /// special meaning.
+    AltEnum(EnumInfo),
+
+    /// The field is a bitmask of the values of the given enumeration.
+    Mask(EnumInfo),
+
+    /// The field can take any value of its type, but the given enumeration gives a special
+    /// meaning to some bits.
+    AltMask(EnumInfo),
+}
+
+/// A description of an enumeration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EnumInfo {
+    /// The name of the enumeration, e.g. `EventMask`.
+    pub name: &'static str,
+
+    /// The values of the enumeration.
+    ///
+    /// Each entry contains the value, the name of the constant in the Rust type, e.g.
+    /// `KEY_PRESS`, and the name of the value, e.g. `KeyPress`.
+    pub variants: &'static [(u32, &'static str, &'static str)],
+}
+
+/// A description of a case of a `<switch>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CaseInfo {
+    /// The name of the case, if it has one.
+    pub name: Option<&'static str>,
+
+    /// The fields that are present in this case.
+    pub fields: &'static [FieldInfo],
+}
@@ -0,0 +1,89 @@
+//! X11 rust bindings without connection handling.
+//!
+//! This crate contains the definitions of the X11 protocol that x11rb is built on: The generated
+//! structs, enums, events, errors, requests and replies in [`protocol`] together with the code
+//! that parses them from and serializes them into raw bytes. It does not contain any code for
+//! talking to an X11 server. Use the `x11rb` crate for that, which re-exports everything from
+//! this crate.
+//!
+//! This crate is `#![no_std]` and only needs the `alloc` crate, so that it can be used to decode
+//! X11 traffic in environments without an operating system.
+//!
+//! The [`trace`] module turns decoded requests, replies and events into legible one-line
+//! descriptions in the style of the `xtrace` tool.
+//!
+//! The [`introspect`] module describes the fields of all requests, replies and events at runtime,
+//! for tools that need to know the structure of the protocol without parsing xcb-proto.
+//!
+//! ## Feature flags
+//!
+//! The X11 extensions are behind the same feature flags as in x11rb: `composite`, `damage`,
+//! `dpms`, `dri2`, `dri3`, `glx`, `present`, `randr`, `record`, `render`, `res`, `screensaver`,
+//! `shape`, `shm`, `sync`, `xevie`, `xf86dri`, `xf86vidmode`, `xfixes`, `xinerama`, `xinput`,
+//! `xkb`, `xprint`, `xselinux`, `xtest`, `xv`, `xvmc`. The `all-extensions` feature enables all of
+//! them.
+//!
+//! Additionally, the following flags exist:
+//! * `std` (enabled by default): Implement `std::error::Error` for [`errors::ParseError`] and
+//!   support passing file descriptors via [`utils::RawFdContainer`] on unix. Without this flag,
+//!   `RawFdContainer` cannot be constructed and requests or replies with file descriptors can only
+//!   be handled if they do not contain any.
+//! * `serde`: Implement `serde::Serialize` and `serde::Deserialize` for the structs, events,
+//!   replies, enums and bitmasks in [`protocol`].
+
+#![no_std]
+#![forbid(
+    missing_copy_implementations,
+    missing_debug_implementations,
+    private_doc_tests,
+    rust_2018_idioms,
+    //single_use_lifetimes,
+    trivial_casts,
+    trivial_numeric_casts,
+    unreachable_pub,
+    unsafe_code,
+    unused_import_braces,
+    unused_results,
+    clippy::cast_lossless,
+    clippy::needless_pass_by_value,
+)]
+// A list of lints that are only #![deny] and not the stronger #![forbid]. Each one has a comment
+// explaining why it gets the weaker treatment.
+#![deny(
+    // #[derive] generates an #[allow] for this
+    unused_qualifications,
+    // Not everything in x11rb_protocol::protocol has doc comments
+    missing_docs,
+)]
+#![allow(
+    // This suggests a method that was stabilised in Rust 1.45, while our MSRV is 1.40.
+    clippy::manual_strip,
+    // This suggests a macro that was stabilised in Rust 1.42, while our MSRV is 1.40.
+    clippy::match_like_matches_macro,
+)]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+pub mod errors;
+pub mod introspect;
+pub mod trace;
+pub mod utils;
+#[macro_use]
+pub mod x11_utils;
+pub mod wrapper;
+#[rustfmt::skip]
+#[allow(missing_docs)]
+pub mod protocol;
+
+use utils::RawFdContainer;
+
+/// A combination of a buffer and a list of file descriptors.
+pub type BufWithFds<B> = (B, Vec<RawFdContainer>);
+
+/// A buffer that is logically continuous, but presented in a number of pieces.
+pub type PiecewiseBuf<'a> = Vec<Cow<'a, [u8]>>;
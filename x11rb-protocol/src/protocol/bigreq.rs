@@ -0,0 +1,197 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `BigRequests` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "BIG-REQUESTS";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (0, 0);
+
+/// Opcode for the Enable request
+pub const ENABLE_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnableRequest;
+impl EnableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            ENABLE_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ENABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(EnableRequest
+        )
+    }
+}
+impl Request for EnableRequest {
+    type Reply = EnableReply;
+}
+impl Trace for EnableRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnableReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub maximum_request_length: u32,
+}
+impl TryParse for EnableReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maximum_request_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = EnableReply { sequence, length, maximum_request_length };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for EnableReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for EnableReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("maximum-request-length", |tracer| tracer.value(&self.maximum_request_length))?;
+        Ok(())
+    }
+}
+impl Serialize for EnableReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.maximum_request_length.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("BigRequests"),
+        name: "Enable",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maximum_request_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
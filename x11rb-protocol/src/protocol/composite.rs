@@ -0,0 +1,1130 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Composite` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xfixes;
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "Composite";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (0, 4);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Redirect(u8);
+impl Redirect {
+    pub const AUTOMATIC: Self = Self(0);
+    pub const MANUAL: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "AUTOMATIC", "Automatic"),
+        (1, "MANUAL", "Manual"),
+    ];
+}
+impl From<Redirect> for u8 {
+    #[inline]
+    fn from(input: Redirect) -> Self {
+        input.0
+    }
+}
+impl From<Redirect> for Option<u8> {
+    #[inline]
+    fn from(input: Redirect) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Redirect> for u16 {
+    #[inline]
+    fn from(input: Redirect) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Redirect> for Option<u16> {
+    #[inline]
+    fn from(input: Redirect) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Redirect> for u32 {
+    #[inline]
+    fn from(input: Redirect) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Redirect> for Option<u32> {
+    #[inline]
+    fn from(input: Redirect) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Redirect {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Redirect  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Redirect {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Redirect {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Redirect {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize_with_order(order);
+        let client_minor_version_bytes = self.client_minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client_major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client-major-version", |tracer| tracer.value(&self.client_major_version))?;
+        tracer.field("client-minor-version", |tracer| tracer.value(&self.client_minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the RedirectWindow request
+pub const REDIRECT_WINDOW_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedirectWindowRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl RedirectWindowRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let update_bytes = u8::from(self.update).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            REDIRECT_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REDIRECT_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let update = update.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(RedirectWindowRequest {
+            window,
+            update,
+        })
+    }
+}
+impl Request for RedirectWindowRequest {
+    type Reply = ();
+}
+impl Trace for RedirectWindowRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("update", |tracer| tracer.value(&self.update))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the RedirectSubwindows request
+pub const REDIRECT_SUBWINDOWS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RedirectSubwindowsRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl RedirectSubwindowsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let update_bytes = u8::from(self.update).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            REDIRECT_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REDIRECT_SUBWINDOWS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let update = update.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(RedirectSubwindowsRequest {
+            window,
+            update,
+        })
+    }
+}
+impl Request for RedirectSubwindowsRequest {
+    type Reply = ();
+}
+impl Trace for RedirectSubwindowsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("update", |tracer| tracer.value(&self.update))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the UnredirectWindow request
+pub const UNREDIRECT_WINDOW_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnredirectWindowRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl UnredirectWindowRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let update_bytes = u8::from(self.update).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            UNREDIRECT_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNREDIRECT_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let update = update.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(UnredirectWindowRequest {
+            window,
+            update,
+        })
+    }
+}
+impl Request for UnredirectWindowRequest {
+    type Reply = ();
+}
+impl Trace for UnredirectWindowRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("update", |tracer| tracer.value(&self.update))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the UnredirectSubwindows request
+pub const UNREDIRECT_SUBWINDOWS_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnredirectSubwindowsRequest {
+    pub window: xproto::Window,
+    pub update: Redirect,
+}
+impl UnredirectSubwindowsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let update_bytes = u8::from(self.update).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            UNREDIRECT_SUBWINDOWS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            update_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNREDIRECT_SUBWINDOWS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (update, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let update = update.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(UnredirectSubwindowsRequest {
+            window,
+            update,
+        })
+    }
+}
+impl Request for UnredirectSubwindowsRequest {
+    type Reply = ();
+}
+impl Trace for UnredirectSubwindowsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("update", |tracer| tracer.value(&self.update))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateRegionFromBorderClip request
+pub const CREATE_REGION_FROM_BORDER_CLIP_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateRegionFromBorderClipRequest {
+    pub region: xfixes::Region,
+    pub window: xproto::Window,
+}
+impl CreateRegionFromBorderClipRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REGION_FROM_BORDER_CLIP_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_BORDER_CLIP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = xfixes::Region::try_parse_with_order(value, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateRegionFromBorderClipRequest {
+            region,
+            window,
+        })
+    }
+}
+impl Request for CreateRegionFromBorderClipRequest {
+    type Reply = ();
+}
+impl Trace for CreateRegionFromBorderClipRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the NameWindowPixmap request
+pub const NAME_WINDOW_PIXMAP_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NameWindowPixmapRequest {
+    pub window: xproto::Window,
+    pub pixmap: xproto::Pixmap,
+}
+impl NameWindowPixmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            NAME_WINDOW_PIXMAP_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != NAME_WINDOW_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(NameWindowPixmapRequest {
+            window,
+            pixmap,
+        })
+    }
+}
+impl Request for NameWindowPixmapRequest {
+    type Reply = ();
+}
+impl Trace for NameWindowPixmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetOverlayWindow request
+pub const GET_OVERLAY_WINDOW_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetOverlayWindowRequest {
+    pub window: xproto::Window,
+}
+impl GetOverlayWindowRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_OVERLAY_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OVERLAY_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetOverlayWindowRequest {
+            window,
+        })
+    }
+}
+impl Request for GetOverlayWindowRequest {
+    type Reply = GetOverlayWindowReply;
+}
+impl Trace for GetOverlayWindowRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetOverlayWindowReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub overlay_win: xproto::Window,
+}
+impl TryParse for GetOverlayWindowReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (overlay_win, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetOverlayWindowReply { sequence, length, overlay_win };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetOverlayWindowReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetOverlayWindowReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("overlay-win", |tracer| tracer.xid(&self.overlay_win))?;
+        Ok(())
+    }
+}
+impl Serialize for GetOverlayWindowReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.overlay_win.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ReleaseOverlayWindow request
+pub const RELEASE_OVERLAY_WINDOW_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReleaseOverlayWindowRequest {
+    pub window: xproto::Window,
+}
+impl ReleaseOverlayWindowRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            RELEASE_OVERLAY_WINDOW_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RELEASE_OVERLAY_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(ReleaseOverlayWindowRequest {
+            window,
+        })
+    }
+}
+impl Request for ReleaseOverlayWindowRequest {
+    type Reply = ();
+}
+impl Trace for ReleaseOverlayWindowRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "RedirectWindow",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Redirect", variants: Redirect::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "RedirectSubwindows",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Redirect", variants: Redirect::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "UnredirectWindow",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Redirect", variants: Redirect::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "UnredirectSubwindows",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Redirect", variants: Redirect::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "CreateRegionFromBorderClip",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "NameWindowPixmap",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "GetOverlayWindow",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "overlay_win",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Composite"),
+        name: "ReleaseOverlayWindow",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
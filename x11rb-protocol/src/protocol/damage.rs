@@ -0,0 +1,864 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Damage` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xfixes;
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "DAMAGE";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 1);
+
+pub type Damage = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ReportLevel(u8);
+impl ReportLevel {
+    pub const RAW_RECTANGLES: Self = Self(0);
+    pub const DELTA_RECTANGLES: Self = Self(1);
+    pub const BOUNDING_BOX: Self = Self(2);
+    pub const NON_EMPTY: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "RAW_RECTANGLES", "RawRectangles"),
+        (1, "DELTA_RECTANGLES", "DeltaRectangles"),
+        (2, "BOUNDING_BOX", "BoundingBox"),
+        (3, "NON_EMPTY", "NonEmpty"),
+    ];
+}
+impl From<ReportLevel> for u8 {
+    #[inline]
+    fn from(input: ReportLevel) -> Self {
+        input.0
+    }
+}
+impl From<ReportLevel> for Option<u8> {
+    #[inline]
+    fn from(input: ReportLevel) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ReportLevel> for u16 {
+    #[inline]
+    fn from(input: ReportLevel) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ReportLevel> for Option<u16> {
+    #[inline]
+    fn from(input: ReportLevel) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ReportLevel> for u32 {
+    #[inline]
+    fn from(input: ReportLevel) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ReportLevel> for Option<u32> {
+    #[inline]
+    fn from(input: ReportLevel) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ReportLevel {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ReportLevel  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ReportLevel {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ReportLevel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ReportLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the BadDamage error
+pub const BAD_DAMAGE_ERROR: u8 = 0;
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize_with_order(order);
+        let client_minor_version_bytes = self.client_minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client_major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client-major-version", |tracer| tracer.value(&self.client_major_version))?;
+        tracer.field("client-minor-version", |tracer| tracer.value(&self.client_minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Create request
+pub const CREATE_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateRequest {
+    pub damage: Damage,
+    pub drawable: xproto::Drawable,
+    pub level: ReportLevel,
+}
+impl CreateRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let damage_bytes = self.damage.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let level_bytes = u8::from(self.level).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            level_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (damage, remaining) = Damage::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let level = level.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(CreateRequest {
+            damage,
+            drawable,
+            level,
+        })
+    }
+}
+impl Request for CreateRequest {
+    type Reply = ();
+}
+impl Trace for CreateRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("damage", |tracer| tracer.xid(&self.damage))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("level", |tracer| tracer.value(&self.level))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Destroy request
+pub const DESTROY_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyRequest {
+    pub damage: Damage,
+}
+impl DestroyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let damage_bytes = self.damage.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (damage, remaining) = Damage::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyRequest {
+            damage,
+        })
+    }
+}
+impl Request for DestroyRequest {
+    type Reply = ();
+}
+impl Trace for DestroyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("damage", |tracer| tracer.xid(&self.damage))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Subtract request
+pub const SUBTRACT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubtractRequest {
+    pub damage: Damage,
+    pub repair: xfixes::Region,
+    pub parts: xfixes::Region,
+}
+impl SubtractRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let damage_bytes = self.damage.serialize_with_order(order);
+        let repair_bytes = self.repair.serialize_with_order(order);
+        let parts_bytes = self.parts.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SUBTRACT_REQUEST,
+            0,
+            0,
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            repair_bytes[0],
+            repair_bytes[1],
+            repair_bytes[2],
+            repair_bytes[3],
+            parts_bytes[0],
+            parts_bytes[1],
+            parts_bytes[2],
+            parts_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SUBTRACT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (damage, remaining) = Damage::try_parse_with_order(value, order)?;
+        let (repair, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (parts, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SubtractRequest {
+            damage,
+            repair,
+            parts,
+        })
+    }
+}
+impl Request for SubtractRequest {
+    type Reply = ();
+}
+impl Trace for SubtractRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("damage", |tracer| tracer.xid(&self.damage))?;
+        tracer.field("repair", |tracer| tracer.xid(&self.repair))?;
+        tracer.field("parts", |tracer| tracer.xid(&self.parts))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Add request
+pub const ADD_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddRequest {
+    pub drawable: xproto::Drawable,
+    pub region: xfixes::Region,
+}
+impl AddRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let region_bytes = self.region.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            ADD_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(AddRequest {
+            drawable,
+            region,
+        })
+    }
+}
+impl Request for AddRequest {
+    type Reply = ();
+}
+impl Trace for AddRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Notify event
+pub const NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotifyEvent {
+    pub response_type: u8,
+    pub level: ReportLevel,
+    pub sequence: u16,
+    pub drawable: xproto::Drawable,
+    pub damage: Damage,
+    pub timestamp: xproto::Timestamp,
+    pub area: xproto::Rectangle,
+    pub geometry: xproto::Rectangle,
+}
+impl TryParse for NotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (damage, remaining) = Damage::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (area, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (geometry, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let level = level.into();
+        let result = NotifyEvent { response_type, level, sequence, drawable, damage, timestamp, area, geometry };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for NotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("level", |tracer| tracer.value(&self.level))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("damage", |tracer| tracer.xid(&self.damage))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("area", |tracer| tracer.value(&self.area))?;
+        tracer.field("geometry", |tracer| tracer.value(&self.geometry))?;
+        Ok(())
+    }
+}
+impl NotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let level_bytes = u8::from(self.level).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let damage_bytes = self.damage.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let area_bytes = self.area.serialize_with_order(order);
+        let geometry_bytes = self.geometry.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            level_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            damage_bytes[0],
+            damage_bytes[1],
+            damage_bytes[2],
+            damage_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            area_bytes[0],
+            area_bytes[1],
+            area_bytes[2],
+            area_bytes[3],
+            area_bytes[4],
+            area_bytes[5],
+            area_bytes[6],
+            area_bytes[7],
+            geometry_bytes[0],
+            geometry_bytes[1],
+            geometry_bytes[2],
+            geometry_bytes[3],
+            geometry_bytes[4],
+            geometry_bytes[5],
+            geometry_bytes[6],
+            geometry_bytes[7],
+        ]
+    }
+}
+impl From<&NotifyEvent> for [u8; 32] {
+    fn from(input: &NotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Damage"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Damage"),
+        name: "Create",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "damage",
+                kind: FieldKind::Value(TypeInfo { name: "DAMAGE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "level",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "ReportLevel", variants: ReportLevel::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Damage"),
+        name: "Destroy",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "damage",
+                kind: FieldKind::Value(TypeInfo { name: "DAMAGE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Damage"),
+        name: "Subtract",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "damage",
+                kind: FieldKind::Value(TypeInfo { name: "DAMAGE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "repair",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::AltEnum(EnumInfo { name: "Region", variants: xfixes::RegionEnum::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "parts",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::AltEnum(EnumInfo { name: "Region", variants: xfixes::RegionEnum::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Damage"),
+        name: "Add",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("Damage"),
+        name: "Notify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "level",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "ReportLevel", variants: ReportLevel::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "damage",
+                kind: FieldKind::Value(TypeInfo { name: "DAMAGE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "area",
+                kind: FieldKind::Value(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "geometry",
+                kind: FieldKind::Value(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,1106 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `DPMS` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "DPMS";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (0, 0);
+
+/// Opcode for the GetVersion request
+pub const GET_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetVersionRequest {
+    pub client_major_version: u16,
+    pub client_minor_version: u16,
+}
+impl GetVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize_with_order(order);
+        let client_minor_version_bytes = self.client_minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client_major_version, remaining) = u16::try_parse_with_order(value, order)?;
+        let (client_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+}
+impl Request for GetVersionRequest {
+    type Reply = GetVersionReply;
+}
+impl Trace for GetVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client-major-version", |tracer| tracer.value(&self.client_major_version))?;
+        tracer.field("client-minor-version", |tracer| tracer.value(&self.client_minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+}
+impl TryParse for GetVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetVersionReply { sequence, length, server_major_version, server_minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("server-major-version", |tracer| tracer.value(&self.server_major_version))?;
+        tracer.field("server-minor-version", |tracer| tracer.value(&self.server_minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for GetVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major_version.serialize_into_with_order(bytes, order);
+        self.server_minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Capable request
+pub const CAPABLE_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapableRequest;
+impl CapableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            CAPABLE_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CAPABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(CapableRequest
+        )
+    }
+}
+impl Request for CapableRequest {
+    type Reply = CapableReply;
+}
+impl Trace for CapableRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CapableReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub capable: bool,
+}
+impl TryParse for CapableReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (capable, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CapableReply { sequence, length, capable };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CapableReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CapableReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("capable", |tracer| tracer.value(&self.capable))?;
+        Ok(())
+    }
+}
+impl Serialize for CapableReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.capable.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTimeouts request
+pub const GET_TIMEOUTS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTimeoutsRequest;
+impl GetTimeoutsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            GET_TIMEOUTS_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TIMEOUTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(GetTimeoutsRequest
+        )
+    }
+}
+impl Request for GetTimeoutsRequest {
+    type Reply = GetTimeoutsReply;
+}
+impl Trace for GetTimeoutsRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTimeoutsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub standby_timeout: u16,
+    pub suspend_timeout: u16,
+    pub off_timeout: u16,
+}
+impl TryParse for GetTimeoutsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (standby_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (suspend_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (off_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTimeoutsReply { sequence, length, standby_timeout, suspend_timeout, off_timeout };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTimeoutsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetTimeoutsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("standby-timeout", |tracer| tracer.value(&self.standby_timeout))?;
+        tracer.field("suspend-timeout", |tracer| tracer.value(&self.suspend_timeout))?;
+        tracer.field("off-timeout", |tracer| tracer.value(&self.off_timeout))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTimeoutsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.standby_timeout.serialize_into_with_order(bytes, order);
+        self.suspend_timeout.serialize_into_with_order(bytes, order);
+        self.off_timeout.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetTimeouts request
+pub const SET_TIMEOUTS_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetTimeoutsRequest {
+    pub standby_timeout: u16,
+    pub suspend_timeout: u16,
+    pub off_timeout: u16,
+}
+impl SetTimeoutsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let standby_timeout_bytes = self.standby_timeout.serialize_with_order(order);
+        let suspend_timeout_bytes = self.suspend_timeout.serialize_with_order(order);
+        let off_timeout_bytes = self.off_timeout.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_TIMEOUTS_REQUEST,
+            0,
+            0,
+            standby_timeout_bytes[0],
+            standby_timeout_bytes[1],
+            suspend_timeout_bytes[0],
+            suspend_timeout_bytes[1],
+            off_timeout_bytes[0],
+            off_timeout_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_TIMEOUTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (standby_timeout, remaining) = u16::try_parse_with_order(value, order)?;
+        let (suspend_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (off_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetTimeoutsRequest {
+            standby_timeout,
+            suspend_timeout,
+            off_timeout,
+        })
+    }
+}
+impl Request for SetTimeoutsRequest {
+    type Reply = ();
+}
+impl Trace for SetTimeoutsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("standby-timeout", |tracer| tracer.value(&self.standby_timeout))?;
+        tracer.field("suspend-timeout", |tracer| tracer.value(&self.suspend_timeout))?;
+        tracer.field("off-timeout", |tracer| tracer.value(&self.off_timeout))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Enable request
+pub const ENABLE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnableRequest;
+impl EnableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            ENABLE_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ENABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(EnableRequest
+        )
+    }
+}
+impl Request for EnableRequest {
+    type Reply = ();
+}
+impl Trace for EnableRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+/// Opcode for the Disable request
+pub const DISABLE_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisableRequest;
+impl DisableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            DISABLE_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DISABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(DisableRequest
+        )
+    }
+}
+impl Request for DisableRequest {
+    type Reply = ();
+}
+impl Trace for DisableRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DPMSMode(u16);
+impl DPMSMode {
+    pub const ON: Self = Self(0);
+    pub const STANDBY: Self = Self(1);
+    pub const SUSPEND: Self = Self(2);
+    pub const OFF: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "ON", "On"),
+        (1, "STANDBY", "Standby"),
+        (2, "SUSPEND", "Suspend"),
+        (3, "OFF", "Off"),
+    ];
+}
+impl From<DPMSMode> for u16 {
+    #[inline]
+    fn from(input: DPMSMode) -> Self {
+        input.0
+    }
+}
+impl From<DPMSMode> for Option<u16> {
+    #[inline]
+    fn from(input: DPMSMode) -> Self {
+        Some(input.0)
+    }
+}
+impl From<DPMSMode> for u32 {
+    #[inline]
+    fn from(input: DPMSMode) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<DPMSMode> for Option<u32> {
+    #[inline]
+    fn from(input: DPMSMode) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for DPMSMode {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for DPMSMode {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for DPMSMode  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for DPMSMode {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DPMSMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DPMSMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the ForceLevel request
+pub const FORCE_LEVEL_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForceLevelRequest {
+    pub power_level: DPMSMode,
+}
+impl ForceLevelRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let power_level_bytes = u16::from(self.power_level).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FORCE_LEVEL_REQUEST,
+            0,
+            0,
+            power_level_bytes[0],
+            power_level_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FORCE_LEVEL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (power_level, remaining) = u16::try_parse_with_order(value, order)?;
+        let power_level = power_level.into();
+        let _ = remaining;
+        Ok(ForceLevelRequest {
+            power_level,
+        })
+    }
+}
+impl Request for ForceLevelRequest {
+    type Reply = ();
+}
+impl Trace for ForceLevelRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("power-level", |tracer| tracer.value(&self.power_level))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Info request
+pub const INFO_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfoRequest;
+impl InfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            INFO_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(InfoRequest
+        )
+    }
+}
+impl Request for InfoRequest {
+    type Reply = InfoReply;
+}
+impl Trace for InfoRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InfoReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub power_level: DPMSMode,
+    pub state: bool,
+}
+impl TryParse for InfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (power_level, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let power_level = power_level.into();
+        let result = InfoReply { sequence, length, power_level, state };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for InfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for InfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("power-level", |tracer| tracer.value(&self.power_level))?;
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        Ok(())
+    }
+}
+impl Serialize for InfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        u16::from(self.power_level).serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 21]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "GetVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "Capable",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "capable",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "GetTimeouts",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "standby_timeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "suspend_timeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "off_timeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "SetTimeouts",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "standby_timeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "suspend_timeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "off_timeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "Enable",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "Disable",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "ForceLevel",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "power_level",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Enum(EnumInfo { name: "DPMSMode", variants: DPMSMode::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DPMS"),
+        name: "Info",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "power_level",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Enum(EnumInfo { name: "DPMSMode", variants: DPMSMode::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
@@ -0,0 +1,3392 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `DRI2` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "DRI2";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 4);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Attachment(u32);
+impl Attachment {
+    pub const BUFFER_FRONT_LEFT: Self = Self(0);
+    pub const BUFFER_BACK_LEFT: Self = Self(1);
+    pub const BUFFER_FRONT_RIGHT: Self = Self(2);
+    pub const BUFFER_BACK_RIGHT: Self = Self(3);
+    pub const BUFFER_DEPTH: Self = Self(4);
+    pub const BUFFER_STENCIL: Self = Self(5);
+    pub const BUFFER_ACCUM: Self = Self(6);
+    pub const BUFFER_FAKE_FRONT_LEFT: Self = Self(7);
+    pub const BUFFER_FAKE_FRONT_RIGHT: Self = Self(8);
+    pub const BUFFER_DEPTH_STENCIL: Self = Self(9);
+    pub const BUFFER_HIZ: Self = Self(10);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "BUFFER_FRONT_LEFT", "BufferFrontLeft"),
+        (1, "BUFFER_BACK_LEFT", "BufferBackLeft"),
+        (2, "BUFFER_FRONT_RIGHT", "BufferFrontRight"),
+        (3, "BUFFER_BACK_RIGHT", "BufferBackRight"),
+        (4, "BUFFER_DEPTH", "BufferDepth"),
+        (5, "BUFFER_STENCIL", "BufferStencil"),
+        (6, "BUFFER_ACCUM", "BufferAccum"),
+        (7, "BUFFER_FAKE_FRONT_LEFT", "BufferFakeFrontLeft"),
+        (8, "BUFFER_FAKE_FRONT_RIGHT", "BufferFakeFrontRight"),
+        (9, "BUFFER_DEPTH_STENCIL", "BufferDepthStencil"),
+        (10, "BUFFER_HIZ", "BufferHiz"),
+    ];
+}
+impl From<Attachment> for u32 {
+    #[inline]
+    fn from(input: Attachment) -> Self {
+        input.0
+    }
+}
+impl From<Attachment> for Option<u32> {
+    #[inline]
+    fn from(input: Attachment) -> Self {
+        Some(input.0)
+    }
+}
+impl From<u8> for Attachment {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for Attachment {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u32> for Attachment {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Attachment  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0, Self::VARIANTS)
+    }
+}
+impl Trace for Attachment {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Attachment {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Attachment {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DriverType(u32);
+impl DriverType {
+    pub const DRI: Self = Self(0);
+    pub const VDPAU: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "DRI", "DRI"),
+        (1, "VDPAU", "VDPAU"),
+    ];
+}
+impl From<DriverType> for u32 {
+    #[inline]
+    fn from(input: DriverType) -> Self {
+        input.0
+    }
+}
+impl From<DriverType> for Option<u32> {
+    #[inline]
+    fn from(input: DriverType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<u8> for DriverType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for DriverType {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u32> for DriverType {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for DriverType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0, Self::VARIANTS)
+    }
+}
+impl Trace for DriverType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DriverType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DriverType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EventType(u16);
+impl EventType {
+    pub const EXCHANGE_COMPLETE: Self = Self(1);
+    pub const BLIT_COMPLETE: Self = Self(2);
+    pub const FLIP_COMPLETE: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1, "EXCHANGE_COMPLETE", "ExchangeComplete"),
+        (2, "BLIT_COMPLETE", "BlitComplete"),
+        (3, "FLIP_COMPLETE", "FlipComplete"),
+    ];
+}
+impl From<EventType> for u16 {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        input.0
+    }
+}
+impl From<EventType> for Option<u16> {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<EventType> for u32 {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<EventType> for Option<u32> {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for EventType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for EventType {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for EventType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for EventType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DRI2Buffer {
+    pub attachment: Attachment,
+    pub name: u32,
+    pub pitch: u32,
+    pub cpp: u32,
+    pub flags: u32,
+}
+impl TryParse for DRI2Buffer {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (attachment, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pitch, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (cpp, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let attachment = attachment.into();
+        let result = DRI2Buffer { attachment, name, pitch, cpp, flags };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for DRI2Buffer {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for DRI2Buffer {
+    type Bytes = [u8; 20];
+    fn serialize(&self) -> [u8; 20] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let attachment_bytes = u32::from(self.attachment).serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let cpp_bytes = self.cpp.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        [
+            attachment_bytes[0],
+            attachment_bytes[1],
+            attachment_bytes[2],
+            attachment_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+            pitch_bytes[0],
+            pitch_bytes[1],
+            pitch_bytes[2],
+            pitch_bytes[3],
+            cpp_bytes[0],
+            cpp_bytes[1],
+            cpp_bytes[2],
+            cpp_bytes[3],
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(20);
+        u32::from(self.attachment).serialize_into_with_order(bytes, order);
+        self.name.serialize_into_with_order(bytes, order);
+        self.pitch.serialize_into_with_order(bytes, order);
+        self.cpp.serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for DRI2Buffer {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("attachment", |tracer| tracer.value(&self.attachment))?;
+            tracer.field("name", |tracer| tracer.value(&self.name))?;
+            tracer.field("pitch", |tracer| tracer.value(&self.pitch))?;
+            tracer.field("cpp", |tracer| tracer.value(&self.cpp))?;
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttachFormat {
+    pub attachment: Attachment,
+    pub format: u32,
+}
+impl TryParse for AttachFormat {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (attachment, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let attachment = attachment.into();
+        let result = AttachFormat { attachment, format };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AttachFormat {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for AttachFormat {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let attachment_bytes = u32::from(self.attachment).serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        [
+            attachment_bytes[0],
+            attachment_bytes[1],
+            attachment_bytes[2],
+            attachment_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u32::from(self.attachment).serialize_into_with_order(bytes, order);
+        self.format.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for AttachFormat {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("attachment", |tracer| tracer.value(&self.attachment))?;
+            tracer.field("format", |tracer| tracer.value(&self.format))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Connect request
+pub const CONNECT_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectRequest {
+    pub window: xproto::Window,
+    pub driver_type: DriverType,
+}
+impl ConnectRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let driver_type_bytes = u32::from(self.driver_type).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CONNECT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            driver_type_bytes[0],
+            driver_type_bytes[1],
+            driver_type_bytes[2],
+            driver_type_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CONNECT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (driver_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let driver_type = driver_type.into();
+        let _ = remaining;
+        Ok(ConnectRequest {
+            window,
+            driver_type,
+        })
+    }
+}
+impl Request for ConnectRequest {
+    type Reply = ConnectReply;
+}
+impl Trace for ConnectRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("driver-type", |tracer| tracer.value(&self.driver_type))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnectReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub driver_name: Vec<u8>,
+    pub alignment_pad: Vec<u8>,
+    pub device_name: Vec<u8>,
+}
+impl TryParse for ConnectReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (driver_name_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (device_name_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (driver_name, remaining) = crate::x11_utils::parse_u8_list(remaining, driver_name_length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let driver_name = driver_name.to_vec();
+        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (driver_name_length.checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(driver_name_length).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let alignment_pad = alignment_pad.to_vec();
+        let (device_name, remaining) = crate::x11_utils::parse_u8_list(remaining, device_name_length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let device_name = device_name.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ConnectReply { sequence, length, driver_name, alignment_pad, device_name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ConnectReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ConnectReply {
+    /// Get the value of the `driver_name_length` field.
+    ///
+    /// The `driver_name_length` field is used as the length field of the `driver_name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn driver_name_length(&self) -> u32 {
+        self.driver_name.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `device_name_length` field.
+    ///
+    /// The `device_name_length` field is used as the length field of the `device_name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn device_name_length(&self) -> u32 {
+        self.device_name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ConnectReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("driver-name", |tracer| tracer.string(&self.driver_name))?;
+        tracer.field("alignment-pad", |tracer| tracer.list(&self.alignment_pad, |tracer, value| tracer.value(value)))?;
+        tracer.field("device-name", |tracer| tracer.string(&self.device_name))?;
+        Ok(())
+    }
+}
+impl Serialize for ConnectReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let driver_name_length = u32::try_from(self.driver_name.len()).expect("`driver_name` has too many elements");
+        driver_name_length.serialize_into_with_order(bytes, order);
+        let device_name_length = u32::try_from(self.device_name.len()).expect("`device_name` has too many elements");
+        device_name_length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        bytes.extend_from_slice(&self.driver_name);
+        assert_eq!(self.alignment_pad.len(), usize::try_from((driver_name_length.checked_add(3u32).unwrap() & (!3u32)).checked_sub(driver_name_length).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
+        bytes.extend_from_slice(&self.alignment_pad);
+        bytes.extend_from_slice(&self.device_name);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Authenticate request
+pub const AUTHENTICATE_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthenticateRequest {
+    pub window: xproto::Window,
+    pub magic: u32,
+}
+impl AuthenticateRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let magic_bytes = self.magic.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            AUTHENTICATE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            magic_bytes[0],
+            magic_bytes[1],
+            magic_bytes[2],
+            magic_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AUTHENTICATE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (magic, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(AuthenticateRequest {
+            window,
+            magic,
+        })
+    }
+}
+impl Request for AuthenticateRequest {
+    type Reply = AuthenticateReply;
+}
+impl Trace for AuthenticateRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("magic", |tracer| tracer.value(&self.magic))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthenticateReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub authenticated: u32,
+}
+impl TryParse for AuthenticateReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (authenticated, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = AuthenticateReply { sequence, length, authenticated };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AuthenticateReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for AuthenticateReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("authenticated", |tracer| tracer.value(&self.authenticated))?;
+        Ok(())
+    }
+}
+impl Serialize for AuthenticateReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.authenticated.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreateDrawable request
+pub const CREATE_DRAWABLE_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateDrawableRequest {
+    pub drawable: xproto::Drawable,
+}
+impl CreateDrawableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_DRAWABLE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_DRAWABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(CreateDrawableRequest {
+            drawable,
+        })
+    }
+}
+impl Request for CreateDrawableRequest {
+    type Reply = ();
+}
+impl Trace for CreateDrawableRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyDrawable request
+pub const DESTROY_DRAWABLE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyDrawableRequest {
+    pub drawable: xproto::Drawable,
+}
+impl DestroyDrawableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_DRAWABLE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_DRAWABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyDrawableRequest {
+            drawable,
+        })
+    }
+}
+impl Request for DestroyDrawableRequest {
+    type Reply = ();
+}
+impl Trace for DestroyDrawableRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetBuffers request
+pub const GET_BUFFERS_REQUEST: u8 = 5;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetBuffersRequest<'input> {
+    pub drawable: xproto::Drawable,
+    pub count: u32,
+    pub attachments: Cow<'input, [u32]>,
+}
+impl<'input> GetBuffersRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_BUFFERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attachments_bytes = self.attachments.serialize_with_order(order);
+        let length_so_far = length_so_far + attachments_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attachments_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_BUFFERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut attachments = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = u32::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            attachments.push(v);
+        }
+        let _ = remaining;
+        Ok(GetBuffersRequest {
+            drawable,
+            count,
+            attachments: Cow::Owned(attachments),
+        })
+    }
+    /// Clone all borrowed data in this GetBuffersRequest.
+    pub fn into_owned(self) -> GetBuffersRequest<'static> {
+        GetBuffersRequest {
+            drawable: self.drawable,
+            count: self.count,
+            attachments: Cow::Owned(self.attachments.into_owned()),
+        }
+    }
+}
+impl<'input> Request for GetBuffersRequest<'input> {
+    type Reply = GetBuffersReply;
+}
+impl Trace for GetBuffersRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("count", |tracer| tracer.value(&self.count))?;
+        tracer.field("attachments", |tracer| tracer.list(&self.attachments[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetBuffersReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub buffers: Vec<DRI2Buffer>,
+}
+impl TryParse for GetBuffersReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (buffers, remaining) = crate::x11_utils::parse_list_with_order::<DRI2Buffer>(remaining, count.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetBuffersReply { sequence, length, width, height, buffers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetBuffersReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetBuffersReply {
+    /// Get the value of the `count` field.
+    ///
+    /// The `count` field is used as the length field of the `buffers` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn count(&self) -> u32 {
+        self.buffers.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetBuffersReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("buffers", |tracer| tracer.list(&self.buffers, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetBuffersReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        let count = u32::try_from(self.buffers.len()).expect("`buffers` has too many elements");
+        count.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.buffers.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CopyRegion request
+pub const COPY_REGION_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyRegionRequest {
+    pub drawable: xproto::Drawable,
+    pub region: u32,
+    pub dest: u32,
+    pub src: u32,
+}
+impl CopyRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let region_bytes = self.region.serialize_with_order(order);
+        let dest_bytes = self.dest.serialize_with_order(order);
+        let src_bytes = self.src.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            COPY_REGION_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (region, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dest, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CopyRegionRequest {
+            drawable,
+            region,
+            dest,
+            src,
+        })
+    }
+}
+impl Request for CopyRegionRequest {
+    type Reply = CopyRegionReply;
+}
+impl Trace for CopyRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("region", |tracer| tracer.value(&self.region))?;
+        tracer.field("dest", |tracer| tracer.value(&self.dest))?;
+        tracer.field("src", |tracer| tracer.value(&self.src))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CopyRegionReply {
+    pub sequence: u16,
+    pub length: u32,
+}
+impl TryParse for CopyRegionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CopyRegionReply { sequence, length };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CopyRegionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CopyRegionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        Ok(())
+    }
+}
+impl Serialize for CopyRegionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetBuffersWithFormat request
+pub const GET_BUFFERS_WITH_FORMAT_REQUEST: u8 = 7;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetBuffersWithFormatRequest<'input> {
+    pub drawable: xproto::Drawable,
+    pub count: u32,
+    pub attachments: Cow<'input, [AttachFormat]>,
+}
+impl<'input> GetBuffersWithFormatRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_BUFFERS_WITH_FORMAT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attachments_bytes = self.attachments.serialize_with_order(order);
+        let length_so_far = length_so_far + attachments_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attachments_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_BUFFERS_WITH_FORMAT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut attachments = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = AttachFormat::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            attachments.push(v);
+        }
+        let _ = remaining;
+        Ok(GetBuffersWithFormatRequest {
+            drawable,
+            count,
+            attachments: Cow::Owned(attachments),
+        })
+    }
+    /// Clone all borrowed data in this GetBuffersWithFormatRequest.
+    pub fn into_owned(self) -> GetBuffersWithFormatRequest<'static> {
+        GetBuffersWithFormatRequest {
+            drawable: self.drawable,
+            count: self.count,
+            attachments: Cow::Owned(self.attachments.into_owned()),
+        }
+    }
+}
+impl<'input> Request for GetBuffersWithFormatRequest<'input> {
+    type Reply = GetBuffersWithFormatReply;
+}
+impl Trace for GetBuffersWithFormatRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("count", |tracer| tracer.value(&self.count))?;
+        tracer.field("attachments", |tracer| tracer.list(&self.attachments[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetBuffersWithFormatReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+    pub buffers: Vec<DRI2Buffer>,
+}
+impl TryParse for GetBuffersWithFormatReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (buffers, remaining) = crate::x11_utils::parse_list_with_order::<DRI2Buffer>(remaining, count.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetBuffersWithFormatReply { sequence, length, width, height, buffers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetBuffersWithFormatReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetBuffersWithFormatReply {
+    /// Get the value of the `count` field.
+    ///
+    /// The `count` field is used as the length field of the `buffers` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn count(&self) -> u32 {
+        self.buffers.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetBuffersWithFormatReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("buffers", |tracer| tracer.list(&self.buffers, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetBuffersWithFormatReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        let count = u32::try_from(self.buffers.len()).expect("`buffers` has too many elements");
+        count.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.buffers.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SwapBuffers request
+pub const SWAP_BUFFERS_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapBuffersRequest {
+    pub drawable: xproto::Drawable,
+    pub target_msc_hi: u32,
+    pub target_msc_lo: u32,
+    pub divisor_hi: u32,
+    pub divisor_lo: u32,
+    pub remainder_hi: u32,
+    pub remainder_lo: u32,
+}
+impl SwapBuffersRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let target_msc_hi_bytes = self.target_msc_hi.serialize_with_order(order);
+        let target_msc_lo_bytes = self.target_msc_lo.serialize_with_order(order);
+        let divisor_hi_bytes = self.divisor_hi.serialize_with_order(order);
+        let divisor_lo_bytes = self.divisor_lo.serialize_with_order(order);
+        let remainder_hi_bytes = self.remainder_hi.serialize_with_order(order);
+        let remainder_lo_bytes = self.remainder_lo.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_msc_hi_bytes[0],
+            target_msc_hi_bytes[1],
+            target_msc_hi_bytes[2],
+            target_msc_hi_bytes[3],
+            target_msc_lo_bytes[0],
+            target_msc_lo_bytes[1],
+            target_msc_lo_bytes[2],
+            target_msc_lo_bytes[3],
+            divisor_hi_bytes[0],
+            divisor_hi_bytes[1],
+            divisor_hi_bytes[2],
+            divisor_hi_bytes[3],
+            divisor_lo_bytes[0],
+            divisor_lo_bytes[1],
+            divisor_lo_bytes[2],
+            divisor_lo_bytes[3],
+            remainder_hi_bytes[0],
+            remainder_hi_bytes[1],
+            remainder_hi_bytes[2],
+            remainder_hi_bytes[3],
+            remainder_lo_bytes[0],
+            remainder_lo_bytes[1],
+            remainder_lo_bytes[2],
+            remainder_lo_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWAP_BUFFERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (target_msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (target_msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SwapBuffersRequest {
+            drawable,
+            target_msc_hi,
+            target_msc_lo,
+            divisor_hi,
+            divisor_lo,
+            remainder_hi,
+            remainder_lo,
+        })
+    }
+}
+impl Request for SwapBuffersRequest {
+    type Reply = SwapBuffersReply;
+}
+impl Trace for SwapBuffersRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("target-msc-hi", |tracer| tracer.value(&self.target_msc_hi))?;
+        tracer.field("target-msc-lo", |tracer| tracer.value(&self.target_msc_lo))?;
+        tracer.field("divisor-hi", |tracer| tracer.value(&self.divisor_hi))?;
+        tracer.field("divisor-lo", |tracer| tracer.value(&self.divisor_lo))?;
+        tracer.field("remainder-hi", |tracer| tracer.value(&self.remainder_hi))?;
+        tracer.field("remainder-lo", |tracer| tracer.value(&self.remainder_lo))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapBuffersReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub swap_hi: u32,
+    pub swap_lo: u32,
+}
+impl TryParse for SwapBuffersReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = SwapBuffersReply { sequence, length, swap_hi, swap_lo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SwapBuffersReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for SwapBuffersReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("swap-hi", |tracer| tracer.value(&self.swap_hi))?;
+        tracer.field("swap-lo", |tracer| tracer.value(&self.swap_lo))?;
+        Ok(())
+    }
+}
+impl Serialize for SwapBuffersReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.swap_hi.serialize_into_with_order(bytes, order);
+        self.swap_lo.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMSC request
+pub const GET_MSC_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMSCRequest {
+    pub drawable: xproto::Drawable,
+}
+impl GetMSCRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MSC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MSC_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetMSCRequest {
+            drawable,
+        })
+    }
+}
+impl Request for GetMSCRequest {
+    type Reply = GetMSCReply;
+}
+impl Trace for GetMSCRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMSCReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc_hi: u32,
+    pub sbc_lo: u32,
+}
+impl TryParse for GetMSCReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMSCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMSCReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetMSCReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ust-hi", |tracer| tracer.value(&self.ust_hi))?;
+        tracer.field("ust-lo", |tracer| tracer.value(&self.ust_lo))?;
+        tracer.field("msc-hi", |tracer| tracer.value(&self.msc_hi))?;
+        tracer.field("msc-lo", |tracer| tracer.value(&self.msc_lo))?;
+        tracer.field("sbc-hi", |tracer| tracer.value(&self.sbc_hi))?;
+        tracer.field("sbc-lo", |tracer| tracer.value(&self.sbc_lo))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMSCReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ust_hi.serialize_into_with_order(bytes, order);
+        self.ust_lo.serialize_into_with_order(bytes, order);
+        self.msc_hi.serialize_into_with_order(bytes, order);
+        self.msc_lo.serialize_into_with_order(bytes, order);
+        self.sbc_hi.serialize_into_with_order(bytes, order);
+        self.sbc_lo.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the WaitMSC request
+pub const WAIT_MSC_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitMSCRequest {
+    pub drawable: xproto::Drawable,
+    pub target_msc_hi: u32,
+    pub target_msc_lo: u32,
+    pub divisor_hi: u32,
+    pub divisor_lo: u32,
+    pub remainder_hi: u32,
+    pub remainder_lo: u32,
+}
+impl WaitMSCRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let target_msc_hi_bytes = self.target_msc_hi.serialize_with_order(order);
+        let target_msc_lo_bytes = self.target_msc_lo.serialize_with_order(order);
+        let divisor_hi_bytes = self.divisor_hi.serialize_with_order(order);
+        let divisor_lo_bytes = self.divisor_lo.serialize_with_order(order);
+        let remainder_hi_bytes = self.remainder_hi.serialize_with_order(order);
+        let remainder_lo_bytes = self.remainder_lo.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            WAIT_MSC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_msc_hi_bytes[0],
+            target_msc_hi_bytes[1],
+            target_msc_hi_bytes[2],
+            target_msc_hi_bytes[3],
+            target_msc_lo_bytes[0],
+            target_msc_lo_bytes[1],
+            target_msc_lo_bytes[2],
+            target_msc_lo_bytes[3],
+            divisor_hi_bytes[0],
+            divisor_hi_bytes[1],
+            divisor_hi_bytes[2],
+            divisor_hi_bytes[3],
+            divisor_lo_bytes[0],
+            divisor_lo_bytes[1],
+            divisor_lo_bytes[2],
+            divisor_lo_bytes[3],
+            remainder_hi_bytes[0],
+            remainder_hi_bytes[1],
+            remainder_hi_bytes[2],
+            remainder_hi_bytes[3],
+            remainder_lo_bytes[0],
+            remainder_lo_bytes[1],
+            remainder_lo_bytes[2],
+            remainder_lo_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_MSC_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (target_msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (target_msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (divisor_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (remainder_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(WaitMSCRequest {
+            drawable,
+            target_msc_hi,
+            target_msc_lo,
+            divisor_hi,
+            divisor_lo,
+            remainder_hi,
+            remainder_lo,
+        })
+    }
+}
+impl Request for WaitMSCRequest {
+    type Reply = WaitMSCReply;
+}
+impl Trace for WaitMSCRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("target-msc-hi", |tracer| tracer.value(&self.target_msc_hi))?;
+        tracer.field("target-msc-lo", |tracer| tracer.value(&self.target_msc_lo))?;
+        tracer.field("divisor-hi", |tracer| tracer.value(&self.divisor_hi))?;
+        tracer.field("divisor-lo", |tracer| tracer.value(&self.divisor_lo))?;
+        tracer.field("remainder-hi", |tracer| tracer.value(&self.remainder_hi))?;
+        tracer.field("remainder-lo", |tracer| tracer.value(&self.remainder_lo))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaitMSCReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc_hi: u32,
+    pub sbc_lo: u32,
+}
+impl TryParse for WaitMSCReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = WaitMSCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for WaitMSCReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for WaitMSCReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ust-hi", |tracer| tracer.value(&self.ust_hi))?;
+        tracer.field("ust-lo", |tracer| tracer.value(&self.ust_lo))?;
+        tracer.field("msc-hi", |tracer| tracer.value(&self.msc_hi))?;
+        tracer.field("msc-lo", |tracer| tracer.value(&self.msc_lo))?;
+        tracer.field("sbc-hi", |tracer| tracer.value(&self.sbc_hi))?;
+        tracer.field("sbc-lo", |tracer| tracer.value(&self.sbc_lo))?;
+        Ok(())
+    }
+}
+impl Serialize for WaitMSCReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ust_hi.serialize_into_with_order(bytes, order);
+        self.ust_lo.serialize_into_with_order(bytes, order);
+        self.msc_hi.serialize_into_with_order(bytes, order);
+        self.msc_lo.serialize_into_with_order(bytes, order);
+        self.sbc_hi.serialize_into_with_order(bytes, order);
+        self.sbc_lo.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the WaitSBC request
+pub const WAIT_SBC_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitSBCRequest {
+    pub drawable: xproto::Drawable,
+    pub target_sbc_hi: u32,
+    pub target_sbc_lo: u32,
+}
+impl WaitSBCRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let target_sbc_hi_bytes = self.target_sbc_hi.serialize_with_order(order);
+        let target_sbc_lo_bytes = self.target_sbc_lo.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            WAIT_SBC_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            target_sbc_hi_bytes[0],
+            target_sbc_hi_bytes[1],
+            target_sbc_hi_bytes[2],
+            target_sbc_hi_bytes[3],
+            target_sbc_lo_bytes[0],
+            target_sbc_lo_bytes[1],
+            target_sbc_lo_bytes[2],
+            target_sbc_lo_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_SBC_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (target_sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (target_sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(WaitSBCRequest {
+            drawable,
+            target_sbc_hi,
+            target_sbc_lo,
+        })
+    }
+}
+impl Request for WaitSBCRequest {
+    type Reply = WaitSBCReply;
+}
+impl Trace for WaitSBCRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("target-sbc-hi", |tracer| tracer.value(&self.target_sbc_hi))?;
+        tracer.field("target-sbc-lo", |tracer| tracer.value(&self.target_sbc_lo))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaitSBCReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc_hi: u32,
+    pub sbc_lo: u32,
+}
+impl TryParse for WaitSBCReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = WaitSBCReply { sequence, length, ust_hi, ust_lo, msc_hi, msc_lo, sbc_hi, sbc_lo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for WaitSBCReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for WaitSBCReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ust-hi", |tracer| tracer.value(&self.ust_hi))?;
+        tracer.field("ust-lo", |tracer| tracer.value(&self.ust_lo))?;
+        tracer.field("msc-hi", |tracer| tracer.value(&self.msc_hi))?;
+        tracer.field("msc-lo", |tracer| tracer.value(&self.msc_lo))?;
+        tracer.field("sbc-hi", |tracer| tracer.value(&self.sbc_hi))?;
+        tracer.field("sbc-lo", |tracer| tracer.value(&self.sbc_lo))?;
+        Ok(())
+    }
+}
+impl Serialize for WaitSBCReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ust_hi.serialize_into_with_order(bytes, order);
+        self.ust_lo.serialize_into_with_order(bytes, order);
+        self.msc_hi.serialize_into_with_order(bytes, order);
+        self.msc_lo.serialize_into_with_order(bytes, order);
+        self.sbc_hi.serialize_into_with_order(bytes, order);
+        self.sbc_lo.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SwapInterval request
+pub const SWAP_INTERVAL_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapIntervalRequest {
+    pub drawable: xproto::Drawable,
+    pub interval: u32,
+}
+impl SwapIntervalRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let interval_bytes = self.interval.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SWAP_INTERVAL_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            interval_bytes[0],
+            interval_bytes[1],
+            interval_bytes[2],
+            interval_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWAP_INTERVAL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (interval, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SwapIntervalRequest {
+            drawable,
+            interval,
+        })
+    }
+}
+impl Request for SwapIntervalRequest {
+    type Reply = ();
+}
+impl Trace for SwapIntervalRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("interval", |tracer| tracer.value(&self.interval))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetParam request
+pub const GET_PARAM_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetParamRequest {
+    pub drawable: xproto::Drawable,
+    pub param: u32,
+}
+impl GetParamRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let param_bytes = self.param.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PARAM_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            param_bytes[0],
+            param_bytes[1],
+            param_bytes[2],
+            param_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PARAM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (param, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetParamRequest {
+            drawable,
+            param,
+        })
+    }
+}
+impl Request for GetParamRequest {
+    type Reply = GetParamReply;
+}
+impl Trace for GetParamRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("param", |tracer| tracer.value(&self.param))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetParamReply {
+    pub is_param_recognized: bool,
+    pub sequence: u16,
+    pub length: u32,
+    pub value_hi: u32,
+    pub value_lo: u32,
+}
+impl TryParse for GetParamReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (is_param_recognized, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetParamReply { is_param_recognized, sequence, length, value_hi, value_lo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetParamReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetParamReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("is-param-recognized", |tracer| tracer.value(&self.is_param_recognized))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("value-hi", |tracer| tracer.value(&self.value_hi))?;
+        tracer.field("value-lo", |tracer| tracer.value(&self.value_lo))?;
+        Ok(())
+    }
+}
+impl Serialize for GetParamReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.is_param_recognized.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.value_hi.serialize_into_with_order(bytes, order);
+        self.value_lo.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the BufferSwapComplete event
+pub const BUFFER_SWAP_COMPLETE_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BufferSwapCompleteEvent {
+    pub response_type: u8,
+    pub sequence: u16,
+    pub event_type: EventType,
+    pub drawable: xproto::Drawable,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc: u32,
+}
+impl TryParse for BufferSwapCompleteEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let event_type = event_type.into();
+        let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for BufferSwapCompleteEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for BufferSwapCompleteEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("event-type", |tracer| tracer.value(&self.event_type))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("ust-hi", |tracer| tracer.value(&self.ust_hi))?;
+        tracer.field("ust-lo", |tracer| tracer.value(&self.ust_lo))?;
+        tracer.field("msc-hi", |tracer| tracer.value(&self.msc_hi))?;
+        tracer.field("msc-lo", |tracer| tracer.value(&self.msc_lo))?;
+        tracer.field("sbc", |tracer| tracer.value(&self.sbc))?;
+        Ok(())
+    }
+}
+impl BufferSwapCompleteEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let event_type_bytes = u16::from(self.event_type).serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let ust_hi_bytes = self.ust_hi.serialize_with_order(order);
+        let ust_lo_bytes = self.ust_lo.serialize_with_order(order);
+        let msc_hi_bytes = self.msc_hi.serialize_with_order(order);
+        let msc_lo_bytes = self.msc_lo.serialize_with_order(order);
+        let sbc_bytes = self.sbc.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            0,
+            sequence_bytes[0],
+            sequence_bytes[1],
+            event_type_bytes[0],
+            event_type_bytes[1],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            ust_hi_bytes[0],
+            ust_hi_bytes[1],
+            ust_hi_bytes[2],
+            ust_hi_bytes[3],
+            ust_lo_bytes[0],
+            ust_lo_bytes[1],
+            ust_lo_bytes[2],
+            ust_lo_bytes[3],
+            msc_hi_bytes[0],
+            msc_hi_bytes[1],
+            msc_hi_bytes[2],
+            msc_hi_bytes[3],
+            msc_lo_bytes[0],
+            msc_lo_bytes[1],
+            msc_lo_bytes[2],
+            msc_lo_bytes[3],
+            sbc_bytes[0],
+            sbc_bytes[1],
+            sbc_bytes[2],
+            sbc_bytes[3],
+        ]
+    }
+}
+impl From<&BufferSwapCompleteEvent> for [u8; 32] {
+    fn from(input: &BufferSwapCompleteEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<BufferSwapCompleteEvent> for [u8; 32] {
+    fn from(input: BufferSwapCompleteEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the InvalidateBuffers event
+pub const INVALIDATE_BUFFERS_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InvalidateBuffersEvent {
+    pub response_type: u8,
+    pub sequence: u16,
+    pub drawable: xproto::Drawable,
+}
+impl TryParse for InvalidateBuffersEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let result = InvalidateBuffersEvent { response_type, sequence, drawable };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for InvalidateBuffersEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for InvalidateBuffersEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+impl InvalidateBuffersEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            0,
+            sequence_bytes[0],
+            sequence_bytes[1],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            // trailing padding
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&InvalidateBuffersEvent> for [u8; 32] {
+    fn from(input: &InvalidateBuffersEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<InvalidateBuffersEvent> for [u8; 32] {
+    fn from(input: InvalidateBuffersEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "Connect",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "driver_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "DriverType", variants: DriverType::VARIANTS }) }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "driver_name_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "device_name_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "driver_name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "alignment_pad",
+                kind: FieldKind::List(TypeInfo { name: "void", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "device_name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "Authenticate",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "magic",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "authenticated",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "CreateDrawable",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "DestroyDrawable",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "GetBuffers",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attachments",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "buffers",
+                kind: FieldKind::List(TypeInfo { name: "DRI2Buffer", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "CopyRegion",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dest",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "GetBuffersWithFormat",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attachments",
+                kind: FieldKind::List(TypeInfo { name: "AttachFormat", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "buffers",
+                kind: FieldKind::List(TypeInfo { name: "DRI2Buffer", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "SwapBuffers",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "GetMSC",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "WaitMSC",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "WaitSBC",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_sbc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_sbc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "SwapInterval",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "interval",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DRI2"),
+        name: "GetParam",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "param",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "is_param_recognized",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("DRI2"),
+        name: "BufferSwapComplete",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Enum(EnumInfo { name: "EventType", variants: EventType::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("DRI2"),
+        name: "InvalidateBuffers",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,1878 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `DRI3` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "DRI3";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 2);
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Open request
+pub const OPEN_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenRequest {
+    pub drawable: xproto::Drawable,
+    pub provider: u32,
+}
+impl OpenRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            OPEN_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != OPEN_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (provider, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(OpenRequest {
+            drawable,
+            provider,
+        })
+    }
+}
+impl Request for OpenRequest {
+    type Reply = OpenReply;
+}
+impl Trace for OpenRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("provider", |tracer| tracer.value(&self.provider))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct OpenReply {
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub device_fd: RawFdContainer,
+}
+impl TryParseFd for OpenReply {
+    fn try_parse_fd<'a>(value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::try_parse_fd_with_order(value, fds, ByteOrder::native())
+    }
+    fn try_parse_fd_with_order<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
+        let device_fd = fds.remove(0);
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = OpenReply { nfd, sequence, length, device_fd };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<(&[u8], Vec<RawFdContainer>)> for OpenReply {
+    type Error = ParseError;
+    fn try_from(value: (&[u8], Vec<RawFdContainer>)) -> Result<Self, Self::Error> {
+        let (value, mut fds) = value;
+        Ok(Self::try_parse_fd(value, &mut fds)?.0)
+    }
+}
+impl Trace for OpenReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("nfd", |tracer| tracer.value(&self.nfd))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("device-fd", |tracer| tracer.value(&self.device_fd))?;
+        Ok(())
+    }
+}
+impl Serialize for OpenReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the PixmapFromBuffer request
+pub const PIXMAP_FROM_BUFFER_REQUEST: u8 = 2;
+#[derive(Debug, PartialEq, Eq)]
+pub struct PixmapFromBufferRequest {
+    pub pixmap: xproto::Pixmap,
+    pub drawable: xproto::Drawable,
+    pub size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub stride: u16,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pixmap_fd: RawFdContainer,
+}
+impl PixmapFromBufferRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let size_bytes = self.size.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let stride_bytes = self.stride.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let bpp_bytes = self.bpp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PIXMAP_FROM_BUFFER_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            stride_bytes[0],
+            stride_bytes[1],
+            depth_bytes[0],
+            bpp_bytes[0],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![self.pixmap_fd])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+        Self::try_parse_request_fd_with_order(header, value, fds, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_fd_with_order(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXMAP_FROM_BUFFER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (stride, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
+        let pixmap_fd = fds.remove(0);
+        let _ = remaining;
+        Ok(PixmapFromBufferRequest {
+            pixmap,
+            drawable,
+            size,
+            width,
+            height,
+            stride,
+            depth,
+            bpp,
+            pixmap_fd,
+        })
+    }
+}
+impl Request for PixmapFromBufferRequest {
+    type Reply = ();
+}
+impl Trace for PixmapFromBufferRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("stride", |tracer| tracer.value(&self.stride))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("bpp", |tracer| tracer.value(&self.bpp))?;
+        tracer.field("pixmap-fd", |tracer| tracer.value(&self.pixmap_fd))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the BufferFromPixmap request
+pub const BUFFER_FROM_PIXMAP_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferFromPixmapRequest {
+    pub pixmap: xproto::Pixmap,
+}
+impl BufferFromPixmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            BUFFER_FROM_PIXMAP_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != BUFFER_FROM_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(BufferFromPixmapRequest {
+            pixmap,
+        })
+    }
+}
+impl Request for BufferFromPixmapRequest {
+    type Reply = BufferFromPixmapReply;
+}
+impl Trace for BufferFromPixmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BufferFromPixmapReply {
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub stride: u16,
+    pub depth: u8,
+    pub bpp: u8,
+    pub pixmap_fd: RawFdContainer,
+}
+impl TryParseFd for BufferFromPixmapReply {
+    fn try_parse_fd<'a>(value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::try_parse_fd_with_order(value, fds, ByteOrder::native())
+    }
+    fn try_parse_fd_with_order<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (stride, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
+        let pixmap_fd = fds.remove(0);
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = BufferFromPixmapReply { nfd, sequence, length, size, width, height, stride, depth, bpp, pixmap_fd };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<(&[u8], Vec<RawFdContainer>)> for BufferFromPixmapReply {
+    type Error = ParseError;
+    fn try_from(value: (&[u8], Vec<RawFdContainer>)) -> Result<Self, Self::Error> {
+        let (value, mut fds) = value;
+        Ok(Self::try_parse_fd(value, &mut fds)?.0)
+    }
+}
+impl Trace for BufferFromPixmapReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("nfd", |tracer| tracer.value(&self.nfd))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("stride", |tracer| tracer.value(&self.stride))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("bpp", |tracer| tracer.value(&self.bpp))?;
+        tracer.field("pixmap-fd", |tracer| tracer.value(&self.pixmap_fd))?;
+        Ok(())
+    }
+}
+impl Serialize for BufferFromPixmapReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.stride.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        self.bpp.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the FenceFromFD request
+pub const FENCE_FROM_FD_REQUEST: u8 = 4;
+#[derive(Debug, PartialEq, Eq)]
+pub struct FenceFromFDRequest {
+    pub drawable: xproto::Drawable,
+    pub fence: u32,
+    pub initially_triggered: bool,
+    pub fence_fd: RawFdContainer,
+}
+impl FenceFromFDRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let initially_triggered_bytes = self.initially_triggered.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FENCE_FROM_FD_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+            initially_triggered_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![self.fence_fd])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+        Self::try_parse_request_fd_with_order(header, value, fds, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_fd_with_order(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FENCE_FROM_FD_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (fence, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (initially_triggered, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
+        let fence_fd = fds.remove(0);
+        let _ = remaining;
+        Ok(FenceFromFDRequest {
+            drawable,
+            fence,
+            initially_triggered,
+            fence_fd,
+        })
+    }
+}
+impl Request for FenceFromFDRequest {
+    type Reply = ();
+}
+impl Trace for FenceFromFDRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("fence", |tracer| tracer.value(&self.fence))?;
+        tracer.field("initially-triggered", |tracer| tracer.value(&self.initially_triggered))?;
+        tracer.field("fence-fd", |tracer| tracer.value(&self.fence_fd))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the FDFromFence request
+pub const FD_FROM_FENCE_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FDFromFenceRequest {
+    pub drawable: xproto::Drawable,
+    pub fence: u32,
+}
+impl FDFromFenceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FD_FROM_FENCE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FD_FROM_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (fence, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(FDFromFenceRequest {
+            drawable,
+            fence,
+        })
+    }
+}
+impl Request for FDFromFenceRequest {
+    type Reply = FDFromFenceReply;
+}
+impl Trace for FDFromFenceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("fence", |tracer| tracer.value(&self.fence))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FDFromFenceReply {
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub fence_fd: RawFdContainer,
+}
+impl TryParseFd for FDFromFenceReply {
+    fn try_parse_fd<'a>(value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::try_parse_fd_with_order(value, fds, ByteOrder::native())
+    }
+    fn try_parse_fd_with_order<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
+        let fence_fd = fds.remove(0);
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = FDFromFenceReply { nfd, sequence, length, fence_fd };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<(&[u8], Vec<RawFdContainer>)> for FDFromFenceReply {
+    type Error = ParseError;
+    fn try_from(value: (&[u8], Vec<RawFdContainer>)) -> Result<Self, Self::Error> {
+        let (value, mut fds) = value;
+        Ok(Self::try_parse_fd(value, &mut fds)?.0)
+    }
+}
+impl Trace for FDFromFenceReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("nfd", |tracer| tracer.value(&self.nfd))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("fence-fd", |tracer| tracer.value(&self.fence_fd))?;
+        Ok(())
+    }
+}
+impl Serialize for FDFromFenceReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetSupportedModifiers request
+pub const GET_SUPPORTED_MODIFIERS_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetSupportedModifiersRequest {
+    pub window: u32,
+    pub depth: u8,
+    pub bpp: u8,
+}
+impl GetSupportedModifiersRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let bpp_bytes = self.bpp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_SUPPORTED_MODIFIERS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            depth_bytes[0],
+            bpp_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SUPPORTED_MODIFIERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = u32::try_parse_with_order(value, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetSupportedModifiersRequest {
+            window,
+            depth,
+            bpp,
+        })
+    }
+}
+impl Request for GetSupportedModifiersRequest {
+    type Reply = GetSupportedModifiersReply;
+}
+impl Trace for GetSupportedModifiersRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.value(&self.window))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("bpp", |tracer| tracer.value(&self.bpp))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetSupportedModifiersReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub window_modifiers: Vec<u64>,
+    pub screen_modifiers: Vec<u64>,
+}
+impl TryParse for GetSupportedModifiersReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_window_modifiers, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_screen_modifiers, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (window_modifiers, remaining) = crate::x11_utils::parse_list_with_order::<u64>(remaining, num_window_modifiers.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (screen_modifiers, remaining) = crate::x11_utils::parse_list_with_order::<u64>(remaining, num_screen_modifiers.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetSupportedModifiersReply { sequence, length, window_modifiers, screen_modifiers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetSupportedModifiersReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetSupportedModifiersReply {
+    /// Get the value of the `num_window_modifiers` field.
+    ///
+    /// The `num_window_modifiers` field is used as the length field of the `window_modifiers` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_window_modifiers(&self) -> u32 {
+        self.window_modifiers.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_screen_modifiers` field.
+    ///
+    /// The `num_screen_modifiers` field is used as the length field of the `screen_modifiers` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_screen_modifiers(&self) -> u32 {
+        self.screen_modifiers.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetSupportedModifiersReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("window-modifiers", |tracer| tracer.list(&self.window_modifiers, |tracer, value| tracer.value(value)))?;
+        tracer.field("screen-modifiers", |tracer| tracer.list(&self.screen_modifiers, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetSupportedModifiersReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_window_modifiers = u32::try_from(self.window_modifiers.len()).expect("`window_modifiers` has too many elements");
+        num_window_modifiers.serialize_into_with_order(bytes, order);
+        let num_screen_modifiers = u32::try_from(self.screen_modifiers.len()).expect("`screen_modifiers` has too many elements");
+        num_screen_modifiers.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.window_modifiers.serialize_into_with_order(bytes, order);
+        self.screen_modifiers.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the PixmapFromBuffers request
+pub const PIXMAP_FROM_BUFFERS_REQUEST: u8 = 7;
+#[derive(Debug, PartialEq, Eq)]
+pub struct PixmapFromBuffersRequest {
+    pub pixmap: xproto::Pixmap,
+    pub window: xproto::Window,
+    pub width: u16,
+    pub height: u16,
+    pub stride0: u32,
+    pub offset0: u32,
+    pub stride1: u32,
+    pub offset1: u32,
+    pub stride2: u32,
+    pub offset2: u32,
+    pub stride3: u32,
+    pub offset3: u32,
+    pub depth: u8,
+    pub bpp: u8,
+    pub modifier: u64,
+    pub buffers: Vec<RawFdContainer>,
+}
+impl PixmapFromBuffersRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let num_buffers = u8::try_from(self.buffers.len()).expect("`buffers` has too many elements");
+        let num_buffers_bytes = num_buffers.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let stride0_bytes = self.stride0.serialize_with_order(order);
+        let offset0_bytes = self.offset0.serialize_with_order(order);
+        let stride1_bytes = self.stride1.serialize_with_order(order);
+        let offset1_bytes = self.offset1.serialize_with_order(order);
+        let stride2_bytes = self.stride2.serialize_with_order(order);
+        let offset2_bytes = self.offset2.serialize_with_order(order);
+        let stride3_bytes = self.stride3.serialize_with_order(order);
+        let offset3_bytes = self.offset3.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let bpp_bytes = self.bpp.serialize_with_order(order);
+        let modifier_bytes = self.modifier.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PIXMAP_FROM_BUFFERS_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            num_buffers_bytes[0],
+            0,
+            0,
+            0,
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            stride0_bytes[0],
+            stride0_bytes[1],
+            stride0_bytes[2],
+            stride0_bytes[3],
+            offset0_bytes[0],
+            offset0_bytes[1],
+            offset0_bytes[2],
+            offset0_bytes[3],
+            stride1_bytes[0],
+            stride1_bytes[1],
+            stride1_bytes[2],
+            stride1_bytes[3],
+            offset1_bytes[0],
+            offset1_bytes[1],
+            offset1_bytes[2],
+            offset1_bytes[3],
+            stride2_bytes[0],
+            stride2_bytes[1],
+            stride2_bytes[2],
+            stride2_bytes[3],
+            offset2_bytes[0],
+            offset2_bytes[1],
+            offset2_bytes[2],
+            offset2_bytes[3],
+            stride3_bytes[0],
+            stride3_bytes[1],
+            stride3_bytes[2],
+            stride3_bytes[3],
+            offset3_bytes[0],
+            offset3_bytes[1],
+            offset3_bytes[2],
+            offset3_bytes[3],
+            depth_bytes[0],
+            bpp_bytes[0],
+            0,
+            0,
+            modifier_bytes[0],
+            modifier_bytes[1],
+            modifier_bytes[2],
+            modifier_bytes[3],
+            modifier_bytes[4],
+            modifier_bytes[5],
+            modifier_bytes[6],
+            modifier_bytes[7],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], self.buffers)
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request_fd(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>) -> Result<Self, ParseError> {
+        Self::try_parse_request_fd_with_order(header, value, fds, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_fd_with_order(header: RequestHeader, value: &[u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXMAP_FROM_BUFFERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(value, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (num_buffers, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (stride0, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset0, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stride1, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset1, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stride2, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset2, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (stride3, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset3, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (modifier, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let fds_len = usize::try_from(num_buffers).or(Err(ParseError::ConversionFailed))?;
+        if fds.len() < fds_len { return Err(ParseError::MissingFileDescriptors) }
+        let mut buffers = fds.split_off(fds_len);
+        core::mem::swap(fds, &mut buffers);
+        let _ = remaining;
+        Ok(PixmapFromBuffersRequest {
+            pixmap,
+            window,
+            width,
+            height,
+            stride0,
+            offset0,
+            stride1,
+            offset1,
+            stride2,
+            offset2,
+            stride3,
+            offset3,
+            depth,
+            bpp,
+            modifier,
+            buffers,
+        })
+    }
+}
+impl Request for PixmapFromBuffersRequest {
+    type Reply = ();
+}
+impl Trace for PixmapFromBuffersRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("stride0", |tracer| tracer.value(&self.stride0))?;
+        tracer.field("offset0", |tracer| tracer.value(&self.offset0))?;
+        tracer.field("stride1", |tracer| tracer.value(&self.stride1))?;
+        tracer.field("offset1", |tracer| tracer.value(&self.offset1))?;
+        tracer.field("stride2", |tracer| tracer.value(&self.stride2))?;
+        tracer.field("offset2", |tracer| tracer.value(&self.offset2))?;
+        tracer.field("stride3", |tracer| tracer.value(&self.stride3))?;
+        tracer.field("offset3", |tracer| tracer.value(&self.offset3))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("bpp", |tracer| tracer.value(&self.bpp))?;
+        tracer.field("modifier", |tracer| tracer.value(&self.modifier))?;
+        tracer.field("buffers", |tracer| tracer.list(&self.buffers, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the BuffersFromPixmap request
+pub const BUFFERS_FROM_PIXMAP_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BuffersFromPixmapRequest {
+    pub pixmap: xproto::Pixmap,
+}
+impl BuffersFromPixmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            BUFFERS_FROM_PIXMAP_REQUEST,
+            0,
+            0,
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != BUFFERS_FROM_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(BuffersFromPixmapRequest {
+            pixmap,
+        })
+    }
+}
+impl Request for BuffersFromPixmapRequest {
+    type Reply = BuffersFromPixmapReply;
+}
+impl Trace for BuffersFromPixmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BuffersFromPixmapReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub width: u16,
+    pub height: u16,
+    pub modifier: u64,
+    pub depth: u8,
+    pub bpp: u8,
+    pub strides: Vec<u32>,
+    pub offsets: Vec<u32>,
+    pub buffers: Vec<RawFdContainer>,
+}
+impl TryParseFd for BuffersFromPixmapReply {
+    fn try_parse_fd<'a>(value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::try_parse_fd_with_order(value, fds, ByteOrder::native())
+    }
+    fn try_parse_fd_with_order<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (modifier, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(6..).ok_or(ParseError::InsufficientData)?;
+        let (strides, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, nfd.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (offsets, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, nfd.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let fds_len = usize::try_from(nfd).or(Err(ParseError::ConversionFailed))?;
+        if fds.len() < fds_len { return Err(ParseError::MissingFileDescriptors) }
+        let mut buffers = fds.split_off(fds_len);
+        core::mem::swap(fds, &mut buffers);
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = BuffersFromPixmapReply { sequence, length, width, height, modifier, depth, bpp, strides, offsets, buffers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<(&[u8], Vec<RawFdContainer>)> for BuffersFromPixmapReply {
+    type Error = ParseError;
+    fn try_from(value: (&[u8], Vec<RawFdContainer>)) -> Result<Self, Self::Error> {
+        let (value, mut fds) = value;
+        Ok(Self::try_parse_fd(value, &mut fds)?.0)
+    }
+}
+impl BuffersFromPixmapReply {
+    /// Get the value of the `nfd` field.
+    ///
+    /// The `nfd` field is used as the length field of the `strides` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn nfd(&self) -> u8 {
+        self.strides.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for BuffersFromPixmapReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("modifier", |tracer| tracer.value(&self.modifier))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("bpp", |tracer| tracer.value(&self.bpp))?;
+        tracer.field("strides", |tracer| tracer.list(&self.strides, |tracer, value| tracer.value(value)))?;
+        tracer.field("offsets", |tracer| tracer.list(&self.offsets, |tracer, value| tracer.value(value)))?;
+        tracer.field("buffers", |tracer| tracer.list(&self.buffers, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for BuffersFromPixmapReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        let nfd = u8::try_from(self.strides.len()).expect("`strides` has too many elements");
+        nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.modifier.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        self.bpp.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 6]);
+        self.strides.serialize_into_with_order(bytes, order);
+        assert_eq!(self.offsets.len(), usize::try_from(nfd).unwrap(), "`offsets` has an incorrect length");
+        self.offsets.serialize_into_with_order(bytes, order);
+        assert_eq!(self.buffers.len(), usize::try_from(nfd).unwrap(), "`buffers` has an incorrect length");
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "Open",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nfd",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "device_fd",
+                kind: FieldKind::Fd,
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "PixmapFromBuffer",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stride",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bpp",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap_fd",
+                kind: FieldKind::Fd,
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "BufferFromPixmap",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nfd",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stride",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bpp",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap_fd",
+                kind: FieldKind::Fd,
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "FenceFromFD",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "initially_triggered",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence_fd",
+                kind: FieldKind::Fd,
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "FDFromFence",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nfd",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence_fd",
+                kind: FieldKind::Fd,
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "GetSupportedModifiers",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bpp",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_window_modifiers",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_screen_modifiers",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window_modifiers",
+                kind: FieldKind::List(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen_modifiers",
+                kind: FieldKind::List(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "PixmapFromBuffers",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_buffers",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stride0",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offset0",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stride1",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offset1",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stride2",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offset2",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stride3",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offset3",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bpp",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modifier",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "buffers",
+                kind: FieldKind::FdList,
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("DRI3"),
+        name: "BuffersFromPixmap",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nfd",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modifier",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bpp",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "strides",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offsets",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "buffers",
+                kind: FieldKind::FdList,
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
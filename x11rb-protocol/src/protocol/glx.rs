@@ -0,0 +1,18972 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Glx` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "GLX";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 4);
+
+pub type Pixmap = u32;
+
+pub type Context = u32;
+
+pub type Pbuffer = u32;
+
+pub type Window = u32;
+
+pub type Fbconfig = u32;
+
+pub type Drawable = u32;
+
+pub type Float32 = f32;
+
+pub type Float64 = f64;
+
+pub type Bool32 = u32;
+
+pub type ContextTag = u32;
+
+
+/// Opcode for the BadContext error
+pub const BAD_CONTEXT_ERROR: u8 = 0;
+
+/// Opcode for the BadContextState error
+pub const BAD_CONTEXT_STATE_ERROR: u8 = 1;
+
+/// Opcode for the BadDrawable error
+pub const BAD_DRAWABLE_ERROR: u8 = 2;
+
+/// Opcode for the BadPixmap error
+pub const BAD_PIXMAP_ERROR: u8 = 3;
+
+/// Opcode for the BadContextTag error
+pub const BAD_CONTEXT_TAG_ERROR: u8 = 4;
+
+/// Opcode for the BadCurrentWindow error
+pub const BAD_CURRENT_WINDOW_ERROR: u8 = 5;
+
+/// Opcode for the BadRenderRequest error
+pub const BAD_RENDER_REQUEST_ERROR: u8 = 6;
+
+/// Opcode for the BadLargeRequest error
+pub const BAD_LARGE_REQUEST_ERROR: u8 = 7;
+
+/// Opcode for the UnsupportedPrivateRequest error
+pub const UNSUPPORTED_PRIVATE_REQUEST_ERROR: u8 = 8;
+
+/// Opcode for the BadFBConfig error
+pub const BAD_FB_CONFIG_ERROR: u8 = 9;
+
+/// Opcode for the BadPbuffer error
+pub const BAD_PBUFFER_ERROR: u8 = 10;
+
+/// Opcode for the BadCurrentDrawable error
+pub const BAD_CURRENT_DRAWABLE_ERROR: u8 = 11;
+
+/// Opcode for the BadWindow error
+pub const BAD_WINDOW_ERROR: u8 = 12;
+
+/// Opcode for the GLXBadProfileARB error
+pub const GLX_BAD_PROFILE_ARB_ERROR: u8 = 13;
+
+/// Opcode for the PbufferClobber event
+pub const PBUFFER_CLOBBER_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PbufferClobberEvent {
+    pub response_type: u8,
+    pub sequence: u16,
+    pub event_type: u16,
+    pub draw_type: u16,
+    pub drawable: Drawable,
+    pub b_mask: u32,
+    pub aux_buffer: u16,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub count: u16,
+}
+impl TryParse for PbufferClobberEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (draw_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (b_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (aux_buffer, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let result = PbufferClobberEvent { response_type, sequence, event_type, draw_type, drawable, b_mask, aux_buffer, x, y, width, height, count };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for PbufferClobberEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for PbufferClobberEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("event-type", |tracer| tracer.value(&self.event_type))?;
+        tracer.field("draw-type", |tracer| tracer.value(&self.draw_type))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("b-mask", |tracer| tracer.value(&self.b_mask))?;
+        tracer.field("aux-buffer", |tracer| tracer.value(&self.aux_buffer))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("count", |tracer| tracer.value(&self.count))?;
+        Ok(())
+    }
+}
+impl PbufferClobberEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let draw_type_bytes = self.draw_type.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let b_mask_bytes = self.b_mask.serialize_with_order(order);
+        let aux_buffer_bytes = self.aux_buffer.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            0,
+            sequence_bytes[0],
+            sequence_bytes[1],
+            event_type_bytes[0],
+            event_type_bytes[1],
+            draw_type_bytes[0],
+            draw_type_bytes[1],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            b_mask_bytes[0],
+            b_mask_bytes[1],
+            b_mask_bytes[2],
+            b_mask_bytes[3],
+            aux_buffer_bytes[0],
+            aux_buffer_bytes[1],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            count_bytes[0],
+            count_bytes[1],
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&PbufferClobberEvent> for [u8; 32] {
+    fn from(input: &PbufferClobberEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<PbufferClobberEvent> for [u8; 32] {
+    fn from(input: PbufferClobberEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the BufferSwapComplete event
+pub const BUFFER_SWAP_COMPLETE_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BufferSwapCompleteEvent {
+    pub response_type: u8,
+    pub sequence: u16,
+    pub event_type: u16,
+    pub drawable: Drawable,
+    pub ust_hi: u32,
+    pub ust_lo: u32,
+    pub msc_hi: u32,
+    pub msc_lo: u32,
+    pub sbc: u32,
+}
+impl TryParse for BufferSwapCompleteEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (ust_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_hi, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (msc_lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (sbc, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = BufferSwapCompleteEvent { response_type, sequence, event_type, drawable, ust_hi, ust_lo, msc_hi, msc_lo, sbc };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for BufferSwapCompleteEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for BufferSwapCompleteEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("event-type", |tracer| tracer.value(&self.event_type))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("ust-hi", |tracer| tracer.value(&self.ust_hi))?;
+        tracer.field("ust-lo", |tracer| tracer.value(&self.ust_lo))?;
+        tracer.field("msc-hi", |tracer| tracer.value(&self.msc_hi))?;
+        tracer.field("msc-lo", |tracer| tracer.value(&self.msc_lo))?;
+        tracer.field("sbc", |tracer| tracer.value(&self.sbc))?;
+        Ok(())
+    }
+}
+impl BufferSwapCompleteEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let ust_hi_bytes = self.ust_hi.serialize_with_order(order);
+        let ust_lo_bytes = self.ust_lo.serialize_with_order(order);
+        let msc_hi_bytes = self.msc_hi.serialize_with_order(order);
+        let msc_lo_bytes = self.msc_lo.serialize_with_order(order);
+        let sbc_bytes = self.sbc.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            0,
+            sequence_bytes[0],
+            sequence_bytes[1],
+            event_type_bytes[0],
+            event_type_bytes[1],
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            ust_hi_bytes[0],
+            ust_hi_bytes[1],
+            ust_hi_bytes[2],
+            ust_hi_bytes[3],
+            ust_lo_bytes[0],
+            ust_lo_bytes[1],
+            ust_lo_bytes[2],
+            ust_lo_bytes[3],
+            msc_hi_bytes[0],
+            msc_hi_bytes[1],
+            msc_hi_bytes[2],
+            msc_hi_bytes[3],
+            msc_lo_bytes[0],
+            msc_lo_bytes[1],
+            msc_lo_bytes[2],
+            msc_lo_bytes[3],
+            sbc_bytes[0],
+            sbc_bytes[1],
+            sbc_bytes[2],
+            sbc_bytes[3],
+        ]
+    }
+}
+impl From<&BufferSwapCompleteEvent> for [u8; 32] {
+    fn from(input: &BufferSwapCompleteEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<BufferSwapCompleteEvent> for [u8; 32] {
+    fn from(input: BufferSwapCompleteEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PBCET(u16);
+impl PBCET {
+    pub const DAMAGED: Self = Self(32791);
+    pub const SAVED: Self = Self(32792);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (32791, "DAMAGED", "Damaged"),
+        (32792, "SAVED", "Saved"),
+    ];
+}
+impl From<PBCET> for u16 {
+    #[inline]
+    fn from(input: PBCET) -> Self {
+        input.0
+    }
+}
+impl From<PBCET> for Option<u16> {
+    #[inline]
+    fn from(input: PBCET) -> Self {
+        Some(input.0)
+    }
+}
+impl From<PBCET> for u32 {
+    #[inline]
+    fn from(input: PBCET) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<PBCET> for Option<u32> {
+    #[inline]
+    fn from(input: PBCET) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for PBCET {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for PBCET {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for PBCET  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for PBCET {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for PBCET {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PBCET {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PBCDT(u16);
+impl PBCDT {
+    pub const WINDOW: Self = Self(32793);
+    pub const PBUFFER: Self = Self(32794);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (32793, "WINDOW", "Window"),
+        (32794, "PBUFFER", "Pbuffer"),
+    ];
+}
+impl From<PBCDT> for u16 {
+    #[inline]
+    fn from(input: PBCDT) -> Self {
+        input.0
+    }
+}
+impl From<PBCDT> for Option<u16> {
+    #[inline]
+    fn from(input: PBCDT) -> Self {
+        Some(input.0)
+    }
+}
+impl From<PBCDT> for u32 {
+    #[inline]
+    fn from(input: PBCDT) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<PBCDT> for Option<u32> {
+    #[inline]
+    fn from(input: PBCDT) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for PBCDT {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for PBCDT {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for PBCDT  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for PBCDT {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for PBCDT {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PBCDT {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the Render request
+pub const RENDER_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderRequest<'input> {
+    pub context_tag: ContextTag,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> RenderRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            RENDER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RENDER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (data, remaining) = remaining.split_at(remaining.len());
+        let _ = remaining;
+        Ok(RenderRequest {
+            context_tag,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this RenderRequest.
+    pub fn into_owned(self) -> RenderRequest<'static> {
+        RenderRequest {
+            context_tag: self.context_tag,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for RenderRequest<'input> {
+    type Reply = ();
+}
+impl Trace for RenderRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the RenderLarge request
+pub const RENDER_LARGE_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderLargeRequest<'input> {
+    pub context_tag: ContextTag,
+    pub request_num: u16,
+    pub request_total: u16,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> RenderLargeRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let request_num_bytes = self.request_num.serialize_with_order(order);
+        let request_total_bytes = self.request_total.serialize_with_order(order);
+        let data_len = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        let data_len_bytes = data_len.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            RENDER_LARGE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            request_num_bytes[0],
+            request_num_bytes[1],
+            request_total_bytes[0],
+            request_total_bytes[1],
+            data_len_bytes[0],
+            data_len_bytes[1],
+            data_len_bytes[2],
+            data_len_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RENDER_LARGE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (request_num, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (request_total, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(RenderLargeRequest {
+            context_tag,
+            request_num,
+            request_total,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this RenderLargeRequest.
+    pub fn into_owned(self) -> RenderLargeRequest<'static> {
+        RenderLargeRequest {
+            context_tag: self.context_tag,
+            request_num: self.request_num,
+            request_total: self.request_total,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for RenderLargeRequest<'input> {
+    type Reply = ();
+}
+impl Trace for RenderLargeRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("request-num", |tracer| tracer.value(&self.request_num))?;
+        tracer.field("request-total", |tracer| tracer.value(&self.request_total))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateContext request
+pub const CREATE_CONTEXT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateContextRequest {
+    pub context: Context,
+    pub visual: xproto::Visualid,
+    pub screen: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+}
+impl CreateContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let visual_bytes = self.visual.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let share_list_bytes = self.share_list.serialize_with_order(order);
+        let is_direct_bytes = self.is_direct.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (share_list, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(CreateContextRequest {
+            context,
+            visual,
+            screen,
+            share_list,
+            is_direct,
+        })
+    }
+}
+impl Request for CreateContextRequest {
+    type Reply = ();
+}
+impl Trace for CreateContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("visual", |tracer| tracer.value(&self.visual))?;
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("share-list", |tracer| tracer.xid(&self.share_list))?;
+        tracer.field("is-direct", |tracer| tracer.value(&self.is_direct))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyContext request
+pub const DESTROY_CONTEXT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyContextRequest {
+    pub context: Context,
+}
+impl DestroyContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyContextRequest {
+            context,
+        })
+    }
+}
+impl Request for DestroyContextRequest {
+    type Reply = ();
+}
+impl Trace for DestroyContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the MakeCurrent request
+pub const MAKE_CURRENT_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MakeCurrentRequest {
+    pub drawable: Drawable,
+    pub context: Context,
+    pub old_context_tag: ContextTag,
+}
+impl MakeCurrentRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let context_bytes = self.context.serialize_with_order(order);
+        let old_context_tag_bytes = self.old_context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            MAKE_CURRENT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            old_context_tag_bytes[0],
+            old_context_tag_bytes[1],
+            old_context_tag_bytes[2],
+            old_context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MAKE_CURRENT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = Drawable::try_parse_with_order(value, order)?;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (old_context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(MakeCurrentRequest {
+            drawable,
+            context,
+            old_context_tag,
+        })
+    }
+}
+impl Request for MakeCurrentRequest {
+    type Reply = MakeCurrentReply;
+}
+impl Trace for MakeCurrentRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("old-context-tag", |tracer| tracer.value(&self.old_context_tag))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MakeCurrentReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub context_tag: ContextTag,
+}
+impl TryParse for MakeCurrentReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = MakeCurrentReply { sequence, length, context_tag };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for MakeCurrentReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for MakeCurrentReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+impl Serialize for MakeCurrentReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.context_tag.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the IsDirect request
+pub const IS_DIRECT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsDirectRequest {
+    pub context: Context,
+}
+impl IsDirectRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            IS_DIRECT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_DIRECT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(IsDirectRequest {
+            context,
+        })
+    }
+}
+impl Request for IsDirectRequest {
+    type Reply = IsDirectReply;
+}
+impl Trace for IsDirectRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsDirectReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub is_direct: bool,
+}
+impl TryParse for IsDirectReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = IsDirectReply { sequence, length, is_direct };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IsDirectReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IsDirectReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("is-direct", |tracer| tracer.value(&self.is_direct))?;
+        Ok(())
+    }
+}
+impl Serialize for IsDirectReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.is_direct.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the WaitGL request
+pub const WAIT_GL_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitGLRequest {
+    pub context_tag: ContextTag,
+}
+impl WaitGLRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            WAIT_GL_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_GL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(WaitGLRequest {
+            context_tag,
+        })
+    }
+}
+impl Request for WaitGLRequest {
+    type Reply = ();
+}
+impl Trace for WaitGLRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the WaitX request
+pub const WAIT_X_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitXRequest {
+    pub context_tag: ContextTag,
+}
+impl WaitXRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            WAIT_X_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != WAIT_X_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(WaitXRequest {
+            context_tag,
+        })
+    }
+}
+impl Request for WaitXRequest {
+    type Reply = ();
+}
+impl Trace for WaitXRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CopyContext request
+pub const COPY_CONTEXT_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyContextRequest {
+    pub src: Context,
+    pub dest: Context,
+    pub mask: u32,
+    pub src_context_tag: ContextTag,
+}
+impl CopyContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let src_bytes = self.src.serialize_with_order(order);
+        let dest_bytes = self.dest.serialize_with_order(order);
+        let mask_bytes = self.mask.serialize_with_order(order);
+        let src_context_tag_bytes = self.src_context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            COPY_CONTEXT_REQUEST,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+            src_context_tag_bytes[0],
+            src_context_tag_bytes[1],
+            src_context_tag_bytes[2],
+            src_context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (src, remaining) = Context::try_parse_with_order(value, order)?;
+        let (dest, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src_context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CopyContextRequest {
+            src,
+            dest,
+            mask,
+            src_context_tag,
+        })
+    }
+}
+impl Request for CopyContextRequest {
+    type Reply = ();
+}
+impl Trace for CopyContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("src", |tracer| tracer.xid(&self.src))?;
+        tracer.field("dest", |tracer| tracer.xid(&self.dest))?;
+        tracer.field("mask", |tracer| tracer.value(&self.mask))?;
+        tracer.field("src-context-tag", |tracer| tracer.value(&self.src_context_tag))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GC(u32);
+impl GC {
+    pub const GL_CURRENT_BIT: Self = Self(1 << 0);
+    pub const GL_POINT_BIT: Self = Self(1 << 1);
+    pub const GL_LINE_BIT: Self = Self(1 << 2);
+    pub const GL_POLYGON_BIT: Self = Self(1 << 3);
+    pub const GL_POLYGON_STIPPLE_BIT: Self = Self(1 << 4);
+    pub const GL_PIXEL_MODE_BIT: Self = Self(1 << 5);
+    pub const GL_LIGHTING_BIT: Self = Self(1 << 6);
+    pub const GL_FOG_BIT: Self = Self(1 << 7);
+    pub const GL_DEPTH_BUFFER_BIT: Self = Self(1 << 8);
+    pub const GL_ACCUM_BUFFER_BIT: Self = Self(1 << 9);
+    pub const GL_STENCIL_BUFFER_BIT: Self = Self(1 << 10);
+    pub const GL_VIEWPORT_BIT: Self = Self(1 << 11);
+    pub const GL_TRANSFORM_BIT: Self = Self(1 << 12);
+    pub const GL_ENABLE_BIT: Self = Self(1 << 13);
+    pub const GL_COLOR_BUFFER_BIT: Self = Self(1 << 14);
+    pub const GL_HINT_BIT: Self = Self(1 << 15);
+    pub const GL_EVAL_BIT: Self = Self(1 << 16);
+    pub const GL_LIST_BIT: Self = Self(1 << 17);
+    pub const GL_TEXTURE_BIT: Self = Self(1 << 18);
+    pub const GL_SCISSOR_BIT: Self = Self(1 << 19);
+    pub const GL_ALL_ATTRIB_BITS: Self = Self(16_777_215);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "GL_CURRENT_BIT", "GL_CURRENT_BIT"),
+        (1 << 1, "GL_POINT_BIT", "GL_POINT_BIT"),
+        (1 << 2, "GL_LINE_BIT", "GL_LINE_BIT"),
+        (1 << 3, "GL_POLYGON_BIT", "GL_POLYGON_BIT"),
+        (1 << 4, "GL_POLYGON_STIPPLE_BIT", "GL_POLYGON_STIPPLE_BIT"),
+        (1 << 5, "GL_PIXEL_MODE_BIT", "GL_PIXEL_MODE_BIT"),
+        (1 << 6, "GL_LIGHTING_BIT", "GL_LIGHTING_BIT"),
+        (1 << 7, "GL_FOG_BIT", "GL_FOG_BIT"),
+        (1 << 8, "GL_DEPTH_BUFFER_BIT", "GL_DEPTH_BUFFER_BIT"),
+        (1 << 9, "GL_ACCUM_BUFFER_BIT", "GL_ACCUM_BUFFER_BIT"),
+        (1 << 10, "GL_STENCIL_BUFFER_BIT", "GL_STENCIL_BUFFER_BIT"),
+        (1 << 11, "GL_VIEWPORT_BIT", "GL_VIEWPORT_BIT"),
+        (1 << 12, "GL_TRANSFORM_BIT", "GL_TRANSFORM_BIT"),
+        (1 << 13, "GL_ENABLE_BIT", "GL_ENABLE_BIT"),
+        (1 << 14, "GL_COLOR_BUFFER_BIT", "GL_COLOR_BUFFER_BIT"),
+        (1 << 15, "GL_HINT_BIT", "GL_HINT_BIT"),
+        (1 << 16, "GL_EVAL_BIT", "GL_EVAL_BIT"),
+        (1 << 17, "GL_LIST_BIT", "GL_LIST_BIT"),
+        (1 << 18, "GL_TEXTURE_BIT", "GL_TEXTURE_BIT"),
+        (1 << 19, "GL_SCISSOR_BIT", "GL_SCISSOR_BIT"),
+        (16_777_215, "GL_ALL_ATTRIB_BITS", "GL_ALL_ATTRIB_BITS"),
+    ];
+}
+impl From<GC> for u32 {
+    #[inline]
+    fn from(input: GC) -> Self {
+        input.0
+    }
+}
+impl From<GC> for Option<u32> {
+    #[inline]
+    fn from(input: GC) -> Self {
+        Some(input.0)
+    }
+}
+impl From<u8> for GC {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for GC {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u32> for GC {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for GC  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0, Self::VARIANTS)
+    }
+}
+impl Trace for GC {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for GC {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GC {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the SwapBuffers request
+pub const SWAP_BUFFERS_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapBuffersRequest {
+    pub context_tag: ContextTag,
+    pub drawable: Drawable,
+}
+impl SwapBuffersRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SWAP_BUFFERS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWAP_BUFFERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SwapBuffersRequest {
+            context_tag,
+            drawable,
+        })
+    }
+}
+impl Request for SwapBuffersRequest {
+    type Reply = ();
+}
+impl Trace for SwapBuffersRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the UseXFont request
+pub const USE_X_FONT_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseXFontRequest {
+    pub context_tag: ContextTag,
+    pub font: xproto::Font,
+    pub first: u32,
+    pub count: u32,
+    pub list_base: u32,
+}
+impl UseXFontRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let font_bytes = self.font.serialize_with_order(order);
+        let first_bytes = self.first.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let list_base_bytes = self.list_base.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            USE_X_FONT_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            font_bytes[0],
+            font_bytes[1],
+            font_bytes[2],
+            font_bytes[3],
+            first_bytes[0],
+            first_bytes[1],
+            first_bytes[2],
+            first_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+            list_base_bytes[0],
+            list_base_bytes[1],
+            list_base_bytes[2],
+            list_base_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != USE_X_FONT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (font, remaining) = xproto::Font::try_parse_with_order(remaining, order)?;
+        let (first, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (list_base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(UseXFontRequest {
+            context_tag,
+            font,
+            first,
+            count,
+            list_base,
+        })
+    }
+}
+impl Request for UseXFontRequest {
+    type Reply = ();
+}
+impl Trace for UseXFontRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("font", |tracer| tracer.xid(&self.font))?;
+        tracer.field("first", |tracer| tracer.value(&self.first))?;
+        tracer.field("count", |tracer| tracer.value(&self.count))?;
+        tracer.field("list-base", |tracer| tracer.value(&self.list_base))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateGLXPixmap request
+pub const CREATE_GLX_PIXMAP_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateGLXPixmapRequest {
+    pub screen: u32,
+    pub visual: xproto::Visualid,
+    pub pixmap: xproto::Pixmap,
+    pub glx_pixmap: Pixmap,
+}
+impl CreateGLXPixmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let visual_bytes = self.visual.serialize_with_order(order);
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let glx_pixmap_bytes = self.glx_pixmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_GLX_PIXMAP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_GLX_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateGLXPixmapRequest {
+            screen,
+            visual,
+            pixmap,
+            glx_pixmap,
+        })
+    }
+}
+impl Request for CreateGLXPixmapRequest {
+    type Reply = ();
+}
+impl Trace for CreateGLXPixmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("visual", |tracer| tracer.value(&self.visual))?;
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("glx-pixmap", |tracer| tracer.xid(&self.glx_pixmap))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetVisualConfigs request
+pub const GET_VISUAL_CONFIGS_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetVisualConfigsRequest {
+    pub screen: u32,
+}
+impl GetVisualConfigsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_VISUAL_CONFIGS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VISUAL_CONFIGS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetVisualConfigsRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetVisualConfigsRequest {
+    type Reply = GetVisualConfigsReply;
+}
+impl Trace for GetVisualConfigsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetVisualConfigsReply {
+    pub sequence: u16,
+    pub num_visuals: u32,
+    pub num_properties: u32,
+    pub property_list: Vec<u32>,
+}
+impl TryParse for GetVisualConfigsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_visuals, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_properties, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (property_list, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetVisualConfigsReply { sequence, num_visuals, num_properties, property_list };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetVisualConfigsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetVisualConfigsReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `property_list` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.property_list.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetVisualConfigsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("num-visuals", |tracer| tracer.value(&self.num_visuals))?;
+        tracer.field("num-properties", |tracer| tracer.value(&self.num_properties))?;
+        tracer.field("property-list", |tracer| tracer.list(&self.property_list, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetVisualConfigsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.property_list.len()).expect("`property_list` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.num_visuals.serialize_into_with_order(bytes, order);
+        self.num_properties.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.property_list.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the DestroyGLXPixmap request
+pub const DESTROY_GLX_PIXMAP_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyGLXPixmapRequest {
+    pub glx_pixmap: Pixmap,
+}
+impl DestroyGLXPixmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let glx_pixmap_bytes = self.glx_pixmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_GLX_PIXMAP_REQUEST,
+            0,
+            0,
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_GLX_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyGLXPixmapRequest {
+            glx_pixmap,
+        })
+    }
+}
+impl Request for DestroyGLXPixmapRequest {
+    type Reply = ();
+}
+impl Trace for DestroyGLXPixmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("glx-pixmap", |tracer| tracer.xid(&self.glx_pixmap))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the VendorPrivate request
+pub const VENDOR_PRIVATE_REQUEST: u8 = 16;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VendorPrivateRequest<'input> {
+    pub vendor_code: u32,
+    pub context_tag: ContextTag,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> VendorPrivateRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let vendor_code_bytes = self.vendor_code.serialize_with_order(order);
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            VENDOR_PRIVATE_REQUEST,
+            0,
+            0,
+            vendor_code_bytes[0],
+            vendor_code_bytes[1],
+            vendor_code_bytes[2],
+            vendor_code_bytes[3],
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != VENDOR_PRIVATE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (vendor_code, remaining) = u32::try_parse_with_order(value, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = remaining.split_at(remaining.len());
+        let _ = remaining;
+        Ok(VendorPrivateRequest {
+            vendor_code,
+            context_tag,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this VendorPrivateRequest.
+    pub fn into_owned(self) -> VendorPrivateRequest<'static> {
+        VendorPrivateRequest {
+            vendor_code: self.vendor_code,
+            context_tag: self.context_tag,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for VendorPrivateRequest<'input> {
+    type Reply = ();
+}
+impl Trace for VendorPrivateRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("vendor-code", |tracer| tracer.value(&self.vendor_code))?;
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the VendorPrivateWithReply request
+pub const VENDOR_PRIVATE_WITH_REPLY_REQUEST: u8 = 17;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VendorPrivateWithReplyRequest<'input> {
+    pub vendor_code: u32,
+    pub context_tag: ContextTag,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> VendorPrivateWithReplyRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let vendor_code_bytes = self.vendor_code.serialize_with_order(order);
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            VENDOR_PRIVATE_WITH_REPLY_REQUEST,
+            0,
+            0,
+            vendor_code_bytes[0],
+            vendor_code_bytes[1],
+            vendor_code_bytes[2],
+            vendor_code_bytes[3],
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != VENDOR_PRIVATE_WITH_REPLY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (vendor_code, remaining) = u32::try_parse_with_order(value, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = remaining.split_at(remaining.len());
+        let _ = remaining;
+        Ok(VendorPrivateWithReplyRequest {
+            vendor_code,
+            context_tag,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this VendorPrivateWithReplyRequest.
+    pub fn into_owned(self) -> VendorPrivateWithReplyRequest<'static> {
+        VendorPrivateWithReplyRequest {
+            vendor_code: self.vendor_code,
+            context_tag: self.context_tag,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for VendorPrivateWithReplyRequest<'input> {
+    type Reply = VendorPrivateWithReplyReply;
+}
+impl Trace for VendorPrivateWithReplyRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("vendor-code", |tracer| tracer.value(&self.vendor_code))?;
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VendorPrivateWithReplyReply {
+    pub sequence: u16,
+    pub retval: u32,
+    pub data1: [u8; 24],
+    pub data2: Vec<u8>,
+}
+impl TryParse for VendorPrivateWithReplyReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (retval, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data1, remaining) = crate::x11_utils::parse_u8_list(remaining, 24)?;
+        let data1 = <[u8; 24]>::try_from(data1).unwrap();
+        let (data2, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data2 = data2.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = VendorPrivateWithReplyReply { sequence, retval, data1, data2 };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for VendorPrivateWithReplyReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl VendorPrivateWithReplyReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data2` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data2.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for VendorPrivateWithReplyReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("retval", |tracer| tracer.value(&self.retval))?;
+        tracer.field("data1", |tracer| tracer.list(&self.data1, |tracer, value| tracer.value(value)))?;
+        tracer.field("data2", |tracer| tracer.list(&self.data2, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for VendorPrivateWithReplyReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data2.len() % 4, 0, "`data2` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data2.len() / 4).expect("`data2` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.retval.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.data1);
+        bytes.extend_from_slice(&self.data2);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryExtensionsString request
+pub const QUERY_EXTENSIONS_STRING_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryExtensionsStringRequest {
+    pub screen: u32,
+}
+impl QueryExtensionsStringRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_EXTENSIONS_STRING_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_EXTENSIONS_STRING_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryExtensionsStringRequest {
+            screen,
+        })
+    }
+}
+impl Request for QueryExtensionsStringRequest {
+    type Reply = QueryExtensionsStringReply;
+}
+impl Trace for QueryExtensionsStringRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryExtensionsStringReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub n: u32,
+}
+impl TryParse for QueryExtensionsStringReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryExtensionsStringReply { sequence, length, n };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryExtensionsStringReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryExtensionsStringReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("n", |tracer| tracer.value(&self.n))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryExtensionsStringReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.n.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryServerString request
+pub const QUERY_SERVER_STRING_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryServerStringRequest {
+    pub screen: u32,
+    pub name: u32,
+}
+impl QueryServerStringRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_SERVER_STRING_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_SERVER_STRING_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (name, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryServerStringRequest {
+            screen,
+            name,
+        })
+    }
+}
+impl Request for QueryServerStringRequest {
+    type Reply = QueryServerStringReply;
+}
+impl Trace for QueryServerStringRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("name", |tracer| tracer.value(&self.name))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryServerStringReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub string: Vec<u8>,
+}
+impl TryParse for QueryServerStringReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let string = string.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryServerStringReply { sequence, length, string };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryServerStringReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryServerStringReply {
+    /// Get the value of the `str_len` field.
+    ///
+    /// The `str_len` field is used as the length field of the `string` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn str_len(&self) -> u32 {
+        self.string.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryServerStringReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("string", |tracer| tracer.string(&self.string))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryServerStringReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let str_len = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        str_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        bytes.extend_from_slice(&self.string);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ClientInfo request
+pub const CLIENT_INFO_REQUEST: u8 = 20;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientInfoRequest<'input> {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub string: Cow<'input, [u8]>,
+}
+impl<'input> ClientInfoRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let str_len = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        let str_len_bytes = str_len.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CLIENT_INFO_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            str_len_bytes[0],
+            str_len_bytes[1],
+            str_len_bytes[2],
+            str_len_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.string.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.string, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CLIENT_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, str_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(ClientInfoRequest {
+            major_version,
+            minor_version,
+            string: Cow::Borrowed(string),
+        })
+    }
+    /// Clone all borrowed data in this ClientInfoRequest.
+    pub fn into_owned(self) -> ClientInfoRequest<'static> {
+        ClientInfoRequest {
+            major_version: self.major_version,
+            minor_version: self.minor_version,
+            string: Cow::Owned(self.string.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ClientInfoRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ClientInfoRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        tracer.field("string", |tracer| tracer.string(&self.string[..]))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetFBConfigs request
+pub const GET_FB_CONFIGS_REQUEST: u8 = 21;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetFBConfigsRequest {
+    pub screen: u32,
+}
+impl GetFBConfigsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_FB_CONFIGS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_FB_CONFIGS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetFBConfigsRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetFBConfigsRequest {
+    type Reply = GetFBConfigsReply;
+}
+impl Trace for GetFBConfigsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetFBConfigsReply {
+    pub sequence: u16,
+    pub num_fb_configs: u32,
+    pub num_properties: u32,
+    pub property_list: Vec<u32>,
+}
+impl TryParse for GetFBConfigsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_fb_configs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_properties, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (property_list, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetFBConfigsReply { sequence, num_fb_configs, num_properties, property_list };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetFBConfigsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetFBConfigsReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `property_list` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.property_list.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetFBConfigsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("num-FB-configs", |tracer| tracer.value(&self.num_fb_configs))?;
+        tracer.field("num-properties", |tracer| tracer.value(&self.num_properties))?;
+        tracer.field("property-list", |tracer| tracer.list(&self.property_list, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetFBConfigsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.property_list.len()).expect("`property_list` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.num_fb_configs.serialize_into_with_order(bytes, order);
+        self.num_properties.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.property_list.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreatePixmap request
+pub const CREATE_PIXMAP_REQUEST: u8 = 22;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePixmapRequest<'input> {
+    pub screen: u32,
+    pub fbconfig: Fbconfig,
+    pub pixmap: xproto::Pixmap,
+    pub glx_pixmap: Pixmap,
+    pub attribs: Cow<'input, [u32]>,
+}
+impl<'input> CreatePixmapRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let fbconfig_bytes = self.fbconfig.serialize_with_order(order);
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let glx_pixmap_bytes = self.glx_pixmap.serialize_with_order(order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_PIXMAP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attribs_bytes = self.attribs.serialize_with_order(order);
+        let length_so_far = length_so_far + attribs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreatePixmapRequest {
+            screen,
+            fbconfig,
+            pixmap,
+            glx_pixmap,
+            attribs: Cow::Owned(attribs),
+        })
+    }
+    /// Clone all borrowed data in this CreatePixmapRequest.
+    pub fn into_owned(self) -> CreatePixmapRequest<'static> {
+        CreatePixmapRequest {
+            screen: self.screen,
+            fbconfig: self.fbconfig,
+            pixmap: self.pixmap,
+            glx_pixmap: self.glx_pixmap,
+            attribs: Cow::Owned(self.attribs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreatePixmapRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreatePixmapRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("fbconfig", |tracer| tracer.xid(&self.fbconfig))?;
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("glx-pixmap", |tracer| tracer.xid(&self.glx_pixmap))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyPixmap request
+pub const DESTROY_PIXMAP_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyPixmapRequest {
+    pub glx_pixmap: Pixmap,
+}
+impl DestroyPixmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let glx_pixmap_bytes = self.glx_pixmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_PIXMAP_REQUEST,
+            0,
+            0,
+            glx_pixmap_bytes[0],
+            glx_pixmap_bytes[1],
+            glx_pixmap_bytes[2],
+            glx_pixmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (glx_pixmap, remaining) = Pixmap::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyPixmapRequest {
+            glx_pixmap,
+        })
+    }
+}
+impl Request for DestroyPixmapRequest {
+    type Reply = ();
+}
+impl Trace for DestroyPixmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("glx-pixmap", |tracer| tracer.xid(&self.glx_pixmap))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateNewContext request
+pub const CREATE_NEW_CONTEXT_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateNewContextRequest {
+    pub context: Context,
+    pub fbconfig: Fbconfig,
+    pub screen: u32,
+    pub render_type: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+}
+impl CreateNewContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let fbconfig_bytes = self.fbconfig.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let render_type_bytes = self.render_type.serialize_with_order(order);
+        let share_list_bytes = self.share_list.serialize_with_order(order);
+        let is_direct_bytes = self.is_direct.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_NEW_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            render_type_bytes[0],
+            render_type_bytes[1],
+            render_type_bytes[2],
+            render_type_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_NEW_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (render_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (share_list, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(CreateNewContextRequest {
+            context,
+            fbconfig,
+            screen,
+            render_type,
+            share_list,
+            is_direct,
+        })
+    }
+}
+impl Request for CreateNewContextRequest {
+    type Reply = ();
+}
+impl Trace for CreateNewContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("fbconfig", |tracer| tracer.xid(&self.fbconfig))?;
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("render-type", |tracer| tracer.value(&self.render_type))?;
+        tracer.field("share-list", |tracer| tracer.xid(&self.share_list))?;
+        tracer.field("is-direct", |tracer| tracer.value(&self.is_direct))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryContext request
+pub const QUERY_CONTEXT_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryContextRequest {
+    pub context: Context,
+}
+impl QueryContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryContextRequest {
+            context,
+        })
+    }
+}
+impl Request for QueryContextRequest {
+    type Reply = QueryContextReply;
+}
+impl Trace for QueryContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryContextReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub attribs: Vec<u32>,
+}
+impl TryParse for QueryContextReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryContextReply { sequence, length, attribs };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryContextReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryContextReply {
+    /// Get the value of the `num_attribs` field.
+    ///
+    /// The `num_attribs` field is used as the length field of the `attribs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_attribs(&self) -> u32 {
+        self.attribs.len()
+            .checked_div(2).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryContextReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        num_attribs.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.attribs.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the MakeContextCurrent request
+pub const MAKE_CONTEXT_CURRENT_REQUEST: u8 = 26;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MakeContextCurrentRequest {
+    pub old_context_tag: ContextTag,
+    pub drawable: Drawable,
+    pub read_drawable: Drawable,
+    pub context: Context,
+}
+impl MakeContextCurrentRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let old_context_tag_bytes = self.old_context_tag.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let read_drawable_bytes = self.read_drawable.serialize_with_order(order);
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            MAKE_CONTEXT_CURRENT_REQUEST,
+            0,
+            0,
+            old_context_tag_bytes[0],
+            old_context_tag_bytes[1],
+            old_context_tag_bytes[2],
+            old_context_tag_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            read_drawable_bytes[0],
+            read_drawable_bytes[1],
+            read_drawable_bytes[2],
+            read_drawable_bytes[3],
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MAKE_CONTEXT_CURRENT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (old_context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (read_drawable, remaining) = Drawable::try_parse_with_order(remaining, order)?;
+        let (context, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(MakeContextCurrentRequest {
+            old_context_tag,
+            drawable,
+            read_drawable,
+            context,
+        })
+    }
+}
+impl Request for MakeContextCurrentRequest {
+    type Reply = MakeContextCurrentReply;
+}
+impl Trace for MakeContextCurrentRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("old-context-tag", |tracer| tracer.value(&self.old_context_tag))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("read-drawable", |tracer| tracer.xid(&self.read_drawable))?;
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MakeContextCurrentReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub context_tag: ContextTag,
+}
+impl TryParse for MakeContextCurrentReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = MakeContextCurrentReply { sequence, length, context_tag };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for MakeContextCurrentReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for MakeContextCurrentReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+impl Serialize for MakeContextCurrentReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.context_tag.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreatePbuffer request
+pub const CREATE_PBUFFER_REQUEST: u8 = 27;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePbufferRequest<'input> {
+    pub screen: u32,
+    pub fbconfig: Fbconfig,
+    pub pbuffer: Pbuffer,
+    pub attribs: Cow<'input, [u32]>,
+}
+impl<'input> CreatePbufferRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let fbconfig_bytes = self.fbconfig.serialize_with_order(order);
+        let pbuffer_bytes = self.pbuffer.serialize_with_order(order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_PBUFFER_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            pbuffer_bytes[0],
+            pbuffer_bytes[1],
+            pbuffer_bytes[2],
+            pbuffer_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attribs_bytes = self.attribs.serialize_with_order(order);
+        let length_so_far = length_so_far + attribs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_PBUFFER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (pbuffer, remaining) = Pbuffer::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreatePbufferRequest {
+            screen,
+            fbconfig,
+            pbuffer,
+            attribs: Cow::Owned(attribs),
+        })
+    }
+    /// Clone all borrowed data in this CreatePbufferRequest.
+    pub fn into_owned(self) -> CreatePbufferRequest<'static> {
+        CreatePbufferRequest {
+            screen: self.screen,
+            fbconfig: self.fbconfig,
+            pbuffer: self.pbuffer,
+            attribs: Cow::Owned(self.attribs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreatePbufferRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreatePbufferRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("fbconfig", |tracer| tracer.xid(&self.fbconfig))?;
+        tracer.field("pbuffer", |tracer| tracer.xid(&self.pbuffer))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyPbuffer request
+pub const DESTROY_PBUFFER_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyPbufferRequest {
+    pub pbuffer: Pbuffer,
+}
+impl DestroyPbufferRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let pbuffer_bytes = self.pbuffer.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_PBUFFER_REQUEST,
+            0,
+            0,
+            pbuffer_bytes[0],
+            pbuffer_bytes[1],
+            pbuffer_bytes[2],
+            pbuffer_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_PBUFFER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (pbuffer, remaining) = Pbuffer::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyPbufferRequest {
+            pbuffer,
+        })
+    }
+}
+impl Request for DestroyPbufferRequest {
+    type Reply = ();
+}
+impl Trace for DestroyPbufferRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("pbuffer", |tracer| tracer.xid(&self.pbuffer))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetDrawableAttributes request
+pub const GET_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 29;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDrawableAttributesRequest {
+    pub drawable: Drawable,
+}
+impl GetDrawableAttributesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_DRAWABLE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DRAWABLE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = Drawable::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetDrawableAttributesRequest {
+            drawable,
+        })
+    }
+}
+impl Request for GetDrawableAttributesRequest {
+    type Reply = GetDrawableAttributesReply;
+}
+impl Trace for GetDrawableAttributesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetDrawableAttributesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub attribs: Vec<u32>,
+}
+impl TryParse for GetDrawableAttributesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDrawableAttributesReply { sequence, length, attribs };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetDrawableAttributesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetDrawableAttributesReply {
+    /// Get the value of the `num_attribs` field.
+    ///
+    /// The `num_attribs` field is used as the length field of the `attribs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_attribs(&self) -> u32 {
+        self.attribs.len()
+            .checked_div(2).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetDrawableAttributesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetDrawableAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        num_attribs.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.attribs.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ChangeDrawableAttributes request
+pub const CHANGE_DRAWABLE_ATTRIBUTES_REQUEST: u8 = 30;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeDrawableAttributesRequest<'input> {
+    pub drawable: Drawable,
+    pub attribs: Cow<'input, [u32]>,
+}
+impl<'input> ChangeDrawableAttributesRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_DRAWABLE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attribs_bytes = self.attribs.serialize_with_order(order);
+        let length_so_far = length_so_far + attribs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_DRAWABLE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = Drawable::try_parse_with_order(value, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(ChangeDrawableAttributesRequest {
+            drawable,
+            attribs: Cow::Owned(attribs),
+        })
+    }
+    /// Clone all borrowed data in this ChangeDrawableAttributesRequest.
+    pub fn into_owned(self) -> ChangeDrawableAttributesRequest<'static> {
+        ChangeDrawableAttributesRequest {
+            drawable: self.drawable,
+            attribs: Cow::Owned(self.attribs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ChangeDrawableAttributesRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ChangeDrawableAttributesRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateWindow request
+pub const CREATE_WINDOW_REQUEST: u8 = 31;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateWindowRequest<'input> {
+    pub screen: u32,
+    pub fbconfig: Fbconfig,
+    pub window: xproto::Window,
+    pub glx_window: Window,
+    pub attribs: Cow<'input, [u32]>,
+}
+impl<'input> CreateWindowRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let fbconfig_bytes = self.fbconfig.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let glx_window_bytes = self.glx_window.serialize_with_order(order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_WINDOW_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            glx_window_bytes[0],
+            glx_window_bytes[1],
+            glx_window_bytes[2],
+            glx_window_bytes[3],
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attribs_bytes = self.attribs.serialize_with_order(order);
+        let length_so_far = length_so_far + attribs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (glx_window, remaining) = Window::try_parse_with_order(remaining, order)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreateWindowRequest {
+            screen,
+            fbconfig,
+            window,
+            glx_window,
+            attribs: Cow::Owned(attribs),
+        })
+    }
+    /// Clone all borrowed data in this CreateWindowRequest.
+    pub fn into_owned(self) -> CreateWindowRequest<'static> {
+        CreateWindowRequest {
+            screen: self.screen,
+            fbconfig: self.fbconfig,
+            window: self.window,
+            glx_window: self.glx_window,
+            attribs: Cow::Owned(self.attribs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateWindowRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreateWindowRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("fbconfig", |tracer| tracer.xid(&self.fbconfig))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("glx-window", |tracer| tracer.xid(&self.glx_window))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteWindow request
+pub const DELETE_WINDOW_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteWindowRequest {
+    pub glxwindow: Window,
+}
+impl DeleteWindowRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let glxwindow_bytes = self.glxwindow.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_WINDOW_REQUEST,
+            0,
+            0,
+            glxwindow_bytes[0],
+            glxwindow_bytes[1],
+            glxwindow_bytes[2],
+            glxwindow_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (glxwindow, remaining) = Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DeleteWindowRequest {
+            glxwindow,
+        })
+    }
+}
+impl Request for DeleteWindowRequest {
+    type Reply = ();
+}
+impl Trace for DeleteWindowRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("glxwindow", |tracer| tracer.xid(&self.glxwindow))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetClientInfoARB request
+pub const SET_CLIENT_INFO_ARB_REQUEST: u8 = 33;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetClientInfoARBRequest<'input> {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub gl_versions: Cow<'input, [u32]>,
+    pub gl_extension_string: Cow<'input, [u8]>,
+    pub glx_extension_string: Cow<'input, [u8]>,
+}
+impl<'input> SetClientInfoARBRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        assert_eq!(self.gl_versions.len() % 2, 0, "`gl_versions` has an incorrect length, must be a multiple of 2");
+        let num_versions = u32::try_from(self.gl_versions.len() / 2).expect("`gl_versions` has too many elements");
+        let num_versions_bytes = num_versions.serialize_with_order(order);
+        let gl_str_len = u32::try_from(self.gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
+        let gl_str_len_bytes = gl_str_len.serialize_with_order(order);
+        let glx_str_len = u32::try_from(self.glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
+        let glx_str_len_bytes = glx_str_len.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CLIENT_INFO_ARB_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            num_versions_bytes[0],
+            num_versions_bytes[1],
+            num_versions_bytes[2],
+            num_versions_bytes[3],
+            gl_str_len_bytes[0],
+            gl_str_len_bytes[1],
+            gl_str_len_bytes[2],
+            gl_str_len_bytes[3],
+            glx_str_len_bytes[0],
+            glx_str_len_bytes[1],
+            glx_str_len_bytes[2],
+            glx_str_len_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let gl_versions_bytes = self.gl_versions.serialize_with_order(order);
+        let length_so_far = length_so_far + gl_versions_bytes.len();
+        let length_so_far = length_so_far + self.gl_extension_string.len();
+        let length_so_far = length_so_far + self.glx_extension_string.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), gl_versions_bytes.into(), self.gl_extension_string, self.glx_extension_string, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CLIENT_INFO_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_versions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (glx_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_versions, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_versions.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (gl_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let (glx_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(SetClientInfoARBRequest {
+            major_version,
+            minor_version,
+            gl_versions: Cow::Owned(gl_versions),
+            gl_extension_string: Cow::Borrowed(gl_extension_string),
+            glx_extension_string: Cow::Borrowed(glx_extension_string),
+        })
+    }
+    /// Clone all borrowed data in this SetClientInfoARBRequest.
+    pub fn into_owned(self) -> SetClientInfoARBRequest<'static> {
+        SetClientInfoARBRequest {
+            major_version: self.major_version,
+            minor_version: self.minor_version,
+            gl_versions: Cow::Owned(self.gl_versions.into_owned()),
+            gl_extension_string: Cow::Owned(self.gl_extension_string.into_owned()),
+            glx_extension_string: Cow::Owned(self.glx_extension_string.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetClientInfoARBRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetClientInfoARBRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        tracer.field("gl-versions", |tracer| tracer.list(&self.gl_versions[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("gl-extension-string", |tracer| tracer.string(&self.gl_extension_string[..]))?;
+        tracer.field("glx-extension-string", |tracer| tracer.string(&self.glx_extension_string[..]))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateContextAttribsARB request
+pub const CREATE_CONTEXT_ATTRIBS_ARB_REQUEST: u8 = 34;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateContextAttribsARBRequest<'input> {
+    pub context: Context,
+    pub fbconfig: Fbconfig,
+    pub screen: u32,
+    pub share_list: Context,
+    pub is_direct: bool,
+    pub attribs: Cow<'input, [u32]>,
+}
+impl<'input> CreateContextAttribsARBRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let fbconfig_bytes = self.fbconfig.serialize_with_order(order);
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let share_list_bytes = self.share_list.serialize_with_order(order);
+        let is_direct_bytes = self.is_direct.serialize_with_order(order);
+        assert_eq!(self.attribs.len() % 2, 0, "`attribs` has an incorrect length, must be a multiple of 2");
+        let num_attribs = u32::try_from(self.attribs.len() / 2).expect("`attribs` has too many elements");
+        let num_attribs_bytes = num_attribs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_CONTEXT_ATTRIBS_ARB_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            fbconfig_bytes[0],
+            fbconfig_bytes[1],
+            fbconfig_bytes[2],
+            fbconfig_bytes[3],
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            share_list_bytes[0],
+            share_list_bytes[1],
+            share_list_bytes[2],
+            share_list_bytes[3],
+            is_direct_bytes[0],
+            0,
+            0,
+            0,
+            num_attribs_bytes[0],
+            num_attribs_bytes[1],
+            num_attribs_bytes[2],
+            num_attribs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let attribs_bytes = self.attribs.serialize_with_order(order);
+        let length_so_far = length_so_far + attribs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), attribs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_ATTRIBS_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let (fbconfig, remaining) = Fbconfig::try_parse_with_order(remaining, order)?;
+        let (screen, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (share_list, remaining) = Context::try_parse_with_order(remaining, order)?;
+        let (is_direct, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (num_attribs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (attribs, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_attribs.checked_mul(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreateContextAttribsARBRequest {
+            context,
+            fbconfig,
+            screen,
+            share_list,
+            is_direct,
+            attribs: Cow::Owned(attribs),
+        })
+    }
+    /// Clone all borrowed data in this CreateContextAttribsARBRequest.
+    pub fn into_owned(self) -> CreateContextAttribsARBRequest<'static> {
+        CreateContextAttribsARBRequest {
+            context: self.context,
+            fbconfig: self.fbconfig,
+            screen: self.screen,
+            share_list: self.share_list,
+            is_direct: self.is_direct,
+            attribs: Cow::Owned(self.attribs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateContextAttribsARBRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreateContextAttribsARBRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("fbconfig", |tracer| tracer.xid(&self.fbconfig))?;
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("share-list", |tracer| tracer.xid(&self.share_list))?;
+        tracer.field("is-direct", |tracer| tracer.value(&self.is_direct))?;
+        tracer.field("attribs", |tracer| tracer.list(&self.attribs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetClientInfo2ARB request
+pub const SET_CLIENT_INFO2_ARB_REQUEST: u8 = 35;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetClientInfo2ARBRequest<'input> {
+    pub major_version: u32,
+    pub minor_version: u32,
+    pub gl_versions: Cow<'input, [u32]>,
+    pub gl_extension_string: Cow<'input, [u8]>,
+    pub glx_extension_string: Cow<'input, [u8]>,
+}
+impl<'input> SetClientInfo2ARBRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        assert_eq!(self.gl_versions.len() % 3, 0, "`gl_versions` has an incorrect length, must be a multiple of 3");
+        let num_versions = u32::try_from(self.gl_versions.len() / 3).expect("`gl_versions` has too many elements");
+        let num_versions_bytes = num_versions.serialize_with_order(order);
+        let gl_str_len = u32::try_from(self.gl_extension_string.len()).expect("`gl_extension_string` has too many elements");
+        let gl_str_len_bytes = gl_str_len.serialize_with_order(order);
+        let glx_str_len = u32::try_from(self.glx_extension_string.len()).expect("`glx_extension_string` has too many elements");
+        let glx_str_len_bytes = glx_str_len.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CLIENT_INFO2_ARB_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+            num_versions_bytes[0],
+            num_versions_bytes[1],
+            num_versions_bytes[2],
+            num_versions_bytes[3],
+            gl_str_len_bytes[0],
+            gl_str_len_bytes[1],
+            gl_str_len_bytes[2],
+            gl_str_len_bytes[3],
+            glx_str_len_bytes[0],
+            glx_str_len_bytes[1],
+            glx_str_len_bytes[2],
+            glx_str_len_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let gl_versions_bytes = self.gl_versions.serialize_with_order(order);
+        let length_so_far = length_so_far + gl_versions_bytes.len();
+        let length_so_far = length_so_far + self.gl_extension_string.len();
+        let length_so_far = length_so_far + self.glx_extension_string.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), gl_versions_bytes.into(), self.gl_extension_string, self.glx_extension_string, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CLIENT_INFO2_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_versions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (glx_str_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (gl_versions, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_versions.checked_mul(3u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (gl_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, gl_str_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let (glx_extension_string, remaining) = crate::x11_utils::parse_u8_list(remaining, glx_str_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(SetClientInfo2ARBRequest {
+            major_version,
+            minor_version,
+            gl_versions: Cow::Owned(gl_versions),
+            gl_extension_string: Cow::Borrowed(gl_extension_string),
+            glx_extension_string: Cow::Borrowed(glx_extension_string),
+        })
+    }
+    /// Clone all borrowed data in this SetClientInfo2ARBRequest.
+    pub fn into_owned(self) -> SetClientInfo2ARBRequest<'static> {
+        SetClientInfo2ARBRequest {
+            major_version: self.major_version,
+            minor_version: self.minor_version,
+            gl_versions: Cow::Owned(self.gl_versions.into_owned()),
+            gl_extension_string: Cow::Owned(self.gl_extension_string.into_owned()),
+            glx_extension_string: Cow::Owned(self.glx_extension_string.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetClientInfo2ARBRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetClientInfo2ARBRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        tracer.field("gl-versions", |tracer| tracer.list(&self.gl_versions[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("gl-extension-string", |tracer| tracer.string(&self.gl_extension_string[..]))?;
+        tracer.field("glx-extension-string", |tracer| tracer.string(&self.glx_extension_string[..]))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the NewList request
+pub const NEW_LIST_REQUEST: u8 = 101;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewListRequest {
+    pub context_tag: ContextTag,
+    pub list: u32,
+    pub mode: u32,
+}
+impl NewListRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let list_bytes = self.list.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            NEW_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != NEW_LIST_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (list, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(NewListRequest {
+            context_tag,
+            list,
+            mode,
+        })
+    }
+}
+impl Request for NewListRequest {
+    type Reply = ();
+}
+impl Trace for NewListRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("list", |tracer| tracer.value(&self.list))?;
+        tracer.field("mode", |tracer| tracer.value(&self.mode))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the EndList request
+pub const END_LIST_REQUEST: u8 = 102;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndListRequest {
+    pub context_tag: ContextTag,
+}
+impl EndListRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            END_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != END_LIST_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(EndListRequest {
+            context_tag,
+        })
+    }
+}
+impl Request for EndListRequest {
+    type Reply = ();
+}
+impl Trace for EndListRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteLists request
+pub const DELETE_LISTS_REQUEST: u8 = 103;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteListsRequest {
+    pub context_tag: ContextTag,
+    pub list: u32,
+    pub range: i32,
+}
+impl DeleteListsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let list_bytes = self.list.serialize_with_order(order);
+        let range_bytes = self.range.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_LISTS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+            range_bytes[0],
+            range_bytes[1],
+            range_bytes[2],
+            range_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_LISTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (list, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(DeleteListsRequest {
+            context_tag,
+            list,
+            range,
+        })
+    }
+}
+impl Request for DeleteListsRequest {
+    type Reply = ();
+}
+impl Trace for DeleteListsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("list", |tracer| tracer.value(&self.list))?;
+        tracer.field("range", |tracer| tracer.value(&self.range))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GenLists request
+pub const GEN_LISTS_REQUEST: u8 = 104;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenListsRequest {
+    pub context_tag: ContextTag,
+    pub range: i32,
+}
+impl GenListsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let range_bytes = self.range.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GEN_LISTS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            range_bytes[0],
+            range_bytes[1],
+            range_bytes[2],
+            range_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GEN_LISTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (range, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GenListsRequest {
+            context_tag,
+            range,
+        })
+    }
+}
+impl Request for GenListsRequest {
+    type Reply = GenListsReply;
+}
+impl Trace for GenListsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("range", |tracer| tracer.value(&self.range))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenListsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: u32,
+}
+impl TryParse for GenListsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GenListsReply { sequence, length, ret_val };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GenListsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GenListsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        Ok(())
+    }
+}
+impl Serialize for GenListsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the FeedbackBuffer request
+pub const FEEDBACK_BUFFER_REQUEST: u8 = 105;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeedbackBufferRequest {
+    pub context_tag: ContextTag,
+    pub size: i32,
+    pub type_: i32,
+}
+impl FeedbackBufferRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let size_bytes = self.size.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FEEDBACK_BUFFER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FEEDBACK_BUFFER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (size, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(FeedbackBufferRequest {
+            context_tag,
+            size,
+            type_,
+        })
+    }
+}
+impl Request for FeedbackBufferRequest {
+    type Reply = ();
+}
+impl Trace for FeedbackBufferRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SelectBuffer request
+pub const SELECT_BUFFER_REQUEST: u8 = 106;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectBufferRequest {
+    pub context_tag: ContextTag,
+    pub size: i32,
+}
+impl SelectBufferRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let size_bytes = self.size.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_BUFFER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            size_bytes[2],
+            size_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_BUFFER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (size, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SelectBufferRequest {
+            context_tag,
+            size,
+        })
+    }
+}
+impl Request for SelectBufferRequest {
+    type Reply = ();
+}
+impl Trace for SelectBufferRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the RenderMode request
+pub const RENDER_MODE_REQUEST: u8 = 107;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderModeRequest {
+    pub context_tag: ContextTag,
+    pub mode: u32,
+}
+impl RenderModeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            RENDER_MODE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RENDER_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (mode, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(RenderModeRequest {
+            context_tag,
+            mode,
+        })
+    }
+}
+impl Request for RenderModeRequest {
+    type Reply = RenderModeReply;
+}
+impl Trace for RenderModeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("mode", |tracer| tracer.value(&self.mode))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderModeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: u32,
+    pub new_mode: u32,
+    pub data: Vec<u32>,
+}
+impl TryParse for RenderModeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (new_mode, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = RenderModeReply { sequence, length, ret_val, new_mode, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for RenderModeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl RenderModeReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for RenderModeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        tracer.field("new-mode", |tracer| tracer.value(&self.new_mode))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for RenderModeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.new_mode.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RM(u16);
+impl RM {
+    pub const GL_RENDER: Self = Self(7168);
+    pub const GL_FEEDBACK: Self = Self(7169);
+    pub const GL_SELECT: Self = Self(7170);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (7168, "GL_RENDER", "GL_RENDER"),
+        (7169, "GL_FEEDBACK", "GL_FEEDBACK"),
+        (7170, "GL_SELECT", "GL_SELECT"),
+    ];
+}
+impl From<RM> for u16 {
+    #[inline]
+    fn from(input: RM) -> Self {
+        input.0
+    }
+}
+impl From<RM> for Option<u16> {
+    #[inline]
+    fn from(input: RM) -> Self {
+        Some(input.0)
+    }
+}
+impl From<RM> for u32 {
+    #[inline]
+    fn from(input: RM) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<RM> for Option<u32> {
+    #[inline]
+    fn from(input: RM) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for RM {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for RM {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for RM  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for RM {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for RM {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RM {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the Finish request
+pub const FINISH_REQUEST: u8 = 108;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinishRequest {
+    pub context_tag: ContextTag,
+}
+impl FinishRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FINISH_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FINISH_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(FinishRequest {
+            context_tag,
+        })
+    }
+}
+impl Request for FinishRequest {
+    type Reply = FinishReply;
+}
+impl Trace for FinishRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinishReply {
+    pub sequence: u16,
+    pub length: u32,
+}
+impl TryParse for FinishReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = FinishReply { sequence, length };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for FinishReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for FinishReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        Ok(())
+    }
+}
+impl Serialize for FinishReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the PixelStoref request
+pub const PIXEL_STOREF_REQUEST: u8 = 109;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PixelStorefRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+    pub datum: Float32,
+}
+impl PixelStorefRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let datum_bytes = self.datum.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PIXEL_STOREF_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+            datum_bytes[0],
+            datum_bytes[1],
+            datum_bytes[2],
+            datum_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXEL_STOREF_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(PixelStorefRequest {
+            context_tag,
+            pname,
+            datum,
+        })
+    }
+}
+impl Request for PixelStorefRequest {
+    type Reply = ();
+}
+impl Trace for PixelStorefRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the PixelStorei request
+pub const PIXEL_STOREI_REQUEST: u8 = 110;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelStoreiRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+    pub datum: i32,
+}
+impl PixelStoreiRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let datum_bytes = self.datum.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PIXEL_STOREI_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+            datum_bytes[0],
+            datum_bytes[1],
+            datum_bytes[2],
+            datum_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXEL_STOREI_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(PixelStoreiRequest {
+            context_tag,
+            pname,
+            datum,
+        })
+    }
+}
+impl Request for PixelStoreiRequest {
+    type Reply = ();
+}
+impl Trace for PixelStoreiRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ReadPixels request
+pub const READ_PIXELS_REQUEST: u8 = 111;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadPixelsRequest {
+    pub context_tag: ContextTag,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub lsb_first: bool,
+}
+impl ReadPixelsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let lsb_first_bytes = self.lsb_first.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            READ_PIXELS_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            x_bytes[2],
+            x_bytes[3],
+            y_bytes[0],
+            y_bytes[1],
+            y_bytes[2],
+            y_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            width_bytes[2],
+            width_bytes[3],
+            height_bytes[0],
+            height_bytes[1],
+            height_bytes[2],
+            height_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            lsb_first_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != READ_PIXELS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (x, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (lsb_first, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(ReadPixelsRequest {
+            context_tag,
+            x,
+            y,
+            width,
+            height,
+            format,
+            type_,
+            swap_bytes,
+            lsb_first,
+        })
+    }
+}
+impl Request for ReadPixelsRequest {
+    type Reply = ReadPixelsReply;
+}
+impl Trace for ReadPixelsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        tracer.field("lsb-first", |tracer| tracer.value(&self.lsb_first))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReadPixelsReply {
+    pub sequence: u16,
+    pub data: Vec<u8>,
+}
+impl TryParse for ReadPixelsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ReadPixelsReply { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ReadPixelsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ReadPixelsReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ReadPixelsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for ReadPixelsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetBooleanv request
+pub const GET_BOOLEANV_REQUEST: u8 = 112;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetBooleanvRequest {
+    pub context_tag: ContextTag,
+    pub pname: i32,
+}
+impl GetBooleanvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_BOOLEANV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_BOOLEANV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (pname, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetBooleanvRequest {
+            context_tag,
+            pname,
+        })
+    }
+}
+impl Request for GetBooleanvRequest {
+    type Reply = GetBooleanvReply;
+}
+impl Trace for GetBooleanvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetBooleanvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: bool,
+    pub data: Vec<bool>,
+}
+impl TryParse for GetBooleanvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<bool>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetBooleanvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetBooleanvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetBooleanvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetBooleanvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetBooleanvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetClipPlane request
+pub const GET_CLIP_PLANE_REQUEST: u8 = 113;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetClipPlaneRequest {
+    pub context_tag: ContextTag,
+    pub plane: i32,
+}
+impl GetClipPlaneRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let plane_bytes = self.plane.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CLIP_PLANE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            plane_bytes[0],
+            plane_bytes[1],
+            plane_bytes[2],
+            plane_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CLIP_PLANE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (plane, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetClipPlaneRequest {
+            context_tag,
+            plane,
+        })
+    }
+}
+impl Request for GetClipPlaneRequest {
+    type Reply = GetClipPlaneReply;
+}
+impl Trace for GetClipPlaneRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("plane", |tracer| tracer.value(&self.plane))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetClipPlaneReply {
+    pub sequence: u16,
+    pub data: Vec<Float64>,
+}
+impl TryParse for GetClipPlaneReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, length.checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetClipPlaneReply { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetClipPlaneReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetClipPlaneReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_mul(2).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetClipPlaneReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetClipPlaneReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.data.len()).ok().and_then(|len| len.checked_mul(2)).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetDoublev request
+pub const GET_DOUBLEV_REQUEST: u8 = 114;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDoublevRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+}
+impl GetDoublevRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_DOUBLEV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DOUBLEV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetDoublevRequest {
+            context_tag,
+            pname,
+        })
+    }
+}
+impl Request for GetDoublevRequest {
+    type Reply = GetDoublevReply;
+}
+impl Trace for GetDoublevRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetDoublevReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float64,
+    pub data: Vec<Float64>,
+}
+impl TryParse for GetDoublevReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float64::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDoublevReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetDoublevReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetDoublevReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetDoublevReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetDoublevReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetError request
+pub const GET_ERROR_REQUEST: u8 = 115;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetErrorRequest {
+    pub context_tag: ContextTag,
+}
+impl GetErrorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_ERROR_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_ERROR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetErrorRequest {
+            context_tag,
+        })
+    }
+}
+impl Request for GetErrorRequest {
+    type Reply = GetErrorReply;
+}
+impl Trace for GetErrorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetErrorReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub error: i32,
+}
+impl TryParse for GetErrorReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (error, remaining) = i32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetErrorReply { sequence, length, error };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetErrorReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetErrorReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("error", |tracer| tracer.value(&self.error))?;
+        Ok(())
+    }
+}
+impl Serialize for GetErrorReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.error.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetFloatv request
+pub const GET_FLOATV_REQUEST: u8 = 116;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetFloatvRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+}
+impl GetFloatvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_FLOATV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_FLOATV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetFloatvRequest {
+            context_tag,
+            pname,
+        })
+    }
+}
+impl Request for GetFloatvRequest {
+    type Reply = GetFloatvReply;
+}
+impl Trace for GetFloatvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetFloatvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetFloatvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetFloatvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetFloatvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetFloatvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetFloatvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetFloatvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetIntegerv request
+pub const GET_INTEGERV_REQUEST: u8 = 117;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetIntegervRequest {
+    pub context_tag: ContextTag,
+    pub pname: u32,
+}
+impl GetIntegervRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_INTEGERV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_INTEGERV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetIntegervRequest {
+            context_tag,
+            pname,
+        })
+    }
+}
+impl Request for GetIntegervRequest {
+    type Reply = GetIntegervReply;
+}
+impl Trace for GetIntegervRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetIntegervReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetIntegervReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetIntegervReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetIntegervReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetIntegervReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetIntegervReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetIntegervReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetLightfv request
+pub const GET_LIGHTFV_REQUEST: u8 = 118;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetLightfvRequest {
+    pub context_tag: ContextTag,
+    pub light: u32,
+    pub pname: u32,
+}
+impl GetLightfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let light_bytes = self.light.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_LIGHTFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            light_bytes[0],
+            light_bytes[1],
+            light_bytes[2],
+            light_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_LIGHTFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (light, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetLightfvRequest {
+            context_tag,
+            light,
+            pname,
+        })
+    }
+}
+impl Request for GetLightfvRequest {
+    type Reply = GetLightfvReply;
+}
+impl Trace for GetLightfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("light", |tracer| tracer.value(&self.light))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetLightfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetLightfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetLightfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetLightfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetLightfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetLightfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetLightfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetLightiv request
+pub const GET_LIGHTIV_REQUEST: u8 = 119;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetLightivRequest {
+    pub context_tag: ContextTag,
+    pub light: u32,
+    pub pname: u32,
+}
+impl GetLightivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let light_bytes = self.light.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_LIGHTIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            light_bytes[0],
+            light_bytes[1],
+            light_bytes[2],
+            light_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_LIGHTIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (light, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetLightivRequest {
+            context_tag,
+            light,
+            pname,
+        })
+    }
+}
+impl Request for GetLightivRequest {
+    type Reply = GetLightivReply;
+}
+impl Trace for GetLightivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("light", |tracer| tracer.value(&self.light))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetLightivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetLightivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetLightivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetLightivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetLightivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetLightivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetLightivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMapdv request
+pub const GET_MAPDV_REQUEST: u8 = 120;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMapdvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+impl GetMapdvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let query_bytes = self.query.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MAPDV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAPDV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (query, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMapdvRequest {
+            context_tag,
+            target,
+            query,
+        })
+    }
+}
+impl Request for GetMapdvRequest {
+    type Reply = GetMapdvReply;
+}
+impl Trace for GetMapdvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("query", |tracer| tracer.value(&self.query))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMapdvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float64,
+    pub data: Vec<Float64>,
+}
+impl TryParse for GetMapdvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float64::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapdvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMapdvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMapdvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMapdvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMapdvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMapfv request
+pub const GET_MAPFV_REQUEST: u8 = 121;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMapfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+impl GetMapfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let query_bytes = self.query.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MAPFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAPFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (query, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMapfvRequest {
+            context_tag,
+            target,
+            query,
+        })
+    }
+}
+impl Request for GetMapfvRequest {
+    type Reply = GetMapfvReply;
+}
+impl Trace for GetMapfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("query", |tracer| tracer.value(&self.query))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMapfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetMapfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMapfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMapfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMapfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMapfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMapiv request
+pub const GET_MAPIV_REQUEST: u8 = 122;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMapivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub query: u32,
+}
+impl GetMapivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let query_bytes = self.query.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MAPIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            query_bytes[0],
+            query_bytes[1],
+            query_bytes[2],
+            query_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAPIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (query, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMapivRequest {
+            context_tag,
+            target,
+            query,
+        })
+    }
+}
+impl Request for GetMapivRequest {
+    type Reply = GetMapivReply;
+}
+impl Trace for GetMapivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("query", |tracer| tracer.value(&self.query))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMapivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetMapivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMapivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMapivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMapivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMapivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMaterialfv request
+pub const GET_MATERIALFV_REQUEST: u8 = 123;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMaterialfvRequest {
+    pub context_tag: ContextTag,
+    pub face: u32,
+    pub pname: u32,
+}
+impl GetMaterialfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let face_bytes = self.face.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MATERIALFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            face_bytes[0],
+            face_bytes[1],
+            face_bytes[2],
+            face_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MATERIALFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (face, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMaterialfvRequest {
+            context_tag,
+            face,
+            pname,
+        })
+    }
+}
+impl Request for GetMaterialfvRequest {
+    type Reply = GetMaterialfvReply;
+}
+impl Trace for GetMaterialfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("face", |tracer| tracer.value(&self.face))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMaterialfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetMaterialfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMaterialfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMaterialfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMaterialfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMaterialfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMaterialfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMaterialiv request
+pub const GET_MATERIALIV_REQUEST: u8 = 124;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMaterialivRequest {
+    pub context_tag: ContextTag,
+    pub face: u32,
+    pub pname: u32,
+}
+impl GetMaterialivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let face_bytes = self.face.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MATERIALIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            face_bytes[0],
+            face_bytes[1],
+            face_bytes[2],
+            face_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MATERIALIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (face, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMaterialivRequest {
+            context_tag,
+            face,
+            pname,
+        })
+    }
+}
+impl Request for GetMaterialivRequest {
+    type Reply = GetMaterialivReply;
+}
+impl Trace for GetMaterialivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("face", |tracer| tracer.value(&self.face))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMaterialivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetMaterialivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMaterialivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMaterialivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMaterialivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMaterialivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMaterialivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetPixelMapfv request
+pub const GET_PIXEL_MAPFV_REQUEST: u8 = 125;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPixelMapfvRequest {
+    pub context_tag: ContextTag,
+    pub map: u32,
+}
+impl GetPixelMapfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let map_bytes = self.map.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PIXEL_MAPFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PIXEL_MAPFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetPixelMapfvRequest {
+            context_tag,
+            map,
+        })
+    }
+}
+impl Request for GetPixelMapfvRequest {
+    type Reply = GetPixelMapfvReply;
+}
+impl Trace for GetPixelMapfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("map", |tracer| tracer.value(&self.map))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPixelMapfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetPixelMapfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPixelMapfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPixelMapfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetPixelMapfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetPixelMapfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPixelMapfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetPixelMapuiv request
+pub const GET_PIXEL_MAPUIV_REQUEST: u8 = 126;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPixelMapuivRequest {
+    pub context_tag: ContextTag,
+    pub map: u32,
+}
+impl GetPixelMapuivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let map_bytes = self.map.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PIXEL_MAPUIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PIXEL_MAPUIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetPixelMapuivRequest {
+            context_tag,
+            map,
+        })
+    }
+}
+impl Request for GetPixelMapuivRequest {
+    type Reply = GetPixelMapuivReply;
+}
+impl Trace for GetPixelMapuivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("map", |tracer| tracer.value(&self.map))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPixelMapuivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: u32,
+    pub data: Vec<u32>,
+}
+impl TryParse for GetPixelMapuivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPixelMapuivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPixelMapuivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetPixelMapuivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetPixelMapuivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPixelMapuivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetPixelMapusv request
+pub const GET_PIXEL_MAPUSV_REQUEST: u8 = 127;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPixelMapusvRequest {
+    pub context_tag: ContextTag,
+    pub map: u32,
+}
+impl GetPixelMapusvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let map_bytes = self.map.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PIXEL_MAPUSV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            map_bytes[0],
+            map_bytes[1],
+            map_bytes[2],
+            map_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PIXEL_MAPUSV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetPixelMapusvRequest {
+            context_tag,
+            map,
+        })
+    }
+}
+impl Request for GetPixelMapusvRequest {
+    type Reply = GetPixelMapusvReply;
+}
+impl Trace for GetPixelMapusvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("map", |tracer| tracer.value(&self.map))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPixelMapusvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: u16,
+    pub data: Vec<u16>,
+}
+impl TryParse for GetPixelMapusvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPixelMapusvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPixelMapusvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetPixelMapusvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetPixelMapusvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPixelMapusvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetPolygonStipple request
+pub const GET_POLYGON_STIPPLE_REQUEST: u8 = 128;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPolygonStippleRequest {
+    pub context_tag: ContextTag,
+    pub lsb_first: bool,
+}
+impl GetPolygonStippleRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let lsb_first_bytes = self.lsb_first.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_POLYGON_STIPPLE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            lsb_first_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_POLYGON_STIPPLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (lsb_first, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetPolygonStippleRequest {
+            context_tag,
+            lsb_first,
+        })
+    }
+}
+impl Request for GetPolygonStippleRequest {
+    type Reply = GetPolygonStippleReply;
+}
+impl Trace for GetPolygonStippleRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("lsb-first", |tracer| tracer.value(&self.lsb_first))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPolygonStippleReply {
+    pub sequence: u16,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetPolygonStippleReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPolygonStippleReply { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPolygonStippleReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetPolygonStippleReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetPolygonStippleReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPolygonStippleReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetString request
+pub const GET_STRING_REQUEST: u8 = 129;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetStringRequest {
+    pub context_tag: ContextTag,
+    pub name: u32,
+}
+impl GetStringRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_STRING_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_STRING_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (name, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetStringRequest {
+            context_tag,
+            name,
+        })
+    }
+}
+impl Request for GetStringRequest {
+    type Reply = GetStringReply;
+}
+impl Trace for GetStringRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("name", |tracer| tracer.value(&self.name))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetStringReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub string: Vec<u8>,
+}
+impl TryParse for GetStringReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let string = string.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetStringReply { sequence, length, string };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetStringReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetStringReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `string` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.string.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetStringReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("string", |tracer| tracer.string(&self.string))?;
+        Ok(())
+    }
+}
+impl Serialize for GetStringReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.string.len()).expect("`string` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        bytes.extend_from_slice(&self.string);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexEnvfv request
+pub const GET_TEX_ENVFV_REQUEST: u8 = 130;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexEnvfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexEnvfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_ENVFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_ENVFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexEnvfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetTexEnvfvRequest {
+    type Reply = GetTexEnvfvReply;
+}
+impl Trace for GetTexEnvfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexEnvfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetTexEnvfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexEnvfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexEnvfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexEnvfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexEnvfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexEnvfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexEnviv request
+pub const GET_TEX_ENVIV_REQUEST: u8 = 131;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexEnvivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexEnvivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_ENVIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_ENVIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexEnvivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetTexEnvivRequest {
+    type Reply = GetTexEnvivReply;
+}
+impl Trace for GetTexEnvivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexEnvivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetTexEnvivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexEnvivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexEnvivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexEnvivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexEnvivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexEnvivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexGendv request
+pub const GET_TEX_GENDV_REQUEST: u8 = 132;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexGendvRequest {
+    pub context_tag: ContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+impl GetTexGendvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let coord_bytes = self.coord.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_GENDV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_GENDV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (coord, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexGendvRequest {
+            context_tag,
+            coord,
+            pname,
+        })
+    }
+}
+impl Request for GetTexGendvRequest {
+    type Reply = GetTexGendvReply;
+}
+impl Trace for GetTexGendvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("coord", |tracer| tracer.value(&self.coord))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexGendvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float64,
+    pub data: Vec<Float64>,
+}
+impl TryParse for GetTexGendvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float64::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float64>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexGendvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexGendvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexGendvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexGendvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexGendvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexGenfv request
+pub const GET_TEX_GENFV_REQUEST: u8 = 133;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexGenfvRequest {
+    pub context_tag: ContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+impl GetTexGenfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let coord_bytes = self.coord.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_GENFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_GENFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (coord, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexGenfvRequest {
+            context_tag,
+            coord,
+            pname,
+        })
+    }
+}
+impl Request for GetTexGenfvRequest {
+    type Reply = GetTexGenfvReply;
+}
+impl Trace for GetTexGenfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("coord", |tracer| tracer.value(&self.coord))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexGenfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetTexGenfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexGenfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexGenfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexGenfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexGenfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexGenfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexGeniv request
+pub const GET_TEX_GENIV_REQUEST: u8 = 134;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexGenivRequest {
+    pub context_tag: ContextTag,
+    pub coord: u32,
+    pub pname: u32,
+}
+impl GetTexGenivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let coord_bytes = self.coord.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_GENIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            coord_bytes[0],
+            coord_bytes[1],
+            coord_bytes[2],
+            coord_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_GENIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (coord, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexGenivRequest {
+            context_tag,
+            coord,
+            pname,
+        })
+    }
+}
+impl Request for GetTexGenivRequest {
+    type Reply = GetTexGenivReply;
+}
+impl Trace for GetTexGenivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("coord", |tracer| tracer.value(&self.coord))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexGenivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetTexGenivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexGenivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexGenivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexGenivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexGenivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexGenivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexImage request
+pub const GET_TEX_IMAGE_REQUEST: u8 = 135;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexImageRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetTexImageRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let level_bytes = self.level.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_IMAGE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_IMAGE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexImageRequest {
+            context_tag,
+            target,
+            level,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+}
+impl Request for GetTexImageRequest {
+    type Reply = GetTexImageReply;
+}
+impl Trace for GetTexImageRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("level", |tracer| tracer.value(&self.level))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexImageReply {
+    pub sequence: u16,
+    pub width: i32,
+    pub height: i32,
+    pub depth: i32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetTexImageReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexImageReply { sequence, width, height, depth, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexImageReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexImageReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexImageReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexImageReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexParameterfv request
+pub const GET_TEX_PARAMETERFV_REQUEST: u8 = 136;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexParameterfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_PARAMETERFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetTexParameterfvRequest {
+    type Reply = GetTexParameterfvReply;
+}
+impl Trace for GetTexParameterfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexParameterfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetTexParameterfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexParameterfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexParameterfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexParameterfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexParameteriv request
+pub const GET_TEX_PARAMETERIV_REQUEST: u8 = 137;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetTexParameterivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_PARAMETERIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetTexParameterivRequest {
+    type Reply = GetTexParameterivReply;
+}
+impl Trace for GetTexParameterivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexParameterivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetTexParameterivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexParameterivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexParameterivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexParameterivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexParameterivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexLevelParameterfv request
+pub const GET_TEX_LEVEL_PARAMETERFV_REQUEST: u8 = 138;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexLevelParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub pname: u32,
+}
+impl GetTexLevelParameterfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let level_bytes = self.level.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_LEVEL_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_LEVEL_PARAMETERFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexLevelParameterfvRequest {
+            context_tag,
+            target,
+            level,
+            pname,
+        })
+    }
+}
+impl Request for GetTexLevelParameterfvRequest {
+    type Reply = GetTexLevelParameterfvReply;
+}
+impl Trace for GetTexLevelParameterfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("level", |tracer| tracer.value(&self.level))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexLevelParameterfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetTexLevelParameterfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexLevelParameterfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexLevelParameterfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexLevelParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexLevelParameterfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexLevelParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetTexLevelParameteriv request
+pub const GET_TEX_LEVEL_PARAMETERIV_REQUEST: u8 = 139;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetTexLevelParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+    pub pname: u32,
+}
+impl GetTexLevelParameterivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let level_bytes = self.level.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_TEX_LEVEL_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_TEX_LEVEL_PARAMETERIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetTexLevelParameterivRequest {
+            context_tag,
+            target,
+            level,
+            pname,
+        })
+    }
+}
+impl Request for GetTexLevelParameterivRequest {
+    type Reply = GetTexLevelParameterivReply;
+}
+impl Trace for GetTexLevelParameterivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("level", |tracer| tracer.value(&self.level))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetTexLevelParameterivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetTexLevelParameterivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetTexLevelParameterivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetTexLevelParameterivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetTexLevelParameterivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetTexLevelParameterivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetTexLevelParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the IsEnabled request
+pub const IS_ENABLED_REQUEST: u8 = 140;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsEnabledRequest {
+    pub context_tag: ContextTag,
+    pub capability: u32,
+}
+impl IsEnabledRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let capability_bytes = self.capability.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            IS_ENABLED_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            capability_bytes[0],
+            capability_bytes[1],
+            capability_bytes[2],
+            capability_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_ENABLED_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (capability, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(IsEnabledRequest {
+            context_tag,
+            capability,
+        })
+    }
+}
+impl Request for IsEnabledRequest {
+    type Reply = IsEnabledReply;
+}
+impl Trace for IsEnabledRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("capability", |tracer| tracer.value(&self.capability))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsEnabledReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: Bool32,
+}
+impl TryParse for IsEnabledReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = IsEnabledReply { sequence, length, ret_val };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IsEnabledReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IsEnabledReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        Ok(())
+    }
+}
+impl Serialize for IsEnabledReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the IsList request
+pub const IS_LIST_REQUEST: u8 = 141;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsListRequest {
+    pub context_tag: ContextTag,
+    pub list: u32,
+}
+impl IsListRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let list_bytes = self.list.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            IS_LIST_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            list_bytes[0],
+            list_bytes[1],
+            list_bytes[2],
+            list_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_LIST_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (list, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(IsListRequest {
+            context_tag,
+            list,
+        })
+    }
+}
+impl Request for IsListRequest {
+    type Reply = IsListReply;
+}
+impl Trace for IsListRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("list", |tracer| tracer.value(&self.list))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsListReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: Bool32,
+}
+impl TryParse for IsListReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = IsListReply { sequence, length, ret_val };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IsListReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IsListReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        Ok(())
+    }
+}
+impl Serialize for IsListReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Flush request
+pub const FLUSH_REQUEST: u8 = 142;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlushRequest {
+    pub context_tag: ContextTag,
+}
+impl FlushRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FLUSH_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FLUSH_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(FlushRequest {
+            context_tag,
+        })
+    }
+}
+impl Request for FlushRequest {
+    type Reply = ();
+}
+impl Trace for FlushRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the AreTexturesResident request
+pub const ARE_TEXTURES_RESIDENT_REQUEST: u8 = 143;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AreTexturesResidentRequest<'input> {
+    pub context_tag: ContextTag,
+    pub textures: Cow<'input, [u32]>,
+}
+impl<'input> AreTexturesResidentRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let n = i32::try_from(self.textures.len()).expect("`textures` has too many elements");
+        let n_bytes = n.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            ARE_TEXTURES_RESIDENT_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let textures_bytes = self.textures.serialize_with_order(order);
+        let length_so_far = length_so_far + textures_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), textures_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ARE_TEXTURES_RESIDENT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (textures, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(AreTexturesResidentRequest {
+            context_tag,
+            textures: Cow::Owned(textures),
+        })
+    }
+    /// Clone all borrowed data in this AreTexturesResidentRequest.
+    pub fn into_owned(self) -> AreTexturesResidentRequest<'static> {
+        AreTexturesResidentRequest {
+            context_tag: self.context_tag,
+            textures: Cow::Owned(self.textures.into_owned()),
+        }
+    }
+}
+impl<'input> Request for AreTexturesResidentRequest<'input> {
+    type Reply = AreTexturesResidentReply;
+}
+impl Trace for AreTexturesResidentRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("textures", |tracer| tracer.list(&self.textures[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AreTexturesResidentReply {
+    pub sequence: u16,
+    pub ret_val: Bool32,
+    pub data: Vec<bool>,
+}
+impl TryParse for AreTexturesResidentReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<bool>(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = AreTexturesResidentReply { sequence, ret_val, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AreTexturesResidentReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl AreTexturesResidentReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for AreTexturesResidentReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for AreTexturesResidentReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the DeleteTextures request
+pub const DELETE_TEXTURES_REQUEST: u8 = 144;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteTexturesRequest<'input> {
+    pub context_tag: ContextTag,
+    pub textures: Cow<'input, [u32]>,
+}
+impl<'input> DeleteTexturesRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let n = i32::try_from(self.textures.len()).expect("`textures` has too many elements");
+        let n_bytes = n.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_TEXTURES_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let textures_bytes = self.textures.serialize_with_order(order);
+        let length_so_far = length_so_far + textures_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), textures_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_TEXTURES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (textures, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(DeleteTexturesRequest {
+            context_tag,
+            textures: Cow::Owned(textures),
+        })
+    }
+    /// Clone all borrowed data in this DeleteTexturesRequest.
+    pub fn into_owned(self) -> DeleteTexturesRequest<'static> {
+        DeleteTexturesRequest {
+            context_tag: self.context_tag,
+            textures: Cow::Owned(self.textures.into_owned()),
+        }
+    }
+}
+impl<'input> Request for DeleteTexturesRequest<'input> {
+    type Reply = ();
+}
+impl Trace for DeleteTexturesRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("textures", |tracer| tracer.list(&self.textures[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GenTextures request
+pub const GEN_TEXTURES_REQUEST: u8 = 145;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenTexturesRequest {
+    pub context_tag: ContextTag,
+    pub n: i32,
+}
+impl GenTexturesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let n_bytes = self.n.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GEN_TEXTURES_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GEN_TEXTURES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GenTexturesRequest {
+            context_tag,
+            n,
+        })
+    }
+}
+impl Request for GenTexturesRequest {
+    type Reply = GenTexturesReply;
+}
+impl Trace for GenTexturesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("n", |tracer| tracer.value(&self.n))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenTexturesReply {
+    pub sequence: u16,
+    pub data: Vec<u32>,
+}
+impl TryParse for GenTexturesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GenTexturesReply { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GenTexturesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GenTexturesReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GenTexturesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GenTexturesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the IsTexture request
+pub const IS_TEXTURE_REQUEST: u8 = 146;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsTextureRequest {
+    pub context_tag: ContextTag,
+    pub texture: u32,
+}
+impl IsTextureRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let texture_bytes = self.texture.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            IS_TEXTURE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            texture_bytes[0],
+            texture_bytes[1],
+            texture_bytes[2],
+            texture_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_TEXTURE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (texture, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(IsTextureRequest {
+            context_tag,
+            texture,
+        })
+    }
+}
+impl Request for IsTextureRequest {
+    type Reply = IsTextureReply;
+}
+impl Trace for IsTextureRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("texture", |tracer| tracer.value(&self.texture))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsTextureReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: Bool32,
+}
+impl TryParse for IsTextureReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = IsTextureReply { sequence, length, ret_val };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IsTextureReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IsTextureReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        Ok(())
+    }
+}
+impl Serialize for IsTextureReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetColorTable request
+pub const GET_COLOR_TABLE_REQUEST: u8 = 147;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetColorTableRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetColorTableRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_COLOR_TABLE_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COLOR_TABLE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetColorTableRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+}
+impl Request for GetColorTableRequest {
+    type Reply = GetColorTableReply;
+}
+impl Trace for GetColorTableRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetColorTableReply {
+    pub sequence: u16,
+    pub width: i32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetColorTableReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetColorTableReply { sequence, width, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetColorTableReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetColorTableReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetColorTableReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetColorTableReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetColorTableParameterfv request
+pub const GET_COLOR_TABLE_PARAMETERFV_REQUEST: u8 = 148;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetColorTableParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetColorTableParameterfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_COLOR_TABLE_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COLOR_TABLE_PARAMETERFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetColorTableParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetColorTableParameterfvRequest {
+    type Reply = GetColorTableParameterfvReply;
+}
+impl Trace for GetColorTableParameterfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetColorTableParameterfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetColorTableParameterfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetColorTableParameterfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetColorTableParameterfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetColorTableParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetColorTableParameterfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetColorTableParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetColorTableParameteriv request
+pub const GET_COLOR_TABLE_PARAMETERIV_REQUEST: u8 = 149;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetColorTableParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetColorTableParameterivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_COLOR_TABLE_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COLOR_TABLE_PARAMETERIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetColorTableParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetColorTableParameterivRequest {
+    type Reply = GetColorTableParameterivReply;
+}
+impl Trace for GetColorTableParameterivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetColorTableParameterivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetColorTableParameterivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetColorTableParameterivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetColorTableParameterivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetColorTableParameterivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetColorTableParameterivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetColorTableParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetConvolutionFilter request
+pub const GET_CONVOLUTION_FILTER_REQUEST: u8 = 150;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetConvolutionFilterRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetConvolutionFilterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CONVOLUTION_FILTER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONVOLUTION_FILTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetConvolutionFilterRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+}
+impl Request for GetConvolutionFilterRequest {
+    type Reply = GetConvolutionFilterReply;
+}
+impl Trace for GetConvolutionFilterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetConvolutionFilterReply {
+    pub sequence: u16,
+    pub width: i32,
+    pub height: i32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetConvolutionFilterReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetConvolutionFilterReply { sequence, width, height, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetConvolutionFilterReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetConvolutionFilterReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetConvolutionFilterReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetConvolutionFilterReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetConvolutionParameterfv request
+pub const GET_CONVOLUTION_PARAMETERFV_REQUEST: u8 = 151;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetConvolutionParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetConvolutionParameterfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CONVOLUTION_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONVOLUTION_PARAMETERFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetConvolutionParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetConvolutionParameterfvRequest {
+    type Reply = GetConvolutionParameterfvReply;
+}
+impl Trace for GetConvolutionParameterfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetConvolutionParameterfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetConvolutionParameterfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetConvolutionParameterfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetConvolutionParameterfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetConvolutionParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetConvolutionParameterfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetConvolutionParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetConvolutionParameteriv request
+pub const GET_CONVOLUTION_PARAMETERIV_REQUEST: u8 = 152;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetConvolutionParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetConvolutionParameterivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CONVOLUTION_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONVOLUTION_PARAMETERIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetConvolutionParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetConvolutionParameterivRequest {
+    type Reply = GetConvolutionParameterivReply;
+}
+impl Trace for GetConvolutionParameterivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetConvolutionParameterivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetConvolutionParameterivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetConvolutionParameterivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetConvolutionParameterivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetConvolutionParameterivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetConvolutionParameterivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetConvolutionParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetSeparableFilter request
+pub const GET_SEPARABLE_FILTER_REQUEST: u8 = 153;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetSeparableFilterRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+}
+impl GetSeparableFilterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_SEPARABLE_FILTER_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SEPARABLE_FILTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetSeparableFilterRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+        })
+    }
+}
+impl Request for GetSeparableFilterRequest {
+    type Reply = GetSeparableFilterReply;
+}
+impl Trace for GetSeparableFilterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetSeparableFilterReply {
+    pub sequence: u16,
+    pub row_w: i32,
+    pub col_h: i32,
+    pub rows_and_cols: Vec<u8>,
+}
+impl TryParse for GetSeparableFilterReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (row_w, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (col_h, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (rows_and_cols, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let rows_and_cols = rows_and_cols.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetSeparableFilterReply { sequence, row_w, col_h, rows_and_cols };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetSeparableFilterReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetSeparableFilterReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `rows_and_cols` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.rows_and_cols.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetSeparableFilterReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("row-w", |tracer| tracer.value(&self.row_w))?;
+        tracer.field("col-h", |tracer| tracer.value(&self.col_h))?;
+        tracer.field("rows-and-cols", |tracer| tracer.list(&self.rows_and_cols, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetSeparableFilterReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.rows_and_cols.len() % 4, 0, "`rows_and_cols` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.rows_and_cols.len() / 4).expect("`rows_and_cols` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.row_w.serialize_into_with_order(bytes, order);
+        self.col_h.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.rows_and_cols);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetHistogram request
+pub const GET_HISTOGRAM_REQUEST: u8 = 154;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetHistogramRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub reset: bool,
+}
+impl GetHistogramRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let reset_bytes = self.reset.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_HISTOGRAM_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            reset_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_HISTOGRAM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (reset, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetHistogramRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+            reset,
+        })
+    }
+}
+impl Request for GetHistogramRequest {
+    type Reply = GetHistogramReply;
+}
+impl Trace for GetHistogramRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        tracer.field("reset", |tracer| tracer.value(&self.reset))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetHistogramReply {
+    pub sequence: u16,
+    pub width: i32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetHistogramReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (width, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetHistogramReply { sequence, width, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetHistogramReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetHistogramReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetHistogramReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetHistogramReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.width.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetHistogramParameterfv request
+pub const GET_HISTOGRAM_PARAMETERFV_REQUEST: u8 = 155;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetHistogramParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetHistogramParameterfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_HISTOGRAM_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_HISTOGRAM_PARAMETERFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetHistogramParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetHistogramParameterfvRequest {
+    type Reply = GetHistogramParameterfvReply;
+}
+impl Trace for GetHistogramParameterfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetHistogramParameterfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetHistogramParameterfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetHistogramParameterfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetHistogramParameterfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetHistogramParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetHistogramParameterfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetHistogramParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetHistogramParameteriv request
+pub const GET_HISTOGRAM_PARAMETERIV_REQUEST: u8 = 156;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetHistogramParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetHistogramParameterivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_HISTOGRAM_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_HISTOGRAM_PARAMETERIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetHistogramParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetHistogramParameterivRequest {
+    type Reply = GetHistogramParameterivReply;
+}
+impl Trace for GetHistogramParameterivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetHistogramParameterivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetHistogramParameterivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetHistogramParameterivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetHistogramParameterivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetHistogramParameterivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetHistogramParameterivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetHistogramParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMinmax request
+pub const GET_MINMAX_REQUEST: u8 = 157;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMinmaxRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub swap_bytes: bool,
+    pub reset: bool,
+}
+impl GetMinmaxRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let swap_bytes_bytes = self.swap_bytes.serialize_with_order(order);
+        let reset_bytes = self.reset.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MINMAX_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            format_bytes[0],
+            format_bytes[1],
+            format_bytes[2],
+            format_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            swap_bytes_bytes[0],
+            reset_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MINMAX_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (swap_bytes, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (reset, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMinmaxRequest {
+            context_tag,
+            target,
+            format,
+            type_,
+            swap_bytes,
+            reset,
+        })
+    }
+}
+impl Request for GetMinmaxRequest {
+    type Reply = GetMinmaxReply;
+}
+impl Trace for GetMinmaxRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("type", |tracer| tracer.value(&self.type_))?;
+        tracer.field("swap-bytes", |tracer| tracer.value(&self.swap_bytes))?;
+        tracer.field("reset", |tracer| tracer.value(&self.reset))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMinmaxReply {
+    pub sequence: u16,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetMinmaxReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMinmaxReply { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMinmaxReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMinmaxReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMinmaxReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMinmaxReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMinmaxParameterfv request
+pub const GET_MINMAX_PARAMETERFV_REQUEST: u8 = 158;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMinmaxParameterfvRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetMinmaxParameterfvRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MINMAX_PARAMETERFV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MINMAX_PARAMETERFV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMinmaxParameterfvRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetMinmaxParameterfvRequest {
+    type Reply = GetMinmaxParameterfvReply;
+}
+impl Trace for GetMinmaxParameterfvRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMinmaxParameterfvReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: Float32,
+    pub data: Vec<Float32>,
+}
+impl TryParse for GetMinmaxParameterfvReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = Float32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<Float32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMinmaxParameterfvReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMinmaxParameterfvReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMinmaxParameterfvReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMinmaxParameterfvReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMinmaxParameterfvReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetMinmaxParameteriv request
+pub const GET_MINMAX_PARAMETERIV_REQUEST: u8 = 159;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMinmaxParameterivRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetMinmaxParameterivRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MINMAX_PARAMETERIV_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MINMAX_PARAMETERIV_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMinmaxParameterivRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetMinmaxParameterivRequest {
+    type Reply = GetMinmaxParameterivReply;
+}
+impl Trace for GetMinmaxParameterivRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMinmaxParameterivReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetMinmaxParameterivReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMinmaxParameterivReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMinmaxParameterivReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMinmaxParameterivReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMinmaxParameterivReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMinmaxParameterivReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetCompressedTexImageARB request
+pub const GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST: u8 = 160;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCompressedTexImageARBRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub level: i32,
+}
+impl GetCompressedTexImageARBRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let level_bytes = self.level.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            level_bytes[0],
+            level_bytes[1],
+            level_bytes[2],
+            level_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COMPRESSED_TEX_IMAGE_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetCompressedTexImageARBRequest {
+            context_tag,
+            target,
+            level,
+        })
+    }
+}
+impl Request for GetCompressedTexImageARBRequest {
+    type Reply = GetCompressedTexImageARBReply;
+}
+impl Trace for GetCompressedTexImageARBRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("level", |tracer| tracer.value(&self.level))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCompressedTexImageARBReply {
+    pub sequence: u16,
+    pub size: i32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetCompressedTexImageARBReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (size, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCompressedTexImageARBReply { sequence, size, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCompressedTexImageARBReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCompressedTexImageARBReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCompressedTexImageARBReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCompressedTexImageARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the DeleteQueriesARB request
+pub const DELETE_QUERIES_ARB_REQUEST: u8 = 161;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteQueriesARBRequest<'input> {
+    pub context_tag: ContextTag,
+    pub ids: Cow<'input, [u32]>,
+}
+impl<'input> DeleteQueriesARBRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let n = i32::try_from(self.ids.len()).expect("`ids` has too many elements");
+        let n_bytes = n.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_QUERIES_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let ids_bytes = self.ids.serialize_with_order(order);
+        let length_so_far = length_so_far + ids_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), ids_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_QUERIES_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (ids, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(DeleteQueriesARBRequest {
+            context_tag,
+            ids: Cow::Owned(ids),
+        })
+    }
+    /// Clone all borrowed data in this DeleteQueriesARBRequest.
+    pub fn into_owned(self) -> DeleteQueriesARBRequest<'static> {
+        DeleteQueriesARBRequest {
+            context_tag: self.context_tag,
+            ids: Cow::Owned(self.ids.into_owned()),
+        }
+    }
+}
+impl<'input> Request for DeleteQueriesARBRequest<'input> {
+    type Reply = ();
+}
+impl Trace for DeleteQueriesARBRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("ids", |tracer| tracer.list(&self.ids[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GenQueriesARB request
+pub const GEN_QUERIES_ARB_REQUEST: u8 = 162;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenQueriesARBRequest {
+    pub context_tag: ContextTag,
+    pub n: i32,
+}
+impl GenQueriesARBRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let n_bytes = self.n.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GEN_QUERIES_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            n_bytes[0],
+            n_bytes[1],
+            n_bytes[2],
+            n_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GEN_QUERIES_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (n, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GenQueriesARBRequest {
+            context_tag,
+            n,
+        })
+    }
+}
+impl Request for GenQueriesARBRequest {
+    type Reply = GenQueriesARBReply;
+}
+impl Trace for GenQueriesARBRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("n", |tracer| tracer.value(&self.n))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenQueriesARBReply {
+    pub sequence: u16,
+    pub data: Vec<u32>,
+}
+impl TryParse for GenQueriesARBReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GenQueriesARBReply { sequence, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GenQueriesARBReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GenQueriesARBReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GenQueriesARBReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GenQueriesARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the IsQueryARB request
+pub const IS_QUERY_ARB_REQUEST: u8 = 163;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsQueryARBRequest {
+    pub context_tag: ContextTag,
+    pub id: u32,
+}
+impl IsQueryARBRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let id_bytes = self.id.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            IS_QUERY_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != IS_QUERY_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(IsQueryARBRequest {
+            context_tag,
+            id,
+        })
+    }
+}
+impl Request for IsQueryARBRequest {
+    type Reply = IsQueryARBReply;
+}
+impl Trace for IsQueryARBRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsQueryARBReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ret_val: Bool32,
+}
+impl TryParse for IsQueryARBReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ret_val, remaining) = Bool32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = IsQueryARBReply { sequence, length, ret_val };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IsQueryARBReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IsQueryARBReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ret-val", |tracer| tracer.value(&self.ret_val))?;
+        Ok(())
+    }
+}
+impl Serialize for IsQueryARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.ret_val.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetQueryivARB request
+pub const GET_QUERYIV_ARB_REQUEST: u8 = 164;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetQueryivARBRequest {
+    pub context_tag: ContextTag,
+    pub target: u32,
+    pub pname: u32,
+}
+impl GetQueryivARBRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let target_bytes = self.target.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_QUERYIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_QUERYIV_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (target, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetQueryivARBRequest {
+            context_tag,
+            target,
+            pname,
+        })
+    }
+}
+impl Request for GetQueryivARBRequest {
+    type Reply = GetQueryivARBReply;
+}
+impl Trace for GetQueryivARBRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetQueryivARBReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetQueryivARBReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetQueryivARBReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetQueryivARBReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetQueryivARBReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetQueryivARBReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetQueryivARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetQueryObjectivARB request
+pub const GET_QUERY_OBJECTIV_ARB_REQUEST: u8 = 165;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetQueryObjectivARBRequest {
+    pub context_tag: ContextTag,
+    pub id: u32,
+    pub pname: u32,
+}
+impl GetQueryObjectivARBRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let id_bytes = self.id.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_QUERY_OBJECTIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_QUERY_OBJECTIV_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetQueryObjectivARBRequest {
+            context_tag,
+            id,
+            pname,
+        })
+    }
+}
+impl Request for GetQueryObjectivARBRequest {
+    type Reply = GetQueryObjectivARBReply;
+}
+impl Trace for GetQueryObjectivARBRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetQueryObjectivARBReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: i32,
+    pub data: Vec<i32>,
+}
+impl TryParse for GetQueryObjectivARBReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetQueryObjectivARBReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetQueryObjectivARBReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetQueryObjectivARBReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetQueryObjectivARBReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetQueryObjectivARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetQueryObjectuivARB request
+pub const GET_QUERY_OBJECTUIV_ARB_REQUEST: u8 = 166;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetQueryObjectuivARBRequest {
+    pub context_tag: ContextTag,
+    pub id: u32,
+    pub pname: u32,
+}
+impl GetQueryObjectuivARBRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_tag_bytes = self.context_tag.serialize_with_order(order);
+        let id_bytes = self.id.serialize_with_order(order);
+        let pname_bytes = self.pname.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_QUERY_OBJECTUIV_ARB_REQUEST,
+            0,
+            0,
+            context_tag_bytes[0],
+            context_tag_bytes[1],
+            context_tag_bytes[2],
+            context_tag_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            pname_bytes[0],
+            pname_bytes[1],
+            pname_bytes[2],
+            pname_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_QUERY_OBJECTUIV_ARB_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context_tag, remaining) = ContextTag::try_parse_with_order(value, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pname, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetQueryObjectuivARBRequest {
+            context_tag,
+            id,
+            pname,
+        })
+    }
+}
+impl Request for GetQueryObjectuivARBRequest {
+    type Reply = GetQueryObjectuivARBReply;
+}
+impl Trace for GetQueryObjectuivARBRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context-tag", |tracer| tracer.value(&self.context_tag))?;
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        tracer.field("pname", |tracer| tracer.value(&self.pname))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetQueryObjectuivARBReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub datum: u32,
+    pub data: Vec<u32>,
+}
+impl TryParse for GetQueryObjectuivARBReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (n, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (datum, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, n.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetQueryObjectuivARBReply { sequence, length, datum, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetQueryObjectuivARBReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetQueryObjectuivARBReply {
+    /// Get the value of the `n` field.
+    ///
+    /// The `n` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetQueryObjectuivARBReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("datum", |tracer| tracer.value(&self.datum))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetQueryObjectuivARBReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let n = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        n.serialize_into_with_order(bytes, order);
+        self.datum.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.data.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "Render",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "RenderLarge",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "request_num",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "request_total",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreateContext",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "visual",
+                kind: FieldKind::Value(TypeInfo { name: "VISUALID", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "share_list",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "is_direct",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DestroyContext",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "MakeCurrent",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "old_context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "IsDirect",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "is_direct",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "QueryVersion",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "WaitGL",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "WaitX",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CopyContext",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dest",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "SwapBuffers",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "UseXFont",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "font",
+                kind: FieldKind::Value(TypeInfo { name: "FONT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "first",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "list_base",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreateGLXPixmap",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "visual",
+                kind: FieldKind::Value(TypeInfo { name: "VISUALID", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "xproto:PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "glx:PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetVisualConfigs",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_visuals",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_properties",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property_list",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DestroyGLXPixmap",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "glx:PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "VendorPrivate",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vendor_code",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "VendorPrivateWithReply",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vendor_code",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "retval",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data1",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data2",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "QueryExtensionsString",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "QueryServerString",
+        opcode: 19,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "str_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "ClientInfo",
+        opcode: 20,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "str_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetFBConfigs",
+        opcode: 21,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_FB_configs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_properties",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property_list",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreatePixmap",
+        opcode: 22,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fbconfig",
+                kind: FieldKind::Value(TypeInfo { name: "FBCONFIG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "xproto:PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "glx:PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DestroyPixmap",
+        opcode: 23,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "glx:PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreateNewContext",
+        opcode: 24,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fbconfig",
+                kind: FieldKind::Value(TypeInfo { name: "FBCONFIG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "render_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "share_list",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "is_direct",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "QueryContext",
+        opcode: 25,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "MakeContextCurrent",
+        opcode: 26,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "old_context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "read_drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreatePbuffer",
+        opcode: 27,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fbconfig",
+                kind: FieldKind::Value(TypeInfo { name: "FBCONFIG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pbuffer",
+                kind: FieldKind::Value(TypeInfo { name: "PBUFFER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DestroyPbuffer",
+        opcode: 28,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pbuffer",
+                kind: FieldKind::Value(TypeInfo { name: "PBUFFER", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetDrawableAttributes",
+        opcode: 29,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "ChangeDrawableAttributes",
+        opcode: 30,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreateWindow",
+        opcode: 31,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fbconfig",
+                kind: FieldKind::Value(TypeInfo { name: "FBCONFIG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "xproto:WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_window",
+                kind: FieldKind::Value(TypeInfo { name: "glx:WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DeleteWindow",
+        opcode: 32,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glxwindow",
+                kind: FieldKind::Value(TypeInfo { name: "glx:WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "SetClientInfoARB",
+        opcode: 33,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_versions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gl_str_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_str_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gl_versions",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gl_extension_string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_extension_string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "CreateContextAttribsARB",
+        opcode: 34,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fbconfig",
+                kind: FieldKind::Value(TypeInfo { name: "FBCONFIG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "share_list",
+                kind: FieldKind::Value(TypeInfo { name: "glx:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "is_direct",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attribs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribs",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "SetClientInfo2ARB",
+        opcode: 35,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_versions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gl_str_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_str_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gl_versions",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gl_extension_string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "glx_extension_string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "NewList",
+        opcode: 101,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "list",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "EndList",
+        opcode: 102,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DeleteLists",
+        opcode: 103,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "list",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "range",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GenLists",
+        opcode: 104,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "range",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "FeedbackBuffer",
+        opcode: 105,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "SelectBuffer",
+        opcode: 106,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "RenderMode",
+        opcode: 107,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "new_mode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "Finish",
+        opcode: 108,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "PixelStoref",
+        opcode: 109,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "PixelStorei",
+        opcode: 110,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "ReadPixels",
+        opcode: 111,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "lsb_first",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetBooleanv",
+        opcode: 112,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetClipPlane",
+        opcode: 113,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "plane",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetDoublev",
+        opcode: 114,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetError",
+        opcode: 115,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "error",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetFloatv",
+        opcode: 116,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetIntegerv",
+        opcode: 117,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetLightfv",
+        opcode: 118,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "light",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetLightiv",
+        opcode: 119,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "light",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMapdv",
+        opcode: 120,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "query",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMapfv",
+        opcode: 121,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "query",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMapiv",
+        opcode: 122,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "query",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMaterialfv",
+        opcode: 123,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "face",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMaterialiv",
+        opcode: 124,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "face",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetPixelMapfv",
+        opcode: 125,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "map",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetPixelMapuiv",
+        opcode: 126,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "map",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetPixelMapusv",
+        opcode: 127,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "map",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetPolygonStipple",
+        opcode: 128,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "lsb_first",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetString",
+        opcode: 129,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "string",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexEnvfv",
+        opcode: 130,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexEnviv",
+        opcode: 131,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexGendv",
+        opcode: 132,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "coord",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT64", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexGenfv",
+        opcode: 133,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "coord",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexGeniv",
+        opcode: 134,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "coord",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexImage",
+        opcode: 135,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "level",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexParameterfv",
+        opcode: 136,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexParameteriv",
+        opcode: 137,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexLevelParameterfv",
+        opcode: 138,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "level",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetTexLevelParameteriv",
+        opcode: 139,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "level",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "IsEnabled",
+        opcode: 140,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "capability",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "IsList",
+        opcode: 141,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "list",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "Flush",
+        opcode: 142,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "AreTexturesResident",
+        opcode: 143,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "textures",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DeleteTextures",
+        opcode: 144,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "textures",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GenTextures",
+        opcode: 145,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "IsTexture",
+        opcode: 146,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "texture",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetColorTable",
+        opcode: 147,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetColorTableParameterfv",
+        opcode: 148,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetColorTableParameteriv",
+        opcode: 149,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetConvolutionFilter",
+        opcode: 150,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetConvolutionParameterfv",
+        opcode: 151,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetConvolutionParameteriv",
+        opcode: 152,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetSeparableFilter",
+        opcode: 153,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "row_w",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "col_h",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rows_and_cols",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetHistogram",
+        opcode: 154,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "reset",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetHistogramParameterfv",
+        opcode: 155,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetHistogramParameteriv",
+        opcode: 156,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMinmax",
+        opcode: 157,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "swap_bytes",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "reset",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMinmaxParameterfv",
+        opcode: 158,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "FLOAT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetMinmaxParameteriv",
+        opcode: 159,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetCompressedTexImageARB",
+        opcode: 160,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "level",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "DeleteQueriesARB",
+        opcode: 161,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ids",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GenQueriesARB",
+        opcode: 162,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "IsQueryARB",
+        opcode: 163,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ret_val",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetQueryivARB",
+        opcode: 164,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetQueryObjectivARB",
+        opcode: 165,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Glx"),
+        name: "GetQueryObjectuivARB",
+        opcode: 166,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context_tag",
+                kind: FieldKind::Value(TypeInfo { name: "CONTEXT_TAG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pname",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "n",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "datum",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("Glx"),
+        name: "BufferSwapComplete",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_hi",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc_lo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sbc",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Glx"),
+        name: "PbufferClobber",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "draw_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "glx:DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "b_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "aux_buffer",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
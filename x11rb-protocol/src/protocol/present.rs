@@ -0,0 +1,2236 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Present` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::randr;
+use super::sync;
+use super::xfixes;
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "Present";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 2);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EventEnum(u8);
+impl EventEnum {
+    pub const CONFIGURE_NOTIFY: Self = Self(0);
+    pub const COMPLETE_NOTIFY: Self = Self(1);
+    pub const IDLE_NOTIFY: Self = Self(2);
+    pub const REDIRECT_NOTIFY: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "CONFIGURE_NOTIFY", "ConfigureNotify"),
+        (1, "COMPLETE_NOTIFY", "CompleteNotify"),
+        (2, "IDLE_NOTIFY", "IdleNotify"),
+        (3, "REDIRECT_NOTIFY", "RedirectNotify"),
+    ];
+}
+impl From<EventEnum> for u8 {
+    #[inline]
+    fn from(input: EventEnum) -> Self {
+        input.0
+    }
+}
+impl From<EventEnum> for core::option::Option<u8> {
+    #[inline]
+    fn from(input: EventEnum) -> Self {
+        Some(input.0)
+    }
+}
+impl From<EventEnum> for u16 {
+    #[inline]
+    fn from(input: EventEnum) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<EventEnum> for core::option::Option<u16> {
+    #[inline]
+    fn from(input: EventEnum) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<EventEnum> for u32 {
+    #[inline]
+    fn from(input: EventEnum) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<EventEnum> for core::option::Option<u32> {
+    #[inline]
+    fn from(input: EventEnum) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for EventEnum {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for EventEnum  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for EventEnum {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EventEnum {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EventEnum {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EventMask(u8);
+impl EventMask {
+    pub const NO_EVENT: Self = Self(0);
+    pub const CONFIGURE_NOTIFY: Self = Self(1 << 0);
+    pub const COMPLETE_NOTIFY: Self = Self(1 << 1);
+    pub const IDLE_NOTIFY: Self = Self(1 << 2);
+    pub const REDIRECT_NOTIFY: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NO_EVENT", "NoEvent"),
+        (1 << 0, "CONFIGURE_NOTIFY", "ConfigureNotify"),
+        (1 << 1, "COMPLETE_NOTIFY", "CompleteNotify"),
+        (1 << 2, "IDLE_NOTIFY", "IdleNotify"),
+        (1 << 3, "REDIRECT_NOTIFY", "RedirectNotify"),
+    ];
+}
+impl From<EventMask> for u8 {
+    #[inline]
+    fn from(input: EventMask) -> Self {
+        input.0
+    }
+}
+impl From<EventMask> for core::option::Option<u8> {
+    #[inline]
+    fn from(input: EventMask) -> Self {
+        Some(input.0)
+    }
+}
+impl From<EventMask> for u16 {
+    #[inline]
+    fn from(input: EventMask) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<EventMask> for core::option::Option<u16> {
+    #[inline]
+    fn from(input: EventMask) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<EventMask> for u32 {
+    #[inline]
+    fn from(input: EventMask) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<EventMask> for core::option::Option<u32> {
+    #[inline]
+    fn from(input: EventMask) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for EventMask {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for EventMask  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for EventMask {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EventMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EventMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(EventMask, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Option(u8);
+impl Option {
+    pub const NONE: Self = Self(0);
+    pub const ASYNC: Self = Self(1 << 0);
+    pub const COPY: Self = Self(1 << 1);
+    pub const UST: Self = Self(1 << 2);
+    pub const SUBOPTIMAL: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NONE", "None"),
+        (1 << 0, "ASYNC", "Async"),
+        (1 << 1, "COPY", "Copy"),
+        (1 << 2, "UST", "UST"),
+        (1 << 3, "SUBOPTIMAL", "Suboptimal"),
+    ];
+}
+impl From<Option> for u8 {
+    #[inline]
+    fn from(input: Option) -> Self {
+        input.0
+    }
+}
+impl From<Option> for core::option::Option<u8> {
+    #[inline]
+    fn from(input: Option) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Option> for u16 {
+    #[inline]
+    fn from(input: Option) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Option> for core::option::Option<u16> {
+    #[inline]
+    fn from(input: Option) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Option> for u32 {
+    #[inline]
+    fn from(input: Option) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Option> for core::option::Option<u32> {
+    #[inline]
+    fn from(input: Option) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Option {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Option  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Option {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Option {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Option {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Option, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Capability(u8);
+impl Capability {
+    pub const NONE: Self = Self(0);
+    pub const ASYNC: Self = Self(1 << 0);
+    pub const FENCE: Self = Self(1 << 1);
+    pub const UST: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NONE", "None"),
+        (1 << 0, "ASYNC", "Async"),
+        (1 << 1, "FENCE", "Fence"),
+        (1 << 2, "UST", "UST"),
+    ];
+}
+impl From<Capability> for u8 {
+    #[inline]
+    fn from(input: Capability) -> Self {
+        input.0
+    }
+}
+impl From<Capability> for core::option::Option<u8> {
+    #[inline]
+    fn from(input: Capability) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Capability> for u16 {
+    #[inline]
+    fn from(input: Capability) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Capability> for core::option::Option<u16> {
+    #[inline]
+    fn from(input: Capability) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Capability> for u32 {
+    #[inline]
+    fn from(input: Capability) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Capability> for core::option::Option<u32> {
+    #[inline]
+    fn from(input: Capability) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Capability {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Capability  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Capability {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Capability {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Capability {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Capability, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CompleteKind(u8);
+impl CompleteKind {
+    pub const PIXMAP: Self = Self(0);
+    pub const NOTIFY_MSC: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "PIXMAP", "Pixmap"),
+        (1, "NOTIFY_MSC", "NotifyMSC"),
+    ];
+}
+impl From<CompleteKind> for u8 {
+    #[inline]
+    fn from(input: CompleteKind) -> Self {
+        input.0
+    }
+}
+impl From<CompleteKind> for core::option::Option<u8> {
+    #[inline]
+    fn from(input: CompleteKind) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CompleteKind> for u16 {
+    #[inline]
+    fn from(input: CompleteKind) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CompleteKind> for core::option::Option<u16> {
+    #[inline]
+    fn from(input: CompleteKind) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CompleteKind> for u32 {
+    #[inline]
+    fn from(input: CompleteKind) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CompleteKind> for core::option::Option<u32> {
+    #[inline]
+    fn from(input: CompleteKind) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CompleteKind {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CompleteKind  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CompleteKind {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CompleteKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CompleteKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CompleteMode(u8);
+impl CompleteMode {
+    pub const COPY: Self = Self(0);
+    pub const FLIP: Self = Self(1);
+    pub const SKIP: Self = Self(2);
+    pub const SUBOPTIMAL_COPY: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "COPY", "Copy"),
+        (1, "FLIP", "Flip"),
+        (2, "SKIP", "Skip"),
+        (3, "SUBOPTIMAL_COPY", "SuboptimalCopy"),
+    ];
+}
+impl From<CompleteMode> for u8 {
+    #[inline]
+    fn from(input: CompleteMode) -> Self {
+        input.0
+    }
+}
+impl From<CompleteMode> for core::option::Option<u8> {
+    #[inline]
+    fn from(input: CompleteMode) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CompleteMode> for u16 {
+    #[inline]
+    fn from(input: CompleteMode) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CompleteMode> for core::option::Option<u16> {
+    #[inline]
+    fn from(input: CompleteMode) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CompleteMode> for u32 {
+    #[inline]
+    fn from(input: CompleteMode) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CompleteMode> for core::option::Option<u32> {
+    #[inline]
+    fn from(input: CompleteMode) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CompleteMode {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CompleteMode  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CompleteMode {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CompleteMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CompleteMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Notify {
+    pub window: xproto::Window,
+    pub serial: u32,
+}
+impl TryParse for Notify {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = Notify { window, serial };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Notify {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Notify {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let window_bytes = self.window.serialize_with_order(order);
+        let serial_bytes = self.serial.serialize_with_order(order);
+        [
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            serial_bytes[0],
+            serial_bytes[1],
+            serial_bytes[2],
+            serial_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.window.serialize_into_with_order(bytes, order);
+        self.serial.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Notify {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("serial", |tracer| tracer.value(&self.serial))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Pixmap request
+pub const PIXMAP_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PixmapRequest<'input> {
+    pub window: xproto::Window,
+    pub pixmap: xproto::Pixmap,
+    pub serial: u32,
+    pub valid: xfixes::Region,
+    pub update: xfixes::Region,
+    pub x_off: i16,
+    pub y_off: i16,
+    pub target_crtc: randr::Crtc,
+    pub wait_fence: sync::Fence,
+    pub idle_fence: sync::Fence,
+    pub options: u32,
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+    pub notifies: Cow<'input, [Notify]>,
+}
+impl<'input> PixmapRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let pixmap_bytes = self.pixmap.serialize_with_order(order);
+        let serial_bytes = self.serial.serialize_with_order(order);
+        let valid_bytes = self.valid.serialize_with_order(order);
+        let update_bytes = self.update.serialize_with_order(order);
+        let x_off_bytes = self.x_off.serialize_with_order(order);
+        let y_off_bytes = self.y_off.serialize_with_order(order);
+        let target_crtc_bytes = self.target_crtc.serialize_with_order(order);
+        let wait_fence_bytes = self.wait_fence.serialize_with_order(order);
+        let idle_fence_bytes = self.idle_fence.serialize_with_order(order);
+        let options_bytes = self.options.serialize_with_order(order);
+        let target_msc_bytes = self.target_msc.serialize_with_order(order);
+        let divisor_bytes = self.divisor.serialize_with_order(order);
+        let remainder_bytes = self.remainder.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PIXMAP_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            pixmap_bytes[0],
+            pixmap_bytes[1],
+            pixmap_bytes[2],
+            pixmap_bytes[3],
+            serial_bytes[0],
+            serial_bytes[1],
+            serial_bytes[2],
+            serial_bytes[3],
+            valid_bytes[0],
+            valid_bytes[1],
+            valid_bytes[2],
+            valid_bytes[3],
+            update_bytes[0],
+            update_bytes[1],
+            update_bytes[2],
+            update_bytes[3],
+            x_off_bytes[0],
+            x_off_bytes[1],
+            y_off_bytes[0],
+            y_off_bytes[1],
+            target_crtc_bytes[0],
+            target_crtc_bytes[1],
+            target_crtc_bytes[2],
+            target_crtc_bytes[3],
+            wait_fence_bytes[0],
+            wait_fence_bytes[1],
+            wait_fence_bytes[2],
+            wait_fence_bytes[3],
+            idle_fence_bytes[0],
+            idle_fence_bytes[1],
+            idle_fence_bytes[2],
+            idle_fence_bytes[3],
+            options_bytes[0],
+            options_bytes[1],
+            options_bytes[2],
+            options_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            target_msc_bytes[0],
+            target_msc_bytes[1],
+            target_msc_bytes[2],
+            target_msc_bytes[3],
+            target_msc_bytes[4],
+            target_msc_bytes[5],
+            target_msc_bytes[6],
+            target_msc_bytes[7],
+            divisor_bytes[0],
+            divisor_bytes[1],
+            divisor_bytes[2],
+            divisor_bytes[3],
+            divisor_bytes[4],
+            divisor_bytes[5],
+            divisor_bytes[6],
+            divisor_bytes[7],
+            remainder_bytes[0],
+            remainder_bytes[1],
+            remainder_bytes[2],
+            remainder_bytes[3],
+            remainder_bytes[4],
+            remainder_bytes[5],
+            remainder_bytes[6],
+            remainder_bytes[7],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let notifies_bytes = self.notifies.serialize_with_order(order);
+        let length_so_far = length_so_far + notifies_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), notifies_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PIXMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (valid, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (update, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (x_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (target_crtc, remaining) = randr::Crtc::try_parse_with_order(remaining, order)?;
+        let (wait_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (idle_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (options, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (target_msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (divisor, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (remainder, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut notifies = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Notify::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            notifies.push(v);
+        }
+        let _ = remaining;
+        Ok(PixmapRequest {
+            window,
+            pixmap,
+            serial,
+            valid,
+            update,
+            x_off,
+            y_off,
+            target_crtc,
+            wait_fence,
+            idle_fence,
+            options,
+            target_msc,
+            divisor,
+            remainder,
+            notifies: Cow::Owned(notifies),
+        })
+    }
+    /// Clone all borrowed data in this PixmapRequest.
+    pub fn into_owned(self) -> PixmapRequest<'static> {
+        PixmapRequest {
+            window: self.window,
+            pixmap: self.pixmap,
+            serial: self.serial,
+            valid: self.valid,
+            update: self.update,
+            x_off: self.x_off,
+            y_off: self.y_off,
+            target_crtc: self.target_crtc,
+            wait_fence: self.wait_fence,
+            idle_fence: self.idle_fence,
+            options: self.options,
+            target_msc: self.target_msc,
+            divisor: self.divisor,
+            remainder: self.remainder,
+            notifies: Cow::Owned(self.notifies.into_owned()),
+        }
+    }
+}
+impl<'input> Request for PixmapRequest<'input> {
+    type Reply = ();
+}
+impl Trace for PixmapRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("serial", |tracer| tracer.value(&self.serial))?;
+        tracer.field("valid", |tracer| tracer.xid(&self.valid))?;
+        tracer.field("update", |tracer| tracer.xid(&self.update))?;
+        tracer.field("x-off", |tracer| tracer.value(&self.x_off))?;
+        tracer.field("y-off", |tracer| tracer.value(&self.y_off))?;
+        tracer.field("target-crtc", |tracer| tracer.xid(&self.target_crtc))?;
+        tracer.field("wait-fence", |tracer| tracer.xid(&self.wait_fence))?;
+        tracer.field("idle-fence", |tracer| tracer.xid(&self.idle_fence))?;
+        tracer.field("options", |tracer| tracer.value(&self.options))?;
+        tracer.field("target-msc", |tracer| tracer.value(&self.target_msc))?;
+        tracer.field("divisor", |tracer| tracer.value(&self.divisor))?;
+        tracer.field("remainder", |tracer| tracer.value(&self.remainder))?;
+        tracer.field("notifies", |tracer| tracer.list(&self.notifies[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the NotifyMSC request
+pub const NOTIFY_MSC_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotifyMSCRequest {
+    pub window: xproto::Window,
+    pub serial: u32,
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+}
+impl NotifyMSCRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let serial_bytes = self.serial.serialize_with_order(order);
+        let target_msc_bytes = self.target_msc.serialize_with_order(order);
+        let divisor_bytes = self.divisor.serialize_with_order(order);
+        let remainder_bytes = self.remainder.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            NOTIFY_MSC_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            serial_bytes[0],
+            serial_bytes[1],
+            serial_bytes[2],
+            serial_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            target_msc_bytes[0],
+            target_msc_bytes[1],
+            target_msc_bytes[2],
+            target_msc_bytes[3],
+            target_msc_bytes[4],
+            target_msc_bytes[5],
+            target_msc_bytes[6],
+            target_msc_bytes[7],
+            divisor_bytes[0],
+            divisor_bytes[1],
+            divisor_bytes[2],
+            divisor_bytes[3],
+            divisor_bytes[4],
+            divisor_bytes[5],
+            divisor_bytes[6],
+            divisor_bytes[7],
+            remainder_bytes[0],
+            remainder_bytes[1],
+            remainder_bytes[2],
+            remainder_bytes[3],
+            remainder_bytes[4],
+            remainder_bytes[5],
+            remainder_bytes[6],
+            remainder_bytes[7],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != NOTIFY_MSC_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (target_msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (divisor, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (remainder, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(NotifyMSCRequest {
+            window,
+            serial,
+            target_msc,
+            divisor,
+            remainder,
+        })
+    }
+}
+impl Request for NotifyMSCRequest {
+    type Reply = ();
+}
+impl Trace for NotifyMSCRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("serial", |tracer| tracer.value(&self.serial))?;
+        tracer.field("target-msc", |tracer| tracer.value(&self.target_msc))?;
+        tracer.field("divisor", |tracer| tracer.value(&self.divisor))?;
+        tracer.field("remainder", |tracer| tracer.value(&self.remainder))?;
+        Ok(())
+    }
+}
+
+pub type Event = u32;
+
+/// Opcode for the SelectInput request
+pub const SELECT_INPUT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectInputRequest {
+    pub eid: Event,
+    pub window: xproto::Window,
+    pub event_mask: u32,
+}
+impl SelectInputRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let eid_bytes = self.eid.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let event_mask_bytes = self.event_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            eid_bytes[0],
+            eid_bytes[1],
+            eid_bytes[2],
+            eid_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (eid, remaining) = Event::try_parse_with_order(value, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SelectInputRequest {
+            eid,
+            window,
+            event_mask,
+        })
+    }
+}
+impl Request for SelectInputRequest {
+    type Reply = ();
+}
+impl Trace for SelectInputRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("eid", |tracer| tracer.xid(&self.eid))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("event-mask", |tracer| tracer.bitmask(&self.event_mask, EventMask::VARIANTS))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryCapabilities request
+pub const QUERY_CAPABILITIES_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryCapabilitiesRequest {
+    pub target: u32,
+}
+impl QueryCapabilitiesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let target_bytes = self.target.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_CAPABILITIES_REQUEST,
+            0,
+            0,
+            target_bytes[0],
+            target_bytes[1],
+            target_bytes[2],
+            target_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CAPABILITIES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (target, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryCapabilitiesRequest {
+            target,
+        })
+    }
+}
+impl Request for QueryCapabilitiesRequest {
+    type Reply = QueryCapabilitiesReply;
+}
+impl Trace for QueryCapabilitiesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryCapabilitiesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub capabilities: u32,
+}
+impl TryParse for QueryCapabilitiesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (capabilities, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryCapabilitiesReply { sequence, length, capabilities };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryCapabilitiesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryCapabilitiesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("capabilities", |tracer| tracer.value(&self.capabilities))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryCapabilitiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.capabilities.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Generic event
+pub const GENERIC_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub evtype: u16,
+    pub event: Event,
+}
+impl TryParse for GenericEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (evtype, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let result = GenericEvent { response_type, extension, sequence, length, evtype, event };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GenericEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GenericEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("extension", |tracer| tracer.value(&self.extension))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("length", |tracer| tracer.value(&self.length))?;
+        tracer.field("evtype", |tracer| tracer.value(&self.evtype))?;
+        tracer.field("event", |tracer| tracer.xid(&self.event))?;
+        Ok(())
+    }
+}
+impl GenericEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let extension_bytes = self.extension.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let length_bytes = self.length.serialize_with_order(order);
+        let evtype_bytes = self.evtype.serialize_with_order(order);
+        let event_bytes = self.event.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            extension_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            length_bytes[0],
+            length_bytes[1],
+            length_bytes[2],
+            length_bytes[3],
+            evtype_bytes[0],
+            evtype_bytes[1],
+            0,
+            0,
+            event_bytes[0],
+            event_bytes[1],
+            event_bytes[2],
+            event_bytes[3],
+            // trailing padding
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&GenericEvent> for [u8; 32] {
+    fn from(input: &GenericEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<GenericEvent> for [u8; 32] {
+    fn from(input: GenericEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the ConfigureNotify event
+pub const CONFIGURE_NOTIFY_EVENT: u16 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigureNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub event: Event,
+    pub window: xproto::Window,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub off_x: i16,
+    pub off_y: i16,
+    pub pixmap_width: u16,
+    pub pixmap_height: u16,
+    pub pixmap_flags: u32,
+}
+impl TryParse for ConfigureNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (off_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (off_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (pixmap_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pixmap_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pixmap_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = ConfigureNotifyEvent { response_type, extension, sequence, length, event_type, event, window, x, y, width, height, off_x, off_y, pixmap_width, pixmap_height, pixmap_flags };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ConfigureNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for ConfigureNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("event", |tracer| tracer.xid(&self.event))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("off-x", |tracer| tracer.value(&self.off_x))?;
+        tracer.field("off-y", |tracer| tracer.value(&self.off_y))?;
+        tracer.field("pixmap-width", |tracer| tracer.value(&self.pixmap_width))?;
+        tracer.field("pixmap-height", |tracer| tracer.value(&self.pixmap_height))?;
+        tracer.field("pixmap-flags", |tracer| tracer.value(&self.pixmap_flags))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CompleteNotify event
+pub const COMPLETE_NOTIFY_EVENT: u16 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompleteNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub kind: CompleteKind,
+    pub mode: CompleteMode,
+    pub event: Event,
+    pub window: xproto::Window,
+    pub serial: u32,
+    pub ust: u64,
+    pub msc: u64,
+}
+impl TryParse for CompleteNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ust, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let kind = kind.into();
+        let mode = mode.into();
+        let result = CompleteNotifyEvent { response_type, extension, sequence, length, event_type, kind, mode, event, window, serial, ust, msc };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CompleteNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CompleteNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("kind", |tracer| tracer.value(&self.kind))?;
+        tracer.field("mode", |tracer| tracer.value(&self.mode))?;
+        tracer.field("event", |tracer| tracer.xid(&self.event))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("serial", |tracer| tracer.value(&self.serial))?;
+        tracer.field("ust", |tracer| tracer.value(&self.ust))?;
+        tracer.field("msc", |tracer| tracer.value(&self.msc))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the IdleNotify event
+pub const IDLE_NOTIFY_EVENT: u16 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdleNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub event: Event,
+    pub window: xproto::Window,
+    pub serial: u32,
+    pub pixmap: xproto::Pixmap,
+    pub idle_fence: sync::Fence,
+}
+impl TryParse for IdleNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (idle_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let result = IdleNotifyEvent { response_type, extension, sequence, length, event_type, event, window, serial, pixmap, idle_fence };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IdleNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IdleNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("event", |tracer| tracer.xid(&self.event))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("serial", |tracer| tracer.value(&self.serial))?;
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("idle-fence", |tracer| tracer.xid(&self.idle_fence))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the RedirectNotify event
+pub const REDIRECT_NOTIFY_EVENT: u16 = 3;
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RedirectNotifyEvent {
+    pub response_type: u8,
+    pub extension: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub event_type: u16,
+    pub update_window: bool,
+    pub event: Event,
+    pub event_window: xproto::Window,
+    pub window: xproto::Window,
+    pub pixmap: xproto::Pixmap,
+    pub serial: u32,
+    pub valid_region: xfixes::Region,
+    pub update_region: xfixes::Region,
+    pub valid_rect: xproto::Rectangle,
+    pub update_rect: xproto::Rectangle,
+    pub x_off: i16,
+    pub y_off: i16,
+    pub target_crtc: randr::Crtc,
+    pub wait_fence: sync::Fence,
+    pub idle_fence: sync::Fence,
+    pub options: u32,
+    pub target_msc: u64,
+    pub divisor: u64,
+    pub remainder: u64,
+    pub notifies: Vec<Notify>,
+}
+impl TryParse for RedirectNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (extension, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (update_window, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (event, remaining) = Event::try_parse_with_order(remaining, order)?;
+        let (event_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let (serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (valid_region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (update_region, remaining) = xfixes::Region::try_parse_with_order(remaining, order)?;
+        let (valid_rect, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (update_rect, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (x_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_off, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (target_crtc, remaining) = randr::Crtc::try_parse_with_order(remaining, order)?;
+        let (wait_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (idle_fence, remaining) = sync::Fence::try_parse_with_order(remaining, order)?;
+        let (options, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (target_msc, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (divisor, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let (remainder, remaining) = u64::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut notifies = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Notify::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            notifies.push(v);
+        }
+        let result = RedirectNotifyEvent { response_type, extension, sequence, length, event_type, update_window, event, event_window, window, pixmap, serial, valid_region, update_region, valid_rect, update_rect, x_off, y_off, target_crtc, wait_fence, idle_fence, options, target_msc, divisor, remainder, notifies };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for RedirectNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for RedirectNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("update-window", |tracer| tracer.value(&self.update_window))?;
+        tracer.field("event", |tracer| tracer.xid(&self.event))?;
+        tracer.field("event-window", |tracer| tracer.xid(&self.event_window))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("pixmap", |tracer| tracer.xid(&self.pixmap))?;
+        tracer.field("serial", |tracer| tracer.value(&self.serial))?;
+        tracer.field("valid-region", |tracer| tracer.xid(&self.valid_region))?;
+        tracer.field("update-region", |tracer| tracer.xid(&self.update_region))?;
+        tracer.field("valid-rect", |tracer| tracer.value(&self.valid_rect))?;
+        tracer.field("update-rect", |tracer| tracer.value(&self.update_rect))?;
+        tracer.field("x-off", |tracer| tracer.value(&self.x_off))?;
+        tracer.field("y-off", |tracer| tracer.value(&self.y_off))?;
+        tracer.field("target-crtc", |tracer| tracer.xid(&self.target_crtc))?;
+        tracer.field("wait-fence", |tracer| tracer.xid(&self.wait_fence))?;
+        tracer.field("idle-fence", |tracer| tracer.xid(&self.idle_fence))?;
+        tracer.field("options", |tracer| tracer.value(&self.options))?;
+        tracer.field("target-msc", |tracer| tracer.value(&self.target_msc))?;
+        tracer.field("divisor", |tracer| tracer.value(&self.divisor))?;
+        tracer.field("remainder", |tracer| tracer.value(&self.remainder))?;
+        tracer.field("notifies", |tracer| tracer.list(&self.notifies, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Present"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Present"),
+        name: "Pixmap",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "valid",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x_off",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_off",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wait_fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "idle_fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "options",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "notifies",
+                kind: FieldKind::List(TypeInfo { name: "Notify", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Present"),
+        name: "NotifyMSC",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Present"),
+        name: "SelectInput",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "eid",
+                kind: FieldKind::Value(TypeInfo { name: "EVENT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "EventMask", variants: EventMask::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Present"),
+        name: "QueryCapabilities",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "capabilities",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("Present"),
+        name: "CompleteNotify",
+        number: 1,
+        xge: true,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extension",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "kind",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "CompleteKind", variants: CompleteKind::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "CompleteMode", variants: CompleteMode::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "event",
+                kind: FieldKind::Value(TypeInfo { name: "EVENT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ust",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msc",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Present"),
+        name: "ConfigureNotify",
+        number: 0,
+        xge: true,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extension",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event",
+                kind: FieldKind::Value(TypeInfo { name: "EVENT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "off_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "off_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap_flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Present"),
+        name: "Generic",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extension",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "evtype",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event",
+                kind: FieldKind::Value(TypeInfo { name: "EVENT", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Present"),
+        name: "IdleNotify",
+        number: 2,
+        xge: true,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extension",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event",
+                kind: FieldKind::Value(TypeInfo { name: "EVENT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "idle_fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Present"),
+        name: "RedirectNotify",
+        number: 3,
+        xge: true,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extension",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update_window",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event",
+                kind: FieldKind::Value(TypeInfo { name: "EVENT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pixmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "valid_region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update_region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "valid_rect",
+                kind: FieldKind::Value(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "update_rect",
+                kind: FieldKind::Value(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x_off",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_off",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wait_fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "idle_fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "options",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "target_msc",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "divisor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "remainder",
+                kind: FieldKind::Value(TypeInfo { name: "CARD64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "notifies",
+                kind: FieldKind::List(TypeInfo { name: "Notify", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,10877 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `RandR` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::render;
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "RANDR";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 6);
+
+pub type Mode = u32;
+
+pub type Crtc = u32;
+
+pub type Output = u32;
+
+pub type Provider = u32;
+
+pub type Lease = u32;
+
+/// Opcode for the BadOutput error
+pub const BAD_OUTPUT_ERROR: u8 = 0;
+
+/// Opcode for the BadCrtc error
+pub const BAD_CRTC_ERROR: u8 = 1;
+
+/// Opcode for the BadMode error
+pub const BAD_MODE_ERROR: u8 = 2;
+
+/// Opcode for the BadProvider error
+pub const BAD_PROVIDER_ERROR: u8 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Rotation(u8);
+impl Rotation {
+    pub const ROTATE0: Self = Self(1 << 0);
+    pub const ROTATE90: Self = Self(1 << 1);
+    pub const ROTATE180: Self = Self(1 << 2);
+    pub const ROTATE270: Self = Self(1 << 3);
+    pub const REFLECT_X: Self = Self(1 << 4);
+    pub const REFLECT_Y: Self = Self(1 << 5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "ROTATE0", "Rotate0"),
+        (1 << 1, "ROTATE90", "Rotate90"),
+        (1 << 2, "ROTATE180", "Rotate180"),
+        (1 << 3, "ROTATE270", "Rotate270"),
+        (1 << 4, "REFLECT_X", "ReflectX"),
+        (1 << 5, "REFLECT_Y", "ReflectY"),
+    ];
+}
+impl From<Rotation> for u8 {
+    #[inline]
+    fn from(input: Rotation) -> Self {
+        input.0
+    }
+}
+impl From<Rotation> for Option<u8> {
+    #[inline]
+    fn from(input: Rotation) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Rotation> for u16 {
+    #[inline]
+    fn from(input: Rotation) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Rotation> for Option<u16> {
+    #[inline]
+    fn from(input: Rotation) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Rotation> for u32 {
+    #[inline]
+    fn from(input: Rotation) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Rotation> for Option<u32> {
+    #[inline]
+    fn from(input: Rotation) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Rotation {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Rotation  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Rotation {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rotation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rotation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Rotation, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreenSize {
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16,
+}
+impl TryParse for ScreenSize {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mwidth, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mheight, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = ScreenSize { width, height, mwidth, mheight };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ScreenSize {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ScreenSize {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mwidth_bytes = self.mwidth.serialize_with_order(order);
+        let mheight_bytes = self.mheight.serialize_with_order(order);
+        [
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            mwidth_bytes[0],
+            mwidth_bytes[1],
+            mheight_bytes[0],
+            mheight_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.mwidth.serialize_into_with_order(bytes, order);
+        self.mheight.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ScreenSize {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("height", |tracer| tracer.value(&self.height))?;
+            tracer.field("mwidth", |tracer| tracer.value(&self.mwidth))?;
+            tracer.field("mheight", |tracer| tracer.value(&self.mheight))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RefreshRates {
+    pub rates: Vec<u16>,
+}
+impl TryParse for RefreshRates {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (n_rates, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rates, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, n_rates.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = RefreshRates { rates };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for RefreshRates {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for RefreshRates {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let n_rates = u16::try_from(self.rates.len()).expect("`rates` has too many elements");
+        n_rates.serialize_into_with_order(bytes, order);
+        self.rates.serialize_into_with_order(bytes, order);
+    }
+}
+impl RefreshRates {
+    /// Get the value of the `nRates` field.
+    ///
+    /// The `nRates` field is used as the length field of the `rates` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_rates(&self) -> u16 {
+        self.rates.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for RefreshRates {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("rates", |tracer| tracer.list(&self.rates, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            major_version_bytes[2],
+            major_version_bytes[3],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+            minor_version_bytes[2],
+            minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SetConfig(u8);
+impl SetConfig {
+    pub const SUCCESS: Self = Self(0);
+    pub const INVALID_CONFIG_TIME: Self = Self(1);
+    pub const INVALID_TIME: Self = Self(2);
+    pub const FAILED: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "SUCCESS", "Success"),
+        (1, "INVALID_CONFIG_TIME", "InvalidConfigTime"),
+        (2, "INVALID_TIME", "InvalidTime"),
+        (3, "FAILED", "Failed"),
+    ];
+}
+impl From<SetConfig> for u8 {
+    #[inline]
+    fn from(input: SetConfig) -> Self {
+        input.0
+    }
+}
+impl From<SetConfig> for Option<u8> {
+    #[inline]
+    fn from(input: SetConfig) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SetConfig> for u16 {
+    #[inline]
+    fn from(input: SetConfig) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SetConfig> for Option<u16> {
+    #[inline]
+    fn from(input: SetConfig) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SetConfig> for u32 {
+    #[inline]
+    fn from(input: SetConfig) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SetConfig> for Option<u32> {
+    #[inline]
+    fn from(input: SetConfig) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SetConfig {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SetConfig  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SetConfig {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SetConfig {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SetConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the SetScreenConfig request
+pub const SET_SCREEN_CONFIG_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetScreenConfigRequest {
+    pub window: xproto::Window,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub size_id: u16,
+    pub rotation: u16,
+    pub rate: u16,
+}
+impl SetScreenConfigRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let size_id_bytes = self.size_id.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let rate_bytes = self.rate.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_SCREEN_CONFIG_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            size_id_bytes[0],
+            size_id_bytes[1],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            rate_bytes[0],
+            rate_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_SCREEN_CONFIG_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (size_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rate, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(SetScreenConfigRequest {
+            window,
+            timestamp,
+            config_timestamp,
+            size_id,
+            rotation,
+            rate,
+        })
+    }
+}
+impl Request for SetScreenConfigRequest {
+    type Reply = SetScreenConfigReply;
+}
+impl Trace for SetScreenConfigRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("sizeID", |tracer| tracer.value(&self.size_id))?;
+        tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+        tracer.field("rate", |tracer| tracer.value(&self.rate))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetScreenConfigReply {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub new_timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub root: xproto::Window,
+    pub subpixel_order: render::SubPixel,
+}
+impl TryParse for SetScreenConfigReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (new_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let subpixel_order = subpixel_order.into();
+        let result = SetScreenConfigReply { status, sequence, length, new_timestamp, config_timestamp, root, subpixel_order };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetScreenConfigReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for SetScreenConfigReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("new-timestamp", |tracer| tracer.value(&self.new_timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("root", |tracer| tracer.xid(&self.root))?;
+        tracer.field("subpixel-order", |tracer| tracer.value(&self.subpixel_order))?;
+        Ok(())
+    }
+}
+impl Serialize for SetScreenConfigReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.new_timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        (u32::from(self.subpixel_order) as u16).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 10]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NotifyMask(u8);
+impl NotifyMask {
+    pub const SCREEN_CHANGE: Self = Self(1 << 0);
+    pub const CRTC_CHANGE: Self = Self(1 << 1);
+    pub const OUTPUT_CHANGE: Self = Self(1 << 2);
+    pub const OUTPUT_PROPERTY: Self = Self(1 << 3);
+    pub const PROVIDER_CHANGE: Self = Self(1 << 4);
+    pub const PROVIDER_PROPERTY: Self = Self(1 << 5);
+    pub const RESOURCE_CHANGE: Self = Self(1 << 6);
+    pub const LEASE: Self = Self(1 << 7);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "SCREEN_CHANGE", "ScreenChange"),
+        (1 << 1, "CRTC_CHANGE", "CrtcChange"),
+        (1 << 2, "OUTPUT_CHANGE", "OutputChange"),
+        (1 << 3, "OUTPUT_PROPERTY", "OutputProperty"),
+        (1 << 4, "PROVIDER_CHANGE", "ProviderChange"),
+        (1 << 5, "PROVIDER_PROPERTY", "ProviderProperty"),
+        (1 << 6, "RESOURCE_CHANGE", "ResourceChange"),
+        (1 << 7, "LEASE", "Lease"),
+    ];
+}
+impl From<NotifyMask> for u8 {
+    #[inline]
+    fn from(input: NotifyMask) -> Self {
+        input.0
+    }
+}
+impl From<NotifyMask> for Option<u8> {
+    #[inline]
+    fn from(input: NotifyMask) -> Self {
+        Some(input.0)
+    }
+}
+impl From<NotifyMask> for u16 {
+    #[inline]
+    fn from(input: NotifyMask) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<NotifyMask> for Option<u16> {
+    #[inline]
+    fn from(input: NotifyMask) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<NotifyMask> for u32 {
+    #[inline]
+    fn from(input: NotifyMask) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<NotifyMask> for Option<u32> {
+    #[inline]
+    fn from(input: NotifyMask) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for NotifyMask {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for NotifyMask  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for NotifyMask {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for NotifyMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NotifyMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(NotifyMask, u8);
+
+/// Opcode for the SelectInput request
+pub const SELECT_INPUT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectInputRequest {
+    pub window: xproto::Window,
+    pub enable: u16,
+}
+impl SelectInputRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let enable_bytes = self.enable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            enable_bytes[0],
+            enable_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (enable, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(SelectInputRequest {
+            window,
+            enable,
+        })
+    }
+}
+impl Request for SelectInputRequest {
+    type Reply = ();
+}
+impl Trace for SelectInputRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("enable", |tracer| tracer.bitmask(&self.enable, NotifyMask::VARIANTS))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetScreenInfo request
+pub const GET_SCREEN_INFO_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetScreenInfoRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenInfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_SCREEN_INFO_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetScreenInfoRequest {
+            window,
+        })
+    }
+}
+impl Request for GetScreenInfoRequest {
+    type Reply = GetScreenInfoReply;
+}
+impl Trace for GetScreenInfoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetScreenInfoReply {
+    pub rotations: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub root: xproto::Window,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub size_id: u16,
+    pub rotation: u16,
+    pub rate: u16,
+    pub n_info: u16,
+    pub sizes: Vec<ScreenSize>,
+    pub rates: Vec<RefreshRates>,
+}
+impl TryParse for GetScreenInfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (rotations, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (n_sizes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (size_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rate, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_info, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (sizes, remaining) = crate::x11_utils::parse_list_with_order::<ScreenSize>(remaining, n_sizes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (rates, remaining) = crate::x11_utils::parse_list_with_order::<RefreshRates>(remaining, u32::from(n_info).checked_sub(u32::from(n_sizes)).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenInfoReply { rotations, sequence, length, root, timestamp, config_timestamp, size_id, rotation, rate, n_info, sizes, rates };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetScreenInfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetScreenInfoReply {
+    /// Get the value of the `nSizes` field.
+    ///
+    /// The `nSizes` field is used as the length field of the `sizes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_sizes(&self) -> u16 {
+        self.sizes.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetScreenInfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("rotations", |tracer| tracer.bitmask(&self.rotations, Rotation::VARIANTS))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("root", |tracer| tracer.xid(&self.root))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("sizeID", |tracer| tracer.value(&self.size_id))?;
+        tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+        tracer.field("rate", |tracer| tracer.value(&self.rate))?;
+        tracer.field("nInfo", |tracer| tracer.value(&self.n_info))?;
+        tracer.field("sizes", |tracer| tracer.list(&self.sizes, |tracer, value| tracer.value(value)))?;
+        tracer.field("rates", |tracer| tracer.list(&self.rates, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetScreenInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.rotations.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.root.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        let n_sizes = u16::try_from(self.sizes.len()).expect("`sizes` has too many elements");
+        n_sizes.serialize_into_with_order(bytes, order);
+        self.size_id.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        self.rate.serialize_into_with_order(bytes, order);
+        self.n_info.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.sizes.serialize_into_with_order(bytes, order);
+        assert_eq!(self.rates.len(), usize::try_from(u32::from(self.n_info).checked_sub(u32::from(n_sizes)).unwrap()).unwrap(), "`rates` has an incorrect length");
+        self.rates.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetScreenSizeRange request
+pub const GET_SCREEN_SIZE_RANGE_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetScreenSizeRangeRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenSizeRangeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_SCREEN_SIZE_RANGE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_SIZE_RANGE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetScreenSizeRangeRequest {
+            window,
+        })
+    }
+}
+impl Request for GetScreenSizeRangeRequest {
+    type Reply = GetScreenSizeRangeReply;
+}
+impl Trace for GetScreenSizeRangeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetScreenSizeRangeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub min_width: u16,
+    pub min_height: u16,
+    pub max_width: u16,
+    pub max_height: u16,
+}
+impl TryParse for GetScreenSizeRangeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (max_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenSizeRangeReply { sequence, length, min_width, min_height, max_width, max_height };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetScreenSizeRangeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetScreenSizeRangeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("min-width", |tracer| tracer.value(&self.min_width))?;
+        tracer.field("min-height", |tracer| tracer.value(&self.min_height))?;
+        tracer.field("max-width", |tracer| tracer.value(&self.max_width))?;
+        tracer.field("max-height", |tracer| tracer.value(&self.max_height))?;
+        Ok(())
+    }
+}
+impl Serialize for GetScreenSizeRangeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.min_width.serialize_into_with_order(bytes, order);
+        self.min_height.serialize_into_with_order(bytes, order);
+        self.max_width.serialize_into_with_order(bytes, order);
+        self.max_height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetScreenSize request
+pub const SET_SCREEN_SIZE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetScreenSizeRequest {
+    pub window: xproto::Window,
+    pub width: u16,
+    pub height: u16,
+    pub mm_width: u32,
+    pub mm_height: u32,
+}
+impl SetScreenSizeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mm_width_bytes = self.mm_width.serialize_with_order(order);
+        let mm_height_bytes = self.mm_height.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_SCREEN_SIZE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            mm_width_bytes[0],
+            mm_width_bytes[1],
+            mm_width_bytes[2],
+            mm_width_bytes[3],
+            mm_height_bytes[0],
+            mm_height_bytes[1],
+            mm_height_bytes[2],
+            mm_height_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_SCREEN_SIZE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mm_width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mm_height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetScreenSizeRequest {
+            window,
+            width,
+            height,
+            mm_width,
+            mm_height,
+        })
+    }
+}
+impl Request for SetScreenSizeRequest {
+    type Reply = ();
+}
+impl Trace for SetScreenSizeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("mm-width", |tracer| tracer.value(&self.mm_width))?;
+        tracer.field("mm-height", |tracer| tracer.value(&self.mm_height))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ModeFlag(u16);
+impl ModeFlag {
+    pub const HSYNC_POSITIVE: Self = Self(1 << 0);
+    pub const HSYNC_NEGATIVE: Self = Self(1 << 1);
+    pub const VSYNC_POSITIVE: Self = Self(1 << 2);
+    pub const VSYNC_NEGATIVE: Self = Self(1 << 3);
+    pub const INTERLACE: Self = Self(1 << 4);
+    pub const DOUBLE_SCAN: Self = Self(1 << 5);
+    pub const CSYNC: Self = Self(1 << 6);
+    pub const CSYNC_POSITIVE: Self = Self(1 << 7);
+    pub const CSYNC_NEGATIVE: Self = Self(1 << 8);
+    pub const HSKEW_PRESENT: Self = Self(1 << 9);
+    pub const BCAST: Self = Self(1 << 10);
+    pub const PIXEL_MULTIPLEX: Self = Self(1 << 11);
+    pub const DOUBLE_CLOCK: Self = Self(1 << 12);
+    pub const HALVE_CLOCK: Self = Self(1 << 13);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "HSYNC_POSITIVE", "HsyncPositive"),
+        (1 << 1, "HSYNC_NEGATIVE", "HsyncNegative"),
+        (1 << 2, "VSYNC_POSITIVE", "VsyncPositive"),
+        (1 << 3, "VSYNC_NEGATIVE", "VsyncNegative"),
+        (1 << 4, "INTERLACE", "Interlace"),
+        (1 << 5, "DOUBLE_SCAN", "DoubleScan"),
+        (1 << 6, "CSYNC", "Csync"),
+        (1 << 7, "CSYNC_POSITIVE", "CsyncPositive"),
+        (1 << 8, "CSYNC_NEGATIVE", "CsyncNegative"),
+        (1 << 9, "HSKEW_PRESENT", "HskewPresent"),
+        (1 << 10, "BCAST", "Bcast"),
+        (1 << 11, "PIXEL_MULTIPLEX", "PixelMultiplex"),
+        (1 << 12, "DOUBLE_CLOCK", "DoubleClock"),
+        (1 << 13, "HALVE_CLOCK", "HalveClock"),
+    ];
+}
+impl From<ModeFlag> for u16 {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        input.0
+    }
+}
+impl From<ModeFlag> for Option<u16> {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ModeFlag> for u32 {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ModeFlag> for Option<u32> {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ModeFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for ModeFlag {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ModeFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ModeFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ModeFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ModeFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(ModeFlag, u16);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModeInfo {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub dot_clock: u32,
+    pub hsync_start: u16,
+    pub hsync_end: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vsync_start: u16,
+    pub vsync_end: u16,
+    pub vtotal: u16,
+    pub name_len: u16,
+    pub mode_flags: u32,
+}
+impl TryParse for ModeInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dot_clock, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (hsync_start, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsync_end, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsync_start, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsync_end, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mode_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = ModeInfo { id, width, height, dot_clock, hsync_start, hsync_end, htotal, hskew, vsync_start, vsync_end, vtotal, name_len, mode_flags };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ModeInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ModeInfo {
+    type Bytes = [u8; 32];
+    fn serialize(&self) -> [u8; 32] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let id_bytes = self.id.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let dot_clock_bytes = self.dot_clock.serialize_with_order(order);
+        let hsync_start_bytes = self.hsync_start.serialize_with_order(order);
+        let hsync_end_bytes = self.hsync_end.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vsync_start_bytes = self.vsync_start.serialize_with_order(order);
+        let vsync_end_bytes = self.vsync_end.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let name_len_bytes = self.name_len.serialize_with_order(order);
+        let mode_flags_bytes = self.mode_flags.serialize_with_order(order);
+        [
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            dot_clock_bytes[0],
+            dot_clock_bytes[1],
+            dot_clock_bytes[2],
+            dot_clock_bytes[3],
+            hsync_start_bytes[0],
+            hsync_start_bytes[1],
+            hsync_end_bytes[0],
+            hsync_end_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vsync_start_bytes[0],
+            vsync_start_bytes[1],
+            vsync_end_bytes[0],
+            vsync_end_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            name_len_bytes[0],
+            name_len_bytes[1],
+            mode_flags_bytes[0],
+            mode_flags_bytes[1],
+            mode_flags_bytes[2],
+            mode_flags_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.id.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.dot_clock.serialize_into_with_order(bytes, order);
+        self.hsync_start.serialize_into_with_order(bytes, order);
+        self.hsync_end.serialize_into_with_order(bytes, order);
+        self.htotal.serialize_into_with_order(bytes, order);
+        self.hskew.serialize_into_with_order(bytes, order);
+        self.vsync_start.serialize_into_with_order(bytes, order);
+        self.vsync_end.serialize_into_with_order(bytes, order);
+        self.vtotal.serialize_into_with_order(bytes, order);
+        self.name_len.serialize_into_with_order(bytes, order);
+        self.mode_flags.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ModeInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("id", |tracer| tracer.value(&self.id))?;
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("height", |tracer| tracer.value(&self.height))?;
+            tracer.field("dot-clock", |tracer| tracer.value(&self.dot_clock))?;
+            tracer.field("hsync-start", |tracer| tracer.value(&self.hsync_start))?;
+            tracer.field("hsync-end", |tracer| tracer.value(&self.hsync_end))?;
+            tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+            tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+            tracer.field("vsync-start", |tracer| tracer.value(&self.vsync_start))?;
+            tracer.field("vsync-end", |tracer| tracer.value(&self.vsync_end))?;
+            tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+            tracer.field("name-len", |tracer| tracer.value(&self.name_len))?;
+            tracer.field("mode-flags", |tracer| tracer.bitmask(&self.mode_flags, ModeFlag::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the GetScreenResources request
+pub const GET_SCREEN_RESOURCES_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetScreenResourcesRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenResourcesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_SCREEN_RESOURCES_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_RESOURCES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetScreenResourcesRequest {
+            window,
+        })
+    }
+}
+impl Request for GetScreenResourcesRequest {
+    type Reply = GetScreenResourcesReply;
+}
+impl Trace for GetScreenResourcesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetScreenResourcesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+    pub modes: Vec<ModeInfo>,
+    pub names: Vec<u8>,
+}
+impl TryParse for GetScreenResourcesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_modes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (names_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (modes, remaining) = crate::x11_utils::parse_list_with_order::<ModeInfo>(remaining, num_modes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let names = names.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenResourcesReply { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetScreenResourcesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetScreenResourcesReply {
+    /// Get the value of the `num_crtcs` field.
+    ///
+    /// The `num_crtcs` field is used as the length field of the `crtcs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_crtcs(&self) -> u16 {
+        self.crtcs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_outputs` field.
+    ///
+    /// The `num_outputs` field is used as the length field of the `outputs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_outputs(&self) -> u16 {
+        self.outputs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_modes` field.
+    ///
+    /// The `num_modes` field is used as the length field of the `modes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_modes(&self) -> u16 {
+        self.modes.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `names_len` field.
+    ///
+    /// The `names_len` field is used as the length field of the `names` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn names_len(&self) -> u16 {
+        self.names.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetScreenResourcesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("crtcs", |tracer| tracer.list(&self.crtcs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("outputs", |tracer| tracer.list(&self.outputs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("modes", |tracer| tracer.list(&self.modes, |tracer, value| tracer.value(value)))?;
+        tracer.field("names", |tracer| tracer.list(&self.names, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetScreenResourcesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_modes = u16::try_from(self.modes.len()).expect("`modes` has too many elements");
+        num_modes.serialize_into_with_order(bytes, order);
+        let names_len = u16::try_from(self.names.len()).expect("`names` has too many elements");
+        names_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.modes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.names);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Connection(u8);
+impl Connection {
+    pub const CONNECTED: Self = Self(0);
+    pub const DISCONNECTED: Self = Self(1);
+    pub const UNKNOWN: Self = Self(2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "CONNECTED", "Connected"),
+        (1, "DISCONNECTED", "Disconnected"),
+        (2, "UNKNOWN", "Unknown"),
+    ];
+}
+impl From<Connection> for u8 {
+    #[inline]
+    fn from(input: Connection) -> Self {
+        input.0
+    }
+}
+impl From<Connection> for Option<u8> {
+    #[inline]
+    fn from(input: Connection) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Connection> for u16 {
+    #[inline]
+    fn from(input: Connection) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Connection> for Option<u16> {
+    #[inline]
+    fn from(input: Connection) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Connection> for u32 {
+    #[inline]
+    fn from(input: Connection) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Connection> for Option<u32> {
+    #[inline]
+    fn from(input: Connection) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Connection {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Connection  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Connection {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Connection {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Connection {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the GetOutputInfo request
+pub const GET_OUTPUT_INFO_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetOutputInfoRequest {
+    pub output: Output,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl GetOutputInfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_OUTPUT_INFO_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OUTPUT_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetOutputInfoRequest {
+            output,
+            config_timestamp,
+        })
+    }
+}
+impl Request for GetOutputInfoRequest {
+    type Reply = GetOutputInfoReply;
+}
+impl Trace for GetOutputInfoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetOutputInfoReply {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub crtc: Crtc,
+    pub mm_width: u32,
+    pub mm_height: u32,
+    pub connection: Connection,
+    pub subpixel_order: render::SubPixel,
+    pub num_preferred: u16,
+    pub crtcs: Vec<Crtc>,
+    pub modes: Vec<Mode>,
+    pub clones: Vec<Output>,
+    pub name: Vec<u8>,
+}
+impl TryParse for GetOutputInfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (mm_width, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mm_height, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (connection, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_modes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_preferred, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_clones, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (modes, remaining) = crate::x11_utils::parse_list_with_order::<Mode>(remaining, num_modes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (clones, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_clones.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let connection = connection.into();
+        let subpixel_order = subpixel_order.into();
+        let result = GetOutputInfoReply { status, sequence, length, timestamp, crtc, mm_width, mm_height, connection, subpixel_order, num_preferred, crtcs, modes, clones, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetOutputInfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetOutputInfoReply {
+    /// Get the value of the `num_crtcs` field.
+    ///
+    /// The `num_crtcs` field is used as the length field of the `crtcs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_crtcs(&self) -> u16 {
+        self.crtcs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_modes` field.
+    ///
+    /// The `num_modes` field is used as the length field of the `modes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_modes(&self) -> u16 {
+        self.modes.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_clones` field.
+    ///
+    /// The `num_clones` field is used as the length field of the `clones` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_clones(&self) -> u16 {
+        self.clones.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `name_len` field.
+    ///
+    /// The `name_len` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn name_len(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetOutputInfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        tracer.field("mm-width", |tracer| tracer.value(&self.mm_width))?;
+        tracer.field("mm-height", |tracer| tracer.value(&self.mm_height))?;
+        tracer.field("connection", |tracer| tracer.value(&self.connection))?;
+        tracer.field("subpixel-order", |tracer| tracer.value(&self.subpixel_order))?;
+        tracer.field("num-preferred", |tracer| tracer.value(&self.num_preferred))?;
+        tracer.field("crtcs", |tracer| tracer.list(&self.crtcs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("modes", |tracer| tracer.list(&self.modes, |tracer, value| tracer.xid(value)))?;
+        tracer.field("clones", |tracer| tracer.list(&self.clones, |tracer, value| tracer.xid(value)))?;
+        tracer.field("name", |tracer| tracer.list(&self.name, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetOutputInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.crtc.serialize_into_with_order(bytes, order);
+        self.mm_width.serialize_into_with_order(bytes, order);
+        self.mm_height.serialize_into_with_order(bytes, order);
+        u8::from(self.connection).serialize_into_with_order(bytes, order);
+        (u32::from(self.subpixel_order) as u8).serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_modes = u16::try_from(self.modes.len()).expect("`modes` has too many elements");
+        num_modes.serialize_into_with_order(bytes, order);
+        self.num_preferred.serialize_into_with_order(bytes, order);
+        let num_clones = u16::try_from(self.clones.len()).expect("`clones` has too many elements");
+        num_clones.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.modes.serialize_into_with_order(bytes, order);
+        self.clones.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ListOutputProperties request
+pub const LIST_OUTPUT_PROPERTIES_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListOutputPropertiesRequest {
+    pub output: Output,
+}
+impl ListOutputPropertiesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            LIST_OUTPUT_PROPERTIES_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_OUTPUT_PROPERTIES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(ListOutputPropertiesRequest {
+            output,
+        })
+    }
+}
+impl Request for ListOutputPropertiesRequest {
+    type Reply = ListOutputPropertiesReply;
+}
+impl Trace for ListOutputPropertiesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListOutputPropertiesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: Vec<xproto::Atom>,
+}
+impl TryParse for ListOutputPropertiesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_atoms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListOutputPropertiesReply { sequence, length, atoms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ListOutputPropertiesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ListOutputPropertiesReply {
+    /// Get the value of the `num_atoms` field.
+    ///
+    /// The `num_atoms` field is used as the length field of the `atoms` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_atoms(&self) -> u16 {
+        self.atoms.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ListOutputPropertiesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("atoms", |tracer| tracer.list(&self.atoms, |tracer, value| tracer.atom(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for ListOutputPropertiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_atoms = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        num_atoms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.atoms.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryOutputProperty request
+pub const QUERY_OUTPUT_PROPERTY_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+}
+impl QueryOutputPropertyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryOutputPropertyRequest {
+            output,
+            property,
+        })
+    }
+}
+impl Request for QueryOutputPropertyRequest {
+    type Reply = QueryOutputPropertyReply;
+}
+impl Trace for QueryOutputPropertyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryOutputPropertyReply {
+    pub sequence: u16,
+    pub pending: bool,
+    pub range: bool,
+    pub immutable: bool,
+    pub valid_values: Vec<i32>,
+}
+impl TryParse for QueryOutputPropertyReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (immutable, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
+        let (valid_values, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryOutputPropertyReply { sequence, pending, range, immutable, valid_values };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryOutputPropertyReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryOutputPropertyReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `validValues` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.valid_values.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryOutputPropertyReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("pending", |tracer| tracer.value(&self.pending))?;
+        tracer.field("range", |tracer| tracer.value(&self.range))?;
+        tracer.field("immutable", |tracer| tracer.value(&self.immutable))?;
+        tracer.field("validValues", |tracer| tracer.list(&self.valid_values, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryOutputPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.valid_values.len()).expect("`valid_values` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.pending.serialize_into_with_order(bytes, order);
+        self.range.serialize_into_with_order(bytes, order);
+        self.immutable.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 21]);
+        self.valid_values.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ConfigureOutputProperty request
+pub const CONFIGURE_OUTPUT_PROPERTY_REQUEST: u8 = 12;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigureOutputPropertyRequest<'input> {
+    pub output: Output,
+    pub property: xproto::Atom,
+    pub pending: bool,
+    pub range: bool,
+    pub values: Cow<'input, [i32]>,
+}
+impl<'input> ConfigureOutputPropertyRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let pending_bytes = self.pending.serialize_with_order(order);
+        let range_bytes = self.range.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CONFIGURE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            pending_bytes[0],
+            range_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let values_bytes = self.values.serialize_with_order(order);
+        let length_so_far = length_so_far + values_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), values_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CONFIGURE_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut values = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = i32::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            values.push(v);
+        }
+        let _ = remaining;
+        Ok(ConfigureOutputPropertyRequest {
+            output,
+            property,
+            pending,
+            range,
+            values: Cow::Owned(values),
+        })
+    }
+    /// Clone all borrowed data in this ConfigureOutputPropertyRequest.
+    pub fn into_owned(self) -> ConfigureOutputPropertyRequest<'static> {
+        ConfigureOutputPropertyRequest {
+            output: self.output,
+            property: self.property,
+            pending: self.pending,
+            range: self.range,
+            values: Cow::Owned(self.values.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ConfigureOutputPropertyRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ConfigureOutputPropertyRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        tracer.field("pending", |tracer| tracer.value(&self.pending))?;
+        tracer.field("range", |tracer| tracer.value(&self.range))?;
+        tracer.field("values", |tracer| tracer.list(&self.values[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ChangeOutputProperty request
+pub const CHANGE_OUTPUT_PROPERTY_REQUEST: u8 = 13;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeOutputPropertyRequest<'input> {
+    pub output: Output,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub format: u8,
+    pub mode: xproto::PropMode,
+    pub num_units: u32,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> ChangeOutputPropertyRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
+        let num_units_bytes = self.num_units.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+            num_units_bytes[0],
+            num_units_bytes[1],
+            num_units_bytes[2],
+            num_units_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(self.data.len(), usize::try_from(self.num_units.checked_mul(u32::from(self.format)).unwrap().checked_div(8u32).unwrap()).unwrap(), "`data` has an incorrect length");
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let mode = mode.into();
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (num_units, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_units.checked_mul(u32::from(format)).ok_or(ParseError::InvalidExpression)?.checked_div(8u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(ChangeOutputPropertyRequest {
+            output,
+            property,
+            type_,
+            format,
+            mode,
+            num_units,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this ChangeOutputPropertyRequest.
+    pub fn into_owned(self) -> ChangeOutputPropertyRequest<'static> {
+        ChangeOutputPropertyRequest {
+            output: self.output,
+            property: self.property,
+            type_: self.type_,
+            format: self.format,
+            mode: self.mode,
+            num_units: self.num_units,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ChangeOutputPropertyRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ChangeOutputPropertyRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        tracer.field("type", |tracer| tracer.atom(&self.type_))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("mode", |tracer| tracer.value(&self.mode))?;
+        tracer.field("num-units", |tracer| tracer.value(&self.num_units))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteOutputProperty request
+pub const DELETE_OUTPUT_PROPERTY_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+}
+impl DeleteOutputPropertyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(DeleteOutputPropertyRequest {
+            output,
+            property,
+        })
+    }
+}
+impl Request for DeleteOutputPropertyRequest {
+    type Reply = ();
+}
+impl Trace for DeleteOutputPropertyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetOutputProperty request
+pub const GET_OUTPUT_PROPERTY_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetOutputPropertyRequest {
+    pub output: Output,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub long_offset: u32,
+    pub long_length: u32,
+    pub delete: bool,
+    pub pending: bool,
+}
+impl GetOutputPropertyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let long_offset_bytes = self.long_offset.serialize_with_order(order);
+        let long_length_bytes = self.long_length.serialize_with_order(order);
+        let delete_bytes = self.delete.serialize_with_order(order);
+        let pending_bytes = self.pending.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_OUTPUT_PROPERTY_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+            delete_bytes[0],
+            pending_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OUTPUT_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (long_offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (long_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (delete, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetOutputPropertyRequest {
+            output,
+            property,
+            type_,
+            long_offset,
+            long_length,
+            delete,
+            pending,
+        })
+    }
+}
+impl Request for GetOutputPropertyRequest {
+    type Reply = GetOutputPropertyReply;
+}
+impl Trace for GetOutputPropertyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        tracer.field("type", |tracer| tracer.atom(&self.type_))?;
+        tracer.field("long-offset", |tracer| tracer.value(&self.long_offset))?;
+        tracer.field("long-length", |tracer| tracer.value(&self.long_length))?;
+        tracer.field("delete", |tracer| tracer.value(&self.delete))?;
+        tracer.field("pending", |tracer| tracer.value(&self.pending))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetOutputPropertyReply {
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: xproto::Atom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetOutputPropertyReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (bytes_after, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetOutputPropertyReply { format, sequence, length, type_, bytes_after, num_items, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetOutputPropertyReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetOutputPropertyReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("type", |tracer| tracer.atom(&self.type_))?;
+        tracer.field("bytes-after", |tracer| tracer.value(&self.bytes_after))?;
+        tracer.field("num-items", |tracer| tracer.value(&self.num_items))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetOutputPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.format.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.bytes_after.serialize_into_with_order(bytes, order);
+        self.num_items.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        assert_eq!(self.data.len(), usize::try_from(self.num_items.checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreateMode request
+pub const CREATE_MODE_REQUEST: u8 = 16;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateModeRequest<'input> {
+    pub window: xproto::Window,
+    pub mode_info: ModeInfo,
+    pub name: Cow<'input, [u8]>,
+}
+impl<'input> CreateModeRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mode_info_bytes = self.mode_info.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_MODE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            mode_info_bytes[0],
+            mode_info_bytes[1],
+            mode_info_bytes[2],
+            mode_info_bytes[3],
+            mode_info_bytes[4],
+            mode_info_bytes[5],
+            mode_info_bytes[6],
+            mode_info_bytes[7],
+            mode_info_bytes[8],
+            mode_info_bytes[9],
+            mode_info_bytes[10],
+            mode_info_bytes[11],
+            mode_info_bytes[12],
+            mode_info_bytes[13],
+            mode_info_bytes[14],
+            mode_info_bytes[15],
+            mode_info_bytes[16],
+            mode_info_bytes[17],
+            mode_info_bytes[18],
+            mode_info_bytes[19],
+            mode_info_bytes[20],
+            mode_info_bytes[21],
+            mode_info_bytes[22],
+            mode_info_bytes[23],
+            mode_info_bytes[24],
+            mode_info_bytes[25],
+            mode_info_bytes[26],
+            mode_info_bytes[27],
+            mode_info_bytes[28],
+            mode_info_bytes[29],
+            mode_info_bytes[30],
+            mode_info_bytes[31],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.name.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.name, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (mode_info, remaining) = ModeInfo::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = remaining.split_at(remaining.len());
+        let _ = remaining;
+        Ok(CreateModeRequest {
+            window,
+            mode_info,
+            name: Cow::Borrowed(name),
+        })
+    }
+    /// Clone all borrowed data in this CreateModeRequest.
+    pub fn into_owned(self) -> CreateModeRequest<'static> {
+        CreateModeRequest {
+            window: self.window,
+            mode_info: self.mode_info,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateModeRequest<'input> {
+    type Reply = CreateModeReply;
+}
+impl Trace for CreateModeRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("mode-info", |tracer| tracer.value(&self.mode_info))?;
+        tracer.field("name", |tracer| tracer.string(&self.name[..]))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateModeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub mode: Mode,
+}
+impl TryParse for CreateModeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CreateModeReply { sequence, length, mode };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CreateModeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CreateModeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+        Ok(())
+    }
+}
+impl Serialize for CreateModeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the DestroyMode request
+pub const DESTROY_MODE_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyModeRequest {
+    pub mode: Mode,
+}
+impl DestroyModeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_MODE_REQUEST,
+            0,
+            0,
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (mode, remaining) = Mode::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyModeRequest {
+            mode,
+        })
+    }
+}
+impl Request for DestroyModeRequest {
+    type Reply = ();
+}
+impl Trace for DestroyModeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the AddOutputMode request
+pub const ADD_OUTPUT_MODE_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddOutputModeRequest {
+    pub output: Output,
+    pub mode: Mode,
+}
+impl AddOutputModeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            ADD_OUTPUT_MODE_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_OUTPUT_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(AddOutputModeRequest {
+            output,
+            mode,
+        })
+    }
+}
+impl Request for AddOutputModeRequest {
+    type Reply = ();
+}
+impl Trace for AddOutputModeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteOutputMode request
+pub const DELETE_OUTPUT_MODE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteOutputModeRequest {
+    pub output: Output,
+    pub mode: Mode,
+}
+impl DeleteOutputModeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let output_bytes = self.output.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_OUTPUT_MODE_REQUEST,
+            0,
+            0,
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_OUTPUT_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (output, remaining) = Output::try_parse_with_order(value, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(DeleteOutputModeRequest {
+            output,
+            mode,
+        })
+    }
+}
+impl Request for DeleteOutputModeRequest {
+    type Reply = ();
+}
+impl Trace for DeleteOutputModeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetCrtcInfo request
+pub const GET_CRTC_INFO_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCrtcInfoRequest {
+    pub crtc: Crtc,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl GetCrtcInfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CRTC_INFO_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetCrtcInfoRequest {
+            crtc,
+            config_timestamp,
+        })
+    }
+}
+impl Request for GetCrtcInfoRequest {
+    type Reply = GetCrtcInfoReply;
+}
+impl Trace for GetCrtcInfoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCrtcInfoReply {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub rotations: u16,
+    pub outputs: Vec<Output>,
+    pub possible: Vec<Output>,
+}
+impl TryParse for GetCrtcInfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (rotations, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_possible_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (possible, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_possible_outputs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let result = GetCrtcInfoReply { status, sequence, length, timestamp, x, y, width, height, mode, rotation, rotations, outputs, possible };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCrtcInfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCrtcInfoReply {
+    /// Get the value of the `num_outputs` field.
+    ///
+    /// The `num_outputs` field is used as the length field of the `outputs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_outputs(&self) -> u16 {
+        self.outputs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_possible_outputs` field.
+    ///
+    /// The `num_possible_outputs` field is used as the length field of the `possible` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_possible_outputs(&self) -> u16 {
+        self.possible.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCrtcInfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+        tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+        tracer.field("rotations", |tracer| tracer.bitmask(&self.rotations, Rotation::VARIANTS))?;
+        tracer.field("outputs", |tracer| tracer.list(&self.outputs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("possible", |tracer| tracer.list(&self.possible, |tracer, value| tracer.xid(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCrtcInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        self.rotations.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_possible_outputs = u16::try_from(self.possible.len()).expect("`possible` has too many elements");
+        num_possible_outputs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.possible.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetCrtcConfig request
+pub const SET_CRTC_CONFIG_REQUEST: u8 = 21;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCrtcConfigRequest<'input> {
+    pub crtc: Crtc,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub x: i16,
+    pub y: i16,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub outputs: Cow<'input, [Output]>,
+}
+impl<'input> SetCrtcConfigRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CRTC_CONFIG_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let outputs_bytes = self.outputs.serialize_with_order(order);
+        let length_so_far = length_so_far + outputs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), outputs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CRTC_CONFIG_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut outputs = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Output::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            outputs.push(v);
+        }
+        let _ = remaining;
+        Ok(SetCrtcConfigRequest {
+            crtc,
+            timestamp,
+            config_timestamp,
+            x,
+            y,
+            mode,
+            rotation,
+            outputs: Cow::Owned(outputs),
+        })
+    }
+    /// Clone all borrowed data in this SetCrtcConfigRequest.
+    pub fn into_owned(self) -> SetCrtcConfigRequest<'static> {
+        SetCrtcConfigRequest {
+            crtc: self.crtc,
+            timestamp: self.timestamp,
+            config_timestamp: self.config_timestamp,
+            x: self.x,
+            y: self.y,
+            mode: self.mode,
+            rotation: self.rotation,
+            outputs: Cow::Owned(self.outputs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetCrtcConfigRequest<'input> {
+    type Reply = SetCrtcConfigReply;
+}
+impl Trace for SetCrtcConfigRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+        tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+        tracer.field("outputs", |tracer| tracer.list(&self.outputs[..], |tracer, value| tracer.xid(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetCrtcConfigReply {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+}
+impl TryParse for SetCrtcConfigReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let result = SetCrtcConfigReply { status, sequence, length, timestamp };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetCrtcConfigReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for SetCrtcConfigReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        Ok(())
+    }
+}
+impl Serialize for SetCrtcConfigReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetCrtcGammaSize request
+pub const GET_CRTC_GAMMA_SIZE_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCrtcGammaSizeRequest {
+    pub crtc: Crtc,
+}
+impl GetCrtcGammaSizeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CRTC_GAMMA_SIZE_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_GAMMA_SIZE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetCrtcGammaSizeRequest {
+            crtc,
+        })
+    }
+}
+impl Request for GetCrtcGammaSizeRequest {
+    type Reply = GetCrtcGammaSizeReply;
+}
+impl Trace for GetCrtcGammaSizeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCrtcGammaSizeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u16,
+}
+impl TryParse for GetCrtcGammaSizeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCrtcGammaSizeReply { sequence, length, size };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCrtcGammaSizeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetCrtcGammaSizeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCrtcGammaSizeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetCrtcGamma request
+pub const GET_CRTC_GAMMA_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCrtcGammaRequest {
+    pub crtc: Crtc,
+}
+impl GetCrtcGammaRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CRTC_GAMMA_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_GAMMA_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetCrtcGammaRequest {
+            crtc,
+        })
+    }
+}
+impl Request for GetCrtcGammaRequest {
+    type Reply = GetCrtcGammaReply;
+}
+impl Trace for GetCrtcGammaRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCrtcGammaReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+impl TryParse for GetCrtcGammaReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCrtcGammaReply { sequence, length, red, green, blue };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCrtcGammaReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCrtcGammaReply {
+    /// Get the value of the `size` field.
+    ///
+    /// The `size` field is used as the length field of the `red` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn size(&self) -> u16 {
+        self.red.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCrtcGammaReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("red", |tracer| tracer.list(&self.red, |tracer, value| tracer.value(value)))?;
+        tracer.field("green", |tracer| tracer.list(&self.green, |tracer, value| tracer.value(value)))?;
+        tracer.field("blue", |tracer| tracer.list(&self.blue, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCrtcGammaReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let size = u16::try_from(self.red.len()).expect("`red` has too many elements");
+        size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.red.serialize_into_with_order(bytes, order);
+        assert_eq!(self.green.len(), usize::try_from(size).unwrap(), "`green` has an incorrect length");
+        self.green.serialize_into_with_order(bytes, order);
+        assert_eq!(self.blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
+        self.blue.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetCrtcGamma request
+pub const SET_CRTC_GAMMA_REQUEST: u8 = 24;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCrtcGammaRequest<'input> {
+    pub crtc: Crtc,
+    pub red: Cow<'input, [u16]>,
+    pub green: Cow<'input, [u16]>,
+    pub blue: Cow<'input, [u16]>,
+}
+impl<'input> SetCrtcGammaRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let size = u16::try_from(self.red.len()).expect("`red` has too many elements");
+        let size_bytes = size.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CRTC_GAMMA_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            size_bytes[0],
+            size_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let red_bytes = self.red.serialize_with_order(order);
+        let length_so_far = length_so_far + red_bytes.len();
+        assert_eq!(self.green.len(), usize::try_from(size).unwrap(), "`green` has an incorrect length");
+        let green_bytes = self.green.serialize_with_order(order);
+        let length_so_far = length_so_far + green_bytes.len();
+        assert_eq!(self.blue.len(), usize::try_from(size).unwrap(), "`blue` has an incorrect length");
+        let blue_bytes = self.blue.serialize_with_order(order);
+        let length_so_far = length_so_far + blue_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), red_bytes.into(), green_bytes.into(), blue_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CRTC_GAMMA_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(SetCrtcGammaRequest {
+            crtc,
+            red: Cow::Owned(red),
+            green: Cow::Owned(green),
+            blue: Cow::Owned(blue),
+        })
+    }
+    /// Clone all borrowed data in this SetCrtcGammaRequest.
+    pub fn into_owned(self) -> SetCrtcGammaRequest<'static> {
+        SetCrtcGammaRequest {
+            crtc: self.crtc,
+            red: Cow::Owned(self.red.into_owned()),
+            green: Cow::Owned(self.green.into_owned()),
+            blue: Cow::Owned(self.blue.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetCrtcGammaRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetCrtcGammaRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        tracer.field("red", |tracer| tracer.list(&self.red[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("green", |tracer| tracer.list(&self.green[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("blue", |tracer| tracer.list(&self.blue[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetScreenResourcesCurrent request
+pub const GET_SCREEN_RESOURCES_CURRENT_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetScreenResourcesCurrentRequest {
+    pub window: xproto::Window,
+}
+impl GetScreenResourcesCurrentRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_SCREEN_RESOURCES_CURRENT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_SCREEN_RESOURCES_CURRENT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetScreenResourcesCurrentRequest {
+            window,
+        })
+    }
+}
+impl Request for GetScreenResourcesCurrentRequest {
+    type Reply = GetScreenResourcesCurrentReply;
+}
+impl Trace for GetScreenResourcesCurrentRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetScreenResourcesCurrentReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+    pub modes: Vec<ModeInfo>,
+    pub names: Vec<u8>,
+}
+impl TryParse for GetScreenResourcesCurrentReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_modes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (names_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (modes, remaining) = crate::x11_utils::parse_list_with_order::<ModeInfo>(remaining, num_modes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (names, remaining) = crate::x11_utils::parse_u8_list(remaining, names_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let names = names.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetScreenResourcesCurrentReply { sequence, length, timestamp, config_timestamp, crtcs, outputs, modes, names };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetScreenResourcesCurrentReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetScreenResourcesCurrentReply {
+    /// Get the value of the `num_crtcs` field.
+    ///
+    /// The `num_crtcs` field is used as the length field of the `crtcs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_crtcs(&self) -> u16 {
+        self.crtcs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_outputs` field.
+    ///
+    /// The `num_outputs` field is used as the length field of the `outputs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_outputs(&self) -> u16 {
+        self.outputs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_modes` field.
+    ///
+    /// The `num_modes` field is used as the length field of the `modes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_modes(&self) -> u16 {
+        self.modes.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `names_len` field.
+    ///
+    /// The `names_len` field is used as the length field of the `names` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn names_len(&self) -> u16 {
+        self.names.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetScreenResourcesCurrentReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("crtcs", |tracer| tracer.list(&self.crtcs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("outputs", |tracer| tracer.list(&self.outputs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("modes", |tracer| tracer.list(&self.modes, |tracer, value| tracer.value(value)))?;
+        tracer.field("names", |tracer| tracer.list(&self.names, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetScreenResourcesCurrentReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_modes = u16::try_from(self.modes.len()).expect("`modes` has too many elements");
+        num_modes.serialize_into_with_order(bytes, order);
+        let names_len = u16::try_from(self.names.len()).expect("`names` has too many elements");
+        names_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.modes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.names);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Transform(u8);
+impl Transform {
+    pub const UNIT: Self = Self(1 << 0);
+    pub const SCALE_UP: Self = Self(1 << 1);
+    pub const SCALE_DOWN: Self = Self(1 << 2);
+    pub const PROJECTIVE: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "UNIT", "Unit"),
+        (1 << 1, "SCALE_UP", "ScaleUp"),
+        (1 << 2, "SCALE_DOWN", "ScaleDown"),
+        (1 << 3, "PROJECTIVE", "Projective"),
+    ];
+}
+impl From<Transform> for u8 {
+    #[inline]
+    fn from(input: Transform) -> Self {
+        input.0
+    }
+}
+impl From<Transform> for Option<u8> {
+    #[inline]
+    fn from(input: Transform) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Transform> for u16 {
+    #[inline]
+    fn from(input: Transform) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Transform> for Option<u16> {
+    #[inline]
+    fn from(input: Transform) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Transform> for u32 {
+    #[inline]
+    fn from(input: Transform) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Transform> for Option<u32> {
+    #[inline]
+    fn from(input: Transform) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Transform {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Transform  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Transform {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Transform {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Transform {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Transform, u8);
+
+/// Opcode for the SetCrtcTransform request
+pub const SET_CRTC_TRANSFORM_REQUEST: u8 = 26;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCrtcTransformRequest<'input> {
+    pub crtc: Crtc,
+    pub transform: render::Transform,
+    pub filter_name: Cow<'input, [u8]>,
+    pub filter_params: Cow<'input, [render::Fixed]>,
+}
+impl<'input> SetCrtcTransformRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let transform_bytes = self.transform.serialize_with_order(order);
+        let filter_len = u16::try_from(self.filter_name.len()).expect("`filter_name` has too many elements");
+        let filter_len_bytes = filter_len.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CRTC_TRANSFORM_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            transform_bytes[0],
+            transform_bytes[1],
+            transform_bytes[2],
+            transform_bytes[3],
+            transform_bytes[4],
+            transform_bytes[5],
+            transform_bytes[6],
+            transform_bytes[7],
+            transform_bytes[8],
+            transform_bytes[9],
+            transform_bytes[10],
+            transform_bytes[11],
+            transform_bytes[12],
+            transform_bytes[13],
+            transform_bytes[14],
+            transform_bytes[15],
+            transform_bytes[16],
+            transform_bytes[17],
+            transform_bytes[18],
+            transform_bytes[19],
+            transform_bytes[20],
+            transform_bytes[21],
+            transform_bytes[22],
+            transform_bytes[23],
+            transform_bytes[24],
+            transform_bytes[25],
+            transform_bytes[26],
+            transform_bytes[27],
+            transform_bytes[28],
+            transform_bytes[29],
+            transform_bytes[30],
+            transform_bytes[31],
+            transform_bytes[32],
+            transform_bytes[33],
+            transform_bytes[34],
+            transform_bytes[35],
+            filter_len_bytes[0],
+            filter_len_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.filter_name.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        let filter_params_bytes = self.filter_params.serialize_with_order(order);
+        let length_so_far = length_so_far + filter_params_bytes.len();
+        let padding1 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding1.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.filter_name, padding0.into(), filter_params_bytes.into(), padding1.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CRTC_TRANSFORM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let (transform, remaining) = render::Transform::try_parse_with_order(remaining, order)?;
+        let (filter_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, filter_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut filter_params = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = render::Fixed::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            filter_params.push(v);
+        }
+        let _ = remaining;
+        Ok(SetCrtcTransformRequest {
+            crtc,
+            transform,
+            filter_name: Cow::Borrowed(filter_name),
+            filter_params: Cow::Owned(filter_params),
+        })
+    }
+    /// Clone all borrowed data in this SetCrtcTransformRequest.
+    pub fn into_owned(self) -> SetCrtcTransformRequest<'static> {
+        SetCrtcTransformRequest {
+            crtc: self.crtc,
+            transform: self.transform,
+            filter_name: Cow::Owned(self.filter_name.into_owned()),
+            filter_params: Cow::Owned(self.filter_params.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetCrtcTransformRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetCrtcTransformRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        tracer.field("transform", |tracer| tracer.value(&self.transform))?;
+        tracer.field("filter-name", |tracer| tracer.string(&self.filter_name[..]))?;
+        tracer.field("filter-params", |tracer| tracer.list(&self.filter_params[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetCrtcTransform request
+pub const GET_CRTC_TRANSFORM_REQUEST: u8 = 27;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCrtcTransformRequest {
+    pub crtc: Crtc,
+}
+impl GetCrtcTransformRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CRTC_TRANSFORM_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CRTC_TRANSFORM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetCrtcTransformRequest {
+            crtc,
+        })
+    }
+}
+impl Request for GetCrtcTransformRequest {
+    type Reply = GetCrtcTransformReply;
+}
+impl Trace for GetCrtcTransformRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCrtcTransformReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub pending_transform: render::Transform,
+    pub has_transforms: bool,
+    pub current_transform: render::Transform,
+    pub pending_filter_name: Vec<u8>,
+    pub pending_params: Vec<render::Fixed>,
+    pub current_filter_name: Vec<u8>,
+    pub current_params: Vec<render::Fixed>,
+}
+impl TryParse for GetCrtcTransformReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pending_transform, remaining) = render::Transform::try_parse_with_order(remaining, order)?;
+        let (has_transforms, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (current_transform, remaining) = render::Transform::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (pending_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pending_nparams, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (current_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (current_nparams, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (pending_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, pending_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let pending_filter_name = pending_filter_name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (pending_params, remaining) = crate::x11_utils::parse_list_with_order::<render::Fixed>(remaining, pending_nparams.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (current_filter_name, remaining) = crate::x11_utils::parse_u8_list(remaining, current_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let current_filter_name = current_filter_name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (current_params, remaining) = crate::x11_utils::parse_list_with_order::<render::Fixed>(remaining, current_nparams.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCrtcTransformReply { sequence, length, pending_transform, has_transforms, current_transform, pending_filter_name, pending_params, current_filter_name, current_params };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCrtcTransformReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCrtcTransformReply {
+    /// Get the value of the `pending_len` field.
+    ///
+    /// The `pending_len` field is used as the length field of the `pending_filter_name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn pending_len(&self) -> u16 {
+        self.pending_filter_name.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `pending_nparams` field.
+    ///
+    /// The `pending_nparams` field is used as the length field of the `pending_params` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn pending_nparams(&self) -> u16 {
+        self.pending_params.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `current_len` field.
+    ///
+    /// The `current_len` field is used as the length field of the `current_filter_name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn current_len(&self) -> u16 {
+        self.current_filter_name.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `current_nparams` field.
+    ///
+    /// The `current_nparams` field is used as the length field of the `current_params` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn current_nparams(&self) -> u16 {
+        self.current_params.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCrtcTransformReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("pending-transform", |tracer| tracer.value(&self.pending_transform))?;
+        tracer.field("has-transforms", |tracer| tracer.value(&self.has_transforms))?;
+        tracer.field("current-transform", |tracer| tracer.value(&self.current_transform))?;
+        tracer.field("pending-filter-name", |tracer| tracer.string(&self.pending_filter_name))?;
+        tracer.field("pending-params", |tracer| tracer.list(&self.pending_params, |tracer, value| tracer.value(value)))?;
+        tracer.field("current-filter-name", |tracer| tracer.string(&self.current_filter_name))?;
+        tracer.field("current-params", |tracer| tracer.list(&self.current_params, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCrtcTransformReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.pending_transform.serialize_into_with_order(bytes, order);
+        self.has_transforms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        self.current_transform.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        let pending_len = u16::try_from(self.pending_filter_name.len()).expect("`pending_filter_name` has too many elements");
+        pending_len.serialize_into_with_order(bytes, order);
+        let pending_nparams = u16::try_from(self.pending_params.len()).expect("`pending_params` has too many elements");
+        pending_nparams.serialize_into_with_order(bytes, order);
+        let current_len = u16::try_from(self.current_filter_name.len()).expect("`current_filter_name` has too many elements");
+        current_len.serialize_into_with_order(bytes, order);
+        let current_nparams = u16::try_from(self.current_params.len()).expect("`current_params` has too many elements");
+        current_nparams.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.pending_filter_name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.pending_params.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.current_filter_name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.current_params.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetPanning request
+pub const GET_PANNING_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPanningRequest {
+    pub crtc: Crtc,
+}
+impl GetPanningRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PANNING_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PANNING_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetPanningRequest {
+            crtc,
+        })
+    }
+}
+impl Request for GetPanningRequest {
+    type Reply = GetPanningReply;
+}
+impl Trace for GetPanningRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPanningReply {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub track_left: u16,
+    pub track_top: u16,
+    pub track_width: u16,
+    pub track_height: u16,
+    pub border_left: i16,
+    pub border_top: i16,
+    pub border_right: i16,
+    pub border_bottom: i16,
+}
+impl TryParse for GetPanningReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (border_left, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_top, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_right, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_bottom, remaining) = i16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let result = GetPanningReply { status, sequence, length, timestamp, left, top, width, height, track_left, track_top, track_width, track_height, border_left, border_top, border_right, border_bottom };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPanningReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetPanningReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("left", |tracer| tracer.value(&self.left))?;
+        tracer.field("top", |tracer| tracer.value(&self.top))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("track-left", |tracer| tracer.value(&self.track_left))?;
+        tracer.field("track-top", |tracer| tracer.value(&self.track_top))?;
+        tracer.field("track-width", |tracer| tracer.value(&self.track_width))?;
+        tracer.field("track-height", |tracer| tracer.value(&self.track_height))?;
+        tracer.field("border-left", |tracer| tracer.value(&self.border_left))?;
+        tracer.field("border-top", |tracer| tracer.value(&self.border_top))?;
+        tracer.field("border-right", |tracer| tracer.value(&self.border_right))?;
+        tracer.field("border-bottom", |tracer| tracer.value(&self.border_bottom))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPanningReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.left.serialize_into_with_order(bytes, order);
+        self.top.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.track_left.serialize_into_with_order(bytes, order);
+        self.track_top.serialize_into_with_order(bytes, order);
+        self.track_width.serialize_into_with_order(bytes, order);
+        self.track_height.serialize_into_with_order(bytes, order);
+        self.border_left.serialize_into_with_order(bytes, order);
+        self.border_top.serialize_into_with_order(bytes, order);
+        self.border_right.serialize_into_with_order(bytes, order);
+        self.border_bottom.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetPanning request
+pub const SET_PANNING_REQUEST: u8 = 29;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetPanningRequest {
+    pub crtc: Crtc,
+    pub timestamp: xproto::Timestamp,
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    pub track_left: u16,
+    pub track_top: u16,
+    pub track_width: u16,
+    pub track_height: u16,
+    pub border_left: i16,
+    pub border_top: i16,
+    pub border_right: i16,
+    pub border_bottom: i16,
+}
+impl SetPanningRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let left_bytes = self.left.serialize_with_order(order);
+        let top_bytes = self.top.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let track_left_bytes = self.track_left.serialize_with_order(order);
+        let track_top_bytes = self.track_top.serialize_with_order(order);
+        let track_width_bytes = self.track_width.serialize_with_order(order);
+        let track_height_bytes = self.track_height.serialize_with_order(order);
+        let border_left_bytes = self.border_left.serialize_with_order(order);
+        let border_top_bytes = self.border_top.serialize_with_order(order);
+        let border_right_bytes = self.border_right.serialize_with_order(order);
+        let border_bottom_bytes = self.border_bottom.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_PANNING_REQUEST,
+            0,
+            0,
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            left_bytes[0],
+            left_bytes[1],
+            top_bytes[0],
+            top_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            track_left_bytes[0],
+            track_left_bytes[1],
+            track_top_bytes[0],
+            track_top_bytes[1],
+            track_width_bytes[0],
+            track_width_bytes[1],
+            track_height_bytes[0],
+            track_height_bytes[1],
+            border_left_bytes[0],
+            border_left_bytes[1],
+            border_top_bytes[0],
+            border_top_bytes[1],
+            border_right_bytes[0],
+            border_right_bytes[1],
+            border_bottom_bytes[0],
+            border_bottom_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PANNING_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (crtc, remaining) = Crtc::try_parse_with_order(value, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (track_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (border_left, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_top, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_right, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (border_bottom, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetPanningRequest {
+            crtc,
+            timestamp,
+            left,
+            top,
+            width,
+            height,
+            track_left,
+            track_top,
+            track_width,
+            track_height,
+            border_left,
+            border_top,
+            border_right,
+            border_bottom,
+        })
+    }
+}
+impl Request for SetPanningRequest {
+    type Reply = SetPanningReply;
+}
+impl Trace for SetPanningRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("left", |tracer| tracer.value(&self.left))?;
+        tracer.field("top", |tracer| tracer.value(&self.top))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("track-left", |tracer| tracer.value(&self.track_left))?;
+        tracer.field("track-top", |tracer| tracer.value(&self.track_top))?;
+        tracer.field("track-width", |tracer| tracer.value(&self.track_width))?;
+        tracer.field("track-height", |tracer| tracer.value(&self.track_height))?;
+        tracer.field("border-left", |tracer| tracer.value(&self.border_left))?;
+        tracer.field("border-top", |tracer| tracer.value(&self.border_top))?;
+        tracer.field("border-right", |tracer| tracer.value(&self.border_right))?;
+        tracer.field("border-bottom", |tracer| tracer.value(&self.border_bottom))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPanningReply {
+    pub status: SetConfig,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+}
+impl TryParse for SetPanningReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let status = status.into();
+        let result = SetPanningReply { status, sequence, length, timestamp };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetPanningReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for SetPanningReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        Ok(())
+    }
+}
+impl Serialize for SetPanningReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetOutputPrimary request
+pub const SET_OUTPUT_PRIMARY_REQUEST: u8 = 30;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetOutputPrimaryRequest {
+    pub window: xproto::Window,
+    pub output: Output,
+}
+impl SetOutputPrimaryRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let output_bytes = self.output.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_OUTPUT_PRIMARY_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_OUTPUT_PRIMARY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetOutputPrimaryRequest {
+            window,
+            output,
+        })
+    }
+}
+impl Request for SetOutputPrimaryRequest {
+    type Reply = ();
+}
+impl Trace for SetOutputPrimaryRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetOutputPrimary request
+pub const GET_OUTPUT_PRIMARY_REQUEST: u8 = 31;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetOutputPrimaryRequest {
+    pub window: xproto::Window,
+}
+impl GetOutputPrimaryRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_OUTPUT_PRIMARY_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_OUTPUT_PRIMARY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetOutputPrimaryRequest {
+            window,
+        })
+    }
+}
+impl Request for GetOutputPrimaryRequest {
+    type Reply = GetOutputPrimaryReply;
+}
+impl Trace for GetOutputPrimaryRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetOutputPrimaryReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub output: Output,
+}
+impl TryParse for GetOutputPrimaryReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetOutputPrimaryReply { sequence, length, output };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetOutputPrimaryReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetOutputPrimaryReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("output", |tracer| tracer.xid(&self.output))?;
+        Ok(())
+    }
+}
+impl Serialize for GetOutputPrimaryReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.output.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetProviders request
+pub const GET_PROVIDERS_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetProvidersRequest {
+    pub window: xproto::Window,
+}
+impl GetProvidersRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PROVIDERS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PROVIDERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetProvidersRequest {
+            window,
+        })
+    }
+}
+impl Request for GetProvidersRequest {
+    type Reply = GetProvidersReply;
+}
+impl Trace for GetProvidersRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetProvidersReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub providers: Vec<Provider>,
+}
+impl TryParse for GetProvidersReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (num_providers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (providers, remaining) = crate::x11_utils::parse_list_with_order::<Provider>(remaining, num_providers.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetProvidersReply { sequence, length, timestamp, providers };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetProvidersReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetProvidersReply {
+    /// Get the value of the `num_providers` field.
+    ///
+    /// The `num_providers` field is used as the length field of the `providers` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_providers(&self) -> u16 {
+        self.providers.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetProvidersReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("providers", |tracer| tracer.list(&self.providers, |tracer, value| tracer.xid(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetProvidersReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        let num_providers = u16::try_from(self.providers.len()).expect("`providers` has too many elements");
+        num_providers.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        self.providers.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ProviderCapability(u8);
+impl ProviderCapability {
+    pub const SOURCE_OUTPUT: Self = Self(1 << 0);
+    pub const SINK_OUTPUT: Self = Self(1 << 1);
+    pub const SOURCE_OFFLOAD: Self = Self(1 << 2);
+    pub const SINK_OFFLOAD: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "SOURCE_OUTPUT", "SourceOutput"),
+        (1 << 1, "SINK_OUTPUT", "SinkOutput"),
+        (1 << 2, "SOURCE_OFFLOAD", "SourceOffload"),
+        (1 << 3, "SINK_OFFLOAD", "SinkOffload"),
+    ];
+}
+impl From<ProviderCapability> for u8 {
+    #[inline]
+    fn from(input: ProviderCapability) -> Self {
+        input.0
+    }
+}
+impl From<ProviderCapability> for Option<u8> {
+    #[inline]
+    fn from(input: ProviderCapability) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ProviderCapability> for u16 {
+    #[inline]
+    fn from(input: ProviderCapability) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ProviderCapability> for Option<u16> {
+    #[inline]
+    fn from(input: ProviderCapability) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ProviderCapability> for u32 {
+    #[inline]
+    fn from(input: ProviderCapability) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ProviderCapability> for Option<u32> {
+    #[inline]
+    fn from(input: ProviderCapability) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ProviderCapability {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ProviderCapability  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ProviderCapability {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ProviderCapability {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ProviderCapability {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(ProviderCapability, u8);
+
+/// Opcode for the GetProviderInfo request
+pub const GET_PROVIDER_INFO_REQUEST: u8 = 33;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetProviderInfoRequest {
+    pub provider: Provider,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl GetProviderInfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PROVIDER_INFO_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PROVIDER_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetProviderInfoRequest {
+            provider,
+            config_timestamp,
+        })
+    }
+}
+impl Request for GetProviderInfoRequest {
+    type Reply = GetProviderInfoReply;
+}
+impl Trace for GetProviderInfoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetProviderInfoReply {
+    pub status: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub capabilities: u32,
+    pub crtcs: Vec<Crtc>,
+    pub outputs: Vec<Output>,
+    pub associated_providers: Vec<Provider>,
+    pub associated_capability: Vec<u32>,
+    pub name: Vec<u8>,
+}
+impl TryParse for GetProviderInfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (capabilities, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_associated_providers, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (associated_providers, remaining) = crate::x11_utils::parse_list_with_order::<Provider>(remaining, num_associated_providers.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (associated_capability, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_associated_providers.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetProviderInfoReply { status, sequence, length, timestamp, capabilities, crtcs, outputs, associated_providers, associated_capability, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetProviderInfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetProviderInfoReply {
+    /// Get the value of the `num_crtcs` field.
+    ///
+    /// The `num_crtcs` field is used as the length field of the `crtcs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_crtcs(&self) -> u16 {
+        self.crtcs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_outputs` field.
+    ///
+    /// The `num_outputs` field is used as the length field of the `outputs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_outputs(&self) -> u16 {
+        self.outputs.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_associated_providers` field.
+    ///
+    /// The `num_associated_providers` field is used as the length field of the `associated_providers` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_associated_providers(&self) -> u16 {
+        self.associated_providers.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `name_len` field.
+    ///
+    /// The `name_len` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn name_len(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetProviderInfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("capabilities", |tracer| tracer.bitmask(&self.capabilities, ProviderCapability::VARIANTS))?;
+        tracer.field("crtcs", |tracer| tracer.list(&self.crtcs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("outputs", |tracer| tracer.list(&self.outputs, |tracer, value| tracer.xid(value)))?;
+        tracer.field("associated-providers", |tracer| tracer.list(&self.associated_providers, |tracer, value| tracer.xid(value)))?;
+        tracer.field("associated-capability", |tracer| tracer.list(&self.associated_capability, |tracer, value| tracer.value(value)))?;
+        tracer.field("name", |tracer| tracer.string(&self.name))?;
+        Ok(())
+    }
+}
+impl Serialize for GetProviderInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.status.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.capabilities.serialize_into_with_order(bytes, order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        num_crtcs.serialize_into_with_order(bytes, order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        num_outputs.serialize_into_with_order(bytes, order);
+        let num_associated_providers = u16::try_from(self.associated_providers.len()).expect("`associated_providers` has too many elements");
+        num_associated_providers.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        self.crtcs.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+        self.associated_providers.serialize_into_with_order(bytes, order);
+        assert_eq!(self.associated_capability.len(), usize::try_from(num_associated_providers).unwrap(), "`associated_capability` has an incorrect length");
+        self.associated_capability.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetProviderOffloadSink request
+pub const SET_PROVIDER_OFFLOAD_SINK_REQUEST: u8 = 34;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetProviderOffloadSinkRequest {
+    pub provider: Provider,
+    pub sink_provider: Provider,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl SetProviderOffloadSinkRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let sink_provider_bytes = self.sink_provider.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_PROVIDER_OFFLOAD_SINK_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            sink_provider_bytes[0],
+            sink_provider_bytes[1],
+            sink_provider_bytes[2],
+            sink_provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PROVIDER_OFFLOAD_SINK_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (sink_provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetProviderOffloadSinkRequest {
+            provider,
+            sink_provider,
+            config_timestamp,
+        })
+    }
+}
+impl Request for SetProviderOffloadSinkRequest {
+    type Reply = ();
+}
+impl Trace for SetProviderOffloadSinkRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("sink-provider", |tracer| tracer.xid(&self.sink_provider))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetProviderOutputSource request
+pub const SET_PROVIDER_OUTPUT_SOURCE_REQUEST: u8 = 35;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetProviderOutputSourceRequest {
+    pub provider: Provider,
+    pub source_provider: Provider,
+    pub config_timestamp: xproto::Timestamp,
+}
+impl SetProviderOutputSourceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let source_provider_bytes = self.source_provider.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_PROVIDER_OUTPUT_SOURCE_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            source_provider_bytes[0],
+            source_provider_bytes[1],
+            source_provider_bytes[2],
+            source_provider_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PROVIDER_OUTPUT_SOURCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (source_provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetProviderOutputSourceRequest {
+            provider,
+            source_provider,
+            config_timestamp,
+        })
+    }
+}
+impl Request for SetProviderOutputSourceRequest {
+    type Reply = ();
+}
+impl Trace for SetProviderOutputSourceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("source-provider", |tracer| tracer.xid(&self.source_provider))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ListProviderProperties request
+pub const LIST_PROVIDER_PROPERTIES_REQUEST: u8 = 36;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListProviderPropertiesRequest {
+    pub provider: Provider,
+}
+impl ListProviderPropertiesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            LIST_PROVIDER_PROPERTIES_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_PROVIDER_PROPERTIES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(ListProviderPropertiesRequest {
+            provider,
+        })
+    }
+}
+impl Request for ListProviderPropertiesRequest {
+    type Reply = ListProviderPropertiesReply;
+}
+impl Trace for ListProviderPropertiesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListProviderPropertiesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub atoms: Vec<xproto::Atom>,
+}
+impl TryParse for ListProviderPropertiesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_atoms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (atoms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, num_atoms.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListProviderPropertiesReply { sequence, length, atoms };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ListProviderPropertiesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ListProviderPropertiesReply {
+    /// Get the value of the `num_atoms` field.
+    ///
+    /// The `num_atoms` field is used as the length field of the `atoms` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_atoms(&self) -> u16 {
+        self.atoms.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ListProviderPropertiesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("atoms", |tracer| tracer.list(&self.atoms, |tracer, value| tracer.atom(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for ListProviderPropertiesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_atoms = u16::try_from(self.atoms.len()).expect("`atoms` has too many elements");
+        num_atoms.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.atoms.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryProviderProperty request
+pub const QUERY_PROVIDER_PROPERTY_REQUEST: u8 = 37;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+}
+impl QueryProviderPropertyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryProviderPropertyRequest {
+            provider,
+            property,
+        })
+    }
+}
+impl Request for QueryProviderPropertyRequest {
+    type Reply = QueryProviderPropertyReply;
+}
+impl Trace for QueryProviderPropertyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryProviderPropertyReply {
+    pub sequence: u16,
+    pub pending: bool,
+    pub range: bool,
+    pub immutable: bool,
+    pub valid_values: Vec<i32>,
+}
+impl TryParse for QueryProviderPropertyReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (immutable, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(21..).ok_or(ParseError::InsufficientData)?;
+        let (valid_values, remaining) = crate::x11_utils::parse_list_with_order::<i32>(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryProviderPropertyReply { sequence, pending, range, immutable, valid_values };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryProviderPropertyReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryProviderPropertyReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `valid_values` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.valid_values.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryProviderPropertyReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("pending", |tracer| tracer.value(&self.pending))?;
+        tracer.field("range", |tracer| tracer.value(&self.range))?;
+        tracer.field("immutable", |tracer| tracer.value(&self.immutable))?;
+        tracer.field("valid-values", |tracer| tracer.list(&self.valid_values, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryProviderPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.valid_values.len()).expect("`valid_values` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.pending.serialize_into_with_order(bytes, order);
+        self.range.serialize_into_with_order(bytes, order);
+        self.immutable.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 21]);
+        self.valid_values.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ConfigureProviderProperty request
+pub const CONFIGURE_PROVIDER_PROPERTY_REQUEST: u8 = 38;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigureProviderPropertyRequest<'input> {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+    pub pending: bool,
+    pub range: bool,
+    pub values: Cow<'input, [i32]>,
+}
+impl<'input> ConfigureProviderPropertyRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let pending_bytes = self.pending.serialize_with_order(order);
+        let range_bytes = self.range.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CONFIGURE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            pending_bytes[0],
+            range_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let values_bytes = self.values.serialize_with_order(order);
+        let length_so_far = length_so_far + values_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), values_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CONFIGURE_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (range, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut values = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = i32::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            values.push(v);
+        }
+        let _ = remaining;
+        Ok(ConfigureProviderPropertyRequest {
+            provider,
+            property,
+            pending,
+            range,
+            values: Cow::Owned(values),
+        })
+    }
+    /// Clone all borrowed data in this ConfigureProviderPropertyRequest.
+    pub fn into_owned(self) -> ConfigureProviderPropertyRequest<'static> {
+        ConfigureProviderPropertyRequest {
+            provider: self.provider,
+            property: self.property,
+            pending: self.pending,
+            range: self.range,
+            values: Cow::Owned(self.values.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ConfigureProviderPropertyRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ConfigureProviderPropertyRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        tracer.field("pending", |tracer| tracer.value(&self.pending))?;
+        tracer.field("range", |tracer| tracer.value(&self.range))?;
+        tracer.field("values", |tracer| tracer.list(&self.values[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ChangeProviderProperty request
+pub const CHANGE_PROVIDER_PROPERTY_REQUEST: u8 = 39;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeProviderPropertyRequest<'input> {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub format: u8,
+    pub mode: u8,
+    pub num_items: u32,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> ChangeProviderPropertyRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let format_bytes = self.format.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let num_items_bytes = self.num_items.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            format_bytes[0],
+            mode_bytes[0],
+            0,
+            0,
+            num_items_bytes[0],
+            num_items_bytes[1],
+            num_items_bytes[2],
+            num_items_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(self.data.len(), usize::try_from(self.num_items.checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(ChangeProviderPropertyRequest {
+            provider,
+            property,
+            type_,
+            format,
+            mode,
+            num_items,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this ChangeProviderPropertyRequest.
+    pub fn into_owned(self) -> ChangeProviderPropertyRequest<'static> {
+        ChangeProviderPropertyRequest {
+            provider: self.provider,
+            property: self.property,
+            type_: self.type_,
+            format: self.format,
+            mode: self.mode,
+            num_items: self.num_items,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ChangeProviderPropertyRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ChangeProviderPropertyRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        tracer.field("type", |tracer| tracer.atom(&self.type_))?;
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("mode", |tracer| tracer.value(&self.mode))?;
+        tracer.field("num-items", |tracer| tracer.value(&self.num_items))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteProviderProperty request
+pub const DELETE_PROVIDER_PROPERTY_REQUEST: u8 = 40;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+}
+impl DeleteProviderPropertyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(DeleteProviderPropertyRequest {
+            provider,
+            property,
+        })
+    }
+}
+impl Request for DeleteProviderPropertyRequest {
+    type Reply = ();
+}
+impl Trace for DeleteProviderPropertyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetProviderProperty request
+pub const GET_PROVIDER_PROPERTY_REQUEST: u8 = 41;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetProviderPropertyRequest {
+    pub provider: Provider,
+    pub property: xproto::Atom,
+    pub type_: xproto::Atom,
+    pub long_offset: u32,
+    pub long_length: u32,
+    pub delete: bool,
+    pub pending: bool,
+}
+impl GetProviderPropertyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let property_bytes = self.property.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        let long_offset_bytes = self.long_offset.serialize_with_order(order);
+        let long_length_bytes = self.long_length.serialize_with_order(order);
+        let delete_bytes = self.delete.serialize_with_order(order);
+        let pending_bytes = self.pending.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PROVIDER_PROPERTY_REQUEST,
+            0,
+            0,
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            property_bytes[0],
+            property_bytes[1],
+            property_bytes[2],
+            property_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+            long_offset_bytes[0],
+            long_offset_bytes[1],
+            long_offset_bytes[2],
+            long_offset_bytes[3],
+            long_length_bytes[0],
+            long_length_bytes[1],
+            long_length_bytes[2],
+            long_length_bytes[3],
+            delete_bytes[0],
+            pending_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PROVIDER_PROPERTY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (provider, remaining) = Provider::try_parse_with_order(value, order)?;
+        let (property, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (long_offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (long_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (delete, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (pending, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetProviderPropertyRequest {
+            provider,
+            property,
+            type_,
+            long_offset,
+            long_length,
+            delete,
+            pending,
+        })
+    }
+}
+impl Request for GetProviderPropertyRequest {
+    type Reply = GetProviderPropertyReply;
+}
+impl Trace for GetProviderPropertyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+        tracer.field("property", |tracer| tracer.atom(&self.property))?;
+        tracer.field("type", |tracer| tracer.atom(&self.type_))?;
+        tracer.field("long-offset", |tracer| tracer.value(&self.long_offset))?;
+        tracer.field("long-length", |tracer| tracer.value(&self.long_length))?;
+        tracer.field("delete", |tracer| tracer.value(&self.delete))?;
+        tracer.field("pending", |tracer| tracer.value(&self.pending))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetProviderPropertyReply {
+    pub format: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub type_: xproto::Atom,
+    pub bytes_after: u32,
+    pub num_items: u32,
+    pub data: Vec<u8>,
+}
+impl TryParse for GetProviderPropertyReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (bytes_after, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_items, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, num_items.checked_mul(u32::from(format).checked_div(8u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetProviderPropertyReply { format, sequence, length, type_, bytes_after, num_items, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetProviderPropertyReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetProviderPropertyReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("format", |tracer| tracer.value(&self.format))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("type", |tracer| tracer.atom(&self.type_))?;
+        tracer.field("bytes-after", |tracer| tracer.value(&self.bytes_after))?;
+        tracer.field("num-items", |tracer| tracer.value(&self.num_items))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetProviderPropertyReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.format.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.bytes_after.serialize_into_with_order(bytes, order);
+        self.num_items.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        assert_eq!(self.data.len(), usize::try_from(self.num_items.checked_mul(u32::from(self.format).checked_div(8u32).unwrap()).unwrap()).unwrap(), "`data` has an incorrect length");
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ScreenChangeNotify event
+pub const SCREEN_CHANGE_NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreenChangeNotifyEvent {
+    pub response_type: u8,
+    pub rotation: u8,
+    pub sequence: u16,
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub root: xproto::Window,
+    pub request_window: xproto::Window,
+    pub size_id: u16,
+    pub subpixel_order: render::SubPixel,
+    pub width: u16,
+    pub height: u16,
+    pub mwidth: u16,
+    pub mheight: u16,
+}
+impl TryParse for ScreenChangeNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (request_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (size_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mwidth, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mheight, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let subpixel_order = subpixel_order.into();
+        let result = ScreenChangeNotifyEvent { response_type, rotation, sequence, timestamp, config_timestamp, root, request_window, size_id, subpixel_order, width, height, mwidth, mheight };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ScreenChangeNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for ScreenChangeNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+        tracer.field("root", |tracer| tracer.xid(&self.root))?;
+        tracer.field("request-window", |tracer| tracer.xid(&self.request_window))?;
+        tracer.field("sizeID", |tracer| tracer.value(&self.size_id))?;
+        tracer.field("subpixel-order", |tracer| tracer.value(&self.subpixel_order))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("mwidth", |tracer| tracer.value(&self.mwidth))?;
+        tracer.field("mheight", |tracer| tracer.value(&self.mheight))?;
+        Ok(())
+    }
+}
+impl ScreenChangeNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let root_bytes = self.root.serialize_with_order(order);
+        let request_window_bytes = self.request_window.serialize_with_order(order);
+        let size_id_bytes = self.size_id.serialize_with_order(order);
+        let subpixel_order_bytes = (u32::from(self.subpixel_order) as u16).serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mwidth_bytes = self.mwidth.serialize_with_order(order);
+        let mheight_bytes = self.mheight.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            rotation_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            root_bytes[0],
+            root_bytes[1],
+            root_bytes[2],
+            root_bytes[3],
+            request_window_bytes[0],
+            request_window_bytes[1],
+            request_window_bytes[2],
+            request_window_bytes[3],
+            size_id_bytes[0],
+            size_id_bytes[1],
+            subpixel_order_bytes[0],
+            subpixel_order_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            mwidth_bytes[0],
+            mwidth_bytes[1],
+            mheight_bytes[0],
+            mheight_bytes[1],
+        ]
+    }
+}
+impl From<&ScreenChangeNotifyEvent> for [u8; 32] {
+    fn from(input: &ScreenChangeNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<ScreenChangeNotifyEvent> for [u8; 32] {
+    fn from(input: ScreenChangeNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Notify(u8);
+impl Notify {
+    pub const CRTC_CHANGE: Self = Self(0);
+    pub const OUTPUT_CHANGE: Self = Self(1);
+    pub const OUTPUT_PROPERTY: Self = Self(2);
+    pub const PROVIDER_CHANGE: Self = Self(3);
+    pub const PROVIDER_PROPERTY: Self = Self(4);
+    pub const RESOURCE_CHANGE: Self = Self(5);
+    pub const LEASE: Self = Self(6);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "CRTC_CHANGE", "CrtcChange"),
+        (1, "OUTPUT_CHANGE", "OutputChange"),
+        (2, "OUTPUT_PROPERTY", "OutputProperty"),
+        (3, "PROVIDER_CHANGE", "ProviderChange"),
+        (4, "PROVIDER_PROPERTY", "ProviderProperty"),
+        (5, "RESOURCE_CHANGE", "ResourceChange"),
+        (6, "LEASE", "Lease"),
+    ];
+}
+impl From<Notify> for u8 {
+    #[inline]
+    fn from(input: Notify) -> Self {
+        input.0
+    }
+}
+impl From<Notify> for Option<u8> {
+    #[inline]
+    fn from(input: Notify) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Notify> for u16 {
+    #[inline]
+    fn from(input: Notify) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Notify> for Option<u16> {
+    #[inline]
+    fn from(input: Notify) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Notify> for u32 {
+    #[inline]
+    fn from(input: Notify) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Notify> for Option<u32> {
+    #[inline]
+    fn from(input: Notify) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Notify {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Notify  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Notify {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Notify {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Notify {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrtcChange {
+    pub timestamp: xproto::Timestamp,
+    pub window: xproto::Window,
+    pub crtc: Crtc,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+}
+impl TryParse for CrtcChange {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = CrtcChange { timestamp, window, crtc, mode, rotation, x, y, width, height };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CrtcChange {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for CrtcChange {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        [
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            0,
+            0,
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.crtc.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for CrtcChange {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+            tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+            tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+            tracer.field("x", |tracer| tracer.value(&self.x))?;
+            tracer.field("y", |tracer| tracer.value(&self.y))?;
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("height", |tracer| tracer.value(&self.height))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputChange {
+    pub timestamp: xproto::Timestamp,
+    pub config_timestamp: xproto::Timestamp,
+    pub window: xproto::Window,
+    pub output: Output,
+    pub crtc: Crtc,
+    pub mode: Mode,
+    pub rotation: u16,
+    pub connection: Connection,
+    pub subpixel_order: render::SubPixel,
+}
+impl TryParse for OutputChange {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (config_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (crtc, remaining) = Crtc::try_parse_with_order(remaining, order)?;
+        let (mode, remaining) = Mode::try_parse_with_order(remaining, order)?;
+        let (rotation, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (connection, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subpixel_order, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let connection = connection.into();
+        let subpixel_order = subpixel_order.into();
+        let result = OutputChange { timestamp, config_timestamp, window, output, crtc, mode, rotation, connection, subpixel_order };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for OutputChange {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for OutputChange {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let config_timestamp_bytes = self.config_timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let output_bytes = self.output.serialize_with_order(order);
+        let crtc_bytes = self.crtc.serialize_with_order(order);
+        let mode_bytes = self.mode.serialize_with_order(order);
+        let rotation_bytes = self.rotation.serialize_with_order(order);
+        let connection_bytes = u8::from(self.connection).serialize_with_order(order);
+        let subpixel_order_bytes = (u32::from(self.subpixel_order) as u8).serialize_with_order(order);
+        [
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            config_timestamp_bytes[0],
+            config_timestamp_bytes[1],
+            config_timestamp_bytes[2],
+            config_timestamp_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            crtc_bytes[0],
+            crtc_bytes[1],
+            crtc_bytes[2],
+            crtc_bytes[3],
+            mode_bytes[0],
+            mode_bytes[1],
+            mode_bytes[2],
+            mode_bytes[3],
+            rotation_bytes[0],
+            rotation_bytes[1],
+            connection_bytes[0],
+            subpixel_order_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.config_timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.output.serialize_into_with_order(bytes, order);
+        self.crtc.serialize_into_with_order(bytes, order);
+        self.mode.serialize_into_with_order(bytes, order);
+        self.rotation.serialize_into_with_order(bytes, order);
+        u8::from(self.connection).serialize_into_with_order(bytes, order);
+        (u32::from(self.subpixel_order) as u8).serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for OutputChange {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("config-timestamp", |tracer| tracer.value(&self.config_timestamp))?;
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("output", |tracer| tracer.xid(&self.output))?;
+            tracer.field("crtc", |tracer| tracer.xid(&self.crtc))?;
+            tracer.field("mode", |tracer| tracer.xid(&self.mode))?;
+            tracer.field("rotation", |tracer| tracer.bitmask(&self.rotation, Rotation::VARIANTS))?;
+            tracer.field("connection", |tracer| tracer.value(&self.connection))?;
+            tracer.field("subpixel-order", |tracer| tracer.value(&self.subpixel_order))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputProperty {
+    pub window: xproto::Window,
+    pub output: Output,
+    pub atom: xproto::Atom,
+    pub timestamp: xproto::Timestamp,
+    pub status: xproto::Property,
+}
+impl TryParse for OutputProperty {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (output, remaining) = Output::try_parse_with_order(remaining, order)?;
+        let (atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let status = status.into();
+        let result = OutputProperty { window, output, atom, timestamp, status };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for OutputProperty {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for OutputProperty {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let window_bytes = self.window.serialize_with_order(order);
+        let output_bytes = self.output.serialize_with_order(order);
+        let atom_bytes = self.atom.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let status_bytes = u8::from(self.status).serialize_with_order(order);
+        [
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            output_bytes[0],
+            output_bytes[1],
+            output_bytes[2],
+            output_bytes[3],
+            atom_bytes[0],
+            atom_bytes[1],
+            atom_bytes[2],
+            atom_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            status_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.window.serialize_into_with_order(bytes, order);
+        self.output.serialize_into_with_order(bytes, order);
+        self.atom.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        u8::from(self.status).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 11]);
+    }
+}
+impl Trace for OutputProperty {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("output", |tracer| tracer.xid(&self.output))?;
+            tracer.field("atom", |tracer| tracer.atom(&self.atom))?;
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("status", |tracer| tracer.value(&self.status))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProviderChange {
+    pub timestamp: xproto::Timestamp,
+    pub window: xproto::Window,
+    pub provider: Provider,
+}
+impl TryParse for ProviderChange {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let result = ProviderChange { timestamp, window, provider };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ProviderChange {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ProviderChange {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let provider_bytes = self.provider.serialize_with_order(order);
+        [
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.provider.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+    }
+}
+impl Trace for ProviderChange {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProviderProperty {
+    pub window: xproto::Window,
+    pub provider: Provider,
+    pub atom: xproto::Atom,
+    pub timestamp: xproto::Timestamp,
+    pub state: u8,
+}
+impl TryParse for ProviderProperty {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (provider, remaining) = Provider::try_parse_with_order(remaining, order)?;
+        let (atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let result = ProviderProperty { window, provider, atom, timestamp, state };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ProviderProperty {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ProviderProperty {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let window_bytes = self.window.serialize_with_order(order);
+        let provider_bytes = self.provider.serialize_with_order(order);
+        let atom_bytes = self.atom.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let state_bytes = self.state.serialize_with_order(order);
+        [
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            provider_bytes[0],
+            provider_bytes[1],
+            provider_bytes[2],
+            provider_bytes[3],
+            atom_bytes[0],
+            atom_bytes[1],
+            atom_bytes[2],
+            atom_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            state_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.window.serialize_into_with_order(bytes, order);
+        self.provider.serialize_into_with_order(bytes, order);
+        self.atom.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 11]);
+    }
+}
+impl Trace for ProviderProperty {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("provider", |tracer| tracer.xid(&self.provider))?;
+            tracer.field("atom", |tracer| tracer.atom(&self.atom))?;
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("state", |tracer| tracer.value(&self.state))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceChange {
+    pub timestamp: xproto::Timestamp,
+    pub window: xproto::Window,
+}
+impl TryParse for ResourceChange {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let result = ResourceChange { timestamp, window };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ResourceChange {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ResourceChange {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        [
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+    }
+}
+impl Trace for ResourceChange {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonitorInfo {
+    pub name: xproto::Atom,
+    pub primary: bool,
+    pub automatic: bool,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub width_in_millimeters: u32,
+    pub height_in_millimeters: u32,
+    pub outputs: Vec<Output>,
+}
+impl TryParse for MonitorInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (primary, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (automatic, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (n_output, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width_in_millimeters, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (height_in_millimeters, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, n_output.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = MonitorInfo { name, primary, automatic, x, y, width, height, width_in_millimeters, height_in_millimeters, outputs };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for MonitorInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for MonitorInfo {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(24);
+        self.name.serialize_into_with_order(bytes, order);
+        self.primary.serialize_into_with_order(bytes, order);
+        self.automatic.serialize_into_with_order(bytes, order);
+        let n_output = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        n_output.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.width_in_millimeters.serialize_into_with_order(bytes, order);
+        self.height_in_millimeters.serialize_into_with_order(bytes, order);
+        self.outputs.serialize_into_with_order(bytes, order);
+    }
+}
+impl MonitorInfo {
+    /// Get the value of the `nOutput` field.
+    ///
+    /// The `nOutput` field is used as the length field of the `outputs` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_output(&self) -> u16 {
+        self.outputs.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for MonitorInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("name", |tracer| tracer.atom(&self.name))?;
+            tracer.field("primary", |tracer| tracer.value(&self.primary))?;
+            tracer.field("automatic", |tracer| tracer.value(&self.automatic))?;
+            tracer.field("x", |tracer| tracer.value(&self.x))?;
+            tracer.field("y", |tracer| tracer.value(&self.y))?;
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("height", |tracer| tracer.value(&self.height))?;
+            tracer.field("width-in-millimeters", |tracer| tracer.value(&self.width_in_millimeters))?;
+            tracer.field("height-in-millimeters", |tracer| tracer.value(&self.height_in_millimeters))?;
+            tracer.field("outputs", |tracer| tracer.list(&self.outputs, |tracer, value| tracer.xid(value)))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the GetMonitors request
+pub const GET_MONITORS_REQUEST: u8 = 42;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMonitorsRequest {
+    pub window: xproto::Window,
+    pub get_active: bool,
+}
+impl GetMonitorsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let get_active_bytes = self.get_active.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MONITORS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            get_active_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MONITORS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (get_active, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetMonitorsRequest {
+            window,
+            get_active,
+        })
+    }
+}
+impl Request for GetMonitorsRequest {
+    type Reply = GetMonitorsReply;
+}
+impl Trace for GetMonitorsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("get-active", |tracer| tracer.value(&self.get_active))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMonitorsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub timestamp: xproto::Timestamp,
+    pub n_outputs: u32,
+    pub monitors: Vec<MonitorInfo>,
+}
+impl TryParse for GetMonitorsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (n_monitors, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_outputs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (monitors, remaining) = crate::x11_utils::parse_list_with_order::<MonitorInfo>(remaining, n_monitors.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMonitorsReply { sequence, length, timestamp, n_outputs, monitors };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMonitorsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMonitorsReply {
+    /// Get the value of the `nMonitors` field.
+    ///
+    /// The `nMonitors` field is used as the length field of the `monitors` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_monitors(&self) -> u32 {
+        self.monitors.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMonitorsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("nOutputs", |tracer| tracer.value(&self.n_outputs))?;
+        tracer.field("monitors", |tracer| tracer.list(&self.monitors, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMonitorsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        let n_monitors = u32::try_from(self.monitors.len()).expect("`monitors` has too many elements");
+        n_monitors.serialize_into_with_order(bytes, order);
+        self.n_outputs.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.monitors.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetMonitor request
+pub const SET_MONITOR_REQUEST: u8 = 43;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetMonitorRequest {
+    pub window: xproto::Window,
+    pub monitorinfo: MonitorInfo,
+}
+impl SetMonitorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_MONITOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let monitorinfo_bytes = self.monitorinfo.serialize_with_order(order);
+        let length_so_far = length_so_far + monitorinfo_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), monitorinfo_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_MONITOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (monitorinfo, remaining) = MonitorInfo::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetMonitorRequest {
+            window,
+            monitorinfo,
+        })
+    }
+}
+impl Request for SetMonitorRequest {
+    type Reply = ();
+}
+impl Trace for SetMonitorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("monitorinfo", |tracer| tracer.value(&self.monitorinfo))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteMonitor request
+pub const DELETE_MONITOR_REQUEST: u8 = 44;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteMonitorRequest {
+    pub window: xproto::Window,
+    pub name: xproto::Atom,
+}
+impl DeleteMonitorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_MONITOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_MONITOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(DeleteMonitorRequest {
+            window,
+            name,
+        })
+    }
+}
+impl Request for DeleteMonitorRequest {
+    type Reply = ();
+}
+impl Trace for DeleteMonitorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("name", |tracer| tracer.atom(&self.name))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateLease request
+pub const CREATE_LEASE_REQUEST: u8 = 45;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateLeaseRequest<'input> {
+    pub window: xproto::Window,
+    pub lid: Lease,
+    pub crtcs: Cow<'input, [Crtc]>,
+    pub outputs: Cow<'input, [Output]>,
+}
+impl<'input> CreateLeaseRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let lid_bytes = self.lid.serialize_with_order(order);
+        let num_crtcs = u16::try_from(self.crtcs.len()).expect("`crtcs` has too many elements");
+        let num_crtcs_bytes = num_crtcs.serialize_with_order(order);
+        let num_outputs = u16::try_from(self.outputs.len()).expect("`outputs` has too many elements");
+        let num_outputs_bytes = num_outputs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_LEASE_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            lid_bytes[0],
+            lid_bytes[1],
+            lid_bytes[2],
+            lid_bytes[3],
+            num_crtcs_bytes[0],
+            num_crtcs_bytes[1],
+            num_outputs_bytes[0],
+            num_outputs_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let crtcs_bytes = self.crtcs.serialize_with_order(order);
+        let length_so_far = length_so_far + crtcs_bytes.len();
+        let outputs_bytes = self.outputs.serialize_with_order(order);
+        let length_so_far = length_so_far + outputs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), crtcs_bytes.into(), outputs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_LEASE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (lid, remaining) = Lease::try_parse_with_order(remaining, order)?;
+        let (num_crtcs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_outputs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (crtcs, remaining) = crate::x11_utils::parse_list_with_order::<Crtc>(remaining, num_crtcs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (outputs, remaining) = crate::x11_utils::parse_list_with_order::<Output>(remaining, num_outputs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreateLeaseRequest {
+            window,
+            lid,
+            crtcs: Cow::Owned(crtcs),
+            outputs: Cow::Owned(outputs),
+        })
+    }
+    /// Clone all borrowed data in this CreateLeaseRequest.
+    pub fn into_owned(self) -> CreateLeaseRequest<'static> {
+        CreateLeaseRequest {
+            window: self.window,
+            lid: self.lid,
+            crtcs: Cow::Owned(self.crtcs.into_owned()),
+            outputs: Cow::Owned(self.outputs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateLeaseRequest<'input> {
+    type Reply = CreateLeaseReply;
+}
+impl Trace for CreateLeaseRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("lid", |tracer| tracer.xid(&self.lid))?;
+        tracer.field("crtcs", |tracer| tracer.list(&self.crtcs[..], |tracer, value| tracer.xid(value)))?;
+        tracer.field("outputs", |tracer| tracer.list(&self.outputs[..], |tracer, value| tracer.xid(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CreateLeaseReply {
+    pub nfd: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub master_fd: RawFdContainer,
+}
+impl TryParseFd for CreateLeaseReply {
+    fn try_parse_fd<'a>(value: &'a [u8], fds: &mut Vec<RawFdContainer>) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::try_parse_fd_with_order(value, fds, ByteOrder::native())
+    }
+    fn try_parse_fd_with_order<'a>(initial_value: &'a [u8], fds: &mut Vec<RawFdContainer>, order: ByteOrder) -> Result<(Self, &'a [u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (nfd, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if fds.is_empty() { return Err(ParseError::MissingFileDescriptors) }
+        let master_fd = fds.remove(0);
+        let remaining = remaining.get(24..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = CreateLeaseReply { nfd, sequence, length, master_fd };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<(&[u8], Vec<RawFdContainer>)> for CreateLeaseReply {
+    type Error = ParseError;
+    fn try_from(value: (&[u8], Vec<RawFdContainer>)) -> Result<Self, Self::Error> {
+        let (value, mut fds) = value;
+        Ok(Self::try_parse_fd(value, &mut fds)?.0)
+    }
+}
+impl Trace for CreateLeaseReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("nfd", |tracer| tracer.value(&self.nfd))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("master-fd", |tracer| tracer.value(&self.master_fd))?;
+        Ok(())
+    }
+}
+impl Serialize for CreateLeaseReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.nfd.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 24]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the FreeLease request
+pub const FREE_LEASE_REQUEST: u8 = 46;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreeLeaseRequest {
+    pub lid: Lease,
+    pub terminate: u8,
+}
+impl FreeLeaseRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let lid_bytes = self.lid.serialize_with_order(order);
+        let terminate_bytes = self.terminate.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FREE_LEASE_REQUEST,
+            0,
+            0,
+            lid_bytes[0],
+            lid_bytes[1],
+            lid_bytes[2],
+            lid_bytes[3],
+            terminate_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_LEASE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (lid, remaining) = Lease::try_parse_with_order(value, order)?;
+        let (terminate, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(FreeLeaseRequest {
+            lid,
+            terminate,
+        })
+    }
+}
+impl Request for FreeLeaseRequest {
+    type Reply = ();
+}
+impl Trace for FreeLeaseRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("lid", |tracer| tracer.xid(&self.lid))?;
+        tracer.field("terminate", |tracer| tracer.value(&self.terminate))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeaseNotify {
+    pub timestamp: xproto::Timestamp,
+    pub window: xproto::Window,
+    pub lease: Lease,
+    pub created: u8,
+}
+impl TryParse for LeaseNotify {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (lease, remaining) = Lease::try_parse_with_order(remaining, order)?;
+        let (created, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let result = LeaseNotify { timestamp, window, lease, created };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for LeaseNotify {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for LeaseNotify {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let lease_bytes = self.lease.serialize_with_order(order);
+        let created_bytes = self.created.serialize_with_order(order);
+        [
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            lease_bytes[0],
+            lease_bytes[1],
+            lease_bytes[2],
+            lease_bytes[3],
+            created_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.timestamp.serialize_into_with_order(bytes, order);
+        self.window.serialize_into_with_order(bytes, order);
+        self.lease.serialize_into_with_order(bytes, order);
+        self.created.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+    }
+}
+impl Trace for LeaseNotify {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+            tracer.field("window", |tracer| tracer.xid(&self.window))?;
+            tracer.field("lease", |tracer| tracer.xid(&self.lease))?;
+            tracer.field("created", |tracer| tracer.value(&self.created))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotifyData([u8; 28]);
+impl Trace for NotifyData {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.list(&self.0, |tracer, value| tracer.value(value))
+    }
+}
+impl NotifyData {
+    pub fn as_cc(&self) -> CrtcChange {
+        fn do_the_parse(remaining: &[u8]) -> Result<CrtcChange, ParseError> {
+            let order = ByteOrder::native();
+            let (cc, remaining) = CrtcChange::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(cc)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_oc(&self) -> OutputChange {
+        fn do_the_parse(remaining: &[u8]) -> Result<OutputChange, ParseError> {
+            let order = ByteOrder::native();
+            let (oc, remaining) = OutputChange::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(oc)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_op(&self) -> OutputProperty {
+        fn do_the_parse(remaining: &[u8]) -> Result<OutputProperty, ParseError> {
+            let order = ByteOrder::native();
+            let (op, remaining) = OutputProperty::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(op)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_pc(&self) -> ProviderChange {
+        fn do_the_parse(remaining: &[u8]) -> Result<ProviderChange, ParseError> {
+            let order = ByteOrder::native();
+            let (pc, remaining) = ProviderChange::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(pc)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_pp(&self) -> ProviderProperty {
+        fn do_the_parse(remaining: &[u8]) -> Result<ProviderProperty, ParseError> {
+            let order = ByteOrder::native();
+            let (pp, remaining) = ProviderProperty::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(pp)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_rc(&self) -> ResourceChange {
+        fn do_the_parse(remaining: &[u8]) -> Result<ResourceChange, ParseError> {
+            let order = ByteOrder::native();
+            let (rc, remaining) = ResourceChange::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(rc)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lc(&self) -> LeaseNotify {
+        fn do_the_parse(remaining: &[u8]) -> Result<LeaseNotify, ParseError> {
+            let order = ByteOrder::native();
+            let (lc, remaining) = LeaseNotify::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lc)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+}
+impl Serialize for NotifyData {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.0
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.0);
+    }
+    fn serialize_with_order(&self, _: ByteOrder) -> [u8; 28] {
+        self.0
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _: ByteOrder) {
+        bytes.extend_from_slice(&self.0);
+    }
+}
+impl TryParse for NotifyData {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let inner: [u8; 28] = value.get(..28)
+            .ok_or(ParseError::InsufficientData)?
+            .try_into()
+            .unwrap();
+        let result = NotifyData(inner);
+        Ok((result, &value[28..]))
+    }
+    fn try_parse_with_order(value: &[u8], _: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse(value)
+    }
+}
+impl From<CrtcChange> for NotifyData {
+    fn from(cc: CrtcChange) -> Self {
+        let order = ByteOrder::native();
+        let cc_bytes = cc.serialize_with_order(order);
+        Self(cc_bytes)
+    }
+}
+impl From<OutputChange> for NotifyData {
+    fn from(oc: OutputChange) -> Self {
+        let order = ByteOrder::native();
+        let oc_bytes = oc.serialize_with_order(order);
+        Self(oc_bytes)
+    }
+}
+impl From<OutputProperty> for NotifyData {
+    fn from(op: OutputProperty) -> Self {
+        let order = ByteOrder::native();
+        let op_bytes = op.serialize_with_order(order);
+        Self(op_bytes)
+    }
+}
+impl From<ProviderChange> for NotifyData {
+    fn from(pc: ProviderChange) -> Self {
+        let order = ByteOrder::native();
+        let pc_bytes = pc.serialize_with_order(order);
+        Self(pc_bytes)
+    }
+}
+impl From<ProviderProperty> for NotifyData {
+    fn from(pp: ProviderProperty) -> Self {
+        let order = ByteOrder::native();
+        let pp_bytes = pp.serialize_with_order(order);
+        Self(pp_bytes)
+    }
+}
+impl From<ResourceChange> for NotifyData {
+    fn from(rc: ResourceChange) -> Self {
+        let order = ByteOrder::native();
+        let rc_bytes = rc.serialize_with_order(order);
+        Self(rc_bytes)
+    }
+}
+impl From<LeaseNotify> for NotifyData {
+    fn from(lc: LeaseNotify) -> Self {
+        let order = ByteOrder::native();
+        let lc_bytes = lc.serialize_with_order(order);
+        Self(lc_bytes)
+    }
+}
+
+/// Opcode for the Notify event
+pub const NOTIFY_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotifyEvent {
+    pub response_type: u8,
+    pub sub_code: Notify,
+    pub sequence: u16,
+    pub u: NotifyData,
+}
+impl TryParse for NotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sub_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (u, remaining) = NotifyData::try_parse_with_order(remaining, order)?;
+        let sub_code = sub_code.into();
+        let result = NotifyEvent { response_type, sub_code, sequence, u };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for NotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("subCode", |tracer| tracer.value(&self.sub_code))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("u", |tracer| tracer.value(&self.u))?;
+        Ok(())
+    }
+}
+impl NotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sub_code_bytes = u8::from(self.sub_code).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let u_bytes = self.u.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            sub_code_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            u_bytes[0],
+            u_bytes[1],
+            u_bytes[2],
+            u_bytes[3],
+            u_bytes[4],
+            u_bytes[5],
+            u_bytes[6],
+            u_bytes[7],
+            u_bytes[8],
+            u_bytes[9],
+            u_bytes[10],
+            u_bytes[11],
+            u_bytes[12],
+            u_bytes[13],
+            u_bytes[14],
+            u_bytes[15],
+            u_bytes[16],
+            u_bytes[17],
+            u_bytes[18],
+            u_bytes[19],
+            u_bytes[20],
+            u_bytes[21],
+            u_bytes[22],
+            u_bytes[23],
+            u_bytes[24],
+            u_bytes[25],
+            u_bytes[26],
+            u_bytes[27],
+        ]
+    }
+}
+impl From<&NotifyEvent> for [u8; 32] {
+    fn from(input: &NotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetScreenConfig",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sizeID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rotation",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "rate",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SetConfig", variants: SetConfig::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "new_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "root",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "subpixel_order",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Enum(EnumInfo { name: "SubPixel", variants: render::SubPixel::VARIANTS }) }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SelectInput",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "enable",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NotifyMask", variants: NotifyMask::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetScreenInfo",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rotations",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "root",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nSizes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sizeID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rotation",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "rate",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nInfo",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sizes",
+                kind: FieldKind::List(TypeInfo { name: "ScreenSize", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rates",
+                kind: FieldKind::List(TypeInfo { name: "RefreshRates", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetScreenSizeRange",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "min_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "min_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "max_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "max_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetScreenSize",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mm_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mm_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetScreenResources",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_crtcs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_outputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_modes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "names_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtcs",
+                kind: FieldKind::List(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "outputs",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modes",
+                kind: FieldKind::List(TypeInfo { name: "ModeInfo", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "names",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetOutputInfo",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SetConfig", variants: SetConfig::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mm_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mm_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "connection",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Connection", variants: Connection::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "subpixel_order",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SubPixel", variants: render::SubPixel::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "num_crtcs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_modes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_preferred",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_clones",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtcs",
+                kind: FieldKind::List(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modes",
+                kind: FieldKind::List(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clones",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "ListOutputProperties",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_atoms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "atoms",
+                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "QueryOutputProperty",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "range",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "immutable",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "validValues",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "ConfigureOutputProperty",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "range",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "values",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "ChangeOutputProperty",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "PropMode", variants: xproto::PropMode::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "num_units",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "void", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "DeleteOutputProperty",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetOutputProperty",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::AltEnum(EnumInfo { name: "GetPropertyType", variants: xproto::GetPropertyType::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "long_offset",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "long_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "delete",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::AltEnum(EnumInfo { name: "Atom", variants: xproto::AtomEnum::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "bytes_after",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_items",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "CreateMode",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode_info",
+                kind: FieldKind::Value(TypeInfo { name: "ModeInfo", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "DestroyMode",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "AddOutputMode",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "DeleteOutputMode",
+        opcode: 19,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetCrtcInfo",
+        opcode: 20,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SetConfig", variants: SetConfig::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rotation",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "rotations",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "num_outputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_possible_outputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "outputs",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "possible",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetCrtcConfig",
+        opcode: 21,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "MODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rotation",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "outputs",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SetConfig", variants: SetConfig::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetCrtcGammaSize",
+        opcode: 22,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetCrtcGamma",
+        opcode: 23,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "red",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "green",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "blue",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetCrtcGamma",
+        opcode: 24,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "red",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "green",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "blue",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetScreenResourcesCurrent",
+        opcode: 25,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_crtcs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_outputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_modes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "names_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtcs",
+                kind: FieldKind::List(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "outputs",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modes",
+                kind: FieldKind::List(TypeInfo { name: "ModeInfo", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "names",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetCrtcTransform",
+        opcode: 26,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "transform",
+                kind: FieldKind::Value(TypeInfo { name: "TRANSFORM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "filter_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "filter_name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "filter_params",
+                kind: FieldKind::List(TypeInfo { name: "FIXED", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetCrtcTransform",
+        opcode: 27,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending_transform",
+                kind: FieldKind::Value(TypeInfo { name: "TRANSFORM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "has_transforms",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "current_transform",
+                kind: FieldKind::Value(TypeInfo { name: "TRANSFORM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending_nparams",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "current_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "current_nparams",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending_filter_name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending_params",
+                kind: FieldKind::List(TypeInfo { name: "FIXED", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "current_filter_name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "current_params",
+                kind: FieldKind::List(TypeInfo { name: "FIXED", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetPanning",
+        opcode: 28,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SetConfig", variants: SetConfig::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "left",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "top",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_left",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_top",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_left",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_top",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_right",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_bottom",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetPanning",
+        opcode: 29,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtc",
+                kind: FieldKind::Value(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "left",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "top",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_left",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_top",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "track_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_left",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_top",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_right",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_bottom",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SetConfig", variants: SetConfig::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetOutputPrimary",
+        opcode: 30,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetOutputPrimary",
+        opcode: 31,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "output",
+                kind: FieldKind::Value(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetProviders",
+        opcode: 32,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_providers",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "providers",
+                kind: FieldKind::List(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetProviderInfo",
+        opcode: 33,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "capabilities",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ProviderCapability", variants: ProviderCapability::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "num_crtcs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_outputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_associated_providers",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtcs",
+                kind: FieldKind::List(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "outputs",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "associated_providers",
+                kind: FieldKind::List(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "associated_capability",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetProviderOffloadSink",
+        opcode: 34,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sink_provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetProviderOutputSource",
+        opcode: 35,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source_provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "ListProviderProperties",
+        opcode: 36,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_atoms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "atoms",
+                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "QueryProviderProperty",
+        opcode: 37,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "range",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "immutable",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "valid_values",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "ConfigureProviderProperty",
+        opcode: 38,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "range",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "values",
+                kind: FieldKind::List(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "ChangeProviderProperty",
+        opcode: 39,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_items",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "void", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "DeleteProviderProperty",
+        opcode: 40,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetProviderProperty",
+        opcode: 41,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "provider",
+                kind: FieldKind::Value(TypeInfo { name: "PROVIDER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "property",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "long_offset",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "long_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "delete",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pending",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "type",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bytes_after",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_items",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "void", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "GetMonitors",
+        opcode: 42,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "get_active",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nMonitors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nOutputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "monitors",
+                kind: FieldKind::List(TypeInfo { name: "MonitorInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "SetMonitor",
+        opcode: 43,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "monitorinfo",
+                kind: FieldKind::Value(TypeInfo { name: "MonitorInfo", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "DeleteMonitor",
+        opcode: 44,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "CreateLease",
+        opcode: 45,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "lid",
+                kind: FieldKind::Value(TypeInfo { name: "LEASE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_crtcs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_outputs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "crtcs",
+                kind: FieldKind::List(TypeInfo { name: "CRTC", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "outputs",
+                kind: FieldKind::List(TypeInfo { name: "OUTPUT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nfd",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "master_fd",
+                kind: FieldKind::Fd,
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("RandR"),
+        name: "FreeLease",
+        opcode: 46,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "lid",
+                kind: FieldKind::Value(TypeInfo { name: "LEASE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "terminate",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("RandR"),
+        name: "Notify",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "subCode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Notify", variants: Notify::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "u",
+                kind: FieldKind::Value(TypeInfo { name: "NotifyData", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("RandR"),
+        name: "ScreenChangeNotify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rotation",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "Rotation", variants: Rotation::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "config_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "root",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "request_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sizeID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "subpixel_order",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Enum(EnumInfo { name: "SubPixel", variants: render::SubPixel::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mwidth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mheight",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
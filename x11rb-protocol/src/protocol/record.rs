@@ -0,0 +1,1751 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Record` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "RECORD";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 13);
+
+pub type Context = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Range8 {
+    pub first: u8,
+    pub last: u8,
+}
+impl TryParse for Range8 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (first, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (last, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = Range8 { first, last };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Range8 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Range8 {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let first_bytes = self.first.serialize_with_order(order);
+        let last_bytes = self.last.serialize_with_order(order);
+        [
+            first_bytes[0],
+            last_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.first.serialize_into_with_order(bytes, order);
+        self.last.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Range8 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("first", |tracer| tracer.value(&self.first))?;
+            tracer.field("last", |tracer| tracer.value(&self.last))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Range16 {
+    pub first: u16,
+    pub last: u16,
+}
+impl TryParse for Range16 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (first, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (last, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = Range16 { first, last };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Range16 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Range16 {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let first_bytes = self.first.serialize_with_order(order);
+        let last_bytes = self.last.serialize_with_order(order);
+        [
+            first_bytes[0],
+            first_bytes[1],
+            last_bytes[0],
+            last_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.first.serialize_into_with_order(bytes, order);
+        self.last.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Range16 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("first", |tracer| tracer.value(&self.first))?;
+            tracer.field("last", |tracer| tracer.value(&self.last))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtRange {
+    pub major: Range8,
+    pub minor: Range16,
+}
+impl TryParse for ExtRange {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (major, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (minor, remaining) = Range16::try_parse_with_order(remaining, order)?;
+        let result = ExtRange { major, minor };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ExtRange {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ExtRange {
+    type Bytes = [u8; 6];
+    fn serialize(&self) -> [u8; 6] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 6] {
+        let major_bytes = self.major.serialize_with_order(order);
+        let minor_bytes = self.minor.serialize_with_order(order);
+        [
+            major_bytes[0],
+            major_bytes[1],
+            minor_bytes[0],
+            minor_bytes[1],
+            minor_bytes[2],
+            minor_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(6);
+        self.major.serialize_into_with_order(bytes, order);
+        self.minor.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ExtRange {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("major", |tracer| tracer.value(&self.major))?;
+            tracer.field("minor", |tracer| tracer.value(&self.minor))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Range {
+    pub core_requests: Range8,
+    pub core_replies: Range8,
+    pub ext_requests: ExtRange,
+    pub ext_replies: ExtRange,
+    pub delivered_events: Range8,
+    pub device_events: Range8,
+    pub errors: Range8,
+    pub client_started: bool,
+    pub client_died: bool,
+}
+impl TryParse for Range {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (core_requests, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (core_replies, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (ext_requests, remaining) = ExtRange::try_parse_with_order(remaining, order)?;
+        let (ext_replies, remaining) = ExtRange::try_parse_with_order(remaining, order)?;
+        let (delivered_events, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (device_events, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (errors, remaining) = Range8::try_parse_with_order(remaining, order)?;
+        let (client_started, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (client_died, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let result = Range { core_requests, core_replies, ext_requests, ext_replies, delivered_events, device_events, errors, client_started, client_died };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Range {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Range {
+    type Bytes = [u8; 24];
+    fn serialize(&self) -> [u8; 24] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 24] {
+        let core_requests_bytes = self.core_requests.serialize_with_order(order);
+        let core_replies_bytes = self.core_replies.serialize_with_order(order);
+        let ext_requests_bytes = self.ext_requests.serialize_with_order(order);
+        let ext_replies_bytes = self.ext_replies.serialize_with_order(order);
+        let delivered_events_bytes = self.delivered_events.serialize_with_order(order);
+        let device_events_bytes = self.device_events.serialize_with_order(order);
+        let errors_bytes = self.errors.serialize_with_order(order);
+        let client_started_bytes = self.client_started.serialize_with_order(order);
+        let client_died_bytes = self.client_died.serialize_with_order(order);
+        [
+            core_requests_bytes[0],
+            core_requests_bytes[1],
+            core_replies_bytes[0],
+            core_replies_bytes[1],
+            ext_requests_bytes[0],
+            ext_requests_bytes[1],
+            ext_requests_bytes[2],
+            ext_requests_bytes[3],
+            ext_requests_bytes[4],
+            ext_requests_bytes[5],
+            ext_replies_bytes[0],
+            ext_replies_bytes[1],
+            ext_replies_bytes[2],
+            ext_replies_bytes[3],
+            ext_replies_bytes[4],
+            ext_replies_bytes[5],
+            delivered_events_bytes[0],
+            delivered_events_bytes[1],
+            device_events_bytes[0],
+            device_events_bytes[1],
+            errors_bytes[0],
+            errors_bytes[1],
+            client_started_bytes[0],
+            client_died_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(24);
+        self.core_requests.serialize_into_with_order(bytes, order);
+        self.core_replies.serialize_into_with_order(bytes, order);
+        self.ext_requests.serialize_into_with_order(bytes, order);
+        self.ext_replies.serialize_into_with_order(bytes, order);
+        self.delivered_events.serialize_into_with_order(bytes, order);
+        self.device_events.serialize_into_with_order(bytes, order);
+        self.errors.serialize_into_with_order(bytes, order);
+        self.client_started.serialize_into_with_order(bytes, order);
+        self.client_died.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Range {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("core-requests", |tracer| tracer.value(&self.core_requests))?;
+            tracer.field("core-replies", |tracer| tracer.value(&self.core_replies))?;
+            tracer.field("ext-requests", |tracer| tracer.value(&self.ext_requests))?;
+            tracer.field("ext-replies", |tracer| tracer.value(&self.ext_replies))?;
+            tracer.field("delivered-events", |tracer| tracer.value(&self.delivered_events))?;
+            tracer.field("device-events", |tracer| tracer.value(&self.device_events))?;
+            tracer.field("errors", |tracer| tracer.value(&self.errors))?;
+            tracer.field("client-started", |tracer| tracer.value(&self.client_started))?;
+            tracer.field("client-died", |tracer| tracer.value(&self.client_died))?;
+            Ok(())
+        })
+    }
+}
+
+pub type ElementHeader = u8;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct HType(u8);
+impl HType {
+    pub const FROM_SERVER_TIME: Self = Self(1 << 0);
+    pub const FROM_CLIENT_TIME: Self = Self(1 << 1);
+    pub const FROM_CLIENT_SEQUENCE: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "FROM_SERVER_TIME", "FromServerTime"),
+        (1 << 1, "FROM_CLIENT_TIME", "FromClientTime"),
+        (1 << 2, "FROM_CLIENT_SEQUENCE", "FromClientSequence"),
+    ];
+}
+impl From<HType> for u8 {
+    #[inline]
+    fn from(input: HType) -> Self {
+        input.0
+    }
+}
+impl From<HType> for Option<u8> {
+    #[inline]
+    fn from(input: HType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<HType> for u16 {
+    #[inline]
+    fn from(input: HType) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<HType> for Option<u16> {
+    #[inline]
+    fn from(input: HType) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<HType> for u32 {
+    #[inline]
+    fn from(input: HType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<HType> for Option<u32> {
+    #[inline]
+    fn from(input: HType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for HType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for HType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for HType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for HType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(HType, u8);
+
+pub type ClientSpec = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CS(u8);
+impl CS {
+    pub const CURRENT_CLIENTS: Self = Self(1);
+    pub const FUTURE_CLIENTS: Self = Self(2);
+    pub const ALL_CLIENTS: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1, "CURRENT_CLIENTS", "CurrentClients"),
+        (2, "FUTURE_CLIENTS", "FutureClients"),
+        (3, "ALL_CLIENTS", "AllClients"),
+    ];
+}
+impl From<CS> for u8 {
+    #[inline]
+    fn from(input: CS) -> Self {
+        input.0
+    }
+}
+impl From<CS> for Option<u8> {
+    #[inline]
+    fn from(input: CS) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CS> for u16 {
+    #[inline]
+    fn from(input: CS) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CS> for Option<u16> {
+    #[inline]
+    fn from(input: CS) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CS> for u32 {
+    #[inline]
+    fn from(input: CS) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CS> for Option<u32> {
+    #[inline]
+    fn from(input: CS) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CS {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CS  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CS {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CS {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CS {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClientInfo {
+    pub client_resource: ClientSpec,
+    pub ranges: Vec<Range>,
+}
+impl TryParse for ClientInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (client_resource, remaining) = ClientSpec::try_parse_with_order(remaining, order)?;
+        let (num_ranges, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ranges, remaining) = crate::x11_utils::parse_list_with_order::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = ClientInfo { client_resource, ranges };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ClientInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ClientInfo {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.client_resource.serialize_into_with_order(bytes, order);
+        let num_ranges = u32::try_from(self.ranges.len()).expect("`ranges` has too many elements");
+        num_ranges.serialize_into_with_order(bytes, order);
+        self.ranges.serialize_into_with_order(bytes, order);
+    }
+}
+impl ClientInfo {
+    /// Get the value of the `num_ranges` field.
+    ///
+    /// The `num_ranges` field is used as the length field of the `ranges` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_ranges(&self) -> u32 {
+        self.ranges.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ClientInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("client-resource", |tracer| tracer.value(&self.client_resource))?;
+            tracer.field("ranges", |tracer| tracer.list(&self.ranges, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the BadContext error
+pub const BAD_CONTEXT_ERROR: u8 = 0;
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_version_bytes = self.major_version.serialize_with_order(order);
+        let minor_version_bytes = self.minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            major_version_bytes[0],
+            major_version_bytes[1],
+            minor_version_bytes[0],
+            minor_version_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major_version, remaining) = u16::try_parse_with_order(value, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            major_version,
+            minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreateContext request
+pub const CREATE_CONTEXT_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateContextRequest<'input> {
+    pub context: Context,
+    pub element_header: ElementHeader,
+    pub client_specs: Cow<'input, [ClientSpec]>,
+    pub ranges: Cow<'input, [Range]>,
+}
+impl<'input> CreateContextRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let element_header_bytes = self.element_header.serialize_with_order(order);
+        let num_client_specs = u32::try_from(self.client_specs.len()).expect("`client_specs` has too many elements");
+        let num_client_specs_bytes = num_client_specs.serialize_with_order(order);
+        let num_ranges = u32::try_from(self.ranges.len()).expect("`ranges` has too many elements");
+        let num_ranges_bytes = num_ranges.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            element_header_bytes[0],
+            0,
+            0,
+            0,
+            num_client_specs_bytes[0],
+            num_client_specs_bytes[1],
+            num_client_specs_bytes[2],
+            num_client_specs_bytes[3],
+            num_ranges_bytes[0],
+            num_ranges_bytes[1],
+            num_ranges_bytes[2],
+            num_ranges_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let client_specs_bytes = self.client_specs.serialize_with_order(order);
+        let length_so_far = length_so_far + client_specs_bytes.len();
+        let ranges_bytes = self.ranges.serialize_with_order(order);
+        let length_so_far = length_so_far + ranges_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), client_specs_bytes.into(), ranges_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (num_client_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_ranges, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientSpec>(remaining, num_client_specs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (ranges, remaining) = crate::x11_utils::parse_list_with_order::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreateContextRequest {
+            context,
+            element_header,
+            client_specs: Cow::Owned(client_specs),
+            ranges: Cow::Owned(ranges),
+        })
+    }
+    /// Clone all borrowed data in this CreateContextRequest.
+    pub fn into_owned(self) -> CreateContextRequest<'static> {
+        CreateContextRequest {
+            context: self.context,
+            element_header: self.element_header,
+            client_specs: Cow::Owned(self.client_specs.into_owned()),
+            ranges: Cow::Owned(self.ranges.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateContextRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreateContextRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("element-header", |tracer| tracer.value(&self.element_header))?;
+        tracer.field("client-specs", |tracer| tracer.list(&self.client_specs[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("ranges", |tracer| tracer.list(&self.ranges[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the RegisterClients request
+pub const REGISTER_CLIENTS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterClientsRequest<'input> {
+    pub context: Context,
+    pub element_header: ElementHeader,
+    pub client_specs: Cow<'input, [ClientSpec]>,
+    pub ranges: Cow<'input, [Range]>,
+}
+impl<'input> RegisterClientsRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let element_header_bytes = self.element_header.serialize_with_order(order);
+        let num_client_specs = u32::try_from(self.client_specs.len()).expect("`client_specs` has too many elements");
+        let num_client_specs_bytes = num_client_specs.serialize_with_order(order);
+        let num_ranges = u32::try_from(self.ranges.len()).expect("`ranges` has too many elements");
+        let num_ranges_bytes = num_ranges.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            REGISTER_CLIENTS_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            element_header_bytes[0],
+            0,
+            0,
+            0,
+            num_client_specs_bytes[0],
+            num_client_specs_bytes[1],
+            num_client_specs_bytes[2],
+            num_client_specs_bytes[3],
+            num_ranges_bytes[0],
+            num_ranges_bytes[1],
+            num_ranges_bytes[2],
+            num_ranges_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let client_specs_bytes = self.client_specs.serialize_with_order(order);
+        let length_so_far = length_so_far + client_specs_bytes.len();
+        let ranges_bytes = self.ranges.serialize_with_order(order);
+        let length_so_far = length_so_far + ranges_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), client_specs_bytes.into(), ranges_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REGISTER_CLIENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (num_client_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_ranges, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientSpec>(remaining, num_client_specs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (ranges, remaining) = crate::x11_utils::parse_list_with_order::<Range>(remaining, num_ranges.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(RegisterClientsRequest {
+            context,
+            element_header,
+            client_specs: Cow::Owned(client_specs),
+            ranges: Cow::Owned(ranges),
+        })
+    }
+    /// Clone all borrowed data in this RegisterClientsRequest.
+    pub fn into_owned(self) -> RegisterClientsRequest<'static> {
+        RegisterClientsRequest {
+            context: self.context,
+            element_header: self.element_header,
+            client_specs: Cow::Owned(self.client_specs.into_owned()),
+            ranges: Cow::Owned(self.ranges.into_owned()),
+        }
+    }
+}
+impl<'input> Request for RegisterClientsRequest<'input> {
+    type Reply = ();
+}
+impl Trace for RegisterClientsRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("element-header", |tracer| tracer.value(&self.element_header))?;
+        tracer.field("client-specs", |tracer| tracer.list(&self.client_specs[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("ranges", |tracer| tracer.list(&self.ranges[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the UnregisterClients request
+pub const UNREGISTER_CLIENTS_REQUEST: u8 = 3;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnregisterClientsRequest<'input> {
+    pub context: Context,
+    pub client_specs: Cow<'input, [ClientSpec]>,
+}
+impl<'input> UnregisterClientsRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let num_client_specs = u32::try_from(self.client_specs.len()).expect("`client_specs` has too many elements");
+        let num_client_specs_bytes = num_client_specs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            UNREGISTER_CLIENTS_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+            num_client_specs_bytes[0],
+            num_client_specs_bytes[1],
+            num_client_specs_bytes[2],
+            num_client_specs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let client_specs_bytes = self.client_specs.serialize_with_order(order);
+        let length_so_far = length_so_far + client_specs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), client_specs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNREGISTER_CLIENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let (num_client_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (client_specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientSpec>(remaining, num_client_specs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(UnregisterClientsRequest {
+            context,
+            client_specs: Cow::Owned(client_specs),
+        })
+    }
+    /// Clone all borrowed data in this UnregisterClientsRequest.
+    pub fn into_owned(self) -> UnregisterClientsRequest<'static> {
+        UnregisterClientsRequest {
+            context: self.context,
+            client_specs: Cow::Owned(self.client_specs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for UnregisterClientsRequest<'input> {
+    type Reply = ();
+}
+impl Trace for UnregisterClientsRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        tracer.field("client-specs", |tracer| tracer.list(&self.client_specs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetContext request
+pub const GET_CONTEXT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetContextRequest {
+    pub context: Context,
+}
+impl GetContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetContextRequest {
+            context,
+        })
+    }
+}
+impl Request for GetContextRequest {
+    type Reply = GetContextReply;
+}
+impl Trace for GetContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetContextReply {
+    pub enabled: bool,
+    pub sequence: u16,
+    pub length: u32,
+    pub element_header: ElementHeader,
+    pub intercepted_clients: Vec<ClientInfo>,
+}
+impl TryParse for GetContextReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (enabled, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (num_intercepted_clients, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (intercepted_clients, remaining) = crate::x11_utils::parse_list_with_order::<ClientInfo>(remaining, num_intercepted_clients.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetContextReply { enabled, sequence, length, element_header, intercepted_clients };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetContextReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetContextReply {
+    /// Get the value of the `num_intercepted_clients` field.
+    ///
+    /// The `num_intercepted_clients` field is used as the length field of the `intercepted_clients` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_intercepted_clients(&self) -> u32 {
+        self.intercepted_clients.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetContextReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("enabled", |tracer| tracer.value(&self.enabled))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("element-header", |tracer| tracer.value(&self.element_header))?;
+        tracer.field("intercepted-clients", |tracer| tracer.list(&self.intercepted_clients, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.enabled.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.element_header.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        let num_intercepted_clients = u32::try_from(self.intercepted_clients.len()).expect("`intercepted_clients` has too many elements");
+        num_intercepted_clients.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.intercepted_clients.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the EnableContext request
+pub const ENABLE_CONTEXT_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnableContextRequest {
+    pub context: Context,
+}
+impl EnableContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            ENABLE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ENABLE_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(EnableContextRequest {
+            context,
+        })
+    }
+}
+impl Request for EnableContextRequest {
+    type Reply = EnableContextReply;
+}
+impl Trace for EnableContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnableContextReply {
+    pub category: u8,
+    pub sequence: u16,
+    pub element_header: ElementHeader,
+    pub client_swapped: bool,
+    pub xid_base: u32,
+    pub server_time: u32,
+    pub rec_sequence_num: u32,
+    pub data: Vec<u8>,
+}
+impl TryParse for EnableContextReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (category, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (element_header, remaining) = ElementHeader::try_parse_with_order(remaining, order)?;
+        let (client_swapped, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (xid_base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_time, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rec_sequence_num, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, length.checked_mul(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = EnableContextReply { category, sequence, element_header, client_swapped, xid_base, server_time, rec_sequence_num, data };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for EnableContextReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl EnableContextReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.data.len()
+            .checked_div(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for EnableContextReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("category", |tracer| tracer.value(&self.category))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("element-header", |tracer| tracer.value(&self.element_header))?;
+        tracer.field("client-swapped", |tracer| tracer.value(&self.client_swapped))?;
+        tracer.field("xid-base", |tracer| tracer.value(&self.xid_base))?;
+        tracer.field("server-time", |tracer| tracer.value(&self.server_time))?;
+        tracer.field("rec-sequence-num", |tracer| tracer.value(&self.rec_sequence_num))?;
+        tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for EnableContextReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.category.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        assert_eq!(self.data.len() % 4, 0, "`data` has an incorrect length, must be a multiple of 4");
+        let length = u32::try_from(self.data.len() / 4).expect("`data` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.element_header.serialize_into_with_order(bytes, order);
+        self.client_swapped.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.xid_base.serialize_into_with_order(bytes, order);
+        self.server_time.serialize_into_with_order(bytes, order);
+        self.rec_sequence_num.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&self.data);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the DisableContext request
+pub const DISABLE_CONTEXT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisableContextRequest {
+    pub context: Context,
+}
+impl DisableContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DISABLE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DISABLE_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DisableContextRequest {
+            context,
+        })
+    }
+}
+impl Request for DisableContextRequest {
+    type Reply = ();
+}
+impl Trace for DisableContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the FreeContext request
+pub const FREE_CONTEXT_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreeContextRequest {
+    pub context: Context,
+}
+impl FreeContextRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let context_bytes = self.context.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FREE_CONTEXT_REQUEST,
+            0,
+            0,
+            context_bytes[0],
+            context_bytes[1],
+            context_bytes[2],
+            context_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FREE_CONTEXT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (context, remaining) = Context::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(FreeContextRequest {
+            context,
+        })
+    }
+}
+impl Request for FreeContextRequest {
+    type Reply = ();
+}
+impl Trace for FreeContextRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("context", |tracer| tracer.xid(&self.context))?;
+        Ok(())
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Record"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "CreateContext",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "element_header",
+                kind: FieldKind::Value(TypeInfo { name: "ElementHeader", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_client_specs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_ranges",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_specs",
+                kind: FieldKind::List(TypeInfo { name: "ClientSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ranges",
+                kind: FieldKind::List(TypeInfo { name: "Range", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "RegisterClients",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "element_header",
+                kind: FieldKind::Value(TypeInfo { name: "ElementHeader", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_client_specs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_ranges",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_specs",
+                kind: FieldKind::List(TypeInfo { name: "ClientSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ranges",
+                kind: FieldKind::List(TypeInfo { name: "Range", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "UnregisterClients",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_client_specs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_specs",
+                kind: FieldKind::List(TypeInfo { name: "ClientSpec", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "GetContext",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "enabled",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "element_header",
+                kind: FieldKind::Value(TypeInfo { name: "ElementHeader", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_intercepted_clients",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "intercepted_clients",
+                kind: FieldKind::List(TypeInfo { name: "ClientInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "EnableContext",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "category",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "element_header",
+                kind: FieldKind::Value(TypeInfo { name: "ElementHeader", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_swapped",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xid_base",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_time",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rec_sequence_num",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "BYTE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "DisableContext",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Record"),
+        name: "FreeContext",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "context",
+                kind: FieldKind::Value(TypeInfo { name: "record:CONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
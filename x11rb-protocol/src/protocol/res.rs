@@ -0,0 +1,1730 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Res` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "X-Resource";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Client {
+    pub resource_base: u32,
+    pub resource_mask: u32,
+}
+impl TryParse for Client {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resource_base, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (resource_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = Client { resource_base, resource_mask };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Client {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Client {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let resource_base_bytes = self.resource_base.serialize_with_order(order);
+        let resource_mask_bytes = self.resource_mask.serialize_with_order(order);
+        [
+            resource_base_bytes[0],
+            resource_base_bytes[1],
+            resource_base_bytes[2],
+            resource_base_bytes[3],
+            resource_mask_bytes[0],
+            resource_mask_bytes[1],
+            resource_mask_bytes[2],
+            resource_mask_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.resource_base.serialize_into_with_order(bytes, order);
+        self.resource_mask.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Client {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("resource-base", |tracer| tracer.value(&self.resource_base))?;
+            tracer.field("resource-mask", |tracer| tracer.value(&self.resource_mask))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Type {
+    pub resource_type: xproto::Atom,
+    pub count: u32,
+}
+impl TryParse for Type {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resource_type, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = Type { resource_type, count };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Type {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Type {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let resource_type_bytes = self.resource_type.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        [
+            resource_type_bytes[0],
+            resource_type_bytes[1],
+            resource_type_bytes[2],
+            resource_type_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            count_bytes[2],
+            count_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.resource_type.serialize_into_with_order(bytes, order);
+        self.count.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Type {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("resource-type", |tracer| tracer.atom(&self.resource_type))?;
+            tracer.field("count", |tracer| tracer.value(&self.count))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ClientIdMask(u8);
+impl ClientIdMask {
+    pub const CLIENT_XID: Self = Self(1 << 0);
+    pub const LOCAL_CLIENT_PID: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "CLIENT_XID", "ClientXID"),
+        (1 << 1, "LOCAL_CLIENT_PID", "LocalClientPID"),
+    ];
+}
+impl From<ClientIdMask> for u8 {
+    #[inline]
+    fn from(input: ClientIdMask) -> Self {
+        input.0
+    }
+}
+impl From<ClientIdMask> for Option<u8> {
+    #[inline]
+    fn from(input: ClientIdMask) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ClientIdMask> for u16 {
+    #[inline]
+    fn from(input: ClientIdMask) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ClientIdMask> for Option<u16> {
+    #[inline]
+    fn from(input: ClientIdMask) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ClientIdMask> for u32 {
+    #[inline]
+    fn from(input: ClientIdMask) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ClientIdMask> for Option<u32> {
+    #[inline]
+    fn from(input: ClientIdMask) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ClientIdMask {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ClientIdMask  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ClientIdMask {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClientIdMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClientIdMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(ClientIdMask, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClientIdSpec {
+    pub client: u32,
+    pub mask: u32,
+}
+impl TryParse for ClientIdSpec {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (client, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = ClientIdSpec { client, mask };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ClientIdSpec {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ClientIdSpec {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let client_bytes = self.client.serialize_with_order(order);
+        let mask_bytes = self.mask.serialize_with_order(order);
+        [
+            client_bytes[0],
+            client_bytes[1],
+            client_bytes[2],
+            client_bytes[3],
+            mask_bytes[0],
+            mask_bytes[1],
+            mask_bytes[2],
+            mask_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.client.serialize_into_with_order(bytes, order);
+        self.mask.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ClientIdSpec {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("client", |tracer| tracer.value(&self.client))?;
+            tracer.field("mask", |tracer| tracer.bitmask(&self.mask, ClientIdMask::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClientIdValue {
+    pub spec: ClientIdSpec,
+    pub value: Vec<u32>,
+}
+impl TryParse for ClientIdValue {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (spec, remaining) = ClientIdSpec::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, length.checked_div(4u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = ClientIdValue { spec, value };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ClientIdValue {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ClientIdValue {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(12);
+        self.spec.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.value.len()).ok().and_then(|len| len.checked_mul(4)).expect("`value` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+    }
+}
+impl ClientIdValue {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `value` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.value.len()
+            .checked_mul(4).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ClientIdValue {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("spec", |tracer| tracer.value(&self.spec))?;
+            tracer.field("value", |tracer| tracer.list(&self.value, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceIdSpec {
+    pub resource: u32,
+    pub type_: u32,
+}
+impl TryParse for ResourceIdSpec {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (resource, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = ResourceIdSpec { resource, type_ };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ResourceIdSpec {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ResourceIdSpec {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let resource_bytes = self.resource.serialize_with_order(order);
+        let type_bytes = self.type_.serialize_with_order(order);
+        [
+            resource_bytes[0],
+            resource_bytes[1],
+            resource_bytes[2],
+            resource_bytes[3],
+            type_bytes[0],
+            type_bytes[1],
+            type_bytes[2],
+            type_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.resource.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ResourceIdSpec {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("resource", |tracer| tracer.value(&self.resource))?;
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceSizeSpec {
+    pub spec: ResourceIdSpec,
+    pub bytes: u32,
+    pub ref_count: u32,
+    pub use_count: u32,
+}
+impl TryParse for ResourceSizeSpec {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (spec, remaining) = ResourceIdSpec::try_parse_with_order(remaining, order)?;
+        let (bytes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ref_count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (use_count, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = ResourceSizeSpec { spec, bytes, ref_count, use_count };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ResourceSizeSpec {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ResourceSizeSpec {
+    type Bytes = [u8; 20];
+    fn serialize(&self) -> [u8; 20] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let spec_bytes = self.spec.serialize_with_order(order);
+        let bytes_bytes = self.bytes.serialize_with_order(order);
+        let ref_count_bytes = self.ref_count.serialize_with_order(order);
+        let use_count_bytes = self.use_count.serialize_with_order(order);
+        [
+            spec_bytes[0],
+            spec_bytes[1],
+            spec_bytes[2],
+            spec_bytes[3],
+            spec_bytes[4],
+            spec_bytes[5],
+            spec_bytes[6],
+            spec_bytes[7],
+            bytes_bytes[0],
+            bytes_bytes[1],
+            bytes_bytes[2],
+            bytes_bytes[3],
+            ref_count_bytes[0],
+            ref_count_bytes[1],
+            ref_count_bytes[2],
+            ref_count_bytes[3],
+            use_count_bytes[0],
+            use_count_bytes[1],
+            use_count_bytes[2],
+            use_count_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(20);
+        self.spec.serialize_into_with_order(bytes, order);
+        self.bytes.serialize_into_with_order(bytes, order);
+        self.ref_count.serialize_into_with_order(bytes, order);
+        self.use_count.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ResourceSizeSpec {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("spec", |tracer| tracer.value(&self.spec))?;
+            tracer.field("bytes", |tracer| tracer.value(&self.bytes))?;
+            tracer.field("ref-count", |tracer| tracer.value(&self.ref_count))?;
+            tracer.field("use-count", |tracer| tracer.value(&self.use_count))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResourceSizeValue {
+    pub size: ResourceSizeSpec,
+    pub cross_references: Vec<ResourceSizeSpec>,
+}
+impl TryParse for ResourceSizeValue {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (size, remaining) = ResourceSizeSpec::try_parse_with_order(remaining, order)?;
+        let (num_cross_references, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (cross_references, remaining) = crate::x11_utils::parse_list_with_order::<ResourceSizeSpec>(remaining, num_cross_references.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = ResourceSizeValue { size, cross_references };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ResourceSizeValue {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ResourceSizeValue {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(24);
+        self.size.serialize_into_with_order(bytes, order);
+        let num_cross_references = u32::try_from(self.cross_references.len()).expect("`cross_references` has too many elements");
+        num_cross_references.serialize_into_with_order(bytes, order);
+        self.cross_references.serialize_into_with_order(bytes, order);
+    }
+}
+impl ResourceSizeValue {
+    /// Get the value of the `num_cross_references` field.
+    ///
+    /// The `num_cross_references` field is used as the length field of the `cross_references` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_cross_references(&self) -> u32 {
+        self.cross_references.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ResourceSizeValue {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("size", |tracer| tracer.value(&self.size))?;
+            tracer.field("cross-references", |tracer| tracer.list(&self.cross_references, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub client_major: u8,
+    pub client_minor: u8,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_major_bytes = self.client_major.serialize_with_order(order);
+        let client_minor_bytes = self.client_minor.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_bytes[0],
+            client_minor_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client_major, remaining) = u8::try_parse_with_order(value, order)?;
+        let (client_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            client_major,
+            client_minor,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client-major", |tracer| tracer.value(&self.client_major))?;
+        tracer.field("client-minor", |tracer| tracer.value(&self.client_minor))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major: u16,
+    pub server_minor: u16,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, server_major, server_minor };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("server-major", |tracer| tracer.value(&self.server_major))?;
+        tracer.field("server-minor", |tracer| tracer.value(&self.server_minor))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major.serialize_into_with_order(bytes, order);
+        self.server_minor.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryClients request
+pub const QUERY_CLIENTS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryClientsRequest;
+impl QueryClientsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_CLIENTS_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(QueryClientsRequest
+        )
+    }
+}
+impl Request for QueryClientsRequest {
+    type Reply = QueryClientsReply;
+}
+impl Trace for QueryClientsRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryClientsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub clients: Vec<Client>,
+}
+impl TryParse for QueryClientsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_clients, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (clients, remaining) = crate::x11_utils::parse_list_with_order::<Client>(remaining, num_clients.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientsReply { sequence, length, clients };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryClientsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryClientsReply {
+    /// Get the value of the `num_clients` field.
+    ///
+    /// The `num_clients` field is used as the length field of the `clients` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_clients(&self) -> u32 {
+        self.clients.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryClientsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("clients", |tracer| tracer.list(&self.clients, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryClientsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_clients = u32::try_from(self.clients.len()).expect("`clients` has too many elements");
+        num_clients.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.clients.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryClientResources request
+pub const QUERY_CLIENT_RESOURCES_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryClientResourcesRequest {
+    pub xid: u32,
+}
+impl QueryClientResourcesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let xid_bytes = self.xid.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_CLIENT_RESOURCES_REQUEST,
+            0,
+            0,
+            xid_bytes[0],
+            xid_bytes[1],
+            xid_bytes[2],
+            xid_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENT_RESOURCES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (xid, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryClientResourcesRequest {
+            xid,
+        })
+    }
+}
+impl Request for QueryClientResourcesRequest {
+    type Reply = QueryClientResourcesReply;
+}
+impl Trace for QueryClientResourcesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xid", |tracer| tracer.value(&self.xid))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryClientResourcesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub types: Vec<Type>,
+}
+impl TryParse for QueryClientResourcesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_types, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (types, remaining) = crate::x11_utils::parse_list_with_order::<Type>(remaining, num_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientResourcesReply { sequence, length, types };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryClientResourcesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryClientResourcesReply {
+    /// Get the value of the `num_types` field.
+    ///
+    /// The `num_types` field is used as the length field of the `types` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_types(&self) -> u32 {
+        self.types.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryClientResourcesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("types", |tracer| tracer.list(&self.types, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryClientResourcesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_types = u32::try_from(self.types.len()).expect("`types` has too many elements");
+        num_types.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.types.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryClientPixmapBytes request
+pub const QUERY_CLIENT_PIXMAP_BYTES_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryClientPixmapBytesRequest {
+    pub xid: u32,
+}
+impl QueryClientPixmapBytesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let xid_bytes = self.xid.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_CLIENT_PIXMAP_BYTES_REQUEST,
+            0,
+            0,
+            xid_bytes[0],
+            xid_bytes[1],
+            xid_bytes[2],
+            xid_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENT_PIXMAP_BYTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (xid, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryClientPixmapBytesRequest {
+            xid,
+        })
+    }
+}
+impl Request for QueryClientPixmapBytesRequest {
+    type Reply = QueryClientPixmapBytesReply;
+}
+impl Trace for QueryClientPixmapBytesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xid", |tracer| tracer.value(&self.xid))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryClientPixmapBytesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub bytes: u32,
+    pub bytes_overflow: u32,
+}
+impl TryParse for QueryClientPixmapBytesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bytes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bytes_overflow, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientPixmapBytesReply { sequence, length, bytes, bytes_overflow };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryClientPixmapBytesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryClientPixmapBytesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("bytes", |tracer| tracer.value(&self.bytes))?;
+        tracer.field("bytes-overflow", |tracer| tracer.value(&self.bytes_overflow))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryClientPixmapBytesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.bytes.serialize_into_with_order(bytes, order);
+        self.bytes_overflow.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryClientIds request
+pub const QUERY_CLIENT_IDS_REQUEST: u8 = 4;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryClientIdsRequest<'input> {
+    pub specs: Cow<'input, [ClientIdSpec]>,
+}
+impl<'input> QueryClientIdsRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let num_specs = u32::try_from(self.specs.len()).expect("`specs` has too many elements");
+        let num_specs_bytes = num_specs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_CLIENT_IDS_REQUEST,
+            0,
+            0,
+            num_specs_bytes[0],
+            num_specs_bytes[1],
+            num_specs_bytes[2],
+            num_specs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let specs_bytes = self.specs.serialize_with_order(order);
+        let length_so_far = length_so_far + specs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), specs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_CLIENT_IDS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (num_specs, remaining) = u32::try_parse_with_order(value, order)?;
+        let (specs, remaining) = crate::x11_utils::parse_list_with_order::<ClientIdSpec>(remaining, num_specs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(QueryClientIdsRequest {
+            specs: Cow::Owned(specs),
+        })
+    }
+    /// Clone all borrowed data in this QueryClientIdsRequest.
+    pub fn into_owned(self) -> QueryClientIdsRequest<'static> {
+        QueryClientIdsRequest {
+            specs: Cow::Owned(self.specs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for QueryClientIdsRequest<'input> {
+    type Reply = QueryClientIdsReply;
+}
+impl Trace for QueryClientIdsRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("specs", |tracer| tracer.list(&self.specs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryClientIdsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub ids: Vec<ClientIdValue>,
+}
+impl TryParse for QueryClientIdsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_ids, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (ids, remaining) = crate::x11_utils::parse_list_with_order::<ClientIdValue>(remaining, num_ids.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryClientIdsReply { sequence, length, ids };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryClientIdsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryClientIdsReply {
+    /// Get the value of the `num_ids` field.
+    ///
+    /// The `num_ids` field is used as the length field of the `ids` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_ids(&self) -> u32 {
+        self.ids.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryClientIdsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("ids", |tracer| tracer.list(&self.ids, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryClientIdsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_ids = u32::try_from(self.ids.len()).expect("`ids` has too many elements");
+        num_ids.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.ids.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryResourceBytes request
+pub const QUERY_RESOURCE_BYTES_REQUEST: u8 = 5;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryResourceBytesRequest<'input> {
+    pub client: u32,
+    pub specs: Cow<'input, [ResourceIdSpec]>,
+}
+impl<'input> QueryResourceBytesRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_bytes = self.client.serialize_with_order(order);
+        let num_specs = u32::try_from(self.specs.len()).expect("`specs` has too many elements");
+        let num_specs_bytes = num_specs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_RESOURCE_BYTES_REQUEST,
+            0,
+            0,
+            client_bytes[0],
+            client_bytes[1],
+            client_bytes[2],
+            client_bytes[3],
+            num_specs_bytes[0],
+            num_specs_bytes[1],
+            num_specs_bytes[2],
+            num_specs_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let specs_bytes = self.specs.serialize_with_order(order);
+        let length_so_far = length_so_far + specs_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), specs_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_RESOURCE_BYTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client, remaining) = u32::try_parse_with_order(value, order)?;
+        let (num_specs, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (specs, remaining) = crate::x11_utils::parse_list_with_order::<ResourceIdSpec>(remaining, num_specs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(QueryResourceBytesRequest {
+            client,
+            specs: Cow::Owned(specs),
+        })
+    }
+    /// Clone all borrowed data in this QueryResourceBytesRequest.
+    pub fn into_owned(self) -> QueryResourceBytesRequest<'static> {
+        QueryResourceBytesRequest {
+            client: self.client,
+            specs: Cow::Owned(self.specs.into_owned()),
+        }
+    }
+}
+impl<'input> Request for QueryResourceBytesRequest<'input> {
+    type Reply = QueryResourceBytesReply;
+}
+impl Trace for QueryResourceBytesRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client", |tracer| tracer.value(&self.client))?;
+        tracer.field("specs", |tracer| tracer.list(&self.specs[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryResourceBytesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub sizes: Vec<ResourceSizeValue>,
+}
+impl TryParse for QueryResourceBytesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_sizes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (sizes, remaining) = crate::x11_utils::parse_list_with_order::<ResourceSizeValue>(remaining, num_sizes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryResourceBytesReply { sequence, length, sizes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryResourceBytesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryResourceBytesReply {
+    /// Get the value of the `num_sizes` field.
+    ///
+    /// The `num_sizes` field is used as the length field of the `sizes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_sizes(&self) -> u32 {
+        self.sizes.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryResourceBytesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("sizes", |tracer| tracer.list(&self.sizes, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryResourceBytesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_sizes = u32::try_from(self.sizes.len()).expect("`sizes` has too many elements");
+        num_sizes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.sizes.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Res"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_major",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_minor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_major",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_minor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Res"),
+        name: "QueryClients",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_clients",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clients",
+                kind: FieldKind::List(TypeInfo { name: "Client", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Res"),
+        name: "QueryClientResources",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xid",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_types",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "types",
+                kind: FieldKind::List(TypeInfo { name: "Type", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Res"),
+        name: "QueryClientPixmapBytes",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xid",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bytes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bytes_overflow",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Res"),
+        name: "QueryClientIds",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_specs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "specs",
+                kind: FieldKind::List(TypeInfo { name: "ClientIdSpec", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_ids",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ids",
+                kind: FieldKind::List(TypeInfo { name: "ClientIdValue", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Res"),
+        name: "QueryResourceBytes",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_specs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "specs",
+                kind: FieldKind::List(TypeInfo { name: "ResourceIdSpec", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_sizes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sizes",
+                kind: FieldKind::List(TypeInfo { name: "ResourceSizeValue", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
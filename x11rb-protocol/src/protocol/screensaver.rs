@@ -0,0 +1,1818 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `ScreenSaver` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "MIT-SCREEN-SAVER";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 1);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Kind(u8);
+impl Kind {
+    pub const BLANKED: Self = Self(0);
+    pub const INTERNAL: Self = Self(1);
+    pub const EXTERNAL: Self = Self(2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "BLANKED", "Blanked"),
+        (1, "INTERNAL", "Internal"),
+        (2, "EXTERNAL", "External"),
+    ];
+}
+impl From<Kind> for u8 {
+    #[inline]
+    fn from(input: Kind) -> Self {
+        input.0
+    }
+}
+impl From<Kind> for Option<u8> {
+    #[inline]
+    fn from(input: Kind) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Kind> for u16 {
+    #[inline]
+    fn from(input: Kind) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Kind> for Option<u16> {
+    #[inline]
+    fn from(input: Kind) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Kind> for u32 {
+    #[inline]
+    fn from(input: Kind) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Kind> for Option<u32> {
+    #[inline]
+    fn from(input: Kind) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Kind {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Kind  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Kind {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Kind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Kind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Event(u8);
+impl Event {
+    pub const NOTIFY_MASK: Self = Self(1 << 0);
+    pub const CYCLE_MASK: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "NOTIFY_MASK", "NotifyMask"),
+        (1 << 1, "CYCLE_MASK", "CycleMask"),
+    ];
+}
+impl From<Event> for u8 {
+    #[inline]
+    fn from(input: Event) -> Self {
+        input.0
+    }
+}
+impl From<Event> for Option<u8> {
+    #[inline]
+    fn from(input: Event) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Event> for u16 {
+    #[inline]
+    fn from(input: Event) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Event> for Option<u16> {
+    #[inline]
+    fn from(input: Event) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Event> for u32 {
+    #[inline]
+    fn from(input: Event) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Event> for Option<u32> {
+    #[inline]
+    fn from(input: Event) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Event {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Event  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Event {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Event {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Event {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Event, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct State(u8);
+impl State {
+    pub const OFF: Self = Self(0);
+    pub const ON: Self = Self(1);
+    pub const CYCLE: Self = Self(2);
+    pub const DISABLED: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "OFF", "Off"),
+        (1, "ON", "On"),
+        (2, "CYCLE", "Cycle"),
+        (3, "DISABLED", "Disabled"),
+    ];
+}
+impl From<State> for u8 {
+    #[inline]
+    fn from(input: State) -> Self {
+        input.0
+    }
+}
+impl From<State> for Option<u8> {
+    #[inline]
+    fn from(input: State) -> Self {
+        Some(input.0)
+    }
+}
+impl From<State> for u16 {
+    #[inline]
+    fn from(input: State) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<State> for Option<u16> {
+    #[inline]
+    fn from(input: State) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<State> for u32 {
+    #[inline]
+    fn from(input: State) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<State> for Option<u32> {
+    #[inline]
+    fn from(input: State) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for State {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for State  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for State {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for State {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u8,
+    pub client_minor_version: u8,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize_with_order(order);
+        let client_minor_version_bytes = self.client_minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_minor_version_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client_major_version, remaining) = u8::try_parse_with_order(value, order)?;
+        let (client_minor_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client-major-version", |tracer| tracer.value(&self.client_major_version))?;
+        tracer.field("client-minor-version", |tracer| tracer.value(&self.client_minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major_version: u16,
+    pub server_minor_version: u16,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, server_major_version, server_minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("server-major-version", |tracer| tracer.value(&self.server_major_version))?;
+        tracer.field("server-minor-version", |tracer| tracer.value(&self.server_minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major_version.serialize_into_with_order(bytes, order);
+        self.server_minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryInfo request
+pub const QUERY_INFO_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryInfoRequest {
+    pub drawable: xproto::Drawable,
+}
+impl QueryInfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_INFO_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryInfoRequest {
+            drawable,
+        })
+    }
+}
+impl Request for QueryInfoRequest {
+    type Reply = QueryInfoReply;
+}
+impl Trace for QueryInfoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryInfoReply {
+    pub state: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub saver_window: xproto::Window,
+    pub ms_until_server: u32,
+    pub ms_since_user_input: u32,
+    pub event_mask: u32,
+    pub kind: Kind,
+}
+impl TryParse for QueryInfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (saver_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (ms_until_server, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ms_since_user_input, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let kind = kind.into();
+        let result = QueryInfoReply { state, sequence, length, saver_window, ms_until_server, ms_since_user_input, event_mask, kind };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryInfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryInfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("saver-window", |tracer| tracer.xid(&self.saver_window))?;
+        tracer.field("ms-until-server", |tracer| tracer.value(&self.ms_until_server))?;
+        tracer.field("ms-since-user-input", |tracer| tracer.value(&self.ms_since_user_input))?;
+        tracer.field("event-mask", |tracer| tracer.value(&self.event_mask))?;
+        tracer.field("kind", |tracer| tracer.value(&self.kind))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.state.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.saver_window.serialize_into_with_order(bytes, order);
+        self.ms_until_server.serialize_into_with_order(bytes, order);
+        self.ms_since_user_input.serialize_into_with_order(bytes, order);
+        self.event_mask.serialize_into_with_order(bytes, order);
+        u8::from(self.kind).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 7]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SelectInput request
+pub const SELECT_INPUT_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectInputRequest {
+    pub drawable: xproto::Drawable,
+    pub event_mask: u32,
+}
+impl SelectInputRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let event_mask_bytes = self.event_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SelectInputRequest {
+            drawable,
+            event_mask,
+        })
+    }
+}
+impl Request for SelectInputRequest {
+    type Reply = ();
+}
+impl Trace for SelectInputRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("event-mask", |tracer| tracer.bitmask(&self.event_mask, Event::VARIANTS))?;
+        Ok(())
+    }
+}
+
+/// Auxiliary and optional information for the `set_attributes` function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetAttributesAux {
+    pub background_pixmap: Option<xproto::Pixmap>,
+    pub background_pixel: Option<u32>,
+    pub border_pixmap: Option<xproto::Pixmap>,
+    pub border_pixel: Option<u32>,
+    pub bit_gravity: Option<xproto::Gravity>,
+    pub win_gravity: Option<xproto::Gravity>,
+    pub backing_store: Option<xproto::BackingStore>,
+    pub backing_planes: Option<u32>,
+    pub backing_pixel: Option<u32>,
+    pub override_redirect: Option<xproto::Bool32>,
+    pub save_under: Option<xproto::Bool32>,
+    pub event_mask: Option<u32>,
+    pub do_not_propogate_mask: Option<u32>,
+    pub colormap: Option<xproto::Colormap>,
+    pub cursor: Option<xproto::Cursor>,
+}
+impl Trace for SetAttributesAux {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.background_pixmap {
+                tracer.field("background-pixmap", |tracer| tracer.xid(value))?;
+            }
+            if let Some(ref value) = self.background_pixel {
+                tracer.field("background-pixel", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.border_pixmap {
+                tracer.field("border-pixmap", |tracer| tracer.xid(value))?;
+            }
+            if let Some(ref value) = self.border_pixel {
+                tracer.field("border-pixel", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bit_gravity {
+                tracer.field("bit-gravity", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.win_gravity {
+                tracer.field("win-gravity", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.backing_store {
+                tracer.field("backing-store", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.backing_planes {
+                tracer.field("backing-planes", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.backing_pixel {
+                tracer.field("backing-pixel", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.override_redirect {
+                tracer.field("override-redirect", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.save_under {
+                tracer.field("save-under", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.event_mask {
+                tracer.field("event-mask", |tracer| tracer.bitmask(value, xproto::EventMask::VARIANTS))?;
+            }
+            if let Some(ref value) = self.do_not_propogate_mask {
+                tracer.field("do-not-propogate-mask", |tracer| tracer.bitmask(value, xproto::EventMask::VARIANTS))?;
+            }
+            if let Some(ref value) = self.colormap {
+                tracer.field("colormap", |tracer| tracer.xid(value))?;
+            }
+            if let Some(ref value) = self.cursor {
+                tracer.field("cursor", |tracer| tracer.xid(value))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl SetAttributesAux {
+    fn try_parse_with_order(value: &[u8], value_mask: u32, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = value_mask;
+        let mut outer_remaining = value;
+        let background_pixmap = if switch_expr & u32::from(xproto::CW::BACK_PIXMAP) != 0 {
+            let remaining = outer_remaining;
+            let (background_pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(background_pixmap)
+        } else {
+            None
+        };
+        let background_pixel = if switch_expr & u32::from(xproto::CW::BACK_PIXEL) != 0 {
+            let remaining = outer_remaining;
+            let (background_pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(background_pixel)
+        } else {
+            None
+        };
+        let border_pixmap = if switch_expr & u32::from(xproto::CW::BORDER_PIXMAP) != 0 {
+            let remaining = outer_remaining;
+            let (border_pixmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(border_pixmap)
+        } else {
+            None
+        };
+        let border_pixel = if switch_expr & u32::from(xproto::CW::BORDER_PIXEL) != 0 {
+            let remaining = outer_remaining;
+            let (border_pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(border_pixel)
+        } else {
+            None
+        };
+        let bit_gravity = if switch_expr & u32::from(xproto::CW::BIT_GRAVITY) != 0 {
+            let remaining = outer_remaining;
+            let (bit_gravity, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let bit_gravity = bit_gravity.into();
+            outer_remaining = remaining;
+            Some(bit_gravity)
+        } else {
+            None
+        };
+        let win_gravity = if switch_expr & u32::from(xproto::CW::WIN_GRAVITY) != 0 {
+            let remaining = outer_remaining;
+            let (win_gravity, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let win_gravity = win_gravity.into();
+            outer_remaining = remaining;
+            Some(win_gravity)
+        } else {
+            None
+        };
+        let backing_store = if switch_expr & u32::from(xproto::CW::BACKING_STORE) != 0 {
+            let remaining = outer_remaining;
+            let (backing_store, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let backing_store = backing_store.into();
+            outer_remaining = remaining;
+            Some(backing_store)
+        } else {
+            None
+        };
+        let backing_planes = if switch_expr & u32::from(xproto::CW::BACKING_PLANES) != 0 {
+            let remaining = outer_remaining;
+            let (backing_planes, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(backing_planes)
+        } else {
+            None
+        };
+        let backing_pixel = if switch_expr & u32::from(xproto::CW::BACKING_PIXEL) != 0 {
+            let remaining = outer_remaining;
+            let (backing_pixel, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(backing_pixel)
+        } else {
+            None
+        };
+        let override_redirect = if switch_expr & u32::from(xproto::CW::OVERRIDE_REDIRECT) != 0 {
+            let remaining = outer_remaining;
+            let (override_redirect, remaining) = xproto::Bool32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(override_redirect)
+        } else {
+            None
+        };
+        let save_under = if switch_expr & u32::from(xproto::CW::SAVE_UNDER) != 0 {
+            let remaining = outer_remaining;
+            let (save_under, remaining) = xproto::Bool32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(save_under)
+        } else {
+            None
+        };
+        let event_mask = if switch_expr & u32::from(xproto::CW::EVENT_MASK) != 0 {
+            let remaining = outer_remaining;
+            let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(event_mask)
+        } else {
+            None
+        };
+        let do_not_propogate_mask = if switch_expr & u32::from(xproto::CW::DONT_PROPAGATE) != 0 {
+            let remaining = outer_remaining;
+            let (do_not_propogate_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(do_not_propogate_mask)
+        } else {
+            None
+        };
+        let colormap = if switch_expr & u32::from(xproto::CW::COLORMAP) != 0 {
+            let remaining = outer_remaining;
+            let (colormap, remaining) = xproto::Colormap::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(colormap)
+        } else {
+            None
+        };
+        let cursor = if switch_expr & u32::from(xproto::CW::CURSOR) != 0 {
+            let remaining = outer_remaining;
+            let (cursor, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(cursor)
+        } else {
+            None
+        };
+        let result = SetAttributesAux { background_pixmap, background_pixel, border_pixmap, border_pixel, bit_gravity, win_gravity, backing_store, backing_planes, backing_pixel, override_redirect, save_under, event_mask, do_not_propogate_mask, colormap, cursor };
+        Ok((result, outer_remaining))
+    }
+}
+impl SetAttributesAux {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, value_mask: u32, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, value_mask, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, value_mask: u32, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), value_mask, "switch `value_list` has an inconsistent discriminant");
+        if let Some(background_pixmap) = self.background_pixmap {
+            background_pixmap.serialize_into_with_order(bytes, order);
+        }
+        if let Some(background_pixel) = self.background_pixel {
+            background_pixel.serialize_into_with_order(bytes, order);
+        }
+        if let Some(border_pixmap) = self.border_pixmap {
+            border_pixmap.serialize_into_with_order(bytes, order);
+        }
+        if let Some(border_pixel) = self.border_pixel {
+            border_pixel.serialize_into_with_order(bytes, order);
+        }
+        if let Some(bit_gravity) = self.bit_gravity {
+            u32::from(bit_gravity).serialize_into_with_order(bytes, order);
+        }
+        if let Some(win_gravity) = self.win_gravity {
+            u32::from(win_gravity).serialize_into_with_order(bytes, order);
+        }
+        if let Some(backing_store) = self.backing_store {
+            u32::from(backing_store).serialize_into_with_order(bytes, order);
+        }
+        if let Some(backing_planes) = self.backing_planes {
+            backing_planes.serialize_into_with_order(bytes, order);
+        }
+        if let Some(backing_pixel) = self.backing_pixel {
+            backing_pixel.serialize_into_with_order(bytes, order);
+        }
+        if let Some(override_redirect) = self.override_redirect {
+            override_redirect.serialize_into_with_order(bytes, order);
+        }
+        if let Some(save_under) = self.save_under {
+            save_under.serialize_into_with_order(bytes, order);
+        }
+        if let Some(event_mask) = self.event_mask {
+            event_mask.serialize_into_with_order(bytes, order);
+        }
+        if let Some(do_not_propogate_mask) = self.do_not_propogate_mask {
+            do_not_propogate_mask.serialize_into_with_order(bytes, order);
+        }
+        if let Some(colormap) = self.colormap {
+            colormap.serialize_into_with_order(bytes, order);
+        }
+        if let Some(cursor) = self.cursor {
+            cursor.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl SetAttributesAux {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.background_pixmap.is_some() {
+            expr_value |= u32::from(xproto::CW::BACK_PIXMAP);
+        }
+        if self.background_pixel.is_some() {
+            expr_value |= u32::from(xproto::CW::BACK_PIXEL);
+        }
+        if self.border_pixmap.is_some() {
+            expr_value |= u32::from(xproto::CW::BORDER_PIXMAP);
+        }
+        if self.border_pixel.is_some() {
+            expr_value |= u32::from(xproto::CW::BORDER_PIXEL);
+        }
+        if self.bit_gravity.is_some() {
+            expr_value |= u32::from(xproto::CW::BIT_GRAVITY);
+        }
+        if self.win_gravity.is_some() {
+            expr_value |= u32::from(xproto::CW::WIN_GRAVITY);
+        }
+        if self.backing_store.is_some() {
+            expr_value |= u32::from(xproto::CW::BACKING_STORE);
+        }
+        if self.backing_planes.is_some() {
+            expr_value |= u32::from(xproto::CW::BACKING_PLANES);
+        }
+        if self.backing_pixel.is_some() {
+            expr_value |= u32::from(xproto::CW::BACKING_PIXEL);
+        }
+        if self.override_redirect.is_some() {
+            expr_value |= u32::from(xproto::CW::OVERRIDE_REDIRECT);
+        }
+        if self.save_under.is_some() {
+            expr_value |= u32::from(xproto::CW::SAVE_UNDER);
+        }
+        if self.event_mask.is_some() {
+            expr_value |= u32::from(xproto::CW::EVENT_MASK);
+        }
+        if self.do_not_propogate_mask.is_some() {
+            expr_value |= u32::from(xproto::CW::DONT_PROPAGATE);
+        }
+        if self.colormap.is_some() {
+            expr_value |= u32::from(xproto::CW::COLORMAP);
+        }
+        if self.cursor.is_some() {
+            expr_value |= u32::from(xproto::CW::CURSOR);
+        }
+        expr_value
+    }
+}
+impl SetAttributesAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `background_pixmap` field of this structure.
+    pub fn background_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Pixmap>> {
+        self.background_pixmap = value.into();
+        self
+    }
+    /// Set the `background_pixel` field of this structure.
+    pub fn background_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.background_pixel = value.into();
+        self
+    }
+    /// Set the `border_pixmap` field of this structure.
+    pub fn border_pixmap<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Pixmap>> {
+        self.border_pixmap = value.into();
+        self
+    }
+    /// Set the `border_pixel` field of this structure.
+    pub fn border_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.border_pixel = value.into();
+        self
+    }
+    /// Set the `bit_gravity` field of this structure.
+    pub fn bit_gravity<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Gravity>> {
+        self.bit_gravity = value.into();
+        self
+    }
+    /// Set the `win_gravity` field of this structure.
+    pub fn win_gravity<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Gravity>> {
+        self.win_gravity = value.into();
+        self
+    }
+    /// Set the `backing_store` field of this structure.
+    pub fn backing_store<I>(mut self, value: I) -> Self where I: Into<Option<xproto::BackingStore>> {
+        self.backing_store = value.into();
+        self
+    }
+    /// Set the `backing_planes` field of this structure.
+    pub fn backing_planes<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.backing_planes = value.into();
+        self
+    }
+    /// Set the `backing_pixel` field of this structure.
+    pub fn backing_pixel<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.backing_pixel = value.into();
+        self
+    }
+    /// Set the `override_redirect` field of this structure.
+    pub fn override_redirect<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Bool32>> {
+        self.override_redirect = value.into();
+        self
+    }
+    /// Set the `save_under` field of this structure.
+    pub fn save_under<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Bool32>> {
+        self.save_under = value.into();
+        self
+    }
+    /// Set the `event_mask` field of this structure.
+    pub fn event_mask<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.event_mask = value.into();
+        self
+    }
+    /// Set the `do_not_propogate_mask` field of this structure.
+    pub fn do_not_propogate_mask<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.do_not_propogate_mask = value.into();
+        self
+    }
+    /// Set the `colormap` field of this structure.
+    pub fn colormap<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Colormap>> {
+        self.colormap = value.into();
+        self
+    }
+    /// Set the `cursor` field of this structure.
+    pub fn cursor<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Cursor>> {
+        self.cursor = value.into();
+        self
+    }
+}
+
+/// Opcode for the SetAttributes request
+pub const SET_ATTRIBUTES_REQUEST: u8 = 3;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetAttributesRequest<'input> {
+    pub drawable: xproto::Drawable,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub border_width: u16,
+    pub class: xproto::WindowClass,
+    pub depth: u8,
+    pub visual: xproto::Visualid,
+    pub value_list: Cow<'input, SetAttributesAux>,
+}
+impl<'input> SetAttributesRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let border_width_bytes = self.border_width.serialize_with_order(order);
+        let class_bytes = (u16::from(self.class) as u8).serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let visual_bytes = self.visual.serialize_with_order(order);
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            x_bytes[0],
+            x_bytes[1],
+            y_bytes[0],
+            y_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            border_width_bytes[0],
+            border_width_bytes[1],
+            class_bytes[0],
+            depth_bytes[0],
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let value_list_bytes = self.value_list.serialize_with_order(value_mask, order);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_ATTRIBUTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (border_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (class, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let class = class.into();
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = SetAttributesAux::try_parse_with_order(remaining, value_mask, order)?;
+        let _ = remaining;
+        Ok(SetAttributesRequest {
+            drawable,
+            x,
+            y,
+            width,
+            height,
+            border_width,
+            class,
+            depth,
+            visual,
+            value_list: Cow::Owned(value_list),
+        })
+    }
+    /// Clone all borrowed data in this SetAttributesRequest.
+    pub fn into_owned(self) -> SetAttributesRequest<'static> {
+        SetAttributesRequest {
+            drawable: self.drawable,
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+            border_width: self.border_width,
+            class: self.class,
+            depth: self.depth,
+            visual: self.visual,
+            value_list: Cow::Owned(self.value_list.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetAttributesRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetAttributesRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("border-width", |tracer| tracer.value(&self.border_width))?;
+        tracer.field("class", |tracer| tracer.value(&self.class))?;
+        tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+        tracer.field("visual", |tracer| tracer.value(&self.visual))?;
+        tracer.field("value-list", |tracer| tracer.value(&self.value_list))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the UnsetAttributes request
+pub const UNSET_ATTRIBUTES_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsetAttributesRequest {
+    pub drawable: xproto::Drawable,
+}
+impl UnsetAttributesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            UNSET_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNSET_ATTRIBUTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(UnsetAttributesRequest {
+            drawable,
+        })
+    }
+}
+impl Request for UnsetAttributesRequest {
+    type Reply = ();
+}
+impl Trace for UnsetAttributesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Suspend request
+pub const SUSPEND_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspendRequest {
+    pub suspend: u32,
+}
+impl SuspendRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let suspend_bytes = self.suspend.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SUSPEND_REQUEST,
+            0,
+            0,
+            suspend_bytes[0],
+            suspend_bytes[1],
+            suspend_bytes[2],
+            suspend_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SUSPEND_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (suspend, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(SuspendRequest {
+            suspend,
+        })
+    }
+}
+impl Request for SuspendRequest {
+    type Reply = ();
+}
+impl Trace for SuspendRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("suspend", |tracer| tracer.value(&self.suspend))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Notify event
+pub const NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotifyEvent {
+    pub response_type: u8,
+    pub state: State,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub root: xproto::Window,
+    pub window: xproto::Window,
+    pub kind: Kind,
+    pub forced: bool,
+}
+impl TryParse for NotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (root, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (forced, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
+        let state = state.into();
+        let kind = kind.into();
+        let result = NotifyEvent { response_type, state, sequence, time, root, window, kind, forced };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for NotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("root", |tracer| tracer.xid(&self.root))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("kind", |tracer| tracer.value(&self.kind))?;
+        tracer.field("forced", |tracer| tracer.value(&self.forced))?;
+        Ok(())
+    }
+}
+impl NotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let state_bytes = u8::from(self.state).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let root_bytes = self.root.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let kind_bytes = u8::from(self.kind).serialize_with_order(order);
+        let forced_bytes = self.forced.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            state_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            root_bytes[0],
+            root_bytes[1],
+            root_bytes[2],
+            root_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            kind_bytes[0],
+            forced_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&NotifyEvent> for [u8; 32] {
+    fn from(input: &NotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("ScreenSaver"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("ScreenSaver"),
+        name: "QueryInfo",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "saver_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ms_until_server",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ms_since_user_input",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "kind",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "Kind", variants: Kind::VARIANTS }) }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("ScreenSaver"),
+        name: "SelectInput",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "Event", variants: Event::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("ScreenSaver"),
+        name: "SetAttributes",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "border_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "class",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "WindowClass", variants: xproto::WindowClass::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "depth",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "visual",
+                kind: FieldKind::Value(TypeInfo { name: "VISUALID", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "CW", variants: xproto::CW::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "value_list",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "background_pixmap",
+                                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::AltEnum(EnumInfo { name: "BackPixmap", variants: xproto::BackPixmap::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "background_pixel",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "border_pixmap",
+                                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::AltEnum(EnumInfo { name: "Pixmap", variants: xproto::PixmapEnum::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "border_pixel",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "bit_gravity",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "Gravity", variants: xproto::Gravity::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "win_gravity",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "Gravity", variants: xproto::Gravity::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "backing_store",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "BackingStore", variants: xproto::BackingStore::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "backing_planes",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "backing_pixel",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "override_redirect",
+                                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "save_under",
+                                kind: FieldKind::Value(TypeInfo { name: "BOOL32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "event_mask",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "EventMask", variants: xproto::EventMask::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "do_not_propogate_mask",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "EventMask", variants: xproto::EventMask::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "colormap",
+                                kind: FieldKind::Value(TypeInfo { name: "COLORMAP", value_set: ValueSet::AltEnum(EnumInfo { name: "Colormap", variants: xproto::ColormapEnum::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "cursor",
+                                kind: FieldKind::Value(TypeInfo { name: "CURSOR", value_set: ValueSet::AltEnum(EnumInfo { name: "Cursor", variants: xproto::CursorEnum::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("ScreenSaver"),
+        name: "UnsetAttributes",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("ScreenSaver"),
+        name: "Suspend",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "suspend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("ScreenSaver"),
+        name: "Notify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "State", variants: State::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "root",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "kind",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "Kind", variants: Kind::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "forced",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,1824 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Shape` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "SHAPE";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 1);
+
+pub type Op = u8;
+
+pub type Kind = u8;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SO(u8);
+impl SO {
+    pub const SET: Self = Self(0);
+    pub const UNION: Self = Self(1);
+    pub const INTERSECT: Self = Self(2);
+    pub const SUBTRACT: Self = Self(3);
+    pub const INVERT: Self = Self(4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "SET", "Set"),
+        (1, "UNION", "Union"),
+        (2, "INTERSECT", "Intersect"),
+        (3, "SUBTRACT", "Subtract"),
+        (4, "INVERT", "Invert"),
+    ];
+}
+impl From<SO> for u8 {
+    #[inline]
+    fn from(input: SO) -> Self {
+        input.0
+    }
+}
+impl From<SO> for Option<u8> {
+    #[inline]
+    fn from(input: SO) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SO> for u16 {
+    #[inline]
+    fn from(input: SO) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SO> for Option<u16> {
+    #[inline]
+    fn from(input: SO) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SO> for u32 {
+    #[inline]
+    fn from(input: SO) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SO> for Option<u32> {
+    #[inline]
+    fn from(input: SO) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SO {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SO  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SO {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SO {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SO {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SK(u8);
+impl SK {
+    pub const BOUNDING: Self = Self(0);
+    pub const CLIP: Self = Self(1);
+    pub const INPUT: Self = Self(2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "BOUNDING", "Bounding"),
+        (1, "CLIP", "Clip"),
+        (2, "INPUT", "Input"),
+    ];
+}
+impl From<SK> for u8 {
+    #[inline]
+    fn from(input: SK) -> Self {
+        input.0
+    }
+}
+impl From<SK> for Option<u8> {
+    #[inline]
+    fn from(input: SK) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SK> for u16 {
+    #[inline]
+    fn from(input: SK) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SK> for Option<u16> {
+    #[inline]
+    fn from(input: SK) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SK> for u32 {
+    #[inline]
+    fn from(input: SK) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SK> for Option<u32> {
+    #[inline]
+    fn from(input: SK) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SK {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SK  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SK {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SK {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SK {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the Notify event
+pub const NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotifyEvent {
+    pub response_type: u8,
+    pub shape_kind: SK,
+    pub sequence: u16,
+    pub affected_window: xproto::Window,
+    pub extents_x: i16,
+    pub extents_y: i16,
+    pub extents_width: u16,
+    pub extents_height: u16,
+    pub server_time: xproto::Timestamp,
+    pub shaped: bool,
+}
+impl TryParse for NotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (shape_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (affected_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (extents_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (extents_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (extents_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (extents_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (shaped, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let shape_kind = shape_kind.into();
+        let result = NotifyEvent { response_type, shape_kind, sequence, affected_window, extents_x, extents_y, extents_width, extents_height, server_time, shaped };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for NotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for NotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("shape-kind", |tracer| tracer.value(&self.shape_kind))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("affected-window", |tracer| tracer.xid(&self.affected_window))?;
+        tracer.field("extents-x", |tracer| tracer.value(&self.extents_x))?;
+        tracer.field("extents-y", |tracer| tracer.value(&self.extents_y))?;
+        tracer.field("extents-width", |tracer| tracer.value(&self.extents_width))?;
+        tracer.field("extents-height", |tracer| tracer.value(&self.extents_height))?;
+        tracer.field("server-time", |tracer| tracer.value(&self.server_time))?;
+        tracer.field("shaped", |tracer| tracer.value(&self.shaped))?;
+        Ok(())
+    }
+}
+impl NotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let shape_kind_bytes = Kind::from(self.shape_kind).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let affected_window_bytes = self.affected_window.serialize_with_order(order);
+        let extents_x_bytes = self.extents_x.serialize_with_order(order);
+        let extents_y_bytes = self.extents_y.serialize_with_order(order);
+        let extents_width_bytes = self.extents_width.serialize_with_order(order);
+        let extents_height_bytes = self.extents_height.serialize_with_order(order);
+        let server_time_bytes = self.server_time.serialize_with_order(order);
+        let shaped_bytes = self.shaped.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            shape_kind_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            affected_window_bytes[0],
+            affected_window_bytes[1],
+            affected_window_bytes[2],
+            affected_window_bytes[3],
+            extents_x_bytes[0],
+            extents_x_bytes[1],
+            extents_y_bytes[0],
+            extents_y_bytes[1],
+            extents_width_bytes[0],
+            extents_width_bytes[1],
+            extents_height_bytes[0],
+            extents_height_bytes[1],
+            server_time_bytes[0],
+            server_time_bytes[1],
+            server_time_bytes[2],
+            server_time_bytes[3],
+            shaped_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&NotifyEvent> for [u8; 32] {
+    fn from(input: &NotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<NotifyEvent> for [u8; 32] {
+    fn from(input: NotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest;
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(QueryVersionRequest
+        )
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Rectangles request
+pub const RECTANGLES_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RectanglesRequest<'input> {
+    pub operation: SO,
+    pub destination_kind: SK,
+    pub ordering: xproto::ClipOrdering,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub rectangles: Cow<'input, [xproto::Rectangle]>,
+}
+impl<'input> RectanglesRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let operation_bytes = Op::from(self.operation).serialize_with_order(order);
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize_with_order(order);
+        let ordering_bytes = u8::from(self.ordering).serialize_with_order(order);
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let x_offset_bytes = self.x_offset.serialize_with_order(order);
+        let y_offset_bytes = self.y_offset.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            RECTANGLES_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            ordering_bytes[0],
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let rectangles_bytes = self.rectangles.serialize_with_order(order);
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RECTANGLES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (operation, remaining) = Op::try_parse_with_order(value, order)?;
+        let operation = operation.into();
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let destination_kind = destination_kind.into();
+        let (ordering, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let ordering = ordering.into();
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        Ok(RectanglesRequest {
+            operation,
+            destination_kind,
+            ordering,
+            destination_window,
+            x_offset,
+            y_offset,
+            rectangles: Cow::Owned(rectangles),
+        })
+    }
+    /// Clone all borrowed data in this RectanglesRequest.
+    pub fn into_owned(self) -> RectanglesRequest<'static> {
+        RectanglesRequest {
+            operation: self.operation,
+            destination_kind: self.destination_kind,
+            ordering: self.ordering,
+            destination_window: self.destination_window,
+            x_offset: self.x_offset,
+            y_offset: self.y_offset,
+            rectangles: Cow::Owned(self.rectangles.into_owned()),
+        }
+    }
+}
+impl<'input> Request for RectanglesRequest<'input> {
+    type Reply = ();
+}
+impl Trace for RectanglesRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("operation", |tracer| tracer.value(&self.operation))?;
+        tracer.field("destination-kind", |tracer| tracer.value(&self.destination_kind))?;
+        tracer.field("ordering", |tracer| tracer.value(&self.ordering))?;
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        tracer.field("x-offset", |tracer| tracer.value(&self.x_offset))?;
+        tracer.field("y-offset", |tracer| tracer.value(&self.y_offset))?;
+        tracer.field("rectangles", |tracer| tracer.list(&self.rectangles[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Mask request
+pub const MASK_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaskRequest {
+    pub operation: SO,
+    pub destination_kind: SK,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub source_bitmap: xproto::Pixmap,
+}
+impl MaskRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let operation_bytes = Op::from(self.operation).serialize_with_order(order);
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize_with_order(order);
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let x_offset_bytes = self.x_offset.serialize_with_order(order);
+        let y_offset_bytes = self.y_offset.serialize_with_order(order);
+        let source_bitmap_bytes = self.source_bitmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            MASK_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            source_bitmap_bytes[0],
+            source_bitmap_bytes[1],
+            source_bitmap_bytes[2],
+            source_bitmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MASK_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (operation, remaining) = Op::try_parse_with_order(value, order)?;
+        let operation = operation.into();
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let destination_kind = destination_kind.into();
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (source_bitmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(MaskRequest {
+            operation,
+            destination_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+            source_bitmap,
+        })
+    }
+}
+impl Request for MaskRequest {
+    type Reply = ();
+}
+impl Trace for MaskRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("operation", |tracer| tracer.value(&self.operation))?;
+        tracer.field("destination-kind", |tracer| tracer.value(&self.destination_kind))?;
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        tracer.field("x-offset", |tracer| tracer.value(&self.x_offset))?;
+        tracer.field("y-offset", |tracer| tracer.value(&self.y_offset))?;
+        tracer.field("source-bitmap", |tracer| tracer.xid(&self.source_bitmap))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Combine request
+pub const COMBINE_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombineRequest {
+    pub operation: SO,
+    pub destination_kind: SK,
+    pub source_kind: SK,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub source_window: xproto::Window,
+}
+impl CombineRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let operation_bytes = Op::from(self.operation).serialize_with_order(order);
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize_with_order(order);
+        let source_kind_bytes = Kind::from(self.source_kind).serialize_with_order(order);
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let x_offset_bytes = self.x_offset.serialize_with_order(order);
+        let y_offset_bytes = self.y_offset.serialize_with_order(order);
+        let source_window_bytes = self.source_window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            COMBINE_REQUEST,
+            0,
+            0,
+            operation_bytes[0],
+            destination_kind_bytes[0],
+            source_kind_bytes[0],
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            source_window_bytes[0],
+            source_window_bytes[1],
+            source_window_bytes[2],
+            source_window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COMBINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (operation, remaining) = Op::try_parse_with_order(value, order)?;
+        let operation = operation.into();
+        let (destination_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let destination_kind = destination_kind.into();
+        let (source_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let source_kind = source_kind.into();
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (source_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CombineRequest {
+            operation,
+            destination_kind,
+            source_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+            source_window,
+        })
+    }
+}
+impl Request for CombineRequest {
+    type Reply = ();
+}
+impl Trace for CombineRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("operation", |tracer| tracer.value(&self.operation))?;
+        tracer.field("destination-kind", |tracer| tracer.value(&self.destination_kind))?;
+        tracer.field("source-kind", |tracer| tracer.value(&self.source_kind))?;
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        tracer.field("x-offset", |tracer| tracer.value(&self.x_offset))?;
+        tracer.field("y-offset", |tracer| tracer.value(&self.y_offset))?;
+        tracer.field("source-window", |tracer| tracer.xid(&self.source_window))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Offset request
+pub const OFFSET_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OffsetRequest {
+    pub destination_kind: SK,
+    pub destination_window: xproto::Window,
+    pub x_offset: i16,
+    pub y_offset: i16,
+}
+impl OffsetRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let destination_kind_bytes = Kind::from(self.destination_kind).serialize_with_order(order);
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let x_offset_bytes = self.x_offset.serialize_with_order(order);
+        let y_offset_bytes = self.y_offset.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            OFFSET_REQUEST,
+            0,
+            0,
+            destination_kind_bytes[0],
+            0,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != OFFSET_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (destination_kind, remaining) = Kind::try_parse_with_order(value, order)?;
+        let destination_kind = destination_kind.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(OffsetRequest {
+            destination_kind,
+            destination_window,
+            x_offset,
+            y_offset,
+        })
+    }
+}
+impl Request for OffsetRequest {
+    type Reply = ();
+}
+impl Trace for OffsetRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("destination-kind", |tracer| tracer.value(&self.destination_kind))?;
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        tracer.field("x-offset", |tracer| tracer.value(&self.x_offset))?;
+        tracer.field("y-offset", |tracer| tracer.value(&self.y_offset))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryExtents request
+pub const QUERY_EXTENTS_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryExtentsRequest {
+    pub destination_window: xproto::Window,
+}
+impl QueryExtentsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_EXTENTS_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_EXTENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryExtentsRequest {
+            destination_window,
+        })
+    }
+}
+impl Request for QueryExtentsRequest {
+    type Reply = QueryExtentsReply;
+}
+impl Trace for QueryExtentsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryExtentsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub bounding_shaped: bool,
+    pub clip_shaped: bool,
+    pub bounding_shape_extents_x: i16,
+    pub bounding_shape_extents_y: i16,
+    pub bounding_shape_extents_width: u16,
+    pub bounding_shape_extents_height: u16,
+    pub clip_shape_extents_x: i16,
+    pub clip_shape_extents_y: i16,
+    pub clip_shape_extents_width: u16,
+    pub clip_shape_extents_height: u16,
+}
+impl TryParse for QueryExtentsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (bounding_shaped, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (clip_shaped, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (bounding_shape_extents_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (bounding_shape_extents_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (bounding_shape_extents_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bounding_shape_extents_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (clip_shape_extents_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryExtentsReply { sequence, length, bounding_shaped, clip_shaped, bounding_shape_extents_x, bounding_shape_extents_y, bounding_shape_extents_width, bounding_shape_extents_height, clip_shape_extents_x, clip_shape_extents_y, clip_shape_extents_width, clip_shape_extents_height };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryExtentsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryExtentsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("bounding-shaped", |tracer| tracer.value(&self.bounding_shaped))?;
+        tracer.field("clip-shaped", |tracer| tracer.value(&self.clip_shaped))?;
+        tracer.field("bounding-shape-extents-x", |tracer| tracer.value(&self.bounding_shape_extents_x))?;
+        tracer.field("bounding-shape-extents-y", |tracer| tracer.value(&self.bounding_shape_extents_y))?;
+        tracer.field("bounding-shape-extents-width", |tracer| tracer.value(&self.bounding_shape_extents_width))?;
+        tracer.field("bounding-shape-extents-height", |tracer| tracer.value(&self.bounding_shape_extents_height))?;
+        tracer.field("clip-shape-extents-x", |tracer| tracer.value(&self.clip_shape_extents_x))?;
+        tracer.field("clip-shape-extents-y", |tracer| tracer.value(&self.clip_shape_extents_y))?;
+        tracer.field("clip-shape-extents-width", |tracer| tracer.value(&self.clip_shape_extents_width))?;
+        tracer.field("clip-shape-extents-height", |tracer| tracer.value(&self.clip_shape_extents_height))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryExtentsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.bounding_shaped.serialize_into_with_order(bytes, order);
+        self.clip_shaped.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.bounding_shape_extents_x.serialize_into_with_order(bytes, order);
+        self.bounding_shape_extents_y.serialize_into_with_order(bytes, order);
+        self.bounding_shape_extents_width.serialize_into_with_order(bytes, order);
+        self.bounding_shape_extents_height.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_x.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_y.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_width.serialize_into_with_order(bytes, order);
+        self.clip_shape_extents_height.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SelectInput request
+pub const SELECT_INPUT_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectInputRequest {
+    pub destination_window: xproto::Window,
+    pub enable: bool,
+}
+impl SelectInputRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let enable_bytes = self.enable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_INPUT_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+            enable_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_INPUT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (enable, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(SelectInputRequest {
+            destination_window,
+            enable,
+        })
+    }
+}
+impl Request for SelectInputRequest {
+    type Reply = ();
+}
+impl Trace for SelectInputRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        tracer.field("enable", |tracer| tracer.value(&self.enable))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the InputSelected request
+pub const INPUT_SELECTED_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputSelectedRequest {
+    pub destination_window: xproto::Window,
+}
+impl InputSelectedRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let destination_window_bytes = self.destination_window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            INPUT_SELECTED_REQUEST,
+            0,
+            0,
+            destination_window_bytes[0],
+            destination_window_bytes[1],
+            destination_window_bytes[2],
+            destination_window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INPUT_SELECTED_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (destination_window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(InputSelectedRequest {
+            destination_window,
+        })
+    }
+}
+impl Request for InputSelectedRequest {
+    type Reply = InputSelectedReply;
+}
+impl Trace for InputSelectedRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("destination-window", |tracer| tracer.xid(&self.destination_window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputSelectedReply {
+    pub enabled: bool,
+    pub sequence: u16,
+    pub length: u32,
+}
+impl TryParse for InputSelectedReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (enabled, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = InputSelectedReply { enabled, sequence, length };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for InputSelectedReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for InputSelectedReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("enabled", |tracer| tracer.value(&self.enabled))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        Ok(())
+    }
+}
+impl Serialize for InputSelectedReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.enabled.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetRectangles request
+pub const GET_RECTANGLES_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetRectanglesRequest {
+    pub window: xproto::Window,
+    pub source_kind: SK,
+}
+impl GetRectanglesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let source_kind_bytes = Kind::from(self.source_kind).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_RECTANGLES_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            source_kind_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_RECTANGLES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (source_kind, remaining) = Kind::try_parse_with_order(remaining, order)?;
+        let source_kind = source_kind.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetRectanglesRequest {
+            window,
+            source_kind,
+        })
+    }
+}
+impl Request for GetRectanglesRequest {
+    type Reply = GetRectanglesReply;
+}
+impl Trace for GetRectanglesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("source-kind", |tracer| tracer.value(&self.source_kind))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetRectanglesReply {
+    pub ordering: xproto::ClipOrdering,
+    pub sequence: u16,
+    pub length: u32,
+    pub rectangles: Vec<xproto::Rectangle>,
+}
+impl TryParse for GetRectanglesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ordering, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (rectangles_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (rectangles, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Rectangle>(remaining, rectangles_len.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let ordering = ordering.into();
+        let result = GetRectanglesReply { ordering, sequence, length, rectangles };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetRectanglesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetRectanglesReply {
+    /// Get the value of the `rectangles_len` field.
+    ///
+    /// The `rectangles_len` field is used as the length field of the `rectangles` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn rectangles_len(&self) -> u32 {
+        self.rectangles.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetRectanglesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("ordering", |tracer| tracer.value(&self.ordering))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("rectangles", |tracer| tracer.list(&self.rectangles, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetRectanglesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.ordering).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let rectangles_len = u32::try_from(self.rectangles.len()).expect("`rectangles` has too many elements");
+        rectangles_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.rectangles.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "Rectangles",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "operation",
+                kind: FieldKind::Value(TypeInfo { name: "OP", value_set: ValueSet::Enum(EnumInfo { name: "SO", variants: SO::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ordering",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "ClipOrdering", variants: xproto::ClipOrdering::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rectangles",
+                kind: FieldKind::List(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "Mask",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "operation",
+                kind: FieldKind::Value(TypeInfo { name: "OP", value_set: ValueSet::Enum(EnumInfo { name: "SO", variants: SO::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source_bitmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::AltEnum(EnumInfo { name: "Pixmap", variants: xproto::PixmapEnum::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "Combine",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "operation",
+                kind: FieldKind::Value(TypeInfo { name: "OP", value_set: ValueSet::Enum(EnumInfo { name: "SO", variants: SO::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "source_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "Offset",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "QueryExtents",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bounding_shaped",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clip_shaped",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bounding_shape_extents_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bounding_shape_extents_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bounding_shape_extents_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bounding_shape_extents_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clip_shape_extents_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clip_shape_extents_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clip_shape_extents_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clip_shape_extents_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "SelectInput",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "enable",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "InputSelected",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "enabled",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Shape"),
+        name: "GetRectangles",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ordering",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "ClipOrdering", variants: xproto::ClipOrdering::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rectangles_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rectangles",
+                kind: FieldKind::List(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("Shape"),
+        name: "Notify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "shape_kind",
+                kind: FieldKind::Value(TypeInfo { name: "KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "affected_window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extents_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extents_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extents_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extents_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "server_time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "shaped",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,3805 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Sync` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "SYNC";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (3, 1);
+
+pub type Alarm = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ALARMSTATE(u8);
+impl ALARMSTATE {
+    pub const ACTIVE: Self = Self(0);
+    pub const INACTIVE: Self = Self(1);
+    pub const DESTROYED: Self = Self(2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "ACTIVE", "Active"),
+        (1, "INACTIVE", "Inactive"),
+        (2, "DESTROYED", "Destroyed"),
+    ];
+}
+impl From<ALARMSTATE> for u8 {
+    #[inline]
+    fn from(input: ALARMSTATE) -> Self {
+        input.0
+    }
+}
+impl From<ALARMSTATE> for Option<u8> {
+    #[inline]
+    fn from(input: ALARMSTATE) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ALARMSTATE> for u16 {
+    #[inline]
+    fn from(input: ALARMSTATE) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ALARMSTATE> for Option<u16> {
+    #[inline]
+    fn from(input: ALARMSTATE) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ALARMSTATE> for u32 {
+    #[inline]
+    fn from(input: ALARMSTATE) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ALARMSTATE> for Option<u32> {
+    #[inline]
+    fn from(input: ALARMSTATE) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ALARMSTATE {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ALARMSTATE  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ALARMSTATE {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ALARMSTATE {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ALARMSTATE {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+pub type Counter = u32;
+
+pub type Fence = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TESTTYPE(u32);
+impl TESTTYPE {
+    pub const POSITIVE_TRANSITION: Self = Self(0);
+    pub const NEGATIVE_TRANSITION: Self = Self(1);
+    pub const POSITIVE_COMPARISON: Self = Self(2);
+    pub const NEGATIVE_COMPARISON: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "POSITIVE_TRANSITION", "PositiveTransition"),
+        (1, "NEGATIVE_TRANSITION", "NegativeTransition"),
+        (2, "POSITIVE_COMPARISON", "PositiveComparison"),
+        (3, "NEGATIVE_COMPARISON", "NegativeComparison"),
+    ];
+}
+impl From<TESTTYPE> for u32 {
+    #[inline]
+    fn from(input: TESTTYPE) -> Self {
+        input.0
+    }
+}
+impl From<TESTTYPE> for Option<u32> {
+    #[inline]
+    fn from(input: TESTTYPE) -> Self {
+        Some(input.0)
+    }
+}
+impl From<u8> for TESTTYPE {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for TESTTYPE {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u32> for TESTTYPE {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for TESTTYPE  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0, Self::VARIANTS)
+    }
+}
+impl Trace for TESTTYPE {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for TESTTYPE {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TESTTYPE {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VALUETYPE(u32);
+impl VALUETYPE {
+    pub const ABSOLUTE: Self = Self(0);
+    pub const RELATIVE: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "ABSOLUTE", "Absolute"),
+        (1, "RELATIVE", "Relative"),
+    ];
+}
+impl From<VALUETYPE> for u32 {
+    #[inline]
+    fn from(input: VALUETYPE) -> Self {
+        input.0
+    }
+}
+impl From<VALUETYPE> for Option<u32> {
+    #[inline]
+    fn from(input: VALUETYPE) -> Self {
+        Some(input.0)
+    }
+}
+impl From<u8> for VALUETYPE {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for VALUETYPE {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u32> for VALUETYPE {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for VALUETYPE  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0, Self::VARIANTS)
+    }
+}
+impl Trace for VALUETYPE {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VALUETYPE {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VALUETYPE {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CA(u8);
+impl CA {
+    pub const COUNTER: Self = Self(1 << 0);
+    pub const VALUE_TYPE: Self = Self(1 << 1);
+    pub const VALUE: Self = Self(1 << 2);
+    pub const TEST_TYPE: Self = Self(1 << 3);
+    pub const DELTA: Self = Self(1 << 4);
+    pub const EVENTS: Self = Self(1 << 5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "COUNTER", "Counter"),
+        (1 << 1, "VALUE_TYPE", "ValueType"),
+        (1 << 2, "VALUE", "Value"),
+        (1 << 3, "TEST_TYPE", "TestType"),
+        (1 << 4, "DELTA", "Delta"),
+        (1 << 5, "EVENTS", "Events"),
+    ];
+}
+impl From<CA> for u8 {
+    #[inline]
+    fn from(input: CA) -> Self {
+        input.0
+    }
+}
+impl From<CA> for Option<u8> {
+    #[inline]
+    fn from(input: CA) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CA> for u16 {
+    #[inline]
+    fn from(input: CA) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CA> for Option<u16> {
+    #[inline]
+    fn from(input: CA) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CA> for u32 {
+    #[inline]
+    fn from(input: CA) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CA> for Option<u32> {
+    #[inline]
+    fn from(input: CA) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CA {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CA  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CA {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CA {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CA {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(CA, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Int64 {
+    pub hi: i32,
+    pub lo: u32,
+}
+impl TryParse for Int64 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (hi, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (lo, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = Int64 { hi, lo };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Int64 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Int64 {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let hi_bytes = self.hi.serialize_with_order(order);
+        let lo_bytes = self.lo.serialize_with_order(order);
+        [
+            hi_bytes[0],
+            hi_bytes[1],
+            hi_bytes[2],
+            hi_bytes[3],
+            lo_bytes[0],
+            lo_bytes[1],
+            lo_bytes[2],
+            lo_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.hi.serialize_into_with_order(bytes, order);
+        self.lo.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Int64 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("hi", |tracer| tracer.value(&self.hi))?;
+            tracer.field("lo", |tracer| tracer.value(&self.lo))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Systemcounter {
+    pub counter: Counter,
+    pub resolution: Int64,
+    pub name: Vec<u8>,
+}
+impl TryParse for Systemcounter {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (resolution, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let result = Systemcounter { counter, resolution, name };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Systemcounter {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Systemcounter {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(14);
+        self.counter.serialize_into_with_order(bytes, order);
+        self.resolution.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+    }
+}
+impl Systemcounter {
+    /// Get the value of the `name_len` field.
+    ///
+    /// The `name_len` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn name_len(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Systemcounter {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+            tracer.field("resolution", |tracer| tracer.value(&self.resolution))?;
+            tracer.field("name", |tracer| tracer.string(&self.name))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trigger {
+    pub counter: Counter,
+    pub wait_type: VALUETYPE,
+    pub wait_value: Int64,
+    pub test_type: TESTTYPE,
+}
+impl TryParse for Trigger {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (wait_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (wait_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (test_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let wait_type = wait_type.into();
+        let test_type = test_type.into();
+        let result = Trigger { counter, wait_type, wait_value, test_type };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Trigger {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Trigger {
+    type Bytes = [u8; 20];
+    fn serialize(&self) -> [u8; 20] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 20] {
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let wait_type_bytes = u32::from(self.wait_type).serialize_with_order(order);
+        let wait_value_bytes = self.wait_value.serialize_with_order(order);
+        let test_type_bytes = u32::from(self.test_type).serialize_with_order(order);
+        [
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            wait_type_bytes[0],
+            wait_type_bytes[1],
+            wait_type_bytes[2],
+            wait_type_bytes[3],
+            wait_value_bytes[0],
+            wait_value_bytes[1],
+            wait_value_bytes[2],
+            wait_value_bytes[3],
+            wait_value_bytes[4],
+            wait_value_bytes[5],
+            wait_value_bytes[6],
+            wait_value_bytes[7],
+            test_type_bytes[0],
+            test_type_bytes[1],
+            test_type_bytes[2],
+            test_type_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(20);
+        self.counter.serialize_into_with_order(bytes, order);
+        u32::from(self.wait_type).serialize_into_with_order(bytes, order);
+        self.wait_value.serialize_into_with_order(bytes, order);
+        u32::from(self.test_type).serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Trigger {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+            tracer.field("wait-type", |tracer| tracer.value(&self.wait_type))?;
+            tracer.field("wait-value", |tracer| tracer.value(&self.wait_value))?;
+            tracer.field("test-type", |tracer| tracer.value(&self.test_type))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Waitcondition {
+    pub trigger: Trigger,
+    pub event_threshold: Int64,
+}
+impl TryParse for Waitcondition {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (trigger, remaining) = Trigger::try_parse_with_order(remaining, order)?;
+        let (event_threshold, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let result = Waitcondition { trigger, event_threshold };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Waitcondition {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Waitcondition {
+    type Bytes = [u8; 28];
+    fn serialize(&self) -> [u8; 28] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 28] {
+        let trigger_bytes = self.trigger.serialize_with_order(order);
+        let event_threshold_bytes = self.event_threshold.serialize_with_order(order);
+        [
+            trigger_bytes[0],
+            trigger_bytes[1],
+            trigger_bytes[2],
+            trigger_bytes[3],
+            trigger_bytes[4],
+            trigger_bytes[5],
+            trigger_bytes[6],
+            trigger_bytes[7],
+            trigger_bytes[8],
+            trigger_bytes[9],
+            trigger_bytes[10],
+            trigger_bytes[11],
+            trigger_bytes[12],
+            trigger_bytes[13],
+            trigger_bytes[14],
+            trigger_bytes[15],
+            trigger_bytes[16],
+            trigger_bytes[17],
+            trigger_bytes[18],
+            trigger_bytes[19],
+            event_threshold_bytes[0],
+            event_threshold_bytes[1],
+            event_threshold_bytes[2],
+            event_threshold_bytes[3],
+            event_threshold_bytes[4],
+            event_threshold_bytes[5],
+            event_threshold_bytes[6],
+            event_threshold_bytes[7],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(28);
+        self.trigger.serialize_into_with_order(bytes, order);
+        self.event_threshold.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Waitcondition {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("trigger", |tracer| tracer.value(&self.trigger))?;
+            tracer.field("event-threshold", |tracer| tracer.value(&self.event_threshold))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the Counter error
+pub const COUNTER_ERROR: u8 = 0;
+
+/// Opcode for the Alarm error
+pub const ALARM_ERROR: u8 = 1;
+
+/// Opcode for the Initialize request
+pub const INITIALIZE_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitializeRequest {
+    pub desired_major_version: u8,
+    pub desired_minor_version: u8,
+}
+impl InitializeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let desired_major_version_bytes = self.desired_major_version.serialize_with_order(order);
+        let desired_minor_version_bytes = self.desired_minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            INITIALIZE_REQUEST,
+            0,
+            0,
+            desired_major_version_bytes[0],
+            desired_minor_version_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INITIALIZE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (desired_major_version, remaining) = u8::try_parse_with_order(value, order)?;
+        let (desired_minor_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(InitializeRequest {
+            desired_major_version,
+            desired_minor_version,
+        })
+    }
+}
+impl Request for InitializeRequest {
+    type Reply = InitializeReply;
+}
+impl Trace for InitializeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("desired-major-version", |tracer| tracer.value(&self.desired_major_version))?;
+        tracer.field("desired-minor-version", |tracer| tracer.value(&self.desired_minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u8,
+    pub minor_version: u8,
+}
+impl TryParse for InitializeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = InitializeReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for InitializeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for InitializeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for InitializeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ListSystemCounters request
+pub const LIST_SYSTEM_COUNTERS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListSystemCountersRequest;
+impl ListSystemCountersRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            LIST_SYSTEM_COUNTERS_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_SYSTEM_COUNTERS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(ListSystemCountersRequest
+        )
+    }
+}
+impl Request for ListSystemCountersRequest {
+    type Reply = ListSystemCountersReply;
+}
+impl Trace for ListSystemCountersRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListSystemCountersReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub counters: Vec<Systemcounter>,
+}
+impl TryParse for ListSystemCountersReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (counters_len, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (counters, remaining) = crate::x11_utils::parse_list_with_order::<Systemcounter>(remaining, counters_len.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListSystemCountersReply { sequence, length, counters };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ListSystemCountersReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ListSystemCountersReply {
+    /// Get the value of the `counters_len` field.
+    ///
+    /// The `counters_len` field is used as the length field of the `counters` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn counters_len(&self) -> u32 {
+        self.counters.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ListSystemCountersReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("counters", |tracer| tracer.list(&self.counters, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for ListSystemCountersReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let counters_len = u32::try_from(self.counters.len()).expect("`counters` has too many elements");
+        counters_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.counters.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreateCounter request
+pub const CREATE_COUNTER_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateCounterRequest {
+    pub id: Counter,
+    pub initial_value: Int64,
+}
+impl CreateCounterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize_with_order(order);
+        let initial_value_bytes = self.initial_value.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_COUNTER_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            initial_value_bytes[0],
+            initial_value_bytes[1],
+            initial_value_bytes[2],
+            initial_value_bytes[3],
+            initial_value_bytes[4],
+            initial_value_bytes[5],
+            initial_value_bytes[6],
+            initial_value_bytes[7],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_COUNTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (id, remaining) = Counter::try_parse_with_order(value, order)?;
+        let (initial_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateCounterRequest {
+            id,
+            initial_value,
+        })
+    }
+}
+impl Request for CreateCounterRequest {
+    type Reply = ();
+}
+impl Trace for CreateCounterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("id", |tracer| tracer.xid(&self.id))?;
+        tracer.field("initial-value", |tracer| tracer.value(&self.initial_value))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyCounter request
+pub const DESTROY_COUNTER_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyCounterRequest {
+    pub counter: Counter,
+}
+impl DestroyCounterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_COUNTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (counter, remaining) = Counter::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyCounterRequest {
+            counter,
+        })
+    }
+}
+impl Request for DestroyCounterRequest {
+    type Reply = ();
+}
+impl Trace for DestroyCounterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryCounter request
+pub const QUERY_COUNTER_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryCounterRequest {
+    pub counter: Counter,
+}
+impl QueryCounterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_COUNTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (counter, remaining) = Counter::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryCounterRequest {
+            counter,
+        })
+    }
+}
+impl Request for QueryCounterRequest {
+    type Reply = QueryCounterReply;
+}
+impl Trace for QueryCounterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryCounterReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub counter_value: Int64,
+}
+impl TryParse for QueryCounterReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (counter_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryCounterReply { sequence, length, counter_value };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryCounterReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryCounterReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("counter-value", |tracer| tracer.value(&self.counter_value))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryCounterReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.counter_value.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the Await request
+pub const AWAIT_REQUEST: u8 = 7;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AwaitRequest<'input> {
+    pub wait_list: Cow<'input, [Waitcondition]>,
+}
+impl<'input> AwaitRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            AWAIT_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let wait_list_bytes = self.wait_list.serialize_with_order(order);
+        let length_so_far = length_so_far + wait_list_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), wait_list_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AWAIT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let mut remaining = value;
+        // Length is 'everything left in the input'
+        let mut wait_list = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Waitcondition::try_parse_with_order(value, order)?;
+            remaining = new_remaining;
+            wait_list.push(v);
+        }
+        let _ = remaining;
+        Ok(AwaitRequest {
+            wait_list: Cow::Owned(wait_list),
+        })
+    }
+    /// Clone all borrowed data in this AwaitRequest.
+    pub fn into_owned(self) -> AwaitRequest<'static> {
+        AwaitRequest {
+            wait_list: Cow::Owned(self.wait_list.into_owned()),
+        }
+    }
+}
+impl<'input> Request for AwaitRequest<'input> {
+    type Reply = ();
+}
+impl Trace for AwaitRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("wait-list", |tracer| tracer.list(&self.wait_list[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ChangeCounter request
+pub const CHANGE_COUNTER_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeCounterRequest {
+    pub counter: Counter,
+    pub amount: Int64,
+}
+impl ChangeCounterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let amount_bytes = self.amount.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            amount_bytes[0],
+            amount_bytes[1],
+            amount_bytes[2],
+            amount_bytes[3],
+            amount_bytes[4],
+            amount_bytes[5],
+            amount_bytes[6],
+            amount_bytes[7],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_COUNTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (counter, remaining) = Counter::try_parse_with_order(value, order)?;
+        let (amount, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(ChangeCounterRequest {
+            counter,
+            amount,
+        })
+    }
+}
+impl Request for ChangeCounterRequest {
+    type Reply = ();
+}
+impl Trace for ChangeCounterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+        tracer.field("amount", |tracer| tracer.value(&self.amount))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetCounter request
+pub const SET_COUNTER_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetCounterRequest {
+    pub counter: Counter,
+    pub value: Int64,
+}
+impl SetCounterRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_COUNTER_REQUEST,
+            0,
+            0,
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            value_bytes[4],
+            value_bytes[5],
+            value_bytes[6],
+            value_bytes[7],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_COUNTER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (counter, remaining) = Counter::try_parse_with_order(value, order)?;
+        let (value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetCounterRequest {
+            counter,
+            value,
+        })
+    }
+}
+impl Request for SetCounterRequest {
+    type Reply = ();
+}
+impl Trace for SetCounterRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+        tracer.field("value", |tracer| tracer.value(&self.value))?;
+        Ok(())
+    }
+}
+
+/// Auxiliary and optional information for the `create_alarm` function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateAlarmAux {
+    pub counter: Option<Counter>,
+    pub value_type: Option<VALUETYPE>,
+    pub value: Option<Int64>,
+    pub test_type: Option<TESTTYPE>,
+    pub delta: Option<Int64>,
+    pub events: Option<u32>,
+}
+impl Trace for CreateAlarmAux {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.counter {
+                tracer.field("counter", |tracer| tracer.xid(value))?;
+            }
+            if let Some(ref value) = self.value_type {
+                tracer.field("valueType", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.value {
+                tracer.field("value", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.test_type {
+                tracer.field("testType", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.delta {
+                tracer.field("delta", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.events {
+                tracer.field("events", |tracer| tracer.value(value))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl CreateAlarmAux {
+    fn try_parse_with_order(value: &[u8], value_mask: u32, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = value_mask;
+        let mut outer_remaining = value;
+        let counter = if switch_expr & u32::from(CA::COUNTER) != 0 {
+            let remaining = outer_remaining;
+            let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(counter)
+        } else {
+            None
+        };
+        let value_type = if switch_expr & u32::from(CA::VALUE_TYPE) != 0 {
+            let remaining = outer_remaining;
+            let (value_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let value_type = value_type.into();
+            outer_remaining = remaining;
+            Some(value_type)
+        } else {
+            None
+        };
+        let value = if switch_expr & u32::from(CA::VALUE) != 0 {
+            let remaining = outer_remaining;
+            let (value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(value)
+        } else {
+            None
+        };
+        let test_type = if switch_expr & u32::from(CA::TEST_TYPE) != 0 {
+            let remaining = outer_remaining;
+            let (test_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let test_type = test_type.into();
+            outer_remaining = remaining;
+            Some(test_type)
+        } else {
+            None
+        };
+        let delta = if switch_expr & u32::from(CA::DELTA) != 0 {
+            let remaining = outer_remaining;
+            let (delta, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(delta)
+        } else {
+            None
+        };
+        let events = if switch_expr & u32::from(CA::EVENTS) != 0 {
+            let remaining = outer_remaining;
+            let (events, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(events)
+        } else {
+            None
+        };
+        let result = CreateAlarmAux { counter, value_type, value, test_type, delta, events };
+        Ok((result, outer_remaining))
+    }
+}
+impl CreateAlarmAux {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, value_mask: u32, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, value_mask, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, value_mask: u32, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), value_mask, "switch `value_list` has an inconsistent discriminant");
+        if let Some(counter) = self.counter {
+            counter.serialize_into_with_order(bytes, order);
+        }
+        if let Some(value_type) = self.value_type {
+            u32::from(value_type).serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref value) = self.value {
+            value.serialize_into_with_order(bytes, order);
+        }
+        if let Some(test_type) = self.test_type {
+            u32::from(test_type).serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref delta) = self.delta {
+            delta.serialize_into_with_order(bytes, order);
+        }
+        if let Some(events) = self.events {
+            events.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl CreateAlarmAux {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.counter.is_some() {
+            expr_value |= u32::from(CA::COUNTER);
+        }
+        if self.value_type.is_some() {
+            expr_value |= u32::from(CA::VALUE_TYPE);
+        }
+        if self.value.is_some() {
+            expr_value |= u32::from(CA::VALUE);
+        }
+        if self.test_type.is_some() {
+            expr_value |= u32::from(CA::TEST_TYPE);
+        }
+        if self.delta.is_some() {
+            expr_value |= u32::from(CA::DELTA);
+        }
+        if self.events.is_some() {
+            expr_value |= u32::from(CA::EVENTS);
+        }
+        expr_value
+    }
+}
+impl CreateAlarmAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `counter` field of this structure.
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Option<Counter>> {
+        self.counter = value.into();
+        self
+    }
+    /// Set the `value_type` field of this structure.
+    pub fn value_type<I>(mut self, value: I) -> Self where I: Into<Option<VALUETYPE>> {
+        self.value_type = value.into();
+        self
+    }
+    /// Set the `value` field of this structure.
+    pub fn value<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.value = value.into();
+        self
+    }
+    /// Set the `test_type` field of this structure.
+    pub fn test_type<I>(mut self, value: I) -> Self where I: Into<Option<TESTTYPE>> {
+        self.test_type = value.into();
+        self
+    }
+    /// Set the `delta` field of this structure.
+    pub fn delta<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.delta = value.into();
+        self
+    }
+    /// Set the `events` field of this structure.
+    pub fn events<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.events = value.into();
+        self
+    }
+}
+
+/// Opcode for the CreateAlarm request
+pub const CREATE_ALARM_REQUEST: u8 = 8;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateAlarmRequest<'input> {
+    pub id: Alarm,
+    pub value_list: Cow<'input, CreateAlarmAux>,
+}
+impl<'input> CreateAlarmRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize_with_order(order);
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_ALARM_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let value_list_bytes = self.value_list.serialize_with_order(value_mask, order);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_ALARM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (id, remaining) = Alarm::try_parse_with_order(value, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = CreateAlarmAux::try_parse_with_order(remaining, value_mask, order)?;
+        let _ = remaining;
+        Ok(CreateAlarmRequest {
+            id,
+            value_list: Cow::Owned(value_list),
+        })
+    }
+    /// Clone all borrowed data in this CreateAlarmRequest.
+    pub fn into_owned(self) -> CreateAlarmRequest<'static> {
+        CreateAlarmRequest {
+            id: self.id,
+            value_list: Cow::Owned(self.value_list.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateAlarmRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreateAlarmRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("id", |tracer| tracer.xid(&self.id))?;
+        tracer.field("value-list", |tracer| tracer.value(&self.value_list))?;
+        Ok(())
+    }
+}
+
+/// Auxiliary and optional information for the `change_alarm` function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeAlarmAux {
+    pub counter: Option<Counter>,
+    pub value_type: Option<VALUETYPE>,
+    pub value: Option<Int64>,
+    pub test_type: Option<TESTTYPE>,
+    pub delta: Option<Int64>,
+    pub events: Option<u32>,
+}
+impl Trace for ChangeAlarmAux {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.counter {
+                tracer.field("counter", |tracer| tracer.xid(value))?;
+            }
+            if let Some(ref value) = self.value_type {
+                tracer.field("valueType", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.value {
+                tracer.field("value", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.test_type {
+                tracer.field("testType", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.delta {
+                tracer.field("delta", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.events {
+                tracer.field("events", |tracer| tracer.value(value))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl ChangeAlarmAux {
+    fn try_parse_with_order(value: &[u8], value_mask: u32, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = value_mask;
+        let mut outer_remaining = value;
+        let counter = if switch_expr & u32::from(CA::COUNTER) != 0 {
+            let remaining = outer_remaining;
+            let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(counter)
+        } else {
+            None
+        };
+        let value_type = if switch_expr & u32::from(CA::VALUE_TYPE) != 0 {
+            let remaining = outer_remaining;
+            let (value_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let value_type = value_type.into();
+            outer_remaining = remaining;
+            Some(value_type)
+        } else {
+            None
+        };
+        let value = if switch_expr & u32::from(CA::VALUE) != 0 {
+            let remaining = outer_remaining;
+            let (value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(value)
+        } else {
+            None
+        };
+        let test_type = if switch_expr & u32::from(CA::TEST_TYPE) != 0 {
+            let remaining = outer_remaining;
+            let (test_type, remaining) = u32::try_parse_with_order(remaining, order)?;
+            let test_type = test_type.into();
+            outer_remaining = remaining;
+            Some(test_type)
+        } else {
+            None
+        };
+        let delta = if switch_expr & u32::from(CA::DELTA) != 0 {
+            let remaining = outer_remaining;
+            let (delta, remaining) = Int64::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(delta)
+        } else {
+            None
+        };
+        let events = if switch_expr & u32::from(CA::EVENTS) != 0 {
+            let remaining = outer_remaining;
+            let (events, remaining) = u32::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(events)
+        } else {
+            None
+        };
+        let result = ChangeAlarmAux { counter, value_type, value, test_type, delta, events };
+        Ok((result, outer_remaining))
+    }
+}
+impl ChangeAlarmAux {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, value_mask: u32, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, value_mask, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, value_mask: u32, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), value_mask, "switch `value_list` has an inconsistent discriminant");
+        if let Some(counter) = self.counter {
+            counter.serialize_into_with_order(bytes, order);
+        }
+        if let Some(value_type) = self.value_type {
+            u32::from(value_type).serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref value) = self.value {
+            value.serialize_into_with_order(bytes, order);
+        }
+        if let Some(test_type) = self.test_type {
+            u32::from(test_type).serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref delta) = self.delta {
+            delta.serialize_into_with_order(bytes, order);
+        }
+        if let Some(events) = self.events {
+            events.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl ChangeAlarmAux {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.counter.is_some() {
+            expr_value |= u32::from(CA::COUNTER);
+        }
+        if self.value_type.is_some() {
+            expr_value |= u32::from(CA::VALUE_TYPE);
+        }
+        if self.value.is_some() {
+            expr_value |= u32::from(CA::VALUE);
+        }
+        if self.test_type.is_some() {
+            expr_value |= u32::from(CA::TEST_TYPE);
+        }
+        if self.delta.is_some() {
+            expr_value |= u32::from(CA::DELTA);
+        }
+        if self.events.is_some() {
+            expr_value |= u32::from(CA::EVENTS);
+        }
+        expr_value
+    }
+}
+impl ChangeAlarmAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `counter` field of this structure.
+    pub fn counter<I>(mut self, value: I) -> Self where I: Into<Option<Counter>> {
+        self.counter = value.into();
+        self
+    }
+    /// Set the `value_type` field of this structure.
+    pub fn value_type<I>(mut self, value: I) -> Self where I: Into<Option<VALUETYPE>> {
+        self.value_type = value.into();
+        self
+    }
+    /// Set the `value` field of this structure.
+    pub fn value<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.value = value.into();
+        self
+    }
+    /// Set the `test_type` field of this structure.
+    pub fn test_type<I>(mut self, value: I) -> Self where I: Into<Option<TESTTYPE>> {
+        self.test_type = value.into();
+        self
+    }
+    /// Set the `delta` field of this structure.
+    pub fn delta<I>(mut self, value: I) -> Self where I: Into<Option<Int64>> {
+        self.delta = value.into();
+        self
+    }
+    /// Set the `events` field of this structure.
+    pub fn events<I>(mut self, value: I) -> Self where I: Into<Option<u32>> {
+        self.events = value.into();
+        self
+    }
+}
+
+/// Opcode for the ChangeAlarm request
+pub const CHANGE_ALARM_REQUEST: u8 = 9;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeAlarmRequest<'input> {
+    pub id: Alarm,
+    pub value_list: Cow<'input, ChangeAlarmAux>,
+}
+impl<'input> ChangeAlarmRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize_with_order(order);
+        let value_mask = self.value_list.switch_expr();
+        let value_mask_bytes = value_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_ALARM_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            value_mask_bytes[0],
+            value_mask_bytes[1],
+            value_mask_bytes[2],
+            value_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let value_list_bytes = self.value_list.serialize_with_order(value_mask, order);
+        let length_so_far = length_so_far + value_list_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), value_list_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_ALARM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (id, remaining) = Alarm::try_parse_with_order(value, order)?;
+        let (value_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value_list, remaining) = ChangeAlarmAux::try_parse_with_order(remaining, value_mask, order)?;
+        let _ = remaining;
+        Ok(ChangeAlarmRequest {
+            id,
+            value_list: Cow::Owned(value_list),
+        })
+    }
+    /// Clone all borrowed data in this ChangeAlarmRequest.
+    pub fn into_owned(self) -> ChangeAlarmRequest<'static> {
+        ChangeAlarmRequest {
+            id: self.id,
+            value_list: Cow::Owned(self.value_list.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ChangeAlarmRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ChangeAlarmRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("id", |tracer| tracer.xid(&self.id))?;
+        tracer.field("value-list", |tracer| tracer.value(&self.value_list))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyAlarm request
+pub const DESTROY_ALARM_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyAlarmRequest {
+    pub alarm: Alarm,
+}
+impl DestroyAlarmRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let alarm_bytes = self.alarm.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_ALARM_REQUEST,
+            0,
+            0,
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_ALARM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (alarm, remaining) = Alarm::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyAlarmRequest {
+            alarm,
+        })
+    }
+}
+impl Request for DestroyAlarmRequest {
+    type Reply = ();
+}
+impl Trace for DestroyAlarmRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("alarm", |tracer| tracer.xid(&self.alarm))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryAlarm request
+pub const QUERY_ALARM_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryAlarmRequest {
+    pub alarm: Alarm,
+}
+impl QueryAlarmRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let alarm_bytes = self.alarm.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_ALARM_REQUEST,
+            0,
+            0,
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_ALARM_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (alarm, remaining) = Alarm::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryAlarmRequest {
+            alarm,
+        })
+    }
+}
+impl Request for QueryAlarmRequest {
+    type Reply = QueryAlarmReply;
+}
+impl Trace for QueryAlarmRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("alarm", |tracer| tracer.xid(&self.alarm))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryAlarmReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub trigger: Trigger,
+    pub delta: Int64,
+    pub events: bool,
+    pub state: ALARMSTATE,
+}
+impl TryParse for QueryAlarmReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (trigger, remaining) = Trigger::try_parse_with_order(remaining, order)?;
+        let (delta, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (events, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let state = state.into();
+        let result = QueryAlarmReply { sequence, length, trigger, delta, events, state };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryAlarmReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryAlarmReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("trigger", |tracer| tracer.value(&self.trigger))?;
+        tracer.field("delta", |tracer| tracer.value(&self.delta))?;
+        tracer.field("events", |tracer| tracer.value(&self.events))?;
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryAlarmReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.trigger.serialize_into_with_order(bytes, order);
+        self.delta.serialize_into_with_order(bytes, order);
+        self.events.serialize_into_with_order(bytes, order);
+        u8::from(self.state).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetPriority request
+pub const SET_PRIORITY_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetPriorityRequest {
+    pub id: u32,
+    pub priority: i32,
+}
+impl SetPriorityRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize_with_order(order);
+        let priority_bytes = self.priority.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_PRIORITY_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            priority_bytes[0],
+            priority_bytes[1],
+            priority_bytes[2],
+            priority_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PRIORITY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (id, remaining) = u32::try_parse_with_order(value, order)?;
+        let (priority, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetPriorityRequest {
+            id,
+            priority,
+        })
+    }
+}
+impl Request for SetPriorityRequest {
+    type Reply = ();
+}
+impl Trace for SetPriorityRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        tracer.field("priority", |tracer| tracer.value(&self.priority))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetPriority request
+pub const GET_PRIORITY_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPriorityRequest {
+    pub id: u32,
+}
+impl GetPriorityRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let id_bytes = self.id.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PRIORITY_REQUEST,
+            0,
+            0,
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PRIORITY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (id, remaining) = u32::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetPriorityRequest {
+            id,
+        })
+    }
+}
+impl Request for GetPriorityRequest {
+    type Reply = GetPriorityReply;
+}
+impl Trace for GetPriorityRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPriorityReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub priority: i32,
+}
+impl TryParse for GetPriorityReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (priority, remaining) = i32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPriorityReply { sequence, length, priority };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPriorityReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetPriorityReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("priority", |tracer| tracer.value(&self.priority))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPriorityReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.priority.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the CreateFence request
+pub const CREATE_FENCE_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateFenceRequest {
+    pub drawable: xproto::Drawable,
+    pub fence: Fence,
+    pub initially_triggered: bool,
+}
+impl CreateFenceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let initially_triggered_bytes = self.initially_triggered.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_FENCE_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+            initially_triggered_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (fence, remaining) = Fence::try_parse_with_order(remaining, order)?;
+        let (initially_triggered, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateFenceRequest {
+            drawable,
+            fence,
+            initially_triggered,
+        })
+    }
+}
+impl Request for CreateFenceRequest {
+    type Reply = ();
+}
+impl Trace for CreateFenceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("fence", |tracer| tracer.xid(&self.fence))?;
+        tracer.field("initially-triggered", |tracer| tracer.value(&self.initially_triggered))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the TriggerFence request
+pub const TRIGGER_FENCE_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerFenceRequest {
+    pub fence: Fence,
+}
+impl TriggerFenceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            TRIGGER_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRIGGER_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (fence, remaining) = Fence::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(TriggerFenceRequest {
+            fence,
+        })
+    }
+}
+impl Request for TriggerFenceRequest {
+    type Reply = ();
+}
+impl Trace for TriggerFenceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("fence", |tracer| tracer.xid(&self.fence))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ResetFence request
+pub const RESET_FENCE_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResetFenceRequest {
+    pub fence: Fence,
+}
+impl ResetFenceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            RESET_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != RESET_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (fence, remaining) = Fence::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(ResetFenceRequest {
+            fence,
+        })
+    }
+}
+impl Request for ResetFenceRequest {
+    type Reply = ();
+}
+impl Trace for ResetFenceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("fence", |tracer| tracer.xid(&self.fence))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyFence request
+pub const DESTROY_FENCE_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyFenceRequest {
+    pub fence: Fence,
+}
+impl DestroyFenceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (fence, remaining) = Fence::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyFenceRequest {
+            fence,
+        })
+    }
+}
+impl Request for DestroyFenceRequest {
+    type Reply = ();
+}
+impl Trace for DestroyFenceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("fence", |tracer| tracer.xid(&self.fence))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryFence request
+pub const QUERY_FENCE_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryFenceRequest {
+    pub fence: Fence,
+}
+impl QueryFenceRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let fence_bytes = self.fence.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_FENCE_REQUEST,
+            0,
+            0,
+            fence_bytes[0],
+            fence_bytes[1],
+            fence_bytes[2],
+            fence_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (fence, remaining) = Fence::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryFenceRequest {
+            fence,
+        })
+    }
+}
+impl Request for QueryFenceRequest {
+    type Reply = QueryFenceReply;
+}
+impl Trace for QueryFenceRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("fence", |tracer| tracer.xid(&self.fence))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryFenceReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub triggered: bool,
+}
+impl TryParse for QueryFenceReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (triggered, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(23..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryFenceReply { sequence, length, triggered };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryFenceReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryFenceReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("triggered", |tracer| tracer.value(&self.triggered))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryFenceReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.triggered.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 23]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the AwaitFence request
+pub const AWAIT_FENCE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AwaitFenceRequest<'input> {
+    pub fence_list: Cow<'input, [Fence]>,
+}
+impl<'input> AwaitFenceRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            AWAIT_FENCE_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let fence_list_bytes = self.fence_list.serialize_with_order(order);
+        let length_so_far = length_so_far + fence_list_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), fence_list_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != AWAIT_FENCE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let mut remaining = value;
+        // Length is 'everything left in the input'
+        let mut fence_list = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = Fence::try_parse_with_order(value, order)?;
+            remaining = new_remaining;
+            fence_list.push(v);
+        }
+        let _ = remaining;
+        Ok(AwaitFenceRequest {
+            fence_list: Cow::Owned(fence_list),
+        })
+    }
+    /// Clone all borrowed data in this AwaitFenceRequest.
+    pub fn into_owned(self) -> AwaitFenceRequest<'static> {
+        AwaitFenceRequest {
+            fence_list: Cow::Owned(self.fence_list.into_owned()),
+        }
+    }
+}
+impl<'input> Request for AwaitFenceRequest<'input> {
+    type Reply = ();
+}
+impl Trace for AwaitFenceRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("fence-list", |tracer| tracer.list(&self.fence_list[..], |tracer, value| tracer.xid(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CounterNotify event
+pub const COUNTER_NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CounterNotifyEvent {
+    pub response_type: u8,
+    pub kind: u8,
+    pub sequence: u16,
+    pub counter: Counter,
+    pub wait_value: Int64,
+    pub counter_value: Int64,
+    pub timestamp: xproto::Timestamp,
+    pub count: u16,
+    pub destroyed: bool,
+}
+impl TryParse for CounterNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (counter, remaining) = Counter::try_parse_with_order(remaining, order)?;
+        let (wait_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (counter_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (destroyed, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let result = CounterNotifyEvent { response_type, kind, sequence, counter, wait_value, counter_value, timestamp, count, destroyed };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CounterNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CounterNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("kind", |tracer| tracer.value(&self.kind))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("counter", |tracer| tracer.xid(&self.counter))?;
+        tracer.field("wait-value", |tracer| tracer.value(&self.wait_value))?;
+        tracer.field("counter-value", |tracer| tracer.value(&self.counter_value))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("count", |tracer| tracer.value(&self.count))?;
+        tracer.field("destroyed", |tracer| tracer.value(&self.destroyed))?;
+        Ok(())
+    }
+}
+impl CounterNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let kind_bytes = self.kind.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let counter_bytes = self.counter.serialize_with_order(order);
+        let wait_value_bytes = self.wait_value.serialize_with_order(order);
+        let counter_value_bytes = self.counter_value.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let destroyed_bytes = self.destroyed.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            kind_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            counter_bytes[0],
+            counter_bytes[1],
+            counter_bytes[2],
+            counter_bytes[3],
+            wait_value_bytes[0],
+            wait_value_bytes[1],
+            wait_value_bytes[2],
+            wait_value_bytes[3],
+            wait_value_bytes[4],
+            wait_value_bytes[5],
+            wait_value_bytes[6],
+            wait_value_bytes[7],
+            counter_value_bytes[0],
+            counter_value_bytes[1],
+            counter_value_bytes[2],
+            counter_value_bytes[3],
+            counter_value_bytes[4],
+            counter_value_bytes[5],
+            counter_value_bytes[6],
+            counter_value_bytes[7],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            count_bytes[0],
+            count_bytes[1],
+            destroyed_bytes[0],
+            0,
+        ]
+    }
+}
+impl From<&CounterNotifyEvent> for [u8; 32] {
+    fn from(input: &CounterNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<CounterNotifyEvent> for [u8; 32] {
+    fn from(input: CounterNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the AlarmNotify event
+pub const ALARM_NOTIFY_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlarmNotifyEvent {
+    pub response_type: u8,
+    pub kind: u8,
+    pub sequence: u16,
+    pub alarm: Alarm,
+    pub counter_value: Int64,
+    pub alarm_value: Int64,
+    pub timestamp: xproto::Timestamp,
+    pub state: ALARMSTATE,
+}
+impl TryParse for AlarmNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (alarm, remaining) = Alarm::try_parse_with_order(remaining, order)?;
+        let (counter_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (alarm_value, remaining) = Int64::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let state = state.into();
+        let result = AlarmNotifyEvent { response_type, kind, sequence, alarm, counter_value, alarm_value, timestamp, state };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AlarmNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for AlarmNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("kind", |tracer| tracer.value(&self.kind))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("alarm", |tracer| tracer.xid(&self.alarm))?;
+        tracer.field("counter-value", |tracer| tracer.value(&self.counter_value))?;
+        tracer.field("alarm-value", |tracer| tracer.value(&self.alarm_value))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        Ok(())
+    }
+}
+impl AlarmNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let kind_bytes = self.kind.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let alarm_bytes = self.alarm.serialize_with_order(order);
+        let counter_value_bytes = self.counter_value.serialize_with_order(order);
+        let alarm_value_bytes = self.alarm_value.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let state_bytes = u8::from(self.state).serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            kind_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            alarm_bytes[0],
+            alarm_bytes[1],
+            alarm_bytes[2],
+            alarm_bytes[3],
+            counter_value_bytes[0],
+            counter_value_bytes[1],
+            counter_value_bytes[2],
+            counter_value_bytes[3],
+            counter_value_bytes[4],
+            counter_value_bytes[5],
+            counter_value_bytes[6],
+            counter_value_bytes[7],
+            alarm_value_bytes[0],
+            alarm_value_bytes[1],
+            alarm_value_bytes[2],
+            alarm_value_bytes[3],
+            alarm_value_bytes[4],
+            alarm_value_bytes[5],
+            alarm_value_bytes[6],
+            alarm_value_bytes[7],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            state_bytes[0],
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&AlarmNotifyEvent> for [u8; 32] {
+    fn from(input: &AlarmNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<AlarmNotifyEvent> for [u8; 32] {
+    fn from(input: AlarmNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "Initialize",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "desired_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "desired_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "ListSystemCounters",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counters_len",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counters",
+                kind: FieldKind::List(TypeInfo { name: "SYSTEMCOUNTER", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "CreateCounter",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "initial_value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "SetCounter",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter",
+                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "ChangeCounter",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter",
+                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "amount",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "QueryCounter",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter",
+                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter_value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "DestroyCounter",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter",
+                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "Await",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wait_list",
+                kind: FieldKind::List(TypeInfo { name: "WAITCONDITION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "CreateAlarm",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "ALARM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "CA", variants: CA::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "value_list",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "counter",
+                                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "valueType",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "VALUETYPE", variants: VALUETYPE::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "value",
+                                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "testType",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "TESTTYPE", variants: TESTTYPE::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "delta",
+                                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "events",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "ChangeAlarm",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "ALARM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "CA", variants: CA::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "value_list",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "counter",
+                                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "valueType",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "VALUETYPE", variants: VALUETYPE::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "value",
+                                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "testType",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Enum(EnumInfo { name: "TESTTYPE", variants: TESTTYPE::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "delta",
+                                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "events",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "QueryAlarm",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "alarm",
+                kind: FieldKind::Value(TypeInfo { name: "ALARM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "trigger",
+                kind: FieldKind::Value(TypeInfo { name: "TRIGGER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "delta",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "events",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "ALARMSTATE", variants: ALARMSTATE::VARIANTS }) }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "DestroyAlarm",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "alarm",
+                kind: FieldKind::Value(TypeInfo { name: "ALARM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "SetPriority",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "priority",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "GetPriority",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "priority",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "CreateFence",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "initially_triggered",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "TriggerFence",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "ResetFence",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "DestroyFence",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "QueryFence",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence",
+                kind: FieldKind::Value(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "triggered",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Sync"),
+        name: "AwaitFence",
+        opcode: 19,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "fence_list",
+                kind: FieldKind::List(TypeInfo { name: "FENCE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("Sync"),
+        name: "AlarmNotify",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "kind",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "alarm",
+                kind: FieldKind::Value(TypeInfo { name: "ALARM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter_value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "alarm_value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "ALARMSTATE", variants: ALARMSTATE::VARIANTS }) }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Sync"),
+        name: "CounterNotify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "kind",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter",
+                kind: FieldKind::Value(TypeInfo { name: "COUNTER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wait_value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "counter_value",
+                kind: FieldKind::Value(TypeInfo { name: "sync:INT64", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "count",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destroyed",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
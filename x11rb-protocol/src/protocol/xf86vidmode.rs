@@ -0,0 +1,4674 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `XF86VidMode` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "XFree86-VidModeExtension";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (2, 2);
+
+pub type Syncrange = u32;
+
+pub type Dotclock = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ModeFlag(u16);
+impl ModeFlag {
+    pub const POSITIVE_H_SYNC: Self = Self(1 << 0);
+    pub const NEGATIVE_H_SYNC: Self = Self(1 << 1);
+    pub const POSITIVE_V_SYNC: Self = Self(1 << 2);
+    pub const NEGATIVE_V_SYNC: Self = Self(1 << 3);
+    pub const INTERLACE: Self = Self(1 << 4);
+    pub const COMPOSITE_SYNC: Self = Self(1 << 5);
+    pub const POSITIVE_C_SYNC: Self = Self(1 << 6);
+    pub const NEGATIVE_C_SYNC: Self = Self(1 << 7);
+    pub const H_SKEW: Self = Self(1 << 8);
+    pub const BROADCAST: Self = Self(1 << 9);
+    pub const PIXMUX: Self = Self(1 << 10);
+    pub const DOUBLE_CLOCK: Self = Self(1 << 11);
+    pub const HALF_CLOCK: Self = Self(1 << 12);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "POSITIVE_H_SYNC", "PositiveHSync"),
+        (1 << 1, "NEGATIVE_H_SYNC", "NegativeHSync"),
+        (1 << 2, "POSITIVE_V_SYNC", "PositiveVSync"),
+        (1 << 3, "NEGATIVE_V_SYNC", "NegativeVSync"),
+        (1 << 4, "INTERLACE", "Interlace"),
+        (1 << 5, "COMPOSITE_SYNC", "CompositeSync"),
+        (1 << 6, "POSITIVE_C_SYNC", "PositiveCSync"),
+        (1 << 7, "NEGATIVE_C_SYNC", "NegativeCSync"),
+        (1 << 8, "H_SKEW", "HSkew"),
+        (1 << 9, "BROADCAST", "Broadcast"),
+        (1 << 10, "PIXMUX", "Pixmux"),
+        (1 << 11, "DOUBLE_CLOCK", "DoubleClock"),
+        (1 << 12, "HALF_CLOCK", "HalfClock"),
+    ];
+}
+impl From<ModeFlag> for u16 {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        input.0
+    }
+}
+impl From<ModeFlag> for Option<u16> {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ModeFlag> for u32 {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ModeFlag> for Option<u32> {
+    #[inline]
+    fn from(input: ModeFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ModeFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for ModeFlag {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ModeFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ModeFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ModeFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ModeFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(ModeFlag, u16);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ClockFlag(u8);
+impl ClockFlag {
+    pub const PROGRAMABLE: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "PROGRAMABLE", "Programable"),
+    ];
+}
+impl From<ClockFlag> for u8 {
+    #[inline]
+    fn from(input: ClockFlag) -> Self {
+        input.0
+    }
+}
+impl From<ClockFlag> for Option<u8> {
+    #[inline]
+    fn from(input: ClockFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ClockFlag> for u16 {
+    #[inline]
+    fn from(input: ClockFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ClockFlag> for Option<u16> {
+    #[inline]
+    fn from(input: ClockFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ClockFlag> for u32 {
+    #[inline]
+    fn from(input: ClockFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ClockFlag> for Option<u32> {
+    #[inline]
+    fn from(input: ClockFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ClockFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ClockFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ClockFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClockFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClockFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(ClockFlag, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Permission(u8);
+impl Permission {
+    pub const READ: Self = Self(1 << 0);
+    pub const WRITE: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "READ", "Read"),
+        (1 << 1, "WRITE", "Write"),
+    ];
+}
+impl From<Permission> for u8 {
+    #[inline]
+    fn from(input: Permission) -> Self {
+        input.0
+    }
+}
+impl From<Permission> for Option<u8> {
+    #[inline]
+    fn from(input: Permission) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Permission> for u16 {
+    #[inline]
+    fn from(input: Permission) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Permission> for Option<u16> {
+    #[inline]
+    fn from(input: Permission) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Permission> for u32 {
+    #[inline]
+    fn from(input: Permission) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Permission> for Option<u32> {
+    #[inline]
+    fn from(input: Permission) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Permission {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Permission  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Permission {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Permission {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Permission {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Permission, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModeInfo {
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u32,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub privsize: u32,
+}
+impl TryParse for ModeInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = ModeInfo { dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, privsize };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ModeInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ModeInfo {
+    type Bytes = [u8; 48];
+    fn serialize(&self) -> [u8; 48] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 48] {
+        let dotclock_bytes = self.dotclock.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let privsize_bytes = self.privsize.serialize_with_order(order);
+        [
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            hskew_bytes[2],
+            hskew_bytes[3],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(48);
+        self.dotclock.serialize_into_with_order(bytes, order);
+        self.hdisplay.serialize_into_with_order(bytes, order);
+        self.hsyncstart.serialize_into_with_order(bytes, order);
+        self.hsyncend.serialize_into_with_order(bytes, order);
+        self.htotal.serialize_into_with_order(bytes, order);
+        self.hskew.serialize_into_with_order(bytes, order);
+        self.vdisplay.serialize_into_with_order(bytes, order);
+        self.vsyncstart.serialize_into_with_order(bytes, order);
+        self.vsyncend.serialize_into_with_order(bytes, order);
+        self.vtotal.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.flags.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.privsize.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ModeInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("dotclock", |tracer| tracer.value(&self.dotclock))?;
+            tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+            tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+            tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+            tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+            tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+            tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+            tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+            tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+            tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+            tracer.field("privsize", |tracer| tracer.value(&self.privsize))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest;
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(QueryVersionRequest
+        )
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetModeLine request
+pub const GET_MODE_LINE_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetModeLineRequest {
+    pub screen: u16,
+}
+impl GetModeLineRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetModeLineRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetModeLineRequest {
+    type Reply = GetModeLineReply;
+}
+impl Trace for GetModeLineRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetModeLineReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub private: Vec<u8>,
+}
+impl TryParse for GetModeLineReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let private = private.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetModeLineReply { sequence, length, dotclock, hdisplay, hsyncstart, hsyncend, htotal, hskew, vdisplay, vsyncstart, vsyncend, vtotal, flags, private };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetModeLineReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetModeLineReply {
+    /// Get the value of the `privsize` field.
+    ///
+    /// The `privsize` field is used as the length field of the `private` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn privsize(&self) -> u32 {
+        self.private.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetModeLineReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("dotclock", |tracer| tracer.value(&self.dotclock))?;
+        tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+        tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+        tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+        tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+        tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+        tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+        tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+        tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+        tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+        tracer.field("private", |tracer| tracer.list(&self.private, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetModeLineReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.dotclock.serialize_into_with_order(bytes, order);
+        self.hdisplay.serialize_into_with_order(bytes, order);
+        self.hsyncstart.serialize_into_with_order(bytes, order);
+        self.hsyncend.serialize_into_with_order(bytes, order);
+        self.htotal.serialize_into_with_order(bytes, order);
+        self.hskew.serialize_into_with_order(bytes, order);
+        self.vdisplay.serialize_into_with_order(bytes, order);
+        self.vsyncstart.serialize_into_with_order(bytes, order);
+        self.vsyncend.serialize_into_with_order(bytes, order);
+        self.vtotal.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.flags.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        privsize.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.private);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ModModeLine request
+pub const MOD_MODE_LINE_REQUEST: u8 = 2;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModModeLineRequest<'input> {
+    pub screen: u32,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub private: Cow<'input, [u8]>,
+}
+impl<'input> ModModeLineRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            MOD_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.private.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.private, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != MOD_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(ModModeLineRequest {
+            screen,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private: Cow::Borrowed(private),
+        })
+    }
+    /// Clone all borrowed data in this ModModeLineRequest.
+    pub fn into_owned(self) -> ModModeLineRequest<'static> {
+        ModModeLineRequest {
+            screen: self.screen,
+            hdisplay: self.hdisplay,
+            hsyncstart: self.hsyncstart,
+            hsyncend: self.hsyncend,
+            htotal: self.htotal,
+            hskew: self.hskew,
+            vdisplay: self.vdisplay,
+            vsyncstart: self.vsyncstart,
+            vsyncend: self.vsyncend,
+            vtotal: self.vtotal,
+            flags: self.flags,
+            private: Cow::Owned(self.private.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ModModeLineRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ModModeLineRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+        tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+        tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+        tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+        tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+        tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+        tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+        tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+        tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+        tracer.field("private", |tracer| tracer.list(&self.private[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SwitchMode request
+pub const SWITCH_MODE_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwitchModeRequest {
+    pub screen: u16,
+    pub zoom: u16,
+}
+impl SwitchModeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let zoom_bytes = self.zoom.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SWITCH_MODE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            zoom_bytes[0],
+            zoom_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWITCH_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let (zoom, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SwitchModeRequest {
+            screen,
+            zoom,
+        })
+    }
+}
+impl Request for SwitchModeRequest {
+    type Reply = ();
+}
+impl Trace for SwitchModeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("zoom", |tracer| tracer.value(&self.zoom))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetMonitor request
+pub const GET_MONITOR_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMonitorRequest {
+    pub screen: u16,
+}
+impl GetMonitorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MONITOR_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MONITOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetMonitorRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetMonitorRequest {
+    type Reply = GetMonitorReply;
+}
+impl Trace for GetMonitorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMonitorReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub hsync: Vec<Syncrange>,
+    pub vsync: Vec<Syncrange>,
+    pub vendor: Vec<u8>,
+    pub alignment_pad: Vec<u8>,
+    pub model: Vec<u8>,
+}
+impl TryParse for GetMonitorReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vendor_length, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (model_length, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_hsync, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_vsync, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (hsync, remaining) = crate::x11_utils::parse_list_with_order::<Syncrange>(remaining, num_hsync.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (vsync, remaining) = crate::x11_utils::parse_list_with_order::<Syncrange>(remaining, num_vsync.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (vendor, remaining) = crate::x11_utils::parse_u8_list(remaining, vendor_length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let vendor = vendor.to_vec();
+        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(vendor_length).checked_add(3u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(vendor_length)).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let alignment_pad = alignment_pad.to_vec();
+        let (model, remaining) = crate::x11_utils::parse_u8_list(remaining, model_length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let model = model.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMonitorReply { sequence, length, hsync, vsync, vendor, alignment_pad, model };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMonitorReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetMonitorReply {
+    /// Get the value of the `vendor_length` field.
+    ///
+    /// The `vendor_length` field is used as the length field of the `vendor` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn vendor_length(&self) -> u8 {
+        self.vendor.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `model_length` field.
+    ///
+    /// The `model_length` field is used as the length field of the `model` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn model_length(&self) -> u8 {
+        self.model.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_hsync` field.
+    ///
+    /// The `num_hsync` field is used as the length field of the `hsync` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_hsync(&self) -> u8 {
+        self.hsync.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_vsync` field.
+    ///
+    /// The `num_vsync` field is used as the length field of the `vsync` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_vsync(&self) -> u8 {
+        self.vsync.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetMonitorReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("hsync", |tracer| tracer.list(&self.hsync, |tracer, value| tracer.value(value)))?;
+        tracer.field("vsync", |tracer| tracer.list(&self.vsync, |tracer, value| tracer.value(value)))?;
+        tracer.field("vendor", |tracer| tracer.string(&self.vendor))?;
+        tracer.field("alignment-pad", |tracer| tracer.list(&self.alignment_pad, |tracer, value| tracer.value(value)))?;
+        tracer.field("model", |tracer| tracer.string(&self.model))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMonitorReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let vendor_length = u8::try_from(self.vendor.len()).expect("`vendor` has too many elements");
+        vendor_length.serialize_into_with_order(bytes, order);
+        let model_length = u8::try_from(self.model.len()).expect("`model` has too many elements");
+        model_length.serialize_into_with_order(bytes, order);
+        let num_hsync = u8::try_from(self.hsync.len()).expect("`hsync` has too many elements");
+        num_hsync.serialize_into_with_order(bytes, order);
+        let num_vsync = u8::try_from(self.vsync.len()).expect("`vsync` has too many elements");
+        num_vsync.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.hsync.serialize_into_with_order(bytes, order);
+        self.vsync.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.vendor);
+        assert_eq!(self.alignment_pad.len(), usize::try_from((u32::from(vendor_length).checked_add(3u32).unwrap() & (!3u32)).checked_sub(u32::from(vendor_length)).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
+        bytes.extend_from_slice(&self.alignment_pad);
+        bytes.extend_from_slice(&self.model);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the LockModeSwitch request
+pub const LOCK_MODE_SWITCH_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockModeSwitchRequest {
+    pub screen: u16,
+    pub lock: u16,
+}
+impl LockModeSwitchRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let lock_bytes = self.lock.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            LOCK_MODE_SWITCH_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            lock_bytes[0],
+            lock_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LOCK_MODE_SWITCH_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let (lock, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(LockModeSwitchRequest {
+            screen,
+            lock,
+        })
+    }
+}
+impl Request for LockModeSwitchRequest {
+    type Reply = ();
+}
+impl Trace for LockModeSwitchRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("lock", |tracer| tracer.value(&self.lock))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetAllModeLines request
+pub const GET_ALL_MODE_LINES_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetAllModeLinesRequest {
+    pub screen: u16,
+}
+impl GetAllModeLinesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_ALL_MODE_LINES_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_ALL_MODE_LINES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetAllModeLinesRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetAllModeLinesRequest {
+    type Reply = GetAllModeLinesReply;
+}
+impl Trace for GetAllModeLinesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetAllModeLinesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub modeinfo: Vec<ModeInfo>,
+}
+impl TryParse for GetAllModeLinesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (modecount, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (modeinfo, remaining) = crate::x11_utils::parse_list_with_order::<ModeInfo>(remaining, modecount.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetAllModeLinesReply { sequence, length, modeinfo };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetAllModeLinesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetAllModeLinesReply {
+    /// Get the value of the `modecount` field.
+    ///
+    /// The `modecount` field is used as the length field of the `modeinfo` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn modecount(&self) -> u32 {
+        self.modeinfo.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetAllModeLinesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("modeinfo", |tracer| tracer.list(&self.modeinfo, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetAllModeLinesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let modecount = u32::try_from(self.modeinfo.len()).expect("`modeinfo` has too many elements");
+        modecount.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.modeinfo.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the AddModeLine request
+pub const ADD_MODE_LINE_REQUEST: u8 = 7;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddModeLineRequest<'input> {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub after_dotclock: Dotclock,
+    pub after_hdisplay: u16,
+    pub after_hsyncstart: u16,
+    pub after_hsyncend: u16,
+    pub after_htotal: u16,
+    pub after_hskew: u16,
+    pub after_vdisplay: u16,
+    pub after_vsyncstart: u16,
+    pub after_vsyncend: u16,
+    pub after_vtotal: u16,
+    pub after_flags: u32,
+    pub private: Cow<'input, [u8]>,
+}
+impl<'input> AddModeLineRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let dotclock_bytes = self.dotclock.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize_with_order(order);
+        let after_dotclock_bytes = self.after_dotclock.serialize_with_order(order);
+        let after_hdisplay_bytes = self.after_hdisplay.serialize_with_order(order);
+        let after_hsyncstart_bytes = self.after_hsyncstart.serialize_with_order(order);
+        let after_hsyncend_bytes = self.after_hsyncend.serialize_with_order(order);
+        let after_htotal_bytes = self.after_htotal.serialize_with_order(order);
+        let after_hskew_bytes = self.after_hskew.serialize_with_order(order);
+        let after_vdisplay_bytes = self.after_vdisplay.serialize_with_order(order);
+        let after_vsyncstart_bytes = self.after_vsyncstart.serialize_with_order(order);
+        let after_vsyncend_bytes = self.after_vsyncend.serialize_with_order(order);
+        let after_vtotal_bytes = self.after_vtotal.serialize_with_order(order);
+        let after_flags_bytes = self.after_flags.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            ADD_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+            after_dotclock_bytes[0],
+            after_dotclock_bytes[1],
+            after_dotclock_bytes[2],
+            after_dotclock_bytes[3],
+            after_hdisplay_bytes[0],
+            after_hdisplay_bytes[1],
+            after_hsyncstart_bytes[0],
+            after_hsyncstart_bytes[1],
+            after_hsyncend_bytes[0],
+            after_hsyncend_bytes[1],
+            after_htotal_bytes[0],
+            after_htotal_bytes[1],
+            after_hskew_bytes[0],
+            after_hskew_bytes[1],
+            after_vdisplay_bytes[0],
+            after_vdisplay_bytes[1],
+            after_vsyncstart_bytes[0],
+            after_vsyncstart_bytes[1],
+            after_vsyncend_bytes[0],
+            after_vsyncend_bytes[1],
+            after_vtotal_bytes[0],
+            after_vtotal_bytes[1],
+            0,
+            0,
+            after_flags_bytes[0],
+            after_flags_bytes[1],
+            after_flags_bytes[2],
+            after_flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.private.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.private, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != ADD_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (after_dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (after_hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (after_vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (after_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(AddModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            after_dotclock,
+            after_hdisplay,
+            after_hsyncstart,
+            after_hsyncend,
+            after_htotal,
+            after_hskew,
+            after_vdisplay,
+            after_vsyncstart,
+            after_vsyncend,
+            after_vtotal,
+            after_flags,
+            private: Cow::Borrowed(private),
+        })
+    }
+    /// Clone all borrowed data in this AddModeLineRequest.
+    pub fn into_owned(self) -> AddModeLineRequest<'static> {
+        AddModeLineRequest {
+            screen: self.screen,
+            dotclock: self.dotclock,
+            hdisplay: self.hdisplay,
+            hsyncstart: self.hsyncstart,
+            hsyncend: self.hsyncend,
+            htotal: self.htotal,
+            hskew: self.hskew,
+            vdisplay: self.vdisplay,
+            vsyncstart: self.vsyncstart,
+            vsyncend: self.vsyncend,
+            vtotal: self.vtotal,
+            flags: self.flags,
+            after_dotclock: self.after_dotclock,
+            after_hdisplay: self.after_hdisplay,
+            after_hsyncstart: self.after_hsyncstart,
+            after_hsyncend: self.after_hsyncend,
+            after_htotal: self.after_htotal,
+            after_hskew: self.after_hskew,
+            after_vdisplay: self.after_vdisplay,
+            after_vsyncstart: self.after_vsyncstart,
+            after_vsyncend: self.after_vsyncend,
+            after_vtotal: self.after_vtotal,
+            after_flags: self.after_flags,
+            private: Cow::Owned(self.private.into_owned()),
+        }
+    }
+}
+impl<'input> Request for AddModeLineRequest<'input> {
+    type Reply = ();
+}
+impl Trace for AddModeLineRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("dotclock", |tracer| tracer.value(&self.dotclock))?;
+        tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+        tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+        tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+        tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+        tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+        tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+        tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+        tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+        tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+        tracer.field("after-dotclock", |tracer| tracer.value(&self.after_dotclock))?;
+        tracer.field("after-hdisplay", |tracer| tracer.value(&self.after_hdisplay))?;
+        tracer.field("after-hsyncstart", |tracer| tracer.value(&self.after_hsyncstart))?;
+        tracer.field("after-hsyncend", |tracer| tracer.value(&self.after_hsyncend))?;
+        tracer.field("after-htotal", |tracer| tracer.value(&self.after_htotal))?;
+        tracer.field("after-hskew", |tracer| tracer.value(&self.after_hskew))?;
+        tracer.field("after-vdisplay", |tracer| tracer.value(&self.after_vdisplay))?;
+        tracer.field("after-vsyncstart", |tracer| tracer.value(&self.after_vsyncstart))?;
+        tracer.field("after-vsyncend", |tracer| tracer.value(&self.after_vsyncend))?;
+        tracer.field("after-vtotal", |tracer| tracer.value(&self.after_vtotal))?;
+        tracer.field("after-flags", |tracer| tracer.bitmask(&self.after_flags, ModeFlag::VARIANTS))?;
+        tracer.field("private", |tracer| tracer.list(&self.private[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeleteModeLine request
+pub const DELETE_MODE_LINE_REQUEST: u8 = 8;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteModeLineRequest<'input> {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub private: Cow<'input, [u8]>,
+}
+impl<'input> DeleteModeLineRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let dotclock_bytes = self.dotclock.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.private.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.private, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(DeleteModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private: Cow::Borrowed(private),
+        })
+    }
+    /// Clone all borrowed data in this DeleteModeLineRequest.
+    pub fn into_owned(self) -> DeleteModeLineRequest<'static> {
+        DeleteModeLineRequest {
+            screen: self.screen,
+            dotclock: self.dotclock,
+            hdisplay: self.hdisplay,
+            hsyncstart: self.hsyncstart,
+            hsyncend: self.hsyncend,
+            htotal: self.htotal,
+            hskew: self.hskew,
+            vdisplay: self.vdisplay,
+            vsyncstart: self.vsyncstart,
+            vsyncend: self.vsyncend,
+            vtotal: self.vtotal,
+            flags: self.flags,
+            private: Cow::Owned(self.private.into_owned()),
+        }
+    }
+}
+impl<'input> Request for DeleteModeLineRequest<'input> {
+    type Reply = ();
+}
+impl Trace for DeleteModeLineRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("dotclock", |tracer| tracer.value(&self.dotclock))?;
+        tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+        tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+        tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+        tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+        tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+        tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+        tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+        tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+        tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+        tracer.field("private", |tracer| tracer.list(&self.private[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ValidateModeLine request
+pub const VALIDATE_MODE_LINE_REQUEST: u8 = 9;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidateModeLineRequest<'input> {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub private: Cow<'input, [u8]>,
+}
+impl<'input> ValidateModeLineRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let dotclock_bytes = self.dotclock.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            VALIDATE_MODE_LINE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.private.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.private, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != VALIDATE_MODE_LINE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(ValidateModeLineRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private: Cow::Borrowed(private),
+        })
+    }
+    /// Clone all borrowed data in this ValidateModeLineRequest.
+    pub fn into_owned(self) -> ValidateModeLineRequest<'static> {
+        ValidateModeLineRequest {
+            screen: self.screen,
+            dotclock: self.dotclock,
+            hdisplay: self.hdisplay,
+            hsyncstart: self.hsyncstart,
+            hsyncend: self.hsyncend,
+            htotal: self.htotal,
+            hskew: self.hskew,
+            vdisplay: self.vdisplay,
+            vsyncstart: self.vsyncstart,
+            vsyncend: self.vsyncend,
+            vtotal: self.vtotal,
+            flags: self.flags,
+            private: Cow::Owned(self.private.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ValidateModeLineRequest<'input> {
+    type Reply = ValidateModeLineReply;
+}
+impl Trace for ValidateModeLineRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("dotclock", |tracer| tracer.value(&self.dotclock))?;
+        tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+        tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+        tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+        tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+        tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+        tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+        tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+        tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+        tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+        tracer.field("private", |tracer| tracer.list(&self.private[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidateModeLineReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub status: u32,
+}
+impl TryParse for ValidateModeLineReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (status, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ValidateModeLineReply { sequence, length, status };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ValidateModeLineReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for ValidateModeLineReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("status", |tracer| tracer.value(&self.status))?;
+        Ok(())
+    }
+}
+impl Serialize for ValidateModeLineReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.status.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SwitchToMode request
+pub const SWITCH_TO_MODE_REQUEST: u8 = 10;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchToModeRequest<'input> {
+    pub screen: u32,
+    pub dotclock: Dotclock,
+    pub hdisplay: u16,
+    pub hsyncstart: u16,
+    pub hsyncend: u16,
+    pub htotal: u16,
+    pub hskew: u16,
+    pub vdisplay: u16,
+    pub vsyncstart: u16,
+    pub vsyncend: u16,
+    pub vtotal: u16,
+    pub flags: u32,
+    pub private: Cow<'input, [u8]>,
+}
+impl<'input> SwitchToModeRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let dotclock_bytes = self.dotclock.serialize_with_order(order);
+        let hdisplay_bytes = self.hdisplay.serialize_with_order(order);
+        let hsyncstart_bytes = self.hsyncstart.serialize_with_order(order);
+        let hsyncend_bytes = self.hsyncend.serialize_with_order(order);
+        let htotal_bytes = self.htotal.serialize_with_order(order);
+        let hskew_bytes = self.hskew.serialize_with_order(order);
+        let vdisplay_bytes = self.vdisplay.serialize_with_order(order);
+        let vsyncstart_bytes = self.vsyncstart.serialize_with_order(order);
+        let vsyncend_bytes = self.vsyncend.serialize_with_order(order);
+        let vtotal_bytes = self.vtotal.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let privsize = u32::try_from(self.private.len()).expect("`private` has too many elements");
+        let privsize_bytes = privsize.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SWITCH_TO_MODE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            screen_bytes[2],
+            screen_bytes[3],
+            dotclock_bytes[0],
+            dotclock_bytes[1],
+            dotclock_bytes[2],
+            dotclock_bytes[3],
+            hdisplay_bytes[0],
+            hdisplay_bytes[1],
+            hsyncstart_bytes[0],
+            hsyncstart_bytes[1],
+            hsyncend_bytes[0],
+            hsyncend_bytes[1],
+            htotal_bytes[0],
+            htotal_bytes[1],
+            hskew_bytes[0],
+            hskew_bytes[1],
+            vdisplay_bytes[0],
+            vdisplay_bytes[1],
+            vsyncstart_bytes[0],
+            vsyncstart_bytes[1],
+            vsyncend_bytes[0],
+            vsyncend_bytes[1],
+            vtotal_bytes[0],
+            vtotal_bytes[1],
+            0,
+            0,
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            privsize_bytes[0],
+            privsize_bytes[1],
+            privsize_bytes[2],
+            privsize_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.private.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.private, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SWITCH_TO_MODE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u32::try_parse_with_order(value, order)?;
+        let (dotclock, remaining) = Dotclock::try_parse_with_order(remaining, order)?;
+        let (hdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (htotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (hskew, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vdisplay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncstart, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vsyncend, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vtotal, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (privsize, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (private, remaining) = crate::x11_utils::parse_u8_list(remaining, privsize.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(SwitchToModeRequest {
+            screen,
+            dotclock,
+            hdisplay,
+            hsyncstart,
+            hsyncend,
+            htotal,
+            hskew,
+            vdisplay,
+            vsyncstart,
+            vsyncend,
+            vtotal,
+            flags,
+            private: Cow::Borrowed(private),
+        })
+    }
+    /// Clone all borrowed data in this SwitchToModeRequest.
+    pub fn into_owned(self) -> SwitchToModeRequest<'static> {
+        SwitchToModeRequest {
+            screen: self.screen,
+            dotclock: self.dotclock,
+            hdisplay: self.hdisplay,
+            hsyncstart: self.hsyncstart,
+            hsyncend: self.hsyncend,
+            htotal: self.htotal,
+            hskew: self.hskew,
+            vdisplay: self.vdisplay,
+            vsyncstart: self.vsyncstart,
+            vsyncend: self.vsyncend,
+            vtotal: self.vtotal,
+            flags: self.flags,
+            private: Cow::Owned(self.private.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SwitchToModeRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SwitchToModeRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("dotclock", |tracer| tracer.value(&self.dotclock))?;
+        tracer.field("hdisplay", |tracer| tracer.value(&self.hdisplay))?;
+        tracer.field("hsyncstart", |tracer| tracer.value(&self.hsyncstart))?;
+        tracer.field("hsyncend", |tracer| tracer.value(&self.hsyncend))?;
+        tracer.field("htotal", |tracer| tracer.value(&self.htotal))?;
+        tracer.field("hskew", |tracer| tracer.value(&self.hskew))?;
+        tracer.field("vdisplay", |tracer| tracer.value(&self.vdisplay))?;
+        tracer.field("vsyncstart", |tracer| tracer.value(&self.vsyncstart))?;
+        tracer.field("vsyncend", |tracer| tracer.value(&self.vsyncend))?;
+        tracer.field("vtotal", |tracer| tracer.value(&self.vtotal))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ModeFlag::VARIANTS))?;
+        tracer.field("private", |tracer| tracer.list(&self.private[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetViewPort request
+pub const GET_VIEW_PORT_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetViewPortRequest {
+    pub screen: u16,
+}
+impl GetViewPortRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_VIEW_PORT_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VIEW_PORT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetViewPortRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetViewPortRequest {
+    type Reply = GetViewPortReply;
+}
+impl Trace for GetViewPortRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetViewPortReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub x: u32,
+    pub y: u32,
+}
+impl TryParse for GetViewPortReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetViewPortReply { sequence, length, x, y };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetViewPortReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetViewPortReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        Ok(())
+    }
+}
+impl Serialize for GetViewPortReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetViewPort request
+pub const SET_VIEW_PORT_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetViewPortRequest {
+    pub screen: u16,
+    pub x: u32,
+    pub y: u32,
+}
+impl SetViewPortRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let x_bytes = self.x.serialize_with_order(order);
+        let y_bytes = self.y.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_VIEW_PORT_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            x_bytes[0],
+            x_bytes[1],
+            x_bytes[2],
+            x_bytes[3],
+            y_bytes[0],
+            y_bytes[1],
+            y_bytes[2],
+            y_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_VIEW_PORT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (x, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetViewPortRequest {
+            screen,
+            x,
+            y,
+        })
+    }
+}
+impl Request for SetViewPortRequest {
+    type Reply = ();
+}
+impl Trace for SetViewPortRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetDotClocks request
+pub const GET_DOT_CLOCKS_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDotClocksRequest {
+    pub screen: u16,
+}
+impl GetDotClocksRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_DOT_CLOCKS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DOT_CLOCKS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetDotClocksRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetDotClocksRequest {
+    type Reply = GetDotClocksReply;
+}
+impl Trace for GetDotClocksRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetDotClocksReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub flags: u32,
+    pub clocks: u32,
+    pub maxclocks: u32,
+    pub clock: Vec<u32>,
+}
+impl TryParse for GetDotClocksReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (clocks, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maxclocks, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (clock, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, 1u32.checked_sub(flags & 1u32).ok_or(ParseError::InvalidExpression)?.checked_mul(clocks).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDotClocksReply { sequence, length, flags, clocks, maxclocks, clock };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetDotClocksReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetDotClocksReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ClockFlag::VARIANTS))?;
+        tracer.field("clocks", |tracer| tracer.value(&self.clocks))?;
+        tracer.field("maxclocks", |tracer| tracer.value(&self.maxclocks))?;
+        tracer.field("clock", |tracer| tracer.list(&self.clock, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetDotClocksReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.clocks.serialize_into_with_order(bytes, order);
+        self.maxclocks.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        assert_eq!(self.clock.len(), usize::try_from(1u32.checked_sub(self.flags & 1u32).unwrap().checked_mul(self.clocks).unwrap()).unwrap(), "`clock` has an incorrect length");
+        self.clock.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetClientVersion request
+pub const SET_CLIENT_VERSION_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetClientVersionRequest {
+    pub major: u16,
+    pub minor: u16,
+}
+impl SetClientVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let major_bytes = self.major.serialize_with_order(order);
+        let minor_bytes = self.minor.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CLIENT_VERSION_REQUEST,
+            0,
+            0,
+            major_bytes[0],
+            major_bytes[1],
+            minor_bytes[0],
+            minor_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CLIENT_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (major, remaining) = u16::try_parse_with_order(value, order)?;
+        let (minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetClientVersionRequest {
+            major,
+            minor,
+        })
+    }
+}
+impl Request for SetClientVersionRequest {
+    type Reply = ();
+}
+impl Trace for SetClientVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("major", |tracer| tracer.value(&self.major))?;
+        tracer.field("minor", |tracer| tracer.value(&self.minor))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetGamma request
+pub const SET_GAMMA_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetGammaRequest {
+    pub screen: u16,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+impl SetGammaRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let red_bytes = self.red.serialize_with_order(order);
+        let green_bytes = self.green.serialize_with_order(order);
+        let blue_bytes = self.blue.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_GAMMA_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            red_bytes[0],
+            red_bytes[1],
+            red_bytes[2],
+            red_bytes[3],
+            green_bytes[0],
+            green_bytes[1],
+            green_bytes[2],
+            green_bytes[3],
+            blue_bytes[0],
+            blue_bytes[1],
+            blue_bytes[2],
+            blue_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_GAMMA_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (green, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (blue, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(SetGammaRequest {
+            screen,
+            red,
+            green,
+            blue,
+        })
+    }
+}
+impl Request for SetGammaRequest {
+    type Reply = ();
+}
+impl Trace for SetGammaRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("red", |tracer| tracer.value(&self.red))?;
+        tracer.field("green", |tracer| tracer.value(&self.green))?;
+        tracer.field("blue", |tracer| tracer.value(&self.blue))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetGamma request
+pub const GET_GAMMA_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetGammaRequest {
+    pub screen: u16,
+}
+impl GetGammaRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_GAMMA_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_GAMMA_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(26..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetGammaRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetGammaRequest {
+    type Reply = GetGammaReply;
+}
+impl Trace for GetGammaRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetGammaReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+impl TryParse for GetGammaReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (red, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (green, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (blue, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetGammaReply { sequence, length, red, green, blue };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetGammaReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetGammaReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("red", |tracer| tracer.value(&self.red))?;
+        tracer.field("green", |tracer| tracer.value(&self.green))?;
+        tracer.field("blue", |tracer| tracer.value(&self.blue))?;
+        Ok(())
+    }
+}
+impl Serialize for GetGammaReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.red.serialize_into_with_order(bytes, order);
+        self.green.serialize_into_with_order(bytes, order);
+        self.blue.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetGammaRamp request
+pub const GET_GAMMA_RAMP_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetGammaRampRequest {
+    pub screen: u16,
+    pub size: u16,
+}
+impl GetGammaRampRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let size_bytes = self.size.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_GAMMA_RAMP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            size_bytes[0],
+            size_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_GAMMA_RAMP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetGammaRampRequest {
+            screen,
+            size,
+        })
+    }
+}
+impl Request for GetGammaRampRequest {
+    type Reply = GetGammaRampReply;
+}
+impl Trace for GetGammaRampRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetGammaRampReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u16,
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+impl TryParse for GetGammaRampReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetGammaRampReply { sequence, length, size, red, green, blue };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetGammaRampReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetGammaRampReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        tracer.field("red", |tracer| tracer.list(&self.red, |tracer, value| tracer.value(value)))?;
+        tracer.field("green", |tracer| tracer.list(&self.green, |tracer, value| tracer.value(value)))?;
+        tracer.field("blue", |tracer| tracer.list(&self.blue, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetGammaRampReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        assert_eq!(self.red.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`red` has an incorrect length");
+        self.red.serialize_into_with_order(bytes, order);
+        assert_eq!(self.green.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`green` has an incorrect length");
+        self.green.serialize_into_with_order(bytes, order);
+        assert_eq!(self.blue.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
+        self.blue.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetGammaRamp request
+pub const SET_GAMMA_RAMP_REQUEST: u8 = 18;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetGammaRampRequest<'input> {
+    pub screen: u16,
+    pub size: u16,
+    pub red: Cow<'input, [u16]>,
+    pub green: Cow<'input, [u16]>,
+    pub blue: Cow<'input, [u16]>,
+}
+impl<'input> SetGammaRampRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let size_bytes = self.size.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_GAMMA_RAMP_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            size_bytes[0],
+            size_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(self.red.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`red` has an incorrect length");
+        let red_bytes = self.red.serialize_with_order(order);
+        let length_so_far = length_so_far + red_bytes.len();
+        assert_eq!(self.green.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`green` has an incorrect length");
+        let green_bytes = self.green.serialize_with_order(order);
+        let length_so_far = length_so_far + green_bytes.len();
+        assert_eq!(self.blue.len(), usize::try_from(u32::from(self.size).checked_add(1u32).unwrap() & (!1u32)).unwrap(), "`blue` has an incorrect length");
+        let blue_bytes = self.blue.serialize_with_order(order);
+        let length_so_far = length_so_far + blue_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), red_bytes.into(), green_bytes.into(), blue_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_GAMMA_RAMP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (red, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (green, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (blue, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, (u32::from(size).checked_add(1u32).ok_or(ParseError::InvalidExpression)? & (!1u32)).try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(SetGammaRampRequest {
+            screen,
+            size,
+            red: Cow::Owned(red),
+            green: Cow::Owned(green),
+            blue: Cow::Owned(blue),
+        })
+    }
+    /// Clone all borrowed data in this SetGammaRampRequest.
+    pub fn into_owned(self) -> SetGammaRampRequest<'static> {
+        SetGammaRampRequest {
+            screen: self.screen,
+            size: self.size,
+            red: Cow::Owned(self.red.into_owned()),
+            green: Cow::Owned(self.green.into_owned()),
+            blue: Cow::Owned(self.blue.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetGammaRampRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetGammaRampRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        tracer.field("red", |tracer| tracer.list(&self.red[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("green", |tracer| tracer.list(&self.green[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("blue", |tracer| tracer.list(&self.blue[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetGammaRampSize request
+pub const GET_GAMMA_RAMP_SIZE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetGammaRampSizeRequest {
+    pub screen: u16,
+}
+impl GetGammaRampSizeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_GAMMA_RAMP_SIZE_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_GAMMA_RAMP_SIZE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetGammaRampSizeRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetGammaRampSizeRequest {
+    type Reply = GetGammaRampSizeReply;
+}
+impl Trace for GetGammaRampSizeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetGammaRampSizeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub size: u16,
+}
+impl TryParse for GetGammaRampSizeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetGammaRampSizeReply { sequence, length, size };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetGammaRampSizeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetGammaRampSizeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("size", |tracer| tracer.value(&self.size))?;
+        Ok(())
+    }
+}
+impl Serialize for GetGammaRampSizeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetPermissions request
+pub const GET_PERMISSIONS_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPermissionsRequest {
+    pub screen: u16,
+}
+impl GetPermissionsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let screen_bytes = self.screen.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PERMISSIONS_REQUEST,
+            0,
+            0,
+            screen_bytes[0],
+            screen_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PERMISSIONS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (screen, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetPermissionsRequest {
+            screen,
+        })
+    }
+}
+impl Request for GetPermissionsRequest {
+    type Reply = GetPermissionsReply;
+}
+impl Trace for GetPermissionsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("screen", |tracer| tracer.value(&self.screen))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPermissionsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub permissions: u32,
+}
+impl TryParse for GetPermissionsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (permissions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPermissionsReply { sequence, length, permissions };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPermissionsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetPermissionsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("permissions", |tracer| tracer.bitmask(&self.permissions, Permission::VARIANTS))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPermissionsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.permissions.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the BadClock error
+pub const BAD_CLOCK_ERROR: u8 = 0;
+
+/// Opcode for the BadHTimings error
+pub const BAD_H_TIMINGS_ERROR: u8 = 1;
+
+/// Opcode for the BadVTimings error
+pub const BAD_V_TIMINGS_ERROR: u8 = 2;
+
+/// Opcode for the ModeUnsuitable error
+pub const MODE_UNSUITABLE_ERROR: u8 = 3;
+
+/// Opcode for the ExtensionDisabled error
+pub const EXTENSION_DISABLED_ERROR: u8 = 4;
+
+/// Opcode for the ClientNotLocal error
+pub const CLIENT_NOT_LOCAL_ERROR: u8 = 5;
+
+/// Opcode for the ZoomLocked error
+pub const ZOOM_LOCKED_ERROR: u8 = 6;
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetModeLine",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dotclock",
+                kind: FieldKind::Value(TypeInfo { name: "DOTCLOCK", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "privsize",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "private",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "ModModeLine",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "privsize",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "private",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "SwitchMode",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "zoom",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetMonitor",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vendor_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "model_length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_hsync",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_vsync",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsync",
+                kind: FieldKind::List(TypeInfo { name: "SYNCRANGE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsync",
+                kind: FieldKind::List(TypeInfo { name: "SYNCRANGE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vendor",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "alignment_pad",
+                kind: FieldKind::List(TypeInfo { name: "void", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "model",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "LockModeSwitch",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "lock",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetAllModeLines",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modecount",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "modeinfo",
+                kind: FieldKind::List(TypeInfo { name: "ModeInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "AddModeLine",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dotclock",
+                kind: FieldKind::Value(TypeInfo { name: "DOTCLOCK", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "privsize",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_dotclock",
+                kind: FieldKind::Value(TypeInfo { name: "DOTCLOCK", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "after_flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "private",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "DeleteModeLine",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dotclock",
+                kind: FieldKind::Value(TypeInfo { name: "DOTCLOCK", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "privsize",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "private",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "ValidateModeLine",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dotclock",
+                kind: FieldKind::Value(TypeInfo { name: "DOTCLOCK", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "privsize",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "private",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "status",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "SwitchToMode",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dotclock",
+                kind: FieldKind::Value(TypeInfo { name: "DOTCLOCK", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "htotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hskew",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vdisplay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncstart",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vsyncend",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vtotal",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ModeFlag", variants: ModeFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "privsize",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "private",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetViewPort",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "SetViewPort",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetDotClocks",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "ClockFlag", variants: ClockFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "clocks",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxclocks",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "clock",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "SetClientVersion",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "SetGamma",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "red",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "green",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "blue",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetGamma",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "red",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "green",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "blue",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetGammaRamp",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "red",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "green",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "blue",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "SetGammaRamp",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "red",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "green",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "blue",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetGammaRampSize",
+        opcode: 19,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XF86VidMode"),
+        name: "GetPermissions",
+        opcode: 20,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "screen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "permissions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "Permission", variants: Permission::VARIANTS }) }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[];
+
@@ -0,0 +1,5051 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `XFixes` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::render;
+use super::shape;
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "XFIXES";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (5, 0);
+
+/// Opcode for the QueryVersion request
+pub const QUERY_VERSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryVersionRequest {
+    pub client_major_version: u32,
+    pub client_minor_version: u32,
+}
+impl QueryVersionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let client_major_version_bytes = self.client_major_version.serialize_with_order(order);
+        let client_minor_version_bytes = self.client_minor_version.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_VERSION_REQUEST,
+            0,
+            0,
+            client_major_version_bytes[0],
+            client_major_version_bytes[1],
+            client_major_version_bytes[2],
+            client_major_version_bytes[3],
+            client_minor_version_bytes[0],
+            client_minor_version_bytes[1],
+            client_minor_version_bytes[2],
+            client_minor_version_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_VERSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (client_major_version, remaining) = u32::try_parse_with_order(value, order)?;
+        let (client_minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryVersionRequest {
+            client_major_version,
+            client_minor_version,
+        })
+    }
+}
+impl Request for QueryVersionRequest {
+    type Reply = QueryVersionReply;
+}
+impl Trace for QueryVersionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("client-major-version", |tracer| tracer.value(&self.client_major_version))?;
+        tracer.field("client-minor-version", |tracer| tracer.value(&self.client_minor_version))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryVersionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major_version: u32,
+    pub minor_version: u32,
+}
+impl TryParse for QueryVersionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_version, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryVersionReply { sequence, length, major_version, minor_version };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryVersionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryVersionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major-version", |tracer| tracer.value(&self.major_version))?;
+        tracer.field("minor-version", |tracer| tracer.value(&self.minor_version))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryVersionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major_version.serialize_into_with_order(bytes, order);
+        self.minor_version.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SaveSetMode(u8);
+impl SaveSetMode {
+    pub const INSERT: Self = Self(0);
+    pub const DELETE: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "INSERT", "Insert"),
+        (1, "DELETE", "Delete"),
+    ];
+}
+impl From<SaveSetMode> for u8 {
+    #[inline]
+    fn from(input: SaveSetMode) -> Self {
+        input.0
+    }
+}
+impl From<SaveSetMode> for Option<u8> {
+    #[inline]
+    fn from(input: SaveSetMode) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SaveSetMode> for u16 {
+    #[inline]
+    fn from(input: SaveSetMode) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SaveSetMode> for Option<u16> {
+    #[inline]
+    fn from(input: SaveSetMode) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SaveSetMode> for u32 {
+    #[inline]
+    fn from(input: SaveSetMode) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SaveSetMode> for Option<u32> {
+    #[inline]
+    fn from(input: SaveSetMode) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SaveSetMode {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SaveSetMode  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SaveSetMode {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SaveSetMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SaveSetMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SaveSetTarget(u8);
+impl SaveSetTarget {
+    pub const NEAREST: Self = Self(0);
+    pub const ROOT: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NEAREST", "Nearest"),
+        (1, "ROOT", "Root"),
+    ];
+}
+impl From<SaveSetTarget> for u8 {
+    #[inline]
+    fn from(input: SaveSetTarget) -> Self {
+        input.0
+    }
+}
+impl From<SaveSetTarget> for Option<u8> {
+    #[inline]
+    fn from(input: SaveSetTarget) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SaveSetTarget> for u16 {
+    #[inline]
+    fn from(input: SaveSetTarget) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SaveSetTarget> for Option<u16> {
+    #[inline]
+    fn from(input: SaveSetTarget) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SaveSetTarget> for u32 {
+    #[inline]
+    fn from(input: SaveSetTarget) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SaveSetTarget> for Option<u32> {
+    #[inline]
+    fn from(input: SaveSetTarget) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SaveSetTarget {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SaveSetTarget  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SaveSetTarget {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SaveSetTarget {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SaveSetTarget {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SaveSetMapping(u8);
+impl SaveSetMapping {
+    pub const MAP: Self = Self(0);
+    pub const UNMAP: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "MAP", "Map"),
+        (1, "UNMAP", "Unmap"),
+    ];
+}
+impl From<SaveSetMapping> for u8 {
+    #[inline]
+    fn from(input: SaveSetMapping) -> Self {
+        input.0
+    }
+}
+impl From<SaveSetMapping> for Option<u8> {
+    #[inline]
+    fn from(input: SaveSetMapping) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SaveSetMapping> for u16 {
+    #[inline]
+    fn from(input: SaveSetMapping) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SaveSetMapping> for Option<u16> {
+    #[inline]
+    fn from(input: SaveSetMapping) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SaveSetMapping> for u32 {
+    #[inline]
+    fn from(input: SaveSetMapping) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SaveSetMapping> for Option<u32> {
+    #[inline]
+    fn from(input: SaveSetMapping) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SaveSetMapping {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SaveSetMapping  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SaveSetMapping {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SaveSetMapping {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SaveSetMapping {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the ChangeSaveSet request
+pub const CHANGE_SAVE_SET_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeSaveSetRequest {
+    pub mode: SaveSetMode,
+    pub target: SaveSetTarget,
+    pub map: SaveSetMapping,
+    pub window: xproto::Window,
+}
+impl ChangeSaveSetRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mode_bytes = u8::from(self.mode).serialize_with_order(order);
+        let target_bytes = u8::from(self.target).serialize_with_order(order);
+        let map_bytes = u8::from(self.map).serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_SAVE_SET_REQUEST,
+            0,
+            0,
+            mode_bytes[0],
+            target_bytes[0],
+            map_bytes[0],
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_SAVE_SET_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (mode, remaining) = u8::try_parse_with_order(value, order)?;
+        let mode = mode.into();
+        let (target, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let target = target.into();
+        let (map, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let map = map.into();
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(ChangeSaveSetRequest {
+            mode,
+            target,
+            map,
+            window,
+        })
+    }
+}
+impl Request for ChangeSaveSetRequest {
+    type Reply = ();
+}
+impl Trace for ChangeSaveSetRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("mode", |tracer| tracer.value(&self.mode))?;
+        tracer.field("target", |tracer| tracer.value(&self.target))?;
+        tracer.field("map", |tracer| tracer.value(&self.map))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SelectionEvent(u8);
+impl SelectionEvent {
+    pub const SET_SELECTION_OWNER: Self = Self(0);
+    pub const SELECTION_WINDOW_DESTROY: Self = Self(1);
+    pub const SELECTION_CLIENT_CLOSE: Self = Self(2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "SET_SELECTION_OWNER", "SetSelectionOwner"),
+        (1, "SELECTION_WINDOW_DESTROY", "SelectionWindowDestroy"),
+        (2, "SELECTION_CLIENT_CLOSE", "SelectionClientClose"),
+    ];
+}
+impl From<SelectionEvent> for u8 {
+    #[inline]
+    fn from(input: SelectionEvent) -> Self {
+        input.0
+    }
+}
+impl From<SelectionEvent> for Option<u8> {
+    #[inline]
+    fn from(input: SelectionEvent) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SelectionEvent> for u16 {
+    #[inline]
+    fn from(input: SelectionEvent) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SelectionEvent> for Option<u16> {
+    #[inline]
+    fn from(input: SelectionEvent) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SelectionEvent> for u32 {
+    #[inline]
+    fn from(input: SelectionEvent) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SelectionEvent> for Option<u32> {
+    #[inline]
+    fn from(input: SelectionEvent) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SelectionEvent {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SelectionEvent  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SelectionEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SelectionEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SelectionEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SelectionEventMask(u8);
+impl SelectionEventMask {
+    pub const SET_SELECTION_OWNER: Self = Self(1 << 0);
+    pub const SELECTION_WINDOW_DESTROY: Self = Self(1 << 1);
+    pub const SELECTION_CLIENT_CLOSE: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "SET_SELECTION_OWNER", "SetSelectionOwner"),
+        (1 << 1, "SELECTION_WINDOW_DESTROY", "SelectionWindowDestroy"),
+        (1 << 2, "SELECTION_CLIENT_CLOSE", "SelectionClientClose"),
+    ];
+}
+impl From<SelectionEventMask> for u8 {
+    #[inline]
+    fn from(input: SelectionEventMask) -> Self {
+        input.0
+    }
+}
+impl From<SelectionEventMask> for Option<u8> {
+    #[inline]
+    fn from(input: SelectionEventMask) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SelectionEventMask> for u16 {
+    #[inline]
+    fn from(input: SelectionEventMask) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SelectionEventMask> for Option<u16> {
+    #[inline]
+    fn from(input: SelectionEventMask) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SelectionEventMask> for u32 {
+    #[inline]
+    fn from(input: SelectionEventMask) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SelectionEventMask> for Option<u32> {
+    #[inline]
+    fn from(input: SelectionEventMask) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SelectionEventMask {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SelectionEventMask  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SelectionEventMask {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SelectionEventMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SelectionEventMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SelectionEventMask, u8);
+
+/// Opcode for the SelectionNotify event
+pub const SELECTION_NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectionNotifyEvent {
+    pub response_type: u8,
+    pub subtype: SelectionEvent,
+    pub sequence: u16,
+    pub window: xproto::Window,
+    pub owner: xproto::Window,
+    pub selection: xproto::Atom,
+    pub timestamp: xproto::Timestamp,
+    pub selection_timestamp: xproto::Timestamp,
+}
+impl TryParse for SelectionNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subtype, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (owner, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (selection, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (selection_timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let subtype = subtype.into();
+        let result = SelectionNotifyEvent { response_type, subtype, sequence, window, owner, selection, timestamp, selection_timestamp };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectionNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for SelectionNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("subtype", |tracer| tracer.value(&self.subtype))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("owner", |tracer| tracer.xid(&self.owner))?;
+        tracer.field("selection", |tracer| tracer.atom(&self.selection))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("selection-timestamp", |tracer| tracer.value(&self.selection_timestamp))?;
+        Ok(())
+    }
+}
+impl SelectionNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let subtype_bytes = u8::from(self.subtype).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let owner_bytes = self.owner.serialize_with_order(order);
+        let selection_bytes = self.selection.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let selection_timestamp_bytes = self.selection_timestamp.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            subtype_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            owner_bytes[0],
+            owner_bytes[1],
+            owner_bytes[2],
+            owner_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            selection_timestamp_bytes[0],
+            selection_timestamp_bytes[1],
+            selection_timestamp_bytes[2],
+            selection_timestamp_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&SelectionNotifyEvent> for [u8; 32] {
+    fn from(input: &SelectionNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<SelectionNotifyEvent> for [u8; 32] {
+    fn from(input: SelectionNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the SelectSelectionInput request
+pub const SELECT_SELECTION_INPUT_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectSelectionInputRequest {
+    pub window: xproto::Window,
+    pub selection: xproto::Atom,
+    pub event_mask: u32,
+}
+impl SelectSelectionInputRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let selection_bytes = self.selection.serialize_with_order(order);
+        let event_mask_bytes = self.event_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_SELECTION_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            selection_bytes[0],
+            selection_bytes[1],
+            selection_bytes[2],
+            selection_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_SELECTION_INPUT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (selection, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SelectSelectionInputRequest {
+            window,
+            selection,
+            event_mask,
+        })
+    }
+}
+impl Request for SelectSelectionInputRequest {
+    type Reply = ();
+}
+impl Trace for SelectSelectionInputRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("selection", |tracer| tracer.atom(&self.selection))?;
+        tracer.field("event-mask", |tracer| tracer.bitmask(&self.event_mask, SelectionEventMask::VARIANTS))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CursorNotify(u8);
+impl CursorNotify {
+    pub const DISPLAY_CURSOR: Self = Self(0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "DISPLAY_CURSOR", "DisplayCursor"),
+    ];
+}
+impl From<CursorNotify> for u8 {
+    #[inline]
+    fn from(input: CursorNotify) -> Self {
+        input.0
+    }
+}
+impl From<CursorNotify> for Option<u8> {
+    #[inline]
+    fn from(input: CursorNotify) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CursorNotify> for u16 {
+    #[inline]
+    fn from(input: CursorNotify) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CursorNotify> for Option<u16> {
+    #[inline]
+    fn from(input: CursorNotify) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CursorNotify> for u32 {
+    #[inline]
+    fn from(input: CursorNotify) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CursorNotify> for Option<u32> {
+    #[inline]
+    fn from(input: CursorNotify) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CursorNotify {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CursorNotify  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CursorNotify {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CursorNotify {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CursorNotify {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CursorNotifyMask(u8);
+impl CursorNotifyMask {
+    pub const DISPLAY_CURSOR: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "DISPLAY_CURSOR", "DisplayCursor"),
+    ];
+}
+impl From<CursorNotifyMask> for u8 {
+    #[inline]
+    fn from(input: CursorNotifyMask) -> Self {
+        input.0
+    }
+}
+impl From<CursorNotifyMask> for Option<u8> {
+    #[inline]
+    fn from(input: CursorNotifyMask) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CursorNotifyMask> for u16 {
+    #[inline]
+    fn from(input: CursorNotifyMask) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CursorNotifyMask> for Option<u16> {
+    #[inline]
+    fn from(input: CursorNotifyMask) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CursorNotifyMask> for u32 {
+    #[inline]
+    fn from(input: CursorNotifyMask) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CursorNotifyMask> for Option<u32> {
+    #[inline]
+    fn from(input: CursorNotifyMask) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CursorNotifyMask {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CursorNotifyMask  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CursorNotifyMask {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CursorNotifyMask {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CursorNotifyMask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(CursorNotifyMask, u8);
+
+/// Opcode for the CursorNotify event
+pub const CURSOR_NOTIFY_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorNotifyEvent {
+    pub response_type: u8,
+    pub subtype: CursorNotify,
+    pub sequence: u16,
+    pub window: xproto::Window,
+    pub cursor_serial: u32,
+    pub timestamp: xproto::Timestamp,
+    pub name: xproto::Atom,
+}
+impl TryParse for CursorNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (subtype, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (cursor_serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (timestamp, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let subtype = subtype.into();
+        let result = CursorNotifyEvent { response_type, subtype, sequence, window, cursor_serial, timestamp, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CursorNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CursorNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("subtype", |tracer| tracer.value(&self.subtype))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("cursor-serial", |tracer| tracer.value(&self.cursor_serial))?;
+        tracer.field("timestamp", |tracer| tracer.value(&self.timestamp))?;
+        tracer.field("name", |tracer| tracer.atom(&self.name))?;
+        Ok(())
+    }
+}
+impl CursorNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let subtype_bytes = u8::from(self.subtype).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let cursor_serial_bytes = self.cursor_serial.serialize_with_order(order);
+        let timestamp_bytes = self.timestamp.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            subtype_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            cursor_serial_bytes[0],
+            cursor_serial_bytes[1],
+            cursor_serial_bytes[2],
+            cursor_serial_bytes[3],
+            timestamp_bytes[0],
+            timestamp_bytes[1],
+            timestamp_bytes[2],
+            timestamp_bytes[3],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&CursorNotifyEvent> for [u8; 32] {
+    fn from(input: &CursorNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<CursorNotifyEvent> for [u8; 32] {
+    fn from(input: CursorNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the SelectCursorInput request
+pub const SELECT_CURSOR_INPUT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectCursorInputRequest {
+    pub window: xproto::Window,
+    pub event_mask: u32,
+}
+impl SelectCursorInputRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let event_mask_bytes = self.event_mask.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_CURSOR_INPUT_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            event_mask_bytes[0],
+            event_mask_bytes[1],
+            event_mask_bytes[2],
+            event_mask_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_CURSOR_INPUT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (event_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SelectCursorInputRequest {
+            window,
+            event_mask,
+        })
+    }
+}
+impl Request for SelectCursorInputRequest {
+    type Reply = ();
+}
+impl Trace for SelectCursorInputRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("event-mask", |tracer| tracer.bitmask(&self.event_mask, CursorNotifyMask::VARIANTS))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetCursorImage request
+pub const GET_CURSOR_IMAGE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCursorImageRequest;
+impl GetCursorImageRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            GET_CURSOR_IMAGE_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CURSOR_IMAGE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(GetCursorImageRequest
+        )
+    }
+}
+impl Request for GetCursorImageRequest {
+    type Reply = GetCursorImageReply;
+}
+impl Trace for GetCursorImageRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCursorImageReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_image: Vec<u32>,
+}
+impl TryParse for GetCursorImageReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (xhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (yhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (cursor_serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        let (cursor_image, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCursorImageReply { sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_image };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCursorImageReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetCursorImageReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("xhot", |tracer| tracer.value(&self.xhot))?;
+        tracer.field("yhot", |tracer| tracer.value(&self.yhot))?;
+        tracer.field("cursor-serial", |tracer| tracer.value(&self.cursor_serial))?;
+        tracer.field("cursor-image", |tracer| tracer.list(&self.cursor_image, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCursorImageReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.xhot.serialize_into_with_order(bytes, order);
+        self.yhot.serialize_into_with_order(bytes, order);
+        self.cursor_serial.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        assert_eq!(self.cursor_image.len(), usize::try_from(u32::from(self.width).checked_mul(u32::from(self.height)).unwrap()).unwrap(), "`cursor_image` has an incorrect length");
+        self.cursor_image.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+pub type Region = u32;
+
+/// Opcode for the BadRegion error
+pub const BAD_REGION_ERROR: u8 = 0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RegionEnum(u8);
+impl RegionEnum {
+    pub const NONE: Self = Self(0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NONE", "None"),
+    ];
+}
+impl From<RegionEnum> for u8 {
+    #[inline]
+    fn from(input: RegionEnum) -> Self {
+        input.0
+    }
+}
+impl From<RegionEnum> for Option<u8> {
+    #[inline]
+    fn from(input: RegionEnum) -> Self {
+        Some(input.0)
+    }
+}
+impl From<RegionEnum> for u16 {
+    #[inline]
+    fn from(input: RegionEnum) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<RegionEnum> for Option<u16> {
+    #[inline]
+    fn from(input: RegionEnum) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<RegionEnum> for u32 {
+    #[inline]
+    fn from(input: RegionEnum) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<RegionEnum> for Option<u32> {
+    #[inline]
+    fn from(input: RegionEnum) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for RegionEnum {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for RegionEnum  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for RegionEnum {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for RegionEnum {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RegionEnum {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the CreateRegion request
+pub const CREATE_REGION_REQUEST: u8 = 5;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateRegionRequest<'input> {
+    pub region: Region,
+    pub rectangles: Cow<'input, [xproto::Rectangle]>,
+}
+impl<'input> CreateRegionRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let rectangles_bytes = self.rectangles.serialize_with_order(order);
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        Ok(CreateRegionRequest {
+            region,
+            rectangles: Cow::Owned(rectangles),
+        })
+    }
+    /// Clone all borrowed data in this CreateRegionRequest.
+    pub fn into_owned(self) -> CreateRegionRequest<'static> {
+        CreateRegionRequest {
+            region: self.region,
+            rectangles: Cow::Owned(self.rectangles.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreateRegionRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreateRegionRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("rectangles", |tracer| tracer.list(&self.rectangles[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateRegionFromBitmap request
+pub const CREATE_REGION_FROM_BITMAP_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateRegionFromBitmapRequest {
+    pub region: Region,
+    pub bitmap: xproto::Pixmap,
+}
+impl CreateRegionFromBitmapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let bitmap_bytes = self.bitmap.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REGION_FROM_BITMAP_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            bitmap_bytes[0],
+            bitmap_bytes[1],
+            bitmap_bytes[2],
+            bitmap_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_BITMAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let (bitmap, remaining) = xproto::Pixmap::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateRegionFromBitmapRequest {
+            region,
+            bitmap,
+        })
+    }
+}
+impl Request for CreateRegionFromBitmapRequest {
+    type Reply = ();
+}
+impl Trace for CreateRegionFromBitmapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("bitmap", |tracer| tracer.xid(&self.bitmap))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateRegionFromWindow request
+pub const CREATE_REGION_FROM_WINDOW_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateRegionFromWindowRequest {
+    pub region: Region,
+    pub window: xproto::Window,
+    pub kind: shape::SK,
+}
+impl CreateRegionFromWindowRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let kind_bytes = shape::Kind::from(self.kind).serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REGION_FROM_WINDOW_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            kind_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_WINDOW_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (kind, remaining) = shape::Kind::try_parse_with_order(remaining, order)?;
+        let kind = kind.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(CreateRegionFromWindowRequest {
+            region,
+            window,
+            kind,
+        })
+    }
+}
+impl Request for CreateRegionFromWindowRequest {
+    type Reply = ();
+}
+impl Trace for CreateRegionFromWindowRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("kind", |tracer| tracer.value(&self.kind))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateRegionFromGC request
+pub const CREATE_REGION_FROM_GC_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateRegionFromGCRequest {
+    pub region: Region,
+    pub gc: xproto::Gcontext,
+}
+impl CreateRegionFromGCRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REGION_FROM_GC_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_GC_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateRegionFromGCRequest {
+            region,
+            gc,
+        })
+    }
+}
+impl Request for CreateRegionFromGCRequest {
+    type Reply = ();
+}
+impl Trace for CreateRegionFromGCRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CreateRegionFromPicture request
+pub const CREATE_REGION_FROM_PICTURE_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreateRegionFromPictureRequest {
+    pub region: Region,
+    pub picture: render::Picture,
+}
+impl CreateRegionFromPictureRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let picture_bytes = self.picture.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_REGION_FROM_PICTURE_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_REGION_FROM_PICTURE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let (picture, remaining) = render::Picture::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CreateRegionFromPictureRequest {
+            region,
+            picture,
+        })
+    }
+}
+impl Request for CreateRegionFromPictureRequest {
+    type Reply = ();
+}
+impl Trace for CreateRegionFromPictureRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("picture", |tracer| tracer.xid(&self.picture))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DestroyRegion request
+pub const DESTROY_REGION_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DestroyRegionRequest {
+    pub region: Region,
+}
+impl DestroyRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DESTROY_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DESTROY_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DestroyRegionRequest {
+            region,
+        })
+    }
+}
+impl Request for DestroyRegionRequest {
+    type Reply = ();
+}
+impl Trace for DestroyRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetRegion request
+pub const SET_REGION_REQUEST: u8 = 11;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetRegionRequest<'input> {
+    pub region: Region,
+    pub rectangles: Cow<'input, [xproto::Rectangle]>,
+}
+impl<'input> SetRegionRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let rectangles_bytes = self.rectangles.serialize_with_order(order);
+        let length_so_far = length_so_far + rectangles_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), rectangles_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let mut remaining = remaining;
+        // Length is 'everything left in the input'
+        let mut rectangles = Vec::new();
+        while !remaining.is_empty() {
+            let (v, new_remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+            remaining = new_remaining;
+            rectangles.push(v);
+        }
+        let _ = remaining;
+        Ok(SetRegionRequest {
+            region,
+            rectangles: Cow::Owned(rectangles),
+        })
+    }
+    /// Clone all borrowed data in this SetRegionRequest.
+    pub fn into_owned(self) -> SetRegionRequest<'static> {
+        SetRegionRequest {
+            region: self.region,
+            rectangles: Cow::Owned(self.rectangles.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetRegionRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetRegionRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("rectangles", |tracer| tracer.list(&self.rectangles[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the CopyRegion request
+pub const COPY_REGION_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyRegionRequest {
+    pub source: Region,
+    pub destination: Region,
+}
+impl CopyRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source_bytes = self.source.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            COPY_REGION_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != COPY_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source, remaining) = Region::try_parse_with_order(value, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(CopyRegionRequest {
+            source,
+            destination,
+        })
+    }
+}
+impl Request for CopyRegionRequest {
+    type Reply = ();
+}
+impl Trace for CopyRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source", |tracer| tracer.xid(&self.source))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the UnionRegion request
+pub const UNION_REGION_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnionRegionRequest {
+    pub source1: Region,
+    pub source2: Region,
+    pub destination: Region,
+}
+impl UnionRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source1_bytes = self.source1.serialize_with_order(order);
+        let source2_bytes = self.source2.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            UNION_REGION_REQUEST,
+            0,
+            0,
+            source1_bytes[0],
+            source1_bytes[1],
+            source1_bytes[2],
+            source1_bytes[3],
+            source2_bytes[0],
+            source2_bytes[1],
+            source2_bytes[2],
+            source2_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNION_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source1, remaining) = Region::try_parse_with_order(value, order)?;
+        let (source2, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(UnionRegionRequest {
+            source1,
+            source2,
+            destination,
+        })
+    }
+}
+impl Request for UnionRegionRequest {
+    type Reply = ();
+}
+impl Trace for UnionRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source1", |tracer| tracer.xid(&self.source1))?;
+        tracer.field("source2", |tracer| tracer.xid(&self.source2))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the IntersectRegion request
+pub const INTERSECT_REGION_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntersectRegionRequest {
+    pub source1: Region,
+    pub source2: Region,
+    pub destination: Region,
+}
+impl IntersectRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source1_bytes = self.source1.serialize_with_order(order);
+        let source2_bytes = self.source2.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            INTERSECT_REGION_REQUEST,
+            0,
+            0,
+            source1_bytes[0],
+            source1_bytes[1],
+            source1_bytes[2],
+            source1_bytes[3],
+            source2_bytes[0],
+            source2_bytes[1],
+            source2_bytes[2],
+            source2_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INTERSECT_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source1, remaining) = Region::try_parse_with_order(value, order)?;
+        let (source2, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(IntersectRegionRequest {
+            source1,
+            source2,
+            destination,
+        })
+    }
+}
+impl Request for IntersectRegionRequest {
+    type Reply = ();
+}
+impl Trace for IntersectRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source1", |tracer| tracer.xid(&self.source1))?;
+        tracer.field("source2", |tracer| tracer.xid(&self.source2))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SubtractRegion request
+pub const SUBTRACT_REGION_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubtractRegionRequest {
+    pub source1: Region,
+    pub source2: Region,
+    pub destination: Region,
+}
+impl SubtractRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source1_bytes = self.source1.serialize_with_order(order);
+        let source2_bytes = self.source2.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SUBTRACT_REGION_REQUEST,
+            0,
+            0,
+            source1_bytes[0],
+            source1_bytes[1],
+            source1_bytes[2],
+            source1_bytes[3],
+            source2_bytes[0],
+            source2_bytes[1],
+            source2_bytes[2],
+            source2_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SUBTRACT_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source1, remaining) = Region::try_parse_with_order(value, order)?;
+        let (source2, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SubtractRegionRequest {
+            source1,
+            source2,
+            destination,
+        })
+    }
+}
+impl Request for SubtractRegionRequest {
+    type Reply = ();
+}
+impl Trace for SubtractRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source1", |tracer| tracer.xid(&self.source1))?;
+        tracer.field("source2", |tracer| tracer.xid(&self.source2))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the InvertRegion request
+pub const INVERT_REGION_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvertRegionRequest {
+    pub source: Region,
+    pub bounds: xproto::Rectangle,
+    pub destination: Region,
+}
+impl InvertRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source_bytes = self.source.serialize_with_order(order);
+        let bounds_bytes = self.bounds.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            INVERT_REGION_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            bounds_bytes[0],
+            bounds_bytes[1],
+            bounds_bytes[2],
+            bounds_bytes[3],
+            bounds_bytes[4],
+            bounds_bytes[5],
+            bounds_bytes[6],
+            bounds_bytes[7],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != INVERT_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source, remaining) = Region::try_parse_with_order(value, order)?;
+        let (bounds, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(InvertRegionRequest {
+            source,
+            bounds,
+            destination,
+        })
+    }
+}
+impl Request for InvertRegionRequest {
+    type Reply = ();
+}
+impl Trace for InvertRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source", |tracer| tracer.xid(&self.source))?;
+        tracer.field("bounds", |tracer| tracer.value(&self.bounds))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the TranslateRegion request
+pub const TRANSLATE_REGION_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TranslateRegionRequest {
+    pub region: Region,
+    pub dx: i16,
+    pub dy: i16,
+}
+impl TranslateRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let dx_bytes = self.dx.serialize_with_order(order);
+        let dy_bytes = self.dy.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            TRANSLATE_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            dx_bytes[0],
+            dx_bytes[1],
+            dy_bytes[0],
+            dy_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != TRANSLATE_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let (dx, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (dy, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(TranslateRegionRequest {
+            region,
+            dx,
+            dy,
+        })
+    }
+}
+impl Request for TranslateRegionRequest {
+    type Reply = ();
+}
+impl Trace for TranslateRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("dx", |tracer| tracer.value(&self.dx))?;
+        tracer.field("dy", |tracer| tracer.value(&self.dy))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the RegionExtents request
+pub const REGION_EXTENTS_REQUEST: u8 = 18;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionExtentsRequest {
+    pub source: Region,
+    pub destination: Region,
+}
+impl RegionExtentsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source_bytes = self.source.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            REGION_EXTENTS_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != REGION_EXTENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source, remaining) = Region::try_parse_with_order(value, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(RegionExtentsRequest {
+            source,
+            destination,
+        })
+    }
+}
+impl Request for RegionExtentsRequest {
+    type Reply = ();
+}
+impl Trace for RegionExtentsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source", |tracer| tracer.xid(&self.source))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the FetchRegion request
+pub const FETCH_REGION_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FetchRegionRequest {
+    pub region: Region,
+}
+impl FetchRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let region_bytes = self.region.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            FETCH_REGION_REQUEST,
+            0,
+            0,
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != FETCH_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (region, remaining) = Region::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(FetchRegionRequest {
+            region,
+        })
+    }
+}
+impl Request for FetchRegionRequest {
+    type Reply = FetchRegionReply;
+}
+impl Trace for FetchRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FetchRegionReply {
+    pub sequence: u16,
+    pub extents: xproto::Rectangle,
+    pub rectangles: Vec<xproto::Rectangle>,
+}
+impl TryParse for FetchRegionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (extents, remaining) = xproto::Rectangle::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (rectangles, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Rectangle>(remaining, length.checked_div(2u32).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = FetchRegionReply { sequence, extents, rectangles };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for FetchRegionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl FetchRegionReply {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `rectangles` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u32 {
+        self.rectangles.len()
+            .checked_mul(2).unwrap()
+            .try_into().unwrap()
+    }
+}
+impl Trace for FetchRegionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("extents", |tracer| tracer.value(&self.extents))?;
+        tracer.field("rectangles", |tracer| tracer.list(&self.rectangles, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for FetchRegionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        let length = u32::try_from(self.rectangles.len()).ok().and_then(|len| len.checked_mul(2)).expect("`rectangles` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        self.extents.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.rectangles.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetGCClipRegion request
+pub const SET_GC_CLIP_REGION_REQUEST: u8 = 20;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetGCClipRegionRequest {
+    pub gc: xproto::Gcontext,
+    pub region: Region,
+    pub x_origin: i16,
+    pub y_origin: i16,
+}
+impl SetGCClipRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let region_bytes = self.region.serialize_with_order(order);
+        let x_origin_bytes = self.x_origin.serialize_with_order(order);
+        let y_origin_bytes = self.y_origin.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_GC_CLIP_REGION_REQUEST,
+            0,
+            0,
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            x_origin_bytes[0],
+            x_origin_bytes[1],
+            y_origin_bytes[0],
+            y_origin_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_GC_CLIP_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(value, order)?;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (x_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetGCClipRegionRequest {
+            gc,
+            region,
+            x_origin,
+            y_origin,
+        })
+    }
+}
+impl Request for SetGCClipRegionRequest {
+    type Reply = ();
+}
+impl Trace for SetGCClipRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("x-origin", |tracer| tracer.value(&self.x_origin))?;
+        tracer.field("y-origin", |tracer| tracer.value(&self.y_origin))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetWindowShapeRegion request
+pub const SET_WINDOW_SHAPE_REGION_REQUEST: u8 = 21;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetWindowShapeRegionRequest {
+    pub dest: xproto::Window,
+    pub dest_kind: shape::SK,
+    pub x_offset: i16,
+    pub y_offset: i16,
+    pub region: Region,
+}
+impl SetWindowShapeRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let dest_bytes = self.dest.serialize_with_order(order);
+        let dest_kind_bytes = shape::Kind::from(self.dest_kind).serialize_with_order(order);
+        let x_offset_bytes = self.x_offset.serialize_with_order(order);
+        let y_offset_bytes = self.y_offset.serialize_with_order(order);
+        let region_bytes = self.region.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_WINDOW_SHAPE_REGION_REQUEST,
+            0,
+            0,
+            dest_bytes[0],
+            dest_bytes[1],
+            dest_bytes[2],
+            dest_bytes[3],
+            dest_kind_bytes[0],
+            0,
+            0,
+            0,
+            x_offset_bytes[0],
+            x_offset_bytes[1],
+            y_offset_bytes[0],
+            y_offset_bytes[1],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_WINDOW_SHAPE_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (dest, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let (dest_kind, remaining) = shape::Kind::try_parse_with_order(remaining, order)?;
+        let dest_kind = dest_kind.into();
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (x_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_offset, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetWindowShapeRegionRequest {
+            dest,
+            dest_kind,
+            x_offset,
+            y_offset,
+            region,
+        })
+    }
+}
+impl Request for SetWindowShapeRegionRequest {
+    type Reply = ();
+}
+impl Trace for SetWindowShapeRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("dest", |tracer| tracer.xid(&self.dest))?;
+        tracer.field("dest-kind", |tracer| tracer.value(&self.dest_kind))?;
+        tracer.field("x-offset", |tracer| tracer.value(&self.x_offset))?;
+        tracer.field("y-offset", |tracer| tracer.value(&self.y_offset))?;
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetPictureClipRegion request
+pub const SET_PICTURE_CLIP_REGION_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetPictureClipRegionRequest {
+    pub picture: render::Picture,
+    pub region: Region,
+    pub x_origin: i16,
+    pub y_origin: i16,
+}
+impl SetPictureClipRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let picture_bytes = self.picture.serialize_with_order(order);
+        let region_bytes = self.region.serialize_with_order(order);
+        let x_origin_bytes = self.x_origin.serialize_with_order(order);
+        let y_origin_bytes = self.y_origin.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_PICTURE_CLIP_REGION_REQUEST,
+            0,
+            0,
+            picture_bytes[0],
+            picture_bytes[1],
+            picture_bytes[2],
+            picture_bytes[3],
+            region_bytes[0],
+            region_bytes[1],
+            region_bytes[2],
+            region_bytes[3],
+            x_origin_bytes[0],
+            x_origin_bytes[1],
+            y_origin_bytes[0],
+            y_origin_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PICTURE_CLIP_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (picture, remaining) = render::Picture::try_parse_with_order(value, order)?;
+        let (region, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (x_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y_origin, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetPictureClipRegionRequest {
+            picture,
+            region,
+            x_origin,
+            y_origin,
+        })
+    }
+}
+impl Request for SetPictureClipRegionRequest {
+    type Reply = ();
+}
+impl Trace for SetPictureClipRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("picture", |tracer| tracer.xid(&self.picture))?;
+        tracer.field("region", |tracer| tracer.xid(&self.region))?;
+        tracer.field("x-origin", |tracer| tracer.value(&self.x_origin))?;
+        tracer.field("y-origin", |tracer| tracer.value(&self.y_origin))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetCursorName request
+pub const SET_CURSOR_NAME_REQUEST: u8 = 23;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCursorNameRequest<'input> {
+    pub cursor: xproto::Cursor,
+    pub name: Cow<'input, [u8]>,
+}
+impl<'input> SetCursorNameRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let cursor_bytes = self.cursor.serialize_with_order(order);
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let nbytes_bytes = nbytes.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CURSOR_NAME_REQUEST,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+            nbytes_bytes[0],
+            nbytes_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.name.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.name, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CURSOR_NAME_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(value, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(SetCursorNameRequest {
+            cursor,
+            name: Cow::Borrowed(name),
+        })
+    }
+    /// Clone all borrowed data in this SetCursorNameRequest.
+    pub fn into_owned(self) -> SetCursorNameRequest<'static> {
+        SetCursorNameRequest {
+            cursor: self.cursor,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetCursorNameRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetCursorNameRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("cursor", |tracer| tracer.xid(&self.cursor))?;
+        tracer.field("name", |tracer| tracer.string(&self.name[..]))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetCursorName request
+pub const GET_CURSOR_NAME_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCursorNameRequest {
+    pub cursor: xproto::Cursor,
+}
+impl GetCursorNameRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let cursor_bytes = self.cursor.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CURSOR_NAME_REQUEST,
+            0,
+            0,
+            cursor_bytes[0],
+            cursor_bytes[1],
+            cursor_bytes[2],
+            cursor_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CURSOR_NAME_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (cursor, remaining) = xproto::Cursor::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(GetCursorNameRequest {
+            cursor,
+        })
+    }
+}
+impl Request for GetCursorNameRequest {
+    type Reply = GetCursorNameReply;
+}
+impl Trace for GetCursorNameRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("cursor", |tracer| tracer.xid(&self.cursor))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCursorNameReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub atom: xproto::Atom,
+    pub name: Vec<u8>,
+}
+impl TryParse for GetCursorNameReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(18..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCursorNameReply { sequence, length, atom, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCursorNameReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCursorNameReply {
+    /// Get the value of the `nbytes` field.
+    ///
+    /// The `nbytes` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn nbytes(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCursorNameReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("atom", |tracer| tracer.atom(&self.atom))?;
+        tracer.field("name", |tracer| tracer.string(&self.name))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCursorNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.atom.serialize_into_with_order(bytes, order);
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        nbytes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 18]);
+        bytes.extend_from_slice(&self.name);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetCursorImageAndName request
+pub const GET_CURSOR_IMAGE_AND_NAME_REQUEST: u8 = 25;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCursorImageAndNameRequest;
+impl GetCursorImageAndNameRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            GET_CURSOR_IMAGE_AND_NAME_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CURSOR_IMAGE_AND_NAME_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(GetCursorImageAndNameRequest
+        )
+    }
+}
+impl Request for GetCursorImageAndNameRequest {
+    type Reply = GetCursorImageAndNameReply;
+}
+impl Trace for GetCursorImageAndNameRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCursorImageAndNameReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub xhot: u16,
+    pub yhot: u16,
+    pub cursor_serial: u32,
+    pub cursor_atom: xproto::Atom,
+    pub cursor_image: Vec<u32>,
+    pub name: Vec<u8>,
+}
+impl TryParse for GetCursorImageAndNameReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (xhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (yhot, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (cursor_serial, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (cursor_atom, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (cursor_image, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, u32::from(width).checked_mul(u32::from(height)).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCursorImageAndNameReply { sequence, length, x, y, width, height, xhot, yhot, cursor_serial, cursor_atom, cursor_image, name };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCursorImageAndNameReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCursorImageAndNameReply {
+    /// Get the value of the `nbytes` field.
+    ///
+    /// The `nbytes` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn nbytes(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCursorImageAndNameReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("x", |tracer| tracer.value(&self.x))?;
+        tracer.field("y", |tracer| tracer.value(&self.y))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("xhot", |tracer| tracer.value(&self.xhot))?;
+        tracer.field("yhot", |tracer| tracer.value(&self.yhot))?;
+        tracer.field("cursor-serial", |tracer| tracer.value(&self.cursor_serial))?;
+        tracer.field("cursor-atom", |tracer| tracer.atom(&self.cursor_atom))?;
+        tracer.field("cursor-image", |tracer| tracer.list(&self.cursor_image, |tracer, value| tracer.value(value)))?;
+        tracer.field("name", |tracer| tracer.string(&self.name))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCursorImageAndNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.x.serialize_into_with_order(bytes, order);
+        self.y.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        self.xhot.serialize_into_with_order(bytes, order);
+        self.yhot.serialize_into_with_order(bytes, order);
+        self.cursor_serial.serialize_into_with_order(bytes, order);
+        self.cursor_atom.serialize_into_with_order(bytes, order);
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        nbytes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        assert_eq!(self.cursor_image.len(), usize::try_from(u32::from(self.width).checked_mul(u32::from(self.height)).unwrap()).unwrap(), "`cursor_image` has an incorrect length");
+        self.cursor_image.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ChangeCursor request
+pub const CHANGE_CURSOR_REQUEST: u8 = 26;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeCursorRequest {
+    pub source: xproto::Cursor,
+    pub destination: xproto::Cursor,
+}
+impl ChangeCursorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source_bytes = self.source.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_CURSOR_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_CURSOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source, remaining) = xproto::Cursor::try_parse_with_order(value, order)?;
+        let (destination, remaining) = xproto::Cursor::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(ChangeCursorRequest {
+            source,
+            destination,
+        })
+    }
+}
+impl Request for ChangeCursorRequest {
+    type Reply = ();
+}
+impl Trace for ChangeCursorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source", |tracer| tracer.xid(&self.source))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ChangeCursorByName request
+pub const CHANGE_CURSOR_BY_NAME_REQUEST: u8 = 27;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeCursorByNameRequest<'input> {
+    pub src: xproto::Cursor,
+    pub name: Cow<'input, [u8]>,
+}
+impl<'input> ChangeCursorByNameRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let src_bytes = self.src.serialize_with_order(order);
+        let nbytes = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        let nbytes_bytes = nbytes.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CHANGE_CURSOR_BY_NAME_REQUEST,
+            0,
+            0,
+            src_bytes[0],
+            src_bytes[1],
+            src_bytes[2],
+            src_bytes[3],
+            nbytes_bytes[0],
+            nbytes_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.name.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.name, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CHANGE_CURSOR_BY_NAME_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (src, remaining) = xproto::Cursor::try_parse_with_order(value, order)?;
+        let (nbytes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, nbytes.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(ChangeCursorByNameRequest {
+            src,
+            name: Cow::Borrowed(name),
+        })
+    }
+    /// Clone all borrowed data in this ChangeCursorByNameRequest.
+    pub fn into_owned(self) -> ChangeCursorByNameRequest<'static> {
+        ChangeCursorByNameRequest {
+            src: self.src,
+            name: Cow::Owned(self.name.into_owned()),
+        }
+    }
+}
+impl<'input> Request for ChangeCursorByNameRequest<'input> {
+    type Reply = ();
+}
+impl Trace for ChangeCursorByNameRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("src", |tracer| tracer.xid(&self.src))?;
+        tracer.field("name", |tracer| tracer.string(&self.name[..]))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ExpandRegion request
+pub const EXPAND_REGION_REQUEST: u8 = 28;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpandRegionRequest {
+    pub source: Region,
+    pub destination: Region,
+    pub left: u16,
+    pub right: u16,
+    pub top: u16,
+    pub bottom: u16,
+}
+impl ExpandRegionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let source_bytes = self.source.serialize_with_order(order);
+        let destination_bytes = self.destination.serialize_with_order(order);
+        let left_bytes = self.left.serialize_with_order(order);
+        let right_bytes = self.right.serialize_with_order(order);
+        let top_bytes = self.top.serialize_with_order(order);
+        let bottom_bytes = self.bottom.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            EXPAND_REGION_REQUEST,
+            0,
+            0,
+            source_bytes[0],
+            source_bytes[1],
+            source_bytes[2],
+            source_bytes[3],
+            destination_bytes[0],
+            destination_bytes[1],
+            destination_bytes[2],
+            destination_bytes[3],
+            left_bytes[0],
+            left_bytes[1],
+            right_bytes[0],
+            right_bytes[1],
+            top_bytes[0],
+            top_bytes[1],
+            bottom_bytes[0],
+            bottom_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != EXPAND_REGION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (source, remaining) = Region::try_parse_with_order(value, order)?;
+        let (destination, remaining) = Region::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (right, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (top, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bottom, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(ExpandRegionRequest {
+            source,
+            destination,
+            left,
+            right,
+            top,
+            bottom,
+        })
+    }
+}
+impl Request for ExpandRegionRequest {
+    type Reply = ();
+}
+impl Trace for ExpandRegionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("source", |tracer| tracer.xid(&self.source))?;
+        tracer.field("destination", |tracer| tracer.xid(&self.destination))?;
+        tracer.field("left", |tracer| tracer.value(&self.left))?;
+        tracer.field("right", |tracer| tracer.value(&self.right))?;
+        tracer.field("top", |tracer| tracer.value(&self.top))?;
+        tracer.field("bottom", |tracer| tracer.value(&self.bottom))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the HideCursor request
+pub const HIDE_CURSOR_REQUEST: u8 = 29;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HideCursorRequest {
+    pub window: xproto::Window,
+}
+impl HideCursorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            HIDE_CURSOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != HIDE_CURSOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(HideCursorRequest {
+            window,
+        })
+    }
+}
+impl Request for HideCursorRequest {
+    type Reply = ();
+}
+impl Trace for HideCursorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ShowCursor request
+pub const SHOW_CURSOR_REQUEST: u8 = 30;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowCursorRequest {
+    pub window: xproto::Window,
+}
+impl ShowCursorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SHOW_CURSOR_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SHOW_CURSOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(ShowCursorRequest {
+            window,
+        })
+    }
+}
+impl Request for ShowCursorRequest {
+    type Reply = ();
+}
+impl Trace for ShowCursorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+pub type Barrier = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BarrierDirections(u8);
+impl BarrierDirections {
+    pub const POSITIVE_X: Self = Self(1 << 0);
+    pub const POSITIVE_Y: Self = Self(1 << 1);
+    pub const NEGATIVE_X: Self = Self(1 << 2);
+    pub const NEGATIVE_Y: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "POSITIVE_X", "PositiveX"),
+        (1 << 1, "POSITIVE_Y", "PositiveY"),
+        (1 << 2, "NEGATIVE_X", "NegativeX"),
+        (1 << 3, "NEGATIVE_Y", "NegativeY"),
+    ];
+}
+impl From<BarrierDirections> for u8 {
+    #[inline]
+    fn from(input: BarrierDirections) -> Self {
+        input.0
+    }
+}
+impl From<BarrierDirections> for Option<u8> {
+    #[inline]
+    fn from(input: BarrierDirections) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BarrierDirections> for u16 {
+    #[inline]
+    fn from(input: BarrierDirections) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<BarrierDirections> for Option<u16> {
+    #[inline]
+    fn from(input: BarrierDirections) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<BarrierDirections> for u32 {
+    #[inline]
+    fn from(input: BarrierDirections) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BarrierDirections> for Option<u32> {
+    #[inline]
+    fn from(input: BarrierDirections) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BarrierDirections {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BarrierDirections  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BarrierDirections {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BarrierDirections {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BarrierDirections {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(BarrierDirections, u8);
+
+/// Opcode for the CreatePointerBarrier request
+pub const CREATE_POINTER_BARRIER_REQUEST: u8 = 31;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatePointerBarrierRequest<'input> {
+    pub barrier: Barrier,
+    pub window: xproto::Window,
+    pub x1: u16,
+    pub y1: u16,
+    pub x2: u16,
+    pub y2: u16,
+    pub directions: u32,
+    pub devices: Cow<'input, [u16]>,
+}
+impl<'input> CreatePointerBarrierRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let barrier_bytes = self.barrier.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let x1_bytes = self.x1.serialize_with_order(order);
+        let y1_bytes = self.y1.serialize_with_order(order);
+        let x2_bytes = self.x2.serialize_with_order(order);
+        let y2_bytes = self.y2.serialize_with_order(order);
+        let directions_bytes = self.directions.serialize_with_order(order);
+        let num_devices = u16::try_from(self.devices.len()).expect("`devices` has too many elements");
+        let num_devices_bytes = num_devices.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            CREATE_POINTER_BARRIER_REQUEST,
+            0,
+            0,
+            barrier_bytes[0],
+            barrier_bytes[1],
+            barrier_bytes[2],
+            barrier_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            x1_bytes[0],
+            x1_bytes[1],
+            y1_bytes[0],
+            y1_bytes[1],
+            x2_bytes[0],
+            x2_bytes[1],
+            y2_bytes[0],
+            y2_bytes[1],
+            directions_bytes[0],
+            directions_bytes[1],
+            directions_bytes[2],
+            directions_bytes[3],
+            0,
+            0,
+            num_devices_bytes[0],
+            num_devices_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let devices_bytes = self.devices.serialize_with_order(order);
+        let length_so_far = length_so_far + devices_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), devices_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != CREATE_POINTER_BARRIER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (barrier, remaining) = Barrier::try_parse_with_order(value, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (x1, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y1, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (x2, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (y2, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (directions, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (num_devices, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (devices, remaining) = crate::x11_utils::parse_list_with_order::<u16>(remaining, num_devices.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(CreatePointerBarrierRequest {
+            barrier,
+            window,
+            x1,
+            y1,
+            x2,
+            y2,
+            directions,
+            devices: Cow::Owned(devices),
+        })
+    }
+    /// Clone all borrowed data in this CreatePointerBarrierRequest.
+    pub fn into_owned(self) -> CreatePointerBarrierRequest<'static> {
+        CreatePointerBarrierRequest {
+            barrier: self.barrier,
+            window: self.window,
+            x1: self.x1,
+            y1: self.y1,
+            x2: self.x2,
+            y2: self.y2,
+            directions: self.directions,
+            devices: Cow::Owned(self.devices.into_owned()),
+        }
+    }
+}
+impl<'input> Request for CreatePointerBarrierRequest<'input> {
+    type Reply = ();
+}
+impl Trace for CreatePointerBarrierRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("barrier", |tracer| tracer.xid(&self.barrier))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("x1", |tracer| tracer.value(&self.x1))?;
+        tracer.field("y1", |tracer| tracer.value(&self.y1))?;
+        tracer.field("x2", |tracer| tracer.value(&self.x2))?;
+        tracer.field("y2", |tracer| tracer.value(&self.y2))?;
+        tracer.field("directions", |tracer| tracer.bitmask(&self.directions, BarrierDirections::VARIANTS))?;
+        tracer.field("devices", |tracer| tracer.list(&self.devices[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the DeletePointerBarrier request
+pub const DELETE_POINTER_BARRIER_REQUEST: u8 = 32;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeletePointerBarrierRequest {
+    pub barrier: Barrier,
+}
+impl DeletePointerBarrierRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let barrier_bytes = self.barrier.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            DELETE_POINTER_BARRIER_REQUEST,
+            0,
+            0,
+            barrier_bytes[0],
+            barrier_bytes[1],
+            barrier_bytes[2],
+            barrier_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != DELETE_POINTER_BARRIER_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (barrier, remaining) = Barrier::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(DeletePointerBarrierRequest {
+            barrier,
+        })
+    }
+}
+impl Request for DeletePointerBarrierRequest {
+    type Reply = ();
+}
+impl Trace for DeletePointerBarrierRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("barrier", |tracer| tracer.xid(&self.barrier))?;
+        Ok(())
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "QueryVersion",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "client_minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_version",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "ChangeSaveSet",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mode",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "SaveSetMode", variants: SaveSetMode::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "target",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "SaveSetTarget", variants: SaveSetTarget::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "SaveSetMapping", variants: SaveSetMapping::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SelectSelectionInput",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "selection",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "SelectionEventMask", variants: SelectionEventMask::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SelectCursorInput",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "event_mask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "CursorNotifyMask", variants: CursorNotifyMask::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "GetCursorImage",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xhot",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "yhot",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor_serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor_image",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CreateRegion",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rectangles",
+                kind: FieldKind::List(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CreateRegionFromBitmap",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bitmap",
+                kind: FieldKind::Value(TypeInfo { name: "PIXMAP", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CreateRegionFromWindow",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "kind",
+                kind: FieldKind::Value(TypeInfo { name: "shape:KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: shape::SK::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CreateRegionFromGC",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CreateRegionFromPicture",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "picture",
+                kind: FieldKind::Value(TypeInfo { name: "PICTURE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "DestroyRegion",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SetRegion",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rectangles",
+                kind: FieldKind::List(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CopyRegion",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "UnionRegion",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source1",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source2",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "IntersectRegion",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source1",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source2",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SubtractRegion",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source1",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source2",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "InvertRegion",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bounds",
+                kind: FieldKind::Value(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "TranslateRegion",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dx",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dy",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "RegionExtents",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "FetchRegion",
+        opcode: 19,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extents",
+                kind: FieldKind::Value(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "rectangles",
+                kind: FieldKind::List(TypeInfo { name: "RECTANGLE", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SetGCClipRegion",
+        opcode: 20,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::AltEnum(EnumInfo { name: "Region", variants: RegionEnum::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "x_origin",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_origin",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SetWindowShapeRegion",
+        opcode: 21,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dest",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dest_kind",
+                kind: FieldKind::Value(TypeInfo { name: "shape:KIND", value_set: ValueSet::Enum(EnumInfo { name: "SK", variants: shape::SK::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "x_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_offset",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::AltEnum(EnumInfo { name: "Region", variants: RegionEnum::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SetPictureClipRegion",
+        opcode: 22,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "picture",
+                kind: FieldKind::Value(TypeInfo { name: "PICTURE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "region",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::AltEnum(EnumInfo { name: "Region", variants: RegionEnum::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "x_origin",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y_origin",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "SetCursorName",
+        opcode: 23,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor",
+                kind: FieldKind::Value(TypeInfo { name: "CURSOR", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nbytes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "GetCursorName",
+        opcode: 24,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor",
+                kind: FieldKind::Value(TypeInfo { name: "CURSOR", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "atom",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::AltEnum(EnumInfo { name: "Atom", variants: xproto::AtomEnum::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "nbytes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "GetCursorImageAndName",
+        opcode: 25,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xhot",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "yhot",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor_serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor_atom",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::AltEnum(EnumInfo { name: "Atom", variants: xproto::AtomEnum::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "nbytes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor_image",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "ChangeCursor",
+        opcode: 26,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source",
+                kind: FieldKind::Value(TypeInfo { name: "CURSOR", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "CURSOR", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "ChangeCursorByName",
+        opcode: 27,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src",
+                kind: FieldKind::Value(TypeInfo { name: "CURSOR", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nbytes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "char", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "ExpandRegion",
+        opcode: 28,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "source",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "destination",
+                kind: FieldKind::Value(TypeInfo { name: "REGION", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "left",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "right",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "top",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bottom",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "HideCursor",
+        opcode: 29,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "ShowCursor",
+        opcode: 30,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "CreatePointerBarrier",
+        opcode: 31,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "barrier",
+                kind: FieldKind::Value(TypeInfo { name: "BARRIER", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x1",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y1",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "x2",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "y2",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "directions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BarrierDirections", variants: BarrierDirections::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "num_devices",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "devices",
+                kind: FieldKind::List(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("XFixes"),
+        name: "DeletePointerBarrier",
+        opcode: 32,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "barrier",
+                kind: FieldKind::Value(TypeInfo { name: "BARRIER", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("XFixes"),
+        name: "CursorNotify",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "subtype",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "CursorNotify", variants: CursorNotify::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "cursor_serial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::AltEnum(EnumInfo { name: "Atom", variants: xproto::AtomEnum::VARIANTS }) }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("XFixes"),
+        name: "SelectionNotify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "subtype",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "SelectionEvent", variants: SelectionEvent::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "owner",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "selection",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "selection_timestamp",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
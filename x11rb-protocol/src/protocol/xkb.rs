@@ -0,0 +1,21293 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `xkb` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "XKEYBOARD";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (1, 0);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Const(u8);
+impl Const {
+    pub const MAX_LEGAL_KEY_CODE: Self = Self(255);
+    pub const PER_KEY_BIT_ARRAY_SIZE: Self = Self(32);
+    pub const KEY_NAME_LENGTH: Self = Self(4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (255, "MAX_LEGAL_KEY_CODE", "MaxLegalKeyCode"),
+        (32, "PER_KEY_BIT_ARRAY_SIZE", "PerKeyBitArraySize"),
+        (4, "KEY_NAME_LENGTH", "KeyNameLength"),
+    ];
+}
+impl From<Const> for u8 {
+    #[inline]
+    fn from(input: Const) -> Self {
+        input.0
+    }
+}
+impl From<Const> for Option<u8> {
+    #[inline]
+    fn from(input: Const) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Const> for u16 {
+    #[inline]
+    fn from(input: Const) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Const> for Option<u16> {
+    #[inline]
+    fn from(input: Const) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Const> for u32 {
+    #[inline]
+    fn from(input: Const) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Const> for Option<u32> {
+    #[inline]
+    fn from(input: Const) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Const {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Const  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Const {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Const {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Const {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct EventType(u16);
+impl EventType {
+    pub const NEW_KEYBOARD_NOTIFY: Self = Self(1 << 0);
+    pub const MAP_NOTIFY: Self = Self(1 << 1);
+    pub const STATE_NOTIFY: Self = Self(1 << 2);
+    pub const CONTROLS_NOTIFY: Self = Self(1 << 3);
+    pub const INDICATOR_STATE_NOTIFY: Self = Self(1 << 4);
+    pub const INDICATOR_MAP_NOTIFY: Self = Self(1 << 5);
+    pub const NAMES_NOTIFY: Self = Self(1 << 6);
+    pub const COMPAT_MAP_NOTIFY: Self = Self(1 << 7);
+    pub const BELL_NOTIFY: Self = Self(1 << 8);
+    pub const ACTION_MESSAGE: Self = Self(1 << 9);
+    pub const ACCESS_X_NOTIFY: Self = Self(1 << 10);
+    pub const EXTENSION_DEVICE_NOTIFY: Self = Self(1 << 11);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "NEW_KEYBOARD_NOTIFY", "NewKeyboardNotify"),
+        (1 << 1, "MAP_NOTIFY", "MapNotify"),
+        (1 << 2, "STATE_NOTIFY", "StateNotify"),
+        (1 << 3, "CONTROLS_NOTIFY", "ControlsNotify"),
+        (1 << 4, "INDICATOR_STATE_NOTIFY", "IndicatorStateNotify"),
+        (1 << 5, "INDICATOR_MAP_NOTIFY", "IndicatorMapNotify"),
+        (1 << 6, "NAMES_NOTIFY", "NamesNotify"),
+        (1 << 7, "COMPAT_MAP_NOTIFY", "CompatMapNotify"),
+        (1 << 8, "BELL_NOTIFY", "BellNotify"),
+        (1 << 9, "ACTION_MESSAGE", "ActionMessage"),
+        (1 << 10, "ACCESS_X_NOTIFY", "AccessXNotify"),
+        (1 << 11, "EXTENSION_DEVICE_NOTIFY", "ExtensionDeviceNotify"),
+    ];
+}
+impl From<EventType> for u16 {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        input.0
+    }
+}
+impl From<EventType> for Option<u16> {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<EventType> for u32 {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<EventType> for Option<u32> {
+    #[inline]
+    fn from(input: EventType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for EventType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for EventType {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for EventType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for EventType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for EventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EventType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(EventType, u16);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NKNDetail(u8);
+impl NKNDetail {
+    pub const KEYCODES: Self = Self(1 << 0);
+    pub const GEOMETRY: Self = Self(1 << 1);
+    pub const DEVICE_ID: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "KEYCODES", "Keycodes"),
+        (1 << 1, "GEOMETRY", "Geometry"),
+        (1 << 2, "DEVICE_ID", "DeviceID"),
+    ];
+}
+impl From<NKNDetail> for u8 {
+    #[inline]
+    fn from(input: NKNDetail) -> Self {
+        input.0
+    }
+}
+impl From<NKNDetail> for Option<u8> {
+    #[inline]
+    fn from(input: NKNDetail) -> Self {
+        Some(input.0)
+    }
+}
+impl From<NKNDetail> for u16 {
+    #[inline]
+    fn from(input: NKNDetail) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<NKNDetail> for Option<u16> {
+    #[inline]
+    fn from(input: NKNDetail) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<NKNDetail> for u32 {
+    #[inline]
+    fn from(input: NKNDetail) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<NKNDetail> for Option<u32> {
+    #[inline]
+    fn from(input: NKNDetail) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for NKNDetail {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for NKNDetail  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for NKNDetail {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for NKNDetail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NKNDetail {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(NKNDetail, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AXNDetail(u8);
+impl AXNDetail {
+    pub const SK_PRESS: Self = Self(1 << 0);
+    pub const SK_ACCEPT: Self = Self(1 << 1);
+    pub const SK_REJECT: Self = Self(1 << 2);
+    pub const SK_RELEASE: Self = Self(1 << 3);
+    pub const BK_ACCEPT: Self = Self(1 << 4);
+    pub const BK_REJECT: Self = Self(1 << 5);
+    pub const AXK_WARNING: Self = Self(1 << 6);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "SK_PRESS", "SKPress"),
+        (1 << 1, "SK_ACCEPT", "SKAccept"),
+        (1 << 2, "SK_REJECT", "SKReject"),
+        (1 << 3, "SK_RELEASE", "SKRelease"),
+        (1 << 4, "BK_ACCEPT", "BKAccept"),
+        (1 << 5, "BK_REJECT", "BKReject"),
+        (1 << 6, "AXK_WARNING", "AXKWarning"),
+    ];
+}
+impl From<AXNDetail> for u8 {
+    #[inline]
+    fn from(input: AXNDetail) -> Self {
+        input.0
+    }
+}
+impl From<AXNDetail> for Option<u8> {
+    #[inline]
+    fn from(input: AXNDetail) -> Self {
+        Some(input.0)
+    }
+}
+impl From<AXNDetail> for u16 {
+    #[inline]
+    fn from(input: AXNDetail) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<AXNDetail> for Option<u16> {
+    #[inline]
+    fn from(input: AXNDetail) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<AXNDetail> for u32 {
+    #[inline]
+    fn from(input: AXNDetail) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<AXNDetail> for Option<u32> {
+    #[inline]
+    fn from(input: AXNDetail) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for AXNDetail {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for AXNDetail  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for AXNDetail {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AXNDetail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AXNDetail {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(AXNDetail, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MapPart(u8);
+impl MapPart {
+    pub const KEY_TYPES: Self = Self(1 << 0);
+    pub const KEY_SYMS: Self = Self(1 << 1);
+    pub const MODIFIER_MAP: Self = Self(1 << 2);
+    pub const EXPLICIT_COMPONENTS: Self = Self(1 << 3);
+    pub const KEY_ACTIONS: Self = Self(1 << 4);
+    pub const KEY_BEHAVIORS: Self = Self(1 << 5);
+    pub const VIRTUAL_MODS: Self = Self(1 << 6);
+    pub const VIRTUAL_MOD_MAP: Self = Self(1 << 7);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "KEY_TYPES", "KeyTypes"),
+        (1 << 1, "KEY_SYMS", "KeySyms"),
+        (1 << 2, "MODIFIER_MAP", "ModifierMap"),
+        (1 << 3, "EXPLICIT_COMPONENTS", "ExplicitComponents"),
+        (1 << 4, "KEY_ACTIONS", "KeyActions"),
+        (1 << 5, "KEY_BEHAVIORS", "KeyBehaviors"),
+        (1 << 6, "VIRTUAL_MODS", "VirtualMods"),
+        (1 << 7, "VIRTUAL_MOD_MAP", "VirtualModMap"),
+    ];
+}
+impl From<MapPart> for u8 {
+    #[inline]
+    fn from(input: MapPart) -> Self {
+        input.0
+    }
+}
+impl From<MapPart> for Option<u8> {
+    #[inline]
+    fn from(input: MapPart) -> Self {
+        Some(input.0)
+    }
+}
+impl From<MapPart> for u16 {
+    #[inline]
+    fn from(input: MapPart) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<MapPart> for Option<u16> {
+    #[inline]
+    fn from(input: MapPart) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<MapPart> for u32 {
+    #[inline]
+    fn from(input: MapPart) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<MapPart> for Option<u32> {
+    #[inline]
+    fn from(input: MapPart) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for MapPart {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for MapPart  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for MapPart {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for MapPart {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MapPart {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(MapPart, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SetMapFlags(u8);
+impl SetMapFlags {
+    pub const RESIZE_TYPES: Self = Self(1 << 0);
+    pub const RECOMPUTE_ACTIONS: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "RESIZE_TYPES", "ResizeTypes"),
+        (1 << 1, "RECOMPUTE_ACTIONS", "RecomputeActions"),
+    ];
+}
+impl From<SetMapFlags> for u8 {
+    #[inline]
+    fn from(input: SetMapFlags) -> Self {
+        input.0
+    }
+}
+impl From<SetMapFlags> for Option<u8> {
+    #[inline]
+    fn from(input: SetMapFlags) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SetMapFlags> for u16 {
+    #[inline]
+    fn from(input: SetMapFlags) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SetMapFlags> for Option<u16> {
+    #[inline]
+    fn from(input: SetMapFlags) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SetMapFlags> for u32 {
+    #[inline]
+    fn from(input: SetMapFlags) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SetMapFlags> for Option<u32> {
+    #[inline]
+    fn from(input: SetMapFlags) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SetMapFlags {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SetMapFlags  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SetMapFlags {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SetMapFlags {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SetMapFlags {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SetMapFlags, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct StatePart(u16);
+impl StatePart {
+    pub const MODIFIER_STATE: Self = Self(1 << 0);
+    pub const MODIFIER_BASE: Self = Self(1 << 1);
+    pub const MODIFIER_LATCH: Self = Self(1 << 2);
+    pub const MODIFIER_LOCK: Self = Self(1 << 3);
+    pub const GROUP_STATE: Self = Self(1 << 4);
+    pub const GROUP_BASE: Self = Self(1 << 5);
+    pub const GROUP_LATCH: Self = Self(1 << 6);
+    pub const GROUP_LOCK: Self = Self(1 << 7);
+    pub const COMPAT_STATE: Self = Self(1 << 8);
+    pub const GRAB_MODS: Self = Self(1 << 9);
+    pub const COMPAT_GRAB_MODS: Self = Self(1 << 10);
+    pub const LOOKUP_MODS: Self = Self(1 << 11);
+    pub const COMPAT_LOOKUP_MODS: Self = Self(1 << 12);
+    pub const POINTER_BUTTONS: Self = Self(1 << 13);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "MODIFIER_STATE", "ModifierState"),
+        (1 << 1, "MODIFIER_BASE", "ModifierBase"),
+        (1 << 2, "MODIFIER_LATCH", "ModifierLatch"),
+        (1 << 3, "MODIFIER_LOCK", "ModifierLock"),
+        (1 << 4, "GROUP_STATE", "GroupState"),
+        (1 << 5, "GROUP_BASE", "GroupBase"),
+        (1 << 6, "GROUP_LATCH", "GroupLatch"),
+        (1 << 7, "GROUP_LOCK", "GroupLock"),
+        (1 << 8, "COMPAT_STATE", "CompatState"),
+        (1 << 9, "GRAB_MODS", "GrabMods"),
+        (1 << 10, "COMPAT_GRAB_MODS", "CompatGrabMods"),
+        (1 << 11, "LOOKUP_MODS", "LookupMods"),
+        (1 << 12, "COMPAT_LOOKUP_MODS", "CompatLookupMods"),
+        (1 << 13, "POINTER_BUTTONS", "PointerButtons"),
+    ];
+}
+impl From<StatePart> for u16 {
+    #[inline]
+    fn from(input: StatePart) -> Self {
+        input.0
+    }
+}
+impl From<StatePart> for Option<u16> {
+    #[inline]
+    fn from(input: StatePart) -> Self {
+        Some(input.0)
+    }
+}
+impl From<StatePart> for u32 {
+    #[inline]
+    fn from(input: StatePart) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<StatePart> for Option<u32> {
+    #[inline]
+    fn from(input: StatePart) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for StatePart {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for StatePart {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for StatePart  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for StatePart {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for StatePart {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StatePart {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(StatePart, u16);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BoolCtrl(u16);
+impl BoolCtrl {
+    pub const REPEAT_KEYS: Self = Self(1 << 0);
+    pub const SLOW_KEYS: Self = Self(1 << 1);
+    pub const BOUNCE_KEYS: Self = Self(1 << 2);
+    pub const STICKY_KEYS: Self = Self(1 << 3);
+    pub const MOUSE_KEYS: Self = Self(1 << 4);
+    pub const MOUSE_KEYS_ACCEL: Self = Self(1 << 5);
+    pub const ACCESS_X_KEYS: Self = Self(1 << 6);
+    pub const ACCESS_X_TIMEOUT_MASK: Self = Self(1 << 7);
+    pub const ACCESS_X_FEEDBACK_MASK: Self = Self(1 << 8);
+    pub const AUDIBLE_BELL_MASK: Self = Self(1 << 9);
+    pub const OVERLAY1_MASK: Self = Self(1 << 10);
+    pub const OVERLAY2_MASK: Self = Self(1 << 11);
+    pub const IGNORE_GROUP_LOCK_MASK: Self = Self(1 << 12);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "REPEAT_KEYS", "RepeatKeys"),
+        (1 << 1, "SLOW_KEYS", "SlowKeys"),
+        (1 << 2, "BOUNCE_KEYS", "BounceKeys"),
+        (1 << 3, "STICKY_KEYS", "StickyKeys"),
+        (1 << 4, "MOUSE_KEYS", "MouseKeys"),
+        (1 << 5, "MOUSE_KEYS_ACCEL", "MouseKeysAccel"),
+        (1 << 6, "ACCESS_X_KEYS", "AccessXKeys"),
+        (1 << 7, "ACCESS_X_TIMEOUT_MASK", "AccessXTimeoutMask"),
+        (1 << 8, "ACCESS_X_FEEDBACK_MASK", "AccessXFeedbackMask"),
+        (1 << 9, "AUDIBLE_BELL_MASK", "AudibleBellMask"),
+        (1 << 10, "OVERLAY1_MASK", "Overlay1Mask"),
+        (1 << 11, "OVERLAY2_MASK", "Overlay2Mask"),
+        (1 << 12, "IGNORE_GROUP_LOCK_MASK", "IgnoreGroupLockMask"),
+    ];
+}
+impl From<BoolCtrl> for u16 {
+    #[inline]
+    fn from(input: BoolCtrl) -> Self {
+        input.0
+    }
+}
+impl From<BoolCtrl> for Option<u16> {
+    #[inline]
+    fn from(input: BoolCtrl) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BoolCtrl> for u32 {
+    #[inline]
+    fn from(input: BoolCtrl) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BoolCtrl> for Option<u32> {
+    #[inline]
+    fn from(input: BoolCtrl) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BoolCtrl {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for BoolCtrl {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BoolCtrl  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BoolCtrl {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BoolCtrl {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BoolCtrl {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(BoolCtrl, u16);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Control(u32);
+impl Control {
+    pub const GROUPS_WRAP: Self = Self(1 << 27);
+    pub const INTERNAL_MODS: Self = Self(1 << 28);
+    pub const IGNORE_LOCK_MODS: Self = Self(1 << 29);
+    pub const PER_KEY_REPEAT: Self = Self(1 << 30);
+    pub const CONTROLS_ENABLED: Self = Self(1 << 31);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 27, "GROUPS_WRAP", "GroupsWrap"),
+        (1 << 28, "INTERNAL_MODS", "InternalMods"),
+        (1 << 29, "IGNORE_LOCK_MODS", "IgnoreLockMods"),
+        (1 << 30, "PER_KEY_REPEAT", "PerKeyRepeat"),
+        (1 << 31, "CONTROLS_ENABLED", "ControlsEnabled"),
+    ];
+}
+impl From<Control> for u32 {
+    #[inline]
+    fn from(input: Control) -> Self {
+        input.0
+    }
+}
+impl From<Control> for Option<u32> {
+    #[inline]
+    fn from(input: Control) -> Self {
+        Some(input.0)
+    }
+}
+impl From<u8> for Control {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for Control {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u32> for Control {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Control  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0, Self::VARIANTS)
+    }
+}
+impl Trace for Control {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Control {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Control {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Control, u32);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AXOption(u16);
+impl AXOption {
+    pub const SK_PRESS_FB: Self = Self(1 << 0);
+    pub const SK_ACCEPT_FB: Self = Self(1 << 1);
+    pub const FEATURE_FB: Self = Self(1 << 2);
+    pub const SLOW_WARN_FB: Self = Self(1 << 3);
+    pub const INDICATOR_FB: Self = Self(1 << 4);
+    pub const STICKY_KEYS_FB: Self = Self(1 << 5);
+    pub const TWO_KEYS: Self = Self(1 << 6);
+    pub const LATCH_TO_LOCK: Self = Self(1 << 7);
+    pub const SK_RELEASE_FB: Self = Self(1 << 8);
+    pub const SK_REJECT_FB: Self = Self(1 << 9);
+    pub const BK_REJECT_FB: Self = Self(1 << 10);
+    pub const DUMB_BELL: Self = Self(1 << 11);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "SK_PRESS_FB", "SKPressFB"),
+        (1 << 1, "SK_ACCEPT_FB", "SKAcceptFB"),
+        (1 << 2, "FEATURE_FB", "FeatureFB"),
+        (1 << 3, "SLOW_WARN_FB", "SlowWarnFB"),
+        (1 << 4, "INDICATOR_FB", "IndicatorFB"),
+        (1 << 5, "STICKY_KEYS_FB", "StickyKeysFB"),
+        (1 << 6, "TWO_KEYS", "TwoKeys"),
+        (1 << 7, "LATCH_TO_LOCK", "LatchToLock"),
+        (1 << 8, "SK_RELEASE_FB", "SKReleaseFB"),
+        (1 << 9, "SK_REJECT_FB", "SKRejectFB"),
+        (1 << 10, "BK_REJECT_FB", "BKRejectFB"),
+        (1 << 11, "DUMB_BELL", "DumbBell"),
+    ];
+}
+impl From<AXOption> for u16 {
+    #[inline]
+    fn from(input: AXOption) -> Self {
+        input.0
+    }
+}
+impl From<AXOption> for Option<u16> {
+    #[inline]
+    fn from(input: AXOption) -> Self {
+        Some(input.0)
+    }
+}
+impl From<AXOption> for u32 {
+    #[inline]
+    fn from(input: AXOption) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<AXOption> for Option<u32> {
+    #[inline]
+    fn from(input: AXOption) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for AXOption {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for AXOption {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for AXOption  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for AXOption {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AXOption {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AXOption {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(AXOption, u16);
+
+pub type DeviceSpec = u16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LedClassResult(u16);
+impl LedClassResult {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const LED_FEEDBACK_CLASS: Self = Self(4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "KBD_FEEDBACK_CLASS", "KbdFeedbackClass"),
+        (4, "LED_FEEDBACK_CLASS", "LedFeedbackClass"),
+    ];
+}
+impl From<LedClassResult> for u16 {
+    #[inline]
+    fn from(input: LedClassResult) -> Self {
+        input.0
+    }
+}
+impl From<LedClassResult> for Option<u16> {
+    #[inline]
+    fn from(input: LedClassResult) -> Self {
+        Some(input.0)
+    }
+}
+impl From<LedClassResult> for u32 {
+    #[inline]
+    fn from(input: LedClassResult) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<LedClassResult> for Option<u32> {
+    #[inline]
+    fn from(input: LedClassResult) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for LedClassResult {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for LedClassResult {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for LedClassResult  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for LedClassResult {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for LedClassResult {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LedClassResult {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LedClass(u16);
+impl LedClass {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const LED_FEEDBACK_CLASS: Self = Self(4);
+    pub const DFLT_XI_CLASS: Self = Self(768);
+    pub const ALL_XI_CLASSES: Self = Self(1280);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "KBD_FEEDBACK_CLASS", "KbdFeedbackClass"),
+        (4, "LED_FEEDBACK_CLASS", "LedFeedbackClass"),
+        (768, "DFLT_XI_CLASS", "DfltXIClass"),
+        (1280, "ALL_XI_CLASSES", "AllXIClasses"),
+    ];
+}
+impl From<LedClass> for u16 {
+    #[inline]
+    fn from(input: LedClass) -> Self {
+        input.0
+    }
+}
+impl From<LedClass> for Option<u16> {
+    #[inline]
+    fn from(input: LedClass) -> Self {
+        Some(input.0)
+    }
+}
+impl From<LedClass> for u32 {
+    #[inline]
+    fn from(input: LedClass) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<LedClass> for Option<u32> {
+    #[inline]
+    fn from(input: LedClass) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for LedClass {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for LedClass {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for LedClass  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for LedClass {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for LedClass {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LedClass {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+pub type LedClassSpec = u16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BellClassResult(u8);
+impl BellClassResult {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const BELL_FEEDBACK_CLASS: Self = Self(5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "KBD_FEEDBACK_CLASS", "KbdFeedbackClass"),
+        (5, "BELL_FEEDBACK_CLASS", "BellFeedbackClass"),
+    ];
+}
+impl From<BellClassResult> for u8 {
+    #[inline]
+    fn from(input: BellClassResult) -> Self {
+        input.0
+    }
+}
+impl From<BellClassResult> for Option<u8> {
+    #[inline]
+    fn from(input: BellClassResult) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BellClassResult> for u16 {
+    #[inline]
+    fn from(input: BellClassResult) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<BellClassResult> for Option<u16> {
+    #[inline]
+    fn from(input: BellClassResult) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<BellClassResult> for u32 {
+    #[inline]
+    fn from(input: BellClassResult) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BellClassResult> for Option<u32> {
+    #[inline]
+    fn from(input: BellClassResult) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BellClassResult {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BellClassResult  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BellClassResult {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BellClassResult {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BellClassResult {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BellClass(u16);
+impl BellClass {
+    pub const KBD_FEEDBACK_CLASS: Self = Self(0);
+    pub const BELL_FEEDBACK_CLASS: Self = Self(5);
+    pub const DFLT_XI_CLASS: Self = Self(768);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "KBD_FEEDBACK_CLASS", "KbdFeedbackClass"),
+        (5, "BELL_FEEDBACK_CLASS", "BellFeedbackClass"),
+        (768, "DFLT_XI_CLASS", "DfltXIClass"),
+    ];
+}
+impl From<BellClass> for u16 {
+    #[inline]
+    fn from(input: BellClass) -> Self {
+        input.0
+    }
+}
+impl From<BellClass> for Option<u16> {
+    #[inline]
+    fn from(input: BellClass) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BellClass> for u32 {
+    #[inline]
+    fn from(input: BellClass) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BellClass> for Option<u32> {
+    #[inline]
+    fn from(input: BellClass) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BellClass {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for BellClass {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BellClass  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BellClass {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BellClass {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BellClass {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+pub type BellClassSpec = u16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ID(u16);
+impl ID {
+    pub const USE_CORE_KBD: Self = Self(256);
+    pub const USE_CORE_PTR: Self = Self(512);
+    pub const DFLT_XI_CLASS: Self = Self(768);
+    pub const DFLT_XI_ID: Self = Self(1024);
+    pub const ALL_XI_CLASS: Self = Self(1280);
+    pub const ALL_XI_ID: Self = Self(1536);
+    pub const XI_NONE: Self = Self(65280);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (256, "USE_CORE_KBD", "UseCoreKbd"),
+        (512, "USE_CORE_PTR", "UseCorePtr"),
+        (768, "DFLT_XI_CLASS", "DfltXIClass"),
+        (1024, "DFLT_XI_ID", "DfltXIId"),
+        (1280, "ALL_XI_CLASS", "AllXIClass"),
+        (1536, "ALL_XI_ID", "AllXIId"),
+        (65280, "XI_NONE", "XINone"),
+    ];
+}
+impl From<ID> for u16 {
+    #[inline]
+    fn from(input: ID) -> Self {
+        input.0
+    }
+}
+impl From<ID> for Option<u16> {
+    #[inline]
+    fn from(input: ID) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ID> for u32 {
+    #[inline]
+    fn from(input: ID) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ID> for Option<u32> {
+    #[inline]
+    fn from(input: ID) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ID {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for ID {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ID  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ID {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ID {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ID {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+pub type IDSpec = u16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Group(u8);
+impl Group {
+    pub const M1: Self = Self(0);
+    pub const M2: Self = Self(1);
+    pub const M3: Self = Self(2);
+    pub const M4: Self = Self(3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "M1", "M1"),
+        (1, "M2", "M2"),
+        (2, "M3", "M3"),
+        (3, "M4", "M4"),
+    ];
+}
+impl From<Group> for u8 {
+    #[inline]
+    fn from(input: Group) -> Self {
+        input.0
+    }
+}
+impl From<Group> for Option<u8> {
+    #[inline]
+    fn from(input: Group) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Group> for u16 {
+    #[inline]
+    fn from(input: Group) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Group> for Option<u16> {
+    #[inline]
+    fn from(input: Group) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Group> for u32 {
+    #[inline]
+    fn from(input: Group) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Group> for Option<u32> {
+    #[inline]
+    fn from(input: Group) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Group {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Group  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Group {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Group {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Group {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Groups(u8);
+impl Groups {
+    pub const ANY: Self = Self(254);
+    pub const ALL: Self = Self(255);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (254, "ANY", "Any"),
+        (255, "ALL", "All"),
+    ];
+}
+impl From<Groups> for u8 {
+    #[inline]
+    fn from(input: Groups) -> Self {
+        input.0
+    }
+}
+impl From<Groups> for Option<u8> {
+    #[inline]
+    fn from(input: Groups) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Groups> for u16 {
+    #[inline]
+    fn from(input: Groups) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Groups> for Option<u16> {
+    #[inline]
+    fn from(input: Groups) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Groups> for u32 {
+    #[inline]
+    fn from(input: Groups) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Groups> for Option<u32> {
+    #[inline]
+    fn from(input: Groups) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Groups {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Groups  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Groups {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Groups {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Groups {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SetOfGroup(u8);
+impl SetOfGroup {
+    pub const GROUP1: Self = Self(1 << 0);
+    pub const GROUP2: Self = Self(1 << 1);
+    pub const GROUP3: Self = Self(1 << 2);
+    pub const GROUP4: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "GROUP1", "Group1"),
+        (1 << 1, "GROUP2", "Group2"),
+        (1 << 2, "GROUP3", "Group3"),
+        (1 << 3, "GROUP4", "Group4"),
+    ];
+}
+impl From<SetOfGroup> for u8 {
+    #[inline]
+    fn from(input: SetOfGroup) -> Self {
+        input.0
+    }
+}
+impl From<SetOfGroup> for Option<u8> {
+    #[inline]
+    fn from(input: SetOfGroup) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SetOfGroup> for u16 {
+    #[inline]
+    fn from(input: SetOfGroup) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SetOfGroup> for Option<u16> {
+    #[inline]
+    fn from(input: SetOfGroup) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SetOfGroup> for u32 {
+    #[inline]
+    fn from(input: SetOfGroup) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SetOfGroup> for Option<u32> {
+    #[inline]
+    fn from(input: SetOfGroup) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SetOfGroup {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SetOfGroup  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SetOfGroup {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SetOfGroup {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SetOfGroup {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SetOfGroup, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SetOfGroups(u8);
+impl SetOfGroups {
+    pub const ANY: Self = Self(1 << 7);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 7, "ANY", "Any"),
+    ];
+}
+impl From<SetOfGroups> for u8 {
+    #[inline]
+    fn from(input: SetOfGroups) -> Self {
+        input.0
+    }
+}
+impl From<SetOfGroups> for Option<u8> {
+    #[inline]
+    fn from(input: SetOfGroups) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SetOfGroups> for u16 {
+    #[inline]
+    fn from(input: SetOfGroups) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SetOfGroups> for Option<u16> {
+    #[inline]
+    fn from(input: SetOfGroups) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SetOfGroups> for u32 {
+    #[inline]
+    fn from(input: SetOfGroups) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SetOfGroups> for Option<u32> {
+    #[inline]
+    fn from(input: SetOfGroups) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SetOfGroups {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SetOfGroups  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SetOfGroups {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SetOfGroups {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SetOfGroups {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SetOfGroups, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GroupsWrap(u8);
+impl GroupsWrap {
+    pub const WRAP_INTO_RANGE: Self = Self(0);
+    pub const CLAMP_INTO_RANGE: Self = Self(1 << 6);
+    pub const REDIRECT_INTO_RANGE: Self = Self(1 << 7);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "WRAP_INTO_RANGE", "WrapIntoRange"),
+        (1 << 6, "CLAMP_INTO_RANGE", "ClampIntoRange"),
+        (1 << 7, "REDIRECT_INTO_RANGE", "RedirectIntoRange"),
+    ];
+}
+impl From<GroupsWrap> for u8 {
+    #[inline]
+    fn from(input: GroupsWrap) -> Self {
+        input.0
+    }
+}
+impl From<GroupsWrap> for Option<u8> {
+    #[inline]
+    fn from(input: GroupsWrap) -> Self {
+        Some(input.0)
+    }
+}
+impl From<GroupsWrap> for u16 {
+    #[inline]
+    fn from(input: GroupsWrap) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<GroupsWrap> for Option<u16> {
+    #[inline]
+    fn from(input: GroupsWrap) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<GroupsWrap> for u32 {
+    #[inline]
+    fn from(input: GroupsWrap) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<GroupsWrap> for Option<u32> {
+    #[inline]
+    fn from(input: GroupsWrap) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for GroupsWrap {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for GroupsWrap  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for GroupsWrap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for GroupsWrap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GroupsWrap {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(GroupsWrap, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VModsHigh(u8);
+impl VModsHigh {
+    pub const M15: Self = Self(1 << 7);
+    pub const M14: Self = Self(1 << 6);
+    pub const M13: Self = Self(1 << 5);
+    pub const M12: Self = Self(1 << 4);
+    pub const M11: Self = Self(1 << 3);
+    pub const M10: Self = Self(1 << 2);
+    pub const M9: Self = Self(1 << 1);
+    pub const M8: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 7, "M15", "M15"),
+        (1 << 6, "M14", "M14"),
+        (1 << 5, "M13", "M13"),
+        (1 << 4, "M12", "M12"),
+        (1 << 3, "M11", "M11"),
+        (1 << 2, "M10", "M10"),
+        (1 << 1, "M9", "M9"),
+        (1 << 0, "M8", "M8"),
+    ];
+}
+impl From<VModsHigh> for u8 {
+    #[inline]
+    fn from(input: VModsHigh) -> Self {
+        input.0
+    }
+}
+impl From<VModsHigh> for Option<u8> {
+    #[inline]
+    fn from(input: VModsHigh) -> Self {
+        Some(input.0)
+    }
+}
+impl From<VModsHigh> for u16 {
+    #[inline]
+    fn from(input: VModsHigh) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<VModsHigh> for Option<u16> {
+    #[inline]
+    fn from(input: VModsHigh) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<VModsHigh> for u32 {
+    #[inline]
+    fn from(input: VModsHigh) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<VModsHigh> for Option<u32> {
+    #[inline]
+    fn from(input: VModsHigh) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for VModsHigh {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for VModsHigh  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for VModsHigh {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VModsHigh {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VModsHigh {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(VModsHigh, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VModsLow(u8);
+impl VModsLow {
+    pub const M7: Self = Self(1 << 7);
+    pub const M6: Self = Self(1 << 6);
+    pub const M5: Self = Self(1 << 5);
+    pub const M4: Self = Self(1 << 4);
+    pub const M3: Self = Self(1 << 3);
+    pub const M2: Self = Self(1 << 2);
+    pub const M1: Self = Self(1 << 1);
+    pub const M0: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 7, "M7", "M7"),
+        (1 << 6, "M6", "M6"),
+        (1 << 5, "M5", "M5"),
+        (1 << 4, "M4", "M4"),
+        (1 << 3, "M3", "M3"),
+        (1 << 2, "M2", "M2"),
+        (1 << 1, "M1", "M1"),
+        (1 << 0, "M0", "M0"),
+    ];
+}
+impl From<VModsLow> for u8 {
+    #[inline]
+    fn from(input: VModsLow) -> Self {
+        input.0
+    }
+}
+impl From<VModsLow> for Option<u8> {
+    #[inline]
+    fn from(input: VModsLow) -> Self {
+        Some(input.0)
+    }
+}
+impl From<VModsLow> for u16 {
+    #[inline]
+    fn from(input: VModsLow) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<VModsLow> for Option<u16> {
+    #[inline]
+    fn from(input: VModsLow) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<VModsLow> for u32 {
+    #[inline]
+    fn from(input: VModsLow) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<VModsLow> for Option<u32> {
+    #[inline]
+    fn from(input: VModsLow) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for VModsLow {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for VModsLow  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for VModsLow {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VModsLow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VModsLow {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(VModsLow, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VMod(u16);
+impl VMod {
+    pub const M15: Self = Self(1 << 15);
+    pub const M14: Self = Self(1 << 14);
+    pub const M13: Self = Self(1 << 13);
+    pub const M12: Self = Self(1 << 12);
+    pub const M11: Self = Self(1 << 11);
+    pub const M10: Self = Self(1 << 10);
+    pub const M9: Self = Self(1 << 9);
+    pub const M8: Self = Self(1 << 8);
+    pub const M7: Self = Self(1 << 7);
+    pub const M6: Self = Self(1 << 6);
+    pub const M5: Self = Self(1 << 5);
+    pub const M4: Self = Self(1 << 4);
+    pub const M3: Self = Self(1 << 3);
+    pub const M2: Self = Self(1 << 2);
+    pub const M1: Self = Self(1 << 1);
+    pub const M0: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 15, "M15", "M15"),
+        (1 << 14, "M14", "M14"),
+        (1 << 13, "M13", "M13"),
+        (1 << 12, "M12", "M12"),
+        (1 << 11, "M11", "M11"),
+        (1 << 10, "M10", "M10"),
+        (1 << 9, "M9", "M9"),
+        (1 << 8, "M8", "M8"),
+        (1 << 7, "M7", "M7"),
+        (1 << 6, "M6", "M6"),
+        (1 << 5, "M5", "M5"),
+        (1 << 4, "M4", "M4"),
+        (1 << 3, "M3", "M3"),
+        (1 << 2, "M2", "M2"),
+        (1 << 1, "M1", "M1"),
+        (1 << 0, "M0", "M0"),
+    ];
+}
+impl From<VMod> for u16 {
+    #[inline]
+    fn from(input: VMod) -> Self {
+        input.0
+    }
+}
+impl From<VMod> for Option<u16> {
+    #[inline]
+    fn from(input: VMod) -> Self {
+        Some(input.0)
+    }
+}
+impl From<VMod> for u32 {
+    #[inline]
+    fn from(input: VMod) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<VMod> for Option<u32> {
+    #[inline]
+    fn from(input: VMod) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for VMod {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for VMod {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for VMod  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for VMod {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VMod {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VMod {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(VMod, u16);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Explicit(u8);
+impl Explicit {
+    pub const V_MOD_MAP: Self = Self(1 << 7);
+    pub const BEHAVIOR: Self = Self(1 << 6);
+    pub const AUTO_REPEAT: Self = Self(1 << 5);
+    pub const INTERPRET: Self = Self(1 << 4);
+    pub const KEY_TYPE4: Self = Self(1 << 3);
+    pub const KEY_TYPE3: Self = Self(1 << 2);
+    pub const KEY_TYPE2: Self = Self(1 << 1);
+    pub const KEY_TYPE1: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 7, "V_MOD_MAP", "VModMap"),
+        (1 << 6, "BEHAVIOR", "Behavior"),
+        (1 << 5, "AUTO_REPEAT", "AutoRepeat"),
+        (1 << 4, "INTERPRET", "Interpret"),
+        (1 << 3, "KEY_TYPE4", "KeyType4"),
+        (1 << 2, "KEY_TYPE3", "KeyType3"),
+        (1 << 1, "KEY_TYPE2", "KeyType2"),
+        (1 << 0, "KEY_TYPE1", "KeyType1"),
+    ];
+}
+impl From<Explicit> for u8 {
+    #[inline]
+    fn from(input: Explicit) -> Self {
+        input.0
+    }
+}
+impl From<Explicit> for Option<u8> {
+    #[inline]
+    fn from(input: Explicit) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Explicit> for u16 {
+    #[inline]
+    fn from(input: Explicit) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Explicit> for Option<u16> {
+    #[inline]
+    fn from(input: Explicit) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Explicit> for u32 {
+    #[inline]
+    fn from(input: Explicit) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Explicit> for Option<u32> {
+    #[inline]
+    fn from(input: Explicit) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Explicit {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Explicit  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Explicit {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Explicit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Explicit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Explicit, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SymInterpretMatch(u8);
+impl SymInterpretMatch {
+    pub const NONE_OF: Self = Self(0);
+    pub const ANY_OF_OR_NONE: Self = Self(1);
+    pub const ANY_OF: Self = Self(2);
+    pub const ALL_OF: Self = Self(3);
+    pub const EXACTLY: Self = Self(4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NONE_OF", "NoneOf"),
+        (1, "ANY_OF_OR_NONE", "AnyOfOrNone"),
+        (2, "ANY_OF", "AnyOf"),
+        (3, "ALL_OF", "AllOf"),
+        (4, "EXACTLY", "Exactly"),
+    ];
+}
+impl From<SymInterpretMatch> for u8 {
+    #[inline]
+    fn from(input: SymInterpretMatch) -> Self {
+        input.0
+    }
+}
+impl From<SymInterpretMatch> for Option<u8> {
+    #[inline]
+    fn from(input: SymInterpretMatch) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SymInterpretMatch> for u16 {
+    #[inline]
+    fn from(input: SymInterpretMatch) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SymInterpretMatch> for Option<u16> {
+    #[inline]
+    fn from(input: SymInterpretMatch) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SymInterpretMatch> for u32 {
+    #[inline]
+    fn from(input: SymInterpretMatch) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SymInterpretMatch> for Option<u32> {
+    #[inline]
+    fn from(input: SymInterpretMatch) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SymInterpretMatch {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SymInterpretMatch  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SymInterpretMatch {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SymInterpretMatch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SymInterpretMatch {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SymInterpMatch(u8);
+impl SymInterpMatch {
+    pub const LEVEL_ONE_ONLY: Self = Self(1 << 7);
+    pub const OP_MASK: Self = Self(127);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 7, "LEVEL_ONE_ONLY", "LevelOneOnly"),
+        (127, "OP_MASK", "OpMask"),
+    ];
+}
+impl From<SymInterpMatch> for u8 {
+    #[inline]
+    fn from(input: SymInterpMatch) -> Self {
+        input.0
+    }
+}
+impl From<SymInterpMatch> for Option<u8> {
+    #[inline]
+    fn from(input: SymInterpMatch) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SymInterpMatch> for u16 {
+    #[inline]
+    fn from(input: SymInterpMatch) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SymInterpMatch> for Option<u16> {
+    #[inline]
+    fn from(input: SymInterpMatch) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SymInterpMatch> for u32 {
+    #[inline]
+    fn from(input: SymInterpMatch) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SymInterpMatch> for Option<u32> {
+    #[inline]
+    fn from(input: SymInterpMatch) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SymInterpMatch {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SymInterpMatch  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SymInterpMatch {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SymInterpMatch {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SymInterpMatch {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct IMFlag(u8);
+impl IMFlag {
+    pub const NO_EXPLICIT: Self = Self(1 << 7);
+    pub const NO_AUTOMATIC: Self = Self(1 << 6);
+    pub const LED_DRIVES_KB: Self = Self(1 << 5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 7, "NO_EXPLICIT", "NoExplicit"),
+        (1 << 6, "NO_AUTOMATIC", "NoAutomatic"),
+        (1 << 5, "LED_DRIVES_KB", "LEDDrivesKB"),
+    ];
+}
+impl From<IMFlag> for u8 {
+    #[inline]
+    fn from(input: IMFlag) -> Self {
+        input.0
+    }
+}
+impl From<IMFlag> for Option<u8> {
+    #[inline]
+    fn from(input: IMFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<IMFlag> for u16 {
+    #[inline]
+    fn from(input: IMFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<IMFlag> for Option<u16> {
+    #[inline]
+    fn from(input: IMFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<IMFlag> for u32 {
+    #[inline]
+    fn from(input: IMFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<IMFlag> for Option<u32> {
+    #[inline]
+    fn from(input: IMFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for IMFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for IMFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for IMFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for IMFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IMFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(IMFlag, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct IMModsWhich(u8);
+impl IMModsWhich {
+    pub const USE_COMPAT: Self = Self(1 << 4);
+    pub const USE_EFFECTIVE: Self = Self(1 << 3);
+    pub const USE_LOCKED: Self = Self(1 << 2);
+    pub const USE_LATCHED: Self = Self(1 << 1);
+    pub const USE_BASE: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 4, "USE_COMPAT", "UseCompat"),
+        (1 << 3, "USE_EFFECTIVE", "UseEffective"),
+        (1 << 2, "USE_LOCKED", "UseLocked"),
+        (1 << 1, "USE_LATCHED", "UseLatched"),
+        (1 << 0, "USE_BASE", "UseBase"),
+    ];
+}
+impl From<IMModsWhich> for u8 {
+    #[inline]
+    fn from(input: IMModsWhich) -> Self {
+        input.0
+    }
+}
+impl From<IMModsWhich> for Option<u8> {
+    #[inline]
+    fn from(input: IMModsWhich) -> Self {
+        Some(input.0)
+    }
+}
+impl From<IMModsWhich> for u16 {
+    #[inline]
+    fn from(input: IMModsWhich) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<IMModsWhich> for Option<u16> {
+    #[inline]
+    fn from(input: IMModsWhich) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<IMModsWhich> for u32 {
+    #[inline]
+    fn from(input: IMModsWhich) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<IMModsWhich> for Option<u32> {
+    #[inline]
+    fn from(input: IMModsWhich) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for IMModsWhich {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for IMModsWhich  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for IMModsWhich {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for IMModsWhich {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IMModsWhich {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(IMModsWhich, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct IMGroupsWhich(u8);
+impl IMGroupsWhich {
+    pub const USE_COMPAT: Self = Self(1 << 4);
+    pub const USE_EFFECTIVE: Self = Self(1 << 3);
+    pub const USE_LOCKED: Self = Self(1 << 2);
+    pub const USE_LATCHED: Self = Self(1 << 1);
+    pub const USE_BASE: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 4, "USE_COMPAT", "UseCompat"),
+        (1 << 3, "USE_EFFECTIVE", "UseEffective"),
+        (1 << 2, "USE_LOCKED", "UseLocked"),
+        (1 << 1, "USE_LATCHED", "UseLatched"),
+        (1 << 0, "USE_BASE", "UseBase"),
+    ];
+}
+impl From<IMGroupsWhich> for u8 {
+    #[inline]
+    fn from(input: IMGroupsWhich) -> Self {
+        input.0
+    }
+}
+impl From<IMGroupsWhich> for Option<u8> {
+    #[inline]
+    fn from(input: IMGroupsWhich) -> Self {
+        Some(input.0)
+    }
+}
+impl From<IMGroupsWhich> for u16 {
+    #[inline]
+    fn from(input: IMGroupsWhich) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<IMGroupsWhich> for Option<u16> {
+    #[inline]
+    fn from(input: IMGroupsWhich) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<IMGroupsWhich> for u32 {
+    #[inline]
+    fn from(input: IMGroupsWhich) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<IMGroupsWhich> for Option<u32> {
+    #[inline]
+    fn from(input: IMGroupsWhich) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for IMGroupsWhich {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for IMGroupsWhich  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for IMGroupsWhich {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for IMGroupsWhich {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IMGroupsWhich {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(IMGroupsWhich, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndicatorMap {
+    pub flags: IMFlag,
+    pub which_groups: IMGroupsWhich,
+    pub groups: SetOfGroup,
+    pub which_mods: IMModsWhich,
+    pub mods: u8,
+    pub real_mods: u8,
+    pub vmods: u16,
+    pub ctrls: u32,
+}
+impl TryParse for IndicatorMap {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (which_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (which_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let flags = flags.into();
+        let which_groups = which_groups.into();
+        let groups = groups.into();
+        let which_mods = which_mods.into();
+        let result = IndicatorMap { flags, which_groups, groups, which_mods, mods, real_mods, vmods, ctrls };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IndicatorMap {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for IndicatorMap {
+    type Bytes = [u8; 12];
+    fn serialize(&self) -> [u8; 12] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 12] {
+        let flags_bytes = u8::from(self.flags).serialize_with_order(order);
+        let which_groups_bytes = u8::from(self.which_groups).serialize_with_order(order);
+        let groups_bytes = u8::from(self.groups).serialize_with_order(order);
+        let which_mods_bytes = u8::from(self.which_mods).serialize_with_order(order);
+        let mods_bytes = self.mods.serialize_with_order(order);
+        let real_mods_bytes = self.real_mods.serialize_with_order(order);
+        let vmods_bytes = self.vmods.serialize_with_order(order);
+        let ctrls_bytes = self.ctrls.serialize_with_order(order);
+        [
+            flags_bytes[0],
+            which_groups_bytes[0],
+            groups_bytes[0],
+            which_mods_bytes[0],
+            mods_bytes[0],
+            real_mods_bytes[0],
+            vmods_bytes[0],
+            vmods_bytes[1],
+            ctrls_bytes[0],
+            ctrls_bytes[1],
+            ctrls_bytes[2],
+            ctrls_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(12);
+        u8::from(self.flags).serialize_into_with_order(bytes, order);
+        u8::from(self.which_groups).serialize_into_with_order(bytes, order);
+        u8::from(self.groups).serialize_into_with_order(bytes, order);
+        u8::from(self.which_mods).serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.real_mods.serialize_into_with_order(bytes, order);
+        self.vmods.serialize_into_with_order(bytes, order);
+        self.ctrls.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for IndicatorMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("whichGroups", |tracer| tracer.value(&self.which_groups))?;
+            tracer.field("groups", |tracer| tracer.value(&self.groups))?;
+            tracer.field("whichMods", |tracer| tracer.value(&self.which_mods))?;
+            tracer.field("mods", |tracer| tracer.bitmask(&self.mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("realMods", |tracer| tracer.bitmask(&self.real_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("vmods", |tracer| tracer.bitmask(&self.vmods, VMod::VARIANTS))?;
+            tracer.field("ctrls", |tracer| tracer.bitmask(&self.ctrls, BoolCtrl::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CMDetail(u8);
+impl CMDetail {
+    pub const SYM_INTERP: Self = Self(1 << 0);
+    pub const GROUP_COMPAT: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "SYM_INTERP", "SymInterp"),
+        (1 << 1, "GROUP_COMPAT", "GroupCompat"),
+    ];
+}
+impl From<CMDetail> for u8 {
+    #[inline]
+    fn from(input: CMDetail) -> Self {
+        input.0
+    }
+}
+impl From<CMDetail> for Option<u8> {
+    #[inline]
+    fn from(input: CMDetail) -> Self {
+        Some(input.0)
+    }
+}
+impl From<CMDetail> for u16 {
+    #[inline]
+    fn from(input: CMDetail) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<CMDetail> for Option<u16> {
+    #[inline]
+    fn from(input: CMDetail) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<CMDetail> for u32 {
+    #[inline]
+    fn from(input: CMDetail) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<CMDetail> for Option<u32> {
+    #[inline]
+    fn from(input: CMDetail) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for CMDetail {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for CMDetail  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for CMDetail {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for CMDetail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CMDetail {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(CMDetail, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NameDetail(u16);
+impl NameDetail {
+    pub const KEYCODES: Self = Self(1 << 0);
+    pub const GEOMETRY: Self = Self(1 << 1);
+    pub const SYMBOLS: Self = Self(1 << 2);
+    pub const PHYS_SYMBOLS: Self = Self(1 << 3);
+    pub const TYPES: Self = Self(1 << 4);
+    pub const COMPAT: Self = Self(1 << 5);
+    pub const KEY_TYPE_NAMES: Self = Self(1 << 6);
+    pub const KT_LEVEL_NAMES: Self = Self(1 << 7);
+    pub const INDICATOR_NAMES: Self = Self(1 << 8);
+    pub const KEY_NAMES: Self = Self(1 << 9);
+    pub const KEY_ALIASES: Self = Self(1 << 10);
+    pub const VIRTUAL_MOD_NAMES: Self = Self(1 << 11);
+    pub const GROUP_NAMES: Self = Self(1 << 12);
+    pub const RG_NAMES: Self = Self(1 << 13);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "KEYCODES", "Keycodes"),
+        (1 << 1, "GEOMETRY", "Geometry"),
+        (1 << 2, "SYMBOLS", "Symbols"),
+        (1 << 3, "PHYS_SYMBOLS", "PhysSymbols"),
+        (1 << 4, "TYPES", "Types"),
+        (1 << 5, "COMPAT", "Compat"),
+        (1 << 6, "KEY_TYPE_NAMES", "KeyTypeNames"),
+        (1 << 7, "KT_LEVEL_NAMES", "KTLevelNames"),
+        (1 << 8, "INDICATOR_NAMES", "IndicatorNames"),
+        (1 << 9, "KEY_NAMES", "KeyNames"),
+        (1 << 10, "KEY_ALIASES", "KeyAliases"),
+        (1 << 11, "VIRTUAL_MOD_NAMES", "VirtualModNames"),
+        (1 << 12, "GROUP_NAMES", "GroupNames"),
+        (1 << 13, "RG_NAMES", "RGNames"),
+    ];
+}
+impl From<NameDetail> for u16 {
+    #[inline]
+    fn from(input: NameDetail) -> Self {
+        input.0
+    }
+}
+impl From<NameDetail> for Option<u16> {
+    #[inline]
+    fn from(input: NameDetail) -> Self {
+        Some(input.0)
+    }
+}
+impl From<NameDetail> for u32 {
+    #[inline]
+    fn from(input: NameDetail) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<NameDetail> for Option<u32> {
+    #[inline]
+    fn from(input: NameDetail) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for NameDetail {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value.into())
+    }
+}
+impl From<u16> for NameDetail {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for NameDetail  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for NameDetail {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for NameDetail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NameDetail {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(NameDetail, u16);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GBNDetail(u8);
+impl GBNDetail {
+    pub const TYPES: Self = Self(1 << 0);
+    pub const COMPAT_MAP: Self = Self(1 << 1);
+    pub const CLIENT_SYMBOLS: Self = Self(1 << 2);
+    pub const SERVER_SYMBOLS: Self = Self(1 << 3);
+    pub const INDICATOR_MAPS: Self = Self(1 << 4);
+    pub const KEY_NAMES: Self = Self(1 << 5);
+    pub const GEOMETRY: Self = Self(1 << 6);
+    pub const OTHER_NAMES: Self = Self(1 << 7);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "TYPES", "Types"),
+        (1 << 1, "COMPAT_MAP", "CompatMap"),
+        (1 << 2, "CLIENT_SYMBOLS", "ClientSymbols"),
+        (1 << 3, "SERVER_SYMBOLS", "ServerSymbols"),
+        (1 << 4, "INDICATOR_MAPS", "IndicatorMaps"),
+        (1 << 5, "KEY_NAMES", "KeyNames"),
+        (1 << 6, "GEOMETRY", "Geometry"),
+        (1 << 7, "OTHER_NAMES", "OtherNames"),
+    ];
+}
+impl From<GBNDetail> for u8 {
+    #[inline]
+    fn from(input: GBNDetail) -> Self {
+        input.0
+    }
+}
+impl From<GBNDetail> for Option<u8> {
+    #[inline]
+    fn from(input: GBNDetail) -> Self {
+        Some(input.0)
+    }
+}
+impl From<GBNDetail> for u16 {
+    #[inline]
+    fn from(input: GBNDetail) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<GBNDetail> for Option<u16> {
+    #[inline]
+    fn from(input: GBNDetail) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<GBNDetail> for u32 {
+    #[inline]
+    fn from(input: GBNDetail) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<GBNDetail> for Option<u32> {
+    #[inline]
+    fn from(input: GBNDetail) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for GBNDetail {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for GBNDetail  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for GBNDetail {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for GBNDetail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GBNDetail {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(GBNDetail, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct XIFeature(u8);
+impl XIFeature {
+    pub const KEYBOARDS: Self = Self(1 << 0);
+    pub const BUTTON_ACTIONS: Self = Self(1 << 1);
+    pub const INDICATOR_NAMES: Self = Self(1 << 2);
+    pub const INDICATOR_MAPS: Self = Self(1 << 3);
+    pub const INDICATOR_STATE: Self = Self(1 << 4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "KEYBOARDS", "Keyboards"),
+        (1 << 1, "BUTTON_ACTIONS", "ButtonActions"),
+        (1 << 2, "INDICATOR_NAMES", "IndicatorNames"),
+        (1 << 3, "INDICATOR_MAPS", "IndicatorMaps"),
+        (1 << 4, "INDICATOR_STATE", "IndicatorState"),
+    ];
+}
+impl From<XIFeature> for u8 {
+    #[inline]
+    fn from(input: XIFeature) -> Self {
+        input.0
+    }
+}
+impl From<XIFeature> for Option<u8> {
+    #[inline]
+    fn from(input: XIFeature) -> Self {
+        Some(input.0)
+    }
+}
+impl From<XIFeature> for u16 {
+    #[inline]
+    fn from(input: XIFeature) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<XIFeature> for Option<u16> {
+    #[inline]
+    fn from(input: XIFeature) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<XIFeature> for u32 {
+    #[inline]
+    fn from(input: XIFeature) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<XIFeature> for Option<u32> {
+    #[inline]
+    fn from(input: XIFeature) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for XIFeature {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for XIFeature  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for XIFeature {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for XIFeature {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for XIFeature {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(XIFeature, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PerClientFlag(u8);
+impl PerClientFlag {
+    pub const DETECTABLE_AUTO_REPEAT: Self = Self(1 << 0);
+    pub const GRABS_USE_XKB_STATE: Self = Self(1 << 1);
+    pub const AUTO_RESET_CONTROLS: Self = Self(1 << 2);
+    pub const LOOKUP_STATE_WHEN_GRABBED: Self = Self(1 << 3);
+    pub const SEND_EVENT_USES_XKB_STATE: Self = Self(1 << 4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "DETECTABLE_AUTO_REPEAT", "DetectableAutoRepeat"),
+        (1 << 1, "GRABS_USE_XKB_STATE", "GrabsUseXKBState"),
+        (1 << 2, "AUTO_RESET_CONTROLS", "AutoResetControls"),
+        (1 << 3, "LOOKUP_STATE_WHEN_GRABBED", "LookupStateWhenGrabbed"),
+        (1 << 4, "SEND_EVENT_USES_XKB_STATE", "SendEventUsesXKBState"),
+    ];
+}
+impl From<PerClientFlag> for u8 {
+    #[inline]
+    fn from(input: PerClientFlag) -> Self {
+        input.0
+    }
+}
+impl From<PerClientFlag> for Option<u8> {
+    #[inline]
+    fn from(input: PerClientFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<PerClientFlag> for u16 {
+    #[inline]
+    fn from(input: PerClientFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<PerClientFlag> for Option<u16> {
+    #[inline]
+    fn from(input: PerClientFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<PerClientFlag> for u32 {
+    #[inline]
+    fn from(input: PerClientFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<PerClientFlag> for Option<u32> {
+    #[inline]
+    fn from(input: PerClientFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for PerClientFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for PerClientFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for PerClientFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for PerClientFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PerClientFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(PerClientFlag, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModDef {
+    pub mask: u8,
+    pub real_mods: u8,
+    pub vmods: u16,
+}
+impl TryParse for ModDef {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = ModDef { mask, real_mods, vmods };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ModDef {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ModDef {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let mask_bytes = self.mask.serialize_with_order(order);
+        let real_mods_bytes = self.real_mods.serialize_with_order(order);
+        let vmods_bytes = self.vmods.serialize_with_order(order);
+        [
+            mask_bytes[0],
+            real_mods_bytes[0],
+            vmods_bytes[0],
+            vmods_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.mask.serialize_into_with_order(bytes, order);
+        self.real_mods.serialize_into_with_order(bytes, order);
+        self.vmods.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for ModDef {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("mask", |tracer| tracer.bitmask(&self.mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("realMods", |tracer| tracer.bitmask(&self.real_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("vmods", |tracer| tracer.bitmask(&self.vmods, VMod::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyName {
+    pub name: [u8; 4],
+}
+impl TryParse for KeyName {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let _ = order;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let name = <[u8; 4]>::try_from(name).unwrap();
+        let result = KeyName { name };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KeyName {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KeyName {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let _ = order;
+        [
+            self.name[0],
+            self.name[1],
+            self.name[2],
+            self.name[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let _ = order;
+        bytes.reserve(4);
+        bytes.extend_from_slice(&self.name);
+    }
+}
+impl Trace for KeyName {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("name", |tracer| tracer.string(&self.name))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyAlias {
+    pub real: [u8; 4],
+    pub alias: [u8; 4],
+}
+impl TryParse for KeyAlias {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let _ = order;
+        let (real, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let real = <[u8; 4]>::try_from(real).unwrap();
+        let (alias, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let alias = <[u8; 4]>::try_from(alias).unwrap();
+        let result = KeyAlias { real, alias };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KeyAlias {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KeyAlias {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let _ = order;
+        [
+            self.real[0],
+            self.real[1],
+            self.real[2],
+            self.real[3],
+            self.alias[0],
+            self.alias[1],
+            self.alias[2],
+            self.alias[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let _ = order;
+        bytes.reserve(8);
+        bytes.extend_from_slice(&self.real);
+        bytes.extend_from_slice(&self.alias);
+    }
+}
+impl Trace for KeyAlias {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("real", |tracer| tracer.string(&self.real))?;
+            tracer.field("alias", |tracer| tracer.string(&self.alias))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CountedString16 {
+    pub string: Vec<u8>,
+    pub alignment_pad: Vec<u8>,
+}
+impl TryParse for CountedString16 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (length, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let string = string.to_vec();
+        let (alignment_pad, remaining) = crate::x11_utils::parse_u8_list(remaining, (u32::from(length).checked_add(5u32).ok_or(ParseError::InvalidExpression)? & (!3u32)).checked_sub(u32::from(length).checked_add(2u32).ok_or(ParseError::InvalidExpression)?).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let alignment_pad = alignment_pad.to_vec();
+        let result = CountedString16 { string, alignment_pad };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CountedString16 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for CountedString16 {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let length = u16::try_from(self.string.len()).expect("`string` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.string);
+        assert_eq!(self.alignment_pad.len(), usize::try_from((u32::from(length).checked_add(5u32).unwrap() & (!3u32)).checked_sub(u32::from(length).checked_add(2u32).unwrap()).unwrap()).unwrap(), "`alignment_pad` has an incorrect length");
+        bytes.extend_from_slice(&self.alignment_pad);
+    }
+}
+impl CountedString16 {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `string` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u16 {
+        self.string.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for CountedString16 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("string", |tracer| tracer.string(&self.string))?;
+            tracer.field("alignment-pad", |tracer| tracer.list(&self.alignment_pad, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KTMapEntry {
+    pub active: bool,
+    pub mods_mask: u8,
+    pub level: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16,
+}
+impl TryParse for KTMapEntry {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (active, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let result = KTMapEntry { active, mods_mask, level, mods_mods, mods_vmods };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KTMapEntry {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KTMapEntry {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let active_bytes = self.active.serialize_with_order(order);
+        let mods_mask_bytes = self.mods_mask.serialize_with_order(order);
+        let level_bytes = self.level.serialize_with_order(order);
+        let mods_mods_bytes = self.mods_mods.serialize_with_order(order);
+        let mods_vmods_bytes = self.mods_vmods.serialize_with_order(order);
+        [
+            active_bytes[0],
+            mods_mask_bytes[0],
+            level_bytes[0],
+            mods_mods_bytes[0],
+            mods_vmods_bytes[0],
+            mods_vmods_bytes[1],
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.active.serialize_into_with_order(bytes, order);
+        self.mods_mask.serialize_into_with_order(bytes, order);
+        self.level.serialize_into_with_order(bytes, order);
+        self.mods_mods.serialize_into_with_order(bytes, order);
+        self.mods_vmods.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+    }
+}
+impl Trace for KTMapEntry {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("active", |tracer| tracer.value(&self.active))?;
+            tracer.field("mods-mask", |tracer| tracer.bitmask(&self.mods_mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("level", |tracer| tracer.value(&self.level))?;
+            tracer.field("mods-mods", |tracer| tracer.bitmask(&self.mods_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("mods-vmods", |tracer| tracer.bitmask(&self.mods_vmods, VMod::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyType {
+    pub mods_mask: u8,
+    pub mods_mods: u8,
+    pub mods_vmods: u16,
+    pub num_levels: u8,
+    pub has_preserve: bool,
+    pub map: Vec<KTMapEntry>,
+    pub preserve: Vec<ModDef>,
+}
+impl TryParse for KeyType {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_levels, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_map_entries, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (has_preserve, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (map, remaining) = crate::x11_utils::parse_list_with_order::<KTMapEntry>(remaining, n_map_entries.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (preserve, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, u32::from(has_preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = KeyType { mods_mask, mods_mods, mods_vmods, num_levels, has_preserve, map, preserve };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KeyType {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KeyType {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.mods_mask.serialize_into_with_order(bytes, order);
+        self.mods_mods.serialize_into_with_order(bytes, order);
+        self.mods_vmods.serialize_into_with_order(bytes, order);
+        self.num_levels.serialize_into_with_order(bytes, order);
+        let n_map_entries = u8::try_from(self.map.len()).expect("`map` has too many elements");
+        n_map_entries.serialize_into_with_order(bytes, order);
+        self.has_preserve.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.map.serialize_into_with_order(bytes, order);
+        assert_eq!(self.preserve.len(), usize::try_from(u32::from(self.has_preserve).checked_mul(u32::from(n_map_entries)).unwrap()).unwrap(), "`preserve` has an incorrect length");
+        self.preserve.serialize_into_with_order(bytes, order);
+    }
+}
+impl KeyType {
+    /// Get the value of the `nMapEntries` field.
+    ///
+    /// The `nMapEntries` field is used as the length field of the `map` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_map_entries(&self) -> u8 {
+        self.map.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for KeyType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("mods-mask", |tracer| tracer.bitmask(&self.mods_mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("mods-mods", |tracer| tracer.bitmask(&self.mods_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("mods-vmods", |tracer| tracer.bitmask(&self.mods_vmods, VMod::VARIANTS))?;
+            tracer.field("numLevels", |tracer| tracer.value(&self.num_levels))?;
+            tracer.field("hasPreserve", |tracer| tracer.value(&self.has_preserve))?;
+            tracer.field("map", |tracer| tracer.list(&self.map, |tracer, value| tracer.value(value)))?;
+            tracer.field("preserve", |tracer| tracer.list(&self.preserve, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeySymMap {
+    pub kt_index: [u8; 4],
+    pub group_info: u8,
+    pub width: u8,
+    pub syms: Vec<xproto::Keysym>,
+}
+impl TryParse for KeySymMap {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (kt_index, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let kt_index = <[u8; 4]>::try_from(kt_index).unwrap();
+        let (group_info, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (syms, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Keysym>(remaining, n_syms.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = KeySymMap { kt_index, group_info, width, syms };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KeySymMap {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KeySymMap {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        bytes.extend_from_slice(&self.kt_index);
+        self.group_info.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        let n_syms = u16::try_from(self.syms.len()).expect("`syms` has too many elements");
+        n_syms.serialize_into_with_order(bytes, order);
+        self.syms.serialize_into_with_order(bytes, order);
+    }
+}
+impl KeySymMap {
+    /// Get the value of the `nSyms` field.
+    ///
+    /// The `nSyms` field is used as the length field of the `syms` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_syms(&self) -> u16 {
+        self.syms.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for KeySymMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("kt-index", |tracer| tracer.list(&self.kt_index, |tracer, value| tracer.value(value)))?;
+            tracer.field("groupInfo", |tracer| tracer.value(&self.group_info))?;
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("syms", |tracer| tracer.list(&self.syms, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommonBehavior {
+    pub type_: u8,
+    pub data: u8,
+}
+impl TryParse for CommonBehavior {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = CommonBehavior { type_, data };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CommonBehavior {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for CommonBehavior {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        let data_bytes = self.data.serialize_with_order(order);
+        [
+            type_bytes[0],
+            data_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.data.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for CommonBehavior {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("data", |tracer| tracer.value(&self.data))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefaultBehavior {
+    pub type_: u8,
+}
+impl TryParse for DefaultBehavior {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let result = DefaultBehavior { type_ };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for DefaultBehavior {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for DefaultBehavior {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        [
+            type_bytes[0],
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.type_.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+    }
+}
+impl Trace for DefaultBehavior {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            Ok(())
+        })
+    }
+}
+
+pub type LockBehavior = DefaultBehavior;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadioGroupBehavior {
+    pub type_: u8,
+    pub group: u8,
+}
+impl TryParse for RadioGroupBehavior {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = RadioGroupBehavior { type_, group };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for RadioGroupBehavior {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for RadioGroupBehavior {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        let group_bytes = self.group.serialize_with_order(order);
+        [
+            type_bytes[0],
+            group_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for RadioGroupBehavior {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("group", |tracer| tracer.value(&self.group))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlayBehavior {
+    pub type_: u8,
+    pub key: xproto::Keycode,
+}
+impl TryParse for OverlayBehavior {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let result = OverlayBehavior { type_, key };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for OverlayBehavior {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for OverlayBehavior {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let type_bytes = self.type_.serialize_with_order(order);
+        let key_bytes = self.key.serialize_with_order(order);
+        [
+            type_bytes[0],
+            key_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.type_.serialize_into_with_order(bytes, order);
+        self.key.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for OverlayBehavior {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("key", |tracer| tracer.value(&self.key))?;
+            Ok(())
+        })
+    }
+}
+
+pub type PermamentLockBehavior = LockBehavior;
+
+pub type PermamentRadioGroupBehavior = RadioGroupBehavior;
+
+pub type PermamentOverlayBehavior = OverlayBehavior;
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Behavior([u8; 2]);
+impl Trace for Behavior {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.list(&self.0, |tracer, value| tracer.value(value))
+    }
+}
+impl Behavior {
+    pub fn as_common(&self) -> CommonBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<CommonBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (common, remaining) = CommonBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(common)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_default(&self) -> DefaultBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<DefaultBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (default, remaining) = DefaultBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(default)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lock(&self) -> LockBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<LockBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (lock, remaining) = LockBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lock)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_radio_group(&self) -> RadioGroupBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<RadioGroupBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (radio_group, remaining) = RadioGroupBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(radio_group)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_overlay1(&self) -> OverlayBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<OverlayBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (overlay1, remaining) = OverlayBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(overlay1)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_overlay2(&self) -> OverlayBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<OverlayBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (overlay2, remaining) = OverlayBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(overlay2)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_permament_lock(&self) -> PermamentLockBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<PermamentLockBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (permament_lock, remaining) = PermamentLockBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(permament_lock)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_permament_radio_group(&self) -> PermamentRadioGroupBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<PermamentRadioGroupBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (permament_radio_group, remaining) = PermamentRadioGroupBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(permament_radio_group)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_permament_overlay1(&self) -> PermamentOverlayBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<PermamentOverlayBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (permament_overlay1, remaining) = PermamentOverlayBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(permament_overlay1)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_permament_overlay2(&self) -> PermamentOverlayBehavior {
+        fn do_the_parse(remaining: &[u8]) -> Result<PermamentOverlayBehavior, ParseError> {
+            let order = ByteOrder::native();
+            let (permament_overlay2, remaining) = PermamentOverlayBehavior::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(permament_overlay2)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_type(&self) -> u8 {
+        fn do_the_parse(remaining: &[u8]) -> Result<u8, ParseError> {
+            let order = ByteOrder::native();
+            let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(type_)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+}
+impl Serialize for Behavior {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.0
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.0);
+    }
+    fn serialize_with_order(&self, _: ByteOrder) -> [u8; 2] {
+        self.0
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _: ByteOrder) {
+        bytes.extend_from_slice(&self.0);
+    }
+}
+impl TryParse for Behavior {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let inner: [u8; 2] = value.get(..2)
+            .ok_or(ParseError::InsufficientData)?
+            .try_into()
+            .unwrap();
+        let result = Behavior(inner);
+        Ok((result, &value[2..]))
+    }
+    fn try_parse_with_order(value: &[u8], _: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse(value)
+    }
+}
+impl From<CommonBehavior> for Behavior {
+    fn from(common: CommonBehavior) -> Self {
+        let order = ByteOrder::native();
+        let common_bytes = common.serialize_with_order(order);
+        Self(common_bytes)
+    }
+}
+impl From<DefaultBehavior> for Behavior {
+    fn from(default: DefaultBehavior) -> Self {
+        let order = ByteOrder::native();
+        let default_bytes = default.serialize_with_order(order);
+        Self(default_bytes)
+    }
+}
+impl From<RadioGroupBehavior> for Behavior {
+    fn from(radio_group: RadioGroupBehavior) -> Self {
+        let order = ByteOrder::native();
+        let radio_group_bytes = radio_group.serialize_with_order(order);
+        Self(radio_group_bytes)
+    }
+}
+impl From<OverlayBehavior> for Behavior {
+    fn from(overlay1: OverlayBehavior) -> Self {
+        let order = ByteOrder::native();
+        let overlay1_bytes = overlay1.serialize_with_order(order);
+        Self(overlay1_bytes)
+    }
+}
+impl From<u8> for Behavior {
+    fn from(type_: u8) -> Self {
+        let order = ByteOrder::native();
+        let type_bytes = type_.serialize_with_order(order);
+        let value = [
+            type_bytes[0],
+            0,
+        ];
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BehaviorType(u8);
+impl BehaviorType {
+    pub const DEFAULT: Self = Self(0);
+    pub const LOCK: Self = Self(1);
+    pub const RADIO_GROUP: Self = Self(2);
+    pub const OVERLAY1: Self = Self(3);
+    pub const OVERLAY2: Self = Self(4);
+    pub const PERMAMENT_LOCK: Self = Self(129);
+    pub const PERMAMENT_RADIO_GROUP: Self = Self(130);
+    pub const PERMAMENT_OVERLAY1: Self = Self(131);
+    pub const PERMAMENT_OVERLAY2: Self = Self(132);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "DEFAULT", "Default"),
+        (1, "LOCK", "Lock"),
+        (2, "RADIO_GROUP", "RadioGroup"),
+        (3, "OVERLAY1", "Overlay1"),
+        (4, "OVERLAY2", "Overlay2"),
+        (129, "PERMAMENT_LOCK", "PermamentLock"),
+        (130, "PERMAMENT_RADIO_GROUP", "PermamentRadioGroup"),
+        (131, "PERMAMENT_OVERLAY1", "PermamentOverlay1"),
+        (132, "PERMAMENT_OVERLAY2", "PermamentOverlay2"),
+    ];
+}
+impl From<BehaviorType> for u8 {
+    #[inline]
+    fn from(input: BehaviorType) -> Self {
+        input.0
+    }
+}
+impl From<BehaviorType> for Option<u8> {
+    #[inline]
+    fn from(input: BehaviorType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BehaviorType> for u16 {
+    #[inline]
+    fn from(input: BehaviorType) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<BehaviorType> for Option<u16> {
+    #[inline]
+    fn from(input: BehaviorType) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<BehaviorType> for u32 {
+    #[inline]
+    fn from(input: BehaviorType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BehaviorType> for Option<u32> {
+    #[inline]
+    fn from(input: BehaviorType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BehaviorType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BehaviorType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BehaviorType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BehaviorType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BehaviorType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetBehavior {
+    pub keycode: xproto::Keycode,
+    pub behavior: Behavior,
+}
+impl TryParse for SetBehavior {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (behavior, remaining) = Behavior::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let result = SetBehavior { keycode, behavior };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetBehavior {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SetBehavior {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let behavior_bytes = self.behavior.serialize_with_order(order);
+        [
+            keycode_bytes[0],
+            behavior_bytes[0],
+            behavior_bytes[1],
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.keycode.serialize_into_with_order(bytes, order);
+        self.behavior.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+    }
+}
+impl Trace for SetBehavior {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+            tracer.field("behavior", |tracer| tracer.value(&self.behavior))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetExplicit {
+    pub keycode: xproto::Keycode,
+    pub explicit: u8,
+}
+impl TryParse for SetExplicit {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = SetExplicit { keycode, explicit };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetExplicit {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SetExplicit {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let explicit_bytes = self.explicit.serialize_with_order(order);
+        [
+            keycode_bytes[0],
+            explicit_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.keycode.serialize_into_with_order(bytes, order);
+        self.explicit.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SetExplicit {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+            tracer.field("explicit", |tracer| tracer.bitmask(&self.explicit, Explicit::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyModMap {
+    pub keycode: xproto::Keycode,
+    pub mods: u8,
+}
+impl TryParse for KeyModMap {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = KeyModMap { keycode, mods };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KeyModMap {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KeyModMap {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let mods_bytes = self.mods.serialize_with_order(order);
+        [
+            keycode_bytes[0],
+            mods_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.keycode.serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for KeyModMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+            tracer.field("mods", |tracer| tracer.bitmask(&self.mods, xproto::ModMask::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyVModMap {
+    pub keycode: xproto::Keycode,
+    pub vmods: u16,
+}
+impl TryParse for KeyVModMap {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = KeyVModMap { keycode, vmods };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KeyVModMap {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KeyVModMap {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let vmods_bytes = self.vmods.serialize_with_order(order);
+        [
+            keycode_bytes[0],
+            0,
+            vmods_bytes[0],
+            vmods_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.keycode.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.vmods.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for KeyVModMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+            tracer.field("vmods", |tracer| tracer.bitmask(&self.vmods, VMod::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KTSetMapEntry {
+    pub level: u8,
+    pub real_mods: u8,
+    pub virtual_mods: u16,
+}
+impl TryParse for KTSetMapEntry {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (level, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = KTSetMapEntry { level, real_mods, virtual_mods };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for KTSetMapEntry {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for KTSetMapEntry {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let level_bytes = self.level.serialize_with_order(order);
+        let real_mods_bytes = self.real_mods.serialize_with_order(order);
+        let virtual_mods_bytes = self.virtual_mods.serialize_with_order(order);
+        [
+            level_bytes[0],
+            real_mods_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.level.serialize_into_with_order(bytes, order);
+        self.real_mods.serialize_into_with_order(bytes, order);
+        self.virtual_mods.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for KTSetMapEntry {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("level", |tracer| tracer.value(&self.level))?;
+            tracer.field("realMods", |tracer| tracer.bitmask(&self.real_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetKeyType {
+    pub mask: u8,
+    pub real_mods: u8,
+    pub virtual_mods: u16,
+    pub num_levels: u8,
+    pub preserve: bool,
+    pub entries: Vec<KTSetMapEntry>,
+    pub preserve_entries: Vec<KTSetMapEntry>,
+}
+impl TryParse for SetKeyType {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_levels, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_map_entries, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (preserve, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (entries, remaining) = crate::x11_utils::parse_list_with_order::<KTSetMapEntry>(remaining, n_map_entries.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (preserve_entries, remaining) = crate::x11_utils::parse_list_with_order::<KTSetMapEntry>(remaining, u32::from(preserve).checked_mul(u32::from(n_map_entries)).ok_or(ParseError::InvalidExpression)?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = SetKeyType { mask, real_mods, virtual_mods, num_levels, preserve, entries, preserve_entries };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetKeyType {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SetKeyType {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.mask.serialize_into_with_order(bytes, order);
+        self.real_mods.serialize_into_with_order(bytes, order);
+        self.virtual_mods.serialize_into_with_order(bytes, order);
+        self.num_levels.serialize_into_with_order(bytes, order);
+        let n_map_entries = u8::try_from(self.entries.len()).expect("`entries` has too many elements");
+        n_map_entries.serialize_into_with_order(bytes, order);
+        self.preserve.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.entries.serialize_into_with_order(bytes, order);
+        assert_eq!(self.preserve_entries.len(), usize::try_from(u32::from(self.preserve).checked_mul(u32::from(n_map_entries)).unwrap()).unwrap(), "`preserve_entries` has an incorrect length");
+        self.preserve_entries.serialize_into_with_order(bytes, order);
+    }
+}
+impl SetKeyType {
+    /// Get the value of the `nMapEntries` field.
+    ///
+    /// The `nMapEntries` field is used as the length field of the `entries` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_map_entries(&self) -> u8 {
+        self.entries.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for SetKeyType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("mask", |tracer| tracer.bitmask(&self.mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("realMods", |tracer| tracer.bitmask(&self.real_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+            tracer.field("numLevels", |tracer| tracer.value(&self.num_levels))?;
+            tracer.field("preserve", |tracer| tracer.value(&self.preserve))?;
+            tracer.field("entries", |tracer| tracer.list(&self.entries, |tracer, value| tracer.value(value)))?;
+            tracer.field("preserve-entries", |tracer| tracer.list(&self.preserve_entries, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+pub type String8 = u8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Outline {
+    pub corner_radius: u8,
+    pub points: Vec<xproto::Point>,
+}
+impl TryParse for Outline {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (n_points, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (corner_radius, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (points, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Point>(remaining, n_points.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = Outline { corner_radius, points };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Outline {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Outline {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        let n_points = u8::try_from(self.points.len()).expect("`points` has too many elements");
+        n_points.serialize_into_with_order(bytes, order);
+        self.corner_radius.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.points.serialize_into_with_order(bytes, order);
+    }
+}
+impl Outline {
+    /// Get the value of the `nPoints` field.
+    ///
+    /// The `nPoints` field is used as the length field of the `points` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_points(&self) -> u8 {
+        self.points.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Outline {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("cornerRadius", |tracer| tracer.value(&self.corner_radius))?;
+            tracer.field("points", |tracer| tracer.list(&self.points, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shape {
+    pub name: xproto::Atom,
+    pub primary_ndx: u8,
+    pub approx_ndx: u8,
+    pub outlines: Vec<Outline>,
+}
+impl TryParse for Shape {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (n_outlines, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (primary_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (approx_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (outlines, remaining) = crate::x11_utils::parse_list_with_order::<Outline>(remaining, n_outlines.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = Shape { name, primary_ndx, approx_ndx, outlines };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Shape {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Shape {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.name.serialize_into_with_order(bytes, order);
+        let n_outlines = u8::try_from(self.outlines.len()).expect("`outlines` has too many elements");
+        n_outlines.serialize_into_with_order(bytes, order);
+        self.primary_ndx.serialize_into_with_order(bytes, order);
+        self.approx_ndx.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.outlines.serialize_into_with_order(bytes, order);
+    }
+}
+impl Shape {
+    /// Get the value of the `nOutlines` field.
+    ///
+    /// The `nOutlines` field is used as the length field of the `outlines` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_outlines(&self) -> u8 {
+        self.outlines.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Shape {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("name", |tracer| tracer.atom(&self.name))?;
+            tracer.field("primaryNdx", |tracer| tracer.value(&self.primary_ndx))?;
+            tracer.field("approxNdx", |tracer| tracer.value(&self.approx_ndx))?;
+            tracer.field("outlines", |tracer| tracer.list(&self.outlines, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Key {
+    pub name: [String8; 4],
+    pub gap: i16,
+    pub shape_ndx: u8,
+    pub color_ndx: u8,
+}
+impl TryParse for Key {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let name = <[u8; 4]>::try_from(name).unwrap();
+        let (gap, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (shape_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (color_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = Key { name, gap, shape_ndx, color_ndx };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Key {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Key {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let gap_bytes = self.gap.serialize_with_order(order);
+        let shape_ndx_bytes = self.shape_ndx.serialize_with_order(order);
+        let color_ndx_bytes = self.color_ndx.serialize_with_order(order);
+        [
+            self.name[0],
+            self.name[1],
+            self.name[2],
+            self.name[3],
+            gap_bytes[0],
+            gap_bytes[1],
+            shape_ndx_bytes[0],
+            color_ndx_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        bytes.extend_from_slice(&self.name);
+        self.gap.serialize_into_with_order(bytes, order);
+        self.shape_ndx.serialize_into_with_order(bytes, order);
+        self.color_ndx.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Key {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("name", |tracer| tracer.string(&self.name))?;
+            tracer.field("gap", |tracer| tracer.value(&self.gap))?;
+            tracer.field("shapeNdx", |tracer| tracer.value(&self.shape_ndx))?;
+            tracer.field("colorNdx", |tracer| tracer.value(&self.color_ndx))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlayKey {
+    pub over: [String8; 4],
+    pub under: [String8; 4],
+}
+impl TryParse for OverlayKey {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let _ = order;
+        let (over, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let over = <[u8; 4]>::try_from(over).unwrap();
+        let (under, remaining) = crate::x11_utils::parse_u8_list(remaining, 4)?;
+        let under = <[u8; 4]>::try_from(under).unwrap();
+        let result = OverlayKey { over, under };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for OverlayKey {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for OverlayKey {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let _ = order;
+        [
+            self.over[0],
+            self.over[1],
+            self.over[2],
+            self.over[3],
+            self.under[0],
+            self.under[1],
+            self.under[2],
+            self.under[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let _ = order;
+        bytes.reserve(8);
+        bytes.extend_from_slice(&self.over);
+        bytes.extend_from_slice(&self.under);
+    }
+}
+impl Trace for OverlayKey {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("over", |tracer| tracer.string(&self.over))?;
+            tracer.field("under", |tracer| tracer.string(&self.under))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlayRow {
+    pub row_under: u8,
+    pub keys: Vec<OverlayKey>,
+}
+impl TryParse for OverlayRow {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (row_under, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (keys, remaining) = crate::x11_utils::parse_list_with_order::<OverlayKey>(remaining, n_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = OverlayRow { row_under, keys };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for OverlayRow {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for OverlayRow {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.row_under.serialize_into_with_order(bytes, order);
+        let n_keys = u8::try_from(self.keys.len()).expect("`keys` has too many elements");
+        n_keys.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.keys.serialize_into_with_order(bytes, order);
+    }
+}
+impl OverlayRow {
+    /// Get the value of the `nKeys` field.
+    ///
+    /// The `nKeys` field is used as the length field of the `keys` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_keys(&self) -> u8 {
+        self.keys.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for OverlayRow {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("rowUnder", |tracer| tracer.value(&self.row_under))?;
+            tracer.field("keys", |tracer| tracer.list(&self.keys, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Overlay {
+    pub name: xproto::Atom,
+    pub rows: Vec<OverlayRow>,
+}
+impl TryParse for Overlay {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (n_rows, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (rows, remaining) = crate::x11_utils::parse_list_with_order::<OverlayRow>(remaining, n_rows.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = Overlay { name, rows };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Overlay {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Overlay {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.name.serialize_into_with_order(bytes, order);
+        let n_rows = u8::try_from(self.rows.len()).expect("`rows` has too many elements");
+        n_rows.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        self.rows.serialize_into_with_order(bytes, order);
+    }
+}
+impl Overlay {
+    /// Get the value of the `nRows` field.
+    ///
+    /// The `nRows` field is used as the length field of the `rows` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_rows(&self) -> u8 {
+        self.rows.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Overlay {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("name", |tracer| tracer.atom(&self.name))?;
+            tracer.field("rows", |tracer| tracer.list(&self.rows, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Row {
+    pub top: i16,
+    pub left: i16,
+    pub vertical: bool,
+    pub keys: Vec<Key>,
+}
+impl TryParse for Row {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (top, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (left, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vertical, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (keys, remaining) = crate::x11_utils::parse_list_with_order::<Key>(remaining, n_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = Row { top, left, vertical, keys };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Row {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Row {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.top.serialize_into_with_order(bytes, order);
+        self.left.serialize_into_with_order(bytes, order);
+        let n_keys = u8::try_from(self.keys.len()).expect("`keys` has too many elements");
+        n_keys.serialize_into_with_order(bytes, order);
+        self.vertical.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.keys.serialize_into_with_order(bytes, order);
+    }
+}
+impl Row {
+    /// Get the value of the `nKeys` field.
+    ///
+    /// The `nKeys` field is used as the length field of the `keys` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_keys(&self) -> u8 {
+        self.keys.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Row {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("top", |tracer| tracer.value(&self.top))?;
+            tracer.field("left", |tracer| tracer.value(&self.left))?;
+            tracer.field("vertical", |tracer| tracer.value(&self.vertical))?;
+            tracer.field("keys", |tracer| tracer.list(&self.keys, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DoodadType(u8);
+impl DoodadType {
+    pub const OUTLINE: Self = Self(1);
+    pub const SOLID: Self = Self(2);
+    pub const TEXT: Self = Self(3);
+    pub const INDICATOR: Self = Self(4);
+    pub const LOGO: Self = Self(5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1, "OUTLINE", "Outline"),
+        (2, "SOLID", "Solid"),
+        (3, "TEXT", "Text"),
+        (4, "INDICATOR", "Indicator"),
+        (5, "LOGO", "Logo"),
+    ];
+}
+impl From<DoodadType> for u8 {
+    #[inline]
+    fn from(input: DoodadType) -> Self {
+        input.0
+    }
+}
+impl From<DoodadType> for Option<u8> {
+    #[inline]
+    fn from(input: DoodadType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<DoodadType> for u16 {
+    #[inline]
+    fn from(input: DoodadType) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<DoodadType> for Option<u16> {
+    #[inline]
+    fn from(input: DoodadType) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<DoodadType> for u32 {
+    #[inline]
+    fn from(input: DoodadType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<DoodadType> for Option<u32> {
+    #[inline]
+    fn from(input: DoodadType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for DoodadType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for DoodadType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for DoodadType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for DoodadType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DoodadType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Listing {
+    pub flags: u16,
+    pub string: Vec<String8>,
+}
+impl TryParse for Listing {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (flags, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (string, remaining) = crate::x11_utils::parse_u8_list(remaining, length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let string = string.to_vec();
+        // Align offset to multiple of 2
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (2 - (offset % 2)) % 2;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let result = Listing { flags, string };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Listing {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Listing {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.flags.serialize_into_with_order(bytes, order);
+        let length = u16::try_from(self.string.len()).expect("`string` has too many elements");
+        length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.string);
+        bytes.extend_from_slice(&[0; 1][..(2 - (bytes.len() % 2)) % 2]);
+    }
+}
+impl Listing {
+    /// Get the value of the `length` field.
+    ///
+    /// The `length` field is used as the length field of the `string` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn length(&self) -> u16 {
+        self.string.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Listing {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("string", |tracer| tracer.string(&self.string))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceLedInfo {
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+    pub names_present: u32,
+    pub maps_present: u32,
+    pub phys_indicators: u32,
+    pub state: u32,
+    pub names: Vec<xproto::Atom>,
+    pub maps: Vec<IndicatorMap>,
+}
+impl TryParse for DeviceLedInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let (names_present, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maps_present, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (phys_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, names_present.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, maps_present.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let led_class = led_class.into();
+        let result = DeviceLedInfo { led_class, led_id, names_present, maps_present, phys_indicators, state, names, maps };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for DeviceLedInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for DeviceLedInfo {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(20);
+        LedClassSpec::from(self.led_class).serialize_into_with_order(bytes, order);
+        self.led_id.serialize_into_with_order(bytes, order);
+        self.names_present.serialize_into_with_order(bytes, order);
+        self.maps_present.serialize_into_with_order(bytes, order);
+        self.phys_indicators.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        assert_eq!(self.names.len(), usize::try_from(self.names_present.count_ones()).unwrap(), "`names` has an incorrect length");
+        self.names.serialize_into_with_order(bytes, order);
+        assert_eq!(self.maps.len(), usize::try_from(self.maps_present.count_ones()).unwrap(), "`maps` has an incorrect length");
+        self.maps.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for DeviceLedInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("ledClass", |tracer| tracer.value(&self.led_class))?;
+            tracer.field("ledID", |tracer| tracer.enum_value(&self.led_id, ID::VARIANTS))?;
+            tracer.field("namesPresent", |tracer| tracer.value(&self.names_present))?;
+            tracer.field("mapsPresent", |tracer| tracer.value(&self.maps_present))?;
+            tracer.field("physIndicators", |tracer| tracer.value(&self.phys_indicators))?;
+            tracer.field("state", |tracer| tracer.value(&self.state))?;
+            tracer.field("names", |tracer| tracer.list(&self.names, |tracer, value| tracer.atom(value)))?;
+            tracer.field("maps", |tracer| tracer.list(&self.maps, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Error(u8);
+impl Error {
+    pub const BAD_DEVICE: Self = Self(255);
+    pub const BAD_CLASS: Self = Self(254);
+    pub const BAD_ID: Self = Self(253);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (255, "BAD_DEVICE", "BadDevice"),
+        (254, "BAD_CLASS", "BadClass"),
+        (253, "BAD_ID", "BadId"),
+    ];
+}
+impl From<Error> for u8 {
+    #[inline]
+    fn from(input: Error) -> Self {
+        input.0
+    }
+}
+impl From<Error> for Option<u8> {
+    #[inline]
+    fn from(input: Error) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Error> for u16 {
+    #[inline]
+    fn from(input: Error) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Error> for Option<u16> {
+    #[inline]
+    fn from(input: Error) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Error> for u32 {
+    #[inline]
+    fn from(input: Error) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Error> for Option<u32> {
+    #[inline]
+    fn from(input: Error) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Error {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Error  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Error {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Error {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Error {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+/// Opcode for the Keyboard error
+pub const KEYBOARD_ERROR: u8 = 0;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SA(u8);
+impl SA {
+    pub const CLEAR_LOCKS: Self = Self(1 << 0);
+    pub const LATCH_TO_LOCK: Self = Self(1 << 1);
+    pub const USE_MOD_MAP_MODS: Self = Self(1 << 2);
+    pub const GROUP_ABSOLUTE: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "CLEAR_LOCKS", "ClearLocks"),
+        (1 << 1, "LATCH_TO_LOCK", "LatchToLock"),
+        (1 << 2, "USE_MOD_MAP_MODS", "UseModMapMods"),
+        (1 << 2, "GROUP_ABSOLUTE", "GroupAbsolute"),
+    ];
+}
+impl From<SA> for u8 {
+    #[inline]
+    fn from(input: SA) -> Self {
+        input.0
+    }
+}
+impl From<SA> for Option<u8> {
+    #[inline]
+    fn from(input: SA) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SA> for u16 {
+    #[inline]
+    fn from(input: SA) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SA> for Option<u16> {
+    #[inline]
+    fn from(input: SA) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SA> for u32 {
+    #[inline]
+    fn from(input: SA) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SA> for Option<u32> {
+    #[inline]
+    fn from(input: SA) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SA {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SA  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SA {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SA {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SA {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SA, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SAType(u8);
+impl SAType {
+    pub const NO_ACTION: Self = Self(0);
+    pub const SET_MODS: Self = Self(1);
+    pub const LATCH_MODS: Self = Self(2);
+    pub const LOCK_MODS: Self = Self(3);
+    pub const SET_GROUP: Self = Self(4);
+    pub const LATCH_GROUP: Self = Self(5);
+    pub const LOCK_GROUP: Self = Self(6);
+    pub const MOVE_PTR: Self = Self(7);
+    pub const PTR_BTN: Self = Self(8);
+    pub const LOCK_PTR_BTN: Self = Self(9);
+    pub const SET_PTR_DFLT: Self = Self(10);
+    pub const ISO_LOCK: Self = Self(11);
+    pub const TERMINATE: Self = Self(12);
+    pub const SWITCH_SCREEN: Self = Self(13);
+    pub const SET_CONTROLS: Self = Self(14);
+    pub const LOCK_CONTROLS: Self = Self(15);
+    pub const ACTION_MESSAGE: Self = Self(16);
+    pub const REDIRECT_KEY: Self = Self(17);
+    pub const DEVICE_BTN: Self = Self(18);
+    pub const LOCK_DEVICE_BTN: Self = Self(19);
+    pub const DEVICE_VALUATOR: Self = Self(20);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "NO_ACTION", "NoAction"),
+        (1, "SET_MODS", "SetMods"),
+        (2, "LATCH_MODS", "LatchMods"),
+        (3, "LOCK_MODS", "LockMods"),
+        (4, "SET_GROUP", "SetGroup"),
+        (5, "LATCH_GROUP", "LatchGroup"),
+        (6, "LOCK_GROUP", "LockGroup"),
+        (7, "MOVE_PTR", "MovePtr"),
+        (8, "PTR_BTN", "PtrBtn"),
+        (9, "LOCK_PTR_BTN", "LockPtrBtn"),
+        (10, "SET_PTR_DFLT", "SetPtrDflt"),
+        (11, "ISO_LOCK", "ISOLock"),
+        (12, "TERMINATE", "Terminate"),
+        (13, "SWITCH_SCREEN", "SwitchScreen"),
+        (14, "SET_CONTROLS", "SetControls"),
+        (15, "LOCK_CONTROLS", "LockControls"),
+        (16, "ACTION_MESSAGE", "ActionMessage"),
+        (17, "REDIRECT_KEY", "RedirectKey"),
+        (18, "DEVICE_BTN", "DeviceBtn"),
+        (19, "LOCK_DEVICE_BTN", "LockDeviceBtn"),
+        (20, "DEVICE_VALUATOR", "DeviceValuator"),
+    ];
+}
+impl From<SAType> for u8 {
+    #[inline]
+    fn from(input: SAType) -> Self {
+        input.0
+    }
+}
+impl From<SAType> for Option<u8> {
+    #[inline]
+    fn from(input: SAType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SAType> for u16 {
+    #[inline]
+    fn from(input: SAType) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SAType> for Option<u16> {
+    #[inline]
+    fn from(input: SAType) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SAType> for u32 {
+    #[inline]
+    fn from(input: SAType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SAType> for Option<u32> {
+    #[inline]
+    fn from(input: SAType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SAType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SAType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SAType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SAType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SAType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SANoAction {
+    pub type_: SAType,
+}
+impl TryParse for SANoAction {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SANoAction { type_ };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SANoAction {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SANoAction {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        [
+            type_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 7]);
+    }
+}
+impl Trace for SANoAction {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SASetMods {
+    pub type_: SAType,
+    pub flags: u8,
+    pub mask: u8,
+    pub real_mods: u8,
+    pub vmods_high: u8,
+    pub vmods_low: u8,
+}
+impl TryParse for SASetMods {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SASetMods { type_, flags, mask, real_mods, vmods_high, vmods_low };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SASetMods {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SASetMods {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let mask_bytes = self.mask.serialize_with_order(order);
+        let real_mods_bytes = self.real_mods.serialize_with_order(order);
+        let vmods_high_bytes = self.vmods_high.serialize_with_order(order);
+        let vmods_low_bytes = self.vmods_low.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            mask_bytes[0],
+            real_mods_bytes[0],
+            vmods_high_bytes[0],
+            vmods_low_bytes[0],
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.mask.serialize_into_with_order(bytes, order);
+        self.real_mods.serialize_into_with_order(bytes, order);
+        self.vmods_high.serialize_into_with_order(bytes, order);
+        self.vmods_low.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+    }
+}
+impl Trace for SASetMods {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, SA::VARIANTS))?;
+            tracer.field("mask", |tracer| tracer.bitmask(&self.mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("realMods", |tracer| tracer.bitmask(&self.real_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("vmodsHigh", |tracer| tracer.bitmask(&self.vmods_high, VModsHigh::VARIANTS))?;
+            tracer.field("vmodsLow", |tracer| tracer.bitmask(&self.vmods_low, VModsLow::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+pub type SALatchMods = SASetMods;
+
+pub type SALockMods = SASetMods;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SASetGroup {
+    pub type_: SAType,
+    pub flags: u8,
+    pub group: i8,
+}
+impl TryParse for SASetGroup {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SASetGroup { type_, flags, group };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SASetGroup {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SASetGroup {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let group_bytes = self.group.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            group_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 5]);
+    }
+}
+impl Trace for SASetGroup {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, SA::VARIANTS))?;
+            tracer.field("group", |tracer| tracer.value(&self.group))?;
+            Ok(())
+        })
+    }
+}
+
+pub type SALatchGroup = SASetGroup;
+
+pub type SALockGroup = SASetGroup;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SAMovePtrFlag(u8);
+impl SAMovePtrFlag {
+    pub const NO_ACCELERATION: Self = Self(1 << 0);
+    pub const MOVE_ABSOLUTE_X: Self = Self(1 << 1);
+    pub const MOVE_ABSOLUTE_Y: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "NO_ACCELERATION", "NoAcceleration"),
+        (1 << 1, "MOVE_ABSOLUTE_X", "MoveAbsoluteX"),
+        (1 << 2, "MOVE_ABSOLUTE_Y", "MoveAbsoluteY"),
+    ];
+}
+impl From<SAMovePtrFlag> for u8 {
+    #[inline]
+    fn from(input: SAMovePtrFlag) -> Self {
+        input.0
+    }
+}
+impl From<SAMovePtrFlag> for Option<u8> {
+    #[inline]
+    fn from(input: SAMovePtrFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SAMovePtrFlag> for u16 {
+    #[inline]
+    fn from(input: SAMovePtrFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SAMovePtrFlag> for Option<u16> {
+    #[inline]
+    fn from(input: SAMovePtrFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SAMovePtrFlag> for u32 {
+    #[inline]
+    fn from(input: SAMovePtrFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SAMovePtrFlag> for Option<u32> {
+    #[inline]
+    fn from(input: SAMovePtrFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SAMovePtrFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SAMovePtrFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SAMovePtrFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SAMovePtrFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SAMovePtrFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SAMovePtrFlag, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SAMovePtr {
+    pub type_: SAType,
+    pub flags: u8,
+    pub x_high: i8,
+    pub x_low: u8,
+    pub y_high: i8,
+    pub y_low: u8,
+}
+impl TryParse for SAMovePtr {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (x_high, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (x_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (y_high, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (y_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SAMovePtr { type_, flags, x_high, x_low, y_high, y_low };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SAMovePtr {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SAMovePtr {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let x_high_bytes = self.x_high.serialize_with_order(order);
+        let x_low_bytes = self.x_low.serialize_with_order(order);
+        let y_high_bytes = self.y_high.serialize_with_order(order);
+        let y_low_bytes = self.y_low.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            x_high_bytes[0],
+            x_low_bytes[0],
+            y_high_bytes[0],
+            y_low_bytes[0],
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.x_high.serialize_into_with_order(bytes, order);
+        self.x_low.serialize_into_with_order(bytes, order);
+        self.y_high.serialize_into_with_order(bytes, order);
+        self.y_low.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+    }
+}
+impl Trace for SAMovePtr {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, SAMovePtrFlag::VARIANTS))?;
+            tracer.field("xHigh", |tracer| tracer.value(&self.x_high))?;
+            tracer.field("xLow", |tracer| tracer.value(&self.x_low))?;
+            tracer.field("yHigh", |tracer| tracer.value(&self.y_high))?;
+            tracer.field("yLow", |tracer| tracer.value(&self.y_low))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SAPtrBtn {
+    pub type_: SAType,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+}
+impl TryParse for SAPtrBtn {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SAPtrBtn { type_, flags, count, button };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SAPtrBtn {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SAPtrBtn {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            count_bytes[0],
+            button_bytes[0],
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.count.serialize_into_with_order(bytes, order);
+        self.button.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+    }
+}
+impl Trace for SAPtrBtn {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("count", |tracer| tracer.value(&self.count))?;
+            tracer.field("button", |tracer| tracer.value(&self.button))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SALockPtrBtn {
+    pub type_: SAType,
+    pub flags: u8,
+    pub button: u8,
+}
+impl TryParse for SALockPtrBtn {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SALockPtrBtn { type_, flags, button };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SALockPtrBtn {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SALockPtrBtn {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            0,
+            button_bytes[0],
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.button.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+    }
+}
+impl Trace for SALockPtrBtn {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("button", |tracer| tracer.value(&self.button))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SASetPtrDfltFlag(u8);
+impl SASetPtrDfltFlag {
+    pub const DFLT_BTN_ABSOLUTE: Self = Self(1 << 2);
+    pub const AFFECT_DFLT_BUTTON: Self = Self(1 << 0);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 2, "DFLT_BTN_ABSOLUTE", "DfltBtnAbsolute"),
+        (1 << 0, "AFFECT_DFLT_BUTTON", "AffectDfltButton"),
+    ];
+}
+impl From<SASetPtrDfltFlag> for u8 {
+    #[inline]
+    fn from(input: SASetPtrDfltFlag) -> Self {
+        input.0
+    }
+}
+impl From<SASetPtrDfltFlag> for Option<u8> {
+    #[inline]
+    fn from(input: SASetPtrDfltFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SASetPtrDfltFlag> for u16 {
+    #[inline]
+    fn from(input: SASetPtrDfltFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SASetPtrDfltFlag> for Option<u16> {
+    #[inline]
+    fn from(input: SASetPtrDfltFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SASetPtrDfltFlag> for u32 {
+    #[inline]
+    fn from(input: SASetPtrDfltFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SASetPtrDfltFlag> for Option<u32> {
+    #[inline]
+    fn from(input: SASetPtrDfltFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SASetPtrDfltFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SASetPtrDfltFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SASetPtrDfltFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SASetPtrDfltFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SASetPtrDfltFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SASetPtrDfltFlag, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SASetPtrDflt {
+    pub type_: SAType,
+    pub flags: u8,
+    pub affect: u8,
+    pub value: i8,
+}
+impl TryParse for SASetPtrDflt {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SASetPtrDflt { type_, flags, affect, value };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SASetPtrDflt {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SASetPtrDflt {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let affect_bytes = self.affect.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            affect_bytes[0],
+            value_bytes[0],
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.affect.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+    }
+}
+impl Trace for SASetPtrDflt {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, SASetPtrDfltFlag::VARIANTS))?;
+            tracer.field("affect", |tracer| tracer.bitmask(&self.affect, SASetPtrDfltFlag::VARIANTS))?;
+            tracer.field("value", |tracer| tracer.value(&self.value))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SAIsoLockFlag(u8);
+impl SAIsoLockFlag {
+    pub const NO_LOCK: Self = Self(1 << 0);
+    pub const NO_UNLOCK: Self = Self(1 << 1);
+    pub const USE_MOD_MAP_MODS: Self = Self(1 << 2);
+    pub const GROUP_ABSOLUTE: Self = Self(1 << 2);
+    pub const ISO_DFLT_IS_GROUP: Self = Self(1 << 3);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "NO_LOCK", "NoLock"),
+        (1 << 1, "NO_UNLOCK", "NoUnlock"),
+        (1 << 2, "USE_MOD_MAP_MODS", "UseModMapMods"),
+        (1 << 2, "GROUP_ABSOLUTE", "GroupAbsolute"),
+        (1 << 3, "ISO_DFLT_IS_GROUP", "ISODfltIsGroup"),
+    ];
+}
+impl From<SAIsoLockFlag> for u8 {
+    #[inline]
+    fn from(input: SAIsoLockFlag) -> Self {
+        input.0
+    }
+}
+impl From<SAIsoLockFlag> for Option<u8> {
+    #[inline]
+    fn from(input: SAIsoLockFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SAIsoLockFlag> for u16 {
+    #[inline]
+    fn from(input: SAIsoLockFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SAIsoLockFlag> for Option<u16> {
+    #[inline]
+    fn from(input: SAIsoLockFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SAIsoLockFlag> for u32 {
+    #[inline]
+    fn from(input: SAIsoLockFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SAIsoLockFlag> for Option<u32> {
+    #[inline]
+    fn from(input: SAIsoLockFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SAIsoLockFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SAIsoLockFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SAIsoLockFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SAIsoLockFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SAIsoLockFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SAIsoLockFlag, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SAIsoLockNoAffect(u8);
+impl SAIsoLockNoAffect {
+    pub const CTRLS: Self = Self(1 << 3);
+    pub const PTR: Self = Self(1 << 4);
+    pub const GROUP: Self = Self(1 << 5);
+    pub const MODS: Self = Self(1 << 6);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 3, "CTRLS", "Ctrls"),
+        (1 << 4, "PTR", "Ptr"),
+        (1 << 5, "GROUP", "Group"),
+        (1 << 6, "MODS", "Mods"),
+    ];
+}
+impl From<SAIsoLockNoAffect> for u8 {
+    #[inline]
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        input.0
+    }
+}
+impl From<SAIsoLockNoAffect> for Option<u8> {
+    #[inline]
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SAIsoLockNoAffect> for u16 {
+    #[inline]
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SAIsoLockNoAffect> for Option<u16> {
+    #[inline]
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SAIsoLockNoAffect> for u32 {
+    #[inline]
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SAIsoLockNoAffect> for Option<u32> {
+    #[inline]
+    fn from(input: SAIsoLockNoAffect) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SAIsoLockNoAffect {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SAIsoLockNoAffect  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SAIsoLockNoAffect {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SAIsoLockNoAffect {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SAIsoLockNoAffect {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SAIsoLockNoAffect, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SAIsoLock {
+    pub type_: SAType,
+    pub flags: u8,
+    pub mask: u8,
+    pub real_mods: u8,
+    pub group: i8,
+    pub affect: u8,
+    pub vmods_high: u8,
+    pub vmods_low: u8,
+}
+impl TryParse for SAIsoLock {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (affect, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = type_.into();
+        let result = SAIsoLock { type_, flags, mask, real_mods, group, affect, vmods_high, vmods_low };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SAIsoLock {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SAIsoLock {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let mask_bytes = self.mask.serialize_with_order(order);
+        let real_mods_bytes = self.real_mods.serialize_with_order(order);
+        let group_bytes = self.group.serialize_with_order(order);
+        let affect_bytes = self.affect.serialize_with_order(order);
+        let vmods_high_bytes = self.vmods_high.serialize_with_order(order);
+        let vmods_low_bytes = self.vmods_low.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            mask_bytes[0],
+            real_mods_bytes[0],
+            group_bytes[0],
+            affect_bytes[0],
+            vmods_high_bytes[0],
+            vmods_low_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.mask.serialize_into_with_order(bytes, order);
+        self.real_mods.serialize_into_with_order(bytes, order);
+        self.group.serialize_into_with_order(bytes, order);
+        self.affect.serialize_into_with_order(bytes, order);
+        self.vmods_high.serialize_into_with_order(bytes, order);
+        self.vmods_low.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SAIsoLock {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, SAIsoLockFlag::VARIANTS))?;
+            tracer.field("mask", |tracer| tracer.bitmask(&self.mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("realMods", |tracer| tracer.bitmask(&self.real_mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("group", |tracer| tracer.value(&self.group))?;
+            tracer.field("affect", |tracer| tracer.bitmask(&self.affect, SAIsoLockNoAffect::VARIANTS))?;
+            tracer.field("vmodsHigh", |tracer| tracer.bitmask(&self.vmods_high, VModsHigh::VARIANTS))?;
+            tracer.field("vmodsLow", |tracer| tracer.bitmask(&self.vmods_low, VModsLow::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SATerminate {
+    pub type_: SAType,
+}
+impl TryParse for SATerminate {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SATerminate { type_ };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SATerminate {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SATerminate {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        [
+            type_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 7]);
+    }
+}
+impl Trace for SATerminate {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SwitchScreenFlag(u8);
+impl SwitchScreenFlag {
+    pub const APPLICATION: Self = Self(1 << 0);
+    pub const ABSOLUTE: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "APPLICATION", "Application"),
+        (1 << 2, "ABSOLUTE", "Absolute"),
+    ];
+}
+impl From<SwitchScreenFlag> for u8 {
+    #[inline]
+    fn from(input: SwitchScreenFlag) -> Self {
+        input.0
+    }
+}
+impl From<SwitchScreenFlag> for Option<u8> {
+    #[inline]
+    fn from(input: SwitchScreenFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SwitchScreenFlag> for u16 {
+    #[inline]
+    fn from(input: SwitchScreenFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SwitchScreenFlag> for Option<u16> {
+    #[inline]
+    fn from(input: SwitchScreenFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SwitchScreenFlag> for u32 {
+    #[inline]
+    fn from(input: SwitchScreenFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SwitchScreenFlag> for Option<u32> {
+    #[inline]
+    fn from(input: SwitchScreenFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SwitchScreenFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SwitchScreenFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SwitchScreenFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SwitchScreenFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SwitchScreenFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(SwitchScreenFlag, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SASwitchScreen {
+    pub type_: SAType,
+    pub flags: u8,
+    pub new_screen: i8,
+}
+impl TryParse for SASwitchScreen {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (new_screen, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(5..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SASwitchScreen { type_, flags, new_screen };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SASwitchScreen {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SASwitchScreen {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let new_screen_bytes = self.new_screen.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            new_screen_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.new_screen.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 5]);
+    }
+}
+impl Trace for SASwitchScreen {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("newScreen", |tracer| tracer.value(&self.new_screen))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BoolCtrlsHigh(u8);
+impl BoolCtrlsHigh {
+    pub const ACCESS_X_FEEDBACK: Self = Self(1 << 0);
+    pub const AUDIBLE_BELL: Self = Self(1 << 1);
+    pub const OVERLAY1: Self = Self(1 << 2);
+    pub const OVERLAY2: Self = Self(1 << 3);
+    pub const IGNORE_GROUP_LOCK: Self = Self(1 << 4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "ACCESS_X_FEEDBACK", "AccessXFeedback"),
+        (1 << 1, "AUDIBLE_BELL", "AudibleBell"),
+        (1 << 2, "OVERLAY1", "Overlay1"),
+        (1 << 3, "OVERLAY2", "Overlay2"),
+        (1 << 4, "IGNORE_GROUP_LOCK", "IgnoreGroupLock"),
+    ];
+}
+impl From<BoolCtrlsHigh> for u8 {
+    #[inline]
+    fn from(input: BoolCtrlsHigh) -> Self {
+        input.0
+    }
+}
+impl From<BoolCtrlsHigh> for Option<u8> {
+    #[inline]
+    fn from(input: BoolCtrlsHigh) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BoolCtrlsHigh> for u16 {
+    #[inline]
+    fn from(input: BoolCtrlsHigh) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<BoolCtrlsHigh> for Option<u16> {
+    #[inline]
+    fn from(input: BoolCtrlsHigh) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<BoolCtrlsHigh> for u32 {
+    #[inline]
+    fn from(input: BoolCtrlsHigh) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BoolCtrlsHigh> for Option<u32> {
+    #[inline]
+    fn from(input: BoolCtrlsHigh) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BoolCtrlsHigh {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BoolCtrlsHigh  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BoolCtrlsHigh {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BoolCtrlsHigh {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BoolCtrlsHigh {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(BoolCtrlsHigh, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BoolCtrlsLow(u8);
+impl BoolCtrlsLow {
+    pub const REPEAT_KEYS: Self = Self(1 << 0);
+    pub const SLOW_KEYS: Self = Self(1 << 1);
+    pub const BOUNCE_KEYS: Self = Self(1 << 2);
+    pub const STICKY_KEYS: Self = Self(1 << 3);
+    pub const MOUSE_KEYS: Self = Self(1 << 4);
+    pub const MOUSE_KEYS_ACCEL: Self = Self(1 << 5);
+    pub const ACCESS_X_KEYS: Self = Self(1 << 6);
+    pub const ACCESS_X_TIMEOUT: Self = Self(1 << 7);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "REPEAT_KEYS", "RepeatKeys"),
+        (1 << 1, "SLOW_KEYS", "SlowKeys"),
+        (1 << 2, "BOUNCE_KEYS", "BounceKeys"),
+        (1 << 3, "STICKY_KEYS", "StickyKeys"),
+        (1 << 4, "MOUSE_KEYS", "MouseKeys"),
+        (1 << 5, "MOUSE_KEYS_ACCEL", "MouseKeysAccel"),
+        (1 << 6, "ACCESS_X_KEYS", "AccessXKeys"),
+        (1 << 7, "ACCESS_X_TIMEOUT", "AccessXTimeout"),
+    ];
+}
+impl From<BoolCtrlsLow> for u8 {
+    #[inline]
+    fn from(input: BoolCtrlsLow) -> Self {
+        input.0
+    }
+}
+impl From<BoolCtrlsLow> for Option<u8> {
+    #[inline]
+    fn from(input: BoolCtrlsLow) -> Self {
+        Some(input.0)
+    }
+}
+impl From<BoolCtrlsLow> for u16 {
+    #[inline]
+    fn from(input: BoolCtrlsLow) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<BoolCtrlsLow> for Option<u16> {
+    #[inline]
+    fn from(input: BoolCtrlsLow) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<BoolCtrlsLow> for u32 {
+    #[inline]
+    fn from(input: BoolCtrlsLow) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<BoolCtrlsLow> for Option<u32> {
+    #[inline]
+    fn from(input: BoolCtrlsLow) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for BoolCtrlsLow {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for BoolCtrlsLow  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for BoolCtrlsLow {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for BoolCtrlsLow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BoolCtrlsLow {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(BoolCtrlsLow, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SASetControls {
+    pub type_: SAType,
+    pub bool_ctrls_high: u8,
+    pub bool_ctrls_low: u8,
+}
+impl TryParse for SASetControls {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (bool_ctrls_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bool_ctrls_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SASetControls { type_, bool_ctrls_high, bool_ctrls_low };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SASetControls {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SASetControls {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let bool_ctrls_high_bytes = self.bool_ctrls_high.serialize_with_order(order);
+        let bool_ctrls_low_bytes = self.bool_ctrls_low.serialize_with_order(order);
+        [
+            type_bytes[0],
+            0,
+            0,
+            0,
+            bool_ctrls_high_bytes[0],
+            bool_ctrls_low_bytes[0],
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        self.bool_ctrls_high.serialize_into_with_order(bytes, order);
+        self.bool_ctrls_low.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+    }
+}
+impl Trace for SASetControls {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("boolCtrlsHigh", |tracer| tracer.bitmask(&self.bool_ctrls_high, BoolCtrlsHigh::VARIANTS))?;
+            tracer.field("boolCtrlsLow", |tracer| tracer.bitmask(&self.bool_ctrls_low, BoolCtrlsLow::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+pub type SALockControls = SASetControls;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ActionMessageFlag(u8);
+impl ActionMessageFlag {
+    pub const ON_PRESS: Self = Self(1 << 0);
+    pub const ON_RELEASE: Self = Self(1 << 1);
+    pub const GEN_KEY_EVENT: Self = Self(1 << 2);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "ON_PRESS", "OnPress"),
+        (1 << 1, "ON_RELEASE", "OnRelease"),
+        (1 << 2, "GEN_KEY_EVENT", "GenKeyEvent"),
+    ];
+}
+impl From<ActionMessageFlag> for u8 {
+    #[inline]
+    fn from(input: ActionMessageFlag) -> Self {
+        input.0
+    }
+}
+impl From<ActionMessageFlag> for Option<u8> {
+    #[inline]
+    fn from(input: ActionMessageFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ActionMessageFlag> for u16 {
+    #[inline]
+    fn from(input: ActionMessageFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ActionMessageFlag> for Option<u16> {
+    #[inline]
+    fn from(input: ActionMessageFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ActionMessageFlag> for u32 {
+    #[inline]
+    fn from(input: ActionMessageFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ActionMessageFlag> for Option<u32> {
+    #[inline]
+    fn from(input: ActionMessageFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ActionMessageFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ActionMessageFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ActionMessageFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ActionMessageFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ActionMessageFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(ActionMessageFlag, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SAActionMessage {
+    pub type_: SAType,
+    pub flags: u8,
+    pub message: [u8; 6],
+}
+impl TryParse for SAActionMessage {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, 6)?;
+        let message = <[u8; 6]>::try_from(message).unwrap();
+        let type_ = type_.into();
+        let result = SAActionMessage { type_, flags, message };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SAActionMessage {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SAActionMessage {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            self.message[0],
+            self.message[1],
+            self.message[2],
+            self.message[3],
+            self.message[4],
+            self.message[5],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.message);
+    }
+}
+impl Trace for SAActionMessage {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, ActionMessageFlag::VARIANTS))?;
+            tracer.field("message", |tracer| tracer.list(&self.message, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SARedirectKey {
+    pub type_: SAType,
+    pub newkey: xproto::Keycode,
+    pub mask: u8,
+    pub real_modifiers: u8,
+    pub vmods_mask_high: u8,
+    pub vmods_mask_low: u8,
+    pub vmods_high: u8,
+    pub vmods_low: u8,
+}
+impl TryParse for SARedirectKey {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (newkey, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (real_modifiers, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_mask_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_mask_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_high, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (vmods_low, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = type_.into();
+        let result = SARedirectKey { type_, newkey, mask, real_modifiers, vmods_mask_high, vmods_mask_low, vmods_high, vmods_low };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SARedirectKey {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SARedirectKey {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let newkey_bytes = self.newkey.serialize_with_order(order);
+        let mask_bytes = self.mask.serialize_with_order(order);
+        let real_modifiers_bytes = self.real_modifiers.serialize_with_order(order);
+        let vmods_mask_high_bytes = self.vmods_mask_high.serialize_with_order(order);
+        let vmods_mask_low_bytes = self.vmods_mask_low.serialize_with_order(order);
+        let vmods_high_bytes = self.vmods_high.serialize_with_order(order);
+        let vmods_low_bytes = self.vmods_low.serialize_with_order(order);
+        [
+            type_bytes[0],
+            newkey_bytes[0],
+            mask_bytes[0],
+            real_modifiers_bytes[0],
+            vmods_mask_high_bytes[0],
+            vmods_mask_low_bytes[0],
+            vmods_high_bytes[0],
+            vmods_low_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.newkey.serialize_into_with_order(bytes, order);
+        self.mask.serialize_into_with_order(bytes, order);
+        self.real_modifiers.serialize_into_with_order(bytes, order);
+        self.vmods_mask_high.serialize_into_with_order(bytes, order);
+        self.vmods_mask_low.serialize_into_with_order(bytes, order);
+        self.vmods_high.serialize_into_with_order(bytes, order);
+        self.vmods_low.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SARedirectKey {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("newkey", |tracer| tracer.value(&self.newkey))?;
+            tracer.field("mask", |tracer| tracer.bitmask(&self.mask, xproto::ModMask::VARIANTS))?;
+            tracer.field("realModifiers", |tracer| tracer.bitmask(&self.real_modifiers, xproto::ModMask::VARIANTS))?;
+            tracer.field("vmodsMaskHigh", |tracer| tracer.bitmask(&self.vmods_mask_high, VModsHigh::VARIANTS))?;
+            tracer.field("vmodsMaskLow", |tracer| tracer.bitmask(&self.vmods_mask_low, VModsLow::VARIANTS))?;
+            tracer.field("vmodsHigh", |tracer| tracer.bitmask(&self.vmods_high, VModsHigh::VARIANTS))?;
+            tracer.field("vmodsLow", |tracer| tracer.bitmask(&self.vmods_low, VModsLow::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SADeviceBtn {
+    pub type_: SAType,
+    pub flags: u8,
+    pub count: u8,
+    pub button: u8,
+    pub device: u8,
+}
+impl TryParse for SADeviceBtn {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (count, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SADeviceBtn { type_, flags, count, button, device };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SADeviceBtn {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SADeviceBtn {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let count_bytes = self.count.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
+        let device_bytes = self.device.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            count_bytes[0],
+            button_bytes[0],
+            device_bytes[0],
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.count.serialize_into_with_order(bytes, order);
+        self.button.serialize_into_with_order(bytes, order);
+        self.device.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+    }
+}
+impl Trace for SADeviceBtn {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("count", |tracer| tracer.value(&self.count))?;
+            tracer.field("button", |tracer| tracer.value(&self.button))?;
+            tracer.field("device", |tracer| tracer.value(&self.device))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LockDeviceFlags(u8);
+impl LockDeviceFlags {
+    pub const NO_LOCK: Self = Self(1 << 0);
+    pub const NO_UNLOCK: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "NO_LOCK", "NoLock"),
+        (1 << 1, "NO_UNLOCK", "NoUnlock"),
+    ];
+}
+impl From<LockDeviceFlags> for u8 {
+    #[inline]
+    fn from(input: LockDeviceFlags) -> Self {
+        input.0
+    }
+}
+impl From<LockDeviceFlags> for Option<u8> {
+    #[inline]
+    fn from(input: LockDeviceFlags) -> Self {
+        Some(input.0)
+    }
+}
+impl From<LockDeviceFlags> for u16 {
+    #[inline]
+    fn from(input: LockDeviceFlags) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<LockDeviceFlags> for Option<u16> {
+    #[inline]
+    fn from(input: LockDeviceFlags) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<LockDeviceFlags> for u32 {
+    #[inline]
+    fn from(input: LockDeviceFlags) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<LockDeviceFlags> for Option<u32> {
+    #[inline]
+    fn from(input: LockDeviceFlags) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for LockDeviceFlags {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for LockDeviceFlags  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for LockDeviceFlags {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for LockDeviceFlags {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LockDeviceFlags {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(LockDeviceFlags, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SALockDeviceBtn {
+    pub type_: SAType,
+    pub flags: u8,
+    pub button: u8,
+    pub device: u8,
+}
+impl TryParse for SALockDeviceBtn {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let result = SALockDeviceBtn { type_, flags, button, device };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SALockDeviceBtn {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SALockDeviceBtn {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let button_bytes = self.button.serialize_with_order(order);
+        let device_bytes = self.device.serialize_with_order(order);
+        [
+            type_bytes[0],
+            flags_bytes[0],
+            0,
+            button_bytes[0],
+            device_bytes[0],
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.button.serialize_into_with_order(bytes, order);
+        self.device.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+    }
+}
+impl Trace for SALockDeviceBtn {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, LockDeviceFlags::VARIANTS))?;
+            tracer.field("button", |tracer| tracer.value(&self.button))?;
+            tracer.field("device", |tracer| tracer.value(&self.device))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SAValWhat(u8);
+impl SAValWhat {
+    pub const IGNORE_VAL: Self = Self(0);
+    pub const SET_VAL_MIN: Self = Self(1);
+    pub const SET_VAL_CENTER: Self = Self(2);
+    pub const SET_VAL_MAX: Self = Self(3);
+    pub const SET_VAL_RELATIVE: Self = Self(4);
+    pub const SET_VAL_ABSOLUTE: Self = Self(5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "IGNORE_VAL", "IgnoreVal"),
+        (1, "SET_VAL_MIN", "SetValMin"),
+        (2, "SET_VAL_CENTER", "SetValCenter"),
+        (3, "SET_VAL_MAX", "SetValMax"),
+        (4, "SET_VAL_RELATIVE", "SetValRelative"),
+        (5, "SET_VAL_ABSOLUTE", "SetValAbsolute"),
+    ];
+}
+impl From<SAValWhat> for u8 {
+    #[inline]
+    fn from(input: SAValWhat) -> Self {
+        input.0
+    }
+}
+impl From<SAValWhat> for Option<u8> {
+    #[inline]
+    fn from(input: SAValWhat) -> Self {
+        Some(input.0)
+    }
+}
+impl From<SAValWhat> for u16 {
+    #[inline]
+    fn from(input: SAValWhat) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<SAValWhat> for Option<u16> {
+    #[inline]
+    fn from(input: SAValWhat) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<SAValWhat> for u32 {
+    #[inline]
+    fn from(input: SAValWhat) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<SAValWhat> for Option<u32> {
+    #[inline]
+    fn from(input: SAValWhat) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for SAValWhat {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for SAValWhat  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for SAValWhat {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for SAValWhat {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SAValWhat {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SADeviceValuator {
+    pub type_: SAType,
+    pub device: u8,
+    pub val1what: SAValWhat,
+    pub val1index: u8,
+    pub val1value: u8,
+    pub val2what: SAValWhat,
+    pub val2index: u8,
+    pub val2value: u8,
+}
+impl TryParse for SADeviceValuator {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val1what, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val1index, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val1value, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val2what, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val2index, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (val2value, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let type_ = type_.into();
+        let val1what = val1what.into();
+        let val2what = val2what.into();
+        let result = SADeviceValuator { type_, device, val1what, val1index, val1value, val2what, val2index, val2value };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SADeviceValuator {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SADeviceValuator {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let device_bytes = self.device.serialize_with_order(order);
+        let val1what_bytes = u8::from(self.val1what).serialize_with_order(order);
+        let val1index_bytes = self.val1index.serialize_with_order(order);
+        let val1value_bytes = self.val1value.serialize_with_order(order);
+        let val2what_bytes = u8::from(self.val2what).serialize_with_order(order);
+        let val2index_bytes = self.val2index.serialize_with_order(order);
+        let val2value_bytes = self.val2value.serialize_with_order(order);
+        [
+            type_bytes[0],
+            device_bytes[0],
+            val1what_bytes[0],
+            val1index_bytes[0],
+            val1value_bytes[0],
+            val2what_bytes[0],
+            val2index_bytes[0],
+            val2value_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        self.device.serialize_into_with_order(bytes, order);
+        u8::from(self.val1what).serialize_into_with_order(bytes, order);
+        self.val1index.serialize_into_with_order(bytes, order);
+        self.val1value.serialize_into_with_order(bytes, order);
+        u8::from(self.val2what).serialize_into_with_order(bytes, order);
+        self.val2index.serialize_into_with_order(bytes, order);
+        self.val2value.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SADeviceValuator {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("device", |tracer| tracer.value(&self.device))?;
+            tracer.field("val1what", |tracer| tracer.value(&self.val1what))?;
+            tracer.field("val1index", |tracer| tracer.value(&self.val1index))?;
+            tracer.field("val1value", |tracer| tracer.value(&self.val1value))?;
+            tracer.field("val2what", |tracer| tracer.value(&self.val2what))?;
+            tracer.field("val2index", |tracer| tracer.value(&self.val2index))?;
+            tracer.field("val2value", |tracer| tracer.value(&self.val2value))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SIAction {
+    pub type_: SAType,
+    pub data: [u8; 7],
+}
+impl TryParse for SIAction {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, 7)?;
+        let data = <[u8; 7]>::try_from(data).unwrap();
+        let type_ = type_.into();
+        let result = SIAction { type_, data };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SIAction {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SIAction {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        [
+            type_bytes[0],
+            self.data[0],
+            self.data[1],
+            self.data[2],
+            self.data[3],
+            self.data[4],
+            self.data[5],
+            self.data[6],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.data);
+    }
+}
+impl Trace for SIAction {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SymInterpret {
+    pub sym: xproto::Keysym,
+    pub mods: u8,
+    pub match_: u8,
+    pub virtual_mod: u8,
+    pub flags: u8,
+    pub action: SIAction,
+}
+impl TryParse for SymInterpret {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (sym, remaining) = xproto::Keysym::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (match_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mod, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (action, remaining) = SIAction::try_parse_with_order(remaining, order)?;
+        let result = SymInterpret { sym, mods, match_, virtual_mod, flags, action };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SymInterpret {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SymInterpret {
+    type Bytes = [u8; 16];
+    fn serialize(&self) -> [u8; 16] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 16] {
+        let sym_bytes = self.sym.serialize_with_order(order);
+        let mods_bytes = self.mods.serialize_with_order(order);
+        let match_bytes = self.match_.serialize_with_order(order);
+        let virtual_mod_bytes = self.virtual_mod.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let action_bytes = self.action.serialize_with_order(order);
+        [
+            sym_bytes[0],
+            sym_bytes[1],
+            sym_bytes[2],
+            sym_bytes[3],
+            mods_bytes[0],
+            match_bytes[0],
+            virtual_mod_bytes[0],
+            flags_bytes[0],
+            action_bytes[0],
+            action_bytes[1],
+            action_bytes[2],
+            action_bytes[3],
+            action_bytes[4],
+            action_bytes[5],
+            action_bytes[6],
+            action_bytes[7],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(16);
+        self.sym.serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.match_.serialize_into_with_order(bytes, order);
+        self.virtual_mod.serialize_into_with_order(bytes, order);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.action.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SymInterpret {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("sym", |tracer| tracer.value(&self.sym))?;
+            tracer.field("mods", |tracer| tracer.bitmask(&self.mods, xproto::ModMask::VARIANTS))?;
+            tracer.field("match", |tracer| tracer.enum_value(&self.match_, SymInterpretMatch::VARIANTS))?;
+            tracer.field("virtualMod", |tracer| tracer.bitmask(&self.virtual_mod, VModsLow::VARIANTS))?;
+            tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+            tracer.field("action", |tracer| tracer.value(&self.action))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Action([u8; 8]);
+impl Trace for Action {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.list(&self.0, |tracer, value| tracer.value(value))
+    }
+}
+impl Action {
+    pub fn as_noaction(&self) -> SANoAction {
+        fn do_the_parse(remaining: &[u8]) -> Result<SANoAction, ParseError> {
+            let order = ByteOrder::native();
+            let (noaction, remaining) = SANoAction::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(noaction)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_setmods(&self) -> SASetMods {
+        fn do_the_parse(remaining: &[u8]) -> Result<SASetMods, ParseError> {
+            let order = ByteOrder::native();
+            let (setmods, remaining) = SASetMods::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(setmods)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_latchmods(&self) -> SALatchMods {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALatchMods, ParseError> {
+            let order = ByteOrder::native();
+            let (latchmods, remaining) = SALatchMods::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(latchmods)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lockmods(&self) -> SALockMods {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALockMods, ParseError> {
+            let order = ByteOrder::native();
+            let (lockmods, remaining) = SALockMods::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lockmods)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_setgroup(&self) -> SASetGroup {
+        fn do_the_parse(remaining: &[u8]) -> Result<SASetGroup, ParseError> {
+            let order = ByteOrder::native();
+            let (setgroup, remaining) = SASetGroup::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(setgroup)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_latchgroup(&self) -> SALatchGroup {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALatchGroup, ParseError> {
+            let order = ByteOrder::native();
+            let (latchgroup, remaining) = SALatchGroup::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(latchgroup)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lockgroup(&self) -> SALockGroup {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALockGroup, ParseError> {
+            let order = ByteOrder::native();
+            let (lockgroup, remaining) = SALockGroup::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lockgroup)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_moveptr(&self) -> SAMovePtr {
+        fn do_the_parse(remaining: &[u8]) -> Result<SAMovePtr, ParseError> {
+            let order = ByteOrder::native();
+            let (moveptr, remaining) = SAMovePtr::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(moveptr)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_ptrbtn(&self) -> SAPtrBtn {
+        fn do_the_parse(remaining: &[u8]) -> Result<SAPtrBtn, ParseError> {
+            let order = ByteOrder::native();
+            let (ptrbtn, remaining) = SAPtrBtn::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(ptrbtn)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lockptrbtn(&self) -> SALockPtrBtn {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALockPtrBtn, ParseError> {
+            let order = ByteOrder::native();
+            let (lockptrbtn, remaining) = SALockPtrBtn::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lockptrbtn)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_setptrdflt(&self) -> SASetPtrDflt {
+        fn do_the_parse(remaining: &[u8]) -> Result<SASetPtrDflt, ParseError> {
+            let order = ByteOrder::native();
+            let (setptrdflt, remaining) = SASetPtrDflt::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(setptrdflt)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_isolock(&self) -> SAIsoLock {
+        fn do_the_parse(remaining: &[u8]) -> Result<SAIsoLock, ParseError> {
+            let order = ByteOrder::native();
+            let (isolock, remaining) = SAIsoLock::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(isolock)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_terminate(&self) -> SATerminate {
+        fn do_the_parse(remaining: &[u8]) -> Result<SATerminate, ParseError> {
+            let order = ByteOrder::native();
+            let (terminate, remaining) = SATerminate::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(terminate)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_switchscreen(&self) -> SASwitchScreen {
+        fn do_the_parse(remaining: &[u8]) -> Result<SASwitchScreen, ParseError> {
+            let order = ByteOrder::native();
+            let (switchscreen, remaining) = SASwitchScreen::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(switchscreen)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_setcontrols(&self) -> SASetControls {
+        fn do_the_parse(remaining: &[u8]) -> Result<SASetControls, ParseError> {
+            let order = ByteOrder::native();
+            let (setcontrols, remaining) = SASetControls::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(setcontrols)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lockcontrols(&self) -> SALockControls {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALockControls, ParseError> {
+            let order = ByteOrder::native();
+            let (lockcontrols, remaining) = SALockControls::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lockcontrols)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_message(&self) -> SAActionMessage {
+        fn do_the_parse(remaining: &[u8]) -> Result<SAActionMessage, ParseError> {
+            let order = ByteOrder::native();
+            let (message, remaining) = SAActionMessage::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(message)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_redirect(&self) -> SARedirectKey {
+        fn do_the_parse(remaining: &[u8]) -> Result<SARedirectKey, ParseError> {
+            let order = ByteOrder::native();
+            let (redirect, remaining) = SARedirectKey::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(redirect)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_devbtn(&self) -> SADeviceBtn {
+        fn do_the_parse(remaining: &[u8]) -> Result<SADeviceBtn, ParseError> {
+            let order = ByteOrder::native();
+            let (devbtn, remaining) = SADeviceBtn::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(devbtn)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_lockdevbtn(&self) -> SALockDeviceBtn {
+        fn do_the_parse(remaining: &[u8]) -> Result<SALockDeviceBtn, ParseError> {
+            let order = ByteOrder::native();
+            let (lockdevbtn, remaining) = SALockDeviceBtn::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(lockdevbtn)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_devval(&self) -> SADeviceValuator {
+        fn do_the_parse(remaining: &[u8]) -> Result<SADeviceValuator, ParseError> {
+            let order = ByteOrder::native();
+            let (devval, remaining) = SADeviceValuator::try_parse_with_order(remaining, order)?;
+            let _ = remaining;
+            Ok(devval)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+    pub fn as_type(&self) -> SAType {
+        fn do_the_parse(remaining: &[u8]) -> Result<SAType, ParseError> {
+            let order = ByteOrder::native();
+            let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+            let type_ = type_.into();
+            let _ = remaining;
+            Ok(type_)
+        }
+        do_the_parse(&self.0).unwrap()
+    }
+}
+impl Serialize for Action {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.0
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.0);
+    }
+    fn serialize_with_order(&self, _: ByteOrder) -> [u8; 8] {
+        self.0
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _: ByteOrder) {
+        bytes.extend_from_slice(&self.0);
+    }
+}
+impl TryParse for Action {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let inner: [u8; 8] = value.get(..8)
+            .ok_or(ParseError::InsufficientData)?
+            .try_into()
+            .unwrap();
+        let result = Action(inner);
+        Ok((result, &value[8..]))
+    }
+    fn try_parse_with_order(value: &[u8], _: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse(value)
+    }
+}
+impl From<SANoAction> for Action {
+    fn from(noaction: SANoAction) -> Self {
+        let order = ByteOrder::native();
+        let noaction_bytes = noaction.serialize_with_order(order);
+        Self(noaction_bytes)
+    }
+}
+impl From<SASetMods> for Action {
+    fn from(setmods: SASetMods) -> Self {
+        let order = ByteOrder::native();
+        let setmods_bytes = setmods.serialize_with_order(order);
+        Self(setmods_bytes)
+    }
+}
+impl From<SASetGroup> for Action {
+    fn from(setgroup: SASetGroup) -> Self {
+        let order = ByteOrder::native();
+        let setgroup_bytes = setgroup.serialize_with_order(order);
+        Self(setgroup_bytes)
+    }
+}
+impl From<SAMovePtr> for Action {
+    fn from(moveptr: SAMovePtr) -> Self {
+        let order = ByteOrder::native();
+        let moveptr_bytes = moveptr.serialize_with_order(order);
+        Self(moveptr_bytes)
+    }
+}
+impl From<SAPtrBtn> for Action {
+    fn from(ptrbtn: SAPtrBtn) -> Self {
+        let order = ByteOrder::native();
+        let ptrbtn_bytes = ptrbtn.serialize_with_order(order);
+        Self(ptrbtn_bytes)
+    }
+}
+impl From<SALockPtrBtn> for Action {
+    fn from(lockptrbtn: SALockPtrBtn) -> Self {
+        let order = ByteOrder::native();
+        let lockptrbtn_bytes = lockptrbtn.serialize_with_order(order);
+        Self(lockptrbtn_bytes)
+    }
+}
+impl From<SASetPtrDflt> for Action {
+    fn from(setptrdflt: SASetPtrDflt) -> Self {
+        let order = ByteOrder::native();
+        let setptrdflt_bytes = setptrdflt.serialize_with_order(order);
+        Self(setptrdflt_bytes)
+    }
+}
+impl From<SAIsoLock> for Action {
+    fn from(isolock: SAIsoLock) -> Self {
+        let order = ByteOrder::native();
+        let isolock_bytes = isolock.serialize_with_order(order);
+        Self(isolock_bytes)
+    }
+}
+impl From<SATerminate> for Action {
+    fn from(terminate: SATerminate) -> Self {
+        let order = ByteOrder::native();
+        let terminate_bytes = terminate.serialize_with_order(order);
+        Self(terminate_bytes)
+    }
+}
+impl From<SASwitchScreen> for Action {
+    fn from(switchscreen: SASwitchScreen) -> Self {
+        let order = ByteOrder::native();
+        let switchscreen_bytes = switchscreen.serialize_with_order(order);
+        Self(switchscreen_bytes)
+    }
+}
+impl From<SASetControls> for Action {
+    fn from(setcontrols: SASetControls) -> Self {
+        let order = ByteOrder::native();
+        let setcontrols_bytes = setcontrols.serialize_with_order(order);
+        Self(setcontrols_bytes)
+    }
+}
+impl From<SAActionMessage> for Action {
+    fn from(message: SAActionMessage) -> Self {
+        let order = ByteOrder::native();
+        let message_bytes = message.serialize_with_order(order);
+        Self(message_bytes)
+    }
+}
+impl From<SARedirectKey> for Action {
+    fn from(redirect: SARedirectKey) -> Self {
+        let order = ByteOrder::native();
+        let redirect_bytes = redirect.serialize_with_order(order);
+        Self(redirect_bytes)
+    }
+}
+impl From<SADeviceBtn> for Action {
+    fn from(devbtn: SADeviceBtn) -> Self {
+        let order = ByteOrder::native();
+        let devbtn_bytes = devbtn.serialize_with_order(order);
+        Self(devbtn_bytes)
+    }
+}
+impl From<SALockDeviceBtn> for Action {
+    fn from(lockdevbtn: SALockDeviceBtn) -> Self {
+        let order = ByteOrder::native();
+        let lockdevbtn_bytes = lockdevbtn.serialize_with_order(order);
+        Self(lockdevbtn_bytes)
+    }
+}
+impl From<SADeviceValuator> for Action {
+    fn from(devval: SADeviceValuator) -> Self {
+        let order = ByteOrder::native();
+        let devval_bytes = devval.serialize_with_order(order);
+        Self(devval_bytes)
+    }
+}
+impl From<SAType> for Action {
+    fn from(type_: SAType) -> Self {
+        let order = ByteOrder::native();
+        let type_bytes = u8::from(type_).serialize_with_order(order);
+        let value = [
+            type_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ];
+        Self(value)
+    }
+}
+
+/// Opcode for the UseExtension request
+pub const USE_EXTENSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UseExtensionRequest {
+    pub wanted_major: u16,
+    pub wanted_minor: u16,
+}
+impl UseExtensionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let wanted_major_bytes = self.wanted_major.serialize_with_order(order);
+        let wanted_minor_bytes = self.wanted_minor.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            USE_EXTENSION_REQUEST,
+            0,
+            0,
+            wanted_major_bytes[0],
+            wanted_major_bytes[1],
+            wanted_minor_bytes[0],
+            wanted_minor_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != USE_EXTENSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (wanted_major, remaining) = u16::try_parse_with_order(value, order)?;
+        let (wanted_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(UseExtensionRequest {
+            wanted_major,
+            wanted_minor,
+        })
+    }
+}
+impl Request for UseExtensionRequest {
+    type Reply = UseExtensionReply;
+}
+impl Trace for UseExtensionRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("wantedMajor", |tracer| tracer.value(&self.wanted_major))?;
+        tracer.field("wantedMinor", |tracer| tracer.value(&self.wanted_minor))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UseExtensionReply {
+    pub supported: bool,
+    pub sequence: u16,
+    pub length: u32,
+    pub server_major: u16,
+    pub server_minor: u16,
+}
+impl TryParse for UseExtensionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (server_major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (server_minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = UseExtensionReply { supported, sequence, length, server_major, server_minor };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for UseExtensionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for UseExtensionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("supported", |tracer| tracer.value(&self.supported))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("serverMajor", |tracer| tracer.value(&self.server_major))?;
+        tracer.field("serverMinor", |tracer| tracer.value(&self.server_minor))?;
+        Ok(())
+    }
+}
+impl Serialize for UseExtensionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.supported.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.server_major.serialize_into_with_order(bytes, order);
+        self.server_minor.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase1 {
+    pub affect_new_keyboard: u16,
+    pub new_keyboard_details: u16,
+}
+impl TryParse for SelectEventsAuxBitcase1 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_new_keyboard, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (new_keyboard_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase1 { affect_new_keyboard, new_keyboard_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase1 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase1 {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_new_keyboard_bytes = self.affect_new_keyboard.serialize_with_order(order);
+        let new_keyboard_details_bytes = self.new_keyboard_details.serialize_with_order(order);
+        [
+            affect_new_keyboard_bytes[0],
+            affect_new_keyboard_bytes[1],
+            new_keyboard_details_bytes[0],
+            new_keyboard_details_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.affect_new_keyboard.serialize_into_with_order(bytes, order);
+        self.new_keyboard_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase1 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectNewKeyboard", |tracer| tracer.bitmask(&self.affect_new_keyboard, NKNDetail::VARIANTS))?;
+            tracer.field("newKeyboardDetails", |tracer| tracer.bitmask(&self.new_keyboard_details, NKNDetail::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase2 {
+    pub affect_state: u16,
+    pub state_details: u16,
+}
+impl TryParse for SelectEventsAuxBitcase2 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (state_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase2 { affect_state, state_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase2 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase2 {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_state_bytes = self.affect_state.serialize_with_order(order);
+        let state_details_bytes = self.state_details.serialize_with_order(order);
+        [
+            affect_state_bytes[0],
+            affect_state_bytes[1],
+            state_details_bytes[0],
+            state_details_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.affect_state.serialize_into_with_order(bytes, order);
+        self.state_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase2 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectState", |tracer| tracer.bitmask(&self.affect_state, StatePart::VARIANTS))?;
+            tracer.field("stateDetails", |tracer| tracer.bitmask(&self.state_details, StatePart::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase3 {
+    pub affect_ctrls: u32,
+    pub ctrl_details: u32,
+}
+impl TryParse for SelectEventsAuxBitcase3 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ctrl_details, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase3 { affect_ctrls, ctrl_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase3 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase3 {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let affect_ctrls_bytes = self.affect_ctrls.serialize_with_order(order);
+        let ctrl_details_bytes = self.ctrl_details.serialize_with_order(order);
+        [
+            affect_ctrls_bytes[0],
+            affect_ctrls_bytes[1],
+            affect_ctrls_bytes[2],
+            affect_ctrls_bytes[3],
+            ctrl_details_bytes[0],
+            ctrl_details_bytes[1],
+            ctrl_details_bytes[2],
+            ctrl_details_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.affect_ctrls.serialize_into_with_order(bytes, order);
+        self.ctrl_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase3 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectCtrls", |tracer| tracer.bitmask(&self.affect_ctrls, Control::VARIANTS))?;
+            tracer.field("ctrlDetails", |tracer| tracer.bitmask(&self.ctrl_details, Control::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase4 {
+    pub affect_indicator_state: u32,
+    pub indicator_state_details: u32,
+}
+impl TryParse for SelectEventsAuxBitcase4 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_indicator_state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (indicator_state_details, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase4 { affect_indicator_state, indicator_state_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase4 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase4 {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let affect_indicator_state_bytes = self.affect_indicator_state.serialize_with_order(order);
+        let indicator_state_details_bytes = self.indicator_state_details.serialize_with_order(order);
+        [
+            affect_indicator_state_bytes[0],
+            affect_indicator_state_bytes[1],
+            affect_indicator_state_bytes[2],
+            affect_indicator_state_bytes[3],
+            indicator_state_details_bytes[0],
+            indicator_state_details_bytes[1],
+            indicator_state_details_bytes[2],
+            indicator_state_details_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.affect_indicator_state.serialize_into_with_order(bytes, order);
+        self.indicator_state_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase4 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectIndicatorState", |tracer| tracer.value(&self.affect_indicator_state))?;
+            tracer.field("indicatorStateDetails", |tracer| tracer.value(&self.indicator_state_details))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase5 {
+    pub affect_indicator_map: u32,
+    pub indicator_map_details: u32,
+}
+impl TryParse for SelectEventsAuxBitcase5 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_indicator_map, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (indicator_map_details, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase5 { affect_indicator_map, indicator_map_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase5 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase5 {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let affect_indicator_map_bytes = self.affect_indicator_map.serialize_with_order(order);
+        let indicator_map_details_bytes = self.indicator_map_details.serialize_with_order(order);
+        [
+            affect_indicator_map_bytes[0],
+            affect_indicator_map_bytes[1],
+            affect_indicator_map_bytes[2],
+            affect_indicator_map_bytes[3],
+            indicator_map_details_bytes[0],
+            indicator_map_details_bytes[1],
+            indicator_map_details_bytes[2],
+            indicator_map_details_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.affect_indicator_map.serialize_into_with_order(bytes, order);
+        self.indicator_map_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase5 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectIndicatorMap", |tracer| tracer.value(&self.affect_indicator_map))?;
+            tracer.field("indicatorMapDetails", |tracer| tracer.value(&self.indicator_map_details))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase6 {
+    pub affect_names: u16,
+    pub names_details: u16,
+}
+impl TryParse for SelectEventsAuxBitcase6 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_names, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (names_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase6 { affect_names, names_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase6 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase6 {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_names_bytes = self.affect_names.serialize_with_order(order);
+        let names_details_bytes = self.names_details.serialize_with_order(order);
+        [
+            affect_names_bytes[0],
+            affect_names_bytes[1],
+            names_details_bytes[0],
+            names_details_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.affect_names.serialize_into_with_order(bytes, order);
+        self.names_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase6 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectNames", |tracer| tracer.bitmask(&self.affect_names, NameDetail::VARIANTS))?;
+            tracer.field("namesDetails", |tracer| tracer.bitmask(&self.names_details, NameDetail::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase7 {
+    pub affect_compat: u8,
+    pub compat_details: u8,
+}
+impl TryParse for SelectEventsAuxBitcase7 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_compat, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase7 { affect_compat, compat_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase7 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase7 {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let affect_compat_bytes = self.affect_compat.serialize_with_order(order);
+        let compat_details_bytes = self.compat_details.serialize_with_order(order);
+        [
+            affect_compat_bytes[0],
+            compat_details_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.affect_compat.serialize_into_with_order(bytes, order);
+        self.compat_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase7 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectCompat", |tracer| tracer.bitmask(&self.affect_compat, CMDetail::VARIANTS))?;
+            tracer.field("compatDetails", |tracer| tracer.bitmask(&self.compat_details, CMDetail::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase8 {
+    pub affect_bell: u8,
+    pub bell_details: u8,
+}
+impl TryParse for SelectEventsAuxBitcase8 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_bell, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bell_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase8 { affect_bell, bell_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase8 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase8 {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let affect_bell_bytes = self.affect_bell.serialize_with_order(order);
+        let bell_details_bytes = self.bell_details.serialize_with_order(order);
+        [
+            affect_bell_bytes[0],
+            bell_details_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.affect_bell.serialize_into_with_order(bytes, order);
+        self.bell_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase8 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectBell", |tracer| tracer.value(&self.affect_bell))?;
+            tracer.field("bellDetails", |tracer| tracer.value(&self.bell_details))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase9 {
+    pub affect_msg_details: u8,
+    pub msg_details: u8,
+}
+impl TryParse for SelectEventsAuxBitcase9 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_msg_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (msg_details, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase9 { affect_msg_details, msg_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase9 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase9 {
+    type Bytes = [u8; 2];
+    fn serialize(&self) -> [u8; 2] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 2] {
+        let affect_msg_details_bytes = self.affect_msg_details.serialize_with_order(order);
+        let msg_details_bytes = self.msg_details.serialize_with_order(order);
+        [
+            affect_msg_details_bytes[0],
+            msg_details_bytes[0],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(2);
+        self.affect_msg_details.serialize_into_with_order(bytes, order);
+        self.msg_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase9 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectMsgDetails", |tracer| tracer.value(&self.affect_msg_details))?;
+            tracer.field("msgDetails", |tracer| tracer.value(&self.msg_details))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase10 {
+    pub affect_access_x: u16,
+    pub access_x_details: u16,
+}
+impl TryParse for SelectEventsAuxBitcase10 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_access_x, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase10 { affect_access_x, access_x_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase10 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase10 {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_access_x_bytes = self.affect_access_x.serialize_with_order(order);
+        let access_x_details_bytes = self.access_x_details.serialize_with_order(order);
+        [
+            affect_access_x_bytes[0],
+            affect_access_x_bytes[1],
+            access_x_details_bytes[0],
+            access_x_details_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.affect_access_x.serialize_into_with_order(bytes, order);
+        self.access_x_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase10 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectAccessX", |tracer| tracer.bitmask(&self.affect_access_x, AXNDetail::VARIANTS))?;
+            tracer.field("accessXDetails", |tracer| tracer.bitmask(&self.access_x_details, AXNDetail::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAuxBitcase11 {
+    pub affect_ext_dev: u16,
+    pub extdev_details: u16,
+}
+impl TryParse for SelectEventsAuxBitcase11 {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (affect_ext_dev, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (extdev_details, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let result = SelectEventsAuxBitcase11 { affect_ext_dev, extdev_details };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SelectEventsAuxBitcase11 {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for SelectEventsAuxBitcase11 {
+    type Bytes = [u8; 4];
+    fn serialize(&self) -> [u8; 4] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 4] {
+        let affect_ext_dev_bytes = self.affect_ext_dev.serialize_with_order(order);
+        let extdev_details_bytes = self.extdev_details.serialize_with_order(order);
+        [
+            affect_ext_dev_bytes[0],
+            affect_ext_dev_bytes[1],
+            extdev_details_bytes[0],
+            extdev_details_bytes[1],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(4);
+        self.affect_ext_dev.serialize_into_with_order(bytes, order);
+        self.extdev_details.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SelectEventsAuxBitcase11 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("affectExtDev", |tracer| tracer.bitmask(&self.affect_ext_dev, XIFeature::VARIANTS))?;
+            tracer.field("extdevDetails", |tracer| tracer.bitmask(&self.extdev_details, XIFeature::VARIANTS))?;
+            Ok(())
+        })
+    }
+}
+/// Auxiliary and optional information for the `select_events` function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectEventsAux {
+    pub bitcase1: Option<SelectEventsAuxBitcase1>,
+    pub bitcase2: Option<SelectEventsAuxBitcase2>,
+    pub bitcase3: Option<SelectEventsAuxBitcase3>,
+    pub bitcase4: Option<SelectEventsAuxBitcase4>,
+    pub bitcase5: Option<SelectEventsAuxBitcase5>,
+    pub bitcase6: Option<SelectEventsAuxBitcase6>,
+    pub bitcase7: Option<SelectEventsAuxBitcase7>,
+    pub bitcase8: Option<SelectEventsAuxBitcase8>,
+    pub bitcase9: Option<SelectEventsAuxBitcase9>,
+    pub bitcase10: Option<SelectEventsAuxBitcase10>,
+    pub bitcase11: Option<SelectEventsAuxBitcase11>,
+}
+impl Trace for SelectEventsAux {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.bitcase1 {
+                tracer.field("bitcase1", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase2 {
+                tracer.field("bitcase2", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase3 {
+                tracer.field("bitcase3", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase4 {
+                tracer.field("bitcase4", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase5 {
+                tracer.field("bitcase5", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase6 {
+                tracer.field("bitcase6", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase7 {
+                tracer.field("bitcase7", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase8 {
+                tracer.field("bitcase8", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase9 {
+                tracer.field("bitcase9", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase10 {
+                tracer.field("bitcase10", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.bitcase11 {
+                tracer.field("bitcase11", |tracer| tracer.value(value))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl SelectEventsAux {
+    fn try_parse_with_order(value: &[u8], affect_which: u16, clear: u16, select_all: u16, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(affect_which) & ((!u32::from(clear)) & (!u32::from(select_all)));
+        let mut outer_remaining = value;
+        let bitcase1 = if switch_expr & u32::from(EventType::NEW_KEYBOARD_NOTIFY) != 0 {
+            let (bitcase1, new_remaining) = SelectEventsAuxBitcase1::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase1)
+        } else {
+            None
+        };
+        let bitcase2 = if switch_expr & u32::from(EventType::STATE_NOTIFY) != 0 {
+            let (bitcase2, new_remaining) = SelectEventsAuxBitcase2::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase2)
+        } else {
+            None
+        };
+        let bitcase3 = if switch_expr & u32::from(EventType::CONTROLS_NOTIFY) != 0 {
+            let (bitcase3, new_remaining) = SelectEventsAuxBitcase3::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase3)
+        } else {
+            None
+        };
+        let bitcase4 = if switch_expr & u32::from(EventType::INDICATOR_STATE_NOTIFY) != 0 {
+            let (bitcase4, new_remaining) = SelectEventsAuxBitcase4::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase4)
+        } else {
+            None
+        };
+        let bitcase5 = if switch_expr & u32::from(EventType::INDICATOR_MAP_NOTIFY) != 0 {
+            let (bitcase5, new_remaining) = SelectEventsAuxBitcase5::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase5)
+        } else {
+            None
+        };
+        let bitcase6 = if switch_expr & u32::from(EventType::NAMES_NOTIFY) != 0 {
+            let (bitcase6, new_remaining) = SelectEventsAuxBitcase6::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase6)
+        } else {
+            None
+        };
+        let bitcase7 = if switch_expr & u32::from(EventType::COMPAT_MAP_NOTIFY) != 0 {
+            let (bitcase7, new_remaining) = SelectEventsAuxBitcase7::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase7)
+        } else {
+            None
+        };
+        let bitcase8 = if switch_expr & u32::from(EventType::BELL_NOTIFY) != 0 {
+            let (bitcase8, new_remaining) = SelectEventsAuxBitcase8::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase8)
+        } else {
+            None
+        };
+        let bitcase9 = if switch_expr & u32::from(EventType::ACTION_MESSAGE) != 0 {
+            let (bitcase9, new_remaining) = SelectEventsAuxBitcase9::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase9)
+        } else {
+            None
+        };
+        let bitcase10 = if switch_expr & u32::from(EventType::ACCESS_X_NOTIFY) != 0 {
+            let (bitcase10, new_remaining) = SelectEventsAuxBitcase10::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase10)
+        } else {
+            None
+        };
+        let bitcase11 = if switch_expr & u32::from(EventType::EXTENSION_DEVICE_NOTIFY) != 0 {
+            let (bitcase11, new_remaining) = SelectEventsAuxBitcase11::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase11)
+        } else {
+            None
+        };
+        let result = SelectEventsAux { bitcase1, bitcase2, bitcase3, bitcase4, bitcase5, bitcase6, bitcase7, bitcase8, bitcase9, bitcase10, bitcase11 };
+        Ok((result, outer_remaining))
+    }
+}
+impl SelectEventsAux {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, affect_which: u16, clear: u16, select_all: u16, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, affect_which, clear, select_all, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, affect_which: u16, clear: u16, select_all: u16, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), u32::from(affect_which) & ((!u32::from(clear)) & (!u32::from(select_all))), "switch `details` has an inconsistent discriminant");
+        if let Some(ref bitcase1) = self.bitcase1 {
+            bitcase1.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase2) = self.bitcase2 {
+            bitcase2.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase3) = self.bitcase3 {
+            bitcase3.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase4) = self.bitcase4 {
+            bitcase4.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase5) = self.bitcase5 {
+            bitcase5.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase6) = self.bitcase6 {
+            bitcase6.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase7) = self.bitcase7 {
+            bitcase7.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase8) = self.bitcase8 {
+            bitcase8.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase9) = self.bitcase9 {
+            bitcase9.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase10) = self.bitcase10 {
+            bitcase10.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase11) = self.bitcase11 {
+            bitcase11.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl SelectEventsAux {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.bitcase1.is_some() {
+            expr_value |= u32::from(EventType::NEW_KEYBOARD_NOTIFY);
+        }
+        if self.bitcase2.is_some() {
+            expr_value |= u32::from(EventType::STATE_NOTIFY);
+        }
+        if self.bitcase3.is_some() {
+            expr_value |= u32::from(EventType::CONTROLS_NOTIFY);
+        }
+        if self.bitcase4.is_some() {
+            expr_value |= u32::from(EventType::INDICATOR_STATE_NOTIFY);
+        }
+        if self.bitcase5.is_some() {
+            expr_value |= u32::from(EventType::INDICATOR_MAP_NOTIFY);
+        }
+        if self.bitcase6.is_some() {
+            expr_value |= u32::from(EventType::NAMES_NOTIFY);
+        }
+        if self.bitcase7.is_some() {
+            expr_value |= u32::from(EventType::COMPAT_MAP_NOTIFY);
+        }
+        if self.bitcase8.is_some() {
+            expr_value |= u32::from(EventType::BELL_NOTIFY);
+        }
+        if self.bitcase9.is_some() {
+            expr_value |= u32::from(EventType::ACTION_MESSAGE);
+        }
+        if self.bitcase10.is_some() {
+            expr_value |= u32::from(EventType::ACCESS_X_NOTIFY);
+        }
+        if self.bitcase11.is_some() {
+            expr_value |= u32::from(EventType::EXTENSION_DEVICE_NOTIFY);
+        }
+        expr_value
+    }
+}
+impl SelectEventsAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `bitcase1` field of this structure.
+    pub fn bitcase1<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase1>> {
+        self.bitcase1 = value.into();
+        self
+    }
+    /// Set the `bitcase2` field of this structure.
+    pub fn bitcase2<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase2>> {
+        self.bitcase2 = value.into();
+        self
+    }
+    /// Set the `bitcase3` field of this structure.
+    pub fn bitcase3<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase3>> {
+        self.bitcase3 = value.into();
+        self
+    }
+    /// Set the `bitcase4` field of this structure.
+    pub fn bitcase4<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase4>> {
+        self.bitcase4 = value.into();
+        self
+    }
+    /// Set the `bitcase5` field of this structure.
+    pub fn bitcase5<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase5>> {
+        self.bitcase5 = value.into();
+        self
+    }
+    /// Set the `bitcase6` field of this structure.
+    pub fn bitcase6<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase6>> {
+        self.bitcase6 = value.into();
+        self
+    }
+    /// Set the `bitcase7` field of this structure.
+    pub fn bitcase7<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase7>> {
+        self.bitcase7 = value.into();
+        self
+    }
+    /// Set the `bitcase8` field of this structure.
+    pub fn bitcase8<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase8>> {
+        self.bitcase8 = value.into();
+        self
+    }
+    /// Set the `bitcase9` field of this structure.
+    pub fn bitcase9<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase9>> {
+        self.bitcase9 = value.into();
+        self
+    }
+    /// Set the `bitcase10` field of this structure.
+    pub fn bitcase10<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase10>> {
+        self.bitcase10 = value.into();
+        self
+    }
+    /// Set the `bitcase11` field of this structure.
+    pub fn bitcase11<I>(mut self, value: I) -> Self where I: Into<Option<SelectEventsAuxBitcase11>> {
+        self.bitcase11 = value.into();
+        self
+    }
+}
+
+/// Opcode for the SelectEvents request
+pub const SELECT_EVENTS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectEventsRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub clear: u16,
+    pub select_all: u16,
+    pub affect_map: u16,
+    pub map: u16,
+    pub details: Cow<'input, SelectEventsAux>,
+}
+impl<'input> SelectEventsRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let affect_which = u16::try_from(self.details.switch_expr() | (u32::from(self.clear) | u32::from(self.select_all))).unwrap();
+        let affect_which_bytes = affect_which.serialize_with_order(order);
+        let clear_bytes = self.clear.serialize_with_order(order);
+        let select_all_bytes = self.select_all.serialize_with_order(order);
+        let affect_map_bytes = self.affect_map.serialize_with_order(order);
+        let map_bytes = self.map.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_EVENTS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_which_bytes[0],
+            affect_which_bytes[1],
+            clear_bytes[0],
+            clear_bytes[1],
+            select_all_bytes[0],
+            select_all_bytes[1],
+            affect_map_bytes[0],
+            affect_map_bytes[1],
+            map_bytes[0],
+            map_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let details_bytes = self.details.serialize_with_order(affect_which, self.clear, self.select_all, order);
+        let length_so_far = length_so_far + details_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), details_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_EVENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (affect_which, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (clear, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (select_all, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (affect_map, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (details, remaining) = SelectEventsAux::try_parse_with_order(remaining, affect_which, clear, select_all, order)?;
+        let _ = remaining;
+        Ok(SelectEventsRequest {
+            device_spec,
+            clear,
+            select_all,
+            affect_map,
+            map,
+            details: Cow::Owned(details),
+        })
+    }
+    /// Clone all borrowed data in this SelectEventsRequest.
+    pub fn into_owned(self) -> SelectEventsRequest<'static> {
+        SelectEventsRequest {
+            device_spec: self.device_spec,
+            clear: self.clear,
+            select_all: self.select_all,
+            affect_map: self.affect_map,
+            map: self.map,
+            details: Cow::Owned(self.details.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SelectEventsRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SelectEventsRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("clear", |tracer| tracer.bitmask(&self.clear, EventType::VARIANTS))?;
+        tracer.field("selectAll", |tracer| tracer.bitmask(&self.select_all, EventType::VARIANTS))?;
+        tracer.field("affectMap", |tracer| tracer.bitmask(&self.affect_map, MapPart::VARIANTS))?;
+        tracer.field("map", |tracer| tracer.bitmask(&self.map, MapPart::VARIANTS))?;
+        tracer.field("details", |tracer| tracer.value(&self.details))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the Bell request
+pub const BELL_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BellRequest {
+    pub device_spec: DeviceSpec,
+    pub bell_class: BellClassSpec,
+    pub bell_id: IDSpec,
+    pub percent: i8,
+    pub force_sound: bool,
+    pub event_only: bool,
+    pub pitch: i16,
+    pub duration: i16,
+    pub name: xproto::Atom,
+    pub window: xproto::Window,
+}
+impl BellRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let bell_class_bytes = self.bell_class.serialize_with_order(order);
+        let bell_id_bytes = self.bell_id.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let force_sound_bytes = self.force_sound.serialize_with_order(order);
+        let event_only_bytes = self.event_only.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            BELL_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            bell_class_bytes[0],
+            bell_class_bytes[1],
+            bell_id_bytes[0],
+            bell_id_bytes[1],
+            percent_bytes[0],
+            force_sound_bytes[0],
+            event_only_bytes[0],
+            0,
+            pitch_bytes[0],
+            pitch_bytes[1],
+            duration_bytes[0],
+            duration_bytes[1],
+            0,
+            0,
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != BELL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (bell_class, remaining) = BellClassSpec::try_parse_with_order(remaining, order)?;
+        let (bell_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = i8::try_parse_with_order(remaining, order)?;
+        let (force_sound, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (event_only, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (pitch, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(BellRequest {
+            device_spec,
+            bell_class,
+            bell_id,
+            percent,
+            force_sound,
+            event_only,
+            pitch,
+            duration,
+            name,
+            window,
+        })
+    }
+}
+impl Request for BellRequest {
+    type Reply = ();
+}
+impl Trace for BellRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("bellClass", |tracer| tracer.value(&self.bell_class))?;
+        tracer.field("bellID", |tracer| tracer.value(&self.bell_id))?;
+        tracer.field("percent", |tracer| tracer.value(&self.percent))?;
+        tracer.field("forceSound", |tracer| tracer.value(&self.force_sound))?;
+        tracer.field("eventOnly", |tracer| tracer.value(&self.event_only))?;
+        tracer.field("pitch", |tracer| tracer.value(&self.pitch))?;
+        tracer.field("duration", |tracer| tracer.value(&self.duration))?;
+        tracer.field("name", |tracer| tracer.atom(&self.name))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetState request
+pub const GET_STATE_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetStateRequest {
+    pub device_spec: DeviceSpec,
+}
+impl GetStateRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_STATE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetStateRequest {
+            device_spec,
+        })
+    }
+}
+impl Request for GetStateRequest {
+    type Reply = GetStateReply;
+}
+impl Trace for GetStateRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetStateReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub mods: u8,
+    pub base_mods: u8,
+    pub latched_mods: u8,
+    pub locked_mods: u8,
+    pub group: Group,
+    pub locked_group: Group,
+    pub base_group: i16,
+    pub latched_group: i16,
+    pub compat_state: u8,
+    pub grab_mods: u8,
+    pub compat_grab_mods: u8,
+    pub lookup_mods: u8,
+    pub compat_lookup_mods: u8,
+    pub ptr_btn_state: u16,
+}
+impl TryParse for GetStateReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (latched_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked_group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (latched_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (compat_state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (lookup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_lookup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (ptr_btn_state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(6..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let group = group.into();
+        let locked_group = locked_group.into();
+        let result = GetStateReply { device_id, sequence, length, mods, base_mods, latched_mods, locked_mods, group, locked_group, base_group, latched_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_lookup_mods, ptr_btn_state };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetStateReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetStateReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("mods", |tracer| tracer.bitmask(&self.mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("baseMods", |tracer| tracer.bitmask(&self.base_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("latchedMods", |tracer| tracer.bitmask(&self.latched_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("lockedMods", |tracer| tracer.bitmask(&self.locked_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("group", |tracer| tracer.value(&self.group))?;
+        tracer.field("lockedGroup", |tracer| tracer.value(&self.locked_group))?;
+        tracer.field("baseGroup", |tracer| tracer.value(&self.base_group))?;
+        tracer.field("latchedGroup", |tracer| tracer.value(&self.latched_group))?;
+        tracer.field("compatState", |tracer| tracer.bitmask(&self.compat_state, xproto::ModMask::VARIANTS))?;
+        tracer.field("grabMods", |tracer| tracer.bitmask(&self.grab_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("compatGrabMods", |tracer| tracer.bitmask(&self.compat_grab_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("lookupMods", |tracer| tracer.bitmask(&self.lookup_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("compatLookupMods", |tracer| tracer.bitmask(&self.compat_lookup_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("ptrBtnState", |tracer| tracer.bitmask(&self.ptr_btn_state, xproto::KeyButMask::VARIANTS))?;
+        Ok(())
+    }
+}
+impl Serialize for GetStateReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.mods.serialize_into_with_order(bytes, order);
+        self.base_mods.serialize_into_with_order(bytes, order);
+        self.latched_mods.serialize_into_with_order(bytes, order);
+        self.locked_mods.serialize_into_with_order(bytes, order);
+        u8::from(self.group).serialize_into_with_order(bytes, order);
+        u8::from(self.locked_group).serialize_into_with_order(bytes, order);
+        self.base_group.serialize_into_with_order(bytes, order);
+        self.latched_group.serialize_into_with_order(bytes, order);
+        self.compat_state.serialize_into_with_order(bytes, order);
+        self.grab_mods.serialize_into_with_order(bytes, order);
+        self.compat_grab_mods.serialize_into_with_order(bytes, order);
+        self.lookup_mods.serialize_into_with_order(bytes, order);
+        self.compat_lookup_mods.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.ptr_btn_state.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 6]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the LatchLockState request
+pub const LATCH_LOCK_STATE_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatchLockStateRequest {
+    pub device_spec: DeviceSpec,
+    pub affect_mod_locks: u8,
+    pub mod_locks: u8,
+    pub lock_group: bool,
+    pub group_lock: Group,
+    pub affect_mod_latches: u8,
+    pub latch_group: bool,
+    pub group_latch: u16,
+}
+impl LatchLockStateRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let affect_mod_locks_bytes = self.affect_mod_locks.serialize_with_order(order);
+        let mod_locks_bytes = self.mod_locks.serialize_with_order(order);
+        let lock_group_bytes = self.lock_group.serialize_with_order(order);
+        let group_lock_bytes = u8::from(self.group_lock).serialize_with_order(order);
+        let affect_mod_latches_bytes = self.affect_mod_latches.serialize_with_order(order);
+        let latch_group_bytes = self.latch_group.serialize_with_order(order);
+        let group_latch_bytes = self.group_latch.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            LATCH_LOCK_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_mod_locks_bytes[0],
+            mod_locks_bytes[0],
+            lock_group_bytes[0],
+            group_lock_bytes[0],
+            affect_mod_latches_bytes[0],
+            0,
+            0,
+            latch_group_bytes[0],
+            group_latch_bytes[0],
+            group_latch_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LATCH_LOCK_STATE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (affect_mod_locks, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mod_locks, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (lock_group, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (group_lock, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let group_lock = group_lock.into();
+        let (affect_mod_latches, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (latch_group, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (group_latch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(LatchLockStateRequest {
+            device_spec,
+            affect_mod_locks,
+            mod_locks,
+            lock_group,
+            group_lock,
+            affect_mod_latches,
+            latch_group,
+            group_latch,
+        })
+    }
+}
+impl Request for LatchLockStateRequest {
+    type Reply = ();
+}
+impl Trace for LatchLockStateRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("affectModLocks", |tracer| tracer.bitmask(&self.affect_mod_locks, xproto::ModMask::VARIANTS))?;
+        tracer.field("modLocks", |tracer| tracer.bitmask(&self.mod_locks, xproto::ModMask::VARIANTS))?;
+        tracer.field("lockGroup", |tracer| tracer.value(&self.lock_group))?;
+        tracer.field("groupLock", |tracer| tracer.value(&self.group_lock))?;
+        tracer.field("affectModLatches", |tracer| tracer.bitmask(&self.affect_mod_latches, xproto::ModMask::VARIANTS))?;
+        tracer.field("latchGroup", |tracer| tracer.value(&self.latch_group))?;
+        tracer.field("groupLatch", |tracer| tracer.value(&self.group_latch))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetControls request
+pub const GET_CONTROLS_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetControlsRequest {
+    pub device_spec: DeviceSpec,
+}
+impl GetControlsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_CONTROLS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_CONTROLS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetControlsRequest {
+            device_spec,
+        })
+    }
+}
+impl Request for GetControlsRequest {
+    type Reply = GetControlsReply;
+}
+impl Trace for GetControlsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetControlsReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub mouse_keys_dflt_btn: u8,
+    pub num_groups: u8,
+    pub groups_wrap: u8,
+    pub internal_mods_mask: u8,
+    pub ignore_lock_mods_mask: u8,
+    pub internal_mods_real_mods: u8,
+    pub ignore_lock_mods_real_mods: u8,
+    pub internal_mods_vmods: u16,
+    pub ignore_lock_mods_vmods: u16,
+    pub repeat_delay: u16,
+    pub repeat_interval: u16,
+    pub slow_keys_delay: u16,
+    pub debounce_delay: u16,
+    pub mouse_keys_delay: u16,
+    pub mouse_keys_interval: u16,
+    pub mouse_keys_time_to_max: u16,
+    pub mouse_keys_max_speed: u16,
+    pub mouse_keys_curve: i16,
+    pub access_x_option: u16,
+    pub access_x_timeout: u16,
+    pub access_x_timeout_options_mask: u16,
+    pub access_x_timeout_options_values: u16,
+    pub access_x_timeout_mask: u32,
+    pub access_x_timeout_values: u32,
+    pub enabled_controls: u32,
+    pub per_key_repeat: [u8; 32],
+}
+impl TryParse for GetControlsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_dflt_btn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (groups_wrap, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (internal_mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_mods_mask, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (internal_mods_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_mods_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (internal_mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_mods_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (repeat_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (repeat_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (slow_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (debounce_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_time_to_max, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_max_speed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_curve, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (access_x_option, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_values, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (access_x_timeout_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (per_key_repeat, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
+        let per_key_repeat = <[u8; 32]>::try_from(per_key_repeat).unwrap();
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetControlsReply { device_id, sequence, length, mouse_keys_dflt_btn, num_groups, groups_wrap, internal_mods_mask, ignore_lock_mods_mask, internal_mods_real_mods, ignore_lock_mods_real_mods, internal_mods_vmods, ignore_lock_mods_vmods, repeat_delay, repeat_interval, slow_keys_delay, debounce_delay, mouse_keys_delay, mouse_keys_interval, mouse_keys_time_to_max, mouse_keys_max_speed, mouse_keys_curve, access_x_option, access_x_timeout, access_x_timeout_options_mask, access_x_timeout_options_values, access_x_timeout_mask, access_x_timeout_values, enabled_controls, per_key_repeat };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetControlsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetControlsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("mouseKeysDfltBtn", |tracer| tracer.value(&self.mouse_keys_dflt_btn))?;
+        tracer.field("numGroups", |tracer| tracer.value(&self.num_groups))?;
+        tracer.field("groupsWrap", |tracer| tracer.value(&self.groups_wrap))?;
+        tracer.field("internalModsMask", |tracer| tracer.bitmask(&self.internal_mods_mask, xproto::ModMask::VARIANTS))?;
+        tracer.field("ignoreLockModsMask", |tracer| tracer.bitmask(&self.ignore_lock_mods_mask, xproto::ModMask::VARIANTS))?;
+        tracer.field("internalModsRealMods", |tracer| tracer.bitmask(&self.internal_mods_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("ignoreLockModsRealMods", |tracer| tracer.bitmask(&self.ignore_lock_mods_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("internalModsVmods", |tracer| tracer.bitmask(&self.internal_mods_vmods, VMod::VARIANTS))?;
+        tracer.field("ignoreLockModsVmods", |tracer| tracer.bitmask(&self.ignore_lock_mods_vmods, VMod::VARIANTS))?;
+        tracer.field("repeatDelay", |tracer| tracer.value(&self.repeat_delay))?;
+        tracer.field("repeatInterval", |tracer| tracer.value(&self.repeat_interval))?;
+        tracer.field("slowKeysDelay", |tracer| tracer.value(&self.slow_keys_delay))?;
+        tracer.field("debounceDelay", |tracer| tracer.value(&self.debounce_delay))?;
+        tracer.field("mouseKeysDelay", |tracer| tracer.value(&self.mouse_keys_delay))?;
+        tracer.field("mouseKeysInterval", |tracer| tracer.value(&self.mouse_keys_interval))?;
+        tracer.field("mouseKeysTimeToMax", |tracer| tracer.value(&self.mouse_keys_time_to_max))?;
+        tracer.field("mouseKeysMaxSpeed", |tracer| tracer.value(&self.mouse_keys_max_speed))?;
+        tracer.field("mouseKeysCurve", |tracer| tracer.value(&self.mouse_keys_curve))?;
+        tracer.field("accessXOption", |tracer| tracer.bitmask(&self.access_x_option, AXOption::VARIANTS))?;
+        tracer.field("accessXTimeout", |tracer| tracer.value(&self.access_x_timeout))?;
+        tracer.field("accessXTimeoutOptionsMask", |tracer| tracer.bitmask(&self.access_x_timeout_options_mask, AXOption::VARIANTS))?;
+        tracer.field("accessXTimeoutOptionsValues", |tracer| tracer.bitmask(&self.access_x_timeout_options_values, AXOption::VARIANTS))?;
+        tracer.field("accessXTimeoutMask", |tracer| tracer.bitmask(&self.access_x_timeout_mask, BoolCtrl::VARIANTS))?;
+        tracer.field("accessXTimeoutValues", |tracer| tracer.bitmask(&self.access_x_timeout_values, BoolCtrl::VARIANTS))?;
+        tracer.field("enabledControls", |tracer| tracer.bitmask(&self.enabled_controls, BoolCtrl::VARIANTS))?;
+        tracer.field("perKeyRepeat", |tracer| tracer.list(&self.per_key_repeat, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetControlsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.mouse_keys_dflt_btn.serialize_into_with_order(bytes, order);
+        self.num_groups.serialize_into_with_order(bytes, order);
+        self.groups_wrap.serialize_into_with_order(bytes, order);
+        self.internal_mods_mask.serialize_into_with_order(bytes, order);
+        self.ignore_lock_mods_mask.serialize_into_with_order(bytes, order);
+        self.internal_mods_real_mods.serialize_into_with_order(bytes, order);
+        self.ignore_lock_mods_real_mods.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.internal_mods_vmods.serialize_into_with_order(bytes, order);
+        self.ignore_lock_mods_vmods.serialize_into_with_order(bytes, order);
+        self.repeat_delay.serialize_into_with_order(bytes, order);
+        self.repeat_interval.serialize_into_with_order(bytes, order);
+        self.slow_keys_delay.serialize_into_with_order(bytes, order);
+        self.debounce_delay.serialize_into_with_order(bytes, order);
+        self.mouse_keys_delay.serialize_into_with_order(bytes, order);
+        self.mouse_keys_interval.serialize_into_with_order(bytes, order);
+        self.mouse_keys_time_to_max.serialize_into_with_order(bytes, order);
+        self.mouse_keys_max_speed.serialize_into_with_order(bytes, order);
+        self.mouse_keys_curve.serialize_into_with_order(bytes, order);
+        self.access_x_option.serialize_into_with_order(bytes, order);
+        self.access_x_timeout.serialize_into_with_order(bytes, order);
+        self.access_x_timeout_options_mask.serialize_into_with_order(bytes, order);
+        self.access_x_timeout_options_values.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.access_x_timeout_mask.serialize_into_with_order(bytes, order);
+        self.access_x_timeout_values.serialize_into_with_order(bytes, order);
+        self.enabled_controls.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.per_key_repeat);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetControls request
+pub const SET_CONTROLS_REQUEST: u8 = 7;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetControlsRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub affect_internal_real_mods: u8,
+    pub internal_real_mods: u8,
+    pub affect_ignore_lock_real_mods: u8,
+    pub ignore_lock_real_mods: u8,
+    pub affect_internal_virtual_mods: u16,
+    pub internal_virtual_mods: u16,
+    pub affect_ignore_lock_virtual_mods: u16,
+    pub ignore_lock_virtual_mods: u16,
+    pub mouse_keys_dflt_btn: u8,
+    pub groups_wrap: u8,
+    pub access_x_options: u16,
+    pub affect_enabled_controls: u32,
+    pub enabled_controls: u32,
+    pub change_controls: u32,
+    pub repeat_delay: u16,
+    pub repeat_interval: u16,
+    pub slow_keys_delay: u16,
+    pub debounce_delay: u16,
+    pub mouse_keys_delay: u16,
+    pub mouse_keys_interval: u16,
+    pub mouse_keys_time_to_max: u16,
+    pub mouse_keys_max_speed: u16,
+    pub mouse_keys_curve: i16,
+    pub access_x_timeout: u16,
+    pub access_x_timeout_mask: u32,
+    pub access_x_timeout_values: u32,
+    pub access_x_timeout_options_mask: u16,
+    pub access_x_timeout_options_values: u16,
+    pub per_key_repeat: Cow<'input, [u8; 32]>,
+}
+impl<'input> SetControlsRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let affect_internal_real_mods_bytes = self.affect_internal_real_mods.serialize_with_order(order);
+        let internal_real_mods_bytes = self.internal_real_mods.serialize_with_order(order);
+        let affect_ignore_lock_real_mods_bytes = self.affect_ignore_lock_real_mods.serialize_with_order(order);
+        let ignore_lock_real_mods_bytes = self.ignore_lock_real_mods.serialize_with_order(order);
+        let affect_internal_virtual_mods_bytes = self.affect_internal_virtual_mods.serialize_with_order(order);
+        let internal_virtual_mods_bytes = self.internal_virtual_mods.serialize_with_order(order);
+        let affect_ignore_lock_virtual_mods_bytes = self.affect_ignore_lock_virtual_mods.serialize_with_order(order);
+        let ignore_lock_virtual_mods_bytes = self.ignore_lock_virtual_mods.serialize_with_order(order);
+        let mouse_keys_dflt_btn_bytes = self.mouse_keys_dflt_btn.serialize_with_order(order);
+        let groups_wrap_bytes = self.groups_wrap.serialize_with_order(order);
+        let access_x_options_bytes = self.access_x_options.serialize_with_order(order);
+        let affect_enabled_controls_bytes = self.affect_enabled_controls.serialize_with_order(order);
+        let enabled_controls_bytes = self.enabled_controls.serialize_with_order(order);
+        let change_controls_bytes = self.change_controls.serialize_with_order(order);
+        let repeat_delay_bytes = self.repeat_delay.serialize_with_order(order);
+        let repeat_interval_bytes = self.repeat_interval.serialize_with_order(order);
+        let slow_keys_delay_bytes = self.slow_keys_delay.serialize_with_order(order);
+        let debounce_delay_bytes = self.debounce_delay.serialize_with_order(order);
+        let mouse_keys_delay_bytes = self.mouse_keys_delay.serialize_with_order(order);
+        let mouse_keys_interval_bytes = self.mouse_keys_interval.serialize_with_order(order);
+        let mouse_keys_time_to_max_bytes = self.mouse_keys_time_to_max.serialize_with_order(order);
+        let mouse_keys_max_speed_bytes = self.mouse_keys_max_speed.serialize_with_order(order);
+        let mouse_keys_curve_bytes = self.mouse_keys_curve.serialize_with_order(order);
+        let access_x_timeout_bytes = self.access_x_timeout.serialize_with_order(order);
+        let access_x_timeout_mask_bytes = self.access_x_timeout_mask.serialize_with_order(order);
+        let access_x_timeout_values_bytes = self.access_x_timeout_values.serialize_with_order(order);
+        let access_x_timeout_options_mask_bytes = self.access_x_timeout_options_mask.serialize_with_order(order);
+        let access_x_timeout_options_values_bytes = self.access_x_timeout_options_values.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_CONTROLS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            affect_internal_real_mods_bytes[0],
+            internal_real_mods_bytes[0],
+            affect_ignore_lock_real_mods_bytes[0],
+            ignore_lock_real_mods_bytes[0],
+            affect_internal_virtual_mods_bytes[0],
+            affect_internal_virtual_mods_bytes[1],
+            internal_virtual_mods_bytes[0],
+            internal_virtual_mods_bytes[1],
+            affect_ignore_lock_virtual_mods_bytes[0],
+            affect_ignore_lock_virtual_mods_bytes[1],
+            ignore_lock_virtual_mods_bytes[0],
+            ignore_lock_virtual_mods_bytes[1],
+            mouse_keys_dflt_btn_bytes[0],
+            groups_wrap_bytes[0],
+            access_x_options_bytes[0],
+            access_x_options_bytes[1],
+            0,
+            0,
+            affect_enabled_controls_bytes[0],
+            affect_enabled_controls_bytes[1],
+            affect_enabled_controls_bytes[2],
+            affect_enabled_controls_bytes[3],
+            enabled_controls_bytes[0],
+            enabled_controls_bytes[1],
+            enabled_controls_bytes[2],
+            enabled_controls_bytes[3],
+            change_controls_bytes[0],
+            change_controls_bytes[1],
+            change_controls_bytes[2],
+            change_controls_bytes[3],
+            repeat_delay_bytes[0],
+            repeat_delay_bytes[1],
+            repeat_interval_bytes[0],
+            repeat_interval_bytes[1],
+            slow_keys_delay_bytes[0],
+            slow_keys_delay_bytes[1],
+            debounce_delay_bytes[0],
+            debounce_delay_bytes[1],
+            mouse_keys_delay_bytes[0],
+            mouse_keys_delay_bytes[1],
+            mouse_keys_interval_bytes[0],
+            mouse_keys_interval_bytes[1],
+            mouse_keys_time_to_max_bytes[0],
+            mouse_keys_time_to_max_bytes[1],
+            mouse_keys_max_speed_bytes[0],
+            mouse_keys_max_speed_bytes[1],
+            mouse_keys_curve_bytes[0],
+            mouse_keys_curve_bytes[1],
+            access_x_timeout_bytes[0],
+            access_x_timeout_bytes[1],
+            access_x_timeout_mask_bytes[0],
+            access_x_timeout_mask_bytes[1],
+            access_x_timeout_mask_bytes[2],
+            access_x_timeout_mask_bytes[3],
+            access_x_timeout_values_bytes[0],
+            access_x_timeout_values_bytes[1],
+            access_x_timeout_values_bytes[2],
+            access_x_timeout_values_bytes[3],
+            access_x_timeout_options_mask_bytes[0],
+            access_x_timeout_options_mask_bytes[1],
+            access_x_timeout_options_values_bytes[0],
+            access_x_timeout_options_values_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.per_key_repeat.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), Cow::Owned(self.per_key_repeat.to_vec())], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_CONTROLS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (affect_internal_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (internal_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect_ignore_lock_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (affect_internal_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (internal_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (affect_ignore_lock_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (ignore_lock_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_dflt_btn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (groups_wrap, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (access_x_options, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (affect_enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (change_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (repeat_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (repeat_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (slow_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (debounce_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_interval, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_time_to_max, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_max_speed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (mouse_keys_curve, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_mask, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (access_x_timeout_options_values, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (per_key_repeat, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
+        let per_key_repeat = <&[u8; 32]>::try_from(per_key_repeat).unwrap();
+        let _ = remaining;
+        Ok(SetControlsRequest {
+            device_spec,
+            affect_internal_real_mods,
+            internal_real_mods,
+            affect_ignore_lock_real_mods,
+            ignore_lock_real_mods,
+            affect_internal_virtual_mods,
+            internal_virtual_mods,
+            affect_ignore_lock_virtual_mods,
+            ignore_lock_virtual_mods,
+            mouse_keys_dflt_btn,
+            groups_wrap,
+            access_x_options,
+            affect_enabled_controls,
+            enabled_controls,
+            change_controls,
+            repeat_delay,
+            repeat_interval,
+            slow_keys_delay,
+            debounce_delay,
+            mouse_keys_delay,
+            mouse_keys_interval,
+            mouse_keys_time_to_max,
+            mouse_keys_max_speed,
+            mouse_keys_curve,
+            access_x_timeout,
+            access_x_timeout_mask,
+            access_x_timeout_values,
+            access_x_timeout_options_mask,
+            access_x_timeout_options_values,
+            per_key_repeat: Cow::Borrowed(per_key_repeat),
+        })
+    }
+    /// Clone all borrowed data in this SetControlsRequest.
+    pub fn into_owned(self) -> SetControlsRequest<'static> {
+        SetControlsRequest {
+            device_spec: self.device_spec,
+            affect_internal_real_mods: self.affect_internal_real_mods,
+            internal_real_mods: self.internal_real_mods,
+            affect_ignore_lock_real_mods: self.affect_ignore_lock_real_mods,
+            ignore_lock_real_mods: self.ignore_lock_real_mods,
+            affect_internal_virtual_mods: self.affect_internal_virtual_mods,
+            internal_virtual_mods: self.internal_virtual_mods,
+            affect_ignore_lock_virtual_mods: self.affect_ignore_lock_virtual_mods,
+            ignore_lock_virtual_mods: self.ignore_lock_virtual_mods,
+            mouse_keys_dflt_btn: self.mouse_keys_dflt_btn,
+            groups_wrap: self.groups_wrap,
+            access_x_options: self.access_x_options,
+            affect_enabled_controls: self.affect_enabled_controls,
+            enabled_controls: self.enabled_controls,
+            change_controls: self.change_controls,
+            repeat_delay: self.repeat_delay,
+            repeat_interval: self.repeat_interval,
+            slow_keys_delay: self.slow_keys_delay,
+            debounce_delay: self.debounce_delay,
+            mouse_keys_delay: self.mouse_keys_delay,
+            mouse_keys_interval: self.mouse_keys_interval,
+            mouse_keys_time_to_max: self.mouse_keys_time_to_max,
+            mouse_keys_max_speed: self.mouse_keys_max_speed,
+            mouse_keys_curve: self.mouse_keys_curve,
+            access_x_timeout: self.access_x_timeout,
+            access_x_timeout_mask: self.access_x_timeout_mask,
+            access_x_timeout_values: self.access_x_timeout_values,
+            access_x_timeout_options_mask: self.access_x_timeout_options_mask,
+            access_x_timeout_options_values: self.access_x_timeout_options_values,
+            per_key_repeat: Cow::Owned(self.per_key_repeat.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetControlsRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetControlsRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("affectInternalRealMods", |tracer| tracer.bitmask(&self.affect_internal_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("internalRealMods", |tracer| tracer.bitmask(&self.internal_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("affectIgnoreLockRealMods", |tracer| tracer.bitmask(&self.affect_ignore_lock_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("ignoreLockRealMods", |tracer| tracer.bitmask(&self.ignore_lock_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("affectInternalVirtualMods", |tracer| tracer.bitmask(&self.affect_internal_virtual_mods, VMod::VARIANTS))?;
+        tracer.field("internalVirtualMods", |tracer| tracer.bitmask(&self.internal_virtual_mods, VMod::VARIANTS))?;
+        tracer.field("affectIgnoreLockVirtualMods", |tracer| tracer.bitmask(&self.affect_ignore_lock_virtual_mods, VMod::VARIANTS))?;
+        tracer.field("ignoreLockVirtualMods", |tracer| tracer.bitmask(&self.ignore_lock_virtual_mods, VMod::VARIANTS))?;
+        tracer.field("mouseKeysDfltBtn", |tracer| tracer.value(&self.mouse_keys_dflt_btn))?;
+        tracer.field("groupsWrap", |tracer| tracer.value(&self.groups_wrap))?;
+        tracer.field("accessXOptions", |tracer| tracer.bitmask(&self.access_x_options, AXOption::VARIANTS))?;
+        tracer.field("affectEnabledControls", |tracer| tracer.bitmask(&self.affect_enabled_controls, BoolCtrl::VARIANTS))?;
+        tracer.field("enabledControls", |tracer| tracer.bitmask(&self.enabled_controls, BoolCtrl::VARIANTS))?;
+        tracer.field("changeControls", |tracer| tracer.bitmask(&self.change_controls, Control::VARIANTS))?;
+        tracer.field("repeatDelay", |tracer| tracer.value(&self.repeat_delay))?;
+        tracer.field("repeatInterval", |tracer| tracer.value(&self.repeat_interval))?;
+        tracer.field("slowKeysDelay", |tracer| tracer.value(&self.slow_keys_delay))?;
+        tracer.field("debounceDelay", |tracer| tracer.value(&self.debounce_delay))?;
+        tracer.field("mouseKeysDelay", |tracer| tracer.value(&self.mouse_keys_delay))?;
+        tracer.field("mouseKeysInterval", |tracer| tracer.value(&self.mouse_keys_interval))?;
+        tracer.field("mouseKeysTimeToMax", |tracer| tracer.value(&self.mouse_keys_time_to_max))?;
+        tracer.field("mouseKeysMaxSpeed", |tracer| tracer.value(&self.mouse_keys_max_speed))?;
+        tracer.field("mouseKeysCurve", |tracer| tracer.value(&self.mouse_keys_curve))?;
+        tracer.field("accessXTimeout", |tracer| tracer.value(&self.access_x_timeout))?;
+        tracer.field("accessXTimeoutMask", |tracer| tracer.bitmask(&self.access_x_timeout_mask, BoolCtrl::VARIANTS))?;
+        tracer.field("accessXTimeoutValues", |tracer| tracer.bitmask(&self.access_x_timeout_values, BoolCtrl::VARIANTS))?;
+        tracer.field("accessXTimeoutOptionsMask", |tracer| tracer.bitmask(&self.access_x_timeout_options_mask, AXOption::VARIANTS))?;
+        tracer.field("accessXTimeoutOptionsValues", |tracer| tracer.bitmask(&self.access_x_timeout_options_values, AXOption::VARIANTS))?;
+        tracer.field("perKeyRepeat", |tracer| tracer.list(&self.per_key_repeat[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetMap request
+pub const GET_MAP_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetMapRequest {
+    pub device_spec: DeviceSpec,
+    pub full: u16,
+    pub partial: u16,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub n_key_syms: u8,
+    pub first_key_action: xproto::Keycode,
+    pub n_key_actions: u8,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behaviors: u8,
+    pub virtual_mods: u16,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+}
+impl GetMapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let full_bytes = self.full.serialize_with_order(order);
+        let partial_bytes = self.partial.serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_key_sym_bytes = self.first_key_sym.serialize_with_order(order);
+        let n_key_syms_bytes = self.n_key_syms.serialize_with_order(order);
+        let first_key_action_bytes = self.first_key_action.serialize_with_order(order);
+        let n_key_actions_bytes = self.n_key_actions.serialize_with_order(order);
+        let first_key_behavior_bytes = self.first_key_behavior.serialize_with_order(order);
+        let n_key_behaviors_bytes = self.n_key_behaviors.serialize_with_order(order);
+        let virtual_mods_bytes = self.virtual_mods.serialize_with_order(order);
+        let first_key_explicit_bytes = self.first_key_explicit.serialize_with_order(order);
+        let n_key_explicit_bytes = self.n_key_explicit.serialize_with_order(order);
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize_with_order(order);
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize_with_order(order);
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize_with_order(order);
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            full_bytes[0],
+            full_bytes[1],
+            partial_bytes[0],
+            partial_bytes[1],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            first_key_action_bytes[0],
+            n_key_actions_bytes[0],
+            first_key_behavior_bytes[0],
+            n_key_behaviors_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_MAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (full, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (partial, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetMapRequest {
+            device_spec,
+            full,
+            partial,
+            first_type,
+            n_types,
+            first_key_sym,
+            n_key_syms,
+            first_key_action,
+            n_key_actions,
+            first_key_behavior,
+            n_key_behaviors,
+            virtual_mods,
+            first_key_explicit,
+            n_key_explicit,
+            first_mod_map_key,
+            n_mod_map_keys,
+            first_v_mod_map_key,
+            n_v_mod_map_keys,
+        })
+    }
+}
+impl Request for GetMapRequest {
+    type Reply = GetMapReply;
+}
+impl Trace for GetMapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("full", |tracer| tracer.bitmask(&self.full, MapPart::VARIANTS))?;
+        tracer.field("partial", |tracer| tracer.bitmask(&self.partial, MapPart::VARIANTS))?;
+        tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("firstKeySym", |tracer| tracer.value(&self.first_key_sym))?;
+        tracer.field("nKeySyms", |tracer| tracer.value(&self.n_key_syms))?;
+        tracer.field("firstKeyAction", |tracer| tracer.value(&self.first_key_action))?;
+        tracer.field("nKeyActions", |tracer| tracer.value(&self.n_key_actions))?;
+        tracer.field("firstKeyBehavior", |tracer| tracer.value(&self.first_key_behavior))?;
+        tracer.field("nKeyBehaviors", |tracer| tracer.value(&self.n_key_behaviors))?;
+        tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+        tracer.field("firstKeyExplicit", |tracer| tracer.value(&self.first_key_explicit))?;
+        tracer.field("nKeyExplicit", |tracer| tracer.value(&self.n_key_explicit))?;
+        tracer.field("firstModMapKey", |tracer| tracer.value(&self.first_mod_map_key))?;
+        tracer.field("nModMapKeys", |tracer| tracer.value(&self.n_mod_map_keys))?;
+        tracer.field("firstVModMapKey", |tracer| tracer.value(&self.first_v_mod_map_key))?;
+        tracer.field("nVModMapKeys", |tracer| tracer.value(&self.n_v_mod_map_keys))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMapMapBitcase3 {
+    pub acts_rtrn_count: Vec<u8>,
+    pub acts_rtrn_acts: Vec<Action>,
+}
+impl GetMapMapBitcase3 {
+    pub fn try_parse(value: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, n_key_actions, total_actions, ByteOrder::native())
+    }
+    pub fn try_parse_with_order(remaining: &[u8], n_key_actions: u8, total_actions: u16, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let acts_rtrn_count = acts_rtrn_count.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = GetMapMapBitcase3 { acts_rtrn_count, acts_rtrn_acts };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
+impl GetMapMapBitcase3 {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, n_key_actions: u8, total_actions: u16, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, n_key_actions, total_actions, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, n_key_actions: u8, total_actions: u16, order: ByteOrder) {
+        assert_eq!(self.acts_rtrn_count.len(), usize::try_from(n_key_actions).unwrap(), "`acts_rtrn_count` has an incorrect length");
+        bytes.extend_from_slice(&self.acts_rtrn_count);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.acts_rtrn_acts.len(), usize::try_from(total_actions).unwrap(), "`acts_rtrn_acts` has an incorrect length");
+        self.acts_rtrn_acts.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for GetMapMapBitcase3 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("acts-rtrn-count", |tracer| tracer.list(&self.acts_rtrn_count, |tracer, value| tracer.value(value)))?;
+            tracer.field("acts-rtrn-acts", |tracer| tracer.list(&self.acts_rtrn_acts, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMapMap {
+    pub types_rtrn: Option<Vec<KeyType>>,
+    pub syms_rtrn: Option<Vec<KeySymMap>>,
+    pub bitcase3: Option<GetMapMapBitcase3>,
+    pub behaviors_rtrn: Option<Vec<SetBehavior>>,
+    pub vmods_rtrn: Option<Vec<u8>>,
+    pub explicit_rtrn: Option<Vec<SetExplicit>>,
+    pub modmap_rtrn: Option<Vec<KeyModMap>>,
+    pub vmodmap_rtrn: Option<Vec<KeyVModMap>>,
+}
+impl Trace for GetMapMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.types_rtrn {
+                tracer.field("types-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.syms_rtrn {
+                tracer.field("syms-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.bitcase3 {
+                tracer.field("bitcase3", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.behaviors_rtrn {
+                tracer.field("behaviors-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.vmods_rtrn {
+                tracer.field("vmods-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.bitmask(value, xproto::ModMask::VARIANTS)))?;
+            }
+            if let Some(ref value) = self.explicit_rtrn {
+                tracer.field("explicit-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.modmap_rtrn {
+                tracer.field("modmap-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.vmodmap_rtrn {
+                tracer.field("vmodmap-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl GetMapMap {
+    fn try_parse_with_order(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(present);
+        let mut outer_remaining = value;
+        let types_rtrn = if switch_expr & u32::from(MapPart::KEY_TYPES) != 0 {
+            let remaining = outer_remaining;
+            let (types_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyType>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(types_rtrn)
+        } else {
+            None
+        };
+        let syms_rtrn = if switch_expr & u32::from(MapPart::KEY_SYMS) != 0 {
+            let remaining = outer_remaining;
+            let (syms_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(syms_rtrn)
+        } else {
+            None
+        };
+        let bitcase3 = if switch_expr & u32::from(MapPart::KEY_ACTIONS) != 0 {
+            let (bitcase3, new_remaining) = GetMapMapBitcase3::try_parse_with_order(outer_remaining, n_key_actions, total_actions, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase3)
+        } else {
+            None
+        };
+        let behaviors_rtrn = if switch_expr & u32::from(MapPart::KEY_BEHAVIORS) != 0 {
+            let remaining = outer_remaining;
+            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(behaviors_rtrn)
+        } else {
+            None
+        };
+        let vmods_rtrn = if switch_expr & u32::from(MapPart::VIRTUAL_MODS) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (vmods_rtrn, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ConversionFailed))?)?;
+            let vmods_rtrn = vmods_rtrn.to_vec();
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(vmods_rtrn)
+        } else {
+            None
+        };
+        let explicit_rtrn = if switch_expr & u32::from(MapPart::EXPLICIT_COMPONENTS) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(explicit_rtrn)
+        } else {
+            None
+        };
+        let modmap_rtrn = if switch_expr & u32::from(MapPart::MODIFIER_MAP) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(modmap_rtrn)
+        } else {
+            None
+        };
+        let vmodmap_rtrn = if switch_expr & u32::from(MapPart::VIRTUAL_MOD_MAP) != 0 {
+            let remaining = outer_remaining;
+            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(vmodmap_rtrn)
+        } else {
+            None
+        };
+        let result = GetMapMap { types_rtrn, syms_rtrn, bitcase3, behaviors_rtrn, vmods_rtrn, explicit_rtrn, modmap_rtrn, vmodmap_rtrn };
+        Ok((result, outer_remaining))
+    }
+}
+impl GetMapMap {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), u32::from(present), "switch `map` has an inconsistent discriminant");
+        if let Some(ref types_rtrn) = self.types_rtrn {
+            assert_eq!(types_rtrn.len(), usize::try_from(n_types).unwrap(), "`types_rtrn` has an incorrect length");
+            types_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref syms_rtrn) = self.syms_rtrn {
+            assert_eq!(syms_rtrn.len(), usize::try_from(n_key_syms).unwrap(), "`syms_rtrn` has an incorrect length");
+            syms_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase3) = self.bitcase3 {
+            bitcase3.serialize_into_with_order(bytes, n_key_actions, total_actions, order);
+        }
+        if let Some(ref behaviors_rtrn) = self.behaviors_rtrn {
+            assert_eq!(behaviors_rtrn.len(), usize::try_from(total_key_behaviors).unwrap(), "`behaviors_rtrn` has an incorrect length");
+            behaviors_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref vmods_rtrn) = self.vmods_rtrn {
+            assert_eq!(vmods_rtrn.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`vmods_rtrn` has an incorrect length");
+            bytes.extend_from_slice(&vmods_rtrn);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref explicit_rtrn) = self.explicit_rtrn {
+            assert_eq!(explicit_rtrn.len(), usize::try_from(total_key_explicit).unwrap(), "`explicit_rtrn` has an incorrect length");
+            explicit_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref modmap_rtrn) = self.modmap_rtrn {
+            assert_eq!(modmap_rtrn.len(), usize::try_from(total_mod_map_keys).unwrap(), "`modmap_rtrn` has an incorrect length");
+            modmap_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref vmodmap_rtrn) = self.vmodmap_rtrn {
+            assert_eq!(vmodmap_rtrn.len(), usize::try_from(total_v_mod_map_keys).unwrap(), "`vmodmap_rtrn` has an incorrect length");
+            vmodmap_rtrn.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetMapMap {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.types_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KEY_TYPES);
+        }
+        if self.syms_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KEY_SYMS);
+        }
+        if self.bitcase3.is_some() {
+            expr_value |= u32::from(MapPart::KEY_ACTIONS);
+        }
+        if self.behaviors_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KEY_BEHAVIORS);
+        }
+        if self.vmods_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VIRTUAL_MODS);
+        }
+        if self.explicit_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::EXPLICIT_COMPONENTS);
+        }
+        if self.modmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::MODIFIER_MAP);
+        }
+        if self.vmodmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VIRTUAL_MOD_MAP);
+        }
+        expr_value
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetMapReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub total_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub total_syms: u16,
+    pub n_key_syms: u8,
+    pub first_key_action: xproto::Keycode,
+    pub total_actions: u16,
+    pub n_key_actions: u8,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behaviors: u8,
+    pub total_key_behaviors: u8,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub total_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub total_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+    pub total_v_mod_map_keys: u8,
+    pub virtual_mods: u16,
+    pub map: GetMapMap,
+}
+impl TryParse for GetMapReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_actions, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = GetMapMap::try_parse_with_order(remaining, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetMapReply { device_id, sequence, length, min_key_code, max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetMapReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetMapReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("minKeyCode", |tracer| tracer.value(&self.min_key_code))?;
+        tracer.field("maxKeyCode", |tracer| tracer.value(&self.max_key_code))?;
+        tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("totalTypes", |tracer| tracer.value(&self.total_types))?;
+        tracer.field("firstKeySym", |tracer| tracer.value(&self.first_key_sym))?;
+        tracer.field("totalSyms", |tracer| tracer.value(&self.total_syms))?;
+        tracer.field("nKeySyms", |tracer| tracer.value(&self.n_key_syms))?;
+        tracer.field("firstKeyAction", |tracer| tracer.value(&self.first_key_action))?;
+        tracer.field("totalActions", |tracer| tracer.value(&self.total_actions))?;
+        tracer.field("nKeyActions", |tracer| tracer.value(&self.n_key_actions))?;
+        tracer.field("firstKeyBehavior", |tracer| tracer.value(&self.first_key_behavior))?;
+        tracer.field("nKeyBehaviors", |tracer| tracer.value(&self.n_key_behaviors))?;
+        tracer.field("totalKeyBehaviors", |tracer| tracer.value(&self.total_key_behaviors))?;
+        tracer.field("firstKeyExplicit", |tracer| tracer.value(&self.first_key_explicit))?;
+        tracer.field("nKeyExplicit", |tracer| tracer.value(&self.n_key_explicit))?;
+        tracer.field("totalKeyExplicit", |tracer| tracer.value(&self.total_key_explicit))?;
+        tracer.field("firstModMapKey", |tracer| tracer.value(&self.first_mod_map_key))?;
+        tracer.field("nModMapKeys", |tracer| tracer.value(&self.n_mod_map_keys))?;
+        tracer.field("totalModMapKeys", |tracer| tracer.value(&self.total_mod_map_keys))?;
+        tracer.field("firstVModMapKey", |tracer| tracer.value(&self.first_v_mod_map_key))?;
+        tracer.field("nVModMapKeys", |tracer| tracer.value(&self.n_v_mod_map_keys))?;
+        tracer.field("totalVModMapKeys", |tracer| tracer.value(&self.total_v_mod_map_keys))?;
+        tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+        tracer.field("map", |tracer| tracer.value(&self.map))?;
+        Ok(())
+    }
+}
+impl Serialize for GetMapReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.min_key_code.serialize_into_with_order(bytes, order);
+        self.max_key_code.serialize_into_with_order(bytes, order);
+        let present = u16::try_from(self.map.switch_expr()).unwrap();
+        present.serialize_into_with_order(bytes, order);
+        self.first_type.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        self.total_types.serialize_into_with_order(bytes, order);
+        self.first_key_sym.serialize_into_with_order(bytes, order);
+        self.total_syms.serialize_into_with_order(bytes, order);
+        self.n_key_syms.serialize_into_with_order(bytes, order);
+        self.first_key_action.serialize_into_with_order(bytes, order);
+        self.total_actions.serialize_into_with_order(bytes, order);
+        self.n_key_actions.serialize_into_with_order(bytes, order);
+        self.first_key_behavior.serialize_into_with_order(bytes, order);
+        self.n_key_behaviors.serialize_into_with_order(bytes, order);
+        self.total_key_behaviors.serialize_into_with_order(bytes, order);
+        self.first_key_explicit.serialize_into_with_order(bytes, order);
+        self.n_key_explicit.serialize_into_with_order(bytes, order);
+        self.total_key_explicit.serialize_into_with_order(bytes, order);
+        self.first_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.first_v_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.virtual_mods.serialize_into_with_order(bytes, order);
+        self.map.serialize_into_with_order(bytes, present, self.n_types, self.n_key_syms, self.n_key_actions, self.total_actions, self.total_key_behaviors, self.virtual_mods, self.total_key_explicit, self.total_mod_map_keys, self.total_v_mod_map_keys, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMapAuxBitcase3 {
+    pub actions_count: Vec<u8>,
+    pub actions: Vec<Action>,
+}
+impl SetMapAuxBitcase3 {
+    pub fn try_parse(value: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, n_key_actions, total_actions, ByteOrder::native())
+    }
+    pub fn try_parse_with_order(remaining: &[u8], n_key_actions: u8, total_actions: u16, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (actions_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let actions_count = actions_count.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (actions, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = SetMapAuxBitcase3 { actions_count, actions };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
+impl SetMapAuxBitcase3 {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, n_key_actions: u8, total_actions: u16, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, n_key_actions, total_actions, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, n_key_actions: u8, total_actions: u16, order: ByteOrder) {
+        assert_eq!(self.actions_count.len(), usize::try_from(n_key_actions).unwrap(), "`actions_count` has an incorrect length");
+        bytes.extend_from_slice(&self.actions_count);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.actions.len(), usize::try_from(total_actions).unwrap(), "`actions` has an incorrect length");
+        self.actions.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SetMapAuxBitcase3 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("actionsCount", |tracer| tracer.list(&self.actions_count, |tracer, value| tracer.value(value)))?;
+            tracer.field("actions", |tracer| tracer.list(&self.actions, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+/// Auxiliary and optional information for the `set_map` function
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMapAux {
+    pub types: Option<Vec<SetKeyType>>,
+    pub syms: Option<Vec<KeySymMap>>,
+    pub bitcase3: Option<SetMapAuxBitcase3>,
+    pub behaviors: Option<Vec<SetBehavior>>,
+    pub vmods: Option<Vec<u8>>,
+    pub explicit: Option<Vec<SetExplicit>>,
+    pub modmap: Option<Vec<KeyModMap>>,
+    pub vmodmap: Option<Vec<KeyVModMap>>,
+}
+impl Trace for SetMapAux {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.types {
+                tracer.field("types", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.syms {
+                tracer.field("syms", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.bitcase3 {
+                tracer.field("bitcase3", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.behaviors {
+                tracer.field("behaviors", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.vmods {
+                tracer.field("vmods", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.explicit {
+                tracer.field("explicit", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.modmap {
+                tracer.field("modmap", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.vmodmap {
+                tracer.field("vmodmap", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl SetMapAux {
+    fn try_parse_with_order(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(present);
+        let mut outer_remaining = value;
+        let types = if switch_expr & u32::from(MapPart::KEY_TYPES) != 0 {
+            let remaining = outer_remaining;
+            let (types, remaining) = crate::x11_utils::parse_list_with_order::<SetKeyType>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(types)
+        } else {
+            None
+        };
+        let syms = if switch_expr & u32::from(MapPart::KEY_SYMS) != 0 {
+            let remaining = outer_remaining;
+            let (syms, remaining) = crate::x11_utils::parse_list_with_order::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(syms)
+        } else {
+            None
+        };
+        let bitcase3 = if switch_expr & u32::from(MapPart::KEY_ACTIONS) != 0 {
+            let (bitcase3, new_remaining) = SetMapAuxBitcase3::try_parse_with_order(outer_remaining, n_key_actions, total_actions, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase3)
+        } else {
+            None
+        };
+        let behaviors = if switch_expr & u32::from(MapPart::KEY_BEHAVIORS) != 0 {
+            let remaining = outer_remaining;
+            let (behaviors, remaining) = crate::x11_utils::parse_list_with_order::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(behaviors)
+        } else {
+            None
+        };
+        let vmods = if switch_expr & u32::from(MapPart::VIRTUAL_MODS) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (vmods, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ConversionFailed))?)?;
+            let vmods = vmods.to_vec();
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(vmods)
+        } else {
+            None
+        };
+        let explicit = if switch_expr & u32::from(MapPart::EXPLICIT_COMPONENTS) != 0 {
+            let remaining = outer_remaining;
+            let (explicit, remaining) = crate::x11_utils::parse_list_with_order::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(explicit)
+        } else {
+            None
+        };
+        let modmap = if switch_expr & u32::from(MapPart::MODIFIER_MAP) != 0 {
+            let remaining = outer_remaining;
+            let (modmap, remaining) = crate::x11_utils::parse_list_with_order::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(modmap)
+        } else {
+            None
+        };
+        let vmodmap = if switch_expr & u32::from(MapPart::VIRTUAL_MOD_MAP) != 0 {
+            let remaining = outer_remaining;
+            let (vmodmap, remaining) = crate::x11_utils::parse_list_with_order::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(vmodmap)
+        } else {
+            None
+        };
+        let result = SetMapAux { types, syms, bitcase3, behaviors, vmods, explicit, modmap, vmodmap };
+        Ok((result, outer_remaining))
+    }
+}
+impl SetMapAux {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), u32::from(present), "switch `values` has an inconsistent discriminant");
+        if let Some(ref types) = self.types {
+            assert_eq!(types.len(), usize::try_from(n_types).unwrap(), "`types` has an incorrect length");
+            types.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref syms) = self.syms {
+            assert_eq!(syms.len(), usize::try_from(n_key_syms).unwrap(), "`syms` has an incorrect length");
+            syms.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase3) = self.bitcase3 {
+            bitcase3.serialize_into_with_order(bytes, n_key_actions, total_actions, order);
+        }
+        if let Some(ref behaviors) = self.behaviors {
+            assert_eq!(behaviors.len(), usize::try_from(total_key_behaviors).unwrap(), "`behaviors` has an incorrect length");
+            behaviors.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref vmods) = self.vmods {
+            assert_eq!(vmods.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`vmods` has an incorrect length");
+            bytes.extend_from_slice(&vmods);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref explicit) = self.explicit {
+            assert_eq!(explicit.len(), usize::try_from(total_key_explicit).unwrap(), "`explicit` has an incorrect length");
+            explicit.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref modmap) = self.modmap {
+            assert_eq!(modmap.len(), usize::try_from(total_mod_map_keys).unwrap(), "`modmap` has an incorrect length");
+            modmap.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref vmodmap) = self.vmodmap {
+            assert_eq!(vmodmap.len(), usize::try_from(total_v_mod_map_keys).unwrap(), "`vmodmap` has an incorrect length");
+            vmodmap.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl SetMapAux {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.types.is_some() {
+            expr_value |= u32::from(MapPart::KEY_TYPES);
+        }
+        if self.syms.is_some() {
+            expr_value |= u32::from(MapPart::KEY_SYMS);
+        }
+        if self.bitcase3.is_some() {
+            expr_value |= u32::from(MapPart::KEY_ACTIONS);
+        }
+        if self.behaviors.is_some() {
+            expr_value |= u32::from(MapPart::KEY_BEHAVIORS);
+        }
+        if self.vmods.is_some() {
+            expr_value |= u32::from(MapPart::VIRTUAL_MODS);
+        }
+        if self.explicit.is_some() {
+            expr_value |= u32::from(MapPart::EXPLICIT_COMPONENTS);
+        }
+        if self.modmap.is_some() {
+            expr_value |= u32::from(MapPart::MODIFIER_MAP);
+        }
+        if self.vmodmap.is_some() {
+            expr_value |= u32::from(MapPart::VIRTUAL_MOD_MAP);
+        }
+        expr_value
+    }
+}
+impl SetMapAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `types` field of this structure.
+    pub fn types<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetKeyType>>> {
+        self.types = value.into();
+        self
+    }
+    /// Set the `syms` field of this structure.
+    pub fn syms<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeySymMap>>> {
+        self.syms = value.into();
+        self
+    }
+    /// Set the `bitcase3` field of this structure.
+    pub fn bitcase3<I>(mut self, value: I) -> Self where I: Into<Option<SetMapAuxBitcase3>> {
+        self.bitcase3 = value.into();
+        self
+    }
+    /// Set the `behaviors` field of this structure.
+    pub fn behaviors<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetBehavior>>> {
+        self.behaviors = value.into();
+        self
+    }
+    /// Set the `vmods` field of this structure.
+    pub fn vmods<I>(mut self, value: I) -> Self where I: Into<Option<Vec<u8>>> {
+        self.vmods = value.into();
+        self
+    }
+    /// Set the `explicit` field of this structure.
+    pub fn explicit<I>(mut self, value: I) -> Self where I: Into<Option<Vec<SetExplicit>>> {
+        self.explicit = value.into();
+        self
+    }
+    /// Set the `modmap` field of this structure.
+    pub fn modmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyModMap>>> {
+        self.modmap = value.into();
+        self
+    }
+    /// Set the `vmodmap` field of this structure.
+    pub fn vmodmap<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyVModMap>>> {
+        self.vmodmap = value.into();
+        self
+    }
+}
+
+/// Opcode for the SetMap request
+pub const SET_MAP_REQUEST: u8 = 9;
+#[derive(Debug, Clone)]
+pub struct SetMapRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub flags: u16,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub n_key_syms: u8,
+    pub total_syms: u16,
+    pub first_key_action: xproto::Keycode,
+    pub n_key_actions: u8,
+    pub total_actions: u16,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behaviors: u8,
+    pub total_key_behaviors: u8,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub total_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub total_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+    pub total_v_mod_map_keys: u8,
+    pub virtual_mods: u16,
+    pub values: Cow<'input, SetMapAux>,
+}
+impl<'input> SetMapRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let present = u16::try_from(self.values.switch_expr()).unwrap();
+        let present_bytes = present.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let min_key_code_bytes = self.min_key_code.serialize_with_order(order);
+        let max_key_code_bytes = self.max_key_code.serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_key_sym_bytes = self.first_key_sym.serialize_with_order(order);
+        let n_key_syms_bytes = self.n_key_syms.serialize_with_order(order);
+        let total_syms_bytes = self.total_syms.serialize_with_order(order);
+        let first_key_action_bytes = self.first_key_action.serialize_with_order(order);
+        let n_key_actions_bytes = self.n_key_actions.serialize_with_order(order);
+        let total_actions_bytes = self.total_actions.serialize_with_order(order);
+        let first_key_behavior_bytes = self.first_key_behavior.serialize_with_order(order);
+        let n_key_behaviors_bytes = self.n_key_behaviors.serialize_with_order(order);
+        let total_key_behaviors_bytes = self.total_key_behaviors.serialize_with_order(order);
+        let first_key_explicit_bytes = self.first_key_explicit.serialize_with_order(order);
+        let n_key_explicit_bytes = self.n_key_explicit.serialize_with_order(order);
+        let total_key_explicit_bytes = self.total_key_explicit.serialize_with_order(order);
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize_with_order(order);
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize_with_order(order);
+        let total_mod_map_keys_bytes = self.total_mod_map_keys.serialize_with_order(order);
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize_with_order(order);
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize_with_order(order);
+        let total_v_mod_map_keys_bytes = self.total_v_mod_map_keys.serialize_with_order(order);
+        let virtual_mods_bytes = self.virtual_mods.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            present_bytes[0],
+            present_bytes[1],
+            flags_bytes[0],
+            flags_bytes[1],
+            min_key_code_bytes[0],
+            max_key_code_bytes[0],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            total_syms_bytes[0],
+            total_syms_bytes[1],
+            first_key_action_bytes[0],
+            n_key_actions_bytes[0],
+            total_actions_bytes[0],
+            total_actions_bytes[1],
+            first_key_behavior_bytes[0],
+            n_key_behaviors_bytes[0],
+            total_key_behaviors_bytes[0],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            total_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            total_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            total_v_mod_map_keys_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let values_bytes = self.values.serialize_with_order(present, self.n_types, self.n_key_syms, self.n_key_actions, self.total_actions, self.total_key_behaviors, self.virtual_mods, self.total_key_explicit, self.total_mod_map_keys, self.total_v_mod_map_keys, order);
+        let length_so_far = length_so_far + values_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), values_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_MAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_actions, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (values, remaining) = SetMapAux::try_parse_with_order(remaining, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys, order)?;
+        let _ = remaining;
+        Ok(SetMapRequest {
+            device_spec,
+            flags,
+            min_key_code,
+            max_key_code,
+            first_type,
+            n_types,
+            first_key_sym,
+            n_key_syms,
+            total_syms,
+            first_key_action,
+            n_key_actions,
+            total_actions,
+            first_key_behavior,
+            n_key_behaviors,
+            total_key_behaviors,
+            first_key_explicit,
+            n_key_explicit,
+            total_key_explicit,
+            first_mod_map_key,
+            n_mod_map_keys,
+            total_mod_map_keys,
+            first_v_mod_map_key,
+            n_v_mod_map_keys,
+            total_v_mod_map_keys,
+            virtual_mods,
+            values: Cow::Owned(values),
+        })
+    }
+    /// Clone all borrowed data in this SetMapRequest.
+    pub fn into_owned(self) -> SetMapRequest<'static> {
+        SetMapRequest {
+            device_spec: self.device_spec,
+            flags: self.flags,
+            min_key_code: self.min_key_code,
+            max_key_code: self.max_key_code,
+            first_type: self.first_type,
+            n_types: self.n_types,
+            first_key_sym: self.first_key_sym,
+            n_key_syms: self.n_key_syms,
+            total_syms: self.total_syms,
+            first_key_action: self.first_key_action,
+            n_key_actions: self.n_key_actions,
+            total_actions: self.total_actions,
+            first_key_behavior: self.first_key_behavior,
+            n_key_behaviors: self.n_key_behaviors,
+            total_key_behaviors: self.total_key_behaviors,
+            first_key_explicit: self.first_key_explicit,
+            n_key_explicit: self.n_key_explicit,
+            total_key_explicit: self.total_key_explicit,
+            first_mod_map_key: self.first_mod_map_key,
+            n_mod_map_keys: self.n_mod_map_keys,
+            total_mod_map_keys: self.total_mod_map_keys,
+            first_v_mod_map_key: self.first_v_mod_map_key,
+            n_v_mod_map_keys: self.n_v_mod_map_keys,
+            total_v_mod_map_keys: self.total_v_mod_map_keys,
+            virtual_mods: self.virtual_mods,
+            values: Cow::Owned(self.values.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetMapRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetMapRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("flags", |tracer| tracer.bitmask(&self.flags, SetMapFlags::VARIANTS))?;
+        tracer.field("minKeyCode", |tracer| tracer.value(&self.min_key_code))?;
+        tracer.field("maxKeyCode", |tracer| tracer.value(&self.max_key_code))?;
+        tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("firstKeySym", |tracer| tracer.value(&self.first_key_sym))?;
+        tracer.field("nKeySyms", |tracer| tracer.value(&self.n_key_syms))?;
+        tracer.field("totalSyms", |tracer| tracer.value(&self.total_syms))?;
+        tracer.field("firstKeyAction", |tracer| tracer.value(&self.first_key_action))?;
+        tracer.field("nKeyActions", |tracer| tracer.value(&self.n_key_actions))?;
+        tracer.field("totalActions", |tracer| tracer.value(&self.total_actions))?;
+        tracer.field("firstKeyBehavior", |tracer| tracer.value(&self.first_key_behavior))?;
+        tracer.field("nKeyBehaviors", |tracer| tracer.value(&self.n_key_behaviors))?;
+        tracer.field("totalKeyBehaviors", |tracer| tracer.value(&self.total_key_behaviors))?;
+        tracer.field("firstKeyExplicit", |tracer| tracer.value(&self.first_key_explicit))?;
+        tracer.field("nKeyExplicit", |tracer| tracer.value(&self.n_key_explicit))?;
+        tracer.field("totalKeyExplicit", |tracer| tracer.value(&self.total_key_explicit))?;
+        tracer.field("firstModMapKey", |tracer| tracer.value(&self.first_mod_map_key))?;
+        tracer.field("nModMapKeys", |tracer| tracer.value(&self.n_mod_map_keys))?;
+        tracer.field("totalModMapKeys", |tracer| tracer.value(&self.total_mod_map_keys))?;
+        tracer.field("firstVModMapKey", |tracer| tracer.value(&self.first_v_mod_map_key))?;
+        tracer.field("nVModMapKeys", |tracer| tracer.value(&self.n_v_mod_map_keys))?;
+        tracer.field("totalVModMapKeys", |tracer| tracer.value(&self.total_v_mod_map_keys))?;
+        tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+        tracer.field("values", |tracer| tracer.value(&self.values))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetCompatMap request
+pub const GET_COMPAT_MAP_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetCompatMapRequest {
+    pub device_spec: DeviceSpec,
+    pub groups: u8,
+    pub get_all_si: bool,
+    pub first_si: u16,
+    pub n_si: u16,
+}
+impl GetCompatMapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let groups_bytes = self.groups.serialize_with_order(order);
+        let get_all_si_bytes = self.get_all_si.serialize_with_order(order);
+        let first_si_bytes = self.first_si.serialize_with_order(order);
+        let n_si_bytes = self.n_si.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_COMPAT_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            groups_bytes[0],
+            get_all_si_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_COMPAT_MAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (get_all_si, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (first_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetCompatMapRequest {
+            device_spec,
+            groups,
+            get_all_si,
+            first_si,
+            n_si,
+        })
+    }
+}
+impl Request for GetCompatMapRequest {
+    type Reply = GetCompatMapReply;
+}
+impl Trace for GetCompatMapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("groups", |tracer| tracer.bitmask(&self.groups, SetOfGroup::VARIANTS))?;
+        tracer.field("getAllSI", |tracer| tracer.value(&self.get_all_si))?;
+        tracer.field("firstSI", |tracer| tracer.value(&self.first_si))?;
+        tracer.field("nSI", |tracer| tracer.value(&self.n_si))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetCompatMapReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub groups_rtrn: u8,
+    pub first_si_rtrn: u16,
+    pub n_total_si: u16,
+    pub si_rtrn: Vec<SymInterpret>,
+    pub group_rtrn: Vec<ModDef>,
+}
+impl TryParse for GetCompatMapReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (groups_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (first_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_total_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (si_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SymInterpret>(remaining, n_si_rtrn.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (group_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, groups_rtrn.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetCompatMapReply { device_id, sequence, length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetCompatMapReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetCompatMapReply {
+    /// Get the value of the `nSIRtrn` field.
+    ///
+    /// The `nSIRtrn` field is used as the length field of the `si_rtrn` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_si_rtrn(&self) -> u16 {
+        self.si_rtrn.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetCompatMapReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("groupsRtrn", |tracer| tracer.bitmask(&self.groups_rtrn, SetOfGroup::VARIANTS))?;
+        tracer.field("firstSIRtrn", |tracer| tracer.value(&self.first_si_rtrn))?;
+        tracer.field("nTotalSI", |tracer| tracer.value(&self.n_total_si))?;
+        tracer.field("si-rtrn", |tracer| tracer.list(&self.si_rtrn, |tracer, value| tracer.value(value)))?;
+        tracer.field("group-rtrn", |tracer| tracer.list(&self.group_rtrn, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetCompatMapReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.groups_rtrn.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.first_si_rtrn.serialize_into_with_order(bytes, order);
+        let n_si_rtrn = u16::try_from(self.si_rtrn.len()).expect("`si_rtrn` has too many elements");
+        n_si_rtrn.serialize_into_with_order(bytes, order);
+        self.n_total_si.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.si_rtrn.serialize_into_with_order(bytes, order);
+        assert_eq!(self.group_rtrn.len(), usize::try_from(self.groups_rtrn.count_ones()).unwrap(), "`group_rtrn` has an incorrect length");
+        self.group_rtrn.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetCompatMap request
+pub const SET_COMPAT_MAP_REQUEST: u8 = 11;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCompatMapRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub recompute_actions: bool,
+    pub truncate_si: bool,
+    pub groups: u8,
+    pub first_si: u16,
+    pub si: Cow<'input, [SymInterpret]>,
+    pub group_maps: Cow<'input, [ModDef]>,
+}
+impl<'input> SetCompatMapRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let recompute_actions_bytes = self.recompute_actions.serialize_with_order(order);
+        let truncate_si_bytes = self.truncate_si.serialize_with_order(order);
+        let groups_bytes = self.groups.serialize_with_order(order);
+        let first_si_bytes = self.first_si.serialize_with_order(order);
+        let n_si = u16::try_from(self.si.len()).expect("`si` has too many elements");
+        let n_si_bytes = n_si.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_COMPAT_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            recompute_actions_bytes[0],
+            truncate_si_bytes[0],
+            groups_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let si_bytes = self.si.serialize_with_order(order);
+        let length_so_far = length_so_far + si_bytes.len();
+        assert_eq!(self.group_maps.len(), usize::try_from(self.groups.count_ones()).unwrap(), "`group_maps` has an incorrect length");
+        let group_maps_bytes = self.group_maps.serialize_with_order(order);
+        let length_so_far = length_so_far + group_maps_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), si_bytes.into(), group_maps_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_COMPAT_MAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (recompute_actions, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (truncate_si, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (si, remaining) = crate::x11_utils::parse_list_with_order::<SymInterpret>(remaining, n_si.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (group_maps, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, groups.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(SetCompatMapRequest {
+            device_spec,
+            recompute_actions,
+            truncate_si,
+            groups,
+            first_si,
+            si: Cow::Owned(si),
+            group_maps: Cow::Owned(group_maps),
+        })
+    }
+    /// Clone all borrowed data in this SetCompatMapRequest.
+    pub fn into_owned(self) -> SetCompatMapRequest<'static> {
+        SetCompatMapRequest {
+            device_spec: self.device_spec,
+            recompute_actions: self.recompute_actions,
+            truncate_si: self.truncate_si,
+            groups: self.groups,
+            first_si: self.first_si,
+            si: Cow::Owned(self.si.into_owned()),
+            group_maps: Cow::Owned(self.group_maps.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetCompatMapRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetCompatMapRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("recomputeActions", |tracer| tracer.value(&self.recompute_actions))?;
+        tracer.field("truncateSI", |tracer| tracer.value(&self.truncate_si))?;
+        tracer.field("groups", |tracer| tracer.bitmask(&self.groups, SetOfGroup::VARIANTS))?;
+        tracer.field("firstSI", |tracer| tracer.value(&self.first_si))?;
+        tracer.field("si", |tracer| tracer.list(&self.si[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("groupMaps", |tracer| tracer.list(&self.group_maps[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetIndicatorState request
+pub const GET_INDICATOR_STATE_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetIndicatorStateRequest {
+    pub device_spec: DeviceSpec,
+}
+impl GetIndicatorStateRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_INDICATOR_STATE_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_INDICATOR_STATE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetIndicatorStateRequest {
+            device_spec,
+        })
+    }
+}
+impl Request for GetIndicatorStateRequest {
+    type Reply = GetIndicatorStateReply;
+}
+impl Trace for GetIndicatorStateRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetIndicatorStateReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub state: u32,
+}
+impl TryParse for GetIndicatorStateReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetIndicatorStateReply { device_id, sequence, length, state };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetIndicatorStateReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetIndicatorStateReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        Ok(())
+    }
+}
+impl Serialize for GetIndicatorStateReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.state.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetIndicatorMap request
+pub const GET_INDICATOR_MAP_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetIndicatorMapRequest {
+    pub device_spec: DeviceSpec,
+    pub which: u32,
+}
+impl GetIndicatorMapRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let which_bytes = self.which.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_INDICATOR_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_INDICATOR_MAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetIndicatorMapRequest {
+            device_spec,
+            which,
+        })
+    }
+}
+impl Request for GetIndicatorMapRequest {
+    type Reply = GetIndicatorMapReply;
+}
+impl Trace for GetIndicatorMapRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("which", |tracer| tracer.value(&self.which))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetIndicatorMapReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub which: u32,
+    pub real_indicators: u32,
+    pub n_indicators: u8,
+    pub maps: Vec<IndicatorMap>,
+}
+impl TryParse for GetIndicatorMapReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (real_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_indicators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, which.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetIndicatorMapReply { device_id, sequence, length, which, real_indicators, n_indicators, maps };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetIndicatorMapReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetIndicatorMapReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("which", |tracer| tracer.value(&self.which))?;
+        tracer.field("realIndicators", |tracer| tracer.value(&self.real_indicators))?;
+        tracer.field("nIndicators", |tracer| tracer.value(&self.n_indicators))?;
+        tracer.field("maps", |tracer| tracer.list(&self.maps, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetIndicatorMapReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.which.serialize_into_with_order(bytes, order);
+        self.real_indicators.serialize_into_with_order(bytes, order);
+        self.n_indicators.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        assert_eq!(self.maps.len(), usize::try_from(self.which.count_ones()).unwrap(), "`maps` has an incorrect length");
+        self.maps.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetIndicatorMap request
+pub const SET_INDICATOR_MAP_REQUEST: u8 = 14;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetIndicatorMapRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub which: u32,
+    pub maps: Cow<'input, [IndicatorMap]>,
+}
+impl<'input> SetIndicatorMapRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let which_bytes = self.which.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_INDICATOR_MAP_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(self.maps.len(), usize::try_from(self.which.count_ones()).unwrap(), "`maps` has an incorrect length");
+        let maps_bytes = self.maps.serialize_with_order(order);
+        let length_so_far = length_so_far + maps_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), maps_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_INDICATOR_MAP_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, which.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(SetIndicatorMapRequest {
+            device_spec,
+            which,
+            maps: Cow::Owned(maps),
+        })
+    }
+    /// Clone all borrowed data in this SetIndicatorMapRequest.
+    pub fn into_owned(self) -> SetIndicatorMapRequest<'static> {
+        SetIndicatorMapRequest {
+            device_spec: self.device_spec,
+            which: self.which,
+            maps: Cow::Owned(self.maps.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetIndicatorMapRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetIndicatorMapRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("which", |tracer| tracer.value(&self.which))?;
+        tracer.field("maps", |tracer| tracer.list(&self.maps[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetNamedIndicator request
+pub const GET_NAMED_INDICATOR_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetNamedIndicatorRequest {
+    pub device_spec: DeviceSpec,
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+    pub indicator: xproto::Atom,
+}
+impl GetNamedIndicatorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize_with_order(order);
+        let led_id_bytes = self.led_id.serialize_with_order(order);
+        let indicator_bytes = self.indicator.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_NAMED_INDICATOR_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            0,
+            0,
+            indicator_bytes[0],
+            indicator_bytes[1],
+            indicator_bytes[2],
+            indicator_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_NAMED_INDICATOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let led_class = led_class.into();
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (indicator, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetNamedIndicatorRequest {
+            device_spec,
+            led_class,
+            led_id,
+            indicator,
+        })
+    }
+}
+impl Request for GetNamedIndicatorRequest {
+    type Reply = GetNamedIndicatorReply;
+}
+impl Trace for GetNamedIndicatorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("ledClass", |tracer| tracer.value(&self.led_class))?;
+        tracer.field("ledID", |tracer| tracer.enum_value(&self.led_id, ID::VARIANTS))?;
+        tracer.field("indicator", |tracer| tracer.atom(&self.indicator))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetNamedIndicatorReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub indicator: xproto::Atom,
+    pub found: bool,
+    pub on: bool,
+    pub real_indicator: bool,
+    pub ndx: u8,
+    pub map_flags: u8,
+    pub map_which_groups: u8,
+    pub map_groups: u8,
+    pub map_which_mods: u8,
+    pub map_mods: u8,
+    pub map_real_mods: u8,
+    pub map_vmod: u16,
+    pub map_ctrls: u32,
+    pub supported: bool,
+}
+impl TryParse for GetNamedIndicatorReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (indicator, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (found, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (on, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (real_indicator, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_vmod, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetNamedIndicatorReply { device_id, sequence, length, indicator, found, on, real_indicator, ndx, map_flags, map_which_groups, map_groups, map_which_mods, map_mods, map_real_mods, map_vmod, map_ctrls, supported };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetNamedIndicatorReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetNamedIndicatorReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("indicator", |tracer| tracer.atom(&self.indicator))?;
+        tracer.field("found", |tracer| tracer.value(&self.found))?;
+        tracer.field("on", |tracer| tracer.value(&self.on))?;
+        tracer.field("realIndicator", |tracer| tracer.value(&self.real_indicator))?;
+        tracer.field("ndx", |tracer| tracer.value(&self.ndx))?;
+        tracer.field("map-flags", |tracer| tracer.bitmask(&self.map_flags, IMFlag::VARIANTS))?;
+        tracer.field("map-whichGroups", |tracer| tracer.bitmask(&self.map_which_groups, IMGroupsWhich::VARIANTS))?;
+        tracer.field("map-groups", |tracer| tracer.bitmask(&self.map_groups, SetOfGroups::VARIANTS))?;
+        tracer.field("map-whichMods", |tracer| tracer.bitmask(&self.map_which_mods, IMModsWhich::VARIANTS))?;
+        tracer.field("map-mods", |tracer| tracer.bitmask(&self.map_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("map-realMods", |tracer| tracer.bitmask(&self.map_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("map-vmod", |tracer| tracer.bitmask(&self.map_vmod, VMod::VARIANTS))?;
+        tracer.field("map-ctrls", |tracer| tracer.bitmask(&self.map_ctrls, BoolCtrl::VARIANTS))?;
+        tracer.field("supported", |tracer| tracer.value(&self.supported))?;
+        Ok(())
+    }
+}
+impl Serialize for GetNamedIndicatorReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.indicator.serialize_into_with_order(bytes, order);
+        self.found.serialize_into_with_order(bytes, order);
+        self.on.serialize_into_with_order(bytes, order);
+        self.real_indicator.serialize_into_with_order(bytes, order);
+        self.ndx.serialize_into_with_order(bytes, order);
+        self.map_flags.serialize_into_with_order(bytes, order);
+        self.map_which_groups.serialize_into_with_order(bytes, order);
+        self.map_groups.serialize_into_with_order(bytes, order);
+        self.map_which_mods.serialize_into_with_order(bytes, order);
+        self.map_mods.serialize_into_with_order(bytes, order);
+        self.map_real_mods.serialize_into_with_order(bytes, order);
+        self.map_vmod.serialize_into_with_order(bytes, order);
+        self.map_ctrls.serialize_into_with_order(bytes, order);
+        self.supported.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetNamedIndicator request
+pub const SET_NAMED_INDICATOR_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetNamedIndicatorRequest {
+    pub device_spec: DeviceSpec,
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+    pub indicator: xproto::Atom,
+    pub set_state: bool,
+    pub on: bool,
+    pub set_map: bool,
+    pub create_map: bool,
+    pub map_flags: u8,
+    pub map_which_groups: u8,
+    pub map_groups: u8,
+    pub map_which_mods: u8,
+    pub map_real_mods: u8,
+    pub map_vmods: u16,
+    pub map_ctrls: u32,
+}
+impl SetNamedIndicatorRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize_with_order(order);
+        let led_id_bytes = self.led_id.serialize_with_order(order);
+        let indicator_bytes = self.indicator.serialize_with_order(order);
+        let set_state_bytes = self.set_state.serialize_with_order(order);
+        let on_bytes = self.on.serialize_with_order(order);
+        let set_map_bytes = self.set_map.serialize_with_order(order);
+        let create_map_bytes = self.create_map.serialize_with_order(order);
+        let map_flags_bytes = self.map_flags.serialize_with_order(order);
+        let map_which_groups_bytes = self.map_which_groups.serialize_with_order(order);
+        let map_groups_bytes = self.map_groups.serialize_with_order(order);
+        let map_which_mods_bytes = self.map_which_mods.serialize_with_order(order);
+        let map_real_mods_bytes = self.map_real_mods.serialize_with_order(order);
+        let map_vmods_bytes = self.map_vmods.serialize_with_order(order);
+        let map_ctrls_bytes = self.map_ctrls.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_NAMED_INDICATOR_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            0,
+            0,
+            indicator_bytes[0],
+            indicator_bytes[1],
+            indicator_bytes[2],
+            indicator_bytes[3],
+            set_state_bytes[0],
+            on_bytes[0],
+            set_map_bytes[0],
+            create_map_bytes[0],
+            0,
+            map_flags_bytes[0],
+            map_which_groups_bytes[0],
+            map_groups_bytes[0],
+            map_which_mods_bytes[0],
+            map_real_mods_bytes[0],
+            map_vmods_bytes[0],
+            map_vmods_bytes[1],
+            map_ctrls_bytes[0],
+            map_ctrls_bytes[1],
+            map_ctrls_bytes[2],
+            map_ctrls_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_NAMED_INDICATOR_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let led_class = led_class.into();
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (indicator, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (set_state, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (on, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (set_map, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (create_map, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (map_flags, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_which_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_real_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (map_vmods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetNamedIndicatorRequest {
+            device_spec,
+            led_class,
+            led_id,
+            indicator,
+            set_state,
+            on,
+            set_map,
+            create_map,
+            map_flags,
+            map_which_groups,
+            map_groups,
+            map_which_mods,
+            map_real_mods,
+            map_vmods,
+            map_ctrls,
+        })
+    }
+}
+impl Request for SetNamedIndicatorRequest {
+    type Reply = ();
+}
+impl Trace for SetNamedIndicatorRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("ledClass", |tracer| tracer.value(&self.led_class))?;
+        tracer.field("ledID", |tracer| tracer.enum_value(&self.led_id, ID::VARIANTS))?;
+        tracer.field("indicator", |tracer| tracer.atom(&self.indicator))?;
+        tracer.field("setState", |tracer| tracer.value(&self.set_state))?;
+        tracer.field("on", |tracer| tracer.value(&self.on))?;
+        tracer.field("setMap", |tracer| tracer.value(&self.set_map))?;
+        tracer.field("createMap", |tracer| tracer.value(&self.create_map))?;
+        tracer.field("map-flags", |tracer| tracer.bitmask(&self.map_flags, IMFlag::VARIANTS))?;
+        tracer.field("map-whichGroups", |tracer| tracer.bitmask(&self.map_which_groups, IMGroupsWhich::VARIANTS))?;
+        tracer.field("map-groups", |tracer| tracer.bitmask(&self.map_groups, SetOfGroups::VARIANTS))?;
+        tracer.field("map-whichMods", |tracer| tracer.bitmask(&self.map_which_mods, IMModsWhich::VARIANTS))?;
+        tracer.field("map-realMods", |tracer| tracer.bitmask(&self.map_real_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("map-vmods", |tracer| tracer.bitmask(&self.map_vmods, VMod::VARIANTS))?;
+        tracer.field("map-ctrls", |tracer| tracer.bitmask(&self.map_ctrls, BoolCtrl::VARIANTS))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetNames request
+pub const GET_NAMES_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetNamesRequest {
+    pub device_spec: DeviceSpec,
+    pub which: u32,
+}
+impl GetNamesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let which_bytes = self.which.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_NAMES_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_NAMES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetNamesRequest {
+            device_spec,
+            which,
+        })
+    }
+}
+impl Request for GetNamesRequest {
+    type Reply = GetNamesReply;
+}
+impl Trace for GetNamesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("which", |tracer| tracer.bitmask(&self.which, NameDetail::VARIANTS))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetNamesValueListBitcase8 {
+    pub n_levels_per_type: Vec<u8>,
+    pub kt_level_names: Vec<xproto::Atom>,
+}
+impl GetNamesValueListBitcase8 {
+    pub fn try_parse(value: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, n_types, ByteOrder::native())
+    }
+    pub fn try_parse_with_order(remaining: &[u8], n_types: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let n_levels_per_type = n_levels_per_type.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = GetNamesValueListBitcase8 { n_levels_per_type, kt_level_names };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
+impl GetNamesValueListBitcase8 {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, n_types: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, n_types, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, n_types: u8, order: ByteOrder) {
+        assert_eq!(self.n_levels_per_type.len(), usize::try_from(n_types).unwrap(), "`n_levels_per_type` has an incorrect length");
+        bytes.extend_from_slice(&self.n_levels_per_type);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.kt_level_names.len(), usize::try_from(self.n_levels_per_type.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).unwrap())).unwrap(), "`kt_level_names` has an incorrect length");
+        self.kt_level_names.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for GetNamesValueListBitcase8 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("nLevelsPerType", |tracer| tracer.list(&self.n_levels_per_type, |tracer, value| tracer.value(value)))?;
+            tracer.field("ktLevelNames", |tracer| tracer.list(&self.kt_level_names, |tracer, value| tracer.atom(value)))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetNamesValueList {
+    pub keycodes_name: Option<xproto::Atom>,
+    pub geometry_name: Option<xproto::Atom>,
+    pub symbols_name: Option<xproto::Atom>,
+    pub phys_symbols_name: Option<xproto::Atom>,
+    pub types_name: Option<xproto::Atom>,
+    pub compat_name: Option<xproto::Atom>,
+    pub type_names: Option<Vec<xproto::Atom>>,
+    pub bitcase8: Option<GetNamesValueListBitcase8>,
+    pub indicator_names: Option<Vec<xproto::Atom>>,
+    pub virtual_mod_names: Option<Vec<xproto::Atom>>,
+    pub groups: Option<Vec<xproto::Atom>>,
+    pub key_names: Option<Vec<KeyName>>,
+    pub key_aliases: Option<Vec<KeyAlias>>,
+    pub radio_group_names: Option<Vec<xproto::Atom>>,
+}
+impl Trace for GetNamesValueList {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.keycodes_name {
+                tracer.field("keycodesName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.geometry_name {
+                tracer.field("geometryName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.symbols_name {
+                tracer.field("symbolsName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.phys_symbols_name {
+                tracer.field("physSymbolsName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.types_name {
+                tracer.field("typesName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.compat_name {
+                tracer.field("compatName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.type_names {
+                tracer.field("typeNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.bitcase8 {
+                tracer.field("bitcase8", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.indicator_names {
+                tracer.field("indicatorNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.virtual_mod_names {
+                tracer.field("virtualModNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.groups {
+                tracer.field("groups", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.key_names {
+                tracer.field("keyNames", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.key_aliases {
+                tracer.field("keyAliases", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.radio_group_names {
+                tracer.field("radioGroupNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl GetNamesValueList {
+    fn try_parse_with_order(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = which;
+        let mut outer_remaining = value;
+        let keycodes_name = if switch_expr & u32::from(NameDetail::KEYCODES) != 0 {
+            let remaining = outer_remaining;
+            let (keycodes_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(keycodes_name)
+        } else {
+            None
+        };
+        let geometry_name = if switch_expr & u32::from(NameDetail::GEOMETRY) != 0 {
+            let remaining = outer_remaining;
+            let (geometry_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(geometry_name)
+        } else {
+            None
+        };
+        let symbols_name = if switch_expr & u32::from(NameDetail::SYMBOLS) != 0 {
+            let remaining = outer_remaining;
+            let (symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(symbols_name)
+        } else {
+            None
+        };
+        let phys_symbols_name = if switch_expr & u32::from(NameDetail::PHYS_SYMBOLS) != 0 {
+            let remaining = outer_remaining;
+            let (phys_symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(phys_symbols_name)
+        } else {
+            None
+        };
+        let types_name = if switch_expr & u32::from(NameDetail::TYPES) != 0 {
+            let remaining = outer_remaining;
+            let (types_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(types_name)
+        } else {
+            None
+        };
+        let compat_name = if switch_expr & u32::from(NameDetail::COMPAT) != 0 {
+            let remaining = outer_remaining;
+            let (compat_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(compat_name)
+        } else {
+            None
+        };
+        let type_names = if switch_expr & u32::from(NameDetail::KEY_TYPE_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (type_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(type_names)
+        } else {
+            None
+        };
+        let bitcase8 = if switch_expr & u32::from(NameDetail::KT_LEVEL_NAMES) != 0 {
+            let (bitcase8, new_remaining) = GetNamesValueListBitcase8::try_parse_with_order(outer_remaining, n_types, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase8)
+        } else {
+            None
+        };
+        let indicator_names = if switch_expr & u32::from(NameDetail::INDICATOR_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(indicator_names)
+        } else {
+            None
+        };
+        let virtual_mod_names = if switch_expr & u32::from(NameDetail::VIRTUAL_MOD_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(virtual_mod_names)
+        } else {
+            None
+        };
+        let groups = if switch_expr & u32::from(NameDetail::GROUP_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (groups, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, group_names.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(groups)
+        } else {
+            None
+        };
+        let key_names = if switch_expr & u32::from(NameDetail::KEY_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (key_names, remaining) = crate::x11_utils::parse_list_with_order::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(key_names)
+        } else {
+            None
+        };
+        let key_aliases = if switch_expr & u32::from(NameDetail::KEY_ALIASES) != 0 {
+            let remaining = outer_remaining;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list_with_order::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(key_aliases)
+        } else {
+            None
+        };
+        let radio_group_names = if switch_expr & u32::from(NameDetail::RG_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(radio_group_names)
+        } else {
+            None
+        };
+        let result = GetNamesValueList { keycodes_name, geometry_name, symbols_name, phys_symbols_name, types_name, compat_name, type_names, bitcase8, indicator_names, virtual_mod_names, groups, key_names, key_aliases, radio_group_names };
+        Ok((result, outer_remaining))
+    }
+}
+impl GetNamesValueList {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), which, "switch `value_list` has an inconsistent discriminant");
+        if let Some(keycodes_name) = self.keycodes_name {
+            keycodes_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(geometry_name) = self.geometry_name {
+            geometry_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(symbols_name) = self.symbols_name {
+            symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(phys_symbols_name) = self.phys_symbols_name {
+            phys_symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(types_name) = self.types_name {
+            types_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(compat_name) = self.compat_name {
+            compat_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref type_names) = self.type_names {
+            assert_eq!(type_names.len(), usize::try_from(n_types).unwrap(), "`type_names` has an incorrect length");
+            type_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase8) = self.bitcase8 {
+            bitcase8.serialize_into_with_order(bytes, n_types, order);
+        }
+        if let Some(ref indicator_names) = self.indicator_names {
+            assert_eq!(indicator_names.len(), usize::try_from(indicators.count_ones()).unwrap(), "`indicator_names` has an incorrect length");
+            indicator_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref virtual_mod_names) = self.virtual_mod_names {
+            assert_eq!(virtual_mod_names.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
+            virtual_mod_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref groups) = self.groups {
+            assert_eq!(groups.len(), usize::try_from(group_names.count_ones()).unwrap(), "`groups` has an incorrect length");
+            groups.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            assert_eq!(key_names.len(), usize::try_from(n_keys).unwrap(), "`key_names` has an incorrect length");
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_aliases) = self.key_aliases {
+            assert_eq!(key_aliases.len(), usize::try_from(n_key_aliases).unwrap(), "`key_aliases` has an incorrect length");
+            key_aliases.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref radio_group_names) = self.radio_group_names {
+            assert_eq!(radio_group_names.len(), usize::try_from(n_radio_groups).unwrap(), "`radio_group_names` has an incorrect length");
+            radio_group_names.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetNamesValueList {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.keycodes_name.is_some() {
+            expr_value |= u32::from(NameDetail::KEYCODES);
+        }
+        if self.geometry_name.is_some() {
+            expr_value |= u32::from(NameDetail::GEOMETRY);
+        }
+        if self.symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::SYMBOLS);
+        }
+        if self.phys_symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::PHYS_SYMBOLS);
+        }
+        if self.types_name.is_some() {
+            expr_value |= u32::from(NameDetail::TYPES);
+        }
+        if self.compat_name.is_some() {
+            expr_value |= u32::from(NameDetail::COMPAT);
+        }
+        if self.type_names.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_TYPE_NAMES);
+        }
+        if self.bitcase8.is_some() {
+            expr_value |= u32::from(NameDetail::KT_LEVEL_NAMES);
+        }
+        if self.indicator_names.is_some() {
+            expr_value |= u32::from(NameDetail::INDICATOR_NAMES);
+        }
+        if self.virtual_mod_names.is_some() {
+            expr_value |= u32::from(NameDetail::VIRTUAL_MOD_NAMES);
+        }
+        if self.groups.is_some() {
+            expr_value |= u32::from(NameDetail::GROUP_NAMES);
+        }
+        if self.key_names.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_NAMES);
+        }
+        if self.key_aliases.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_ALIASES);
+        }
+        if self.radio_group_names.is_some() {
+            expr_value |= u32::from(NameDetail::RG_NAMES);
+        }
+        expr_value
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetNamesReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub n_types: u8,
+    pub group_names: u8,
+    pub virtual_mods: u16,
+    pub first_key: xproto::Keycode,
+    pub n_keys: u8,
+    pub indicators: u32,
+    pub n_radio_groups: u8,
+    pub n_key_aliases: u8,
+    pub n_kt_levels: u16,
+    pub value_list: GetNamesValueList,
+}
+impl TryParse for GetNamesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_kt_levels, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (value_list, remaining) = GetNamesValueList::try_parse_with_order(remaining, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetNamesReply { device_id, sequence, length, min_key_code, max_key_code, n_types, group_names, virtual_mods, first_key, n_keys, indicators, n_radio_groups, n_key_aliases, n_kt_levels, value_list };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetNamesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetNamesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("minKeyCode", |tracer| tracer.value(&self.min_key_code))?;
+        tracer.field("maxKeyCode", |tracer| tracer.value(&self.max_key_code))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("groupNames", |tracer| tracer.bitmask(&self.group_names, SetOfGroup::VARIANTS))?;
+        tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+        tracer.field("firstKey", |tracer| tracer.value(&self.first_key))?;
+        tracer.field("nKeys", |tracer| tracer.value(&self.n_keys))?;
+        tracer.field("indicators", |tracer| tracer.value(&self.indicators))?;
+        tracer.field("nRadioGroups", |tracer| tracer.value(&self.n_radio_groups))?;
+        tracer.field("nKeyAliases", |tracer| tracer.value(&self.n_key_aliases))?;
+        tracer.field("nKTLevels", |tracer| tracer.value(&self.n_kt_levels))?;
+        tracer.field("valueList", |tracer| tracer.value(&self.value_list))?;
+        Ok(())
+    }
+}
+impl Serialize for GetNamesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let which = self.value_list.switch_expr();
+        which.serialize_into_with_order(bytes, order);
+        self.min_key_code.serialize_into_with_order(bytes, order);
+        self.max_key_code.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        self.group_names.serialize_into_with_order(bytes, order);
+        self.virtual_mods.serialize_into_with_order(bytes, order);
+        self.first_key.serialize_into_with_order(bytes, order);
+        self.n_keys.serialize_into_with_order(bytes, order);
+        self.indicators.serialize_into_with_order(bytes, order);
+        self.n_radio_groups.serialize_into_with_order(bytes, order);
+        self.n_key_aliases.serialize_into_with_order(bytes, order);
+        self.n_kt_levels.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.value_list.serialize_into_with_order(bytes, which, self.n_types, self.indicators, self.virtual_mods, self.group_names, self.n_keys, self.n_key_aliases, self.n_radio_groups, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetNamesAuxBitcase8 {
+    pub n_levels_per_type: Vec<u8>,
+    pub kt_level_names: Vec<xproto::Atom>,
+}
+impl SetNamesAuxBitcase8 {
+    pub fn try_parse(value: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, n_types, ByteOrder::native())
+    }
+    pub fn try_parse_with_order(remaining: &[u8], n_types: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let n_levels_per_type = n_levels_per_type.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = SetNamesAuxBitcase8 { n_levels_per_type, kt_level_names };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
+impl SetNamesAuxBitcase8 {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, n_types: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, n_types, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, n_types: u8, order: ByteOrder) {
+        assert_eq!(self.n_levels_per_type.len(), usize::try_from(n_types).unwrap(), "`n_levels_per_type` has an incorrect length");
+        bytes.extend_from_slice(&self.n_levels_per_type);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.kt_level_names.len(), usize::try_from(self.n_levels_per_type.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).unwrap())).unwrap(), "`kt_level_names` has an incorrect length");
+        self.kt_level_names.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for SetNamesAuxBitcase8 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("nLevelsPerType", |tracer| tracer.list(&self.n_levels_per_type, |tracer, value| tracer.value(value)))?;
+            tracer.field("ktLevelNames", |tracer| tracer.list(&self.kt_level_names, |tracer, value| tracer.atom(value)))?;
+            Ok(())
+        })
+    }
+}
+/// Auxiliary and optional information for the `set_names` function
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetNamesAux {
+    pub keycodes_name: Option<xproto::Atom>,
+    pub geometry_name: Option<xproto::Atom>,
+    pub symbols_name: Option<xproto::Atom>,
+    pub phys_symbols_name: Option<xproto::Atom>,
+    pub types_name: Option<xproto::Atom>,
+    pub compat_name: Option<xproto::Atom>,
+    pub type_names: Option<Vec<xproto::Atom>>,
+    pub bitcase8: Option<SetNamesAuxBitcase8>,
+    pub indicator_names: Option<Vec<xproto::Atom>>,
+    pub virtual_mod_names: Option<Vec<xproto::Atom>>,
+    pub groups: Option<Vec<xproto::Atom>>,
+    pub key_names: Option<Vec<KeyName>>,
+    pub key_aliases: Option<Vec<KeyAlias>>,
+    pub radio_group_names: Option<Vec<xproto::Atom>>,
+}
+impl Trace for SetNamesAux {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.keycodes_name {
+                tracer.field("keycodesName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.geometry_name {
+                tracer.field("geometryName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.symbols_name {
+                tracer.field("symbolsName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.phys_symbols_name {
+                tracer.field("physSymbolsName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.types_name {
+                tracer.field("typesName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.compat_name {
+                tracer.field("compatName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.type_names {
+                tracer.field("typeNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.bitcase8 {
+                tracer.field("bitcase8", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.indicator_names {
+                tracer.field("indicatorNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.virtual_mod_names {
+                tracer.field("virtualModNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.groups {
+                tracer.field("groups", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.key_names {
+                tracer.field("keyNames", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.key_aliases {
+                tracer.field("keyAliases", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.radio_group_names {
+                tracer.field("radioGroupNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl SetNamesAux {
+    fn try_parse_with_order(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = which;
+        let mut outer_remaining = value;
+        let keycodes_name = if switch_expr & u32::from(NameDetail::KEYCODES) != 0 {
+            let remaining = outer_remaining;
+            let (keycodes_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(keycodes_name)
+        } else {
+            None
+        };
+        let geometry_name = if switch_expr & u32::from(NameDetail::GEOMETRY) != 0 {
+            let remaining = outer_remaining;
+            let (geometry_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(geometry_name)
+        } else {
+            None
+        };
+        let symbols_name = if switch_expr & u32::from(NameDetail::SYMBOLS) != 0 {
+            let remaining = outer_remaining;
+            let (symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(symbols_name)
+        } else {
+            None
+        };
+        let phys_symbols_name = if switch_expr & u32::from(NameDetail::PHYS_SYMBOLS) != 0 {
+            let remaining = outer_remaining;
+            let (phys_symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(phys_symbols_name)
+        } else {
+            None
+        };
+        let types_name = if switch_expr & u32::from(NameDetail::TYPES) != 0 {
+            let remaining = outer_remaining;
+            let (types_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(types_name)
+        } else {
+            None
+        };
+        let compat_name = if switch_expr & u32::from(NameDetail::COMPAT) != 0 {
+            let remaining = outer_remaining;
+            let (compat_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(compat_name)
+        } else {
+            None
+        };
+        let type_names = if switch_expr & u32::from(NameDetail::KEY_TYPE_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (type_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(type_names)
+        } else {
+            None
+        };
+        let bitcase8 = if switch_expr & u32::from(NameDetail::KT_LEVEL_NAMES) != 0 {
+            let (bitcase8, new_remaining) = SetNamesAuxBitcase8::try_parse_with_order(outer_remaining, n_types, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase8)
+        } else {
+            None
+        };
+        let indicator_names = if switch_expr & u32::from(NameDetail::INDICATOR_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(indicator_names)
+        } else {
+            None
+        };
+        let virtual_mod_names = if switch_expr & u32::from(NameDetail::VIRTUAL_MOD_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(virtual_mod_names)
+        } else {
+            None
+        };
+        let groups = if switch_expr & u32::from(NameDetail::GROUP_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (groups, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, group_names.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(groups)
+        } else {
+            None
+        };
+        let key_names = if switch_expr & u32::from(NameDetail::KEY_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (key_names, remaining) = crate::x11_utils::parse_list_with_order::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(key_names)
+        } else {
+            None
+        };
+        let key_aliases = if switch_expr & u32::from(NameDetail::KEY_ALIASES) != 0 {
+            let remaining = outer_remaining;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list_with_order::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(key_aliases)
+        } else {
+            None
+        };
+        let radio_group_names = if switch_expr & u32::from(NameDetail::RG_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(radio_group_names)
+        } else {
+            None
+        };
+        let result = SetNamesAux { keycodes_name, geometry_name, symbols_name, phys_symbols_name, types_name, compat_name, type_names, bitcase8, indicator_names, virtual_mod_names, groups, key_names, key_aliases, radio_group_names };
+        Ok((result, outer_remaining))
+    }
+}
+impl SetNamesAux {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), which, "switch `values` has an inconsistent discriminant");
+        if let Some(keycodes_name) = self.keycodes_name {
+            keycodes_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(geometry_name) = self.geometry_name {
+            geometry_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(symbols_name) = self.symbols_name {
+            symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(phys_symbols_name) = self.phys_symbols_name {
+            phys_symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(types_name) = self.types_name {
+            types_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(compat_name) = self.compat_name {
+            compat_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref type_names) = self.type_names {
+            assert_eq!(type_names.len(), usize::try_from(n_types).unwrap(), "`type_names` has an incorrect length");
+            type_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase8) = self.bitcase8 {
+            bitcase8.serialize_into_with_order(bytes, n_types, order);
+        }
+        if let Some(ref indicator_names) = self.indicator_names {
+            assert_eq!(indicator_names.len(), usize::try_from(indicators.count_ones()).unwrap(), "`indicator_names` has an incorrect length");
+            indicator_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref virtual_mod_names) = self.virtual_mod_names {
+            assert_eq!(virtual_mod_names.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
+            virtual_mod_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref groups) = self.groups {
+            assert_eq!(groups.len(), usize::try_from(group_names.count_ones()).unwrap(), "`groups` has an incorrect length");
+            groups.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            assert_eq!(key_names.len(), usize::try_from(n_keys).unwrap(), "`key_names` has an incorrect length");
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_aliases) = self.key_aliases {
+            assert_eq!(key_aliases.len(), usize::try_from(n_key_aliases).unwrap(), "`key_aliases` has an incorrect length");
+            key_aliases.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref radio_group_names) = self.radio_group_names {
+            assert_eq!(radio_group_names.len(), usize::try_from(n_radio_groups).unwrap(), "`radio_group_names` has an incorrect length");
+            radio_group_names.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl SetNamesAux {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.keycodes_name.is_some() {
+            expr_value |= u32::from(NameDetail::KEYCODES);
+        }
+        if self.geometry_name.is_some() {
+            expr_value |= u32::from(NameDetail::GEOMETRY);
+        }
+        if self.symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::SYMBOLS);
+        }
+        if self.phys_symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::PHYS_SYMBOLS);
+        }
+        if self.types_name.is_some() {
+            expr_value |= u32::from(NameDetail::TYPES);
+        }
+        if self.compat_name.is_some() {
+            expr_value |= u32::from(NameDetail::COMPAT);
+        }
+        if self.type_names.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_TYPE_NAMES);
+        }
+        if self.bitcase8.is_some() {
+            expr_value |= u32::from(NameDetail::KT_LEVEL_NAMES);
+        }
+        if self.indicator_names.is_some() {
+            expr_value |= u32::from(NameDetail::INDICATOR_NAMES);
+        }
+        if self.virtual_mod_names.is_some() {
+            expr_value |= u32::from(NameDetail::VIRTUAL_MOD_NAMES);
+        }
+        if self.groups.is_some() {
+            expr_value |= u32::from(NameDetail::GROUP_NAMES);
+        }
+        if self.key_names.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_NAMES);
+        }
+        if self.key_aliases.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_ALIASES);
+        }
+        if self.radio_group_names.is_some() {
+            expr_value |= u32::from(NameDetail::RG_NAMES);
+        }
+        expr_value
+    }
+}
+impl SetNamesAux {
+    /// Create a new instance with all fields unset / not present.
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Set the `keycodes_name` field of this structure.
+    pub fn keycodes_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.keycodes_name = value.into();
+        self
+    }
+    /// Set the `geometry_name` field of this structure.
+    pub fn geometry_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.geometry_name = value.into();
+        self
+    }
+    /// Set the `symbols_name` field of this structure.
+    pub fn symbols_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.symbols_name = value.into();
+        self
+    }
+    /// Set the `phys_symbols_name` field of this structure.
+    pub fn phys_symbols_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.phys_symbols_name = value.into();
+        self
+    }
+    /// Set the `types_name` field of this structure.
+    pub fn types_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.types_name = value.into();
+        self
+    }
+    /// Set the `compat_name` field of this structure.
+    pub fn compat_name<I>(mut self, value: I) -> Self where I: Into<Option<xproto::Atom>> {
+        self.compat_name = value.into();
+        self
+    }
+    /// Set the `type_names` field of this structure.
+    pub fn type_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.type_names = value.into();
+        self
+    }
+    /// Set the `bitcase8` field of this structure.
+    pub fn bitcase8<I>(mut self, value: I) -> Self where I: Into<Option<SetNamesAuxBitcase8>> {
+        self.bitcase8 = value.into();
+        self
+    }
+    /// Set the `indicator_names` field of this structure.
+    pub fn indicator_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.indicator_names = value.into();
+        self
+    }
+    /// Set the `virtual_mod_names` field of this structure.
+    pub fn virtual_mod_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.virtual_mod_names = value.into();
+        self
+    }
+    /// Set the `groups` field of this structure.
+    pub fn groups<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.groups = value.into();
+        self
+    }
+    /// Set the `key_names` field of this structure.
+    pub fn key_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyName>>> {
+        self.key_names = value.into();
+        self
+    }
+    /// Set the `key_aliases` field of this structure.
+    pub fn key_aliases<I>(mut self, value: I) -> Self where I: Into<Option<Vec<KeyAlias>>> {
+        self.key_aliases = value.into();
+        self
+    }
+    /// Set the `radio_group_names` field of this structure.
+    pub fn radio_group_names<I>(mut self, value: I) -> Self where I: Into<Option<Vec<xproto::Atom>>> {
+        self.radio_group_names = value.into();
+        self
+    }
+}
+
+/// Opcode for the SetNames request
+pub const SET_NAMES_REQUEST: u8 = 18;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetNamesRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub virtual_mods: u16,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_kt_levelt: u8,
+    pub n_kt_levels: u8,
+    pub indicators: u32,
+    pub group_names: u8,
+    pub n_radio_groups: u8,
+    pub first_key: xproto::Keycode,
+    pub n_keys: u8,
+    pub n_key_aliases: u8,
+    pub total_kt_level_names: u16,
+    pub values: Cow<'input, SetNamesAux>,
+}
+impl<'input> SetNamesRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let virtual_mods_bytes = self.virtual_mods.serialize_with_order(order);
+        let which = self.values.switch_expr();
+        let which_bytes = which.serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_kt_levelt_bytes = self.first_kt_levelt.serialize_with_order(order);
+        let n_kt_levels_bytes = self.n_kt_levels.serialize_with_order(order);
+        let indicators_bytes = self.indicators.serialize_with_order(order);
+        let group_names_bytes = self.group_names.serialize_with_order(order);
+        let n_radio_groups_bytes = self.n_radio_groups.serialize_with_order(order);
+        let first_key_bytes = self.first_key.serialize_with_order(order);
+        let n_keys_bytes = self.n_keys.serialize_with_order(order);
+        let n_key_aliases_bytes = self.n_key_aliases.serialize_with_order(order);
+        let total_kt_level_names_bytes = self.total_kt_level_names.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_NAMES_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            which_bytes[0],
+            which_bytes[1],
+            which_bytes[2],
+            which_bytes[3],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_kt_levelt_bytes[0],
+            n_kt_levels_bytes[0],
+            indicators_bytes[0],
+            indicators_bytes[1],
+            indicators_bytes[2],
+            indicators_bytes[3],
+            group_names_bytes[0],
+            n_radio_groups_bytes[0],
+            first_key_bytes[0],
+            n_keys_bytes[0],
+            n_key_aliases_bytes[0],
+            0,
+            total_kt_level_names_bytes[0],
+            total_kt_level_names_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let values_bytes = self.values.serialize_with_order(which, self.n_types, self.indicators, self.virtual_mods, self.group_names, self.n_keys, self.n_key_aliases, self.n_radio_groups, order);
+        let length_so_far = length_so_far + values_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), values_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_NAMES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_kt_levelt, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_kt_levels, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (total_kt_level_names, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (values, remaining) = SetNamesAux::try_parse_with_order(remaining, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups, order)?;
+        let _ = remaining;
+        Ok(SetNamesRequest {
+            device_spec,
+            virtual_mods,
+            first_type,
+            n_types,
+            first_kt_levelt,
+            n_kt_levels,
+            indicators,
+            group_names,
+            n_radio_groups,
+            first_key,
+            n_keys,
+            n_key_aliases,
+            total_kt_level_names,
+            values: Cow::Owned(values),
+        })
+    }
+    /// Clone all borrowed data in this SetNamesRequest.
+    pub fn into_owned(self) -> SetNamesRequest<'static> {
+        SetNamesRequest {
+            device_spec: self.device_spec,
+            virtual_mods: self.virtual_mods,
+            first_type: self.first_type,
+            n_types: self.n_types,
+            first_kt_levelt: self.first_kt_levelt,
+            n_kt_levels: self.n_kt_levels,
+            indicators: self.indicators,
+            group_names: self.group_names,
+            n_radio_groups: self.n_radio_groups,
+            first_key: self.first_key,
+            n_keys: self.n_keys,
+            n_key_aliases: self.n_key_aliases,
+            total_kt_level_names: self.total_kt_level_names,
+            values: Cow::Owned(self.values.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetNamesRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetNamesRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+        tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("firstKTLevelt", |tracer| tracer.value(&self.first_kt_levelt))?;
+        tracer.field("nKTLevels", |tracer| tracer.value(&self.n_kt_levels))?;
+        tracer.field("indicators", |tracer| tracer.value(&self.indicators))?;
+        tracer.field("groupNames", |tracer| tracer.bitmask(&self.group_names, SetOfGroup::VARIANTS))?;
+        tracer.field("nRadioGroups", |tracer| tracer.value(&self.n_radio_groups))?;
+        tracer.field("firstKey", |tracer| tracer.value(&self.first_key))?;
+        tracer.field("nKeys", |tracer| tracer.value(&self.n_keys))?;
+        tracer.field("nKeyAliases", |tracer| tracer.value(&self.n_key_aliases))?;
+        tracer.field("totalKTLevelNames", |tracer| tracer.value(&self.total_kt_level_names))?;
+        tracer.field("values", |tracer| tracer.value(&self.values))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the PerClientFlags request
+pub const PER_CLIENT_FLAGS_REQUEST: u8 = 21;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerClientFlagsRequest {
+    pub device_spec: DeviceSpec,
+    pub change: u32,
+    pub value: u32,
+    pub ctrls_to_change: u32,
+    pub auto_ctrls: u32,
+    pub auto_ctrls_values: u32,
+}
+impl PerClientFlagsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let change_bytes = self.change.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        let ctrls_to_change_bytes = self.ctrls_to_change.serialize_with_order(order);
+        let auto_ctrls_bytes = self.auto_ctrls.serialize_with_order(order);
+        let auto_ctrls_values_bytes = self.auto_ctrls_values.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PER_CLIENT_FLAGS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            0,
+            0,
+            change_bytes[0],
+            change_bytes[1],
+            change_bytes[2],
+            change_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            ctrls_to_change_bytes[0],
+            ctrls_to_change_bytes[1],
+            ctrls_to_change_bytes[2],
+            ctrls_to_change_bytes[3],
+            auto_ctrls_bytes[0],
+            auto_ctrls_bytes[1],
+            auto_ctrls_bytes[2],
+            auto_ctrls_bytes[3],
+            auto_ctrls_values_bytes[0],
+            auto_ctrls_values_bytes[1],
+            auto_ctrls_values_bytes[2],
+            auto_ctrls_values_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PER_CLIENT_FLAGS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (change, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ctrls_to_change, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(PerClientFlagsRequest {
+            device_spec,
+            change,
+            value,
+            ctrls_to_change,
+            auto_ctrls,
+            auto_ctrls_values,
+        })
+    }
+}
+impl Request for PerClientFlagsRequest {
+    type Reply = PerClientFlagsReply;
+}
+impl Trace for PerClientFlagsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("change", |tracer| tracer.bitmask(&self.change, PerClientFlag::VARIANTS))?;
+        tracer.field("value", |tracer| tracer.bitmask(&self.value, PerClientFlag::VARIANTS))?;
+        tracer.field("ctrlsToChange", |tracer| tracer.bitmask(&self.ctrls_to_change, BoolCtrl::VARIANTS))?;
+        tracer.field("autoCtrls", |tracer| tracer.bitmask(&self.auto_ctrls, BoolCtrl::VARIANTS))?;
+        tracer.field("autoCtrlsValues", |tracer| tracer.bitmask(&self.auto_ctrls_values, BoolCtrl::VARIANTS))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerClientFlagsReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub supported: u32,
+    pub value: u32,
+    pub auto_ctrls: u32,
+    pub auto_ctrls_values: u32,
+}
+impl TryParse for PerClientFlagsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (auto_ctrls_values, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = PerClientFlagsReply { device_id, sequence, length, supported, value, auto_ctrls, auto_ctrls_values };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for PerClientFlagsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for PerClientFlagsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("supported", |tracer| tracer.bitmask(&self.supported, PerClientFlag::VARIANTS))?;
+        tracer.field("value", |tracer| tracer.bitmask(&self.value, PerClientFlag::VARIANTS))?;
+        tracer.field("autoCtrls", |tracer| tracer.bitmask(&self.auto_ctrls, BoolCtrl::VARIANTS))?;
+        tracer.field("autoCtrlsValues", |tracer| tracer.bitmask(&self.auto_ctrls_values, BoolCtrl::VARIANTS))?;
+        Ok(())
+    }
+}
+impl Serialize for PerClientFlagsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.supported.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+        self.auto_ctrls.serialize_into_with_order(bytes, order);
+        self.auto_ctrls_values.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ListComponents request
+pub const LIST_COMPONENTS_REQUEST: u8 = 22;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListComponentsRequest {
+    pub device_spec: DeviceSpec,
+    pub max_names: u16,
+}
+impl ListComponentsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let max_names_bytes = self.max_names.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            LIST_COMPONENTS_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            max_names_bytes[0],
+            max_names_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_COMPONENTS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (max_names, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(ListComponentsRequest {
+            device_spec,
+            max_names,
+        })
+    }
+}
+impl Request for ListComponentsRequest {
+    type Reply = ListComponentsReply;
+}
+impl Trace for ListComponentsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("maxNames", |tracer| tracer.value(&self.max_names))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListComponentsReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub extra: u16,
+    pub keymaps: Vec<Listing>,
+    pub keycodes: Vec<Listing>,
+    pub types: Vec<Listing>,
+    pub compat_maps: Vec<Listing>,
+    pub symbols: Vec<Listing>,
+    pub geometries: Vec<Listing>,
+}
+impl TryParse for ListComponentsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_keymaps, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_keycodes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_compat_maps, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_symbols, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_geometries, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (extra, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        let (keymaps, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_keymaps.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (keycodes, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_keycodes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (types, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (compat_maps, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_compat_maps.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (symbols, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_symbols.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (geometries, remaining) = crate::x11_utils::parse_list_with_order::<Listing>(remaining, n_geometries.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListComponentsReply { device_id, sequence, length, extra, keymaps, keycodes, types, compat_maps, symbols, geometries };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ListComponentsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ListComponentsReply {
+    /// Get the value of the `nKeymaps` field.
+    ///
+    /// The `nKeymaps` field is used as the length field of the `keymaps` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_keymaps(&self) -> u16 {
+        self.keymaps.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nKeycodes` field.
+    ///
+    /// The `nKeycodes` field is used as the length field of the `keycodes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_keycodes(&self) -> u16 {
+        self.keycodes.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nTypes` field.
+    ///
+    /// The `nTypes` field is used as the length field of the `types` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_types(&self) -> u16 {
+        self.types.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nCompatMaps` field.
+    ///
+    /// The `nCompatMaps` field is used as the length field of the `compatMaps` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_compat_maps(&self) -> u16 {
+        self.compat_maps.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nSymbols` field.
+    ///
+    /// The `nSymbols` field is used as the length field of the `symbols` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_symbols(&self) -> u16 {
+        self.symbols.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nGeometries` field.
+    ///
+    /// The `nGeometries` field is used as the length field of the `geometries` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_geometries(&self) -> u16 {
+        self.geometries.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ListComponentsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("extra", |tracer| tracer.value(&self.extra))?;
+        tracer.field("keymaps", |tracer| tracer.list(&self.keymaps, |tracer, value| tracer.value(value)))?;
+        tracer.field("keycodes", |tracer| tracer.list(&self.keycodes, |tracer, value| tracer.value(value)))?;
+        tracer.field("types", |tracer| tracer.list(&self.types, |tracer, value| tracer.value(value)))?;
+        tracer.field("compatMaps", |tracer| tracer.list(&self.compat_maps, |tracer, value| tracer.value(value)))?;
+        tracer.field("symbols", |tracer| tracer.list(&self.symbols, |tracer, value| tracer.value(value)))?;
+        tracer.field("geometries", |tracer| tracer.list(&self.geometries, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for ListComponentsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let n_keymaps = u16::try_from(self.keymaps.len()).expect("`keymaps` has too many elements");
+        n_keymaps.serialize_into_with_order(bytes, order);
+        let n_keycodes = u16::try_from(self.keycodes.len()).expect("`keycodes` has too many elements");
+        n_keycodes.serialize_into_with_order(bytes, order);
+        let n_types = u16::try_from(self.types.len()).expect("`types` has too many elements");
+        n_types.serialize_into_with_order(bytes, order);
+        let n_compat_maps = u16::try_from(self.compat_maps.len()).expect("`compat_maps` has too many elements");
+        n_compat_maps.serialize_into_with_order(bytes, order);
+        let n_symbols = u16::try_from(self.symbols.len()).expect("`symbols` has too many elements");
+        n_symbols.serialize_into_with_order(bytes, order);
+        let n_geometries = u16::try_from(self.geometries.len()).expect("`geometries` has too many elements");
+        n_geometries.serialize_into_with_order(bytes, order);
+        self.extra.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 10]);
+        self.keymaps.serialize_into_with_order(bytes, order);
+        self.keycodes.serialize_into_with_order(bytes, order);
+        self.types.serialize_into_with_order(bytes, order);
+        self.compat_maps.serialize_into_with_order(bytes, order);
+        self.symbols.serialize_into_with_order(bytes, order);
+        self.geometries.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetKbdByName request
+pub const GET_KBD_BY_NAME_REQUEST: u8 = 23;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetKbdByNameRequest {
+    pub device_spec: DeviceSpec,
+    pub need: u16,
+    pub want: u16,
+    pub load: bool,
+}
+impl GetKbdByNameRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let need_bytes = self.need.serialize_with_order(order);
+        let want_bytes = self.want.serialize_with_order(order);
+        let load_bytes = self.load.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_KBD_BY_NAME_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            need_bytes[0],
+            need_bytes[1],
+            want_bytes[0],
+            want_bytes[1],
+            load_bytes[0],
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_KBD_BY_NAME_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (need, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (want, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (load, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(GetKbdByNameRequest {
+            device_spec,
+            need,
+            want,
+            load,
+        })
+    }
+}
+impl Request for GetKbdByNameRequest {
+    type Reply = GetKbdByNameReply;
+}
+impl Trace for GetKbdByNameRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("need", |tracer| tracer.bitmask(&self.need, GBNDetail::VARIANTS))?;
+        tracer.field("want", |tracer| tracer.bitmask(&self.want, GBNDetail::VARIANTS))?;
+        tracer.field("load", |tracer| tracer.value(&self.load))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesTypesMapBitcase3 {
+    pub acts_rtrn_count: Vec<u8>,
+    pub acts_rtrn_acts: Vec<Action>,
+}
+impl GetKbdByNameRepliesTypesMapBitcase3 {
+    pub fn try_parse(value: &[u8], n_key_actions: u8, total_actions: u16) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, n_key_actions, total_actions, ByteOrder::native())
+    }
+    pub fn try_parse_with_order(remaining: &[u8], n_key_actions: u8, total_actions: u16, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (acts_rtrn_count, remaining) = crate::x11_utils::parse_u8_list(remaining, n_key_actions.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let acts_rtrn_count = acts_rtrn_count.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (acts_rtrn_acts, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, total_actions.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = GetKbdByNameRepliesTypesMapBitcase3 { acts_rtrn_count, acts_rtrn_acts };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
+impl GetKbdByNameRepliesTypesMapBitcase3 {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, n_key_actions: u8, total_actions: u16, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, n_key_actions, total_actions, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, n_key_actions: u8, total_actions: u16, order: ByteOrder) {
+        assert_eq!(self.acts_rtrn_count.len(), usize::try_from(n_key_actions).unwrap(), "`acts_rtrn_count` has an incorrect length");
+        bytes.extend_from_slice(&self.acts_rtrn_count);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.acts_rtrn_acts.len(), usize::try_from(total_actions).unwrap(), "`acts_rtrn_acts` has an incorrect length");
+        self.acts_rtrn_acts.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for GetKbdByNameRepliesTypesMapBitcase3 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("acts-rtrn-count", |tracer| tracer.list(&self.acts_rtrn_count, |tracer, value| tracer.value(value)))?;
+            tracer.field("acts-rtrn-acts", |tracer| tracer.list(&self.acts_rtrn_acts, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesTypesMap {
+    pub types_rtrn: Option<Vec<KeyType>>,
+    pub syms_rtrn: Option<Vec<KeySymMap>>,
+    pub bitcase3: Option<GetKbdByNameRepliesTypesMapBitcase3>,
+    pub behaviors_rtrn: Option<Vec<SetBehavior>>,
+    pub vmods_rtrn: Option<Vec<u8>>,
+    pub explicit_rtrn: Option<Vec<SetExplicit>>,
+    pub modmap_rtrn: Option<Vec<KeyModMap>>,
+    pub vmodmap_rtrn: Option<Vec<KeyVModMap>>,
+}
+impl Trace for GetKbdByNameRepliesTypesMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.types_rtrn {
+                tracer.field("types-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.syms_rtrn {
+                tracer.field("syms-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.bitcase3 {
+                tracer.field("bitcase3", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.behaviors_rtrn {
+                tracer.field("behaviors-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.vmods_rtrn {
+                tracer.field("vmods-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.bitmask(value, xproto::ModMask::VARIANTS)))?;
+            }
+            if let Some(ref value) = self.explicit_rtrn {
+                tracer.field("explicit-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.modmap_rtrn {
+                tracer.field("modmap-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.vmodmap_rtrn {
+                tracer.field("vmodmap-rtrn", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl GetKbdByNameRepliesTypesMap {
+    fn try_parse_with_order(value: &[u8], present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(present);
+        let mut outer_remaining = value;
+        let types_rtrn = if switch_expr & u32::from(MapPart::KEY_TYPES) != 0 {
+            let remaining = outer_remaining;
+            let (types_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyType>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(types_rtrn)
+        } else {
+            None
+        };
+        let syms_rtrn = if switch_expr & u32::from(MapPart::KEY_SYMS) != 0 {
+            let remaining = outer_remaining;
+            let (syms_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeySymMap>(remaining, n_key_syms.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(syms_rtrn)
+        } else {
+            None
+        };
+        let bitcase3 = if switch_expr & u32::from(MapPart::KEY_ACTIONS) != 0 {
+            let (bitcase3, new_remaining) = GetKbdByNameRepliesTypesMapBitcase3::try_parse_with_order(outer_remaining, n_key_actions, total_actions, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase3)
+        } else {
+            None
+        };
+        let behaviors_rtrn = if switch_expr & u32::from(MapPart::KEY_BEHAVIORS) != 0 {
+            let remaining = outer_remaining;
+            let (behaviors_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetBehavior>(remaining, total_key_behaviors.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(behaviors_rtrn)
+        } else {
+            None
+        };
+        let vmods_rtrn = if switch_expr & u32::from(MapPart::VIRTUAL_MODS) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (vmods_rtrn, remaining) = crate::x11_utils::parse_u8_list(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ConversionFailed))?)?;
+            let vmods_rtrn = vmods_rtrn.to_vec();
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(vmods_rtrn)
+        } else {
+            None
+        };
+        let explicit_rtrn = if switch_expr & u32::from(MapPart::EXPLICIT_COMPONENTS) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (explicit_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SetExplicit>(remaining, total_key_explicit.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(explicit_rtrn)
+        } else {
+            None
+        };
+        let modmap_rtrn = if switch_expr & u32::from(MapPart::MODIFIER_MAP) != 0 {
+            let remaining = outer_remaining;
+            let value = remaining;
+            let (modmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyModMap>(remaining, total_mod_map_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            // Align offset to multiple of 4
+            let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+            let misalignment = (4 - (offset % 4)) % 4;
+            let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+            outer_remaining = remaining;
+            Some(modmap_rtrn)
+        } else {
+            None
+        };
+        let vmodmap_rtrn = if switch_expr & u32::from(MapPart::VIRTUAL_MOD_MAP) != 0 {
+            let remaining = outer_remaining;
+            let (vmodmap_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<KeyVModMap>(remaining, total_v_mod_map_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(vmodmap_rtrn)
+        } else {
+            None
+        };
+        let result = GetKbdByNameRepliesTypesMap { types_rtrn, syms_rtrn, bitcase3, behaviors_rtrn, vmods_rtrn, explicit_rtrn, modmap_rtrn, vmodmap_rtrn };
+        Ok((result, outer_remaining))
+    }
+}
+impl GetKbdByNameRepliesTypesMap {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, present: u16, n_types: u8, n_key_syms: u8, n_key_actions: u8, total_actions: u16, total_key_behaviors: u8, virtual_mods: u16, total_key_explicit: u8, total_mod_map_keys: u8, total_v_mod_map_keys: u8, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), u32::from(present), "switch `map` has an inconsistent discriminant");
+        if let Some(ref types_rtrn) = self.types_rtrn {
+            assert_eq!(types_rtrn.len(), usize::try_from(n_types).unwrap(), "`types_rtrn` has an incorrect length");
+            types_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref syms_rtrn) = self.syms_rtrn {
+            assert_eq!(syms_rtrn.len(), usize::try_from(n_key_syms).unwrap(), "`syms_rtrn` has an incorrect length");
+            syms_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase3) = self.bitcase3 {
+            bitcase3.serialize_into_with_order(bytes, n_key_actions, total_actions, order);
+        }
+        if let Some(ref behaviors_rtrn) = self.behaviors_rtrn {
+            assert_eq!(behaviors_rtrn.len(), usize::try_from(total_key_behaviors).unwrap(), "`behaviors_rtrn` has an incorrect length");
+            behaviors_rtrn.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref vmods_rtrn) = self.vmods_rtrn {
+            assert_eq!(vmods_rtrn.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`vmods_rtrn` has an incorrect length");
+            bytes.extend_from_slice(&vmods_rtrn);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref explicit_rtrn) = self.explicit_rtrn {
+            assert_eq!(explicit_rtrn.len(), usize::try_from(total_key_explicit).unwrap(), "`explicit_rtrn` has an incorrect length");
+            explicit_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref modmap_rtrn) = self.modmap_rtrn {
+            assert_eq!(modmap_rtrn.len(), usize::try_from(total_mod_map_keys).unwrap(), "`modmap_rtrn` has an incorrect length");
+            modmap_rtrn.serialize_into_with_order(bytes, order);
+            bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        }
+        if let Some(ref vmodmap_rtrn) = self.vmodmap_rtrn {
+            assert_eq!(vmodmap_rtrn.len(), usize::try_from(total_v_mod_map_keys).unwrap(), "`vmodmap_rtrn` has an incorrect length");
+            vmodmap_rtrn.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetKbdByNameRepliesTypesMap {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.types_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KEY_TYPES);
+        }
+        if self.syms_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KEY_SYMS);
+        }
+        if self.bitcase3.is_some() {
+            expr_value |= u32::from(MapPart::KEY_ACTIONS);
+        }
+        if self.behaviors_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::KEY_BEHAVIORS);
+        }
+        if self.vmods_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VIRTUAL_MODS);
+        }
+        if self.explicit_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::EXPLICIT_COMPONENTS);
+        }
+        if self.modmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::MODIFIER_MAP);
+        }
+        if self.vmodmap_rtrn.is_some() {
+            expr_value |= u32::from(MapPart::VIRTUAL_MOD_MAP);
+        }
+        expr_value
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesTypes {
+    pub getmap_type: u8,
+    pub type_device_id: u8,
+    pub getmap_sequence: u16,
+    pub getmap_length: u32,
+    pub type_min_key_code: xproto::Keycode,
+    pub type_max_key_code: xproto::Keycode,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub total_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub total_syms: u16,
+    pub n_key_syms: u8,
+    pub first_key_action: xproto::Keycode,
+    pub total_actions: u16,
+    pub n_key_actions: u8,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behaviors: u8,
+    pub total_key_behaviors: u8,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub total_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub total_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+    pub total_v_mod_map_keys: u8,
+    pub virtual_mods: u16,
+    pub map: GetKbdByNameRepliesTypesMap,
+}
+impl TryParse for GetKbdByNameRepliesTypes {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (getmap_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (type_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (getmap_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (getmap_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (type_min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (type_max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_syms, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_action, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (total_actions, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_behaviors, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (map, remaining) = GetKbdByNameRepliesTypesMap::try_parse_with_order(remaining, present, n_types, n_key_syms, n_key_actions, total_actions, total_key_behaviors, virtual_mods, total_key_explicit, total_mod_map_keys, total_v_mod_map_keys, order)?;
+        let result = GetKbdByNameRepliesTypes { getmap_type, type_device_id, getmap_sequence, getmap_length, type_min_key_code, type_max_key_code, first_type, n_types, total_types, first_key_sym, total_syms, n_key_syms, first_key_action, total_actions, n_key_actions, first_key_behavior, n_key_behaviors, total_key_behaviors, first_key_explicit, n_key_explicit, total_key_explicit, first_mod_map_key, n_mod_map_keys, total_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, total_v_mod_map_keys, virtual_mods, map };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetKbdByNameRepliesTypes {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for GetKbdByNameRepliesTypes {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(40);
+        self.getmap_type.serialize_into_with_order(bytes, order);
+        self.type_device_id.serialize_into_with_order(bytes, order);
+        self.getmap_sequence.serialize_into_with_order(bytes, order);
+        self.getmap_length.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.type_min_key_code.serialize_into_with_order(bytes, order);
+        self.type_max_key_code.serialize_into_with_order(bytes, order);
+        let present = u16::try_from(self.map.switch_expr()).unwrap();
+        present.serialize_into_with_order(bytes, order);
+        self.first_type.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        self.total_types.serialize_into_with_order(bytes, order);
+        self.first_key_sym.serialize_into_with_order(bytes, order);
+        self.total_syms.serialize_into_with_order(bytes, order);
+        self.n_key_syms.serialize_into_with_order(bytes, order);
+        self.first_key_action.serialize_into_with_order(bytes, order);
+        self.total_actions.serialize_into_with_order(bytes, order);
+        self.n_key_actions.serialize_into_with_order(bytes, order);
+        self.first_key_behavior.serialize_into_with_order(bytes, order);
+        self.n_key_behaviors.serialize_into_with_order(bytes, order);
+        self.total_key_behaviors.serialize_into_with_order(bytes, order);
+        self.first_key_explicit.serialize_into_with_order(bytes, order);
+        self.n_key_explicit.serialize_into_with_order(bytes, order);
+        self.total_key_explicit.serialize_into_with_order(bytes, order);
+        self.first_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.first_v_mod_map_key.serialize_into_with_order(bytes, order);
+        self.n_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        self.total_v_mod_map_keys.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.virtual_mods.serialize_into_with_order(bytes, order);
+        self.map.serialize_into_with_order(bytes, present, self.n_types, self.n_key_syms, self.n_key_actions, self.total_actions, self.total_key_behaviors, self.virtual_mods, self.total_key_explicit, self.total_mod_map_keys, self.total_v_mod_map_keys, order);
+    }
+}
+impl Trace for GetKbdByNameRepliesTypes {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("getmap-type", |tracer| tracer.value(&self.getmap_type))?;
+            tracer.field("typeDeviceID", |tracer| tracer.value(&self.type_device_id))?;
+            tracer.field("getmap-sequence", |tracer| tracer.value(&self.getmap_sequence))?;
+            tracer.field("getmap-length", |tracer| tracer.value(&self.getmap_length))?;
+            tracer.field("typeMinKeyCode", |tracer| tracer.value(&self.type_min_key_code))?;
+            tracer.field("typeMaxKeyCode", |tracer| tracer.value(&self.type_max_key_code))?;
+            tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+            tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+            tracer.field("totalTypes", |tracer| tracer.value(&self.total_types))?;
+            tracer.field("firstKeySym", |tracer| tracer.value(&self.first_key_sym))?;
+            tracer.field("totalSyms", |tracer| tracer.value(&self.total_syms))?;
+            tracer.field("nKeySyms", |tracer| tracer.value(&self.n_key_syms))?;
+            tracer.field("firstKeyAction", |tracer| tracer.value(&self.first_key_action))?;
+            tracer.field("totalActions", |tracer| tracer.value(&self.total_actions))?;
+            tracer.field("nKeyActions", |tracer| tracer.value(&self.n_key_actions))?;
+            tracer.field("firstKeyBehavior", |tracer| tracer.value(&self.first_key_behavior))?;
+            tracer.field("nKeyBehaviors", |tracer| tracer.value(&self.n_key_behaviors))?;
+            tracer.field("totalKeyBehaviors", |tracer| tracer.value(&self.total_key_behaviors))?;
+            tracer.field("firstKeyExplicit", |tracer| tracer.value(&self.first_key_explicit))?;
+            tracer.field("nKeyExplicit", |tracer| tracer.value(&self.n_key_explicit))?;
+            tracer.field("totalKeyExplicit", |tracer| tracer.value(&self.total_key_explicit))?;
+            tracer.field("firstModMapKey", |tracer| tracer.value(&self.first_mod_map_key))?;
+            tracer.field("nModMapKeys", |tracer| tracer.value(&self.n_mod_map_keys))?;
+            tracer.field("totalModMapKeys", |tracer| tracer.value(&self.total_mod_map_keys))?;
+            tracer.field("firstVModMapKey", |tracer| tracer.value(&self.first_v_mod_map_key))?;
+            tracer.field("nVModMapKeys", |tracer| tracer.value(&self.n_v_mod_map_keys))?;
+            tracer.field("totalVModMapKeys", |tracer| tracer.value(&self.total_v_mod_map_keys))?;
+            tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+            tracer.field("map", |tracer| tracer.value(&self.map))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesCompatMap {
+    pub compatmap_type: u8,
+    pub compat_device_id: u8,
+    pub compatmap_sequence: u16,
+    pub compatmap_length: u32,
+    pub groups_rtrn: u8,
+    pub first_si_rtrn: u16,
+    pub n_total_si: u16,
+    pub si_rtrn: Vec<SymInterpret>,
+    pub group_rtrn: Vec<ModDef>,
+}
+impl TryParse for GetKbdByNameRepliesCompatMap {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (compatmap_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compatmap_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (compatmap_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (groups_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (first_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si_rtrn, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_total_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (si_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<SymInterpret>(remaining, n_si_rtrn.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (group_rtrn, remaining) = crate::x11_utils::parse_list_with_order::<ModDef>(remaining, groups_rtrn.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = GetKbdByNameRepliesCompatMap { compatmap_type, compat_device_id, compatmap_sequence, compatmap_length, groups_rtrn, first_si_rtrn, n_total_si, si_rtrn, group_rtrn };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetKbdByNameRepliesCompatMap {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for GetKbdByNameRepliesCompatMap {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.compatmap_type.serialize_into_with_order(bytes, order);
+        self.compat_device_id.serialize_into_with_order(bytes, order);
+        self.compatmap_sequence.serialize_into_with_order(bytes, order);
+        self.compatmap_length.serialize_into_with_order(bytes, order);
+        self.groups_rtrn.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.first_si_rtrn.serialize_into_with_order(bytes, order);
+        let n_si_rtrn = u16::try_from(self.si_rtrn.len()).expect("`si_rtrn` has too many elements");
+        n_si_rtrn.serialize_into_with_order(bytes, order);
+        self.n_total_si.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.si_rtrn.serialize_into_with_order(bytes, order);
+        assert_eq!(self.group_rtrn.len(), usize::try_from(self.groups_rtrn.count_ones()).unwrap(), "`group_rtrn` has an incorrect length");
+        self.group_rtrn.serialize_into_with_order(bytes, order);
+    }
+}
+impl GetKbdByNameRepliesCompatMap {
+    /// Get the value of the `nSIRtrn` field.
+    ///
+    /// The `nSIRtrn` field is used as the length field of the `si_rtrn` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_si_rtrn(&self) -> u16 {
+        self.si_rtrn.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetKbdByNameRepliesCompatMap {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("compatmap-type", |tracer| tracer.value(&self.compatmap_type))?;
+            tracer.field("compatDeviceID", |tracer| tracer.value(&self.compat_device_id))?;
+            tracer.field("compatmap-sequence", |tracer| tracer.value(&self.compatmap_sequence))?;
+            tracer.field("compatmap-length", |tracer| tracer.value(&self.compatmap_length))?;
+            tracer.field("groupsRtrn", |tracer| tracer.bitmask(&self.groups_rtrn, SetOfGroup::VARIANTS))?;
+            tracer.field("firstSIRtrn", |tracer| tracer.value(&self.first_si_rtrn))?;
+            tracer.field("nTotalSI", |tracer| tracer.value(&self.n_total_si))?;
+            tracer.field("si-rtrn", |tracer| tracer.list(&self.si_rtrn, |tracer, value| tracer.value(value)))?;
+            tracer.field("group-rtrn", |tracer| tracer.list(&self.group_rtrn, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesIndicatorMaps {
+    pub indicatormap_type: u8,
+    pub indicator_device_id: u8,
+    pub indicatormap_sequence: u16,
+    pub indicatormap_length: u32,
+    pub which: u32,
+    pub real_indicators: u32,
+    pub maps: Vec<IndicatorMap>,
+}
+impl TryParse for GetKbdByNameRepliesIndicatorMaps {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (indicatormap_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicator_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicatormap_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (indicatormap_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (real_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_indicators, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(15..).ok_or(ParseError::InsufficientData)?;
+        let (maps, remaining) = crate::x11_utils::parse_list_with_order::<IndicatorMap>(remaining, n_indicators.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = GetKbdByNameRepliesIndicatorMaps { indicatormap_type, indicator_device_id, indicatormap_sequence, indicatormap_length, which, real_indicators, maps };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetKbdByNameRepliesIndicatorMaps {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for GetKbdByNameRepliesIndicatorMaps {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.indicatormap_type.serialize_into_with_order(bytes, order);
+        self.indicator_device_id.serialize_into_with_order(bytes, order);
+        self.indicatormap_sequence.serialize_into_with_order(bytes, order);
+        self.indicatormap_length.serialize_into_with_order(bytes, order);
+        self.which.serialize_into_with_order(bytes, order);
+        self.real_indicators.serialize_into_with_order(bytes, order);
+        let n_indicators = u8::try_from(self.maps.len()).expect("`maps` has too many elements");
+        n_indicators.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 15]);
+        self.maps.serialize_into_with_order(bytes, order);
+    }
+}
+impl GetKbdByNameRepliesIndicatorMaps {
+    /// Get the value of the `nIndicators` field.
+    ///
+    /// The `nIndicators` field is used as the length field of the `maps` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_indicators(&self) -> u8 {
+        self.maps.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetKbdByNameRepliesIndicatorMaps {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("indicatormap-type", |tracer| tracer.value(&self.indicatormap_type))?;
+            tracer.field("indicatorDeviceID", |tracer| tracer.value(&self.indicator_device_id))?;
+            tracer.field("indicatormap-sequence", |tracer| tracer.value(&self.indicatormap_sequence))?;
+            tracer.field("indicatormap-length", |tracer| tracer.value(&self.indicatormap_length))?;
+            tracer.field("which", |tracer| tracer.value(&self.which))?;
+            tracer.field("realIndicators", |tracer| tracer.value(&self.real_indicators))?;
+            tracer.field("maps", |tracer| tracer.list(&self.maps, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesKeyNamesValueListBitcase8 {
+    pub n_levels_per_type: Vec<u8>,
+    pub kt_level_names: Vec<xproto::Atom>,
+}
+impl GetKbdByNameRepliesKeyNamesValueListBitcase8 {
+    pub fn try_parse(value: &[u8], n_types: u8) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, n_types, ByteOrder::native())
+    }
+    pub fn try_parse_with_order(remaining: &[u8], n_types: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (n_levels_per_type, remaining) = crate::x11_utils::parse_u8_list(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let n_levels_per_type = n_levels_per_type.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (kt_level_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_levels_per_type.iter().try_fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).ok_or(ParseError::InvalidExpression))?.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = GetKbdByNameRepliesKeyNamesValueListBitcase8 { n_levels_per_type, kt_level_names };
+        Ok((result, remaining))
+    }
+}
+// Skipping TryFrom implementations because of unresolved members
+impl GetKbdByNameRepliesKeyNamesValueListBitcase8 {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, n_types: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, n_types, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, n_types: u8, order: ByteOrder) {
+        assert_eq!(self.n_levels_per_type.len(), usize::try_from(n_types).unwrap(), "`n_levels_per_type` has an incorrect length");
+        bytes.extend_from_slice(&self.n_levels_per_type);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        assert_eq!(self.kt_level_names.len(), usize::try_from(self.n_levels_per_type.iter().fold(0u32, |acc, x| acc.checked_add(u32::from(*x)).unwrap())).unwrap(), "`kt_level_names` has an incorrect length");
+        self.kt_level_names.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for GetKbdByNameRepliesKeyNamesValueListBitcase8 {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("nLevelsPerType", |tracer| tracer.list(&self.n_levels_per_type, |tracer, value| tracer.value(value)))?;
+            tracer.field("ktLevelNames", |tracer| tracer.list(&self.kt_level_names, |tracer, value| tracer.atom(value)))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesKeyNamesValueList {
+    pub keycodes_name: Option<xproto::Atom>,
+    pub geometry_name: Option<xproto::Atom>,
+    pub symbols_name: Option<xproto::Atom>,
+    pub phys_symbols_name: Option<xproto::Atom>,
+    pub types_name: Option<xproto::Atom>,
+    pub compat_name: Option<xproto::Atom>,
+    pub type_names: Option<Vec<xproto::Atom>>,
+    pub bitcase8: Option<GetKbdByNameRepliesKeyNamesValueListBitcase8>,
+    pub indicator_names: Option<Vec<xproto::Atom>>,
+    pub virtual_mod_names: Option<Vec<xproto::Atom>>,
+    pub groups: Option<Vec<xproto::Atom>>,
+    pub key_names: Option<Vec<KeyName>>,
+    pub key_aliases: Option<Vec<KeyAlias>>,
+    pub radio_group_names: Option<Vec<xproto::Atom>>,
+}
+impl Trace for GetKbdByNameRepliesKeyNamesValueList {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.keycodes_name {
+                tracer.field("keycodesName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.geometry_name {
+                tracer.field("geometryName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.symbols_name {
+                tracer.field("symbolsName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.phys_symbols_name {
+                tracer.field("physSymbolsName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.types_name {
+                tracer.field("typesName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.compat_name {
+                tracer.field("compatName", |tracer| tracer.atom(value))?;
+            }
+            if let Some(ref value) = self.type_names {
+                tracer.field("typeNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.bitcase8 {
+                tracer.field("bitcase8", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.indicator_names {
+                tracer.field("indicatorNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.virtual_mod_names {
+                tracer.field("virtualModNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.groups {
+                tracer.field("groups", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            if let Some(ref value) = self.key_names {
+                tracer.field("keyNames", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.key_aliases {
+                tracer.field("keyAliases", |tracer| tracer.list(value, |tracer, value| tracer.value(value)))?;
+            }
+            if let Some(ref value) = self.radio_group_names {
+                tracer.field("radioGroupNames", |tracer| tracer.list(value, |tracer, value| tracer.atom(value)))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl GetKbdByNameRepliesKeyNamesValueList {
+    fn try_parse_with_order(value: &[u8], which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = which;
+        let mut outer_remaining = value;
+        let keycodes_name = if switch_expr & u32::from(NameDetail::KEYCODES) != 0 {
+            let remaining = outer_remaining;
+            let (keycodes_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(keycodes_name)
+        } else {
+            None
+        };
+        let geometry_name = if switch_expr & u32::from(NameDetail::GEOMETRY) != 0 {
+            let remaining = outer_remaining;
+            let (geometry_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(geometry_name)
+        } else {
+            None
+        };
+        let symbols_name = if switch_expr & u32::from(NameDetail::SYMBOLS) != 0 {
+            let remaining = outer_remaining;
+            let (symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(symbols_name)
+        } else {
+            None
+        };
+        let phys_symbols_name = if switch_expr & u32::from(NameDetail::PHYS_SYMBOLS) != 0 {
+            let remaining = outer_remaining;
+            let (phys_symbols_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(phys_symbols_name)
+        } else {
+            None
+        };
+        let types_name = if switch_expr & u32::from(NameDetail::TYPES) != 0 {
+            let remaining = outer_remaining;
+            let (types_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(types_name)
+        } else {
+            None
+        };
+        let compat_name = if switch_expr & u32::from(NameDetail::COMPAT) != 0 {
+            let remaining = outer_remaining;
+            let (compat_name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+            outer_remaining = remaining;
+            Some(compat_name)
+        } else {
+            None
+        };
+        let type_names = if switch_expr & u32::from(NameDetail::KEY_TYPE_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (type_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_types.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(type_names)
+        } else {
+            None
+        };
+        let bitcase8 = if switch_expr & u32::from(NameDetail::KT_LEVEL_NAMES) != 0 {
+            let (bitcase8, new_remaining) = GetKbdByNameRepliesKeyNamesValueListBitcase8::try_parse_with_order(outer_remaining, n_types, order)?;
+            outer_remaining = new_remaining;
+            Some(bitcase8)
+        } else {
+            None
+        };
+        let indicator_names = if switch_expr & u32::from(NameDetail::INDICATOR_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (indicator_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, indicators.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(indicator_names)
+        } else {
+            None
+        };
+        let virtual_mod_names = if switch_expr & u32::from(NameDetail::VIRTUAL_MOD_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (virtual_mod_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, virtual_mods.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(virtual_mod_names)
+        } else {
+            None
+        };
+        let groups = if switch_expr & u32::from(NameDetail::GROUP_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (groups, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, group_names.count_ones().try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(groups)
+        } else {
+            None
+        };
+        let key_names = if switch_expr & u32::from(NameDetail::KEY_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (key_names, remaining) = crate::x11_utils::parse_list_with_order::<KeyName>(remaining, n_keys.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(key_names)
+        } else {
+            None
+        };
+        let key_aliases = if switch_expr & u32::from(NameDetail::KEY_ALIASES) != 0 {
+            let remaining = outer_remaining;
+            let (key_aliases, remaining) = crate::x11_utils::parse_list_with_order::<KeyAlias>(remaining, n_key_aliases.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(key_aliases)
+        } else {
+            None
+        };
+        let radio_group_names = if switch_expr & u32::from(NameDetail::RG_NAMES) != 0 {
+            let remaining = outer_remaining;
+            let (radio_group_names, remaining) = crate::x11_utils::parse_list_with_order::<xproto::Atom>(remaining, n_radio_groups.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+            outer_remaining = remaining;
+            Some(radio_group_names)
+        } else {
+            None
+        };
+        let result = GetKbdByNameRepliesKeyNamesValueList { keycodes_name, geometry_name, symbols_name, phys_symbols_name, types_name, compat_name, type_names, bitcase8, indicator_names, virtual_mod_names, groups, key_names, key_aliases, radio_group_names };
+        Ok((result, outer_remaining))
+    }
+}
+impl GetKbdByNameRepliesKeyNamesValueList {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, which: u32, n_types: u8, indicators: u32, virtual_mods: u16, group_names: u8, n_keys: u8, n_key_aliases: u8, n_radio_groups: u8, order: ByteOrder) {
+        assert_eq!(self.switch_expr(), which, "switch `value_list` has an inconsistent discriminant");
+        if let Some(keycodes_name) = self.keycodes_name {
+            keycodes_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(geometry_name) = self.geometry_name {
+            geometry_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(symbols_name) = self.symbols_name {
+            symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(phys_symbols_name) = self.phys_symbols_name {
+            phys_symbols_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(types_name) = self.types_name {
+            types_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(compat_name) = self.compat_name {
+            compat_name.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref type_names) = self.type_names {
+            assert_eq!(type_names.len(), usize::try_from(n_types).unwrap(), "`type_names` has an incorrect length");
+            type_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref bitcase8) = self.bitcase8 {
+            bitcase8.serialize_into_with_order(bytes, n_types, order);
+        }
+        if let Some(ref indicator_names) = self.indicator_names {
+            assert_eq!(indicator_names.len(), usize::try_from(indicators.count_ones()).unwrap(), "`indicator_names` has an incorrect length");
+            indicator_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref virtual_mod_names) = self.virtual_mod_names {
+            assert_eq!(virtual_mod_names.len(), usize::try_from(virtual_mods.count_ones()).unwrap(), "`virtual_mod_names` has an incorrect length");
+            virtual_mod_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref groups) = self.groups {
+            assert_eq!(groups.len(), usize::try_from(group_names.count_ones()).unwrap(), "`groups` has an incorrect length");
+            groups.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            assert_eq!(key_names.len(), usize::try_from(n_keys).unwrap(), "`key_names` has an incorrect length");
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_aliases) = self.key_aliases {
+            assert_eq!(key_aliases.len(), usize::try_from(n_key_aliases).unwrap(), "`key_aliases` has an incorrect length");
+            key_aliases.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref radio_group_names) = self.radio_group_names {
+            assert_eq!(radio_group_names.len(), usize::try_from(n_radio_groups).unwrap(), "`radio_group_names` has an incorrect length");
+            radio_group_names.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+impl GetKbdByNameRepliesKeyNamesValueList {
+    fn switch_expr(&self) -> u32 {
+        let mut expr_value = 0;
+        if self.keycodes_name.is_some() {
+            expr_value |= u32::from(NameDetail::KEYCODES);
+        }
+        if self.geometry_name.is_some() {
+            expr_value |= u32::from(NameDetail::GEOMETRY);
+        }
+        if self.symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::SYMBOLS);
+        }
+        if self.phys_symbols_name.is_some() {
+            expr_value |= u32::from(NameDetail::PHYS_SYMBOLS);
+        }
+        if self.types_name.is_some() {
+            expr_value |= u32::from(NameDetail::TYPES);
+        }
+        if self.compat_name.is_some() {
+            expr_value |= u32::from(NameDetail::COMPAT);
+        }
+        if self.type_names.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_TYPE_NAMES);
+        }
+        if self.bitcase8.is_some() {
+            expr_value |= u32::from(NameDetail::KT_LEVEL_NAMES);
+        }
+        if self.indicator_names.is_some() {
+            expr_value |= u32::from(NameDetail::INDICATOR_NAMES);
+        }
+        if self.virtual_mod_names.is_some() {
+            expr_value |= u32::from(NameDetail::VIRTUAL_MOD_NAMES);
+        }
+        if self.groups.is_some() {
+            expr_value |= u32::from(NameDetail::GROUP_NAMES);
+        }
+        if self.key_names.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_NAMES);
+        }
+        if self.key_aliases.is_some() {
+            expr_value |= u32::from(NameDetail::KEY_ALIASES);
+        }
+        if self.radio_group_names.is_some() {
+            expr_value |= u32::from(NameDetail::RG_NAMES);
+        }
+        expr_value
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesKeyNames {
+    pub keyname_type: u8,
+    pub key_device_id: u8,
+    pub keyname_sequence: u16,
+    pub keyname_length: u32,
+    pub key_min_key_code: xproto::Keycode,
+    pub key_max_key_code: xproto::Keycode,
+    pub n_types: u8,
+    pub group_names: u8,
+    pub virtual_mods: u16,
+    pub first_key: xproto::Keycode,
+    pub n_keys: u8,
+    pub indicators: u32,
+    pub n_radio_groups: u8,
+    pub n_key_aliases: u8,
+    pub n_kt_levels: u16,
+    pub value_list: GetKbdByNameRepliesKeyNamesValueList,
+}
+impl TryParse for GetKbdByNameRepliesKeyNames {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (keyname_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (key_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keyname_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keyname_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (which, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (key_min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (key_max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_kt_levels, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let (value_list, remaining) = GetKbdByNameRepliesKeyNamesValueList::try_parse_with_order(remaining, which, n_types, indicators, virtual_mods, group_names, n_keys, n_key_aliases, n_radio_groups, order)?;
+        let result = GetKbdByNameRepliesKeyNames { keyname_type, key_device_id, keyname_sequence, keyname_length, key_min_key_code, key_max_key_code, n_types, group_names, virtual_mods, first_key, n_keys, indicators, n_radio_groups, n_key_aliases, n_kt_levels, value_list };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetKbdByNameRepliesKeyNames {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for GetKbdByNameRepliesKeyNames {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.keyname_type.serialize_into_with_order(bytes, order);
+        self.key_device_id.serialize_into_with_order(bytes, order);
+        self.keyname_sequence.serialize_into_with_order(bytes, order);
+        self.keyname_length.serialize_into_with_order(bytes, order);
+        let which = self.value_list.switch_expr();
+        which.serialize_into_with_order(bytes, order);
+        self.key_min_key_code.serialize_into_with_order(bytes, order);
+        self.key_max_key_code.serialize_into_with_order(bytes, order);
+        self.n_types.serialize_into_with_order(bytes, order);
+        self.group_names.serialize_into_with_order(bytes, order);
+        self.virtual_mods.serialize_into_with_order(bytes, order);
+        self.first_key.serialize_into_with_order(bytes, order);
+        self.n_keys.serialize_into_with_order(bytes, order);
+        self.indicators.serialize_into_with_order(bytes, order);
+        self.n_radio_groups.serialize_into_with_order(bytes, order);
+        self.n_key_aliases.serialize_into_with_order(bytes, order);
+        self.n_kt_levels.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 4]);
+        self.value_list.serialize_into_with_order(bytes, which, self.n_types, self.indicators, self.virtual_mods, self.group_names, self.n_keys, self.n_key_aliases, self.n_radio_groups, order);
+    }
+}
+impl Trace for GetKbdByNameRepliesKeyNames {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("keyname-type", |tracer| tracer.value(&self.keyname_type))?;
+            tracer.field("keyDeviceID", |tracer| tracer.value(&self.key_device_id))?;
+            tracer.field("keyname-sequence", |tracer| tracer.value(&self.keyname_sequence))?;
+            tracer.field("keyname-length", |tracer| tracer.value(&self.keyname_length))?;
+            tracer.field("keyMinKeyCode", |tracer| tracer.value(&self.key_min_key_code))?;
+            tracer.field("keyMaxKeyCode", |tracer| tracer.value(&self.key_max_key_code))?;
+            tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+            tracer.field("groupNames", |tracer| tracer.bitmask(&self.group_names, SetOfGroup::VARIANTS))?;
+            tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+            tracer.field("firstKey", |tracer| tracer.value(&self.first_key))?;
+            tracer.field("nKeys", |tracer| tracer.value(&self.n_keys))?;
+            tracer.field("indicators", |tracer| tracer.value(&self.indicators))?;
+            tracer.field("nRadioGroups", |tracer| tracer.value(&self.n_radio_groups))?;
+            tracer.field("nKeyAliases", |tracer| tracer.value(&self.n_key_aliases))?;
+            tracer.field("nKTLevels", |tracer| tracer.value(&self.n_kt_levels))?;
+            tracer.field("valueList", |tracer| tracer.value(&self.value_list))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameRepliesGeometry {
+    pub geometry_type: u8,
+    pub geometry_device_id: u8,
+    pub geometry_sequence: u16,
+    pub geometry_length: u32,
+    pub name: xproto::Atom,
+    pub geometry_found: bool,
+    pub width_mm: u16,
+    pub height_mm: u16,
+    pub n_properties: u16,
+    pub n_colors: u16,
+    pub n_shapes: u16,
+    pub n_sections: u16,
+    pub n_doodads: u16,
+    pub n_key_aliases: u16,
+    pub base_color_ndx: u8,
+    pub label_color_ndx: u8,
+    pub label_font: CountedString16,
+}
+impl TryParse for GetKbdByNameRepliesGeometry {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (geometry_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (geometry_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (geometry_sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (geometry_length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (geometry_found, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (width_mm, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height_mm, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_properties, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_colors, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_shapes, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_sections, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_doodads, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (base_color_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (label_color_ndx, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (label_font, remaining) = CountedString16::try_parse_with_order(remaining, order)?;
+        let result = GetKbdByNameRepliesGeometry { geometry_type, geometry_device_id, geometry_sequence, geometry_length, name, geometry_found, width_mm, height_mm, n_properties, n_colors, n_shapes, n_sections, n_doodads, n_key_aliases, base_color_ndx, label_color_ndx, label_font };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetKbdByNameRepliesGeometry {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for GetKbdByNameRepliesGeometry {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(32);
+        self.geometry_type.serialize_into_with_order(bytes, order);
+        self.geometry_device_id.serialize_into_with_order(bytes, order);
+        self.geometry_sequence.serialize_into_with_order(bytes, order);
+        self.geometry_length.serialize_into_with_order(bytes, order);
+        self.name.serialize_into_with_order(bytes, order);
+        self.geometry_found.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        self.width_mm.serialize_into_with_order(bytes, order);
+        self.height_mm.serialize_into_with_order(bytes, order);
+        self.n_properties.serialize_into_with_order(bytes, order);
+        self.n_colors.serialize_into_with_order(bytes, order);
+        self.n_shapes.serialize_into_with_order(bytes, order);
+        self.n_sections.serialize_into_with_order(bytes, order);
+        self.n_doodads.serialize_into_with_order(bytes, order);
+        self.n_key_aliases.serialize_into_with_order(bytes, order);
+        self.base_color_ndx.serialize_into_with_order(bytes, order);
+        self.label_color_ndx.serialize_into_with_order(bytes, order);
+        self.label_font.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for GetKbdByNameRepliesGeometry {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("geometry-type", |tracer| tracer.value(&self.geometry_type))?;
+            tracer.field("geometryDeviceID", |tracer| tracer.value(&self.geometry_device_id))?;
+            tracer.field("geometry-sequence", |tracer| tracer.value(&self.geometry_sequence))?;
+            tracer.field("geometry-length", |tracer| tracer.value(&self.geometry_length))?;
+            tracer.field("name", |tracer| tracer.atom(&self.name))?;
+            tracer.field("geometryFound", |tracer| tracer.value(&self.geometry_found))?;
+            tracer.field("widthMM", |tracer| tracer.value(&self.width_mm))?;
+            tracer.field("heightMM", |tracer| tracer.value(&self.height_mm))?;
+            tracer.field("nProperties", |tracer| tracer.value(&self.n_properties))?;
+            tracer.field("nColors", |tracer| tracer.value(&self.n_colors))?;
+            tracer.field("nShapes", |tracer| tracer.value(&self.n_shapes))?;
+            tracer.field("nSections", |tracer| tracer.value(&self.n_sections))?;
+            tracer.field("nDoodads", |tracer| tracer.value(&self.n_doodads))?;
+            tracer.field("nKeyAliases", |tracer| tracer.value(&self.n_key_aliases))?;
+            tracer.field("baseColorNdx", |tracer| tracer.value(&self.base_color_ndx))?;
+            tracer.field("labelColorNdx", |tracer| tracer.value(&self.label_color_ndx))?;
+            tracer.field("labelFont", |tracer| tracer.value(&self.label_font))?;
+            Ok(())
+        })
+    }
+}
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameReplies {
+    pub types: Option<GetKbdByNameRepliesTypes>,
+    pub compat_map: Option<GetKbdByNameRepliesCompatMap>,
+    pub indicator_maps: Option<GetKbdByNameRepliesIndicatorMaps>,
+    pub key_names: Option<GetKbdByNameRepliesKeyNames>,
+    pub geometry: Option<GetKbdByNameRepliesGeometry>,
+}
+impl Trace for GetKbdByNameReplies {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            if let Some(ref value) = self.types {
+                tracer.field("types", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.compat_map {
+                tracer.field("compat-map", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.indicator_maps {
+                tracer.field("indicator-maps", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.key_names {
+                tracer.field("key-names", |tracer| tracer.value(value))?;
+            }
+            if let Some(ref value) = self.geometry {
+                tracer.field("geometry", |tracer| tracer.value(value))?;
+            }
+            Ok(())
+        })
+    }
+}
+impl GetKbdByNameReplies {
+    fn try_parse_with_order(value: &[u8], reported: u16, order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let switch_expr = u32::from(reported);
+        let mut outer_remaining = value;
+        let types = if switch_expr & u32::from(GBNDetail::TYPES) != 0 || switch_expr & u32::from(GBNDetail::CLIENT_SYMBOLS) != 0 || switch_expr & u32::from(GBNDetail::SERVER_SYMBOLS) != 0 {
+            let (types, new_remaining) = GetKbdByNameRepliesTypes::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(types)
+        } else {
+            None
+        };
+        let compat_map = if switch_expr & u32::from(GBNDetail::COMPAT_MAP) != 0 {
+            let (compat_map, new_remaining) = GetKbdByNameRepliesCompatMap::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(compat_map)
+        } else {
+            None
+        };
+        let indicator_maps = if switch_expr & u32::from(GBNDetail::INDICATOR_MAPS) != 0 {
+            let (indicator_maps, new_remaining) = GetKbdByNameRepliesIndicatorMaps::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(indicator_maps)
+        } else {
+            None
+        };
+        let key_names = if switch_expr & u32::from(GBNDetail::KEY_NAMES) != 0 || switch_expr & u32::from(GBNDetail::OTHER_NAMES) != 0 {
+            let (key_names, new_remaining) = GetKbdByNameRepliesKeyNames::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(key_names)
+        } else {
+            None
+        };
+        let geometry = if switch_expr & u32::from(GBNDetail::GEOMETRY) != 0 {
+            let (geometry, new_remaining) = GetKbdByNameRepliesGeometry::try_parse_with_order(outer_remaining, order)?;
+            outer_remaining = new_remaining;
+            Some(geometry)
+        } else {
+            None
+        };
+        let result = GetKbdByNameReplies { types, compat_map, indicator_maps, key_names, geometry };
+        Ok((result, outer_remaining))
+    }
+}
+impl GetKbdByNameReplies {
+    #[allow(dead_code)]
+    fn serialize_with_order(&self, reported: u16, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, reported, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, reported: u16, order: ByteOrder) {
+        let _ = u32::from(reported);
+        if let Some(ref types) = self.types {
+            types.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref compat_map) = self.compat_map {
+            compat_map.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref indicator_maps) = self.indicator_maps {
+            indicator_maps.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref key_names) = self.key_names {
+            key_names.serialize_into_with_order(bytes, order);
+        }
+        if let Some(ref geometry) = self.geometry {
+            geometry.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetKbdByNameReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub loaded: bool,
+    pub new_keyboard: bool,
+    pub found: u16,
+    pub reported: u16,
+    pub replies: GetKbdByNameReplies,
+}
+impl TryParse for GetKbdByNameReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (loaded, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (new_keyboard, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (found, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (reported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (replies, remaining) = GetKbdByNameReplies::try_parse_with_order(remaining, reported, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetKbdByNameReply { device_id, sequence, length, min_key_code, max_key_code, loaded, new_keyboard, found, reported, replies };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetKbdByNameReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetKbdByNameReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("minKeyCode", |tracer| tracer.value(&self.min_key_code))?;
+        tracer.field("maxKeyCode", |tracer| tracer.value(&self.max_key_code))?;
+        tracer.field("loaded", |tracer| tracer.value(&self.loaded))?;
+        tracer.field("newKeyboard", |tracer| tracer.value(&self.new_keyboard))?;
+        tracer.field("found", |tracer| tracer.bitmask(&self.found, GBNDetail::VARIANTS))?;
+        tracer.field("reported", |tracer| tracer.bitmask(&self.reported, GBNDetail::VARIANTS))?;
+        tracer.field("replies", |tracer| tracer.value(&self.replies))?;
+        Ok(())
+    }
+}
+impl Serialize for GetKbdByNameReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.min_key_code.serialize_into_with_order(bytes, order);
+        self.max_key_code.serialize_into_with_order(bytes, order);
+        self.loaded.serialize_into_with_order(bytes, order);
+        self.new_keyboard.serialize_into_with_order(bytes, order);
+        self.found.serialize_into_with_order(bytes, order);
+        self.reported.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.replies.serialize_into_with_order(bytes, self.reported, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GetDeviceInfo request
+pub const GET_DEVICE_INFO_REQUEST: u8 = 24;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetDeviceInfoRequest {
+    pub device_spec: DeviceSpec,
+    pub wanted: u16,
+    pub all_buttons: bool,
+    pub first_button: u8,
+    pub n_buttons: u8,
+    pub led_class: LedClass,
+    pub led_id: IDSpec,
+}
+impl GetDeviceInfoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let wanted_bytes = self.wanted.serialize_with_order(order);
+        let all_buttons_bytes = self.all_buttons.serialize_with_order(order);
+        let first_button_bytes = self.first_button.serialize_with_order(order);
+        let n_buttons_bytes = self.n_buttons.serialize_with_order(order);
+        let led_class_bytes = LedClassSpec::from(self.led_class).serialize_with_order(order);
+        let led_id_bytes = self.led_id.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_DEVICE_INFO_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            wanted_bytes[0],
+            wanted_bytes[1],
+            all_buttons_bytes[0],
+            first_button_bytes[0],
+            n_buttons_bytes[0],
+            0,
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_DEVICE_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (wanted, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (all_buttons, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (first_button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (led_class, remaining) = LedClassSpec::try_parse_with_order(remaining, order)?;
+        let led_class = led_class.into();
+        let (led_id, remaining) = IDSpec::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetDeviceInfoRequest {
+            device_spec,
+            wanted,
+            all_buttons,
+            first_button,
+            n_buttons,
+            led_class,
+            led_id,
+        })
+    }
+}
+impl Request for GetDeviceInfoRequest {
+    type Reply = GetDeviceInfoReply;
+}
+impl Trace for GetDeviceInfoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("wanted", |tracer| tracer.bitmask(&self.wanted, XIFeature::VARIANTS))?;
+        tracer.field("allButtons", |tracer| tracer.value(&self.all_buttons))?;
+        tracer.field("firstButton", |tracer| tracer.value(&self.first_button))?;
+        tracer.field("nButtons", |tracer| tracer.value(&self.n_buttons))?;
+        tracer.field("ledClass", |tracer| tracer.value(&self.led_class))?;
+        tracer.field("ledID", |tracer| tracer.enum_value(&self.led_id, ID::VARIANTS))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetDeviceInfoReply {
+    pub device_id: u8,
+    pub sequence: u16,
+    pub length: u32,
+    pub present: u16,
+    pub supported: u16,
+    pub unsupported: u16,
+    pub first_btn_wanted: u8,
+    pub n_btns_wanted: u8,
+    pub first_btn_rtrn: u8,
+    pub total_btns: u8,
+    pub has_own_state: bool,
+    pub dflt_kbd_fb: u16,
+    pub dflt_led_fb: u16,
+    pub dev_type: xproto::Atom,
+    pub name: Vec<String8>,
+    pub btn_actions: Vec<Action>,
+    pub leds: Vec<DeviceLedInfo>,
+}
+impl TryParse for GetDeviceInfoReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let value = remaining;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (present, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (unsupported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_device_led_f_bs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_btn_wanted, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_btns_wanted, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_btn_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_btns_rtrn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (total_btns, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (has_own_state, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (dflt_kbd_fb, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (dflt_led_fb, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (dev_type, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (name_len, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_len.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (btn_actions, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, n_btns_rtrn.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (leds, remaining) = crate::x11_utils::parse_list_with_order::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetDeviceInfoReply { device_id, sequence, length, present, supported, unsupported, first_btn_wanted, n_btns_wanted, first_btn_rtrn, total_btns, has_own_state, dflt_kbd_fb, dflt_led_fb, dev_type, name, btn_actions, leds };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetDeviceInfoReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl GetDeviceInfoReply {
+    /// Get the value of the `nDeviceLedFBs` field.
+    ///
+    /// The `nDeviceLedFBs` field is used as the length field of the `leds` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_device_led_f_bs(&self) -> u16 {
+        self.leds.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nBtnsRtrn` field.
+    ///
+    /// The `nBtnsRtrn` field is used as the length field of the `btnActions` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn n_btns_rtrn(&self) -> u8 {
+        self.btn_actions.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `nameLen` field.
+    ///
+    /// The `nameLen` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn name_len(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for GetDeviceInfoReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("present", |tracer| tracer.bitmask(&self.present, XIFeature::VARIANTS))?;
+        tracer.field("supported", |tracer| tracer.bitmask(&self.supported, XIFeature::VARIANTS))?;
+        tracer.field("unsupported", |tracer| tracer.bitmask(&self.unsupported, XIFeature::VARIANTS))?;
+        tracer.field("firstBtnWanted", |tracer| tracer.value(&self.first_btn_wanted))?;
+        tracer.field("nBtnsWanted", |tracer| tracer.value(&self.n_btns_wanted))?;
+        tracer.field("firstBtnRtrn", |tracer| tracer.value(&self.first_btn_rtrn))?;
+        tracer.field("totalBtns", |tracer| tracer.value(&self.total_btns))?;
+        tracer.field("hasOwnState", |tracer| tracer.value(&self.has_own_state))?;
+        tracer.field("dfltKbdFB", |tracer| tracer.enum_value(&self.dflt_kbd_fb, ID::VARIANTS))?;
+        tracer.field("dfltLedFB", |tracer| tracer.enum_value(&self.dflt_led_fb, ID::VARIANTS))?;
+        tracer.field("devType", |tracer| tracer.atom(&self.dev_type))?;
+        tracer.field("name", |tracer| tracer.string(&self.name))?;
+        tracer.field("btnActions", |tracer| tracer.list(&self.btn_actions, |tracer, value| tracer.value(value)))?;
+        tracer.field("leds", |tracer| tracer.list(&self.leds, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for GetDeviceInfoReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        self.device_id.serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.present.serialize_into_with_order(bytes, order);
+        self.supported.serialize_into_with_order(bytes, order);
+        self.unsupported.serialize_into_with_order(bytes, order);
+        let n_device_led_f_bs = u16::try_from(self.leds.len()).expect("`leds` has too many elements");
+        n_device_led_f_bs.serialize_into_with_order(bytes, order);
+        self.first_btn_wanted.serialize_into_with_order(bytes, order);
+        self.n_btns_wanted.serialize_into_with_order(bytes, order);
+        self.first_btn_rtrn.serialize_into_with_order(bytes, order);
+        let n_btns_rtrn = u8::try_from(self.btn_actions.len()).expect("`btn_actions` has too many elements");
+        n_btns_rtrn.serialize_into_with_order(bytes, order);
+        self.total_btns.serialize_into_with_order(bytes, order);
+        self.has_own_state.serialize_into_with_order(bytes, order);
+        self.dflt_kbd_fb.serialize_into_with_order(bytes, order);
+        self.dflt_led_fb.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.dev_type.serialize_into_with_order(bytes, order);
+        let name_len = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_len.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.btn_actions.serialize_into_with_order(bytes, order);
+        self.leds.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetDeviceInfo request
+pub const SET_DEVICE_INFO_REQUEST: u8 = 25;
+#[derive(Debug, Clone)]
+pub struct SetDeviceInfoRequest<'input> {
+    pub device_spec: DeviceSpec,
+    pub first_btn: u8,
+    pub change: u16,
+    pub btn_actions: Cow<'input, [Action]>,
+    pub leds: Cow<'input, [DeviceLedInfo]>,
+}
+impl<'input> SetDeviceInfoRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let device_spec_bytes = self.device_spec.serialize_with_order(order);
+        let first_btn_bytes = self.first_btn.serialize_with_order(order);
+        let n_btns = u8::try_from(self.btn_actions.len()).expect("`btn_actions` has too many elements");
+        let n_btns_bytes = n_btns.serialize_with_order(order);
+        let change_bytes = self.change.serialize_with_order(order);
+        let n_device_led_f_bs = u16::try_from(self.leds.len()).expect("`leds` has too many elements");
+        let n_device_led_f_bs_bytes = n_device_led_f_bs.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_DEVICE_INFO_REQUEST,
+            0,
+            0,
+            device_spec_bytes[0],
+            device_spec_bytes[1],
+            first_btn_bytes[0],
+            n_btns_bytes[0],
+            change_bytes[0],
+            change_bytes[1],
+            n_device_led_f_bs_bytes[0],
+            n_device_led_f_bs_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let btn_actions_bytes = self.btn_actions.serialize_with_order(order);
+        let length_so_far = length_so_far + btn_actions_bytes.len();
+        let leds_bytes = self.leds.serialize_with_order(order);
+        let length_so_far = length_so_far + leds_bytes.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), btn_actions_bytes.into(), leds_bytes.into(), padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEVICE_INFO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (device_spec, remaining) = DeviceSpec::try_parse_with_order(value, order)?;
+        let (first_btn, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_btns, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (change, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_device_led_f_bs, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (btn_actions, remaining) = crate::x11_utils::parse_list_with_order::<Action>(remaining, n_btns.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (leds, remaining) = crate::x11_utils::parse_list_with_order::<DeviceLedInfo>(remaining, n_device_led_f_bs.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let _ = remaining;
+        Ok(SetDeviceInfoRequest {
+            device_spec,
+            first_btn,
+            change,
+            btn_actions: Cow::Owned(btn_actions),
+            leds: Cow::Owned(leds),
+        })
+    }
+    /// Clone all borrowed data in this SetDeviceInfoRequest.
+    pub fn into_owned(self) -> SetDeviceInfoRequest<'static> {
+        SetDeviceInfoRequest {
+            device_spec: self.device_spec,
+            first_btn: self.first_btn,
+            change: self.change,
+            btn_actions: Cow::Owned(self.btn_actions.into_owned()),
+            leds: Cow::Owned(self.leds.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetDeviceInfoRequest<'input> {
+    type Reply = ();
+}
+impl Trace for SetDeviceInfoRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("deviceSpec", |tracer| tracer.value(&self.device_spec))?;
+        tracer.field("firstBtn", |tracer| tracer.value(&self.first_btn))?;
+        tracer.field("change", |tracer| tracer.bitmask(&self.change, XIFeature::VARIANTS))?;
+        tracer.field("btnActions", |tracer| tracer.list(&self.btn_actions[..], |tracer, value| tracer.value(value)))?;
+        tracer.field("leds", |tracer| tracer.list(&self.leds[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SetDebuggingFlags request
+pub const SET_DEBUGGING_FLAGS_REQUEST: u8 = 101;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetDebuggingFlagsRequest<'input> {
+    pub affect_flags: u32,
+    pub flags: u32,
+    pub affect_ctrls: u32,
+    pub ctrls: u32,
+    pub message: Cow<'input, [String8]>,
+}
+impl<'input> SetDebuggingFlagsRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let msg_length = u16::try_from(self.message.len()).expect("`message` has too many elements");
+        let msg_length_bytes = msg_length.serialize_with_order(order);
+        let affect_flags_bytes = self.affect_flags.serialize_with_order(order);
+        let flags_bytes = self.flags.serialize_with_order(order);
+        let affect_ctrls_bytes = self.affect_ctrls.serialize_with_order(order);
+        let ctrls_bytes = self.ctrls.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_DEBUGGING_FLAGS_REQUEST,
+            0,
+            0,
+            msg_length_bytes[0],
+            msg_length_bytes[1],
+            0,
+            0,
+            affect_flags_bytes[0],
+            affect_flags_bytes[1],
+            affect_flags_bytes[2],
+            affect_flags_bytes[3],
+            flags_bytes[0],
+            flags_bytes[1],
+            flags_bytes[2],
+            flags_bytes[3],
+            affect_ctrls_bytes[0],
+            affect_ctrls_bytes[1],
+            affect_ctrls_bytes[2],
+            affect_ctrls_bytes[3],
+            ctrls_bytes[0],
+            ctrls_bytes[1],
+            ctrls_bytes[2],
+            ctrls_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.message.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.message, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_DEBUGGING_FLAGS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (msg_length, remaining) = u16::try_parse_with_order(value, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (affect_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (affect_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, msg_length.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let _ = remaining;
+        Ok(SetDebuggingFlagsRequest {
+            affect_flags,
+            flags,
+            affect_ctrls,
+            ctrls,
+            message: Cow::Borrowed(message),
+        })
+    }
+    /// Clone all borrowed data in this SetDebuggingFlagsRequest.
+    pub fn into_owned(self) -> SetDebuggingFlagsRequest<'static> {
+        SetDebuggingFlagsRequest {
+            affect_flags: self.affect_flags,
+            flags: self.flags,
+            affect_ctrls: self.affect_ctrls,
+            ctrls: self.ctrls,
+            message: Cow::Owned(self.message.into_owned()),
+        }
+    }
+}
+impl<'input> Request for SetDebuggingFlagsRequest<'input> {
+    type Reply = SetDebuggingFlagsReply;
+}
+impl Trace for SetDebuggingFlagsRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("affectFlags", |tracer| tracer.value(&self.affect_flags))?;
+        tracer.field("flags", |tracer| tracer.value(&self.flags))?;
+        tracer.field("affectCtrls", |tracer| tracer.value(&self.affect_ctrls))?;
+        tracer.field("ctrls", |tracer| tracer.value(&self.ctrls))?;
+        tracer.field("message", |tracer| tracer.string(&self.message[..]))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetDebuggingFlagsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub current_flags: u32,
+    pub current_ctrls: u32,
+    pub supported_flags: u32,
+    pub supported_ctrls: u32,
+}
+impl TryParse for SetDebuggingFlagsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (current_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (current_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported_flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (supported_ctrls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(8..).ok_or(ParseError::InsufficientData)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = SetDebuggingFlagsReply { sequence, length, current_flags, current_ctrls, supported_flags, supported_ctrls };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for SetDebuggingFlagsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for SetDebuggingFlagsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("currentFlags", |tracer| tracer.value(&self.current_flags))?;
+        tracer.field("currentCtrls", |tracer| tracer.value(&self.current_ctrls))?;
+        tracer.field("supportedFlags", |tracer| tracer.value(&self.supported_flags))?;
+        tracer.field("supportedCtrls", |tracer| tracer.value(&self.supported_ctrls))?;
+        Ok(())
+    }
+}
+impl Serialize for SetDebuggingFlagsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.current_flags.serialize_into_with_order(bytes, order);
+        self.current_ctrls.serialize_into_with_order(bytes, order);
+        self.supported_flags.serialize_into_with_order(bytes, order);
+        self.supported_ctrls.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 8]);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the NewKeyboardNotify event
+pub const NEW_KEYBOARD_NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewKeyboardNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub old_device_id: u8,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub old_min_key_code: xproto::Keycode,
+    pub old_max_key_code: xproto::Keycode,
+    pub request_major: u8,
+    pub request_minor: u8,
+    pub changed: u16,
+}
+impl TryParse for NewKeyboardNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (old_device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (old_min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (old_max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (request_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(14..).ok_or(ParseError::InsufficientData)?;
+        let result = NewKeyboardNotifyEvent { response_type, xkb_type, sequence, time, device_id, old_device_id, min_key_code, max_key_code, old_min_key_code, old_max_key_code, request_major, request_minor, changed };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for NewKeyboardNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for NewKeyboardNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("oldDeviceID", |tracer| tracer.value(&self.old_device_id))?;
+        tracer.field("minKeyCode", |tracer| tracer.value(&self.min_key_code))?;
+        tracer.field("maxKeyCode", |tracer| tracer.value(&self.max_key_code))?;
+        tracer.field("oldMinKeyCode", |tracer| tracer.value(&self.old_min_key_code))?;
+        tracer.field("oldMaxKeyCode", |tracer| tracer.value(&self.old_max_key_code))?;
+        tracer.field("requestMajor", |tracer| tracer.value(&self.request_major))?;
+        tracer.field("requestMinor", |tracer| tracer.value(&self.request_minor))?;
+        tracer.field("changed", |tracer| tracer.bitmask(&self.changed, NKNDetail::VARIANTS))?;
+        Ok(())
+    }
+}
+impl NewKeyboardNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let old_device_id_bytes = self.old_device_id.serialize_with_order(order);
+        let min_key_code_bytes = self.min_key_code.serialize_with_order(order);
+        let max_key_code_bytes = self.max_key_code.serialize_with_order(order);
+        let old_min_key_code_bytes = self.old_min_key_code.serialize_with_order(order);
+        let old_max_key_code_bytes = self.old_max_key_code.serialize_with_order(order);
+        let request_major_bytes = self.request_major.serialize_with_order(order);
+        let request_minor_bytes = self.request_minor.serialize_with_order(order);
+        let changed_bytes = self.changed.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            old_device_id_bytes[0],
+            min_key_code_bytes[0],
+            max_key_code_bytes[0],
+            old_min_key_code_bytes[0],
+            old_max_key_code_bytes[0],
+            request_major_bytes[0],
+            request_minor_bytes[0],
+            changed_bytes[0],
+            changed_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&NewKeyboardNotifyEvent> for [u8; 32] {
+    fn from(input: &NewKeyboardNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<NewKeyboardNotifyEvent> for [u8; 32] {
+    fn from(input: NewKeyboardNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the MapNotify event
+pub const MAP_NOTIFY_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub ptr_btn_actions: u8,
+    pub changed: u16,
+    pub min_key_code: xproto::Keycode,
+    pub max_key_code: xproto::Keycode,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_key_sym: xproto::Keycode,
+    pub n_key_syms: u8,
+    pub first_key_act: xproto::Keycode,
+    pub n_key_acts: u8,
+    pub first_key_behavior: xproto::Keycode,
+    pub n_key_behavior: u8,
+    pub first_key_explicit: xproto::Keycode,
+    pub n_key_explicit: u8,
+    pub first_mod_map_key: xproto::Keycode,
+    pub n_mod_map_keys: u8,
+    pub first_v_mod_map_key: xproto::Keycode,
+    pub n_v_mod_map_keys: u8,
+    pub virtual_mods: u16,
+}
+impl TryParse for MapNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ptr_btn_actions, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (min_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (max_key_code, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_sym, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_syms, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_act, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_acts, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_behavior, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_behavior, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_key_explicit, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_key_explicit, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_v_mod_map_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_v_mod_map_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let result = MapNotifyEvent { response_type, xkb_type, sequence, time, device_id, ptr_btn_actions, changed, min_key_code, max_key_code, first_type, n_types, first_key_sym, n_key_syms, first_key_act, n_key_acts, first_key_behavior, n_key_behavior, first_key_explicit, n_key_explicit, first_mod_map_key, n_mod_map_keys, first_v_mod_map_key, n_v_mod_map_keys, virtual_mods };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for MapNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for MapNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("ptrBtnActions", |tracer| tracer.value(&self.ptr_btn_actions))?;
+        tracer.field("changed", |tracer| tracer.bitmask(&self.changed, MapPart::VARIANTS))?;
+        tracer.field("minKeyCode", |tracer| tracer.value(&self.min_key_code))?;
+        tracer.field("maxKeyCode", |tracer| tracer.value(&self.max_key_code))?;
+        tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("firstKeySym", |tracer| tracer.value(&self.first_key_sym))?;
+        tracer.field("nKeySyms", |tracer| tracer.value(&self.n_key_syms))?;
+        tracer.field("firstKeyAct", |tracer| tracer.value(&self.first_key_act))?;
+        tracer.field("nKeyActs", |tracer| tracer.value(&self.n_key_acts))?;
+        tracer.field("firstKeyBehavior", |tracer| tracer.value(&self.first_key_behavior))?;
+        tracer.field("nKeyBehavior", |tracer| tracer.value(&self.n_key_behavior))?;
+        tracer.field("firstKeyExplicit", |tracer| tracer.value(&self.first_key_explicit))?;
+        tracer.field("nKeyExplicit", |tracer| tracer.value(&self.n_key_explicit))?;
+        tracer.field("firstModMapKey", |tracer| tracer.value(&self.first_mod_map_key))?;
+        tracer.field("nModMapKeys", |tracer| tracer.value(&self.n_mod_map_keys))?;
+        tracer.field("firstVModMapKey", |tracer| tracer.value(&self.first_v_mod_map_key))?;
+        tracer.field("nVModMapKeys", |tracer| tracer.value(&self.n_v_mod_map_keys))?;
+        tracer.field("virtualMods", |tracer| tracer.bitmask(&self.virtual_mods, VMod::VARIANTS))?;
+        Ok(())
+    }
+}
+impl MapNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let ptr_btn_actions_bytes = self.ptr_btn_actions.serialize_with_order(order);
+        let changed_bytes = self.changed.serialize_with_order(order);
+        let min_key_code_bytes = self.min_key_code.serialize_with_order(order);
+        let max_key_code_bytes = self.max_key_code.serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_key_sym_bytes = self.first_key_sym.serialize_with_order(order);
+        let n_key_syms_bytes = self.n_key_syms.serialize_with_order(order);
+        let first_key_act_bytes = self.first_key_act.serialize_with_order(order);
+        let n_key_acts_bytes = self.n_key_acts.serialize_with_order(order);
+        let first_key_behavior_bytes = self.first_key_behavior.serialize_with_order(order);
+        let n_key_behavior_bytes = self.n_key_behavior.serialize_with_order(order);
+        let first_key_explicit_bytes = self.first_key_explicit.serialize_with_order(order);
+        let n_key_explicit_bytes = self.n_key_explicit.serialize_with_order(order);
+        let first_mod_map_key_bytes = self.first_mod_map_key.serialize_with_order(order);
+        let n_mod_map_keys_bytes = self.n_mod_map_keys.serialize_with_order(order);
+        let first_v_mod_map_key_bytes = self.first_v_mod_map_key.serialize_with_order(order);
+        let n_v_mod_map_keys_bytes = self.n_v_mod_map_keys.serialize_with_order(order);
+        let virtual_mods_bytes = self.virtual_mods.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            ptr_btn_actions_bytes[0],
+            changed_bytes[0],
+            changed_bytes[1],
+            min_key_code_bytes[0],
+            max_key_code_bytes[0],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_key_sym_bytes[0],
+            n_key_syms_bytes[0],
+            first_key_act_bytes[0],
+            n_key_acts_bytes[0],
+            first_key_behavior_bytes[0],
+            n_key_behavior_bytes[0],
+            first_key_explicit_bytes[0],
+            n_key_explicit_bytes[0],
+            first_mod_map_key_bytes[0],
+            n_mod_map_keys_bytes[0],
+            first_v_mod_map_key_bytes[0],
+            n_v_mod_map_keys_bytes[0],
+            virtual_mods_bytes[0],
+            virtual_mods_bytes[1],
+            0,
+            0,
+        ]
+    }
+}
+impl From<&MapNotifyEvent> for [u8; 32] {
+    fn from(input: &MapNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<MapNotifyEvent> for [u8; 32] {
+    fn from(input: MapNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the StateNotify event
+pub const STATE_NOTIFY_EVENT: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub mods: u8,
+    pub base_mods: u8,
+    pub latched_mods: u8,
+    pub locked_mods: u8,
+    pub group: Group,
+    pub base_group: i16,
+    pub latched_group: i16,
+    pub locked_group: Group,
+    pub compat_state: u8,
+    pub grab_mods: u8,
+    pub compat_grab_mods: u8,
+    pub lookup_mods: u8,
+    pub compat_loockup_mods: u8,
+    pub ptr_btn_state: u16,
+    pub changed: u16,
+    pub keycode: xproto::Keycode,
+    pub event_type: u8,
+    pub request_major: u8,
+    pub request_minor: u8,
+}
+impl TryParse for StateNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (latched_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (locked_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (base_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (latched_group, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (locked_group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_state, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_grab_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (lookup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (compat_loockup_mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (ptr_btn_state, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let group = group.into();
+        let locked_group = locked_group.into();
+        let result = StateNotifyEvent { response_type, xkb_type, sequence, time, device_id, mods, base_mods, latched_mods, locked_mods, group, base_group, latched_group, locked_group, compat_state, grab_mods, compat_grab_mods, lookup_mods, compat_loockup_mods, ptr_btn_state, changed, keycode, event_type, request_major, request_minor };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for StateNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for StateNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("mods", |tracer| tracer.bitmask(&self.mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("baseMods", |tracer| tracer.bitmask(&self.base_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("latchedMods", |tracer| tracer.bitmask(&self.latched_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("lockedMods", |tracer| tracer.bitmask(&self.locked_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("group", |tracer| tracer.value(&self.group))?;
+        tracer.field("baseGroup", |tracer| tracer.value(&self.base_group))?;
+        tracer.field("latchedGroup", |tracer| tracer.value(&self.latched_group))?;
+        tracer.field("lockedGroup", |tracer| tracer.value(&self.locked_group))?;
+        tracer.field("compatState", |tracer| tracer.bitmask(&self.compat_state, xproto::ModMask::VARIANTS))?;
+        tracer.field("grabMods", |tracer| tracer.bitmask(&self.grab_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("compatGrabMods", |tracer| tracer.bitmask(&self.compat_grab_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("lookupMods", |tracer| tracer.bitmask(&self.lookup_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("compatLoockupMods", |tracer| tracer.bitmask(&self.compat_loockup_mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("ptrBtnState", |tracer| tracer.bitmask(&self.ptr_btn_state, xproto::KeyButMask::VARIANTS))?;
+        tracer.field("changed", |tracer| tracer.bitmask(&self.changed, StatePart::VARIANTS))?;
+        tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+        tracer.field("eventType", |tracer| tracer.value(&self.event_type))?;
+        tracer.field("requestMajor", |tracer| tracer.value(&self.request_major))?;
+        tracer.field("requestMinor", |tracer| tracer.value(&self.request_minor))?;
+        Ok(())
+    }
+}
+impl StateNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let mods_bytes = self.mods.serialize_with_order(order);
+        let base_mods_bytes = self.base_mods.serialize_with_order(order);
+        let latched_mods_bytes = self.latched_mods.serialize_with_order(order);
+        let locked_mods_bytes = self.locked_mods.serialize_with_order(order);
+        let group_bytes = u8::from(self.group).serialize_with_order(order);
+        let base_group_bytes = self.base_group.serialize_with_order(order);
+        let latched_group_bytes = self.latched_group.serialize_with_order(order);
+        let locked_group_bytes = u8::from(self.locked_group).serialize_with_order(order);
+        let compat_state_bytes = self.compat_state.serialize_with_order(order);
+        let grab_mods_bytes = self.grab_mods.serialize_with_order(order);
+        let compat_grab_mods_bytes = self.compat_grab_mods.serialize_with_order(order);
+        let lookup_mods_bytes = self.lookup_mods.serialize_with_order(order);
+        let compat_loockup_mods_bytes = self.compat_loockup_mods.serialize_with_order(order);
+        let ptr_btn_state_bytes = self.ptr_btn_state.serialize_with_order(order);
+        let changed_bytes = self.changed.serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let request_major_bytes = self.request_major.serialize_with_order(order);
+        let request_minor_bytes = self.request_minor.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            mods_bytes[0],
+            base_mods_bytes[0],
+            latched_mods_bytes[0],
+            locked_mods_bytes[0],
+            group_bytes[0],
+            base_group_bytes[0],
+            base_group_bytes[1],
+            latched_group_bytes[0],
+            latched_group_bytes[1],
+            locked_group_bytes[0],
+            compat_state_bytes[0],
+            grab_mods_bytes[0],
+            compat_grab_mods_bytes[0],
+            lookup_mods_bytes[0],
+            compat_loockup_mods_bytes[0],
+            ptr_btn_state_bytes[0],
+            ptr_btn_state_bytes[1],
+            changed_bytes[0],
+            changed_bytes[1],
+            keycode_bytes[0],
+            event_type_bytes[0],
+            request_major_bytes[0],
+            request_minor_bytes[0],
+        ]
+    }
+}
+impl From<&StateNotifyEvent> for [u8; 32] {
+    fn from(input: &StateNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<StateNotifyEvent> for [u8; 32] {
+    fn from(input: StateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the ControlsNotify event
+pub const CONTROLS_NOTIFY_EVENT: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlsNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub num_groups: u8,
+    pub changed_controls: u32,
+    pub enabled_controls: u32,
+    pub enabled_control_changes: u32,
+    pub keycode: xproto::Keycode,
+    pub event_type: u8,
+    pub request_major: u8,
+    pub request_minor: u8,
+}
+impl TryParse for ControlsNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (changed_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_controls, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (enabled_control_changes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (event_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_major, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (request_minor, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let result = ControlsNotifyEvent { response_type, xkb_type, sequence, time, device_id, num_groups, changed_controls, enabled_controls, enabled_control_changes, keycode, event_type, request_major, request_minor };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ControlsNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for ControlsNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("numGroups", |tracer| tracer.value(&self.num_groups))?;
+        tracer.field("changedControls", |tracer| tracer.bitmask(&self.changed_controls, Control::VARIANTS))?;
+        tracer.field("enabledControls", |tracer| tracer.bitmask(&self.enabled_controls, BoolCtrl::VARIANTS))?;
+        tracer.field("enabledControlChanges", |tracer| tracer.bitmask(&self.enabled_control_changes, BoolCtrl::VARIANTS))?;
+        tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+        tracer.field("eventType", |tracer| tracer.value(&self.event_type))?;
+        tracer.field("requestMajor", |tracer| tracer.value(&self.request_major))?;
+        tracer.field("requestMinor", |tracer| tracer.value(&self.request_minor))?;
+        Ok(())
+    }
+}
+impl ControlsNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let num_groups_bytes = self.num_groups.serialize_with_order(order);
+        let changed_controls_bytes = self.changed_controls.serialize_with_order(order);
+        let enabled_controls_bytes = self.enabled_controls.serialize_with_order(order);
+        let enabled_control_changes_bytes = self.enabled_control_changes.serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let event_type_bytes = self.event_type.serialize_with_order(order);
+        let request_major_bytes = self.request_major.serialize_with_order(order);
+        let request_minor_bytes = self.request_minor.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            num_groups_bytes[0],
+            0,
+            0,
+            changed_controls_bytes[0],
+            changed_controls_bytes[1],
+            changed_controls_bytes[2],
+            changed_controls_bytes[3],
+            enabled_controls_bytes[0],
+            enabled_controls_bytes[1],
+            enabled_controls_bytes[2],
+            enabled_controls_bytes[3],
+            enabled_control_changes_bytes[0],
+            enabled_control_changes_bytes[1],
+            enabled_control_changes_bytes[2],
+            enabled_control_changes_bytes[3],
+            keycode_bytes[0],
+            event_type_bytes[0],
+            request_major_bytes[0],
+            request_minor_bytes[0],
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&ControlsNotifyEvent> for [u8; 32] {
+    fn from(input: &ControlsNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<ControlsNotifyEvent> for [u8; 32] {
+    fn from(input: ControlsNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the IndicatorStateNotify event
+pub const INDICATOR_STATE_NOTIFY_EVENT: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndicatorStateNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub state: u32,
+    pub state_changed: u32,
+}
+impl TryParse for IndicatorStateNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (state_changed, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let result = IndicatorStateNotifyEvent { response_type, xkb_type, sequence, time, device_id, state, state_changed };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IndicatorStateNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IndicatorStateNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        tracer.field("stateChanged", |tracer| tracer.value(&self.state_changed))?;
+        Ok(())
+    }
+}
+impl IndicatorStateNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let state_bytes = self.state.serialize_with_order(order);
+        let state_changed_bytes = self.state_changed.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+            state_bytes[0],
+            state_bytes[1],
+            state_bytes[2],
+            state_bytes[3],
+            state_changed_bytes[0],
+            state_changed_bytes[1],
+            state_changed_bytes[2],
+            state_changed_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&IndicatorStateNotifyEvent> for [u8; 32] {
+    fn from(input: &IndicatorStateNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<IndicatorStateNotifyEvent> for [u8; 32] {
+    fn from(input: IndicatorStateNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the IndicatorMapNotify event
+pub const INDICATOR_MAP_NOTIFY_EVENT: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndicatorMapNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub state: u32,
+    pub map_changed: u32,
+}
+impl TryParse for IndicatorMapNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (map_changed, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let result = IndicatorMapNotifyEvent { response_type, xkb_type, sequence, time, device_id, state, map_changed };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for IndicatorMapNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for IndicatorMapNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("state", |tracer| tracer.value(&self.state))?;
+        tracer.field("mapChanged", |tracer| tracer.value(&self.map_changed))?;
+        Ok(())
+    }
+}
+impl IndicatorMapNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let state_bytes = self.state.serialize_with_order(order);
+        let map_changed_bytes = self.map_changed.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            0,
+            0,
+            0,
+            state_bytes[0],
+            state_bytes[1],
+            state_bytes[2],
+            state_bytes[3],
+            map_changed_bytes[0],
+            map_changed_bytes[1],
+            map_changed_bytes[2],
+            map_changed_bytes[3],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&IndicatorMapNotifyEvent> for [u8; 32] {
+    fn from(input: &IndicatorMapNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<IndicatorMapNotifyEvent> for [u8; 32] {
+    fn from(input: IndicatorMapNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the NamesNotify event
+pub const NAMES_NOTIFY_EVENT: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NamesNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub changed: u16,
+    pub first_type: u8,
+    pub n_types: u8,
+    pub first_level_name: u8,
+    pub n_level_names: u8,
+    pub n_radio_groups: u8,
+    pub n_key_aliases: u8,
+    pub changed_group_names: u8,
+    pub changed_virtual_mods: u16,
+    pub first_key: xproto::Keycode,
+    pub n_keys: u8,
+    pub changed_indicators: u32,
+}
+impl TryParse for NamesNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (changed, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_types, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_level_name, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_level_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (n_radio_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_key_aliases, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_group_names, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_virtual_mods, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (first_key, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (n_keys, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_indicators, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(4..).ok_or(ParseError::InsufficientData)?;
+        let result = NamesNotifyEvent { response_type, xkb_type, sequence, time, device_id, changed, first_type, n_types, first_level_name, n_level_names, n_radio_groups, n_key_aliases, changed_group_names, changed_virtual_mods, first_key, n_keys, changed_indicators };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for NamesNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for NamesNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("changed", |tracer| tracer.bitmask(&self.changed, NameDetail::VARIANTS))?;
+        tracer.field("firstType", |tracer| tracer.value(&self.first_type))?;
+        tracer.field("nTypes", |tracer| tracer.value(&self.n_types))?;
+        tracer.field("firstLevelName", |tracer| tracer.value(&self.first_level_name))?;
+        tracer.field("nLevelNames", |tracer| tracer.value(&self.n_level_names))?;
+        tracer.field("nRadioGroups", |tracer| tracer.value(&self.n_radio_groups))?;
+        tracer.field("nKeyAliases", |tracer| tracer.value(&self.n_key_aliases))?;
+        tracer.field("changedGroupNames", |tracer| tracer.bitmask(&self.changed_group_names, SetOfGroup::VARIANTS))?;
+        tracer.field("changedVirtualMods", |tracer| tracer.bitmask(&self.changed_virtual_mods, VMod::VARIANTS))?;
+        tracer.field("firstKey", |tracer| tracer.value(&self.first_key))?;
+        tracer.field("nKeys", |tracer| tracer.value(&self.n_keys))?;
+        tracer.field("changedIndicators", |tracer| tracer.value(&self.changed_indicators))?;
+        Ok(())
+    }
+}
+impl NamesNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let changed_bytes = self.changed.serialize_with_order(order);
+        let first_type_bytes = self.first_type.serialize_with_order(order);
+        let n_types_bytes = self.n_types.serialize_with_order(order);
+        let first_level_name_bytes = self.first_level_name.serialize_with_order(order);
+        let n_level_names_bytes = self.n_level_names.serialize_with_order(order);
+        let n_radio_groups_bytes = self.n_radio_groups.serialize_with_order(order);
+        let n_key_aliases_bytes = self.n_key_aliases.serialize_with_order(order);
+        let changed_group_names_bytes = self.changed_group_names.serialize_with_order(order);
+        let changed_virtual_mods_bytes = self.changed_virtual_mods.serialize_with_order(order);
+        let first_key_bytes = self.first_key.serialize_with_order(order);
+        let n_keys_bytes = self.n_keys.serialize_with_order(order);
+        let changed_indicators_bytes = self.changed_indicators.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            0,
+            changed_bytes[0],
+            changed_bytes[1],
+            first_type_bytes[0],
+            n_types_bytes[0],
+            first_level_name_bytes[0],
+            n_level_names_bytes[0],
+            0,
+            n_radio_groups_bytes[0],
+            n_key_aliases_bytes[0],
+            changed_group_names_bytes[0],
+            changed_virtual_mods_bytes[0],
+            changed_virtual_mods_bytes[1],
+            first_key_bytes[0],
+            n_keys_bytes[0],
+            changed_indicators_bytes[0],
+            changed_indicators_bytes[1],
+            changed_indicators_bytes[2],
+            changed_indicators_bytes[3],
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&NamesNotifyEvent> for [u8; 32] {
+    fn from(input: &NamesNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<NamesNotifyEvent> for [u8; 32] {
+    fn from(input: NamesNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the CompatMapNotify event
+pub const COMPAT_MAP_NOTIFY_EVENT: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompatMapNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub changed_groups: u8,
+    pub first_si: u16,
+    pub n_si: u16,
+    pub n_total_si: u16,
+}
+impl TryParse for CompatMapNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (changed_groups, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (first_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (n_total_si, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let result = CompatMapNotifyEvent { response_type, xkb_type, sequence, time, device_id, changed_groups, first_si, n_si, n_total_si };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for CompatMapNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for CompatMapNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("changedGroups", |tracer| tracer.bitmask(&self.changed_groups, SetOfGroup::VARIANTS))?;
+        tracer.field("firstSI", |tracer| tracer.value(&self.first_si))?;
+        tracer.field("nSI", |tracer| tracer.value(&self.n_si))?;
+        tracer.field("nTotalSI", |tracer| tracer.value(&self.n_total_si))?;
+        Ok(())
+    }
+}
+impl CompatMapNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let changed_groups_bytes = self.changed_groups.serialize_with_order(order);
+        let first_si_bytes = self.first_si.serialize_with_order(order);
+        let n_si_bytes = self.n_si.serialize_with_order(order);
+        let n_total_si_bytes = self.n_total_si.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            changed_groups_bytes[0],
+            first_si_bytes[0],
+            first_si_bytes[1],
+            n_si_bytes[0],
+            n_si_bytes[1],
+            n_total_si_bytes[0],
+            n_total_si_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&CompatMapNotifyEvent> for [u8; 32] {
+    fn from(input: &CompatMapNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<CompatMapNotifyEvent> for [u8; 32] {
+    fn from(input: CompatMapNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the BellNotify event
+pub const BELL_NOTIFY_EVENT: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BellNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub bell_class: BellClassResult,
+    pub bell_id: u8,
+    pub percent: u8,
+    pub pitch: u16,
+    pub duration: u16,
+    pub name: xproto::Atom,
+    pub window: xproto::Window,
+    pub event_only: bool,
+}
+impl TryParse for BellNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bell_class, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (bell_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (percent, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (pitch, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (duration, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (window, remaining) = xproto::Window::try_parse_with_order(remaining, order)?;
+        let (event_only, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(7..).ok_or(ParseError::InsufficientData)?;
+        let bell_class = bell_class.into();
+        let result = BellNotifyEvent { response_type, xkb_type, sequence, time, device_id, bell_class, bell_id, percent, pitch, duration, name, window, event_only };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for BellNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for BellNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("bellClass", |tracer| tracer.value(&self.bell_class))?;
+        tracer.field("bellID", |tracer| tracer.value(&self.bell_id))?;
+        tracer.field("percent", |tracer| tracer.value(&self.percent))?;
+        tracer.field("pitch", |tracer| tracer.value(&self.pitch))?;
+        tracer.field("duration", |tracer| tracer.value(&self.duration))?;
+        tracer.field("name", |tracer| tracer.atom(&self.name))?;
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        tracer.field("eventOnly", |tracer| tracer.value(&self.event_only))?;
+        Ok(())
+    }
+}
+impl BellNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let bell_class_bytes = u8::from(self.bell_class).serialize_with_order(order);
+        let bell_id_bytes = self.bell_id.serialize_with_order(order);
+        let percent_bytes = self.percent.serialize_with_order(order);
+        let pitch_bytes = self.pitch.serialize_with_order(order);
+        let duration_bytes = self.duration.serialize_with_order(order);
+        let name_bytes = self.name.serialize_with_order(order);
+        let window_bytes = self.window.serialize_with_order(order);
+        let event_only_bytes = self.event_only.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            bell_class_bytes[0],
+            bell_id_bytes[0],
+            percent_bytes[0],
+            pitch_bytes[0],
+            pitch_bytes[1],
+            duration_bytes[0],
+            duration_bytes[1],
+            name_bytes[0],
+            name_bytes[1],
+            name_bytes[2],
+            name_bytes[3],
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+            event_only_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&BellNotifyEvent> for [u8; 32] {
+    fn from(input: &BellNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<BellNotifyEvent> for [u8; 32] {
+    fn from(input: BellNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the ActionMessage event
+pub const ACTION_MESSAGE_EVENT: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionMessageEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub keycode: xproto::Keycode,
+    pub press: bool,
+    pub key_event_follows: bool,
+    pub mods: u8,
+    pub group: Group,
+    pub message: [String8; 8],
+}
+impl TryParse for ActionMessageEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (press, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (key_event_follows, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let (mods, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (group, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (message, remaining) = crate::x11_utils::parse_u8_list(remaining, 8)?;
+        let message = <[u8; 8]>::try_from(message).unwrap();
+        let remaining = remaining.get(10..).ok_or(ParseError::InsufficientData)?;
+        let group = group.into();
+        let result = ActionMessageEvent { response_type, xkb_type, sequence, time, device_id, keycode, press, key_event_follows, mods, group, message };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ActionMessageEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for ActionMessageEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+        tracer.field("press", |tracer| tracer.value(&self.press))?;
+        tracer.field("keyEventFollows", |tracer| tracer.value(&self.key_event_follows))?;
+        tracer.field("mods", |tracer| tracer.bitmask(&self.mods, xproto::ModMask::VARIANTS))?;
+        tracer.field("group", |tracer| tracer.value(&self.group))?;
+        tracer.field("message", |tracer| tracer.string(&self.message))?;
+        Ok(())
+    }
+}
+impl ActionMessageEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let press_bytes = self.press.serialize_with_order(order);
+        let key_event_follows_bytes = self.key_event_follows.serialize_with_order(order);
+        let mods_bytes = self.mods.serialize_with_order(order);
+        let group_bytes = u8::from(self.group).serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            keycode_bytes[0],
+            press_bytes[0],
+            key_event_follows_bytes[0],
+            mods_bytes[0],
+            group_bytes[0],
+            self.message[0],
+            self.message[1],
+            self.message[2],
+            self.message[3],
+            self.message[4],
+            self.message[5],
+            self.message[6],
+            self.message[7],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&ActionMessageEvent> for [u8; 32] {
+    fn from(input: &ActionMessageEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<ActionMessageEvent> for [u8; 32] {
+    fn from(input: ActionMessageEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the AccessXNotify event
+pub const ACCESS_X_NOTIFY_EVENT: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessXNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub keycode: xproto::Keycode,
+    pub detailt: u16,
+    pub slow_keys_delay: u16,
+    pub debounce_delay: u16,
+}
+impl TryParse for AccessXNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (keycode, remaining) = xproto::Keycode::try_parse_with_order(remaining, order)?;
+        let (detailt, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (slow_keys_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (debounce_delay, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let result = AccessXNotifyEvent { response_type, xkb_type, sequence, time, device_id, keycode, detailt, slow_keys_delay, debounce_delay };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AccessXNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for AccessXNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("keycode", |tracer| tracer.value(&self.keycode))?;
+        tracer.field("detailt", |tracer| tracer.bitmask(&self.detailt, AXNDetail::VARIANTS))?;
+        tracer.field("slowKeysDelay", |tracer| tracer.value(&self.slow_keys_delay))?;
+        tracer.field("debounceDelay", |tracer| tracer.value(&self.debounce_delay))?;
+        Ok(())
+    }
+}
+impl AccessXNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let keycode_bytes = self.keycode.serialize_with_order(order);
+        let detailt_bytes = self.detailt.serialize_with_order(order);
+        let slow_keys_delay_bytes = self.slow_keys_delay.serialize_with_order(order);
+        let debounce_delay_bytes = self.debounce_delay.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            keycode_bytes[0],
+            detailt_bytes[0],
+            detailt_bytes[1],
+            slow_keys_delay_bytes[0],
+            slow_keys_delay_bytes[1],
+            debounce_delay_bytes[0],
+            debounce_delay_bytes[1],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&AccessXNotifyEvent> for [u8; 32] {
+    fn from(input: &AccessXNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<AccessXNotifyEvent> for [u8; 32] {
+    fn from(input: AccessXNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the ExtensionDeviceNotify event
+pub const EXTENSION_DEVICE_NOTIFY_EVENT: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtensionDeviceNotifyEvent {
+    pub response_type: u8,
+    pub xkb_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub device_id: u8,
+    pub reason: u16,
+    pub led_class: LedClassResult,
+    pub led_id: u16,
+    pub leds_defined: u32,
+    pub led_state: u32,
+    pub first_button: u8,
+    pub n_buttons: u8,
+    pub supported: u16,
+    pub unsupported: u16,
+}
+impl TryParse for ExtensionDeviceNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (xkb_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (device_id, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (reason, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_class, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (led_id, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (leds_defined, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (led_state, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (first_button, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (n_buttons, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (supported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (unsupported, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let led_class = led_class.into();
+        let result = ExtensionDeviceNotifyEvent { response_type, xkb_type, sequence, time, device_id, reason, led_class, led_id, leds_defined, led_state, first_button, n_buttons, supported, unsupported };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ExtensionDeviceNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for ExtensionDeviceNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("xkbType", |tracer| tracer.value(&self.xkb_type))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("deviceID", |tracer| tracer.value(&self.device_id))?;
+        tracer.field("reason", |tracer| tracer.bitmask(&self.reason, XIFeature::VARIANTS))?;
+        tracer.field("ledClass", |tracer| tracer.value(&self.led_class))?;
+        tracer.field("ledID", |tracer| tracer.value(&self.led_id))?;
+        tracer.field("ledsDefined", |tracer| tracer.value(&self.leds_defined))?;
+        tracer.field("ledState", |tracer| tracer.value(&self.led_state))?;
+        tracer.field("firstButton", |tracer| tracer.value(&self.first_button))?;
+        tracer.field("nButtons", |tracer| tracer.value(&self.n_buttons))?;
+        tracer.field("supported", |tracer| tracer.bitmask(&self.supported, XIFeature::VARIANTS))?;
+        tracer.field("unsupported", |tracer| tracer.bitmask(&self.unsupported, XIFeature::VARIANTS))?;
+        Ok(())
+    }
+}
+impl ExtensionDeviceNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let xkb_type_bytes = self.xkb_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let device_id_bytes = self.device_id.serialize_with_order(order);
+        let reason_bytes = self.reason.serialize_with_order(order);
+        let led_class_bytes = u16::from(self.led_class).serialize_with_order(order);
+        let led_id_bytes = self.led_id.serialize_with_order(order);
+        let leds_defined_bytes = self.leds_defined.serialize_with_order(order);
+        let led_state_bytes = self.led_state.serialize_with_order(order);
+        let first_button_bytes = self.first_button.serialize_with_order(order);
+        let n_buttons_bytes = self.n_buttons.serialize_with_order(order);
+        let supported_bytes = self.supported.serialize_with_order(order);
+        let unsupported_bytes = self.unsupported.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            xkb_type_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            device_id_bytes[0],
+            0,
+            reason_bytes[0],
+            reason_bytes[1],
+            led_class_bytes[0],
+            led_class_bytes[1],
+            led_id_bytes[0],
+            led_id_bytes[1],
+            leds_defined_bytes[0],
+            leds_defined_bytes[1],
+            leds_defined_bytes[2],
+            leds_defined_bytes[3],
+            led_state_bytes[0],
+            led_state_bytes[1],
+            led_state_bytes[2],
+            led_state_bytes[3],
+            first_button_bytes[0],
+            n_buttons_bytes[0],
+            supported_bytes[0],
+            supported_bytes[1],
+            unsupported_bytes[0],
+            unsupported_bytes[1],
+            0,
+            0,
+        ]
+    }
+}
+impl From<&ExtensionDeviceNotifyEvent> for [u8; 32] {
+    fn from(input: &ExtensionDeviceNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<ExtensionDeviceNotifyEvent> for [u8; 32] {
+    fn from(input: ExtensionDeviceNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "UseExtension",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wantedMajor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wantedMinor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "supported",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serverMajor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "serverMinor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SelectEvents",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "affectWhich",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "EventType", variants: EventType::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "clear",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "EventType", variants: EventType::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "selectAll",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "EventType", variants: EventType::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "affectMap",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "details",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectNewKeyboard",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NKNDetail", variants: NKNDetail::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "newKeyboardDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NKNDetail", variants: NKNDetail::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectState",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "StatePart", variants: StatePart::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "stateDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "StatePart", variants: StatePart::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectCtrls",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "Control", variants: Control::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "ctrlDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "Control", variants: Control::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectIndicatorState",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "indicatorStateDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectIndicatorMap",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "indicatorMapDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectNames",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "namesDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectCompat",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "CMDetail", variants: CMDetail::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "compatDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "CMDetail", variants: CMDetail::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectBell",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "bellDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectMsgDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "msgDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectAccessX",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXNDetail", variants: AXNDetail::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "accessXDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXNDetail", variants: AXNDetail::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "affectExtDev",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "extdevDetails",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "Bell",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bellClass",
+                kind: FieldKind::Value(TypeInfo { name: "BellClassSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bellID",
+                kind: FieldKind::Value(TypeInfo { name: "IDSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "percent",
+                kind: FieldKind::Value(TypeInfo { name: "INT8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "forceSound",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "eventOnly",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pitch",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "duration",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetState",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "baseMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "latchedMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "lockedMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "group",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Group", variants: Group::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "lockedGroup",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Group", variants: Group::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "baseGroup",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "latchedGroup",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "compatState",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "grabMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "compatGrabMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "lookupMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "compatLookupMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ptrBtnState",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "KeyButMask", variants: xproto::KeyButMask::VARIANTS }) }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "LatchLockState",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "affectModLocks",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "modLocks",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "lockGroup",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupLock",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Group", variants: Group::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "affectModLatches",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "latchGroup",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupLatch",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetControls",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysDfltBtn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "numGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupsWrap",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "internalModsMask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ignoreLockModsMask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "internalModsRealMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ignoreLockModsRealMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "internalModsVmods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ignoreLockModsVmods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "repeatDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "repeatInterval",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "slowKeysDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "debounceDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysInterval",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysTimeToMax",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysMaxSpeed",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysCurve",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "accessXOption",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXOption", variants: AXOption::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutOptionsMask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXOption", variants: AXOption::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutOptionsValues",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXOption", variants: AXOption::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutMask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutValues",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "enabledControls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "perKeyRepeat",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetControls",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "affectInternalRealMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "internalRealMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "affectIgnoreLockRealMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ignoreLockRealMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "affectInternalVirtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "internalVirtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "affectIgnoreLockVirtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ignoreLockVirtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "mouseKeysDfltBtn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupsWrap",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "accessXOptions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXOption", variants: AXOption::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "affectEnabledControls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "enabledControls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "changeControls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "Control", variants: Control::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "repeatDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "repeatInterval",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "slowKeysDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "debounceDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysInterval",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysTimeToMax",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysMaxSpeed",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mouseKeysCurve",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "accessXTimeout",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutMask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutValues",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutOptionsMask",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXOption", variants: AXOption::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "accessXTimeoutOptionsValues",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXOption", variants: AXOption::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "perKeyRepeat",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetMap",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "full",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "partial",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeySym",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeySyms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyAction",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyActions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyBehavior",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyBehaviors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "virtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstVModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nVModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "present",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeySym",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalSyms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeySyms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyAction",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalActions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyActions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyBehavior",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyBehaviors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalKeyBehaviors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstVModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nVModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalVModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "virtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "types_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "KeyType", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "syms_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "KeySymMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "acts_rtrn_count",
+                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "acts_rtrn_acts",
+                                kind: FieldKind::List(TypeInfo { name: "Action", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "behaviors_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "SetBehavior", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "vmods_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "explicit_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "SetExplicit", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "modmap_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "KeyModMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "vmodmap_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "KeyVModMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetMap",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "present",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "SetMapFlags", variants: SetMapFlags::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "minKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeySym",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeySyms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalSyms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyAction",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyActions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalActions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyBehavior",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyBehaviors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalKeyBehaviors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstVModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nVModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalVModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "virtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "values",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "types",
+                                kind: FieldKind::List(TypeInfo { name: "SetKeyType", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "syms",
+                                kind: FieldKind::List(TypeInfo { name: "KeySymMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "actionsCount",
+                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "actions",
+                                kind: FieldKind::List(TypeInfo { name: "Action", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "behaviors",
+                                kind: FieldKind::List(TypeInfo { name: "SetBehavior", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "vmods",
+                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "explicit",
+                                kind: FieldKind::List(TypeInfo { name: "SetExplicit", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "modmap",
+                                kind: FieldKind::List(TypeInfo { name: "KeyModMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "vmodmap",
+                                kind: FieldKind::List(TypeInfo { name: "KeyVModMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetCompatMap",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "getAllSI",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupsRtrn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstSIRtrn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nSIRtrn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTotalSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "si_rtrn",
+                kind: FieldKind::List(TypeInfo { name: "SymInterpret", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "group_rtrn",
+                kind: FieldKind::List(TypeInfo { name: "ModDef", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetCompatMap",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "recomputeActions",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "truncateSI",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "si",
+                kind: FieldKind::List(TypeInfo { name: "SymInterpret", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupMaps",
+                kind: FieldKind::List(TypeInfo { name: "ModDef", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetIndicatorState",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetIndicatorMap",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "which",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "which",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "realIndicators",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nIndicators",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maps",
+                kind: FieldKind::List(TypeInfo { name: "IndicatorMap", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetIndicatorMap",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "which",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maps",
+                kind: FieldKind::List(TypeInfo { name: "IndicatorMap", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetNamedIndicator",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ledClass",
+                kind: FieldKind::Value(TypeInfo { name: "LedClassSpec", value_set: ValueSet::Enum(EnumInfo { name: "LedClass", variants: LedClass::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ledID",
+                kind: FieldKind::Value(TypeInfo { name: "IDSpec", value_set: ValueSet::AltEnum(EnumInfo { name: "ID", variants: ID::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "indicator",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "indicator",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "found",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "on",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "realIndicator",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ndx",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "map_flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "IMFlag", variants: IMFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_whichGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "IMGroupsWhich", variants: IMGroupsWhich::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_groups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroups", variants: SetOfGroups::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_whichMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "IMModsWhich", variants: IMModsWhich::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_mods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_realMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_vmod",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_ctrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "supported",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetNamedIndicator",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ledClass",
+                kind: FieldKind::Value(TypeInfo { name: "LedClassSpec", value_set: ValueSet::Enum(EnumInfo { name: "LedClass", variants: LedClass::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ledID",
+                kind: FieldKind::Value(TypeInfo { name: "IDSpec", value_set: ValueSet::AltEnum(EnumInfo { name: "ID", variants: ID::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "indicator",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "setState",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "on",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "setMap",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "createMap",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "map_flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "IMFlag", variants: IMFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_whichGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "IMGroupsWhich", variants: IMGroupsWhich::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_groups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroups", variants: SetOfGroups::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_whichMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "IMModsWhich", variants: IMModsWhich::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_realMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_vmods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "map_ctrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetNames",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "which",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "which",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "minKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupNames",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "virtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "indicators",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nRadioGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyAliases",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKTLevels",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "valueList",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "keycodesName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "geometryName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "symbolsName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "physSymbolsName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "typesName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "compatName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "typeNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "nLevelsPerType",
+                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "ktLevelNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "indicatorNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "virtualModNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "groups",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "keyNames",
+                                kind: FieldKind::List(TypeInfo { name: "KeyName", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "keyAliases",
+                                kind: FieldKind::List(TypeInfo { name: "KeyAlias", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "radioGroupNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetNames",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "virtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "which",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKTLevelt",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKTLevels",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "indicators",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "groupNames",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "nRadioGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyAliases",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalKTLevelNames",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "values",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "keycodesName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "geometryName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "symbolsName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "physSymbolsName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "typesName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "compatName",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "typeNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "nLevelsPerType",
+                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "ktLevelNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "indicatorNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "virtualModNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "groups",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "keyNames",
+                                kind: FieldKind::List(TypeInfo { name: "KeyName", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "keyAliases",
+                                kind: FieldKind::List(TypeInfo { name: "KeyAlias", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: None,
+                        fields: &[
+                            FieldInfo {
+                                name: "radioGroupNames",
+                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "PerClientFlags",
+        opcode: 21,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "change",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "PerClientFlag", variants: PerClientFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "value",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "PerClientFlag", variants: PerClientFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ctrlsToChange",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "autoCtrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "autoCtrlsValues",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "supported",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "PerClientFlag", variants: PerClientFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "value",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "PerClientFlag", variants: PerClientFlag::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "autoCtrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "autoCtrlsValues",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "ListComponents",
+        opcode: 22,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxNames",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeymaps",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeycodes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nCompatMaps",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nSymbols",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nGeometries",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "extra",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "keymaps",
+                kind: FieldKind::List(TypeInfo { name: "Listing", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "keycodes",
+                kind: FieldKind::List(TypeInfo { name: "Listing", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "types",
+                kind: FieldKind::List(TypeInfo { name: "Listing", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "compatMaps",
+                kind: FieldKind::List(TypeInfo { name: "Listing", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "symbols",
+                kind: FieldKind::List(TypeInfo { name: "Listing", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "geometries",
+                kind: FieldKind::List(TypeInfo { name: "Listing", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetKbdByName",
+        opcode: 23,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "need",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "GBNDetail", variants: GBNDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "want",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "GBNDetail", variants: GBNDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "load",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "loaded",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "newKeyboard",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "found",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "GBNDetail", variants: GBNDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "reported",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "GBNDetail", variants: GBNDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "replies",
+                kind: FieldKind::Switch(&[
+                    CaseInfo {
+                        name: Some("types"),
+                        fields: &[
+                            FieldInfo {
+                                name: "getmap_type",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "typeDeviceID",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "getmap_sequence",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "getmap_length",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "typeMinKeyCode",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "typeMaxKeyCode",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "present",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "firstType",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nTypes",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalTypes",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "firstKeySym",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalSyms",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeySyms",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "firstKeyAction",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalActions",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeyActions",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "firstKeyBehavior",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeyBehaviors",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalKeyBehaviors",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "firstKeyExplicit",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeyExplicit",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalKeyExplicit",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "firstModMapKey",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nModMapKeys",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalModMapKeys",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "firstVModMapKey",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nVModMapKeys",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "totalVModMapKeys",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "virtualMods",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "map",
+                                kind: FieldKind::Switch(&[
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "types_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "KeyType", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "syms_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "KeySymMap", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "acts_rtrn_count",
+                                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                                            },
+                                            FieldInfo {
+                                                name: "acts_rtrn_acts",
+                                                kind: FieldKind::List(TypeInfo { name: "Action", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "behaviors_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "SetBehavior", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "vmods_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "explicit_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "SetExplicit", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "modmap_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "KeyModMap", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "vmodmap_rtrn",
+                                                kind: FieldKind::List(TypeInfo { name: "KeyVModMap", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                ]),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: Some("compat_map"),
+                        fields: &[
+                            FieldInfo {
+                                name: "compatmap_type",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "compatDeviceID",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "compatmap_sequence",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "compatmap_length",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "groupsRtrn",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "firstSIRtrn",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nSIRtrn",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nTotalSI",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "si_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "SymInterpret", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "group_rtrn",
+                                kind: FieldKind::List(TypeInfo { name: "ModDef", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: Some("indicator_maps"),
+                        fields: &[
+                            FieldInfo {
+                                name: "indicatormap_type",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "indicatorDeviceID",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "indicatormap_sequence",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "indicatormap_length",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "which",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "realIndicators",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nIndicators",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "maps",
+                                kind: FieldKind::List(TypeInfo { name: "IndicatorMap", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: Some("key_names"),
+                        fields: &[
+                            FieldInfo {
+                                name: "keyname_type",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "keyDeviceID",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "keyname_sequence",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "keyname_length",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "which",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "keyMinKeyCode",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "keyMaxKeyCode",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nTypes",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "groupNames",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "virtualMods",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+                            },
+                            FieldInfo {
+                                name: "firstKey",
+                                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeys",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "indicators",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nRadioGroups",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeyAliases",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKTLevels",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "valueList",
+                                kind: FieldKind::Switch(&[
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "keycodesName",
+                                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "geometryName",
+                                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "symbolsName",
+                                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "physSymbolsName",
+                                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "typesName",
+                                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "compatName",
+                                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "typeNames",
+                                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "nLevelsPerType",
+                                                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                                            },
+                                            FieldInfo {
+                                                name: "ktLevelNames",
+                                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "indicatorNames",
+                                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "virtualModNames",
+                                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "groups",
+                                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "keyNames",
+                                                kind: FieldKind::List(TypeInfo { name: "KeyName", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "keyAliases",
+                                                kind: FieldKind::List(TypeInfo { name: "KeyAlias", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                    CaseInfo {
+                                        name: None,
+                                        fields: &[
+                                            FieldInfo {
+                                                name: "radioGroupNames",
+                                                kind: FieldKind::List(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                                            },
+                                        ],
+                                    },
+                                ]),
+                            },
+                        ],
+                    },
+                    CaseInfo {
+                        name: Some("geometry"),
+                        fields: &[
+                            FieldInfo {
+                                name: "geometry_type",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "geometryDeviceID",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "geometry_sequence",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "geometry_length",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "name",
+                                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "geometryFound",
+                                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "widthMM",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "heightMM",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nProperties",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nColors",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nShapes",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nSections",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nDoodads",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "nKeyAliases",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "baseColorNdx",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "labelColorNdx",
+                                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+                            },
+                            FieldInfo {
+                                name: "labelFont",
+                                kind: FieldKind::Value(TypeInfo { name: "CountedString16", value_set: ValueSet::None }),
+                            },
+                        ],
+                    },
+                ]),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "GetDeviceInfo",
+        opcode: 24,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "wanted",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "allButtons",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstButton",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nButtons",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ledClass",
+                kind: FieldKind::Value(TypeInfo { name: "LedClassSpec", value_set: ValueSet::Enum(EnumInfo { name: "LedClass", variants: LedClass::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ledID",
+                kind: FieldKind::Value(TypeInfo { name: "IDSpec", value_set: ValueSet::AltEnum(EnumInfo { name: "ID", variants: ID::VARIANTS }) }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "present",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "supported",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "unsupported",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "nDeviceLedFBs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstBtnWanted",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nBtnsWanted",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstBtnRtrn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nBtnsRtrn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "totalBtns",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "hasOwnState",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "dfltKbdFB",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::AltEnum(EnumInfo { name: "ID", variants: ID::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "dfltLedFB",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::AltEnum(EnumInfo { name: "ID", variants: ID::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "devType",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nameLen",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::List(TypeInfo { name: "STRING8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "btnActions",
+                kind: FieldKind::List(TypeInfo { name: "Action", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "leds",
+                kind: FieldKind::List(TypeInfo { name: "DeviceLedInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetDeviceInfo",
+        opcode: 25,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceSpec",
+                kind: FieldKind::Value(TypeInfo { name: "DeviceSpec", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstBtn",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nBtns",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "change",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "nDeviceLedFBs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "btnActions",
+                kind: FieldKind::List(TypeInfo { name: "Action", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "leds",
+                kind: FieldKind::List(TypeInfo { name: "DeviceLedInfo", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("xkb"),
+        name: "SetDebuggingFlags",
+        opcode: 101,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "msgLength",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "affectFlags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "flags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "affectCtrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ctrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "message",
+                kind: FieldKind::List(TypeInfo { name: "STRING8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "currentFlags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "currentCtrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "supportedFlags",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "supportedCtrls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("xkb"),
+        name: "AccessXNotify",
+        number: 10,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "keycode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "detailt",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "AXNDetail", variants: AXNDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "slowKeysDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "debounceDelay",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "ActionMessage",
+        number: 9,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "keycode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "press",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "keyEventFollows",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "group",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Group", variants: Group::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "message",
+                kind: FieldKind::List(TypeInfo { name: "STRING8", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "BellNotify",
+        number: 8,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "bellClass",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "BellClassResult", variants: BellClassResult::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "bellID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "percent",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pitch",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "duration",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "name",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "eventOnly",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "CompatMapNotify",
+        number: 7,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changedGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTotalSI",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "ControlsNotify",
+        number: 3,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "numGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changedControls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "Control", variants: Control::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "enabledControls",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "enabledControlChanges",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::Mask(EnumInfo { name: "BoolCtrl", variants: BoolCtrl::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "keycode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "eventType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "requestMajor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "requestMinor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "ExtensionDeviceNotify",
+        number: 11,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "reason",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ledClass",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Enum(EnumInfo { name: "LedClassResult", variants: LedClassResult::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ledID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ledsDefined",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ledState",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstButton",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nButtons",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "supported",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "unsupported",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "XIFeature", variants: XIFeature::VARIANTS }) }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "IndicatorMapNotify",
+        number: 5,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mapChanged",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "IndicatorStateNotify",
+        number: 4,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "state",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "stateChanged",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "MapNotify",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "ptrBtnActions",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changed",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "MapPart", variants: MapPart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "minKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeySym",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeySyms",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyAct",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyActs",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyBehavior",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyBehavior",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyExplicit",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstVModMapKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nVModMapKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "virtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "NamesNotify",
+        number: 6,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changed",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NameDetail", variants: NameDetail::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nTypes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "firstLevelName",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nLevelNames",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nRadioGroups",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeyAliases",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changedGroupNames",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "SetOfGroup", variants: SetOfGroup::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "changedVirtualMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "VMod", variants: VMod::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "firstKey",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "nKeys",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changedIndicators",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "NewKeyboardNotify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "oldDeviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "maxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "oldMinKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "oldMaxKeyCode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "requestMajor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "requestMinor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "changed",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "NKNDetail", variants: NKNDetail::VARIANTS }) }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("xkb"),
+        name: "StateNotify",
+        number: 2,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "xkbType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "deviceID",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "mods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "baseMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "latchedMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "lockedMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "group",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Group", variants: Group::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "baseGroup",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "latchedGroup",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "lockedGroup",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Enum(EnumInfo { name: "Group", variants: Group::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "compatState",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "grabMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "compatGrabMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "lookupMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "compatLoockupMods",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::Mask(EnumInfo { name: "ModMask", variants: xproto::ModMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "ptrBtnState",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "KeyButMask", variants: xproto::KeyButMask::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "changed",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::Mask(EnumInfo { name: "StatePart", variants: StatePart::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "keycode",
+                kind: FieldKind::Value(TypeInfo { name: "KEYCODE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "eventType",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "requestMajor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "requestMinor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,5250 @@
+// This file contains generated code. Do not edit directly.
+// To regenerate this, run 'make'.
+
+//! Bindings to the `Xv` X11 extension.
+
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::identity_op)]
+#![allow(clippy::trivially_copy_pass_by_ref)]
+#![allow(clippy::eq_op)]
+
+#[allow(unused_imports)]
+use alloc::borrow::Cow;
+#[allow(unused_imports)]
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[allow(unused_imports)]
+use core::convert::TryInto;
+use crate::errors::ParseError;
+#[allow(unused_imports)]
+use crate::introspect::{CaseInfo, EnumInfo, EventInfo, FieldInfo, FieldKind, RequestInfo, TypeInfo, ValueSet};
+use crate::trace::{Trace, Tracer};
+#[allow(unused_imports)]
+use crate::utils::{RawFdContainer, pretty_print_bitmask, pretty_print_enum};
+#[allow(unused_imports)]
+use crate::x11_utils::{ByteOrder, Request, RequestHeader, Serialize, TryParse, TryParseFd};
+use crate::{BufWithFds, PiecewiseBuf};
+use super::shm;
+use super::xproto;
+
+/// The X11 name of the extension for QueryExtension
+pub const X11_EXTENSION_NAME: &str = "XVideo";
+
+/// The version number of this extension that this client library supports.
+///
+/// This constant contains the version number of this extension that is supported
+/// by this build of x11rb. For most things, it does not make sense to use this
+/// information. If you need to send a `QueryVersion`, it is recommended to instead
+/// send the maximum version of the extension that you need.
+pub const X11_XML_VERSION: (u32, u32) = (2, 2);
+
+pub type Port = u32;
+
+pub type Encoding = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Type(u8);
+impl Type {
+    pub const INPUT_MASK: Self = Self(1 << 0);
+    pub const OUTPUT_MASK: Self = Self(1 << 1);
+    pub const VIDEO_MASK: Self = Self(1 << 2);
+    pub const STILL_MASK: Self = Self(1 << 3);
+    pub const IMAGE_MASK: Self = Self(1 << 4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "INPUT_MASK", "InputMask"),
+        (1 << 1, "OUTPUT_MASK", "OutputMask"),
+        (1 << 2, "VIDEO_MASK", "VideoMask"),
+        (1 << 3, "STILL_MASK", "StillMask"),
+        (1 << 4, "IMAGE_MASK", "ImageMask"),
+    ];
+}
+impl From<Type> for u8 {
+    #[inline]
+    fn from(input: Type) -> Self {
+        input.0
+    }
+}
+impl From<Type> for Option<u8> {
+    #[inline]
+    fn from(input: Type) -> Self {
+        Some(input.0)
+    }
+}
+impl From<Type> for u16 {
+    #[inline]
+    fn from(input: Type) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<Type> for Option<u16> {
+    #[inline]
+    fn from(input: Type) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<Type> for u32 {
+    #[inline]
+    fn from(input: Type) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<Type> for Option<u32> {
+    #[inline]
+    fn from(input: Type) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for Type {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for Type  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for Type {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for Type {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Type {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(Type, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ImageFormatInfoType(u8);
+impl ImageFormatInfoType {
+    pub const RGB: Self = Self(0);
+    pub const YUV: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "RGB", "RGB"),
+        (1, "YUV", "YUV"),
+    ];
+}
+impl From<ImageFormatInfoType> for u8 {
+    #[inline]
+    fn from(input: ImageFormatInfoType) -> Self {
+        input.0
+    }
+}
+impl From<ImageFormatInfoType> for Option<u8> {
+    #[inline]
+    fn from(input: ImageFormatInfoType) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ImageFormatInfoType> for u16 {
+    #[inline]
+    fn from(input: ImageFormatInfoType) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ImageFormatInfoType> for Option<u16> {
+    #[inline]
+    fn from(input: ImageFormatInfoType) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ImageFormatInfoType> for u32 {
+    #[inline]
+    fn from(input: ImageFormatInfoType) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ImageFormatInfoType> for Option<u32> {
+    #[inline]
+    fn from(input: ImageFormatInfoType) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ImageFormatInfoType {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ImageFormatInfoType  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ImageFormatInfoType {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImageFormatInfoType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ImageFormatInfoType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ImageFormatInfoFormat(u8);
+impl ImageFormatInfoFormat {
+    pub const PACKED: Self = Self(0);
+    pub const PLANAR: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "PACKED", "Packed"),
+        (1, "PLANAR", "Planar"),
+    ];
+}
+impl From<ImageFormatInfoFormat> for u8 {
+    #[inline]
+    fn from(input: ImageFormatInfoFormat) -> Self {
+        input.0
+    }
+}
+impl From<ImageFormatInfoFormat> for Option<u8> {
+    #[inline]
+    fn from(input: ImageFormatInfoFormat) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ImageFormatInfoFormat> for u16 {
+    #[inline]
+    fn from(input: ImageFormatInfoFormat) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ImageFormatInfoFormat> for Option<u16> {
+    #[inline]
+    fn from(input: ImageFormatInfoFormat) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ImageFormatInfoFormat> for u32 {
+    #[inline]
+    fn from(input: ImageFormatInfoFormat) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ImageFormatInfoFormat> for Option<u32> {
+    #[inline]
+    fn from(input: ImageFormatInfoFormat) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ImageFormatInfoFormat {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ImageFormatInfoFormat  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ImageFormatInfoFormat {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImageFormatInfoFormat {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ImageFormatInfoFormat {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AttributeFlag(u8);
+impl AttributeFlag {
+    pub const GETTABLE: Self = Self(1 << 0);
+    pub const SETTABLE: Self = Self(1 << 1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (1 << 0, "GETTABLE", "Gettable"),
+        (1 << 1, "SETTABLE", "Settable"),
+    ];
+}
+impl From<AttributeFlag> for u8 {
+    #[inline]
+    fn from(input: AttributeFlag) -> Self {
+        input.0
+    }
+}
+impl From<AttributeFlag> for Option<u8> {
+    #[inline]
+    fn from(input: AttributeFlag) -> Self {
+        Some(input.0)
+    }
+}
+impl From<AttributeFlag> for u16 {
+    #[inline]
+    fn from(input: AttributeFlag) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<AttributeFlag> for Option<u16> {
+    #[inline]
+    fn from(input: AttributeFlag) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<AttributeFlag> for u32 {
+    #[inline]
+    fn from(input: AttributeFlag) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<AttributeFlag> for Option<u32> {
+    #[inline]
+    fn from(input: AttributeFlag) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for AttributeFlag {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for AttributeFlag  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_bitmask(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for AttributeFlag {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.bitmask(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeFlag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_bitmask(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AttributeFlag {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_bitmask(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+bitmask_binop!(AttributeFlag, u8);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VideoNotifyReason(u8);
+impl VideoNotifyReason {
+    pub const STARTED: Self = Self(0);
+    pub const STOPPED: Self = Self(1);
+    pub const BUSY: Self = Self(2);
+    pub const PREEMPTED: Self = Self(3);
+    pub const HARD_ERROR: Self = Self(4);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "STARTED", "Started"),
+        (1, "STOPPED", "Stopped"),
+        (2, "BUSY", "Busy"),
+        (3, "PREEMPTED", "Preempted"),
+        (4, "HARD_ERROR", "HardError"),
+    ];
+}
+impl From<VideoNotifyReason> for u8 {
+    #[inline]
+    fn from(input: VideoNotifyReason) -> Self {
+        input.0
+    }
+}
+impl From<VideoNotifyReason> for Option<u8> {
+    #[inline]
+    fn from(input: VideoNotifyReason) -> Self {
+        Some(input.0)
+    }
+}
+impl From<VideoNotifyReason> for u16 {
+    #[inline]
+    fn from(input: VideoNotifyReason) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<VideoNotifyReason> for Option<u16> {
+    #[inline]
+    fn from(input: VideoNotifyReason) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<VideoNotifyReason> for u32 {
+    #[inline]
+    fn from(input: VideoNotifyReason) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<VideoNotifyReason> for Option<u32> {
+    #[inline]
+    fn from(input: VideoNotifyReason) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for VideoNotifyReason {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for VideoNotifyReason  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for VideoNotifyReason {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for VideoNotifyReason {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for VideoNotifyReason {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ScanlineOrder(u8);
+impl ScanlineOrder {
+    pub const TOP_TO_BOTTOM: Self = Self(0);
+    pub const BOTTOM_TO_TOP: Self = Self(1);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "TOP_TO_BOTTOM", "TopToBottom"),
+        (1, "BOTTOM_TO_TOP", "BottomToTop"),
+    ];
+}
+impl From<ScanlineOrder> for u8 {
+    #[inline]
+    fn from(input: ScanlineOrder) -> Self {
+        input.0
+    }
+}
+impl From<ScanlineOrder> for Option<u8> {
+    #[inline]
+    fn from(input: ScanlineOrder) -> Self {
+        Some(input.0)
+    }
+}
+impl From<ScanlineOrder> for u16 {
+    #[inline]
+    fn from(input: ScanlineOrder) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<ScanlineOrder> for Option<u16> {
+    #[inline]
+    fn from(input: ScanlineOrder) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<ScanlineOrder> for u32 {
+    #[inline]
+    fn from(input: ScanlineOrder) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<ScanlineOrder> for Option<u32> {
+    #[inline]
+    fn from(input: ScanlineOrder) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for ScanlineOrder {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for ScanlineOrder  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for ScanlineOrder {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for ScanlineOrder {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ScanlineOrder {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct GrabPortStatus(u8);
+impl GrabPortStatus {
+    pub const SUCCESS: Self = Self(0);
+    pub const BAD_EXTENSION: Self = Self(1);
+    pub const ALREADY_GRABBED: Self = Self(2);
+    pub const INVALID_TIME: Self = Self(3);
+    pub const BAD_REPLY: Self = Self(4);
+    pub const BAD_ALLOC: Self = Self(5);
+    /// The values of this enumeration with their names in upper snake case and in camel case.
+    pub(crate) const VARIANTS: &[(u32, &str, &str)] = &[
+        (0, "SUCCESS", "Success"),
+        (1, "BAD_EXTENSION", "BadExtension"),
+        (2, "ALREADY_GRABBED", "AlreadyGrabbed"),
+        (3, "INVALID_TIME", "InvalidTime"),
+        (4, "BAD_REPLY", "BadReply"),
+        (5, "BAD_ALLOC", "BadAlloc"),
+    ];
+}
+impl From<GrabPortStatus> for u8 {
+    #[inline]
+    fn from(input: GrabPortStatus) -> Self {
+        input.0
+    }
+}
+impl From<GrabPortStatus> for Option<u8> {
+    #[inline]
+    fn from(input: GrabPortStatus) -> Self {
+        Some(input.0)
+    }
+}
+impl From<GrabPortStatus> for u16 {
+    #[inline]
+    fn from(input: GrabPortStatus) -> Self {
+        u16::from(input.0)
+    }
+}
+impl From<GrabPortStatus> for Option<u16> {
+    #[inline]
+    fn from(input: GrabPortStatus) -> Self {
+        Some(u16::from(input.0))
+    }
+}
+impl From<GrabPortStatus> for u32 {
+    #[inline]
+    fn from(input: GrabPortStatus) -> Self {
+        u32::from(input.0)
+    }
+}
+impl From<GrabPortStatus> for Option<u32> {
+    #[inline]
+    fn from(input: GrabPortStatus) -> Self {
+        Some(u32::from(input.0))
+    }
+}
+impl From<u8> for GrabPortStatus {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+impl core::fmt::Debug for GrabPortStatus  {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        pretty_print_enum(fmt, self.0.into(), Self::VARIANTS)
+    }
+}
+impl Trace for GrabPortStatus {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.enum_value(&self.0, Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl serde::Serialize for GrabPortStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::utils::serialize_enum(serializer, self.0.into(), Self::VARIANTS)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GrabPortStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::deserialize_enum(deserializer, Self::VARIANTS).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rational {
+    pub numerator: i32,
+    pub denominator: i32,
+}
+impl TryParse for Rational {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (numerator, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (denominator, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let result = Rational { numerator, denominator };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Rational {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Rational {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let numerator_bytes = self.numerator.serialize_with_order(order);
+        let denominator_bytes = self.denominator.serialize_with_order(order);
+        [
+            numerator_bytes[0],
+            numerator_bytes[1],
+            numerator_bytes[2],
+            numerator_bytes[3],
+            denominator_bytes[0],
+            denominator_bytes[1],
+            denominator_bytes[2],
+            denominator_bytes[3],
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.numerator.serialize_into_with_order(bytes, order);
+        self.denominator.serialize_into_with_order(bytes, order);
+    }
+}
+impl Trace for Rational {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("numerator", |tracer| tracer.value(&self.numerator))?;
+            tracer.field("denominator", |tracer| tracer.value(&self.denominator))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Format {
+    pub visual: xproto::Visualid,
+    pub depth: u8,
+}
+impl TryParse for Format {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (visual, remaining) = xproto::Visualid::try_parse_with_order(remaining, order)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let result = Format { visual, depth };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Format {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Format {
+    type Bytes = [u8; 8];
+    fn serialize(&self) -> [u8; 8] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 8] {
+        let visual_bytes = self.visual.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        [
+            visual_bytes[0],
+            visual_bytes[1],
+            visual_bytes[2],
+            visual_bytes[3],
+            depth_bytes[0],
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(8);
+        self.visual.serialize_into_with_order(bytes, order);
+        self.depth.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+    }
+}
+impl Trace for Format {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("visual", |tracer| tracer.value(&self.visual))?;
+            tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdaptorInfo {
+    pub base_id: Port,
+    pub num_ports: u16,
+    pub type_: u8,
+    pub name: Vec<u8>,
+    pub formats: Vec<Format>,
+}
+impl TryParse for AdaptorInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (base_id, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (name_size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_ports, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (num_formats, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_size.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let (formats, remaining) = crate::x11_utils::parse_list_with_order::<Format>(remaining, num_formats.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let result = AdaptorInfo { base_id, num_ports, type_, name, formats };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AdaptorInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for AdaptorInfo {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(12);
+        self.base_id.serialize_into_with_order(bytes, order);
+        let name_size = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_size.serialize_into_with_order(bytes, order);
+        self.num_ports.serialize_into_with_order(bytes, order);
+        let num_formats = u16::try_from(self.formats.len()).expect("`formats` has too many elements");
+        num_formats.serialize_into_with_order(bytes, order);
+        self.type_.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 1]);
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+        self.formats.serialize_into_with_order(bytes, order);
+    }
+}
+impl AdaptorInfo {
+    /// Get the value of the `name_size` field.
+    ///
+    /// The `name_size` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn name_size(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_formats` field.
+    ///
+    /// The `num_formats` field is used as the length field of the `formats` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_formats(&self) -> u16 {
+        self.formats.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for AdaptorInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("base-id", |tracer| tracer.xid(&self.base_id))?;
+            tracer.field("num-ports", |tracer| tracer.value(&self.num_ports))?;
+            tracer.field("type", |tracer| tracer.bitmask(&self.type_, Type::VARIANTS))?;
+            tracer.field("name", |tracer| tracer.string(&self.name))?;
+            tracer.field("formats", |tracer| tracer.list(&self.formats, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncodingInfo {
+    pub encoding: Encoding,
+    pub width: u16,
+    pub height: u16,
+    pub rate: Rational,
+    pub name: Vec<u8>,
+}
+impl TryParse for EncodingInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (encoding, remaining) = Encoding::try_parse_with_order(remaining, order)?;
+        let (name_size, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (rate, remaining) = Rational::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, name_size.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let result = EncodingInfo { encoding, width, height, rate, name };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for EncodingInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for EncodingInfo {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(20);
+        self.encoding.serialize_into_with_order(bytes, order);
+        let name_size = u16::try_from(self.name.len()).expect("`name` has too many elements");
+        name_size.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.rate.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+    }
+}
+impl EncodingInfo {
+    /// Get the value of the `name_size` field.
+    ///
+    /// The `name_size` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn name_size(&self) -> u16 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for EncodingInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("encoding", |tracer| tracer.xid(&self.encoding))?;
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("height", |tracer| tracer.value(&self.height))?;
+            tracer.field("rate", |tracer| tracer.value(&self.rate))?;
+            tracer.field("name", |tracer| tracer.string(&self.name))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Image {
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+    pub pitches: Vec<u32>,
+    pub offsets: Vec<u32>,
+    pub data: Vec<u8>,
+}
+impl TryParse for Image {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_planes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (pitches, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (offsets, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (data, remaining) = crate::x11_utils::parse_u8_list(remaining, data_size.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let data = data.to_vec();
+        let result = Image { id, width, height, pitches, offsets, data };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for Image {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for Image {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(16);
+        self.id.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        let data_size = u32::try_from(self.data.len()).expect("`data` has too many elements");
+        data_size.serialize_into_with_order(bytes, order);
+        let num_planes = u32::try_from(self.pitches.len()).expect("`pitches` has too many elements");
+        num_planes.serialize_into_with_order(bytes, order);
+        self.pitches.serialize_into_with_order(bytes, order);
+        assert_eq!(self.offsets.len(), usize::try_from(num_planes).unwrap(), "`offsets` has an incorrect length");
+        self.offsets.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.data);
+    }
+}
+impl Image {
+    /// Get the value of the `data_size` field.
+    ///
+    /// The `data_size` field is used as the length field of the `data` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn data_size(&self) -> u32 {
+        self.data.len()
+            .try_into().unwrap()
+    }
+    /// Get the value of the `num_planes` field.
+    ///
+    /// The `num_planes` field is used as the length field of the `pitches` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_planes(&self) -> u32 {
+        self.pitches.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for Image {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("id", |tracer| tracer.value(&self.id))?;
+            tracer.field("width", |tracer| tracer.value(&self.width))?;
+            tracer.field("height", |tracer| tracer.value(&self.height))?;
+            tracer.field("pitches", |tracer| tracer.list(&self.pitches, |tracer, value| tracer.value(value)))?;
+            tracer.field("offsets", |tracer| tracer.list(&self.offsets, |tracer, value| tracer.value(value)))?;
+            tracer.field("data", |tracer| tracer.list(&self.data, |tracer, value| tracer.value(value)))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributeInfo {
+    pub flags: u32,
+    pub min: i32,
+    pub max: i32,
+    pub name: Vec<u8>,
+}
+impl TryParse for AttributeInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let value = remaining;
+        let (flags, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (min, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (max, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let (size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (name, remaining) = crate::x11_utils::parse_u8_list(remaining, size.try_into().or(Err(ParseError::ConversionFailed))?)?;
+        let name = name.to_vec();
+        // Align offset to multiple of 4
+        let offset = remaining.as_ptr() as usize - value.as_ptr() as usize;
+        let misalignment = (4 - (offset % 4)) % 4;
+        let remaining = remaining.get(misalignment..).ok_or(ParseError::InsufficientData)?;
+        let result = AttributeInfo { flags, min, max, name };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for AttributeInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for AttributeInfo {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(16);
+        self.flags.serialize_into_with_order(bytes, order);
+        self.min.serialize_into_with_order(bytes, order);
+        self.max.serialize_into_with_order(bytes, order);
+        let size = u32::try_from(self.name.len()).expect("`name` has too many elements");
+        size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.name);
+        bytes.extend_from_slice(&[0; 3][..(4 - (bytes.len() % 4)) % 4]);
+    }
+}
+impl AttributeInfo {
+    /// Get the value of the `size` field.
+    ///
+    /// The `size` field is used as the length field of the `name` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn size(&self) -> u32 {
+        self.name.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for AttributeInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("flags", |tracer| tracer.bitmask(&self.flags, AttributeFlag::VARIANTS))?;
+            tracer.field("min", |tracer| tracer.value(&self.min))?;
+            tracer.field("max", |tracer| tracer.value(&self.max))?;
+            tracer.field("name", |tracer| tracer.string(&self.name))?;
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageFormatInfo {
+    pub id: u32,
+    pub type_: ImageFormatInfoType,
+    pub byte_order: xproto::ImageOrder,
+    pub guid: [u8; 16],
+    pub bpp: u8,
+    pub num_planes: u8,
+    pub depth: u8,
+    pub red_mask: u32,
+    pub green_mask: u32,
+    pub blue_mask: u32,
+    pub format: ImageFormatInfoFormat,
+    pub y_sample_bits: u32,
+    pub u_sample_bits: u32,
+    pub v_sample_bits: u32,
+    pub vhorz_y_period: u32,
+    pub vhorz_u_period: u32,
+    pub vhorz_v_period: u32,
+    pub vvert_y_period: u32,
+    pub vvert_u_period: u32,
+    pub vvert_v_period: u32,
+    pub vcomp_order: [u8; 32],
+    pub vscanline_order: ScanlineOrder,
+}
+impl TryParse for ImageFormatInfo {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(remaining: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (type_, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (byte_order, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (guid, remaining) = crate::x11_utils::parse_u8_list(remaining, 16)?;
+        let guid = <[u8; 16]>::try_from(guid).unwrap();
+        let (bpp, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (num_planes, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(2..).ok_or(ParseError::InsufficientData)?;
+        let (depth, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (red_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (green_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (blue_mask, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (format, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let (y_sample_bits, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (u_sample_bits, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (v_sample_bits, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vhorz_y_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vhorz_u_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vhorz_v_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vvert_y_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vvert_u_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vvert_v_period, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (vcomp_order, remaining) = crate::x11_utils::parse_u8_list(remaining, 32)?;
+        let vcomp_order = <[u8; 32]>::try_from(vcomp_order).unwrap();
+        let (vscanline_order, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(11..).ok_or(ParseError::InsufficientData)?;
+        let type_ = type_.into();
+        let byte_order = byte_order.into();
+        let format = format.into();
+        let vscanline_order = vscanline_order.into();
+        let result = ImageFormatInfo { id, type_, byte_order, guid, bpp, num_planes, depth, red_mask, green_mask, blue_mask, format, y_sample_bits, u_sample_bits, v_sample_bits, vhorz_y_period, vhorz_u_period, vhorz_v_period, vvert_y_period, vvert_u_period, vvert_v_period, vcomp_order, vscanline_order };
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ImageFormatInfo {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Serialize for ImageFormatInfo {
+    type Bytes = [u8; 128];
+    fn serialize(&self) -> [u8; 128] {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> [u8; 128] {
+        let id_bytes = self.id.serialize_with_order(order);
+        let type_bytes = u8::from(self.type_).serialize_with_order(order);
+        let byte_order_bytes = u8::from(self.byte_order).serialize_with_order(order);
+        let bpp_bytes = self.bpp.serialize_with_order(order);
+        let num_planes_bytes = self.num_planes.serialize_with_order(order);
+        let depth_bytes = self.depth.serialize_with_order(order);
+        let red_mask_bytes = self.red_mask.serialize_with_order(order);
+        let green_mask_bytes = self.green_mask.serialize_with_order(order);
+        let blue_mask_bytes = self.blue_mask.serialize_with_order(order);
+        let format_bytes = u8::from(self.format).serialize_with_order(order);
+        let y_sample_bits_bytes = self.y_sample_bits.serialize_with_order(order);
+        let u_sample_bits_bytes = self.u_sample_bits.serialize_with_order(order);
+        let v_sample_bits_bytes = self.v_sample_bits.serialize_with_order(order);
+        let vhorz_y_period_bytes = self.vhorz_y_period.serialize_with_order(order);
+        let vhorz_u_period_bytes = self.vhorz_u_period.serialize_with_order(order);
+        let vhorz_v_period_bytes = self.vhorz_v_period.serialize_with_order(order);
+        let vvert_y_period_bytes = self.vvert_y_period.serialize_with_order(order);
+        let vvert_u_period_bytes = self.vvert_u_period.serialize_with_order(order);
+        let vvert_v_period_bytes = self.vvert_v_period.serialize_with_order(order);
+        let vscanline_order_bytes = u8::from(self.vscanline_order).serialize_with_order(order);
+        [
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            type_bytes[0],
+            byte_order_bytes[0],
+            0,
+            0,
+            self.guid[0],
+            self.guid[1],
+            self.guid[2],
+            self.guid[3],
+            self.guid[4],
+            self.guid[5],
+            self.guid[6],
+            self.guid[7],
+            self.guid[8],
+            self.guid[9],
+            self.guid[10],
+            self.guid[11],
+            self.guid[12],
+            self.guid[13],
+            self.guid[14],
+            self.guid[15],
+            bpp_bytes[0],
+            num_planes_bytes[0],
+            0,
+            0,
+            depth_bytes[0],
+            0,
+            0,
+            0,
+            red_mask_bytes[0],
+            red_mask_bytes[1],
+            red_mask_bytes[2],
+            red_mask_bytes[3],
+            green_mask_bytes[0],
+            green_mask_bytes[1],
+            green_mask_bytes[2],
+            green_mask_bytes[3],
+            blue_mask_bytes[0],
+            blue_mask_bytes[1],
+            blue_mask_bytes[2],
+            blue_mask_bytes[3],
+            format_bytes[0],
+            0,
+            0,
+            0,
+            y_sample_bits_bytes[0],
+            y_sample_bits_bytes[1],
+            y_sample_bits_bytes[2],
+            y_sample_bits_bytes[3],
+            u_sample_bits_bytes[0],
+            u_sample_bits_bytes[1],
+            u_sample_bits_bytes[2],
+            u_sample_bits_bytes[3],
+            v_sample_bits_bytes[0],
+            v_sample_bits_bytes[1],
+            v_sample_bits_bytes[2],
+            v_sample_bits_bytes[3],
+            vhorz_y_period_bytes[0],
+            vhorz_y_period_bytes[1],
+            vhorz_y_period_bytes[2],
+            vhorz_y_period_bytes[3],
+            vhorz_u_period_bytes[0],
+            vhorz_u_period_bytes[1],
+            vhorz_u_period_bytes[2],
+            vhorz_u_period_bytes[3],
+            vhorz_v_period_bytes[0],
+            vhorz_v_period_bytes[1],
+            vhorz_v_period_bytes[2],
+            vhorz_v_period_bytes[3],
+            vvert_y_period_bytes[0],
+            vvert_y_period_bytes[1],
+            vvert_y_period_bytes[2],
+            vvert_y_period_bytes[3],
+            vvert_u_period_bytes[0],
+            vvert_u_period_bytes[1],
+            vvert_u_period_bytes[2],
+            vvert_u_period_bytes[3],
+            vvert_v_period_bytes[0],
+            vvert_v_period_bytes[1],
+            vvert_v_period_bytes[2],
+            vvert_v_period_bytes[3],
+            self.vcomp_order[0],
+            self.vcomp_order[1],
+            self.vcomp_order[2],
+            self.vcomp_order[3],
+            self.vcomp_order[4],
+            self.vcomp_order[5],
+            self.vcomp_order[6],
+            self.vcomp_order[7],
+            self.vcomp_order[8],
+            self.vcomp_order[9],
+            self.vcomp_order[10],
+            self.vcomp_order[11],
+            self.vcomp_order[12],
+            self.vcomp_order[13],
+            self.vcomp_order[14],
+            self.vcomp_order[15],
+            self.vcomp_order[16],
+            self.vcomp_order[17],
+            self.vcomp_order[18],
+            self.vcomp_order[19],
+            self.vcomp_order[20],
+            self.vcomp_order[21],
+            self.vcomp_order[22],
+            self.vcomp_order[23],
+            self.vcomp_order[24],
+            self.vcomp_order[25],
+            self.vcomp_order[26],
+            self.vcomp_order[27],
+            self.vcomp_order[28],
+            self.vcomp_order[29],
+            self.vcomp_order[30],
+            self.vcomp_order[31],
+            vscanline_order_bytes[0],
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        bytes.reserve(128);
+        self.id.serialize_into_with_order(bytes, order);
+        u8::from(self.type_).serialize_into_with_order(bytes, order);
+        u8::from(self.byte_order).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        bytes.extend_from_slice(&self.guid);
+        self.bpp.serialize_into_with_order(bytes, order);
+        self.num_planes.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 2]);
+        self.depth.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        self.red_mask.serialize_into_with_order(bytes, order);
+        self.green_mask.serialize_into_with_order(bytes, order);
+        self.blue_mask.serialize_into_with_order(bytes, order);
+        u8::from(self.format).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 3]);
+        self.y_sample_bits.serialize_into_with_order(bytes, order);
+        self.u_sample_bits.serialize_into_with_order(bytes, order);
+        self.v_sample_bits.serialize_into_with_order(bytes, order);
+        self.vhorz_y_period.serialize_into_with_order(bytes, order);
+        self.vhorz_u_period.serialize_into_with_order(bytes, order);
+        self.vhorz_v_period.serialize_into_with_order(bytes, order);
+        self.vvert_y_period.serialize_into_with_order(bytes, order);
+        self.vvert_u_period.serialize_into_with_order(bytes, order);
+        self.vvert_v_period.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&self.vcomp_order);
+        u8::from(self.vscanline_order).serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 11]);
+    }
+}
+impl Trace for ImageFormatInfo {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.structure(|tracer| {
+            tracer.field("id", |tracer| tracer.value(&self.id))?;
+            tracer.field("type", |tracer| tracer.value(&self.type_))?;
+            tracer.field("byte-order", |tracer| tracer.value(&self.byte_order))?;
+            tracer.field("guid", |tracer| tracer.list(&self.guid, |tracer, value| tracer.value(value)))?;
+            tracer.field("bpp", |tracer| tracer.value(&self.bpp))?;
+            tracer.field("num-planes", |tracer| tracer.value(&self.num_planes))?;
+            tracer.field("depth", |tracer| tracer.value(&self.depth))?;
+            tracer.field("red-mask", |tracer| tracer.value(&self.red_mask))?;
+            tracer.field("green-mask", |tracer| tracer.value(&self.green_mask))?;
+            tracer.field("blue-mask", |tracer| tracer.value(&self.blue_mask))?;
+            tracer.field("format", |tracer| tracer.value(&self.format))?;
+            tracer.field("y-sample-bits", |tracer| tracer.value(&self.y_sample_bits))?;
+            tracer.field("u-sample-bits", |tracer| tracer.value(&self.u_sample_bits))?;
+            tracer.field("v-sample-bits", |tracer| tracer.value(&self.v_sample_bits))?;
+            tracer.field("vhorz-y-period", |tracer| tracer.value(&self.vhorz_y_period))?;
+            tracer.field("vhorz-u-period", |tracer| tracer.value(&self.vhorz_u_period))?;
+            tracer.field("vhorz-v-period", |tracer| tracer.value(&self.vhorz_v_period))?;
+            tracer.field("vvert-y-period", |tracer| tracer.value(&self.vvert_y_period))?;
+            tracer.field("vvert-u-period", |tracer| tracer.value(&self.vvert_u_period))?;
+            tracer.field("vvert-v-period", |tracer| tracer.value(&self.vvert_v_period))?;
+            tracer.field("vcomp-order", |tracer| tracer.list(&self.vcomp_order, |tracer, value| tracer.value(value)))?;
+            tracer.field("vscanline-order", |tracer| tracer.value(&self.vscanline_order))?;
+            Ok(())
+        })
+    }
+}
+
+/// Opcode for the BadPort error
+pub const BAD_PORT_ERROR: u8 = 0;
+
+/// Opcode for the BadEncoding error
+pub const BAD_ENCODING_ERROR: u8 = 1;
+
+/// Opcode for the BadControl error
+pub const BAD_CONTROL_ERROR: u8 = 2;
+
+/// Opcode for the VideoNotify event
+pub const VIDEO_NOTIFY_EVENT: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VideoNotifyEvent {
+    pub response_type: u8,
+    pub reason: VideoNotifyReason,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub drawable: xproto::Drawable,
+    pub port: Port,
+}
+impl TryParse for VideoNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (reason, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let reason = reason.into();
+        let result = VideoNotifyEvent { response_type, reason, sequence, time, drawable, port };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for VideoNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for VideoNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("reason", |tracer| tracer.value(&self.reason))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        Ok(())
+    }
+}
+impl VideoNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let reason_bytes = u8::from(self.reason).serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let port_bytes = self.port.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            reason_bytes[0],
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            // trailing padding
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&VideoNotifyEvent> for [u8; 32] {
+    fn from(input: &VideoNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<VideoNotifyEvent> for [u8; 32] {
+    fn from(input: VideoNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the PortNotify event
+pub const PORT_NOTIFY_EVENT: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortNotifyEvent {
+    pub response_type: u8,
+    pub sequence: u16,
+    pub time: xproto::Timestamp,
+    pub port: Port,
+    pub attribute: xproto::Atom,
+    pub value: i32,
+}
+impl TryParse for PortNotifyEvent {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let (port, remaining) = Port::try_parse_with_order(remaining, order)?;
+        let (attribute, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let result = PortNotifyEvent { response_type, sequence, time, port, attribute, value };
+        let _ = remaining;
+        let remaining = initial_value.get(32..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for PortNotifyEvent {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for PortNotifyEvent {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("time", |tracer| tracer.value(&self.time))?;
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("attribute", |tracer| tracer.atom(&self.attribute))?;
+        tracer.field("value", |tracer| tracer.value(&self.value))?;
+        Ok(())
+    }
+}
+impl PortNotifyEvent {
+    /// Serialize this event into bytes in the given byte order
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let response_type_bytes = self.response_type.serialize_with_order(order);
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let port_bytes = self.port.serialize_with_order(order);
+        let attribute_bytes = self.attribute.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        [
+            response_type_bytes[0],
+            0,
+            sequence_bytes[0],
+            sequence_bytes[1],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            attribute_bytes[0],
+            attribute_bytes[1],
+            attribute_bytes[2],
+            attribute_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+            // trailing padding
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+impl From<&PortNotifyEvent> for [u8; 32] {
+    fn from(input: &PortNotifyEvent) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<PortNotifyEvent> for [u8; 32] {
+    fn from(input: PortNotifyEvent) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Opcode for the QueryExtension request
+pub const QUERY_EXTENSION_REQUEST: u8 = 0;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryExtensionRequest;
+impl QueryExtensionRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_EXTENSION_REQUEST,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_EXTENSION_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let _ = order;
+        let _ = value;
+        Ok(QueryExtensionRequest
+        )
+    }
+}
+impl Request for QueryExtensionRequest {
+    type Reply = QueryExtensionReply;
+}
+impl Trace for QueryExtensionRequest {
+    fn trace(&self, _tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryExtensionReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub major: u16,
+    pub minor: u16,
+}
+impl TryParse for QueryExtensionReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (major, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (minor, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryExtensionReply { sequence, length, major, minor };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryExtensionReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryExtensionReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("major", |tracer| tracer.value(&self.major))?;
+        tracer.field("minor", |tracer| tracer.value(&self.minor))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryExtensionReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.major.serialize_into_with_order(bytes, order);
+        self.minor.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryAdaptors request
+pub const QUERY_ADAPTORS_REQUEST: u8 = 1;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryAdaptorsRequest {
+    pub window: xproto::Window,
+}
+impl QueryAdaptorsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let window_bytes = self.window.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_ADAPTORS_REQUEST,
+            0,
+            0,
+            window_bytes[0],
+            window_bytes[1],
+            window_bytes[2],
+            window_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_ADAPTORS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (window, remaining) = xproto::Window::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryAdaptorsRequest {
+            window,
+        })
+    }
+}
+impl Request for QueryAdaptorsRequest {
+    type Reply = QueryAdaptorsReply;
+}
+impl Trace for QueryAdaptorsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("window", |tracer| tracer.xid(&self.window))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryAdaptorsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub info: Vec<AdaptorInfo>,
+}
+impl TryParse for QueryAdaptorsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_adaptors, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (info, remaining) = crate::x11_utils::parse_list_with_order::<AdaptorInfo>(remaining, num_adaptors.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryAdaptorsReply { sequence, length, info };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryAdaptorsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryAdaptorsReply {
+    /// Get the value of the `num_adaptors` field.
+    ///
+    /// The `num_adaptors` field is used as the length field of the `info` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_adaptors(&self) -> u16 {
+        self.info.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryAdaptorsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("info", |tracer| tracer.list(&self.info, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryAdaptorsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_adaptors = u16::try_from(self.info.len()).expect("`info` has too many elements");
+        num_adaptors.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.info.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryEncodings request
+pub const QUERY_ENCODINGS_REQUEST: u8 = 2;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryEncodingsRequest {
+    pub port: Port,
+}
+impl QueryEncodingsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_ENCODINGS_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_ENCODINGS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryEncodingsRequest {
+            port,
+        })
+    }
+}
+impl Request for QueryEncodingsRequest {
+    type Reply = QueryEncodingsReply;
+}
+impl Trace for QueryEncodingsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryEncodingsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub info: Vec<EncodingInfo>,
+}
+impl TryParse for QueryEncodingsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_encodings, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(22..).ok_or(ParseError::InsufficientData)?;
+        let (info, remaining) = crate::x11_utils::parse_list_with_order::<EncodingInfo>(remaining, num_encodings.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryEncodingsReply { sequence, length, info };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryEncodingsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryEncodingsReply {
+    /// Get the value of the `num_encodings` field.
+    ///
+    /// The `num_encodings` field is used as the length field of the `info` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_encodings(&self) -> u16 {
+        self.info.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryEncodingsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("info", |tracer| tracer.list(&self.info, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryEncodingsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_encodings = u16::try_from(self.info.len()).expect("`info` has too many elements");
+        num_encodings.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 22]);
+        self.info.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the GrabPort request
+pub const GRAB_PORT_REQUEST: u8 = 3;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrabPortRequest {
+    pub port: Port,
+    pub time: xproto::Timestamp,
+}
+impl GrabPortRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GRAB_PORT_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GRAB_PORT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GrabPortRequest {
+            port,
+            time,
+        })
+    }
+}
+impl Request for GrabPortRequest {
+    type Reply = GrabPortReply;
+}
+impl Trace for GrabPortRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("time", |tracer| tracer.enum_value(&self.time, xproto::Time::VARIANTS))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrabPortReply {
+    pub result: GrabPortStatus,
+    pub sequence: u16,
+    pub length: u32,
+}
+impl TryParse for GrabPortReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (result, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = result.into();
+        let result = GrabPortReply { result, sequence, length };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GrabPortReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GrabPortReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("result", |tracer| tracer.value(&self.result))?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        Ok(())
+    }
+}
+impl Serialize for GrabPortReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        u8::from(self.result).serialize_into_with_order(bytes, order);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the UngrabPort request
+pub const UNGRAB_PORT_REQUEST: u8 = 4;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UngrabPortRequest {
+    pub port: Port,
+    pub time: xproto::Timestamp,
+}
+impl UngrabPortRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let time_bytes = self.time.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            UNGRAB_PORT_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            time_bytes[0],
+            time_bytes[1],
+            time_bytes[2],
+            time_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != UNGRAB_PORT_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (time, remaining) = xproto::Timestamp::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(UngrabPortRequest {
+            port,
+            time,
+        })
+    }
+}
+impl Request for UngrabPortRequest {
+    type Reply = ();
+}
+impl Trace for UngrabPortRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("time", |tracer| tracer.enum_value(&self.time, xproto::Time::VARIANTS))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the PutVideo request
+pub const PUT_VIDEO_REQUEST: u8 = 5;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PutVideoRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl PutVideoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let vid_x_bytes = self.vid_x.serialize_with_order(order);
+        let vid_y_bytes = self.vid_y.serialize_with_order(order);
+        let vid_w_bytes = self.vid_w.serialize_with_order(order);
+        let vid_h_bytes = self.vid_h.serialize_with_order(order);
+        let drw_x_bytes = self.drw_x.serialize_with_order(order);
+        let drw_y_bytes = self.drw_y.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PUT_VIDEO_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_VIDEO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(PutVideoRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+}
+impl Request for PutVideoRequest {
+    type Reply = ();
+}
+impl Trace for PutVideoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("vid-x", |tracer| tracer.value(&self.vid_x))?;
+        tracer.field("vid-y", |tracer| tracer.value(&self.vid_y))?;
+        tracer.field("vid-w", |tracer| tracer.value(&self.vid_w))?;
+        tracer.field("vid-h", |tracer| tracer.value(&self.vid_h))?;
+        tracer.field("drw-x", |tracer| tracer.value(&self.drw_x))?;
+        tracer.field("drw-y", |tracer| tracer.value(&self.drw_y))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the PutStill request
+pub const PUT_STILL_REQUEST: u8 = 6;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PutStillRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl PutStillRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let vid_x_bytes = self.vid_x.serialize_with_order(order);
+        let vid_y_bytes = self.vid_y.serialize_with_order(order);
+        let vid_w_bytes = self.vid_w.serialize_with_order(order);
+        let vid_h_bytes = self.vid_h.serialize_with_order(order);
+        let drw_x_bytes = self.drw_x.serialize_with_order(order);
+        let drw_y_bytes = self.drw_y.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PUT_STILL_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_STILL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(PutStillRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+}
+impl Request for PutStillRequest {
+    type Reply = ();
+}
+impl Trace for PutStillRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("vid-x", |tracer| tracer.value(&self.vid_x))?;
+        tracer.field("vid-y", |tracer| tracer.value(&self.vid_y))?;
+        tracer.field("vid-w", |tracer| tracer.value(&self.vid_w))?;
+        tracer.field("vid-h", |tracer| tracer.value(&self.vid_h))?;
+        tracer.field("drw-x", |tracer| tracer.value(&self.drw_x))?;
+        tracer.field("drw-y", |tracer| tracer.value(&self.drw_y))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetVideo request
+pub const GET_VIDEO_REQUEST: u8 = 7;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetVideoRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl GetVideoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let vid_x_bytes = self.vid_x.serialize_with_order(order);
+        let vid_y_bytes = self.vid_y.serialize_with_order(order);
+        let vid_w_bytes = self.vid_w.serialize_with_order(order);
+        let vid_h_bytes = self.vid_h.serialize_with_order(order);
+        let drw_x_bytes = self.drw_x.serialize_with_order(order);
+        let drw_y_bytes = self.drw_y.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_VIDEO_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_VIDEO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetVideoRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+}
+impl Request for GetVideoRequest {
+    type Reply = ();
+}
+impl Trace for GetVideoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("vid-x", |tracer| tracer.value(&self.vid_x))?;
+        tracer.field("vid-y", |tracer| tracer.value(&self.vid_y))?;
+        tracer.field("vid-w", |tracer| tracer.value(&self.vid_w))?;
+        tracer.field("vid-h", |tracer| tracer.value(&self.vid_h))?;
+        tracer.field("drw-x", |tracer| tracer.value(&self.drw_x))?;
+        tracer.field("drw-y", |tracer| tracer.value(&self.drw_y))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetStill request
+pub const GET_STILL_REQUEST: u8 = 8;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetStillRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub vid_x: i16,
+    pub vid_y: i16,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+}
+impl GetStillRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let vid_x_bytes = self.vid_x.serialize_with_order(order);
+        let vid_y_bytes = self.vid_y.serialize_with_order(order);
+        let vid_w_bytes = self.vid_w.serialize_with_order(order);
+        let vid_h_bytes = self.vid_h.serialize_with_order(order);
+        let drw_x_bytes = self.drw_x.serialize_with_order(order);
+        let drw_y_bytes = self.drw_y.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_STILL_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            vid_x_bytes[0],
+            vid_x_bytes[1],
+            vid_y_bytes[0],
+            vid_y_bytes[1],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_STILL_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (vid_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetStillRequest {
+            port,
+            drawable,
+            gc,
+            vid_x,
+            vid_y,
+            vid_w,
+            vid_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+        })
+    }
+}
+impl Request for GetStillRequest {
+    type Reply = ();
+}
+impl Trace for GetStillRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("vid-x", |tracer| tracer.value(&self.vid_x))?;
+        tracer.field("vid-y", |tracer| tracer.value(&self.vid_y))?;
+        tracer.field("vid-w", |tracer| tracer.value(&self.vid_w))?;
+        tracer.field("vid-h", |tracer| tracer.value(&self.vid_h))?;
+        tracer.field("drw-x", |tracer| tracer.value(&self.drw_x))?;
+        tracer.field("drw-y", |tracer| tracer.value(&self.drw_y))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the StopVideo request
+pub const STOP_VIDEO_REQUEST: u8 = 9;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StopVideoRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+}
+impl StopVideoRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            STOP_VIDEO_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != STOP_VIDEO_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(StopVideoRequest {
+            port,
+            drawable,
+        })
+    }
+}
+impl Request for StopVideoRequest {
+    type Reply = ();
+}
+impl Trace for StopVideoRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SelectVideoNotify request
+pub const SELECT_VIDEO_NOTIFY_REQUEST: u8 = 10;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectVideoNotifyRequest {
+    pub drawable: xproto::Drawable,
+    pub onoff: bool,
+}
+impl SelectVideoNotifyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let onoff_bytes = self.onoff.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_VIDEO_NOTIFY_REQUEST,
+            0,
+            0,
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            onoff_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_VIDEO_NOTIFY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(value, order)?;
+        let (onoff, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(SelectVideoNotifyRequest {
+            drawable,
+            onoff,
+        })
+    }
+}
+impl Request for SelectVideoNotifyRequest {
+    type Reply = ();
+}
+impl Trace for SelectVideoNotifyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("onoff", |tracer| tracer.value(&self.onoff))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the SelectPortNotify request
+pub const SELECT_PORT_NOTIFY_REQUEST: u8 = 11;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectPortNotifyRequest {
+    pub port: Port,
+    pub onoff: bool,
+}
+impl SelectPortNotifyRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let onoff_bytes = self.onoff.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SELECT_PORT_NOTIFY_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            onoff_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SELECT_PORT_NOTIFY_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (onoff, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(SelectPortNotifyRequest {
+            port,
+            onoff,
+        })
+    }
+}
+impl Request for SelectPortNotifyRequest {
+    type Reply = ();
+}
+impl Trace for SelectPortNotifyRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("onoff", |tracer| tracer.value(&self.onoff))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the QueryBestSize request
+pub const QUERY_BEST_SIZE_REQUEST: u8 = 12;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryBestSizeRequest {
+    pub port: Port,
+    pub vid_w: u16,
+    pub vid_h: u16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub motion: bool,
+}
+impl QueryBestSizeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let vid_w_bytes = self.vid_w.serialize_with_order(order);
+        let vid_h_bytes = self.vid_h.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let motion_bytes = self.motion.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_BEST_SIZE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            vid_w_bytes[0],
+            vid_w_bytes[1],
+            vid_h_bytes[0],
+            vid_h_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+            motion_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_BEST_SIZE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (vid_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (vid_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (motion, remaining) = bool::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(QueryBestSizeRequest {
+            port,
+            vid_w,
+            vid_h,
+            drw_w,
+            drw_h,
+            motion,
+        })
+    }
+}
+impl Request for QueryBestSizeRequest {
+    type Reply = QueryBestSizeReply;
+}
+impl Trace for QueryBestSizeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("vid-w", |tracer| tracer.value(&self.vid_w))?;
+        tracer.field("vid-h", |tracer| tracer.value(&self.vid_h))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        tracer.field("motion", |tracer| tracer.value(&self.motion))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryBestSizeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub actual_width: u16,
+    pub actual_height: u16,
+}
+impl TryParse for QueryBestSizeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (actual_width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (actual_height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryBestSizeReply { sequence, length, actual_width, actual_height };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryBestSizeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for QueryBestSizeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("actual-width", |tracer| tracer.value(&self.actual_width))?;
+        tracer.field("actual-height", |tracer| tracer.value(&self.actual_height))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryBestSizeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.actual_width.serialize_into_with_order(bytes, order);
+        self.actual_height.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the SetPortAttribute request
+pub const SET_PORT_ATTRIBUTE_REQUEST: u8 = 13;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetPortAttributeRequest {
+    pub port: Port,
+    pub attribute: xproto::Atom,
+    pub value: i32,
+}
+impl SetPortAttributeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let attribute_bytes = self.attribute.serialize_with_order(order);
+        let value_bytes = self.value.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SET_PORT_ATTRIBUTE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            attribute_bytes[0],
+            attribute_bytes[1],
+            attribute_bytes[2],
+            attribute_bytes[3],
+            value_bytes[0],
+            value_bytes[1],
+            value_bytes[2],
+            value_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SET_PORT_ATTRIBUTE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (attribute, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(SetPortAttributeRequest {
+            port,
+            attribute,
+            value,
+        })
+    }
+}
+impl Request for SetPortAttributeRequest {
+    type Reply = ();
+}
+impl Trace for SetPortAttributeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("attribute", |tracer| tracer.atom(&self.attribute))?;
+        tracer.field("value", |tracer| tracer.value(&self.value))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the GetPortAttribute request
+pub const GET_PORT_ATTRIBUTE_REQUEST: u8 = 14;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GetPortAttributeRequest {
+    pub port: Port,
+    pub attribute: xproto::Atom,
+}
+impl GetPortAttributeRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let attribute_bytes = self.attribute.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            GET_PORT_ATTRIBUTE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            attribute_bytes[0],
+            attribute_bytes[1],
+            attribute_bytes[2],
+            attribute_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != GET_PORT_ATTRIBUTE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (attribute, remaining) = xproto::Atom::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(GetPortAttributeRequest {
+            port,
+            attribute,
+        })
+    }
+}
+impl Request for GetPortAttributeRequest {
+    type Reply = GetPortAttributeReply;
+}
+impl Trace for GetPortAttributeRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("attribute", |tracer| tracer.atom(&self.attribute))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GetPortAttributeReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub value: i32,
+}
+impl TryParse for GetPortAttributeReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (value, remaining) = i32::try_parse_with_order(remaining, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = GetPortAttributeReply { sequence, length, value };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for GetPortAttributeReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl Trace for GetPortAttributeReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("value", |tracer| tracer.value(&self.value))?;
+        Ok(())
+    }
+}
+impl Serialize for GetPortAttributeReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        self.value.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryPortAttributes request
+pub const QUERY_PORT_ATTRIBUTES_REQUEST: u8 = 15;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryPortAttributesRequest {
+    pub port: Port,
+}
+impl QueryPortAttributesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_PORT_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_PORT_ATTRIBUTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(QueryPortAttributesRequest {
+            port,
+        })
+    }
+}
+impl Request for QueryPortAttributesRequest {
+    type Reply = QueryPortAttributesReply;
+}
+impl Trace for QueryPortAttributesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryPortAttributesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub text_size: u32,
+    pub attributes: Vec<AttributeInfo>,
+}
+impl TryParse for QueryPortAttributesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_attributes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (text_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(16..).ok_or(ParseError::InsufficientData)?;
+        let (attributes, remaining) = crate::x11_utils::parse_list_with_order::<AttributeInfo>(remaining, num_attributes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryPortAttributesReply { sequence, length, text_size, attributes };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryPortAttributesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryPortAttributesReply {
+    /// Get the value of the `num_attributes` field.
+    ///
+    /// The `num_attributes` field is used as the length field of the `attributes` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_attributes(&self) -> u32 {
+        self.attributes.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryPortAttributesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("text-size", |tracer| tracer.value(&self.text_size))?;
+        tracer.field("attributes", |tracer| tracer.list(&self.attributes, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryPortAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_attributes = u32::try_from(self.attributes.len()).expect("`attributes` has too many elements");
+        num_attributes.serialize_into_with_order(bytes, order);
+        self.text_size.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 16]);
+        self.attributes.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the ListImageFormats request
+pub const LIST_IMAGE_FORMATS_REQUEST: u8 = 16;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListImageFormatsRequest {
+    pub port: Port,
+}
+impl ListImageFormatsRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            LIST_IMAGE_FORMATS_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != LIST_IMAGE_FORMATS_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let _ = remaining;
+        Ok(ListImageFormatsRequest {
+            port,
+        })
+    }
+}
+impl Request for ListImageFormatsRequest {
+    type Reply = ListImageFormatsReply;
+}
+impl Trace for ListImageFormatsRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListImageFormatsReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub format: Vec<ImageFormatInfo>,
+}
+impl TryParse for ListImageFormatsReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_formats, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(20..).ok_or(ParseError::InsufficientData)?;
+        let (format, remaining) = crate::x11_utils::parse_list_with_order::<ImageFormatInfo>(remaining, num_formats.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = ListImageFormatsReply { sequence, length, format };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for ListImageFormatsReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl ListImageFormatsReply {
+    /// Get the value of the `num_formats` field.
+    ///
+    /// The `num_formats` field is used as the length field of the `format` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_formats(&self) -> u32 {
+        self.format.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for ListImageFormatsReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("format", |tracer| tracer.list(&self.format, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for ListImageFormatsReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_formats = u32::try_from(self.format.len()).expect("`format` has too many elements");
+        num_formats.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 20]);
+        self.format.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the QueryImageAttributes request
+pub const QUERY_IMAGE_ATTRIBUTES_REQUEST: u8 = 17;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryImageAttributesRequest {
+    pub port: Port,
+    pub id: u32,
+    pub width: u16,
+    pub height: u16,
+}
+impl QueryImageAttributesRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let id_bytes = self.id.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            QUERY_IMAGE_ATTRIBUTES_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != QUERY_IMAGE_ATTRIBUTES_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let _ = remaining;
+        Ok(QueryImageAttributesRequest {
+            port,
+            id,
+            width,
+            height,
+        })
+    }
+}
+impl Request for QueryImageAttributesRequest {
+    type Reply = QueryImageAttributesReply;
+}
+impl Trace for QueryImageAttributesRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QueryImageAttributesReply {
+    pub sequence: u16,
+    pub length: u32,
+    pub data_size: u32,
+    pub width: u16,
+    pub height: u16,
+    pub pitches: Vec<u32>,
+    pub offsets: Vec<u32>,
+}
+impl TryParse for QueryImageAttributesReply {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse_with_order(value, ByteOrder::native())
+    }
+    fn try_parse_with_order(initial_value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        let remaining = initial_value;
+        let (response_type, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(1..).ok_or(ParseError::InsufficientData)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (num_planes, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (data_size, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(12..).ok_or(ParseError::InsufficientData)?;
+        let (pitches, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        let (offsets, remaining) = crate::x11_utils::parse_list_with_order::<u32>(remaining, num_planes.try_into().or(Err(ParseError::ConversionFailed))?, order)?;
+        if response_type != 1 {
+            return Err(ParseError::InvalidValue);
+        }
+        let result = QueryImageAttributesReply { sequence, length, data_size, width, height, pitches, offsets };
+        let _ = remaining;
+        let remaining = initial_value.get(32 + length as usize * 4..)
+            .ok_or(ParseError::InsufficientData)?;
+        Ok((result, remaining))
+    }
+}
+impl TryFrom<&[u8]> for QueryImageAttributesReply {
+    type Error = ParseError;
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::try_parse(value)?.0)
+    }
+}
+impl QueryImageAttributesReply {
+    /// Get the value of the `num_planes` field.
+    ///
+    /// The `num_planes` field is used as the length field of the `pitches` field.
+    /// This function computes the field's value again based on the length of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be represented in the target type. This
+    /// cannot happen with values of the struct received from the X11 server.
+    pub fn num_planes(&self) -> u32 {
+        self.pitches.len()
+            .try_into().unwrap()
+    }
+}
+impl Trace for QueryImageAttributesReply {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("data-size", |tracer| tracer.value(&self.data_size))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("pitches", |tracer| tracer.list(&self.pitches, |tracer, value| tracer.value(value)))?;
+        tracer.field("offsets", |tracer| tracer.list(&self.offsets, |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+impl Serialize for QueryImageAttributesReply {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.serialize_into_with_order(bytes, ByteOrder::native())
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        let start = bytes.len();
+        bytes.reserve(32);
+        bytes.push(1);
+        bytes.extend_from_slice(&[0; 1]);
+        self.sequence.serialize_into_with_order(bytes, order);
+        self.length.serialize_into_with_order(bytes, order);
+        let num_planes = u32::try_from(self.pitches.len()).expect("`pitches` has too many elements");
+        num_planes.serialize_into_with_order(bytes, order);
+        self.data_size.serialize_into_with_order(bytes, order);
+        self.width.serialize_into_with_order(bytes, order);
+        self.height.serialize_into_with_order(bytes, order);
+        bytes.extend_from_slice(&[0; 12]);
+        self.pitches.serialize_into_with_order(bytes, order);
+        assert_eq!(self.offsets.len(), usize::try_from(num_planes).unwrap(), "`offsets` has an incorrect length");
+        self.offsets.serialize_into_with_order(bytes, order);
+        crate::x11_utils::finish_reply_serialization(bytes, start, order);
+    }
+}
+
+/// Opcode for the PutImage request
+pub const PUT_IMAGE_REQUEST: u8 = 18;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PutImageRequest<'input> {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub id: u32,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_w: u16,
+    pub src_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub width: u16,
+    pub height: u16,
+    pub data: Cow<'input, [u8]>,
+}
+impl<'input> PutImageRequest<'input> {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let id_bytes = self.id.serialize_with_order(order);
+        let src_x_bytes = self.src_x.serialize_with_order(order);
+        let src_y_bytes = self.src_y.serialize_with_order(order);
+        let src_w_bytes = self.src_w.serialize_with_order(order);
+        let src_h_bytes = self.src_h.serialize_with_order(order);
+        let drw_x_bytes = self.drw_x.serialize_with_order(order);
+        let drw_y_bytes = self.drw_y.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            PUT_IMAGE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_w_bytes[0],
+            src_w_bytes[1],
+            src_h_bytes[0],
+            src_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+        ];
+        let length_so_far = length_so_far + request0.len();
+        let length_so_far = length_so_far + self.data.len();
+        let padding0 = &[0; 3][..(4 - (length_so_far % 4)) % 4];
+        let length_so_far = length_so_far + padding0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into(), self.data, padding0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &'input [u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &'input [u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != PUT_IMAGE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (data, remaining) = remaining.split_at(remaining.len());
+        let _ = remaining;
+        Ok(PutImageRequest {
+            port,
+            drawable,
+            gc,
+            id,
+            src_x,
+            src_y,
+            src_w,
+            src_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+            width,
+            height,
+            data: Cow::Borrowed(data),
+        })
+    }
+    /// Clone all borrowed data in this PutImageRequest.
+    pub fn into_owned(self) -> PutImageRequest<'static> {
+        PutImageRequest {
+            port: self.port,
+            drawable: self.drawable,
+            gc: self.gc,
+            id: self.id,
+            src_x: self.src_x,
+            src_y: self.src_y,
+            src_w: self.src_w,
+            src_h: self.src_h,
+            drw_x: self.drw_x,
+            drw_y: self.drw_y,
+            drw_w: self.drw_w,
+            drw_h: self.drw_h,
+            width: self.width,
+            height: self.height,
+            data: Cow::Owned(self.data.into_owned()),
+        }
+    }
+}
+impl<'input> Request for PutImageRequest<'input> {
+    type Reply = ();
+}
+impl Trace for PutImageRequest<'_> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        tracer.field("src-x", |tracer| tracer.value(&self.src_x))?;
+        tracer.field("src-y", |tracer| tracer.value(&self.src_y))?;
+        tracer.field("src-w", |tracer| tracer.value(&self.src_w))?;
+        tracer.field("src-h", |tracer| tracer.value(&self.src_h))?;
+        tracer.field("drw-x", |tracer| tracer.value(&self.drw_x))?;
+        tracer.field("drw-y", |tracer| tracer.value(&self.drw_y))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("data", |tracer| tracer.list(&self.data[..], |tracer, value| tracer.value(value)))?;
+        Ok(())
+    }
+}
+
+/// Opcode for the ShmPutImage request
+pub const SHM_PUT_IMAGE_REQUEST: u8 = 19;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShmPutImageRequest {
+    pub port: Port,
+    pub drawable: xproto::Drawable,
+    pub gc: xproto::Gcontext,
+    pub shmseg: shm::Seg,
+    pub id: u32,
+    pub offset: u32,
+    pub src_x: i16,
+    pub src_y: i16,
+    pub src_w: u16,
+    pub src_h: u16,
+    pub drw_x: i16,
+    pub drw_y: i16,
+    pub drw_w: u16,
+    pub drw_h: u16,
+    pub width: u16,
+    pub height: u16,
+    pub send_event: u8,
+}
+impl ShmPutImageRequest {
+    /// Serialize this request into bytes for the extension with the given major opcode
+    pub fn serialize<'input>(self, major_opcode: u8) -> BufWithFds<PiecewiseBuf<'input>> {
+        self.serialize_with_order(major_opcode, ByteOrder::native())
+    }
+    /// Serialize this request into bytes in the given byte order for the extension with the given major opcode
+    pub fn serialize_with_order<'input>(self, major_opcode: u8, order: ByteOrder) -> BufWithFds<PiecewiseBuf<'input>> {
+        let length_so_far = 0;
+        let port_bytes = self.port.serialize_with_order(order);
+        let drawable_bytes = self.drawable.serialize_with_order(order);
+        let gc_bytes = self.gc.serialize_with_order(order);
+        let shmseg_bytes = self.shmseg.serialize_with_order(order);
+        let id_bytes = self.id.serialize_with_order(order);
+        let offset_bytes = self.offset.serialize_with_order(order);
+        let src_x_bytes = self.src_x.serialize_with_order(order);
+        let src_y_bytes = self.src_y.serialize_with_order(order);
+        let src_w_bytes = self.src_w.serialize_with_order(order);
+        let src_h_bytes = self.src_h.serialize_with_order(order);
+        let drw_x_bytes = self.drw_x.serialize_with_order(order);
+        let drw_y_bytes = self.drw_y.serialize_with_order(order);
+        let drw_w_bytes = self.drw_w.serialize_with_order(order);
+        let drw_h_bytes = self.drw_h.serialize_with_order(order);
+        let width_bytes = self.width.serialize_with_order(order);
+        let height_bytes = self.height.serialize_with_order(order);
+        let send_event_bytes = self.send_event.serialize_with_order(order);
+        let mut request0 = vec![
+            major_opcode,
+            SHM_PUT_IMAGE_REQUEST,
+            0,
+            0,
+            port_bytes[0],
+            port_bytes[1],
+            port_bytes[2],
+            port_bytes[3],
+            drawable_bytes[0],
+            drawable_bytes[1],
+            drawable_bytes[2],
+            drawable_bytes[3],
+            gc_bytes[0],
+            gc_bytes[1],
+            gc_bytes[2],
+            gc_bytes[3],
+            shmseg_bytes[0],
+            shmseg_bytes[1],
+            shmseg_bytes[2],
+            shmseg_bytes[3],
+            id_bytes[0],
+            id_bytes[1],
+            id_bytes[2],
+            id_bytes[3],
+            offset_bytes[0],
+            offset_bytes[1],
+            offset_bytes[2],
+            offset_bytes[3],
+            src_x_bytes[0],
+            src_x_bytes[1],
+            src_y_bytes[0],
+            src_y_bytes[1],
+            src_w_bytes[0],
+            src_w_bytes[1],
+            src_h_bytes[0],
+            src_h_bytes[1],
+            drw_x_bytes[0],
+            drw_x_bytes[1],
+            drw_y_bytes[0],
+            drw_y_bytes[1],
+            drw_w_bytes[0],
+            drw_w_bytes[1],
+            drw_h_bytes[0],
+            drw_h_bytes[1],
+            width_bytes[0],
+            width_bytes[1],
+            height_bytes[0],
+            height_bytes[1],
+            send_event_bytes[0],
+            0,
+            0,
+            0,
+        ];
+        let length_so_far = length_so_far + request0.len();
+        assert_eq!(length_so_far % 4, 0);
+        let length = u16::try_from(length_so_far / 4).unwrap_or(0);
+        request0[2..4].copy_from_slice(&length.serialize_with_order(order));
+        (vec![request0.into()], vec![])
+    }
+    /// Parse this request given its header, its body, and any fds that go along with it
+    pub fn try_parse_request(header: RequestHeader, value: &[u8]) -> Result<Self, ParseError> {
+        Self::try_parse_request_with_order(header, value, ByteOrder::native())
+    }
+    /// Parse this request given its header, its body in the given byte order, and any fds that go along with it
+    pub fn try_parse_request_with_order(header: RequestHeader, value: &[u8], order: ByteOrder) -> Result<Self, ParseError> {
+        if header.minor_opcode != SHM_PUT_IMAGE_REQUEST {
+            return Err(ParseError::InvalidValue);
+        }
+        let (port, remaining) = Port::try_parse_with_order(value, order)?;
+        let (drawable, remaining) = xproto::Drawable::try_parse_with_order(remaining, order)?;
+        let (gc, remaining) = xproto::Gcontext::try_parse_with_order(remaining, order)?;
+        let (shmseg, remaining) = shm::Seg::try_parse_with_order(remaining, order)?;
+        let (id, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (offset, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (src_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (src_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (src_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_x, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_y, remaining) = i16::try_parse_with_order(remaining, order)?;
+        let (drw_w, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (drw_h, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (width, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (height, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (send_event, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let remaining = remaining.get(3..).ok_or(ParseError::InsufficientData)?;
+        let _ = remaining;
+        Ok(ShmPutImageRequest {
+            port,
+            drawable,
+            gc,
+            shmseg,
+            id,
+            offset,
+            src_x,
+            src_y,
+            src_w,
+            src_h,
+            drw_x,
+            drw_y,
+            drw_w,
+            drw_h,
+            width,
+            height,
+            send_event,
+        })
+    }
+}
+impl Request for ShmPutImageRequest {
+    type Reply = ();
+}
+impl Trace for ShmPutImageRequest {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> core::fmt::Result {
+        tracer.field("port", |tracer| tracer.xid(&self.port))?;
+        tracer.field("drawable", |tracer| tracer.xid(&self.drawable))?;
+        tracer.field("gc", |tracer| tracer.xid(&self.gc))?;
+        tracer.field("shmseg", |tracer| tracer.xid(&self.shmseg))?;
+        tracer.field("id", |tracer| tracer.value(&self.id))?;
+        tracer.field("offset", |tracer| tracer.value(&self.offset))?;
+        tracer.field("src-x", |tracer| tracer.value(&self.src_x))?;
+        tracer.field("src-y", |tracer| tracer.value(&self.src_y))?;
+        tracer.field("src-w", |tracer| tracer.value(&self.src_w))?;
+        tracer.field("src-h", |tracer| tracer.value(&self.src_h))?;
+        tracer.field("drw-x", |tracer| tracer.value(&self.drw_x))?;
+        tracer.field("drw-y", |tracer| tracer.value(&self.drw_y))?;
+        tracer.field("drw-w", |tracer| tracer.value(&self.drw_w))?;
+        tracer.field("drw-h", |tracer| tracer.value(&self.drw_h))?;
+        tracer.field("width", |tracer| tracer.value(&self.width))?;
+        tracer.field("height", |tracer| tracer.value(&self.height))?;
+        tracer.field("send-event", |tracer| tracer.value(&self.send_event))?;
+        Ok(())
+    }
+}
+
+/// Descriptions of the requests in this module, sorted by opcode.
+pub static REQUEST_INFOS: &[RequestInfo] = &[
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "QueryExtension",
+        opcode: 0,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "major",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "QueryAdaptors",
+        opcode: 1,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "window",
+                kind: FieldKind::Value(TypeInfo { name: "WINDOW", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_adaptors",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "info",
+                kind: FieldKind::List(TypeInfo { name: "AdaptorInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "QueryEncodings",
+        opcode: 2,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_encodings",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "info",
+                kind: FieldKind::List(TypeInfo { name: "EncodingInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "GrabPort",
+        opcode: 3,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::AltEnum(EnumInfo { name: "Time", variants: xproto::Time::VARIANTS }) }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "result",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "GrabPortStatus", variants: GrabPortStatus::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "UngrabPort",
+        opcode: 4,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::AltEnum(EnumInfo { name: "Time", variants: xproto::Time::VARIANTS }) }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "PutVideo",
+        opcode: 5,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "PutStill",
+        opcode: 6,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "GetVideo",
+        opcode: 7,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "GetStill",
+        opcode: 8,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "StopVideo",
+        opcode: 9,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "SelectVideoNotify",
+        opcode: 10,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "onoff",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "SelectPortNotify",
+        opcode: 11,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "onoff",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "QueryBestSize",
+        opcode: 12,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "vid_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "motion",
+                kind: FieldKind::Value(TypeInfo { name: "BOOL", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "actual_width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "actual_height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "SetPortAttribute",
+        opcode: 13,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribute",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "GetPortAttribute",
+        opcode: 14,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribute",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "QueryPortAttributes",
+        opcode: 15,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_attributes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "text_size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attributes",
+                kind: FieldKind::List(TypeInfo { name: "AttributeInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "ListImageFormats",
+        opcode: 16,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_formats",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "format",
+                kind: FieldKind::List(TypeInfo { name: "ImageFormatInfo", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "QueryImageAttributes",
+        opcode: 17,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: Some(&[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "num_planes",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data_size",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "pitches",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offsets",
+                kind: FieldKind::List(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+        ]),
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "PutImage",
+        opcode: 18,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "data",
+                kind: FieldKind::List(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+    RequestInfo {
+        extension: Some("Xv"),
+        name: "ShmPutImage",
+        opcode: 19,
+        fields: &[
+            FieldInfo {
+                name: "major_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "minor_opcode",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "length",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "gc",
+                kind: FieldKind::Value(TypeInfo { name: "GCONTEXT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "shmseg",
+                kind: FieldKind::Value(TypeInfo { name: "SEG", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "id",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "offset",
+                kind: FieldKind::Value(TypeInfo { name: "CARD32", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "src_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_x",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_y",
+                kind: FieldKind::Value(TypeInfo { name: "INT16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_w",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drw_h",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "width",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "height",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "send_event",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+        ],
+        reply_fields: None,
+    },
+];
+
+/// Descriptions of the events in this module, sorted by name.
+pub static EVENT_INFOS: &[EventInfo] = &[
+    EventInfo {
+        extension: Some("Xv"),
+        name: "PortNotify",
+        number: 1,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "attribute",
+                kind: FieldKind::Value(TypeInfo { name: "ATOM", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "value",
+                kind: FieldKind::Value(TypeInfo { name: "INT32", value_set: ValueSet::None }),
+            },
+        ],
+    },
+    EventInfo {
+        extension: Some("Xv"),
+        name: "VideoNotify",
+        number: 0,
+        xge: false,
+        fields: &[
+            FieldInfo {
+                name: "response_type",
+                kind: FieldKind::Value(TypeInfo { name: "CARD8", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "reason",
+                kind: FieldKind::Value(TypeInfo { name: "BYTE", value_set: ValueSet::Enum(EnumInfo { name: "VideoNotifyReason", variants: VideoNotifyReason::VARIANTS }) }),
+            },
+            FieldInfo {
+                name: "sequence",
+                kind: FieldKind::Value(TypeInfo { name: "CARD16", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "time",
+                kind: FieldKind::Value(TypeInfo { name: "TIMESTAMP", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "drawable",
+                kind: FieldKind::Value(TypeInfo { name: "DRAWABLE", value_set: ValueSet::None }),
+            },
+            FieldInfo {
+                name: "port",
+                kind: FieldKind::Value(TypeInfo { name: "PORT", value_set: ValueSet::None }),
+            },
+        ],
+    },
+];
+
@@ -0,0 +1,365 @@
+//! One-line traces of X11 protocol messages.
+//!
+//! The `Debug` output of the generated types shows their raw structure. The [`Trace`] trait
+//! instead produces a compact, single-line description in the style of the `xtrace` tool:
+//!
+//! ```text
+//! Request InternAtom only-if-exists=false name='WM_NAME'
+//! Reply to InternAtom: sequence=1 atom=0x27("WM_NAME")
+//! Event Expose sequence=2 window=0x02a00001 x=0 y=0 width=100 height=50 count=0
+//! ```
+//!
+//! Resource IDs are printed in hexadecimal, values of enumerations and bitmasks are printed with
+//! their names and long lists are shortened. Atoms are printed together with their name. The
+//! predefined atoms are known. For other atoms, a callback can be provided that looks up the name
+//! of an atom, e.g. based on earlier `InternAtom` requests:
+//!
+//! ```
+//! # use x11rb_protocol::protocol::xproto::ConvertSelectionRequest;
+//! # use x11rb_protocol::trace::Trace;
+//! # let request = ConvertSelectionRequest {
+//! #     requestor: 0x1234u32.into(),
+//! #     selection: 1u32.into(),
+//! #     target: 300u32.into(),
+//! #     property: 0u32.into(),
+//! #     time: 0u32.into(),
+//! # };
+//! let atom_names = |atom| if atom == 300 { Some("UTF8_STRING".to_string()) } else { None };
+//! let trace = request.traced_with_atom_names(&atom_names).to_string();
+//! assert_eq!(
+//!     trace,
+//!     "requestor=0x00001234 selection=0x1(\"PRIMARY\") target=0x12c(\"UTF8_STRING\") \
+//!      property=0x0 time=CurrentTime",
+//! );
+//! ```
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use core::fmt::{self, Arguments, Display, Formatter};
+
+use crate::protocol::xproto::AtomEnum;
+use crate::utils::RawFdContainer;
+use crate::x11_utils::{ExtInfoProvider, NoExtensions, X11Error};
+
+/// The number of list elements that are printed before a list is shortened.
+const MAX_LIST_ELEMENTS: usize = 8;
+
+/// A callback that looks up the name of an atom.
+pub type AtomNames<'a> = &'a dyn Fn(u32) -> Option<String>;
+
+/// A value that can be described in a one-line trace.
+pub trait Trace {
+    /// Write a description of this value to `tracer`.
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> fmt::Result;
+
+    /// Get a wrapper around this value that implements `Display` by producing a trace.
+    fn traced(&self) -> Traced<'_, Self>
+    where
+        Self: Sized,
+    {
+        Traced {
+            value: self,
+            atom_names: None,
+        }
+    }
+
+    /// Get a wrapper around this value that implements `Display` by producing a trace.
+    ///
+    /// The given callback is used for looking up the names of atoms that are not predefined.
+    fn traced_with_atom_names<'a>(&'a self, atom_names: AtomNames<'a>) -> Traced<'a, Self>
+    where
+        Self: Sized,
+    {
+        Traced {
+            value: self,
+            atom_names: Some(atom_names),
+        }
+    }
+}
+
+/// A wrapper that implements `Display` by producing a trace of the wrapped value.
+///
+/// Instances of this type are created by [`Trace::traced`] and
+/// [`Trace::traced_with_atom_names`].
+pub struct Traced<'a, T> {
+    value: &'a T,
+    atom_names: Option<AtomNames<'a>>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Traced<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Traced")
+            .field("value", self.value)
+            .field("has_atom_names", &self.atom_names.is_some())
+            .finish()
+    }
+}
+
+impl<T: Trace> Display for Traced<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut tracer = Tracer {
+            fmt: f,
+            atom_names: self.atom_names,
+            at_start: true,
+        };
+        self.value.trace(&mut tracer)
+    }
+}
+
+/// The output of a trace.
+///
+/// This wraps a [`Formatter`] and provides helpers for printing values in the format of a trace.
+pub struct Tracer<'a, 'b> {
+    fmt: &'a mut Formatter<'b>,
+    atom_names: Option<AtomNames<'a>>,
+    // Is the next field the first one in its structure (and thus not preceded by a space)?
+    at_start: bool,
+}
+
+impl fmt::Debug for Tracer<'_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer")
+            .field("has_atom_names", &self.atom_names.is_some())
+            .field("at_start", &self.at_start)
+            .finish()
+    }
+}
+
+impl<'a, 'b> Tracer<'a, 'b> {
+    /// Write a string to the output.
+    pub fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.at_start = false;
+        self.fmt.write_str(value)
+    }
+
+    /// Write formatted text to the output.
+    pub fn write_fmt(&mut self, args: Arguments<'_>) -> fmt::Result {
+        self.at_start = false;
+        self.fmt.write_fmt(args)
+    }
+
+    /// Write a field as `name=value`.
+    ///
+    /// Fields are separated by spaces. The value is produced by the given callback.
+    pub fn field(
+        &mut self,
+        name: &str,
+        value: impl FnOnce(&mut Self) -> fmt::Result,
+    ) -> fmt::Result {
+        if !self.at_start {
+            self.fmt.write_str(" ")?;
+        }
+        self.write_str(name)?;
+        self.write_str("=")?;
+        value(self)
+    }
+
+    /// Write a structure as `{field1=value1 field2=value2}`.
+    ///
+    /// The fields are written by the given callback.
+    pub fn structure(&mut self, fields: impl FnOnce(&mut Self) -> fmt::Result) -> fmt::Result {
+        self.write_str("{")?;
+        self.at_start = true;
+        fields(self)?;
+        self.write_str("}")
+    }
+
+    /// Write a value via its [`Trace`] implementation.
+    pub fn value<T: Trace + ?Sized>(&mut self, value: &T) -> fmt::Result {
+        value.trace(self)
+    }
+
+    /// Write a list of values as `[value1,value2]`.
+    ///
+    /// Long lists are shortened to their first elements and the number of elements in the list.
+    /// Each element is written by the given callback.
+    pub fn list<T>(
+        &mut self,
+        values: &[T],
+        mut value: impl FnMut(&mut Self, &T) -> fmt::Result,
+    ) -> fmt::Result {
+        self.write_str("[")?;
+        for (index, element) in values.iter().take(MAX_LIST_ELEMENTS).enumerate() {
+            if index != 0 {
+                self.write_str(",")?;
+            }
+            value(self, element)?;
+        }
+        if values.len() > MAX_LIST_ELEMENTS {
+            write!(self, ",...; {} items]", values.len())
+        } else {
+            self.write_str("]")
+        }
+    }
+
+    /// Write a string of bytes as `'text'`.
+    ///
+    /// Bytes that are not printable ASCII characters are escaped.
+    pub fn string(&mut self, value: &[u8]) -> fmt::Result {
+        self.write_str("'")?;
+        for byte in value {
+            match byte {
+                b'\'' => self.write_str("\\'")?,
+                b'\\' => self.write_str("\\\\")?,
+                0x20..=0x7e => write!(self, "{}", char::from(*byte))?,
+                _ => write!(self, "\\x{:02x}", byte)?,
+            }
+        }
+        self.write_str("'")
+    }
+
+    /// Write a resource ID in hexadecimal.
+    pub fn xid<T: Copy + Into<u32>>(&mut self, value: &T) -> fmt::Result {
+        write!(self, "0x{:08x}", (*value).into())
+    }
+
+    /// Write an atom with its name, e.g. as `0x27("WM_NAME")`.
+    ///
+    /// The name is looked up with the callback that this tracer was created with. Predefined atoms
+    /// are also known without a callback.
+    pub fn atom<T: Copy + Into<u32>>(&mut self, value: &T) -> fmt::Result {
+        let value = (*value).into();
+        write!(self, "{:#x}", value)?;
+        if value == 0 {
+            return Ok(());
+        }
+        let name = self.atom_names.and_then(|atom_names| atom_names(value));
+        if let Some(name) = name {
+            return write!(self, "(\"{}\")", name);
+        }
+        let predefined = AtomEnum::VARIANTS
+            .iter()
+            .find(|(variant, _, _)| *variant == value);
+        match predefined {
+            Some((_, name, _)) => write!(self, "(\"{}\")", name),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the value of an enumeration with its name.
+    ///
+    /// Values without a name are written as numbers.
+    pub fn enum_value<T: Copy + Into<u32>>(
+        &mut self,
+        value: &T,
+        variants: &[(u32, &str, &str)],
+    ) -> fmt::Result {
+        let value = (*value).into();
+        match variants.iter().find(|(variant, _, _)| *variant == value) {
+            Some((_, _, name)) => self.write_str(name),
+            None => write!(self, "{}", value),
+        }
+    }
+
+    /// Write a bitmask as the names of its bits separated by `|`.
+    ///
+    /// Bits without a name are written as a number.
+    pub fn bitmask<T: Copy + Into<u32>>(
+        &mut self,
+        value: &T,
+        variants: &[(u32, &str, &str)],
+    ) -> fmt::Result {
+        let value = (*value).into();
+        let known_bits = variants
+            .iter()
+            .fold(0, |acc, (variant, _, _)| acc | variant);
+        let remaining = value & !known_bits;
+        let mut already_printed = if value == 0 || remaining != 0 {
+            write!(self, "{:#x}", remaining)?;
+            true
+        } else {
+            false
+        };
+        for (variant, _, name) in variants {
+            if variant & value != 0 {
+                if already_printed {
+                    self.write_str("|")?;
+                }
+                already_printed = true;
+                self.write_str(name)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+macro_rules! trace_display {
+    ($($t:ty)*) => {
+        $(
+            impl Trace for $t {
+                fn trace(&self, tracer: &mut Tracer<'_, '_>) -> fmt::Result {
+                    write!(tracer, "{}", self)
+                }
+            }
+        )*
+    };
+}
+
+trace_display!(u8 u16 u32 u64 i8 i16 i32 i64 bool f32 f64);
+
+impl<T: Trace + ToOwned + ?Sized> Trace for Cow<'_, T> {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> fmt::Result {
+        (**self).trace(tracer)
+    }
+}
+
+impl Trace for RawFdContainer {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> fmt::Result {
+        write!(tracer, "{:?}", self)
+    }
+}
+
+impl Trace for X11Error {
+    fn trace(&self, tracer: &mut Tracer<'_, '_>) -> fmt::Result {
+        // Without further information, only the names of core requests are known
+        self.trace_with(tracer, &NoExtensions)
+    }
+}
+
+impl X11Error {
+    /// Get a wrapper around this error that implements `Display` by producing a trace.
+    ///
+    /// Unlike [`Trace::traced`], the given provider is used for looking up the names of
+    /// extension requests.
+    pub fn traced_with<'a>(
+        &'a self,
+        ext_info_provider: &'a dyn ExtInfoProvider,
+    ) -> impl Display + 'a {
+        struct TracedWith<'a> {
+            error: &'a X11Error,
+            ext_info_provider: &'a dyn ExtInfoProvider,
+        }
+
+        impl Display for TracedWith<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let mut tracer = Tracer {
+                    fmt: f,
+                    atom_names: None,
+                    at_start: true,
+                };
+                self.error.trace_with(&mut tracer, self.ext_info_provider)
+            }
+        }
+
+        TracedWith {
+            error: self,
+            ext_info_provider,
+        }
+    }
+
+    fn trace_with(
+        &self,
+        tracer: &mut Tracer<'_, '_>,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> fmt::Result {
+        write!(tracer, "Error {:?}", self.error_kind)?;
+        tracer.field("sequence", |tracer| tracer.value(&self.sequence))?;
+        tracer.field("bad-value", |tracer| tracer.xid(&self.bad_value))?;
+        tracer.field("major-opcode", |tracer| tracer.value(&self.major_opcode))?;
+        tracer.field("minor-opcode", |tracer| tracer.value(&self.minor_opcode))?;
+        if let Some(request_name) = self.request_name(ext_info_provider) {
+            tracer.field("request", |tracer| tracer.write_str(request_name))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,485 @@
+//! Utility functions that are not specific to X11.
+//!
+//!
+//! # RawFdContainer
+//!
+//! [`RawFdContainer`] is a variant of `std::os::unix::io::RawFd` with ownership semantics. This
+//! means that the `RawFd` will be closed when the `RawFdContainer` is dropped.
+//!
+//! On non-`cfg(unix)`-systems and without the `std` feature, this is an empty type without methods.
+//! It still exists as a type so that it can appear in interfaces, but it is not actually possible
+//! to construct an instance of `RawFdContainer`.
+
+#[cfg(all(feature = "std", unix))]
+fn nix_error_to_io(e: nix::Error) -> std::io::Error {
+    match e {
+        nix::Error::Sys(errno) => errno.into(),
+        nix::Error::InvalidPath | nix::Error::InvalidUtf8 => {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
+        }
+        nix::Error::UnsupportedOperation => std::io::Error::new(std::io::ErrorKind::Other, e),
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+mod raw_fd_container {
+    use std::mem::forget;
+    use std::os::unix::io::{AsRawFd, IntoRawFd, RawFd};
+
+    use super::nix_error_to_io;
+
+    /// A simple wrapper around RawFd that closes the fd on drop.
+    ///
+    /// On non-unix systems or without the `std` feature, this type is empty and does not provide
+    /// any method.
+    #[derive(Debug, Hash, PartialEq, Eq)]
+    pub struct RawFdContainer(RawFd);
+
+    impl Drop for RawFdContainer {
+        fn drop(&mut self) {
+            let _ = nix::unistd::close(self.0);
+        }
+    }
+
+    impl RawFdContainer {
+        /// Create a new `RawFdContainer` for the given `RawFd`.
+        ///
+        /// The `RawFdContainer` takes ownership of the `RawFd` and closes it on drop.
+        pub fn new(fd: RawFd) -> Self {
+            RawFdContainer(fd)
+        }
+
+        /// Tries to clone the `RawFdContainer` creating a new FD
+        /// with `dup`. The new `RawFdContainer` will take ownership
+        /// of the `dup`ed version, whereas the original `RawFdContainer`
+        /// will keep the ownership of its FD.
+        pub fn try_clone(&self) -> Result<Self, std::io::Error> {
+            Ok(Self::new(
+                nix::unistd::dup(self.0).map_err(nix_error_to_io)?,
+            ))
+        }
+
+        /// Get the `RawFd` out of this `RawFdContainer`.
+        ///
+        /// This function would be an implementation of `IntoRawFd` if that were possible. However, it
+        /// causes a conflict with an `impl` from libcore...
+        pub fn into_raw_fd(self) -> RawFd {
+            let fd = self.0;
+            forget(self);
+            fd
+        }
+
+        /// Consumes the `RawFdContainer` and closes the wrapped FD with
+        /// the `close` system call.
+        ///
+        /// This is similar to dropping the `RawFdContainer`, but it allows
+        /// the caller to handle errors.
+        pub fn close(self) -> Result<(), std::io::Error> {
+            let fd = self.into_raw_fd();
+            nix::unistd::close(fd).map_err(nix_error_to_io)
+        }
+    }
+
+    impl<T: IntoRawFd> From<T> for RawFdContainer {
+        fn from(fd: T) -> Self {
+            Self::new(fd.into_raw_fd())
+        }
+    }
+
+    impl AsRawFd for RawFdContainer {
+        fn as_raw_fd(&self) -> RawFd {
+            self.0
+        }
+    }
+}
+
+#[cfg(not(all(feature = "std", unix)))]
+mod raw_fd_container {
+    /// A simple wrapper around RawFd that closes the fd on drop.
+    ///
+    /// On non-unix systems or without the `std` feature, this type is empty and does not provide
+    /// any method.
+    #[derive(Debug, Hash, PartialEq, Eq)]
+    pub struct RawFdContainer(());
+
+    impl Drop for RawFdContainer {
+        fn drop(&mut self) {
+            // This function exists for symmetry with cfg(all(feature = "std", unix))
+        }
+    }
+}
+
+mod pretty_printer {
+    use core::fmt::{Debug, Formatter, Result};
+
+    /// A helper to pretty-print an enumeration value.
+    ///
+    /// This function prints the given number. If it matches one of the provided variants, that
+    /// match is used. Otherwise, the number is printed as a decimal.
+    ///
+    /// In alternate mode, the second string in the given array is used, else the first.
+    pub(crate) fn pretty_print_enum(
+        fmt: &mut Formatter<'_>,
+        value: u32,
+        cases: &[(u32, &str, &str)],
+    ) -> Result {
+        for (variant, name1, name2) in cases {
+            if &value == variant {
+                if fmt.alternate() {
+                    return fmt.write_str(name2);
+                } else {
+                    return fmt.write_str(name1);
+                }
+            }
+        }
+        Debug::fmt(&value, fmt)
+    }
+
+    /// A helper to pretty-print a bitmask.
+    ///
+    /// This function prints the given number. All bit-matches with the given variants are printed.
+    /// Any left-over number is printed as a decimal.
+    ///
+    /// In alternate mode, the second string in the given array is used, else the first.
+    pub(crate) fn pretty_print_bitmask(
+        fmt: &mut Formatter<'_>,
+        value: u32,
+        cases: &[(u32, &str, &str)],
+    ) -> Result {
+        // First, figure out if there are any bits not covered by any case
+        let known_bits = cases.iter().fold(0, |acc, (value, _, _)| acc | value);
+        let remaining = value & !known_bits;
+        let mut already_printed = if value == 0 || remaining != 0 {
+            Debug::fmt(&remaining, fmt)?;
+            true
+        } else {
+            false
+        };
+        for (variant, name1, name2) in cases {
+            if variant & value != 0 {
+                if already_printed {
+                    fmt.write_str(" | ")?;
+                }
+                already_printed = true;
+                if fmt.alternate() {
+                    fmt.write_str(name2)?;
+                } else {
+                    fmt.write_str(name1)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::{pretty_print_bitmask, pretty_print_enum};
+        use alloc::format;
+        use core::fmt::{Display, Formatter, Result};
+
+        type CallbackType = fn(&mut Formatter<'_>, u32, &[(u32, &str, &str)]) -> Result;
+
+        struct CallbackFormating<'a, 'b> {
+            callback: CallbackType,
+            value: u32,
+            cases: &'a [(u32, &'b str, &'b str)],
+        }
+
+        fn new_enum<'a, 'b>(
+            value: u32,
+            cases: &'a [(u32, &'b str, &'b str)],
+        ) -> CallbackFormating<'a, 'b> {
+            CallbackFormating {
+                callback: pretty_print_enum,
+                value,
+                cases,
+            }
+        }
+
+        fn new_bitmask<'a, 'b>(
+            value: u32,
+            cases: &'a [(u32, &'b str, &'b str)],
+        ) -> CallbackFormating<'a, 'b> {
+            CallbackFormating {
+                callback: pretty_print_bitmask,
+                value,
+                cases,
+            }
+        }
+
+        impl Display for CallbackFormating<'_, '_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                (self.callback)(f, self.value, self.cases)
+            }
+        }
+
+        #[test]
+        fn test_enum() {
+            let cases = [(0, "zero", "ZERO"), (42, "the answer", "ANSWER")];
+            let printer = new_enum(0, &cases);
+            assert_eq!(&format!("{}", printer), "zero");
+            assert_eq!(&format!("{:#}", printer), "ZERO");
+            let printer = new_enum(1, &cases);
+            assert_eq!(&format!("{}", printer), "1");
+            assert_eq!(&format!("{:#}", printer), "1");
+            let printer = new_enum(42, &cases);
+            assert_eq!(&format!("{}", printer), "the answer");
+            assert_eq!(&format!("{:#}", printer), "ANSWER");
+        }
+
+        #[test]
+        fn test_bitmask() {
+            let bits = [
+                (1 << 5, "b5", "B5"),
+                (1 << 1, "b1", "B1"),
+                (1 << 0, "unused", "UNUSED"),
+            ];
+            let printer = new_bitmask(8, &bits);
+            assert_eq!(&format!("{}", printer), "8");
+            assert_eq!(&format!("{:#}", printer), "8");
+            let printer = new_bitmask(32, &bits);
+            assert_eq!(&format!("{}", printer), "b5");
+            assert_eq!(&format!("{:#}", printer), "B5");
+            let printer = new_bitmask(34, &bits);
+            assert_eq!(&format!("{}", printer), "b5 | b1");
+            assert_eq!(&format!("{:#}", printer), "B5 | B1");
+            let printer = new_bitmask(42, &bits);
+            assert_eq!(&format!("{}", printer), "8 | b5 | b1");
+            assert_eq!(&format!("{:#}", printer), "8 | B5 | B1");
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use core::convert::TryFrom;
+    use core::fmt::{Display, Formatter, Result as FmtResult};
+
+    use serde::de::{Deserializer, Error, Unexpected, Visitor};
+    use serde::Serializer;
+
+    use super::pretty_printer::pretty_print_bitmask;
+
+    /// A value that the raw type of an enumeration can be created from.
+    pub(crate) trait EnumRawValue: Sized {
+        /// Convert the given number, or return `None` if it is out of range.
+        fn from_u32(value: u32) -> Option<Self>;
+    }
+
+    impl EnumRawValue for bool {
+        fn from_u32(value: u32) -> Option<Self> {
+            match value {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            }
+        }
+    }
+
+    macro_rules! enum_raw_value {
+        ($($type:ty),*) => {
+            $(
+                impl EnumRawValue for $type {
+                    fn from_u32(value: u32) -> Option<Self> {
+                        <$type>::try_from(value).ok()
+                    }
+                }
+            )*
+        }
+    }
+    enum_raw_value!(u8, u16, u32);
+
+    /// Serialize an enumeration value.
+    ///
+    /// In human-readable formats, this uses the first name of the matching variant and falls back
+    /// to the number if no variant matches. Other formats always get the number.
+    pub(crate) fn serialize_enum<S: Serializer>(
+        serializer: S,
+        value: u32,
+        cases: &[(u32, &str, &str)],
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            for (variant, name, _) in cases {
+                if &value == variant {
+                    return serializer.serialize_str(name);
+                }
+            }
+        }
+        serializer.serialize_u32(value)
+    }
+
+    /// Serialize a bitmask.
+    ///
+    /// In human-readable formats, this uses the same representation as `pretty_print_bitmask`,
+    /// for example `"SHIFT | LOCK"`. Other formats get the number.
+    pub(crate) fn serialize_bitmask<S: Serializer>(
+        serializer: S,
+        value: u32,
+        cases: &[(u32, &str, &str)],
+    ) -> Result<S::Ok, S::Error> {
+        struct Bitmask<'a, 'b> {
+            value: u32,
+            cases: &'a [(u32, &'b str, &'b str)],
+        }
+
+        impl Display for Bitmask<'_, '_> {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+                pretty_print_bitmask(fmt, self.value, self.cases)
+            }
+        }
+
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Bitmask { value, cases })
+        } else {
+            serializer.serialize_u32(value)
+        }
+    }
+
+    /// Deserialize an enumeration value from a variant name or a number.
+    pub(crate) fn deserialize_enum<'de, D, T>(
+        deserializer: D,
+        cases: &[(u32, &str, &str)],
+    ) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: EnumRawValue,
+    {
+        deserialize(deserializer, cases, false)
+    }
+
+    /// Deserialize a bitmask from variant names and numbers separated by `|`, or from a number.
+    pub(crate) fn deserialize_bitmask<'de, D, T>(
+        deserializer: D,
+        cases: &[(u32, &str, &str)],
+    ) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: EnumRawValue,
+    {
+        deserialize(deserializer, cases, true)
+    }
+
+    fn deserialize<'de, D, T>(
+        deserializer: D,
+        cases: &[(u32, &str, &str)],
+        bitmask: bool,
+    ) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: EnumRawValue,
+    {
+        let human_readable = deserializer.is_human_readable();
+        let visitor = ValueVisitor { cases, bitmask };
+        let value = if human_readable {
+            deserializer.deserialize_any(visitor)?
+        } else {
+            deserializer.deserialize_u32(visitor)?
+        };
+        T::from_u32(value)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Unsigned(value.into()), &visitor))
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct ValueVisitor<'a, 'b> {
+        cases: &'a [(u32, &'b str, &'b str)],
+        bitmask: bool,
+    }
+
+    impl ValueVisitor<'_, '_> {
+        fn parse_word(&self, word: &str) -> Option<u32> {
+            self.cases
+                .iter()
+                .find(|(_, name1, name2)| word == *name1 || word == *name2)
+                .map(|(value, _, _)| *value)
+                .or_else(|| word.parse().ok())
+        }
+    }
+
+    impl<'de> Visitor<'de> for ValueVisitor<'_, '_> {
+        type Value = u32;
+
+        fn expecting(&self, fmt: &mut Formatter<'_>) -> FmtResult {
+            if self.bitmask {
+                fmt.write_str("a number or names separated by `|`")
+            } else {
+                fmt.write_str("a number or a variant name")
+            }
+        }
+
+        fn visit_u64<E: Error>(self, value: u64) -> Result<u32, E> {
+            u32::try_from(value).map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &self))
+        }
+
+        fn visit_i64<E: Error>(self, value: i64) -> Result<u32, E> {
+            u32::try_from(value).map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<u32, E> {
+            let result = if self.bitmask {
+                value.split('|').try_fold(0, |acc, word| {
+                    self.parse_word(word.trim()).map(|bits| acc | bits)
+                })
+            } else {
+                self.parse_word(value)
+            };
+            result.ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use serde::de::value::{Error, StrDeserializer, U64Deserializer};
+        use serde::de::IntoDeserializer;
+
+        use super::{deserialize_bitmask, deserialize_enum};
+
+        const CASES: [(u32, &str, &str); 3] = [
+            (0, "NONE", "None"),
+            (1, "SHIFT", "Shift"),
+            (4, "CONTROL", "Control"),
+        ];
+
+        fn from_str<T: super::EnumRawValue>(value: &str, bitmask: bool) -> Result<T, Error> {
+            let deserializer: StrDeserializer<'_, Error> = value.into_deserializer();
+            if bitmask {
+                deserialize_bitmask(deserializer, &CASES)
+            } else {
+                deserialize_enum(deserializer, &CASES)
+            }
+        }
+
+        fn from_number<T: super::EnumRawValue>(value: u64) -> Result<T, Error> {
+            let deserializer: U64Deserializer<Error> = value.into_deserializer();
+            deserialize_enum(deserializer, &CASES)
+        }
+
+        #[test]
+        fn test_enum() {
+            assert_eq!(from_str::<u8>("SHIFT", false), Ok(1));
+            assert_eq!(from_str::<u8>("Control", false), Ok(4));
+            assert_eq!(from_str::<u8>("42", false), Ok(42));
+            assert!(from_str::<u8>("SHIFT | CONTROL", false).is_err());
+            assert!(from_str::<u8>("UNKNOWN", false).is_err());
+            assert!(from_str::<u8>("256", false).is_err());
+            assert_eq!(from_number::<u16>(42), Ok(42));
+            assert!(from_number::<u16>(1 << 16).is_err());
+            assert_eq!(from_number::<bool>(1), Ok(true));
+            assert!(from_number::<bool>(2).is_err());
+        }
+
+        #[test]
+        fn test_bitmask() {
+            assert_eq!(from_str::<u32>("NONE", true), Ok(0));
+            assert_eq!(from_str::<u32>("SHIFT | CONTROL", true), Ok(5));
+            assert_eq!(from_str::<u32>("8 | Shift|CONTROL", true), Ok(13));
+            assert!(from_str::<u32>("SHIFT | UNKNOWN", true).is_err());
+        }
+    }
+}
+
+pub(crate) use pretty_printer::{pretty_print_bitmask, pretty_print_enum};
+pub use raw_fd_container::RawFdContainer;
+#[cfg(feature = "serde")]
+pub(crate) use serde_support::{
+    deserialize_bitmask, deserialize_enum, serialize_bitmask, serialize_enum,
+};
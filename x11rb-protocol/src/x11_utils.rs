@@ -0,0 +1,999 @@
+//! Utility functions for X11 things.
+//!
+//! The most important definitions in this module are the [`TryParse`], [`TryParseFd`] and
+//! [`Serialize`] traits. These traits are used internally for parsing incoming data and producing
+//! outgoing data when talking with the X11 server.
+//!
+//! X11 data is sent in the byte order that the client announced in its `SetupRequest`. The
+//! methods without a [`ByteOrder`] argument use the native byte order of the host, which is what
+//! a client talking to the X11 server needs. The `*_with_order` variants can handle data in either
+//! byte order, e.g. when looking at the traffic of another client.
+
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+
+use crate::errors::ParseError;
+use crate::protocol::{request_name, ErrorKind};
+use crate::utils::RawFdContainer;
+
+/// Representation of an X11 error packet that was sent by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct X11Error {
+    /// The kind of error that occurred.
+    pub error_kind: ErrorKind,
+    /// The kind of error that occurred as it appears "on the wire".
+    pub error_code: u8,
+    /// The sequence number of the request that caused this error.
+    pub sequence: u16,
+    /// The value in the request that caused the error.
+    pub bad_value: u32,
+    /// The minor opcode of the request that caused this error.
+    pub minor_opcode: u16,
+    /// The major opcode of the request that caused this error.
+    pub major_opcode: u8,
+}
+
+impl X11Error {
+    /// Parse an X11 error.
+    pub fn try_parse(
+        data: &[u8],
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> Result<Self, ParseError> {
+        Self::try_parse_with_order(data, ext_info_provider, ByteOrder::native())
+    }
+
+    /// Parse an X11 error that is in the given byte order.
+    pub fn try_parse_with_order(
+        data: &[u8],
+        ext_info_provider: &dyn ExtInfoProvider,
+        order: ByteOrder,
+    ) -> Result<Self, ParseError> {
+        let (response_type, remaining) = u8::try_parse_with_order(data, order)?;
+        let (error_code, remaining) = u8::try_parse_with_order(remaining, order)?;
+        let (sequence, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (bad_value, remaining) = u32::try_parse_with_order(remaining, order)?;
+        let (minor_opcode, remaining) = u16::try_parse_with_order(remaining, order)?;
+        let (major_opcode, _) = u8::try_parse_with_order(remaining, order)?;
+        if response_type != 0 {
+            Err(ParseError::InvalidValue)
+        } else {
+            let error_kind = ErrorKind::from_wire_error_code(error_code, ext_info_provider);
+            Ok(X11Error {
+                error_kind,
+                error_code,
+                sequence,
+                bad_value,
+                minor_opcode,
+                major_opcode,
+            })
+        }
+    }
+
+    /// Get the name of the request that caused this error.
+    ///
+    /// The extension that the major opcode belongs to is looked up via `ext_info_provider`.
+    /// Returns `None` if the request is not known.
+    pub fn request_name(&self, ext_info_provider: &dyn ExtInfoProvider) -> Option<&'static str> {
+        request_name(self.major_opcode, self.minor_opcode, ext_info_provider)
+    }
+
+    /// Get a value that displays this error, including the names of extension requests.
+    ///
+    /// The [`Display`](core::fmt::Display) implementation of `X11Error` only knows the names of
+    /// core requests. This uses `ext_info_provider` to also name requests of extensions, e.g.
+    /// `RandR::SetCrtcConfig`.
+    pub fn display_with<'a>(
+        &'a self,
+        ext_info_provider: &'a dyn ExtInfoProvider,
+    ) -> impl core::fmt::Display + 'a {
+        DisplayWith {
+            error: self,
+            ext_info_provider,
+        }
+    }
+
+    fn fmt_with(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        ext_info_provider: &dyn ExtInfoProvider,
+    ) -> core::fmt::Result {
+        match self.error_kind.name() {
+            Some(name) => write!(f, "X11 error {}", name)?,
+            None => write!(f, "X11 error with code {}", self.error_code)?,
+        }
+        match self.request_name(ext_info_provider) {
+            Some(name) => write!(f, " in request {}", name)?,
+            None => write!(
+                f,
+                " in request with major opcode {} and minor opcode {}",
+                self.major_opcode, self.minor_opcode
+            )?,
+        }
+        write!(
+            f,
+            " (sequence number {}, bad value {:#x})",
+            self.sequence, self.bad_value
+        )
+    }
+}
+
+/// The value returned by [`X11Error::display_with`].
+struct DisplayWith<'a> {
+    error: &'a X11Error,
+    ext_info_provider: &'a dyn ExtInfoProvider,
+}
+
+impl core::fmt::Display for DisplayWith<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.error.fmt_with(f, self.ext_info_provider)
+    }
+}
+
+/// An [`ExtInfoProvider`] that does not know any extensions.
+///
+/// This is used for looking up the names of core requests when no information about extensions
+/// is available.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NoExtensions;
+
+impl ExtInfoProvider for NoExtensions {
+    fn get_from_major_opcode(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_event_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+
+    fn get_from_error_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+}
+
+impl core::fmt::Display for X11Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Without information about extensions, only the names of core requests are known
+        self.fmt_with(f, &NoExtensions)
+    }
+}
+
+impl X11Error {
+    /// Serialize this error into the given byte order.
+    pub fn serialize_with_order(&self, order: ByteOrder) -> [u8; 32] {
+        let sequence_bytes = self.sequence.serialize_with_order(order);
+        let bad_value_bytes = self.bad_value.serialize_with_order(order);
+        let minor_opcode_bytes = self.minor_opcode.serialize_with_order(order);
+        [
+            0,
+            self.error_code,
+            sequence_bytes[0],
+            sequence_bytes[1],
+            bad_value_bytes[0],
+            bad_value_bytes[1],
+            bad_value_bytes[2],
+            bad_value_bytes[3],
+            minor_opcode_bytes[0],
+            minor_opcode_bytes[1],
+            self.major_opcode,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        ]
+    }
+}
+
+impl From<&X11Error> for [u8; 32] {
+    fn from(input: &X11Error) -> Self {
+        input.serialize_with_order(ByteOrder::native())
+    }
+}
+impl From<X11Error> for [u8; 32] {
+    fn from(input: X11Error) -> Self {
+        Self::from(&input)
+    }
+}
+
+/// Information about a X11 extension.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExtensionInformation {
+    /// Major opcode used in request
+    pub major_opcode: u8,
+    /// Lowest event number used by the extension.
+    pub first_event: u8,
+    /// Lowest error number used by the extension.
+    pub first_error: u8,
+}
+
+/// Trait to provide information about extensions.
+pub trait ExtInfoProvider {
+    /// Returns the information of the extension that whose
+    /// opcode is `major_opcode`.
+    fn get_from_major_opcode(&self, major_opcode: u8) -> Option<(&str, ExtensionInformation)>;
+
+    /// Returns the information of the extension that whose
+    /// event number range includes `event_number`.
+    fn get_from_event_code(&self, event_code: u8) -> Option<(&str, ExtensionInformation)>;
+
+    /// Returns the information of the extension that whose
+    /// error number range includes `error_number`.
+    fn get_from_error_code(&self, error_code: u8) -> Option<(&str, ExtensionInformation)>;
+
+    /// Returns the information of the extension with the
+    /// name `extension_name`.
+    ///
+    /// The default implementation returns `None`, i.e. it does not know any extensions.
+    fn get_from_extension_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        let _ = extension_name;
+        None
+    }
+}
+
+/// The byte order in which X11 data is encoded.
+///
+/// A client picks the byte order in its `SetupRequest` and everything that it sends to the X11
+/// server and that the server sends back to it is then encoded in this byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// The least significant byte comes first.
+    LittleEndian,
+    /// The most significant byte comes first.
+    BigEndian,
+}
+
+impl ByteOrder {
+    /// Get the byte order of the host that this code is running on.
+    pub fn native() -> Self {
+        if cfg!(target_endian = "little") {
+            ByteOrder::LittleEndian
+        } else {
+            ByteOrder::BigEndian
+        }
+    }
+
+    /// Get the byte order that is announced by the `byte_order` field of a `SetupRequest`.
+    ///
+    /// This is `0x42` (ASCII `'B'`) for big endian and `0x6c` (ASCII `'l'`) for little endian.
+    /// Other values produce a `ParseError::InvalidValue`.
+    pub fn from_setup_request_byte(value: u8) -> Result<Self, ParseError> {
+        match value {
+            b'B' => Ok(ByteOrder::BigEndian),
+            b'l' => Ok(ByteOrder::LittleEndian),
+            _ => Err(ParseError::InvalidValue),
+        }
+    }
+
+    /// Get the value of the `byte_order` field of a `SetupRequest` that announces this byte order.
+    pub fn setup_request_byte(self) -> u8 {
+        match self {
+            ByteOrder::BigEndian => b'B',
+            ByteOrder::LittleEndian => b'l',
+        }
+    }
+}
+
+/// A type implementing this trait can be parsed from some raw bytes.
+pub trait TryParse: Sized {
+    /// Try to parse the given values into an instance of this type.
+    ///
+    /// If parsing is successful, an instance of the type and a slice for the remaining data should
+    /// be returned. Otherwise, an error is returned.
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError>;
+
+    /// Try to parse the given values, which are in the given byte order, into an instance of this
+    /// type.
+    ///
+    /// The default implementation uses `try_parse` for the native byte order and fails with
+    /// `ParseError::UnsupportedByteOrder` otherwise. Types that contain more than single bytes
+    /// should override it.
+    fn try_parse_with_order(value: &[u8], order: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        if order == ByteOrder::native() {
+            Self::try_parse(value)
+        } else {
+            Err(ParseError::UnsupportedByteOrder)
+        }
+    }
+}
+
+/// A type implementing this trait can be parsed from some raw bytes and a list of fds.
+pub trait TryParseFd: Sized {
+    /// Try to parse the given values into an instance of this type.
+    ///
+    /// File descriptors are consumed by removing them from the beginning of the given `fds` `Vec`.
+    /// If a file descriptor is expected, but missing, a `ParseError` should be returned. If more file
+    /// descriptors are provided than expected, this is not an error and the remaining descriptors
+    /// should be left in the `Vec`.
+    ///
+    /// If parsing is successful, an instance of the type and a slice for the remaining data should
+    /// be returned. Otherwise, an error is returned.
+    fn try_parse_fd<'a>(
+        value: &'a [u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<(Self, &'a [u8]), ParseError>;
+
+    /// Try to parse the given values, which are in the given byte order, into an instance of this
+    /// type.
+    ///
+    /// This works like `try_parse_fd`. The default implementation uses `try_parse_fd` for the
+    /// native byte order and fails with `ParseError::UnsupportedByteOrder` otherwise.
+    fn try_parse_fd_with_order<'a>(
+        value: &'a [u8],
+        fds: &mut Vec<RawFdContainer>,
+        order: ByteOrder,
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        if order == ByteOrder::native() {
+            Self::try_parse_fd(value, fds)
+        } else {
+            Err(ParseError::UnsupportedByteOrder)
+        }
+    }
+}
+
+impl<T: TryParse> TryParseFd for T {
+    fn try_parse_fd<'a>(
+        value: &'a [u8],
+        _: &mut Vec<RawFdContainer>,
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        T::try_parse(value)
+    }
+
+    fn try_parse_fd_with_order<'a>(
+        value: &'a [u8],
+        _: &mut Vec<RawFdContainer>,
+        order: ByteOrder,
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        T::try_parse_with_order(value, order)
+    }
+}
+
+/// A representation of the header of a request.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestHeader {
+    /// The major opcode of the request.
+    pub major_opcode: u8,
+    /// The minor opcode of the request (which, for some requests, may not be an
+    /// opcode at all).
+    pub minor_opcode: u8,
+    /// The remaining length of the request, measured in 4 bytes units. Unlike the wire format,
+    /// this does *not* include the header itself, which is 1 unit (or 2 if BigRequests is
+    /// enabled and the length in the first unit is zero). If the BigRequests extension is
+    /// enabled this can be greater than u16::max_value - 1.
+    pub remaining_length: u32,
+}
+
+impl RequestHeader {
+    /// Serialize this header into bytes.
+    ///
+    /// If `remaining_length` does not fit into the normal header, the header is encoded in the
+    /// format of the BigRequests extension.
+    pub fn serialize(self) -> Vec<u8> {
+        self.serialize_with_order(ByteOrder::native())
+    }
+
+    /// Serialize this header into bytes in the given byte order.
+    ///
+    /// If `remaining_length` does not fit into the normal header, the header is encoded in the
+    /// format of the BigRequests extension.
+    pub fn serialize_with_order(self, order: ByteOrder) -> Vec<u8> {
+        let mut result = Vec::with_capacity(8);
+        self.major_opcode
+            .serialize_into_with_order(&mut result, order);
+        self.minor_opcode
+            .serialize_into_with_order(&mut result, order);
+        match u16::try_from(self.remaining_length + 1) {
+            Ok(length) => length.serialize_into_with_order(&mut result, order),
+            Err(_) => {
+                0u16.serialize_into_with_order(&mut result, order);
+                // Adjust length for the size of this header (two 4 byte units).
+                (self.remaining_length + 2).serialize_into_with_order(&mut result, order);
+            }
+        }
+        result
+    }
+}
+
+/// Has the BigRequests extension been enabled?
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigRequests {
+    /// The BigRequests extension has been enabled.
+    Enabled,
+    /// The BigRequests extension has not been enabled.
+    NotEnabled,
+}
+
+/// Parse the given input for a RequestHeader and the remaining input.
+pub fn parse_request_header(
+    input: &[u8],
+    big_requests_enabled: BigRequests,
+) -> Result<(RequestHeader, &[u8]), ParseError> {
+    parse_request_header_with_order(input, big_requests_enabled, ByteOrder::native())
+}
+
+/// Parse the given input, which is in the given byte order, for a RequestHeader and the remaining
+/// input.
+pub fn parse_request_header_with_order(
+    input: &[u8],
+    big_requests_enabled: BigRequests,
+    order: ByteOrder,
+) -> Result<(RequestHeader, &[u8]), ParseError> {
+    let (major_opcode, remaining) = u8::try_parse_with_order(input, order)?;
+    let (minor_opcode, remaining) = u8::try_parse_with_order(remaining, order)?;
+    let (length, remaining) = u16::try_parse_with_order(remaining, order)?;
+    let (remaining_length, finally_remaining) = if length == 0 {
+        if big_requests_enabled == BigRequests::NotEnabled {
+            return Err(ParseError::InvalidValue);
+        }
+
+        let (length, remaining) = u32::try_parse_with_order(remaining, order)?;
+        if length < 2 {
+            return Err(ParseError::InvalidValue);
+        }
+        // Adjust length for the size of this header (two 4 byte units).
+        (length - 2, remaining)
+    } else {
+        // Adjust length for the size of this header (one 4 byte unit).
+        (u32::from(length) - 1, remaining)
+    };
+    Ok((
+        RequestHeader {
+            major_opcode,
+            minor_opcode,
+            remaining_length,
+        },
+        finally_remaining,
+    ))
+}
+
+/// A type implementing this trait is an X11 request.
+pub trait Request {
+    /// The kind of reply that this request generates.
+    type Reply: Into<crate::protocol::Reply> + TryParseFd;
+
+    /// Parse a reply to this request.
+    ///
+    /// The default implementation of this function uses `Self::Reply::try_parse_fd`. There should
+    /// not be a reason why you need different behaviour.
+    fn parse_reply<'a>(
+        bytes: &'a [u8],
+        fds: &mut Vec<RawFdContainer>,
+    ) -> Result<(crate::protocol::Reply, &'a [u8]), ParseError> {
+        let (reply, remaining) = Self::Reply::try_parse_fd(bytes, fds)?;
+        Ok((reply.into(), remaining))
+    }
+}
+
+/// A type alias for reply parsers (matches the signature of TryParseFd).
+pub type ReplyParsingFunction =
+    for<'a> fn(
+        &'a [u8],
+        &mut Vec<RawFdContainer>,
+    ) -> Result<(crate::protocol::Reply, &'a [u8]), ParseError>;
+
+/// A type implementing this trait can be serialized into X11 raw bytes.
+pub trait Serialize {
+    /// The value returned by `serialize`.
+    ///
+    /// This should be `Vec<u8>` in most cases. However, arrays like `[u8; 4]` should also be
+    /// allowed and thus this is an associated type.
+    ///
+    /// If generic associated types were available, implementing `AsRef<[u8]>` would be required.
+    type Bytes;
+
+    /// Serialize this value into X11 raw bytes.
+    fn serialize(&self) -> Self::Bytes;
+
+    /// Serialize this value into X11 raw bytes, appending the result into `bytes`.
+    ///
+    /// When calling this method, the given vector must satisfy `assert_eq!(bytes.len() % 4, 0);`.
+    /// In words: Its length must be a multiple of four.
+    fn serialize_into(&self, bytes: &mut Vec<u8>);
+
+    /// Serialize this value into X11 raw bytes in the given byte order.
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes;
+
+    /// Serialize this value into X11 raw bytes in the given byte order, appending the result into
+    /// `bytes`.
+    ///
+    /// This has the same requirements as `serialize_into`.
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder);
+}
+
+// Now implement TryParse and Serialize for some primitive data types that we need.
+
+macro_rules! implement_try_parse {
+    ($t:ty) => {
+        impl TryParse for $t {
+            fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+                Self::try_parse_with_order(value, ByteOrder::native())
+            }
+            fn try_parse_with_order(
+                value: &[u8],
+                order: ByteOrder,
+            ) -> Result<(Self, &[u8]), ParseError> {
+                let len = core::mem::size_of::<$t>();
+                let bytes = value
+                    .get(..len)
+                    .ok_or(ParseError::InsufficientData)?
+                    .try_into() // TryInto<[u8; len]>
+                    .unwrap();
+                let result = match order {
+                    ByteOrder::LittleEndian => <$t>::from_le_bytes(bytes),
+                    ByteOrder::BigEndian => <$t>::from_be_bytes(bytes),
+                };
+                Ok((result, &value[len..]))
+            }
+        }
+    };
+}
+
+macro_rules! implement_serialize {
+    ($t:ty: $size:expr) => {
+        impl Serialize for $t {
+            type Bytes = [u8; $size];
+            fn serialize(&self) -> Self::Bytes {
+                self.to_ne_bytes()
+            }
+            fn serialize_into(&self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_ne_bytes());
+            }
+            fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+                match order {
+                    ByteOrder::LittleEndian => self.to_le_bytes(),
+                    ByteOrder::BigEndian => self.to_be_bytes(),
+                }
+            }
+            fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+                bytes.extend_from_slice(&self.serialize_with_order(order));
+            }
+        }
+    };
+}
+
+macro_rules! forward_float {
+    ($from:ty: $to:ty) => {
+        impl TryParse for $from {
+            fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+                Self::try_parse_with_order(value, ByteOrder::native())
+            }
+            fn try_parse_with_order(
+                value: &[u8],
+                order: ByteOrder,
+            ) -> Result<(Self, &[u8]), ParseError> {
+                let (data, remaining) = <$to>::try_parse_with_order(value, order)?;
+                Ok((<$from>::from_bits(data), remaining))
+            }
+        }
+        impl Serialize for $from {
+            type Bytes = <$to as Serialize>::Bytes;
+            fn serialize(&self) -> Self::Bytes {
+                self.to_bits().serialize()
+            }
+            fn serialize_into(&self, bytes: &mut Vec<u8>) {
+                self.to_bits().serialize_into(bytes);
+            }
+            fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+                self.to_bits().serialize_with_order(order)
+            }
+            fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+                self.to_bits().serialize_into_with_order(bytes, order);
+            }
+        }
+    };
+}
+
+implement_try_parse!(u8);
+implement_try_parse!(i8);
+implement_try_parse!(u16);
+implement_try_parse!(i16);
+implement_try_parse!(u32);
+implement_try_parse!(i32);
+implement_try_parse!(u64);
+implement_try_parse!(i64);
+
+implement_serialize!(u8: 1);
+implement_serialize!(i8: 1);
+implement_serialize!(u16: 2);
+implement_serialize!(i16: 2);
+implement_serialize!(u32: 4);
+implement_serialize!(i32: 4);
+implement_serialize!(u64: 8);
+implement_serialize!(i64: 8);
+
+forward_float!(f32: u32);
+forward_float!(f64: u64);
+
+impl TryParse for bool {
+    fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), ParseError> {
+        let (data, remaining) = u8::try_parse(value)?;
+        Ok((data != 0, remaining))
+    }
+    fn try_parse_with_order(value: &[u8], _: ByteOrder) -> Result<(Self, &[u8]), ParseError> {
+        Self::try_parse(value)
+    }
+}
+
+impl Serialize for bool {
+    type Bytes = [u8; 1];
+    fn serialize(&self) -> Self::Bytes {
+        [u8::from(*self)]
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        bytes.push(u8::from(*self));
+    }
+    fn serialize_with_order(&self, _: ByteOrder) -> Self::Bytes {
+        self.serialize()
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, _: ByteOrder) {
+        self.serialize_into(bytes)
+    }
+}
+
+/// Define a distinct type for an XID type of the protocol.
+///
+/// This is used by code that was generated with the `--xid-newtypes` flag of the code generator.
+/// Otherwise, XID types are aliases of `u32`.
+#[allow(unused_macros)]
+macro_rules! xid_newtype {
+    ($name:ident) => {
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        #[repr(transparent)]
+        pub struct $name(pub u32);
+
+        impl From<u32> for $name {
+            #[inline]
+            fn from(value: u32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for u32 {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl crate::x11_utils::TryParse for $name {
+            fn try_parse(value: &[u8]) -> Result<(Self, &[u8]), crate::errors::ParseError> {
+                let (value, remaining) = u32::try_parse(value)?;
+                Ok((Self(value), remaining))
+            }
+            fn try_parse_with_order(
+                value: &[u8],
+                order: crate::x11_utils::ByteOrder,
+            ) -> Result<(Self, &[u8]), crate::errors::ParseError> {
+                let (value, remaining) = u32::try_parse_with_order(value, order)?;
+                Ok((Self(value), remaining))
+            }
+        }
+
+        impl crate::x11_utils::Serialize for $name {
+            type Bytes = [u8; 4];
+            fn serialize(&self) -> Self::Bytes {
+                self.0.serialize()
+            }
+            fn serialize_into(&self, bytes: &mut alloc::vec::Vec<u8>) {
+                self.0.serialize_into(bytes);
+            }
+            fn serialize_with_order(&self, order: crate::x11_utils::ByteOrder) -> Self::Bytes {
+                self.0.serialize_with_order(order)
+            }
+            fn serialize_into_with_order(
+                &self,
+                bytes: &mut alloc::vec::Vec<u8>,
+                order: crate::x11_utils::ByteOrder,
+            ) {
+                self.0.serialize_into_with_order(bytes, order);
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+/// Define a distinct type for an XID union of the protocol.
+///
+/// In addition to [`xid_newtype`], this provides conversions from the types in the union.
+#[allow(unused_macros)]
+macro_rules! xid_union_newtype {
+    ($name:ident: $($member:ty),*) => {
+        xid_newtype!($name);
+
+        $(
+            impl From<$member> for $name {
+                #[inline]
+                fn from(value: $member) -> Self {
+                    Self(value.0)
+                }
+            }
+        )*
+    };
+}
+
+// Tuple handling
+
+macro_rules! tuple_try_parse {
+    ($($name:ident)*) => {
+        impl<$($name,)*> TryParse for ($($name,)*)
+        where $($name: TryParse,)*
+        {
+            #[allow(non_snake_case)]
+            fn try_parse(remaining: &[u8]) -> Result<(($($name,)*), &[u8]), ParseError> {
+                $(let ($name, remaining) = $name::try_parse(remaining)?;)*
+                Ok((($($name,)*), remaining))
+            }
+            #[allow(non_snake_case, unused_variables)]
+            fn try_parse_with_order(
+                remaining: &[u8],
+                order: ByteOrder,
+            ) -> Result<(($($name,)*), &[u8]), ParseError> {
+                $(let ($name, remaining) = $name::try_parse_with_order(remaining, order)?;)*
+                Ok((($($name,)*), remaining))
+            }
+        }
+    }
+}
+
+macro_rules! tuple_serialize {
+    ($($name:ident:$idx:tt)*) => {
+        impl<$($name,)*> Serialize for ($($name,)*)
+        where $($name: Serialize,)*
+        {
+            type Bytes = Vec<u8>;
+            fn serialize(&self) -> Self::Bytes {
+                let mut result = Vec::new();
+                self.serialize_into(&mut result);
+                result
+            }
+            fn serialize_into(&self, bytes: &mut Vec<u8>) {
+                $(self.$idx.serialize_into(bytes);)*
+            }
+            fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+                let mut result = Vec::new();
+                self.serialize_into_with_order(&mut result, order);
+                result
+            }
+            fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+                $(self.$idx.serialize_into_with_order(bytes, order);)*
+            }
+        }
+    }
+}
+
+macro_rules! tuple_impls {
+    ($($name:ident:$idx:tt)*) => {
+        tuple_try_parse!($($name)*);
+        tuple_serialize!($($name:$idx)*);
+    }
+}
+
+// We can optimise serialisation of empty tuples or one-element-tuples with different Bytes type
+impl Serialize for () {
+    type Bytes = [u8; 0];
+    fn serialize(&self) -> Self::Bytes {
+        []
+    }
+    fn serialize_into(&self, _bytes: &mut Vec<u8>) {}
+    fn serialize_with_order(&self, _: ByteOrder) -> Self::Bytes {
+        []
+    }
+    fn serialize_into_with_order(&self, _bytes: &mut Vec<u8>, _: ByteOrder) {}
+}
+
+impl<T: Serialize> Serialize for (T,) {
+    type Bytes = T::Bytes;
+    fn serialize(&self) -> Self::Bytes {
+        self.0.serialize()
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        self.0.serialize_into(bytes)
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+        self.0.serialize_with_order(order)
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        self.0.serialize_into_with_order(bytes, order)
+    }
+}
+
+tuple_try_parse!();
+tuple_try_parse!(A);
+tuple_impls!(A:0 B:1);
+tuple_impls!(A:0 B:1 C:2);
+tuple_impls!(A:0 B:1 C:2 D:3);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12 N:13);
+tuple_impls!(A:0 B:1 C:2 D:3 E:4 F:5 G:6 H:7 I:8 J:9 K:10 L:11 M:12 N:13 O:14);
+
+/// Parse a list of objects from the given data.
+///
+/// This function parses a list of objects where the length of the list was specified externally.
+/// The wire format for `list_length` instances of `T` will be read from the given data.
+pub fn parse_list<T>(data: &[u8], list_length: usize) -> Result<(Vec<T>, &[u8]), ParseError>
+where
+    T: TryParse,
+{
+    parse_list_with_order(data, list_length, ByteOrder::native())
+}
+
+/// Parse a list of objects that are in the given byte order from the given data.
+///
+/// This works like [`parse_list`], but handles data in either byte order.
+pub fn parse_list_with_order<T>(
+    data: &[u8],
+    list_length: usize,
+    order: ByteOrder,
+) -> Result<(Vec<T>, &[u8]), ParseError>
+where
+    T: TryParse,
+{
+    let mut remaining = data;
+    let mut result = Vec::with_capacity(list_length);
+    for _ in 0..list_length {
+        let (entry, new_remaining) = T::try_parse_with_order(remaining, order)?;
+        result.push(entry);
+        remaining = new_remaining;
+    }
+    Ok((result, remaining))
+}
+
+/// Parse a list of `u8` from the given data.
+pub fn parse_u8_list(data: &[u8], list_length: usize) -> Result<(&[u8], &[u8]), ParseError> {
+    if data.len() < list_length {
+        Err(ParseError::InsufficientData)
+    } else {
+        Ok(data.split_at(list_length))
+    }
+}
+
+/// Pad a serialized reply and fill in its length field.
+///
+/// `bytes[start..]` must contain the serialized fields of a reply. Replies are at least 32 bytes
+/// long and their size is a multiple of four. The length field at offset 4 contains the number of
+/// four byte units after the first 32 bytes. It is written in the given byte order.
+pub(crate) fn finish_reply_serialization(bytes: &mut Vec<u8>, start: usize, order: ByteOrder) {
+    let size = (bytes.len() - start).max(32);
+    let size = (size + 3) / 4 * 4;
+    bytes.resize(start + size, 0);
+    let length: u32 = ((size - 32) / 4)
+        .try_into()
+        .expect("the reply is too large");
+    bytes[start + 4..start + 8].copy_from_slice(&length.serialize_with_order(order));
+}
+
+impl<T: Serialize> Serialize for [T] {
+    type Bytes = Vec<u8>;
+    fn serialize(&self) -> Self::Bytes {
+        let mut result = Vec::new();
+        self.serialize_into(&mut result);
+        result
+    }
+    fn serialize_into(&self, bytes: &mut Vec<u8>) {
+        for item in self {
+            item.serialize_into(bytes);
+        }
+    }
+    fn serialize_with_order(&self, order: ByteOrder) -> Self::Bytes {
+        let mut result = Vec::new();
+        self.serialize_into_with_order(&mut result, order);
+        result
+    }
+    fn serialize_into_with_order(&self, bytes: &mut Vec<u8>, order: ByteOrder) {
+        for item in self {
+            item.serialize_into_with_order(bytes, order);
+        }
+    }
+}
+
+// This macro is used by the generated code to implement `core::ops::BitOr` and
+// `core::ops::BitOrAssign`.
+macro_rules! bitmask_binop {
+    ($t:ty, $u:ty) => {
+        impl core::ops::BitOr for $t {
+            type Output = $t;
+            fn bitor(self, other: Self) -> Self::Output {
+                Self::from(<$u>::from(self) | <$u>::from(other))
+            }
+        }
+        impl core::ops::BitOr<$u> for $t {
+            type Output = $t;
+            fn bitor(self, other: $u) -> Self::Output {
+                self | Self::from(other)
+            }
+        }
+        impl core::ops::BitOr<$t> for $u {
+            type Output = $t;
+            fn bitor(self, other: $t) -> Self::Output {
+                <$t>::from(self) | other
+            }
+        }
+        impl core::ops::BitOrAssign<$t> for $u {
+            fn bitor_assign(&mut self, other: $t) {
+                *self |= Self::from(other)
+            }
+        }
+        impl core::ops::BitOrAssign<$u> for $t {
+            fn bitor_assign(&mut self, other: $u) {
+                *self = *self | Self::from(other)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ByteOrder, Serialize, TryParse};
+    use alloc::format;
+
+    xid_newtype!(Window);
+    xid_newtype!(Pixmap);
+    xid_union_newtype!(Drawable: Window, Pixmap);
+
+    #[test]
+    fn xid_union_conversions() {
+        assert_eq!(Drawable::from(Window(1)), Drawable(1));
+        assert_eq!(Drawable::from(Pixmap(2)), Drawable(2));
+        assert_eq!(u32::from(Window::from(3)), 3);
+    }
+
+    #[test]
+    fn xid_wire_format() {
+        let window = Window(0x0060_0009);
+        assert_eq!(window.serialize(), 0x0060_0009u32.serialize());
+        assert_eq!(
+            window.serialize_with_order(ByteOrder::BigEndian),
+            [0x00, 0x60, 0x00, 0x09]
+        );
+        assert_eq!(
+            Window::try_parse(&window.serialize()),
+            Ok((window, &[][..]))
+        );
+        assert_eq!(format!("{} {:#x}", window, window), "6291465 0x600009");
+    }
+}
@@ -0,0 +1,130 @@
+use std::borrow::Cow;
+
+use x11rb_protocol::errors::ParseError;
+use x11rb_protocol::protocol::xproto::{
+    ConfigureWindowAux, ConfigureWindowRequest, ExposeEvent, SetupRequest, StackMode, Window,
+    EXPOSE_EVENT,
+};
+use x11rb_protocol::protocol::{Event, Request};
+use x11rb_protocol::x11_utils::{
+    parse_request_header_with_order, BigRequests, ByteOrder, ExtInfoProvider, ExtensionInformation,
+    Serialize, TryParse,
+};
+
+struct NoExtensions;
+
+impl ExtInfoProvider for NoExtensions {
+    fn get_from_major_opcode(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+    fn get_from_event_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+    fn get_from_error_code(&self, _: u8) -> Option<(&str, ExtensionInformation)> {
+        None
+    }
+}
+
+#[test]
+fn setup_request_byte() {
+    assert_eq!(
+        ByteOrder::from_setup_request_byte(b'B'),
+        Ok(ByteOrder::BigEndian)
+    );
+    assert_eq!(
+        ByteOrder::from_setup_request_byte(b'l'),
+        Ok(ByteOrder::LittleEndian)
+    );
+    assert_eq!(
+        ByteOrder::from_setup_request_byte(b'x'),
+        Err(ParseError::InvalidValue)
+    );
+    for order in &[ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+        assert_eq!(
+            ByteOrder::from_setup_request_byte(order.setup_request_byte()),
+            Ok(*order)
+        );
+    }
+}
+
+#[test]
+fn parse_big_endian_setup_request() {
+    let data = [
+        b'B', 0, 0, 11, 0, 0, 0, 4, 0, 3, 0, 0, b'a', b'b', b'c', b'd', 1, 2, 3, 0,
+    ];
+    let (request, remaining) =
+        SetupRequest::try_parse_with_order(&data, ByteOrder::BigEndian).unwrap();
+    assert!(remaining.is_empty());
+    assert_eq!(request.byte_order, b'B');
+    assert_eq!(
+        (
+            request.protocol_major_version,
+            request.protocol_minor_version
+        ),
+        (11, 0)
+    );
+    assert_eq!(request.authorization_protocol_name, b"abcd");
+    assert_eq!(request.authorization_protocol_data, [1, 2, 3]);
+    assert_eq!(request.serialize_with_order(ByteOrder::BigEndian), data);
+}
+
+#[test]
+fn parse_big_endian_request() {
+    let data = [
+        12, 0, 0, 5, // header: ConfigureWindow, length 5
+        0, 0, 1, 2, // window
+        0, 0x40, 0, 0, // value_mask: StackMode, padding
+        0, 0, 0, 3, // list of values: BottomIf
+        0, 0, 0, 0, // extra data
+    ];
+    let (header, body) =
+        parse_request_header_with_order(&data, BigRequests::NotEnabled, ByteOrder::BigEndian)
+            .unwrap();
+    assert_eq!((header.major_opcode, header.remaining_length), (12, 4));
+    let request = Request::parse_with_order(
+        header,
+        body,
+        &mut Vec::new(),
+        &NoExtensions,
+        ByteOrder::BigEndian,
+    )
+    .unwrap();
+    let expected = ConfigureWindowRequest {
+        window: Window::from(0x0102u32),
+        value_list: Cow::Owned(ConfigureWindowAux::new().stack_mode(StackMode::BOTTOM_IF)),
+    };
+    match request {
+        Request::ConfigureWindow(request) => assert_eq!(request, expected),
+        request => panic!("Unexpected request {:?}", request),
+    }
+}
+
+#[test]
+fn event_round_trip() {
+    let event = ExposeEvent {
+        response_type: EXPOSE_EVENT,
+        sequence: 0x1234,
+        window: Window::from(0x0506_0708u32),
+        x: 1,
+        y: 2,
+        width: 300,
+        height: 400,
+        count: 5,
+    };
+    for order in &[ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+        let data = event.serialize_with_order(*order);
+        let parsed = Event::parse_with_order(&data, &NoExtensions, *order).unwrap();
+        match parsed {
+            Event::Expose(parsed) => assert_eq!(parsed, event),
+            parsed => panic!("Unexpected event {:?}", parsed),
+        }
+    }
+    assert_eq!(
+        event.serialize_with_order(ByteOrder::BigEndian)[4..8],
+        [5, 6, 7, 8]
+    );
+    assert_eq!(
+        event.serialize_with_order(ByteOrder::LittleEndian)[4..8],
+        [8, 7, 6, 5]
+    );
+}
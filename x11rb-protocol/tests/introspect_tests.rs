@@ -0,0 +1,139 @@
+use std::borrow::Cow;
+
+use x11rb_protocol::introspect::{FieldInfo, FieldKind, ValueSet};
+use x11rb_protocol::protocol::xproto::{
+    self, ConfigureWindowAux, ConfigureWindowRequest, ExposeEvent, KeyPressEvent, Window,
+    EXPOSE_EVENT, KEY_RELEASE_EVENT,
+};
+use x11rb_protocol::protocol::{Event, Request};
+use x11rb_protocol::x11_utils::RequestHeader;
+
+fn field<'a>(fields: &'a [FieldInfo], name: &str) -> &'a FieldInfo {
+    fields.iter().find(|field| field.name == name).unwrap()
+}
+
+#[test]
+fn request_info() {
+    let request = Request::ConfigureWindow(ConfigureWindowRequest {
+        window: Window::from(1u32),
+        value_list: Cow::Owned(ConfigureWindowAux::new()),
+    });
+    let info = request.info().unwrap();
+    assert_eq!(info.extension, None);
+    assert_eq!(info.name, "ConfigureWindow");
+    assert_eq!(info.opcode, xproto::CONFIGURE_WINDOW_REQUEST);
+    assert_eq!(info.reply_fields, None);
+
+    let names = info
+        .fields
+        .iter()
+        .map(|field| field.name)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "major_opcode",
+            "length",
+            "window",
+            "value_mask",
+            "value_list"
+        ]
+    );
+
+    match field(info.fields, "value_mask").kind {
+        FieldKind::Value(type_info) => match type_info.value_set {
+            ValueSet::Mask(enum_info) => {
+                assert_eq!(enum_info.name, "ConfigWindow");
+                assert!(enum_info
+                    .variants
+                    .contains(&(1 << 6, "STACK_MODE", "StackMode")));
+            }
+            value_set => panic!("Unexpected value set {:?}", value_set),
+        },
+        kind => panic!("Unexpected kind {:?}", kind),
+    }
+    match field(info.fields, "value_list").kind {
+        FieldKind::Switch(cases) => {
+            assert_eq!(cases.len(), 7);
+            let sibling = field(cases[5].fields, "sibling");
+            match sibling.kind {
+                FieldKind::Value(type_info) => assert_eq!(type_info.name, "WINDOW"),
+                kind => panic!("Unexpected kind {:?}", kind),
+            }
+        }
+        kind => panic!("Unexpected kind {:?}", kind),
+    }
+
+    let header = RequestHeader {
+        major_opcode: 200,
+        minor_opcode: 0,
+        remaining_length: 0,
+    };
+    assert_eq!(Request::Unknown(header, Cow::Borrowed(&[])).info(), None);
+}
+
+#[test]
+fn reply_fields() {
+    let info = xproto::REQUEST_INFOS
+        .iter()
+        .find(|info| info.name == "GetProperty")
+        .unwrap();
+    let reply_fields = info.reply_fields.unwrap();
+    match field(reply_fields, "value").kind {
+        FieldKind::List(type_info) => assert_eq!(type_info.name, "void"),
+        kind => panic!("Unexpected kind {:?}", kind),
+    }
+}
+
+#[test]
+fn event_info() {
+    let event = Event::Expose(ExposeEvent {
+        response_type: EXPOSE_EVENT,
+        sequence: 0,
+        window: Window::from(1u32),
+        x: 0,
+        y: 0,
+        width: 0,
+        height: 0,
+        count: 0,
+    });
+    let info = event.info().unwrap();
+    assert_eq!((info.extension, info.name), (None, "Expose"));
+    assert_eq!(info.number, u16::from(EXPOSE_EVENT));
+    assert!(!info.xge);
+    assert!(info.fields.iter().any(|field| field.name == "count"));
+
+    // Copies of events get their own description
+    let event = Event::KeyRelease(KeyPressEvent {
+        response_type: KEY_RELEASE_EVENT,
+        detail: 0,
+        sequence: 0,
+        time: 0,
+        root: Window::from(1u32),
+        event: Window::from(1u32),
+        child: Window::from(0u32),
+        root_x: 0,
+        root_y: 0,
+        event_x: 0,
+        event_y: 0,
+        state: 0,
+        same_screen: true,
+    });
+    let info = event.info().unwrap();
+    assert_eq!(info.name, "KeyRelease");
+    assert_eq!(info.number, u16::from(KEY_RELEASE_EVENT));
+
+    assert_eq!(Event::Unknown(vec![0; 32]).info(), None);
+}
+
+#[test]
+fn tables_are_complete() {
+    for info in xproto::REQUEST_INFOS.iter() {
+        assert_eq!(info.extension, None);
+        assert!(!info.fields.is_empty());
+    }
+    assert!(xproto::REQUEST_INFOS
+        .windows(2)
+        .all(|infos| infos[0].opcode < infos[1].opcode));
+    assert_eq!(xproto::REQUEST_INFOS.len(), 120);
+}
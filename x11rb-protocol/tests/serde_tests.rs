@@ -0,0 +1,71 @@
+#![cfg(feature = "serde")]
+
+use serde::de::value::{Error, MapDeserializer, StrDeserializer, U64Deserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::{Deserialize, Serialize};
+
+use x11rb_protocol::protocol::xproto::{
+    ClientMessageData, EventMask, ExposeEvent, GetGeometryReply, KeyButMask, ModMask, Point,
+    Screen, VisualClass,
+};
+
+fn from_str<T: DeserializeOwned>(value: &str) -> Result<T, Error> {
+    let deserializer: StrDeserializer<'_, Error> = value.into_deserializer();
+    T::deserialize(deserializer)
+}
+
+fn from_number<T: DeserializeOwned>(value: u64) -> Result<T, Error> {
+    let deserializer: U64Deserializer<Error> = value.into_deserializer();
+    T::deserialize(deserializer)
+}
+
+#[test]
+fn implemented_for_protocol_types() {
+    fn check<T: Serialize + DeserializeOwned>() {}
+
+    check::<Point>();
+    check::<Screen>();
+    check::<ExposeEvent>();
+    check::<GetGeometryReply>();
+    check::<ClientMessageData>();
+    check::<VisualClass>();
+    check::<EventMask>();
+}
+
+#[test]
+fn enum_from_name() {
+    assert_eq!(from_str("TRUE_COLOR"), Ok(VisualClass::TRUE_COLOR));
+    assert_eq!(from_str("TrueColor"), Ok(VisualClass::TRUE_COLOR));
+    assert_eq!(from_str("42"), Ok(VisualClass::from(42)));
+    assert!(from_str::<VisualClass>("TRUE_COLOUR").is_err());
+}
+
+#[test]
+fn enum_from_number() {
+    assert_eq!(from_number(4), Ok(VisualClass::TRUE_COLOR));
+    // VisualClass is a u8 on the wire
+    assert!(from_number::<VisualClass>(256).is_err());
+}
+
+#[test]
+fn bitmask_from_names() {
+    assert_eq!(from_str("SHIFT"), Ok(ModMask::SHIFT));
+    assert_eq!(from_str("SHIFT | LOCK"), Ok(ModMask::SHIFT | ModMask::LOCK));
+    assert_eq!(
+        from_str("BUTTON1 | CONTROL"),
+        Ok(KeyButMask::BUTTON1 | KeyButMask::CONTROL)
+    );
+    assert_eq!(
+        from_str("32768 | KEY_PRESS"),
+        Ok(EventMask::from(1u32 << 15) | EventMask::KEY_PRESS)
+    );
+    assert_eq!(from_number(3), Ok(ModMask::SHIFT | ModMask::LOCK));
+    assert!(from_str::<ModMask>("SHIFT | SUPER").is_err());
+}
+
+#[test]
+fn struct_from_map() {
+    let map = vec![("x", 10i16), ("y", -3)];
+    let deserializer: MapDeserializer<'_, _, Error> = MapDeserializer::new(map.into_iter());
+    assert_eq!(Point::deserialize(deserializer), Ok(Point { x: 10, y: -3 }));
+}
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+use x11rb_protocol::protocol::xproto::{
+    Atom, ChangeWindowAttributesAux, ChangeWindowAttributesRequest, ConfigureWindowAux,
+    ConfigureWindowRequest, Drawable, EventMask, ExposeEvent, Gcontext, GetPropertyRequest,
+    InternAtomReply, InternAtomRequest, Point, PolyPointRequest, StackMode, Window, EXPOSE_EVENT,
+};
+use x11rb_protocol::protocol::{Event, Reply, Request};
+use x11rb_protocol::trace::Trace;
+
+fn atom_names(atom: u32) -> Option<String> {
+    match atom {
+        300 => Some("_NET_WM_NAME".to_string()),
+        301 => Some("UTF8_STRING".to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn request_with_string() {
+    let request = Request::InternAtom(InternAtomRequest {
+        only_if_exists: true,
+        name: Cow::Borrowed(b"it's\\\n"),
+    });
+    assert_eq!(
+        request.traced().to_string(),
+        r"Request InternAtom only-if-exists=true name='it\'s\\\x0a'",
+    );
+}
+
+#[test]
+fn request_with_enum_and_xid() {
+    let request = Request::ConfigureWindow(ConfigureWindowRequest {
+        window: Window::from(0x0102u32),
+        value_list: Cow::Owned(
+            ConfigureWindowAux::new()
+                .x(-5)
+                .sibling(Window::from(0x0400_0001u32))
+                .stack_mode(StackMode::BOTTOM_IF),
+        ),
+    });
+    assert_eq!(
+        request.traced().to_string(),
+        "Request ConfigureWindow window=0x00000102 \
+         value-list={x=-5 sibling=0x04000001 stack-mode=BottomIf}",
+    );
+}
+
+#[test]
+fn request_with_bitmask() {
+    let event_mask = EventMask::KEY_PRESS | EventMask::EXPOSURE;
+    let request = ChangeWindowAttributesRequest {
+        window: Window::from(1u32),
+        value_list: Cow::Owned(ChangeWindowAttributesAux::new().event_mask(event_mask)),
+    };
+    assert_eq!(
+        request.traced().to_string(),
+        "window=0x00000001 value-list={event-mask=KeyPress|Exposure}",
+    );
+
+    let unknown_bit = u32::from(EventMask::KEY_PRESS) | 1 << 30;
+    let request = ChangeWindowAttributesRequest {
+        window: Window::from(1u32),
+        value_list: Cow::Owned(ChangeWindowAttributesAux::new().event_mask(unknown_bit)),
+    };
+    assert_eq!(
+        request.traced().to_string(),
+        "window=0x00000001 value-list={event-mask=0x40000000|KeyPress}",
+    );
+}
+
+#[test]
+fn shortened_list() {
+    let points = (0..10).map(|i| Point { x: i, y: -i }).collect::<Vec<_>>();
+    let request = PolyPointRequest {
+        coordinate_mode: 0.into(),
+        drawable: Drawable::from(2u32),
+        gc: Gcontext::from(3u32),
+        points: Cow::Owned(points[..2].to_vec()),
+    };
+    assert_eq!(
+        request.traced().to_string(),
+        "coordinate-mode=Origin drawable=0x00000002 gc=0x00000003 points=[{x=0 y=0},{x=1 y=-1}]",
+    );
+
+    let request = PolyPointRequest {
+        points: Cow::Owned(points),
+        ..request
+    };
+    assert!(request.traced().to_string().ends_with(
+        "points=[{x=0 y=0},{x=1 y=-1},{x=2 y=-2},{x=3 y=-3},{x=4 y=-4},{x=5 y=-5},{x=6 y=-6},\
+         {x=7 y=-7},...; 10 items]"
+    ));
+}
+
+#[test]
+fn atom_names_callback() {
+    let request = GetPropertyRequest {
+        delete: false,
+        window: Window::from(0x0020_0001u32),
+        property: Atom::from(300u32),
+        type_: Atom::from(301u32),
+        long_offset: 0,
+        long_length: 1024,
+    };
+    assert_eq!(
+        request.traced().to_string(),
+        "delete=false window=0x00200001 property=0x12c type=0x12d long-offset=0 long-length=1024",
+    );
+    assert_eq!(
+        request.traced_with_atom_names(&atom_names).to_string(),
+        "delete=false window=0x00200001 property=0x12c(\"_NET_WM_NAME\") \
+         type=0x12d(\"UTF8_STRING\") long-offset=0 long-length=1024",
+    );
+
+    // Predefined atoms are known without a callback, but the callback takes precedence
+    let request = GetPropertyRequest {
+        property: Atom::from(39u32),
+        type_: Atom::from(0u32),
+        ..request
+    };
+    assert!(request
+        .traced()
+        .to_string()
+        .contains(" property=0x27(\"WM_NAME\") type=0x0 "));
+    let renamed = |atom| {
+        if atom == 39 {
+            Some("X".to_string())
+        } else {
+            None
+        }
+    };
+    assert!(request
+        .traced_with_atom_names(&renamed)
+        .to_string()
+        .contains(" property=0x27(\"X\") "));
+}
+
+#[test]
+fn reply() {
+    let reply = Reply::InternAtom(InternAtomReply {
+        sequence: 10,
+        length: 0,
+        atom: Atom::from(300u32),
+    });
+    assert_eq!(
+        reply.traced_with_atom_names(&atom_names).to_string(),
+        "Reply to InternAtom: sequence=10 atom=0x12c(\"_NET_WM_NAME\")",
+    );
+    assert_eq!(Reply::Void.traced().to_string(), "Empty reply");
+}
+
+#[test]
+fn event() {
+    let event = Event::Expose(ExposeEvent {
+        response_type: EXPOSE_EVENT,
+        sequence: 0x1234,
+        window: Window::from(0x0506_0708u32),
+        x: 1,
+        y: 2,
+        width: 300,
+        height: 400,
+        count: 5,
+    });
+    assert_eq!(
+        event.traced().to_string(),
+        "Event Expose sequence=4660 window=0x05060708 x=1 y=2 width=300 height=400 count=5",
+    );
+
+    let event = Event::Unknown(vec![200; 32]);
+    assert_eq!(
+        event.traced().to_string(),
+        "Event Unknown data=[200,200,200,200,200,200,200,200,...; 32 items]",
+    );
+}
@@ -1,11 +1,11 @@
 use x11rb::errors::ParseError;
 use x11rb::protocol::{xproto, Event, Reply, Request};
+use x11rb::trace::Trace;
 use x11rb::x11_utils::{
-    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, ReplyParsingFunction,
-    TryParse, X11Error,
+    parse_request_header, BigRequests, ExtInfoProvider, ExtensionInformation, TryParse, X11Error,
 };
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::convert::TryInto;
 
 /// Parse some data and print the resulting object.
@@ -41,6 +41,10 @@ pub struct ConnectionInner {
 
     /// Requests which were not yet answered by the X11 server.
     pending_replies: VecDeque<PendingReply>,
+
+    /// Names of atoms that were seen in `InternAtom` and `GetAtomName` requests and their
+    /// replies. This is used for showing atoms with their names.
+    atom_names: HashMap<u32, String>,
 }
 
 impl ConnectionInner {
@@ -94,7 +98,11 @@ impl ConnectionInner {
 
             let (header, remaining) = parse_request_header(packet, BigRequests::Enabled)?;
             let request = Request::parse(header, remaining, &mut Vec::new(), &inner.ext_info)?;
-            println!("client ({}): {:?}", seqno, request);
+            println!(
+                "client ({}): {}",
+                seqno,
+                request.traced_with_atom_names(&|atom| inner.atom_name(atom)),
+            );
 
             // Is this a QueryExtension?
             let queried_extension = if let Request::QueryExtension(ref request) = request {
@@ -113,10 +121,10 @@ impl ConnectionInner {
             };
 
             // Does the request have a reply? If so, remember it.
-            if let Some(parser) = request.reply_parser() {
+            if request.reply_parser().is_some() {
                 inner.pending_replies.push_back(PendingReply {
                     seqno,
-                    parser,
+                    request: request.into_owned(),
                     queried_extension,
                 });
             }
@@ -132,7 +140,11 @@ impl ConnectionInner {
     pub fn server_error(&mut self, packet: &[u8]) {
         fn do_parse(inner: &mut ConnectionInner, packet: &[u8]) -> Result<(), ParseError> {
             let err = X11Error::try_parse(packet, &inner.ext_info)?;
-            println!("server ({}): {:?}", err.sequence, err);
+            println!(
+                "server ({}): {}",
+                err.sequence,
+                err.traced_with(&inner.ext_info)
+            );
 
             // Remove a pending request if it failed
             let next_pending = inner.pending_replies.front().map(|r| r.seqno);
@@ -152,9 +164,9 @@ impl ConnectionInner {
         fn do_parse(inner: &mut ConnectionInner, packet: &[u8]) -> Result<(), ParseError> {
             let event = Event::parse(packet, &inner.ext_info)?;
             println!(
-                "server ({}): {:?}",
+                "server ({}): {}",
                 event.wire_sequence_number().unwrap_or(0),
-                event,
+                event.traced_with_atom_names(&|atom| inner.atom_name(atom)),
             );
             Ok(())
         }
@@ -185,8 +197,25 @@ impl ConnectionInner {
             }
 
             // Actually parse the reply
-            let (reply, _remaining) = (request.parser)(packet, &mut Vec::new())?;
-            println!("server ({}): {:?}", seqno, &reply);
+            let (reply, _remaining) = request
+                .request
+                .parse_reply(packet, &mut Vec::new())
+                .expect("only requests with replies are pending")?;
+            // Remember the names of atoms before printing, so that they already show up here
+            match (&request.request, &reply) {
+                (Request::InternAtom(request), Reply::InternAtom(reply)) => {
+                    inner.add_atom_name(reply.atom, &request.name);
+                }
+                (Request::GetAtomName(request), Reply::GetAtomName(reply)) => {
+                    inner.add_atom_name(request.atom, &reply.name);
+                }
+                _ => {}
+            }
+            println!(
+                "server ({}): {}",
+                seqno,
+                reply.traced_with_atom_names(&|atom| inner.atom_name(atom)),
+            );
 
             // If it is a reply to a QueryExtension request and the extension is present, update
             // our state (add the extension to our ext_info).
@@ -215,30 +244,35 @@ impl ConnectionInner {
             eprintln!("Error while parsing an X11 event: {:?}", e);
         }
     }
+
+    /// Remember the name of an atom
+    fn add_atom_name(&mut self, atom: impl Into<u32>, name: &[u8]) {
+        let atom = atom.into();
+        if atom != 0 {
+            let name = String::from_utf8_lossy(name).into_owned();
+            let _ = self.atom_names.insert(atom, name);
+        }
+    }
+
+    /// Look up the name of an atom
+    fn atom_name(&self, atom: u32) -> Option<String> {
+        self.atom_names.get(&atom).cloned()
+    }
 }
 
 /// Representation of a request that was not yet answered.
+#[derive(Debug)]
 struct PendingReply {
     /// The sequence number of the request.
     seqno: u16,
 
-    /// A function that can parse the reply.
-    parser: ReplyParsingFunction,
+    /// The request, which is used for parsing the reply.
+    request: Request<'static>,
 
     /// For QueryExtension requests: The extension that was queried
     queried_extension: Option<String>,
 }
 
-impl std::fmt::Debug for PendingReply {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("PendingReply")
-            .field("seqno", &self.seqno)
-            .field("parser", &"<???>")
-            .field("queried_extension", &self.queried_extension)
-            .finish()
-    }
-}
-
 /// Information about known extensions.
 #[derive(Debug, Default)]
 struct ExtInfo {
@@ -276,4 +310,11 @@ impl ExtInfoProvider for ExtInfo {
             .max_by_key(|(_, ext)| ext.first_event)
             .map(|(s, ext)| (s.as_ref(), *ext))
     }
+
+    fn get_from_extension_name(&self, extension_name: &str) -> Option<ExtensionInformation> {
+        self.exts
+            .iter()
+            .find(|(s, _)| s == extension_name)
+            .map(|(_, ext)| *ext)
+    }
 }